@@ -0,0 +1,258 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Binance account types, endpoints and stream names.
+
+use std::fmt::{Display, Formatter};
+
+use nautilus_model::{
+    data::bar::BarSpecification,
+    enums::{BarAggregation, PriceType},
+    identifiers::instrument_id::InstrumentId,
+};
+
+use super::BinanceError;
+
+/// The venue name of instruments on all Binance account types.
+pub const BINANCE_VENUE: &str = "BINANCE";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinanceAccountType {
+    Spot,
+    /// USD-M futures.
+    UsdtFutures,
+    /// COIN-M futures.
+    CoinFutures,
+}
+
+impl BinanceAccountType {
+    #[must_use]
+    pub fn http_base_url(&self, testnet: bool) -> &'static str {
+        match (self, testnet) {
+            (Self::Spot, false) => "https://api.binance.com",
+            (Self::Spot, true) => "https://testnet.binance.vision",
+            (Self::UsdtFutures, false) => "https://fapi.binance.com",
+            (Self::CoinFutures, false) => "https://dapi.binance.com",
+            (Self::UsdtFutures | Self::CoinFutures, true) => "https://testnet.binancefuture.com",
+        }
+    }
+
+    #[must_use]
+    pub fn ws_base_url(&self, testnet: bool) -> &'static str {
+        match (self, testnet) {
+            (Self::Spot, false) => "wss://stream.binance.com:9443",
+            (Self::Spot, true) => "wss://testnet.binance.vision",
+            (Self::UsdtFutures, false) => "wss://fstream.binance.com",
+            (Self::UsdtFutures, true) => "wss://stream.binancefuture.com",
+            (Self::CoinFutures, false) => "wss://dstream.binance.com",
+            (Self::CoinFutures, true) => "wss://dstream.binancefuture.com",
+        }
+    }
+
+    #[must_use]
+    pub fn is_futures(&self) -> bool {
+        matches!(self, Self::UsdtFutures | Self::CoinFutures)
+    }
+}
+
+impl Display for BinanceAccountType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Spot => "SPOT",
+            Self::UsdtFutures => "USDT_FUTURE",
+            Self::CoinFutures => "COIN_FUTURE",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl std::str::FromStr for BinanceAccountType {
+    type Err = BinanceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "SPOT" => Ok(Self::Spot),
+            "USDT_FUTURE" => Ok(Self::UsdtFutures),
+            "COIN_FUTURE" => Ok(Self::CoinFutures),
+            _ => Err(BinanceError::InvalidValue {
+                field: "account_type",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// The instrument details needed to convert messages for a Binance symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinanceInstrument {
+    pub instrument_id: InstrumentId,
+    pub price_precision: u8,
+    pub size_precision: u8,
+}
+
+impl BinanceInstrument {
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, price_precision: u8, size_precision: u8) -> Self {
+        Self {
+            instrument_id,
+            price_precision,
+            size_precision,
+        }
+    }
+}
+
+#[must_use]
+pub fn trade_stream(symbol: &str) -> String {
+    format!("{}@trade", symbol.to_lowercase())
+}
+
+#[must_use]
+pub fn agg_trade_stream(symbol: &str) -> String {
+    format!("{}@aggTrade", symbol.to_lowercase())
+}
+
+/// Returns the diff depth stream, updated every 100ms unless `update_speed_ms`
+/// is given.
+#[must_use]
+pub fn depth_stream(symbol: &str, update_speed_ms: Option<u64>) -> String {
+    match update_speed_ms {
+        Some(speed) => format!("{}@depth@{speed}ms", symbol.to_lowercase()),
+        None => format!("{}@depth@100ms", symbol.to_lowercase()),
+    }
+}
+
+#[must_use]
+pub fn kline_stream(symbol: &str, interval: &str) -> String {
+    format!("{}@kline_{interval}", symbol.to_lowercase())
+}
+
+/// Returns the JSON request subscribing to the `streams` on a single connection.
+#[must_use]
+pub fn subscribe_message(streams: &[String], id: u64) -> String {
+    serde_json::json!({
+        "method": "SUBSCRIBE",
+        "params": streams,
+        "id": id,
+    })
+    .to_string()
+}
+
+/// Returns the JSON request unsubscribing from the `streams`.
+#[must_use]
+pub fn unsubscribe_message(streams: &[String], id: u64) -> String {
+    serde_json::json!({
+        "method": "UNSUBSCRIBE",
+        "params": streams,
+        "id": id,
+    })
+    .to_string()
+}
+
+/// Parses a kline interval such as `1m` or `4h` into the `LAST` price bar
+/// specification Binance aggregates for it.
+pub fn parse_kline_interval(interval: &str) -> Result<BarSpecification, BinanceError> {
+    let invalid = || BinanceError::InvalidValue {
+        field: "interval",
+        value: interval.to_string(),
+    };
+
+    let split = interval.len().checked_sub(1).ok_or_else(invalid)?;
+    let (step, unit) = interval.split_at(split);
+    let step: u64 = step.parse().map_err(|_| invalid())?;
+    let aggregation = match unit {
+        "s" => BarAggregation::Second,
+        "m" => BarAggregation::Minute,
+        "h" => BarAggregation::Hour,
+        "d" => BarAggregation::Day,
+        "w" => BarAggregation::Week,
+        "M" => BarAggregation::Month,
+        _ => return Err(invalid()),
+    };
+    Ok(BarSpecification::new(step, aggregation, PriceType::Last))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("SPOT", BinanceAccountType::Spot)]
+    #[case("usdt_future", BinanceAccountType::UsdtFutures)]
+    #[case("COIN_FUTURE", BinanceAccountType::CoinFutures)]
+    fn test_account_type_round_trip(#[case] input: &str, #[case] expected: BinanceAccountType) {
+        let account_type: BinanceAccountType = input.parse().unwrap();
+
+        assert_eq!(account_type, expected);
+        assert_eq!(account_type.to_string(), input.to_uppercase());
+    }
+
+    #[test]
+    fn test_account_type_invalid() {
+        assert!("MARGIN".parse::<BinanceAccountType>().is_err());
+    }
+
+    #[test]
+    fn test_stream_names() {
+        assert_eq!(trade_stream("BTCUSDT"), "btcusdt@trade");
+        assert_eq!(agg_trade_stream("BTCUSDT"), "btcusdt@aggTrade");
+        assert_eq!(depth_stream("BTCUSDT", None), "btcusdt@depth@100ms");
+        assert_eq!(depth_stream("BTCUSDT", Some(250)), "btcusdt@depth@250ms");
+        assert_eq!(kline_stream("BTCUSDT", "1m"), "btcusdt@kline_1m");
+    }
+
+    #[test]
+    fn test_subscribe_message() {
+        let streams = vec![trade_stream("BTCUSDT"), trade_stream("ETHUSDT")];
+
+        assert_eq!(
+            subscribe_message(&streams, 1),
+            r#"{"id":1,"method":"SUBSCRIBE","params":["btcusdt@trade","ethusdt@trade"]}"#
+        );
+    }
+
+    #[rstest]
+    #[case("1s", 1, BarAggregation::Second)]
+    #[case("15m", 15, BarAggregation::Minute)]
+    #[case("4h", 4, BarAggregation::Hour)]
+    #[case("1d", 1, BarAggregation::Day)]
+    #[case("1w", 1, BarAggregation::Week)]
+    #[case("1M", 1, BarAggregation::Month)]
+    fn test_parse_kline_interval(
+        #[case] interval: &str,
+        #[case] step: u64,
+        #[case] aggregation: BarAggregation,
+    ) {
+        let spec = parse_kline_interval(interval).unwrap();
+
+        assert_eq!(
+            spec,
+            BarSpecification::new(step, aggregation, PriceType::Last)
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("m")]
+    #[case("1y")]
+    #[case("xm")]
+    fn test_parse_kline_interval_invalid(#[case] interval: &str) {
+        assert!(parse_kline_interval(interval).is_err());
+    }
+}
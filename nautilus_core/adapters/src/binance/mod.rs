@@ -0,0 +1,44 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The core of the Binance adapter.
+//!
+//! Provides signing of REST requests, parsing of WebSocket stream and user
+//! data messages, and their conversion into Nautilus model types for spot and
+//! futures accounts. The Python adapter drives I/O and subscriptions through
+//! the [`python::BinanceClient`].
+
+pub mod common;
+pub mod parsing;
+pub mod python;
+pub mod signing;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum BinanceError {
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid decimal '{0}'")]
+    InvalidDecimal(String),
+    #[error("Invalid {field} '{value}'")]
+    InvalidValue { field: &'static str, value: String },
+    #[error("Unsupported event '{0}'")]
+    UnsupportedEvent(String),
+    #[error("Unknown symbol '{0}'")]
+    UnknownSymbol(String),
+    #[error("Credentials required to sign requests")]
+    MissingCredentials,
+}
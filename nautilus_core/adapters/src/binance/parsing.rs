@@ -0,0 +1,926 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Deserialization of Binance WebSocket and REST messages, and parsing into
+//! Nautilus model types.
+//!
+//! Binance sends prices and quantities as decimal strings with trailing zeros,
+//! so they are parsed at the precision of the [`BinanceInstrument`] rather than
+//! the precision implied by the string.
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        book::{BookOrder, OrderBookDelta},
+        tick::TradeTick,
+        Data,
+    },
+    enums::{
+        AggregationSource, AggressorSide, BookAction, OrderSide, OrderStatus, OrderType,
+        TimeInForce,
+    },
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, trade_id::TradeId,
+        venue_order_id::VenueOrderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+use pyo3::prelude::*;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{
+    common::{parse_kline_interval, BinanceInstrument},
+    BinanceError,
+};
+
+const NANOS_PER_MILLI: u64 = 1_000_000;
+
+/// A price level as `[price, quantity]`.
+pub type BinanceLevel = (String, String);
+
+/// A raw trade, from the `<symbol>@trade` stream.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BinanceTradeMsg {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "t")]
+    pub trade_id: u64,
+    #[serde(rename = "p")]
+    pub price: String,
+    #[serde(rename = "q")]
+    pub quantity: String,
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+/// An aggregated trade, from the `<symbol>@aggTrade` stream.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BinanceAggTradeMsg {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "a")]
+    pub agg_trade_id: u64,
+    #[serde(rename = "p")]
+    pub price: String,
+    #[serde(rename = "q")]
+    pub quantity: String,
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+/// A diff depth update, from the `<symbol>@depth` stream.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BinanceDepthUpdateMsg {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// The transaction time, futures only.
+    #[serde(rename = "T")]
+    pub transaction_time: Option<u64>,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    #[serde(rename = "u")]
+    pub final_update_id: u64,
+    /// The final update ID of the previous message, futures only.
+    #[serde(rename = "pu")]
+    pub prev_final_update_id: Option<u64>,
+    #[serde(rename = "b")]
+    pub bids: Vec<BinanceLevel>,
+    #[serde(rename = "a")]
+    pub asks: Vec<BinanceLevel>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BinanceKline {
+    #[serde(rename = "t")]
+    pub open_time: u64,
+    #[serde(rename = "T")]
+    pub close_time: u64,
+    #[serde(rename = "i")]
+    pub interval: String,
+    #[serde(rename = "o")]
+    pub open: String,
+    #[serde(rename = "h")]
+    pub high: String,
+    #[serde(rename = "l")]
+    pub low: String,
+    #[serde(rename = "c")]
+    pub close: String,
+    #[serde(rename = "v")]
+    pub volume: String,
+    #[serde(rename = "x")]
+    pub is_closed: bool,
+}
+
+/// A kline update, from the `<symbol>@kline_<interval>` stream.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BinanceKlineMsg {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "k")]
+    pub kline: BinanceKline,
+}
+
+/// The order fields shared by spot `executionReport` and futures
+/// `ORDER_TRADE_UPDATE` user data events.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BinanceOrderData {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    /// The original client order ID of a canceled order, spot only.
+    #[serde(rename = "C", default)]
+    pub orig_client_order_id: Option<String>,
+    #[serde(rename = "S")]
+    pub side: String,
+    #[serde(rename = "o")]
+    pub order_type: String,
+    #[serde(rename = "f")]
+    pub time_in_force: String,
+    #[serde(rename = "q")]
+    pub quantity: String,
+    #[serde(rename = "p")]
+    pub price: String,
+    #[serde(rename = "x")]
+    pub exec_type: String,
+    #[serde(rename = "X")]
+    pub order_status: String,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "l")]
+    pub last_qty: String,
+    #[serde(rename = "z")]
+    pub cum_qty: String,
+    #[serde(rename = "L")]
+    pub last_px: String,
+    #[serde(rename = "n", default)]
+    pub commission: Option<String>,
+    #[serde(rename = "N", default)]
+    pub commission_asset: Option<String>,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    /// The trade ID, `-1` (spot) or `0` (futures) if not a fill.
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+}
+
+/// A futures order update, wrapping the order fields under `o`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BinanceOrderTradeUpdateMsg {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "o")]
+    pub order: BinanceOrderData,
+}
+
+/// An order book snapshot, from the REST `depth` endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BinanceOrderBookSnapshot {
+    #[serde(rename = "lastUpdateId")]
+    pub last_update_id: u64,
+    pub bids: Vec<BinanceLevel>,
+    pub asks: Vec<BinanceLevel>,
+}
+
+/// A WebSocket event, keyed by its `e` field.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "e")]
+pub enum BinanceWsEvent {
+    #[serde(rename = "trade")]
+    Trade(BinanceTradeMsg),
+    #[serde(rename = "aggTrade")]
+    AggTrade(BinanceAggTradeMsg),
+    #[serde(rename = "depthUpdate")]
+    DepthUpdate(BinanceDepthUpdateMsg),
+    #[serde(rename = "kline")]
+    Kline(BinanceKlineMsg),
+    #[serde(rename = "executionReport")]
+    ExecutionReport(BinanceOrderData),
+    #[serde(rename = "ORDER_TRADE_UPDATE")]
+    OrderTradeUpdate(BinanceOrderTradeUpdateMsg),
+}
+
+impl BinanceWsEvent {
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        match self {
+            Self::Trade(msg) => &msg.symbol,
+            Self::AggTrade(msg) => &msg.symbol,
+            Self::DepthUpdate(msg) => &msg.symbol,
+            Self::Kline(msg) => &msg.symbol,
+            Self::ExecutionReport(order) => &order.symbol,
+            Self::OrderTradeUpdate(msg) => &msg.order.symbol,
+        }
+    }
+}
+
+/// Parses a raw WebSocket message, unwrapping combined stream messages.
+///
+/// Returns `None` for messages which are not events, such as responses to
+/// subscription requests.
+///
+/// # Errors
+///
+/// If the message is not valid JSON or is an unsupported event.
+pub fn parse_ws_message(data: &[u8]) -> Result<Option<BinanceWsEvent>, BinanceError> {
+    let mut value: Value = serde_json::from_slice(data)?;
+    if value.get("stream").is_some() {
+        value = value
+            .get_mut("data")
+            .map(Value::take)
+            .unwrap_or(Value::Null);
+    }
+
+    let event = match value.get("e").and_then(Value::as_str) {
+        Some(event) => event,
+        None => return Ok(None),
+    };
+    match event {
+        "trade" | "aggTrade" | "depthUpdate" | "kline" | "executionReport"
+        | "ORDER_TRADE_UPDATE" => Ok(Some(serde_json::from_value(value)?)),
+        other => Err(BinanceError::UnsupportedEvent(other.to_string())),
+    }
+}
+
+/// The execution details of a Binance order update, in model types.
+#[derive(Debug, Clone, PartialEq)]
+#[pyclass]
+pub struct BinanceExecutionReport {
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: VenueOrderId,
+    pub order_side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    pub order_status: OrderStatus,
+    /// The raw Binance execution type, such as `NEW`, `TRADE` or `CANCELED`.
+    pub exec_type: String,
+    pub quantity: Quantity,
+    pub price: Price,
+    pub last_qty: Quantity,
+    pub last_px: Price,
+    pub cum_qty: Quantity,
+    pub commission: f64,
+    pub commission_asset: Option<String>,
+    /// The trade ID if the update is a fill.
+    pub trade_id: Option<TradeId>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl BinanceExecutionReport {
+    #[must_use]
+    pub fn is_fill(&self) -> bool {
+        self.trade_id.is_some()
+    }
+}
+
+/// Parses the `value` at the instrument price precision.
+pub fn parse_price(value: &str, precision: u8) -> Result<Price, BinanceError> {
+    let float: f64 = value
+        .parse()
+        .map_err(|_| BinanceError::InvalidDecimal(value.to_string()))?;
+    Ok(Price::new(float, precision))
+}
+
+/// Parses the `value` at the instrument size precision.
+pub fn parse_quantity(value: &str, precision: u8) -> Result<Quantity, BinanceError> {
+    let float: f64 = value
+        .parse()
+        .map_err(|_| BinanceError::InvalidDecimal(value.to_string()))?;
+    if float < 0.0 {
+        return Err(BinanceError::InvalidDecimal(value.to_string()));
+    }
+    Ok(Quantity::new(float, precision))
+}
+
+#[must_use]
+pub fn millis_to_nanos(millis: u64) -> UnixNanos {
+    millis * NANOS_PER_MILLI
+}
+
+/// Returns the aggressor of a trade, the seller if the buyer was the maker.
+#[must_use]
+pub fn parse_aggressor_side(is_buyer_maker: bool) -> AggressorSide {
+    if is_buyer_maker {
+        AggressorSide::Seller
+    } else {
+        AggressorSide::Buyer
+    }
+}
+
+pub fn parse_order_side(side: &str) -> Result<OrderSide, BinanceError> {
+    match side {
+        "BUY" => Ok(OrderSide::Buy),
+        "SELL" => Ok(OrderSide::Sell),
+        _ => Err(invalid("side", side)),
+    }
+}
+
+/// Parses an order type, where `TAKE_PROFIT` is a market order on spot but a
+/// limit order on futures.
+pub fn parse_order_type(order_type: &str, is_futures: bool) -> Result<OrderType, BinanceError> {
+    match order_type {
+        "LIMIT" | "LIMIT_MAKER" => Ok(OrderType::Limit),
+        "MARKET" | "LIQUIDATION" => Ok(OrderType::Market),
+        "STOP_LOSS" | "STOP_MARKET" => Ok(OrderType::StopMarket),
+        "STOP_LOSS_LIMIT" | "STOP" => Ok(OrderType::StopLimit),
+        "TAKE_PROFIT" if is_futures => Ok(OrderType::LimitIfTouched),
+        "TAKE_PROFIT" | "TAKE_PROFIT_MARKET" => Ok(OrderType::MarketIfTouched),
+        "TAKE_PROFIT_LIMIT" => Ok(OrderType::LimitIfTouched),
+        "TRAILING_STOP_MARKET" => Ok(OrderType::TrailingStopMarket),
+        _ => Err(invalid("order_type", order_type)),
+    }
+}
+
+pub fn parse_time_in_force(time_in_force: &str) -> Result<TimeInForce, BinanceError> {
+    match time_in_force {
+        // GTX is post only, which is good till canceled
+        "GTC" | "GTX" => Ok(TimeInForce::Gtc),
+        "IOC" => Ok(TimeInForce::Ioc),
+        "FOK" => Ok(TimeInForce::Fok),
+        "GTD" => Ok(TimeInForce::Gtd),
+        _ => Err(invalid("time_in_force", time_in_force)),
+    }
+}
+
+pub fn parse_order_status(status: &str) -> Result<OrderStatus, BinanceError> {
+    match status {
+        "NEW" => Ok(OrderStatus::Accepted),
+        "PARTIALLY_FILLED" => Ok(OrderStatus::PartiallyFilled),
+        "FILLED" => Ok(OrderStatus::Filled),
+        "CANCELED" => Ok(OrderStatus::Canceled),
+        "PENDING_CANCEL" => Ok(OrderStatus::PendingCancel),
+        "REJECTED" => Ok(OrderStatus::Rejected),
+        "EXPIRED" | "EXPIRED_IN_MATCH" => Ok(OrderStatus::Expired),
+        _ => Err(invalid("order_status", status)),
+    }
+}
+
+pub fn parse_trade(
+    msg: &BinanceTradeMsg,
+    instrument: &BinanceInstrument,
+    ts_init: UnixNanos,
+) -> Result<TradeTick, BinanceError> {
+    Ok(TradeTick::new(
+        instrument.instrument_id.clone(),
+        parse_price(&msg.price, instrument.price_precision)?,
+        parse_quantity(&msg.quantity, instrument.size_precision)?,
+        parse_aggressor_side(msg.is_buyer_maker),
+        TradeId::new(&msg.trade_id.to_string()),
+        millis_to_nanos(msg.trade_time),
+        ts_init,
+    ))
+}
+
+pub fn parse_agg_trade(
+    msg: &BinanceAggTradeMsg,
+    instrument: &BinanceInstrument,
+    ts_init: UnixNanos,
+) -> Result<TradeTick, BinanceError> {
+    Ok(TradeTick::new(
+        instrument.instrument_id.clone(),
+        parse_price(&msg.price, instrument.price_precision)?,
+        parse_quantity(&msg.quantity, instrument.size_precision)?,
+        parse_aggressor_side(msg.is_buyer_maker),
+        TradeId::new(&msg.agg_trade_id.to_string()),
+        millis_to_nanos(msg.trade_time),
+        ts_init,
+    ))
+}
+
+fn parse_levels(
+    levels: &[BinanceLevel],
+    side: OrderSide,
+    instrument: &BinanceInstrument,
+    sequence: u64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    deltas: &mut Vec<OrderBookDelta>,
+) -> Result<(), BinanceError> {
+    for (price, size) in levels {
+        let price = parse_price(price, instrument.price_precision)?;
+        let size = parse_quantity(size, instrument.size_precision)?;
+        let action = if size.raw == 0 {
+            BookAction::Delete
+        } else {
+            BookAction::Update
+        };
+        deltas.push(OrderBookDelta::new(
+            instrument.instrument_id.clone(),
+            action,
+            BookOrder::new(side, price, size, 0),
+            0,
+            sequence,
+            ts_event,
+            ts_init,
+        ));
+    }
+    Ok(())
+}
+
+/// Parses a diff depth update into deltas, deleting levels with a zero
+/// quantity and updating the rest.
+pub fn parse_depth_update(
+    msg: &BinanceDepthUpdateMsg,
+    instrument: &BinanceInstrument,
+    ts_init: UnixNanos,
+) -> Result<Vec<OrderBookDelta>, BinanceError> {
+    let ts_event = millis_to_nanos(msg.transaction_time.unwrap_or(msg.event_time));
+    let sequence = msg.final_update_id;
+
+    let mut deltas = Vec::with_capacity(msg.bids.len() + msg.asks.len());
+    parse_levels(
+        &msg.bids,
+        OrderSide::Buy,
+        instrument,
+        sequence,
+        ts_event,
+        ts_init,
+        &mut deltas,
+    )?;
+    parse_levels(
+        &msg.asks,
+        OrderSide::Sell,
+        instrument,
+        sequence,
+        ts_event,
+        ts_init,
+        &mut deltas,
+    )?;
+    Ok(deltas)
+}
+
+/// Parses a snapshot into deltas which clear the book, then add every level.
+pub fn parse_book_snapshot(
+    snapshot: &BinanceOrderBookSnapshot,
+    instrument: &BinanceInstrument,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> Result<Vec<OrderBookDelta>, BinanceError> {
+    let sequence = snapshot.last_update_id;
+    let clear = BookOrder::new(
+        OrderSide::NoOrderSide,
+        Price::from_raw(0, instrument.price_precision),
+        Quantity::from_raw(0, instrument.size_precision),
+        0,
+    );
+
+    let mut deltas = Vec::with_capacity(1 + snapshot.bids.len() + snapshot.asks.len());
+    deltas.push(OrderBookDelta::new(
+        instrument.instrument_id.clone(),
+        BookAction::Clear,
+        clear,
+        0,
+        sequence,
+        ts_event,
+        ts_init,
+    ));
+    parse_levels(
+        &snapshot.bids,
+        OrderSide::Buy,
+        instrument,
+        sequence,
+        ts_event,
+        ts_init,
+        &mut deltas,
+    )?;
+    parse_levels(
+        &snapshot.asks,
+        OrderSide::Sell,
+        instrument,
+        sequence,
+        ts_event,
+        ts_init,
+        &mut deltas,
+    )?;
+
+    for delta in deltas.iter_mut().skip(1) {
+        delta.action = BookAction::Add;
+    }
+    Ok(deltas)
+}
+
+/// Parses a kline into a bar, or `None` while the kline is still open.
+pub fn parse_kline(
+    msg: &BinanceKlineMsg,
+    instrument: &BinanceInstrument,
+    ts_init: UnixNanos,
+) -> Result<Option<Bar>, BinanceError> {
+    let kline = &msg.kline;
+    if !kline.is_closed {
+        return Ok(None);
+    }
+
+    let bar_type = BarType::new(
+        instrument.instrument_id.clone(),
+        parse_kline_interval(&kline.interval)?,
+        AggregationSource::External,
+    );
+    let precision = instrument.price_precision;
+    Ok(Some(Bar::new(
+        bar_type,
+        parse_price(&kline.open, precision)?,
+        parse_price(&kline.high, precision)?,
+        parse_price(&kline.low, precision)?,
+        parse_price(&kline.close, precision)?,
+        parse_quantity(&kline.volume, instrument.size_precision)?,
+        millis_to_nanos(kline.close_time),
+        ts_init,
+    )))
+}
+
+/// Parses the market data in a WebSocket event, returning no data for user
+/// data events and open klines.
+pub fn parse_market_data(
+    event: &BinanceWsEvent,
+    instrument: &BinanceInstrument,
+    ts_init: UnixNanos,
+) -> Result<Vec<Data>, BinanceError> {
+    let data = match event {
+        BinanceWsEvent::Trade(msg) => vec![Data::Trade(parse_trade(msg, instrument, ts_init)?)],
+        BinanceWsEvent::AggTrade(msg) => {
+            vec![Data::Trade(parse_agg_trade(msg, instrument, ts_init)?)]
+        }
+        BinanceWsEvent::DepthUpdate(msg) => parse_depth_update(msg, instrument, ts_init)?
+            .into_iter()
+            .map(Data::Delta)
+            .collect(),
+        BinanceWsEvent::Kline(msg) => parse_kline(msg, instrument, ts_init)?
+            .map(Data::Bar)
+            .into_iter()
+            .collect(),
+        BinanceWsEvent::ExecutionReport(_) | BinanceWsEvent::OrderTradeUpdate(_) => Vec::new(),
+    };
+    Ok(data)
+}
+
+/// Parses an order update into an execution report.
+///
+/// The client order ID of a canceled spot order is the original, not the ID
+/// of the cancel request.
+pub fn parse_execution_report(
+    order: &BinanceOrderData,
+    instrument: &BinanceInstrument,
+    is_futures: bool,
+    ts_init: UnixNanos,
+) -> Result<BinanceExecutionReport, BinanceError> {
+    let client_order_id = match &order.orig_client_order_id {
+        Some(orig) if !orig.is_empty() => orig,
+        _ => &order.client_order_id,
+    };
+    let commission = match &order.commission {
+        Some(commission) => commission
+            .parse()
+            .map_err(|_| BinanceError::InvalidDecimal(commission.to_string()))?,
+        None => 0.0,
+    };
+    let trade_id = if order.trade_id > 0 {
+        Some(TradeId::new(&order.trade_id.to_string()))
+    } else {
+        None
+    };
+
+    Ok(BinanceExecutionReport {
+        instrument_id: instrument.instrument_id.clone(),
+        client_order_id: ClientOrderId::new(client_order_id),
+        venue_order_id: VenueOrderId::new(&order.order_id.to_string()),
+        order_side: parse_order_side(&order.side)?,
+        order_type: parse_order_type(&order.order_type, is_futures)?,
+        time_in_force: parse_time_in_force(&order.time_in_force)?,
+        order_status: parse_order_status(&order.order_status)?,
+        exec_type: order.exec_type.clone(),
+        quantity: parse_quantity(&order.quantity, instrument.size_precision)?,
+        price: parse_price(&order.price, instrument.price_precision)?,
+        last_qty: parse_quantity(&order.last_qty, instrument.size_precision)?,
+        last_px: parse_price(&order.last_px, instrument.price_precision)?,
+        cum_qty: parse_quantity(&order.cum_qty, instrument.size_precision)?,
+        commission,
+        commission_asset: order.commission_asset.clone(),
+        trade_id,
+        ts_event: millis_to_nanos(order.transaction_time),
+        ts_init,
+    })
+}
+
+fn invalid(field: &'static str, value: &str) -> BinanceError {
+    BinanceError::InvalidValue {
+        field,
+        value: value.to_string(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::enums::BarAggregation;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn instrument() -> BinanceInstrument {
+        BinanceInstrument::new(InstrumentId::from_str("BTCUSDT.BINANCE").unwrap(), 2, 5)
+    }
+
+    const TRADE: &str = r#"{"e":"trade","E":1672515782136,"s":"BTCUSDT","t":12345,
+        "p":"16500.01000000","q":"0.00100000","b":88,"a":50,"T":1672515782134,"m":true,"M":true}"#;
+
+    const DEPTH_UPDATE: &str = r#"{"e":"depthUpdate","E":1672515782136,"s":"BTCUSDT",
+        "U":157,"u":160,"b":[["16500.00","0.50000"],["16499.00","0.00000"]],
+        "a":[["16501.00","1.25000"]]}"#;
+
+    const EXECUTION_REPORT: &str = r#"{"e":"executionReport","E":1499405658658,"s":"BTCUSDT",
+        "c":"O-20230101-000001","S":"BUY","o":"LIMIT","f":"GTC","q":"1.00000000",
+        "p":"16500.00000000","P":"0.00000000","F":"0.00000000","g":-1,"C":"","x":"TRADE",
+        "X":"PARTIALLY_FILLED","r":"NONE","i":4293153,"l":"0.25000000","z":"0.25000000",
+        "L":"16500.00000000","n":"0.00025000","N":"BNB","T":1499405658657,"t":987,
+        "I":8641984,"w":false,"m":false,"M":false,"O":1499405658657}"#;
+
+    const ORDER_TRADE_UPDATE: &str = r#"{"e":"ORDER_TRADE_UPDATE","E":1568879465651,
+        "T":1568879465650,"o":{"s":"BTCUSDT","c":"O-1","S":"SELL","o":"TAKE_PROFIT",
+        "f":"GTX","q":"0.001","p":"7103.04","ap":"0","sp":"7100","x":"NEW","X":"NEW",
+        "i":8886774,"l":"0","z":"0","L":"0","N":"USDT","n":"0","T":1568879465651,"t":0,
+        "b":"0","a":"9.91","m":false,"R":false,"wt":"CONTRACT_PRICE","ot":"TAKE_PROFIT",
+        "ps":"LONG","cp":false,"rp":"0"}}"#;
+
+    fn kline(is_closed: bool) -> String {
+        format!(
+            r#"{{"e":"kline","E":1672515782136,"s":"BTCUSDT","k":{{"t":1672515720000,
+            "T":1672515779999,"s":"BTCUSDT","i":"1m","f":100,"L":200,"o":"16500.00",
+            "c":"16510.00","h":"16520.00","l":"16490.00","v":"12.50000","n":100,
+            "x":{is_closed},"q":"206250.00","V":"6.00000","Q":"99000.00","B":"0"}}}}"#
+        )
+    }
+
+    fn event(data: &str) -> BinanceWsEvent {
+        parse_ws_message(data.as_bytes()).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_parse_ws_message_combined_stream() {
+        let combined = format!(r#"{{"stream":"btcusdt@trade","data":{TRADE}}}"#);
+
+        let event = parse_ws_message(combined.as_bytes()).unwrap().unwrap();
+
+        assert!(matches!(event, BinanceWsEvent::Trade(_)));
+        assert_eq!(event.symbol(), "BTCUSDT");
+    }
+
+    #[test]
+    fn test_parse_ws_message_subscription_response() {
+        assert_eq!(
+            parse_ws_message(br#"{"result":null,"id":1}"#).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_ws_message_unsupported_event() {
+        let result = parse_ws_message(br#"{"e":"24hrTicker","s":"BTCUSDT"}"#);
+
+        assert!(matches!(result, Err(BinanceError::UnsupportedEvent(e)) if e == "24hrTicker"));
+    }
+
+    #[test]
+    fn test_parse_ws_message_invalid_json() {
+        assert!(matches!(parse_ws_message(b"{"), Err(BinanceError::Json(_))));
+    }
+
+    #[test]
+    fn test_parse_trade() {
+        let msg = match event(TRADE) {
+            BinanceWsEvent::Trade(msg) => msg,
+            other => panic!("unexpected event {other:?}"),
+        };
+
+        let trade = parse_trade(&msg, &instrument(), 1).unwrap();
+
+        assert_eq!(trade.instrument_id, instrument().instrument_id);
+        assert_eq!(trade.price, Price::from("16500.01"));
+        assert_eq!(trade.size, Quantity::from("0.00100"));
+        assert_eq!(trade.aggressor_side, AggressorSide::Seller);
+        assert_eq!(trade.trade_id, TradeId::new("12345"));
+        assert_eq!(trade.ts_event, 1_672_515_782_134_000_000);
+        assert_eq!(trade.ts_init, 1);
+    }
+
+    #[test]
+    fn test_parse_depth_update() {
+        let msg = match event(DEPTH_UPDATE) {
+            BinanceWsEvent::DepthUpdate(msg) => msg,
+            other => panic!("unexpected event {other:?}"),
+        };
+
+        let deltas = parse_depth_update(&msg, &instrument(), 0).unwrap();
+
+        assert_eq!(deltas.len(), 3);
+        assert_eq!(deltas[0].action, BookAction::Update);
+        assert_eq!(deltas[0].order.side, OrderSide::Buy);
+        assert_eq!(deltas[0].order.price, Price::from("16500.00"));
+        assert_eq!(deltas[1].action, BookAction::Delete);
+        assert_eq!(deltas[2].order.side, OrderSide::Sell);
+        assert_eq!(deltas[2].order.size, Quantity::from("1.25000"));
+        assert!(deltas.iter().all(|delta| delta.sequence == 160));
+        assert!(deltas
+            .iter()
+            .all(|delta| delta.ts_event == 1_672_515_782_136_000_000));
+    }
+
+    #[test]
+    fn test_parse_book_snapshot() {
+        let snapshot: BinanceOrderBookSnapshot = serde_json::from_str(
+            r#"{"lastUpdateId":1027024,"bids":[["4.00000000","431.00000000"]],
+            "asks":[["4.00000200","12.00000000"]]}"#,
+        )
+        .unwrap();
+        let instrument = BinanceInstrument::new(instrument().instrument_id, 8, 8);
+
+        let deltas = parse_book_snapshot(&snapshot, &instrument, 1, 2).unwrap();
+
+        let actions: Vec<BookAction> = deltas.iter().map(|delta| delta.action).collect();
+        assert_eq!(
+            actions,
+            vec![BookAction::Clear, BookAction::Add, BookAction::Add]
+        );
+        assert_eq!(deltas[2].order.price, Price::from("4.00000200"));
+        assert!(deltas.iter().all(|delta| delta.sequence == 1_027_024));
+    }
+
+    #[test]
+    fn test_parse_kline_closed() {
+        let msg = match event(&kline(true)) {
+            BinanceWsEvent::Kline(msg) => msg,
+            other => panic!("unexpected event {other:?}"),
+        };
+
+        let bar = parse_kline(&msg, &instrument(), 0).unwrap().unwrap();
+
+        assert_eq!(bar.bar_type.spec.step, 1);
+        assert_eq!(bar.bar_type.spec.aggregation, BarAggregation::Minute);
+        assert_eq!(bar.bar_type.aggregation_source, AggregationSource::External);
+        assert_eq!(bar.open, Price::from("16500.00"));
+        assert_eq!(bar.high, Price::from("16520.00"));
+        assert_eq!(bar.low, Price::from("16490.00"));
+        assert_eq!(bar.close, Price::from("16510.00"));
+        assert_eq!(bar.volume, Quantity::from("12.50000"));
+        assert_eq!(bar.ts_event, 1_672_515_779_999_000_000);
+    }
+
+    #[test]
+    fn test_parse_kline_open_is_skipped() {
+        let data = parse_market_data(&event(&kline(false)), &instrument(), 0).unwrap();
+
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn test_parse_market_data() {
+        let instrument = instrument();
+
+        assert_eq!(
+            parse_market_data(&event(TRADE), &instrument, 0)
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(
+            parse_market_data(&event(DEPTH_UPDATE), &instrument, 0)
+                .unwrap()
+                .len(),
+            3
+        );
+        assert!(parse_market_data(&event(EXECUTION_REPORT), &instrument, 0)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parse_spot_execution_report() {
+        let order = match event(EXECUTION_REPORT) {
+            BinanceWsEvent::ExecutionReport(order) => order,
+            other => panic!("unexpected event {other:?}"),
+        };
+
+        let report = parse_execution_report(&order, &instrument(), false, 0).unwrap();
+
+        assert_eq!(
+            report.client_order_id,
+            ClientOrderId::new("O-20230101-000001")
+        );
+        assert_eq!(report.venue_order_id, VenueOrderId::new("4293153"));
+        assert_eq!(report.order_side, OrderSide::Buy);
+        assert_eq!(report.order_type, OrderType::Limit);
+        assert_eq!(report.time_in_force, TimeInForce::Gtc);
+        assert_eq!(report.order_status, OrderStatus::PartiallyFilled);
+        assert_eq!(report.exec_type, "TRADE");
+        assert_eq!(report.last_qty, Quantity::from("0.25000"));
+        assert_eq!(report.last_px, Price::from("16500.00"));
+        assert_eq!(report.commission, 0.00025);
+        assert_eq!(report.commission_asset.as_deref(), Some("BNB"));
+        assert_eq!(report.trade_id, Some(TradeId::new("987")));
+        assert!(report.is_fill());
+        assert_eq!(report.ts_event, 1_499_405_658_657_000_000);
+    }
+
+    #[test]
+    fn test_parse_spot_cancel_uses_original_client_order_id() {
+        let data = EXECUTION_REPORT
+            .replace(r#""C":"""#, r#""C":"O-20230101-000001""#)
+            .replace("O-20230101-000001\",\"S\"", "cancel-1\",\"S\"")
+            .replace(r#""x":"TRADE""#, r#""x":"CANCELED""#)
+            .replace(r#""X":"PARTIALLY_FILLED""#, r#""X":"CANCELED""#)
+            .replace(r#""t":987"#, r#""t":-1"#);
+        let order = match event(&data) {
+            BinanceWsEvent::ExecutionReport(order) => order,
+            other => panic!("unexpected event {other:?}"),
+        };
+        assert_eq!(order.client_order_id, "cancel-1");
+
+        let report = parse_execution_report(&order, &instrument(), false, 0).unwrap();
+
+        assert_eq!(
+            report.client_order_id,
+            ClientOrderId::new("O-20230101-000001")
+        );
+        assert_eq!(report.order_status, OrderStatus::Canceled);
+        assert!(!report.is_fill());
+    }
+
+    #[test]
+    fn test_parse_futures_order_trade_update() {
+        let msg = match event(ORDER_TRADE_UPDATE) {
+            BinanceWsEvent::OrderTradeUpdate(msg) => msg,
+            other => panic!("unexpected event {other:?}"),
+        };
+
+        let report = parse_execution_report(&msg.order, &instrument(), true, 0).unwrap();
+
+        assert_eq!(report.order_side, OrderSide::Sell);
+        assert_eq!(report.order_type, OrderType::LimitIfTouched);
+        assert_eq!(report.time_in_force, TimeInForce::Gtc);
+        assert_eq!(report.order_status, OrderStatus::Accepted);
+        assert_eq!(report.price, Price::from("7103.04"));
+        assert_eq!(report.trade_id, None);
+    }
+
+    #[rstest]
+    #[case("TAKE_PROFIT", false, OrderType::MarketIfTouched)]
+    #[case("TAKE_PROFIT", true, OrderType::LimitIfTouched)]
+    #[case("STOP_LOSS_LIMIT", false, OrderType::StopLimit)]
+    #[case("STOP_MARKET", true, OrderType::StopMarket)]
+    #[case("LIMIT_MAKER", false, OrderType::Limit)]
+    fn test_parse_order_type(
+        #[case] order_type: &str,
+        #[case] is_futures: bool,
+        #[case] expected: OrderType,
+    ) {
+        assert_eq!(parse_order_type(order_type, is_futures).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("16500.01", true)]
+    #[case("0.00000000", true)]
+    #[case("-1", false)]
+    #[case("abc", false)]
+    fn test_parse_quantity(#[case] value: &str, #[case] valid: bool) {
+        assert_eq!(parse_quantity(value, 8).is_ok(), valid);
+    }
+
+    #[test]
+    fn test_parse_price_uses_instrument_precision() {
+        let price = parse_price("16500.01000000", 2).unwrap();
+
+        assert_eq!(price.precision, 2);
+        assert_eq!(price, Price::from("16500.01"));
+    }
+}
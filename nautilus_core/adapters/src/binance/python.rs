@@ -0,0 +1,430 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The Python interface to the Binance adapter core.
+
+use std::{collections::HashMap, str::FromStr};
+
+use nautilus_core::{cvec::CVec, time::UnixNanos};
+use nautilus_model::{
+    data::Data,
+    enums::{OrderSide, OrderStatus, OrderType, TimeInForce},
+    identifiers::instrument_id::InstrumentId,
+};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyCapsule};
+
+use super::{
+    common::{subscribe_message, unsubscribe_message, BinanceAccountType, BinanceInstrument},
+    parsing::{
+        parse_book_snapshot, parse_execution_report, parse_market_data, parse_ws_message,
+        BinanceExecutionReport, BinanceOrderBookSnapshot, BinanceWsEvent,
+    },
+    signing::BinanceCredential,
+    BinanceError,
+};
+
+/// Signs requests and converts messages for a single Binance account type.
+///
+/// Instruments must be added for each symbol before its messages are parsed.
+#[pyclass]
+pub struct BinanceClient {
+    account_type: BinanceAccountType,
+    testnet: bool,
+    credential: Option<BinanceCredential>,
+    instruments: HashMap<String, BinanceInstrument>,
+}
+
+impl BinanceClient {
+    #[must_use]
+    pub fn new(
+        account_type: BinanceAccountType,
+        testnet: bool,
+        credential: Option<BinanceCredential>,
+    ) -> Self {
+        Self {
+            account_type,
+            testnet,
+            credential,
+            instruments: HashMap::new(),
+        }
+    }
+
+    pub fn add_instrument(&mut self, symbol: &str, instrument: BinanceInstrument) {
+        self.instruments.insert(symbol.to_uppercase(), instrument);
+    }
+
+    pub fn instrument(&self, symbol: &str) -> Result<&BinanceInstrument, BinanceError> {
+        self.instruments
+            .get(&symbol.to_uppercase())
+            .ok_or_else(|| BinanceError::UnknownSymbol(symbol.to_string()))
+    }
+
+    fn credential(&self) -> Result<&BinanceCredential, BinanceError> {
+        self.credential
+            .as_ref()
+            .ok_or(BinanceError::MissingCredentials)
+    }
+
+    /// Parses the market data in a WebSocket message, returning no data for
+    /// other messages.
+    pub fn parse_market_data(
+        &self,
+        data: &[u8],
+        ts_init: UnixNanos,
+    ) -> Result<Vec<Data>, BinanceError> {
+        let event = match parse_ws_message(data)? {
+            Some(event) => event,
+            None => return Ok(Vec::new()),
+        };
+        if matches!(
+            event,
+            BinanceWsEvent::ExecutionReport(_) | BinanceWsEvent::OrderTradeUpdate(_)
+        ) {
+            return Ok(Vec::new());
+        }
+        parse_market_data(&event, self.instrument(event.symbol())?, ts_init)
+    }
+
+    /// Parses the order update in a user data message, returning `None` for
+    /// other messages.
+    pub fn parse_execution_report(
+        &self,
+        data: &[u8],
+        ts_init: UnixNanos,
+    ) -> Result<Option<BinanceExecutionReport>, BinanceError> {
+        let order = match parse_ws_message(data)? {
+            Some(BinanceWsEvent::ExecutionReport(order)) => order,
+            Some(BinanceWsEvent::OrderTradeUpdate(msg)) => msg.order,
+            _ => return Ok(None),
+        };
+        let instrument = self.instrument(&order.symbol)?;
+        parse_execution_report(&order, instrument, self.account_type.is_futures(), ts_init)
+            .map(Some)
+    }
+}
+
+fn to_pyvalue_err(err: BinanceError) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+/// Returns the `data` as a capsule of a `CVec`, which frees the data once the
+/// capsule is garbage collected.
+fn data_to_capsule(py: Python<'_>, data: Vec<Data>) -> PyResult<PyObject> {
+    let cvec = CVec::from_elements(data);
+    let capsule = PyCapsule::new_with_destructor(py, cvec, None, |cvec: CVec, _| unsafe {
+        cvec.drop_elements();
+    })?;
+    Ok(capsule.into_py(py))
+}
+
+#[pymethods]
+impl BinanceClient {
+    #[new]
+    #[pyo3(signature = (account_type, api_key=None, api_secret=None, testnet=false))]
+    fn py_new(
+        account_type: &str,
+        api_key: Option<&str>,
+        api_secret: Option<&str>,
+        testnet: bool,
+    ) -> PyResult<Self> {
+        let account_type = BinanceAccountType::from_str(account_type).map_err(to_pyvalue_err)?;
+        let credential = match (api_key, api_secret) {
+            (Some(key), Some(secret)) => Some(BinanceCredential::new(key, secret)),
+            (None, None) => None,
+            _ => {
+                return Err(PyValueError::new_err(
+                    "Both `api_key` and `api_secret` are required for signing",
+                ))
+            }
+        };
+        Ok(Self::new(account_type, testnet, credential))
+    }
+
+    #[getter]
+    fn account_type(&self) -> String {
+        self.account_type.to_string()
+    }
+
+    #[getter]
+    fn base_url(&self) -> &'static str {
+        self.account_type.http_base_url(self.testnet)
+    }
+
+    #[getter]
+    fn ws_base_url(&self) -> &'static str {
+        self.account_type.ws_base_url(self.testnet)
+    }
+
+    #[getter]
+    fn api_key(&self) -> Option<String> {
+        self.credential
+            .as_ref()
+            .map(|credential| credential.api_key().to_string())
+    }
+
+    #[pyo3(name = "add_instrument")]
+    fn py_add_instrument(
+        &mut self,
+        symbol: &str,
+        instrument_id: &str,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> PyResult<()> {
+        let instrument_id = InstrumentId::from_str(instrument_id)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        self.add_instrument(
+            symbol,
+            BinanceInstrument::new(instrument_id, price_precision, size_precision),
+        );
+        Ok(())
+    }
+
+    #[pyo3(name = "sign")]
+    fn py_sign(&self, payload: &str) -> PyResult<String> {
+        let credential = self.credential().map_err(to_pyvalue_err)?;
+        Ok(credential.sign(payload))
+    }
+
+    #[pyo3(name = "signed_query")]
+    #[pyo3(signature = (params, timestamp_ms, recv_window_ms=None))]
+    fn py_signed_query(
+        &self,
+        params: Vec<(String, String)>,
+        timestamp_ms: u64,
+        recv_window_ms: Option<u64>,
+    ) -> PyResult<String> {
+        let credential = self.credential().map_err(to_pyvalue_err)?;
+        let params: Vec<(&str, &str)> = params
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        Ok(credential.signed_query(&params, timestamp_ms, recv_window_ms))
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "subscribe_message")]
+    fn py_subscribe_message(streams: Vec<String>, id: u64) -> String {
+        subscribe_message(&streams, id)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "unsubscribe_message")]
+    fn py_unsubscribe_message(streams: Vec<String>, id: u64) -> String {
+        unsubscribe_message(&streams, id)
+    }
+
+    /// Returns a capsule of the market data in the message, to be converted
+    /// with `list_from_capsule`.
+    #[pyo3(name = "parse_market_data")]
+    fn py_parse_market_data(
+        &self,
+        py: Python<'_>,
+        data: &[u8],
+        ts_init: UnixNanos,
+    ) -> PyResult<PyObject> {
+        let data = self
+            .parse_market_data(data, ts_init)
+            .map_err(to_pyvalue_err)?;
+        data_to_capsule(py, data)
+    }
+
+    /// Returns a capsule of the deltas for a REST order book snapshot.
+    #[pyo3(name = "parse_book_snapshot")]
+    fn py_parse_book_snapshot(
+        &self,
+        py: Python<'_>,
+        symbol: &str,
+        data: &[u8],
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> PyResult<PyObject> {
+        let instrument = self.instrument(symbol).map_err(to_pyvalue_err)?;
+        let snapshot: BinanceOrderBookSnapshot =
+            serde_json::from_slice(data).map_err(|err| to_pyvalue_err(BinanceError::Json(err)))?;
+        let deltas = parse_book_snapshot(&snapshot, instrument, ts_event, ts_init)
+            .map_err(to_pyvalue_err)?;
+        data_to_capsule(py, deltas.into_iter().map(Data::Delta).collect())
+    }
+
+    #[pyo3(name = "parse_execution_report")]
+    fn py_parse_execution_report(
+        &self,
+        data: &[u8],
+        ts_init: UnixNanos,
+    ) -> PyResult<Option<BinanceExecutionReport>> {
+        self.parse_execution_report(data, ts_init)
+            .map_err(to_pyvalue_err)
+    }
+}
+
+#[pymethods]
+impl BinanceExecutionReport {
+    #[getter(instrument_id)]
+    fn py_instrument_id(&self) -> String {
+        self.instrument_id.to_string()
+    }
+
+    #[getter(client_order_id)]
+    fn py_client_order_id(&self) -> String {
+        self.client_order_id.to_string()
+    }
+
+    #[getter(venue_order_id)]
+    fn py_venue_order_id(&self) -> String {
+        self.venue_order_id.to_string()
+    }
+
+    #[getter(order_side)]
+    fn py_order_side(&self) -> OrderSide {
+        self.order_side
+    }
+
+    #[getter(order_type)]
+    fn py_order_type(&self) -> OrderType {
+        self.order_type
+    }
+
+    #[getter(time_in_force)]
+    fn py_time_in_force(&self) -> TimeInForce {
+        self.time_in_force
+    }
+
+    #[getter(order_status)]
+    fn py_order_status(&self) -> OrderStatus {
+        self.order_status
+    }
+
+    #[getter(exec_type)]
+    fn py_exec_type(&self) -> String {
+        self.exec_type.clone()
+    }
+
+    #[getter(quantity)]
+    fn py_quantity(&self) -> String {
+        self.quantity.to_string()
+    }
+
+    #[getter(price)]
+    fn py_price(&self) -> String {
+        self.price.to_string()
+    }
+
+    #[getter(last_qty)]
+    fn py_last_qty(&self) -> String {
+        self.last_qty.to_string()
+    }
+
+    #[getter(last_px)]
+    fn py_last_px(&self) -> String {
+        self.last_px.to_string()
+    }
+
+    #[getter(cum_qty)]
+    fn py_cum_qty(&self) -> String {
+        self.cum_qty.to_string()
+    }
+
+    #[getter(commission)]
+    fn py_commission(&self) -> f64 {
+        self.commission
+    }
+
+    #[getter(commission_asset)]
+    fn py_commission_asset(&self) -> Option<String> {
+        self.commission_asset.clone()
+    }
+
+    #[getter(trade_id)]
+    fn py_trade_id(&self) -> Option<String> {
+        self.trade_id.as_ref().map(ToString::to_string)
+    }
+
+    #[getter(ts_event)]
+    fn py_ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    #[getter(ts_init)]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    #[getter(is_fill)]
+    fn py_is_fill(&self) -> bool {
+        self.is_fill()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client() -> BinanceClient {
+        let mut client = BinanceClient::new(BinanceAccountType::Spot, false, None);
+        client.add_instrument(
+            "btcusdt",
+            BinanceInstrument::new(InstrumentId::from_str("BTCUSDT.BINANCE").unwrap(), 2, 5),
+        );
+        client
+    }
+
+    #[test]
+    fn test_parse_market_data() {
+        let msg = br#"{"stream":"btcusdt@aggTrade","data":{"e":"aggTrade","E":1,"s":"BTCUSDT",
+            "a":5933014,"p":"16500.01","q":"0.10000","f":100,"l":105,"T":2,"m":false}}"#;
+
+        let data = client().parse_market_data(msg, 3).unwrap();
+
+        assert_eq!(data.len(), 1);
+        assert!(matches!(&data[0], Data::Trade(trade) if trade.trade_id.to_string() == "5933014"));
+    }
+
+    #[test]
+    fn test_parse_market_data_unknown_symbol() {
+        let msg = br#"{"e":"trade","E":1,"s":"ETHUSDT","t":1,"p":"1.0","q":"1.0","T":1,"m":true}"#;
+
+        assert!(matches!(
+            client().parse_market_data(msg, 0),
+            Err(BinanceError::UnknownSymbol(symbol)) if symbol == "ETHUSDT"
+        ));
+    }
+
+    #[test]
+    fn test_parse_market_data_ignores_other_messages() {
+        let client = client();
+
+        assert!(client
+            .parse_market_data(br#"{"result":null,"id":1}"#, 0)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            client
+                .parse_execution_report(br#"{"result":null,"id":1}"#, 0)
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_sign_requires_credentials() {
+        assert!(matches!(
+            client().credential(),
+            Err(BinanceError::MissingCredentials)
+        ));
+    }
+}
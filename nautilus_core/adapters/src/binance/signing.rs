@@ -0,0 +1,183 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! HMAC-SHA256 signing of Binance REST requests.
+
+use std::fmt::{Debug, Formatter};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The header carrying the API key on authenticated requests.
+pub const API_KEY_HEADER: &str = "X-MBX-APIKEY";
+
+/// Binance API credentials for signed (`TRADE` and `USER_DATA`) endpoints.
+#[derive(Clone)]
+pub struct BinanceCredential {
+    api_key: String,
+    api_secret: String,
+}
+
+impl Debug for BinanceCredential {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BinanceCredential")
+            .field("api_key", &self.api_key)
+            .field("api_secret", &"<redacted>")
+            .finish()
+    }
+}
+
+impl BinanceCredential {
+    #[must_use]
+    pub fn new(api_key: &str, api_secret: &str) -> Self {
+        Self {
+            api_key: api_key.to_string(),
+            api_secret: api_secret.to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// Returns the hex encoded HMAC-SHA256 signature of the `payload`.
+    #[must_use]
+    pub fn sign(&self, payload: &str) -> String {
+        let mut mac = HmacSha256::new_from_slice(self.api_secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(payload.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    /// Returns the URL encoded query for the `params` with the `timestamp`
+    /// (and optional `recvWindow`) appended, followed by its `signature`.
+    #[must_use]
+    pub fn signed_query(
+        &self,
+        params: &[(&str, &str)],
+        timestamp_ms: u64,
+        recv_window_ms: Option<u64>,
+    ) -> String {
+        let mut query = encode_query(params);
+        if !query.is_empty() {
+            query.push('&');
+        }
+        if let Some(recv_window_ms) = recv_window_ms {
+            query.push_str(&format!("recvWindow={recv_window_ms}&"));
+        }
+        query.push_str(&format!("timestamp={timestamp_ms}"));
+
+        let signature = self.sign(&query);
+        format!("{query}&signature={signature}")
+    }
+}
+
+/// Returns the `params` as an `application/x-www-form-urlencoded` query.
+#[must_use]
+pub fn encode_query(params: &[(&str, &str)]) -> String {
+    params
+        .iter()
+        .map(|(key, value)| format!("{}={}", url_encode(key), url_encode(value)))
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+fn url_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char);
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    // Example from the Binance API documentation
+    const API_SECRET: &str = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j";
+
+    #[test]
+    fn test_sign() {
+        let credential = BinanceCredential::new("key", API_SECRET);
+        let query = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1\
+                     &recvWindow=5000&timestamp=1499827319559";
+
+        assert_eq!(
+            credential.sign(query),
+            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+        );
+    }
+
+    #[test]
+    fn test_signed_query() {
+        let credential = BinanceCredential::new("key", API_SECRET);
+        let params = [
+            ("symbol", "LTCBTC"),
+            ("side", "BUY"),
+            ("type", "LIMIT"),
+            ("timeInForce", "GTC"),
+            ("quantity", "1"),
+            ("price", "0.1"),
+        ];
+
+        let query = credential.signed_query(&params, 1_499_827_319_559, Some(5000));
+
+        assert_eq!(
+            query,
+            "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1\
+             &recvWindow=5000&timestamp=1499827319559\
+             &signature=c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+        );
+    }
+
+    #[test]
+    fn test_signed_query_without_params() {
+        let credential = BinanceCredential::new("key", API_SECRET);
+
+        let query = credential.signed_query(&[], 1, None);
+
+        assert!(query.starts_with("timestamp=1&signature="));
+    }
+
+    #[test]
+    fn test_debug_redacts_secret() {
+        let credential = BinanceCredential::new("key", API_SECRET);
+
+        assert!(!format!("{credential:?}").contains(API_SECRET));
+    }
+
+    #[rstest]
+    #[case("BTCUSDT", "BTCUSDT")]
+    #[case("O-123_a.b~", "O-123_a.b~")]
+    #[case("a b&c=d", "a%20b%26c%3Dd")]
+    #[case("[\"BTCUSDT\"]", "%5B%22BTCUSDT%22%5D")]
+    fn test_url_encode(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(url_encode(value), expected);
+    }
+}
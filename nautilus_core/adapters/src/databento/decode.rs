@@ -0,0 +1,458 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::io::{ErrorKind, Read};
+
+use nautilus_model::{data::Data, identifiers::venue::Venue};
+
+use super::{
+    parsing::{parse_record, DbnRecord, RecordHeader},
+    symbology::InstrumentMap,
+    DbnError,
+};
+
+/// The supported version of the DBN encoding.
+pub const DBN_VERSION: u8 = 1;
+
+/// The length of fixed-size symbol strings in version 1 metadata.
+const SYMBOL_CSTR_LEN: usize = 22;
+
+const DATASET_CSTR_LEN: usize = 16;
+const METADATA_RESERVED_LEN: usize = 47;
+
+/// Represents a raw symbol and its mappings to output symbols over time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    pub raw_symbol: String,
+    pub intervals: Vec<MappingSymbol>,
+}
+
+/// Represents the output `symbol` of a mapping between two UTC dates (as
+/// `YYYYMMDD`), `start_date` inclusive and `end_date` exclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappingSymbol {
+    pub start_date: u32,
+    pub end_date: u32,
+    pub symbol: String,
+}
+
+/// Represents the metadata header of a DBN stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    pub version: u8,
+    pub dataset: String,
+    /// The schema of the records, or `u16::MAX` for mixed schemas.
+    pub schema: u16,
+    pub start: u64,
+    pub end: u64,
+    pub limit: u64,
+    pub stype_in: u8,
+    pub stype_out: u8,
+    /// If each record is followed by the gateway send timestamp.
+    pub ts_out: bool,
+    pub symbols: Vec<String>,
+    pub mappings: Vec<Mapping>,
+}
+
+/// Provides bounds checked little-endian reads over the metadata bytes.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DbnError> {
+        let end = self.pos + len;
+        if end > self.bytes.len() {
+            return Err(DbnError::InvalidMetadata("unexpected end of metadata"));
+        }
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, DbnError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, DbnError> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, DbnError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, DbnError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    /// Reads a fixed-size, nul padded string.
+    fn cstr(&mut self, len: usize) -> Result<String, DbnError> {
+        let bytes = self.take(len)?;
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(len);
+        std::str::from_utf8(&bytes[..end])
+            .map(ToString::to_string)
+            .map_err(|_| DbnError::InvalidMetadata("symbol is not valid UTF-8"))
+    }
+
+    fn symbols(&mut self) -> Result<Vec<String>, DbnError> {
+        let count = self.u32()?;
+        (0..count).map(|_| self.cstr(SYMBOL_CSTR_LEN)).collect()
+    }
+}
+
+impl Metadata {
+    /// Decodes the metadata header from the start of the `reader`.
+    ///
+    /// # Errors
+    ///
+    /// - If the stream is not DBN, or not of a supported version.
+    /// - If the metadata is truncated or malformed.
+    pub fn decode<R: Read>(reader: &mut R) -> Result<Self, DbnError> {
+        let mut prelude = [0_u8; 8];
+        reader.read_exact(&mut prelude)?;
+        if &prelude[..3] != b"DBN" {
+            return Err(DbnError::InvalidMetadata("missing DBN prefix"));
+        }
+        let version = prelude[3];
+        if version != DBN_VERSION {
+            return Err(DbnError::UnsupportedVersion(version));
+        }
+
+        let length = u32::from_le_bytes(prelude[4..8].try_into().unwrap()) as usize;
+        let mut bytes = vec![0_u8; length];
+        reader.read_exact(&mut bytes)?;
+
+        let mut cursor = Cursor {
+            bytes: &bytes,
+            pos: 0,
+        };
+        let dataset = cursor.cstr(DATASET_CSTR_LEN)?;
+        let schema = cursor.u16()?;
+        let start = cursor.u64()?;
+        let end = cursor.u64()?;
+        let limit = cursor.u64()?;
+        let _record_count = cursor.u64()?;
+        let stype_in = cursor.u8()?;
+        let stype_out = cursor.u8()?;
+        let ts_out = cursor.u8()? != 0;
+        cursor.take(METADATA_RESERVED_LEN)?;
+
+        let schema_definition_len = cursor.u32()? as usize;
+        cursor.take(schema_definition_len)?;
+
+        let symbols = cursor.symbols()?;
+        let _partial = cursor.symbols()?;
+        let _not_found = cursor.symbols()?;
+
+        let mapping_count = cursor.u32()?;
+        let mut mappings = Vec::with_capacity(mapping_count as usize);
+        for _ in 0..mapping_count {
+            let raw_symbol = cursor.cstr(SYMBOL_CSTR_LEN)?;
+            let interval_count = cursor.u32()?;
+            let intervals = (0..interval_count)
+                .map(|_| {
+                    Ok(MappingSymbol {
+                        start_date: cursor.u32()?,
+                        end_date: cursor.u32()?,
+                        symbol: cursor.cstr(SYMBOL_CSTR_LEN)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, DbnError>>()?;
+            mappings.push(Mapping {
+                raw_symbol,
+                intervals,
+            });
+        }
+
+        Ok(Self {
+            version,
+            dataset,
+            schema,
+            start,
+            end,
+            limit,
+            stype_in,
+            stype_out,
+            ts_out,
+            symbols,
+            mappings,
+        })
+    }
+}
+
+/// Provides a decoder of a DBN stream into Nautilus model data.
+///
+/// Records are read one at a time from the `reader` (wrap it with a buffered
+/// and/or zstd decoding reader as required). Numeric DBN instrument IDs are
+/// resolved through an [`InstrumentMap`], initialized from the symbol mappings
+/// in the metadata with raw symbols on the given venue.
+pub struct DbnDecoder<R: Read> {
+    reader: R,
+    metadata: Metadata,
+    instruments: InstrumentMap,
+    price_precision: u8,
+    size_precision: u8,
+    buffer: Vec<u8>,
+}
+
+impl<R: Read> DbnDecoder<R> {
+    /// Initializes a new `DbnDecoder` instance, decoding the metadata header.
+    ///
+    /// # Errors
+    ///
+    /// - If the metadata header is invalid.
+    pub fn new(
+        mut reader: R,
+        venue: &Venue,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> Result<Self, DbnError> {
+        let metadata = Metadata::decode(&mut reader)?;
+        let instruments = InstrumentMap::from_metadata(&metadata, venue);
+
+        Ok(Self {
+            reader,
+            metadata,
+            instruments,
+            price_precision,
+            size_precision,
+            buffer: Vec::with_capacity(256),
+        })
+    }
+
+    #[must_use]
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Returns the instrument map, which can be extended with explicit mappings.
+    pub fn instruments_mut(&mut self) -> &mut InstrumentMap {
+        &mut self.instruments
+    }
+
+    /// Decodes the next raw record, returning `None` at the end of the stream.
+    ///
+    /// # Errors
+    ///
+    /// - If reading fails, or the stream ends part way through a record.
+    pub fn decode_record(&mut self) -> Result<Option<DbnRecord>, DbnError> {
+        let mut length = [0_u8; 1];
+        match self.reader.read_exact(&mut length) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        }
+
+        let size = usize::from(length[0]) * 4;
+        if size < RecordHeader::SIZE {
+            return Err(DbnError::InvalidRecord("record length shorter than header"));
+        }
+        self.buffer.resize(size, 0);
+        self.buffer[0] = length[0];
+        self.reader.read_exact(&mut self.buffer[1..])?;
+
+        DbnRecord::decode(&self.buffer).map(Some)
+    }
+}
+
+impl<R: Read> Iterator for DbnDecoder<R> {
+    type Item = Result<Data, DbnError>;
+
+    /// Returns the next record with a model equivalent, skipping other records.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let record = match self.decode_record() {
+                Ok(Some(record)) => record,
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            };
+            if matches!(record, DbnRecord::Unsupported(_)) {
+                continue;
+            }
+
+            let hd = record.header();
+            let instrument_id = match self.instruments.resolve(hd.instrument_id, hd.ts_event) {
+                Some(instrument_id) => instrument_id.clone(),
+                None => return Some(Err(DbnError::UnknownInstrument(hd.instrument_id))),
+            };
+            if let Some(data) = parse_record(
+                &record,
+                instrument_id,
+                self.price_precision,
+                self.size_precision,
+            ) {
+                return Some(Ok(data));
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::identifiers::instrument_id::InstrumentId;
+
+    use super::*;
+    use crate::databento::{
+        parsing::{
+            rtype,
+            tests::{header_bytes, mbo_bytes, ohlcv_bytes, trade_bytes},
+        },
+        symbology::STYPE_INSTRUMENT_ID,
+    };
+
+    fn cstr(s: &str, len: usize) -> Vec<u8> {
+        let mut bytes = s.as_bytes().to_vec();
+        bytes.resize(len, 0);
+        bytes
+    }
+
+    fn metadata_bytes(version: u8, mappings: &[(&str, u32, u32, &str)]) -> Vec<u8> {
+        let mut body = cstr("GLBX.MDP3", DATASET_CSTR_LEN);
+        body.extend(0xA0_u16.to_le_bytes()); // schema
+        body.extend(0_u64.to_le_bytes()); // start
+        body.extend(u64::MAX.to_le_bytes()); // end
+        body.extend(0_u64.to_le_bytes()); // limit
+        body.extend(0_u64.to_le_bytes()); // record_count
+        body.extend([1, STYPE_INSTRUMENT_ID, 0]); // stype_in, stype_out, ts_out
+        body.extend([0_u8; METADATA_RESERVED_LEN]);
+        body.extend(0_u32.to_le_bytes()); // schema_definition_length
+        body.extend(1_u32.to_le_bytes()); // symbols
+        body.extend(cstr("ESM3", SYMBOL_CSTR_LEN));
+        body.extend(0_u32.to_le_bytes()); // partial
+        body.extend(0_u32.to_le_bytes()); // not_found
+        body.extend((mappings.len() as u32).to_le_bytes());
+        for (raw_symbol, start_date, end_date, symbol) in mappings {
+            body.extend(cstr(raw_symbol, SYMBOL_CSTR_LEN));
+            body.extend(1_u32.to_le_bytes());
+            body.extend(start_date.to_le_bytes());
+            body.extend(end_date.to_le_bytes());
+            body.extend(cstr(symbol, SYMBOL_CSTR_LEN));
+        }
+
+        let mut bytes = b"DBN".to_vec();
+        bytes.push(version);
+        bytes.extend((body.len() as u32).to_le_bytes());
+        bytes.extend(body);
+        bytes
+    }
+
+    fn stream_bytes(records: &[Vec<u8>]) -> Vec<u8> {
+        let mut bytes = metadata_bytes(1, &[("ESM3", 19700101, 20990101, "5482")]);
+        for record in records {
+            bytes.extend(record);
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_decode_metadata() {
+        let bytes = metadata_bytes(1, &[("ESM3", 20230601, 20230602, "5482")]);
+
+        let metadata = Metadata::decode(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(metadata.version, 1);
+        assert_eq!(metadata.dataset, "GLBX.MDP3");
+        assert_eq!(metadata.schema, 0xA0);
+        assert_eq!(metadata.stype_out, STYPE_INSTRUMENT_ID);
+        assert!(!metadata.ts_out);
+        assert_eq!(metadata.symbols, vec!["ESM3".to_string()]);
+        assert_eq!(
+            metadata.mappings,
+            vec![Mapping {
+                raw_symbol: "ESM3".to_string(),
+                intervals: vec![MappingSymbol {
+                    start_date: 20230601,
+                    end_date: 20230602,
+                    symbol: "5482".to_string(),
+                }],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_decode_metadata_invalid() {
+        let unsupported = metadata_bytes(2, &[]);
+        let mut truncated = metadata_bytes(1, &[]);
+        truncated.truncate(40);
+
+        assert!(matches!(
+            Metadata::decode(&mut unsupported.as_slice()),
+            Err(DbnError::UnsupportedVersion(2))
+        ));
+        assert!(Metadata::decode(&mut truncated.as_slice()).is_err());
+        assert!(Metadata::decode(&mut b"PAR1\0\0\0\0".as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_decoder_maps_records_to_data() {
+        let bytes = stream_bytes(&[
+            mbo_bytes(5482, b'A', b'B', 4_500_000_000_000),
+            mbo_bytes(5482, b'T', b'A', 4_500_000_000_000), // Skipped trade action
+            header_bytes(16, 0x13, 5482, 0),                // Skipped unsupported rtype
+            trade_bytes(rtype::MBP_0, 5482, b'B'),
+            trade_bytes(rtype::MBP_1, 5482, b'B'),
+            ohlcv_bytes(rtype::OHLCV_1M, 5482),
+        ]);
+
+        let decoder = DbnDecoder::new(bytes.as_slice(), &Venue::new("GLBX"), 2, 0).unwrap();
+        let data: Vec<Data> = decoder.map(Result::unwrap).collect();
+
+        assert_eq!(data.len(), 4);
+        assert!(matches!(data[0], Data::Delta(_)));
+        assert!(matches!(data[1], Data::Trade(_)));
+        assert!(matches!(data[2], Data::Quote(_)));
+        assert!(matches!(data[3], Data::Bar(_)));
+        let expected = InstrumentId::from_str("ESM3.GLBX").unwrap();
+        match &data[1] {
+            Data::Trade(trade) => assert_eq!(trade.instrument_id, expected),
+            _ => panic!("Expected trade"),
+        }
+    }
+
+    #[test]
+    fn test_decoder_unknown_instrument_returns_error() {
+        let bytes = stream_bytes(&[trade_bytes(rtype::MBP_0, 1, b'B')]);
+        let mut decoder = DbnDecoder::new(bytes.as_slice(), &Venue::new("GLBX"), 2, 0).unwrap();
+
+        assert!(matches!(
+            decoder.next(),
+            Some(Err(DbnError::UnknownInstrument(1)))
+        ));
+
+        // An explicit mapping resolves the instrument
+        let bytes = stream_bytes(&[trade_bytes(rtype::MBP_0, 1, b'B')]);
+        let mut decoder = DbnDecoder::new(bytes.as_slice(), &Venue::new("GLBX"), 2, 0).unwrap();
+        decoder
+            .instruments_mut()
+            .insert(1, InstrumentId::from_str("NQM3.GLBX").unwrap());
+        assert!(matches!(decoder.next(), Some(Ok(Data::Trade(_)))));
+    }
+
+    #[test]
+    fn test_decoder_truncated_record_returns_error() {
+        let mut bytes = stream_bytes(&[trade_bytes(rtype::MBP_0, 5482, b'B')]);
+        bytes.truncate(bytes.len() - 4);
+        let mut decoder = DbnDecoder::new(bytes.as_slice(), &Venue::new("GLBX"), 2, 0).unwrap();
+
+        assert!(matches!(decoder.next(), Some(Err(DbnError::Io(_)))));
+    }
+}
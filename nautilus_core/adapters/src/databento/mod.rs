@@ -0,0 +1,36 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod decode;
+pub mod parsing;
+pub mod symbology;
+
+use std::io;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DbnError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Unsupported DBN version {0}")]
+    UnsupportedVersion(u8),
+    #[error("Invalid DBN metadata: {0}")]
+    InvalidMetadata(&'static str),
+    #[error("Invalid DBN record: {0}")]
+    InvalidRecord(&'static str),
+    #[error("No instrument mapped for DBN instrument ID {0}")]
+    UnknownInstrument(u32),
+}
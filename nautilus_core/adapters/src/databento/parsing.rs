@@ -0,0 +1,677 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Decoding of DBN record layouts, and parsing into Nautilus model types.
+//!
+//! All records are little-endian `repr(C)` structs prefixed by a [`RecordHeader`].
+//! Prices are signed integers in units of 1e-9, which matches the fixed-point
+//! scale of [`Price`], so raw values are carried across without rounding.
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarSpecification, BarType},
+        book::{BookOrder, OrderBookDelta},
+        tick::{QuoteTick, TradeTick},
+        Data,
+    },
+    enums::{AggregationSource, AggressorSide, BarAggregation, BookAction, OrderSide, PriceType},
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    types::{price::Price, quantity::Quantity},
+};
+
+use super::DbnError;
+
+/// The sentinel for an undefined price.
+pub const UNDEF_PRICE: i64 = i64::MAX;
+
+const NANOS_PER_UNIT: u64 = 1_000_000_000;
+
+pub mod rtype {
+    pub const MBP_0: u8 = 0x00;
+    pub const MBP_1: u8 = 0x01;
+    pub const MBP_10: u8 = 0x0A;
+    pub const OHLCV_1S: u8 = 0x20;
+    pub const OHLCV_1M: u8 = 0x21;
+    pub const OHLCV_1H: u8 = 0x22;
+    pub const OHLCV_1D: u8 = 0x23;
+    pub const MBO: u8 = 0xA0;
+}
+
+/// Provides little-endian field access to the raw bytes of a record.
+struct Fields<'a>(&'a [u8]);
+
+impl Fields<'_> {
+    fn u8(&self, offset: usize) -> u8 {
+        self.0[offset]
+    }
+
+    fn u16(&self, offset: usize) -> u16 {
+        u16::from_le_bytes(self.0[offset..offset + 2].try_into().unwrap())
+    }
+
+    fn u32(&self, offset: usize) -> u32 {
+        u32::from_le_bytes(self.0[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn u64(&self, offset: usize) -> u64 {
+        u64::from_le_bytes(self.0[offset..offset + 8].try_into().unwrap())
+    }
+
+    fn i64(&self, offset: usize) -> i64 {
+        i64::from_le_bytes(self.0[offset..offset + 8].try_into().unwrap())
+    }
+}
+
+/// The common header of every DBN record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordHeader {
+    /// The length of the record in 32-bit words.
+    pub length: u8,
+    pub rtype: u8,
+    pub publisher_id: u16,
+    pub instrument_id: u32,
+    pub ts_event: UnixNanos,
+}
+
+impl RecordHeader {
+    pub const SIZE: usize = 16;
+
+    fn decode(bytes: &[u8]) -> Self {
+        let fields = Fields(bytes);
+        Self {
+            length: fields.u8(0),
+            rtype: fields.u8(1),
+            publisher_id: fields.u16(2),
+            instrument_id: fields.u32(4),
+            ts_event: fields.u64(8),
+        }
+    }
+
+    /// Returns the length of the record in bytes.
+    #[must_use]
+    pub fn record_size(&self) -> usize {
+        usize::from(self.length) * 4
+    }
+}
+
+/// A market by order (MBO) record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MboMsg {
+    pub hd: RecordHeader,
+    pub order_id: u64,
+    pub price: i64,
+    pub size: u32,
+    pub flags: u8,
+    pub channel_id: u8,
+    pub action: u8,
+    pub side: u8,
+    pub ts_recv: UnixNanos,
+    pub ts_in_delta: i32,
+    pub sequence: u32,
+}
+
+/// A trade record (MBP-0).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TradeMsg {
+    pub hd: RecordHeader,
+    pub price: i64,
+    pub size: u32,
+    pub action: u8,
+    pub side: u8,
+    pub flags: u8,
+    pub depth: u8,
+    pub ts_recv: UnixNanos,
+    pub ts_in_delta: i32,
+    pub sequence: u32,
+}
+
+/// A top of book record (MBP-1), the trade fields followed by one price level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mbp1Msg {
+    pub trade: TradeMsg,
+    pub bid_px: i64,
+    pub ask_px: i64,
+    pub bid_sz: u32,
+    pub ask_sz: u32,
+    pub bid_ct: u32,
+    pub ask_ct: u32,
+}
+
+/// An OHLCV bar record, timestamped at the open of the bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OhlcvMsg {
+    pub hd: RecordHeader,
+    pub open: i64,
+    pub high: i64,
+    pub low: i64,
+    pub close: i64,
+    pub volume: u64,
+}
+
+impl MboMsg {
+    pub const SIZE: usize = 56;
+
+    fn decode(bytes: &[u8]) -> Self {
+        let fields = Fields(bytes);
+        Self {
+            hd: RecordHeader::decode(bytes),
+            order_id: fields.u64(16),
+            price: fields.i64(24),
+            size: fields.u32(32),
+            flags: fields.u8(36),
+            channel_id: fields.u8(37),
+            action: fields.u8(38),
+            side: fields.u8(39),
+            ts_recv: fields.u64(40),
+            ts_in_delta: fields.u32(48) as i32,
+            sequence: fields.u32(52),
+        }
+    }
+}
+
+impl TradeMsg {
+    pub const SIZE: usize = 48;
+
+    fn decode(bytes: &[u8]) -> Self {
+        let fields = Fields(bytes);
+        Self {
+            hd: RecordHeader::decode(bytes),
+            price: fields.i64(16),
+            size: fields.u32(24),
+            action: fields.u8(28),
+            side: fields.u8(29),
+            flags: fields.u8(30),
+            depth: fields.u8(31),
+            ts_recv: fields.u64(32),
+            ts_in_delta: fields.u32(40) as i32,
+            sequence: fields.u32(44),
+        }
+    }
+}
+
+impl Mbp1Msg {
+    pub const SIZE: usize = 80;
+
+    fn decode(bytes: &[u8]) -> Self {
+        let fields = Fields(bytes);
+        Self {
+            trade: TradeMsg::decode(bytes),
+            bid_px: fields.i64(48),
+            ask_px: fields.i64(56),
+            bid_sz: fields.u32(64),
+            ask_sz: fields.u32(68),
+            bid_ct: fields.u32(72),
+            ask_ct: fields.u32(76),
+        }
+    }
+}
+
+impl OhlcvMsg {
+    pub const SIZE: usize = 56;
+
+    fn decode(bytes: &[u8]) -> Self {
+        let fields = Fields(bytes);
+        Self {
+            hd: RecordHeader::decode(bytes),
+            open: fields.i64(16),
+            high: fields.i64(24),
+            low: fields.i64(32),
+            close: fields.i64(40),
+            volume: fields.u64(48),
+        }
+    }
+}
+
+/// A decoded DBN record of a supported schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DbnRecord {
+    Mbo(MboMsg),
+    Trade(TradeMsg),
+    Mbp1(Mbp1Msg),
+    Ohlcv(OhlcvMsg),
+    /// A record of an unsupported `rtype`, which is skipped.
+    Unsupported(RecordHeader),
+}
+
+impl DbnRecord {
+    /// Decodes the record from its raw `bytes`, which must hold exactly the
+    /// length given in the header.
+    ///
+    /// # Errors
+    ///
+    /// - If the record is shorter than the layout of its `rtype`.
+    pub fn decode(bytes: &[u8]) -> Result<Self, DbnError> {
+        if bytes.len() < RecordHeader::SIZE {
+            return Err(DbnError::InvalidRecord("record shorter than header"));
+        }
+        let hd = RecordHeader::decode(bytes);
+        let min_size = match hd.rtype {
+            rtype::MBO => MboMsg::SIZE,
+            rtype::MBP_0 => TradeMsg::SIZE,
+            rtype::MBP_1 => Mbp1Msg::SIZE,
+            rtype::OHLCV_1S | rtype::OHLCV_1M | rtype::OHLCV_1H | rtype::OHLCV_1D => OhlcvMsg::SIZE,
+            _ => return Ok(Self::Unsupported(hd)),
+        };
+        if bytes.len() < min_size {
+            return Err(DbnError::InvalidRecord("record shorter than its schema"));
+        }
+
+        Ok(match hd.rtype {
+            rtype::MBO => Self::Mbo(MboMsg::decode(bytes)),
+            rtype::MBP_0 => Self::Trade(TradeMsg::decode(bytes)),
+            rtype::MBP_1 => Self::Mbp1(Mbp1Msg::decode(bytes)),
+            _ => Self::Ohlcv(OhlcvMsg::decode(bytes)),
+        })
+    }
+
+    #[must_use]
+    pub fn header(&self) -> &RecordHeader {
+        match self {
+            Self::Mbo(msg) => &msg.hd,
+            Self::Trade(msg) => &msg.hd,
+            Self::Mbp1(msg) => &msg.trade.hd,
+            Self::Ohlcv(msg) => &msg.hd,
+            Self::Unsupported(hd) => hd,
+        }
+    }
+}
+
+#[must_use]
+pub fn parse_order_side(side: u8) -> OrderSide {
+    match side {
+        b'A' => OrderSide::Sell,
+        b'B' => OrderSide::Buy,
+        _ => OrderSide::NoOrderSide,
+    }
+}
+
+/// Parses the aggressor side, where the side of a trade is the side of the
+/// aggressing order.
+#[must_use]
+pub fn parse_aggressor_side(side: u8) -> AggressorSide {
+    match side {
+        b'A' => AggressorSide::Seller,
+        b'B' => AggressorSide::Buyer,
+        _ => AggressorSide::NoAggressor,
+    }
+}
+
+/// Parses the book action, returning `None` for trade and fill actions which
+/// do not change the book.
+#[must_use]
+pub fn parse_book_action(action: u8) -> Option<BookAction> {
+    match action {
+        b'A' => Some(BookAction::Add),
+        b'C' => Some(BookAction::Delete),
+        b'M' => Some(BookAction::Update),
+        b'R' => Some(BookAction::Clear),
+        _ => None,
+    }
+}
+
+fn parse_price(raw: i64, precision: u8) -> Option<Price> {
+    (raw != UNDEF_PRICE).then(|| Price::from_raw(raw, precision))
+}
+
+fn parse_quantity(size: u64, precision: u8) -> Quantity {
+    Quantity::from_raw(size.saturating_mul(NANOS_PER_UNIT), precision)
+}
+
+/// Returns the bar specification and interval (nanoseconds) of an OHLCV `rtype`.
+#[must_use]
+pub fn parse_ohlcv_spec(rtype: u8) -> Option<(BarSpecification, u64)> {
+    let (aggregation, interval) = match rtype {
+        rtype::OHLCV_1S => (BarAggregation::Second, NANOS_PER_UNIT),
+        rtype::OHLCV_1M => (BarAggregation::Minute, 60 * NANOS_PER_UNIT),
+        rtype::OHLCV_1H => (BarAggregation::Hour, 60 * 60 * NANOS_PER_UNIT),
+        rtype::OHLCV_1D => (BarAggregation::Day, 24 * 60 * 60 * NANOS_PER_UNIT),
+        _ => return None,
+    };
+    let spec = BarSpecification {
+        step: 1,
+        aggregation,
+        price_type: PriceType::Last,
+    };
+    Some((spec, interval))
+}
+
+/// Parses the `record` into model data for the `instrument_id`.
+///
+/// Returns `None` for records with no equivalent model type, such as trade
+/// actions of MBO records or quotes with an undefined side.
+#[must_use]
+pub fn parse_record(
+    record: &DbnRecord,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+) -> Option<Data> {
+    match record {
+        DbnRecord::Mbo(msg) => {
+            let action = parse_book_action(msg.action)?;
+            let (price, size) = if action == BookAction::Clear {
+                (
+                    Price::from_raw(0, price_precision),
+                    parse_quantity(0, size_precision),
+                )
+            } else {
+                (
+                    parse_price(msg.price, price_precision)?,
+                    parse_quantity(u64::from(msg.size), size_precision),
+                )
+            };
+            let order = BookOrder::new(parse_order_side(msg.side), price, size, msg.order_id);
+            Some(Data::Delta(OrderBookDelta::new(
+                instrument_id,
+                action,
+                order,
+                msg.flags,
+                u64::from(msg.sequence),
+                msg.hd.ts_event,
+                msg.ts_recv,
+            )))
+        }
+        DbnRecord::Trade(msg) => Some(Data::Trade(TradeTick {
+            instrument_id,
+            price: parse_price(msg.price, price_precision)?,
+            size: parse_quantity(u64::from(msg.size), size_precision),
+            aggressor_side: parse_aggressor_side(msg.side),
+            trade_id: TradeId::new(&msg.sequence.to_string()),
+            ts_event: msg.hd.ts_event,
+            ts_init: msg.ts_recv,
+        })),
+        DbnRecord::Mbp1(msg) => Some(Data::Quote(QuoteTick {
+            instrument_id,
+            bid: parse_price(msg.bid_px, price_precision)?,
+            ask: parse_price(msg.ask_px, price_precision)?,
+            bid_size: parse_quantity(u64::from(msg.bid_sz), size_precision),
+            ask_size: parse_quantity(u64::from(msg.ask_sz), size_precision),
+            ts_event: msg.trade.hd.ts_event,
+            ts_init: msg.trade.ts_recv,
+        })),
+        DbnRecord::Ohlcv(msg) => {
+            let (spec, interval) = parse_ohlcv_spec(msg.hd.rtype)?;
+            // Bars are timestamped at the close
+            let ts_close = msg.hd.ts_event + interval;
+            Some(Data::Bar(Bar {
+                bar_type: BarType {
+                    instrument_id,
+                    spec,
+                    aggregation_source: AggregationSource::External,
+                },
+                open: parse_price(msg.open, price_precision)?,
+                high: parse_price(msg.high, price_precision)?,
+                low: parse_price(msg.low, price_precision)?,
+                close: parse_price(msg.close, price_precision)?,
+                volume: parse_quantity(msg.volume, size_precision),
+                ts_event: ts_close,
+                ts_init: ts_close,
+            }))
+        }
+        DbnRecord::Unsupported(_) => None,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    pub(crate) fn header_bytes(
+        size: usize,
+        rtype: u8,
+        instrument_id: u32,
+        ts_event: u64,
+    ) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(size);
+        bytes.push((size / 4) as u8);
+        bytes.push(rtype);
+        bytes.extend(1_u16.to_le_bytes());
+        bytes.extend(instrument_id.to_le_bytes());
+        bytes.extend(ts_event.to_le_bytes());
+        bytes
+    }
+
+    pub(crate) fn mbo_bytes(instrument_id: u32, action: u8, side: u8, price: i64) -> Vec<u8> {
+        let mut bytes = header_bytes(MboMsg::SIZE, rtype::MBO, instrument_id, 1_000);
+        bytes.extend(42_u64.to_le_bytes()); // order_id
+        bytes.extend(price.to_le_bytes());
+        bytes.extend(5_u32.to_le_bytes()); // size
+        bytes.extend([128, 0, action, side]); // flags, channel_id, action, side
+        bytes.extend(1_010_u64.to_le_bytes()); // ts_recv
+        bytes.extend(10_i32.to_le_bytes()); // ts_in_delta
+        bytes.extend(7_u32.to_le_bytes()); // sequence
+        bytes
+    }
+
+    pub(crate) fn trade_bytes(rtype: u8, instrument_id: u32, side: u8) -> Vec<u8> {
+        let size = if rtype == rtype::MBP_1 {
+            Mbp1Msg::SIZE
+        } else {
+            TradeMsg::SIZE
+        };
+        let mut bytes = header_bytes(size, rtype, instrument_id, 2_000);
+        bytes.extend(4_500_250_000_000_i64.to_le_bytes()); // price
+        bytes.extend(3_u32.to_le_bytes()); // size
+        bytes.extend([b'T', side, 0, 0]); // action, side, flags, depth
+        bytes.extend(2_020_u64.to_le_bytes()); // ts_recv
+        bytes.extend(20_i32.to_le_bytes()); // ts_in_delta
+        bytes.extend(99_u32.to_le_bytes()); // sequence
+        if rtype == rtype::MBP_1 {
+            bytes.extend(4_500_000_000_000_i64.to_le_bytes()); // bid_px
+            bytes.extend(4_500_250_000_000_i64.to_le_bytes()); // ask_px
+            bytes.extend(10_u32.to_le_bytes()); // bid_sz
+            bytes.extend(12_u32.to_le_bytes()); // ask_sz
+            bytes.extend(2_u32.to_le_bytes()); // bid_ct
+            bytes.extend(3_u32.to_le_bytes()); // ask_ct
+        }
+        bytes
+    }
+
+    pub(crate) fn ohlcv_bytes(rtype: u8, instrument_id: u32) -> Vec<u8> {
+        let mut bytes = header_bytes(OhlcvMsg::SIZE, rtype, instrument_id, 60_000_000_000);
+        for price in [100_i64, 120, 90, 110] {
+            bytes.extend((price * 1_000_000_000).to_le_bytes());
+        }
+        bytes.extend(1_500_u64.to_le_bytes()); // volume
+        bytes
+    }
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from_str("ESM3.GLBX").unwrap()
+    }
+
+    #[test]
+    fn test_decode_mbo() {
+        let record = DbnRecord::decode(&mbo_bytes(5482, b'A', b'B', 4_500_000_000_000)).unwrap();
+
+        assert_eq!(
+            record,
+            DbnRecord::Mbo(MboMsg {
+                hd: RecordHeader {
+                    length: 14,
+                    rtype: rtype::MBO,
+                    publisher_id: 1,
+                    instrument_id: 5482,
+                    ts_event: 1_000,
+                },
+                order_id: 42,
+                price: 4_500_000_000_000,
+                size: 5,
+                flags: 128,
+                channel_id: 0,
+                action: b'A',
+                side: b'B',
+                ts_recv: 1_010,
+                ts_in_delta: 10,
+                sequence: 7,
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_short_record_returns_error() {
+        let mut bytes = mbo_bytes(1, b'A', b'B', 0);
+        bytes.truncate(40);
+
+        assert!(DbnRecord::decode(&bytes).is_err());
+        assert!(DbnRecord::decode(&bytes[..8]).is_err());
+    }
+
+    #[test]
+    fn test_decode_unsupported_rtype() {
+        let bytes = header_bytes(16, 0x13, 1, 0);
+
+        let record = DbnRecord::decode(&bytes).unwrap();
+
+        assert!(matches!(record, DbnRecord::Unsupported(hd) if hd.rtype == 0x13));
+    }
+
+    #[rstest]
+    #[case(b'A', Some(BookAction::Add))]
+    #[case(b'C', Some(BookAction::Delete))]
+    #[case(b'M', Some(BookAction::Update))]
+    #[case(b'R', Some(BookAction::Clear))]
+    #[case(b'T', None)]
+    #[case(b'F', None)]
+    fn test_parse_book_action(#[case] action: u8, #[case] expected: Option<BookAction>) {
+        assert_eq!(parse_book_action(action), expected);
+    }
+
+    #[rstest]
+    #[case(b'A', OrderSide::Sell, AggressorSide::Seller)]
+    #[case(b'B', OrderSide::Buy, AggressorSide::Buyer)]
+    #[case(b'N', OrderSide::NoOrderSide, AggressorSide::NoAggressor)]
+    fn test_parse_sides(
+        #[case] side: u8,
+        #[case] order_side: OrderSide,
+        #[case] aggressor_side: AggressorSide,
+    ) {
+        assert_eq!(parse_order_side(side), order_side);
+        assert_eq!(parse_aggressor_side(side), aggressor_side);
+    }
+
+    #[test]
+    fn test_parse_mbo_to_delta() {
+        let record = DbnRecord::decode(&mbo_bytes(1, b'A', b'B', 4_500_250_000_000)).unwrap();
+
+        let data = parse_record(&record, instrument_id(), 2, 0).unwrap();
+
+        match data {
+            Data::Delta(delta) => {
+                assert_eq!(delta.instrument_id, instrument_id());
+                assert_eq!(delta.action, BookAction::Add);
+                assert_eq!(delta.order.side, OrderSide::Buy);
+                assert_eq!(delta.order.price, Price::new(4500.25, 2));
+                assert_eq!(delta.order.size, Quantity::new(5.0, 0));
+                assert_eq!(delta.order.order_id, 42);
+                assert_eq!(delta.flags, 128);
+                assert_eq!(delta.sequence, 7);
+                assert_eq!(delta.ts_event, 1_000);
+                assert_eq!(delta.ts_init, 1_010);
+            }
+            _ => panic!("Expected delta"),
+        }
+    }
+
+    #[test]
+    fn test_parse_mbo_clear_has_zero_price() {
+        let record = DbnRecord::decode(&mbo_bytes(1, b'R', b'N', UNDEF_PRICE)).unwrap();
+
+        match parse_record(&record, instrument_id(), 2, 0).unwrap() {
+            Data::Delta(delta) => {
+                assert_eq!(delta.action, BookAction::Clear);
+                assert_eq!(delta.order.price.raw, 0);
+            }
+            _ => panic!("Expected delta"),
+        }
+    }
+
+    #[test]
+    fn test_parse_mbo_trade_action_is_skipped() {
+        let record = DbnRecord::decode(&mbo_bytes(1, b'T', b'A', 4_500_000_000_000)).unwrap();
+
+        assert!(parse_record(&record, instrument_id(), 2, 0).is_none());
+    }
+
+    #[test]
+    fn test_parse_trade() {
+        let record = DbnRecord::decode(&trade_bytes(rtype::MBP_0, 1, b'A')).unwrap();
+
+        match parse_record(&record, instrument_id(), 2, 0).unwrap() {
+            Data::Trade(trade) => {
+                assert_eq!(trade.price, Price::new(4500.25, 2));
+                assert_eq!(trade.size, Quantity::new(3.0, 0));
+                assert_eq!(trade.aggressor_side, AggressorSide::Seller);
+                assert_eq!(trade.trade_id, TradeId::new("99"));
+                assert_eq!(trade.ts_event, 2_000);
+                assert_eq!(trade.ts_init, 2_020);
+            }
+            _ => panic!("Expected trade"),
+        }
+    }
+
+    #[test]
+    fn test_parse_mbp1_to_quote() {
+        let record = DbnRecord::decode(&trade_bytes(rtype::MBP_1, 1, b'B')).unwrap();
+
+        match parse_record(&record, instrument_id(), 2, 0).unwrap() {
+            Data::Quote(quote) => {
+                assert_eq!(quote.bid, Price::new(4500.00, 2));
+                assert_eq!(quote.ask, Price::new(4500.25, 2));
+                assert_eq!(quote.bid_size, Quantity::new(10.0, 0));
+                assert_eq!(quote.ask_size, Quantity::new(12.0, 0));
+                assert_eq!(quote.ts_event, 2_000);
+                assert_eq!(quote.ts_init, 2_020);
+            }
+            _ => panic!("Expected quote"),
+        }
+    }
+
+    #[rstest]
+    #[case(rtype::OHLCV_1S, BarAggregation::Second, 1_000_000_000)]
+    #[case(rtype::OHLCV_1M, BarAggregation::Minute, 60_000_000_000)]
+    #[case(rtype::OHLCV_1H, BarAggregation::Hour, 3_600_000_000_000)]
+    #[case(rtype::OHLCV_1D, BarAggregation::Day, 86_400_000_000_000)]
+    fn test_parse_ohlcv_to_bar(
+        #[case] rtype: u8,
+        #[case] aggregation: BarAggregation,
+        #[case] interval: u64,
+    ) {
+        let record = DbnRecord::decode(&ohlcv_bytes(rtype, 1)).unwrap();
+
+        match parse_record(&record, instrument_id(), 2, 0).unwrap() {
+            Data::Bar(bar) => {
+                assert_eq!(bar.bar_type.spec.aggregation, aggregation);
+                assert_eq!(bar.bar_type.spec.price_type, PriceType::Last);
+                assert_eq!(bar.bar_type.aggregation_source, AggregationSource::External);
+                assert_eq!(bar.open, Price::new(100.0, 2));
+                assert_eq!(bar.high, Price::new(120.0, 2));
+                assert_eq!(bar.low, Price::new(90.0, 2));
+                assert_eq!(bar.close, Price::new(110.0, 2));
+                assert_eq!(bar.volume, Quantity::new(1500.0, 0));
+                assert_eq!(bar.ts_event, 60_000_000_000 + interval);
+            }
+            _ => panic!("Expected bar"),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use chrono::{Datelike, NaiveDateTime};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::identifiers::{instrument_id::InstrumentId, symbol::Symbol, venue::Venue};
+
+use super::decode::Metadata;
+
+/// The symbology type of DBN instrument IDs.
+pub const STYPE_INSTRUMENT_ID: u8 = 0;
+
+/// Represents the mapping of a DBN instrument ID over a range of UTC dates,
+/// `start_date` inclusive and `end_date` exclusive, each as `YYYYMMDD`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MappingInterval {
+    start_date: u32,
+    end_date: u32,
+    instrument_id: InstrumentId,
+}
+
+/// Provides the mapping of numeric DBN instrument IDs to Nautilus instrument IDs.
+///
+/// Venues may reuse numeric IDs for different instruments over time, so
+/// mappings taken from the DBN metadata are resolved by the date of each
+/// record. Mappings inserted explicitly apply to all dates and take priority.
+#[derive(Debug, Clone, Default)]
+pub struct InstrumentMap {
+    fixed: HashMap<u32, InstrumentId>,
+    intervals: HashMap<u32, Vec<MappingInterval>>,
+}
+
+impl InstrumentMap {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Initializes a new `InstrumentMap` from the symbol mappings in the
+    /// `metadata`, with raw symbols on the given `venue`.
+    ///
+    /// Mappings are only available when the output symbology of the metadata
+    /// is instrument IDs, otherwise the map is empty.
+    #[must_use]
+    pub fn from_metadata(metadata: &Metadata, venue: &Venue) -> Self {
+        let mut map = Self::new();
+        if metadata.stype_out != STYPE_INSTRUMENT_ID {
+            return map;
+        }
+
+        for mapping in &metadata.mappings {
+            let instrument_id = InstrumentId::new(Symbol::new(&mapping.raw_symbol), venue.clone());
+            for interval in &mapping.intervals {
+                if let Ok(dbn_id) = interval.symbol.parse::<u32>() {
+                    map.insert_interval(
+                        dbn_id,
+                        interval.start_date,
+                        interval.end_date,
+                        instrument_id.clone(),
+                    );
+                }
+            }
+        }
+        map
+    }
+
+    /// Maps the DBN `dbn_id` to the `instrument_id` for all dates.
+    pub fn insert(&mut self, dbn_id: u32, instrument_id: InstrumentId) {
+        self.fixed.insert(dbn_id, instrument_id);
+    }
+
+    /// Maps the DBN `dbn_id` to the `instrument_id` between the UTC dates.
+    pub fn insert_interval(
+        &mut self,
+        dbn_id: u32,
+        start_date: u32,
+        end_date: u32,
+        instrument_id: InstrumentId,
+    ) {
+        self.intervals
+            .entry(dbn_id)
+            .or_default()
+            .push(MappingInterval {
+                start_date,
+                end_date,
+                instrument_id,
+            });
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fixed.is_empty() && self.intervals.is_empty()
+    }
+
+    /// Returns the instrument ID for the DBN `dbn_id` at the time `ts_event`.
+    #[must_use]
+    pub fn resolve(&self, dbn_id: u32, ts_event: UnixNanos) -> Option<&InstrumentId> {
+        if let Some(instrument_id) = self.fixed.get(&dbn_id) {
+            return Some(instrument_id);
+        }
+
+        let date = utc_date(ts_event);
+        self.intervals
+            .get(&dbn_id)?
+            .iter()
+            .find(|interval| interval.start_date <= date && date < interval.end_date)
+            .map(|interval| &interval.instrument_id)
+    }
+}
+
+/// Returns the UTC date of the timestamp as `YYYYMMDD`.
+#[must_use]
+pub fn utc_date(ts: UnixNanos) -> u32 {
+    let secs = (ts / 1_000_000_000) as i64;
+    let datetime = NaiveDateTime::from_timestamp_opt(secs, 0).expect("Timestamp out of range");
+    datetime.year() as u32 * 10_000 + datetime.month() * 100 + datetime.day()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::databento::decode::{Mapping, MappingSymbol};
+
+    const JUN_1: UnixNanos = 1_685_577_600_000_000_000; // 2023-06-01T00:00:00Z
+    const NANOS_PER_DAY: UnixNanos = 86_400_000_000_000;
+
+    fn metadata(stype_out: u8) -> Metadata {
+        Metadata {
+            version: 1,
+            dataset: "GLBX.MDP3".to_string(),
+            schema: 0,
+            start: JUN_1,
+            end: JUN_1 + 2 * NANOS_PER_DAY,
+            limit: 0,
+            stype_in: 1,
+            stype_out,
+            ts_out: false,
+            symbols: vec!["ESM3".to_string()],
+            mappings: vec![
+                Mapping {
+                    raw_symbol: "ESM3".to_string(),
+                    intervals: vec![MappingSymbol {
+                        start_date: 20230601,
+                        end_date: 20230602,
+                        symbol: "5482".to_string(),
+                    }],
+                },
+                Mapping {
+                    raw_symbol: "ESU3".to_string(),
+                    intervals: vec![MappingSymbol {
+                        start_date: 20230602,
+                        end_date: 20230603,
+                        symbol: "5482".to_string(),
+                    }],
+                },
+            ],
+        }
+    }
+
+    #[rstest]
+    #[case(0, 19700101)]
+    #[case(JUN_1, 20230601)]
+    #[case(JUN_1 + NANOS_PER_DAY - 1, 20230601)]
+    #[case(JUN_1 + NANOS_PER_DAY, 20230602)]
+    fn test_utc_date(#[case] ts: UnixNanos, #[case] expected: u32) {
+        assert_eq!(utc_date(ts), expected);
+    }
+
+    #[test]
+    fn test_from_metadata_resolves_by_date() {
+        let map = InstrumentMap::from_metadata(&metadata(STYPE_INSTRUMENT_ID), &Venue::new("GLBX"));
+
+        assert_eq!(
+            map.resolve(5482, JUN_1),
+            Some(&InstrumentId::from_str("ESM3.GLBX").unwrap())
+        );
+        assert_eq!(
+            map.resolve(5482, JUN_1 + NANOS_PER_DAY),
+            Some(&InstrumentId::from_str("ESU3.GLBX").unwrap())
+        );
+        assert_eq!(map.resolve(5482, JUN_1 + 2 * NANOS_PER_DAY), None);
+        assert_eq!(map.resolve(1, JUN_1), None);
+    }
+
+    #[test]
+    fn test_from_metadata_other_stype_is_empty() {
+        let map = InstrumentMap::from_metadata(&metadata(1), &Venue::new("GLBX"));
+
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_fixed_mapping_takes_priority() {
+        let mut map =
+            InstrumentMap::from_metadata(&metadata(STYPE_INSTRUMENT_ID), &Venue::new("GLBX"));
+        let instrument_id = InstrumentId::from_str("ES.GLBX").unwrap();
+
+        map.insert(5482, instrument_id.clone());
+
+        assert_eq!(map.resolve(5482, 0), Some(&instrument_id));
+        assert_eq!(map.resolve(5482, JUN_1), Some(&instrument_id));
+    }
+}
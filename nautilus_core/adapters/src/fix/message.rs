@@ -0,0 +1,545 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX tag=value message encoding, decoding and stream framing.
+
+use std::{fmt::Write, str::FromStr};
+
+use super::{tags, FixError};
+
+/// The FIX field separator.
+pub const SOH: u8 = 0x01;
+pub const BEGIN_STRING_FIX44: &str = "FIX.4.4";
+
+/// Length of the trailing `10=NNN<SOH>` checksum field.
+const CHECKSUM_FIELD_LEN: usize = 7;
+
+/// Describes a repeating group so it can be read back from a flat message.
+///
+/// Each group instance starts with the `delimiter` tag and runs until the
+/// next delimiter or the first tag which is not one of the `members`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroupSpec {
+    pub count_tag: u32,
+    pub delimiter: u32,
+    pub members: &'static [u32],
+}
+
+/// Read access to the fields of a message or repeating group.
+pub trait FieldAccess {
+    /// Returns the value of the first field with the given tag.
+    fn get(&self, tag: u32) -> Option<&str>;
+
+    fn require(&self, tag: u32) -> Result<&str, FixError> {
+        self.get(tag).ok_or(FixError::MissingTag(tag))
+    }
+
+    fn parse<T: FromStr>(&self, tag: u32) -> Result<Option<T>, FixError> {
+        self.get(tag)
+            .map(|value| {
+                value.parse().map_err(|_| FixError::InvalidValue {
+                    tag,
+                    value: value.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    fn require_parsed<T: FromStr>(&self, tag: u32) -> Result<T, FixError> {
+        self.parse(tag)?.ok_or(FixError::MissingTag(tag))
+    }
+}
+
+/// A single instance of a repeating group.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixGroup {
+    fields: Vec<(u32, String)>,
+}
+
+impl FixGroup {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with(mut self, tag: u32, value: impl ToString) -> Self {
+        self.fields.push((tag, value.to_string()));
+        self
+    }
+
+    #[must_use]
+    pub fn fields(&self) -> &[(u32, String)] {
+        &self.fields
+    }
+}
+
+impl FieldAccess for FixGroup {
+    fn get(&self, tag: u32) -> Option<&str> {
+        find_field(&self.fields, tag)
+    }
+}
+
+/// A FIX message, holding its fields in wire order.
+///
+/// The BeginString, BodyLength and CheckSum fields are derived when the
+/// message is encoded, so are never held in `fields`. The MsgType is held
+/// separately as every message has exactly one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixMessage {
+    msg_type: String,
+    fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    #[must_use]
+    pub fn new(msg_type: &str) -> Self {
+        Self {
+            msg_type: msg_type.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn msg_type(&self) -> &str {
+        &self.msg_type
+    }
+
+    #[must_use]
+    pub fn fields(&self) -> &[(u32, String)] {
+        &self.fields
+    }
+
+    /// Appends a field, returning the message for chaining.
+    #[must_use]
+    pub fn with(mut self, tag: u32, value: impl ToString) -> Self {
+        self.push(tag, value);
+        self
+    }
+
+    /// Appends a field.
+    pub fn push(&mut self, tag: u32, value: impl ToString) {
+        self.fields.push((tag, value.to_string()));
+    }
+
+    /// Replaces the value of the first field with the given tag, appending
+    /// the field if it is not present.
+    pub fn set(&mut self, tag: u32, value: impl ToString) {
+        match self.fields.iter_mut().find(|(t, _)| *t == tag) {
+            Some(field) => field.1 = value.to_string(),
+            None => self.push(tag, value),
+        }
+    }
+
+    /// Removes every field with the given tag.
+    pub fn remove(&mut self, tag: u32) {
+        self.fields.retain(|(t, _)| *t != tag);
+    }
+
+    /// Appends a repeating group, preceded by its count field.
+    pub fn push_group(&mut self, count_tag: u32, groups: &[FixGroup]) {
+        self.push(count_tag, groups.len());
+        for group in groups {
+            self.fields.extend(group.fields.iter().cloned());
+        }
+    }
+
+    /// Reads back the repeating group described by `spec`.
+    ///
+    /// Returns an empty vector if the count field is not present.
+    pub fn groups(&self, spec: &GroupSpec) -> Result<Vec<FixGroup>, FixError> {
+        let start = match self.fields.iter().position(|(t, _)| *t == spec.count_tag) {
+            Some(start) => start,
+            None => return Ok(Vec::new()),
+        };
+        let count: usize = self.require_parsed(spec.count_tag)?;
+
+        let mut groups = Vec::with_capacity(count);
+        let mut fields = self.fields[start + 1..].iter().peekable();
+        for _ in 0..count {
+            match fields.next() {
+                Some((tag, value)) if *tag == spec.delimiter => {
+                    let mut group = FixGroup::new().with(*tag, value);
+                    while let Some((tag, value)) = fields
+                        .next_if(|(tag, _)| *tag != spec.delimiter && spec.members.contains(tag))
+                    {
+                        group.fields.push((*tag, value.clone()));
+                    }
+                    groups.push(group);
+                }
+                _ => {
+                    return Err(FixError::Malformed(format!(
+                        "expected {count} groups for tag {} starting with tag {}",
+                        spec.count_tag, spec.delimiter
+                    )))
+                }
+            }
+        }
+
+        Ok(groups)
+    }
+
+    /// Encodes the message, deriving the BodyLength and CheckSum fields.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut body = String::new();
+        write_field(&mut body, tags::MSG_TYPE, &self.msg_type);
+        for (tag, value) in &self.fields {
+            write_field(&mut body, *tag, value);
+        }
+
+        let mut msg = String::with_capacity(body.len() + 32);
+        write_field(&mut msg, tags::BEGIN_STRING, BEGIN_STRING_FIX44);
+        write_field(&mut msg, tags::BODY_LENGTH, &body.len().to_string());
+        msg.push_str(&body);
+
+        let mut bytes = msg.into_bytes();
+        let checksum = format!("{}={:03}", tags::CHECKSUM, checksum(&bytes));
+        bytes.extend_from_slice(checksum.as_bytes());
+        bytes.push(SOH);
+        bytes
+    }
+
+    /// Decodes a complete message, validating its BeginString, BodyLength
+    /// and CheckSum.
+    pub fn decode(bytes: &[u8]) -> Result<Self, FixError> {
+        if bytes.len() <= CHECKSUM_FIELD_LEN || bytes.last() != Some(&SOH) {
+            return Err(FixError::Malformed(
+                "message must end with a field separator".to_string(),
+            ));
+        }
+
+        let checksum_start = bytes.len() - CHECKSUM_FIELD_LEN;
+        let trailer = &bytes[checksum_start..bytes.len() - 1];
+        if !trailer.starts_with(b"10=") {
+            return Err(FixError::MissingTag(tags::CHECKSUM));
+        }
+        let declared = parse_ascii::<u8>(&trailer[3..])
+            .ok_or_else(|| FixError::Malformed("invalid CheckSum".to_string()))?;
+        let actual = checksum(&bytes[..checksum_start]);
+        if declared != actual {
+            return Err(FixError::InvalidChecksum { declared, actual });
+        }
+
+        let mut fields = bytes[..checksum_start - 1]
+            .split(|b| *b == SOH)
+            .map(parse_field);
+
+        match fields.next().transpose()? {
+            Some((tags::BEGIN_STRING, value)) if value == BEGIN_STRING_FIX44 => {}
+            Some((tags::BEGIN_STRING, value)) => return Err(FixError::InvalidBeginString(value)),
+            _ => return Err(FixError::MissingTag(tags::BEGIN_STRING)),
+        }
+
+        let declared = match fields.next().transpose()? {
+            Some((tags::BODY_LENGTH, value)) => {
+                value.parse().map_err(|_| FixError::InvalidValue {
+                    tag: tags::BODY_LENGTH,
+                    value,
+                })?
+            }
+            _ => return Err(FixError::MissingTag(tags::BODY_LENGTH)),
+        };
+        let actual = checksum_start - body_start(bytes)?;
+        if declared != actual {
+            return Err(FixError::InvalidBodyLength { declared, actual });
+        }
+
+        let msg_type = match fields.next().transpose()? {
+            Some((tags::MSG_TYPE, value)) => value,
+            _ => return Err(FixError::MissingTag(tags::MSG_TYPE)),
+        };
+
+        Ok(Self {
+            msg_type,
+            fields: fields.collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl FieldAccess for FixMessage {
+    fn get(&self, tag: u32) -> Option<&str> {
+        find_field(&self.fields, tag)
+    }
+}
+
+/// Returns the FIX checksum of `bytes`, the sum of all bytes modulo 256.
+#[must_use]
+pub fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b))
+}
+
+/// Drains the next complete message from the front of a stream buffer.
+///
+/// Any bytes before the first BeginString are discarded. Returns `None` if
+/// the buffer does not yet hold a complete message. An error means the
+/// stream is corrupt and the connection should be dropped.
+pub fn next_frame(buf: &mut Vec<u8>) -> Result<Option<Vec<u8>>, FixError> {
+    match buf.windows(2).position(|w| w == b"8=") {
+        Some(0) => {}
+        Some(start) => {
+            buf.drain(..start);
+        }
+        None => {
+            // Keep a trailing '8' which may start the next message
+            let keep = usize::from(buf.last() == Some(&b'8'));
+            buf.drain(..buf.len() - keep);
+            return Ok(None);
+        }
+    }
+
+    // Wait until the BodyLength field has been received
+    let body_start = match body_start(buf) {
+        Ok(body_start) => body_start,
+        Err(_) => return Ok(None),
+    };
+    let length_field = &buf[..body_start - 1];
+    let body_length: usize = length_field
+        .iter()
+        .position(|b| *b == SOH)
+        .and_then(|i| length_field[i + 1..].strip_prefix(b"9="))
+        .and_then(parse_ascii)
+        .ok_or(FixError::MissingTag(tags::BODY_LENGTH))?;
+
+    let end = body_start + body_length + CHECKSUM_FIELD_LEN;
+    if buf.len() < end {
+        return Ok(None);
+    }
+
+    Ok(Some(buf.drain(..end).collect()))
+}
+
+/// Returns the index just after the BodyLength field, where the body starts.
+fn body_start(bytes: &[u8]) -> Result<usize, FixError> {
+    let mut separators = bytes
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| **b == SOH)
+        .map(|(i, _)| i);
+    match (separators.next(), separators.next()) {
+        (Some(_), Some(second)) => Ok(second + 1),
+        _ => Err(FixError::MissingTag(tags::BODY_LENGTH)),
+    }
+}
+
+fn find_field(fields: &[(u32, String)], tag: u32) -> Option<&str> {
+    fields
+        .iter()
+        .find(|(t, _)| *t == tag)
+        .map(|(_, value)| value.as_str())
+}
+
+fn write_field(out: &mut String, tag: u32, value: &str) {
+    write!(out, "{tag}={value}").expect("writing to a String cannot fail");
+    out.push(char::from(SOH));
+}
+
+fn parse_ascii<T: FromStr>(bytes: &[u8]) -> Option<T> {
+    std::str::from_utf8(bytes).ok()?.parse().ok()
+}
+
+fn parse_field(field: &[u8]) -> Result<(u32, String), FixError> {
+    let field = std::str::from_utf8(field)
+        .map_err(|_| FixError::Malformed("field is not valid UTF-8".to_string()))?;
+    let (tag, value) = field
+        .split_once('=')
+        .ok_or_else(|| FixError::Malformed(format!("field {field:?} has no '='")))?;
+    let tag = tag
+        .parse()
+        .map_err(|_| FixError::Malformed(format!("invalid tag {tag:?}")))?;
+    Ok((tag, value.to_string()))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::fix::tags::msg_type;
+
+    /// Converts a message written with '|' separators into wire format.
+    pub fn wire(msg: &str) -> Vec<u8> {
+        msg.replace('|', "\x01").into_bytes()
+    }
+
+    /// Appends a valid CheckSum field to a message written with '|' separators.
+    fn with_checksum(msg: &str) -> Vec<u8> {
+        let mut bytes = wire(msg);
+        let trailer = format!("10={:03}\x01", checksum(&bytes));
+        bytes.extend_from_slice(trailer.as_bytes());
+        bytes
+    }
+
+    const MD_ENTRY_GROUP: GroupSpec = GroupSpec {
+        count_tag: tags::NO_MD_ENTRIES,
+        delimiter: tags::MD_ENTRY_TYPE,
+        members: &[tags::MD_ENTRY_TYPE, tags::MD_ENTRY_PX, tags::MD_ENTRY_SIZE],
+    };
+
+    #[test]
+    fn test_encode() {
+        let msg = FixMessage::new(msg_type::HEARTBEAT)
+            .with(tags::SENDER_COMP_ID, "SENDER")
+            .with(tags::TARGET_COMP_ID, "TARGET")
+            .with(tags::MSG_SEQ_NUM, 2)
+            .with(tags::SENDING_TIME, "20230601-12:00:00.000");
+
+        assert_eq!(
+            msg.encode(),
+            with_checksum("8=FIX.4.4|9=55|35=0|49=SENDER|56=TARGET|34=2|52=20230601-12:00:00.000|")
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        let bytes = FixMessage::new(msg_type::HEARTBEAT)
+            .with(tags::SENDER_COMP_ID, "SENDER")
+            .with(tags::MSG_SEQ_NUM, 7)
+            .encode();
+
+        let msg = FixMessage::decode(&bytes).unwrap();
+
+        assert_eq!(msg.msg_type(), msg_type::HEARTBEAT);
+        assert_eq!(msg.get(tags::SENDER_COMP_ID), Some("SENDER"));
+        assert_eq!(msg.require_parsed::<u64>(tags::MSG_SEQ_NUM).unwrap(), 7);
+        assert_eq!(msg.get(tags::TEXT), None);
+    }
+
+    #[test]
+    fn test_decode_invalid_checksum() {
+        let mut bytes = FixMessage::new(msg_type::HEARTBEAT)
+            .with(tags::MSG_SEQ_NUM, 1)
+            .encode();
+        let len = bytes.len();
+        bytes[len - 2] = if bytes[len - 2] == b'0' { b'1' } else { b'0' };
+
+        assert!(matches!(
+            FixMessage::decode(&bytes),
+            Err(FixError::InvalidChecksum { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case(
+        with_checksum("8=FIX.4.2|9=5|35=0|"),
+        FixError::InvalidBeginString("FIX.4.2".to_string())
+    )]
+    #[case(
+        with_checksum("8=FIX.4.4|9=99|35=0|"),
+        FixError::InvalidBodyLength { declared: 99, actual: 5 }
+    )]
+    #[case(
+        with_checksum("8=FIX.4.4|9=5|99=0|"),
+        FixError::MissingTag(tags::MSG_TYPE)
+    )]
+    #[case(wire("8=FIX.4.4|9=5|35=0|"), FixError::MissingTag(tags::CHECKSUM))]
+    fn test_decode_rejects_malformed(#[case] bytes: Vec<u8>, #[case] expected: FixError) {
+        assert_eq!(FixMessage::decode(&bytes), Err(expected));
+    }
+
+    #[test]
+    fn test_parse_invalid_value() {
+        let msg = FixMessage::new(msg_type::HEARTBEAT).with(tags::MSG_SEQ_NUM, "abc");
+
+        assert_eq!(
+            msg.parse::<u64>(tags::MSG_SEQ_NUM),
+            Err(FixError::InvalidValue {
+                tag: tags::MSG_SEQ_NUM,
+                value: "abc".to_string()
+            })
+        );
+        assert_eq!(
+            msg.require(tags::TEXT),
+            Err(FixError::MissingTag(tags::TEXT))
+        );
+    }
+
+    #[test]
+    fn test_set_and_remove() {
+        let mut msg = FixMessage::new(msg_type::HEARTBEAT).with(tags::MSG_SEQ_NUM, 1);
+
+        msg.set(tags::MSG_SEQ_NUM, 2);
+        msg.set(tags::POSS_DUP_FLAG, "Y");
+        msg.remove(tags::MSG_SEQ_NUM);
+
+        assert_eq!(msg.fields(), &[(tags::POSS_DUP_FLAG, "Y".to_string())]);
+    }
+
+    #[test]
+    fn test_repeating_groups_round_trip() {
+        let entries = vec![
+            FixGroup::new()
+                .with(tags::MD_ENTRY_TYPE, 0)
+                .with(tags::MD_ENTRY_PX, "100.5")
+                .with(tags::MD_ENTRY_SIZE, 10),
+            FixGroup::new()
+                .with(tags::MD_ENTRY_TYPE, 1)
+                .with(tags::MD_ENTRY_PX, "101.0"),
+        ];
+        let mut msg = FixMessage::new(msg_type::MARKET_DATA_SNAPSHOT).with(tags::SYMBOL, "AAPL");
+        msg.push_group(tags::NO_MD_ENTRIES, &entries);
+        msg.push(tags::TEXT, "after group");
+
+        let decoded = FixMessage::decode(&msg.encode()).unwrap();
+        let groups = decoded.groups(&MD_ENTRY_GROUP).unwrap();
+
+        assert_eq!(groups, entries);
+        assert_eq!(groups[1].get(tags::MD_ENTRY_SIZE), None);
+        assert_eq!(decoded.get(tags::TEXT), Some("after group"));
+    }
+
+    #[test]
+    fn test_groups_missing_count_is_empty() {
+        let msg = FixMessage::new(msg_type::MARKET_DATA_SNAPSHOT);
+
+        assert!(msg.groups(&MD_ENTRY_GROUP).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_groups_count_mismatch() {
+        let msg = FixMessage::new(msg_type::MARKET_DATA_SNAPSHOT)
+            .with(tags::NO_MD_ENTRIES, 2)
+            .with(tags::MD_ENTRY_TYPE, 0);
+
+        assert!(matches!(
+            msg.groups(&MD_ENTRY_GROUP),
+            Err(FixError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn test_next_frame() {
+        let first = FixMessage::new(msg_type::HEARTBEAT)
+            .with(tags::MSG_SEQ_NUM, 1)
+            .encode();
+        let second = FixMessage::new(msg_type::TEST_REQUEST)
+            .with(tags::TEST_REQ_ID, "abc")
+            .encode();
+
+        let mut buf = b"garbage".to_vec();
+        buf.extend_from_slice(&first);
+        buf.extend_from_slice(&second[..10]);
+
+        assert_eq!(next_frame(&mut buf).unwrap(), Some(first));
+        assert_eq!(next_frame(&mut buf).unwrap(), None);
+
+        buf.extend_from_slice(&second[10..]);
+        assert_eq!(next_frame(&mut buf).unwrap(), Some(second));
+        assert!(buf.is_empty());
+    }
+}
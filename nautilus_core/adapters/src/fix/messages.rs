@@ -0,0 +1,434 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Typed FIX 4.4 application messages.
+
+use nautilus_model::enums::OrderSide;
+
+use super::{
+    message::{FieldAccess, FixGroup, FixMessage, GroupSpec},
+    tags::{self, msg_type},
+    FixError,
+};
+
+const SNAPSHOT_ENTRIES: GroupSpec = GroupSpec {
+    count_tag: tags::NO_MD_ENTRIES,
+    delimiter: tags::MD_ENTRY_TYPE,
+    members: &[
+        tags::MD_ENTRY_TYPE,
+        tags::MD_ENTRY_PX,
+        tags::MD_ENTRY_SIZE,
+        tags::MD_ENTRY_ID,
+    ],
+};
+
+const INCREMENTAL_ENTRIES: GroupSpec = GroupSpec {
+    count_tag: tags::NO_MD_ENTRIES,
+    delimiter: tags::MD_UPDATE_ACTION,
+    members: &[
+        tags::MD_UPDATE_ACTION,
+        tags::MD_ENTRY_TYPE,
+        tags::MD_ENTRY_ID,
+        tags::SYMBOL,
+        tags::MD_ENTRY_PX,
+        tags::MD_ENTRY_SIZE,
+    ],
+};
+
+/// An ExecutionReport (35=8).
+///
+/// The ExecType (150) and OrdStatus (39) values are kept as their FIX codes,
+/// for example '0' for new, 'F' for a trade or '4' for canceled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionReport {
+    pub order_id: String,
+    pub cl_ord_id: Option<String>,
+    pub exec_id: String,
+    pub exec_type: char,
+    pub ord_status: char,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_qty: Option<f64>,
+    pub price: Option<f64>,
+    pub last_qty: Option<f64>,
+    pub last_px: Option<f64>,
+    pub leaves_qty: f64,
+    pub cum_qty: f64,
+    pub avg_px: Option<f64>,
+    pub transact_time: Option<String>,
+    pub text: Option<String>,
+}
+
+impl TryFrom<&FixMessage> for ExecutionReport {
+    type Error = FixError;
+
+    fn try_from(msg: &FixMessage) -> Result<Self, Self::Error> {
+        expect_msg_type(msg, msg_type::EXECUTION_REPORT)?;
+
+        Ok(Self {
+            order_id: msg.require(tags::ORDER_ID)?.to_string(),
+            cl_ord_id: msg.get(tags::CL_ORD_ID).map(str::to_string),
+            exec_id: msg.require(tags::EXEC_ID)?.to_string(),
+            exec_type: msg.require_parsed(tags::EXEC_TYPE)?,
+            ord_status: msg.require_parsed(tags::ORD_STATUS)?,
+            symbol: msg.require(tags::SYMBOL)?.to_string(),
+            side: parse_side(msg.require(tags::SIDE)?)?,
+            order_qty: msg.parse(tags::ORDER_QTY)?,
+            price: msg.parse(tags::PRICE)?,
+            last_qty: msg.parse(tags::LAST_QTY)?,
+            last_px: msg.parse(tags::LAST_PX)?,
+            leaves_qty: msg.require_parsed(tags::LEAVES_QTY)?,
+            cum_qty: msg.require_parsed(tags::CUM_QTY)?,
+            avg_px: msg.parse(tags::AVG_PX)?,
+            transact_time: msg.get(tags::TRANSACT_TIME).map(str::to_string),
+            text: msg.get(tags::TEXT).map(str::to_string),
+        })
+    }
+}
+
+/// The MDEntryType (269) of a market data entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MdEntryType {
+    Bid,
+    Offer,
+    Trade,
+    Other(char),
+}
+
+impl MdEntryType {
+    fn from_fix(value: char) -> Self {
+        match value {
+            '0' => Self::Bid,
+            '1' => Self::Offer,
+            '2' => Self::Trade,
+            other => Self::Other(other),
+        }
+    }
+
+    #[must_use]
+    pub fn as_fix(&self) -> char {
+        match self {
+            Self::Bid => '0',
+            Self::Offer => '1',
+            Self::Trade => '2',
+            Self::Other(other) => *other,
+        }
+    }
+}
+
+/// The MDUpdateAction (279) of an incremental market data entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MdUpdateAction {
+    New,
+    Change,
+    Delete,
+}
+
+impl MdUpdateAction {
+    fn from_fix(value: &str) -> Result<Self, FixError> {
+        match value {
+            "0" => Ok(Self::New),
+            "1" => Ok(Self::Change),
+            "2" => Ok(Self::Delete),
+            _ => Err(FixError::InvalidValue {
+                tag: tags::MD_UPDATE_ACTION,
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+/// An entry of a market data snapshot or incremental refresh.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketDataEntry {
+    pub update_action: Option<MdUpdateAction>,
+    pub entry_type: MdEntryType,
+    pub entry_id: Option<String>,
+    pub symbol: Option<String>,
+    pub price: Option<f64>,
+    pub size: Option<f64>,
+}
+
+impl TryFrom<&FixGroup> for MarketDataEntry {
+    type Error = FixError;
+
+    fn try_from(group: &FixGroup) -> Result<Self, Self::Error> {
+        Ok(Self {
+            update_action: group
+                .get(tags::MD_UPDATE_ACTION)
+                .map(MdUpdateAction::from_fix)
+                .transpose()?,
+            entry_type: MdEntryType::from_fix(group.require_parsed(tags::MD_ENTRY_TYPE)?),
+            entry_id: group.get(tags::MD_ENTRY_ID).map(str::to_string),
+            symbol: group.get(tags::SYMBOL).map(str::to_string),
+            price: group.parse(tags::MD_ENTRY_PX)?,
+            size: group.parse(tags::MD_ENTRY_SIZE)?,
+        })
+    }
+}
+
+/// A MarketDataSnapshotFullRefresh (35=W).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketDataSnapshot {
+    pub md_req_id: Option<String>,
+    pub symbol: String,
+    pub entries: Vec<MarketDataEntry>,
+}
+
+impl TryFrom<&FixMessage> for MarketDataSnapshot {
+    type Error = FixError;
+
+    fn try_from(msg: &FixMessage) -> Result<Self, Self::Error> {
+        expect_msg_type(msg, msg_type::MARKET_DATA_SNAPSHOT)?;
+
+        Ok(Self {
+            md_req_id: msg.get(tags::MD_REQ_ID).map(str::to_string),
+            symbol: msg.require(tags::SYMBOL)?.to_string(),
+            entries: parse_entries(msg, &SNAPSHOT_ENTRIES)?,
+        })
+    }
+}
+
+/// A MarketDataIncrementalRefresh (35=X).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketDataIncrementalRefresh {
+    pub md_req_id: Option<String>,
+    pub entries: Vec<MarketDataEntry>,
+}
+
+impl TryFrom<&FixMessage> for MarketDataIncrementalRefresh {
+    type Error = FixError;
+
+    fn try_from(msg: &FixMessage) -> Result<Self, Self::Error> {
+        expect_msg_type(msg, msg_type::MARKET_DATA_INCREMENTAL_REFRESH)?;
+
+        Ok(Self {
+            md_req_id: msg.get(tags::MD_REQ_ID).map(str::to_string),
+            entries: parse_entries(msg, &INCREMENTAL_ENTRIES)?,
+        })
+    }
+}
+
+/// A MarketDataRequest (35=V) subscribing to updates for some symbols.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarketDataRequest {
+    pub md_req_id: String,
+    pub symbols: Vec<String>,
+    pub entry_types: Vec<MdEntryType>,
+    /// Zero for the full book, one for top of book.
+    pub market_depth: u32,
+}
+
+impl From<&MarketDataRequest> for FixMessage {
+    fn from(request: &MarketDataRequest) -> Self {
+        // SubscriptionRequestType 1 is snapshot plus updates
+        let mut msg = FixMessage::new(msg_type::MARKET_DATA_REQUEST)
+            .with(tags::MD_REQ_ID, &request.md_req_id)
+            .with(tags::SUBSCRIPTION_REQUEST_TYPE, 1)
+            .with(tags::MARKET_DEPTH, request.market_depth);
+
+        let entry_types: Vec<FixGroup> = request
+            .entry_types
+            .iter()
+            .map(|entry_type| FixGroup::new().with(tags::MD_ENTRY_TYPE, entry_type.as_fix()))
+            .collect();
+        msg.push_group(tags::NO_MD_ENTRY_TYPES, &entry_types);
+
+        let symbols: Vec<FixGroup> = request
+            .symbols
+            .iter()
+            .map(|symbol| FixGroup::new().with(tags::SYMBOL, symbol))
+            .collect();
+        msg.push_group(tags::NO_RELATED_SYM, &symbols);
+
+        msg
+    }
+}
+
+fn expect_msg_type(msg: &FixMessage, expected: &str) -> Result<(), FixError> {
+    if msg.msg_type() == expected {
+        Ok(())
+    } else {
+        Err(FixError::InvalidValue {
+            tag: tags::MSG_TYPE,
+            value: msg.msg_type().to_string(),
+        })
+    }
+}
+
+fn parse_side(value: &str) -> Result<OrderSide, FixError> {
+    match value {
+        "1" => Ok(OrderSide::Buy),
+        "2" => Ok(OrderSide::Sell),
+        _ => Err(FixError::InvalidValue {
+            tag: tags::SIDE,
+            value: value.to_string(),
+        }),
+    }
+}
+
+fn parse_entries(msg: &FixMessage, spec: &GroupSpec) -> Result<Vec<MarketDataEntry>, FixError> {
+    msg.groups(spec)?
+        .iter()
+        .map(MarketDataEntry::try_from)
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn execution_report(side: &str) -> FixMessage {
+        FixMessage::new(msg_type::EXECUTION_REPORT)
+            .with(tags::ORDER_ID, "O-1")
+            .with(tags::CL_ORD_ID, "C-1")
+            .with(tags::EXEC_ID, "E-1")
+            .with(tags::EXEC_TYPE, "F")
+            .with(tags::ORD_STATUS, "1")
+            .with(tags::SYMBOL, "AAPL")
+            .with(tags::SIDE, side)
+            .with(tags::ORDER_QTY, "100")
+            .with(tags::PRICE, "150.25")
+            .with(tags::LAST_QTY, "40")
+            .with(tags::LAST_PX, "150.20")
+            .with(tags::LEAVES_QTY, "60")
+            .with(tags::CUM_QTY, "40")
+            .with(tags::AVG_PX, "150.20")
+    }
+
+    #[test]
+    fn test_execution_report() {
+        let report = ExecutionReport::try_from(&execution_report("1")).unwrap();
+
+        assert_eq!(report.order_id, "O-1");
+        assert_eq!(report.cl_ord_id.as_deref(), Some("C-1"));
+        assert_eq!(report.exec_type, 'F');
+        assert_eq!(report.ord_status, '1');
+        assert_eq!(report.side, OrderSide::Buy);
+        assert_eq!(report.price, Some(150.25));
+        assert_eq!(report.last_qty, Some(40.0));
+        assert_eq!(report.leaves_qty, 60.0);
+        assert_eq!(report.transact_time, None);
+    }
+
+    #[test]
+    fn test_execution_report_invalid_side() {
+        assert_eq!(
+            ExecutionReport::try_from(&execution_report("9")),
+            Err(FixError::InvalidValue {
+                tag: tags::SIDE,
+                value: "9".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_execution_report_wrong_msg_type() {
+        let msg = FixMessage::new(msg_type::HEARTBEAT);
+
+        assert!(ExecutionReport::try_from(&msg).is_err());
+    }
+
+    #[test]
+    fn test_market_data_snapshot() {
+        let mut msg = FixMessage::new(msg_type::MARKET_DATA_SNAPSHOT)
+            .with(tags::MD_REQ_ID, "MD-1")
+            .with(tags::SYMBOL, "AAPL");
+        msg.push_group(
+            tags::NO_MD_ENTRIES,
+            &[
+                FixGroup::new()
+                    .with(tags::MD_ENTRY_TYPE, 0)
+                    .with(tags::MD_ENTRY_PX, "150.00")
+                    .with(tags::MD_ENTRY_SIZE, "500"),
+                FixGroup::new()
+                    .with(tags::MD_ENTRY_TYPE, 1)
+                    .with(tags::MD_ENTRY_PX, "150.05")
+                    .with(tags::MD_ENTRY_SIZE, "300"),
+            ],
+        );
+
+        let snapshot = MarketDataSnapshot::try_from(&msg).unwrap();
+
+        assert_eq!(snapshot.symbol, "AAPL");
+        assert_eq!(snapshot.entries.len(), 2);
+        assert_eq!(snapshot.entries[0].entry_type, MdEntryType::Bid);
+        assert_eq!(snapshot.entries[1].entry_type, MdEntryType::Offer);
+        assert_eq!(snapshot.entries[1].price, Some(150.05));
+        assert_eq!(snapshot.entries[1].size, Some(300.0));
+    }
+
+    #[test]
+    fn test_market_data_incremental_refresh() {
+        let mut msg = FixMessage::new(msg_type::MARKET_DATA_INCREMENTAL_REFRESH);
+        msg.push_group(
+            tags::NO_MD_ENTRIES,
+            &[
+                FixGroup::new()
+                    .with(tags::MD_UPDATE_ACTION, 0)
+                    .with(tags::MD_ENTRY_TYPE, 2)
+                    .with(tags::SYMBOL, "AAPL")
+                    .with(tags::MD_ENTRY_PX, "150.01")
+                    .with(tags::MD_ENTRY_SIZE, "10"),
+                FixGroup::new()
+                    .with(tags::MD_UPDATE_ACTION, 2)
+                    .with(tags::MD_ENTRY_TYPE, 0)
+                    .with(tags::MD_ENTRY_ID, "B-1")
+                    .with(tags::SYMBOL, "MSFT"),
+            ],
+        );
+
+        let refresh = MarketDataIncrementalRefresh::try_from(&msg).unwrap();
+
+        assert_eq!(refresh.entries.len(), 2);
+        assert_eq!(refresh.entries[0].update_action, Some(MdUpdateAction::New));
+        assert_eq!(refresh.entries[0].entry_type, MdEntryType::Trade);
+        assert_eq!(
+            refresh.entries[1].update_action,
+            Some(MdUpdateAction::Delete)
+        );
+        assert_eq!(refresh.entries[1].entry_id.as_deref(), Some("B-1"));
+        assert_eq!(refresh.entries[1].symbol.as_deref(), Some("MSFT"));
+        assert_eq!(refresh.entries[1].price, None);
+    }
+
+    #[test]
+    fn test_market_data_request() {
+        let request = MarketDataRequest {
+            md_req_id: "MD-1".to_string(),
+            symbols: vec!["AAPL".to_string(), "MSFT".to_string()],
+            entry_types: vec![MdEntryType::Bid, MdEntryType::Offer],
+            market_depth: 1,
+        };
+
+        let msg = FixMessage::from(&request);
+        let decoded = FixMessage::decode(&msg.encode()).unwrap();
+        let symbols = decoded
+            .groups(&GroupSpec {
+                count_tag: tags::NO_RELATED_SYM,
+                delimiter: tags::SYMBOL,
+                members: &[tags::SYMBOL],
+            })
+            .unwrap();
+
+        assert_eq!(decoded.msg_type(), msg_type::MARKET_DATA_REQUEST);
+        assert_eq!(decoded.get(tags::NO_MD_ENTRY_TYPES), Some("2"));
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols[1].get(tags::SYMBOL), Some("MSFT"));
+    }
+}
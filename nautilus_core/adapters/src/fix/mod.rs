@@ -0,0 +1,50 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A FIX 4.4 session layer.
+//!
+//! The session is sans-IO: bytes received from the counterparty are fed in
+//! and the bytes to write back are returned, leaving the transport to the
+//! caller. Application messages are decoded into typed messages and passed
+//! to a [`session::FixApplication`].
+
+pub mod message;
+pub mod messages;
+pub mod session;
+pub mod tags;
+
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FixError {
+    #[error("Malformed FIX message: {0}")]
+    Malformed(String),
+    #[error("Invalid BeginString {0}")]
+    InvalidBeginString(String),
+    #[error("Invalid BodyLength, declared {declared} but was {actual}")]
+    InvalidBodyLength { declared: usize, actual: usize },
+    #[error("Invalid CheckSum, declared {declared} but was {actual}")]
+    InvalidChecksum { declared: u8, actual: u8 },
+    #[error("Missing required tag {0}")]
+    MissingTag(u32),
+    #[error("Invalid value {value:?} for tag {tag}")]
+    InvalidValue { tag: u32, value: String },
+    #[error("MsgSeqNum {received} lower than expected {expected}")]
+    SequenceTooLow { expected: u64, received: u64 },
+    #[error("No heartbeat received in response to TestRequest")]
+    HeartbeatTimeout,
+    #[error("Session is not active")]
+    NotActive,
+}
@@ -0,0 +1,897 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The FIX 4.4 session layer for an initiator.
+//!
+//! [`FixSession`] implements logon and logout, heartbeats and test requests,
+//! and sequence number management including gap detection, resend requests
+//! and gap fills. It holds no connection: received bytes are passed to
+//! [`FixSession::on_bytes`] and every method returns the encoded messages
+//! which must be written to the counterparty, in order.
+
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use chrono::{TimeZone, Utc};
+use nautilus_core::time::UnixNanos;
+
+use super::{
+    message::{next_frame, FieldAccess, FixMessage},
+    messages::{ExecutionReport, MarketDataIncrementalRefresh, MarketDataSnapshot},
+    tags::{self, msg_type},
+    FixError,
+};
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// Tags set by the session on every outgoing message.
+const HEADER_TAGS: [u32; 6] = [
+    tags::SENDER_COMP_ID,
+    tags::TARGET_COMP_ID,
+    tags::MSG_SEQ_NUM,
+    tags::SENDING_TIME,
+    tags::POSS_DUP_FLAG,
+    tags::ORIG_SENDING_TIME,
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionConfig {
+    pub sender_comp_id: String,
+    pub target_comp_id: String,
+    pub heartbeat_interval_secs: u64,
+    /// Sends ResetSeqNumFlag (141=Y) on logon, starting both sequences at 1.
+    pub reset_seq_num_on_logon: bool,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+impl SessionConfig {
+    #[must_use]
+    pub fn new(sender_comp_id: &str, target_comp_id: &str, heartbeat_interval_secs: u64) -> Self {
+        Self {
+            sender_comp_id: sender_comp_id.to_string(),
+            target_comp_id: target_comp_id.to_string(),
+            heartbeat_interval_secs,
+            reset_seq_num_on_logon: false,
+            username: None,
+            password: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionState {
+    Disconnected,
+    LogonSent,
+    Active,
+    LogoutSent,
+}
+
+/// Callbacks for events and application messages received by a session.
+///
+/// Every method has an empty default so implementors only handle what
+/// they need.
+pub trait FixApplication {
+    fn on_logon(&mut self) {}
+    fn on_logout(&mut self, _text: Option<&str>) {}
+    fn on_reject(&mut self, _ref_seq_num: Option<u64>, _text: Option<&str>) {}
+    fn on_execution_report(&mut self, _report: ExecutionReport) {}
+    fn on_market_data_snapshot(&mut self, _snapshot: MarketDataSnapshot) {}
+    fn on_market_data_incremental(&mut self, _refresh: MarketDataIncrementalRefresh) {}
+    /// Called for application messages without a typed callback.
+    fn on_message(&mut self, _msg: &FixMessage) {}
+}
+
+/// A FIX 4.4 initiator session.
+///
+/// Sent application messages are kept so they can be resent on request,
+/// administrative messages are replaced by a gap fill. Messages received
+/// ahead of a sequence gap are queued and delivered once the gap is filled.
+pub struct FixSession<A> {
+    config: SessionConfig,
+    app: A,
+    state: SessionState,
+    next_sender_seq_num: u64,
+    next_target_seq_num: u64,
+    last_sent_ns: UnixNanos,
+    last_received_ns: UnixNanos,
+    test_request_sent_ns: Option<UnixNanos>,
+    test_request_count: u64,
+    resend_requested: bool,
+    sent: BTreeMap<u64, FixMessage>,
+    queued: BTreeMap<u64, FixMessage>,
+    buffer: Vec<u8>,
+}
+
+impl<A> FixSession<A>
+where
+    A: FixApplication,
+{
+    #[must_use]
+    pub fn new(config: SessionConfig, app: A) -> Self {
+        Self {
+            config,
+            app,
+            state: SessionState::Disconnected,
+            next_sender_seq_num: 1,
+            next_target_seq_num: 1,
+            last_sent_ns: 0,
+            last_received_ns: 0,
+            test_request_sent_ns: None,
+            test_request_count: 0,
+            resend_requested: false,
+            sent: BTreeMap::new(),
+            queued: BTreeMap::new(),
+            buffer: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn state(&self) -> SessionState {
+        self.state
+    }
+
+    #[must_use]
+    pub fn app(&self) -> &A {
+        &self.app
+    }
+
+    pub fn app_mut(&mut self) -> &mut A {
+        &mut self.app
+    }
+
+    #[must_use]
+    pub fn next_sender_seq_num(&self) -> u64 {
+        self.next_sender_seq_num
+    }
+
+    #[must_use]
+    pub fn next_target_seq_num(&self) -> u64 {
+        self.next_target_seq_num
+    }
+
+    /// Starts the session, returning the Logon message to send once connected.
+    pub fn logon(&mut self, now: UnixNanos) -> Vec<u8> {
+        if self.config.reset_seq_num_on_logon {
+            self.next_sender_seq_num = 1;
+            self.next_target_seq_num = 1;
+            self.sent.clear();
+        }
+        self.queued.clear();
+        self.buffer.clear();
+        self.resend_requested = false;
+        self.test_request_sent_ns = None;
+        self.last_received_ns = now;
+
+        let mut msg = FixMessage::new(msg_type::LOGON)
+            .with(tags::ENCRYPT_METHOD, 0)
+            .with(tags::HEART_BT_INT, self.config.heartbeat_interval_secs);
+        if self.config.reset_seq_num_on_logon {
+            msg.push(tags::RESET_SEQ_NUM_FLAG, "Y");
+        }
+        if let Some(username) = &self.config.username {
+            msg.push(tags::USERNAME, username);
+        }
+        if let Some(password) = &self.config.password {
+            msg.push(tags::PASSWORD, password);
+        }
+
+        self.state = SessionState::LogonSent;
+        self.send_admin(msg, now)
+    }
+
+    /// Ends the session, returning the Logout message to send.
+    ///
+    /// The session is disconnected once the counterparty confirms.
+    pub fn logout(&mut self, text: Option<&str>, now: UnixNanos) -> Vec<u8> {
+        let mut msg = FixMessage::new(msg_type::LOGOUT);
+        if let Some(text) = text {
+            msg.push(tags::TEXT, text);
+        }
+
+        self.state = SessionState::LogoutSent;
+        self.send_admin(msg, now)
+    }
+
+    /// Sends an application message, returning it encoded with the header set.
+    pub fn send(&mut self, msg: FixMessage, now: UnixNanos) -> Result<Vec<u8>, FixError> {
+        if self.state != SessionState::Active {
+            return Err(FixError::NotActive);
+        }
+
+        let seq_num = self.next_sender_seq_num;
+        let msg = self.stamp(msg, seq_num, now, None);
+        self.next_sender_seq_num += 1;
+        self.last_sent_ns = now;
+
+        let bytes = msg.encode();
+        self.sent.insert(seq_num, msg);
+        Ok(bytes)
+    }
+
+    /// Processes bytes received from the counterparty.
+    ///
+    /// Partial messages are buffered until complete. An error means the
+    /// session can no longer continue and the connection should be dropped.
+    pub fn on_bytes(&mut self, data: &[u8], now: UnixNanos) -> Result<Vec<Vec<u8>>, FixError> {
+        self.buffer.extend_from_slice(data);
+
+        let mut out = Vec::new();
+        loop {
+            let frame = match next_frame(&mut self.buffer)? {
+                Some(frame) => frame,
+                None => break,
+            };
+            let msg = FixMessage::decode(&frame)?;
+            out.extend(self.on_message(msg, now)?);
+        }
+        Ok(out)
+    }
+
+    /// Processes a single decoded message from the counterparty.
+    pub fn on_message(
+        &mut self,
+        msg: FixMessage,
+        now: UnixNanos,
+    ) -> Result<Vec<Vec<u8>>, FixError> {
+        self.last_received_ns = now;
+        self.test_request_sent_ns = None;
+
+        let seq_num: u64 = msg.require_parsed(tags::MSG_SEQ_NUM)?;
+        let mut out = Vec::new();
+
+        // A SequenceReset in reset mode applies regardless of its MsgSeqNum
+        if msg.msg_type() == msg_type::SEQUENCE_RESET && msg.get(tags::GAP_FILL_FLAG) != Some("Y") {
+            let new_seq_num: u64 = msg.require_parsed(tags::NEW_SEQ_NO)?;
+            self.next_target_seq_num = self.next_target_seq_num.max(new_seq_num);
+            self.deliver_queued(now, &mut out)?;
+            return Ok(out);
+        }
+
+        if msg.msg_type() == msg_type::LOGON && msg.get(tags::RESET_SEQ_NUM_FLAG) == Some("Y") {
+            self.next_target_seq_num = seq_num;
+        }
+
+        match seq_num.cmp(&self.next_target_seq_num) {
+            Ordering::Equal => {
+                self.next_target_seq_num += 1;
+                out.extend(self.process(msg, seq_num, now)?);
+                self.deliver_queued(now, &mut out)?;
+            }
+            Ordering::Greater => {
+                match msg.msg_type() {
+                    // The session must become active, or end, even with a gap. A
+                    // heartbeat stands in for the message to keep the queue in order.
+                    msg_type::LOGON | msg_type::LOGOUT => {
+                        out.extend(self.process(msg, seq_num, now)?);
+                        self.queued
+                            .insert(seq_num, FixMessage::new(msg_type::HEARTBEAT));
+                    }
+                    _ => {
+                        self.queued.insert(seq_num, msg);
+                    }
+                }
+                if !self.resend_requested && self.state != SessionState::Disconnected {
+                    self.resend_requested = true;
+                    let request = FixMessage::new(msg_type::RESEND_REQUEST)
+                        .with(tags::BEGIN_SEQ_NO, self.next_target_seq_num)
+                        .with(tags::END_SEQ_NO, 0);
+                    out.push(self.send_admin(request, now));
+                }
+            }
+            Ordering::Less => {
+                if msg.get(tags::POSS_DUP_FLAG) != Some("Y") {
+                    return Err(FixError::SequenceTooLow {
+                        expected: self.next_target_seq_num,
+                        received: seq_num,
+                    });
+                }
+                // Already processed duplicate
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Checks heartbeat timers, returning any Heartbeat or TestRequest to send.
+    ///
+    /// Should be called at least once a second. Returns an error if the
+    /// counterparty did not answer a TestRequest within a heartbeat interval.
+    pub fn on_timer(&mut self, now: UnixNanos) -> Result<Vec<Vec<u8>>, FixError> {
+        let mut out = Vec::new();
+        if self.state != SessionState::Active {
+            return Ok(out);
+        }
+
+        let interval = self.config.heartbeat_interval_secs * NANOS_PER_SECOND;
+        if let Some(sent_ns) = self.test_request_sent_ns {
+            if now.saturating_sub(sent_ns) >= interval {
+                self.state = SessionState::Disconnected;
+                return Err(FixError::HeartbeatTimeout);
+            }
+        } else if now.saturating_sub(self.last_received_ns) >= interval + interval / 5 {
+            // Allow some transmission delay before probing the counterparty
+            self.test_request_count += 1;
+            let request = FixMessage::new(msg_type::TEST_REQUEST).with(
+                tags::TEST_REQ_ID,
+                format!("TEST-{}", self.test_request_count),
+            );
+            out.push(self.send_admin(request, now));
+            self.test_request_sent_ns = Some(now);
+        }
+
+        if now.saturating_sub(self.last_sent_ns) >= interval {
+            out.push(self.send_admin(FixMessage::new(msg_type::HEARTBEAT), now));
+        }
+
+        Ok(out)
+    }
+
+    fn process(
+        &mut self,
+        msg: FixMessage,
+        seq_num: u64,
+        now: UnixNanos,
+    ) -> Result<Vec<Vec<u8>>, FixError> {
+        let mut out = Vec::new();
+        match msg.msg_type() {
+            msg_type::HEARTBEAT => {}
+            msg_type::TEST_REQUEST => {
+                let heartbeat = FixMessage::new(msg_type::HEARTBEAT)
+                    .with(tags::TEST_REQ_ID, msg.require(tags::TEST_REQ_ID)?);
+                out.push(self.send_admin(heartbeat, now));
+            }
+            msg_type::RESEND_REQUEST => {
+                let begin: u64 = msg.require_parsed(tags::BEGIN_SEQ_NO)?;
+                let end: u64 = msg.require_parsed(tags::END_SEQ_NO)?;
+                out.extend(self.resend(begin, end, now));
+            }
+            msg_type::REJECT => {
+                self.app
+                    .on_reject(msg.parse(tags::REF_SEQ_NUM)?, msg.get(tags::TEXT));
+            }
+            msg_type::SEQUENCE_RESET => {
+                // Gap fill, reset mode is handled before sequence checks
+                let new_seq_num: u64 = msg.require_parsed(tags::NEW_SEQ_NO)?;
+                self.next_target_seq_num = self.next_target_seq_num.max(new_seq_num);
+            }
+            msg_type::LOGON => {
+                self.state = SessionState::Active;
+                self.app.on_logon();
+            }
+            msg_type::LOGOUT => {
+                if self.state != SessionState::LogoutSent {
+                    out.push(self.send_admin(FixMessage::new(msg_type::LOGOUT), now));
+                }
+                self.state = SessionState::Disconnected;
+                self.app.on_logout(msg.get(tags::TEXT));
+            }
+            msg_type::EXECUTION_REPORT => match ExecutionReport::try_from(&msg) {
+                Ok(report) => self.app.on_execution_report(report),
+                Err(err) => out.push(self.reject(seq_num, &err, now)),
+            },
+            msg_type::MARKET_DATA_SNAPSHOT => match MarketDataSnapshot::try_from(&msg) {
+                Ok(snapshot) => self.app.on_market_data_snapshot(snapshot),
+                Err(err) => out.push(self.reject(seq_num, &err, now)),
+            },
+            msg_type::MARKET_DATA_INCREMENTAL_REFRESH => {
+                match MarketDataIncrementalRefresh::try_from(&msg) {
+                    Ok(refresh) => self.app.on_market_data_incremental(refresh),
+                    Err(err) => out.push(self.reject(seq_num, &err, now)),
+                }
+            }
+            _ => self.app.on_message(&msg),
+        }
+        Ok(out)
+    }
+
+    /// Delivers queued messages which are now in sequence.
+    fn deliver_queued(&mut self, now: UnixNanos, out: &mut Vec<Vec<u8>>) -> Result<(), FixError> {
+        loop {
+            // Drop messages skipped over by a gap fill or reset
+            let next = self.next_target_seq_num;
+            self.queued.retain(|seq_num, _| *seq_num >= next);
+
+            match self.queued.remove(&next) {
+                Some(msg) => {
+                    self.next_target_seq_num += 1;
+                    out.extend(self.process(msg, next, now)?);
+                }
+                None => break,
+            }
+        }
+
+        if self.queued.is_empty() {
+            self.resend_requested = false;
+        }
+        Ok(())
+    }
+
+    /// Resends stored application messages in the range, replacing
+    /// administrative or unknown messages with gap fills.
+    fn resend(&mut self, begin: u64, end: u64, now: UnixNanos) -> Vec<Vec<u8>> {
+        let last = self.next_sender_seq_num.saturating_sub(1);
+        let end = if end == 0 { last } else { end.min(last) };
+
+        let mut out = Vec::new();
+        let mut gap_start: Option<u64> = None;
+        for seq_num in begin..=end {
+            let original = match self.sent.get(&seq_num) {
+                Some(original) => original.clone(),
+                None => {
+                    gap_start.get_or_insert(seq_num);
+                    continue;
+                }
+            };
+            if let Some(start) = gap_start.take() {
+                out.push(self.gap_fill(start, seq_num, now));
+            }
+
+            let orig_sending_time = original.get(tags::SENDING_TIME).map(str::to_string);
+            let msg = self.stamp(original, seq_num, now, orig_sending_time);
+            out.push(msg.encode());
+        }
+        if let Some(start) = gap_start {
+            out.push(self.gap_fill(start, end + 1, now));
+        }
+
+        self.last_sent_ns = now;
+        out
+    }
+
+    fn gap_fill(&self, seq_num: u64, new_seq_num: u64, now: UnixNanos) -> Vec<u8> {
+        let msg = FixMessage::new(msg_type::SEQUENCE_RESET)
+            .with(tags::GAP_FILL_FLAG, "Y")
+            .with(tags::NEW_SEQ_NO, new_seq_num);
+        let orig_sending_time = format_sending_time(now);
+        self.stamp(msg, seq_num, now, Some(orig_sending_time))
+            .encode()
+    }
+
+    fn reject(&mut self, ref_seq_num: u64, err: &FixError, now: UnixNanos) -> Vec<u8> {
+        let msg = FixMessage::new(msg_type::REJECT)
+            .with(tags::REF_SEQ_NUM, ref_seq_num)
+            .with(tags::TEXT, err);
+        self.send_admin(msg, now)
+    }
+
+    fn send_admin(&mut self, msg: FixMessage, now: UnixNanos) -> Vec<u8> {
+        let msg = self.stamp(msg, self.next_sender_seq_num, now, None);
+        self.next_sender_seq_num += 1;
+        self.last_sent_ns = now;
+        msg.encode()
+    }
+
+    /// Returns the message with the standard header set, marked as a possible
+    /// duplicate when an original sending time is given.
+    fn stamp(
+        &self,
+        msg: FixMessage,
+        seq_num: u64,
+        now: UnixNanos,
+        orig_sending_time: Option<String>,
+    ) -> FixMessage {
+        let mut stamped = FixMessage::new(msg.msg_type())
+            .with(tags::SENDER_COMP_ID, &self.config.sender_comp_id)
+            .with(tags::TARGET_COMP_ID, &self.config.target_comp_id)
+            .with(tags::MSG_SEQ_NUM, seq_num)
+            .with(tags::SENDING_TIME, format_sending_time(now));
+        if let Some(orig_sending_time) = orig_sending_time {
+            stamped.push(tags::POSS_DUP_FLAG, "Y");
+            stamped.push(tags::ORIG_SENDING_TIME, orig_sending_time);
+        }
+
+        for (tag, value) in msg.fields() {
+            if !HEADER_TAGS.contains(tag) {
+                stamped.push(*tag, value);
+            }
+        }
+        stamped
+    }
+}
+
+/// Formats a UNIX timestamp as a FIX UTCTimestamp with milliseconds.
+#[must_use]
+pub fn format_sending_time(timestamp_ns: UnixNanos) -> String {
+    Utc.timestamp_nanos(timestamp_ns as i64)
+        .format("%Y%m%d-%H:%M:%S%.3f")
+        .to_string()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::enums::OrderSide;
+
+    use super::*;
+
+    const SECOND: u64 = NANOS_PER_SECOND;
+
+    #[derive(Debug, Default)]
+    struct Recorder {
+        logons: usize,
+        logouts: Vec<Option<String>>,
+        rejects: Vec<Option<u64>>,
+        reports: Vec<ExecutionReport>,
+        other: Vec<String>,
+    }
+
+    impl FixApplication for Recorder {
+        fn on_logon(&mut self) {
+            self.logons += 1;
+        }
+
+        fn on_logout(&mut self, text: Option<&str>) {
+            self.logouts.push(text.map(str::to_string));
+        }
+
+        fn on_reject(&mut self, ref_seq_num: Option<u64>, _text: Option<&str>) {
+            self.rejects.push(ref_seq_num);
+        }
+
+        fn on_execution_report(&mut self, report: ExecutionReport) {
+            self.reports.push(report);
+        }
+
+        fn on_message(&mut self, msg: &FixMessage) {
+            self.other.push(msg.msg_type().to_string());
+        }
+    }
+
+    fn session() -> FixSession<Recorder> {
+        FixSession::new(
+            SessionConfig::new("CLIENT", "BROKER", 30),
+            Recorder::default(),
+        )
+    }
+
+    /// Encodes a message as sent by the counterparty.
+    fn incoming(msg: FixMessage, seq_num: u64) -> Vec<u8> {
+        let mut stamped = FixMessage::new(msg.msg_type())
+            .with(tags::SENDER_COMP_ID, "BROKER")
+            .with(tags::TARGET_COMP_ID, "CLIENT")
+            .with(tags::MSG_SEQ_NUM, seq_num)
+            .with(tags::SENDING_TIME, "20230601-12:00:00.000");
+        for (tag, value) in msg.fields() {
+            stamped.push(*tag, value);
+        }
+        stamped.encode()
+    }
+
+    fn decode_all(out: &[Vec<u8>]) -> Vec<FixMessage> {
+        out.iter()
+            .map(|bytes| FixMessage::decode(bytes).unwrap())
+            .collect()
+    }
+
+    fn active_session() -> FixSession<Recorder> {
+        let mut session = session();
+        session.logon(0);
+        session
+            .on_bytes(&incoming(FixMessage::new(msg_type::LOGON), 1), 0)
+            .unwrap();
+        session
+    }
+
+    fn execution_report(exec_id: &str) -> FixMessage {
+        FixMessage::new(msg_type::EXECUTION_REPORT)
+            .with(tags::ORDER_ID, "O-1")
+            .with(tags::EXEC_ID, exec_id)
+            .with(tags::EXEC_TYPE, "0")
+            .with(tags::ORD_STATUS, "0")
+            .with(tags::SYMBOL, "AAPL")
+            .with(tags::SIDE, 2)
+            .with(tags::LEAVES_QTY, 100)
+            .with(tags::CUM_QTY, 0)
+    }
+
+    #[test]
+    fn test_format_sending_time() {
+        assert_eq!(
+            format_sending_time(1_685_620_800_123_456_789),
+            "20230601-12:00:00.123"
+        );
+    }
+
+    #[test]
+    fn test_logon() {
+        let mut session = session();
+        session.config.reset_seq_num_on_logon = true;
+        session.config.username = Some("user".to_string());
+
+        let logon = FixMessage::decode(&session.logon(0)).unwrap();
+
+        assert_eq!(session.state(), SessionState::LogonSent);
+        assert_eq!(logon.msg_type(), msg_type::LOGON);
+        assert_eq!(logon.get(tags::SENDER_COMP_ID), Some("CLIENT"));
+        assert_eq!(logon.get(tags::TARGET_COMP_ID), Some("BROKER"));
+        assert_eq!(logon.get(tags::MSG_SEQ_NUM), Some("1"));
+        assert_eq!(logon.get(tags::HEART_BT_INT), Some("30"));
+        assert_eq!(logon.get(tags::RESET_SEQ_NUM_FLAG), Some("Y"));
+        assert_eq!(logon.get(tags::USERNAME), Some("user"));
+        assert_eq!(logon.get(tags::PASSWORD), None);
+    }
+
+    #[test]
+    fn test_logon_response_activates_session() {
+        let session = active_session();
+
+        assert_eq!(session.state(), SessionState::Active);
+        assert_eq!(session.app().logons, 1);
+        assert_eq!(session.next_sender_seq_num(), 2);
+        assert_eq!(session.next_target_seq_num(), 2);
+    }
+
+    #[test]
+    fn test_send_requires_active_session() {
+        let mut session = session();
+
+        assert_eq!(
+            session.send(FixMessage::new(msg_type::NEW_ORDER_SINGLE), 0),
+            Err(FixError::NotActive)
+        );
+    }
+
+    #[test]
+    fn test_test_request_is_answered() {
+        let mut session = active_session();
+        let request = FixMessage::new(msg_type::TEST_REQUEST).with(tags::TEST_REQ_ID, "PING");
+
+        let out = decode_all(&session.on_bytes(&incoming(request, 2), 0).unwrap());
+
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].msg_type(), msg_type::HEARTBEAT);
+        assert_eq!(out[0].get(tags::TEST_REQ_ID), Some("PING"));
+    }
+
+    #[test]
+    fn test_execution_report_callback() {
+        let mut session = active_session();
+
+        let out = session
+            .on_bytes(&incoming(execution_report("E-1"), 2), 0)
+            .unwrap();
+
+        assert!(out.is_empty());
+        assert_eq!(session.app().reports.len(), 1);
+        assert_eq!(session.app().reports[0].side, OrderSide::Sell);
+    }
+
+    #[test]
+    fn test_invalid_execution_report_is_rejected() {
+        let mut session = active_session();
+        let mut report = execution_report("E-1");
+        report.remove(tags::EXEC_ID);
+
+        let out = decode_all(&session.on_bytes(&incoming(report, 2), 0).unwrap());
+
+        assert_eq!(out[0].msg_type(), msg_type::REJECT);
+        assert_eq!(out[0].get(tags::REF_SEQ_NUM), Some("2"));
+        assert!(session.app().reports.is_empty());
+        assert_eq!(session.next_target_seq_num(), 3);
+    }
+
+    #[test]
+    fn test_other_messages_are_passed_on() {
+        let mut session = active_session();
+        let msg = FixMessage::new("j").with(tags::TEXT, "business reject");
+
+        session.on_bytes(&incoming(msg, 2), 0).unwrap();
+
+        assert_eq!(session.app().other, vec!["j".to_string()]);
+    }
+
+    #[test]
+    fn test_gap_requests_resend_and_queues() {
+        let mut session = active_session();
+
+        let out = decode_all(
+            &session
+                .on_bytes(&incoming(execution_report("E-4"), 4), 0)
+                .unwrap(),
+        );
+
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].msg_type(), msg_type::RESEND_REQUEST);
+        assert_eq!(out[0].get(tags::BEGIN_SEQ_NO), Some("2"));
+        assert_eq!(out[0].get(tags::END_SEQ_NO), Some("0"));
+        assert!(session.app().reports.is_empty());
+
+        // A second out of sequence message does not request again
+        let out = session
+            .on_bytes(&incoming(execution_report("E-5"), 5), 0)
+            .unwrap();
+        assert!(out.is_empty());
+
+        // Resent message, then a gap fill over an admin message
+        let resent = execution_report("E-2").with(tags::POSS_DUP_FLAG, "Y");
+        session.on_bytes(&incoming(resent, 2), 0).unwrap();
+        let gap_fill = FixMessage::new(msg_type::SEQUENCE_RESET)
+            .with(tags::GAP_FILL_FLAG, "Y")
+            .with(tags::NEW_SEQ_NO, 4);
+        session.on_bytes(&incoming(gap_fill, 3), 0).unwrap();
+
+        let exec_ids: Vec<&str> = session
+            .app()
+            .reports
+            .iter()
+            .map(|report| report.exec_id.as_str())
+            .collect();
+        assert_eq!(exec_ids, vec!["E-2", "E-4", "E-5"]);
+        assert_eq!(session.next_target_seq_num(), 6);
+        assert!(!session.resend_requested);
+    }
+
+    #[test]
+    fn test_sequence_reset_reset_mode() {
+        let mut session = active_session();
+        let reset = FixMessage::new(msg_type::SEQUENCE_RESET).with(tags::NEW_SEQ_NO, 10);
+
+        session.on_bytes(&incoming(reset, 99), 0).unwrap();
+
+        assert_eq!(session.next_target_seq_num(), 10);
+    }
+
+    #[test]
+    fn test_sequence_too_low() {
+        let mut session = active_session();
+        session
+            .on_bytes(&incoming(FixMessage::new(msg_type::HEARTBEAT), 2), 0)
+            .unwrap();
+
+        // A possible duplicate is ignored
+        let duplicate = execution_report("E-1").with(tags::POSS_DUP_FLAG, "Y");
+        assert!(session
+            .on_bytes(&incoming(duplicate, 2), 0)
+            .unwrap()
+            .is_empty());
+        assert!(session.app().reports.is_empty());
+
+        assert_eq!(
+            session.on_bytes(&incoming(execution_report("E-1"), 2), 0),
+            Err(FixError::SequenceTooLow {
+                expected: 3,
+                received: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_resend_request_resends_and_gap_fills() {
+        let mut session = active_session();
+        let order = FixMessage::new(msg_type::NEW_ORDER_SINGLE).with(tags::CL_ORD_ID, "C-1");
+        session.send(order, SECOND).unwrap(); // Seq 2
+        session.on_timer(31 * SECOND).unwrap(); // Heartbeat seq 3
+
+        let request = FixMessage::new(msg_type::RESEND_REQUEST)
+            .with(tags::BEGIN_SEQ_NO, 1)
+            .with(tags::END_SEQ_NO, 0);
+        let out = decode_all(
+            &session
+                .on_bytes(&incoming(request, 2), 40 * SECOND)
+                .unwrap(),
+        );
+
+        assert_eq!(out.len(), 3);
+        assert_eq!(out[0].msg_type(), msg_type::SEQUENCE_RESET);
+        assert_eq!(out[0].get(tags::MSG_SEQ_NUM), Some("1"));
+        assert_eq!(out[0].get(tags::NEW_SEQ_NO), Some("2"));
+        assert_eq!(out[1].msg_type(), msg_type::NEW_ORDER_SINGLE);
+        assert_eq!(out[1].get(tags::MSG_SEQ_NUM), Some("2"));
+        assert_eq!(out[1].get(tags::POSS_DUP_FLAG), Some("Y"));
+        assert_eq!(
+            out[1].get(tags::ORIG_SENDING_TIME),
+            Some("19700101-00:00:01.000")
+        );
+        assert_eq!(out[1].get(tags::CL_ORD_ID), Some("C-1"));
+        assert_eq!(out[2].get(tags::GAP_FILL_FLAG), Some("Y"));
+        assert_eq!(out[2].get(tags::MSG_SEQ_NUM), Some("3"));
+        assert_eq!(out[2].get(tags::NEW_SEQ_NO), Some("4"));
+
+        // Resends do not consume new sequence numbers
+        assert_eq!(session.next_sender_seq_num(), 4);
+    }
+
+    #[test]
+    fn test_heartbeats_and_test_requests() {
+        let mut session = active_session();
+
+        assert!(session.on_timer(10 * SECOND).unwrap().is_empty());
+
+        let out = decode_all(&session.on_timer(30 * SECOND).unwrap());
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].msg_type(), msg_type::HEARTBEAT);
+
+        let out = decode_all(&session.on_timer(36 * SECOND).unwrap());
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].msg_type(), msg_type::TEST_REQUEST);
+        assert_eq!(out[0].get(tags::TEST_REQ_ID), Some("TEST-1"));
+
+        assert_eq!(
+            session.on_timer(66 * SECOND),
+            Err(FixError::HeartbeatTimeout)
+        );
+        assert_eq!(session.state(), SessionState::Disconnected);
+    }
+
+    #[test]
+    fn test_heartbeat_answer_clears_test_request() {
+        let mut session = active_session();
+        session.on_timer(36 * SECOND).unwrap();
+
+        let heartbeat = FixMessage::new(msg_type::HEARTBEAT).with(tags::TEST_REQ_ID, "TEST-1");
+        session
+            .on_bytes(&incoming(heartbeat, 2), 37 * SECOND)
+            .unwrap();
+
+        assert!(session.on_timer(66 * SECOND).is_ok());
+        assert_eq!(session.state(), SessionState::Active);
+    }
+
+    #[test]
+    fn test_logout_initiated() {
+        let mut session = active_session();
+        let logout = FixMessage::decode(&session.logout(Some("done"), 0)).unwrap();
+        assert_eq!(logout.get(tags::TEXT), Some("done"));
+        assert_eq!(session.state(), SessionState::LogoutSent);
+
+        let out = session
+            .on_bytes(&incoming(FixMessage::new(msg_type::LOGOUT), 2), 0)
+            .unwrap();
+
+        assert!(out.is_empty());
+        assert_eq!(session.state(), SessionState::Disconnected);
+        assert_eq!(session.app().logouts, vec![None]);
+    }
+
+    #[test]
+    fn test_logout_by_counterparty_is_confirmed() {
+        let mut session = active_session();
+        let logout = FixMessage::new(msg_type::LOGOUT).with(tags::TEXT, "maintenance");
+
+        let out = decode_all(&session.on_bytes(&incoming(logout, 2), 0).unwrap());
+
+        assert_eq!(out[0].msg_type(), msg_type::LOGOUT);
+        assert_eq!(session.state(), SessionState::Disconnected);
+        assert_eq!(session.app().logouts, vec![Some("maintenance".to_string())]);
+    }
+
+    #[test]
+    fn test_on_bytes_buffers_partial_messages() {
+        let mut session = active_session();
+        let mut bytes = incoming(execution_report("E-1"), 2);
+        bytes.extend(incoming(execution_report("E-2"), 3));
+        let (first, second) = bytes.split_at(bytes.len() / 2 + 10);
+
+        session.on_bytes(first, 0).unwrap();
+        assert_eq!(session.app().reports.len(), 1);
+
+        session.on_bytes(second, 0).unwrap();
+        assert_eq!(session.app().reports.len(), 2);
+    }
+
+    #[test]
+    fn test_reject_callback() {
+        let mut session = active_session();
+        let reject = FixMessage::new(msg_type::REJECT)
+            .with(tags::REF_SEQ_NUM, 7)
+            .with(tags::TEXT, "bad");
+
+        session.on_bytes(&incoming(reject, 2), 0).unwrap();
+
+        assert_eq!(session.app().rejects, vec![Some(7)]);
+    }
+}
@@ -0,0 +1,98 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX 4.4 tag numbers and message types used by the session layer.
+
+// Standard header and trailer
+pub const BEGIN_STRING: u32 = 8;
+pub const BODY_LENGTH: u32 = 9;
+pub const CHECKSUM: u32 = 10;
+pub const MSG_SEQ_NUM: u32 = 34;
+pub const MSG_TYPE: u32 = 35;
+pub const POSS_DUP_FLAG: u32 = 43;
+pub const SENDER_COMP_ID: u32 = 49;
+pub const SENDING_TIME: u32 = 52;
+pub const TARGET_COMP_ID: u32 = 56;
+pub const ORIG_SENDING_TIME: u32 = 122;
+
+// Session messages
+pub const BEGIN_SEQ_NO: u32 = 7;
+pub const END_SEQ_NO: u32 = 16;
+pub const NEW_SEQ_NO: u32 = 36;
+pub const REF_SEQ_NUM: u32 = 45;
+pub const TEXT: u32 = 58;
+pub const ENCRYPT_METHOD: u32 = 98;
+pub const HEART_BT_INT: u32 = 108;
+pub const TEST_REQ_ID: u32 = 112;
+pub const GAP_FILL_FLAG: u32 = 123;
+pub const RESET_SEQ_NUM_FLAG: u32 = 141;
+pub const USERNAME: u32 = 553;
+pub const PASSWORD: u32 = 554;
+
+// Execution reports
+pub const AVG_PX: u32 = 6;
+pub const CL_ORD_ID: u32 = 11;
+pub const CUM_QTY: u32 = 14;
+pub const EXEC_ID: u32 = 17;
+pub const LAST_PX: u32 = 31;
+pub const LAST_QTY: u32 = 32;
+pub const ORDER_ID: u32 = 37;
+pub const ORDER_QTY: u32 = 38;
+pub const ORD_STATUS: u32 = 39;
+pub const PRICE: u32 = 44;
+pub const SIDE: u32 = 54;
+pub const SYMBOL: u32 = 55;
+pub const TRANSACT_TIME: u32 = 60;
+pub const EXEC_TYPE: u32 = 150;
+pub const LEAVES_QTY: u32 = 151;
+
+// Market data
+pub const NO_RELATED_SYM: u32 = 146;
+pub const MD_REQ_ID: u32 = 262;
+pub const SUBSCRIPTION_REQUEST_TYPE: u32 = 263;
+pub const MARKET_DEPTH: u32 = 264;
+pub const NO_MD_ENTRY_TYPES: u32 = 267;
+pub const NO_MD_ENTRIES: u32 = 268;
+pub const MD_ENTRY_TYPE: u32 = 269;
+pub const MD_ENTRY_PX: u32 = 270;
+pub const MD_ENTRY_SIZE: u32 = 271;
+pub const MD_ENTRY_ID: u32 = 278;
+pub const MD_UPDATE_ACTION: u32 = 279;
+
+/// Values of the MsgType (35) field.
+pub mod msg_type {
+    pub const HEARTBEAT: &str = "0";
+    pub const TEST_REQUEST: &str = "1";
+    pub const RESEND_REQUEST: &str = "2";
+    pub const REJECT: &str = "3";
+    pub const SEQUENCE_RESET: &str = "4";
+    pub const LOGOUT: &str = "5";
+    pub const EXECUTION_REPORT: &str = "8";
+    pub const LOGON: &str = "A";
+    pub const NEW_ORDER_SINGLE: &str = "D";
+    pub const ORDER_CANCEL_REQUEST: &str = "F";
+    pub const MARKET_DATA_REQUEST: &str = "V";
+    pub const MARKET_DATA_SNAPSHOT: &str = "W";
+    pub const MARKET_DATA_INCREMENTAL_REFRESH: &str = "X";
+
+    /// Returns true if the message type is handled by the session layer.
+    #[must_use]
+    pub fn is_admin(msg_type: &str) -> bool {
+        matches!(
+            msg_type,
+            HEARTBEAT | TEST_REQUEST | RESEND_REQUEST | REJECT | SEQUENCE_RESET | LOGOUT | LOGON
+        )
+    }
+}
@@ -0,0 +1,356 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Framing of TWS API messages.
+//!
+//! After the handshake every message is a 4-byte big-endian length followed
+//! by NUL terminated ASCII fields.
+
+use std::str::FromStr;
+
+use super::IbError;
+
+/// Sent before the handshake frame when connecting.
+pub const API_SIGNATURE: &[u8] = b"API\0";
+
+/// The client versions offered in the handshake, as the server version range
+/// this codec can encode and decode.
+pub const MIN_CLIENT_VERSION: u32 = 157;
+pub const MAX_CLIENT_VERSION: u32 = 176;
+
+/// The maximum length of a message payload accepted by TWS.
+pub const MAX_MSG_LEN: usize = 0xFF_FFFF;
+
+/// The value TWS sends for unset doubles.
+const UNSET_DOUBLE: &str = "1.7976931348623157E308";
+
+/// The value TWS sends for unset decimals.
+const UNSET_DECIMAL: &str = "170141183460469231731687303715884105727";
+
+/// Returns the bytes sent to open a connection, offering the supported
+/// client version range.
+#[must_use]
+pub fn handshake() -> Vec<u8> {
+    let versions = format!("v{MIN_CLIENT_VERSION}..{MAX_CLIENT_VERSION}");
+    let mut bytes = API_SIGNATURE.to_vec();
+    bytes.extend_from_slice(&(versions.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(versions.as_bytes());
+    bytes
+}
+
+/// Parses the server version and connection time from the first frame
+/// received after the handshake.
+pub fn parse_handshake_response(fields: &[String]) -> Result<(u32, String), IbError> {
+    let server_version: u32 = fields
+        .first()
+        .and_then(|version| version.parse().ok())
+        .ok_or(IbError::InvalidHandshake)?;
+    if !(MIN_CLIENT_VERSION..=MAX_CLIENT_VERSION).contains(&server_version) {
+        return Err(IbError::UnsupportedServerVersion(server_version));
+    }
+    let connection_time = fields.get(1).cloned().unwrap_or_default();
+    Ok((server_version, connection_time))
+}
+
+/// Encodes the fields as a length prefixed frame.
+#[must_use]
+pub fn encode_frame(fields: &[String]) -> Vec<u8> {
+    let len: usize = fields.iter().map(|field| field.len() + 1).sum();
+    let mut bytes = Vec::with_capacity(4 + len);
+    bytes.extend_from_slice(&(len as u32).to_be_bytes());
+    for field in fields {
+        bytes.extend_from_slice(field.as_bytes());
+        bytes.push(0);
+    }
+    bytes
+}
+
+/// Removes the next complete frame from the front of `buf` and returns its
+/// fields, or `None` if more bytes are needed.
+pub fn next_frame(buf: &mut Vec<u8>) -> Result<Option<Vec<String>>, IbError> {
+    if buf.len() < 4 {
+        return Ok(None);
+    }
+    let len = u32::from_be_bytes(buf[..4].try_into().unwrap()) as usize;
+    if len > MAX_MSG_LEN {
+        return Err(IbError::FrameTooLarge(len));
+    }
+    if buf.len() < 4 + len {
+        return Ok(None);
+    }
+
+    let payload: Vec<u8> = buf.drain(..4 + len).skip(4).collect();
+    let mut fields: Vec<String> = payload
+        .split(|&b| b == 0)
+        .map(|field| String::from_utf8_lossy(field).into_owned())
+        .collect();
+    // Every field is terminated, leaving an empty trailing split
+    if payload.last() == Some(&0) {
+        fields.pop();
+    }
+    Ok(Some(fields))
+}
+
+/// Builds the fields of an outgoing message.
+#[derive(Debug, Default)]
+pub struct FieldWriter {
+    fields: Vec<String>,
+}
+
+impl FieldWriter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, value: impl ToString) -> &mut Self {
+        self.fields.push(value.to_string());
+        self
+    }
+
+    pub fn push_bool(&mut self, value: bool) -> &mut Self {
+        self.push(u8::from(value))
+    }
+
+    /// Pushes the value, or an empty field if unset.
+    pub fn push_opt(&mut self, value: Option<impl ToString>) -> &mut Self {
+        match value {
+            Some(value) => self.push(value),
+            None => self.push(""),
+        }
+    }
+
+    #[must_use]
+    pub fn finish(self) -> Vec<String> {
+        self.fields
+    }
+}
+
+/// Reads the fields of an incoming message in order.
+///
+/// Empty numeric fields decode as zero, matching `ibapi`.
+#[derive(Debug)]
+pub struct FieldReader<'a> {
+    fields: &'a [String],
+    pos: usize,
+}
+
+impl<'a> FieldReader<'a> {
+    #[must_use]
+    pub fn new(fields: &'a [String]) -> Self {
+        Self { fields, pos: 0 }
+    }
+
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.fields.len() - self.pos
+    }
+
+    pub fn next_str(&mut self) -> Result<&'a str, IbError> {
+        let field = self
+            .fields
+            .get(self.pos)
+            .ok_or(IbError::MissingField(self.pos))?;
+        self.pos += 1;
+        Ok(field)
+    }
+
+    pub fn next_string(&mut self) -> Result<String, IbError> {
+        self.next_str().map(ToString::to_string)
+    }
+
+    /// Reads a number, where an empty field is the default (zero).
+    pub fn next_parsed<T: FromStr + Default>(&mut self) -> Result<T, IbError> {
+        let index = self.pos;
+        let field = self.next_str()?;
+        if field.is_empty() {
+            return Ok(T::default());
+        }
+        field.parse().map_err(|_| IbError::InvalidField {
+            index,
+            value: field.to_string(),
+        })
+    }
+
+    /// Reads a double, where an empty or unset field is `None`.
+    pub fn next_opt_f64(&mut self) -> Result<Option<f64>, IbError> {
+        let index = self.pos;
+        let field = self.next_str()?;
+        if field.is_empty() || field == UNSET_DOUBLE {
+            return Ok(None);
+        }
+        field.parse().map(Some).map_err(|_| IbError::InvalidField {
+            index,
+            value: field.to_string(),
+        })
+    }
+
+    /// Reads a decimal such as a size, where empty or unset is `None`.
+    pub fn next_opt_decimal(&mut self) -> Result<Option<f64>, IbError> {
+        let index = self.pos;
+        let field = self.next_str()?;
+        if field.is_empty() || field == UNSET_DECIMAL {
+            return Ok(None);
+        }
+        field.parse().map(Some).map_err(|_| IbError::InvalidField {
+            index,
+            value: field.to_string(),
+        })
+    }
+
+    pub fn next_bool(&mut self) -> Result<bool, IbError> {
+        let index = self.pos;
+        match self.next_str()? {
+            "1" | "true" => Ok(true),
+            "0" | "false" | "" => Ok(false),
+            other => Err(IbError::InvalidField {
+                index,
+                value: other.to_string(),
+            }),
+        }
+    }
+
+    pub fn skip(&mut self, count: usize) -> Result<(), IbError> {
+        for _ in 0..count {
+            self.next_str()?;
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    pub fn fields(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_handshake() {
+        assert_eq!(handshake(), b"API\0\0\0\0\x09v157..176".to_vec());
+    }
+
+    #[rstest]
+    #[case(&["176", "20230601 12:00:00 EST"], Ok((176, "20230601 12:00:00 EST".to_string())))]
+    #[case(&["157"], Ok((157, String::new())))]
+    #[case(&["100", ""], Err(IbError::UnsupportedServerVersion(100)))]
+    #[case(&["abc"], Err(IbError::InvalidHandshake))]
+    #[case(&[], Err(IbError::InvalidHandshake))]
+    fn test_parse_handshake_response(
+        #[case] values: &[&str],
+        #[case] expected: Result<(u32, String), IbError>,
+    ) {
+        assert_eq!(parse_handshake_response(&fields(values)), expected);
+    }
+
+    #[test]
+    fn test_encode_frame() {
+        assert_eq!(
+            encode_frame(&fields(&["71", "2", "1", ""])),
+            b"\0\0\0\x0871\x002\x001\x00\x00".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_next_frame_round_trip() {
+        let frame_fields = fields(&["1", "6", "100", "", "150.25"]);
+        let mut buf = encode_frame(&frame_fields);
+        buf.extend(encode_frame(&fields(&["9", "1", "42"])));
+
+        assert_eq!(next_frame(&mut buf).unwrap(), Some(frame_fields));
+        assert_eq!(
+            next_frame(&mut buf).unwrap(),
+            Some(fields(&["9", "1", "42"]))
+        );
+        assert_eq!(next_frame(&mut buf).unwrap(), None);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_next_frame_partial() {
+        let frame = encode_frame(&fields(&["49", "1", "1685620800"]));
+        let mut buf = frame[..frame.len() - 1].to_vec();
+
+        assert_eq!(next_frame(&mut buf).unwrap(), None);
+
+        buf.push(0);
+        assert_eq!(
+            next_frame(&mut buf).unwrap(),
+            Some(fields(&["49", "1", "1685620800"]))
+        );
+    }
+
+    #[test]
+    fn test_next_frame_too_large() {
+        let mut buf = 0x0100_0000_u32.to_be_bytes().to_vec();
+
+        assert_eq!(
+            next_frame(&mut buf),
+            Err(IbError::FrameTooLarge(0x0100_0000))
+        );
+    }
+
+    #[test]
+    fn test_field_writer() {
+        let mut writer = FieldWriter::new();
+        writer
+            .push(1)
+            .push("AAPL")
+            .push_bool(true)
+            .push_opt(None::<f64>)
+            .push_opt(Some(1.5));
+
+        assert_eq!(writer.finish(), fields(&["1", "AAPL", "1", "", "1.5"]));
+    }
+
+    #[test]
+    fn test_field_reader() {
+        let values = fields(&["7", "", "1", "1.7976931348623157E308", "2.5", "x"]);
+        let mut reader = FieldReader::new(&values);
+
+        assert_eq!(reader.next_parsed::<i32>().unwrap(), 7);
+        assert_eq!(reader.next_parsed::<f64>().unwrap(), 0.0);
+        assert!(reader.next_bool().unwrap());
+        assert_eq!(reader.next_opt_f64().unwrap(), None);
+        assert_eq!(reader.next_opt_f64().unwrap(), Some(2.5));
+        assert_eq!(reader.remaining(), 1);
+        assert_eq!(
+            reader.next_parsed::<i64>(),
+            Err(IbError::InvalidField {
+                index: 5,
+                value: "x".to_string()
+            })
+        );
+        assert_eq!(reader.next_str(), Err(IbError::MissingField(6)));
+    }
+
+    #[rstest]
+    #[case("", None)]
+    #[case("170141183460469231731687303715884105727", None)]
+    #[case("100", Some(100.0))]
+    #[case("0.25", Some(0.25))]
+    fn test_field_reader_next_opt_decimal(#[case] value: &str, #[case] expected: Option<f64>) {
+        let values = fields(&[value]);
+        let mut reader = FieldReader::new(&values);
+
+        assert_eq!(reader.next_opt_decimal().unwrap(), expected);
+    }
+}
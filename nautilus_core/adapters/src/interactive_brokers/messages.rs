@@ -0,0 +1,865 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Typed TWS API requests and responses.
+//!
+//! Field layouts follow `ibapi` for server versions from
+//! [`MIN_CLIENT_VERSION`](super::codec::MIN_CLIENT_VERSION) to
+//! [`MAX_CLIENT_VERSION`](super::codec::MAX_CLIENT_VERSION). Only the messages
+//! needed for contract lookup and market data are typed; others decode as
+//! [`IncomingMessage::Other`].
+
+use super::{
+    codec::{encode_frame, FieldReader, FieldWriter},
+    IbError,
+};
+
+pub mod outgoing {
+    pub const REQ_MKT_DATA: u32 = 1;
+    pub const CANCEL_MKT_DATA: u32 = 2;
+    pub const REQ_IDS: u32 = 8;
+    pub const REQ_CONTRACT_DATA: u32 = 9;
+    pub const REQ_CURRENT_TIME: u32 = 49;
+    pub const START_API: u32 = 71;
+    pub const REQ_TICK_BY_TICK_DATA: u32 = 97;
+    pub const CANCEL_TICK_BY_TICK_DATA: u32 = 98;
+}
+
+pub mod incoming {
+    pub const TICK_PRICE: u32 = 1;
+    pub const TICK_SIZE: u32 = 2;
+    pub const ERR_MSG: u32 = 4;
+    pub const NEXT_VALID_ID: u32 = 9;
+    pub const CONTRACT_DATA: u32 = 10;
+    pub const MANAGED_ACCTS: u32 = 15;
+    pub const CURRENT_TIME: u32 = 49;
+    pub const CONTRACT_DATA_END: u32 = 52;
+    pub const TICK_BY_TICK: u32 = 99;
+}
+
+/// The tick types of `TICK_PRICE` and `TICK_SIZE` messages.
+pub mod tick_type {
+    pub const BID_SIZE: i32 = 0;
+    pub const BID: i32 = 1;
+    pub const ASK: i32 = 2;
+    pub const ASK_SIZE: i32 = 3;
+    pub const LAST: i32 = 4;
+    pub const LAST_SIZE: i32 = 5;
+    pub const HIGH: i32 = 6;
+    pub const LOW: i32 = 7;
+    pub const VOLUME: i32 = 8;
+    pub const CLOSE: i32 = 9;
+}
+
+const MIN_SERVER_VER_FRACTIONAL_SIZE_SUPPORT: u32 = 163;
+const MIN_SERVER_VER_SIZE_RULES: u32 = 164;
+const MIN_SERVER_VER_ADVANCED_ORDER_REJECT: u32 = 166;
+const MIN_SERVER_VER_BOND_ISSUERID: u32 = 176;
+
+/// Represents an IB contract, identifying an instrument for requests.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Contract {
+    pub con_id: i64,
+    pub symbol: String,
+    /// The security type, such as `STK`, `FUT`, `OPT` or `CASH`.
+    pub sec_type: String,
+    pub last_trade_date_or_contract_month: String,
+    pub strike: f64,
+    /// `C` or `P` for options.
+    pub right: String,
+    pub multiplier: String,
+    pub exchange: String,
+    pub primary_exchange: String,
+    pub currency: String,
+    pub local_symbol: String,
+    pub trading_class: String,
+    pub include_expired: bool,
+    pub sec_id_type: String,
+    pub sec_id: String,
+    pub issuer_id: String,
+}
+
+impl Contract {
+    /// Returns a stock contract routed through SMART.
+    #[must_use]
+    pub fn stock(symbol: &str, primary_exchange: &str, currency: &str) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            sec_type: "STK".to_string(),
+            exchange: "SMART".to_string(),
+            primary_exchange: primary_exchange.to_string(),
+            currency: currency.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns a contract identified only by its IB contract ID.
+    #[must_use]
+    pub fn from_con_id(con_id: i64, exchange: &str) -> Self {
+        Self {
+            con_id,
+            exchange: exchange.to_string(),
+            ..Self::default()
+        }
+    }
+
+    fn write(&self, writer: &mut FieldWriter) -> Result<(), IbError> {
+        if self.sec_type == "BAG" {
+            return Err(IbError::UnsupportedContract(
+                "combo (BAG) contracts".to_string(),
+            ));
+        }
+        writer
+            .push(self.con_id)
+            .push(&self.symbol)
+            .push(&self.sec_type)
+            .push(&self.last_trade_date_or_contract_month)
+            .push(self.strike)
+            .push(&self.right)
+            .push(&self.multiplier)
+            .push(&self.exchange)
+            .push(&self.primary_exchange)
+            .push(&self.currency)
+            .push(&self.local_symbol)
+            .push(&self.trading_class);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickByTickType {
+    Last = 1,
+    AllLast = 2,
+    BidAsk = 3,
+    MidPoint = 4,
+}
+
+impl TickByTickType {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Last => "Last",
+            Self::AllLast => "AllLast",
+            Self::BidAsk => "BidAsk",
+            Self::MidPoint => "MidPoint",
+        }
+    }
+}
+
+/// A request sent to TWS.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutgoingMessage {
+    StartApi {
+        client_id: i32,
+        optional_capabilities: String,
+    },
+    ReqIds,
+    ReqCurrentTime,
+    ReqContractDetails {
+        req_id: i32,
+        contract: Contract,
+    },
+    ReqMktData {
+        req_id: i32,
+        contract: Contract,
+        /// Comma separated generic tick types, empty for the defaults.
+        generic_ticks: String,
+        snapshot: bool,
+    },
+    CancelMktData {
+        req_id: i32,
+    },
+    ReqTickByTickData {
+        req_id: i32,
+        contract: Contract,
+        tick_type: TickByTickType,
+        /// The number of historical ticks to send first, zero for none.
+        number_of_ticks: i32,
+        ignore_size: bool,
+    },
+    CancelTickByTickData {
+        req_id: i32,
+    },
+}
+
+impl OutgoingMessage {
+    /// Returns the fields of the request for the server version.
+    pub fn fields(&self, server_version: u32) -> Result<Vec<String>, IbError> {
+        let mut writer = FieldWriter::new();
+        match self {
+            Self::StartApi {
+                client_id,
+                optional_capabilities,
+            } => {
+                writer
+                    .push(outgoing::START_API)
+                    .push(2)
+                    .push(client_id)
+                    .push(optional_capabilities);
+            }
+            Self::ReqIds => {
+                writer.push(outgoing::REQ_IDS).push(1).push(1);
+            }
+            Self::ReqCurrentTime => {
+                writer.push(outgoing::REQ_CURRENT_TIME).push(1);
+            }
+            Self::ReqContractDetails { req_id, contract } => {
+                writer
+                    .push(outgoing::REQ_CONTRACT_DATA)
+                    .push(8)
+                    .push(req_id);
+                contract.write(&mut writer)?;
+                writer
+                    .push_bool(contract.include_expired)
+                    .push(&contract.sec_id_type)
+                    .push(&contract.sec_id);
+                if server_version >= MIN_SERVER_VER_BOND_ISSUERID {
+                    writer.push(&contract.issuer_id);
+                }
+            }
+            Self::ReqMktData {
+                req_id,
+                contract,
+                generic_ticks,
+                snapshot,
+            } => {
+                writer.push(outgoing::REQ_MKT_DATA).push(11).push(req_id);
+                contract.write(&mut writer)?;
+                writer
+                    .push_bool(false) // No delta neutral contract
+                    .push(generic_ticks)
+                    .push_bool(*snapshot)
+                    .push_bool(false) // No regulatory snapshot
+                    .push(""); // Market data options
+            }
+            Self::CancelMktData { req_id } => {
+                writer.push(outgoing::CANCEL_MKT_DATA).push(2).push(req_id);
+            }
+            Self::ReqTickByTickData {
+                req_id,
+                contract,
+                tick_type,
+                number_of_ticks,
+                ignore_size,
+            } => {
+                writer.push(outgoing::REQ_TICK_BY_TICK_DATA).push(req_id);
+                contract.write(&mut writer)?;
+                writer
+                    .push(tick_type.as_str())
+                    .push(number_of_ticks)
+                    .push_bool(*ignore_size);
+            }
+            Self::CancelTickByTickData { req_id } => {
+                writer.push(outgoing::CANCEL_TICK_BY_TICK_DATA).push(req_id);
+            }
+        }
+        Ok(writer.finish())
+    }
+
+    /// Returns the request encoded as a frame for the server version.
+    pub fn encode(&self, server_version: u32) -> Result<Vec<u8>, IbError> {
+        Ok(encode_frame(&self.fields(server_version)?))
+    }
+}
+
+/// The details of a contract, from a `CONTRACT_DATA` message.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContractDetails {
+    pub contract: Contract,
+    pub market_name: String,
+    pub min_tick: f64,
+    pub order_types: String,
+    pub valid_exchanges: String,
+    pub price_magnifier: i64,
+    pub under_con_id: i64,
+    pub long_name: String,
+    pub contract_month: String,
+    pub industry: String,
+    pub category: String,
+    pub subcategory: String,
+    pub time_zone_id: String,
+    pub trading_hours: String,
+    pub liquid_hours: String,
+    pub ev_rule: String,
+    pub ev_multiplier: i64,
+    /// Security identifiers as `(type, value)`, such as `("ISIN", ...)`.
+    pub sec_id_list: Vec<(String, String)>,
+    pub agg_group: i64,
+    pub under_symbol: String,
+    pub under_sec_type: String,
+    pub market_rule_ids: String,
+    pub real_expiration_date: String,
+    pub stock_type: String,
+    pub min_size: Option<f64>,
+    pub size_increment: Option<f64>,
+    pub suggested_size_increment: Option<f64>,
+}
+
+impl ContractDetails {
+    #[must_use]
+    pub fn sec_id(&self, sec_id_type: &str) -> Option<&str> {
+        self.sec_id_list
+            .iter()
+            .find(|(tag, _)| tag == sec_id_type)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn decode(reader: &mut FieldReader<'_>, server_version: u32) -> Result<Self, IbError> {
+        let mut details = Self::default();
+        let contract = &mut details.contract;
+        contract.symbol = reader.next_string()?;
+        contract.sec_type = reader.next_string()?;
+        contract.last_trade_date_or_contract_month = reader.next_string()?;
+        contract.strike = reader.next_parsed()?;
+        contract.right = reader.next_string()?;
+        contract.exchange = reader.next_string()?;
+        contract.currency = reader.next_string()?;
+        contract.local_symbol = reader.next_string()?;
+        details.market_name = reader.next_string()?;
+        details.contract.trading_class = reader.next_string()?;
+        details.contract.con_id = reader.next_parsed()?;
+        details.min_tick = reader.next_parsed()?;
+        if server_version < MIN_SERVER_VER_SIZE_RULES {
+            reader.skip(1)?; // Market data size multiplier
+        }
+        details.contract.multiplier = reader.next_string()?;
+        details.order_types = reader.next_string()?;
+        details.valid_exchanges = reader.next_string()?;
+        details.price_magnifier = reader.next_parsed()?;
+        details.under_con_id = reader.next_parsed()?;
+        details.long_name = reader.next_string()?;
+        details.contract.primary_exchange = reader.next_string()?;
+        details.contract_month = reader.next_string()?;
+        details.industry = reader.next_string()?;
+        details.category = reader.next_string()?;
+        details.subcategory = reader.next_string()?;
+        details.time_zone_id = reader.next_string()?;
+        details.trading_hours = reader.next_string()?;
+        details.liquid_hours = reader.next_string()?;
+        details.ev_rule = reader.next_string()?;
+        details.ev_multiplier = reader.next_parsed()?;
+        let sec_id_count: usize = reader.next_parsed()?;
+        for _ in 0..sec_id_count {
+            let tag = reader.next_string()?;
+            let value = reader.next_string()?;
+            details.sec_id_list.push((tag, value));
+        }
+        details.agg_group = reader.next_parsed()?;
+        details.under_symbol = reader.next_string()?;
+        details.under_sec_type = reader.next_string()?;
+        details.market_rule_ids = reader.next_string()?;
+        details.real_expiration_date = reader.next_string()?;
+        details.stock_type = reader.next_string()?;
+        if server_version >= MIN_SERVER_VER_SIZE_RULES {
+            details.min_size = reader.next_opt_decimal()?;
+            details.size_increment = reader.next_opt_decimal()?;
+            details.suggested_size_increment = reader.next_opt_decimal()?;
+        } else if server_version >= MIN_SERVER_VER_FRACTIONAL_SIZE_SUPPORT {
+            details.min_size = reader.next_opt_decimal()?;
+        }
+        Ok(details)
+    }
+}
+
+/// A tick from a tick-by-tick data subscription, with its time in seconds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TickByTick {
+    Last {
+        time: i64,
+        price: f64,
+        size: f64,
+        past_limit: bool,
+        unreported: bool,
+        exchange: String,
+        special_conditions: String,
+    },
+    BidAsk {
+        time: i64,
+        bid_price: f64,
+        ask_price: f64,
+        bid_size: f64,
+        ask_size: f64,
+        bid_past_low: bool,
+        ask_past_high: bool,
+    },
+    MidPoint {
+        time: i64,
+        mid_point: f64,
+    },
+}
+
+/// A message received from TWS.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IncomingMessage {
+    TickPrice {
+        req_id: i32,
+        tick_type: i32,
+        price: f64,
+        size: Option<f64>,
+        attr_mask: i32,
+    },
+    TickSize {
+        req_id: i32,
+        tick_type: i32,
+        size: Option<f64>,
+    },
+    /// An error or notice, with a request ID of `-1` if not for a request.
+    Error {
+        req_id: i32,
+        code: i32,
+        message: String,
+    },
+    NextValidId(i64),
+    ContractData {
+        req_id: i32,
+        details: Box<ContractDetails>,
+    },
+    ContractDataEnd {
+        req_id: i32,
+    },
+    ManagedAccounts(Vec<String>),
+    CurrentTime(i64),
+    TickByTick {
+        req_id: i32,
+        tick: TickByTick,
+    },
+    /// A message without a typed decoding, with its raw fields.
+    Other {
+        msg_id: u32,
+        fields: Vec<String>,
+    },
+}
+
+impl IncomingMessage {
+    /// Decodes the fields of a frame for the server version.
+    pub fn decode(fields: &[String], server_version: u32) -> Result<Self, IbError> {
+        let mut reader = FieldReader::new(fields);
+        let msg_id: u32 = reader.next_parsed()?;
+
+        let msg = match msg_id {
+            incoming::TICK_PRICE => {
+                reader.skip(1)?; // Version
+                Self::TickPrice {
+                    req_id: reader.next_parsed()?,
+                    tick_type: reader.next_parsed()?,
+                    price: reader.next_parsed()?,
+                    size: reader.next_opt_decimal()?,
+                    attr_mask: reader.next_parsed()?,
+                }
+            }
+            incoming::TICK_SIZE => {
+                reader.skip(1)?;
+                Self::TickSize {
+                    req_id: reader.next_parsed()?,
+                    tick_type: reader.next_parsed()?,
+                    size: reader.next_opt_decimal()?,
+                }
+            }
+            incoming::ERR_MSG => {
+                reader.skip(1)?;
+                let msg = Self::Error {
+                    req_id: reader.next_parsed()?,
+                    code: reader.next_parsed()?,
+                    message: reader.next_string()?,
+                };
+                if server_version >= MIN_SERVER_VER_ADVANCED_ORDER_REJECT {
+                    reader.skip(1)?;
+                }
+                msg
+            }
+            incoming::NEXT_VALID_ID => {
+                reader.skip(1)?;
+                Self::NextValidId(reader.next_parsed()?)
+            }
+            incoming::CONTRACT_DATA => {
+                if server_version < MIN_SERVER_VER_SIZE_RULES {
+                    reader.skip(1)?;
+                }
+                let req_id = reader.next_parsed()?;
+                let details = ContractDetails::decode(&mut reader, server_version)?;
+                Self::ContractData {
+                    req_id,
+                    details: Box::new(details),
+                }
+            }
+            incoming::CONTRACT_DATA_END => {
+                reader.skip(1)?;
+                Self::ContractDataEnd {
+                    req_id: reader.next_parsed()?,
+                }
+            }
+            incoming::MANAGED_ACCTS => {
+                reader.skip(1)?;
+                let accounts = reader
+                    .next_str()?
+                    .split(',')
+                    .filter(|account| !account.is_empty())
+                    .map(ToString::to_string)
+                    .collect();
+                Self::ManagedAccounts(accounts)
+            }
+            incoming::CURRENT_TIME => {
+                reader.skip(1)?;
+                Self::CurrentTime(reader.next_parsed()?)
+            }
+            incoming::TICK_BY_TICK => {
+                let req_id = reader.next_parsed()?;
+                let tick = decode_tick_by_tick(&mut reader)?;
+                Self::TickByTick { req_id, tick }
+            }
+            _ => Self::Other {
+                msg_id,
+                fields: fields[1..].to_vec(),
+            },
+        };
+        Ok(msg)
+    }
+}
+
+fn decode_tick_by_tick(reader: &mut FieldReader<'_>) -> Result<TickByTick, IbError> {
+    let tick_type: i32 = reader.next_parsed()?;
+    let time = reader.next_parsed()?;
+    let tick = match tick_type {
+        1 | 2 => {
+            let price = reader.next_parsed()?;
+            let size = reader.next_opt_decimal()?.unwrap_or_default();
+            let mask: i32 = reader.next_parsed()?;
+            TickByTick::Last {
+                time,
+                price,
+                size,
+                past_limit: mask & 1 != 0,
+                unreported: mask & 2 != 0,
+                exchange: reader.next_string()?,
+                special_conditions: reader.next_string()?,
+            }
+        }
+        3 => {
+            let bid_price = reader.next_parsed()?;
+            let ask_price = reader.next_parsed()?;
+            let bid_size = reader.next_opt_decimal()?.unwrap_or_default();
+            let ask_size = reader.next_opt_decimal()?.unwrap_or_default();
+            let mask: i32 = reader.next_parsed()?;
+            TickByTick::BidAsk {
+                time,
+                bid_price,
+                ask_price,
+                bid_size,
+                ask_size,
+                bid_past_low: mask & 1 != 0,
+                ask_past_high: mask & 2 != 0,
+            }
+        }
+        4 => TickByTick::MidPoint {
+            time,
+            mid_point: reader.next_parsed()?,
+        },
+        other => {
+            return Err(IbError::InvalidField {
+                index: 2,
+                value: other.to_string(),
+            })
+        }
+    };
+    Ok(tick)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::interactive_brokers::codec::tests::fields;
+
+    const SERVER_VERSION: u32 = 176;
+
+    pub fn aapl_contract_data_fields() -> Vec<String> {
+        fields(&[
+            "10",
+            "1",
+            "AAPL",
+            "STK",
+            "",
+            "0",
+            "",
+            "SMART",
+            "USD",
+            "AAPL",
+            "NMS",
+            "NMS",
+            "265598",
+            "0.01",
+            "",
+            "ACTIVETIM,AD,ALERT",
+            "SMART,NASDAQ,NYSE",
+            "1",
+            "0",
+            "APPLE INC",
+            "NASDAQ",
+            "",
+            "Technology",
+            "Computers",
+            "Computers",
+            "US/Eastern",
+            "20230601:0400-20230601:2000",
+            "20230601:0930-20230601:1600",
+            "",
+            "0",
+            "1",
+            "ISIN",
+            "US0378331005",
+            "1",
+            "",
+            "",
+            "26,26,26",
+            "",
+            "COMMON",
+            "0.0001",
+            "0.0001",
+            "100",
+        ])
+    }
+
+    #[test]
+    fn test_start_api_fields() {
+        let msg = OutgoingMessage::StartApi {
+            client_id: 1,
+            optional_capabilities: String::new(),
+        };
+
+        assert_eq!(
+            msg.fields(SERVER_VERSION).unwrap(),
+            fields(&["71", "2", "1", ""])
+        );
+    }
+
+    #[test]
+    fn test_req_contract_details_fields() {
+        let msg = OutgoingMessage::ReqContractDetails {
+            req_id: 7,
+            contract: Contract::stock("AAPL", "NASDAQ", "USD"),
+        };
+
+        assert_eq!(
+            msg.fields(SERVER_VERSION).unwrap(),
+            fields(&[
+                "9", "8", "7", "0", "AAPL", "STK", "", "0", "", "", "SMART", "NASDAQ", "USD", "",
+                "", "0", "", "", "",
+            ])
+        );
+        assert_eq!(msg.fields(175).unwrap().len(), 18);
+    }
+
+    #[test]
+    fn test_req_tick_by_tick_fields() {
+        let msg = OutgoingMessage::ReqTickByTickData {
+            req_id: 3,
+            contract: Contract::from_con_id(265598, "SMART"),
+            tick_type: TickByTickType::BidAsk,
+            number_of_ticks: 0,
+            ignore_size: false,
+        };
+
+        assert_eq!(
+            msg.fields(SERVER_VERSION).unwrap(),
+            fields(&[
+                "97", "3", "265598", "", "", "", "0", "", "", "SMART", "", "", "", "", "BidAsk",
+                "0", "0",
+            ])
+        );
+    }
+
+    #[test]
+    fn test_bag_contract_unsupported() {
+        let contract = Contract {
+            sec_type: "BAG".to_string(),
+            ..Contract::default()
+        };
+        let msg = OutgoingMessage::ReqMktData {
+            req_id: 1,
+            contract,
+            generic_ticks: String::new(),
+            snapshot: false,
+        };
+
+        assert!(matches!(
+            msg.encode(SERVER_VERSION),
+            Err(IbError::UnsupportedContract(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_tick_price() {
+        let msg = IncomingMessage::decode(
+            &fields(&["1", "6", "5", "1", "189.5", "300", "0"]),
+            SERVER_VERSION,
+        )
+        .unwrap();
+
+        assert_eq!(
+            msg,
+            IncomingMessage::TickPrice {
+                req_id: 5,
+                tick_type: tick_type::BID,
+                price: 189.5,
+                size: Some(300.0),
+                attr_mask: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let msg = IncomingMessage::decode(
+            &fields(&[
+                "4",
+                "2",
+                "-1",
+                "2104",
+                "Market data farm connection is OK:usfarm",
+                "",
+            ]),
+            SERVER_VERSION,
+        )
+        .unwrap();
+
+        assert_eq!(
+            msg,
+            IncomingMessage::Error {
+                req_id: -1,
+                code: 2104,
+                message: "Market data farm connection is OK:usfarm".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_managed_accounts() {
+        let msg =
+            IncomingMessage::decode(&fields(&["15", "1", "DU123,DU456,"]), SERVER_VERSION).unwrap();
+
+        assert_eq!(
+            msg,
+            IncomingMessage::ManagedAccounts(vec!["DU123".to_string(), "DU456".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_decode_contract_data() {
+        let values = aapl_contract_data_fields();
+        let msg = IncomingMessage::decode(&values, SERVER_VERSION).unwrap();
+
+        let details = match msg {
+            IncomingMessage::ContractData { req_id, details } => {
+                assert_eq!(req_id, 1);
+                details
+            }
+            other => panic!("unexpected message {other:?}"),
+        };
+        assert_eq!(details.contract.symbol, "AAPL");
+        assert_eq!(details.contract.con_id, 265598);
+        assert_eq!(details.contract.primary_exchange, "NASDAQ");
+        assert_eq!(details.min_tick, 0.01);
+        assert_eq!(details.long_name, "APPLE INC");
+        assert_eq!(details.sec_id("ISIN"), Some("US0378331005"));
+        assert_eq!(details.stock_type, "COMMON");
+        assert_eq!(details.size_increment, Some(0.0001));
+        assert_eq!(details.suggested_size_increment, Some(100.0));
+    }
+
+    #[test]
+    fn test_decode_tick_by_tick_bid_ask() {
+        let msg = IncomingMessage::decode(
+            &fields(&[
+                "99",
+                "3",
+                "3",
+                "1685620800",
+                "189.5",
+                "189.52",
+                "300",
+                "200",
+                "0",
+            ]),
+            SERVER_VERSION,
+        )
+        .unwrap();
+
+        assert_eq!(
+            msg,
+            IncomingMessage::TickByTick {
+                req_id: 3,
+                tick: TickByTick::BidAsk {
+                    time: 1685620800,
+                    bid_price: 189.5,
+                    ask_price: 189.52,
+                    bid_size: 300.0,
+                    ask_size: 200.0,
+                    bid_past_low: false,
+                    ask_past_high: false,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_tick_by_tick_last() {
+        let msg = IncomingMessage::decode(
+            &fields(&[
+                "99",
+                "3",
+                "1",
+                "1685620800",
+                "189.51",
+                "100",
+                "2",
+                "NASDAQ",
+                "",
+            ]),
+            SERVER_VERSION,
+        )
+        .unwrap();
+
+        assert_eq!(
+            msg,
+            IncomingMessage::TickByTick {
+                req_id: 3,
+                tick: TickByTick::Last {
+                    time: 1685620800,
+                    price: 189.51,
+                    size: 100.0,
+                    past_limit: false,
+                    unreported: true,
+                    exchange: "NASDAQ".to_string(),
+                    special_conditions: String::new(),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_unknown_message() {
+        let msg = IncomingMessage::decode(&fields(&["81", "1", "2"]), SERVER_VERSION).unwrap();
+
+        assert_eq!(
+            msg,
+            IncomingMessage::Other {
+                msg_id: 81,
+                fields: fields(&["1", "2"]),
+            }
+        );
+    }
+}
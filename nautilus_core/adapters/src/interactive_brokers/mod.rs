@@ -0,0 +1,48 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A codec for the Interactive Brokers TWS API socket protocol.
+//!
+//! Implements the framing, handshake and typed messages of the API used by TWS
+//! and IB Gateway, so that market data can be decoded without the pure Python
+//! `ibapi` client. Like the FIX session layer this is sans-IO: received bytes
+//! are split into frames with [`codec::next_frame`] and decoded with
+//! [`messages::IncomingMessage::decode`].
+
+pub mod codec;
+pub mod messages;
+pub mod parsing;
+
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum IbError {
+    #[error("Frame of {0} bytes exceeds the maximum message length")]
+    FrameTooLarge(usize),
+    #[error("Missing field {0} of message")]
+    MissingField(usize),
+    #[error("Invalid value '{value}' for field {index}")]
+    InvalidField { index: usize, value: String },
+    #[error("Invalid handshake response")]
+    InvalidHandshake,
+    #[error("Server version {0} is not supported")]
+    UnsupportedServerVersion(u32),
+    #[error("Unsupported contract: {0}")]
+    UnsupportedContract(String),
+    #[error("Invalid contract: {0}")]
+    InvalidContract(String),
+    #[error("Unknown currency '{0}'")]
+    UnknownCurrency(String),
+}
@@ -0,0 +1,541 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Parsing of decoded TWS messages into Nautilus instruments and ticks.
+//!
+//! Instrument IDs follow the simplified symbology of the Python adapter, so
+//! `AAPL` routed through SMART is `AAPL.NASDAQ` and `EUR.USD` is `EUR/USD.IDEALPRO`.
+
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::{
+        tick::{QuoteTick, TradeTick},
+        Data,
+    },
+    enums::{AggressorSide, AssetClass, OptionKind},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol, trade_id::TradeId, venue::Venue},
+    instruments::{
+        currency_pair::CurrencyPair, equity::Equity, futures_contract::FuturesContract,
+        options_contract::OptionsContract,
+    },
+    types::{currency::Currency, fixed::FIXED_PRECISION, price::Price, quantity::Quantity},
+};
+use rust_decimal::Decimal;
+
+use super::{
+    messages::{Contract, ContractDetails, TickByTick},
+    IbError,
+};
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// An instrument parsed from IB contract details.
+#[derive(Debug, Clone)]
+pub enum IbInstrument {
+    Equity(Equity),
+    FuturesContract(FuturesContract),
+    OptionsContract(OptionsContract),
+    CurrencyPair(CurrencyPair),
+}
+
+impl IbInstrument {
+    #[must_use]
+    pub fn id(&self) -> InstrumentId {
+        match self {
+            Self::Equity(inst) => inst.id,
+            Self::FuturesContract(inst) => inst.id,
+            Self::OptionsContract(inst) => inst.id,
+            Self::CurrencyPair(inst) => inst.id,
+        }
+    }
+}
+
+/// Returns the Nautilus instrument ID for the contract.
+pub fn instrument_id_from_contract(contract: &Contract) -> Result<InstrumentId, IbError> {
+    let (symbol, venue) = match contract.sec_type.as_str() {
+        "STK" => {
+            let venue = if contract.exchange == "SMART" {
+                &contract.primary_exchange
+            } else {
+                &contract.exchange
+            };
+            (contract.local_symbol.replace(' ', "-"), venue)
+        }
+        "FUT" | "OPT" => (contract.local_symbol.replace(' ', ""), &contract.exchange),
+        "CASH" => (contract.local_symbol.replace('.', "/"), &contract.exchange),
+        other => return Err(IbError::UnsupportedContract(other.to_string())),
+    };
+    if symbol.is_empty() || venue.is_empty() {
+        return Err(IbError::InvalidContract(format!(
+            "missing local symbol or exchange for contract {}",
+            contract.con_id
+        )));
+    }
+    Ok(InstrumentId::new(Symbol::new(&symbol), Venue::new(venue)))
+}
+
+/// Returns the number of decimal places of the increment, as TWS reports
+/// increments as doubles such as `0.01` or `5.0E-5`.
+pub fn increment_precision(increment: f64) -> Result<u8, IbError> {
+    if !increment.is_finite() || increment <= 0.0 {
+        return Err(IbError::InvalidContract(format!(
+            "invalid increment {increment}"
+        )));
+    }
+    let formatted = format!("{increment:.10}");
+    let decimals = formatted
+        .split_once('.')
+        .map_or(0, |(_, frac)| frac.trim_end_matches('0').len());
+    if decimals > FIXED_PRECISION as usize {
+        return Err(IbError::InvalidContract(format!(
+            "increment {increment} exceeds the maximum precision"
+        )));
+    }
+    Ok(decimals as u8)
+}
+
+/// Parses the contract details into an instrument.
+///
+/// Margins and fees are not part of the contract details, so are zero.
+pub fn parse_instrument(details: &ContractDetails) -> Result<IbInstrument, IbError> {
+    let contract = &details.contract;
+    let id = instrument_id_from_contract(contract)?;
+    let native_symbol = Symbol::new(&contract.local_symbol);
+    let currency = parse_currency(&contract.currency)?;
+    let price_precision = increment_precision(details.min_tick)?;
+    let price_increment = Price::new(details.min_tick, price_precision);
+    let multiplier = parse_multiplier(&contract.multiplier)?;
+
+    let instrument = match contract.sec_type.as_str() {
+        "STK" => IbInstrument::Equity(Equity::new(
+            id,
+            native_symbol,
+            currency,
+            price_precision,
+            price_increment,
+            multiplier,
+            None,
+            details.sec_id("ISIN").map(ToString::to_string),
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+        )),
+        "FUT" => IbInstrument::FuturesContract(FuturesContract::new(
+            id,
+            native_symbol,
+            parse_asset_class(&details.under_sec_type),
+            currency,
+            price_precision,
+            price_increment,
+            multiplier,
+            None,
+            contract.symbol.clone(),
+            0,
+            parse_expiration(&contract.last_trade_date_or_contract_month)?,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+        )),
+        "OPT" => {
+            let kind = match contract.right.as_str() {
+                "C" | "CALL" => OptionKind::Call,
+                "P" | "PUT" => OptionKind::Put,
+                other => {
+                    return Err(IbError::InvalidContract(format!(
+                        "invalid option right '{other}'"
+                    )))
+                }
+            };
+            IbInstrument::OptionsContract(OptionsContract::new(
+                id,
+                native_symbol,
+                parse_asset_class(&details.under_sec_type),
+                currency,
+                price_precision,
+                price_increment,
+                multiplier,
+                None,
+                contract.symbol.clone(),
+                kind,
+                0,
+                parse_expiration(&contract.last_trade_date_or_contract_month)?,
+                Price::new(contract.strike, price_precision),
+                Decimal::ZERO,
+                Decimal::ZERO,
+                Decimal::ZERO,
+                Decimal::ZERO,
+            ))
+        }
+        "CASH" => {
+            let size_increment = details.size_increment.unwrap_or(1.0);
+            let size_precision = increment_precision(size_increment)?;
+            IbInstrument::CurrencyPair(CurrencyPair::new(
+                id,
+                native_symbol,
+                parse_currency(&contract.symbol)?,
+                currency,
+                price_precision,
+                size_precision,
+                price_increment,
+                Quantity::new(size_increment, size_precision),
+                None,
+                None,
+                details
+                    .min_size
+                    .map(|size| Quantity::new(size, size_precision)),
+                None,
+                None,
+                Decimal::ZERO,
+                Decimal::ZERO,
+                Decimal::ZERO,
+                Decimal::ZERO,
+            ))
+        }
+        other => return Err(IbError::UnsupportedContract(other.to_string())),
+    };
+    Ok(instrument)
+}
+
+/// Parses a tick-by-tick tick into a trade or quote tick, returning `None`
+/// for midpoints.
+#[must_use]
+pub fn parse_tick_by_tick(
+    tick: &TickByTick,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> Option<Data> {
+    match tick {
+        TickByTick::Last {
+            time, price, size, ..
+        } => {
+            let price = Price::new(*price, price_precision);
+            let size = Quantity::new(*size, size_precision);
+            let trade_id = TradeId::new(&format!("{time}-{price}-{size}"));
+            Some(Data::Trade(TradeTick::new(
+                instrument_id,
+                price,
+                size,
+                AggressorSide::NoAggressor,
+                trade_id,
+                secs_to_nanos(*time),
+                ts_init,
+            )))
+        }
+        TickByTick::BidAsk {
+            time,
+            bid_price,
+            ask_price,
+            bid_size,
+            ask_size,
+            ..
+        } => Some(Data::Quote(QuoteTick::new(
+            instrument_id,
+            Price::new(*bid_price, price_precision),
+            Price::new(*ask_price, price_precision),
+            Quantity::new(*bid_size, size_precision),
+            Quantity::new(*ask_size, size_precision),
+            secs_to_nanos(*time),
+            ts_init,
+        ))),
+        TickByTick::MidPoint { .. } => None,
+    }
+}
+
+fn parse_currency(code: &str) -> Result<Currency, IbError> {
+    Currency::from_str(code).map_err(|_| IbError::UnknownCurrency(code.to_string()))
+}
+
+fn parse_multiplier(multiplier: &str) -> Result<Quantity, IbError> {
+    if multiplier.is_empty() {
+        return Ok(Quantity::new(1.0, 0));
+    }
+    match multiplier.parse::<f64>() {
+        Ok(value) if value > 0.0 => {
+            let precision = increment_precision(value)?;
+            Ok(Quantity::new(value, precision))
+        }
+        _ => Err(IbError::InvalidContract(format!(
+            "invalid multiplier '{multiplier}'"
+        ))),
+    }
+}
+
+fn parse_asset_class(under_sec_type: &str) -> AssetClass {
+    match under_sec_type {
+        "STK" => AssetClass::Equity,
+        "IND" => AssetClass::Index,
+        "CASH" => AssetClass::FX,
+        "BOND" => AssetClass::Bond,
+        "CRYPTO" => AssetClass::Cryptocurrency,
+        _ => AssetClass::Commodity,
+    }
+}
+
+/// Parses a `YYYYMMDD` last trade date, ignoring any time suffix, as
+/// midnight UTC.
+fn parse_expiration(date: &str) -> Result<UnixNanos, IbError> {
+    let invalid = || IbError::InvalidContract(format!("invalid last trade date '{date}'"));
+    let day = date.get(..8).ok_or_else(invalid)?;
+    let date = NaiveDate::parse_from_str(day, "%Y%m%d").map_err(|_| invalid())?;
+    let secs = date.and_hms_opt(0, 0, 0).ok_or_else(invalid)?.timestamp();
+    u64::try_from(secs)
+        .map(|secs| secs * NANOS_PER_SECOND)
+        .map_err(|_| invalid())
+}
+
+fn secs_to_nanos(secs: i64) -> UnixNanos {
+    u64::try_from(secs).unwrap_or_default() * NANOS_PER_SECOND
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::interactive_brokers::messages::{tests::aapl_contract_data_fields, IncomingMessage};
+
+    fn aapl_details() -> ContractDetails {
+        match IncomingMessage::decode(&aapl_contract_data_fields(), 176).unwrap() {
+            IncomingMessage::ContractData { details, .. } => *details,
+            other => panic!("unexpected message {other:?}"),
+        }
+    }
+
+    fn contract(sec_type: &str, local_symbol: &str, exchange: &str) -> Contract {
+        Contract {
+            sec_type: sec_type.to_string(),
+            local_symbol: local_symbol.to_string(),
+            exchange: exchange.to_string(),
+            ..Contract::default()
+        }
+    }
+
+    #[rstest]
+    #[case(contract("STK", "BRK B", "NYSE"), "BRK-B.NYSE")]
+    #[case(contract("FUT", "ESM3", "CME"), "ESM3.CME")]
+    #[case(
+        contract("OPT", "AAPL  230616C00180000", "SMART"),
+        "AAPL230616C00180000.SMART"
+    )]
+    #[case(contract("CASH", "EUR.USD", "IDEALPRO"), "EUR/USD.IDEALPRO")]
+    fn test_instrument_id_from_contract(#[case] contract: Contract, #[case] expected: &str) {
+        assert_eq!(
+            instrument_id_from_contract(&contract).unwrap(),
+            InstrumentId::from_str(expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_instrument_id_from_smart_stock_uses_primary_exchange() {
+        let mut contract = contract("STK", "AAPL", "SMART");
+        contract.primary_exchange = "NASDAQ".to_string();
+
+        assert_eq!(
+            instrument_id_from_contract(&contract).unwrap(),
+            InstrumentId::from_str("AAPL.NASDAQ").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_instrument_id_from_bond_unsupported() {
+        assert_eq!(
+            instrument_id_from_contract(&contract("BOND", "T 4 05/31/30", "SMART")),
+            Err(IbError::UnsupportedContract("BOND".to_string()))
+        );
+    }
+
+    #[rstest]
+    #[case(0.01, 2)]
+    #[case(0.25, 2)]
+    #[case(5.0E-5, 5)]
+    #[case(1.0, 0)]
+    #[case(100.0, 0)]
+    fn test_increment_precision(#[case] increment: f64, #[case] expected: u8) {
+        assert_eq!(increment_precision(increment).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(-0.01)]
+    #[case(f64::NAN)]
+    #[case(1.0E-10)]
+    fn test_increment_precision_invalid(#[case] increment: f64) {
+        assert!(increment_precision(increment).is_err());
+    }
+
+    #[test]
+    fn test_parse_equity() {
+        let instrument = match parse_instrument(&aapl_details()).unwrap() {
+            IbInstrument::Equity(equity) => equity,
+            other => panic!("unexpected instrument {other:?}"),
+        };
+
+        assert_eq!(
+            instrument.id,
+            InstrumentId::from_str("AAPL.NASDAQ").unwrap()
+        );
+        assert_eq!(instrument.price_precision, 2);
+        assert_eq!(instrument.price_increment, Price::from("0.01"));
+        assert_eq!(instrument.multiplier, Quantity::from("1"));
+        assert_eq!(instrument.isin, Some("US0378331005".to_string()));
+    }
+
+    #[test]
+    fn test_parse_futures_contract() {
+        let details = ContractDetails {
+            contract: Contract {
+                symbol: "ES".to_string(),
+                sec_type: "FUT".to_string(),
+                last_trade_date_or_contract_month: "20230616".to_string(),
+                multiplier: "50".to_string(),
+                exchange: "CME".to_string(),
+                currency: "USD".to_string(),
+                local_symbol: "ESM3".to_string(),
+                ..Contract::default()
+            },
+            min_tick: 0.25,
+            under_sec_type: "IND".to_string(),
+            ..ContractDetails::default()
+        };
+
+        let instrument = match parse_instrument(&details).unwrap() {
+            IbInstrument::FuturesContract(future) => future,
+            other => panic!("unexpected instrument {other:?}"),
+        };
+
+        assert_eq!(instrument.id, InstrumentId::from_str("ESM3.CME").unwrap());
+        assert_eq!(instrument.asset_class, AssetClass::Index);
+        assert_eq!(instrument.price_increment, Price::from("0.25"));
+        assert_eq!(instrument.multiplier, Quantity::from("50"));
+        assert_eq!(instrument.underlying, "ES");
+        assert_eq!(instrument.expiration_ns, 1_686_873_600_000_000_000);
+    }
+
+    #[test]
+    fn test_parse_currency_pair() {
+        let details = ContractDetails {
+            contract: Contract {
+                symbol: "EUR".to_string(),
+                sec_type: "CASH".to_string(),
+                exchange: "IDEALPRO".to_string(),
+                currency: "USD".to_string(),
+                local_symbol: "EUR.USD".to_string(),
+                ..Contract::default()
+            },
+            min_tick: 5.0E-5,
+            size_increment: Some(1.0),
+            min_size: Some(1.0),
+            ..ContractDetails::default()
+        };
+
+        let instrument = match parse_instrument(&details).unwrap() {
+            IbInstrument::CurrencyPair(pair) => pair,
+            other => panic!("unexpected instrument {other:?}"),
+        };
+
+        assert_eq!(
+            instrument.id,
+            InstrumentId::from_str("EUR/USD.IDEALPRO").unwrap()
+        );
+        assert_eq!(
+            instrument.base_currency,
+            Some(Currency::from_str("EUR").unwrap())
+        );
+        assert_eq!(instrument.price_precision, 5);
+        assert_eq!(instrument.size_precision, 0);
+    }
+
+    #[test]
+    fn test_parse_instrument_unknown_currency() {
+        let mut details = aapl_details();
+        details.contract.currency = "XYZ".to_string();
+
+        assert!(matches!(
+            parse_instrument(&details),
+            Err(IbError::UnknownCurrency(code)) if code == "XYZ"
+        ));
+    }
+
+    #[test]
+    fn test_parse_tick_by_tick_last() {
+        let tick = TickByTick::Last {
+            time: 1_685_620_800,
+            price: 189.51,
+            size: 100.0,
+            past_limit: false,
+            unreported: false,
+            exchange: "NASDAQ".to_string(),
+            special_conditions: String::new(),
+        };
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+
+        let trade = match parse_tick_by_tick(&tick, instrument_id, 2, 0, 1) {
+            Some(Data::Trade(trade)) => trade,
+            other => panic!("unexpected data {other:?}"),
+        };
+
+        assert_eq!(trade.price, Price::from("189.51"));
+        assert_eq!(trade.size, Quantity::from("100"));
+        assert_eq!(trade.aggressor_side, AggressorSide::NoAggressor);
+        assert_eq!(trade.trade_id, TradeId::new("1685620800-189.51-100"));
+        assert_eq!(trade.ts_event, 1_685_620_800_000_000_000);
+        assert_eq!(trade.ts_init, 1);
+    }
+
+    #[test]
+    fn test_parse_tick_by_tick_bid_ask() {
+        let tick = TickByTick::BidAsk {
+            time: 1_685_620_800,
+            bid_price: 189.5,
+            ask_price: 189.52,
+            bid_size: 300.0,
+            ask_size: 200.0,
+            bid_past_low: false,
+            ask_past_high: false,
+        };
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+
+        let quote = match parse_tick_by_tick(&tick, instrument_id, 2, 0, 1) {
+            Some(Data::Quote(quote)) => quote,
+            other => panic!("unexpected data {other:?}"),
+        };
+
+        assert_eq!(quote.bid, Price::from("189.50"));
+        assert_eq!(quote.ask, Price::from("189.52"));
+        assert_eq!(quote.bid_size, Quantity::from("300"));
+        assert_eq!(quote.ask_size, Quantity::from("200"));
+    }
+
+    #[test]
+    fn test_parse_tick_by_tick_midpoint() {
+        let tick = TickByTick::MidPoint {
+            time: 1_685_620_800,
+            mid_point: 189.51,
+        };
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+
+        assert!(parse_tick_by_tick(&tick, instrument_id, 2, 0, 1).is_none());
+    }
+}
@@ -0,0 +1,481 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{BTreeMap, HashMap};
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    enums::OrderSide,
+    events::position::PositionClosed,
+    identifiers::position_id::PositionId,
+    position::Position,
+    types::{currency::Currency, money::Money},
+};
+use pyo3::prelude::*;
+
+use crate::statistics;
+
+/// The trading period in days used to annualize the returns statistics.
+pub const TRADING_PERIOD_DAYS: usize = 252;
+
+/// Provides a portfolio performance analyzer for tracking and generating
+/// performance metrics and statistics, matching the Python `PortfolioAnalyzer`.
+#[derive(Clone, Debug, Default)]
+#[pyclass]
+pub struct PortfolioAnalyzer {
+    account_balances_starting: HashMap<Currency, Money>,
+    account_balances: HashMap<Currency, Money>,
+    realized_pnls: HashMap<Currency, HashMap<PositionId, f64>>,
+    returns: BTreeMap<UnixNanos, f64>,
+    position_count: usize,
+    long_count: usize,
+}
+
+impl PortfolioAnalyzer {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resets the analyzer, clearing all stateful values.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the currencies of the analyzed account balances.
+    #[must_use]
+    pub fn currencies(&self) -> Vec<&Currency> {
+        self.account_balances.keys().collect()
+    }
+
+    /// Returns the returns added to the analyzer, keyed by UNIX timestamp (nanoseconds).
+    #[must_use]
+    pub fn returns(&self) -> &BTreeMap<UnixNanos, f64> {
+        &self.returns
+    }
+
+    /// Calculates the performance statistics from the given account balances and positions,
+    /// replacing any previously added trades and returns.
+    pub fn calculate_statistics(
+        &mut self,
+        starting_balances: HashMap<Currency, Money>,
+        balances: HashMap<Currency, Money>,
+        positions: &[Position],
+    ) {
+        self.reset();
+        self.account_balances_starting = starting_balances;
+        self.account_balances = balances;
+        self.add_positions(positions);
+    }
+
+    /// Adds the trades and returns of the given closed `positions` (open positions are
+    /// skipped).
+    pub fn add_positions(&mut self, positions: &[Position]) {
+        for position in positions.iter().filter(|p| p.is_closed()) {
+            if let Some(realized_pnl) = &position.realized_pnl {
+                self.add_trade(&position.id, realized_pnl);
+            }
+            if let (Some(ts_closed), Some(realized_return)) =
+                (position.ts_closed, position.realized_return)
+            {
+                self.add_return(ts_closed, realized_return);
+            }
+            self.count_position(position.entry);
+        }
+    }
+
+    /// Adds the trade and return of the position closed `event`.
+    pub fn add_position_closed(&mut self, event: &PositionClosed) {
+        self.add_trade(&event.position_id, &event.realized_pnl);
+        self.add_return(event.ts_closed, event.realized_return);
+        self.count_position(event.entry);
+    }
+
+    /// Adds the `realized_pnl` for the trade of the given `position_id`, replacing any
+    /// previous PnL for the position.
+    pub fn add_trade(&mut self, position_id: &PositionId, realized_pnl: &Money) {
+        self.realized_pnls
+            .entry(realized_pnl.currency.clone())
+            .or_default()
+            .insert(position_id.clone(), realized_pnl.as_f64());
+    }
+
+    /// Adds the return `value` at the timestamp `ts`, summed with any other returns at the
+    /// same timestamp. NaN returns are skipped (as pandas skips them for statistics).
+    pub fn add_return(&mut self, ts: UnixNanos, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        *self.returns.entry(ts).or_insert(0.0) += value;
+    }
+
+    /// Returns the realized PnLs for the given `currency`, if any trades were added.
+    #[must_use]
+    pub fn realized_pnls(&self, currency: &Currency) -> Option<Vec<f64>> {
+        self.realized_pnls
+            .get(currency)
+            .map(|pnls| pnls.values().copied().collect())
+    }
+
+    /// Returns the total PnL for the given `currency`, including any `unrealized_pnl`.
+    ///
+    /// # Panics
+    ///
+    /// - If `unrealized_pnl` is not in the given `currency`.
+    #[must_use]
+    pub fn total_pnl(&self, currency: &Currency, unrealized_pnl: Option<&Money>) -> f64 {
+        let (balance, starting) = match self.balances(currency, unrealized_pnl) {
+            Some(balances) => balances,
+            None => return 0.0,
+        };
+        balance - starting + unrealized_pnl.map_or(0.0, Money::as_f64)
+    }
+
+    /// Returns the percentage change of the total PnL for the given `currency`, including
+    /// any `unrealized_pnl`.
+    ///
+    /// # Panics
+    ///
+    /// - If `unrealized_pnl` is not in the given `currency`.
+    #[must_use]
+    pub fn total_pnl_percentage(&self, currency: &Currency, unrealized_pnl: Option<&Money>) -> f64 {
+        let (balance, starting) = match self.balances(currency, unrealized_pnl) {
+            Some(balances) => balances,
+            None => return 0.0,
+        };
+        if starting == 0.0 {
+            return 0.0; // Protect divide by zero
+        }
+        let current = balance + unrealized_pnl.map_or(0.0, Money::as_f64);
+        (current - starting) / starting * 100.0
+    }
+
+    /// Returns the PnL performance statistics for the given `currency`, including any
+    /// `unrealized_pnl` in the totals.
+    #[must_use]
+    pub fn get_performance_stats_pnls(
+        &self,
+        currency: &Currency,
+        unrealized_pnl: Option<&Money>,
+    ) -> HashMap<String, f64> {
+        let pnls = self.realized_pnls(currency).unwrap_or_default();
+        HashMap::from([
+            (
+                "PnL (total)".to_string(),
+                self.total_pnl(currency, unrealized_pnl),
+            ),
+            (
+                "PnL% (total)".to_string(),
+                self.total_pnl_percentage(currency, unrealized_pnl),
+            ),
+            ("Max Winner".to_string(), statistics::max_winner(&pnls)),
+            ("Avg Winner".to_string(), statistics::avg_winner(&pnls)),
+            ("Min Winner".to_string(), statistics::min_winner(&pnls)),
+            ("Min Loser".to_string(), statistics::min_loser(&pnls)),
+            ("Avg Loser".to_string(), statistics::avg_loser(&pnls)),
+            ("Max Loser".to_string(), statistics::max_loser(&pnls)),
+            ("Expectancy".to_string(), statistics::expectancy(&pnls)),
+            ("Win Rate".to_string(), statistics::win_rate(&pnls)),
+        ])
+    }
+
+    /// Returns the returns performance statistics, with volatility and risk ratios
+    /// annualized over [`TRADING_PERIOD_DAYS`].
+    #[must_use]
+    pub fn get_performance_stats_returns(&self) -> HashMap<String, f64> {
+        let returns: Vec<f64> = self.returns.values().copied().collect();
+        let daily = statistics::daily_returns(&self.returns);
+        let period = TRADING_PERIOD_DAYS;
+        HashMap::from([
+            (
+                format!("Returns Volatility ({period} days)"),
+                statistics::returns_volatility(&daily, period),
+            ),
+            (
+                "Average (Return)".to_string(),
+                statistics::returns_avg(&returns),
+            ),
+            (
+                "Average Loss (Return)".to_string(),
+                statistics::returns_avg_loss(&returns),
+            ),
+            (
+                "Average Win (Return)".to_string(),
+                statistics::returns_avg_win(&returns),
+            ),
+            (
+                format!("Sharpe Ratio ({period} days)"),
+                statistics::sharpe_ratio(&daily, period),
+            ),
+            (
+                format!("Sortino Ratio ({period} days)"),
+                statistics::sortino_ratio(&daily, period),
+            ),
+            (
+                "Profit Factor".to_string(),
+                statistics::profit_factor(&returns),
+            ),
+            (
+                "Risk Return Ratio".to_string(),
+                statistics::risk_return_ratio(&returns),
+            ),
+            (
+                "Max Drawdown".to_string(),
+                statistics::max_drawdown(&returns),
+            ),
+        ])
+    }
+
+    /// Returns the general performance statistics of the analyzed positions.
+    #[must_use]
+    pub fn get_performance_stats_general(&self) -> HashMap<String, f64> {
+        if self.position_count == 0 {
+            return HashMap::new();
+        }
+        let long_ratio = self.long_count as f64 / self.position_count as f64;
+        HashMap::from([("Long Ratio".to_string(), long_ratio)])
+    }
+
+    fn count_position(&mut self, entry: OrderSide) {
+        self.position_count += 1;
+        if entry == OrderSide::Buy {
+            self.long_count += 1;
+        }
+    }
+
+    fn balances(&self, currency: &Currency, unrealized_pnl: Option<&Money>) -> Option<(f64, f64)> {
+        if let Some(unrealized_pnl) = unrealized_pnl {
+            assert_eq!(
+                &unrealized_pnl.currency, currency,
+                "unrealized PnL currency is not {}",
+                currency.code
+            );
+        }
+        let balance = self.account_balances.get(currency)?.as_f64();
+        let starting = self
+            .account_balances_starting
+            .get(currency)
+            .map_or(0.0, Money::as_f64);
+        Some((balance, starting))
+    }
+}
+
+#[pymethods]
+impl PortfolioAnalyzer {
+    #[new]
+    fn py_new() -> Self {
+        Self::new()
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    /// Sets the starting and current total account balances (one per currency).
+    #[pyo3(name = "set_account_balances")]
+    fn set_account_balances_py(&mut self, starting_balances: Vec<Money>, balances: Vec<Money>) {
+        let by_currency = |balances: Vec<Money>| {
+            balances
+                .into_iter()
+                .map(|money| (money.currency.clone(), money))
+                .collect()
+        };
+        self.account_balances_starting = by_currency(starting_balances);
+        self.account_balances = by_currency(balances);
+    }
+
+    #[pyo3(name = "add_trade")]
+    fn add_trade_py(&mut self, position_id: PositionId, realized_pnl: Money) {
+        self.add_trade(&position_id, &realized_pnl);
+    }
+
+    #[pyo3(name = "add_return")]
+    fn add_return_py(&mut self, ts: UnixNanos, value: f64) {
+        self.add_return(ts, value);
+    }
+
+    /// Adds the given `(ts, value)` returns in bulk.
+    #[pyo3(name = "add_returns")]
+    fn add_returns_py(&mut self, returns: Vec<(UnixNanos, f64)>) {
+        for (ts, value) in returns {
+            self.add_return(ts, value);
+        }
+    }
+
+    #[pyo3(name = "returns")]
+    fn returns_py(&self) -> Vec<(UnixNanos, f64)> {
+        self.returns
+            .iter()
+            .map(|(ts, value)| (*ts, *value))
+            .collect()
+    }
+
+    #[pyo3(name = "realized_pnls")]
+    fn realized_pnls_py(&self, currency: Currency) -> Option<Vec<f64>> {
+        self.realized_pnls(&currency)
+    }
+
+    #[pyo3(name = "total_pnl")]
+    fn total_pnl_py(&self, currency: Currency, unrealized_pnl: Option<Money>) -> f64 {
+        self.total_pnl(&currency, unrealized_pnl.as_ref())
+    }
+
+    #[pyo3(name = "total_pnl_percentage")]
+    fn total_pnl_percentage_py(&self, currency: Currency, unrealized_pnl: Option<Money>) -> f64 {
+        self.total_pnl_percentage(&currency, unrealized_pnl.as_ref())
+    }
+
+    #[pyo3(name = "get_performance_stats_pnls")]
+    fn get_performance_stats_pnls_py(
+        &self,
+        currency: Currency,
+        unrealized_pnl: Option<Money>,
+    ) -> HashMap<String, f64> {
+        self.get_performance_stats_pnls(&currency, unrealized_pnl.as_ref())
+    }
+
+    #[pyo3(name = "get_performance_stats_returns")]
+    fn get_performance_stats_returns_py(&self) -> HashMap<String, f64> {
+        self.get_performance_stats_returns()
+    }
+
+    #[pyo3(name = "get_performance_stats_general")]
+    fn get_performance_stats_general_py(&self) -> HashMap<String, f64> {
+        self.get_performance_stats_general()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::currencies::{AUD, USD};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const NANOSECONDS_IN_DAY: u64 = 86_400_000_000_000;
+
+    #[fixture]
+    fn analyzer() -> PortfolioAnalyzer {
+        let mut analyzer = PortfolioAnalyzer::new();
+        analyzer.account_balances_starting =
+            HashMap::from([(USD.clone(), Money::new(1_000_000.0, USD.clone()))]);
+        analyzer.account_balances =
+            HashMap::from([(USD.clone(), Money::new(1_010_000.0, USD.clone()))]);
+        analyzer
+    }
+
+    #[rstest]
+    fn test_add_trade_replaces_position_pnl(mut analyzer: PortfolioAnalyzer) {
+        let position_id = PositionId::new("P-1");
+        analyzer.add_trade(&position_id, &Money::new(100.0, USD.clone()));
+        analyzer.add_trade(&position_id, &Money::new(150.0, USD.clone()));
+        analyzer.add_trade(&PositionId::new("P-2"), &Money::new(-50.0, USD.clone()));
+
+        let mut pnls = analyzer.realized_pnls(&USD).unwrap();
+        pnls.sort_by(f64::total_cmp);
+
+        assert_eq!(pnls, vec![-50.0, 150.0]);
+        assert!(analyzer.realized_pnls(&AUD).is_none());
+    }
+
+    #[rstest]
+    fn test_add_return_sums_same_timestamp(mut analyzer: PortfolioAnalyzer) {
+        analyzer.add_return(1, 0.01);
+        analyzer.add_return(1, 0.02);
+        analyzer.add_return(2, f64::NAN);
+
+        assert_eq!(analyzer.returns().len(), 1);
+        assert!((analyzer.returns()[&1] - 0.03).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_total_pnl(analyzer: PortfolioAnalyzer) {
+        let unrealized_pnl = Money::new(5_000.0, USD.clone());
+
+        assert_eq!(analyzer.total_pnl(&USD, None), 10_000.0);
+        assert_eq!(analyzer.total_pnl(&USD, Some(&unrealized_pnl)), 15_000.0);
+        assert_eq!(analyzer.total_pnl_percentage(&USD, None), 1.0);
+        assert_eq!(analyzer.total_pnl(&AUD, None), 0.0);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "unrealized PnL currency is not USD")]
+    fn test_total_pnl_with_mismatched_unrealized_pnl_currency(analyzer: PortfolioAnalyzer) {
+        let _ = analyzer.total_pnl(&USD, Some(&Money::new(1.0, AUD.clone())));
+    }
+
+    #[rstest]
+    fn test_get_performance_stats_pnls(mut analyzer: PortfolioAnalyzer) {
+        analyzer.add_trade(&PositionId::new("P-1"), &Money::new(100.0, USD.clone()));
+        analyzer.add_trade(&PositionId::new("P-2"), &Money::new(-50.0, USD.clone()));
+
+        let stats = analyzer.get_performance_stats_pnls(&USD, None);
+
+        assert_eq!(stats["PnL (total)"], 10_000.0);
+        assert_eq!(stats["Win Rate"], 0.5);
+        assert_eq!(stats["Max Winner"], 100.0);
+        assert_eq!(stats["Max Loser"], -50.0);
+        assert_eq!(stats["Expectancy"], 25.0);
+    }
+
+    #[rstest]
+    fn test_get_performance_stats_returns(mut analyzer: PortfolioAnalyzer) {
+        analyzer.add_return(0, 0.01);
+        analyzer.add_return(NANOSECONDS_IN_DAY, -0.02);
+        analyzer.add_return(2 * NANOSECONDS_IN_DAY, 0.03);
+
+        let stats = analyzer.get_performance_stats_returns();
+
+        assert_eq!(stats.len(), 9);
+        assert!(stats["Sharpe Ratio (252 days)"] > 0.0);
+        assert!((stats["Profit Factor"] - 2.0).abs() < 1e-12);
+        assert!((stats["Max Drawdown"] - -0.02).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_get_performance_stats_returns_when_empty(analyzer: PortfolioAnalyzer) {
+        let stats = analyzer.get_performance_stats_returns();
+
+        assert!(stats["Sharpe Ratio (252 days)"].is_nan());
+        assert_eq!(stats["Max Drawdown"], 0.0);
+    }
+
+    #[rstest]
+    fn test_get_performance_stats_general(mut analyzer: PortfolioAnalyzer) {
+        assert!(analyzer.get_performance_stats_general().is_empty());
+
+        analyzer.count_position(OrderSide::Buy);
+        analyzer.count_position(OrderSide::Sell);
+        analyzer.count_position(OrderSide::Buy);
+        analyzer.count_position(OrderSide::Buy);
+
+        assert_eq!(analyzer.get_performance_stats_general()["Long Ratio"], 0.75);
+    }
+
+    #[rstest]
+    fn test_reset(mut analyzer: PortfolioAnalyzer) {
+        analyzer.add_trade(&PositionId::new("P-1"), &Money::new(100.0, USD.clone()));
+        analyzer.add_return(1, 0.01);
+        analyzer.reset();
+
+        assert!(analyzer.returns().is_empty());
+        assert!(analyzer.realized_pnls(&USD).is_none());
+        assert!(analyzer.currencies().is_empty());
+    }
+}
@@ -0,0 +1,335 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Portfolio statistics, matching the Python `PortfolioStatistic` implementations.
+//!
+//! PnL statistics take the realized PnLs for a single currency, and return zero when
+//! there are none. Returns statistics return NaN when there are no returns.
+
+use std::collections::BTreeMap;
+
+use nautilus_core::time::UnixNanos;
+
+const NANOSECONDS_IN_DAY: u64 = 86_400_000_000_000;
+
+/// Returns the sum of `returns` in each UTC day from the first to the last return,
+/// with zero for days without returns (as for a pandas `resample("1D").sum()`).
+#[must_use]
+pub fn daily_returns(returns: &BTreeMap<UnixNanos, f64>) -> Vec<f64> {
+    let (first, last) = match (returns.keys().next(), returns.keys().next_back()) {
+        (Some(first), Some(last)) => (first / NANOSECONDS_IN_DAY, last / NANOSECONDS_IN_DAY),
+        _ => return Vec::new(),
+    };
+
+    let mut daily = vec![0.0; (last - first + 1) as usize];
+    for (ts, value) in returns {
+        daily[(ts / NANOSECONDS_IN_DAY - first) as usize] += value;
+    }
+    daily
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return f64::NAN;
+    }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Returns the sample standard deviation (one delta degree of freedom).
+fn std(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return f64::NAN;
+    }
+    let mean = mean(values);
+    let sum_sq: f64 = values.iter().map(|x| (x - mean).powi(2)).sum();
+    (sum_sq / (values.len() - 1) as f64).sqrt()
+}
+
+fn mean_where(values: &[f64], predicate: impl Fn(f64) -> bool) -> f64 {
+    let filtered: Vec<f64> = values.iter().copied().filter(|x| predicate(*x)).collect();
+    mean(&filtered)
+}
+
+/// Returns the ratio of winning (positive) PnLs to all PnLs.
+#[must_use]
+pub fn win_rate(pnls: &[f64]) -> f64 {
+    if pnls.is_empty() {
+        return 0.0;
+    }
+    let winners = pnls.iter().filter(|x| **x > 0.0).count();
+    winners as f64 / pnls.len() as f64
+}
+
+/// Returns the average winning PnL.
+#[must_use]
+pub fn avg_winner(pnls: &[f64]) -> f64 {
+    let avg = mean_where(pnls, |x| x > 0.0);
+    if avg.is_nan() {
+        0.0
+    } else {
+        avg
+    }
+}
+
+/// Returns the average losing PnL (break even PnLs count as losers).
+#[must_use]
+pub fn avg_loser(pnls: &[f64]) -> f64 {
+    let avg = mean_where(pnls, |x| x <= 0.0);
+    if avg.is_nan() {
+        0.0
+    } else {
+        avg
+    }
+}
+
+/// Returns the maximum PnL.
+#[must_use]
+pub fn max_winner(pnls: &[f64]) -> f64 {
+    pnls.iter().copied().reduce(f64::max).unwrap_or(0.0)
+}
+
+/// Returns the smallest winning PnL.
+#[must_use]
+pub fn min_winner(pnls: &[f64]) -> f64 {
+    pnls.iter()
+        .copied()
+        .filter(|x| *x > 0.0)
+        .reduce(f64::min)
+        .unwrap_or(0.0)
+}
+
+/// Returns the largest losing PnL (the most negative).
+#[must_use]
+pub fn max_loser(pnls: &[f64]) -> f64 {
+    pnls.iter()
+        .copied()
+        .filter(|x| *x < 0.0)
+        .reduce(f64::min)
+        .unwrap_or(0.0)
+}
+
+/// Returns the smallest losing PnL (the least negative, break even included).
+#[must_use]
+pub fn min_loser(pnls: &[f64]) -> f64 {
+    pnls.iter()
+        .copied()
+        .filter(|x| *x <= 0.0)
+        .reduce(f64::max)
+        .unwrap_or(0.0)
+}
+
+/// Returns the expected PnL per trade from the win rate and average winner and loser.
+#[must_use]
+pub fn expectancy(pnls: &[f64]) -> f64 {
+    if pnls.is_empty() {
+        return 0.0;
+    }
+    let win_rate = win_rate(pnls);
+    let loss_rate = 1.0 - win_rate;
+    (avg_winner(pnls) * win_rate) + (avg_loser(pnls) * loss_rate)
+}
+
+/// Returns the average of the non-zero returns.
+#[must_use]
+pub fn returns_avg(returns: &[f64]) -> f64 {
+    mean_where(returns, |x| x != 0.0)
+}
+
+/// Returns the average of the positive returns.
+#[must_use]
+pub fn returns_avg_win(returns: &[f64]) -> f64 {
+    mean_where(returns, |x| x > 0.0)
+}
+
+/// Returns the average of the negative returns.
+#[must_use]
+pub fn returns_avg_loss(returns: &[f64]) -> f64 {
+    mean_where(returns, |x| x < 0.0)
+}
+
+/// Returns the volatility of the `daily_returns` annualized over `period` trading days.
+#[must_use]
+pub fn returns_volatility(daily_returns: &[f64], period: usize) -> f64 {
+    std(daily_returns) * (period as f64).sqrt()
+}
+
+/// Returns the Sharpe ratio of the `daily_returns` annualized over `period` trading days.
+#[must_use]
+pub fn sharpe_ratio(daily_returns: &[f64], period: usize) -> f64 {
+    mean(daily_returns) / std(daily_returns) * (period as f64).sqrt()
+}
+
+/// Returns the Sortino ratio of the `daily_returns` annualized over `period` trading days.
+#[must_use]
+pub fn sortino_ratio(daily_returns: &[f64], period: usize) -> f64 {
+    if daily_returns.is_empty() {
+        return f64::NAN;
+    }
+    let downside_sq: f64 = daily_returns
+        .iter()
+        .filter(|x| **x < 0.0)
+        .map(|x| x.powi(2))
+        .sum();
+    let downside = (downside_sq / daily_returns.len() as f64).sqrt();
+    if downside == 0.0 {
+        return f64::NAN;
+    }
+    mean(daily_returns) / downside * (period as f64).sqrt()
+}
+
+/// Returns the ratio of the sum of positive returns to the sum of negative returns.
+#[must_use]
+pub fn profit_factor(returns: &[f64]) -> f64 {
+    let positive: f64 = returns.iter().filter(|x| **x >= 0.0).sum();
+    let negative: f64 = returns.iter().filter(|x| **x < 0.0).sum();
+    if negative == 0.0 {
+        return f64::NAN;
+    }
+    (positive / negative).abs()
+}
+
+/// Returns the ratio of the mean of the returns to their standard deviation.
+#[must_use]
+pub fn risk_return_ratio(returns: &[f64]) -> f64 {
+    mean(returns) / std(returns)
+}
+
+/// Returns the maximum drawdown of the equity curve compounded from `returns`, as a
+/// negative fraction of the peak equity (zero if there was no drawdown).
+#[must_use]
+pub fn max_drawdown(returns: &[f64]) -> f64 {
+    let mut equity = 1.0;
+    let mut peak = 1.0;
+    let mut max_drawdown: f64 = 0.0;
+    for value in returns {
+        equity *= 1.0 + value;
+        peak = f64::max(peak, equity);
+        max_drawdown = max_drawdown.min(equity / peak - 1.0);
+    }
+    max_drawdown
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const PNLS: [f64; 5] = [100.0, -50.0, 200.0, 0.0, -25.0];
+
+    fn assert_approx_eq(left: f64, right: f64) {
+        assert!((left - right).abs() < 1e-9, "{left} != {right}");
+    }
+
+    #[rstest]
+    fn test_daily_returns_fills_missing_days() {
+        let returns = BTreeMap::from([
+            (0, 0.01),
+            (1_000, 0.02),
+            (2 * NANOSECONDS_IN_DAY + 1, -0.01),
+        ]);
+
+        let daily = daily_returns(&returns);
+
+        assert_eq!(daily.len(), 3);
+        assert_approx_eq(daily[0], 0.03);
+        assert_eq!(daily[1], 0.0);
+        assert_eq!(daily[2], -0.01);
+    }
+
+    #[rstest]
+    fn test_daily_returns_empty() {
+        assert!(daily_returns(&BTreeMap::new()).is_empty());
+    }
+
+    #[rstest]
+    fn test_pnl_statistics() {
+        assert_eq!(win_rate(&PNLS), 0.4);
+        assert_eq!(avg_winner(&PNLS), 150.0);
+        assert_eq!(avg_loser(&PNLS), -25.0);
+        assert_eq!(max_winner(&PNLS), 200.0);
+        assert_eq!(min_winner(&PNLS), 100.0);
+        assert_eq!(max_loser(&PNLS), -50.0);
+        assert_eq!(min_loser(&PNLS), 0.0);
+        assert_approx_eq(expectancy(&PNLS), 150.0 * 0.4 - 25.0 * 0.6);
+    }
+
+    #[rstest]
+    fn test_pnl_statistics_when_empty() {
+        assert_eq!(win_rate(&[]), 0.0);
+        assert_eq!(avg_winner(&[]), 0.0);
+        assert_eq!(avg_loser(&[]), 0.0);
+        assert_eq!(max_winner(&[]), 0.0);
+        assert_eq!(max_loser(&[]), 0.0);
+        assert_eq!(expectancy(&[]), 0.0);
+    }
+
+    #[rstest]
+    fn test_returns_averages() {
+        let returns = [0.02, -0.01, 0.0, 0.04, -0.03];
+
+        assert_approx_eq(returns_avg(&returns), 0.005);
+        assert_approx_eq(returns_avg_win(&returns), 0.03);
+        assert_approx_eq(returns_avg_loss(&returns), -0.02);
+    }
+
+    #[rstest]
+    fn test_returns_statistics_when_empty() {
+        assert!(returns_avg(&[]).is_nan());
+        assert!(returns_volatility(&[], 252).is_nan());
+        assert!(sharpe_ratio(&[], 252).is_nan());
+        assert!(sortino_ratio(&[], 252).is_nan());
+        assert!(profit_factor(&[]).is_nan());
+        assert!(risk_return_ratio(&[]).is_nan());
+    }
+
+    #[rstest]
+    fn test_sharpe_ratio() {
+        let returns = [0.01, -0.01, 0.02, 0.0];
+        let std = (0.0005_f64 / 3.0).sqrt();
+
+        assert_approx_eq(sharpe_ratio(&returns, 252), 0.005 / std * 252_f64.sqrt());
+        assert_approx_eq(returns_volatility(&returns, 252), std * 252_f64.sqrt());
+    }
+
+    #[rstest]
+    fn test_sortino_ratio() {
+        let returns = [0.01, -0.01, 0.02, 0.0];
+        let downside = (0.0001_f64 / 4.0).sqrt();
+
+        assert_approx_eq(
+            sortino_ratio(&returns, 252),
+            0.005 / downside * 252_f64.sqrt(),
+        );
+        assert!(sortino_ratio(&[0.01, 0.02], 252).is_nan());
+    }
+
+    #[rstest]
+    fn test_profit_factor() {
+        assert_approx_eq(profit_factor(&[0.03, -0.01, 0.01, -0.01]), 2.0);
+    }
+
+    #[rstest]
+    #[case(&[], 0.0)]
+    #[case(&[0.1, 0.1], 0.0)]
+    #[case(&[0.1, -0.5, 0.2], -0.5)]
+    #[case(&[-0.1, -0.1], -0.19)]
+    fn test_max_drawdown(#[case] returns: &[f64], #[case] expected: f64) {
+        assert_approx_eq(max_drawdown(returns), expected);
+    }
+}
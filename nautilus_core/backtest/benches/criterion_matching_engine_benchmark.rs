@@ -0,0 +1,126 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::str::FromStr;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use nautilus_backtest::{
+    matching_engine::{OrderMatchingEngine, OrderMatchingEngineConfig},
+    models::FillModel,
+};
+use nautilus_model::{
+    data::tick::QuoteTick,
+    enums::{BookType, OrderSide, OrderType, TimeInForce},
+    events::order::{OrderEvent, OrderInitializedBuilder, OrderSubmittedBuilder},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+    },
+    instruments::BaseInstrument,
+    orders::Order,
+    types::{price::Price, quantity::Quantity},
+};
+use nautilus_test_kit::instruments::audusd_sim;
+
+const NUM_ORDERS: usize = 1000;
+
+fn quote_tick(bid: &str, ask: &str, ts: u64) -> QuoteTick {
+    QuoteTick {
+        instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+        bid: Price::from(bid),
+        ask: Price::from(ask),
+        bid_size: Quantity::from("1000000000"),
+        ask_size: Quantity::from("1000000000"),
+        ts_event: ts,
+        ts_init: ts,
+    }
+}
+
+fn submitted_orders(order_type: OrderType, price: Option<&str>) -> Vec<Order> {
+    (0..NUM_ORDERS)
+        .map(|i| {
+            let init = OrderInitializedBuilder::default()
+                .instrument_id(InstrumentId::from_str("AUD/USD.SIM").unwrap())
+                .client_order_id(ClientOrderId::new(&format!("O-{i}")))
+                .order_side(OrderSide::Buy)
+                .order_type(order_type)
+                .quantity(Quantity::from("100000"))
+                .price(price.map(Price::from))
+                .time_in_force(TimeInForce::Gtc)
+                .build()
+                .unwrap();
+            let mut order = Order::from(init);
+            let submitted = OrderSubmittedBuilder::default()
+                .client_order_id(order.client_order_id.clone())
+                .build()
+                .unwrap();
+            order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+            order
+        })
+        .collect()
+}
+
+fn engine() -> OrderMatchingEngine<BaseInstrument> {
+    let mut engine = OrderMatchingEngine::new(
+        audusd_sim(),
+        BookType::L1_TBBO,
+        AccountId::new("SIM-001"),
+        OrderMatchingEngineConfig::default(),
+        FillModel::default(),
+    );
+    engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+    engine
+}
+
+fn matching_engine_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("matching_engine");
+
+    let market_orders = submitted_orders(OrderType::Market, None);
+    group.bench_function("market_order_fills", |b| {
+        b.iter_batched(
+            || (engine(), market_orders.clone()),
+            |(mut engine, orders)| {
+                for order in orders {
+                    engine.process_order(order, 2);
+                }
+                assert_eq!(engine.drain_fills().len(), NUM_ORDERS);
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    // Resting limit orders which are all filled by the next quote crossing their price
+    let limit_orders = submitted_orders(OrderType::Limit, Some("0.99990"));
+    group.bench_function("limit_order_fills_on_quote", |b| {
+        b.iter_batched(
+            || {
+                let mut engine = engine();
+                for order in limit_orders.clone() {
+                    engine.process_order(order, 2);
+                }
+                engine.drain_events();
+                engine
+            },
+            |mut engine| {
+                engine.process_quote_tick(&quote_tick("0.99986", "0.99988", 3));
+                assert_eq!(engine.drain_fills().len(), NUM_ORDERS);
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, matching_engine_bench);
+criterion_main!(benches);
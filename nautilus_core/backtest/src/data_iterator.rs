@@ -0,0 +1,469 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    fmt::{Display, Formatter},
+};
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::data::Data;
+use thiserror::Error;
+
+/// The maximum number of offending rows listed in a [`DataIteratorError::NonMonotonic`] message.
+const MAX_ROWS_DISPLAYED: usize = 10;
+
+/// Represents a row in a data stream whose `ts_init` is earlier than the preceding row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonMonotonicRow {
+    /// The index of the offending row within the stream.
+    pub index: usize,
+    /// The `ts_init` of the offending row.
+    pub ts_init: UnixNanos,
+    /// The `ts_init` of the preceding row.
+    pub prev_ts_init: UnixNanos,
+}
+
+impl Display for NonMonotonicRow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "row {} (ts_init={} < previous {})",
+            self.index, self.ts_init, self.prev_ts_init
+        )
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DataIteratorError {
+    #[error("Stream '{0}' has already been added")]
+    DuplicateStream(String),
+    #[error(
+        "Stream '{name}' has non-monotonic `ts_init` at {} row(s): {}",
+        .rows.len(),
+        format_rows(.rows)
+    )]
+    NonMonotonic {
+        name: String,
+        rows: Vec<NonMonotonicRow>,
+    },
+}
+
+fn format_rows(rows: &[NonMonotonicRow]) -> String {
+    let mut output = rows
+        .iter()
+        .take(MAX_ROWS_DISPLAYED)
+        .map(NonMonotonicRow::to_string)
+        .collect::<Vec<String>>()
+        .join(", ");
+    if rows.len() > MAX_ROWS_DISPLAYED {
+        output.push_str(&format!(
+            ", ... and {} more",
+            rows.len() - MAX_ROWS_DISPLAYED
+        ));
+    }
+    output
+}
+
+/// Returns every row of `data` whose `ts_init` is earlier than the preceding row.
+#[must_use]
+pub fn find_non_monotonic_rows(data: &[Data]) -> Vec<NonMonotonicRow> {
+    data.windows(2)
+        .enumerate()
+        .filter_map(|(i, pair)| {
+            let prev_ts_init = pair[0].get_ts_init();
+            let ts_init = pair[1].get_ts_init();
+            (ts_init < prev_ts_init).then_some(NonMonotonicRow {
+                index: i + 1,
+                ts_init,
+                prev_ts_init,
+            })
+        })
+        .collect()
+}
+
+struct DataStream {
+    name: String,
+    priority: i32,
+    data: VecDeque<Data>,
+}
+
+/// The heap key for the head of a stream, ordered by `ts_init`, then `priority`, then the
+/// order in which the stream was added.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct StreamHead {
+    ts_init: UnixNanos,
+    priority: i32,
+    stream_id: usize,
+}
+
+/// Provides a merged iterator over multiple data streams for backtesting.
+///
+/// Data is released strictly in `ts_init` order across all streams. Where data from several
+/// streams shares the same `ts_init`, the stream with the lowest `priority` value is released
+/// first, and then the stream which was added first. Within a stream the original order is
+/// preserved.
+///
+/// Each stream must be sorted by `ts_init` (non-decreasing) when added, otherwise it is rejected
+/// with [`DataIteratorError::NonMonotonic`] listing the offending rows.
+pub struct BacktestDataIterator {
+    streams: Vec<Option<DataStream>>,
+    heap: BinaryHeap<Reverse<StreamHead>>,
+    last_ts_init: UnixNanos,
+}
+
+impl BacktestDataIterator {
+    /// Initializes a new empty `BacktestDataIterator` instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            streams: Vec::new(),
+            heap: BinaryHeap::new(),
+            last_ts_init: 0,
+        }
+    }
+
+    /// Adds a data stream with the given `name` and `priority`.
+    ///
+    /// # Errors
+    ///
+    /// - If a stream with the same `name` has already been added.
+    /// - If the `ts_init` timestamps of `data` are not monotonically non-decreasing.
+    pub fn add_stream(
+        &mut self,
+        name: &str,
+        data: Vec<Data>,
+        priority: i32,
+    ) -> Result<(), DataIteratorError> {
+        if self.stream_names().any(|n| n == name) {
+            return Err(DataIteratorError::DuplicateStream(name.to_string()));
+        }
+
+        let rows = find_non_monotonic_rows(&data);
+        if !rows.is_empty() {
+            return Err(DataIteratorError::NonMonotonic {
+                name: name.to_string(),
+                rows,
+            });
+        }
+
+        let stream_id = self.streams.len();
+        self.streams.push(Some(DataStream {
+            name: name.to_string(),
+            priority,
+            data: data.into(),
+        }));
+        self.push_head(stream_id);
+        Ok(())
+    }
+
+    /// Removes the stream with the given `name` along with any of its unreleased data.
+    ///
+    /// Returns `true` if the stream existed.
+    pub fn remove_stream(&mut self, name: &str) -> bool {
+        let position = self
+            .streams
+            .iter()
+            .position(|s| s.as_ref().map_or(false, |s| s.name == name));
+        match position {
+            Some(stream_id) => {
+                self.streams[stream_id] = None;
+                self.heap.retain(|head| head.0.stream_id != stream_id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the names of the streams currently held by the iterator.
+    pub fn stream_names(&self) -> impl Iterator<Item = &str> {
+        self.streams.iter().flatten().map(|s| s.name.as_str())
+    }
+
+    /// Returns the number of data items which have not yet been released.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.streams.iter().flatten().map(|s| s.data.len()).sum()
+    }
+
+    /// Returns `true` if all data has been released.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the `ts_init` of the next data item to be released (if any).
+    #[must_use]
+    pub fn peek_ts_init(&self) -> Option<UnixNanos> {
+        self.heap.peek().map(|head| head.0.ts_init)
+    }
+
+    /// Returns the `ts_init` of the most recently released data item (zero if none yet).
+    #[must_use]
+    pub fn last_ts_init(&self) -> UnixNanos {
+        self.last_ts_init
+    }
+
+    /// Releases all remaining data with a `ts_init` at or before `ts_now`, in order.
+    ///
+    /// Data timestamped after `ts_now` is held back, so no future data is visible.
+    pub fn next_until(&mut self, ts_now: UnixNanos) -> Vec<Data> {
+        let mut released = Vec::new();
+        while self.peek_ts_init().map_or(false, |ts| ts <= ts_now) {
+            if let Some(data) = self.next() {
+                released.push(data);
+            }
+        }
+        released
+    }
+
+    /// Drops all unreleased data and streams and resets the iterator.
+    pub fn reset(&mut self) {
+        self.streams.clear();
+        self.heap.clear();
+        self.last_ts_init = 0;
+    }
+
+    fn push_head(&mut self, stream_id: usize) {
+        if let Some(stream) = &self.streams[stream_id] {
+            if let Some(data) = stream.data.front() {
+                self.heap.push(Reverse(StreamHead {
+                    ts_init: data.get_ts_init(),
+                    priority: stream.priority,
+                    stream_id,
+                }));
+            }
+        }
+    }
+}
+
+impl Iterator for BacktestDataIterator {
+    type Item = Data;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let head = self.heap.pop()?.0;
+        let data = self.streams[head.stream_id]
+            .as_mut()
+            .and_then(|s| s.data.pop_front())?;
+
+        // Streams are validated on add, so release order can never go backwards
+        debug_assert!(
+            data.get_ts_init() >= self.last_ts_init,
+            "Data released out of `ts_init` order"
+        );
+        self.last_ts_init = data.get_ts_init();
+        self.push_head(head.stream_id);
+        Some(data)
+    }
+}
+
+impl Default for BacktestDataIterator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{
+        data::tick::QuoteTick,
+        identifiers::instrument_id::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    use super::*;
+
+    fn quote(instrument_id: &str, ts_init: UnixNanos) -> Data {
+        Data::Quote(QuoteTick::new(
+            InstrumentId::from_str(instrument_id).unwrap(),
+            Price::from("1.00000"),
+            Price::from("1.00010"),
+            Quantity::from("100000"),
+            Quantity::from("100000"),
+            ts_init,
+            ts_init,
+        ))
+    }
+
+    fn quotes(instrument_id: &str, timestamps: &[UnixNanos]) -> Vec<Data> {
+        timestamps
+            .iter()
+            .map(|ts| quote(instrument_id, *ts))
+            .collect()
+    }
+
+    fn instrument_id(data: &Data) -> String {
+        match data {
+            Data::Quote(q) => q.instrument_id.to_string(),
+            _ => panic!("unexpected data type"),
+        }
+    }
+
+    #[test]
+    fn test_empty_iterator() {
+        let mut iterator = BacktestDataIterator::new();
+        assert!(iterator.is_empty());
+        assert_eq!(iterator.peek_ts_init(), None);
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn test_merges_streams_by_ts_init() {
+        let mut iterator = BacktestDataIterator::new();
+        iterator
+            .add_stream("a", quotes("AUD/USD.SIM", &[1, 3, 5]), 0)
+            .unwrap();
+        iterator
+            .add_stream("b", quotes("EUR/USD.SIM", &[2, 4, 6]), 0)
+            .unwrap();
+
+        assert_eq!(iterator.remaining(), 6);
+        let timestamps: Vec<UnixNanos> = iterator.map(|d| d.get_ts_init()).collect();
+        assert_eq!(timestamps, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_equal_timestamps_released_by_priority_then_insertion() {
+        let mut iterator = BacktestDataIterator::new();
+        iterator
+            .add_stream("low", quotes("AUD/USD.SIM", &[1, 1]), 10)
+            .unwrap();
+        iterator
+            .add_stream("high", quotes("EUR/USD.SIM", &[1]), -1)
+            .unwrap();
+        iterator
+            .add_stream("low2", quotes("GBP/USD.SIM", &[1]), 10)
+            .unwrap();
+
+        let ids: Vec<String> = iterator.map(|d| instrument_id(&d)).collect();
+        assert_eq!(
+            ids,
+            vec!["EUR/USD.SIM", "AUD/USD.SIM", "AUD/USD.SIM", "GBP/USD.SIM"]
+        );
+    }
+
+    #[test]
+    fn test_next_until_holds_back_future_data() {
+        let mut iterator = BacktestDataIterator::new();
+        iterator
+            .add_stream("a", quotes("AUD/USD.SIM", &[1, 2, 5]), 0)
+            .unwrap();
+
+        assert_eq!(iterator.next_until(3).len(), 2);
+        assert_eq!(iterator.last_ts_init(), 2);
+        assert_eq!(iterator.peek_ts_init(), Some(5));
+        assert!(iterator.next_until(4).is_empty());
+        assert_eq!(iterator.next_until(5).len(), 1);
+        assert!(iterator.is_empty());
+    }
+
+    #[test]
+    fn test_add_duplicate_stream_returns_error() {
+        let mut iterator = BacktestDataIterator::new();
+        iterator
+            .add_stream("a", quotes("AUD/USD.SIM", &[1]), 0)
+            .unwrap();
+
+        let result = iterator.add_stream("a", quotes("AUD/USD.SIM", &[2]), 0);
+        assert_eq!(
+            result,
+            Err(DataIteratorError::DuplicateStream("a".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_add_non_monotonic_stream_lists_offending_rows() {
+        let mut iterator = BacktestDataIterator::new();
+        let result = iterator.add_stream("a", quotes("AUD/USD.SIM", &[1, 5, 3, 4, 2]), 0);
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err,
+            DataIteratorError::NonMonotonic {
+                name: "a".to_string(),
+                rows: vec![
+                    NonMonotonicRow {
+                        index: 2,
+                        ts_init: 3,
+                        prev_ts_init: 5,
+                    },
+                    NonMonotonicRow {
+                        index: 4,
+                        ts_init: 2,
+                        prev_ts_init: 4,
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Stream 'a' has non-monotonic `ts_init` at 2 row(s): \
+             row 2 (ts_init=3 < previous 5), row 4 (ts_init=2 < previous 4)"
+        );
+        assert!(iterator.is_empty());
+    }
+
+    #[test]
+    fn test_non_monotonic_error_truncates_long_row_listing() {
+        let timestamps: Vec<UnixNanos> = (0..30).rev().collect();
+        let rows = find_non_monotonic_rows(&quotes("AUD/USD.SIM", &timestamps));
+        let err = DataIteratorError::NonMonotonic {
+            name: "a".to_string(),
+            rows,
+        };
+
+        assert!(err.to_string().ends_with(", ... and 19 more"));
+    }
+
+    #[test]
+    fn test_remove_stream() {
+        let mut iterator = BacktestDataIterator::new();
+        iterator
+            .add_stream("a", quotes("AUD/USD.SIM", &[1, 3]), 0)
+            .unwrap();
+        iterator
+            .add_stream("b", quotes("EUR/USD.SIM", &[2]), 0)
+            .unwrap();
+
+        assert!(iterator.remove_stream("a"));
+        assert!(!iterator.remove_stream("a"));
+        assert_eq!(iterator.stream_names().collect::<Vec<&str>>(), vec!["b"]);
+        let timestamps: Vec<UnixNanos> = iterator.map(|d| d.get_ts_init()).collect();
+        assert_eq!(timestamps, vec![2]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut iterator = BacktestDataIterator::new();
+        iterator
+            .add_stream("a", quotes("AUD/USD.SIM", &[1, 2]), 0)
+            .unwrap();
+        iterator.next();
+        iterator.reset();
+
+        assert!(iterator.is_empty());
+        assert_eq!(iterator.remaining(), 0);
+        assert_eq!(iterator.last_ts_init(), 0);
+        assert!(iterator
+            .add_stream("a", quotes("AUD/USD.SIM", &[1]), 0)
+            .is_ok());
+    }
+}
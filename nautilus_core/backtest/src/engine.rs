@@ -13,10 +13,52 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::ops::{Deref, DerefMut};
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
 
-use nautilus_common::{clock::TestClock, clock_api::TestClock_API, timer::TimeEventHandler};
-use nautilus_core::{cvec::CVec, time::UnixNanos};
+use nautilus_common::{
+    clock::{Clock, TestClock},
+    clock_api::TestClock_API,
+    timer::TimeEventHandler,
+};
+use nautilus_core::{cvec::CVec, time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    commands::TradingCommand,
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+        Data,
+    },
+    enums::{AssetClass, AssetType, BookType, OrderSide, OrderType, TimeInForce},
+    events::order::{OrderDenied, OrderEvent, OrderFilled, OrderInitialized, OrderSubmitted},
+    generators::{client_order_id::ClientOrderIdGenerator, position_id::PositionIdGenerator},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        strategy_id::StrategyId, trader_id::TraderId,
+    },
+    instruments::BaseInstrument,
+    orders::{Order, OrderError},
+    position::Position,
+    python::to_pyvalue_err,
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+use nautilus_trading::{context::TradingContext, strategy::Strategy};
+use pyo3::{
+    prelude::*,
+    types::{PyDict, PyTuple},
+};
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::{
+    data_iterator::{BacktestDataIterator, DataIteratorError},
+    matching_engine::{OrderMatchingEngine, OrderMatchingEngineConfig},
+    models::FillModel,
+};
 
 /// Provides a means of accumulating and draining time event handlers.
 pub struct TimeEventAccumulator {
@@ -55,6 +97,1043 @@ impl Default for TimeEventAccumulator {
     }
 }
 
+/// Represents a request made by a strategy through its [`StrategyContext`].
+#[derive(Clone, Debug)]
+pub enum StrategyRequest {
+    SubmitOrder(OrderInitialized),
+    CancelOrder(ClientOrderId),
+}
+
+struct ContextState {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    ts_now: UnixNanos,
+    order_id_generator: ClientOrderIdGenerator,
+    precisions: HashMap<InstrumentId, (u8, u8)>,
+    net_positions: HashMap<InstrumentId, f64>,
+    requests: Vec<StrategyRequest>,
+    halt_reason: Option<String>,
+}
+
+/// Provides a strategy with the current time and positions of a backtest run, and buffers
+/// its order requests until the [`BacktestEngine`] processes them.
+///
+/// Clones share the same underlying state.
+#[pyclass]
+#[derive(Clone)]
+pub struct StrategyContext {
+    state: Arc<Mutex<ContextState>>,
+}
+
+impl StrategyContext {
+    fn new(trader_id: TraderId, strategy_id: StrategyId) -> Self {
+        let order_id_generator =
+            ClientOrderIdGenerator::new(trader_id.clone(), strategy_id.clone(), 0);
+        Self {
+            state: Arc::new(Mutex::new(ContextState {
+                trader_id,
+                strategy_id,
+                ts_now: 0,
+                order_id_generator,
+                precisions: HashMap::new(),
+                net_positions: HashMap::new(),
+                requests: Vec::new(),
+                halt_reason: None,
+            })),
+        }
+    }
+
+    #[must_use]
+    pub fn strategy_id(&self) -> StrategyId {
+        self.state.lock().unwrap().strategy_id.clone()
+    }
+
+    /// Returns the current backtest time (UNIX nanoseconds).
+    #[must_use]
+    pub fn timestamp_ns(&self) -> UnixNanos {
+        self.state.lock().unwrap().ts_now
+    }
+
+    /// Returns the signed quantity of the strategies open position for `instrument_id`
+    /// (zero if flat).
+    #[must_use]
+    pub fn net_position(&self, instrument_id: &InstrumentId) -> f64 {
+        self.state
+            .lock()
+            .unwrap()
+            .net_positions
+            .get(instrument_id)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Submits a GTC market order, returning its client order ID.
+    pub fn submit_market_order(
+        &self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+    ) -> ClientOrderId {
+        self.submit_order(instrument_id, order_side, OrderType::Market, quantity, None)
+    }
+
+    /// Submits a GTC limit order, returning its client order ID.
+    pub fn submit_limit_order(
+        &self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+    ) -> ClientOrderId {
+        self.submit_order(
+            instrument_id,
+            order_side,
+            OrderType::Limit,
+            quantity,
+            Some(price),
+        )
+    }
+
+    /// Cancels the working order with the given `client_order_id`.
+    pub fn cancel_order(&self, client_order_id: ClientOrderId) {
+        self.state
+            .lock()
+            .unwrap()
+            .requests
+            .push(StrategyRequest::CancelOrder(client_order_id));
+    }
+
+    /// Submits the order initialized by `order`.
+    pub fn submit(&self, order: OrderInitialized) {
+        self.state
+            .lock()
+            .unwrap()
+            .requests
+            .push(StrategyRequest::SubmitOrder(order));
+    }
+
+    /// Halts the backtest run after the current callback returns, with the given `reason`.
+    pub fn halt(&self, reason: &str) {
+        let mut state = self.state.lock().unwrap();
+        if state.halt_reason.is_none() {
+            state.halt_reason = Some(reason.to_string());
+        }
+    }
+
+    fn submit_order(
+        &self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        order_type: OrderType,
+        quantity: Quantity,
+        price: Option<Price>,
+    ) -> ClientOrderId {
+        let mut state = self.state.lock().unwrap();
+        let ts_now = state.ts_now;
+        let client_order_id = state.order_id_generator.generate(ts_now);
+        let init = OrderInitialized {
+            trader_id: state.trader_id.clone(),
+            strategy_id: state.strategy_id.clone(),
+            instrument_id,
+            client_order_id: client_order_id.clone(),
+            order_side,
+            order_type,
+            quantity,
+            price,
+            time_in_force: TimeInForce::Gtc,
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+            ..Default::default()
+        };
+        state.requests.push(StrategyRequest::SubmitOrder(init));
+        client_order_id
+    }
+
+    fn precisions(&self, instrument_id: &InstrumentId) -> Option<(u8, u8)> {
+        self.state
+            .lock()
+            .unwrap()
+            .precisions
+            .get(instrument_id)
+            .copied()
+    }
+
+    fn set_time(&self, ts_now: UnixNanos) {
+        self.state.lock().unwrap().ts_now = ts_now;
+    }
+
+    fn set_precisions(&self, instrument_id: InstrumentId, price_precision: u8, size_precision: u8) {
+        self.state
+            .lock()
+            .unwrap()
+            .precisions
+            .insert(instrument_id, (price_precision, size_precision));
+    }
+
+    fn set_net_position(&self, instrument_id: InstrumentId, signed_qty: f64) {
+        self.state
+            .lock()
+            .unwrap()
+            .net_positions
+            .insert(instrument_id, signed_qty);
+    }
+
+    fn drain_requests(&self) -> Vec<StrategyRequest> {
+        self.state.lock().unwrap().requests.drain(..).collect()
+    }
+
+    fn take_halt_reason(&self) -> Option<String> {
+        self.state.lock().unwrap().halt_reason.take()
+    }
+
+    fn reset(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.ts_now = 0;
+        state.order_id_generator.reset();
+        state.net_positions.clear();
+        state.requests.clear();
+        state.halt_reason = None;
+    }
+}
+
+/// The callbacks of a strategy run by the [`BacktestEngine`].
+///
+/// Orders are submitted through the given [`StrategyContext`] and processed by the engine
+/// once the callback returns.
+pub trait BacktestStrategy {
+    fn on_start(&mut self, _ctx: &StrategyContext) {}
+    fn on_quote_tick(&mut self, _ctx: &StrategyContext, _tick: &QuoteTick) {}
+    fn on_trade_tick(&mut self, _ctx: &StrategyContext, _tick: &TradeTick) {}
+    fn on_bar(&mut self, _ctx: &StrategyContext, _bar: &Bar) {}
+    fn on_order_event(&mut self, _ctx: &StrategyContext, _event: &OrderEvent) {}
+    fn on_stop(&mut self, _ctx: &StrategyContext) {}
+}
+
+/// Runs a pure Rust [`Strategy`] as a [`BacktestStrategy`], by routing the commands sent
+/// through its [`TradingContext`] to the [`StrategyContext`].
+struct TradingStrategyAdapter<S: Strategy> {
+    strategy: S,
+    ctx: TradingContext,
+}
+
+impl<S: Strategy> TradingStrategyAdapter<S> {
+    fn forward(&mut self, ctx: &StrategyContext, f: impl FnOnce(&mut S, &mut TradingContext)) {
+        self.ctx.set_time(ctx.timestamp_ns());
+        f(&mut self.strategy, &mut self.ctx);
+        for command in self.ctx.drain_commands() {
+            match command {
+                TradingCommand::SubmitOrder(command) => ctx.submit(command.order),
+                TradingCommand::CancelOrder(command) => ctx.cancel_order(command.client_order_id),
+                command => {
+                    ctx.halt(&format!("{} commands are not supported", command.name()));
+                }
+            }
+        }
+    }
+}
+
+impl<S: Strategy> BacktestStrategy for TradingStrategyAdapter<S> {
+    fn on_start(&mut self, ctx: &StrategyContext) {
+        self.forward(ctx, |strategy, trading_ctx| strategy.on_start(trading_ctx));
+    }
+
+    fn on_quote_tick(&mut self, ctx: &StrategyContext, tick: &QuoteTick) {
+        self.forward(ctx, |strategy, trading_ctx| {
+            strategy.on_quote_tick(trading_ctx, tick);
+        });
+    }
+
+    fn on_trade_tick(&mut self, ctx: &StrategyContext, tick: &TradeTick) {
+        self.forward(ctx, |strategy, trading_ctx| {
+            strategy.on_trade_tick(trading_ctx, tick);
+        });
+    }
+
+    fn on_bar(&mut self, ctx: &StrategyContext, bar: &Bar) {
+        self.forward(ctx, |strategy, trading_ctx| {
+            strategy.on_bar(trading_ctx, bar)
+        });
+    }
+
+    fn on_order_event(&mut self, ctx: &StrategyContext, event: &OrderEvent) {
+        if let OrderEvent::OrderFilled(fill) | OrderEvent::OrderPartiallyFilled(fill) = event {
+            self.ctx.set_net_position(
+                fill.instrument_id.clone(),
+                ctx.net_position(&fill.instrument_id),
+            );
+        }
+        self.forward(ctx, |strategy, trading_ctx| {
+            strategy.on_event(trading_ctx, event)
+        });
+    }
+
+    fn on_stop(&mut self, ctx: &StrategyContext) {
+        self.forward(ctx, |strategy, trading_ctx| strategy.on_stop(trading_ctx));
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum BacktestEngineError {
+    #[error("Instrument {0} has already been added")]
+    DuplicateInstrument(InstrumentId),
+    #[error("Instrument {0} has not been added")]
+    UnknownInstrument(InstrumentId),
+    #[error("Strategy {0} has already been added")]
+    DuplicateStrategy(StrategyId),
+    #[error(transparent)]
+    DataIterator(#[from] DataIteratorError),
+    #[error(transparent)]
+    Order(#[from] OrderError),
+    #[error("Strategy {strategy_id} halted the backtest: {reason}")]
+    StrategyHalted {
+        strategy_id: StrategyId,
+        reason: String,
+    },
+}
+
+/// Provides a backtest engine which runs strategies over historical data entirely on the
+/// Rust event loop.
+///
+/// Each data point advances the clock (firing any timers), is processed by the matching
+/// engine for its instrument, then dispatched to every strategy. Fills are applied to
+/// netting positions keyed by instrument and strategy.
+#[pyclass]
+pub struct BacktestEngine {
+    pub trader_id: TraderId,
+    pub account_id: AccountId,
+    clock: TestClock,
+    iterator: BacktestDataIterator,
+    instruments: HashMap<InstrumentId, BaseInstrument>,
+    matching_engines: Vec<OrderMatchingEngine<BaseInstrument>>,
+    engine_index: HashMap<InstrumentId, usize>,
+    strategies: Vec<(StrategyContext, Box<dyn BacktestStrategy>)>,
+    order_owners: HashMap<ClientOrderId, (InstrumentId, usize)>,
+    position_id_generator: PositionIdGenerator,
+    positions: HashMap<(InstrumentId, StrategyId), Position>,
+    closed_positions: Vec<Position>,
+    fills: Vec<OrderFilled>,
+    iteration: u64,
+}
+
+// Note: Intended to be used on a single python thread
+unsafe impl Send for BacktestEngine {}
+
+impl BacktestEngine {
+    /// Initializes a new `BacktestEngine` instance.
+    #[must_use]
+    pub fn new(trader_id: TraderId, account_id: AccountId) -> Self {
+        Self {
+            position_id_generator: PositionIdGenerator::new(trader_id.clone()),
+            trader_id,
+            account_id,
+            clock: TestClock::new(),
+            iterator: BacktestDataIterator::new(),
+            instruments: HashMap::new(),
+            matching_engines: Vec::new(),
+            engine_index: HashMap::new(),
+            strategies: Vec::new(),
+            order_owners: HashMap::new(),
+            positions: HashMap::new(),
+            closed_positions: Vec::new(),
+            fills: Vec::new(),
+            iteration: 0,
+        }
+    }
+
+    /// Returns a mutable reference to the engines clock (for setting timers).
+    pub fn clock_mut(&mut self) -> &mut TestClock {
+        &mut self.clock
+    }
+
+    /// Returns the number of data points processed since the last reset.
+    #[must_use]
+    pub fn iteration(&self) -> u64 {
+        self.iteration
+    }
+
+    /// Returns all fills since the last reset, in the order they occurred.
+    #[must_use]
+    pub fn fills(&self) -> &[OrderFilled] {
+        &self.fills
+    }
+
+    /// Returns the open position for the given `instrument_id` and `strategy_id` (if any).
+    #[must_use]
+    pub fn position(
+        &self,
+        instrument_id: &InstrumentId,
+        strategy_id: &StrategyId,
+    ) -> Option<&Position> {
+        self.positions
+            .get(&(instrument_id.clone(), strategy_id.clone()))
+    }
+
+    /// Returns all closed positions since the last reset.
+    #[must_use]
+    pub fn closed_positions(&self) -> &[Position] {
+        &self.closed_positions
+    }
+
+    /// Returns the realized PnL summed over all open and closed positions for the given
+    /// `instrument_id` (if any position has realized PnL).
+    #[must_use]
+    pub fn realized_pnl(&self, instrument_id: &InstrumentId) -> Option<Money> {
+        self.positions
+            .values()
+            .chain(self.closed_positions.iter())
+            .filter(|position| &position.instrument_id == instrument_id)
+            .filter_map(|position| position.realized_pnl.clone())
+            .reduce(|total, pnl| total + pnl)
+    }
+
+    /// Adds the given `instrument` along with a matching engine to simulate its venue.
+    ///
+    /// # Errors
+    ///
+    /// - If the instrument has already been added.
+    pub fn add_instrument(
+        &mut self,
+        instrument: BaseInstrument,
+        book_type: BookType,
+        config: OrderMatchingEngineConfig,
+        fill_model: FillModel,
+    ) -> Result<(), BacktestEngineError> {
+        if self.instruments.contains_key(&instrument.id) {
+            return Err(BacktestEngineError::DuplicateInstrument(
+                instrument.id.clone(),
+            ));
+        }
+
+        for (ctx, _) in &self.strategies {
+            ctx.set_precisions(
+                instrument.id.clone(),
+                instrument.price_precision,
+                instrument.size_precision,
+            );
+        }
+        self.engine_index
+            .insert(instrument.id.clone(), self.matching_engines.len());
+        self.matching_engines.push(OrderMatchingEngine::new(
+            instrument.clone(),
+            book_type,
+            self.account_id.clone(),
+            config,
+            fill_model,
+        ));
+        self.instruments.insert(instrument.id.clone(), instrument);
+        Ok(())
+    }
+
+    /// Adds the given `data` as a named stream for the backtest.
+    ///
+    /// # Errors
+    ///
+    /// - If any data is for an instrument which has not been added.
+    /// - If the stream cannot be added to the data iterator.
+    pub fn add_data(&mut self, name: &str, data: Vec<Data>) -> Result<(), BacktestEngineError> {
+        if let Some(instrument_id) = data
+            .iter()
+            .map(data_instrument_id)
+            .find(|id| !self.engine_index.contains_key(id))
+        {
+            return Err(BacktestEngineError::UnknownInstrument(
+                instrument_id.clone(),
+            ));
+        }
+
+        self.iterator.add_stream(name, data, 0)?;
+        Ok(())
+    }
+
+    /// Adds the given `strategy` to be run with the given `strategy_id`, returning its context.
+    ///
+    /// # Errors
+    ///
+    /// - If a strategy with the same ID has already been added.
+    pub fn add_strategy(
+        &mut self,
+        strategy_id: StrategyId,
+        strategy: Box<dyn BacktestStrategy>,
+    ) -> Result<StrategyContext, BacktestEngineError> {
+        if self
+            .strategies
+            .iter()
+            .any(|(ctx, _)| ctx.strategy_id() == strategy_id)
+        {
+            return Err(BacktestEngineError::DuplicateStrategy(strategy_id));
+        }
+
+        let ctx = StrategyContext::new(self.trader_id.clone(), strategy_id);
+        for instrument in self.instruments.values() {
+            ctx.set_precisions(
+                instrument.id.clone(),
+                instrument.price_precision,
+                instrument.size_precision,
+            );
+        }
+        self.strategies.push((ctx.clone(), strategy));
+        Ok(ctx)
+    }
+
+    /// Adds the given pure Rust `strategy` to be run with the given `strategy_id`, returning
+    /// its backtest context.
+    ///
+    /// # Errors
+    ///
+    /// - If a strategy with the same ID has already been added.
+    pub fn add_trading_strategy<S: Strategy + 'static>(
+        &mut self,
+        strategy_id: StrategyId,
+        strategy: S,
+    ) -> Result<StrategyContext, BacktestEngineError> {
+        let adapter = TradingStrategyAdapter {
+            strategy,
+            ctx: TradingContext::new(self.trader_id.clone(), strategy_id.clone()),
+        };
+        self.add_strategy(strategy_id, Box::new(adapter))
+    }
+
+    /// Runs the backtest over all remaining data, up to and including `end_ns` (if given).
+    ///
+    /// # Errors
+    ///
+    /// - If a strategy halts the run (the strategies are not stopped in this case).
+    pub fn run(&mut self, end_ns: Option<UnixNanos>) -> Result<(), BacktestEngineError> {
+        let ts_start = self
+            .iterator
+            .peek_ts_init()
+            .unwrap_or(self.clock.timestamp_ns());
+        self.set_time(ts_start);
+        self.dispatch_all(|strategy, ctx| strategy.on_start(ctx))?;
+        self.process_requests()?;
+
+        while let Some(ts_next) = self.iterator.peek_ts_init() {
+            if end_ns.map_or(false, |end_ns| ts_next > end_ns) {
+                break;
+            }
+            let data = self.iterator.next().expect("data was peeked");
+
+            let events = self.clock.advance_time(ts_next, true);
+            self.clock.handle_events(events);
+            self.set_time(ts_next);
+
+            let index = self.engine_index[data_instrument_id(&data)];
+            let engine = &mut self.matching_engines[index];
+            match &data {
+                Data::Delta(delta) => engine.process_order_book_delta(delta.clone()),
+                Data::Quote(tick) => engine.process_quote_tick(tick),
+                Data::Trade(tick) => engine.process_trade_tick(tick),
+                Data::Bar(bar) => engine.process_bar(bar),
+            }
+            self.process_engine_events()?;
+
+            match &data {
+                Data::Delta(_) => {}
+                Data::Quote(tick) => {
+                    self.dispatch_all(|strategy, ctx| strategy.on_quote_tick(ctx, tick))?;
+                }
+                Data::Trade(tick) => {
+                    self.dispatch_all(|strategy, ctx| strategy.on_trade_tick(ctx, tick))?;
+                }
+                Data::Bar(bar) => self.dispatch_all(|strategy, ctx| strategy.on_bar(ctx, bar))?,
+            }
+            self.process_requests()?;
+            self.iteration += 1;
+        }
+
+        if let Some(end_ns) = end_ns {
+            if end_ns > self.clock.timestamp_ns() {
+                let events = self.clock.advance_time(end_ns, true);
+                self.clock.handle_events(events);
+                self.set_time(end_ns);
+            }
+        }
+        self.dispatch_all(|strategy, ctx| strategy.on_stop(ctx))
+    }
+
+    /// Resets the engine, strategy contexts and data streams so the backtest can be rerun.
+    ///
+    /// Strategies keep any state of their own.
+    pub fn reset(&mut self) {
+        self.clock = TestClock::new();
+        self.iterator.reset();
+        for engine in &mut self.matching_engines {
+            engine.reset();
+        }
+        for (ctx, _) in &self.strategies {
+            ctx.reset();
+        }
+        self.order_owners.clear();
+        self.position_id_generator.reset();
+        self.positions.clear();
+        self.closed_positions.clear();
+        self.fills.clear();
+        self.iteration = 0;
+    }
+
+    fn set_time(&mut self, ts_now: UnixNanos) {
+        for (ctx, _) in &self.strategies {
+            ctx.set_time(ts_now);
+        }
+    }
+
+    fn dispatch(
+        &mut self,
+        index: usize,
+        f: impl FnOnce(&mut dyn BacktestStrategy, &StrategyContext),
+    ) -> Result<(), BacktestEngineError> {
+        let (ctx, strategy) = &mut self.strategies[index];
+        f(strategy.as_mut(), ctx);
+        match ctx.take_halt_reason() {
+            Some(reason) => Err(BacktestEngineError::StrategyHalted {
+                strategy_id: ctx.strategy_id(),
+                reason,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn dispatch_all(
+        &mut self,
+        f: impl Fn(&mut dyn BacktestStrategy, &StrategyContext),
+    ) -> Result<(), BacktestEngineError> {
+        for index in 0..self.strategies.len() {
+            self.dispatch(index, &f)?;
+        }
+        Ok(())
+    }
+
+    fn process_requests(&mut self) -> Result<(), BacktestEngineError> {
+        // Callbacks for the resulting order events may make further requests
+        loop {
+            let requests: Vec<(usize, StrategyRequest)> = self
+                .strategies
+                .iter()
+                .enumerate()
+                .flat_map(|(index, (ctx, _))| {
+                    ctx.drain_requests()
+                        .into_iter()
+                        .map(move |request| (index, request))
+                })
+                .collect();
+            if requests.is_empty() {
+                return Ok(());
+            }
+
+            let ts_now = self.clock.timestamp_ns();
+            for (index, request) in requests {
+                match request {
+                    StrategyRequest::SubmitOrder(init) => self.submit_order(index, init, ts_now)?,
+                    StrategyRequest::CancelOrder(client_order_id) => {
+                        if let Some((instrument_id, _)) = self.order_owners.get(&client_order_id) {
+                            let engine_index = self.engine_index[instrument_id];
+                            self.matching_engines[engine_index]
+                                .cancel_order(&client_order_id, ts_now);
+                        }
+                    }
+                }
+                self.process_engine_events()?;
+            }
+        }
+    }
+
+    fn submit_order(
+        &mut self,
+        index: usize,
+        init: OrderInitialized,
+        ts_now: UnixNanos,
+    ) -> Result<(), BacktestEngineError> {
+        let engine_index = match self.engine_index.get(&init.instrument_id) {
+            Some(engine_index) => *engine_index,
+            None => {
+                let denied = OrderDenied {
+                    trader_id: init.trader_id.clone(),
+                    strategy_id: init.strategy_id.clone(),
+                    instrument_id: init.instrument_id.clone(),
+                    client_order_id: init.client_order_id.clone(),
+                    reason: Box::new(format!("Instrument {} not found", init.instrument_id)),
+                    event_id: UUID4::new(),
+                    ts_event: ts_now,
+                    ts_init: ts_now,
+                };
+                let event = OrderEvent::OrderDenied(denied);
+                return self.dispatch(index, |strategy, ctx| strategy.on_order_event(ctx, &event));
+            }
+        };
+
+        let mut order = Order::from(init);
+        let submitted = OrderSubmitted {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            account_id: self.account_id.clone(),
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+        };
+        order.apply(OrderEvent::OrderSubmitted(submitted))?;
+
+        self.order_owners.insert(
+            order.client_order_id.clone(),
+            (order.instrument_id.clone(), index),
+        );
+        self.matching_engines[engine_index].process_order(order, ts_now);
+        Ok(())
+    }
+
+    fn process_engine_events(&mut self) -> Result<(), BacktestEngineError> {
+        if let Some(e) = self
+            .matching_engines
+            .iter_mut()
+            .flat_map(|engine| engine.drain_errors())
+            .next()
+        {
+            return Err(e.into());
+        }
+
+        let events: Vec<OrderEvent> = self
+            .matching_engines
+            .iter_mut()
+            .flat_map(|engine| engine.drain_events())
+            .collect();
+
+        for mut event in events {
+            if let OrderEvent::OrderFilled(fill) | OrderEvent::OrderPartiallyFilled(fill) =
+                &mut event
+            {
+                self.apply_fill(fill);
+            }
+
+            if let Some((_, index)) = self.order_owners.get(event.client_order_id()) {
+                let index = *index;
+                self.dispatch(index, |strategy, ctx| strategy.on_order_event(ctx, &event))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_fill(&mut self, fill: &mut OrderFilled) {
+        let key = (fill.instrument_id.clone(), fill.strategy_id.clone());
+        let signed_qty = match self.positions.get_mut(&key) {
+            Some(position) => {
+                fill.position_id = Some(position.id.clone());
+                position.apply(fill.clone());
+                position.signed_qty
+            }
+            None => {
+                let position_id =
+                    self.position_id_generator
+                        .generate(&fill.strategy_id, false, fill.ts_event);
+                fill.position_id = Some(position_id);
+                let position = Position::new(&self.instruments[&fill.instrument_id], fill.clone());
+                let signed_qty = position.signed_qty;
+                self.positions.insert(key.clone(), position);
+                signed_qty
+            }
+        };
+
+        if self.positions[&key].is_closed() {
+            let position = self.positions.remove(&key).expect("position exists");
+            self.closed_positions.push(position);
+        }
+        if let Some((_, index)) = self.order_owners.get(&fill.client_order_id) {
+            self.strategies[*index]
+                .0
+                .set_net_position(fill.instrument_id.clone(), signed_qty);
+        }
+        self.fills.push(fill.clone());
+    }
+}
+
+fn data_instrument_id(data: &Data) -> &InstrumentId {
+    match data {
+        Data::Delta(delta) => &delta.instrument_id,
+        Data::Quote(tick) => &tick.instrument_id,
+        Data::Trade(tick) => &tick.instrument_id,
+        Data::Bar(bar) => &bar.bar_type.instrument_id,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+
+/// Wraps a Python strategy object, calling any of its `on_start`, `on_quote_tick`,
+/// `on_trade_tick`, `on_bar`, `on_order_filled` and `on_stop` methods which are defined.
+///
+/// Exceptions raised by a callback halt the backtest.
+struct PyStrategy(PyObject);
+
+impl PyStrategy {
+    fn call(
+        &self,
+        ctx: &StrategyContext,
+        name: &str,
+        arg: impl FnOnce(Python) -> Option<PyObject>,
+    ) {
+        Python::with_gil(|py| {
+            let result = self.0.as_ref(py).hasattr(name).and_then(|has_method| {
+                if !has_method {
+                    return Ok(());
+                }
+                let mut args = vec![ctx.clone().into_py(py)];
+                args.extend(arg(py));
+                self.0
+                    .call_method1(py, name, PyTuple::new(py, args))
+                    .map(|_| ())
+            });
+            if let Err(e) = result {
+                ctx.halt(&format!("{name}: {e}"));
+            }
+        });
+    }
+}
+
+impl BacktestStrategy for PyStrategy {
+    fn on_start(&mut self, ctx: &StrategyContext) {
+        self.call(ctx, "on_start", |_| None);
+    }
+
+    fn on_quote_tick(&mut self, ctx: &StrategyContext, tick: &QuoteTick) {
+        self.call(ctx, "on_quote_tick", |py| Some(tick.clone().into_py(py)));
+    }
+
+    fn on_trade_tick(&mut self, ctx: &StrategyContext, tick: &TradeTick) {
+        self.call(ctx, "on_trade_tick", |py| Some(tick.clone().into_py(py)));
+    }
+
+    fn on_bar(&mut self, ctx: &StrategyContext, bar: &Bar) {
+        self.call(ctx, "on_bar", |py| Some(bar.clone().into_py(py)));
+    }
+
+    fn on_order_event(&mut self, ctx: &StrategyContext, event: &OrderEvent) {
+        if let OrderEvent::OrderFilled(fill) | OrderEvent::OrderPartiallyFilled(fill) = event {
+            self.call(ctx, "on_order_filled", |py| {
+                let dict = PyDict::new(py);
+                let items: [(&str, PyObject); 7] = [
+                    ("client_order_id", fill.client_order_id.clone().into_py(py)),
+                    ("instrument_id", fill.instrument_id.clone().into_py(py)),
+                    ("order_side", fill.order_side.into_py(py)),
+                    ("last_qty", fill.last_qty.as_f64().into_py(py)),
+                    ("last_px", fill.last_px.as_f64().into_py(py)),
+                    ("commission", fill.commission.as_f64().into_py(py)),
+                    ("ts_event", fill.ts_event.into_py(py)),
+                ];
+                for (key, value) in items {
+                    dict.set_item(key, value).expect("key is a valid str");
+                }
+                Some(dict.into_py(py))
+            });
+        }
+    }
+
+    fn on_stop(&mut self, ctx: &StrategyContext) {
+        self.call(ctx, "on_stop", |_| None);
+    }
+}
+
+#[pymethods]
+impl StrategyContext {
+    #[getter]
+    #[pyo3(name = "strategy_id")]
+    fn strategy_id_py(&self) -> String {
+        self.strategy_id().to_string()
+    }
+
+    #[pyo3(name = "timestamp_ns")]
+    fn timestamp_ns_py(&self) -> UnixNanos {
+        self.timestamp_ns()
+    }
+
+    #[pyo3(name = "net_position")]
+    fn net_position_py(&self, instrument_id: InstrumentId) -> f64 {
+        self.net_position(&instrument_id)
+    }
+
+    #[pyo3(name = "submit_market_order")]
+    fn submit_market_order_py(
+        &self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: f64,
+    ) -> PyResult<ClientOrderId> {
+        let (_, size_precision) = self.precisions_py(&instrument_id)?;
+        Ok(self.submit_market_order(
+            instrument_id,
+            order_side,
+            Quantity::new(quantity, size_precision),
+        ))
+    }
+
+    #[pyo3(name = "submit_limit_order")]
+    fn submit_limit_order_py(
+        &self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: f64,
+        price: f64,
+    ) -> PyResult<ClientOrderId> {
+        let (price_precision, size_precision) = self.precisions_py(&instrument_id)?;
+        Ok(self.submit_limit_order(
+            instrument_id,
+            order_side,
+            Quantity::new(quantity, size_precision),
+            Price::new(price, price_precision),
+        ))
+    }
+
+    #[pyo3(name = "cancel_order")]
+    fn cancel_order_py(&self, client_order_id: ClientOrderId) {
+        self.cancel_order(client_order_id);
+    }
+}
+
+impl StrategyContext {
+    fn precisions_py(&self, instrument_id: &InstrumentId) -> PyResult<(u8, u8)> {
+        self.precisions(instrument_id).ok_or_else(|| {
+            to_pyvalue_err(BacktestEngineError::UnknownInstrument(
+                instrument_id.clone(),
+            ))
+        })
+    }
+}
+
+#[pymethods]
+impl BacktestEngine {
+    #[new]
+    #[pyo3(signature = (trader_id="BACKTESTER-001", account_id="SIM-001"))]
+    fn py_new(trader_id: &str, account_id: &str) -> Self {
+        Self::new(TraderId::new(trader_id), AccountId::new(account_id))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "add_instrument")]
+    #[pyo3(signature = (
+        instrument_id,
+        price_precision,
+        size_precision,
+        quote_currency="USD",
+        maker_fee="0",
+        taker_fee="0",
+        asset_class=AssetClass::FX,
+        book_type=BookType::L1_TBBO,
+    ))]
+    fn add_instrument_py(
+        &mut self,
+        instrument_id: InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+        quote_currency: &str,
+        maker_fee: &str,
+        taker_fee: &str,
+        asset_class: AssetClass,
+        book_type: BookType,
+    ) -> PyResult<()> {
+        let quote_currency = Currency::from_str(quote_currency).map_err(to_pyvalue_err)?;
+        let maker_fee = Decimal::from_str(maker_fee).map_err(to_pyvalue_err)?;
+        let taker_fee = Decimal::from_str(taker_fee).map_err(to_pyvalue_err)?;
+        let instrument = BaseInstrument {
+            native_symbol: instrument_id.symbol.clone(),
+            id: instrument_id,
+            asset_class,
+            asset_type: AssetType::Spot,
+            quote_currency: quote_currency.clone(),
+            base_currency: None,
+            cost_currency: quote_currency,
+            is_inverse: false,
+            price_precision,
+            size_precision,
+            price_increment: Price::new(10_f64.powi(-i32::from(price_precision)), price_precision),
+            size_increment: Quantity::new(10_f64.powi(-i32::from(size_precision)), size_precision),
+            multiplier: Quantity::new(1.0, 0),
+            lot_size: None,
+            max_quantity: None,
+            min_quantity: None,
+            max_price: None,
+            min_price: None,
+            margin_init: Decimal::ZERO,
+            margin_maint: Decimal::ZERO,
+            maker_fee,
+            taker_fee,
+        };
+        self.add_instrument(
+            instrument,
+            book_type,
+            OrderMatchingEngineConfig::default(),
+            FillModel::default(),
+        )
+        .map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "add_quote_ticks")]
+    fn add_quote_ticks_py(&mut self, name: &str, ticks: Vec<QuoteTick>) -> PyResult<()> {
+        let data = ticks.into_iter().map(Data::Quote).collect();
+        self.add_data(name, data).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "add_trade_ticks")]
+    fn add_trade_ticks_py(&mut self, name: &str, ticks: Vec<TradeTick>) -> PyResult<()> {
+        let data = ticks.into_iter().map(Data::Trade).collect();
+        self.add_data(name, data).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "add_bars")]
+    fn add_bars_py(&mut self, name: &str, bars: Vec<Bar>) -> PyResult<()> {
+        let data = bars.into_iter().map(Data::Bar).collect();
+        self.add_data(name, data).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "add_strategy")]
+    #[pyo3(signature = (strategy, strategy_id=None))]
+    fn add_strategy_py(
+        &mut self,
+        strategy: PyObject,
+        strategy_id: Option<&str>,
+    ) -> PyResult<StrategyContext> {
+        let strategy_id = match strategy_id {
+            Some(strategy_id) => StrategyId::new(strategy_id),
+            None => StrategyId::new(&format!("S-{:03}", self.strategies.len() + 1)),
+        };
+        self.add_strategy(strategy_id, Box::new(PyStrategy(strategy)))
+            .map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "run")]
+    #[pyo3(signature = (end=None))]
+    fn run_py(&mut self, end: Option<UnixNanos>) -> PyResult<()> {
+        self.run(end).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    #[getter]
+    #[pyo3(name = "iteration")]
+    fn iteration_py(&self) -> u64 {
+        self.iteration
+    }
+
+    #[pyo3(name = "fill_count")]
+    fn fill_count_py(&self) -> usize {
+        self.fills.len()
+    }
+
+    #[pyo3(name = "net_position")]
+    fn net_position_py(&self, instrument_id: InstrumentId, strategy_id: &str) -> f64 {
+        self.position(&instrument_id, &StrategyId::new(strategy_id))
+            .map_or(0.0, |position| position.signed_qty)
+    }
+
+    #[pyo3(name = "realized_pnl")]
+    fn realized_pnl_py(&self, instrument_id: InstrumentId) -> Option<f64> {
+        self.realized_pnl(&instrument_id).map(|pnl| pnl.as_f64())
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
@@ -106,8 +1185,11 @@ pub extern "C" fn time_event_accumulator_drain(accumulator: &mut TimeEventAccumu
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
     use nautilus_common::timer::TimeEvent;
-    use nautilus_core::uuid::UUID4;
+    use nautilus_model::currencies::USD;
+    use nautilus_test_kit::instruments;
     use pyo3::{types::PyList, AsPyPointer, Py, Python};
 
     use super::*;
@@ -158,4 +1240,276 @@ mod tests {
             assert_eq!(drained_handlers[2].event.ts_event, time_event2.ts_event);
         });
     }
+
+    fn audusd_sim() -> BaseInstrument {
+        BaseInstrument {
+            maker_fee: Decimal::ZERO,
+            taker_fee: Decimal::ZERO,
+            ..instruments::audusd_sim()
+        }
+    }
+
+    fn quote(bid: &str, ask: &str, ts: UnixNanos) -> Data {
+        Data::Quote(QuoteTick {
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            bid: Price::from(bid),
+            ask: Price::from(ask),
+            bid_size: Quantity::from("1000000"),
+            ask_size: Quantity::from("1000000"),
+            ts_event: ts,
+            ts_init: ts,
+        })
+    }
+
+    fn backtest_engine() -> BacktestEngine {
+        let mut engine =
+            BacktestEngine::new(TraderId::new("TRADER-001"), AccountId::new("SIM-001"));
+        engine
+            .add_instrument(
+                audusd_sim(),
+                BookType::L1_TBBO,
+                OrderMatchingEngineConfig::default(),
+                FillModel::default(),
+            )
+            .unwrap();
+        engine
+            .add_data(
+                "quotes",
+                vec![
+                    quote("0.80000", "0.80010", 1),
+                    quote("0.80100", "0.80110", 2),
+                    quote("0.80050", "0.80060", 3),
+                ],
+            )
+            .unwrap();
+        engine
+    }
+
+    /// Buys on the first quote then closes the position on the second.
+    struct RoundTrip {
+        log: Rc<RefCell<Vec<String>>>,
+        ticks: usize,
+    }
+
+    impl BacktestStrategy for RoundTrip {
+        fn on_start(&mut self, _ctx: &StrategyContext) {
+            self.log.borrow_mut().push("start".to_string());
+        }
+
+        fn on_quote_tick(&mut self, ctx: &StrategyContext, tick: &QuoteTick) {
+            self.ticks += 1;
+            let side = match self.ticks {
+                1 => OrderSide::Buy,
+                2 => OrderSide::Sell,
+                _ => return,
+            };
+            ctx.submit_market_order(tick.instrument_id.clone(), side, Quantity::from("100000"));
+        }
+
+        fn on_order_event(&mut self, ctx: &StrategyContext, event: &OrderEvent) {
+            if let OrderEvent::OrderFilled(fill) = event {
+                let net = ctx.net_position(&fill.instrument_id);
+                self.log
+                    .borrow_mut()
+                    .push(format!("{} {} {net}", fill.order_side, fill.last_px));
+            }
+        }
+
+        fn on_stop(&mut self, ctx: &StrategyContext) {
+            self.log
+                .borrow_mut()
+                .push(format!("stop {}", ctx.timestamp_ns()));
+        }
+    }
+
+    struct Halting;
+
+    impl BacktestStrategy for Halting {
+        fn on_quote_tick(&mut self, ctx: &StrategyContext, _tick: &QuoteTick) {
+            ctx.halt("boom");
+        }
+    }
+
+    #[test]
+    fn test_backtest_engine_round_trip() {
+        let mut engine = backtest_engine();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let strategy = RoundTrip {
+            log: log.clone(),
+            ticks: 0,
+        };
+        engine
+            .add_strategy(StrategyId::new("S-001"), Box::new(strategy))
+            .unwrap();
+
+        engine.run(None).unwrap();
+
+        let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        assert_eq!(engine.iteration(), 3);
+        assert_eq!(engine.fills().len(), 2);
+        assert!(engine
+            .position(&instrument_id, &StrategyId::new("S-001"))
+            .is_none());
+        assert_eq!(engine.closed_positions().len(), 1);
+        assert_eq!(
+            engine.realized_pnl(&instrument_id),
+            Some(Money::new(90.0, USD.clone()))
+        );
+        assert_eq!(
+            *log.borrow(),
+            vec!["start", "BUY 0.80010 100000", "SELL 0.80100 0", "stop 3"]
+        );
+    }
+
+    #[test]
+    fn test_backtest_engine_run_until_end() {
+        let mut engine = backtest_engine();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let strategy = RoundTrip {
+            log: log.clone(),
+            ticks: 0,
+        };
+        let ctx = engine
+            .add_strategy(StrategyId::new("S-001"), Box::new(strategy))
+            .unwrap();
+
+        engine.run(Some(1)).unwrap();
+
+        let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        assert_eq!(engine.iteration(), 1);
+        assert_eq!(ctx.net_position(&instrument_id), 100_000.0);
+        assert_eq!(
+            engine
+                .position(&instrument_id, &StrategyId::new("S-001"))
+                .map(|position| position.signed_qty),
+            Some(100_000.0)
+        );
+    }
+
+    /// Places a limit buy below the market on start, then cancels it on the first quote.
+    struct LimitThenCancel {
+        client_order_id: Option<ClientOrderId>,
+        events: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl Strategy for LimitThenCancel {
+        fn on_start(&mut self, ctx: &mut TradingContext) {
+            let ts_now = ctx.timestamp_ns();
+            let order = ctx.order_factory().limit(
+                InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+                OrderSide::Buy,
+                Quantity::from("100000"),
+                Price::from("0.79000"),
+                TimeInForce::Gtc,
+                None,
+                false,
+                false,
+                false,
+                None,
+                None,
+                None,
+                ts_now,
+            );
+            self.client_order_id = Some(order.client_order_id.clone());
+            ctx.submit_order(&order);
+        }
+
+        fn on_quote_tick(&mut self, ctx: &mut TradingContext, tick: &QuoteTick) {
+            if let Some(client_order_id) = self.client_order_id.take() {
+                ctx.cancel_order(tick.instrument_id.clone(), client_order_id);
+            }
+        }
+
+        fn on_event(&mut self, _ctx: &mut TradingContext, event: &OrderEvent) {
+            self.events.borrow_mut().push(event.name());
+        }
+    }
+
+    #[test]
+    fn test_backtest_engine_runs_trading_strategy() {
+        let mut engine = backtest_engine();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let strategy = LimitThenCancel {
+            client_order_id: None,
+            events: events.clone(),
+        };
+        let ctx = engine
+            .add_trading_strategy(StrategyId::new("S-001"), strategy)
+            .unwrap();
+
+        engine.run(None).unwrap();
+
+        assert!(engine.fills().is_empty());
+        assert_eq!(
+            ctx.net_position(&InstrumentId::from_str("AUD/USD.SIM").unwrap()),
+            0.0
+        );
+        assert_eq!(*events.borrow(), vec!["OrderAccepted", "OrderCanceled"]);
+        assert!(engine.matching_engines[0].open_orders().is_empty());
+    }
+
+    #[test]
+    fn test_backtest_engine_reset() {
+        let mut engine = backtest_engine();
+        let strategy = RoundTrip {
+            log: Rc::new(RefCell::new(Vec::new())),
+            ticks: 0,
+        };
+        engine
+            .add_strategy(StrategyId::new("S-001"), Box::new(strategy))
+            .unwrap();
+        engine.run(None).unwrap();
+
+        engine.reset();
+
+        assert_eq!(engine.iteration(), 0);
+        assert!(engine.fills().is_empty());
+        assert!(engine.closed_positions().is_empty());
+    }
+
+    #[test]
+    fn test_backtest_engine_strategy_halts() {
+        let mut engine = backtest_engine();
+        engine
+            .add_strategy(StrategyId::new("S-001"), Box::new(Halting))
+            .unwrap();
+
+        let result = engine.run(None);
+
+        assert!(matches!(
+            result,
+            Err(BacktestEngineError::StrategyHalted { reason, .. }) if reason == "boom"
+        ));
+        assert_eq!(engine.iteration(), 0);
+    }
+
+    #[test]
+    fn test_backtest_engine_rejects_unknown_instrument_data() {
+        let mut engine =
+            BacktestEngine::new(TraderId::new("TRADER-001"), AccountId::new("SIM-001"));
+
+        let result = engine.add_data("quotes", vec![quote("0.80000", "0.80010", 1)]);
+
+        assert!(matches!(
+            result,
+            Err(BacktestEngineError::UnknownInstrument(_))
+        ));
+    }
+
+    #[test]
+    fn test_backtest_engine_rejects_duplicate_instrument() {
+        let mut engine = backtest_engine();
+
+        let result = engine.add_instrument(
+            audusd_sim(),
+            BookType::L1_TBBO,
+            OrderMatchingEngineConfig::default(),
+            FillModel::default(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(BacktestEngineError::DuplicateInstrument(_))
+        ));
+    }
 }
@@ -0,0 +1,261 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::commands::TradingCommand;
+
+use crate::models::LatencyModel;
+
+/// A trading command in flight to the exchange, ordered by arrival time and then by the
+/// order in which it was sent.
+struct InflightCommand {
+    ts_arrival: UnixNanos,
+    sequence: u64,
+    command: TradingCommand,
+}
+
+impl PartialEq for InflightCommand {
+    fn eq(&self, other: &Self) -> bool {
+        self.ts_arrival == other.ts_arrival && self.sequence == other.sequence
+    }
+}
+
+impl Eq for InflightCommand {}
+
+impl PartialOrd for InflightCommand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InflightCommand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.ts_arrival, self.sequence).cmp(&(other.ts_arrival, other.sequence))
+    }
+}
+
+/// Provides the inbound command queue for a simulated exchange.
+///
+/// Each trading command sent to the exchange is delayed by the latency model before it is
+/// released to the matching engine: submits incur the insert latency, modifies the update
+/// latency, and cancels the cancel latency (queries incur the base latency only). Commands
+/// arriving at the same time are released in the order they were sent. Without a latency
+/// model commands are released immediately.
+pub struct InflightCommandQueue {
+    latency_model: Option<LatencyModel>,
+    queue: BinaryHeap<Reverse<InflightCommand>>,
+    sequence: u64,
+}
+
+impl InflightCommandQueue {
+    /// Initializes a new `InflightCommandQueue` instance.
+    #[must_use]
+    pub fn new(latency_model: Option<LatencyModel>) -> Self {
+        Self {
+            latency_model,
+            queue: BinaryHeap::new(),
+            sequence: 0,
+        }
+    }
+
+    /// Returns the number of commands in flight.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if there are no commands in flight.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Returns the arrival time of the next command to be released (if any).
+    #[must_use]
+    pub fn next_arrival_ns(&self) -> Option<UnixNanos> {
+        self.queue.peek().map(|c| c.0.ts_arrival)
+    }
+
+    /// Sends the `command` to the exchange at `ts_now`, returning its arrival time.
+    pub fn send(&mut self, command: TradingCommand, ts_now: UnixNanos) -> UnixNanos {
+        let ts_arrival = ts_now + self.latency(&command);
+        self.sequence += 1;
+        self.queue.push(Reverse(InflightCommand {
+            ts_arrival,
+            sequence: self.sequence,
+            command,
+        }));
+        ts_arrival
+    }
+
+    /// Releases all commands which have arrived at or before `ts_now`, in arrival order.
+    pub fn pop_arrived(&mut self, ts_now: UnixNanos) -> Vec<(UnixNanos, TradingCommand)> {
+        let mut arrived = Vec::new();
+        while self.next_arrival_ns().map_or(false, |ts| ts <= ts_now) {
+            if let Some(Reverse(inflight)) = self.queue.pop() {
+                arrived.push((inflight.ts_arrival, inflight.command));
+            }
+        }
+        arrived
+    }
+
+    /// Drops all commands in flight.
+    pub fn reset(&mut self) {
+        self.queue.clear();
+        self.sequence = 0;
+    }
+
+    fn latency(&mut self, command: &TradingCommand) -> UnixNanos {
+        let latency_model = match &mut self.latency_model {
+            Some(latency_model) => latency_model,
+            None => return 0,
+        };
+        match command {
+            TradingCommand::SubmitOrder(_) | TradingCommand::SubmitOrderList(_) => {
+                latency_model.insert_latency()
+            }
+            TradingCommand::ModifyOrder(_) => latency_model.update_latency(),
+            TradingCommand::CancelOrder(_) | TradingCommand::CancelAllOrders(_) => {
+                latency_model.cancel_latency()
+            }
+            TradingCommand::QueryOrder(_) => latency_model.base_latency_nanos,
+        }
+    }
+}
+
+impl Default for InflightCommandQueue {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        commands::{CancelOrder, ModifyOrder},
+        identifiers::{
+            client_order_id::ClientOrderId, instrument_id::InstrumentId, strategy_id::StrategyId,
+            trader_id::TraderId,
+        },
+    };
+
+    use super::*;
+
+    fn cancel_order(client_order_id: &str) -> TradingCommand {
+        TradingCommand::CancelOrder(CancelOrder {
+            trader_id: TraderId::new("TRADER-001"),
+            client_id: None,
+            strategy_id: StrategyId::new("S-001"),
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            client_order_id: ClientOrderId::new(client_order_id),
+            venue_order_id: None,
+            command_id: UUID4::new(),
+            ts_init: 0,
+        })
+    }
+
+    fn modify_order(client_order_id: &str) -> TradingCommand {
+        TradingCommand::ModifyOrder(ModifyOrder {
+            trader_id: TraderId::new("TRADER-001"),
+            client_id: None,
+            strategy_id: StrategyId::new("S-001"),
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            client_order_id: ClientOrderId::new(client_order_id),
+            venue_order_id: None,
+            quantity: None,
+            price: None,
+            trigger_price: None,
+            command_id: UUID4::new(),
+            ts_init: 0,
+        })
+    }
+
+    fn client_order_id(command: &TradingCommand) -> String {
+        match command {
+            TradingCommand::CancelOrder(c) => c.client_order_id.to_string(),
+            TradingCommand::ModifyOrder(c) => c.client_order_id.to_string(),
+            _ => panic!("unexpected command"),
+        }
+    }
+
+    #[test]
+    fn test_without_latency_model_commands_arrive_immediately() {
+        let mut queue = InflightCommandQueue::default();
+
+        assert_eq!(queue.send(cancel_order("O-1"), 100), 100);
+        let arrived = queue.pop_arrived(100);
+
+        assert_eq!(arrived.len(), 1);
+        assert_eq!(arrived[0].0, 100);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_commands_held_until_latency_elapsed() {
+        let latency_model = LatencyModel::new(1_000, 100, 200, 300, 0, None);
+        let mut queue = InflightCommandQueue::new(Some(latency_model));
+
+        assert_eq!(queue.send(cancel_order("O-1"), 0), 1_300);
+        assert_eq!(queue.send(modify_order("O-2"), 0), 1_200);
+
+        assert!(queue.pop_arrived(1_199).is_empty());
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.next_arrival_ns(), Some(1_200));
+
+        let arrived = queue.pop_arrived(2_000);
+        let ids: Vec<String> = arrived.iter().map(|(_, c)| client_order_id(c)).collect();
+        assert_eq!(ids, vec!["O-2", "O-1"]);
+        assert_eq!(arrived[0].0, 1_200);
+        assert_eq!(arrived[1].0, 1_300);
+    }
+
+    #[test]
+    fn test_commands_with_equal_arrival_released_in_send_order() {
+        let latency_model = LatencyModel::new(1_000, 0, 0, 0, 0, None);
+        let mut queue = InflightCommandQueue::new(Some(latency_model));
+
+        queue.send(cancel_order("O-1"), 0);
+        queue.send(modify_order("O-2"), 0);
+        queue.send(cancel_order("O-3"), 0);
+
+        let ids: Vec<String> = queue
+            .pop_arrived(1_000)
+            .iter()
+            .map(|(_, c)| client_order_id(c))
+            .collect();
+        assert_eq!(ids, vec!["O-1", "O-2", "O-3"]);
+    }
+
+    #[test]
+    fn test_reset_drops_commands_in_flight() {
+        let mut queue = InflightCommandQueue::new(Some(LatencyModel::default()));
+        queue.send(cancel_order("O-1"), 0);
+        queue.reset();
+
+        assert!(queue.is_empty());
+        assert_eq!(queue.next_arrival_ns(), None);
+    }
+}
@@ -13,4 +13,21 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use pyo3::prelude::*;
+
+pub mod data_iterator;
 pub mod engine;
+pub mod exchange;
+pub mod matching_engine;
+pub mod models;
+pub mod modules;
+
+/// Loaded as nautilus_pyo3.backtest
+#[pymodule]
+pub fn backtest(_: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<engine::BacktestEngine>()?;
+    m.add_class::<engine::StrategyContext>()?;
+    m.add_class::<models::FillModel>()?;
+    m.add_class::<models::LatencyModel>()?;
+    Ok(())
+}
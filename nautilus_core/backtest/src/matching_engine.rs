@@ -0,0 +1,2692 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    cell::RefCell,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_common::{calendar::TradingCalendar, calendar_api::TradingCalendar_API};
+use nautilus_core::{
+    cvec::{CVec, CVecElementType},
+    ffi_error::{set_last_error, write_result, FFI_ERROR, FFI_OK},
+    pool::{ObjectPool, PoolStats},
+    time::UnixNanos,
+    uuid::UUID4,
+};
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        book::{BookOrder, OrderBookDelta, OrderBookDeltas},
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::{
+        AggressorSide, BookType, ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType,
+        TimeInForce,
+    },
+    events::order::{
+        OrderAccepted, OrderCanceled, OrderEvent, OrderExpired, OrderFilled, OrderInitialized,
+        OrderRejected, OrderSubmitted, OrderTriggered, OrderUpdated,
+    },
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, trade_id::TradeId,
+        venue_order_id::VenueOrderId,
+    },
+    instruments::{BaseInstrument, Instrument},
+    orderbook::book::OrderBook,
+    orders::{
+        trailing::{trailing_stop_calculate, TrailingStopError},
+        Order, OrderError,
+    },
+    types::{
+        price::{Price, PRICE_MAX, PRICE_MIN},
+        quantity::Quantity,
+    },
+};
+
+use crate::models::FillModel;
+
+/// Configuration for an [`OrderMatchingEngine`].
+#[derive(Clone, Copy, Debug)]
+pub struct OrderMatchingEngineConfig {
+    /// If bars should be processed by the engine (and move the market).
+    pub bar_execution: bool,
+    /// If stop orders are rejected when their trigger price is already in the market.
+    pub reject_stop_orders: bool,
+    /// If passive limit orders queue behind the book size resting at their price level.
+    ///
+    /// For L3_MBO books the individual book orders ahead are tracked, so the queue also
+    /// advances as those orders are canceled or reduced.
+    pub use_queue_position: bool,
+    /// If GTD orders are expired at their expire time, otherwise they are treated as GTC.
+    pub support_gtd_orders: bool,
+}
+
+impl Default for OrderMatchingEngineConfig {
+    fn default() -> Self {
+        Self {
+            bar_execution: true,
+            reject_stop_orders: true,
+            use_queue_position: false,
+            support_gtd_orders: true,
+        }
+    }
+}
+
+/// An order working at the matching engine.
+struct RestingOrder {
+    order: Order,
+    queue_ahead: Quantity,
+    /// The IDs of the book orders ahead in the queue (L3_MBO only).
+    orders_ahead: Vec<u64>,
+    is_triggered: bool,
+}
+
+/// A change in the state of a contingent order which may affect its linked orders.
+struct ContingencyTrigger {
+    client_order_id: ClientOrderId,
+    contingency_type: ContingencyType,
+    linked_order_ids: Vec<ClientOrderId>,
+    status: OrderStatus,
+    filled_qty: Quantity,
+    leaves_qty: Quantity,
+}
+
+/// Provides an order matching engine for a single instrument at a simulated venue.
+///
+/// Submitted orders are matched against the engines internal [`OrderBook`], which is
+/// maintained from order book deltas (L2/L3) or from quotes, trades and bars (L1).
+/// All generated order events are buffered until drained. An event which cannot be applied
+/// to its order is not buffered, and the error is buffered instead until drained.
+///
+/// GTD orders are expired once the engine iterates at or beyond their expire time.
+///
+/// If a trading calendar is set, orders submitted outside of its sessions are rejected, and
+/// open orders are only matched while the market is open.
+///
+/// Trailing stop orders have their trigger (and limit) prices moved to follow the market
+/// on each iteration until triggered, based on the last trade price and/or top of book.
+///
+/// Contingent orders are linked by their `linked_order_ids`:
+/// - OTO child orders are held until their parent fills, and are rejected if the parent
+///   closes without any fills.
+/// - When an OCO order closes, its linked orders are canceled.
+/// - When an OUO order closes its linked orders are canceled, and when it is partially
+///   filled its linked orders are reduced to the same leaves quantity.
+pub struct OrderMatchingEngine<I: Instrument> {
+    pub instrument: I,
+    pub book: OrderBook,
+    pub account_id: AccountId,
+    pub config: OrderMatchingEngineConfig,
+    pub fill_model: FillModel,
+    calendar: Option<TradingCalendar>,
+    last_price: Option<Price>,
+    orders: Vec<RestingOrder>,
+    pending_children: Vec<Order>,
+    contingencies: Vec<ContingencyTrigger>,
+    events: Vec<OrderEvent>,
+    errors: Vec<OrderError>,
+    venue_order_count: u64,
+    execution_count: u64,
+}
+
+impl<I: Instrument> OrderMatchingEngine<I> {
+    /// Initializes a new `OrderMatchingEngine` instance.
+    #[must_use]
+    pub fn new(
+        instrument: I,
+        book_type: BookType,
+        account_id: AccountId,
+        config: OrderMatchingEngineConfig,
+        fill_model: FillModel,
+    ) -> Self {
+        let book = OrderBook::new(instrument.id().clone(), book_type);
+        Self {
+            instrument,
+            book,
+            account_id,
+            config,
+            fill_model,
+            calendar: None,
+            last_price: None,
+            orders: Vec::new(),
+            pending_children: Vec::new(),
+            contingencies: Vec::new(),
+            events: Vec::new(),
+            errors: Vec::new(),
+            venue_order_count: 0,
+            execution_count: 0,
+        }
+    }
+
+    /// Resets the engine to its initial state.
+    pub fn reset(&mut self) {
+        self.book.reset();
+        self.last_price = None;
+        self.orders.clear();
+        self.pending_children.clear();
+        self.contingencies.clear();
+        self.events.clear();
+        self.errors.clear();
+        self.venue_order_count = 0;
+        self.execution_count = 0;
+    }
+
+    /// Sets the trading `calendar` for the venue.
+    pub fn set_calendar(&mut self, calendar: TradingCalendar) {
+        self.calendar = Some(calendar);
+    }
+
+    /// Returns whether the market is open at `ts_now` (always, if no calendar is set).
+    #[must_use]
+    pub fn is_market_open(&self, ts_now: UnixNanos) -> bool {
+        self.calendar
+            .as_ref()
+            .map_or(true, |calendar| calendar.is_open(ts_now))
+    }
+
+    #[must_use]
+    pub fn best_bid_price(&self) -> Option<Price> {
+        self.book.best_bid_price()
+    }
+
+    #[must_use]
+    pub fn best_ask_price(&self) -> Option<Price> {
+        self.book.best_ask_price()
+    }
+
+    /// Returns the orders currently working at the engine, in arrival order.
+    #[must_use]
+    pub fn open_orders(&self) -> Vec<&Order> {
+        self.orders.iter().map(|resting| &resting.order).collect()
+    }
+
+    /// Returns the OTO child orders being held until their parent order fills.
+    #[must_use]
+    pub fn pending_orders(&self) -> Vec<&Order> {
+        self.pending_children.iter().collect()
+    }
+
+    #[must_use]
+    pub fn order(&self, client_order_id: &ClientOrderId) -> Option<&Order> {
+        self.orders
+            .iter()
+            .map(|resting| &resting.order)
+            .find(|order| &order.client_order_id == client_order_id)
+    }
+
+    /// Returns the earliest expire time of the open GTD orders, if any.
+    #[must_use]
+    pub fn next_expire_time_ns(&self) -> Option<UnixNanos> {
+        if !self.config.support_gtd_orders {
+            return None;
+        }
+        self.orders
+            .iter()
+            .filter_map(|resting| self.expire_time_ns(&resting.order))
+            .min()
+    }
+
+    /// Returns the quantity of market volume still ahead of the given open order.
+    #[must_use]
+    pub fn queue_ahead(&self, client_order_id: &ClientOrderId) -> Option<Quantity> {
+        self.orders
+            .iter()
+            .find(|resting| &resting.order.client_order_id == client_order_id)
+            .map(|resting| resting.queue_ahead)
+    }
+
+    /// Drains all buffered order events in the order they were generated.
+    pub fn drain_events(&mut self) -> Vec<OrderEvent> {
+        self.events.drain(..).collect()
+    }
+
+    /// Drains all buffered errors from events which could not be applied to their orders.
+    pub fn drain_errors(&mut self) -> Vec<OrderError> {
+        self.errors.drain(..).collect()
+    }
+
+    /// Drains the buffered fill events, leaving all other events buffered.
+    pub fn drain_fills(&mut self) -> Vec<OrderFilled> {
+        let mut fills = Vec::new();
+        self.drain_fills_into(&mut fills, usize::MAX);
+        fills
+    }
+
+    /// Drains up to `max` buffered fill events onto `buffer` in the order they were
+    /// generated, leaving all other events (and any further fills) buffered.
+    pub fn drain_fills_into(&mut self, buffer: &mut Vec<OrderFilled>, max: usize) {
+        let mut remaining = max;
+        self.events.retain(|event| match event {
+            OrderEvent::OrderPartiallyFilled(fill) | OrderEvent::OrderFilled(fill)
+                if remaining > 0 =>
+            {
+                buffer.push(fill.clone());
+                remaining -= 1;
+                false
+            }
+            _ => true,
+        });
+    }
+
+    pub fn process_order_book_delta(&mut self, delta: OrderBookDelta) {
+        let ts_now = delta.ts_init;
+        self.book.apply_delta(delta);
+        self.update_orders_ahead();
+        self.iterate(ts_now);
+    }
+
+    /// Processes the given `deltas` in sequence, as when replaying market-by-order data.
+    pub fn process_order_book_deltas(&mut self, deltas: OrderBookDeltas) {
+        for delta in deltas.deltas {
+            self.process_order_book_delta(delta);
+        }
+    }
+
+    pub fn process_quote_tick(&mut self, tick: &QuoteTick) {
+        if self.book.book_type == BookType::L1_TBBO {
+            self.book.update_quote_tick(tick);
+        }
+        self.iterate(tick.ts_init);
+    }
+
+    pub fn process_trade_tick(&mut self, tick: &TradeTick) {
+        self.last_price = Some(tick.price);
+        if self.config.use_queue_position {
+            self.match_trade(tick.price, tick.size, tick.aggressor_side, tick.ts_init);
+        }
+        if self.book.book_type == BookType::L1_TBBO {
+            self.book.update_trade_tick(tick);
+        }
+        self.iterate(tick.ts_init);
+    }
+
+    /// Processes the given `bar` as a sequence of four trades at the open, high, low and
+    /// close prices, each carrying a quarter of the bar volume.
+    ///
+    /// The high is assumed to precede the low when it is closer to the open.
+    /// Bars are ignored unless `bar_execution` is enabled for an L1 book.
+    pub fn process_bar(&mut self, bar: &Bar) {
+        if !self.config.bar_execution || self.book.book_type != BookType::L1_TBBO {
+            return;
+        }
+
+        let (first, second) =
+            if (bar.high.raw - bar.open.raw).abs() < (bar.open.raw - bar.low.raw).abs() {
+                (bar.high, bar.low)
+            } else {
+                (bar.low, bar.high)
+            };
+        let size = Quantity::from_raw(bar.volume.raw / 4, bar.volume.precision);
+
+        for price in [bar.open, first, second, bar.close] {
+            let tick = TradeTick::new(
+                bar.bar_type.instrument_id.clone(),
+                price,
+                size,
+                AggressorSide::NoAggressor,
+                TradeId::new(&bar.ts_event.to_string()),
+                bar.ts_event,
+                bar.ts_init,
+            );
+            self.process_trade_tick(&tick);
+        }
+    }
+
+    /// Processes the given submitted `order` against the current market.
+    ///
+    /// # Panics
+    ///
+    /// - If `order` is not in a `SUBMITTED` state.
+    pub fn process_order(&mut self, order: Order, ts_now: UnixNanos) {
+        assert_eq!(
+            order.status,
+            OrderStatus::Submitted,
+            "Matching engine can only process submitted orders"
+        );
+
+        let is_parent_working = order.parent_order_id.as_ref().map_or(false, |parent_id| {
+            self.order(parent_id).is_some()
+                || self
+                    .pending_children
+                    .iter()
+                    .any(|child| &child.client_order_id == parent_id)
+        });
+        if is_parent_working {
+            self.pending_children.push(order);
+            return;
+        }
+
+        self.execute_order(order, ts_now);
+        self.process_contingencies(ts_now);
+    }
+
+    fn execute_order(&mut self, mut order: Order, ts_now: UnixNanos) {
+        if let Some(reason) = self.check_order(&order) {
+            self.reject(&mut order, reason, ts_now);
+            return;
+        }
+        if !self.is_market_open(ts_now) {
+            let reason = format!("Market for {} was closed", self.instrument.id());
+            self.reject(&mut order, reason, ts_now);
+            return;
+        }
+        if self.is_expired(&order, ts_now) {
+            let expire_time = order.expire_time.unwrap_or_default();
+            let reason = format!("GTD order expire time {expire_time} was in the past");
+            self.reject(&mut order, reason, ts_now);
+            return;
+        }
+
+        match order.order_type {
+            OrderType::Market => self.process_market_order(order, ts_now),
+            OrderType::Limit => self.process_limit_order(order, ts_now),
+            OrderType::StopMarket | OrderType::StopLimit => self.process_stop_order(order, ts_now),
+            OrderType::TrailingStopMarket | OrderType::TrailingStopLimit => {
+                self.process_trailing_stop_order(order, ts_now);
+            }
+            order_type => {
+                self.reject(
+                    &mut order,
+                    format!("{order_type} orders are not supported by the matching engine"),
+                    ts_now,
+                );
+            }
+        }
+    }
+
+    /// Cancels the open (or pending child) order with the given `client_order_id`.
+    ///
+    /// Returns `false` if no such order is working at the engine.
+    pub fn cancel_order(&mut self, client_order_id: &ClientOrderId, ts_now: UnixNanos) -> bool {
+        let is_canceled = self.cancel_working_order(client_order_id, ts_now);
+        self.process_contingencies(ts_now);
+        is_canceled
+    }
+
+    /// Matches all open orders against the current state of the book.
+    ///
+    /// Any GTD orders which have reached their expire time are expired first, and no
+    /// orders are matched while the market is closed.
+    pub fn iterate(&mut self, ts_now: UnixNanos) {
+        let is_market_open = self.is_market_open(ts_now);
+        let orders = std::mem::take(&mut self.orders);
+        for mut resting in orders {
+            if self.is_expired(&resting.order, ts_now) {
+                self.expire(&mut resting.order, ts_now);
+                continue;
+            }
+            if !is_market_open {
+                self.orders.push(resting);
+                continue;
+            }
+            if let Some(resting) = self.match_order(resting, ts_now) {
+                self.orders.push(resting);
+            }
+        }
+        self.process_contingencies(ts_now);
+    }
+
+    fn cancel_working_order(&mut self, client_order_id: &ClientOrderId, ts_now: UnixNanos) -> bool {
+        if let Some(index) = self
+            .orders
+            .iter()
+            .position(|resting| &resting.order.client_order_id == client_order_id)
+        {
+            let mut resting = self.orders.remove(index);
+            self.cancel(&mut resting.order, ts_now);
+            return true;
+        }
+        if let Some(index) = self
+            .pending_children
+            .iter()
+            .position(|child| &child.client_order_id == client_order_id)
+        {
+            let mut child = self.pending_children.remove(index);
+            self.cancel(&mut child, ts_now);
+            return true;
+        }
+        false
+    }
+
+    /// Applies the contingency actions for every contingent order which changed state,
+    /// including any changes caused by those actions.
+    fn process_contingencies(&mut self, ts_now: UnixNanos) {
+        while !self.contingencies.is_empty() {
+            let triggers = std::mem::take(&mut self.contingencies);
+            for trigger in triggers {
+                let is_closed = matches!(
+                    trigger.status,
+                    OrderStatus::Filled
+                        | OrderStatus::Canceled
+                        | OrderStatus::Rejected
+                        | OrderStatus::Expired
+                );
+                match trigger.contingency_type {
+                    ContingencyType::Oto if is_closed => {
+                        for child_id in &trigger.linked_order_ids {
+                            self.release_child(child_id, &trigger, ts_now);
+                        }
+                    }
+                    ContingencyType::Oco | ContingencyType::Ouo if is_closed => {
+                        for linked_id in &trigger.linked_order_ids {
+                            self.cancel_working_order(linked_id, ts_now);
+                        }
+                    }
+                    ContingencyType::Ouo => {
+                        for linked_id in &trigger.linked_order_ids {
+                            self.reduce_linked_order(linked_id, trigger.leaves_qty, ts_now);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Releases the pending `child_id` order of a closed OTO parent for execution, or rejects
+    /// it if the parent closed without any fills.
+    fn release_child(
+        &mut self,
+        child_id: &ClientOrderId,
+        parent: &ContingencyTrigger,
+        ts_now: UnixNanos,
+    ) {
+        let index = match self
+            .pending_children
+            .iter()
+            .position(|child| &child.client_order_id == child_id)
+        {
+            Some(index) => index,
+            None => return,
+        };
+        let mut child = self.pending_children.remove(index);
+
+        if parent.filled_qty.is_zero() {
+            let reason = format!(
+                "Parent order {} closed as {} with no fills",
+                parent.client_order_id, parent.status
+            );
+            self.reject(&mut child, reason, ts_now);
+        } else {
+            self.execute_order(child, ts_now);
+        }
+    }
+
+    /// Reduces the quantity of the open `linked_id` order so its leaves quantity matches
+    /// `leaves_qty`.
+    fn reduce_linked_order(
+        &mut self,
+        linked_id: &ClientOrderId,
+        leaves_qty: Quantity,
+        ts_now: UnixNanos,
+    ) {
+        let index = match self
+            .orders
+            .iter()
+            .position(|resting| &resting.order.client_order_id == linked_id)
+        {
+            Some(index) => index,
+            None => return,
+        };
+        let mut resting = self.orders.remove(index);
+        if resting.order.leaves_qty.raw > leaves_qty.raw {
+            let quantity = Quantity::from_raw(
+                resting.order.filled_qty.raw + leaves_qty.raw,
+                resting.order.quantity.precision,
+            );
+            self.update(&mut resting.order, quantity, None, None, ts_now);
+        }
+        self.orders.insert(index, resting);
+    }
+
+    fn check_order(&self, order: &Order) -> Option<String> {
+        let instrument_id = self.instrument.id();
+        if &order.instrument_id != instrument_id {
+            return Some(format!(
+                "Order instrument ID {} did not match engine instrument ID {instrument_id}",
+                order.instrument_id
+            ));
+        }
+        if order.quantity.precision != self.instrument.size_precision() {
+            return Some(format!(
+                "Invalid order quantity precision for order {}, was {} when {instrument_id} size precision is {}",
+                order.client_order_id,
+                order.quantity.precision,
+                self.instrument.size_precision(),
+            ));
+        }
+        for price in [order.price, order.trigger_price].into_iter().flatten() {
+            if price.precision != self.instrument.price_precision() {
+                return Some(format!(
+                    "Invalid order price precision for order {}, was {} when {instrument_id} price precision is {}",
+                    order.client_order_id,
+                    price.precision,
+                    self.instrument.price_precision(),
+                ));
+            }
+        }
+        if order.time_in_force == TimeInForce::Gtd
+            && self.config.support_gtd_orders
+            && order.expire_time.is_none()
+        {
+            return Some(format!(
+                "GTD order {} has no expire time",
+                order.client_order_id
+            ));
+        }
+        if matches!(order.order_type, OrderType::Limit | OrderType::StopLimit)
+            && order.price.is_none()
+        {
+            return Some(format!("{} order has no limit price", order.order_type));
+        }
+        if matches!(
+            order.order_type,
+            OrderType::StopMarket | OrderType::StopLimit
+        ) && order.trigger_price.is_none()
+        {
+            return Some(format!("{} order has no trigger price", order.order_type));
+        }
+        None
+    }
+
+    fn process_market_order(&mut self, mut order: Order, ts_now: UnixNanos) {
+        let has_market = match order.side {
+            OrderSide::Buy => self.book.has_ask(),
+            _ => self.book.has_bid(),
+        };
+        if !has_market {
+            let reason = format!("No market for {}", self.instrument.id());
+            self.reject(&mut order, reason, ts_now);
+            return;
+        }
+
+        self.accept(&mut order, ts_now);
+        let fills = self.simulate_fills(&order, None);
+        self.apply_fills(&mut order, fills, LiquiditySide::Taker, ts_now);
+
+        // Any quantity the book could not absorb is canceled
+        if order.is_open() {
+            self.cancel(&mut order, ts_now);
+        }
+    }
+
+    fn process_limit_order(&mut self, mut order: Order, ts_now: UnixNanos) {
+        let price = order.price.expect("checked on submission");
+
+        if self.is_limit_marketable(order.side, price) {
+            if order.is_post_only {
+                let reason = format!(
+                    "POST_ONLY {} {} order limit px of {price} would have been a TAKER",
+                    order.side, order.order_type,
+                );
+                self.reject(&mut order, reason, ts_now);
+                return;
+            }
+
+            self.accept(&mut order, ts_now);
+            let fills = self.simulate_fills(&order, Some(price));
+            let fillable = fills.iter().map(|(_, qty)| qty.raw).sum::<u64>();
+            if order.time_in_force != TimeInForce::Fok || fillable >= order.leaves_qty.raw {
+                self.apply_fills(&mut order, fills, LiquiditySide::Taker, ts_now);
+            }
+        } else {
+            self.accept(&mut order, ts_now);
+        }
+
+        if !order.is_open() {
+            return;
+        }
+        if matches!(order.time_in_force, TimeInForce::Ioc | TimeInForce::Fok) {
+            self.cancel(&mut order, ts_now);
+            return;
+        }
+        self.rest(order);
+    }
+
+    fn process_trailing_stop_order(&mut self, mut order: Order, ts_now: UnixNanos) {
+        match self.calculate_trailing_stop(&order) {
+            Ok((trigger_price, price)) => {
+                // The order is not yet accepted, so the initial prices are set directly
+                if trigger_price.is_some() {
+                    order.trigger_price = trigger_price;
+                }
+                if price.is_some() {
+                    order.price = price;
+                }
+            }
+            Err(e) => {
+                self.reject(&mut order, e.to_string(), ts_now);
+                return;
+            }
+        }
+        self.process_stop_order(order, ts_now);
+    }
+
+    fn process_stop_order(&mut self, mut order: Order, ts_now: UnixNanos) {
+        let trigger_price = order.trigger_price.expect("checked on submission");
+
+        if self.is_stop_triggered(order.side, trigger_price) && self.config.reject_stop_orders {
+            let reason = format!(
+                "{} {} order trigger px of {trigger_price} was in the market",
+                order.side, order.order_type,
+            );
+            self.reject(&mut order, reason, ts_now);
+            return;
+        }
+
+        self.accept(&mut order, ts_now);
+        let resting = RestingOrder {
+            queue_ahead: Quantity::zero(self.instrument.size_precision()),
+            orders_ahead: Vec::new(),
+            order,
+            is_triggered: false,
+        };
+        if let Some(resting) = self.match_order(resting, ts_now) {
+            self.orders.push(resting);
+        }
+    }
+
+    /// Matches the `resting` order against the book, returning it if still open.
+    fn match_order(
+        &mut self,
+        mut resting: RestingOrder,
+        ts_now: UnixNanos,
+    ) -> Option<RestingOrder> {
+        let side = resting.order.side;
+
+        match resting.order.order_type {
+            OrderType::StopMarket | OrderType::TrailingStopMarket => {
+                let trigger_price = resting.order.trigger_price.expect("checked on submission");
+                if self.is_stop_triggered(side, trigger_price) && self.fill_model.is_stop_filled() {
+                    let fills = self.simulate_fills(&resting.order, None);
+                    self.apply_fills(&mut resting.order, fills, LiquiditySide::Taker, ts_now);
+                }
+            }
+            OrderType::StopLimit | OrderType::TrailingStopLimit if !resting.is_triggered => {
+                let trigger_price = resting.order.trigger_price.expect("checked on submission");
+                if self.is_stop_triggered(side, trigger_price) {
+                    self.trigger(&mut resting.order, ts_now);
+                    resting.is_triggered = true;
+
+                    // Once triggered the order works as a limit order, taking any liquidity
+                    // available at its limit price before joining the queue
+                    let price = resting.order.price.expect("checked on submission");
+                    if self.is_limit_marketable(side, price) {
+                        let fills = self.simulate_fills(&resting.order, Some(price));
+                        self.apply_fills(&mut resting.order, fills, LiquiditySide::Taker, ts_now);
+                    }
+                    resting.queue_ahead = self.initial_queue_ahead(side, price);
+                    resting.orders_ahead = self.initial_orders_ahead(side, price);
+                }
+            }
+            _ => {
+                let price = resting.order.price.expect("checked on submission");
+                let is_filled = self.is_limit_crossed(side, price)
+                    || (!self.config.use_queue_position
+                        && self.is_limit_marketable(side, price)
+                        && self.fill_model.is_limit_filled());
+                if is_filled {
+                    let leaves_qty = resting.order.leaves_qty;
+                    self.fill(
+                        &mut resting.order,
+                        price,
+                        leaves_qty,
+                        LiquiditySide::Maker,
+                        ts_now,
+                    );
+                }
+            }
+        }
+
+        if !resting.order.is_open() {
+            return None;
+        }
+        let is_trailing = match resting.order.order_type {
+            OrderType::TrailingStopMarket => true,
+            OrderType::TrailingStopLimit => !resting.is_triggered,
+            _ => false,
+        };
+        if is_trailing {
+            self.update_trailing_stop(&mut resting.order, ts_now);
+        }
+        Some(resting)
+    }
+
+    /// Moves the prices of the trailing stop `order` to follow the market, where it has
+    /// moved in the orders favor.
+    ///
+    /// The prices are left unchanged while the market prices required by the orders
+    /// trigger type are not yet available.
+    fn update_trailing_stop(&mut self, order: &mut Order, ts_now: UnixNanos) {
+        if let Ok((trigger_price, price)) = self.calculate_trailing_stop(order) {
+            if trigger_price.is_some() || price.is_some() {
+                let quantity = order.quantity;
+                self.update(order, quantity, price, trigger_price, ts_now);
+            }
+        }
+    }
+
+    fn calculate_trailing_stop(
+        &self,
+        order: &Order,
+    ) -> Result<(Option<Price>, Option<Price>), TrailingStopError> {
+        trailing_stop_calculate(
+            self.instrument.base().price_increment,
+            order,
+            self.book.best_bid_price(),
+            self.book.best_ask_price(),
+            self.last_price,
+        )
+    }
+
+    /// Matches a trade of `size` at `price` against the queues of open limit orders at that
+    /// price, filling any volume which trades through the queue ahead.
+    fn match_trade(
+        &mut self,
+        price: Price,
+        size: Quantity,
+        aggressor_side: AggressorSide,
+        ts_now: UnixNanos,
+    ) {
+        let mut orders = std::mem::take(&mut self.orders);
+        let mut filled_raw: u64 = 0; // Volume already allocated to earlier orders
+
+        for resting in &mut orders {
+            if resting.order.price != Some(price)
+                || (matches!(
+                    resting.order.order_type,
+                    OrderType::StopLimit | OrderType::TrailingStopLimit
+                ) && !resting.is_triggered)
+            {
+                continue;
+            }
+            let is_contra = match aggressor_side {
+                AggressorSide::Buyer => resting.order.side == OrderSide::Sell,
+                AggressorSide::Seller => resting.order.side == OrderSide::Buy,
+                AggressorSide::NoAggressor => true,
+            };
+            if !is_contra {
+                continue;
+            }
+
+            let queue_raw = resting.queue_ahead.raw;
+            if size.raw <= queue_raw {
+                resting.queue_ahead = Quantity::from_raw(queue_raw - size.raw, size.precision);
+                continue;
+            }
+            resting.queue_ahead = Quantity::zero(resting.queue_ahead.precision);
+
+            let excess_raw = (size.raw - queue_raw).saturating_sub(filled_raw);
+            let fill_raw = excess_raw.min(resting.order.leaves_qty.raw);
+            if fill_raw > 0 {
+                let last_qty = Quantity::from_raw(fill_raw, size.precision);
+                self.fill(
+                    &mut resting.order,
+                    price,
+                    last_qty,
+                    LiquiditySide::Maker,
+                    ts_now,
+                );
+                filled_raw += fill_raw;
+            }
+        }
+
+        orders.retain(|resting| resting.order.is_open());
+        self.orders = orders;
+    }
+
+    fn rest(&mut self, order: Order) {
+        let (queue_ahead, orders_ahead) = match order.price {
+            Some(price) => (
+                self.initial_queue_ahead(order.side, price),
+                self.initial_orders_ahead(order.side, price),
+            ),
+            None => (Quantity::zero(self.instrument.size_precision()), Vec::new()),
+        };
+        self.orders.push(RestingOrder {
+            order,
+            queue_ahead,
+            orders_ahead,
+            is_triggered: false,
+        });
+    }
+
+    fn initial_queue_ahead(&self, side: OrderSide, price: Price) -> Quantity {
+        let zero = Quantity::zero(self.instrument.size_precision());
+        if !self.config.use_queue_position {
+            return zero;
+        }
+        let levels = match side {
+            OrderSide::Buy => self.book.bids(),
+            _ => self.book.asks(),
+        };
+        levels
+            .iter()
+            .find(|level| level.price.value == price)
+            .map_or(zero, |level| level.size())
+    }
+
+    /// Returns the IDs of the book orders queued at `price`, which an order joining the level
+    /// will be behind (only tracked for L3_MBO books).
+    fn initial_orders_ahead(&self, side: OrderSide, price: Price) -> Vec<u64> {
+        if !self.config.use_queue_position || self.book.book_type != BookType::L3_MBO {
+            return Vec::new();
+        }
+        let levels = match side {
+            OrderSide::Buy => self.book.bids(),
+            _ => self.book.asks(),
+        };
+        levels
+            .iter()
+            .find(|level| level.price.value == price)
+            .map_or(Vec::new(), |level| {
+                level.orders.iter().map(|o| o.order_id).collect()
+            })
+    }
+
+    /// Advances the queue position of open orders as the book orders ahead of them are
+    /// canceled, reduced or moved away from their price level.
+    ///
+    /// The queue ahead never increases, so volume already consumed by trades is not counted
+    /// again when the corresponding book updates arrive.
+    fn update_orders_ahead(&mut self) {
+        for resting in &mut self.orders {
+            if resting.orders_ahead.is_empty() {
+                continue;
+            }
+            let price = match resting.order.price {
+                Some(price) => price,
+                None => continue,
+            };
+            let levels = match resting.order.side {
+                OrderSide::Buy => self.book.bids(),
+                _ => self.book.asks(),
+            };
+            let ahead: Vec<&BookOrder> = levels
+                .iter()
+                .find(|level| level.price.value == price)
+                .map_or(Vec::new(), |level| {
+                    level
+                        .orders
+                        .iter()
+                        .filter(|o| resting.orders_ahead.contains(&o.order_id))
+                        .collect()
+                });
+
+            let ahead_raw = ahead.iter().map(|o| o.size.raw).sum::<u64>();
+            resting.orders_ahead = ahead.iter().map(|o| o.order_id).collect();
+            if ahead_raw < resting.queue_ahead.raw {
+                resting.queue_ahead = Quantity::from_raw(ahead_raw, resting.queue_ahead.precision);
+            }
+        }
+    }
+
+    fn expire_time_ns(&self, order: &Order) -> Option<UnixNanos> {
+        match order.time_in_force {
+            TimeInForce::Gtd => order.expire_time,
+            _ => None,
+        }
+    }
+
+    fn is_expired(&self, order: &Order, ts_now: UnixNanos) -> bool {
+        self.config.support_gtd_orders
+            && self
+                .expire_time_ns(order)
+                .map_or(false, |expire_time| ts_now >= expire_time)
+    }
+
+    fn is_limit_marketable(&self, side: OrderSide, price: Price) -> bool {
+        match side {
+            OrderSide::Buy => self.book.best_ask_price().map_or(false, |ask| price >= ask),
+            _ => self.book.best_bid_price().map_or(false, |bid| price <= bid),
+        }
+    }
+
+    fn is_limit_crossed(&self, side: OrderSide, price: Price) -> bool {
+        match side {
+            OrderSide::Buy => self.book.best_ask_price().map_or(false, |ask| price > ask),
+            _ => self.book.best_bid_price().map_or(false, |bid| price < bid),
+        }
+    }
+
+    fn is_stop_triggered(&self, side: OrderSide, trigger_price: Price) -> bool {
+        match side {
+            OrderSide::Buy => self
+                .book
+                .best_ask_price()
+                .map_or(false, |ask| ask >= trigger_price),
+            _ => self
+                .book
+                .best_bid_price()
+                .map_or(false, |bid| bid <= trigger_price),
+        }
+    }
+
+    /// Simulates filling the leaves quantity of `order` against the opposite side of the book,
+    /// up to the optional limit `price`.
+    ///
+    /// Fills without a limit price on an L1 book may slip by one tick, per the fill model.
+    fn simulate_fills(&mut self, order: &Order, price: Option<Price>) -> Vec<(Price, Quantity)> {
+        let price_precision = self.instrument.price_precision();
+        let is_market = price.is_none();
+        let price = price.unwrap_or_else(|| match order.side {
+            OrderSide::Buy => Price::new(PRICE_MAX, price_precision),
+            _ => Price::new(PRICE_MIN, price_precision),
+        });
+        let mut fills =
+            self.book
+                .simulate_fills(&BookOrder::new(order.side, price, order.leaves_qty, 0));
+
+        if self.book.book_type != BookType::L1_TBBO {
+            return fills;
+        }
+
+        // The top of an L1 book only represents the displayed size, so assume the
+        // remainder can be filled at the last price reached
+        let filled_raw = fills.iter().map(|(_, qty)| qty.raw).sum::<u64>();
+        if let Some(&(last_px, _)) = fills.last() {
+            if filled_raw < order.leaves_qty.raw {
+                let remainder = order.leaves_qty.raw - filled_raw;
+                fills.push((
+                    last_px,
+                    Quantity::from_raw(remainder, order.leaves_qty.precision),
+                ));
+            }
+        }
+
+        if is_market && self.fill_model.is_slipped() {
+            let tick = self.instrument.base().price_increment;
+            for (last_px, _) in &mut fills {
+                *last_px = match order.side {
+                    OrderSide::Buy => *last_px + tick,
+                    _ => *last_px - tick,
+                };
+            }
+        }
+        fills
+    }
+
+    fn apply_fills(
+        &mut self,
+        order: &mut Order,
+        fills: Vec<(Price, Quantity)>,
+        liquidity_side: LiquiditySide,
+        ts_now: UnixNanos,
+    ) {
+        for (last_px, last_qty) in fills {
+            if !order.is_open() {
+                break;
+            }
+            if last_qty.is_zero() {
+                continue;
+            }
+            let last_qty = last_qty.min(order.leaves_qty);
+            self.fill(order, last_px, last_qty, liquidity_side, ts_now);
+        }
+    }
+
+    fn fill(
+        &mut self,
+        order: &mut Order,
+        last_px: Price,
+        last_qty: Quantity,
+        liquidity_side: LiquiditySide,
+        ts_now: UnixNanos,
+    ) {
+        self.execution_count += 1;
+        let last_px = Price::from_raw(last_px.raw, self.instrument.price_precision());
+        let last_qty = Quantity::from_raw(last_qty.raw, self.instrument.size_precision());
+        let commission =
+            self.instrument
+                .calculate_commission(last_qty, last_px, liquidity_side, false);
+        let trade_id = format!("{}-{}", self.instrument.id().venue, self.execution_count);
+
+        let fill = OrderFilled {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: order.venue_order_id.clone().unwrap_or_default(),
+            account_id: self.account_id.clone(),
+            trade_id: TradeId::new(&trade_id),
+            position_id: order.position_id.clone(),
+            order_side: order.side,
+            order_type: order.order_type,
+            last_qty,
+            last_px,
+            currency: self.instrument.quote_currency().clone(),
+            commission,
+            liquidity_side,
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+            reconciliation: false,
+        };
+        let event = if last_qty < order.leaves_qty {
+            OrderEvent::OrderPartiallyFilled(fill)
+        } else {
+            OrderEvent::OrderFilled(fill)
+        };
+        self.apply(order, event);
+    }
+
+    fn accept(&mut self, order: &mut Order, ts_now: UnixNanos) {
+        self.venue_order_count += 1;
+        let venue_order_id = format!("{}-{}", self.instrument.id().venue, self.venue_order_count);
+        let event = OrderAccepted {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: VenueOrderId::new(&venue_order_id),
+            account_id: self.account_id.clone(),
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+            reconciliation: false,
+        };
+        self.apply(order, OrderEvent::OrderAccepted(event));
+    }
+
+    fn reject(&mut self, order: &mut Order, reason: String, ts_now: UnixNanos) {
+        let event = OrderRejected {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: order.venue_order_id.clone().unwrap_or_default(),
+            account_id: self.account_id.clone(),
+            reason,
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+            reconciliation: false,
+        };
+        self.apply(order, OrderEvent::OrderRejected(event));
+    }
+
+    fn trigger(&mut self, order: &mut Order, ts_now: UnixNanos) {
+        let event = OrderTriggered {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: order.venue_order_id.clone(),
+            account_id: Some(self.account_id.clone()),
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+            reconciliation: false,
+        };
+        self.apply(order, OrderEvent::OrderTriggered(event));
+    }
+
+    fn update(
+        &mut self,
+        order: &mut Order,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        ts_now: UnixNanos,
+    ) {
+        let event = OrderUpdated {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: order.venue_order_id.clone(),
+            account_id: Some(self.account_id.clone()),
+            quantity,
+            price,
+            trigger_price,
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+            reconciliation: false,
+        };
+        self.apply(order, OrderEvent::OrderUpdated(event));
+    }
+
+    fn cancel(&mut self, order: &mut Order, ts_now: UnixNanos) {
+        let event = OrderCanceled {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: order.venue_order_id.clone(),
+            account_id: Some(self.account_id.clone()),
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+            reconciliation: false,
+        };
+        self.apply(order, OrderEvent::OrderCanceled(event));
+    }
+
+    fn expire(&mut self, order: &mut Order, ts_now: UnixNanos) {
+        let event = OrderExpired {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: order.venue_order_id.clone(),
+            account_id: Some(self.account_id.clone()),
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+            reconciliation: false,
+        };
+        self.apply(order, OrderEvent::OrderExpired(event));
+    }
+
+    /// Applies the engine generated `event` to the `order` and buffers it, or buffers the
+    /// error if the event does not apply to the order.
+    fn apply(&mut self, order: &mut Order, event: OrderEvent) {
+        if let Err(e) = order.apply(event.clone()) {
+            self.errors.push(e);
+            return;
+        }
+        let affects_linked = matches!(
+            event,
+            OrderEvent::OrderPartiallyFilled(_)
+                | OrderEvent::OrderFilled(_)
+                | OrderEvent::OrderCanceled(_)
+                | OrderEvent::OrderRejected(_)
+                | OrderEvent::OrderExpired(_)
+        );
+        self.events.push(event);
+
+        if !affects_linked {
+            return;
+        }
+        match (&order.contingency_type, &order.linked_order_ids) {
+            (Some(contingency_type), Some(linked_order_ids))
+                if *contingency_type != ContingencyType::NoContingency =>
+            {
+                self.contingencies.push(ContingencyTrigger {
+                    client_order_id: order.client_order_id.clone(),
+                    contingency_type: *contingency_type,
+                    linked_order_ids: linked_order_ids.clone(),
+                    status: order.status,
+                    filled_qty: order.filled_qty,
+                    leaves_qty: order.leaves_qty,
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+
+/// The maximum number of idle fill vectors kept for reuse per thread.
+const FILLS_POOL_MAX_IDLE: usize = 64;
+
+/// The maximum number of fills an idle vector keeps room for.
+const FILLS_POOL_MAX_CAPACITY: usize = 1_024;
+
+thread_local! {
+    static FILLS_POOL: RefCell<ObjectPool<Vec<OrderFilled>>> = RefCell::new(
+        ObjectPool::new(FILLS_POOL_MAX_IDLE, Vec::new).with_max_capacity(FILLS_POOL_MAX_CAPACITY),
+    );
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`OrderMatchingEngine`].
+///
+/// This struct wraps `OrderMatchingEngine` in a way that makes it compatible with C function
+/// calls, enabling interaction with `OrderMatchingEngine` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of
+/// `OrderMatchingEngine_API` to be dereferenced to `OrderMatchingEngine`, providing access
+/// to `OrderMatchingEngine`'s methods without having to manually access the underlying
+/// `OrderMatchingEngine` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct OrderMatchingEngine_API(Box<OrderMatchingEngine<BaseInstrument>>);
+
+impl Deref for OrderMatchingEngine_API {
+    type Target = OrderMatchingEngine<BaseInstrument>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for OrderMatchingEngine_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// # Safety
+///
+/// - Assumes `random_seed` is either null or a valid pointer to a `u64`.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn order_matching_engine_new(
+    instrument: &BaseInstrument,
+    book_type: BookType,
+    account_id: AccountId,
+    bar_execution: u8,
+    reject_stop_orders: u8,
+    use_queue_position: u8,
+    support_gtd_orders: u8,
+    prob_fill_on_limit: f64,
+    prob_fill_on_stop: f64,
+    prob_slippage: f64,
+    random_seed: *const u64,
+) -> OrderMatchingEngine_API {
+    let config = OrderMatchingEngineConfig {
+        bar_execution: bar_execution != 0,
+        reject_stop_orders: reject_stop_orders != 0,
+        use_queue_position: use_queue_position != 0,
+        support_gtd_orders: support_gtd_orders != 0,
+    };
+    let fill_model = FillModel::new(
+        prob_fill_on_limit,
+        prob_fill_on_stop,
+        prob_slippage,
+        random_seed.as_ref().copied(),
+    );
+    OrderMatchingEngine_API(Box::new(OrderMatchingEngine::new(
+        instrument.clone(),
+        book_type,
+        account_id,
+        config,
+        fill_model,
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_engine_drop(engine: OrderMatchingEngine_API) {
+    drop(engine); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_engine_reset(engine: &mut OrderMatchingEngine_API) {
+    engine.reset();
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_engine_set_calendar(
+    engine: &mut OrderMatchingEngine_API,
+    calendar: &TradingCalendar_API,
+) {
+    engine.set_calendar(calendar.deref().clone());
+}
+
+/// Returns zero, otherwise a non-zero status code with the last error set if any event
+/// generated by the engine could not be applied to its order.
+fn drain_errors_status(engine: &mut OrderMatchingEngine_API) -> u8 {
+    let errors = engine.drain_errors();
+    if errors.is_empty() {
+        return FFI_OK;
+    }
+    let message = errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join("; ");
+    set_last_error(message);
+    FFI_ERROR
+}
+
+/// Returns zero on success, otherwise a non-zero status code with the last error set.
+#[no_mangle]
+pub extern "C" fn order_matching_engine_process_order_book_delta(
+    engine: &mut OrderMatchingEngine_API,
+    delta: OrderBookDelta,
+) -> u8 {
+    engine.process_order_book_delta(delta);
+    drain_errors_status(engine)
+}
+
+/// Returns zero on success, otherwise a non-zero status code with the last error set.
+#[no_mangle]
+pub extern "C" fn order_matching_engine_process_quote_tick(
+    engine: &mut OrderMatchingEngine_API,
+    tick: &QuoteTick,
+) -> u8 {
+    engine.process_quote_tick(tick);
+    drain_errors_status(engine)
+}
+
+/// Returns zero on success, otherwise a non-zero status code with the last error set.
+#[no_mangle]
+pub extern "C" fn order_matching_engine_process_trade_tick(
+    engine: &mut OrderMatchingEngine_API,
+    tick: &TradeTick,
+) -> u8 {
+    engine.process_trade_tick(tick);
+    drain_errors_status(engine)
+}
+
+/// Returns zero on success, otherwise a non-zero status code with the last error set.
+#[no_mangle]
+pub extern "C" fn order_matching_engine_process_bar(
+    engine: &mut OrderMatchingEngine_API,
+    bar: &Bar,
+) -> u8 {
+    engine.process_bar(bar);
+    drain_errors_status(engine)
+}
+
+/// Submits the order initialized by `init` to the engine for the engines account.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// the order cannot be submitted.
+#[no_mangle]
+pub extern "C" fn order_matching_engine_process_order(
+    engine: &mut OrderMatchingEngine_API,
+    init: &OrderInitialized,
+    ts_now: UnixNanos,
+) -> u8 {
+    let mut order = Order::from(init.clone());
+    let submitted = OrderSubmitted {
+        trader_id: order.trader_id.clone(),
+        strategy_id: order.strategy_id.clone(),
+        instrument_id: order.instrument_id.clone(),
+        client_order_id: order.client_order_id.clone(),
+        account_id: engine.account_id.clone(),
+        event_id: UUID4::new(),
+        ts_event: ts_now,
+        ts_init: ts_now,
+    };
+    if let Err(e) = order.apply(OrderEvent::OrderSubmitted(submitted)) {
+        set_last_error(e);
+        return FFI_ERROR;
+    }
+    engine.process_order(order, ts_now);
+    drain_errors_status(engine)
+}
+
+/// Cancels the open order, writing 1 to `canceled` if it was canceled, otherwise 0.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set.
+///
+/// # Safety
+///
+/// - Assumes `canceled` is a valid pointer to write the result to.
+#[no_mangle]
+pub unsafe extern "C" fn order_matching_engine_cancel_order(
+    engine: &mut OrderMatchingEngine_API,
+    client_order_id: &ClientOrderId,
+    ts_now: UnixNanos,
+    canceled: *mut u8,
+) -> u8 {
+    let result = u8::from(engine.cancel_order(client_order_id, ts_now));
+    match drain_errors_status(engine) {
+        FFI_OK => write_result(Ok::<_, String>(result), canceled, |_| FFI_ERROR),
+        status => status,
+    }
+}
+
+/// Returns zero on success, otherwise a non-zero status code with the last error set.
+#[no_mangle]
+pub extern "C" fn order_matching_engine_iterate(
+    engine: &mut OrderMatchingEngine_API,
+    ts_now: UnixNanos,
+) -> u8 {
+    engine.iterate(ts_now);
+    drain_errors_status(engine)
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_engine_open_order_count(
+    engine: &OrderMatchingEngine_API,
+) -> usize {
+    engine.orders.len()
+}
+
+/// Returns the earliest expire time of the open GTD orders, or 0 if there are none.
+#[no_mangle]
+pub extern "C" fn order_matching_engine_next_expire_time_ns(
+    engine: &OrderMatchingEngine_API,
+) -> UnixNanos {
+    engine.next_expire_time_ns().unwrap_or(0)
+}
+
+/// Drains the buffered fill events into a vector from the pool of the calling thread.
+///
+/// The returned vector must be freed with `vec_order_filled_drop`, or `vec_order_filled_recycle`
+/// to reuse its allocation for the next call.
+#[no_mangle]
+pub extern "C" fn order_matching_engine_drain_fills(engine: &mut OrderMatchingEngine_API) -> CVec {
+    let mut fills = FILLS_POOL.with(|pool| pool.borrow_mut().acquire());
+    engine.drain_fills_into(&mut fills, usize::MAX);
+    if fills.is_empty() {
+        FILLS_POOL.with(|pool| pool.borrow_mut().release(fills));
+        return CVec::empty();
+    }
+    CVec::from_elements(fills)
+}
+
+/// Drains up to `max` buffered fill events into the `buffer`, reusing its allocation, and
+/// writes the number of fills drained to `count`.
+///
+/// The fills from the previous call are dropped, so a single buffer created with
+/// `vec_order_filled_new` can be polled repeatedly, then freed with `vec_order_filled_drop`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `buffer` holds elements other than fills (in which case nothing is written to `count`).
+///
+/// # Safety
+///
+/// - Assumes `buffer` was created with `vec_order_filled_new` or from fills, and has not been
+///   dropped.
+/// - Assumes `count` is a valid pointer to write the result to.
+#[no_mangle]
+pub unsafe extern "C" fn order_matching_engine_drain_fills_into(
+    engine: &mut OrderMatchingEngine_API,
+    buffer: &mut CVec,
+    max: usize,
+    count: *mut usize,
+) -> u8 {
+    if buffer.cap != 0 && !buffer.is_element_type::<OrderFilled>() {
+        set_last_error(format!(
+            "`buffer` element type was {:?}, expected {:?}",
+            buffer.element_type,
+            CVecElementType::OrderFilled,
+        ));
+        return FFI_ERROR;
+    }
+    let drained = buffer.refill(|fills: &mut Vec<OrderFilled>| engine.drain_fills_into(fills, max));
+    write_result(Ok::<_, String>(drained), count, |_| FFI_ERROR)
+}
+
+/// Returns an empty vector of fills allocated for `capacity` fills, to pass to
+/// `order_matching_engine_drain_fills_into`.
+#[no_mangle]
+pub extern "C" fn vec_order_filled_new(capacity: usize) -> CVec {
+    CVec::with_capacity::<OrderFilled>(capacity)
+}
+
+#[allow(clippy::drop_non_drop)]
+#[no_mangle]
+pub extern "C" fn vec_order_filled_drop(v: CVec) {
+    let CVec { ptr, len, cap, .. } = v;
+    if ptr.is_null() {
+        return;
+    }
+    let data: Vec<OrderFilled> = unsafe { Vec::from_raw_parts(ptr as *mut OrderFilled, len, cap) };
+    drop(data); // Memory freed here
+}
+
+/// Drops the fills, returning the vector to the pool of the calling thread for reuse by
+/// `order_matching_engine_drain_fills`.
+#[no_mangle]
+pub extern "C" fn vec_order_filled_recycle(v: CVec) {
+    if v.ptr.is_null() {
+        return;
+    }
+    let fills: Vec<OrderFilled> = unsafe { v.into_vec() };
+    FILLS_POOL.with(|pool| pool.borrow_mut().release(fills));
+}
+
+/// Returns the statistics of the fill vector pool of the calling thread.
+#[no_mangle]
+pub extern "C" fn vec_order_filled_pool_stats() -> PoolStats {
+    FILLS_POOL.with(|pool| pool.borrow().stats())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{mem::MaybeUninit, str::FromStr};
+
+    use nautilus_common::calendar::Session;
+    use nautilus_core::ffi_error::last_error;
+    use nautilus_model::{
+        data::bar::BarType,
+        enums::{BookAction, TrailingOffsetType, TriggerType},
+        events::order::OrderInitializedBuilder,
+        identifiers::{
+            instrument_id::InstrumentId, strategy_id::StrategyId, trader_id::TraderId, venue::Venue,
+        },
+        testkit::order_side,
+    };
+    use nautilus_test_kit::instruments::audusd_sim;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn engine(
+        book_type: BookType,
+        config: OrderMatchingEngineConfig,
+    ) -> OrderMatchingEngine<BaseInstrument> {
+        OrderMatchingEngine::new(
+            audusd_sim(),
+            book_type,
+            AccountId::new("SIM-001"),
+            config,
+            FillModel::default(),
+        )
+    }
+
+    fn submitted_order(
+        client_order_id: &str,
+        side: OrderSide,
+        order_type: OrderType,
+        quantity: &str,
+        price: Option<&str>,
+        trigger_price: Option<&str>,
+    ) -> Order {
+        let init = OrderInitializedBuilder::default()
+            .trader_id(TraderId::new("TRADER-001"))
+            .strategy_id(StrategyId::new("S-001"))
+            .instrument_id(InstrumentId::from_str("AUD/USD.SIM").unwrap())
+            .client_order_id(ClientOrderId::new(client_order_id))
+            .order_side(side)
+            .order_type(order_type)
+            .quantity(Quantity::from(quantity))
+            .price(price.map(Price::from))
+            .trigger_price(trigger_price.map(Price::from))
+            .time_in_force(TimeInForce::Gtc)
+            .build()
+            .unwrap();
+        let mut order = Order::from(init);
+        let submitted = OrderSubmitted {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            account_id: AccountId::new("SIM-001"),
+            event_id: UUID4::new(),
+            ts_event: 0,
+            ts_init: 0,
+        };
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order
+    }
+
+    fn quote_tick(bid: &str, ask: &str, ts: UnixNanos) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            bid: Price::from(bid),
+            ask: Price::from(ask),
+            bid_size: Quantity::from("100000"),
+            ask_size: Quantity::from("100000"),
+            ts_event: ts,
+            ts_init: ts,
+        }
+    }
+
+    fn trade_tick(price: &str, size: &str, aggressor_side: AggressorSide) -> TradeTick {
+        TradeTick {
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            price: Price::from(price),
+            size: Quantity::from(size),
+            aggressor_side,
+            trade_id: TradeId::new("T-1"),
+            ts_event: 2,
+            ts_init: 2,
+        }
+    }
+
+    fn add_delta(side: OrderSide, price: &str, size: &str, sequence: u64) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            BookAction::Add,
+            BookOrder::new(side, Price::from(price), Quantity::from(size), sequence),
+            0,
+            sequence,
+            1,
+            1,
+        )
+    }
+
+    fn drain_fills_into(
+        engine: &mut OrderMatchingEngine_API,
+        buffer: &mut CVec,
+        max: usize,
+    ) -> usize {
+        let mut count = MaybeUninit::<usize>::uninit();
+        let status = unsafe {
+            order_matching_engine_drain_fills_into(engine, buffer, max, count.as_mut_ptr())
+        };
+        assert_eq!(status, FFI_OK);
+        unsafe { count.assume_init() }
+    }
+
+    #[test]
+    fn test_drain_fills_into_reused_buffer() {
+        let mut engine = OrderMatchingEngine_API(Box::new(engine(
+            BookType::L1_TBBO,
+            OrderMatchingEngineConfig::default(),
+        )));
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Market,
+            "150000",
+            None,
+            None,
+        );
+        engine.process_order(order, 2);
+        let mut buffer = vec_order_filled_new(1);
+
+        let first = drain_fills_into(&mut engine, &mut buffer, 1);
+        let first_qty = unsafe { buffer.as_slice::<OrderFilled>()[0].last_qty };
+        let second = drain_fills_into(&mut engine, &mut buffer, 1);
+        let second_qty = unsafe { buffer.as_slice::<OrderFilled>()[0].last_qty };
+        let third = drain_fills_into(&mut engine, &mut buffer, 1);
+
+        assert_eq!((first, second, third), (1, 1, 0));
+        assert_eq!(first_qty, Quantity::from("100000"));
+        assert_eq!(second_qty, Quantity::from("50000"));
+        assert_eq!(buffer.cap, 1);
+        vec_order_filled_drop(buffer);
+    }
+
+    #[test]
+    fn test_vec_order_filled_drop_empty() {
+        let mut engine = OrderMatchingEngine_API(Box::new(engine(
+            BookType::L1_TBBO,
+            OrderMatchingEngineConfig::default(),
+        )));
+        let fills = order_matching_engine_drain_fills(&mut engine);
+        assert!(fills.ptr.is_null());
+        vec_order_filled_drop(fills);
+        vec_order_filled_drop(CVec::empty());
+    }
+
+    #[test]
+    fn test_vec_order_filled_recycle_reuses_vector() {
+        let mut engine = OrderMatchingEngine_API(Box::new(engine(
+            BookType::L1_TBBO,
+            OrderMatchingEngineConfig::default(),
+        )));
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+        let before = vec_order_filled_pool_stats();
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Market,
+            "150000",
+            None,
+            None,
+        );
+        engine.process_order(order, 2);
+        let fills = order_matching_engine_drain_fills(&mut engine);
+        let ptr = fills.ptr;
+        vec_order_filled_recycle(fills);
+
+        let order = submitted_order(
+            "O-2",
+            OrderSide::Buy,
+            OrderType::Market,
+            "50000",
+            None,
+            None,
+        );
+        engine.process_order(order, 3);
+        let fills = order_matching_engine_drain_fills(&mut engine);
+
+        let after = vec_order_filled_pool_stats();
+        assert_eq!(fills.ptr, ptr);
+        assert_eq!(fills.len, 1);
+        assert_eq!(after.reused - before.reused, 1);
+        assert_eq!(after.released - before.released, 1);
+        vec_order_filled_recycle(fills);
+    }
+
+    #[test]
+    fn test_drain_fills_into_buffer_from_drain_fills() {
+        let mut engine = OrderMatchingEngine_API(Box::new(engine(
+            BookType::L1_TBBO,
+            OrderMatchingEngineConfig::default(),
+        )));
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Market,
+            "150000",
+            None,
+            None,
+        );
+        engine.process_order(order, 2);
+        let mut buffer = order_matching_engine_drain_fills(&mut engine);
+        assert_eq!(buffer.len, 2);
+
+        let order = submitted_order(
+            "O-2",
+            OrderSide::Buy,
+            OrderType::Market,
+            "50000",
+            None,
+            None,
+        );
+        engine.process_order(order, 3);
+        let count = drain_fills_into(&mut engine, &mut buffer, 10);
+        let fills = unsafe { buffer.as_slice::<OrderFilled>() };
+
+        assert_eq!(count, 1);
+        assert_eq!(fills[0].client_order_id, ClientOrderId::from("O-2"));
+        assert_eq!(fills[0].last_qty, Quantity::from("50000"));
+        vec_order_filled_drop(buffer);
+    }
+
+    #[test]
+    fn test_drain_fills_into_buffer_of_other_elements() {
+        let mut engine = OrderMatchingEngine_API(Box::new(engine(
+            BookType::L1_TBBO,
+            OrderMatchingEngineConfig::default(),
+        )));
+        let mut buffer = CVec::from_elements(vec![1.0_f64, 2.0]);
+        let mut count = MaybeUninit::<usize>::uninit();
+
+        let status = unsafe {
+            order_matching_engine_drain_fills_into(&mut engine, &mut buffer, 10, count.as_mut_ptr())
+        };
+
+        assert_eq!(status, FFI_ERROR);
+        assert!(last_error().unwrap().contains("expected OrderFilled"));
+        assert_eq!(buffer.len, 2);
+        drop(unsafe { Vec::from_raw_parts(buffer.ptr as *mut f64, buffer.len, buffer.cap) });
+    }
+
+    #[test]
+    fn test_market_order_fills_at_best_ask() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Market,
+            "150000",
+            None,
+            None,
+        );
+        engine.process_order(order, 2);
+
+        let fills = engine.drain_fills();
+        assert_eq!(fills.len(), 2);
+        assert!(fills
+            .iter()
+            .all(|fill| fill.last_px == Price::from("1.00002")));
+        assert!(fills
+            .iter()
+            .all(|fill| fill.liquidity_side == LiquiditySide::Taker));
+        assert_eq!(fills[0].last_qty, Quantity::from("100000"));
+        assert_eq!(fills[1].last_qty, Quantity::from("50000"));
+        assert_eq!(fills[0].trade_id, TradeId::new("SIM-1"));
+        assert_eq!(fills[0].venue_order_id, VenueOrderId::new("SIM-1"));
+        assert_eq!(fills[0].account_id, AccountId::new("SIM-001"));
+        assert!(engine.open_orders().is_empty());
+    }
+
+    #[test]
+    fn test_market_order_rejected_with_no_market() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Sell,
+            OrderType::Market,
+            "1000",
+            None,
+            None,
+        );
+        engine.process_order(order, 1);
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 1);
+        assert!(
+            matches!(&events[0], OrderEvent::OrderRejected(event) if event.reason == "No market for AUD/USD.SIM")
+        );
+    }
+
+    #[test]
+    fn test_orders_rejected_and_not_matched_when_market_closed() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        let mut calendar = TradingCalendar::new(Venue::new("SIM"), 0);
+        calendar.add_weekday_session(Session::new(10, 20)); // 1970-01-01 was a Thursday
+        calendar.add_weekday_session(Session::new(30, 40));
+        engine.set_calendar(calendar);
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let market = submitted_order("O-1", OrderSide::Buy, OrderType::Market, "1000", None, None);
+        engine.process_order(market, 2);
+        let events = engine.drain_events();
+        assert!(
+            matches!(&events[0], OrderEvent::OrderRejected(event) if event.reason == "Market for AUD/USD.SIM was closed")
+        );
+
+        let limit = submitted_order(
+            "O-2",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.99990"),
+            None,
+        );
+        engine.process_order(limit, 10_000_000_000);
+        assert_eq!(engine.open_orders().len(), 1);
+
+        engine.process_quote_tick(&quote_tick("0.99980", "0.99985", 25_000_000_000));
+        assert!(engine.drain_fills().is_empty());
+        assert_eq!(engine.open_orders().len(), 1);
+
+        engine.process_quote_tick(&quote_tick("0.99980", "0.99985", 30_000_000_000));
+        assert_eq!(engine.drain_fills().len(), 1);
+    }
+
+    #[test]
+    fn test_order_with_invalid_precision_rejected() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("1.000"),
+            None,
+        );
+        engine.process_order(order, 2);
+
+        let events = engine.drain_events();
+        assert!(matches!(&events[0], OrderEvent::OrderRejected(_)));
+    }
+
+    #[test]
+    fn test_market_order_walks_l2_book_and_cancels_remainder() {
+        let mut engine = engine(BookType::L2_MBP, OrderMatchingEngineConfig::default());
+        engine.process_order_book_delta(add_delta(OrderSide::Sell, "1.00002", "1000", 1));
+        engine.process_order_book_delta(add_delta(OrderSide::Sell, "1.00003", "2000", 2));
+
+        let order = submitted_order("O-1", OrderSide::Buy, OrderType::Market, "5000", None, None);
+        engine.process_order(order, 3);
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 4);
+        assert!(matches!(&events[0], OrderEvent::OrderAccepted(_)));
+        assert!(
+            matches!(&events[1], OrderEvent::OrderPartiallyFilled(fill) if fill.last_px == Price::from("1.00002") && fill.last_qty == Quantity::from("1000"))
+        );
+        assert!(
+            matches!(&events[2], OrderEvent::OrderPartiallyFilled(fill) if fill.last_px == Price::from("1.00003") && fill.last_qty == Quantity::from("2000"))
+        );
+        assert!(matches!(&events[3], OrderEvent::OrderCanceled(_)));
+    }
+
+    #[test]
+    fn test_passive_limit_order_fills_as_maker_when_market_moves() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.99990"),
+            None,
+        );
+        engine.process_order(order, 2);
+        assert_eq!(engine.open_orders().len(), 1);
+        assert!(engine.drain_fills().is_empty());
+
+        engine.process_quote_tick(&quote_tick("0.99980", "0.99985", 3));
+
+        let fills = engine.drain_fills();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].last_px, Price::from("0.99990"));
+        assert_eq!(fills[0].liquidity_side, LiquiditySide::Maker);
+        assert_eq!(fills[0].ts_event, 3);
+        assert!(engine.open_orders().is_empty());
+    }
+
+    #[test]
+    fn test_marketable_post_only_limit_order_rejected() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let mut order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("1.00005"),
+            None,
+        );
+        order.is_post_only = true;
+        engine.process_order(order, 2);
+
+        let events = engine.drain_events();
+        assert!(matches!(&events[0], OrderEvent::OrderRejected(_)));
+        assert!(engine.open_orders().is_empty());
+    }
+
+    #[test]
+    fn test_limit_order_fills_through_queue_position() {
+        let config = OrderMatchingEngineConfig {
+            use_queue_position: true,
+            ..Default::default()
+        };
+        let mut engine = engine(BookType::L2_MBP, config);
+        engine.process_order_book_delta(add_delta(OrderSide::Buy, "1.00000", "1000", 1));
+        engine.process_order_book_delta(add_delta(OrderSide::Sell, "1.00002", "1000", 2));
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "500",
+            Some("1.00000"),
+            None,
+        );
+        let client_order_id = order.client_order_id.clone();
+        engine.process_order(order, 3);
+        assert_eq!(
+            engine.queue_ahead(&client_order_id),
+            Some(Quantity::from("1000")),
+        );
+
+        engine.process_trade_tick(&trade_tick("1.00000", "600", AggressorSide::Seller));
+        assert!(engine.drain_fills().is_empty());
+        assert_eq!(
+            engine.queue_ahead(&client_order_id),
+            Some(Quantity::from("400"))
+        );
+
+        // Buyer aggressed trades do not consume the bid queue
+        engine.process_trade_tick(&trade_tick("1.00000", "600", AggressorSide::Buyer));
+        assert_eq!(
+            engine.queue_ahead(&client_order_id),
+            Some(Quantity::from("400"))
+        );
+
+        engine.process_trade_tick(&trade_tick("1.00000", "600", AggressorSide::Seller));
+        let fills = engine.drain_fills();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].last_qty, Quantity::from("200"));
+        assert_eq!(fills[0].liquidity_side, LiquiditySide::Maker);
+        assert_eq!(
+            engine.order(&client_order_id).unwrap().status,
+            OrderStatus::PartiallyFilled,
+        );
+    }
+
+    fn delta(
+        action: BookAction,
+        side: OrderSide,
+        price: &str,
+        size: &str,
+        order_id: u64,
+        sequence: u64,
+    ) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            action,
+            BookOrder::new(side, Price::from(price), Quantity::from(size), order_id),
+            0,
+            sequence,
+            1,
+            1,
+        )
+    }
+
+    #[test]
+    fn test_l3_limit_order_advances_as_orders_ahead_cancel() {
+        let config = OrderMatchingEngineConfig {
+            use_queue_position: true,
+            ..Default::default()
+        };
+        let mut engine = engine(BookType::L3_MBO, config);
+        engine.process_order_book_deltas(OrderBookDeltas {
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            deltas: vec![
+                delta(BookAction::Add, OrderSide::Buy, "1.00000", "400", 1, 1),
+                delta(BookAction::Add, OrderSide::Buy, "1.00000", "600", 2, 2),
+                delta(BookAction::Add, OrderSide::Sell, "1.00002", "1000", 3, 3),
+            ],
+            flags: 0,
+            sequence: 3,
+            ts_event: 1,
+            ts_init: 1,
+        });
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "300",
+            Some("1.00000"),
+            None,
+        );
+        let client_order_id = order.client_order_id.clone();
+        engine.process_order(order, 2);
+        assert_eq!(
+            engine.queue_ahead(&client_order_id),
+            Some(Quantity::from("1000"))
+        );
+
+        // Orders joining the level after ours do not affect our position
+        engine.process_order_book_delta(delta(
+            BookAction::Add,
+            OrderSide::Buy,
+            "1.00000",
+            "5000",
+            4,
+            4,
+        ));
+        assert_eq!(
+            engine.queue_ahead(&client_order_id),
+            Some(Quantity::from("1000"))
+        );
+
+        engine.process_order_book_delta(delta(
+            BookAction::Delete,
+            OrderSide::Buy,
+            "1.00000",
+            "400",
+            1,
+            5,
+        ));
+        engine.process_order_book_delta(delta(
+            BookAction::Update,
+            OrderSide::Buy,
+            "1.00000",
+            "100",
+            2,
+            6,
+        ));
+        assert_eq!(
+            engine.queue_ahead(&client_order_id),
+            Some(Quantity::from("100"))
+        );
+
+        engine.process_trade_tick(&trade_tick("1.00000", "250", AggressorSide::Seller));
+        let fills = engine.drain_fills();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].last_qty, Quantity::from("150"));
+        assert_eq!(fills[0].liquidity_side, LiquiditySide::Maker);
+
+        // The book update for the filled order ahead does not move the queue backwards
+        engine.process_order_book_delta(delta(
+            BookAction::Delete,
+            OrderSide::Buy,
+            "1.00000",
+            "100",
+            2,
+            7,
+        ));
+        assert_eq!(
+            engine.queue_ahead(&client_order_id),
+            Some(Quantity::from("0"))
+        );
+    }
+
+    #[test]
+    fn test_stop_order_in_market_rejected() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::StopMarket,
+            "1000",
+            None,
+            Some("1.00001"),
+        );
+        engine.process_order(order, 2);
+
+        let events = engine.drain_events();
+        assert!(matches!(&events[0], OrderEvent::OrderRejected(_)));
+    }
+
+    #[test]
+    fn test_stop_market_order_fills_when_triggered() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Sell,
+            OrderType::StopMarket,
+            "1000",
+            None,
+            Some("0.99990"),
+        );
+        engine.process_order(order, 2);
+        assert_eq!(engine.open_orders().len(), 1);
+
+        engine.process_quote_tick(&quote_tick("0.99989", "0.99991", 3));
+
+        let fills = engine.drain_fills();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].last_px, Price::from("0.99989"));
+        assert_eq!(fills[0].liquidity_side, LiquiditySide::Taker);
+        assert!(engine.open_orders().is_empty());
+    }
+
+    #[test]
+    fn test_stop_limit_order_triggers_and_rests() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::StopLimit,
+            "1000",
+            Some("1.00008"),
+            Some("1.00010"),
+        );
+        let client_order_id = order.client_order_id.clone();
+        engine.process_order(order, 2);
+
+        engine.process_quote_tick(&quote_tick("1.00009", "1.00011", 3));
+
+        let order = engine.order(&client_order_id).unwrap();
+        assert_eq!(order.status, OrderStatus::Triggered);
+        assert!(engine.drain_fills().is_empty());
+
+        engine.process_quote_tick(&quote_tick("1.00005", "1.00007", 4));
+
+        let fills = engine.drain_fills();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].last_px, Price::from("1.00008"));
+    }
+
+    #[test]
+    fn test_trailing_stop_market_order_follows_market_and_fills() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let mut order = submitted_order(
+            "O-1",
+            OrderSide::Sell,
+            OrderType::TrailingStopMarket,
+            "1000",
+            None,
+            None,
+        );
+        order.trigger_type = Some(TriggerType::BidAsk);
+        order.trailing_offset = Some(Price::from("0.00010"));
+        order.trailing_offset_type = Some(TrailingOffsetType::Price);
+        let client_order_id = order.client_order_id.clone();
+        engine.process_order(order, 2);
+
+        let order = engine.order(&client_order_id).unwrap();
+        assert_eq!(order.trigger_price, Some(Price::from("0.99990")));
+
+        // Bid rises so the trigger trails up, then falls back through it
+        engine.process_quote_tick(&quote_tick("1.00020", "1.00022", 3));
+        let order = engine.order(&client_order_id).unwrap();
+        assert_eq!(order.trigger_price, Some(Price::from("1.00010")));
+
+        engine.process_quote_tick(&quote_tick("1.00015", "1.00017", 4));
+        let order = engine.order(&client_order_id).unwrap();
+        assert_eq!(order.trigger_price, Some(Price::from("1.00010")));
+        assert!(engine.drain_fills().is_empty());
+
+        engine.process_quote_tick(&quote_tick("1.00009", "1.00011", 5));
+        let fills = engine.drain_fills();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].last_px, Price::from("1.00009"));
+        assert!(engine.open_orders().is_empty());
+    }
+
+    #[test]
+    fn test_trailing_stop_order_rejected_with_no_last_price() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let mut order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::TrailingStopLimit,
+            "1000",
+            None,
+            None,
+        );
+        order.trigger_type = Some(TriggerType::LastTrade);
+        order.trailing_offset = Some(Price::from("0.00010"));
+        order.limit_offset = Some(Price::from("0.00020"));
+        order.trailing_offset_type = Some(TrailingOffsetType::Price);
+        engine.process_order(order, 2);
+
+        let events = engine.drain_events();
+        assert!(matches!(events.last(), Some(OrderEvent::OrderRejected(_))));
+    }
+
+    #[test]
+    fn test_gtd_order_expires_at_expire_time() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let mut order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.99990"),
+            None,
+        );
+        order.time_in_force = TimeInForce::Gtd;
+        order.expire_time = Some(10);
+        engine.process_order(order, 2);
+        assert_eq!(engine.next_expire_time_ns(), Some(10));
+
+        engine.iterate(9);
+        assert_eq!(engine.open_orders().len(), 1);
+
+        engine.iterate(10);
+        assert!(engine.open_orders().is_empty());
+        assert_eq!(engine.next_expire_time_ns(), None);
+        let events = engine.drain_events();
+        assert!(matches!(events.last(), Some(OrderEvent::OrderExpired(_))));
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some(1))]
+    fn test_gtd_order_rejected_without_future_expire_time(#[case] expire_time: Option<u64>) {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let mut order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.99990"),
+            None,
+        );
+        order.time_in_force = TimeInForce::Gtd;
+        order.expire_time = expire_time;
+        engine.process_order(order, 2);
+
+        let events = engine.drain_events();
+        assert!(matches!(events.last(), Some(OrderEvent::OrderRejected(_))));
+    }
+
+    #[test]
+    fn test_gtd_order_treated_as_gtc_when_unsupported() {
+        let config = OrderMatchingEngineConfig {
+            support_gtd_orders: false,
+            ..Default::default()
+        };
+        let mut engine = engine(BookType::L1_TBBO, config);
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let mut order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.99990"),
+            None,
+        );
+        order.time_in_force = TimeInForce::Gtd;
+        order.expire_time = Some(10);
+        engine.process_order(order, 2);
+        engine.iterate(20);
+
+        assert_eq!(engine.open_orders().len(), 1);
+        assert_eq!(engine.next_expire_time_ns(), None);
+    }
+
+    #[test]
+    fn test_bar_execution_fills_limit_order() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Sell,
+            OrderType::Limit,
+            "1000",
+            Some("1.00010"),
+            None,
+        );
+        engine.process_order(order, 2);
+
+        let bar = Bar {
+            bar_type: BarType::from_str("AUD/USD.SIM-1-MINUTE-LAST-EXTERNAL").unwrap(),
+            open: Price::from("1.00001"),
+            high: Price::from("1.00012"),
+            low: Price::from("0.99995"),
+            close: Price::from("1.00003"),
+            volume: Quantity::from("100000"),
+            ts_event: 3,
+            ts_init: 3,
+        };
+        engine.process_bar(&bar);
+
+        let fills = engine.drain_fills();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].last_px, Price::from("1.00010"));
+        assert_eq!(fills[0].liquidity_side, LiquiditySide::Maker);
+    }
+
+    #[test]
+    fn test_cancel_order() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.99990"),
+            None,
+        );
+        let client_order_id = order.client_order_id.clone();
+        engine.process_order(order, 2);
+
+        assert!(engine.cancel_order(&client_order_id, 3));
+        assert!(!engine.cancel_order(&client_order_id, 4));
+        assert!(engine.open_orders().is_empty());
+        assert!(matches!(
+            engine.drain_events().last(),
+            Some(OrderEvent::OrderCanceled(_))
+        ));
+    }
+
+    #[test]
+    fn test_event_not_applicable_to_order_buffers_error() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        let mut order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.99990"),
+            None,
+        );
+
+        engine.expire(&mut order, 1);
+
+        assert_eq!(order.status, OrderStatus::Submitted);
+        assert!(engine.drain_events().is_empty());
+        assert_eq!(
+            engine.drain_errors(),
+            vec![OrderError::InvalidStateTransition {
+                status: OrderStatus::Submitted,
+                event: "OrderExpired",
+            }]
+        );
+        assert!(engine.drain_errors().is_empty());
+    }
+
+    fn link(
+        mut order: Order,
+        contingency_type: ContingencyType,
+        linked_order_ids: &[&str],
+        parent_order_id: Option<&str>,
+    ) -> Order {
+        order.contingency_type = Some(contingency_type);
+        order.linked_order_ids = Some(
+            linked_order_ids
+                .iter()
+                .map(|id| ClientOrderId::new(id))
+                .collect(),
+        );
+        order.parent_order_id = parent_order_id.map(ClientOrderId::new);
+        order
+    }
+
+    #[test]
+    fn test_oco_fill_cancels_linked_order() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let stop_loss = submitted_order(
+            "O-1",
+            OrderSide::Sell,
+            OrderType::StopMarket,
+            "1000",
+            None,
+            Some("0.99990"),
+        );
+        let take_profit = submitted_order(
+            "O-2",
+            OrderSide::Sell,
+            OrderType::Limit,
+            "1000",
+            Some("1.00010"),
+            None,
+        );
+        engine.process_order(link(stop_loss, ContingencyType::Oco, &["O-2"], None), 2);
+        engine.process_order(link(take_profit, ContingencyType::Oco, &["O-1"], None), 2);
+        assert_eq!(engine.open_orders().len(), 2);
+        engine.drain_events();
+
+        engine.process_quote_tick(&quote_tick("1.00010", "1.00012", 3));
+
+        let events = engine.drain_events();
+        assert!(engine.open_orders().is_empty());
+        assert!(
+            matches!(&events[0], OrderEvent::OrderFilled(e) if e.client_order_id == ClientOrderId::new("O-2"))
+        );
+        assert!(
+            matches!(&events[1], OrderEvent::OrderCanceled(e) if e.client_order_id == ClientOrderId::new("O-1"))
+        );
+        assert_eq!(events.len(), 2);
+    }
+
+    #[test]
+    fn test_oto_child_held_until_parent_fills() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let entry = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.99990"),
+            None,
+        );
+        let take_profit = submitted_order(
+            "O-2",
+            OrderSide::Sell,
+            OrderType::Limit,
+            "1000",
+            Some("1.00010"),
+            None,
+        );
+        engine.process_order(link(entry, ContingencyType::Oto, &["O-2"], None), 2);
+        engine.process_order(link(take_profit, ContingencyType::Oco, &[], Some("O-1")), 2);
+        assert_eq!(engine.open_orders().len(), 1);
+        assert_eq!(engine.pending_orders().len(), 1);
+        assert_eq!(engine.drain_events().len(), 1); // Entry accepted only
+
+        engine.process_quote_tick(&quote_tick("0.99980", "0.99988", 3));
+
+        let events = engine.drain_events();
+        assert!(matches!(&events[0], OrderEvent::OrderFilled(_)));
+        assert!(
+            matches!(&events[1], OrderEvent::OrderAccepted(e) if e.client_order_id == ClientOrderId::new("O-2"))
+        );
+        assert!(engine.pending_orders().is_empty());
+        assert!(engine.order(&ClientOrderId::new("O-2")).is_some());
+    }
+
+    #[test]
+    fn test_oto_child_rejected_when_parent_canceled() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let entry = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.99990"),
+            None,
+        );
+        let stop_loss = submitted_order(
+            "O-2",
+            OrderSide::Sell,
+            OrderType::StopMarket,
+            "1000",
+            None,
+            Some("0.99980"),
+        );
+        engine.process_order(link(entry, ContingencyType::Oto, &["O-2"], None), 2);
+        engine.process_order(link(stop_loss, ContingencyType::Oco, &[], Some("O-1")), 2);
+        engine.drain_events();
+
+        assert!(engine.cancel_order(&ClientOrderId::new("O-1"), 3));
+
+        let events = engine.drain_events();
+        assert!(matches!(&events[0], OrderEvent::OrderCanceled(_)));
+        assert!(
+            matches!(&events[1], OrderEvent::OrderRejected(e) if e.client_order_id == ClientOrderId::new("O-2"))
+        );
+        assert!(engine.pending_orders().is_empty());
+        assert!(engine.open_orders().is_empty());
+    }
+
+    #[test]
+    fn test_ouo_partial_fill_reduces_linked_order() {
+        let config = OrderMatchingEngineConfig {
+            use_queue_position: true,
+            ..Default::default()
+        };
+        let mut engine = engine(BookType::L2_MBP, config);
+        engine.process_order_book_delta(add_delta(OrderSide::Buy, "1.00000", "1000", 1));
+        engine.process_order_book_delta(add_delta(OrderSide::Sell, "1.00002", "1000", 2));
+
+        let bid = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "500",
+            Some("1.00000"),
+            None,
+        );
+        let ask = submitted_order(
+            "O-2",
+            OrderSide::Sell,
+            OrderType::Limit,
+            "500",
+            Some("1.00010"),
+            None,
+        );
+        engine.process_order(link(bid, ContingencyType::Ouo, &["O-2"], None), 3);
+        engine.process_order(link(ask, ContingencyType::Ouo, &["O-1"], None), 3);
+        engine.drain_events();
+
+        engine.process_trade_tick(&trade_tick("1.00000", "1200", AggressorSide::Seller));
+
+        let events = engine.drain_events();
+        assert!(matches!(&events[0], OrderEvent::OrderPartiallyFilled(_)));
+        assert!(
+            matches!(&events[1], OrderEvent::OrderUpdated(e) if e.quantity == Quantity::from("300"))
+        );
+        let linked = engine.order(&ClientOrderId::new("O-2")).unwrap();
+        assert_eq!(linked.leaves_qty, Quantity::from("300"));
+        assert_eq!(linked.status, OrderStatus::Accepted);
+    }
+
+    #[test]
+    fn test_market_order_slips_one_tick() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.fill_model = FillModel::new(1.0, 1.0, 1.0, None);
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Sell,
+            OrderType::Market,
+            "1000",
+            None,
+            None,
+        );
+        engine.process_order(order, 2);
+
+        let fills = engine.drain_fills();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].last_px, Price::from("0.99999"));
+    }
+
+    #[test]
+    fn test_touched_limit_order_not_filled_when_fill_model_declines() {
+        let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+        engine.fill_model = FillModel::new(0.0, 1.0, 0.0, None);
+        engine.process_quote_tick(&quote_tick("1.00000", "1.00002", 1));
+
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.99990"),
+            None,
+        );
+        engine.process_order(order, 2);
+
+        engine.process_quote_tick(&quote_tick("0.99988", "0.99990", 3));
+        assert!(engine.drain_fills().is_empty());
+
+        engine.process_quote_tick(&quote_tick("0.99987", "0.99989", 4));
+        assert_eq!(engine.drain_fills().len(), 1);
+    }
+
+    proptest! {
+        /// Market orders are always filled in full at the top of an L1 book, as the
+        /// remainder beyond the displayed size is assumed to fill at the same price.
+        #[test]
+        fn test_market_order_fills_in_full_at_top_of_l1_book(
+            side in order_side(),
+            quantity in 1..1_000_000_u64,
+            bid in 50_000..150_000_i64,
+            spread in 1..100_i64,
+        ) {
+            let mut engine = engine(BookType::L1_TBBO, OrderMatchingEngineConfig::default());
+            let tick = QuoteTick {
+                bid: Price::from_raw(bid * 10_000, 5),
+                ask: Price::from_raw((bid + spread) * 10_000, 5),
+                ..quote_tick("1.00000", "1.00002", 1)
+            };
+            engine.process_quote_tick(&tick);
+
+            let order = submitted_order(
+                "O-1",
+                side,
+                OrderType::Market,
+                &quantity.to_string(),
+                None,
+                None,
+            );
+            engine.process_order(order, 2);
+
+            let fills = engine.drain_fills();
+            let expected_px = if side == OrderSide::Buy { tick.ask } else { tick.bid };
+            let filled_raw: u64 = fills.iter().map(|fill| fill.last_qty.raw).sum();
+            prop_assert_eq!(filled_raw, Quantity::from(quantity.to_string().as_str()).raw);
+            prop_assert!(fills.iter().all(|fill| fill.last_px == expected_px));
+        }
+    }
+}
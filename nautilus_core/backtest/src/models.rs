@@ -0,0 +1,325 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{correctness, time::UnixNanos};
+use pyo3::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Seeds a new random number generator, from entropy if no `random_seed` is given.
+fn seeded_rng(random_seed: Option<u64>) -> StdRng {
+    match random_seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Provides probabilistic modeling for order fill dynamics including probability
+/// of fills and slippage by order type.
+#[derive(Clone, Debug)]
+#[pyclass]
+pub struct FillModel {
+    /// The probability of limit order filling if the market rests on its price.
+    #[pyo3(get)]
+    pub prob_fill_on_limit: f64,
+    /// The probability of stop orders filling if the market rests on its price.
+    #[pyo3(get)]
+    pub prob_fill_on_stop: f64,
+    /// The probability of order fill prices slipping by one tick.
+    #[pyo3(get)]
+    pub prob_slippage: f64,
+    /// The random seed (if `None` then the generator is seeded from entropy).
+    #[pyo3(get)]
+    pub random_seed: Option<u64>,
+    rng: StdRng,
+}
+
+impl FillModel {
+    /// Initializes a new `FillModel` instance.
+    ///
+    /// # Panics
+    ///
+    /// - If any probability is not in the range [0, 1].
+    #[must_use]
+    pub fn new(
+        prob_fill_on_limit: f64,
+        prob_fill_on_stop: f64,
+        prob_slippage: f64,
+        random_seed: Option<u64>,
+    ) -> Self {
+        correctness::f64_in_range_inclusive(prob_fill_on_limit, 0.0, 1.0, "`prob_fill_on_limit`");
+        correctness::f64_in_range_inclusive(prob_fill_on_stop, 0.0, 1.0, "`prob_fill_on_stop`");
+        correctness::f64_in_range_inclusive(prob_slippage, 0.0, 1.0, "`prob_slippage`");
+
+        Self {
+            prob_fill_on_limit,
+            prob_fill_on_stop,
+            prob_slippage,
+            random_seed,
+            rng: seeded_rng(random_seed),
+        }
+    }
+
+    /// Returns a simulated outcome for a limit order filling when the market is at its price.
+    pub fn is_limit_filled(&mut self) -> bool {
+        self.event_success(self.prob_fill_on_limit)
+    }
+
+    /// Returns a simulated outcome for a triggered stop order filling.
+    pub fn is_stop_filled(&mut self) -> bool {
+        self.event_success(self.prob_fill_on_stop)
+    }
+
+    /// Returns a simulated outcome for an aggressive fill slipping by one tick.
+    pub fn is_slipped(&mut self) -> bool {
+        self.event_success(self.prob_slippage)
+    }
+
+    /// Certain outcomes do not draw from the generator, so a fully deterministic model
+    /// leaves the random sequence of any other model untouched.
+    fn event_success(&mut self, probability: f64) -> bool {
+        if probability <= 0.0 {
+            false
+        } else if probability >= 1.0 {
+            true
+        } else {
+            self.rng.gen_bool(probability)
+        }
+    }
+}
+
+impl Default for FillModel {
+    fn default() -> Self {
+        Self::new(1.0, 1.0, 0.0, None)
+    }
+}
+
+#[pymethods]
+impl FillModel {
+    #[new]
+    #[pyo3(signature = (prob_fill_on_limit=1.0, prob_fill_on_stop=1.0, prob_slippage=0.0, random_seed=None))]
+    fn py_new(
+        prob_fill_on_limit: f64,
+        prob_fill_on_stop: f64,
+        prob_slippage: f64,
+        random_seed: Option<u64>,
+    ) -> Self {
+        Self::new(
+            prob_fill_on_limit,
+            prob_fill_on_stop,
+            prob_slippage,
+            random_seed,
+        )
+    }
+
+    #[pyo3(name = "is_limit_filled")]
+    fn is_limit_filled_py(&mut self) -> bool {
+        self.is_limit_filled()
+    }
+
+    #[pyo3(name = "is_stop_filled")]
+    fn is_stop_filled_py(&mut self) -> bool {
+        self.is_stop_filled()
+    }
+
+    #[pyo3(name = "is_slipped")]
+    fn is_slipped_py(&mut self) -> bool {
+        self.is_slipped()
+    }
+}
+
+/// Provides a latency model for simulated exchange message processing.
+///
+/// Each message type has a fixed latency (added to the base latency), plus an optional
+/// random jitter sampled uniformly from `[0, jitter_nanos]` for every message.
+#[derive(Clone, Debug)]
+#[pyclass]
+pub struct LatencyModel {
+    /// The base latency (nanoseconds) for all messages.
+    #[pyo3(get)]
+    pub base_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order insert messages.
+    #[pyo3(get)]
+    pub insert_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order update messages.
+    #[pyo3(get)]
+    pub update_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order cancel messages.
+    #[pyo3(get)]
+    pub cancel_latency_nanos: u64,
+    /// The maximum random jitter (nanoseconds) added to every message.
+    #[pyo3(get)]
+    pub jitter_nanos: u64,
+    /// The random seed (if `None` then the generator is seeded from entropy).
+    #[pyo3(get)]
+    pub random_seed: Option<u64>,
+    rng: StdRng,
+}
+
+impl LatencyModel {
+    /// Initializes a new `LatencyModel` instance.
+    #[must_use]
+    pub fn new(
+        base_latency_nanos: u64,
+        insert_latency_nanos: u64,
+        update_latency_nanos: u64,
+        cancel_latency_nanos: u64,
+        jitter_nanos: u64,
+        random_seed: Option<u64>,
+    ) -> Self {
+        Self {
+            base_latency_nanos,
+            insert_latency_nanos,
+            update_latency_nanos,
+            cancel_latency_nanos,
+            jitter_nanos,
+            random_seed,
+            rng: seeded_rng(random_seed),
+        }
+    }
+
+    /// Returns a simulated latency for an order insert message.
+    pub fn insert_latency(&mut self) -> UnixNanos {
+        self.base_latency_nanos + self.insert_latency_nanos + self.jitter()
+    }
+
+    /// Returns a simulated latency for an order update message.
+    pub fn update_latency(&mut self) -> UnixNanos {
+        self.base_latency_nanos + self.update_latency_nanos + self.jitter()
+    }
+
+    /// Returns a simulated latency for an order cancel message.
+    pub fn cancel_latency(&mut self) -> UnixNanos {
+        self.base_latency_nanos + self.cancel_latency_nanos + self.jitter()
+    }
+
+    fn jitter(&mut self) -> u64 {
+        if self.jitter_nanos == 0 {
+            return 0;
+        }
+        self.rng.gen_range(0..=self.jitter_nanos)
+    }
+}
+
+impl Default for LatencyModel {
+    fn default() -> Self {
+        Self::new(1_000_000, 0, 0, 0, 0, None)
+    }
+}
+
+#[pymethods]
+impl LatencyModel {
+    #[new]
+    #[pyo3(signature = (
+        base_latency_nanos=1_000_000,
+        insert_latency_nanos=0,
+        update_latency_nanos=0,
+        cancel_latency_nanos=0,
+        jitter_nanos=0,
+        random_seed=None,
+    ))]
+    fn py_new(
+        base_latency_nanos: u64,
+        insert_latency_nanos: u64,
+        update_latency_nanos: u64,
+        cancel_latency_nanos: u64,
+        jitter_nanos: u64,
+        random_seed: Option<u64>,
+    ) -> Self {
+        Self::new(
+            base_latency_nanos,
+            insert_latency_nanos,
+            update_latency_nanos,
+            cancel_latency_nanos,
+            jitter_nanos,
+            random_seed,
+        )
+    }
+
+    #[pyo3(name = "insert_latency")]
+    fn insert_latency_py(&mut self) -> UnixNanos {
+        self.insert_latency()
+    }
+
+    #[pyo3(name = "update_latency")]
+    fn update_latency_py(&mut self) -> UnixNanos {
+        self.update_latency()
+    }
+
+    #[pyo3(name = "cancel_latency")]
+    fn cancel_latency_py(&mut self) -> UnixNanos {
+        self.cancel_latency()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_fill_model_defaults() {
+        let mut fill_model = FillModel::default();
+
+        assert!(fill_model.is_limit_filled());
+        assert!(fill_model.is_stop_filled());
+        assert!(!fill_model.is_slipped());
+    }
+
+    #[rstest]
+    #[case(-0.1)]
+    #[case(1.1)]
+    #[should_panic]
+    fn test_fill_model_with_invalid_probability(#[case] prob_slippage: f64) {
+        let _ = FillModel::new(1.0, 1.0, prob_slippage, None);
+    }
+
+    #[test]
+    fn test_fill_model_with_seed_is_deterministic() {
+        let mut fill_model1 = FillModel::new(0.5, 0.5, 0.5, Some(42));
+        let mut fill_model2 = FillModel::new(0.5, 0.5, 0.5, Some(42));
+
+        let outcomes1: Vec<bool> = (0..100).map(|_| fill_model1.is_slipped()).collect();
+        let outcomes2: Vec<bool> = (0..100).map(|_| fill_model2.is_slipped()).collect();
+
+        assert_eq!(outcomes1, outcomes2);
+        assert!(outcomes1.contains(&true));
+        assert!(outcomes1.contains(&false));
+    }
+
+    #[test]
+    fn test_latency_model_fixed_latencies() {
+        let mut latency_model = LatencyModel::new(1_000, 100, 200, 300, 0, None);
+
+        assert_eq!(latency_model.insert_latency(), 1_100);
+        assert_eq!(latency_model.update_latency(), 1_200);
+        assert_eq!(latency_model.cancel_latency(), 1_300);
+    }
+
+    #[test]
+    fn test_latency_model_jitter_is_bounded_and_deterministic() {
+        let mut latency_model1 = LatencyModel::new(1_000, 100, 0, 0, 50, Some(1));
+        let mut latency_model2 = LatencyModel::new(1_000, 100, 0, 0, 50, Some(1));
+
+        for _ in 0..100 {
+            let latency = latency_model1.insert_latency();
+            assert!((1_100..=1_150).contains(&latency));
+            assert_eq!(latency, latency_model2.insert_latency());
+        }
+    }
+}
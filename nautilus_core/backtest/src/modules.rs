@@ -0,0 +1,531 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::Data,
+    identifiers::instrument_id::InstrumentId,
+    types::{currency::Currency, money::Money},
+};
+
+/// The number of nanoseconds in one day.
+const NANOSECONDS_IN_DAY: u64 = 86_400_000_000_000;
+
+/// The number of days used to convert annual rates to daily rates.
+const DAYS_IN_YEAR: f64 = 365.0;
+
+/// Provides the view of a simulated exchange which a [`SimulationModule`] may read and adjust.
+pub trait SimulatedExchangeContext {
+    /// Returns the signed quantity of every open position held at the exchange.
+    fn net_positions(&self) -> HashMap<InstrumentId, f64>;
+    /// Returns the total account balance for the given `currency` (if any).
+    fn balance_total(&self, currency: &Currency) -> Option<Money>;
+    /// Adjusts the account balance by the given amount (a negative `adjustment` is a debit).
+    fn adjust_account(&mut self, adjustment: Money);
+}
+
+/// Provides custom simulation behaviour for a simulated exchange.
+///
+/// Data is passed to `pre_process` before the exchange processes it, and `process` is called
+/// each time the exchange clock is advanced.
+pub trait SimulationModule {
+    /// Pre-process the given data before it is handled by the exchange.
+    fn pre_process(&mut self, _data: &Data) {}
+    /// Process the simulation module at `ts_now`, applying any account adjustments.
+    fn process(&mut self, ts_now: UnixNanos, exchange: &mut dyn SimulatedExchangeContext);
+    /// Resets the module to its initial state.
+    fn reset(&mut self);
+}
+
+/// Returns the next multiple of `interval_ns` strictly after `ts_now`.
+fn next_boundary(ts_now: UnixNanos, interval_ns: u64) -> UnixNanos {
+    (ts_now / interval_ns + 1) * interval_ns
+}
+
+fn add_total(
+    totals: &mut HashMap<InstrumentId, Money>,
+    instrument_id: &InstrumentId,
+    amount: Money,
+) {
+    totals
+        .entry(instrument_id.clone())
+        .and_modify(|total| *total += amount.clone())
+        .or_insert(amount);
+}
+
+/// Provides periodic funding payments for perpetual futures positions.
+///
+/// At each funding time (multiples of `interval_ns` since the UNIX epoch) every open position
+/// with a funding rate and mark price pays `signed_qty * mark_price * funding_rate`, so longs pay
+/// shorts when the rate is positive. Mark prices are taken from the latest quote mid, trade or bar
+/// close seen by `pre_process`.
+pub struct FundingRateModule {
+    currency: Currency,
+    interval_ns: u64,
+    next_funding_ns: Option<UnixNanos>,
+    funding_rates: HashMap<InstrumentId, f64>,
+    mark_prices: HashMap<InstrumentId, f64>,
+    funding_totals: HashMap<InstrumentId, Money>,
+}
+
+impl FundingRateModule {
+    /// Initializes a new `FundingRateModule` instance settling in `currency`.
+    ///
+    /// # Panics
+    ///
+    /// If `interval_ns` is zero.
+    #[must_use]
+    pub fn new(currency: Currency, interval_ns: u64) -> Self {
+        assert!(interval_ns > 0, "`interval_ns` must be positive");
+        Self {
+            currency,
+            interval_ns,
+            next_funding_ns: None,
+            funding_rates: HashMap::new(),
+            mark_prices: HashMap::new(),
+            funding_totals: HashMap::new(),
+        }
+    }
+
+    /// Sets the funding rate applied per interval for the given instrument.
+    pub fn set_funding_rate(&mut self, instrument_id: InstrumentId, rate: f64) {
+        self.funding_rates.insert(instrument_id, rate);
+    }
+
+    /// Returns the cumulative funding paid (negative) or received per instrument.
+    #[must_use]
+    pub fn funding_totals(&self) -> &HashMap<InstrumentId, Money> {
+        &self.funding_totals
+    }
+
+    fn apply_funding(&mut self, exchange: &mut dyn SimulatedExchangeContext) {
+        for (instrument_id, signed_qty) in exchange.net_positions() {
+            let (rate, mark) = match (
+                self.funding_rates.get(&instrument_id),
+                self.mark_prices.get(&instrument_id),
+            ) {
+                (Some(rate), Some(mark)) => (*rate, *mark),
+                _ => continue,
+            };
+            let payment = Money::new(-signed_qty * mark * rate, self.currency.clone());
+            if payment.is_zero() {
+                continue;
+            }
+            add_total(&mut self.funding_totals, &instrument_id, payment.clone());
+            exchange.adjust_account(payment);
+        }
+    }
+}
+
+impl SimulationModule for FundingRateModule {
+    fn pre_process(&mut self, data: &Data) {
+        let (instrument_id, mark) = match data {
+            Data::Quote(q) => (&q.instrument_id, (q.bid.as_f64() + q.ask.as_f64()) / 2.0),
+            Data::Trade(t) => (&t.instrument_id, t.price.as_f64()),
+            Data::Bar(b) => (&b.bar_type.instrument_id, b.close.as_f64()),
+            Data::Delta(_) => return,
+        };
+        self.mark_prices.insert(instrument_id.clone(), mark);
+    }
+
+    fn process(&mut self, ts_now: UnixNanos, exchange: &mut dyn SimulatedExchangeContext) {
+        let mut next_funding_ns = match self.next_funding_ns {
+            Some(ts) => ts,
+            None => next_boundary(ts_now, self.interval_ns),
+        };
+        while ts_now >= next_funding_ns {
+            self.apply_funding(exchange);
+            next_funding_ns += self.interval_ns;
+        }
+        self.next_funding_ns = Some(next_funding_ns);
+    }
+
+    fn reset(&mut self) {
+        self.next_funding_ns = None;
+        self.mark_prices.clear();
+        self.funding_totals.clear();
+    }
+}
+
+/// Provides daily interest on the account balance for a single currency.
+///
+/// At each UTC midnight a negative balance is charged `borrow_rate / 365` of its absolute value,
+/// and a positive balance is credited `credit_rate / 365` of its value.
+pub struct MarginInterestModule {
+    currency: Currency,
+    borrow_rate: f64,
+    credit_rate: f64,
+    next_accrual_ns: Option<UnixNanos>,
+    interest_total: Money,
+}
+
+impl MarginInterestModule {
+    /// Initializes a new `MarginInterestModule` instance with annual rates.
+    ///
+    /// # Panics
+    ///
+    /// If either rate is negative.
+    #[must_use]
+    pub fn new(currency: Currency, borrow_rate: f64, credit_rate: f64) -> Self {
+        assert!(borrow_rate >= 0.0, "`borrow_rate` was negative");
+        assert!(credit_rate >= 0.0, "`credit_rate` was negative");
+        Self {
+            interest_total: Money::new(0.0, currency.clone()),
+            currency,
+            borrow_rate,
+            credit_rate,
+            next_accrual_ns: None,
+        }
+    }
+
+    /// Returns the cumulative interest charged (negative) or credited.
+    #[must_use]
+    pub fn interest_total(&self) -> &Money {
+        &self.interest_total
+    }
+
+    fn apply_interest(&mut self, exchange: &mut dyn SimulatedExchangeContext) {
+        let balance = match exchange.balance_total(&self.currency) {
+            Some(balance) => balance.as_f64(),
+            None => return,
+        };
+        let rate = if balance < 0.0 {
+            self.borrow_rate
+        } else {
+            self.credit_rate
+        };
+        let interest = Money::new(balance * rate / DAYS_IN_YEAR, self.currency.clone());
+        if interest.is_zero() {
+            return;
+        }
+        self.interest_total += interest.clone();
+        exchange.adjust_account(interest);
+    }
+}
+
+impl SimulationModule for MarginInterestModule {
+    fn process(&mut self, ts_now: UnixNanos, exchange: &mut dyn SimulatedExchangeContext) {
+        let mut next_accrual_ns = match self.next_accrual_ns {
+            Some(ts) => ts,
+            None => next_boundary(ts_now, NANOSECONDS_IN_DAY),
+        };
+        while ts_now >= next_accrual_ns {
+            self.apply_interest(exchange);
+            next_accrual_ns += NANOSECONDS_IN_DAY;
+        }
+        self.next_accrual_ns = Some(next_accrual_ns);
+    }
+
+    fn reset(&mut self) {
+        self.next_accrual_ns = None;
+        self.interest_total = Money::new(0.0, self.currency.clone());
+    }
+}
+
+/// Represents a cash dividend paid per unit of an instrument held at the ex-date.
+#[derive(Clone, Debug)]
+pub struct CashDividend {
+    pub instrument_id: InstrumentId,
+    pub ts_ex_date: UnixNanos,
+    pub amount: Money,
+}
+
+/// Provides cash dividend payments for equity positions.
+///
+/// When the exchange clock reaches a dividend's ex-date, every open position in the instrument
+/// receives `signed_qty * amount`, so short positions pay the dividend.
+pub struct CashDividendModule {
+    /// Scheduled dividends sorted by ex-date, with the next applicable at `cursor`.
+    dividends: Vec<CashDividend>,
+    cursor: usize,
+    dividend_totals: HashMap<InstrumentId, Money>,
+}
+
+impl CashDividendModule {
+    /// Initializes a new `CashDividendModule` instance with no scheduled dividends.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            dividends: Vec::new(),
+            cursor: 0,
+            dividend_totals: HashMap::new(),
+        }
+    }
+
+    /// Schedules the given dividend.
+    pub fn add_dividend(&mut self, dividend: CashDividend) {
+        self.dividends.push(dividend);
+        self.dividends[self.cursor..].sort_by_key(|d| d.ts_ex_date);
+    }
+
+    /// Returns the cumulative dividends received (or paid, if negative) per instrument.
+    #[must_use]
+    pub fn dividend_totals(&self) -> &HashMap<InstrumentId, Money> {
+        &self.dividend_totals
+    }
+}
+
+impl SimulationModule for CashDividendModule {
+    fn process(&mut self, ts_now: UnixNanos, exchange: &mut dyn SimulatedExchangeContext) {
+        if self
+            .dividends
+            .get(self.cursor)
+            .map_or(true, |d| d.ts_ex_date > ts_now)
+        {
+            return; // Nothing due
+        }
+
+        let positions = exchange.net_positions();
+        while let Some(dividend) = self.dividends.get(self.cursor) {
+            if dividend.ts_ex_date > ts_now {
+                break;
+            }
+            self.cursor += 1;
+
+            let signed_qty = match positions.get(&dividend.instrument_id) {
+                Some(qty) if *qty != 0.0 => *qty,
+                _ => continue,
+            };
+            let payment = Money::new(
+                signed_qty * dividend.amount.as_f64(),
+                dividend.amount.currency.clone(),
+            );
+            add_total(
+                &mut self.dividend_totals,
+                &dividend.instrument_id,
+                payment.clone(),
+            );
+            exchange.adjust_account(payment);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.cursor = 0;
+        self.dividend_totals.clear();
+    }
+}
+
+impl Default for CashDividendModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{
+        currencies::USD,
+        data::tick::{QuoteTick, TradeTick},
+        enums::AggressorSide,
+        identifiers::trade_id::TradeId,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    use super::*;
+
+    const NANOSECONDS_IN_HOUR: u64 = 3_600_000_000_000;
+
+    #[derive(Default)]
+    struct StubExchange {
+        positions: HashMap<InstrumentId, f64>,
+        balances: HashMap<Currency, Money>,
+        adjustments: Vec<Money>,
+    }
+
+    impl SimulatedExchangeContext for StubExchange {
+        fn net_positions(&self) -> HashMap<InstrumentId, f64> {
+            self.positions.clone()
+        }
+
+        fn balance_total(&self, currency: &Currency) -> Option<Money> {
+            self.balances.get(currency).cloned()
+        }
+
+        fn adjust_account(&mut self, adjustment: Money) {
+            self.adjustments.push(adjustment);
+        }
+    }
+
+    fn btcusdt() -> InstrumentId {
+        InstrumentId::from_str("BTCUSDT-PERP.BINANCE").unwrap()
+    }
+
+    fn trade(instrument_id: InstrumentId, price: &str) -> Data {
+        Data::Trade(TradeTick::new(
+            instrument_id,
+            Price::from(price),
+            Quantity::from("1"),
+            AggressorSide::Buyer,
+            TradeId::new("1"),
+            0,
+            0,
+        ))
+    }
+
+    #[test]
+    fn test_funding_applied_at_each_interval() {
+        let mut module = FundingRateModule::new(USD.clone(), 8 * NANOSECONDS_IN_HOUR);
+        module.set_funding_rate(btcusdt(), 0.0001);
+        module.pre_process(&trade(btcusdt(), "20000.0"));
+
+        let mut exchange = StubExchange::default();
+        exchange.positions.insert(btcusdt(), 2.0);
+
+        module.process(NANOSECONDS_IN_HOUR, &mut exchange);
+        assert!(exchange.adjustments.is_empty());
+
+        module.process(17 * NANOSECONDS_IN_HOUR, &mut exchange);
+        assert_eq!(
+            exchange.adjustments,
+            vec![Money::new(-4.0, USD.clone()), Money::new(-4.0, USD.clone())]
+        );
+        assert_eq!(
+            module.funding_totals().get(&btcusdt()),
+            Some(&Money::new(-8.0, USD.clone()))
+        );
+    }
+
+    #[test]
+    fn test_funding_short_receives_using_quote_mid() {
+        let mut module = FundingRateModule::new(USD.clone(), 8 * NANOSECONDS_IN_HOUR);
+        module.set_funding_rate(btcusdt(), 0.001);
+        module.pre_process(&Data::Quote(QuoteTick::new(
+            btcusdt(),
+            Price::from("999.0"),
+            Price::from("1001.0"),
+            Quantity::from("1"),
+            Quantity::from("1"),
+            0,
+            0,
+        )));
+
+        let mut exchange = StubExchange::default();
+        exchange.positions.insert(btcusdt(), -1.0);
+
+        module.process(0, &mut exchange);
+        module.process(8 * NANOSECONDS_IN_HOUR, &mut exchange);
+        assert_eq!(exchange.adjustments, vec![Money::new(1.0, USD.clone())]);
+    }
+
+    #[test]
+    fn test_funding_skips_instruments_without_rate_or_mark() {
+        let mut module = FundingRateModule::new(USD.clone(), 8 * NANOSECONDS_IN_HOUR);
+        let ethusdt = InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap();
+        module.set_funding_rate(btcusdt(), 0.0001);
+        module.pre_process(&trade(ethusdt.clone(), "1000.0"));
+
+        let mut exchange = StubExchange::default();
+        exchange.positions.insert(btcusdt(), 1.0);
+        exchange.positions.insert(ethusdt, 1.0);
+
+        module.process(0, &mut exchange);
+        module.process(8 * NANOSECONDS_IN_HOUR, &mut exchange);
+        assert!(exchange.adjustments.is_empty());
+    }
+
+    #[test]
+    fn test_margin_interest_charged_daily_on_negative_balance() {
+        let mut module = MarginInterestModule::new(USD.clone(), 0.0365, 0.0);
+        let mut exchange = StubExchange::default();
+        exchange
+            .balances
+            .insert(USD.clone(), Money::new(-10_000.0, USD.clone()));
+
+        module.process(0, &mut exchange);
+        module.process(2 * NANOSECONDS_IN_DAY, &mut exchange);
+
+        assert_eq!(
+            exchange.adjustments,
+            vec![Money::new(-1.0, USD.clone()), Money::new(-1.0, USD.clone())]
+        );
+        assert_eq!(module.interest_total(), &Money::new(-2.0, USD.clone()));
+    }
+
+    #[test]
+    fn test_margin_interest_credit_on_positive_balance() {
+        let mut module = MarginInterestModule::new(USD.clone(), 0.05, 0.0365);
+        let mut exchange = StubExchange::default();
+        exchange
+            .balances
+            .insert(USD.clone(), Money::new(10_000.0, USD.clone()));
+
+        module.process(0, &mut exchange);
+        module.process(NANOSECONDS_IN_DAY, &mut exchange);
+
+        assert_eq!(exchange.adjustments, vec![Money::new(1.0, USD.clone())]);
+    }
+
+    #[test]
+    fn test_cash_dividend_paid_at_ex_date() {
+        let aapl = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+        let mut module = CashDividendModule::new();
+        module.add_dividend(CashDividend {
+            instrument_id: aapl.clone(),
+            ts_ex_date: 2 * NANOSECONDS_IN_DAY,
+            amount: Money::new(0.25, USD.clone()),
+        });
+        module.add_dividend(CashDividend {
+            instrument_id: aapl.clone(),
+            ts_ex_date: NANOSECONDS_IN_DAY,
+            amount: Money::new(0.5, USD.clone()),
+        });
+
+        let mut exchange = StubExchange::default();
+        exchange.positions.insert(aapl.clone(), 100.0);
+
+        module.process(0, &mut exchange);
+        assert!(exchange.adjustments.is_empty());
+
+        module.process(NANOSECONDS_IN_DAY, &mut exchange);
+        exchange.positions.insert(aapl.clone(), -10.0);
+        module.process(3 * NANOSECONDS_IN_DAY, &mut exchange);
+
+        assert_eq!(
+            exchange.adjustments,
+            vec![Money::new(50.0, USD.clone()), Money::new(-2.5, USD.clone())]
+        );
+        assert_eq!(
+            module.dividend_totals().get(&aapl),
+            Some(&Money::new(47.5, USD.clone()))
+        );
+    }
+
+    #[test]
+    fn test_cash_dividend_reset_replays_schedule() {
+        let aapl = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+        let mut module = CashDividendModule::new();
+        module.add_dividend(CashDividend {
+            instrument_id: aapl.clone(),
+            ts_ex_date: NANOSECONDS_IN_DAY,
+            amount: Money::new(1.0, USD.clone()),
+        });
+
+        let mut exchange = StubExchange::default();
+        exchange.positions.insert(aapl, 1.0);
+
+        module.process(NANOSECONDS_IN_DAY, &mut exchange);
+        module.reset();
+        module.process(NANOSECONDS_IN_DAY, &mut exchange);
+
+        assert_eq!(exchange.adjustments.len(), 2);
+    }
+}
@@ -0,0 +1,98 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{hint::spin_loop, str::FromStr, thread};
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use nautilus_common::ring_buffer::spsc_ring_buffer;
+use nautilus_model::{
+    data::tick::QuoteTick,
+    identifiers::instrument_id::InstrumentId,
+    types::{price::Price, quantity::Quantity},
+};
+
+const NUM_TICKS: u64 = 100_000;
+const CAPACITY: usize = 1024;
+
+fn quote_tick() -> QuoteTick {
+    QuoteTick {
+        instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+        bid: Price::from("1.00000"),
+        ask: Price::from("1.00002"),
+        bid_size: Quantity::from("100000"),
+        ask_size: Quantity::from("100000"),
+        ts_event: 0,
+        ts_init: 0,
+    }
+}
+
+fn ring_buffer_transfer(tick: &QuoteTick) {
+    let (mut producer, mut consumer) = spsc_ring_buffer(CAPACITY);
+    let tick = tick.clone();
+    let handle = thread::spawn(move || {
+        for ts in 0..NUM_TICKS {
+            let mut value = QuoteTick {
+                ts_event: ts,
+                ..tick.clone()
+            };
+            while let Err(v) = producer.push(value) {
+                value = v;
+                spin_loop();
+            }
+        }
+    });
+    let mut received = 0;
+    while received < NUM_TICKS {
+        match consumer.pop() {
+            Some(_) => received += 1,
+            None => spin_loop(),
+        }
+    }
+    handle.join().unwrap();
+}
+
+fn crossbeam_transfer(tick: &QuoteTick) {
+    let (tx, rx) = crossbeam_channel::bounded(CAPACITY);
+    let tick = tick.clone();
+    let handle = thread::spawn(move || {
+        for ts in 0..NUM_TICKS {
+            tx.send(QuoteTick {
+                ts_event: ts,
+                ..tick.clone()
+            })
+            .unwrap();
+        }
+    });
+    for _ in 0..NUM_TICKS {
+        rx.recv().unwrap();
+    }
+    handle.join().unwrap();
+}
+
+fn spsc_bench(c: &mut Criterion) {
+    let tick = quote_tick();
+    let mut group = c.benchmark_group("spsc_quote_ticks");
+    group.throughput(Throughput::Elements(NUM_TICKS));
+
+    group.bench_function("ring_buffer", |b| b.iter(|| ring_buffer_transfer(&tick)));
+    group.bench_function("crossbeam_bounded", |b| {
+        b.iter(|| crossbeam_transfer(&tick))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, spsc_bench);
+criterion_main!(benches);
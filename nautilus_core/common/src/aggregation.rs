@@ -0,0 +1,750 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::BarAggregation,
+    types::{
+        fixed::{FIXED_PRECISION, FIXED_SCALAR},
+        price::Price,
+        quantity::Quantity,
+    },
+};
+use pyo3::prelude::*;
+
+use crate::{calendar::TradingCalendar, clock::Clock, timer::TimeEvent};
+
+const NANOSECONDS_IN_MILLISECOND: u64 = 1_000_000;
+const NANOSECONDS_IN_SECOND: u64 = 1_000_000_000;
+const NANOSECONDS_IN_MINUTE: u64 = 60 * NANOSECONDS_IN_SECOND;
+const NANOSECONDS_IN_HOUR: u64 = 60 * NANOSECONDS_IN_MINUTE;
+const NANOSECONDS_IN_DAY: u64 = 24 * NANOSECONDS_IN_HOUR;
+const NANOSECONDS_IN_WEEK: u64 = 7 * NANOSECONDS_IN_DAY;
+
+/// Provides a generic bar builder for aggregation.
+pub struct BarBuilder {
+    bar_type: BarType,
+    price_precision: u8,
+    size_precision: u8,
+    initialized: bool,
+    ts_last: UnixNanos,
+    count: usize,
+    last_close: Option<Price>,
+    open: Option<Price>,
+    high: Option<Price>,
+    low: Option<Price>,
+    close: Option<Price>,
+    volume: Quantity,
+}
+
+impl BarBuilder {
+    #[must_use]
+    pub fn new(bar_type: BarType, price_precision: u8, size_precision: u8) -> Self {
+        Self {
+            bar_type,
+            price_precision,
+            size_precision,
+            initialized: false,
+            ts_last: 0,
+            count: 0,
+            last_close: None,
+            open: None,
+            high: None,
+            low: None,
+            close: None,
+            volume: Quantity::zero(size_precision),
+        }
+    }
+
+    #[must_use]
+    pub fn bar_type(&self) -> &BarType {
+        &self.bar_type
+    }
+
+    #[must_use]
+    pub fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    #[must_use]
+    pub fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    /// Returns whether the builder has received at least one update.
+    #[must_use]
+    pub fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) of the last update.
+    #[must_use]
+    pub fn ts_last(&self) -> UnixNanos {
+        self.ts_last
+    }
+
+    /// Returns the count of updates since the last bar was built.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the accumulated volume since the last bar was built.
+    #[must_use]
+    pub fn volume(&self) -> Quantity {
+        self.volume
+    }
+
+    /// Update the bar builder with the given price and size.
+    ///
+    /// Updates with a `ts_event` earlier than the last update are ignored.
+    pub fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        if ts_event < self.ts_last {
+            return; // Not applicable
+        }
+
+        match (self.high, self.low) {
+            (Some(high), Some(low)) => {
+                self.high = Some(high.max(price));
+                self.low = Some(low.min(price));
+            }
+            _ => {
+                self.open = Some(price);
+                self.high = Some(price);
+                self.low = Some(price);
+                self.initialized = true;
+            }
+        }
+
+        self.close = Some(price);
+        self.volume = Quantity::from_raw(self.volume.raw + size.raw, self.size_precision);
+        self.count += 1;
+        self.ts_last = ts_event;
+    }
+
+    /// Reset the bar builder, preserving the last close price.
+    pub fn reset(&mut self) {
+        self.open = None;
+        self.high = None;
+        self.low = None;
+        self.volume = Quantity::zero(self.size_precision);
+        self.count = 0;
+    }
+
+    /// Return the aggregated bar stamped with the timestamp of the last update, and reset.
+    pub fn build_now(&mut self) -> Bar {
+        self.build(self.ts_last, self.ts_last)
+    }
+
+    /// Return the aggregated bar with the given timestamps, and reset.
+    ///
+    /// If no updates were received since the last bar was built, then the bar is
+    /// built flat at the last close price.
+    ///
+    /// # Panics
+    ///
+    /// If the builder has never been updated.
+    pub fn build(&mut self, ts_event: UnixNanos, ts_init: UnixNanos) -> Bar {
+        if self.open.is_none() {
+            self.open = self.last_close;
+            self.high = self.last_close;
+            self.low = self.last_close;
+            self.close = self.last_close;
+        }
+
+        let bar = Bar::new(
+            self.bar_type.clone(),
+            self.open.expect("`BarBuilder` was not initialized"),
+            self.high.expect("`BarBuilder` was not initialized"),
+            self.low.expect("`BarBuilder` was not initialized"),
+            self.close.expect("`BarBuilder` was not initialized"),
+            self.volume,
+            ts_event,
+            ts_init,
+        );
+
+        self.last_close = self.close;
+        self.reset();
+        bar
+    }
+}
+
+/// Represents a type which aggregates ticks into bars, passing each completed
+/// bar to its registered handler.
+pub trait BarAggregator {
+    /// Return the bar type for the aggregator.
+    fn bar_type(&self) -> &BarType;
+
+    /// Update the aggregator with the given price and size.
+    fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos);
+
+    /// Update the aggregator with the given quote tick, using the price type of
+    /// the aggregators bar specification.
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        let price_type = self.bar_type().spec.price_type;
+        self.update(
+            tick.extract_price(price_type),
+            tick.extract_volume(price_type),
+            tick.ts_event,
+        );
+    }
+
+    /// Update the aggregator with the given trade tick.
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update(tick.price, tick.size, tick.ts_event);
+    }
+}
+
+/// Provides a means of building tick bars from ticks.
+///
+/// When received tick count reaches the step threshold of the bar
+/// specification, then a bar is created and sent to the handler.
+pub struct TickBarAggregator {
+    builder: BarBuilder,
+    handler: Box<dyn FnMut(Bar)>,
+}
+
+impl TickBarAggregator {
+    #[must_use]
+    pub fn new(
+        bar_type: BarType,
+        price_precision: u8,
+        size_precision: u8,
+        handler: Box<dyn FnMut(Bar)>,
+    ) -> Self {
+        Self {
+            builder: BarBuilder::new(bar_type, price_precision, size_precision),
+            handler,
+        }
+    }
+}
+
+impl BarAggregator for TickBarAggregator {
+    fn bar_type(&self) -> &BarType {
+        self.builder.bar_type()
+    }
+
+    fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        self.builder.update(price, size, ts_event);
+
+        if self.builder.count() as u64 == self.builder.bar_type().spec.step {
+            let bar = self.builder.build_now();
+            (self.handler)(bar);
+        }
+    }
+}
+
+/// Provides a means of building volume bars from ticks.
+///
+/// When received volume reaches the step threshold of the bar
+/// specification, then a bar is created and sent to the handler. Any
+/// volume in excess of the threshold is carried over into the next bar.
+pub struct VolumeBarAggregator {
+    builder: BarBuilder,
+    handler: Box<dyn FnMut(Bar)>,
+}
+
+impl VolumeBarAggregator {
+    #[must_use]
+    pub fn new(
+        bar_type: BarType,
+        price_precision: u8,
+        size_precision: u8,
+        handler: Box<dyn FnMut(Bar)>,
+    ) -> Self {
+        Self {
+            builder: BarBuilder::new(bar_type, price_precision, size_precision),
+            handler,
+        }
+    }
+}
+
+impl BarAggregator for VolumeBarAggregator {
+    fn bar_type(&self) -> &BarType {
+        self.builder.bar_type()
+    }
+
+    fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        let raw_step = self.builder.bar_type().spec.step * FIXED_SCALAR as u64;
+        let mut raw_size_update = size.raw;
+
+        while raw_size_update > 0 {
+            if self.builder.volume().raw + raw_size_update < raw_step {
+                self.builder.update(
+                    price,
+                    Quantity::from_raw(raw_size_update, size.precision),
+                    ts_event,
+                );
+                break;
+            }
+
+            let raw_size_diff = raw_step - self.builder.volume().raw;
+            self.builder.update(
+                price,
+                Quantity::from_raw(raw_size_diff, size.precision),
+                ts_event,
+            );
+
+            let bar = self.builder.build_now();
+            (self.handler)(bar);
+            raw_size_update -= raw_size_diff;
+        }
+    }
+}
+
+/// Provides a means of building value bars from ticks.
+///
+/// When received value (price * size) reaches the step threshold of the bar
+/// specification, then a bar is created and sent to the handler. Any value
+/// in excess of the threshold is carried over into the next bar.
+pub struct ValueBarAggregator {
+    builder: BarBuilder,
+    handler: Box<dyn FnMut(Bar)>,
+    raw_cum_value: i128,
+}
+
+impl ValueBarAggregator {
+    #[must_use]
+    pub fn new(
+        bar_type: BarType,
+        price_precision: u8,
+        size_precision: u8,
+        handler: Box<dyn FnMut(Bar)>,
+    ) -> Self {
+        Self {
+            builder: BarBuilder::new(bar_type, price_precision, size_precision),
+            handler,
+            raw_cum_value: 0,
+        }
+    }
+
+    /// Returns the cumulative value for the aggregator.
+    #[must_use]
+    pub fn cum_value(&self) -> f64 {
+        self.raw_cum_value as f64 / FIXED_SCALAR
+    }
+}
+
+impl BarAggregator for ValueBarAggregator {
+    fn bar_type(&self) -> &BarType {
+        self.builder.bar_type()
+    }
+
+    fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        let scalar = FIXED_SCALAR as i128;
+        let raw_step = i128::from(self.builder.bar_type().spec.step) * scalar;
+        let raw_size_increment = 10_u64.pow(u32::from(FIXED_PRECISION - size.precision));
+        let mut raw_size_update = size.raw;
+
+        while raw_size_update > 0 {
+            let raw_value_update = i128::from(price.raw) * i128::from(raw_size_update) / scalar;
+            if self.raw_cum_value + raw_value_update < raw_step {
+                self.raw_cum_value += raw_value_update;
+                self.builder.update(
+                    price,
+                    Quantity::from_raw(raw_size_update, size.precision),
+                    ts_event,
+                );
+                break;
+            }
+
+            // The size which fills the bar is rounded down to the size precision, taking at
+            // least one increment so that each pass makes progress
+            let raw_value_diff = raw_step - self.raw_cum_value;
+            let raw_size_diff =
+                (i128::from(raw_size_update) * raw_value_diff / raw_value_update) as u64;
+            let raw_size_diff = (raw_size_diff - raw_size_diff % raw_size_increment)
+                .max(raw_size_increment)
+                .min(raw_size_update);
+            self.builder.update(
+                price,
+                Quantity::from_raw(raw_size_diff, size.precision),
+                ts_event,
+            );
+
+            let bar = self.builder.build_now();
+            (self.handler)(bar);
+            self.raw_cum_value = 0;
+            raw_size_update -= raw_size_diff;
+        }
+    }
+}
+
+/// Provides a means of building time bars from ticks with an internal timer.
+///
+/// When the timer fires (see [`TimeBarAggregator::on_time_event`]) a bar is
+/// created and sent to the handler.
+pub struct TimeBarAggregator {
+    builder: BarBuilder,
+    handler: Box<dyn FnMut(Bar)>,
+    interval_ns: u64,
+    next_close_ns: UnixNanos,
+    build_with_no_updates: bool,
+    calendar: Option<TradingCalendar>,
+}
+
+impl TimeBarAggregator {
+    /// # Panics
+    ///
+    /// If the bar aggregation of `bar_type` is not time based.
+    #[must_use]
+    pub fn new(
+        bar_type: BarType,
+        price_precision: u8,
+        size_precision: u8,
+        handler: Box<dyn FnMut(Bar)>,
+        build_with_no_updates: bool,
+    ) -> Self {
+        let interval_ns = get_bar_interval_ns(&bar_type);
+        Self {
+            builder: BarBuilder::new(bar_type, price_precision, size_precision),
+            handler,
+            interval_ns,
+            next_close_ns: 0,
+            build_with_no_updates,
+            calendar: None,
+        }
+    }
+
+    /// Sets the trading `calendar` for the venue, so that bars without updates are only
+    /// built while the market is open.
+    pub fn set_calendar(&mut self, calendar: TradingCalendar) {
+        self.calendar = Some(calendar);
+    }
+
+    /// Returns the time interval (nanoseconds) for the aggregator.
+    #[must_use]
+    pub fn interval_ns(&self) -> u64 {
+        self.interval_ns
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) of the next bar close.
+    #[must_use]
+    pub fn next_close_ns(&self) -> UnixNanos {
+        self.next_close_ns
+    }
+
+    /// Returns the name of the timer used to close bars.
+    #[must_use]
+    pub fn timer_name(&self) -> String {
+        self.builder.bar_type().to_string()
+    }
+
+    /// Returns the start time for the current bar interval, aligned to the
+    /// interval boundary at or before `ts_now`.
+    #[must_use]
+    pub fn get_start_time_ns(&self, ts_now: UnixNanos) -> UnixNanos {
+        ts_now - (ts_now % self.interval_ns)
+    }
+
+    /// Start the aggregator by setting a repeating timer on the given clock.
+    ///
+    /// Each generated [`TimeEvent`] should be routed back to
+    /// [`TimeBarAggregator::on_time_event`] via `callback_py`.
+    pub fn start<C: Clock>(&mut self, clock: &mut C, callback_py: Option<PyObject>) {
+        let start_time_ns = self.get_start_time_ns(clock.timestamp_ns());
+        clock.set_timer_ns_py(
+            self.timer_name(),
+            self.interval_ns,
+            start_time_ns,
+            None,
+            callback_py,
+        );
+        self.next_close_ns = start_time_ns + self.interval_ns;
+    }
+
+    /// Stop the aggregator by cancelling its timer on the given clock.
+    pub fn stop<C: Clock>(&mut self, clock: &mut C) {
+        clock.cancel_timer(&self.timer_name());
+    }
+
+    /// Handle the given timer event by building and sending a bar.
+    pub fn on_time_event(&mut self, event: &TimeEvent) {
+        self.build_bar(event.ts_event);
+    }
+
+    /// Build a bar closing at `ts_event` and send it to the handler.
+    ///
+    /// No bar is built if the builder has never been updated, or if there have
+    /// been no updates since the last bar and `build_with_no_updates` is false
+    /// (or the market was closed at the end of the bar, when a calendar is set).
+    pub fn build_bar(&mut self, ts_event: UnixNanos) {
+        self.next_close_ns = ts_event + self.interval_ns;
+
+        if !self.builder.initialized() {
+            return; // Not initialized
+        }
+
+        let is_market_closed = self.calendar.as_ref().map_or(false, |calendar| {
+            !calendar.is_open(ts_event.saturating_sub(1))
+        });
+        if self.builder.count() == 0 && (!self.build_with_no_updates || is_market_closed) {
+            return; // Do not build and emit bar
+        }
+
+        let bar = self.builder.build(ts_event, ts_event);
+        (self.handler)(bar);
+    }
+}
+
+impl BarAggregator for TimeBarAggregator {
+    fn bar_type(&self) -> &BarType {
+        self.builder.bar_type()
+    }
+
+    fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        self.builder.update(price, size, ts_event);
+    }
+}
+
+/// Returns the time interval (nanoseconds) of the given time based `bar_type`.
+///
+/// # Panics
+///
+/// If the bar aggregation is not time based, or is `MONTH` (which has no fixed interval).
+#[must_use]
+pub fn get_bar_interval_ns(bar_type: &BarType) -> u64 {
+    let step = bar_type.spec.step;
+    match bar_type.spec.aggregation {
+        BarAggregation::Millisecond => step * NANOSECONDS_IN_MILLISECOND,
+        BarAggregation::Second => step * NANOSECONDS_IN_SECOND,
+        BarAggregation::Minute => step * NANOSECONDS_IN_MINUTE,
+        BarAggregation::Hour => step * NANOSECONDS_IN_HOUR,
+        BarAggregation::Day => step * NANOSECONDS_IN_DAY,
+        BarAggregation::Week => step * NANOSECONDS_IN_WEEK,
+        aggregation => panic!("Aggregation {aggregation} not supported for time bars"),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc, str::FromStr};
+
+    use nautilus_model::{enums::AggressorSide, identifiers::trade_id::TradeId};
+
+    use super::*;
+
+    fn collect_bars() -> (Rc<RefCell<Vec<Bar>>>, Box<dyn FnMut(Bar)>) {
+        let bars = Rc::new(RefCell::new(Vec::new()));
+        let bars_clone = bars.clone();
+        let handler = Box::new(move |bar: Bar| bars_clone.borrow_mut().push(bar));
+        (bars, handler)
+    }
+
+    fn trade_tick(bar_type: &BarType, price: &str, size: &str, ts_event: UnixNanos) -> TradeTick {
+        TradeTick::new(
+            bar_type.instrument_id.clone(),
+            Price::from(price),
+            Quantity::from(size),
+            AggressorSide::Buyer,
+            TradeId::new("123456"),
+            ts_event,
+            ts_event,
+        )
+    }
+
+    #[test]
+    fn test_bar_builder_update_and_build() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-3-TICK-LAST-INTERNAL").unwrap();
+        let mut builder = BarBuilder::new(bar_type, 5, 0);
+
+        builder.update(Price::from("1.00001"), Quantity::from("1"), 1);
+        builder.update(Price::from("1.00002"), Quantity::from("1"), 2);
+        builder.update(Price::from("1.00000"), Quantity::from("1"), 3);
+        let bar = builder.build_now();
+
+        assert_eq!(bar.open, Price::from("1.00001"));
+        assert_eq!(bar.high, Price::from("1.00002"));
+        assert_eq!(bar.low, Price::from("1.00000"));
+        assert_eq!(bar.close, Price::from("1.00000"));
+        assert_eq!(bar.volume, Quantity::from("3"));
+        assert_eq!(bar.ts_event, 3);
+        assert_eq!(builder.count(), 0);
+    }
+
+    #[test]
+    fn test_bar_builder_ignores_earlier_update() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-3-TICK-LAST-INTERNAL").unwrap();
+        let mut builder = BarBuilder::new(bar_type, 5, 0);
+
+        builder.update(Price::from("1.00001"), Quantity::from("1"), 2);
+        builder.update(Price::from("1.00002"), Quantity::from("1"), 1);
+
+        assert_eq!(builder.count(), 1);
+        assert_eq!(builder.ts_last(), 2);
+    }
+
+    #[test]
+    fn test_bar_builder_build_with_no_updates_uses_last_close() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-1-MINUTE-LAST-INTERNAL").unwrap();
+        let mut builder = BarBuilder::new(bar_type, 5, 0);
+
+        builder.update(Price::from("1.00001"), Quantity::from("1"), 1);
+        let _ = builder.build_now();
+        let bar = builder.build(60, 60);
+
+        assert_eq!(bar.open, Price::from("1.00001"));
+        assert_eq!(bar.close, Price::from("1.00001"));
+        assert_eq!(bar.volume, Quantity::from("0"));
+    }
+
+    #[test]
+    fn test_tick_bar_aggregator() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-3-TICK-LAST-INTERNAL").unwrap();
+        let (bars, handler) = collect_bars();
+        let mut aggregator = TickBarAggregator::new(bar_type.clone(), 5, 0, handler);
+
+        aggregator.handle_trade_tick(&trade_tick(&bar_type, "1.00001", "1", 1));
+        aggregator.handle_trade_tick(&trade_tick(&bar_type, "1.00003", "1", 2));
+        assert!(bars.borrow().is_empty());
+
+        aggregator.handle_trade_tick(&trade_tick(&bar_type, "1.00002", "1", 3));
+        assert_eq!(bars.borrow().len(), 1);
+        assert_eq!(bars.borrow()[0].high, Price::from("1.00003"));
+        assert_eq!(bars.borrow()[0].volume, Quantity::from("3"));
+    }
+
+    #[test]
+    fn test_tick_bar_aggregator_handles_quote_ticks() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-1-TICK-BID-INTERNAL").unwrap();
+        let (bars, handler) = collect_bars();
+        let mut aggregator = TickBarAggregator::new(bar_type.clone(), 5, 0, handler);
+
+        let tick = QuoteTick::new(
+            bar_type.instrument_id.clone(),
+            Price::from("1.00001"),
+            Price::from("1.00004"),
+            Quantity::from("1"),
+            Quantity::from("2"),
+            1,
+            1,
+        );
+        aggregator.handle_quote_tick(&tick);
+
+        assert_eq!(bars.borrow().len(), 1);
+        assert_eq!(bars.borrow()[0].close, Price::from("1.00001"));
+        assert_eq!(bars.borrow()[0].volume, Quantity::from("1"));
+    }
+
+    #[test]
+    fn test_volume_bar_aggregator_splits_excess_volume() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-10-VOLUME-LAST-INTERNAL").unwrap();
+        let (bars, handler) = collect_bars();
+        let mut aggregator = VolumeBarAggregator::new(bar_type.clone(), 5, 0, handler);
+
+        aggregator.handle_trade_tick(&trade_tick(&bar_type, "1.00001", "25", 1));
+
+        assert_eq!(bars.borrow().len(), 2);
+        assert_eq!(bars.borrow()[0].volume, Quantity::from("10"));
+        assert_eq!(bars.borrow()[1].volume, Quantity::from("10"));
+    }
+
+    #[test]
+    fn test_value_bar_aggregator() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-100-VALUE-LAST-INTERNAL").unwrap();
+        let (bars, handler) = collect_bars();
+        let mut aggregator = ValueBarAggregator::new(bar_type.clone(), 2, 0, handler);
+
+        aggregator.handle_trade_tick(&trade_tick(&bar_type, "10.00", "5", 1));
+        assert!(bars.borrow().is_empty());
+        assert_eq!(aggregator.cum_value(), 50.0);
+
+        aggregator.handle_trade_tick(&trade_tick(&bar_type, "10.00", "10", 2));
+        assert_eq!(bars.borrow().len(), 1);
+        assert_eq!(bars.borrow()[0].volume, Quantity::from("10"));
+        assert_eq!(aggregator.cum_value(), 50.0);
+    }
+
+    #[test]
+    fn test_value_bar_aggregator_with_size_increment_above_step_makes_progress() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-10-VALUE-LAST-INTERNAL").unwrap();
+        let (bars, handler) = collect_bars();
+        let mut aggregator = ValueBarAggregator::new(bar_type.clone(), 2, 0, handler);
+
+        aggregator.handle_trade_tick(&trade_tick(&bar_type, "100.00", "5", 1));
+
+        assert_eq!(bars.borrow().len(), 5);
+        assert!(bars
+            .borrow()
+            .iter()
+            .all(|bar| bar.volume == Quantity::from("1")));
+        assert_eq!(aggregator.cum_value(), 0.0);
+    }
+
+    #[test]
+    fn test_time_bar_aggregator_interval() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-5-MINUTE-LAST-INTERNAL").unwrap();
+        let (_, handler) = collect_bars();
+        let aggregator = TimeBarAggregator::new(bar_type, 5, 0, handler, true);
+
+        assert_eq!(aggregator.interval_ns(), 300_000_000_000);
+        assert_eq!(
+            aggregator.get_start_time_ns(301_000_000_000),
+            300_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_time_bar_aggregator_build_bar() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-1-SECOND-LAST-INTERNAL").unwrap();
+        let (bars, handler) = collect_bars();
+        let mut aggregator = TimeBarAggregator::new(bar_type.clone(), 5, 0, handler, false);
+
+        aggregator.build_bar(1_000_000_000);
+        assert!(bars.borrow().is_empty());
+
+        aggregator.handle_trade_tick(&trade_tick(&bar_type, "1.00001", "1", 1_500_000_000));
+        aggregator.build_bar(2_000_000_000);
+        aggregator.build_bar(3_000_000_000); // No updates
+        assert_eq!(bars.borrow().len(), 1);
+        assert_eq!(bars.borrow()[0].ts_event, 2_000_000_000);
+        assert_eq!(aggregator.next_close_ns(), 4_000_000_000);
+    }
+
+    #[test]
+    fn test_time_bar_aggregator_skips_empty_bars_when_market_closed() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-1-SECOND-LAST-INTERNAL").unwrap();
+        let (bars, handler) = collect_bars();
+        let mut aggregator = TimeBarAggregator::new(bar_type.clone(), 5, 0, handler, true);
+        let mut calendar = TradingCalendar::new(bar_type.instrument_id.venue.clone(), 0);
+        calendar.add_session(chrono::Weekday::Thu, crate::calendar::Session::new(0, 2));
+        aggregator.set_calendar(calendar); // 1970-01-01 was a Thursday
+
+        aggregator.handle_trade_tick(&trade_tick(&bar_type, "1.00001", "1", 500_000_000));
+        aggregator.build_bar(1_000_000_000);
+        aggregator.build_bar(2_000_000_000); // No updates, market open
+        aggregator.build_bar(3_000_000_000); // No updates, market closed
+        aggregator.handle_trade_tick(&trade_tick(&bar_type, "1.00002", "1", 3_500_000_000));
+        aggregator.build_bar(4_000_000_000); // Market closed, with updates
+
+        let ts_events: Vec<UnixNanos> = bars.borrow().iter().map(|bar| bar.ts_event).collect();
+        assert_eq!(ts_events, vec![1_000_000_000, 2_000_000_000, 4_000_000_000]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_time_bar_aggregator_with_tick_aggregation_panics() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-1-TICK-LAST-INTERNAL").unwrap();
+        let (_, handler) = collect_bars();
+        let _ = TimeBarAggregator::new(bar_type, 5, 0, handler, true);
+    }
+}
@@ -0,0 +1,276 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::{
+    ffi_error::{write_result, FFI_ERROR},
+    time::UnixNanos,
+};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::BarAggregation,
+};
+use pyo3::{ffi, prelude::*};
+
+use crate::{
+    aggregation::{
+        BarAggregator, TickBarAggregator, TimeBarAggregator, ValueBarAggregator,
+        VolumeBarAggregator,
+    },
+    timer::TimeEvent,
+};
+
+/// Returns a bar handler which calls the given Python callable with each bar.
+///
+/// # Safety
+///
+/// - Assumes `callback_ptr` is a valid PyCallable pointer.
+unsafe fn bar_handler_from_py(
+    callback_ptr: *mut ffi::PyObject,
+) -> Result<Box<dyn FnMut(Bar)>, String> {
+    if callback_ptr.is_null() || ffi::Py_None() == callback_ptr {
+        return Err("invalid bar handler, `callback_ptr` was NULL or `None`".to_string());
+    }
+
+    let callback_py = Python::with_gil(|py| PyObject::from_borrowed_ptr(py, callback_ptr));
+    Ok(Box::new(move |bar: Bar| {
+        Python::with_gil(|py| {
+            if let Err(e) = callback_py.call1(py, (bar,)) {
+                // Report the exception in Python rather than unwinding across the FFI boundary
+                e.print(py);
+            }
+        });
+    }))
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying tick,
+/// volume or value [`BarAggregator`].
+///
+/// This struct wraps the aggregator in a way that makes it compatible with C function
+/// calls, enabling interaction with the aggregator in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `BarAggregator_API`
+/// to be dereferenced to the underlying aggregator, providing access to its methods without
+/// having to manually access the underlying instance.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct BarAggregator_API(Box<Box<dyn BarAggregator>>);
+
+impl Deref for BarAggregator_API {
+    type Target = Box<dyn BarAggregator>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for BarAggregator_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Writes a new bar aggregator for the tick, volume or value based `bar_type` to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `callback_ptr` is NULL or `None`, or the bar aggregation of `bar_type` is not tick,
+/// volume or value based.
+///
+/// # Safety
+///
+/// - Assumes `callback_ptr` is a valid PyCallable pointer.
+/// - Assumes `out` is a valid pointer to write the aggregator to.
+#[no_mangle]
+pub unsafe extern "C" fn bar_aggregator_new(
+    bar_type: BarType,
+    price_precision: u8,
+    size_precision: u8,
+    callback_ptr: *mut ffi::PyObject,
+    out: *mut BarAggregator_API,
+) -> u8 {
+    let result = bar_handler_from_py(callback_ptr).and_then(|handler| {
+        let aggregator: Box<dyn BarAggregator> = match bar_type.spec.aggregation {
+            BarAggregation::Tick => Box::new(TickBarAggregator::new(
+                bar_type,
+                price_precision,
+                size_precision,
+                handler,
+            )),
+            BarAggregation::Volume => Box::new(VolumeBarAggregator::new(
+                bar_type,
+                price_precision,
+                size_precision,
+                handler,
+            )),
+            BarAggregation::Value => Box::new(ValueBarAggregator::new(
+                bar_type,
+                price_precision,
+                size_precision,
+                handler,
+            )),
+            aggregation => {
+                return Err(format!(
+                    "Aggregation {aggregation} not supported by `bar_aggregator_new`"
+                ))
+            }
+        };
+        Ok(BarAggregator_API(Box::new(aggregator)))
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[no_mangle]
+pub extern "C" fn bar_aggregator_drop(aggregator: BarAggregator_API) {
+    drop(aggregator); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn bar_aggregator_bar_type(aggregator: &BarAggregator_API) -> BarType {
+    aggregator.bar_type().clone()
+}
+
+#[no_mangle]
+pub extern "C" fn bar_aggregator_handle_quote_tick(
+    aggregator: &mut BarAggregator_API,
+    tick: &QuoteTick,
+) {
+    aggregator.handle_quote_tick(tick);
+}
+
+#[no_mangle]
+pub extern "C" fn bar_aggregator_handle_trade_tick(
+    aggregator: &mut BarAggregator_API,
+    tick: &TradeTick,
+) {
+    aggregator.handle_trade_tick(tick);
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`TimeBarAggregator`].
+///
+/// This struct wraps `TimeBarAggregator` in a way that makes it compatible with C function
+/// calls, enabling interaction with `TimeBarAggregator` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `TimeBarAggregator_API`
+/// to be dereferenced to `TimeBarAggregator`, providing access to `TimeBarAggregator`'s methods
+/// without having to manually access the underlying `TimeBarAggregator` instance.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct TimeBarAggregator_API(Box<TimeBarAggregator>);
+
+impl Deref for TimeBarAggregator_API {
+    type Target = TimeBarAggregator;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for TimeBarAggregator_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Writes a new time bar aggregator for the time based `bar_type` to `out`.
+///
+/// The caller is responsible for setting a timer with the aggregators interval, and
+/// routing each resulting [`TimeEvent`] to `time_bar_aggregator_on_time_event`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `callback_ptr` is NULL or `None`.
+///
+/// # Safety
+///
+/// - Assumes `callback_ptr` is a valid PyCallable pointer.
+/// - Assumes `build_with_no_updates` is a correct `uint8_t` of either 0 or 1.
+/// - Assumes `out` is a valid pointer to write the aggregator to.
+#[no_mangle]
+pub unsafe extern "C" fn time_bar_aggregator_new(
+    bar_type: BarType,
+    price_precision: u8,
+    size_precision: u8,
+    build_with_no_updates: u8,
+    callback_ptr: *mut ffi::PyObject,
+    out: *mut TimeBarAggregator_API,
+) -> u8 {
+    let result = bar_handler_from_py(callback_ptr).map(|handler| {
+        TimeBarAggregator_API(Box::new(TimeBarAggregator::new(
+            bar_type,
+            price_precision,
+            size_precision,
+            handler,
+            build_with_no_updates != 0,
+        )))
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[no_mangle]
+pub extern "C" fn time_bar_aggregator_drop(aggregator: TimeBarAggregator_API) {
+    drop(aggregator); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn time_bar_aggregator_bar_type(aggregator: &TimeBarAggregator_API) -> BarType {
+    aggregator.bar_type().clone()
+}
+
+#[no_mangle]
+pub extern "C" fn time_bar_aggregator_interval_ns(aggregator: &TimeBarAggregator_API) -> u64 {
+    aggregator.interval_ns()
+}
+
+#[no_mangle]
+pub extern "C" fn time_bar_aggregator_next_close_ns(
+    aggregator: &TimeBarAggregator_API,
+) -> UnixNanos {
+    aggregator.next_close_ns()
+}
+
+#[no_mangle]
+pub extern "C" fn time_bar_aggregator_start_time_ns(
+    aggregator: &TimeBarAggregator_API,
+    ts_now: UnixNanos,
+) -> UnixNanos {
+    aggregator.get_start_time_ns(ts_now)
+}
+
+#[no_mangle]
+pub extern "C" fn time_bar_aggregator_handle_quote_tick(
+    aggregator: &mut TimeBarAggregator_API,
+    tick: &QuoteTick,
+) {
+    aggregator.handle_quote_tick(tick);
+}
+
+#[no_mangle]
+pub extern "C" fn time_bar_aggregator_handle_trade_tick(
+    aggregator: &mut TimeBarAggregator_API,
+    tick: &TradeTick,
+) {
+    aggregator.handle_trade_tick(tick);
+}
+
+#[no_mangle]
+pub extern "C" fn time_bar_aggregator_on_time_event(
+    aggregator: &mut TimeBarAggregator_API,
+    event: &TimeEvent,
+) {
+    aggregator.on_time_event(event);
+}
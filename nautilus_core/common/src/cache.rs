@@ -0,0 +1,1255 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A central in-memory cache of trading state.
+//!
+//! The [`Cache`] holds the instruments, accounts, orders, positions and recent market data
+//! for a trading node, along with secondary indexes to support the common queries made by
+//! the engines and strategies (by venue, instrument and strategy).
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    accounts::any::AccountAny,
+    data::{
+        bar::{Bar, BarType},
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::{OrderSide, PositionSide},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, strategy_id::StrategyId, venue::Venue,
+    },
+    instruments::BaseInstrument,
+    orders::Order,
+    position::Position,
+};
+use thiserror::Error;
+
+const DEFAULT_TICK_CAPACITY: usize = 10_000;
+const DEFAULT_BAR_CAPACITY: usize = 10_000;
+
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum CacheError {
+    #[error("Order {0} already exists in the cache")]
+    OrderExists(ClientOrderId),
+    #[error("Order {0} not found in the cache")]
+    OrderNotFound(ClientOrderId),
+    #[error("Position {0} already exists in the cache")]
+    PositionExists(PositionId),
+    #[error("Position {0} not found in the cache")]
+    PositionNotFound(PositionId),
+}
+
+/// Configuration for a [`Cache`].
+#[derive(Clone, Copy, Debug)]
+pub struct CacheConfig {
+    /// The maximum number of quote and trade ticks to hold per instrument.
+    pub tick_capacity: usize,
+    /// The maximum number of bars to hold per bar type.
+    pub bar_capacity: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            tick_capacity: DEFAULT_TICK_CAPACITY,
+            bar_capacity: DEFAULT_BAR_CAPACITY,
+        }
+    }
+}
+
+/// A point in time copy of the state held by a [`Cache`].
+///
+/// Market data is held newest first, in the same order as returned by the cache.
+#[derive(Clone, Debug, Default)]
+pub struct CacheSnapshot {
+    pub instruments: Vec<BaseInstrument>,
+    pub accounts: Vec<AccountAny>,
+    pub orders: Vec<Order>,
+    pub positions: Vec<Position>,
+    pub order_positions: Vec<(ClientOrderId, PositionId)>,
+    pub quote_ticks: Vec<(InstrumentId, Vec<QuoteTick>)>,
+    pub trade_ticks: Vec<(InstrumentId, Vec<TradeTick>)>,
+    pub bars: Vec<(BarType, Vec<Bar>)>,
+}
+
+#[derive(Default)]
+struct CacheIndex {
+    venue_account: HashMap<Venue, AccountId>,
+    venue_orders: HashMap<Venue, HashSet<ClientOrderId>>,
+    instrument_orders: HashMap<InstrumentId, HashSet<ClientOrderId>>,
+    strategy_orders: HashMap<StrategyId, HashSet<ClientOrderId>>,
+    side_orders: HashMap<OrderSide, HashSet<ClientOrderId>>,
+    venue_positions: HashMap<Venue, HashSet<PositionId>>,
+    instrument_positions: HashMap<InstrumentId, HashSet<PositionId>>,
+    strategy_positions: HashMap<StrategyId, HashSet<PositionId>>,
+    side_positions: HashMap<PositionSide, HashSet<PositionId>>,
+    order_position: HashMap<ClientOrderId, PositionId>,
+    orders: HashSet<ClientOrderId>,
+    orders_open: HashSet<ClientOrderId>,
+    orders_closed: HashSet<ClientOrderId>,
+    positions: HashSet<PositionId>,
+    positions_open: HashSet<PositionId>,
+    positions_closed: HashSet<PositionId>,
+}
+
+impl CacheIndex {
+    fn index_order(&mut self, order: &Order) {
+        let client_order_id = &order.client_order_id;
+        self.orders.insert(client_order_id.clone());
+        self.venue_orders
+            .entry(order.instrument_id.venue.clone())
+            .or_default()
+            .insert(client_order_id.clone());
+        self.instrument_orders
+            .entry(order.instrument_id.clone())
+            .or_default()
+            .insert(client_order_id.clone());
+        self.strategy_orders
+            .entry(order.strategy_id.clone())
+            .or_default()
+            .insert(client_order_id.clone());
+        self.side_orders
+            .entry(order.side)
+            .or_default()
+            .insert(client_order_id.clone());
+        if let Some(position_id) = &order.position_id {
+            self.order_position
+                .insert(client_order_id.clone(), position_id.clone());
+        }
+        self.index_order_status(order);
+    }
+
+    fn index_order_status(&mut self, order: &Order) {
+        let client_order_id = &order.client_order_id;
+        if order.is_closed() {
+            self.orders_open.remove(client_order_id);
+            self.orders_closed.insert(client_order_id.clone());
+        } else {
+            self.orders_closed.remove(client_order_id);
+            self.orders_open.insert(client_order_id.clone());
+        }
+    }
+
+    fn index_position(&mut self, position: &Position) {
+        let position_id = &position.id;
+        self.positions.insert(position_id.clone());
+        self.venue_positions
+            .entry(position.instrument_id.venue.clone())
+            .or_default()
+            .insert(position_id.clone());
+        self.instrument_positions
+            .entry(position.instrument_id.clone())
+            .or_default()
+            .insert(position_id.clone());
+        self.strategy_positions
+            .entry(position.strategy_id.clone())
+            .or_default()
+            .insert(position_id.clone());
+        self.index_position_status(position);
+    }
+
+    fn index_position_status(&mut self, position: &Position) {
+        let position_id = &position.id;
+        // The side of a position changes as it is filled, so it is re-indexed on every update
+        for positions in self.side_positions.values_mut() {
+            positions.remove(position_id);
+        }
+        self.side_positions
+            .entry(position.side)
+            .or_default()
+            .insert(position_id.clone());
+        if position.is_closed() {
+            self.positions_open.remove(position_id);
+            self.positions_closed.insert(position_id.clone());
+        } else {
+            self.positions_closed.remove(position_id);
+            self.positions_open.insert(position_id.clone());
+        }
+    }
+
+    fn order_filters(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<OrderSide>,
+    ) -> Option<Vec<&HashSet<ClientOrderId>>> {
+        let mut filters = Vec::new();
+        if let Some(venue) = venue {
+            filters.push(self.venue_orders.get(venue)?);
+        }
+        if let Some(instrument_id) = instrument_id {
+            filters.push(self.instrument_orders.get(instrument_id)?);
+        }
+        if let Some(strategy_id) = strategy_id {
+            filters.push(self.strategy_orders.get(strategy_id)?);
+        }
+        if let Some(side) = side {
+            filters.push(self.side_orders.get(&side)?);
+        }
+        Some(filters)
+    }
+
+    fn position_filters(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<PositionSide>,
+    ) -> Option<Vec<&HashSet<PositionId>>> {
+        let mut filters = Vec::new();
+        if let Some(venue) = venue {
+            filters.push(self.venue_positions.get(venue)?);
+        }
+        if let Some(instrument_id) = instrument_id {
+            filters.push(self.instrument_positions.get(instrument_id)?);
+        }
+        if let Some(strategy_id) = strategy_id {
+            filters.push(self.strategy_positions.get(strategy_id)?);
+        }
+        if let Some(side) = side {
+            filters.push(self.side_positions.get(&side)?);
+        }
+        Some(filters)
+    }
+}
+
+/// Returns the keys from `keys` which are contained in every one of the `filters`.
+///
+/// The smallest of `keys` and the `filters` is iterated, with each key then checked for
+/// membership of the remaining sets.
+fn select<'a, K: Eq + std::hash::Hash + 'a>(
+    keys: &'a HashSet<K>,
+    filters: Option<Vec<&'a HashSet<K>>>,
+) -> Vec<&'a K> {
+    let mut sets = match filters {
+        Some(filters) => filters,
+        None => return Vec::new(), // A filter matched nothing
+    };
+    sets.push(keys);
+    sets.sort_by_key(|set| set.len());
+    let (smallest, rest) = sets.split_first().expect("always at least `keys`");
+    smallest
+        .iter()
+        .filter(|key| rest.iter().all(|set| set.contains(*key)))
+        .collect()
+}
+
+/// Returns the values in `buffer` (held newest first) with a timestamp from `ts` within the
+/// inclusive `start` and `end` bounds.
+///
+/// Values are assumed to have been added in timestamp order, so the bounds are located with a
+/// binary search.
+fn select_range<T>(
+    buffer: &VecDeque<T>,
+    ts: impl Fn(&T) -> UnixNanos,
+    start: Option<UnixNanos>,
+    end: Option<UnixNanos>,
+) -> Vec<&T> {
+    let first = end.map_or(0, |end| buffer.partition_point(|value| ts(value) > end));
+    let last = start.map_or(buffer.len(), |start| {
+        buffer.partition_point(|value| ts(value) >= start)
+    });
+    if first >= last {
+        return Vec::new();
+    }
+    buffer.range(first..last).collect()
+}
+
+/// Pushes `value` to the front of `buffer`, dropping the oldest values beyond `capacity`.
+fn push_bounded<T>(buffer: &mut VecDeque<T>, value: T, capacity: usize) {
+    buffer.push_front(value);
+    buffer.truncate(capacity);
+}
+
+/// Provides a central in-memory cache of trading state.
+///
+/// Orders and positions are indexed by venue, instrument and strategy, and by whether they
+/// are open or closed. Query results are sorted by `ts_init` (then by identifier) so that
+/// they are deterministic.
+pub struct Cache {
+    config: CacheConfig,
+    index: CacheIndex,
+    instruments: HashMap<InstrumentId, BaseInstrument>,
+    accounts: HashMap<AccountId, AccountAny>,
+    orders: HashMap<ClientOrderId, Order>,
+    positions: HashMap<PositionId, Position>,
+    quote_ticks: HashMap<InstrumentId, VecDeque<QuoteTick>>,
+    trade_ticks: HashMap<InstrumentId, VecDeque<TradeTick>>,
+    bars: HashMap<BarType, VecDeque<Bar>>,
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Self::new(CacheConfig::default())
+    }
+}
+
+impl Cache {
+    #[must_use]
+    pub fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            index: CacheIndex::default(),
+            instruments: HashMap::new(),
+            accounts: HashMap::new(),
+            orders: HashMap::new(),
+            positions: HashMap::new(),
+            quote_ticks: HashMap::new(),
+            trade_ticks: HashMap::new(),
+            bars: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(&self) -> CacheConfig {
+        self.config
+    }
+
+    // -- INSTRUMENTS -----------------------------------------------------------------------------
+
+    /// Adds the `instrument` to the cache, replacing any instrument with the same ID.
+    pub fn add_instrument(&mut self, instrument: BaseInstrument) {
+        self.instruments.insert(instrument.id.clone(), instrument);
+    }
+
+    #[must_use]
+    pub fn instrument(&self, instrument_id: &InstrumentId) -> Option<&BaseInstrument> {
+        self.instruments.get(instrument_id)
+    }
+
+    /// Returns the IDs of all cached instruments, optionally filtered by `venue`.
+    #[must_use]
+    pub fn instrument_ids(&self, venue: Option<&Venue>) -> Vec<&InstrumentId> {
+        let mut instrument_ids: Vec<&InstrumentId> = self
+            .instruments
+            .keys()
+            .filter(|id| venue.map_or(true, |venue| &id.venue == venue))
+            .collect();
+        instrument_ids.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+        instrument_ids
+    }
+
+    // -- ACCOUNTS --------------------------------------------------------------------------------
+
+    /// Adds the `account` to the cache, replacing any account with the same ID.
+    ///
+    /// The account is indexed against the venue given by its issuer.
+    pub fn add_account(&mut self, account: AccountAny) {
+        let venue = Venue::new(account.id.get_issuer());
+        self.index.venue_account.insert(venue, account.id.clone());
+        self.accounts.insert(account.id.clone(), account);
+    }
+
+    #[must_use]
+    pub fn account(&self, account_id: &AccountId) -> Option<&AccountAny> {
+        self.accounts.get(account_id)
+    }
+
+    #[must_use]
+    pub fn account_for_venue(&self, venue: &Venue) -> Option<&AccountAny> {
+        self.index
+            .venue_account
+            .get(venue)
+            .and_then(|account_id| self.accounts.get(account_id))
+    }
+
+    // -- ORDERS ----------------------------------------------------------------------------------
+
+    /// Adds the `order` to the cache, optionally associating it with `position_id`.
+    ///
+    /// # Errors
+    ///
+    /// If an order with the same client order ID already exists in the cache.
+    pub fn add_order(
+        &mut self,
+        order: Order,
+        position_id: Option<PositionId>,
+    ) -> Result<(), CacheError> {
+        if self.orders.contains_key(&order.client_order_id) {
+            return Err(CacheError::OrderExists(order.client_order_id));
+        }
+        self.index.index_order(&order);
+        if let Some(position_id) = position_id {
+            self.index
+                .order_position
+                .insert(order.client_order_id.clone(), position_id);
+        }
+        self.orders.insert(order.client_order_id.clone(), order);
+        Ok(())
+    }
+
+    /// Replaces the cached order with the updated `order`, re-indexing its status.
+    ///
+    /// # Errors
+    ///
+    /// If the order does not already exist in the cache.
+    pub fn update_order(&mut self, order: Order) -> Result<(), CacheError> {
+        if !self.orders.contains_key(&order.client_order_id) {
+            return Err(CacheError::OrderNotFound(order.client_order_id));
+        }
+        self.index.index_order(&order);
+        self.orders.insert(order.client_order_id.clone(), order);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn order(&self, client_order_id: &ClientOrderId) -> Option<&Order> {
+        self.orders.get(client_order_id)
+    }
+
+    #[must_use]
+    pub fn order_exists(&self, client_order_id: &ClientOrderId) -> bool {
+        self.orders.contains_key(client_order_id)
+    }
+
+    /// Returns all orders matching the optional filters.
+    #[must_use]
+    pub fn orders(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<OrderSide>,
+    ) -> Vec<&Order> {
+        let filters = self
+            .index
+            .order_filters(venue, instrument_id, strategy_id, side);
+        self.get_orders(select(&self.index.orders, filters))
+    }
+
+    #[must_use]
+    pub fn orders_count(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<OrderSide>,
+    ) -> usize {
+        let filters = self
+            .index
+            .order_filters(venue, instrument_id, strategy_id, side);
+        select(&self.index.orders, filters).len()
+    }
+
+    /// Returns the open orders matching the optional filters.
+    #[must_use]
+    pub fn orders_open(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<OrderSide>,
+    ) -> Vec<&Order> {
+        let filters = self
+            .index
+            .order_filters(venue, instrument_id, strategy_id, side);
+        self.get_orders(select(&self.index.orders_open, filters))
+    }
+
+    #[must_use]
+    pub fn orders_open_count(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<OrderSide>,
+    ) -> usize {
+        let filters = self
+            .index
+            .order_filters(venue, instrument_id, strategy_id, side);
+        select(&self.index.orders_open, filters).len()
+    }
+
+    /// Returns the closed orders matching the optional filters.
+    #[must_use]
+    pub fn orders_closed(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<OrderSide>,
+    ) -> Vec<&Order> {
+        let filters = self
+            .index
+            .order_filters(venue, instrument_id, strategy_id, side);
+        self.get_orders(select(&self.index.orders_closed, filters))
+    }
+
+    #[must_use]
+    pub fn orders_closed_count(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<OrderSide>,
+    ) -> usize {
+        let filters = self
+            .index
+            .order_filters(venue, instrument_id, strategy_id, side);
+        select(&self.index.orders_closed, filters).len()
+    }
+
+    fn get_orders(&self, client_order_ids: Vec<&ClientOrderId>) -> Vec<&Order> {
+        let mut orders: Vec<&Order> = client_order_ids
+            .into_iter()
+            .filter_map(|id| self.orders.get(id))
+            .collect();
+        orders.sort_by(|a, b| {
+            a.ts_init
+                .cmp(&b.ts_init)
+                .then_with(|| a.client_order_id.value.cmp(&b.client_order_id.value))
+        });
+        orders
+    }
+
+    // -- POSITIONS -------------------------------------------------------------------------------
+
+    /// Adds the `position` to the cache, associating its opening order with it.
+    ///
+    /// # Errors
+    ///
+    /// If a position with the same ID already exists in the cache.
+    pub fn add_position(&mut self, position: Position) -> Result<(), CacheError> {
+        if self.positions.contains_key(&position.id) {
+            return Err(CacheError::PositionExists(position.id));
+        }
+        self.index.index_position(&position);
+        self.index
+            .order_position
+            .insert(position.opening_order_id.clone(), position.id.clone());
+        self.positions.insert(position.id.clone(), position);
+        Ok(())
+    }
+
+    /// Replaces the cached position with the updated `position`, re-indexing its status.
+    ///
+    /// # Errors
+    ///
+    /// If the position does not already exist in the cache.
+    pub fn update_position(&mut self, position: Position) -> Result<(), CacheError> {
+        if !self.positions.contains_key(&position.id) {
+            return Err(CacheError::PositionNotFound(position.id));
+        }
+        self.index.index_position_status(&position);
+        if let Some(closing_order_id) = &position.closing_order_id {
+            self.index
+                .order_position
+                .insert(closing_order_id.clone(), position.id.clone());
+        }
+        self.positions.insert(position.id.clone(), position);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn position(&self, position_id: &PositionId) -> Option<&Position> {
+        self.positions.get(position_id)
+    }
+
+    #[must_use]
+    pub fn position_exists(&self, position_id: &PositionId) -> bool {
+        self.positions.contains_key(position_id)
+    }
+
+    #[must_use]
+    pub fn position_id(&self, client_order_id: &ClientOrderId) -> Option<&PositionId> {
+        self.index.order_position.get(client_order_id)
+    }
+
+    #[must_use]
+    pub fn position_for_order(&self, client_order_id: &ClientOrderId) -> Option<&Position> {
+        self.position_id(client_order_id)
+            .and_then(|position_id| self.positions.get(position_id))
+    }
+
+    /// Returns all positions matching the optional filters.
+    #[must_use]
+    pub fn positions(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<PositionSide>,
+    ) -> Vec<&Position> {
+        let filters = self
+            .index
+            .position_filters(venue, instrument_id, strategy_id, side);
+        self.get_positions(select(&self.index.positions, filters))
+    }
+
+    #[must_use]
+    pub fn positions_count(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<PositionSide>,
+    ) -> usize {
+        let filters = self
+            .index
+            .position_filters(venue, instrument_id, strategy_id, side);
+        select(&self.index.positions, filters).len()
+    }
+
+    /// Returns the open positions matching the optional filters.
+    #[must_use]
+    pub fn positions_open(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<PositionSide>,
+    ) -> Vec<&Position> {
+        let filters = self
+            .index
+            .position_filters(venue, instrument_id, strategy_id, side);
+        self.get_positions(select(&self.index.positions_open, filters))
+    }
+
+    #[must_use]
+    pub fn positions_open_count(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<PositionSide>,
+    ) -> usize {
+        let filters = self
+            .index
+            .position_filters(venue, instrument_id, strategy_id, side);
+        select(&self.index.positions_open, filters).len()
+    }
+
+    /// Returns the closed positions matching the optional filters.
+    #[must_use]
+    pub fn positions_closed(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<PositionSide>,
+    ) -> Vec<&Position> {
+        let filters = self
+            .index
+            .position_filters(venue, instrument_id, strategy_id, side);
+        self.get_positions(select(&self.index.positions_closed, filters))
+    }
+
+    #[must_use]
+    pub fn positions_closed_count(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<PositionSide>,
+    ) -> usize {
+        let filters = self
+            .index
+            .position_filters(venue, instrument_id, strategy_id, side);
+        select(&self.index.positions_closed, filters).len()
+    }
+
+    fn get_positions(&self, position_ids: Vec<&PositionId>) -> Vec<&Position> {
+        let mut positions: Vec<&Position> = position_ids
+            .into_iter()
+            .filter_map(|id| self.positions.get(id))
+            .collect();
+        positions.sort_by(|a, b| {
+            a.ts_opened
+                .cmp(&b.ts_opened)
+                .then_with(|| a.id.value.cmp(&b.id.value))
+        });
+        positions
+    }
+
+    // -- MARKET DATA -----------------------------------------------------------------------------
+
+    pub fn add_quote_tick(&mut self, tick: QuoteTick) {
+        let buffer = self
+            .quote_ticks
+            .entry(tick.instrument_id.clone())
+            .or_default();
+        push_bounded(buffer, tick, self.config.tick_capacity);
+    }
+
+    pub fn add_trade_tick(&mut self, tick: TradeTick) {
+        let buffer = self
+            .trade_ticks
+            .entry(tick.instrument_id.clone())
+            .or_default();
+        push_bounded(buffer, tick, self.config.tick_capacity);
+    }
+
+    pub fn add_bar(&mut self, bar: Bar) {
+        let buffer = self.bars.entry(bar.bar_type.clone()).or_default();
+        push_bounded(buffer, bar, self.config.bar_capacity);
+    }
+
+    /// Returns the latest quote tick for the `instrument_id`.
+    #[must_use]
+    pub fn quote_tick(&self, instrument_id: &InstrumentId) -> Option<&QuoteTick> {
+        self.quote_ticks
+            .get(instrument_id)
+            .and_then(VecDeque::front)
+    }
+
+    /// Returns the cached quote ticks for the `instrument_id`, newest first.
+    #[must_use]
+    pub fn quote_ticks(&self, instrument_id: &InstrumentId) -> Vec<&QuoteTick> {
+        self.quote_ticks
+            .get(instrument_id)
+            .map(|buffer| buffer.iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the cached quote ticks for the `instrument_id` with a `ts_init` within the
+    /// inclusive `start` and `end` bounds, newest first.
+    #[must_use]
+    pub fn quote_ticks_range(
+        &self,
+        instrument_id: &InstrumentId,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> Vec<&QuoteTick> {
+        self.quote_ticks
+            .get(instrument_id)
+            .map(|buffer| select_range(buffer, |tick| tick.ts_init, start, end))
+            .unwrap_or_default()
+    }
+
+    /// Returns the latest trade tick for the `instrument_id`.
+    #[must_use]
+    pub fn trade_tick(&self, instrument_id: &InstrumentId) -> Option<&TradeTick> {
+        self.trade_ticks
+            .get(instrument_id)
+            .and_then(VecDeque::front)
+    }
+
+    /// Returns the cached trade ticks for the `instrument_id`, newest first.
+    #[must_use]
+    pub fn trade_ticks(&self, instrument_id: &InstrumentId) -> Vec<&TradeTick> {
+        self.trade_ticks
+            .get(instrument_id)
+            .map(|buffer| buffer.iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the cached trade ticks for the `instrument_id` with a `ts_init` within the
+    /// inclusive `start` and `end` bounds, newest first.
+    #[must_use]
+    pub fn trade_ticks_range(
+        &self,
+        instrument_id: &InstrumentId,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> Vec<&TradeTick> {
+        self.trade_ticks
+            .get(instrument_id)
+            .map(|buffer| select_range(buffer, |tick| tick.ts_init, start, end))
+            .unwrap_or_default()
+    }
+
+    /// Returns the latest bar for the `bar_type`.
+    #[must_use]
+    pub fn bar(&self, bar_type: &BarType) -> Option<&Bar> {
+        self.bars.get(bar_type).and_then(VecDeque::front)
+    }
+
+    /// Returns the cached bars for the `bar_type`, newest first.
+    #[must_use]
+    pub fn bars(&self, bar_type: &BarType) -> Vec<&Bar> {
+        self.bars
+            .get(bar_type)
+            .map(|buffer| buffer.iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the cached bars for the `bar_type` with a `ts_init` within the inclusive
+    /// `start` and `end` bounds, newest first.
+    #[must_use]
+    pub fn bars_range(
+        &self,
+        bar_type: &BarType,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> Vec<&Bar> {
+        self.bars
+            .get(bar_type)
+            .map(|buffer| select_range(buffer, |bar| bar.ts_init, start, end))
+            .unwrap_or_default()
+    }
+
+    // -- SNAPSHOTS -------------------------------------------------------------------------------
+
+    /// Returns a copy of all state held by the cache.
+    #[must_use]
+    pub fn snapshot(&self) -> CacheSnapshot {
+        CacheSnapshot {
+            instruments: self.instruments.values().cloned().collect(),
+            accounts: self.accounts.values().cloned().collect(),
+            orders: self.orders.values().cloned().collect(),
+            positions: self.positions.values().cloned().collect(),
+            order_positions: self
+                .index
+                .order_position
+                .iter()
+                .map(|(client_order_id, position_id)| {
+                    (client_order_id.clone(), position_id.clone())
+                })
+                .collect(),
+            quote_ticks: self
+                .quote_ticks
+                .iter()
+                .map(|(id, buffer)| (id.clone(), buffer.iter().cloned().collect()))
+                .collect(),
+            trade_ticks: self
+                .trade_ticks
+                .iter()
+                .map(|(id, buffer)| (id.clone(), buffer.iter().cloned().collect()))
+                .collect(),
+            bars: self
+                .bars
+                .iter()
+                .map(|(bar_type, buffer)| (bar_type.clone(), buffer.iter().cloned().collect()))
+                .collect(),
+        }
+    }
+
+    /// Replaces all state held by the cache with the `snapshot`, rebuilding the indexes.
+    pub fn restore(&mut self, snapshot: CacheSnapshot) {
+        self.reset();
+
+        for instrument in snapshot.instruments {
+            self.add_instrument(instrument);
+        }
+        for account in snapshot.accounts {
+            self.add_account(account);
+        }
+        for order in snapshot.orders {
+            self.index.index_order(&order);
+            self.orders.insert(order.client_order_id.clone(), order);
+        }
+        for position in snapshot.positions {
+            self.index.index_position(&position);
+            self.positions.insert(position.id.clone(), position);
+        }
+        self.index.order_position.extend(snapshot.order_positions);
+
+        let tick_capacity = self.config.tick_capacity;
+        let bar_capacity = self.config.bar_capacity;
+        self.quote_ticks = snapshot
+            .quote_ticks
+            .into_iter()
+            .map(|(id, ticks)| (id, ticks.into_iter().take(tick_capacity).collect()))
+            .collect();
+        self.trade_ticks = snapshot
+            .trade_ticks
+            .into_iter()
+            .map(|(id, ticks)| (id, ticks.into_iter().take(tick_capacity).collect()))
+            .collect();
+        self.bars = snapshot
+            .bars
+            .into_iter()
+            .map(|(bar_type, bars)| (bar_type, bars.into_iter().take(bar_capacity).collect()))
+            .collect();
+    }
+
+    /// Clears all state held by the cache.
+    pub fn reset(&mut self) {
+        self.index = CacheIndex::default();
+        self.instruments.clear();
+        self.accounts.clear();
+        self.orders.clear();
+        self.positions.clear();
+        self.quote_ticks.clear();
+        self.trade_ticks.clear();
+        self.bars.clear();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        currencies::{AUD, USD},
+        enums::{AssetClass, AssetType, LiquiditySide, OrderStatus, OrderType, TimeInForce},
+        events::order::OrderFilled,
+        identifiers::{
+            symbol::Symbol, trade_id::TradeId, trader_id::TraderId, venue_order_id::VenueOrderId,
+        },
+        orders::factory::OrderFactory,
+        types::{money::Money, price::Price, quantity::Quantity},
+    };
+    use rstest::{fixture, rstest};
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    fn instrument(symbol: &str, venue: &str) -> BaseInstrument {
+        BaseInstrument {
+            id: InstrumentId::from_str(&format!("{symbol}.{venue}")).unwrap(),
+            native_symbol: Symbol::new(symbol),
+            asset_class: AssetClass::FX,
+            asset_type: AssetType::Spot,
+            quote_currency: USD.clone(),
+            base_currency: Some(AUD.clone()),
+            cost_currency: USD.clone(),
+            is_inverse: false,
+            price_precision: 5,
+            size_precision: 0,
+            price_increment: Price::from("0.00001"),
+            size_increment: Quantity::from("1"),
+            multiplier: Quantity::from("1"),
+            lot_size: Some(Quantity::from("1000")),
+            max_quantity: None,
+            min_quantity: None,
+            max_price: None,
+            min_price: None,
+            margin_init: Decimal::ZERO,
+            margin_maint: Decimal::ZERO,
+            maker_fee: Decimal::ZERO,
+            taker_fee: Decimal::ZERO,
+        }
+    }
+
+    fn market_order(
+        factory: &mut OrderFactory,
+        instrument_id: &InstrumentId,
+        ts_init: UnixNanos,
+    ) -> Order {
+        factory
+            .market(
+                instrument_id.clone(),
+                OrderSide::Buy,
+                Quantity::from("100000"),
+                TimeInForce::Gtc,
+                false,
+                false,
+                None,
+                ts_init,
+            )
+            .into()
+    }
+
+    fn position(order: &Order, position_id: &str, side: OrderSide, ts: UnixNanos) -> Position {
+        let fill = OrderFilled {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: VenueOrderId::default(),
+            account_id: AccountId::new("SIM-001"),
+            trade_id: TradeId::new(position_id),
+            position_id: Some(PositionId::new(position_id)),
+            order_side: side,
+            order_type: OrderType::Market,
+            last_qty: Quantity::from("100000"),
+            last_px: Price::from("1.00000"),
+            currency: USD.clone(),
+            commission: Money::new(0.0, USD.clone()),
+            liquidity_side: LiquiditySide::Taker,
+            event_id: UUID4::default(),
+            ts_event: ts,
+            ts_init: ts,
+            reconciliation: false,
+        };
+        Position::new(&instrument("AUD/USD", "SIM"), fill)
+    }
+
+    fn quote(instrument_id: &InstrumentId, bid: &str, ts: UnixNanos) -> QuoteTick {
+        QuoteTick {
+            instrument_id: instrument_id.clone(),
+            bid: Price::from(bid),
+            ask: Price::from(bid),
+            bid_size: Quantity::from("1000000"),
+            ask_size: Quantity::from("1000000"),
+            ts_event: ts,
+            ts_init: ts,
+        }
+    }
+
+    #[fixture]
+    fn factory() -> OrderFactory {
+        OrderFactory::new(TraderId::new("TRADER-001"), StrategyId::new("S-001"), 0, 0)
+    }
+
+    #[rstest]
+    fn test_add_order_rejects_duplicate(mut factory: OrderFactory) {
+        let mut cache = Cache::default();
+        let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        let order = market_order(&mut factory, &instrument_id, 1);
+
+        cache.add_order(order.clone(), None).unwrap();
+        let result = cache.add_order(order.clone(), None);
+
+        assert_eq!(
+            result,
+            Err(CacheError::OrderExists(order.client_order_id.clone()))
+        );
+        assert!(cache.order_exists(&order.client_order_id));
+    }
+
+    #[rstest]
+    fn test_update_order_not_found(mut factory: OrderFactory) {
+        let mut cache = Cache::default();
+        let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        let order = market_order(&mut factory, &instrument_id, 1);
+
+        let result = cache.update_order(order.clone());
+
+        assert_eq!(
+            result,
+            Err(CacheError::OrderNotFound(order.client_order_id))
+        );
+    }
+
+    #[rstest]
+    fn test_orders_filtered_by_indexes(mut factory: OrderFactory) {
+        let mut cache = Cache::default();
+        let audusd = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        let ethusdt = InstrumentId::from_str("ETH/USDT.BINANCE").unwrap();
+        let order1 = market_order(&mut factory, &audusd, 1);
+        let order2 = market_order(&mut factory, &ethusdt, 2);
+        let mut order3 = market_order(&mut factory, &audusd, 3);
+        cache.add_order(order1.clone(), None).unwrap();
+        cache.add_order(order2.clone(), None).unwrap();
+        cache.add_order(order3.clone(), None).unwrap();
+
+        order3.status = OrderStatus::Canceled;
+        cache.update_order(order3.clone()).unwrap();
+
+        let strategy_id = factory.strategy_id.clone();
+        let sim = Venue::new("SIM");
+        let ids = |orders: Vec<&Order>| {
+            orders
+                .into_iter()
+                .map(|order| order.client_order_id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(cache.orders(None, None, None, None).len(), 3);
+        assert_eq!(
+            ids(cache.orders(Some(&sim), None, None, None)),
+            vec![
+                order1.client_order_id.clone(),
+                order3.client_order_id.clone()
+            ]
+        );
+        assert_eq!(
+            ids(cache.orders_open(Some(&sim), None, Some(&strategy_id), None)),
+            vec![order1.client_order_id.clone()]
+        );
+        assert_eq!(
+            ids(cache.orders_closed(None, Some(&audusd), None, None)),
+            vec![order3.client_order_id.clone()]
+        );
+        assert_eq!(
+            ids(cache.orders_open(None, Some(&ethusdt), None, None)),
+            vec![order2.client_order_id.clone()]
+        );
+        assert!(cache
+            .orders(None, None, Some(&StrategyId::new("S-002")), None)
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_orders_filtered_by_side(mut factory: OrderFactory) {
+        let mut cache = Cache::default();
+        let audusd = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        let buy = market_order(&mut factory, &audusd, 1);
+        let sell: Order = factory
+            .market(
+                audusd.clone(),
+                OrderSide::Sell,
+                Quantity::from("100000"),
+                TimeInForce::Gtc,
+                false,
+                false,
+                None,
+                2,
+            )
+            .into();
+        cache.add_order(buy.clone(), None).unwrap();
+        cache.add_order(sell.clone(), None).unwrap();
+
+        let sells = cache.orders_open(None, Some(&audusd), None, Some(OrderSide::Sell));
+
+        assert_eq!(sells.len(), 1);
+        assert_eq!(sells[0].client_order_id, sell.client_order_id);
+        assert_eq!(
+            cache.orders_open_count(Some(&audusd.venue), None, None, Some(OrderSide::Buy)),
+            1
+        );
+        assert_eq!(cache.orders_closed_count(None, None, None, None), 0);
+        assert_eq!(cache.orders_count(None, None, None, None), 2);
+    }
+
+    #[rstest]
+    fn test_positions_indexed_by_status(mut factory: OrderFactory) {
+        let mut cache = Cache::default();
+        let audusd = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        let order1 = market_order(&mut factory, &audusd, 1);
+        let order2 = market_order(&mut factory, &audusd, 2);
+        let mut position = position(&order1, "P-1", OrderSide::Buy, 1);
+        cache.add_order(order1.clone(), None).unwrap();
+        cache.add_position(position.clone()).unwrap();
+
+        assert_eq!(
+            cache.positions_open(None, Some(&audusd), None, None).len(),
+            1
+        );
+        assert_eq!(
+            cache.positions_open_count(None, None, None, Some(PositionSide::Long)),
+            1
+        );
+        assert_eq!(
+            cache
+                .position_for_order(&order1.client_order_id)
+                .unwrap()
+                .id,
+            PositionId::new("P-1")
+        );
+
+        let mut close = self::position(&order2, "P-1", OrderSide::Sell, 2).events()[0].clone();
+        close.trade_id = TradeId::new("P-2");
+        position.apply(close);
+        cache.update_position(position).unwrap();
+
+        assert!(cache.positions_open(None, None, None, None).is_empty());
+        assert_eq!(
+            cache
+                .positions_closed(None, Some(&audusd), None, None)
+                .len(),
+            1
+        );
+        assert_eq!(
+            cache.positions_count(None, None, None, Some(PositionSide::Long)),
+            0
+        );
+        assert_eq!(
+            cache.positions_closed_count(None, None, None, Some(PositionSide::Flat)),
+            1
+        );
+        assert_eq!(
+            cache.position_id(&order2.client_order_id),
+            Some(&PositionId::new("P-1"))
+        );
+        assert_eq!(
+            cache.add_position(self::position(&order1, "P-1", OrderSide::Buy, 1)),
+            Err(CacheError::PositionExists(PositionId::new("P-1")))
+        );
+    }
+
+    #[rstest]
+    fn test_quote_ticks_are_bounded_newest_first() {
+        let mut cache = Cache::new(CacheConfig {
+            tick_capacity: 2,
+            bar_capacity: 2,
+        });
+        let audusd = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        cache.add_quote_tick(quote(&audusd, "1.00001", 1));
+        cache.add_quote_tick(quote(&audusd, "1.00002", 2));
+        cache.add_quote_tick(quote(&audusd, "1.00003", 3));
+
+        let ticks = cache.quote_ticks(&audusd);
+
+        assert_eq!(ticks.len(), 2);
+        assert_eq!(ticks[0].ts_event, 3);
+        assert_eq!(ticks[1].ts_event, 2);
+        assert_eq!(
+            cache.quote_tick(&audusd).unwrap().bid,
+            Price::from("1.00003")
+        );
+        assert!(cache.trade_tick(&audusd).is_none());
+    }
+
+    #[rstest]
+    fn test_quote_ticks_range() {
+        let mut cache = Cache::default();
+        let audusd = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        for ts in 1..=5 {
+            cache.add_quote_tick(quote(&audusd, "1.00001", ts));
+        }
+
+        let ts_init = |ticks: Vec<&QuoteTick>| ticks.iter().map(|t| t.ts_init).collect::<Vec<_>>();
+
+        assert_eq!(
+            ts_init(cache.quote_ticks_range(&audusd, Some(2), Some(4))),
+            vec![4, 3, 2]
+        );
+        assert_eq!(
+            ts_init(cache.quote_ticks_range(&audusd, Some(4), None)),
+            vec![5, 4]
+        );
+        assert_eq!(
+            ts_init(cache.quote_ticks_range(&audusd, None, Some(1))),
+            vec![1]
+        );
+        assert!(cache.quote_ticks_range(&audusd, Some(6), None).is_empty());
+        assert!(cache
+            .quote_ticks_range(&audusd, Some(4), Some(2))
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_snapshot_restore_round_trip(mut factory: OrderFactory) {
+        let mut cache = Cache::default();
+        let audusd = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        let order = market_order(&mut factory, &audusd, 1);
+        cache.add_instrument(instrument("AUD/USD", "SIM"));
+        cache.add_order(order.clone(), None).unwrap();
+        cache
+            .add_position(position(&order, "P-1", OrderSide::Buy, 1))
+            .unwrap();
+        cache.add_quote_tick(quote(&audusd, "1.00001", 1));
+        cache.add_quote_tick(quote(&audusd, "1.00002", 2));
+
+        let snapshot = cache.snapshot();
+        let mut restored = Cache::default();
+        restored.restore(snapshot);
+        cache.reset();
+
+        assert!(cache.orders(None, None, None, None).is_empty());
+        assert!(restored.instrument(&audusd).is_some());
+        assert_eq!(
+            restored.instrument_ids(Some(&Venue::new("SIM"))),
+            vec![&audusd]
+        );
+        assert_eq!(
+            restored
+                .orders_open(Some(&audusd.venue), None, None, None)
+                .len(),
+            1
+        );
+        assert_eq!(
+            restored
+                .positions_open(None, Some(&audusd), None, None)
+                .len(),
+            1
+        );
+        assert_eq!(
+            restored.position_id(&order.client_order_id),
+            Some(&PositionId::new("P-1"))
+        );
+        assert_eq!(restored.quote_tick(&audusd).unwrap().ts_event, 2);
+    }
+}
@@ -0,0 +1,299 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::{OrderSide, PositionSide},
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, position_id::PositionId,
+        strategy_id::StrategyId, venue::Venue,
+    },
+};
+
+use crate::cache::{Cache, CacheConfig, CacheSnapshot};
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Cache`].
+///
+/// This struct wraps `Cache` in a way that makes it compatible with C function
+/// calls, enabling interaction with `Cache` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `Cache_API` to be
+/// dereferenced to `Cache`, providing access to `Cache`'s methods without having to manually
+/// access the underlying `Cache` instance.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct Cache_API(Box<Cache>);
+
+impl Deref for Cache_API {
+    type Target = Cache;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Cache_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`CacheSnapshot`].
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct CacheSnapshot_API(Box<CacheSnapshot>);
+
+impl Deref for CacheSnapshot_API {
+    type Target = CacheSnapshot;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn cache_new(tick_capacity: usize, bar_capacity: usize) -> Cache_API {
+    let config = CacheConfig {
+        tick_capacity,
+        bar_capacity,
+    };
+    Cache_API(Box::new(Cache::new(config)))
+}
+
+#[no_mangle]
+pub extern "C" fn cache_drop(cache: Cache_API) {
+    drop(cache); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn cache_reset(cache: &mut Cache_API) {
+    cache.reset();
+}
+
+#[no_mangle]
+pub extern "C" fn cache_add_quote_tick(cache: &mut Cache_API, tick: &QuoteTick) {
+    cache.add_quote_tick(tick.clone());
+}
+
+#[no_mangle]
+pub extern "C" fn cache_add_trade_tick(cache: &mut Cache_API, tick: &TradeTick) {
+    cache.add_trade_tick(tick.clone());
+}
+
+#[no_mangle]
+pub extern "C" fn cache_add_bar(cache: &mut Cache_API, bar: &Bar) {
+    cache.add_bar(bar.clone());
+}
+
+/// Writes the latest quote tick for the `instrument_id` to `out`.
+///
+/// Returns 1 if a quote tick was written, otherwise 0.
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to a `QuoteTick`.
+#[no_mangle]
+pub unsafe extern "C" fn cache_quote_tick(
+    cache: &Cache_API,
+    instrument_id: &InstrumentId,
+    out: *mut QuoteTick,
+) -> u8 {
+    match cache.quote_tick(instrument_id) {
+        Some(tick) => {
+            out.write(tick.clone());
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Writes the latest trade tick for the `instrument_id` to `out`.
+///
+/// Returns 1 if a trade tick was written, otherwise 0.
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to a `TradeTick`.
+#[no_mangle]
+pub unsafe extern "C" fn cache_trade_tick(
+    cache: &Cache_API,
+    instrument_id: &InstrumentId,
+    out: *mut TradeTick,
+) -> u8 {
+    match cache.trade_tick(instrument_id) {
+        Some(tick) => {
+            out.write(tick.clone());
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Writes the latest bar for the `bar_type` to `out`.
+///
+/// Returns 1 if a bar was written, otherwise 0.
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to a `Bar`.
+#[no_mangle]
+pub unsafe extern "C" fn cache_bar(cache: &Cache_API, bar_type: &BarType, out: *mut Bar) -> u8 {
+    match cache.bar(bar_type) {
+        Some(bar) => {
+            out.write(bar.clone());
+            1
+        }
+        None => 0,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn cache_quote_tick_count(cache: &Cache_API, instrument_id: &InstrumentId) -> usize {
+    cache.quote_ticks(instrument_id).len()
+}
+
+#[no_mangle]
+pub extern "C" fn cache_trade_tick_count(cache: &Cache_API, instrument_id: &InstrumentId) -> usize {
+    cache.trade_ticks(instrument_id).len()
+}
+
+#[no_mangle]
+pub extern "C" fn cache_bar_count(cache: &Cache_API, bar_type: &BarType) -> usize {
+    cache.bars(bar_type).len()
+}
+
+#[no_mangle]
+pub extern "C" fn cache_order_exists(cache: &Cache_API, client_order_id: &ClientOrderId) -> u8 {
+    u8::from(cache.order_exists(client_order_id))
+}
+
+#[no_mangle]
+pub extern "C" fn cache_position_exists(cache: &Cache_API, position_id: &PositionId) -> u8 {
+    u8::from(cache.position_exists(position_id))
+}
+
+/// Returns the count of open orders matching the filters, where a `side` of
+/// `NO_ORDER_SIDE` applies no side filter.
+///
+/// # Safety
+///
+/// - Assumes each filter is either NULL (no filter) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn cache_orders_open_count(
+    cache: &Cache_API,
+    venue: *const Venue,
+    instrument_id: *const InstrumentId,
+    strategy_id: *const StrategyId,
+    side: OrderSide,
+) -> usize {
+    let side = (side != OrderSide::NoOrderSide).then_some(side);
+    cache.orders_open_count(
+        venue.as_ref(),
+        instrument_id.as_ref(),
+        strategy_id.as_ref(),
+        side,
+    )
+}
+
+/// Returns the count of closed orders matching the filters, where a `side` of
+/// `NO_ORDER_SIDE` applies no side filter.
+///
+/// # Safety
+///
+/// - Assumes each filter is either NULL (no filter) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn cache_orders_closed_count(
+    cache: &Cache_API,
+    venue: *const Venue,
+    instrument_id: *const InstrumentId,
+    strategy_id: *const StrategyId,
+    side: OrderSide,
+) -> usize {
+    let side = (side != OrderSide::NoOrderSide).then_some(side);
+    cache.orders_closed_count(
+        venue.as_ref(),
+        instrument_id.as_ref(),
+        strategy_id.as_ref(),
+        side,
+    )
+}
+
+/// Returns the count of open positions matching the filters, where a `side` of
+/// `NO_POSITION_SIDE` applies no side filter.
+///
+/// # Safety
+///
+/// - Assumes each filter is either NULL (no filter) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn cache_positions_open_count(
+    cache: &Cache_API,
+    venue: *const Venue,
+    instrument_id: *const InstrumentId,
+    strategy_id: *const StrategyId,
+    side: PositionSide,
+) -> usize {
+    let side = (side != PositionSide::NoPositionSide).then_some(side);
+    cache.positions_open_count(
+        venue.as_ref(),
+        instrument_id.as_ref(),
+        strategy_id.as_ref(),
+        side,
+    )
+}
+
+/// Returns the count of closed positions matching the filters, where a `side` of
+/// `NO_POSITION_SIDE` applies no side filter.
+///
+/// # Safety
+///
+/// - Assumes each filter is either NULL (no filter) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn cache_positions_closed_count(
+    cache: &Cache_API,
+    venue: *const Venue,
+    instrument_id: *const InstrumentId,
+    strategy_id: *const StrategyId,
+    side: PositionSide,
+) -> usize {
+    let side = (side != PositionSide::NoPositionSide).then_some(side);
+    cache.positions_closed_count(
+        venue.as_ref(),
+        instrument_id.as_ref(),
+        strategy_id.as_ref(),
+        side,
+    )
+}
+
+/// Returns a snapshot of the cache state, which must be freed with `cache_snapshot_drop`.
+#[no_mangle]
+pub extern "C" fn cache_snapshot(cache: &Cache_API) -> CacheSnapshot_API {
+    CacheSnapshot_API(Box::new(cache.snapshot()))
+}
+
+#[no_mangle]
+pub extern "C" fn cache_restore(cache: &mut Cache_API, snapshot: &CacheSnapshot_API) {
+    cache.restore(snapshot.deref().clone());
+}
+
+#[no_mangle]
+pub extern "C" fn cache_snapshot_drop(snapshot: CacheSnapshot_API) {
+    drop(snapshot); // Memory freed here
+}
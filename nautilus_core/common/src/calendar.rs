@@ -0,0 +1,416 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trading session calendars, defining when a venue is open for trading.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Weekday};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::identifiers::venue::Venue;
+use thiserror::Error;
+
+const NANOSECONDS_IN_SECOND: u64 = 1_000_000_000;
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+const SECONDS_IN_DAY: u32 = 86_400;
+
+/// The number of days searched forward for the next session open or close.
+const MAX_SEARCH_DAYS: u64 = 400;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CalendarError {
+    #[error("invalid session {0}-{1} (expected open before close within a day)")]
+    InvalidSession(u32, u32),
+    #[error("session {0:?} overlapped an existing session on {1}")]
+    OverlappingSession(Session, Weekday),
+}
+
+/// Represents a trading session within a day, in seconds from local midnight.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Session {
+    pub open_secs: u32,
+    pub close_secs: u32,
+}
+
+impl Session {
+    /// # Errors
+    ///
+    /// - If `open_secs` is not before `close_secs`, or `close_secs` is after midnight.
+    pub fn try_new(open_secs: u32, close_secs: u32) -> Result<Self, CalendarError> {
+        if open_secs >= close_secs || close_secs > SECONDS_IN_DAY {
+            return Err(CalendarError::InvalidSession(open_secs, close_secs));
+        }
+        Ok(Self {
+            open_secs,
+            close_secs,
+        })
+    }
+
+    /// # Panics
+    ///
+    /// - If `open_secs` is not before `close_secs`, or `close_secs` is after midnight.
+    #[must_use]
+    pub fn new(open_secs: u32, close_secs: u32) -> Self {
+        Self::try_new(open_secs, close_secs).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Returns a session from the given local `(hour, minute)` open and close times.
+    #[must_use]
+    pub fn from_hm(open: (u32, u32), close: (u32, u32)) -> Self {
+        Self::new(open.0 * 3600 + open.1 * 60, close.0 * 3600 + close.1 * 60)
+    }
+
+    /// Returns a session covering the whole day.
+    #[must_use]
+    pub fn all_day() -> Self {
+        Self::new(0, SECONDS_IN_DAY)
+    }
+}
+
+/// Provides the trading sessions of a venue, with holidays and half-days, for queries in
+/// UNIX nanoseconds.
+///
+/// Sessions are defined per weekday in the venues local time, given as a fixed offset from
+/// UTC, and may not span midnight. Adjacent sessions (including across midnight) are treated
+/// as one continuous session, so a market which never closes within the search horizon
+/// has no next open or close.
+#[derive(Clone, Debug)]
+pub struct TradingCalendar {
+    pub venue: Venue,
+    utc_offset_secs: i32,
+    weekly: [Vec<Session>; 7],
+    holidays: BTreeSet<NaiveDate>,
+    early_closes: BTreeMap<NaiveDate, u32>,
+}
+
+impl TradingCalendar {
+    /// Creates a new calendar with no sessions, in local time `utc_offset_secs` from UTC.
+    #[must_use]
+    pub fn new(venue: Venue, utc_offset_secs: i32) -> Self {
+        Self {
+            venue,
+            utc_offset_secs,
+            weekly: Default::default(),
+            holidays: BTreeSet::new(),
+            early_closes: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a new calendar which is open at all times (such as for crypto venues).
+    #[must_use]
+    pub fn always_open(venue: Venue) -> Self {
+        let mut calendar = Self::new(venue, 0);
+        for weekday in WEEKDAYS {
+            calendar.add_session(weekday, Session::all_day());
+        }
+        calendar
+    }
+
+    #[must_use]
+    pub fn utc_offset_secs(&self) -> i32 {
+        self.utc_offset_secs
+    }
+
+    /// Adds the `session` on every `weekday`.
+    ///
+    /// # Errors
+    ///
+    /// - If the session overlaps an existing session on that weekday.
+    pub fn try_add_session(
+        &mut self,
+        weekday: Weekday,
+        session: Session,
+    ) -> Result<(), CalendarError> {
+        let sessions = &mut self.weekly[weekday.num_days_from_monday() as usize];
+        if sessions.iter().any(|existing| {
+            session.open_secs < existing.close_secs && existing.open_secs < session.close_secs
+        }) {
+            return Err(CalendarError::OverlappingSession(session, weekday));
+        }
+        sessions.push(session);
+        sessions.sort();
+        Ok(())
+    }
+
+    /// Adds the `session` on every `weekday`.
+    ///
+    /// # Panics
+    ///
+    /// - If the session overlaps an existing session on that weekday.
+    pub fn add_session(&mut self, weekday: Weekday, session: Session) {
+        self.try_add_session(weekday, session)
+            .unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    /// Adds the `session` on every weekday from Monday to Friday.
+    pub fn add_weekday_session(&mut self, session: Session) {
+        for weekday in &WEEKDAYS[..5] {
+            self.add_session(*weekday, session);
+        }
+    }
+
+    /// Adds a holiday on the local `date`, on which there are no sessions.
+    pub fn add_holiday(&mut self, date: NaiveDate) {
+        self.holidays.insert(date);
+    }
+
+    /// Adds a half-day on the local `date`, closing all sessions at `close_secs` from local
+    /// midnight.
+    pub fn add_half_day(&mut self, date: NaiveDate, close_secs: u32) {
+        self.early_closes.insert(date, close_secs);
+    }
+
+    #[must_use]
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date)
+    }
+
+    /// Returns the sessions on the local `date` as `(open, close)` UNIX nanosecond pairs.
+    ///
+    /// Sessions before the UNIX epoch are omitted.
+    #[must_use]
+    pub fn sessions_on(&self, date: NaiveDate) -> Vec<(UnixNanos, UnixNanos)> {
+        if self.is_holiday(date) {
+            return Vec::new();
+        }
+        let midnight_secs = match date.and_hms_opt(0, 0, 0) {
+            Some(midnight) => midnight.timestamp() - i64::from(self.utc_offset_secs),
+            None => return Vec::new(),
+        };
+        let early_close = self.early_closes.get(&date).copied();
+        let to_nanos = |secs: u32| -> Option<UnixNanos> {
+            let secs = u64::try_from(midnight_secs + i64::from(secs)).ok()?;
+            Some(secs * NANOSECONDS_IN_SECOND)
+        };
+
+        self.weekly[date.weekday().num_days_from_monday() as usize]
+            .iter()
+            .filter_map(|session| {
+                let close_secs = early_close.map_or(session.close_secs, |early_close| {
+                    early_close.min(session.close_secs)
+                });
+                if close_secs <= session.open_secs {
+                    return None; // Closed early before the session opens
+                }
+                Some((to_nanos(session.open_secs)?, to_nanos(close_secs)?))
+            })
+            .collect()
+    }
+
+    /// Returns the sessions overlapping `[start, end]` as `(open, close)` UNIX nanosecond
+    /// pairs, ordered by open.
+    #[must_use]
+    pub fn sessions_between(
+        &self,
+        start: UnixNanos,
+        end: UnixNanos,
+    ) -> Vec<(UnixNanos, UnixNanos)> {
+        let (first, last) = match (self.local_date(start), self.local_date(end)) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Vec::new(),
+        };
+        first
+            .iter_days()
+            .take_while(|date| *date <= last)
+            .flat_map(|date| self.sessions_on(date))
+            .filter(|(open, close)| *close > start && *open <= end)
+            .collect()
+    }
+
+    /// Returns whether the venue is open at `ts` (sessions are open from their open
+    /// inclusive until their close exclusive).
+    #[must_use]
+    pub fn is_open(&self, ts: UnixNanos) -> bool {
+        self.local_date(ts).map_or(false, |date| {
+            self.sessions_on(date)
+                .iter()
+                .any(|(open, close)| *open <= ts && ts < *close)
+        })
+    }
+
+    /// Returns the next session open after `ts`, or `None` if there is none within the
+    /// search horizon.
+    #[must_use]
+    pub fn next_open(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.merged_sessions_from(ts)
+            .map(|(open, _)| open)
+            .find(|open| *open > ts)
+    }
+
+    /// Returns the next session close after `ts`, or `None` if there is none within the
+    /// search horizon.
+    #[must_use]
+    pub fn next_close(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.merged_sessions_from(ts)
+            .map(|(_, close)| close)
+            .find(|close| *close > ts)
+            .filter(|close| *close != UnixNanos::MAX)
+    }
+
+    fn local_date(&self, ts: UnixNanos) -> Option<NaiveDate> {
+        let secs =
+            i64::try_from(ts / NANOSECONDS_IN_SECOND).ok()? + i64::from(self.utc_offset_secs);
+        NaiveDateTime::from_timestamp_opt(secs, 0).map(|datetime| datetime.date())
+    }
+
+    /// Returns the sessions from the local date of `ts` with adjacent sessions merged, where a
+    /// session continuing to the end of the search horizon closes at `UnixNanos::MAX`.
+    fn merged_sessions_from(
+        &self,
+        ts: UnixNanos,
+    ) -> impl Iterator<Item = (UnixNanos, UnixNanos)> + '_ {
+        let mut sessions = self
+            .local_date(ts)
+            .into_iter()
+            .flat_map(|date| date.iter_days().take(MAX_SEARCH_DAYS as usize))
+            .flat_map(|date| self.sessions_on(date))
+            .peekable();
+
+        std::iter::from_fn(move || {
+            let (open, mut close) = sessions.next()?;
+            while let Some((_, next_close)) = sessions.next_if(|(next_open, _)| *next_open <= close)
+            {
+                close = close.max(next_close);
+            }
+            let is_unbounded = sessions.peek().is_none()
+                && close - open > (MAX_SEARCH_DAYS - 7) * NANOSECONDS_IN_DAY;
+            Some((open, if is_unbounded { UnixNanos::MAX } else { close }))
+        })
+    }
+}
+
+/// The days of the week, from Monday.
+pub(crate) const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const EST_OFFSET_SECS: i32 = -5 * 3600;
+
+    fn utc_ns(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> UnixNanos {
+        let datetime = NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap();
+        datetime.timestamp() as u64 * NANOSECONDS_IN_SECOND
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn nyse_calendar() -> TradingCalendar {
+        let mut calendar = TradingCalendar::new(Venue::new("XNYS"), EST_OFFSET_SECS);
+        calendar.add_weekday_session(Session::from_hm((9, 30), (16, 0)));
+        calendar.add_holiday(date(2023, 12, 25));
+        calendar.add_half_day(date(2023, 11, 24), 13 * 3600);
+        calendar
+    }
+
+    #[rstest]
+    #[case(utc_ns(2023, 11, 20, 14, 29), false)]
+    #[case(utc_ns(2023, 11, 20, 14, 30), true)]
+    #[case(utc_ns(2023, 11, 20, 20, 59), true)]
+    #[case(utc_ns(2023, 11, 20, 21, 0), false)]
+    #[case(utc_ns(2023, 11, 18, 15, 0), false)] // Saturday
+    #[case(utc_ns(2023, 12, 25, 15, 0), false)] // Holiday
+    #[case(utc_ns(2023, 11, 24, 17, 59), true)]
+    #[case(utc_ns(2023, 11, 24, 18, 0), false)] // Half-day
+    fn test_is_open(#[case] ts: UnixNanos, #[case] expected: bool) {
+        assert_eq!(nyse_calendar().is_open(ts), expected);
+    }
+
+    #[rstest]
+    #[case(utc_ns(2023, 11, 20, 12, 0), utc_ns(2023, 11, 20, 14, 30))]
+    #[case(utc_ns(2023, 11, 20, 14, 30), utc_ns(2023, 11, 21, 14, 30))]
+    #[case(utc_ns(2023, 11, 17, 22, 0), utc_ns(2023, 11, 20, 14, 30))]
+    #[case(utc_ns(2023, 12, 22, 22, 0), utc_ns(2023, 12, 26, 14, 30))]
+    fn test_next_open(#[case] ts: UnixNanos, #[case] expected: UnixNanos) {
+        assert_eq!(nyse_calendar().next_open(ts), Some(expected));
+    }
+
+    #[rstest]
+    #[case(utc_ns(2023, 11, 20, 15, 0), utc_ns(2023, 11, 20, 21, 0))]
+    #[case(utc_ns(2023, 11, 20, 21, 0), utc_ns(2023, 11, 21, 21, 0))]
+    #[case(utc_ns(2023, 11, 24, 15, 0), utc_ns(2023, 11, 24, 18, 0))]
+    fn test_next_close(#[case] ts: UnixNanos, #[case] expected: UnixNanos) {
+        assert_eq!(nyse_calendar().next_close(ts), Some(expected));
+    }
+
+    #[test]
+    fn test_sessions_between() {
+        let calendar = nyse_calendar();
+        let sessions =
+            calendar.sessions_between(utc_ns(2023, 12, 22, 15, 0), utc_ns(2023, 12, 27, 0, 0));
+        assert_eq!(
+            sessions,
+            vec![
+                (utc_ns(2023, 12, 22, 14, 30), utc_ns(2023, 12, 22, 21, 0)),
+                (utc_ns(2023, 12, 26, 14, 30), utc_ns(2023, 12, 26, 21, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lunch_break_sessions() {
+        let mut calendar = TradingCalendar::new(Venue::new("XTKS"), 9 * 3600);
+        calendar.add_weekday_session(Session::from_hm((9, 0), (11, 30)));
+        calendar.add_weekday_session(Session::from_hm((12, 30), (15, 0)));
+
+        // Monday 2023-11-20 11:45 JST
+        let ts = utc_ns(2023, 11, 20, 2, 45);
+        assert!(!calendar.is_open(ts));
+        assert_eq!(calendar.next_open(ts), Some(utc_ns(2023, 11, 20, 3, 30)));
+        assert_eq!(calendar.next_close(ts), Some(utc_ns(2023, 11, 20, 6, 0)));
+    }
+
+    #[test]
+    fn test_always_open() {
+        let calendar = TradingCalendar::always_open(Venue::new("BINANCE"));
+        let ts = utc_ns(2023, 11, 18, 23, 59);
+        assert!(calendar.is_open(ts));
+        assert_eq!(calendar.next_open(ts), None);
+        assert_eq!(calendar.next_close(ts), None);
+    }
+
+    #[test]
+    fn test_no_sessions() {
+        let calendar = TradingCalendar::new(Venue::new("SIM"), 0);
+        assert!(!calendar.is_open(0));
+        assert_eq!(calendar.next_open(0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overlapped an existing session")]
+    fn test_add_overlapping_session() {
+        let mut calendar = TradingCalendar::new(Venue::new("SIM"), 0);
+        calendar.add_session(Weekday::Mon, Session::from_hm((9, 0), (12, 0)));
+        calendar.add_session(Weekday::Mon, Session::from_hm((11, 0), (13, 0)));
+    }
+}
@@ -0,0 +1,149 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use chrono::NaiveDate;
+use nautilus_core::{
+    ffi_error::{write_result, FFI_ERROR},
+    time::UnixNanos,
+};
+use nautilus_model::identifiers::venue::Venue;
+
+use crate::calendar::{Session, TradingCalendar, WEEKDAYS};
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`TradingCalendar`].
+///
+/// This struct wraps `TradingCalendar` in a way that makes it compatible with C function
+/// calls, enabling interaction with `TradingCalendar` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `TradingCalendar_API`
+/// to be dereferenced to `TradingCalendar`, providing access to `TradingCalendar`'s methods
+/// without having to manually access the underlying `TradingCalendar` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct TradingCalendar_API(Box<TradingCalendar>);
+
+impl Deref for TradingCalendar_API {
+    type Target = TradingCalendar;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for TradingCalendar_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn trading_calendar_new(venue: &Venue, utc_offset_secs: i32) -> TradingCalendar_API {
+    TradingCalendar_API(Box::new(TradingCalendar::new(
+        venue.clone(),
+        utc_offset_secs,
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn trading_calendar_always_open(venue: &Venue) -> TradingCalendar_API {
+    TradingCalendar_API(Box::new(TradingCalendar::always_open(venue.clone())))
+}
+
+#[no_mangle]
+pub extern "C" fn trading_calendar_drop(calendar: TradingCalendar_API) {
+    drop(calendar); // Memory freed here
+}
+
+/// Adds a session on the `weekday` (0 for Monday to 6 for Sunday), in seconds from local
+/// midnight.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `weekday` is greater than 6, or the session is invalid or overlaps another session.
+#[no_mangle]
+pub extern "C" fn trading_calendar_add_session(
+    calendar: &mut TradingCalendar_API,
+    weekday: u8,
+    open_secs: u32,
+    close_secs: u32,
+) -> u8 {
+    let result = match WEEKDAYS.get(weekday as usize) {
+        Some(weekday) => Session::try_new(open_secs, close_secs)
+            .and_then(|session| calendar.try_add_session(*weekday, session))
+            .map_err(|e| e.to_string()),
+        None => Err(format!("invalid weekday {weekday}")),
+    };
+    unsafe { write_result(result, &mut (), |_| FFI_ERROR) }
+}
+
+/// Adds a holiday on the local date.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// the date is invalid.
+#[no_mangle]
+pub extern "C" fn trading_calendar_add_holiday(
+    calendar: &mut TradingCalendar_API,
+    year: i32,
+    month: u32,
+    day: u32,
+) -> u8 {
+    let result = parse_date(year, month, day).map(|date| calendar.add_holiday(date));
+    unsafe { write_result(result, &mut (), |_| FFI_ERROR) }
+}
+
+/// Adds a half-day on the local date, closing at `close_secs` from local midnight.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// the date is invalid.
+#[no_mangle]
+pub extern "C" fn trading_calendar_add_half_day(
+    calendar: &mut TradingCalendar_API,
+    year: i32,
+    month: u32,
+    day: u32,
+    close_secs: u32,
+) -> u8 {
+    let result = parse_date(year, month, day).map(|date| calendar.add_half_day(date, close_secs));
+    unsafe { write_result(result, &mut (), |_| FFI_ERROR) }
+}
+
+#[no_mangle]
+pub extern "C" fn trading_calendar_is_open(calendar: &TradingCalendar_API, ts: UnixNanos) -> u8 {
+    u8::from(calendar.is_open(ts))
+}
+
+/// Returns the next session open after `ts`, or 0 if there is none.
+#[no_mangle]
+pub extern "C" fn trading_calendar_next_open(
+    calendar: &TradingCalendar_API,
+    ts: UnixNanos,
+) -> UnixNanos {
+    calendar.next_open(ts).unwrap_or(0)
+}
+
+/// Returns the next session close after `ts`, or 0 if there is none.
+#[no_mangle]
+pub extern "C" fn trading_calendar_next_close(
+    calendar: &TradingCalendar_API,
+    ts: UnixNanos,
+) -> UnixNanos {
+    calendar.next_close(ts).unwrap_or(0)
+}
+
+fn parse_date(year: i32, month: u32, day: u32) -> Result<NaiveDate, String> {
+    NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(|| format!("invalid date {year}-{month}-{day}"))
+}
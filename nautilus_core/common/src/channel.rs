@@ -0,0 +1,528 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A bounded multi-producer, single-consumer channel for queues between components.
+//!
+//! Unlike `std::sync::mpsc::channel`, the queue never grows beyond its capacity. When a
+//! sender finds the queue full, the channel's [`OverflowPolicy`] decides whether the
+//! sender waits, the oldest queued value is dropped, or the send fails. Both ends can
+//! report [`ChannelMetrics`] such as the queue depth, so overload can be observed.
+
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug, Display},
+    sync::{
+        mpsc::{RecvError, RecvTimeoutError, TryRecvError},
+        Arc, Condvar, Mutex, MutexGuard,
+    },
+    time::{Duration, Instant},
+};
+
+use nautilus_core::correctness;
+
+/// The behaviour of a send when the queue is full.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// The sender waits until the receiver makes room (backpressure).
+    #[default]
+    Block,
+    /// The oldest queued value is dropped to make room.
+    DropOldest,
+    /// The send fails, returning the value to the sender.
+    Fail,
+}
+
+/// An error returned from a send, holding the value which was not sent.
+#[derive(Clone, PartialEq, Eq)]
+pub enum SendError<T> {
+    /// The queue was full under [`OverflowPolicy::Fail`] (or for a `try_send`).
+    Full(T),
+    /// The receiver was dropped.
+    Disconnected(T),
+}
+
+impl<T> SendError<T> {
+    /// Returns the value which was not sent.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Full(value) | Self::Disconnected(value) => value,
+        }
+    }
+}
+
+impl<T> Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_) => write!(f, "Full(..)"),
+            Self::Disconnected(_) => write!(f, "Disconnected(..)"),
+        }
+    }
+}
+
+impl<T> Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_) => write!(f, "sending on a full channel"),
+            Self::Disconnected(_) => write!(f, "sending on a disconnected channel"),
+        }
+    }
+}
+
+impl<T> std::error::Error for SendError<T> {}
+
+/// Represents a snapshot of the metrics of a channel.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChannelMetrics {
+    /// The maximum number of queued values.
+    pub capacity: usize,
+    /// The number of values currently queued.
+    pub depth: usize,
+    /// The greatest number of values queued at once.
+    pub high_water_mark: usize,
+    /// The count of values sent.
+    pub sent: u64,
+    /// The count of queued values dropped under [`OverflowPolicy::DropOldest`].
+    pub dropped: u64,
+    /// The count of sends which failed because the queue was full.
+    pub rejected: u64,
+    /// The count of sends which had to wait under [`OverflowPolicy::Block`].
+    pub blocked: u64,
+}
+
+#[derive(Debug)]
+struct State<T> {
+    queue: VecDeque<T>,
+    senders: usize,
+    is_receiver_connected: bool,
+    metrics: ChannelMetrics,
+}
+
+#[derive(Debug)]
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    policy: OverflowPolicy,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap()
+    }
+
+    fn metrics(&self) -> ChannelMetrics {
+        let state = self.lock();
+        ChannelMetrics {
+            depth: state.queue.len(),
+            ..state.metrics
+        }
+    }
+}
+
+/// Creates a bounded channel holding up to `capacity` values, applying the `policy` when
+/// the queue is full.
+///
+/// # Panics
+///
+/// - If `capacity` is zero.
+#[must_use]
+pub fn bounded<T>(
+    capacity: usize,
+    policy: OverflowPolicy,
+) -> (BoundedSender<T>, BoundedReceiver<T>) {
+    correctness::u64_in_range_inclusive(capacity as u64, 1, u64::MAX, "capacity");
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::with_capacity(capacity),
+            senders: 1,
+            is_receiver_connected: true,
+            metrics: ChannelMetrics {
+                capacity,
+                ..Default::default()
+            },
+        }),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+        policy,
+    });
+    (
+        BoundedSender {
+            shared: shared.clone(),
+        },
+        BoundedReceiver { shared },
+    )
+}
+
+/// The sending half of a [`bounded`] channel, which can be cloned for each producer.
+#[derive(Debug)]
+pub struct BoundedSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BoundedSender<T> {
+    /// Sends the `value`, applying the channel's [`OverflowPolicy`] if the queue is full.
+    ///
+    /// # Errors
+    ///
+    /// - If the queue is full under [`OverflowPolicy::Fail`].
+    /// - If the receiver was dropped.
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        self.send_with(value, self.shared.policy)
+    }
+
+    /// Sends the `value` if it can be done without waiting, otherwise returns it.
+    ///
+    /// # Errors
+    ///
+    /// - If the queue is full under [`OverflowPolicy::Block`] or [`OverflowPolicy::Fail`].
+    /// - If the receiver was dropped.
+    pub fn try_send(&self, value: T) -> Result<(), SendError<T>> {
+        let policy = match self.shared.policy {
+            OverflowPolicy::Block => OverflowPolicy::Fail,
+            policy => policy,
+        };
+        self.send_with(value, policy)
+    }
+
+    /// Returns the number of values currently queued.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.shared.lock().queue.len()
+    }
+
+    /// Returns true if no values are queued.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[must_use]
+    pub fn metrics(&self) -> ChannelMetrics {
+        self.shared.metrics()
+    }
+
+    fn send_with(&self, value: T, policy: OverflowPolicy) -> Result<(), SendError<T>> {
+        let mut state = self.shared.lock();
+        let mut has_blocked = false;
+        loop {
+            if !state.is_receiver_connected {
+                return Err(SendError::Disconnected(value));
+            }
+            if state.queue.len() < state.metrics.capacity {
+                break;
+            }
+            match policy {
+                OverflowPolicy::Block => {
+                    if !has_blocked {
+                        state.metrics.blocked += 1;
+                        has_blocked = true;
+                    }
+                    state = self.shared.not_full.wait(state).unwrap();
+                }
+                OverflowPolicy::DropOldest => {
+                    state.queue.pop_front();
+                    state.metrics.dropped += 1;
+                }
+                OverflowPolicy::Fail => {
+                    state.metrics.rejected += 1;
+                    return Err(SendError::Full(value));
+                }
+            }
+        }
+
+        state.queue.push_back(value);
+        state.metrics.sent += 1;
+        state.metrics.high_water_mark = state.metrics.high_water_mark.max(state.queue.len());
+        drop(state);
+        self.shared.not_empty.notify_one();
+        Ok(())
+    }
+}
+
+impl<T> Clone for BoundedSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for BoundedSender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+        if state.senders == 0 {
+            drop(state);
+            // Wake the receiver so it can observe the hang up
+            self.shared.not_empty.notify_all();
+        }
+    }
+}
+
+/// The receiving half of a [`bounded`] channel.
+#[derive(Debug)]
+pub struct BoundedReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BoundedReceiver<T> {
+    /// Waits for a value, returning an error once the queue is empty and every sender
+    /// has been dropped.
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(value) = self.pop(&mut state) {
+                return Ok(value);
+            }
+            if state.senders == 0 {
+                return Err(RecvError);
+            }
+            state = self.shared.not_empty.wait(state).unwrap();
+        }
+    }
+
+    /// Waits up to `timeout` for a value.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(value) = self.pop(&mut state) {
+                return Ok(value);
+            }
+            if state.senders == 0 {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(RecvTimeoutError::Timeout);
+            }
+            state = self
+                .shared
+                .not_empty
+                .wait_timeout(state, deadline - now)
+                .unwrap()
+                .0;
+        }
+    }
+
+    /// Returns a value if one is queued, without waiting.
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let mut state = self.shared.lock();
+        match self.pop(&mut state) {
+            Some(value) => Ok(value),
+            None if state.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Returns the number of values currently queued.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.shared.lock().queue.len()
+    }
+
+    /// Returns true if no values are queued.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[must_use]
+    pub fn metrics(&self) -> ChannelMetrics {
+        self.shared.metrics()
+    }
+
+    fn pop(&self, state: &mut MutexGuard<'_, State<T>>) -> Option<T> {
+        let value = state.queue.pop_front();
+        if value.is_some() {
+            self.shared.not_full.notify_one();
+        }
+        value
+    }
+}
+
+impl<T> Drop for BoundedReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.lock().is_receiver_connected = false;
+        // Wake any blocked senders so they can observe the hang up
+        self.shared.not_full.notify_all();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_zero_capacity_panics() {
+        let _ = bounded::<u32>(0, OverflowPolicy::Block);
+    }
+
+    #[rstest]
+    #[case(OverflowPolicy::Block)]
+    #[case(OverflowPolicy::DropOldest)]
+    #[case(OverflowPolicy::Fail)]
+    fn test_send_and_recv_in_order(#[case] policy: OverflowPolicy) {
+        let (tx, rx) = bounded(3, policy);
+
+        for i in 0..3 {
+            tx.send(i).unwrap();
+        }
+
+        assert_eq!(rx.len(), 3);
+        assert_eq!(rx.recv(), Ok(0));
+        assert_eq!(rx.try_recv(), Ok(1));
+        assert_eq!(rx.recv_timeout(Duration::ZERO), Ok(2));
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+    }
+
+    #[test]
+    fn test_drop_oldest() {
+        let (tx, rx) = bounded(2, OverflowPolicy::DropOldest);
+
+        for i in 0..5 {
+            tx.send(i).unwrap();
+        }
+
+        assert_eq!(rx.recv(), Ok(3));
+        assert_eq!(rx.recv(), Ok(4));
+        let metrics = rx.metrics();
+        assert_eq!(metrics.sent, 5);
+        assert_eq!(metrics.dropped, 3);
+        assert_eq!(metrics.high_water_mark, 2);
+        assert_eq!(metrics.depth, 0);
+    }
+
+    #[test]
+    fn test_fail_returns_value() {
+        let (tx, rx) = bounded(1, OverflowPolicy::Fail);
+
+        tx.send(1).unwrap();
+        let result = tx.send(2);
+
+        assert_eq!(result, Err(SendError::Full(2)));
+        assert_eq!(result.unwrap_err().into_inner(), 2);
+        assert_eq!(tx.metrics().rejected, 1);
+        assert_eq!(rx.recv(), Ok(1));
+    }
+
+    #[test]
+    fn test_try_send_does_not_block() {
+        let (tx, _rx) = bounded(1, OverflowPolicy::Block);
+
+        tx.send(1).unwrap();
+
+        assert_eq!(tx.try_send(2), Err(SendError::Full(2)));
+    }
+
+    #[test]
+    fn test_block_waits_for_receiver() {
+        let (tx, rx) = bounded(1, OverflowPolicy::Block);
+        tx.send(0).unwrap();
+
+        let producer = thread::spawn(move || {
+            for i in 1..=10 {
+                tx.send(i).unwrap();
+            }
+            tx.metrics()
+        });
+
+        let received: Vec<i32> = std::iter::from_fn(|| rx.recv().ok()).collect();
+        let metrics = producer.join().unwrap();
+
+        assert_eq!(received, (0..=10).collect::<Vec<_>>());
+        assert_eq!(metrics.sent, 11);
+        assert_eq!(metrics.high_water_mark, 1);
+        assert_eq!(metrics.dropped, 0);
+    }
+
+    #[test]
+    fn test_multiple_producers() {
+        let (tx, rx) = bounded(4, OverflowPolicy::Block);
+
+        let producers: Vec<_> = (0..4)
+            .map(|_| {
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    for i in 0..100 {
+                        tx.send(i).unwrap();
+                    }
+                })
+            })
+            .collect();
+        drop(tx);
+
+        let mut count = 0;
+        while rx.recv().is_ok() {
+            count += 1;
+        }
+        for producer in producers {
+            producer.join().unwrap();
+        }
+
+        assert_eq!(count, 400);
+    }
+
+    #[test]
+    fn test_disconnected() {
+        let (tx, rx) = bounded(2, OverflowPolicy::Block);
+        tx.send(1).unwrap();
+        drop(tx);
+
+        // Queued values are still delivered after the senders hang up
+        assert_eq!(rx.recv(), Ok(1));
+        assert_eq!(rx.recv(), Err(RecvError));
+        assert_eq!(
+            rx.recv_timeout(Duration::from_millis(1)),
+            Err(RecvTimeoutError::Disconnected)
+        );
+
+        let (tx, rx) = bounded(1, OverflowPolicy::Block);
+        drop(rx);
+
+        assert_eq!(tx.send(1), Err(SendError::Disconnected(1)));
+    }
+
+    #[test]
+    fn test_blocked_sender_wakes_on_receiver_drop() {
+        let (tx, rx) = bounded(1, OverflowPolicy::Block);
+        tx.send(1).unwrap();
+
+        let producer = thread::spawn(move || tx.send(2));
+        thread::sleep(Duration::from_millis(10));
+        drop(rx);
+
+        assert_eq!(producer.join().unwrap(), Err(SendError::Disconnected(2)));
+    }
+
+    #[test]
+    fn test_recv_timeout() {
+        let (_tx, rx) = bounded::<u32>(1, OverflowPolicy::Block);
+
+        assert_eq!(
+            rx.recv_timeout(Duration::from_millis(5)),
+            Err(RecvTimeoutError::Timeout)
+        );
+    }
+}
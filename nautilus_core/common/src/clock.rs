@@ -22,7 +22,7 @@ use nautilus_core::{
 };
 use pyo3::{prelude::*, AsPyPointer};
 
-use crate::timer::{TestTimer, TimeEvent, TimeEventHandler};
+use crate::timer::{LiveTimer, TestTimer, TimeEvent, TimeEventHandler};
 
 const ONE_NANOSECOND_DURATION: Duration = Duration::from_nanos(1);
 
@@ -115,6 +115,27 @@ pub trait Clock {
 
     fn register_default_handler_py(&mut self, callback_py: PyObject);
 
+    /// Set a [Timer] to alert at a particular time. Optional
+    /// callback gets used to handle generated events.
+    fn set_time_alert_ns(
+        &mut self,
+        name: String,
+        alert_time_ns: UnixNanos,
+        callback: Option<Box<dyn Fn(TimeEvent)>>,
+    );
+
+    /// Set a [Timer] to start alerting at every interval
+    /// between start and stop time. Optional callback gets
+    /// used to handle generated events.
+    fn set_timer_ns(
+        &mut self,
+        name: String,
+        interval_ns: u64,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<Box<dyn Fn(TimeEvent)>>,
+    );
+
     /// Set a [Timer] to alert at a particular time. Optional
     /// callback gets used to handle generated events.
     fn set_time_alert_ns_py(
@@ -146,7 +167,7 @@ pub struct TestClock {
     timers: HashMap<String, TestTimer>,
     default_callback: Option<Box<dyn Fn(TimeEvent)>>,
     default_callback_py: Option<PyObject>,
-    _callbacks: HashMap<String, Box<dyn Fn(TimeEvent)>>,
+    callbacks: HashMap<String, Box<dyn Fn(TimeEvent)>>,
     callbacks_py: HashMap<String, PyObject>,
 }
 
@@ -155,10 +176,32 @@ impl TestClock {
         &self.timers
     }
 
+    /// Checks a Python timer named `name` can be set on the clock.
+    ///
+    /// # Errors
+    ///
+    /// - If `name` is not a valid string.
+    /// - If `has_callback` is false and no default Python handler has been registered.
+    pub fn check_timer_py(&self, name: &str, has_callback: bool) -> Result<(), String> {
+        correctness::check_valid_string(name, "`Timer` name")?;
+        if !has_callback && self.default_callback_py.is_none() {
+            return Err("All Python callbacks were `None`".to_string());
+        }
+        Ok(())
+    }
+
     pub fn set_time(&mut self, to_time_ns: UnixNanos) {
         self.time_ns = to_time_ns
     }
 
+    /// Advances the clock to `to_time_ns`, returning the time events fired by all timers
+    /// in the interval, sorted by `ts_event` then timer name.
+    ///
+    /// Timers which expire are removed from the clock.
+    ///
+    /// # Panics
+    ///
+    /// - If `to_time_ns` is less than the current time.
     pub fn advance_time(&mut self, to_time_ns: UnixNanos, set_time: bool) -> Vec<TimeEvent> {
         // Time should increase monotonically
         assert!(
@@ -170,15 +213,35 @@ impl TestClock {
             self.time_ns = to_time_ns;
         }
 
-        let mut timers: Vec<TimeEvent> = self
+        let mut events: Vec<TimeEvent> = self
             .timers
             .iter_mut()
             .filter(|(_, timer)| !timer.is_expired)
             .flat_map(|(_, timer)| timer.advance(to_time_ns))
             .collect();
+        self.timers.retain(|_, timer| !timer.is_expired);
 
-        timers.sort_by(|a, b| a.ts_event.cmp(&b.ts_event));
-        timers
+        // Timers are held in a hash map, so break ties on name for a deterministic order
+        events.sort_by(|a, b| {
+            a.ts_event
+                .cmp(&b.ts_event)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        events
+    }
+
+    /// Calls the registered Rust callback for each of the given `events`, falling back to
+    /// the default callback. Events without a Rust callback are skipped.
+    pub fn handle_events(&self, events: Vec<TimeEvent>) {
+        for event in events {
+            let callback = self
+                .callbacks
+                .get(event.name.as_str())
+                .or(self.default_callback.as_ref());
+            if let Some(callback) = callback {
+                callback(event);
+            }
+        }
     }
 
     /// Assumes time events are sorted by their `ts_event`.
@@ -211,7 +274,7 @@ impl Clock for TestClock {
             timers: HashMap::new(),
             default_callback: None,
             default_callback_py: None,
-            _callbacks: HashMap::new(), // TBC
+            callbacks: HashMap::new(),
             callbacks_py: HashMap::new(),
         }
     }
@@ -255,16 +318,69 @@ impl Clock for TestClock {
         self.default_callback_py = Some(callback_py)
     }
 
+    fn set_time_alert_ns(
+        &mut self,
+        name: String,
+        alert_time_ns: UnixNanos,
+        callback: Option<Box<dyn Fn(TimeEvent)>>,
+    ) {
+        correctness::valid_string(&name, "`Timer` name");
+        assert!(
+            alert_time_ns >= self.time_ns,
+            "`alert_time_ns` was < the current time"
+        );
+        assert!(
+            callback.is_some() | self.default_callback.is_some(),
+            "All callbacks were `None`"
+        );
+
+        if let Some(callback) = callback {
+            self.callbacks.insert(name.clone(), callback);
+        }
+
+        let timer = TestTimer::new(
+            name.clone(),
+            alert_time_ns - self.time_ns,
+            self.time_ns,
+            Some(alert_time_ns),
+        );
+        self.timers.insert(name, timer);
+    }
+
+    fn set_timer_ns(
+        &mut self,
+        name: String,
+        interval_ns: u64,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<Box<dyn Fn(TimeEvent)>>,
+    ) {
+        correctness::valid_string(&name, "`Timer` name");
+        assert!(
+            callback.is_some() | self.default_callback.is_some(),
+            "All callbacks were `None`"
+        );
+
+        if let Some(callback) = callback {
+            self.callbacks.insert(name.clone(), callback);
+        }
+
+        let timer = TestTimer::new(name.clone(), interval_ns, start_time_ns, stop_time_ns);
+        self.timers.insert(name, timer);
+    }
+
     fn set_time_alert_ns_py(
         &mut self,
         name: String,
         alert_time_ns: UnixNanos,
         callback_py: Option<PyObject>,
     ) {
-        correctness::valid_string(&name, "`Timer` name");
+        if let Err(e) = self.check_timer_py(&name, callback_py.is_some()) {
+            panic!("{e}");
+        }
         assert!(
-            callback_py.is_some() | self.default_callback_py.is_some(),
-            "All Python callbacks were `None`"
+            alert_time_ns >= self.time_ns,
+            "`alert_time_ns` was < the current time"
         );
 
         match callback_py {
@@ -289,11 +405,9 @@ impl Clock for TestClock {
         stop_time_ns: Option<UnixNanos>,
         callback_py: Option<PyObject>,
     ) {
-        correctness::valid_string(&name, "`Timer` name");
-        assert!(
-            callback_py.is_some() | self.default_callback_py.is_some(),
-            "All Python callbacks were `None`"
-        );
+        if let Err(e) = self.check_timer_py(&name, callback_py.is_some()) {
+            panic!("{e}");
+        }
 
         match callback_py {
             Some(callback_py) => self.callbacks_py.insert(name.clone(), callback_py),
@@ -331,20 +445,63 @@ impl Clock for TestClock {
 pub struct LiveClock {
     internal: MonotonicClock,
     timers: HashMap<String, TestTimer>,
+    live_timers: HashMap<String, LiveTimer>,
+    event_loop: Option<PyObject>,
     default_callback: Option<Box<dyn Fn(TimeEvent)>>,
     default_callback_py: Option<PyObject>,
-    _callbacks: HashMap<String, Box<dyn Fn(TimeEvent)>>, // TBC
+    callbacks: HashMap<String, Box<dyn Fn(TimeEvent)>>,
     callbacks_py: HashMap<String, PyObject>,
 }
 
+impl LiveClock {
+    /// Sets the Python asyncio event loop onto which Python timer events are delivered.
+    ///
+    /// Python timers set after this call run as [`LiveTimer`]s on the Tokio runtime.
+    pub fn set_event_loop_py(&mut self, event_loop: PyObject) {
+        self.event_loop = Some(event_loop);
+    }
+
+    /// Starts a [`LiveTimer`] for the Python callback registered for `name` (or the
+    /// default), returning `false` if no event loop has been set.
+    fn start_live_timer(
+        &mut self,
+        name: String,
+        interval_ns: u64,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+    ) -> bool {
+        let event_loop = match &self.event_loop {
+            Some(event_loop) => event_loop,
+            None => return false,
+        };
+        let callback_py = self
+            .callbacks_py
+            .get(&name)
+            .or(self.default_callback_py.as_ref())
+            .expect("All Python callbacks were `None`");
+
+        let (callback_py, event_loop) =
+            Python::with_gil(|py| (callback_py.clone_ref(py), event_loop.clone_ref(py)));
+        let mut timer = LiveTimer::new(name.clone(), interval_ns, start_time_ns, stop_time_ns);
+        timer.start(callback_py, event_loop);
+
+        // Any existing live timer with the same name is dropped, which cancels it
+        self.timers.remove(&name);
+        self.live_timers.insert(name, timer);
+        true
+    }
+}
+
 impl Clock for LiveClock {
     fn new() -> LiveClock {
         LiveClock {
             internal: MonotonicClock::default(),
             timers: HashMap::new(),
+            live_timers: HashMap::new(),
+            event_loop: None,
             default_callback: None,
             default_callback_py: None,
-            _callbacks: HashMap::new(),
+            callbacks: HashMap::new(),
             callbacks_py: HashMap::new(),
         }
     }
@@ -370,14 +527,17 @@ impl Clock for LiveClock {
             .iter()
             .filter(|(_, timer)| !timer.is_expired)
             .map(|(k, _)| k.as_str())
+            .chain(
+                self.live_timers
+                    .iter()
+                    .filter(|(_, timer)| !timer.is_expired())
+                    .map(|(k, _)| k.as_str()),
+            )
             .collect()
     }
 
     fn timer_count(&self) -> usize {
-        self.timers
-            .iter()
-            .filter(|(_, timer)| !timer.is_expired)
-            .count()
+        self.timer_names().len()
     }
 
     fn register_default_handler(&mut self, handler: Box<dyn Fn(TimeEvent)>) {
@@ -388,6 +548,55 @@ impl Clock for LiveClock {
         self.default_callback_py = Some(callback_py)
     }
 
+    fn set_time_alert_ns(
+        &mut self,
+        name: String,
+        mut alert_time_ns: UnixNanos,
+        callback: Option<Box<dyn Fn(TimeEvent)>>,
+    ) {
+        correctness::valid_string(&name, "`Timer` name");
+        assert!(
+            callback.is_some() | self.default_callback.is_some(),
+            "All callbacks were `None`"
+        );
+
+        if let Some(callback) = callback {
+            self.callbacks.insert(name.clone(), callback);
+        }
+
+        let ts_now = self.timestamp_ns();
+        alert_time_ns = std::cmp::max(alert_time_ns, ts_now);
+        let timer = TestTimer::new(
+            name.clone(),
+            alert_time_ns - ts_now,
+            ts_now,
+            Some(alert_time_ns),
+        );
+        self.timers.insert(name, timer);
+    }
+
+    fn set_timer_ns(
+        &mut self,
+        name: String,
+        interval_ns: u64,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<Box<dyn Fn(TimeEvent)>>,
+    ) {
+        correctness::valid_string(&name, "`Timer` name");
+        assert!(
+            callback.is_some() | self.default_callback.is_some(),
+            "All callbacks were `None`"
+        );
+
+        if let Some(callback) = callback {
+            self.callbacks.insert(name.clone(), callback);
+        }
+
+        let timer = TestTimer::new(name.clone(), interval_ns, start_time_ns, stop_time_ns);
+        self.timers.insert(name, timer);
+    }
+
     fn set_time_alert_ns_py(
         &mut self,
         name: String,
@@ -407,6 +616,15 @@ impl Clock for LiveClock {
 
         let ts_now = self.timestamp_ns();
         alert_time_ns = std::cmp::max(alert_time_ns, ts_now);
+        if self.start_live_timer(
+            name.clone(),
+            alert_time_ns - ts_now,
+            ts_now,
+            Some(alert_time_ns),
+        ) {
+            return;
+        }
+
         let timer = TestTimer::new(
             name.clone(),
             alert_time_ns - ts_now,
@@ -435,11 +653,18 @@ impl Clock for LiveClock {
             None => None,
         };
 
+        if self.start_live_timer(name.clone(), interval_ns, start_time_ns, stop_time_ns) {
+            return;
+        }
+
         let timer = TestTimer::new(name.clone(), interval_ns, start_time_ns, stop_time_ns);
         self.timers.insert(name, timer);
     }
 
     fn next_time_ns(&mut self, name: &str) -> UnixNanos {
+        if let Some(timer) = self.live_timers.get(name) {
+            return timer.next_time_ns();
+        }
         let timer = self.timers.get(name);
         match timer {
             None => 0,
@@ -448,6 +673,9 @@ impl Clock for LiveClock {
     }
 
     fn cancel_timer(&mut self, name: &str) {
+        if let Some(mut timer) = self.live_timers.remove(name) {
+            timer.cancel();
+        }
         let timer = self.timers.remove(name);
         match timer {
             None => {}
@@ -456,6 +684,10 @@ impl Clock for LiveClock {
     }
 
     fn cancel_timers(&mut self) {
+        for (_, timer) in self.live_timers.iter_mut() {
+            timer.cancel()
+        }
+        self.live_timers = HashMap::new();
         for (_, timer) in self.timers.iter_mut() {
             timer.cancel()
         }
@@ -468,6 +700,8 @@ impl Clock for LiveClock {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
     use pyo3::types::PyList;
 
     use super::*;
@@ -610,4 +844,110 @@ mod tests {
             assert_eq!(clock.time_ns, 0);
         });
     }
+
+    #[test]
+    fn test_set_time_alert_ns() {
+        let mut clock = TestClock::new();
+        clock.register_default_handler(Box::new(|_| {}));
+
+        clock.set_time_alert_ns(String::from("TEST_ALERT"), 10, None);
+
+        assert_eq!(clock.timer_names(), ["TEST_ALERT"]);
+        assert_eq!(clock.next_time_ns("TEST_ALERT"), 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_time_alert_ns_in_past() {
+        let mut clock = TestClock::new();
+        clock.register_default_handler(Box::new(|_| {}));
+        clock.set_time(10);
+
+        clock.set_time_alert_ns(String::from("TEST_ALERT"), 5, None);
+    }
+
+    #[test]
+    fn test_time_alert_at_current_time_fires_once() {
+        let mut clock = TestClock::new();
+        clock.register_default_handler(Box::new(|_| {}));
+        clock.set_time(10);
+
+        clock.set_time_alert_ns(String::from("TEST_ALERT"), 10, None);
+        let events = clock.advance_time(20, true);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].ts_event, 10);
+        assert_eq!(clock.timer_count(), 0);
+    }
+
+    #[test]
+    fn test_advance_time_removes_expired_timers() {
+        let mut clock = TestClock::new();
+        clock.register_default_handler(Box::new(|_| {}));
+
+        clock.set_time_alert_ns(String::from("TEST_ALERT"), 5, None);
+        let events = clock.advance_time(5, true);
+
+        assert_eq!(events.len(), 1);
+        assert!(clock.timer_names().is_empty());
+        assert!(clock.get_timers().is_empty());
+    }
+
+    #[test]
+    fn test_advance_time_events_sorted_by_time_then_name() {
+        let mut clock = TestClock::new();
+        clock.register_default_handler(Box::new(|_| {}));
+
+        clock.set_timer_ns(String::from("TIMER_B"), 2, 0, None, None);
+        clock.set_timer_ns(String::from("TIMER_A"), 2, 0, None, None);
+        clock.set_timer_ns(String::from("TIMER_C"), 3, 0, None, None);
+        let events = clock.advance_time(6, true);
+
+        let fired: Vec<(UnixNanos, &str)> = events
+            .iter()
+            .map(|event| (event.ts_event, event.name.as_str()))
+            .collect();
+        assert_eq!(
+            fired,
+            [
+                (2, "TIMER_A"),
+                (2, "TIMER_B"),
+                (3, "TIMER_C"),
+                (4, "TIMER_A"),
+                (4, "TIMER_B"),
+                (6, "TIMER_A"),
+                (6, "TIMER_B"),
+                (6, "TIMER_C"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_handle_events_calls_named_then_default_callbacks() {
+        let named = Rc::new(RefCell::new(Vec::new()));
+        let default = Rc::new(RefCell::new(Vec::new()));
+        let mut clock = TestClock::new();
+
+        let default_clone = default.clone();
+        clock.register_default_handler(Box::new(move |event: TimeEvent| {
+            default_clone.borrow_mut().push(event.ts_event)
+        }));
+        let named_clone = named.clone();
+        clock.set_timer_ns(
+            String::from("TIMER_A"),
+            1,
+            0,
+            None,
+            Some(Box::new(move |event: TimeEvent| {
+                named_clone.borrow_mut().push(event.ts_event)
+            })),
+        );
+        clock.set_time_alert_ns(String::from("TEST_ALERT"), 2, None);
+
+        let events = clock.advance_time(3, true);
+        clock.handle_events(events);
+
+        assert_eq!(*named.borrow(), [1, 2, 3]);
+        assert_eq!(*default.borrow(), [2]);
+    }
 }
@@ -18,7 +18,11 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use nautilus_core::{cvec::CVec, string::cstr_to_string, time::UnixNanos};
+use nautilus_core::{
+    cvec::CVec,
+    ffi_error::{set_last_error, try_cstr_to_str, write_result, FFI_ERROR, FFI_OK},
+    time::UnixNanos,
+};
 use pyo3::{
     ffi,
     prelude::*,
@@ -67,18 +71,24 @@ pub extern "C" fn test_clock_drop(clock: TestClock_API) {
     drop(clock); // Memory freed here
 }
 
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `callback_ptr` is NULL or `None`.
+///
 /// # Safety
 /// - Assumes `callback_ptr` is a valid PyCallable pointer.
 #[no_mangle]
 pub unsafe extern "C" fn test_clock_register_default_handler(
     clock: &mut TestClock_API,
     callback_ptr: *mut ffi::PyObject,
-) {
-    assert!(!callback_ptr.is_null());
-    assert!(ffi::Py_None() != callback_ptr);
+) -> u8 {
+    if callback_ptr.is_null() || ffi::Py_None() == callback_ptr {
+        set_last_error("invalid default handler, `callback_ptr` was NULL or `None`");
+        return FFI_ERROR;
+    }
 
     let callback_py = Python::with_gil(|py| PyObject::from_borrowed_ptr(py, callback_ptr));
     clock.register_default_handler_py(callback_py);
+    FFI_OK
 }
 
 #[no_mangle]
@@ -124,6 +134,10 @@ pub extern "C" fn test_clock_timer_count(clock: &mut TestClock_API) -> usize {
     clock.timer_count()
 }
 
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `name_ptr` is not a valid timer name, `callback_ptr` is NULL, `callback_ptr` is `None`
+/// with no default handler registered, or `alert_time_ns` is before the current time.
+///
 /// # Safety
 ///
 /// - Assumes `name_ptr` is a valid C string pointer.
@@ -134,17 +148,29 @@ pub unsafe extern "C" fn test_clock_set_time_alert_ns(
     name_ptr: *const c_char,
     alert_time_ns: UnixNanos,
     callback_ptr: *mut ffi::PyObject,
-) {
-    assert!(!callback_ptr.is_null());
-
-    let name = cstr_to_string(name_ptr);
-    let callback_py = Python::with_gil(|py| match callback_ptr {
-        ptr if ptr != ffi::Py_None() => Some(PyObject::from_borrowed_ptr(py, ptr)),
-        _ => None,
+) -> u8 {
+    let result = check_timer_args(clock, name_ptr, callback_ptr).and_then(|args| {
+        if alert_time_ns < clock.timestamp_ns() {
+            return Err("`alert_time_ns` was < the current time".to_string());
+        }
+        Ok(args)
     });
-    clock.set_time_alert_ns_py(name, alert_time_ns, callback_py);
+    match result {
+        Ok((name, callback_py)) => {
+            clock.set_time_alert_ns_py(name, alert_time_ns, callback_py);
+            FFI_OK
+        }
+        Err(e) => {
+            set_last_error(e);
+            FFI_ERROR
+        }
+    }
 }
 
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `name_ptr` is not a valid timer name, `callback_ptr` is NULL, or `callback_ptr` is
+/// `None` with no default handler registered.
+///
 /// # Safety
 ///
 /// - Assumes `name_ptr` is a valid C string pointer.
@@ -157,19 +183,38 @@ pub unsafe extern "C" fn test_clock_set_timer_ns(
     start_time_ns: UnixNanos,
     stop_time_ns: UnixNanos,
     callback_ptr: *mut ffi::PyObject,
-) {
-    assert!(!callback_ptr.is_null());
-
-    let name = cstr_to_string(name_ptr);
+) -> u8 {
     let stop_time_ns = match stop_time_ns {
         0 => None,
         _ => Some(stop_time_ns),
     };
+    match check_timer_args(clock, name_ptr, callback_ptr) {
+        Ok((name, callback_py)) => {
+            clock.set_timer_ns_py(name, interval_ns, start_time_ns, stop_time_ns, callback_py);
+            FFI_OK
+        }
+        Err(e) => {
+            set_last_error(e);
+            FFI_ERROR
+        }
+    }
+}
+
+unsafe fn check_timer_args(
+    clock: &TestClock,
+    name_ptr: *const c_char,
+    callback_ptr: *mut ffi::PyObject,
+) -> Result<(String, Option<PyObject>), String> {
+    if callback_ptr.is_null() {
+        return Err("invalid timer callback, `callback_ptr` was NULL".to_string());
+    }
+    let name = try_cstr_to_str(name_ptr, "timer name")?;
     let callback_py = Python::with_gil(|py| match callback_ptr {
         ptr if ptr != ffi::Py_None() => Some(PyObject::from_borrowed_ptr(py, ptr)),
         _ => None,
     });
-    clock.set_timer_ns_py(name, interval_ns, start_time_ns, stop_time_ns, callback_py);
+    clock.check_timer_py(name, callback_py.is_some())?;
+    Ok((name.to_string(), callback_py))
 }
 
 /// # Safety
@@ -190,24 +235,34 @@ pub unsafe extern "C" fn test_clock_advance_time(
 #[allow(clippy::drop_non_drop)]
 #[no_mangle]
 pub extern "C" fn vec_time_event_handlers_drop(v: CVec) {
-    let CVec { ptr, len, cap } = v;
+    let CVec { ptr, len, cap, .. } = v;
     let data: Vec<TimeEventHandler> =
         unsafe { Vec::from_raw_parts(ptr as *mut TimeEventHandler, len, cap) };
     drop(data); // Memory freed here
 }
 
+/// Writes the next time of the timer named `name_ptr` to `out` (zero if no such timer).
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `name_ptr` is not a valid C string (in which case nothing is written to `out`).
+///
 /// # Safety
 ///
 /// - Assumes `name_ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`UnixNanos`].
 #[no_mangle]
 pub unsafe extern "C" fn test_clock_next_time_ns(
     clock: &mut TestClock_API,
     name_ptr: *const c_char,
-) -> UnixNanos {
-    let name = cstr_to_string(name_ptr);
-    clock.next_time_ns(&name)
+    out: *mut UnixNanos,
+) -> u8 {
+    let result = try_cstr_to_str(name_ptr, "timer name").map(|name| clock.next_time_ns(name));
+    write_result(result, out, |_| FFI_ERROR)
 }
 
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `name_ptr` is not a valid C string.
+///
 /// # Safety
 ///
 /// - Assumes `name_ptr` is a valid C string pointer.
@@ -215,9 +270,17 @@ pub unsafe extern "C" fn test_clock_next_time_ns(
 pub unsafe extern "C" fn test_clock_cancel_timer(
     clock: &mut TestClock_API,
     name_ptr: *const c_char,
-) {
-    let name = cstr_to_string(name_ptr);
-    clock.cancel_timer(&name);
+) -> u8 {
+    match try_cstr_to_str(name_ptr, "timer name") {
+        Ok(name) => {
+            clock.cancel_timer(name);
+            FFI_OK
+        }
+        Err(e) => {
+            set_last_error(e);
+            FFI_ERROR
+        }
+    }
 }
 
 #[no_mangle]
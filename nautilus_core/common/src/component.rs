@@ -0,0 +1,238 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use thiserror::Error;
+
+use crate::enums::{ComponentState, ComponentTrigger};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ComponentError {
+    #[error("Invalid state transition from {state} on {trigger}")]
+    InvalidStateTransition {
+        state: ComponentState,
+        trigger: ComponentTrigger,
+    },
+}
+
+impl ComponentError {
+    /// Returns the error code for passing across the FFI boundary (0 is reserved for success).
+    #[must_use]
+    pub fn code(&self) -> u8 {
+        match self {
+            ComponentError::InvalidStateTransition { .. } => 1,
+        }
+    }
+}
+
+impl ComponentState {
+    #[rustfmt::skip]
+    pub fn transition(&self, trigger: &ComponentTrigger) -> Result<ComponentState, ComponentError> {
+        let new_state = match (self, trigger) {
+            (ComponentState::PreInitialized, ComponentTrigger::Initialize) => ComponentState::Ready,
+            (ComponentState::Ready, ComponentTrigger::Reset) => ComponentState::Resetting,  // Transitional state
+            (ComponentState::Ready, ComponentTrigger::Start) => ComponentState::Starting,  // Transitional state
+            (ComponentState::Ready, ComponentTrigger::Dispose) => ComponentState::Disposing,  // Transitional state
+            (ComponentState::Resetting, ComponentTrigger::ResetCompleted) => ComponentState::Ready,
+            (ComponentState::Starting, ComponentTrigger::StartCompleted) => ComponentState::Running,
+            (ComponentState::Starting, ComponentTrigger::Stop) => ComponentState::Stopping,  // Transitional state
+            (ComponentState::Starting, ComponentTrigger::Fault) => ComponentState::Faulting,  // Transitional state
+            (ComponentState::Running, ComponentTrigger::Stop) => ComponentState::Stopping,  // Transitional state
+            (ComponentState::Running, ComponentTrigger::Degrade) => ComponentState::Degrading,  // Transitional state
+            (ComponentState::Running, ComponentTrigger::Fault) => ComponentState::Faulting,  // Transitional state
+            (ComponentState::Resuming, ComponentTrigger::Stop) => ComponentState::Stopping,  // Transitional state
+            (ComponentState::Resuming, ComponentTrigger::ResumeCompleted) => ComponentState::Running,
+            (ComponentState::Resuming, ComponentTrigger::Fault) => ComponentState::Faulting,  // Transitional state
+            (ComponentState::Stopping, ComponentTrigger::StopCompleted) => ComponentState::Stopped,
+            (ComponentState::Stopping, ComponentTrigger::Fault) => ComponentState::Faulting,  // Transitional state
+            (ComponentState::Stopped, ComponentTrigger::Reset) => ComponentState::Resetting,  // Transitional state
+            (ComponentState::Stopped, ComponentTrigger::Resume) => ComponentState::Resuming,  // Transitional state
+            (ComponentState::Stopped, ComponentTrigger::Dispose) => ComponentState::Disposing,  // Transitional state
+            (ComponentState::Stopped, ComponentTrigger::Fault) => ComponentState::Faulting,  // Transitional state
+            (ComponentState::Degrading, ComponentTrigger::DegradeCompleted) => ComponentState::Degraded,
+            (ComponentState::Degraded, ComponentTrigger::Resume) => ComponentState::Resuming,  // Transitional state
+            (ComponentState::Degraded, ComponentTrigger::Stop) => ComponentState::Stopping,  // Transitional state
+            (ComponentState::Degraded, ComponentTrigger::Fault) => ComponentState::Faulting,  // Transitional state
+            (ComponentState::Disposing, ComponentTrigger::DisposeCompleted) => ComponentState::Disposed,  // Terminal state
+            (ComponentState::Faulting, ComponentTrigger::FaultCompleted) => ComponentState::Faulted,  // Terminal state
+            _ => {
+                return Err(ComponentError::InvalidStateTransition {
+                    state: *self,
+                    trigger: *trigger,
+                })
+            }
+        };
+        Ok(new_state)
+    }
+}
+
+/// Provides the lifecycle state machine shared by all components of the system.
+///
+/// A component starts in [`ComponentState::PreInitialized`], and only moves to a new state on
+/// a [`ComponentTrigger`] which is valid for its current state.
+#[derive(Clone, Debug)]
+pub struct ComponentFsm {
+    state: ComponentState,
+    previous_state: Option<ComponentState>,
+}
+
+impl ComponentFsm {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            state: ComponentState::PreInitialized,
+            previous_state: None,
+        }
+    }
+
+    #[must_use]
+    pub fn state(&self) -> ComponentState {
+        self.state
+    }
+
+    #[must_use]
+    pub fn previous_state(&self) -> Option<ComponentState> {
+        self.previous_state
+    }
+
+    /// Applies the `trigger`, returning the new state.
+    ///
+    /// The state is left unchanged if the transition is invalid.
+    pub fn trigger(&mut self, trigger: ComponentTrigger) -> Result<ComponentState, ComponentError> {
+        let new_state = self.state.transition(&trigger)?;
+        self.previous_state = Some(self.state);
+        self.state = new_state;
+        Ok(new_state)
+    }
+
+    #[must_use]
+    pub fn is_initialized(&self) -> bool {
+        self.state != ComponentState::PreInitialized
+    }
+
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.state == ComponentState::Running
+    }
+
+    #[must_use]
+    pub fn is_stopped(&self) -> bool {
+        self.state == ComponentState::Stopped
+    }
+
+    #[must_use]
+    pub fn is_disposed(&self) -> bool {
+        self.state == ComponentState::Disposed
+    }
+
+    #[must_use]
+    pub fn is_degraded(&self) -> bool {
+        self.state == ComponentState::Degraded
+    }
+
+    #[must_use]
+    pub fn is_faulted(&self) -> bool {
+        self.state == ComponentState::Faulted
+    }
+}
+
+impl Default for ComponentFsm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_new_fsm_is_pre_initialized() {
+        let fsm = ComponentFsm::new();
+        assert_eq!(fsm.state(), ComponentState::PreInitialized);
+        assert_eq!(fsm.previous_state(), None);
+        assert!(!fsm.is_initialized());
+    }
+
+    #[rstest]
+    fn test_full_lifecycle() {
+        let mut fsm = ComponentFsm::new();
+        for trigger in [
+            ComponentTrigger::Initialize,
+            ComponentTrigger::Start,
+            ComponentTrigger::StartCompleted,
+        ] {
+            fsm.trigger(trigger).unwrap();
+        }
+        assert!(fsm.is_running());
+
+        fsm.trigger(ComponentTrigger::Stop).unwrap();
+        fsm.trigger(ComponentTrigger::StopCompleted).unwrap();
+        assert!(fsm.is_stopped());
+        assert_eq!(fsm.previous_state(), Some(ComponentState::Stopping));
+
+        fsm.trigger(ComponentTrigger::Dispose).unwrap();
+        fsm.trigger(ComponentTrigger::DisposeCompleted).unwrap();
+        assert!(fsm.is_disposed());
+    }
+
+    #[rstest]
+    fn test_invalid_transition_leaves_state_unchanged() {
+        let mut fsm = ComponentFsm::new();
+        let result = fsm.trigger(ComponentTrigger::Start);
+
+        assert_eq!(
+            result,
+            Err(ComponentError::InvalidStateTransition {
+                state: ComponentState::PreInitialized,
+                trigger: ComponentTrigger::Start,
+            })
+        );
+        assert_eq!(fsm.state(), ComponentState::PreInitialized);
+    }
+
+    #[rstest]
+    #[case(ComponentState::Disposed)]
+    #[case(ComponentState::Faulted)]
+    fn test_terminal_states_reject_all_triggers(#[case] state: ComponentState) {
+        for value in 1..=15 {
+            let trigger = ComponentTrigger::from_repr(value).unwrap();
+            assert!(state.transition(&trigger).is_err());
+        }
+    }
+
+    #[rstest]
+    fn test_resume_from_degraded() {
+        let mut fsm = ComponentFsm::new();
+        for trigger in [
+            ComponentTrigger::Initialize,
+            ComponentTrigger::Start,
+            ComponentTrigger::StartCompleted,
+            ComponentTrigger::Degrade,
+            ComponentTrigger::DegradeCompleted,
+        ] {
+            fsm.trigger(trigger).unwrap();
+        }
+        assert!(fsm.is_degraded());
+
+        fsm.trigger(ComponentTrigger::Resume).unwrap();
+        fsm.trigger(ComponentTrigger::ResumeCompleted).unwrap();
+        assert!(fsm.is_running());
+    }
+}
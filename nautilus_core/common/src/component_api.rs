@@ -0,0 +1,111 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use crate::{
+    component::{ComponentError, ComponentFsm},
+    enums::{ComponentState, ComponentTrigger},
+};
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`ComponentFsm`].
+///
+/// This struct wraps `ComponentFsm` in a way that makes it compatible with C function
+/// calls, enabling interaction with `ComponentFsm` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `ComponentFsm_API` to
+/// be dereferenced to `ComponentFsm`, providing access to `ComponentFsm`'s methods without
+/// having to manually access the underlying `ComponentFsm` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct ComponentFsm_API(Box<ComponentFsm>);
+
+impl Deref for ComponentFsm_API {
+    type Target = ComponentFsm;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ComponentFsm_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_new() -> ComponentFsm_API {
+    ComponentFsm_API(Box::new(ComponentFsm::new()))
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_drop(fsm: ComponentFsm_API) {
+    drop(fsm); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_state(fsm: &ComponentFsm_API) -> ComponentState {
+    fsm.state()
+}
+
+/// Applies the `trigger` to the `fsm`, returning 0 on success or the [`ComponentError`] code.
+#[no_mangle]
+pub extern "C" fn component_fsm_trigger(
+    fsm: &mut ComponentFsm_API,
+    trigger: ComponentTrigger,
+) -> u8 {
+    fsm.trigger(trigger)
+        .map_or_else(|e: ComponentError| e.code(), |_| 0)
+}
+
+/// Returns 1 if `trigger` is valid from `state`, otherwise 0.
+#[no_mangle]
+pub extern "C" fn component_state_can_transition(
+    state: ComponentState,
+    trigger: ComponentTrigger,
+) -> u8 {
+    u8::from(state.transition(&trigger).is_ok())
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_is_initialized(fsm: &ComponentFsm_API) -> u8 {
+    u8::from(fsm.is_initialized())
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_is_running(fsm: &ComponentFsm_API) -> u8 {
+    u8::from(fsm.is_running())
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_is_stopped(fsm: &ComponentFsm_API) -> u8 {
+    u8::from(fsm.is_stopped())
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_is_disposed(fsm: &ComponentFsm_API) -> u8 {
+    u8::from(fsm.is_disposed())
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_is_degraded(fsm: &ComponentFsm_API) -> u8 {
+    u8::from(fsm.is_degraded())
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_is_faulted(fsm: &ComponentFsm_API) -> u8 {
+    u8::from(fsm.is_faulted())
+}
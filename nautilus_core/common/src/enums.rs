@@ -13,9 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{ffi::c_char, fmt::Debug, str::FromStr};
+use std::{ffi::c_char, fmt::Debug};
 
-use nautilus_core::string::{cstr_to_string, str_to_cstr};
+use nautilus_core::{ffi_error::write_parsed_cstr, string::str_to_cstr};
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, FromRepr};
 
@@ -93,6 +93,18 @@ pub enum ComponentTrigger {
     FaultCompleted = 15,
 }
 
+/// The health of a data or execution client, as determined by the time since its last message.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum HealthStatus {
+    /// The client has received a message within its stale threshold.
+    Healthy = 1,
+    /// The client has not received a message within its stale threshold.
+    Degraded = 2,
+}
+
 /// The log level for log messages.
 #[repr(C)]
 #[derive(
@@ -209,15 +221,20 @@ pub extern "C" fn component_state_to_cstr(value: ComponentState) -> *const c_cha
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`ComponentState`].
 #[no_mangle]
-pub unsafe extern "C" fn component_state_from_cstr(ptr: *const c_char) -> ComponentState {
-    let value = cstr_to_string(ptr);
-    ComponentState::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `ComponentState` enum string value, was '{value}'"))
+pub unsafe extern "C" fn component_state_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut ComponentState,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`ComponentState` enum")
 }
 
 #[no_mangle]
@@ -225,15 +242,41 @@ pub extern "C" fn component_trigger_to_cstr(value: ComponentTrigger) -> *const c
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`ComponentTrigger`].
 #[no_mangle]
-pub unsafe extern "C" fn component_trigger_from_cstr(ptr: *const c_char) -> ComponentTrigger {
-    let value = cstr_to_string(ptr);
-    ComponentTrigger::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `ComponentTrigger` enum string value, was '{value}'"))
+pub unsafe extern "C" fn component_trigger_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut ComponentTrigger,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`ComponentTrigger` enum")
+}
+
+#[no_mangle]
+pub extern "C" fn health_status_to_cstr(value: HealthStatus) -> *const c_char {
+    str_to_cstr(&value.to_string())
+}
+
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`HealthStatus`].
+#[no_mangle]
+pub unsafe extern "C" fn health_status_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut HealthStatus,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`HealthStatus` enum")
 }
 
 #[no_mangle]
@@ -241,15 +284,17 @@ pub extern "C" fn log_level_to_cstr(value: LogLevel) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`LogLevel`].
 #[no_mangle]
-pub unsafe extern "C" fn log_level_from_cstr(ptr: *const c_char) -> LogLevel {
-    let value = cstr_to_string(ptr);
-    LogLevel::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `LogLevel` enum string value, was '{value}'"))
+pub unsafe extern "C" fn log_level_try_from_cstr(ptr: *const c_char, out: *mut LogLevel) -> u8 {
+    write_parsed_cstr(ptr, out, "`LogLevel` enum")
 }
 
 #[no_mangle]
@@ -257,13 +302,15 @@ pub extern "C" fn log_color_to_cstr(value: LogColor) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`LogColor`].
 #[no_mangle]
-pub unsafe extern "C" fn log_color_from_cstr(ptr: *const c_char) -> LogColor {
-    let value = cstr_to_string(ptr);
-    LogColor::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `LogColor` enum string value, was '{value}'"))
+pub unsafe extern "C" fn log_color_try_from_cstr(ptr: *const c_char, out: *mut LogColor) -> u8 {
+    write_parsed_cstr(ptr, out, "`LogColor` enum")
 }
@@ -0,0 +1,282 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    enums::TimeInForce, identifiers::client_order_id::ClientOrderId, orders::Order,
+};
+
+use crate::{clock::Clock, timer::TimeEvent};
+
+const GTD_EXPIRY_PREFIX: &str = "GTD-EXPIRY:";
+
+/// Provides expiry timer management for GTD (good till date) orders, for execution
+/// clients whose venues do not expire orders themselves.
+///
+/// Registering an order sets a time alert named [`GtdExpiryTimers::timer_name`] on the clock,
+/// using the default handler of the clock, which must route the event back to
+/// [`GtdExpiryTimers::on_time_event`]. The caller then cancels (or expires) the returned
+/// order at the venue.
+#[derive(Debug, Default)]
+pub struct GtdExpiryTimers {
+    expirations: HashMap<ClientOrderId, UnixNanos>,
+}
+
+impl GtdExpiryTimers {
+    /// Initializes a new `GtdExpiryTimers` instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the name of the expiry timer for the given `client_order_id`.
+    #[must_use]
+    pub fn timer_name(client_order_id: &ClientOrderId) -> String {
+        format!("{GTD_EXPIRY_PREFIX}{client_order_id}")
+    }
+
+    /// Returns the count of pending expirations.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.expirations.len()
+    }
+
+    #[must_use]
+    pub fn is_pending(&self, client_order_id: &ClientOrderId) -> bool {
+        self.expirations.contains_key(client_order_id)
+    }
+
+    #[must_use]
+    pub fn expire_time_ns(&self, client_order_id: &ClientOrderId) -> Option<UnixNanos> {
+        self.expirations.get(client_order_id).copied()
+    }
+
+    /// Returns the earliest pending expire time, if any.
+    #[must_use]
+    pub fn next_expire_time_ns(&self) -> Option<UnixNanos> {
+        self.expirations.values().min().copied()
+    }
+
+    /// Returns the pending expirations sorted by expire time, then client order ID.
+    #[must_use]
+    pub fn pending_expirations(&self) -> Vec<(ClientOrderId, UnixNanos)> {
+        let mut pending: Vec<(ClientOrderId, UnixNanos)> = self
+            .expirations
+            .iter()
+            .map(|(client_order_id, &expire_time)| (client_order_id.clone(), expire_time))
+            .collect();
+        pending.sort_by(|a, b| {
+            a.1.cmp(&b.1)
+                .then_with(|| a.0.value.as_str().cmp(b.0.value.as_str()))
+        });
+        pending
+    }
+
+    /// Registers an expiry timer for the given `order`, if it is a GTD order with an
+    /// expire time.
+    ///
+    /// Returns `true` if a timer was registered.
+    pub fn register_order<C: Clock>(&mut self, order: &Order, clock: &mut C) -> bool {
+        match (order.time_in_force, order.expire_time) {
+            (TimeInForce::Gtd, Some(expire_time)) => {
+                self.register(order.client_order_id.clone(), expire_time, clock);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Registers an expiry timer for `client_order_id` at `expire_time_ns`, replacing any
+    /// existing timer for the order.
+    ///
+    /// An expire time already in the past alerts at the current time.
+    pub fn register<C: Clock>(
+        &mut self,
+        client_order_id: ClientOrderId,
+        expire_time_ns: UnixNanos,
+        clock: &mut C,
+    ) {
+        let timer_name = Self::timer_name(&client_order_id);
+        if self.expirations.contains_key(&client_order_id) {
+            clock.cancel_timer(&timer_name);
+        }
+        let alert_time_ns = expire_time_ns.max(clock.timestamp_ns());
+        clock.set_time_alert_ns(timer_name, alert_time_ns, None);
+        self.expirations.insert(client_order_id, expire_time_ns);
+    }
+
+    /// Cancels the expiry timer for `client_order_id`, as when the order closes before
+    /// its expire time.
+    ///
+    /// Returns `false` if no expiration was pending for the order.
+    pub fn cancel<C: Clock>(&mut self, client_order_id: &ClientOrderId, clock: &mut C) -> bool {
+        if self.expirations.remove(client_order_id).is_none() {
+            return false;
+        }
+        clock.cancel_timer(&Self::timer_name(client_order_id));
+        true
+    }
+
+    /// Handles the time `event`, returning the client order ID of the expired order if the
+    /// event is from one of the pending expiry timers.
+    pub fn on_time_event(&mut self, event: &TimeEvent) -> Option<ClientOrderId> {
+        let value = event.name.as_str().strip_prefix(GTD_EXPIRY_PREFIX)?;
+        let client_order_id = self
+            .expirations
+            .keys()
+            .find(|client_order_id| client_order_id.value.as_str() == value)?
+            .clone();
+        self.expirations.remove(&client_order_id);
+        Some(client_order_id)
+    }
+
+    /// Cancels all pending expiry timers.
+    pub fn reset<C: Clock>(&mut self, clock: &mut C) {
+        for client_order_id in self.expirations.keys() {
+            clock.cancel_timer(&Self::timer_name(client_order_id));
+        }
+        self.expirations.clear();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::order::OrderInitializedBuilder;
+
+    use super::*;
+    use crate::clock::TestClock;
+
+    fn test_clock() -> TestClock {
+        let mut clock = TestClock::new();
+        clock.register_default_handler(Box::new(|_| {}));
+        clock
+    }
+
+    fn advance(
+        timers: &mut GtdExpiryTimers,
+        clock: &mut TestClock,
+        to_time_ns: UnixNanos,
+    ) -> Vec<ClientOrderId> {
+        clock
+            .advance_time(to_time_ns, true)
+            .iter()
+            .filter_map(|event| timers.on_time_event(event))
+            .collect()
+    }
+
+    #[test]
+    fn test_register_order_ignores_non_gtd_orders() {
+        let mut clock = test_clock();
+        let mut timers = GtdExpiryTimers::new();
+        let order: Order = OrderInitializedBuilder::default().build().unwrap().into();
+
+        assert!(!timers.register_order(&order, &mut clock));
+        assert_eq!(timers.count(), 0);
+        assert_eq!(clock.timer_count(), 0);
+    }
+
+    #[test]
+    fn test_register_order_sets_timer() {
+        let mut clock = test_clock();
+        let mut timers = GtdExpiryTimers::new();
+        let order: Order = OrderInitializedBuilder::default()
+            .time_in_force(TimeInForce::Gtd)
+            .expire_time(Some(1_000))
+            .build()
+            .unwrap()
+            .into();
+
+        assert!(timers.register_order(&order, &mut clock));
+        assert!(timers.is_pending(&order.client_order_id));
+        assert_eq!(timers.next_expire_time_ns(), Some(1_000));
+        assert_eq!(
+            clock.timer_names(),
+            vec![GtdExpiryTimers::timer_name(&order.client_order_id)]
+        );
+    }
+
+    #[test]
+    fn test_expirations_fire_in_order() {
+        let mut clock = test_clock();
+        let mut timers = GtdExpiryTimers::new();
+        timers.register(ClientOrderId::new("O-2"), 2_000, &mut clock);
+        timers.register(ClientOrderId::new("O-1"), 1_000, &mut clock);
+        assert_eq!(
+            timers.pending_expirations(),
+            vec![
+                (ClientOrderId::new("O-1"), 1_000),
+                (ClientOrderId::new("O-2"), 2_000),
+            ]
+        );
+
+        assert_eq!(
+            advance(&mut timers, &mut clock, 1_500),
+            vec![ClientOrderId::new("O-1")]
+        );
+        assert_eq!(
+            advance(&mut timers, &mut clock, 2_000),
+            vec![ClientOrderId::new("O-2")]
+        );
+        assert_eq!(timers.count(), 0);
+    }
+
+    #[test]
+    fn test_register_replaces_existing_timer() {
+        let mut clock = test_clock();
+        let mut timers = GtdExpiryTimers::new();
+        timers.register(ClientOrderId::new("O-1"), 1_000, &mut clock);
+        timers.register(ClientOrderId::new("O-1"), 3_000, &mut clock);
+
+        assert!(advance(&mut timers, &mut clock, 2_000).is_empty());
+        assert_eq!(
+            advance(&mut timers, &mut clock, 3_000),
+            vec![ClientOrderId::new("O-1")]
+        );
+    }
+
+    #[test]
+    fn test_cancel_removes_timer() {
+        let mut clock = test_clock();
+        let mut timers = GtdExpiryTimers::new();
+        timers.register(ClientOrderId::new("O-1"), 1_000, &mut clock);
+
+        assert!(timers.cancel(&ClientOrderId::new("O-1"), &mut clock));
+        assert!(!timers.cancel(&ClientOrderId::new("O-1"), &mut clock));
+        assert_eq!(clock.timer_count(), 0);
+        assert!(advance(&mut timers, &mut clock, 2_000).is_empty());
+    }
+
+    #[test]
+    fn test_past_expire_time_alerts_at_current_time() {
+        let mut clock = test_clock();
+        clock.set_time(5_000);
+        let mut timers = GtdExpiryTimers::new();
+        timers.register(ClientOrderId::new("O-1"), 1_000, &mut clock);
+
+        assert_eq!(
+            timers.expire_time_ns(&ClientOrderId::new("O-1")),
+            Some(1_000)
+        );
+        assert_eq!(
+            advance(&mut timers, &mut clock, 5_000),
+            vec![ClientOrderId::new("O-1")]
+        );
+    }
+}
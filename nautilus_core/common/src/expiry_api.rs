@@ -0,0 +1,161 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::{cvec::CVec, time::UnixNanos};
+use nautilus_model::identifiers::client_order_id::ClientOrderId;
+
+use crate::{clock_api::LiveClock_API, expiry::GtdExpiryTimers, timer::TimeEvent};
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`GtdExpiryTimers`].
+///
+/// This struct wraps `GtdExpiryTimers` in a way that makes it compatible with C function
+/// calls, enabling interaction with `GtdExpiryTimers` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of
+/// `GtdExpiryTimers_API` to be dereferenced to `GtdExpiryTimers`, providing access to
+/// `GtdExpiryTimers`'s methods without having to manually access the underlying instance.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct GtdExpiryTimers_API(Box<GtdExpiryTimers>);
+
+impl Deref for GtdExpiryTimers_API {
+    type Target = GtdExpiryTimers;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for GtdExpiryTimers_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Represents a pending GTD order expiration.
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct GtdExpiration {
+    pub client_order_id: ClientOrderId,
+    pub expire_time_ns: UnixNanos,
+}
+
+#[no_mangle]
+pub extern "C" fn gtd_expiry_timers_new() -> GtdExpiryTimers_API {
+    GtdExpiryTimers_API(Box::new(GtdExpiryTimers::new()))
+}
+
+#[no_mangle]
+pub extern "C" fn gtd_expiry_timers_drop(timers: GtdExpiryTimers_API) {
+    drop(timers); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn gtd_expiry_timers_count(timers: &GtdExpiryTimers_API) -> usize {
+    timers.count()
+}
+
+#[no_mangle]
+pub extern "C" fn gtd_expiry_timers_is_pending(
+    timers: &GtdExpiryTimers_API,
+    client_order_id: &ClientOrderId,
+) -> u8 {
+    u8::from(timers.is_pending(client_order_id))
+}
+
+/// Returns the earliest pending expire time, or 0 if there are no pending expirations.
+#[no_mangle]
+pub extern "C" fn gtd_expiry_timers_next_expire_time_ns(timers: &GtdExpiryTimers_API) -> UnixNanos {
+    timers.next_expire_time_ns().unwrap_or(0)
+}
+
+/// Returns the pending expirations sorted by expire time.
+///
+/// The returned vector must be freed with `vec_gtd_expirations_drop`.
+#[no_mangle]
+pub extern "C" fn gtd_expiry_timers_pending(timers: &GtdExpiryTimers_API) -> CVec {
+    timers
+        .pending_expirations()
+        .into_iter()
+        .map(|(client_order_id, expire_time_ns)| GtdExpiration {
+            client_order_id,
+            expire_time_ns,
+        })
+        .collect::<Vec<GtdExpiration>>()
+        .into()
+}
+
+#[no_mangle]
+pub extern "C" fn vec_gtd_expirations_drop(v: CVec) {
+    let CVec { ptr, len, cap, .. } = v;
+    let data: Vec<GtdExpiration> =
+        unsafe { Vec::from_raw_parts(ptr as *mut GtdExpiration, len, cap) };
+    drop(data); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn gtd_expiry_timers_register(
+    timers: &mut GtdExpiryTimers_API,
+    clock: &mut LiveClock_API,
+    client_order_id: &ClientOrderId,
+    expire_time_ns: UnixNanos,
+) {
+    timers.register(client_order_id.clone(), expire_time_ns, &mut **clock);
+}
+
+/// Returns 1 if a pending expiration was canceled, otherwise 0.
+#[no_mangle]
+pub extern "C" fn gtd_expiry_timers_cancel(
+    timers: &mut GtdExpiryTimers_API,
+    clock: &mut LiveClock_API,
+    client_order_id: &ClientOrderId,
+) -> u8 {
+    u8::from(timers.cancel(client_order_id, &mut **clock))
+}
+
+/// Handles the time `event`, writing the client order ID of the expired order to `out`.
+///
+/// Returns 1 if the event was from a pending expiry timer, otherwise 0 (in which case
+/// nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`ClientOrderId`], which is not read
+///   or dropped.
+#[no_mangle]
+pub unsafe extern "C" fn gtd_expiry_timers_on_time_event(
+    timers: &mut GtdExpiryTimers_API,
+    event: &TimeEvent,
+    out: *mut ClientOrderId,
+) -> u8 {
+    match timers.on_time_event(event) {
+        Some(client_order_id) => {
+            out.write(client_order_id);
+            1
+        }
+        None => 0,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn gtd_expiry_timers_reset(
+    timers: &mut GtdExpiryTimers_API,
+    clock: &mut LiveClock_API,
+) {
+    timers.reset(&mut **clock);
+}
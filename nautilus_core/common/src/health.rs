@@ -0,0 +1,229 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::identifiers::client_id::ClientId;
+
+use crate::{
+    enums::HealthStatus,
+    msgbus::{Message, MessageBus},
+};
+
+/// The topic prefix for alerts published when a client degrades.
+pub const HEALTH_DEGRADED_TOPIC: &str = "health.degraded";
+/// The topic prefix for alerts published when a degraded client recovers.
+pub const HEALTH_RECOVERED_TOPIC: &str = "health.recovered";
+
+/// Represents the health of a single data or execution client.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientHealth {
+    pub client_id: ClientId,
+    pub status: HealthStatus,
+    /// The UNIX timestamp (nanoseconds) of the last message from the client.
+    pub last_message_ns: UnixNanos,
+    /// The time without a message after which the client is degraded (nanoseconds).
+    pub stale_threshold_ns: u64,
+    /// The UNIX timestamp (nanoseconds) when the client degraded (0 if healthy).
+    pub ts_degraded: UnixNanos,
+}
+
+/// Provides health monitoring of data and execution clients from the time since their last
+/// message.
+///
+/// Each [`HealthMonitor::check`] publishes an alert event on the message bus for every client
+/// which has stalled beyond its stale threshold since the previous check, on the topic
+/// `health.degraded.<client_id>`, and for every degraded client which has since received a
+/// message, on the topic `health.recovered.<client_id>`.
+#[derive(Debug)]
+pub struct HealthMonitor {
+    default_stale_threshold_ns: u64,
+    clients: HashMap<ClientId, ClientHealth>,
+}
+
+impl HealthMonitor {
+    /// Initializes a new `HealthMonitor` instance.
+    #[must_use]
+    pub fn new(default_stale_threshold_ns: u64) -> Self {
+        Self {
+            default_stale_threshold_ns,
+            clients: HashMap::new(),
+        }
+    }
+
+    /// Registers the client to monitor, as healthy from `ts_now`.
+    ///
+    /// The default stale threshold is used if `stale_threshold_ns` is `None`.
+    pub fn register_client(
+        &mut self,
+        client_id: ClientId,
+        stale_threshold_ns: Option<u64>,
+        ts_now: UnixNanos,
+    ) {
+        let health = ClientHealth {
+            client_id: client_id.clone(),
+            status: HealthStatus::Healthy,
+            last_message_ns: ts_now,
+            stale_threshold_ns: stale_threshold_ns.unwrap_or(self.default_stale_threshold_ns),
+            ts_degraded: 0,
+        };
+        self.clients.insert(client_id, health);
+    }
+
+    pub fn deregister_client(&mut self, client_id: &ClientId) {
+        self.clients.remove(client_id);
+    }
+
+    /// Records a message from the client at `ts`, ignoring unregistered clients.
+    pub fn record_message(&mut self, client_id: &ClientId, ts: UnixNanos) {
+        if let Some(health) = self.clients.get_mut(client_id) {
+            health.last_message_ns = health.last_message_ns.max(ts);
+        }
+    }
+
+    #[must_use]
+    pub fn client_health(&self, client_id: &ClientId) -> Option<&ClientHealth> {
+        self.clients.get(client_id)
+    }
+
+    /// Returns true if no registered client is degraded.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.clients
+            .values()
+            .all(|health| health.status == HealthStatus::Healthy)
+    }
+
+    /// Updates the status of every client as at `ts_now`, publishing an alert on the message
+    /// bus for each change, and returns the clients which degraded.
+    pub fn check(&mut self, ts_now: UnixNanos, msgbus: &mut MessageBus) -> Vec<ClientId> {
+        let mut degraded = Vec::new();
+        for health in self.clients.values_mut() {
+            let is_stale =
+                ts_now.saturating_sub(health.last_message_ns) > health.stale_threshold_ns;
+            let topic = match (health.status, is_stale) {
+                (HealthStatus::Healthy, true) => {
+                    health.status = HealthStatus::Degraded;
+                    health.ts_degraded = ts_now;
+                    degraded.push(health.client_id.clone());
+                    HEALTH_DEGRADED_TOPIC
+                }
+                (HealthStatus::Degraded, false) => {
+                    health.status = HealthStatus::Healthy;
+                    health.ts_degraded = 0;
+                    HEALTH_RECOVERED_TOPIC
+                }
+                _ => continue,
+            };
+
+            let alert = Message::Event {
+                id: UUID4::new(),
+                ts_init: ts_now,
+                ts_event: health.last_message_ns,
+            };
+            msgbus.publish(&format!("{topic}.{}", health.client_id), &alert);
+        }
+        degraded.sort_by(|a, b| a.value.cmp(&b.value));
+        degraded
+    }
+
+    /// Returns the health of every client, sorted by client ID.
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<ClientHealth> {
+        let mut snapshot: Vec<ClientHealth> = self.clients.values().cloned().collect();
+        snapshot.sort_by(|a, b| a.client_id.value.cmp(&b.client_id.value));
+        snapshot
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_model::identifiers::trader_id::TraderId;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::msgbus::MessageHandler;
+
+    fn msgbus_with_alerts() -> (MessageBus, Rc<RefCell<Vec<Message>>>) {
+        let mut msgbus = MessageBus::new(TraderId::new("TRADER-001"), None);
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let handler_received = received.clone();
+        let handler = MessageHandler::new(
+            "health",
+            Rc::new(move |msg: &Message| handler_received.borrow_mut().push(*msg)),
+        );
+        msgbus.subscribe("health.*", handler, 0);
+        (msgbus, received)
+    }
+
+    #[rstest]
+    fn test_client_within_threshold_is_healthy() {
+        let (mut msgbus, received) = msgbus_with_alerts();
+        let mut monitor = HealthMonitor::new(1_000);
+        let client_id = ClientId::new("BINANCE");
+        monitor.register_client(client_id.clone(), None, 0);
+        monitor.record_message(&client_id, 500);
+
+        let degraded = monitor.check(1_500, &mut msgbus);
+
+        assert!(degraded.is_empty());
+        assert!(monitor.is_healthy());
+        assert!(received.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_stalled_client_degrades_once_then_recovers() {
+        let (mut msgbus, received) = msgbus_with_alerts();
+        let mut monitor = HealthMonitor::new(1_000);
+        let client_id = ClientId::new("BINANCE");
+        monitor.register_client(client_id.clone(), None, 0);
+
+        assert_eq!(monitor.check(1_001, &mut msgbus), vec![client_id.clone()]);
+        assert!(monitor.check(2_000, &mut msgbus).is_empty());
+        let health = monitor.client_health(&client_id).unwrap();
+        assert_eq!(health.status, HealthStatus::Degraded);
+        assert_eq!(health.ts_degraded, 1_001);
+
+        monitor.record_message(&client_id, 2_500);
+        monitor.check(3_000, &mut msgbus);
+
+        assert!(monitor.is_healthy());
+        assert_eq!(received.borrow().len(), 2);
+    }
+
+    #[rstest]
+    fn test_per_client_threshold_and_snapshot() {
+        let (mut msgbus, _) = msgbus_with_alerts();
+        let mut monitor = HealthMonitor::new(1_000);
+        monitor.register_client(ClientId::new("SIM"), Some(10_000), 0);
+        monitor.register_client(ClientId::new("BINANCE"), None, 0);
+
+        monitor.check(5_000, &mut msgbus);
+        let snapshot = monitor.snapshot();
+
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].client_id, ClientId::new("BINANCE"));
+        assert_eq!(snapshot[0].status, HealthStatus::Degraded);
+        assert_eq!(snapshot[1].status, HealthStatus::Healthy);
+        assert_eq!(snapshot[1].stale_threshold_ns, 10_000);
+    }
+}
@@ -0,0 +1,124 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::{cvec::CVec, time::UnixNanos};
+use nautilus_model::identifiers::client_id::ClientId;
+
+use crate::{
+    health::{ClientHealth, HealthMonitor},
+    msgbus_api::MessageBus_API,
+};
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`HealthMonitor`].
+///
+/// This struct wraps `HealthMonitor` in a way that makes it compatible with C function
+/// calls, enabling interaction with `HealthMonitor` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `HealthMonitor_API`
+/// to be dereferenced to `HealthMonitor`, providing access to `HealthMonitor`'s methods
+/// without having to manually access the underlying `HealthMonitor` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct HealthMonitor_API(Box<HealthMonitor>);
+
+impl Deref for HealthMonitor_API {
+    type Target = HealthMonitor;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for HealthMonitor_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn health_monitor_new(default_stale_threshold_ns: u64) -> HealthMonitor_API {
+    HealthMonitor_API(Box::new(HealthMonitor::new(default_stale_threshold_ns)))
+}
+
+#[no_mangle]
+pub extern "C" fn health_monitor_drop(monitor: HealthMonitor_API) {
+    drop(monitor); // Memory freed here
+}
+
+/// Registers the client to monitor, using the default stale threshold if
+/// `stale_threshold_ns` is 0.
+#[no_mangle]
+pub extern "C" fn health_monitor_register_client(
+    monitor: &mut HealthMonitor_API,
+    client_id: &ClientId,
+    stale_threshold_ns: u64,
+    ts_now: UnixNanos,
+) {
+    let stale_threshold_ns = match stale_threshold_ns {
+        0 => None,
+        value => Some(value),
+    };
+    monitor.register_client(client_id.clone(), stale_threshold_ns, ts_now);
+}
+
+#[no_mangle]
+pub extern "C" fn health_monitor_deregister_client(
+    monitor: &mut HealthMonitor_API,
+    client_id: &ClientId,
+) {
+    monitor.deregister_client(client_id);
+}
+
+#[no_mangle]
+pub extern "C" fn health_monitor_record_message(
+    monitor: &mut HealthMonitor_API,
+    client_id: &ClientId,
+    ts: UnixNanos,
+) {
+    monitor.record_message(client_id, ts);
+}
+
+/// Returns the count of clients which degraded, publishing alerts on the `msgbus`.
+#[no_mangle]
+pub extern "C" fn health_monitor_check(
+    monitor: &mut HealthMonitor_API,
+    msgbus: &mut MessageBus_API,
+    ts_now: UnixNanos,
+) -> usize {
+    monitor.check(ts_now, &mut **msgbus).len()
+}
+
+#[no_mangle]
+pub extern "C" fn health_monitor_is_healthy(monitor: &HealthMonitor_API) -> u8 {
+    u8::from(monitor.is_healthy())
+}
+
+/// Returns the health of every client, sorted by client ID.
+///
+/// The returned vector must be freed with `vec_client_health_drop`.
+#[no_mangle]
+pub extern "C" fn health_monitor_snapshot(monitor: &HealthMonitor_API) -> CVec {
+    monitor.snapshot().into()
+}
+
+#[no_mangle]
+pub extern "C" fn vec_client_health_drop(v: CVec) {
+    let CVec { ptr, len, cap, .. } = v;
+    let data: Vec<ClientHealth> =
+        unsafe { Vec::from_raw_parts(ptr as *mut ClientHealth, len, cap) };
+    drop(data); // Memory freed here
+}
@@ -13,12 +13,47 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod aggregation;
+pub mod aggregation_api;
+pub mod cache;
+pub mod cache_api;
+pub mod calendar;
+pub mod calendar_api;
+pub mod channel;
 pub mod clock;
 pub mod clock_api;
+pub mod component;
+pub mod component_api;
 pub mod enums;
+pub mod expiry;
+pub mod expiry_api;
+pub mod health;
+pub mod health_api;
 pub mod logging;
 pub mod logging_api;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "metrics")]
+pub mod metrics_api;
 pub mod msgbus;
+pub mod msgbus_api;
+#[cfg(feature = "redis")]
+pub mod redis_streams;
+pub mod ring_buffer;
+pub mod runtime;
 pub mod testing;
+pub mod throttler;
 pub mod timer;
 pub mod timer_api;
+
+use pyo3::{prelude::*, types::PyModule, Python};
+
+/// Loaded as nautilus_pyo3.common
+#[pymodule]
+pub fn common(_: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<timer::TimeEvent>()?;
+    m.add_class::<timer::LiveTimer>()?;
+    m.add_class::<runtime::ThreadConfig>()?;
+    m.add_class::<runtime::RuntimeConfig>()?;
+    Ok(())
+}
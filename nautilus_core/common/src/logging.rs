@@ -19,24 +19,37 @@ use std::{
     fs::{create_dir_all, File},
     io::{self, BufWriter, Stderr, Stdout, Write},
     path::{Path, PathBuf},
-    sync::mpsc::{channel, Receiver, SendError, Sender},
+    sync::mpsc::RecvTimeoutError,
     thread,
+    time::{Duration, Instant},
 };
 
 use chrono::{prelude::*, Utc};
-use nautilus_core::{datetime::unix_nanos_to_iso8601, time::UnixNanos, uuid::UUID4};
+use nautilus_core::{
+    datetime::unix_nanos_to_iso8601,
+    time::{duration_since_unix_epoch, UnixNanos},
+    uuid::UUID4,
+};
 use nautilus_model::identifiers::trader_id::TraderId;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::enums::{LogColor, LogLevel};
+use crate::{
+    channel::{bounded, BoundedReceiver, BoundedSender, ChannelMetrics, OverflowPolicy},
+    enums::{LogColor, LogLevel},
+};
+
+/// The maximum number of log events queued for the writer thread, beyond which callers
+/// wait for the writer to catch up.
+const LOG_QUEUE_CAPACITY: usize = 100_000;
 
-/// Provides a high-performance logger utilizing a MPSC channel under the hood.
+/// Provides a high-performance logger utilizing a bounded MPSC channel under the hood.
 ///
 /// A separate thead is spawned at initialization which receives [`LogEvent`] structs over the
-/// channel.
+/// channel, and is solely responsible for formatting and writing to stdout/stderr and the log
+/// file (so the caller never blocks on I/O, unless the queue is full).
 pub struct Logger {
-    tx: Sender<LogEvent>,
+    tx: BoundedSender<LogEvent>,
     /// The trader ID for the logger.
     pub trader_id: TraderId,
     /// The machine ID for the logger.
@@ -47,6 +60,12 @@ pub struct Logger {
     pub level_stdout: LogLevel,
     /// The minimum log level to write to a log file.
     pub level_file: Option<LogLevel>,
+    /// If console output includes ANSI color codes.
+    pub is_colored: bool,
+    /// The maximum number of log events written per second (errors are never limited).
+    pub rate_limit: Option<u32>,
+    /// The maximum size (bytes) of a log file before it is rotated.
+    pub max_file_size: Option<u64>,
     /// If logging is bypassed.
     pub is_bypassed: bool,
 }
@@ -77,6 +96,224 @@ impl fmt::Display for LogEvent {
     }
 }
 
+/// Limits the number of log events written within each one second window.
+///
+/// Events at [`LogLevel::Error`] and above are always written, and the number of
+/// suppressed events is reported once the window rolls over.
+struct RateLimiter {
+    limit: u32,
+    window_start: Instant,
+    count: u32,
+    suppressed: u64,
+}
+
+impl RateLimiter {
+    const WINDOW: Duration = Duration::from_secs(1);
+
+    fn new(limit: u32) -> Self {
+        Self {
+            limit,
+            window_start: Instant::now(),
+            count: 0,
+            suppressed: 0,
+        }
+    }
+
+    /// Returns the number of events suppressed in the previous window, if it has just closed.
+    fn roll_window(&mut self, now: Instant) -> Option<u64> {
+        if now.duration_since(self.window_start) < Self::WINDOW {
+            return None;
+        }
+
+        self.window_start = now;
+        self.count = 0;
+        match self.suppressed {
+            0 => None,
+            suppressed => {
+                self.suppressed = 0;
+                Some(suppressed)
+            }
+        }
+    }
+
+    /// Returns the time until the current window closes, if any events have been suppressed
+    /// within it.
+    fn pending_summary_timeout(&self, now: Instant) -> Option<Duration> {
+        if self.suppressed == 0 {
+            return None;
+        }
+
+        Some(Self::WINDOW.saturating_sub(now.duration_since(self.window_start)))
+    }
+
+    fn allow(&mut self, level: LogLevel) -> bool {
+        if level >= LogLevel::Error {
+            return true;
+        }
+
+        if self.count < self.limit {
+            self.count += 1;
+            true
+        } else {
+            self.suppressed += 1;
+            false
+        }
+    }
+}
+
+/// Writes log lines to a file, rotating to a new file when the UTC date changes or the
+/// maximum file size would be exceeded.
+struct FileWriter {
+    directory: Option<String>,
+    file_name: Option<String>,
+    trader_id: String,
+    instance_id: String,
+    is_json_format: bool,
+    max_file_size: Option<u64>,
+    buf: BufWriter<File>,
+    path: PathBuf,
+    date: NaiveDate,
+    size: u64,
+    index: u32,
+}
+
+impl FileWriter {
+    fn new(
+        directory: Option<String>,
+        file_name: Option<String>,
+        trader_id: String,
+        instance_id: String,
+        is_json_format: bool,
+        max_file_size: Option<u64>,
+    ) -> Self {
+        let date = Utc::now().date_naive();
+        let path = Self::create_log_file_path(
+            &directory,
+            &file_name,
+            &trader_id,
+            &instance_id,
+            date,
+            0,
+            is_json_format,
+        );
+        let (buf, size) = Self::open(&path);
+
+        Self {
+            directory,
+            file_name,
+            trader_id,
+            instance_id,
+            is_json_format,
+            max_file_size,
+            buf,
+            path,
+            date,
+            size,
+            index: 0,
+        }
+    }
+
+    fn open(path: &Path) -> (BufWriter<File>, u64) {
+        let file = File::options()
+            .create(true)
+            .append(true)
+            .open(path)
+            .expect("Error creating log file");
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+        (BufWriter::new(file), size)
+    }
+
+    fn should_rotate(&self, date: NaiveDate, line_len: u64) -> bool {
+        if date != self.date {
+            return true;
+        }
+
+        match self.max_file_size {
+            Some(max_file_size) => self.size > 0 && self.size + line_len > max_file_size,
+            None => false,
+        }
+    }
+
+    fn rotate(&mut self, date: NaiveDate) {
+        // Ensure previous file buffer flushed
+        self.flush();
+
+        if date == self.date {
+            self.index += 1;
+        } else {
+            self.date = date;
+            self.index = 0;
+        }
+
+        self.path = Self::create_log_file_path(
+            &self.directory,
+            &self.file_name,
+            &self.trader_id,
+            &self.instance_id,
+            self.date,
+            self.index,
+            self.is_json_format,
+        );
+        (self.buf, self.size) = Self::open(&self.path);
+    }
+
+    fn write(&mut self, line: &str) {
+        let line_len = line.len() as u64;
+        let date = Utc::now().date_naive();
+        if self.should_rotate(date, line_len) {
+            self.rotate(date);
+        }
+
+        match self.buf.write_all(line.as_bytes()) {
+            Ok(_) => self.size += line_len,
+            Err(e) => eprintln!("Error writing to file: {e:?}"),
+        }
+    }
+
+    fn flush(&mut self) {
+        match self.buf.flush() {
+            Ok(_) => {}
+            Err(e) => eprintln!("Error writing to file: {e:?}"),
+        }
+    }
+
+    fn default_log_file_basename(trader_id: &str, instance_id: &str, date: NaiveDate) -> String {
+        format!("{}_{}_{}", trader_id, date.format("%Y-%m-%d"), instance_id)
+    }
+
+    fn create_log_file_path(
+        directory: &Option<String>,
+        file_name: &Option<String>,
+        trader_id: &str,
+        instance_id: &str,
+        date: NaiveDate,
+        index: u32,
+        is_json_format: bool,
+    ) -> PathBuf {
+        let mut basename = if let Some(file_name) = file_name {
+            file_name.to_owned()
+        } else {
+            Self::default_log_file_basename(trader_id, instance_id, date)
+        };
+
+        if index > 0 {
+            basename = format!("{basename}_{index}");
+        }
+
+        let suffix = if is_json_format { "json" } else { "log" };
+        let mut file_path = PathBuf::new();
+
+        if let Some(directory) = directory {
+            file_path.push(directory);
+            create_dir_all(&file_path).expect("Failed to create directories for log file");
+        }
+
+        file_path.push(basename);
+        file_path.set_extension(suffix);
+        file_path
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 impl Logger {
     pub fn new(
@@ -89,9 +326,12 @@ impl Logger {
         file_name: Option<String>,
         file_format: Option<String>,
         component_levels: Option<HashMap<String, Value>>,
+        is_colored: bool,
+        rate_limit: Option<u32>,
+        max_file_size: Option<u64>,
         is_bypassed: bool,
     ) -> Self {
-        let (tx, rx) = channel::<LogEvent>();
+        let (tx, rx) = bounded::<LogEvent>(LOG_QUEUE_CAPACITY, OverflowPolicy::Block);
         let mut level_filters = HashMap::<String, LogLevel>::new();
 
         if let Some(component_levels_map) = component_levels {
@@ -109,18 +349,21 @@ impl Logger {
         }
 
         let trader_id_clone = trader_id.value.to_string();
-        let instance_id_clone = instance_id.value.to_string();
+        let instance_id_clone = instance_id.to_string();
 
         thread::spawn(move || {
             Self::handle_messages(
-                &trader_id_clone,
-                &instance_id_clone,
+                trader_id_clone,
+                instance_id_clone,
                 level_stdout,
                 level_file,
                 directory,
                 file_name,
                 file_format,
                 level_filters,
+                is_colored,
+                rate_limit,
+                max_file_size,
                 rx,
             )
         });
@@ -131,21 +374,27 @@ impl Logger {
             instance_id,
             level_stdout,
             level_file,
+            is_colored,
+            rate_limit,
+            max_file_size,
             is_bypassed,
             tx,
         }
     }
 
     fn handle_messages(
-        trader_id: &str,
-        instance_id: &str,
+        trader_id: String,
+        instance_id: String,
         level_stdout: LogLevel,
         level_file: Option<LogLevel>,
         directory: Option<String>,
         file_name: Option<String>,
         file_format: Option<String>,
         level_filters: HashMap<String, LogLevel>,
-        rx: Receiver<LogEvent>,
+        is_colored: bool,
+        rate_limit: Option<u32>,
+        max_file_size: Option<u64>,
+        rx: BoundedReceiver<LogEvent>,
     ) {
         // Setup std I/O buffers
         let mut out_buf = BufWriter::new(io::stdout());
@@ -164,37 +413,61 @@ impl Logger {
             }
         };
 
-        let file_path = PathBuf::new();
-        let file = if level_file.is_some() {
-            let file_path = Self::create_log_file_path(
-                &directory,
-                &file_name,
-                trader_id,
+        let mut file_writer = level_file.map(|_| {
+            FileWriter::new(
+                directory,
+                file_name,
+                trader_id.clone(),
                 instance_id,
                 is_json_format,
-            );
-
-            Some(
-                File::options()
-                    .create(true)
-                    .append(true)
-                    .open(file_path)
-                    .expect("Error creating log file"),
+                max_file_size,
             )
-        } else {
-            None
-        };
+        });
 
-        let mut file_buf = file.map(BufWriter::new);
+        let mut rate_limiter = rate_limit.map(RateLimiter::new);
 
         // Setup templates for formatting
-        let template_console = String::from(
-            "\x1b[1m{ts}\x1b[0m {color}[{level}] {trader_id}.{component}: {message}\x1b[0m\n",
-        );
+        let template_console = if is_colored {
+            String::from(
+                "\x1b[1m{ts}\x1b[0m {color}[{level}] {trader_id}.{component}: {message}\x1b[0m\n",
+            )
+        } else {
+            String::from("{ts} [{level}] {trader_id}.{component}: {message}\n")
+        };
         let template_file = String::from("{ts} [{level}] {trader_id}.{component}: {message}\n");
 
         // Continue to receive and handle log events until channel is hung up
-        while let Ok(event) = rx.recv() {
+        loop {
+            // Wake at the end of a window with suppressed events to report them, rather
+            // than waiting for the next event
+            let received = match rate_limiter
+                .as_ref()
+                .and_then(|rate_limiter| rate_limiter.pending_summary_timeout(Instant::now()))
+            {
+                Some(timeout) => rx.recv_timeout(timeout),
+                None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            if let Some(rate_limiter) = rate_limiter.as_mut() {
+                if let Some(suppressed) = rate_limiter.roll_window(Instant::now()) {
+                    let line = format!(
+                        "{} [{}] {}.Logger: Rate limit exceeded, suppressed {} log messages\n",
+                        unix_nanos_to_iso8601(duration_since_unix_epoch().as_nanos() as u64),
+                        LogLevel::Warning,
+                        trader_id,
+                        suppressed,
+                    );
+                    Self::write_stderr(&mut err_buf, &line);
+                    Self::flush_stderr(&mut err_buf);
+                }
+            }
+
+            let event = match received {
+                Ok(event) => event,
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => break,
+            };
+
             let component_level = level_filters.get(&event.component);
 
             // Check if the component exists in level_filters and if its level is greater than event.level
@@ -204,51 +477,40 @@ impl Logger {
                 }
             }
 
+            // Only events which will be written count towards the rate limit
+            let is_written = event.level >= LogLevel::Error
+                || event.level >= level_stdout
+                || level_file.map_or(false, |level_file| event.level >= level_file);
+            if !is_written {
+                continue;
+            }
+
+            if let Some(rate_limiter) = rate_limiter.as_mut() {
+                if !rate_limiter.allow(event.level) {
+                    continue;
+                }
+            }
+
             if event.level >= LogLevel::Error {
-                let line = Self::format_log_line_console(&event, trader_id, &template_console);
+                let line = Self::format_log_line_console(&event, &trader_id, &template_console);
                 Self::write_stderr(&mut err_buf, &line);
                 Self::flush_stderr(&mut err_buf);
             } else if event.level >= level_stdout {
-                let line = Self::format_log_line_console(&event, trader_id, &template_console);
+                let line = Self::format_log_line_console(&event, &trader_id, &template_console);
                 Self::write_stdout(&mut out_buf, &line);
                 Self::flush_stdout(&mut out_buf);
             }
 
-            if let Some(level_file) = level_file {
-                if Self::should_rotate_file(&file_path) {
-                    // Ensure previous file buffer flushed
-                    if let Some(file_buf) = file_buf.as_mut() {
-                        Self::flush_file(file_buf);
-                    };
-
-                    let file_path = Self::create_log_file_path(
-                        &directory,
-                        &file_name,
-                        trader_id,
-                        instance_id,
+            if let (Some(level_file), Some(file_writer)) = (level_file, file_writer.as_mut()) {
+                if event.level >= level_file {
+                    let line = Self::format_log_line_file(
+                        &event,
+                        &trader_id,
+                        &template_file,
                         is_json_format,
                     );
-
-                    let file = File::options()
-                        .create(true)
-                        .append(true)
-                        .open(file_path)
-                        .expect("Error creating log file");
-
-                    file_buf = Some(BufWriter::new(file));
-                }
-
-                if event.level >= level_file {
-                    if let Some(file_buf) = file_buf.as_mut() {
-                        let line = Self::format_log_line_file(
-                            &event,
-                            trader_id,
-                            &template_file,
-                            is_json_format,
-                        );
-                        Self::write_file(file_buf, &line);
-                        Self::flush_file(file_buf);
-                    }
+                    file_writer.write(&line);
+                    file_writer.flush();
                 }
             }
         }
@@ -256,58 +518,11 @@ impl Logger {
         // Finally ensure remaining buffers are flushed
         Self::flush_stderr(&mut err_buf);
         Self::flush_stdout(&mut out_buf);
-    }
-
-    fn should_rotate_file(file_path: &Path) -> bool {
-        if file_path.exists() {
-            let current_date_utc = Utc::now().date_naive();
-            let metadata = file_path
-                .metadata()
-                .expect("Failed to read log file metadata");
-            let creation_time = metadata
-                .created()
-                .expect("Failed to get log file creation time");
-
-            let creation_time_utc: DateTime<Utc> = creation_time.into();
-            let creation_date_utc = creation_time_utc.date_naive();
-
-            current_date_utc != creation_date_utc
-        } else {
-            false
+        if let Some(file_writer) = file_writer.as_mut() {
+            file_writer.flush();
         }
     }
 
-    fn default_log_file_basename(trader_id: &str, instance_id: &str) -> String {
-        let current_date_utc = Utc::now().format("%Y-%m-%d");
-        format!("{}_{}_{}", trader_id, current_date_utc, instance_id)
-    }
-
-    fn create_log_file_path(
-        directory: &Option<String>,
-        file_name: &Option<String>,
-        trader_id: &str,
-        instance_id: &str,
-        is_json_format: bool,
-    ) -> PathBuf {
-        let basename = if let Some(file_name) = file_name {
-            file_name.to_owned()
-        } else {
-            Self::default_log_file_basename(trader_id, instance_id)
-        };
-
-        let suffix = if is_json_format { "json" } else { "log" };
-        let mut file_path = PathBuf::new();
-
-        if let Some(directory) = directory {
-            file_path.push(directory);
-            create_dir_all(&file_path).expect("Failed to create directories for log file");
-        }
-
-        file_path.push(basename);
-        file_path.set_extension(suffix);
-        file_path
-    }
-
     fn format_log_line_console(event: &LogEvent, trader_id: &str, template: &str) -> String {
         template
             .replace("{ts}", &unix_nanos_to_iso8601(event.timestamp))
@@ -366,20 +581,6 @@ impl Logger {
         }
     }
 
-    fn write_file(file_buf: &mut BufWriter<File>, line: &str) {
-        match file_buf.write_all(line.as_bytes()) {
-            Ok(_) => {}
-            Err(e) => eprintln!("Error writing to file: {e:?}"),
-        }
-    }
-
-    fn flush_file(file_buf: &mut BufWriter<File>) {
-        match file_buf.flush() {
-            Ok(_) => {}
-            Err(e) => eprintln!("Error writing to file: {e:?}"),
-        }
-    }
-
     pub fn send(
         &mut self,
         timestamp: u64,
@@ -395,11 +596,17 @@ impl Logger {
             component,
             message,
         };
-        if let Err(SendError(e)) = self.tx.send(event) {
-            eprintln!("Error sending log event: {}", e);
+        if let Err(e) = self.tx.send(event) {
+            eprintln!("Error sending log event: {}", e.into_inner());
         }
     }
 
+    /// Returns the metrics of the queue to the writer thread.
+    #[must_use]
+    pub fn queue_metrics(&self) -> ChannelMetrics {
+        self.tx.metrics()
+    }
+
     pub fn debug(&mut self, timestamp: u64, color: LogColor, component: String, message: String) {
         self.send(timestamp, LogLevel::Debug, color, component, message)
     }
@@ -452,6 +659,9 @@ mod tests {
             None,
             None,
             None,
+            true,
+            None,
+            None,
             false,
         )
     }
@@ -547,6 +757,9 @@ mod tests {
             None,
             None,
             None,
+            true,
+            None,
+            None,
             false,
         );
 
@@ -612,6 +825,9 @@ mod tests {
                 String::from("RiskEngine"),
                 Value::from("ERROR"), // <-- This should be filtered
             )))),
+            true,
+            None,
+            None,
             false,
         );
 
@@ -666,6 +882,9 @@ mod tests {
             None,
             Some("json".to_string()),
             None,
+            true,
+            None,
+            None,
             false,
         );
 
@@ -702,4 +921,171 @@ mod tests {
         "{\"timestamp\":1650000000000000,\"level\":\"INFO\",\"component\":\"RiskEngine\",\"message\":\"This is a test.\"}\n"
     );
     }
+
+    #[test]
+    fn test_logging_to_file_without_colors() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+
+        let mut logger = Logger::new(
+            TraderId::new("TRADER-001"),
+            String::from("user-01"),
+            UUID4::new(),
+            LogLevel::Info,
+            Some(LogLevel::Debug),
+            Some(temp_dir.path().to_str().unwrap().to_string()),
+            Some(String::from("plain")),
+            None,
+            None,
+            false,
+            None,
+            None,
+            false,
+        );
+
+        logger.info(
+            1_650_000_000_000_000,
+            LogColor::Green,
+            String::from("RiskEngine"),
+            String::from("This is a test."),
+        );
+
+        let log_file_path = temp_dir.path().join("plain.log");
+        let mut log_contents = String::new();
+
+        wait_until(
+            || {
+                log_contents = std::fs::read_to_string(&log_file_path).unwrap_or_default();
+                !log_contents.is_empty()
+            },
+            Duration::from_secs(2),
+        );
+
+        assert!(!logger.is_colored);
+        assert!(!log_contents.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_log_file_rotates_on_max_file_size() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+
+        let mut logger = Logger::new(
+            TraderId::new("TRADER-001"),
+            String::from("user-01"),
+            UUID4::new(),
+            LogLevel::Error,
+            Some(LogLevel::Debug),
+            Some(temp_dir.path().to_str().unwrap().to_string()),
+            Some(String::from("rotating")),
+            None,
+            None,
+            true,
+            None,
+            Some(100),
+            false,
+        );
+
+        for _ in 0..3 {
+            logger.info(
+                1_650_000_000_000_000,
+                LogColor::Normal,
+                String::from("RiskEngine"),
+                String::from("This is a test message which fills most of a file."),
+            );
+        }
+
+        let file_count = || {
+            std::fs::read_dir(&temp_dir)
+                .expect("Failed to read directory")
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().is_file())
+                .count()
+        };
+
+        wait_until(|| file_count() == 3, Duration::from_secs(2));
+
+        assert!(temp_dir.path().join("rotating.log").exists());
+        assert!(temp_dir.path().join("rotating_1.log").exists());
+        assert!(temp_dir.path().join("rotating_2.log").exists());
+    }
+
+    #[test]
+    fn test_rate_limit_ignores_events_below_the_written_levels() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+
+        let mut logger = Logger::new(
+            TraderId::new("TRADER-001"),
+            String::from("user-01"),
+            UUID4::new(),
+            LogLevel::Error,
+            Some(LogLevel::Info),
+            Some(temp_dir.path().to_str().unwrap().to_string()),
+            Some(String::from("limited")),
+            None,
+            None,
+            false,
+            Some(1),
+            None,
+            false,
+        );
+
+        for _ in 0..3 {
+            logger.debug(
+                1_650_000_000_000_000,
+                LogColor::Normal,
+                String::from("RiskEngine"),
+                String::from("This is filtered."),
+            );
+        }
+        logger.info(
+            1_650_000_000_000_000,
+            LogColor::Normal,
+            String::from("RiskEngine"),
+            String::from("This is a test."),
+        );
+
+        let log_file_path = temp_dir.path().join("limited.log");
+        let mut log_contents = String::new();
+
+        wait_until(
+            || {
+                log_contents = std::fs::read_to_string(&log_file_path).unwrap_or_default();
+                !log_contents.is_empty()
+            },
+            Duration::from_secs(2),
+        );
+
+        assert_eq!(
+            log_contents,
+            "1970-01-20T02:20:00.000000000Z [INF] TRADER-001.RiskEngine: This is a test.\n"
+        );
+    }
+
+    #[test]
+    fn test_rate_limiter_suppresses_events_within_window() {
+        let mut rate_limiter = RateLimiter::new(2);
+
+        assert!(rate_limiter.allow(LogLevel::Info));
+        assert!(rate_limiter.allow(LogLevel::Info));
+        assert!(!rate_limiter.allow(LogLevel::Info));
+        assert!(!rate_limiter.allow(LogLevel::Debug));
+        assert!(rate_limiter.allow(LogLevel::Error)); // Errors are never limited
+        assert_eq!(rate_limiter.roll_window(rate_limiter.window_start), None);
+        assert_eq!(
+            rate_limiter.pending_summary_timeout(rate_limiter.window_start),
+            Some(RateLimiter::WINDOW)
+        );
+
+        let next_window = rate_limiter.window_start + RateLimiter::WINDOW;
+        assert_eq!(
+            rate_limiter.pending_summary_timeout(next_window),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(rate_limiter.roll_window(next_window), Some(2));
+        assert_eq!(rate_limiter.pending_summary_timeout(next_window), None);
+        assert!(rate_limiter.allow(LogLevel::Info));
+        assert_eq!(
+            rate_limiter.roll_window(next_window + RateLimiter::WINDOW),
+            None
+        );
+    }
 }
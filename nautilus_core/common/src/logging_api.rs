@@ -16,11 +16,15 @@
 use std::{
     ffi::c_char,
     ops::{Deref, DerefMut},
+    str::FromStr,
 };
 
 use nautilus_core::{
+    ffi_error::{
+        set_last_error, try_cstr_to_str, try_optional_cstr_to_str, write_result, FFI_ERROR, FFI_OK,
+    },
     parsing::optional_bytes_to_json,
-    string::{cstr_to_string, optional_cstr_to_string, str_to_cstr},
+    string::str_to_cstr,
     uuid::UUID4,
 };
 use nautilus_model::identifiers::trader_id::TraderId;
@@ -56,13 +60,22 @@ impl DerefMut for Logger_API {
     }
 }
 
-/// Creates a new logger.
+/// Creates a new logger, written to `out`.
+///
+/// A `rate_limit` or `max_file_size` of zero disables rate limiting or size based file
+/// rotation respectively.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// any of the ID strings are invalid (in which case nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `trader_id_ptr` is a valid C string pointer.
 /// - Assumes `machine_id_ptr` is a valid C string pointer.
 /// - Assumes `instance_id_ptr` is a valid C string pointer.
+/// - Assumes `directory_ptr`, `file_name_ptr`, `file_format_ptr` and `component_levels_ptr`
+///   are either null or valid C string pointers.
+/// - Assumes `out` is a valid pointer to memory for a [`Logger_API`].
 #[no_mangle]
 pub unsafe extern "C" fn logger_new(
     trader_id_ptr: *const c_char,
@@ -75,24 +88,66 @@ pub unsafe extern "C" fn logger_new(
     file_name_ptr: *const c_char,
     file_format_ptr: *const c_char,
     component_levels_ptr: *const c_char,
+    is_colored: u8,
+    rate_limit: u32,
+    max_file_size: u64,
     is_bypassed: u8,
-) -> Logger_API {
-    Logger_API(Box::new(Logger::new(
-        TraderId::new(&cstr_to_string(trader_id_ptr)),
-        String::from(&cstr_to_string(machine_id_ptr)),
-        UUID4::from(cstr_to_string(instance_id_ptr).as_str()),
-        level_stdout,
-        if file_logging != 0 {
-            Some(level_file)
-        } else {
-            None
-        },
-        optional_cstr_to_string(directory_ptr),
-        optional_cstr_to_string(file_name_ptr),
-        optional_cstr_to_string(file_format_ptr),
-        optional_bytes_to_json(component_levels_ptr),
-        is_bypassed != 0,
-    )))
+    out: *mut Logger_API,
+) -> u8 {
+    let ids = parse_logger_ids(trader_id_ptr, machine_id_ptr, instance_id_ptr);
+    let result = ids.and_then(|(trader_id, machine_id, instance_id)| {
+        let directory = try_optional_cstr_to_str(directory_ptr, "directory")?;
+        let file_name = try_optional_cstr_to_str(file_name_ptr, "file name")?;
+        let file_format = try_optional_cstr_to_str(file_format_ptr, "file format")?;
+
+        Ok(Logger_API(Box::new(Logger::new(
+            trader_id,
+            machine_id,
+            instance_id,
+            level_stdout,
+            if file_logging != 0 {
+                Some(level_file)
+            } else {
+                None
+            },
+            directory.map(ToString::to_string),
+            file_name.map(ToString::to_string),
+            file_format.map(ToString::to_string),
+            optional_bytes_to_json(component_levels_ptr),
+            is_colored != 0,
+            if rate_limit > 0 {
+                Some(rate_limit)
+            } else {
+                None
+            },
+            if max_file_size > 0 {
+                Some(max_file_size)
+            } else {
+                None
+            },
+            is_bypassed != 0,
+        ))))
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Parses the trader ID, machine ID and instance ID of a logger.
+///
+/// # Safety
+///
+/// - Assumes `trader_id_ptr`, `machine_id_ptr` and `instance_id_ptr` are valid C string pointers.
+unsafe fn parse_logger_ids(
+    trader_id_ptr: *const c_char,
+    machine_id_ptr: *const c_char,
+    instance_id_ptr: *const c_char,
+) -> Result<(TraderId, String, UUID4), String> {
+    let trader_id = TraderId::try_new(try_cstr_to_str(trader_id_ptr, "`TraderId`")?)
+        .map_err(|e| e.to_string())?;
+    let machine_id = try_cstr_to_str(machine_id_ptr, "machine ID")?.to_string();
+    let instance_id = try_cstr_to_str(instance_id_ptr, "instance ID")?;
+    let instance_id = UUID4::from_str(instance_id)
+        .map_err(|e| format!("invalid instance ID string, was '{instance_id}': {e}"))?;
+    Ok((trader_id, machine_id, instance_id))
 }
 
 #[no_mangle]
@@ -112,7 +167,7 @@ pub extern "C" fn logger_get_machine_id_cstr(logger: &Logger_API) -> *const c_ch
 
 #[no_mangle]
 pub extern "C" fn logger_get_instance_id(logger: &Logger_API) -> UUID4 {
-    logger.instance_id.clone()
+    logger.instance_id
 }
 
 #[no_mangle]
@@ -120,8 +175,17 @@ pub extern "C" fn logger_is_bypassed(logger: &Logger_API) -> u8 {
     logger.is_bypassed as u8
 }
 
+/// Returns the number of log events queued for the writer thread.
+#[no_mangle]
+pub extern "C" fn logger_queue_depth(logger: &Logger_API) -> usize {
+    logger.queue_metrics().depth
+}
+
 /// Create a new log event.
 ///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `component_ptr` or `message_ptr` is not a valid C string.
+///
 /// # Safety
 ///
 /// - Assumes `component_ptr` is a valid C string pointer.
@@ -134,8 +198,21 @@ pub unsafe extern "C" fn logger_log(
     color: LogColor,
     component_ptr: *const c_char,
     message_ptr: *const c_char,
-) {
-    let component = cstr_to_string(component_ptr);
-    let message = cstr_to_string(message_ptr);
+) -> u8 {
+    let component = match try_cstr_to_str(component_ptr, "log component") {
+        Ok(component) => component.to_string(),
+        Err(e) => {
+            set_last_error(e);
+            return FFI_ERROR;
+        }
+    };
+    let message = match try_cstr_to_str(message_ptr, "log message") {
+        Ok(message) => message.to_string(),
+        Err(e) => {
+            set_last_error(e);
+            return FFI_ERROR;
+        }
+    };
     logger.send(timestamp_ns, level, color, component, message);
+    FFI_OK
 }
@@ -0,0 +1,286 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    fmt::Write,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        OnceLock,
+    },
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+/// The upper bounds of the event loop latency histogram buckets (nanoseconds).
+const LATENCY_BUCKETS_NS: [u64; 11] = [
+    10_000,
+    50_000,
+    100_000,
+    500_000,
+    1_000_000,
+    5_000_000,
+    10_000_000,
+    50_000_000,
+    100_000_000,
+    500_000_000,
+    1_000_000_000,
+];
+
+static METRICS: OnceLock<MetricsRegistry> = OnceLock::new();
+
+/// Returns the global metrics registry for the process.
+pub fn metrics() -> &'static MetricsRegistry {
+    METRICS.get_or_init(MetricsRegistry::new)
+}
+
+/// Represents a monotonically increasing count.
+#[derive(Debug, Default)]
+pub struct Counter(AtomicU64);
+
+impl Counter {
+    pub fn inc(&self) {
+        self.inc_by(1);
+    }
+
+    pub fn inc_by(&self, value: u64) {
+        self.0.fetch_add(value, Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Represents a distribution of durations over a fixed set of buckets.
+#[derive(Debug)]
+pub struct Histogram {
+    bounds_ns: &'static [u64],
+    /// The count of observations per bucket (not cumulative), with a final `+Inf` bucket.
+    counts: Vec<AtomicU64>,
+    sum_ns: AtomicU64,
+}
+
+impl Histogram {
+    #[must_use]
+    pub fn new(bounds_ns: &'static [u64]) -> Self {
+        Self {
+            bounds_ns,
+            counts: (0..=bounds_ns.len()).map(|_| AtomicU64::new(0)).collect(),
+            sum_ns: AtomicU64::new(0),
+        }
+    }
+
+    pub fn observe_ns(&self, value_ns: u64) {
+        let i = self.bounds_ns.partition_point(|&bound| bound < value_ns);
+        self.counts[i].fetch_add(1, Ordering::Relaxed);
+        self.sum_ns.fetch_add(value_ns, Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.counts.iter().map(|c| c.load(Ordering::Relaxed)).sum()
+    }
+
+    fn render(&self, name: &str, help: &str, out: &mut String) {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} histogram");
+        let mut cumulative = 0;
+        for (bound_ns, count) in self.bounds_ns.iter().zip(&self.counts) {
+            cumulative += count.load(Ordering::Relaxed);
+            let le = *bound_ns as f64 / 1_000_000_000.0;
+            let _ = writeln!(out, "{name}_bucket{{le=\"{le}\"}} {cumulative}");
+        }
+        let count = self.count();
+        let sum = self.sum_ns.load(Ordering::Relaxed) as f64 / 1_000_000_000.0;
+        let _ = writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {count}");
+        let _ = writeln!(out, "{name}_sum {sum}");
+        let _ = writeln!(out, "{name}_count {count}");
+    }
+}
+
+/// Provides the metrics of a live node, rendered in the Prometheus text format.
+#[derive(Debug)]
+pub struct MetricsRegistry {
+    pub orders_submitted: Counter,
+    pub orders_filled: Counter,
+    pub ticks_processed: Counter,
+    pub event_loop_latency: Histogram,
+}
+
+impl MetricsRegistry {
+    /// Initializes a new `MetricsRegistry` instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            orders_submitted: Counter::default(),
+            orders_filled: Counter::default(),
+            ticks_processed: Counter::default(),
+            event_loop_latency: Histogram::new(&LATENCY_BUCKETS_NS),
+        }
+    }
+
+    /// Returns the metrics in the Prometheus text exposition format.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for (name, help, counter) in [
+            (
+                "nautilus_orders_submitted_total",
+                "The count of orders submitted.",
+                &self.orders_submitted,
+            ),
+            (
+                "nautilus_orders_filled_total",
+                "The count of order fills.",
+                &self.orders_filled,
+            ),
+            (
+                "nautilus_ticks_processed_total",
+                "The count of market data ticks processed.",
+                &self.ticks_processed,
+            ),
+        ] {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} counter");
+            let _ = writeln!(out, "{name} {}", counter.get());
+        }
+        self.event_loop_latency.render(
+            "nautilus_event_loop_latency_seconds",
+            "The latency of event loop callbacks.",
+            &mut out,
+        );
+        out
+    }
+}
+
+impl Default for MetricsRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Serves `GET /metrics` requests on the `listener` with the rendered `registry`, until the
+/// listener fails.
+pub async fn serve(listener: TcpListener, registry: &'static MetricsRegistry) {
+    loop {
+        let (mut stream, _) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                eprintln!("Error accepting metrics connection: {e}");
+                return;
+            }
+        };
+
+        tokio::spawn(async move {
+            let mut buf = [0u8; 1024];
+            let n = stream.read(&mut buf).await.unwrap_or(0);
+            let request = String::from_utf8_lossy(&buf[..n]);
+
+            let response = if request.starts_with("GET /metrics") {
+                let body = registry.render();
+                format!(
+                    "HTTP/1.1 200 OK\r\n\
+                     Content-Type: text/plain; version=0.0.4\r\n\
+                     Content-Length: {}\r\n\
+                     Connection: close\r\n\r\n{body}",
+                    body.len()
+                )
+            } else {
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string()
+            };
+            if let Err(e) = stream.write_all(response.as_bytes()).await {
+                eprintln!("Error writing metrics response: {e}");
+            }
+        });
+    }
+}
+
+/// Starts serving the global metrics registry at `http://<addr>/metrics` on the shared Tokio
+/// runtime.
+pub fn start_server(addr: SocketAddr) -> std::io::Result<()> {
+    let runtime = pyo3_asyncio::tokio::get_runtime();
+    let listener = runtime.block_on(TcpListener::bind(addr))?;
+    runtime.spawn(serve(listener, metrics()));
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tokio::net::TcpStream;
+
+    use super::*;
+
+    #[rstest]
+    fn test_histogram_buckets_are_cumulative() {
+        let histogram = Histogram::new(&LATENCY_BUCKETS_NS);
+        histogram.observe_ns(10_000); // On the first bound
+        histogram.observe_ns(75_000);
+        histogram.observe_ns(2_000_000_000); // Beyond the last bound
+
+        let mut out = String::new();
+        histogram.render("latency", "Latency.", &mut out);
+
+        assert!(out.contains("latency_bucket{le=\"0.00001\"} 1\n"));
+        assert!(out.contains("latency_bucket{le=\"0.0001\"} 2\n"));
+        assert!(out.contains("latency_bucket{le=\"1\"} 2\n"));
+        assert!(out.contains("latency_bucket{le=\"+Inf\"} 3\n"));
+        assert!(out.contains("latency_count 3\n"));
+    }
+
+    #[rstest]
+    fn test_render_counters() {
+        let registry = MetricsRegistry::new();
+        registry.orders_submitted.inc();
+        registry.orders_submitted.inc();
+        registry.ticks_processed.inc_by(100);
+
+        let out = registry.render();
+
+        assert!(out.contains("# TYPE nautilus_orders_submitted_total counter\n"));
+        assert!(out.contains("nautilus_orders_submitted_total 2\n"));
+        assert!(out.contains("nautilus_orders_filled_total 0\n"));
+        assert!(out.contains("nautilus_ticks_processed_total 100\n"));
+    }
+
+    #[tokio::test]
+    async fn test_serve_metrics_endpoint() {
+        let registry: &'static MetricsRegistry = Box::leak(Box::new(MetricsRegistry::new()));
+        registry.orders_filled.inc();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve(listener, registry));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("nautilus_orders_filled_total 1\n"));
+    }
+}
@@ -0,0 +1,64 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    net::{Ipv4Addr, SocketAddr},
+};
+
+use nautilus_core::string::str_to_cstr;
+
+use crate::metrics::{metrics, start_server};
+
+/// Starts serving the metrics at `http://0.0.0.0:<port>/metrics`.
+///
+/// Returns 1 if the server was started, otherwise 0 (if the port could not be bound).
+#[no_mangle]
+pub extern "C" fn metrics_start_server(port: u16) -> u8 {
+    let addr = SocketAddr::from((Ipv4Addr::UNSPECIFIED, port));
+    match start_server(addr) {
+        Ok(()) => 1,
+        Err(e) => {
+            eprintln!("Error starting metrics server on {addr}: {e}");
+            0
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn metrics_inc_orders_submitted() {
+    metrics().orders_submitted.inc();
+}
+
+#[no_mangle]
+pub extern "C" fn metrics_inc_orders_filled() {
+    metrics().orders_filled.inc();
+}
+
+#[no_mangle]
+pub extern "C" fn metrics_inc_ticks_processed(count: u64) {
+    metrics().ticks_processed.inc_by(count);
+}
+
+#[no_mangle]
+pub extern "C" fn metrics_observe_event_loop_latency_ns(latency_ns: u64) {
+    metrics().event_loop_latency.observe_ns(latency_ns);
+}
+
+/// Returns the metrics in the Prometheus text format, as a C string pointer.
+#[no_mangle]
+pub extern "C" fn metrics_render_to_cstr() -> *const c_char {
+    str_to_cstr(&metrics().render())
+}
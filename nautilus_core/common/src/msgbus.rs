@@ -13,216 +13,783 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, rc::Rc};
+use std::{
+    collections::HashMap,
+    fmt,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
 
-use nautilus_core::uuid::UUID4;
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::identifiers::trader_id::TraderId;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
-use crate::clock::TestClock;
-
-type Handler = Rc<dyn Fn(&Message)>;
-
-#[allow(dead_code)]
-#[derive(Debug, Clone)]
-enum Message {
+/// Represents a message which can be sent, published, requested or responded to
+/// over the [`MessageBus`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Message {
     Command {
         id: UUID4,
-        ts_init: u64,
+        ts_init: UnixNanos,
     },
     Document {
         id: UUID4,
-        ts_init: u64,
+        ts_init: UnixNanos,
     },
     Event {
         id: UUID4,
-        ts_init: u64,
-        ts_event: u64,
+        ts_init: UnixNanos,
+        ts_event: UnixNanos,
     },
     Request {
         id: UUID4,
-        ts_init: u64,
+        ts_init: UnixNanos,
     },
     Response {
         id: UUID4,
-        ts_init: u64,
+        ts_init: UnixNanos,
         correlation_id: UUID4,
     },
 }
 
-#[allow(dead_code)]
-struct MessageBus {
-    name: String,
-    clock: TestClock,
-    /// mapping from topic to the corresponding handler
-    /// a topic can be a string with wildcards
-    /// * '?' - any character
-    /// * '*' - any number of any characters
-    subscriptions: HashMap<String, Handler>,
-    /// maps a pattern to all the handlers registered for it
-    /// this is updated whenever a new subscription is created
-    patterns: HashMap<String, Vec<Handler>>,
-    /// handles a message or a request destined for a specific endpoint
-    endpoints: HashMap<String, Handler>,
-    /// Relates a request with a response
-    /// a request maps it's id to a handler so that a response
-    /// with the same id can later be handled
-    correlation_index: HashMap<UUID4, Handler>,
-}
-
-#[allow(dead_code)]
+impl Message {
+    /// Returns the unique ID of the message.
+    #[must_use]
+    pub fn id(&self) -> UUID4 {
+        match self {
+            Self::Command { id, .. }
+            | Self::Document { id, .. }
+            | Self::Event { id, .. }
+            | Self::Request { id, .. }
+            | Self::Response { id, .. } => *id,
+        }
+    }
+}
+
+/// Represents a handler for messages, identified by a unique `handler_id`.
+///
+/// Rust closures cannot be compared, so handler equality is determined by the ID alone.
+#[derive(Clone)]
+pub struct MessageHandler {
+    pub handler_id: String,
+    pub callback: Rc<dyn Fn(&Message)>,
+}
+
+impl MessageHandler {
+    #[must_use]
+    pub fn new(handler_id: &str, callback: Rc<dyn Fn(&Message)>) -> Self {
+        Self {
+            handler_id: handler_id.to_string(),
+            callback,
+        }
+    }
+
+    fn handle(&self, msg: &Message) {
+        (self.callback)(msg);
+    }
+}
+
+impl PartialEq for MessageHandler {
+    fn eq(&self, other: &Self) -> bool {
+        self.handler_id == other.handler_id
+    }
+}
+
+impl Eq for MessageHandler {}
+
+impl fmt::Debug for MessageHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", stringify!(MessageHandler), self.handler_id)
+    }
+}
+
+/// Represents a subscription to a particular topic.
+///
+/// The subscription equality is determined by the topic and handler, priority is not
+/// considered (and could change).
+#[derive(Clone, Debug)]
+pub struct Subscription {
+    /// The topic for the subscription, which may include wildcard characters `*` and `?`.
+    pub topic: String,
+    /// The handler for the subscription.
+    pub handler: MessageHandler,
+    /// The priority for the subscription (higher priority handlers receive messages first).
+    pub priority: u8,
+}
+
+impl Subscription {
+    #[must_use]
+    pub fn new(topic: &str, handler: MessageHandler, priority: u8) -> Self {
+        Self {
+            topic: topic.to_string(),
+            handler,
+            priority,
+        }
+    }
+}
+
+impl PartialEq for Subscription {
+    fn eq(&self, other: &Self) -> bool {
+        self.topic == other.topic && self.handler == other.handler
+    }
+}
+
+impl Eq for Subscription {}
+
+impl Hash for Subscription {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.topic.hash(state);
+        self.handler.handler_id.hash(state);
+    }
+}
+
+/// Provides an external backing for published messages (e.g. a Redis stream), so
+/// that other processes can consume the message bus.
+pub trait MessagePublisher {
+    /// Publishes the given message for the `topic` externally.
+    fn publish(&self, topic: &str, msg: &Message);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MessageBusError {
+    #[error("Endpoint '{0}' already registered")]
+    EndpointAlreadyRegistered(String),
+    #[error("No endpoint registered at '{0}'")]
+    EndpointNotFound(String),
+    #[error("Duplicate request ID {0} found in correlation index")]
+    DuplicateRequestId(UUID4),
+    #[error("No callback found for correlation ID {0}")]
+    CorrelationIdNotFound(UUID4),
+    #[error("Invalid message type for {0}: {1:?}")]
+    InvalidMessage(&'static str, Message),
+}
+
+/// Provides a generic message bus to facilitate various messaging patterns.
+///
+/// The bus provides both a producer and consumer API for Pub/Sub, Req/Rep, as
+/// well as direct point-to-point messaging to registered endpoints.
+///
+/// Pub/Sub wildcard patterns for hierarchical topics are possible:
+///  - `*` asterisk represents one or more characters in a pattern.
+///  - `?` question mark represents a single character in a pattern.
+///
+/// Given a topic and pattern potentially containing wildcard characters, i.e.
+/// `*` and `?`, where `?` can match any single character in the topic, and `*`
+/// can match any number of characters including zero characters.
+///
+/// The asterisk in a wildcard matches any character zero or more times. For
+/// example, `comp*` matches anything beginning with `comp` which means `comp`,
+/// `complete`, and `computer` are all matched.
+///
+/// A question mark matches a single character once. For example, `c?mp` matches
+/// `camp` and `comp`. The question mark can also be used more than once.
+/// For example, `c??p` would match both of the above examples and `coop`.
+pub struct MessageBus {
+    /// The trader ID associated with the message bus.
+    pub trader_id: TraderId,
+    /// The name for the message bus.
+    pub name: String,
+    /// The count of messages sent through the bus.
+    pub sent_count: u64,
+    /// The count of requests processed by the bus.
+    pub req_count: u64,
+    /// The count of responses processed by the bus.
+    pub res_count: u64,
+    /// The count of messages published by the bus.
+    pub pub_count: u64,
+    /// Maps a subscription to the topics it has been matched against.
+    subscriptions: HashMap<Subscription, Vec<String>>,
+    /// Maps a published topic to its matching subscriptions, sorted by priority
+    /// (highest first). This is updated whenever a subscription is added or removed.
+    patterns: HashMap<String, Vec<Subscription>>,
+    /// Maps an endpoint address to its handler.
+    endpoints: HashMap<String, MessageHandler>,
+    /// Maps a request ID to the callback for its response.
+    correlation_index: HashMap<UUID4, MessageHandler>,
+    /// The optional external publisher for all published messages.
+    external_publisher: Option<Box<dyn MessagePublisher>>,
+}
+
 impl MessageBus {
-    // TODO: not needed accessible from struct field
-    fn endpoints(&self) {}
-
-    fn topics(&self) -> Vec<String> {
-        // TODO: Consider using itertools library for ergonomic
-        // and performant iterator apis.
-        self.subscriptions.keys().cloned().collect()
-    }
-
-    // TODO: This is the modified version of matching_subscriptions
-    // Since we've separated subscription and handler we can choose to return
-    // one of those fields or reconstruct the subscription as a tuple and
-    // return that.
-    // Depends on on how the output of this function is meant to be used
-    fn matching_handlers<'a>(&'a self, pattern: &'a String) -> impl Iterator<Item = &'a Handler> {
-        self.subscriptions.iter().filter_map(|(topic, handler)| {
-            if is_matching(topic, pattern) {
-                Some(handler)
-            } else {
-                None
-            }
-        })
+    /// Initializes a new `MessageBus` instance.
+    #[must_use]
+    pub fn new(trader_id: TraderId, name: Option<String>) -> Self {
+        Self {
+            trader_id,
+            name: name.unwrap_or_else(|| stringify!(MessageBus).to_string()),
+            sent_count: 0,
+            req_count: 0,
+            res_count: 0,
+            pub_count: 0,
+            subscriptions: HashMap::new(),
+            patterns: HashMap::new(),
+            endpoints: HashMap::new(),
+            correlation_index: HashMap::new(),
+            external_publisher: None,
+        }
+    }
+
+    /// Sets the external publisher which receives every message published on the bus,
+    /// after all local subscribers have handled it.
+    pub fn set_external_publisher(&mut self, publisher: Box<dyn MessagePublisher>) {
+        self.external_publisher = Some(publisher);
     }
 
-    fn has_subscribers(&self, pattern: &String) -> bool {
-        self.matching_handlers(pattern).next().is_some()
+    /// Returns whether messages are also published to an external backing.
+    #[must_use]
+    pub fn has_external_publisher(&self) -> bool {
+        self.external_publisher.is_some()
     }
 
-    fn register(&mut self, endpoint: String, handler: Handler) {
-        // updates value if key already exists
-        self.endpoints.insert(endpoint, handler);
+    /// Returns all endpoint addresses registered with the message bus.
+    #[must_use]
+    pub fn endpoints(&self) -> Vec<&str> {
+        let mut endpoints: Vec<&str> = self.endpoints.keys().map(String::as_str).collect();
+        endpoints.sort_unstable();
+        endpoints
     }
 
-    fn deregister(&mut self, endpoint: &String) {
-        // removes entry if it exists for endpoint
-        self.endpoints.remove(endpoint);
+    /// Returns all topics with active subscribers.
+    #[must_use]
+    pub fn topics(&self) -> Vec<&str> {
+        let mut topics: Vec<&str> = self
+            .subscriptions
+            .keys()
+            .map(|s| s.topic.as_str())
+            .collect();
+        topics.sort_unstable();
+        topics.dedup();
+        topics
     }
 
-    fn send(&self, endpoint: &String, msg: &Message) {
-        if let Some(handler) = self.endpoints.get(endpoint) {
-            handler(msg)
+    /// Returns all subscriptions matching the given topic `pattern` (all subscriptions if
+    /// `None`), sorted by priority (highest first).
+    #[must_use]
+    pub fn subscriptions(&self, pattern: Option<&str>) -> Vec<&Subscription> {
+        let pattern = pattern.unwrap_or("*");
+        let mut subs: Vec<&Subscription> = self
+            .subscriptions
+            .keys()
+            .filter(|s| is_matching(&s.topic, pattern))
+            .collect();
+        subs.sort_by(|a, b| b.priority.cmp(&a.priority));
+        subs
+    }
+
+    /// Returns whether there are subscribers for the given topic `pattern` (any topic
+    /// if `None`).
+    #[must_use]
+    pub fn has_subscribers(&self, pattern: Option<&str>) -> bool {
+        !self.subscriptions(pattern).is_empty()
+    }
+
+    /// Returns whether the given topic and handler is subscribed, regardless of priority.
+    #[must_use]
+    pub fn is_subscribed(&self, topic: &str, handler: &MessageHandler) -> bool {
+        let sub = Subscription::new(topic, handler.clone(), 0);
+        self.subscriptions.contains_key(&sub)
+    }
+
+    /// Registers the given `handler` to receive messages at the `endpoint` address.
+    pub fn register(
+        &mut self,
+        endpoint: &str,
+        handler: MessageHandler,
+    ) -> Result<(), MessageBusError> {
+        if self.endpoints.contains_key(endpoint) {
+            return Err(MessageBusError::EndpointAlreadyRegistered(
+                endpoint.to_string(),
+            ));
+        }
+
+        self.endpoints.insert(endpoint.to_string(), handler);
+        Ok(())
+    }
+
+    /// Deregisters the handler from the `endpoint` address.
+    pub fn deregister(&mut self, endpoint: &str) -> Result<(), MessageBusError> {
+        match self.endpoints.remove(endpoint) {
+            Some(_) => Ok(()),
+            None => Err(MessageBusError::EndpointNotFound(endpoint.to_string())),
+        }
+    }
+
+    /// Sends the given message to the handler registered at the `endpoint` address.
+    pub fn send(&mut self, endpoint: &str, msg: &Message) -> Result<(), MessageBusError> {
+        let handler = self
+            .endpoints
+            .get(endpoint)
+            .ok_or_else(|| MessageBusError::EndpointNotFound(endpoint.to_string()))?;
+
+        handler.handle(msg);
+        self.sent_count += 1;
+        Ok(())
+    }
+
+    /// Sends the given `request` to the `endpoint` address, with the `callback` to be
+    /// called with the response which has a matching correlation ID.
+    pub fn request(
+        &mut self,
+        endpoint: &str,
+        request: &Message,
+        callback: MessageHandler,
+    ) -> Result<(), MessageBusError> {
+        let id = match request {
+            Message::Request { id, .. } => *id,
+            _ => return Err(MessageBusError::InvalidMessage("request", *request)),
+        };
+
+        if self.correlation_index.contains_key(&id) {
+            return Err(MessageBusError::DuplicateRequestId(id));
         }
+
+        let handler = self
+            .endpoints
+            .get(endpoint)
+            .ok_or_else(|| MessageBusError::EndpointNotFound(endpoint.to_string()))?;
+
+        self.correlation_index.insert(id, callback);
+        handler.handle(request);
+        self.req_count += 1;
+        Ok(())
     }
 
-    #[allow(unused_variables)]
-    fn request(&mut self, endpoint: &String, request: &Message, callback: Handler) {
-        match request {
-            Message::Request { id, ts_init } => {
-                if self.correlation_index.contains_key(id) {
-                    todo!()
-                } else {
-                    self.correlation_index.insert(id.clone(), callback);
-                    if let Some(handler) = self.endpoints.get(endpoint) {
-                        handler(request)
-                    } else {
-                        // TODO: log error
-                    }
-                }
+    /// Passes the given `response` to the callback of the request it correlates with.
+    pub fn response(&mut self, response: &Message) -> Result<(), MessageBusError> {
+        let correlation_id = match response {
+            Message::Response { correlation_id, .. } => *correlation_id,
+            _ => return Err(MessageBusError::InvalidMessage("response", *response)),
+        };
+
+        let callback = self
+            .correlation_index
+            .remove(&correlation_id)
+            .ok_or(MessageBusError::CorrelationIdNotFound(correlation_id))?;
+
+        callback.handle(response);
+        self.res_count += 1;
+        Ok(())
+    }
+
+    /// Subscribes the given `handler` to the `topic`, which may include wildcard
+    /// characters `*` and `?`.
+    ///
+    /// Handlers with a higher `priority` receive messages before lower priority
+    /// handlers. Subscribing an existing topic and handler is a no-op.
+    pub fn subscribe(&mut self, topic: &str, handler: MessageHandler, priority: u8) {
+        let sub = Subscription::new(topic, handler, priority);
+        if self.subscriptions.contains_key(&sub) {
+            return;
+        }
+
+        let mut matches: Vec<String> = Vec::new();
+        for (pattern, subs) in &mut self.patterns {
+            if is_matching(pattern, topic) {
+                subs.push(sub.clone());
+                // Stable sort keeps subscription order for equal priorities
+                subs.sort_by(|a, b| b.priority.cmp(&a.priority));
+                matches.push(pattern.clone());
             }
-            _ => unreachable!(
-                "message bus request should only be called with Message::Request variant"
-            ),
-        }
-    }
-
-    #[allow(unused_variables)]
-    fn response(&mut self, response: &Message) {
-        match response {
-            Message::Response {
-                id,
-                ts_init,
-                correlation_id,
-            } => {
-                if let Some(callback) = self.correlation_index.get(correlation_id) {
-                    callback(response)
-                } else {
-                    // TODO: log error
-                }
+        }
+
+        matches.sort();
+        self.subscriptions.insert(sub, matches);
+    }
+
+    /// Unsubscribes the given `handler` from the `topic`.
+    ///
+    /// Returns `false` if no such subscription existed.
+    pub fn unsubscribe(&mut self, topic: &str, handler: &MessageHandler) -> bool {
+        let sub = Subscription::new(topic, handler.clone(), 0);
+        let patterns = match self.subscriptions.remove(&sub) {
+            Some(patterns) => patterns,
+            None => return false,
+        };
+
+        for pattern in patterns {
+            if let Some(subs) = self.patterns.get_mut(&pattern) {
+                subs.retain(|s| s != &sub);
             }
-            _ => unreachable!(
-                "message bus response should only be called with Message::Response variant"
-            ),
         }
+        true
     }
 
-    fn subscribe(&mut self, topic: String, handler: Handler) {
-        if self.subscriptions.contains_key(&topic) {
-            // TODO: log
-            return;
+    /// Publishes the given message on the `topic`.
+    ///
+    /// Subscription handlers receive the message in priority order (highest first).
+    pub fn publish(&mut self, topic: &str, msg: &Message) {
+        if !self.patterns.contains_key(topic) {
+            self.resolve_subscriptions(topic);
         }
 
-        self.subscriptions.insert(topic, handler);
+        if let Some(subs) = self.patterns.get(topic) {
+            for sub in subs {
+                sub.handler.handle(msg);
+            }
+        }
+
+        if let Some(publisher) = &self.external_publisher {
+            publisher.publish(topic, msg);
+        }
+        self.pub_count += 1;
     }
 
-    #[allow(unused_variables)]
-    fn unsubscribe(&mut self, topic: &String, handler: Handler) {
-        self.subscriptions.remove(topic);
+    fn resolve_subscriptions(&mut self, topic: &str) {
+        let mut subs: Vec<Subscription> = Vec::new();
+        for (sub, matches) in &mut self.subscriptions {
+            if is_matching(topic, &sub.topic) {
+                subs.push(sub.clone());
+                matches.push(topic.to_string());
+                matches.sort();
+            }
+        }
+
+        subs.sort_by(|a, b| b.priority.cmp(&a.priority));
+        self.patterns.insert(topic.to_string(), subs);
     }
+}
 
-    fn publish(&mut self, pattern: String, msg: &Message) {
-        // TODO: check if clone can be avoided
-        // Although not possible with this style - https://github.com/rust-lang/rust/issues/51604
-        let entry = self.patterns.entry(pattern.clone());
+/// Returns whether the given `topic` matches the `pattern`, which may contain the
+/// wildcard characters:
+///  - `*` matching zero or more characters.
+///  - `?` matching any single character.
+#[must_use]
+pub fn is_matching(topic: &str, pattern: &str) -> bool {
+    let topic = topic.as_bytes();
+    let pattern = pattern.as_bytes();
+    let m = pattern.len();
 
-        // function to find matching handlers
-        let matching_handlers = || {
-            self.subscriptions
-                .iter()
-                .filter_map(|(topic, handler)| {
-                    if is_matching(topic, &pattern) {
-                        Some(handler.clone())
-                    } else {
-                        None
-                    }
-                })
-                .collect()
-        };
+    // Row `i` of the lookup table holds whether the first `i` topic characters
+    // match each prefix of the pattern, so only the previous row is needed.
+    let mut prev = vec![false; m + 1];
+    let mut curr = vec![false; m + 1];
 
-        // get or insert and return matched handlers
-        let handlers = entry.or_insert_with(matching_handlers);
+    prev[0] = true;
+    for j in 1..=m {
+        if pattern[j - 1] == b'*' {
+            prev[j] = prev[j - 1];
+        }
+    }
 
-        // call matched handlers
-        handlers.iter().for_each(|handler| handler(msg))
+    for &tc in topic {
+        curr[0] = false;
+        for j in 1..=m {
+            let pc = pattern[j - 1];
+            curr[j] = if pc == b'*' {
+                prev[j] || curr[j - 1]
+            } else if pc == b'?' || pc == tc {
+                prev[j - 1]
+            } else {
+                false
+            };
+        }
+        std::mem::swap(&mut prev, &mut curr);
     }
+
+    prev[m]
 }
 
-/// match a topic and a string pattern
-/// pattern can contains -
-/// '*' - match 0 or more characters after this
-/// '?' - match any character once
-/// 'a-z' - match the specific character
-fn is_matching(topic: &String, pattern: &String) -> bool {
-    let mut table = [[false; 256]; 256];
-    table[0][0] = true;
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
 
-    let m = pattern.len();
-    let n = topic.len();
+    use rstest::rstest;
+
+    use super::*;
+
+    fn msgbus() -> MessageBus {
+        MessageBus::new(TraderId::new("TRADER-001"), None)
+    }
+
+    fn recording_handler(handler_id: &str, received: &Rc<RefCell<Vec<String>>>) -> MessageHandler {
+        let received = received.clone();
+        let name = handler_id.to_string();
+        MessageHandler::new(
+            handler_id,
+            Rc::new(move |_msg: &Message| received.borrow_mut().push(name.clone())),
+        )
+    }
 
-    pattern.chars().enumerate().for_each(|(j, c)| {
-        if c == '*' {
-            table[0][j + 1] = table[0][j];
+    fn event() -> Message {
+        Message::Event {
+            id: UUID4::new(),
+            ts_init: 1,
+            ts_event: 1,
         }
-    });
+    }
+
+    #[rstest]
+    #[case("*", "*", true)]
+    #[case("a", "*", true)]
+    #[case("a", "a", true)]
+    #[case("a", "b", false)]
+    #[case("data.quotes.BINANCE", "data.*", true)]
+    #[case("data.quotes.BINANCE", "data.quotes*", true)]
+    #[case("data.quotes.BINANCE", "data.*.BINANCE", true)]
+    #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.*", true)]
+    #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.ETH???", true)]
+    #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.ETH??", false)]
+    #[case("data.trades.BINANCE.ETHUSDT", "events.*", false)]
+    fn test_is_matching(#[case] topic: &str, #[case] pattern: &str, #[case] expected: bool) {
+        assert_eq!(is_matching(topic, pattern), expected);
+    }
+
+    #[test]
+    fn test_is_matching_long_topic() {
+        let topic = "a".repeat(1_000);
+        assert!(is_matching(&topic, "a*a"));
+        assert!(!is_matching(&topic, "a*b"));
+    }
+
+    #[test]
+    fn test_new_msgbus() {
+        let msgbus = msgbus();
+
+        assert_eq!(msgbus.trader_id, TraderId::new("TRADER-001"));
+        assert_eq!(msgbus.name, "MessageBus");
+        assert!(msgbus.endpoints().is_empty());
+        assert!(msgbus.topics().is_empty());
+        assert!(!msgbus.has_subscribers(None));
+    }
+
+    #[test]
+    fn test_register_and_send() {
+        let mut msgbus = msgbus();
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let handler = recording_handler("handler", &received);
+
+        msgbus.register("RiskEngine.execute", handler).unwrap();
+        msgbus.send("RiskEngine.execute", &event()).unwrap();
+
+        assert_eq!(msgbus.endpoints(), vec!["RiskEngine.execute"]);
+        assert_eq!(*received.borrow(), vec!["handler"]);
+        assert_eq!(msgbus.sent_count, 1);
+    }
+
+    #[test]
+    fn test_register_duplicate_endpoint_returns_error() {
+        let mut msgbus = msgbus();
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        msgbus
+            .register("endpoint", recording_handler("a", &received))
+            .unwrap();
+        let result = msgbus.register("endpoint", recording_handler("b", &received));
+
+        assert_eq!(
+            result,
+            Err(MessageBusError::EndpointAlreadyRegistered(
+                "endpoint".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_send_to_unknown_endpoint_returns_error() {
+        let mut msgbus = msgbus();
+
+        let result = msgbus.send("unknown", &event());
+
+        assert_eq!(
+            result,
+            Err(MessageBusError::EndpointNotFound("unknown".to_string()))
+        );
+        assert_eq!(msgbus.sent_count, 0);
+    }
+
+    #[test]
+    fn test_deregister() {
+        let mut msgbus = msgbus();
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        msgbus
+            .register("endpoint", recording_handler("a", &received))
+            .unwrap();
+        msgbus.deregister("endpoint").unwrap();
+
+        assert!(msgbus.endpoints().is_empty());
+        assert!(msgbus.deregister("endpoint").is_err());
+    }
+
+    #[test]
+    fn test_request_and_response() {
+        let mut msgbus = msgbus();
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let request_id = UUID4::new();
+        let request = Message::Request {
+            id: request_id,
+            ts_init: 1,
+        };
+        let response = Message::Response {
+            id: UUID4::new(),
+            ts_init: 2,
+            correlation_id: request_id,
+        };
+
+        msgbus
+            .register(
+                "DataEngine.request",
+                recording_handler("endpoint", &received),
+            )
+            .unwrap();
+        msgbus
+            .request(
+                "DataEngine.request",
+                &request,
+                recording_handler("callback", &received),
+            )
+            .unwrap();
+        msgbus.response(&response).unwrap();
+
+        assert_eq!(*received.borrow(), vec!["endpoint", "callback"]);
+        assert_eq!(msgbus.req_count, 1);
+        assert_eq!(msgbus.res_count, 1);
+        assert_eq!(
+            msgbus.response(&response),
+            Err(MessageBusError::CorrelationIdNotFound(request_id))
+        );
+    }
+
+    #[test]
+    fn test_request_with_duplicate_id_returns_error() {
+        let mut msgbus = msgbus();
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let request_id = UUID4::new();
+        let request = Message::Request {
+            id: request_id,
+            ts_init: 1,
+        };
+
+        msgbus
+            .register("endpoint", recording_handler("endpoint", &received))
+            .unwrap();
+        msgbus
+            .request("endpoint", &request, recording_handler("a", &received))
+            .unwrap();
+        let result = msgbus.request("endpoint", &request, recording_handler("b", &received));
+
+        assert_eq!(result, Err(MessageBusError::DuplicateRequestId(request_id)));
+    }
+
+    #[test]
+    fn test_request_with_non_request_message_returns_error() {
+        let mut msgbus = msgbus();
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let msg = event();
+
+        let result = msgbus.request("endpoint", &msg, recording_handler("a", &received));
+
+        assert_eq!(result, Err(MessageBusError::InvalidMessage("request", msg)));
+    }
+
+    #[test]
+    fn test_subscribe_and_publish_with_wildcards() {
+        let mut msgbus = msgbus();
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        msgbus.subscribe("data.quotes.*", recording_handler("quotes", &received), 0);
+        msgbus.subscribe("data.*", recording_handler("data", &received), 0);
+        msgbus.subscribe("events.*", recording_handler("events", &received), 0);
+
+        msgbus.publish("data.quotes.BINANCE", &event());
+
+        let mut handled = received.borrow().clone();
+        handled.sort();
+        assert_eq!(handled, vec!["data", "quotes"]);
+        assert_eq!(msgbus.topics(), vec!["data.*", "data.quotes.*", "events.*"]);
+        assert_eq!(msgbus.pub_count, 1);
+    }
+
+    #[test]
+    fn test_publish_in_priority_order() {
+        let mut msgbus = msgbus();
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        msgbus.subscribe("data.*", recording_handler("low", &received), 0);
+        msgbus.subscribe("data.*", recording_handler("high", &received), 10);
+        msgbus.publish("data.quotes", &event());
+        // Subscriptions added after the topic is first published are also prioritized
+        msgbus.subscribe("data.*", recording_handler("mid", &received), 5);
+        msgbus.publish("data.quotes", &event());
 
-    topic.chars().enumerate().for_each(|(i, tc)| {
-        pattern.chars().enumerate().for_each(|(j, pc)| {
-            if pc == '*' {
-                table[i + 1][j + 1] = table[i][j + 1] || table[i + 1][j];
-            } else if pc == '?' || tc == pc {
-                table[i + 1][j + 1] = table[i][j];
+        assert_eq!(
+            *received.borrow(),
+            vec!["high", "low", "high", "mid", "low"]
+        );
+    }
+
+    #[test]
+    fn test_subscribe_duplicate_is_ignored() {
+        let mut msgbus = msgbus();
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let handler = recording_handler("handler", &received);
+
+        msgbus.subscribe("data.*", handler.clone(), 0);
+        msgbus.subscribe("data.*", handler.clone(), 1);
+        msgbus.publish("data.quotes", &event());
+
+        assert!(msgbus.is_subscribed("data.*", &handler));
+        assert_eq!(msgbus.subscriptions(None).len(), 1);
+        assert_eq!(*received.borrow(), vec!["handler"]);
+    }
+
+    #[test]
+    fn test_publish_forwards_to_external_publisher() {
+        struct RecordingPublisher(Rc<RefCell<Vec<String>>>);
+
+        impl MessagePublisher for RecordingPublisher {
+            fn publish(&self, topic: &str, _msg: &Message) {
+                self.0.borrow_mut().push(topic.to_string());
             }
-        })
-    });
+        }
+
+        let mut msgbus = msgbus();
+        let published = Rc::new(RefCell::new(Vec::new()));
+        msgbus.set_external_publisher(Box::new(RecordingPublisher(published.clone())));
 
-    table[n][m]
+        msgbus.publish("data.quotes", &event());
+
+        assert!(msgbus.has_external_publisher());
+        assert_eq!(*published.borrow(), vec!["data.quotes"]);
+    }
+
+    #[test]
+    fn test_message_serialization() {
+        let msg = Message::Request {
+            id: UUID4::from("6ba7b810-9dad-11d1-80b4-00c04fd430c8"),
+            ts_init: 1,
+        };
+
+        let json = serde_json::to_string(&msg).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"type":"Request","id":"6ba7b810-9dad-11d1-80b4-00c04fd430c8","ts_init":1}"#
+        );
+        assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let mut msgbus = msgbus();
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let handler = recording_handler("handler", &received);
+
+        msgbus.subscribe("data.*", handler.clone(), 0);
+        msgbus.publish("data.quotes", &event());
+
+        assert!(msgbus.unsubscribe("data.*", &handler));
+        assert!(!msgbus.unsubscribe("data.*", &handler));
+        msgbus.publish("data.quotes", &event());
+
+        assert!(!msgbus.is_subscribed("data.*", &handler));
+        assert!(!msgbus.has_subscribers(Some("data.*")));
+        assert_eq!(*received.borrow(), vec!["handler"]);
+    }
 }
@@ -0,0 +1,497 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::{c_char, c_void},
+    fmt::Display,
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
+
+use nautilus_core::ffi_error::{
+    set_last_error, try_cstr_to_str, try_optional_cstr_to_str, write_result, FFI_ERROR, FFI_OK,
+};
+use nautilus_model::identifiers::trader_id::TraderId;
+
+use crate::msgbus::{is_matching, Message, MessageBus, MessageHandler};
+
+/// A C callback for handling messages from the [`MessageBus`].
+///
+/// The `context` pointer is passed back unchanged on every call, allowing the caller
+/// to route the message to the owning object (e.g. a Cython actor).
+pub type MessageCallback = unsafe extern "C" fn(context: *mut c_void, msg: *const Message);
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`MessageBus`].
+///
+/// This struct wraps `MessageBus` in a way that makes it compatible with C function
+/// calls, enabling interaction with `MessageBus` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `MessageBus_API` to be
+/// dereferenced to `MessageBus`, providing access to `MessageBus`'s methods without
+/// having to manually access the underlying `MessageBus` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct MessageBus_API(Box<MessageBus>);
+
+impl Deref for MessageBus_API {
+    type Target = MessageBus;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for MessageBus_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// # Safety
+///
+/// - Assumes `handler_id_ptr` is a valid C string pointer.
+/// - Assumes `context` remains valid for as long as the handler is held by the bus.
+unsafe fn c_message_handler(
+    handler_id_ptr: *const c_char,
+    callback: MessageCallback,
+    context: *mut c_void,
+) -> Result<MessageHandler, String> {
+    let handler_id = try_cstr_to_str(handler_id_ptr, "handler ID")?;
+    Ok(MessageHandler::new(
+        handler_id,
+        Rc::new(move |msg: &Message| callback(context, msg)),
+    ))
+}
+
+/// Returns a handler which can only be used to identify an existing handler.
+unsafe fn handler_key(handler_id_ptr: *const c_char) -> Result<MessageHandler, String> {
+    let handler_id = try_cstr_to_str(handler_id_ptr, "handler ID")?;
+    Ok(MessageHandler::new(handler_id, Rc::new(|_: &Message| {})))
+}
+
+/// Returns the status code for `result`, recording the error as the last error.
+fn status<E: Display>(result: Result<(), E>) -> u8 {
+    match result {
+        Ok(()) => FFI_OK,
+        Err(e) => {
+            set_last_error(e);
+            FFI_ERROR
+        }
+    }
+}
+
+/// Creates a new message bus, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `trader_id_ptr` is not a valid trader ID or `name_ptr` is not a valid C string (in which
+/// case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `trader_id_ptr` is a valid C string pointer.
+/// - Assumes `name_ptr` is a valid C string pointer or NULL.
+/// - Assumes `out` is a valid pointer to memory for a [`MessageBus_API`].
+#[no_mangle]
+pub unsafe extern "C" fn msgbus_new(
+    trader_id_ptr: *const c_char,
+    name_ptr: *const c_char,
+    out: *mut MessageBus_API,
+) -> u8 {
+    let result = try_cstr_to_str(trader_id_ptr, "`TraderId`").and_then(|trader_id| {
+        let trader_id = TraderId::try_new(trader_id).map_err(|e| e.to_string())?;
+        let name = try_optional_cstr_to_str(name_ptr, "message bus name")?;
+        Ok(MessageBus_API(Box::new(MessageBus::new(
+            trader_id,
+            name.map(ToString::to_string),
+        ))))
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[no_mangle]
+pub extern "C" fn msgbus_drop(bus: MessageBus_API) {
+    drop(bus); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn msgbus_sent_count(bus: &MessageBus_API) -> u64 {
+    bus.sent_count
+}
+
+#[no_mangle]
+pub extern "C" fn msgbus_req_count(bus: &MessageBus_API) -> u64 {
+    bus.req_count
+}
+
+#[no_mangle]
+pub extern "C" fn msgbus_res_count(bus: &MessageBus_API) -> u64 {
+    bus.res_count
+}
+
+#[no_mangle]
+pub extern "C" fn msgbus_pub_count(bus: &MessageBus_API) -> u64 {
+    bus.pub_count
+}
+
+/// Registers the C `callback` to receive messages at the `endpoint` address.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// a string is invalid or the endpoint was already registered.
+///
+/// # Safety
+///
+/// - Assumes `endpoint_ptr` is a valid C string pointer.
+/// - Assumes `handler_id_ptr` is a valid C string pointer.
+/// - Assumes `context` remains valid until the endpoint is deregistered.
+#[no_mangle]
+pub unsafe extern "C" fn msgbus_register(
+    bus: &mut MessageBus_API,
+    endpoint_ptr: *const c_char,
+    handler_id_ptr: *const c_char,
+    callback: MessageCallback,
+    context: *mut c_void,
+) -> u8 {
+    status(
+        try_cstr_to_str(endpoint_ptr, "endpoint").and_then(|endpoint| {
+            let handler = c_message_handler(handler_id_ptr, callback, context)?;
+            bus.register(endpoint, handler).map_err(|e| e.to_string())
+        }),
+    )
+}
+
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `endpoint_ptr` is not a valid C string or no handler was registered at the endpoint.
+///
+/// # Safety
+///
+/// - Assumes `endpoint_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn msgbus_deregister(
+    bus: &mut MessageBus_API,
+    endpoint_ptr: *const c_char,
+) -> u8 {
+    status(
+        try_cstr_to_str(endpoint_ptr, "endpoint")
+            .and_then(|endpoint| bus.deregister(endpoint).map_err(|e| e.to_string())),
+    )
+}
+
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `endpoint_ptr` is not a valid C string or no handler was registered at the endpoint.
+///
+/// # Safety
+///
+/// - Assumes `endpoint_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn msgbus_send(
+    bus: &mut MessageBus_API,
+    endpoint_ptr: *const c_char,
+    msg: &Message,
+) -> u8 {
+    status(
+        try_cstr_to_str(endpoint_ptr, "endpoint")
+            .and_then(|endpoint| bus.send(endpoint, msg).map_err(|e| e.to_string())),
+    )
+}
+
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// a string is invalid or the request was not handled.
+///
+/// # Safety
+///
+/// - Assumes `endpoint_ptr` is a valid C string pointer.
+/// - Assumes `handler_id_ptr` is a valid C string pointer.
+/// - Assumes `context` remains valid until the response is received.
+#[no_mangle]
+pub unsafe extern "C" fn msgbus_request(
+    bus: &mut MessageBus_API,
+    endpoint_ptr: *const c_char,
+    request: &Message,
+    handler_id_ptr: *const c_char,
+    callback: MessageCallback,
+    context: *mut c_void,
+) -> u8 {
+    status(
+        try_cstr_to_str(endpoint_ptr, "endpoint").and_then(|endpoint| {
+            let handler = c_message_handler(handler_id_ptr, callback, context)?;
+            bus.request(endpoint, request, handler)
+                .map_err(|e| e.to_string())
+        }),
+    )
+}
+
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// the response was not handled.
+#[no_mangle]
+pub extern "C" fn msgbus_response(bus: &mut MessageBus_API, response: &Message) -> u8 {
+    status(bus.response(response))
+}
+
+/// Subscribes the C `callback` to the `topic`, which may include wildcard characters.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `topic_ptr` or `handler_id_ptr` is not a valid C string.
+///
+/// # Safety
+///
+/// - Assumes `topic_ptr` is a valid C string pointer.
+/// - Assumes `handler_id_ptr` is a valid C string pointer.
+/// - Assumes `context` remains valid until the handler is unsubscribed.
+#[no_mangle]
+pub unsafe extern "C" fn msgbus_subscribe(
+    bus: &mut MessageBus_API,
+    topic_ptr: *const c_char,
+    handler_id_ptr: *const c_char,
+    callback: MessageCallback,
+    context: *mut c_void,
+    priority: u8,
+) -> u8 {
+    status(try_cstr_to_str(topic_ptr, "topic").and_then(|topic| {
+        let handler = c_message_handler(handler_id_ptr, callback, context)?;
+        bus.subscribe(topic, handler, priority);
+        Ok(())
+    }))
+}
+
+/// Writes 1 to `out` if unsubscribed, or 0 if no such subscription existed.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `topic_ptr` or `handler_id_ptr` is not a valid C string (in which case nothing is
+/// written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `topic_ptr` is a valid C string pointer.
+/// - Assumes `handler_id_ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a `u8`.
+#[no_mangle]
+pub unsafe extern "C" fn msgbus_unsubscribe(
+    bus: &mut MessageBus_API,
+    topic_ptr: *const c_char,
+    handler_id_ptr: *const c_char,
+    out: *mut u8,
+) -> u8 {
+    let result = try_cstr_to_str(topic_ptr, "topic").and_then(|topic| {
+        let handler = handler_key(handler_id_ptr)?;
+        Ok(u8::from(bus.unsubscribe(topic, &handler)))
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Writes 1 to `out` if the handler is subscribed to the `topic`, otherwise 0.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `topic_ptr` or `handler_id_ptr` is not a valid C string (in which case nothing is
+/// written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `topic_ptr` is a valid C string pointer.
+/// - Assumes `handler_id_ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a `u8`.
+#[no_mangle]
+pub unsafe extern "C" fn msgbus_is_subscribed(
+    bus: &MessageBus_API,
+    topic_ptr: *const c_char,
+    handler_id_ptr: *const c_char,
+    out: *mut u8,
+) -> u8 {
+    let result = try_cstr_to_str(topic_ptr, "topic").and_then(|topic| {
+        let handler = handler_key(handler_id_ptr)?;
+        Ok(u8::from(bus.is_subscribed(topic, &handler)))
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Writes 1 to `out` if there are subscribers matching the `pattern`, otherwise 0.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `pattern_ptr` is not a valid C string (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `pattern_ptr` is a valid C string pointer or NULL (for all topics).
+/// - Assumes `out` is a valid pointer to memory for a `u8`.
+#[no_mangle]
+pub unsafe extern "C" fn msgbus_has_subscribers(
+    bus: &MessageBus_API,
+    pattern_ptr: *const c_char,
+    out: *mut u8,
+) -> u8 {
+    let result = try_optional_cstr_to_str(pattern_ptr, "pattern")
+        .map(|pattern| u8::from(bus.has_subscribers(pattern)));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `topic_ptr` is not a valid C string.
+///
+/// # Safety
+///
+/// - Assumes `topic_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn msgbus_publish(
+    bus: &mut MessageBus_API,
+    topic_ptr: *const c_char,
+    msg: &Message,
+) -> u8 {
+    status(try_cstr_to_str(topic_ptr, "topic").map(|topic| bus.publish(topic, msg)))
+}
+
+/// Writes 1 to `out` if the `topic` matches the `pattern`, otherwise 0.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `topic_ptr` or `pattern_ptr` is not a valid C string (in which case nothing is written
+/// to `out`).
+///
+/// # Safety
+///
+/// - Assumes `topic_ptr` is a valid C string pointer.
+/// - Assumes `pattern_ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a `u8`.
+#[no_mangle]
+pub unsafe extern "C" fn msgbus_is_matching(
+    topic_ptr: *const c_char,
+    pattern_ptr: *const c_char,
+    out: *mut u8,
+) -> u8 {
+    let result = try_cstr_to_str(topic_ptr, "topic").and_then(|topic| {
+        let pattern = try_cstr_to_str(pattern_ptr, "pattern")?;
+        Ok(u8::from(is_matching(topic, pattern)))
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, mem::MaybeUninit};
+
+    use nautilus_core::{ffi_error::last_error, uuid::UUID4};
+
+    use super::*;
+
+    unsafe extern "C" fn count_messages(context: *mut c_void, _msg: *const Message) {
+        *(context as *mut u64) += 1;
+    }
+
+    #[test]
+    fn test_c_api_subscribe_and_publish() {
+        let trader_id = CString::new("TRADER-001").unwrap();
+        let topic = CString::new("data.*").unwrap();
+        let handler_id = CString::new("handler").unwrap();
+        let publish_topic = CString::new("data.quotes").unwrap();
+        let mut count: u64 = 0;
+        let msg = Message::Event {
+            id: UUID4::new(),
+            ts_init: 0,
+            ts_event: 0,
+        };
+
+        unsafe {
+            let mut bus = MaybeUninit::<MessageBus_API>::uninit();
+            assert_eq!(
+                msgbus_new(trader_id.as_ptr(), std::ptr::null(), bus.as_mut_ptr()),
+                FFI_OK
+            );
+            let mut bus = bus.assume_init();
+            assert_eq!(
+                msgbus_subscribe(
+                    &mut bus,
+                    topic.as_ptr(),
+                    handler_id.as_ptr(),
+                    count_messages,
+                    &mut count as *mut u64 as *mut c_void,
+                    0,
+                ),
+                FFI_OK
+            );
+            assert_eq!(
+                msgbus_publish(&mut bus, publish_topic.as_ptr(), &msg),
+                FFI_OK
+            );
+
+            let mut out = 0;
+            assert_eq!(
+                msgbus_is_subscribed(&bus, topic.as_ptr(), handler_id.as_ptr(), &mut out),
+                FFI_OK
+            );
+            assert_eq!(out, 1);
+            assert_eq!(
+                msgbus_unsubscribe(&mut bus, topic.as_ptr(), handler_id.as_ptr(), &mut out),
+                FFI_OK
+            );
+            assert_eq!(out, 1);
+            assert_eq!(
+                msgbus_publish(&mut bus, publish_topic.as_ptr(), &msg),
+                FFI_OK
+            );
+
+            assert_eq!(msgbus_pub_count(&bus), 2);
+            msgbus_drop(bus);
+        }
+
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_c_api_is_matching() {
+        let topic = CString::new("data.quotes.BINANCE").unwrap();
+        let pattern = CString::new("data.*.BINANCE").unwrap();
+        let mut out = 0;
+
+        assert_eq!(
+            unsafe { msgbus_is_matching(topic.as_ptr(), pattern.as_ptr(), &mut out) },
+            FFI_OK
+        );
+        assert_eq!(out, 1);
+    }
+
+    #[test]
+    fn test_c_api_new_with_invalid_trader_id() {
+        let trader_id = CString::new("TRADER").unwrap();
+        let mut bus = MaybeUninit::<MessageBus_API>::uninit();
+
+        let status = unsafe { msgbus_new(trader_id.as_ptr(), std::ptr::null(), bus.as_mut_ptr()) };
+
+        assert_eq!(status, FFI_ERROR);
+        assert!(last_error().is_some());
+    }
+
+    #[test]
+    fn test_c_api_publish_with_null_topic() {
+        let trader_id = CString::new("TRADER-001").unwrap();
+        let msg = Message::Event {
+            id: UUID4::new(),
+            ts_init: 0,
+            ts_event: 0,
+        };
+
+        unsafe {
+            let mut bus = MaybeUninit::<MessageBus_API>::uninit();
+            msgbus_new(trader_id.as_ptr(), std::ptr::null(), bus.as_mut_ptr());
+            let mut bus = bus.assume_init();
+
+            assert_eq!(msgbus_publish(&mut bus, std::ptr::null(), &msg), FFI_ERROR);
+            assert_eq!(
+                last_error(),
+                Some("invalid topic string, `ptr` was NULL".to_string())
+            );
+            msgbus_drop(bus);
+        }
+    }
+}
@@ -0,0 +1,404 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    sync::mpsc::RecvTimeoutError,
+    thread::{self, JoinHandle},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+use redis::{Client, Connection, Pipeline};
+
+use crate::{
+    channel::{bounded, BoundedReceiver, BoundedSender, OverflowPolicy},
+    msgbus::{Message, MessagePublisher},
+};
+
+/// The default base stream key for published messages.
+const DEFAULT_STREAM: &str = "stream";
+
+/// Configuration for a [`RedisStreamPublisher`].
+#[derive(Clone, Debug)]
+pub struct RedisStreamConfig {
+    /// The Redis connection URL, e.g. `redis://localhost:6379`.
+    pub url: String,
+    /// The base stream key (defaults to `stream`).
+    pub stream: Option<String>,
+    /// If the trader ID is prefixed to the stream key.
+    pub use_trader_id: bool,
+    /// If the instance ID is prefixed to the stream key.
+    pub use_instance_id: bool,
+    /// The interval (milliseconds) at which buffered messages are written. If `None`
+    /// then every message is written immediately.
+    pub buffer_interval_ms: Option<u64>,
+    /// The maximum number of buffered messages before a write is forced.
+    pub max_buffer_size: usize,
+    /// The age (minutes) beyond which messages are trimmed from the stream. If `None`
+    /// then the stream is never trimmed.
+    pub autotrim_mins: Option<u64>,
+    /// The maximum number of messages queued for the writer thread.
+    pub queue_capacity: usize,
+    /// The behaviour of a publish when the writer queue is full (defaults to blocking
+    /// until there is room, so no messages are lost).
+    pub overflow_policy: OverflowPolicy,
+}
+
+impl Default for RedisStreamConfig {
+    fn default() -> Self {
+        Self {
+            url: String::from("redis://127.0.0.1:6379"),
+            stream: None,
+            use_trader_id: true,
+            use_instance_id: false,
+            buffer_interval_ms: None,
+            max_buffer_size: 1_000,
+            autotrim_mins: None,
+            queue_capacity: 10_000,
+            overflow_policy: OverflowPolicy::Block,
+        }
+    }
+}
+
+impl RedisStreamConfig {
+    /// Returns the stream key for the given trader and instance IDs, joining the
+    /// enabled segments with `:`.
+    #[must_use]
+    pub fn stream_key(&self, trader_id: &TraderId, instance_id: &UUID4) -> String {
+        let mut segments: Vec<String> = Vec::new();
+        if self.use_trader_id {
+            segments.push(format!("trader-{trader_id}"));
+        }
+        if self.use_instance_id {
+            segments.push(instance_id.to_string());
+        }
+        segments.push(
+            self.stream
+                .clone()
+                .unwrap_or_else(|| DEFAULT_STREAM.to_string()),
+        );
+        segments.join(":")
+    }
+}
+
+/// Represents a message serialized for the stream, along with its topic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct StreamEntry {
+    topic: String,
+    payload: String,
+}
+
+/// Provides a [`MessagePublisher`] which writes messages to a Redis stream.
+///
+/// Messages are serialized to JSON on the calling thread, then sent over a bounded MPSC
+/// channel to a separate thread which buffers them and writes each batch with a single
+/// pipelined round trip (so publishing does not wait on each write). If Redis falls behind
+/// and the queue fills, the configured [`OverflowPolicy`] applies, with any messages dropped
+/// reported from the writer thread.
+pub struct RedisStreamPublisher {
+    /// The stream key messages are written to.
+    pub stream_key: String,
+    tx: Option<BoundedSender<StreamEntry>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl RedisStreamPublisher {
+    /// Initializes a new `RedisStreamPublisher` instance.
+    ///
+    /// # Errors
+    ///
+    /// - If the `config.url` is not a valid Redis connection URL.
+    /// - If the initial connection to Redis fails.
+    ///
+    /// # Panics
+    ///
+    /// - If `config.queue_capacity` is zero.
+    pub fn new(
+        trader_id: &TraderId,
+        instance_id: &UUID4,
+        config: RedisStreamConfig,
+    ) -> Result<Self, redis::RedisError> {
+        let stream_key = config.stream_key(trader_id, instance_id);
+        let client = Client::open(config.url.as_str())?;
+        let conn = client.get_connection()?;
+
+        let (tx, rx) = bounded::<StreamEntry>(config.queue_capacity, config.overflow_policy);
+        let key = stream_key.clone();
+        let handle = thread::spawn(move || Self::handle_messages(conn, key, config, rx));
+
+        Ok(Self {
+            stream_key,
+            tx: Some(tx),
+            handle: Some(handle),
+        })
+    }
+
+    fn handle_messages(
+        mut conn: Connection,
+        stream_key: String,
+        config: RedisStreamConfig,
+        rx: BoundedReceiver<StreamEntry>,
+    ) {
+        let interval = config.buffer_interval_ms.map(Duration::from_millis);
+        let mut buffer: Vec<StreamEntry> = Vec::with_capacity(config.max_buffer_size);
+        let mut last_write = Instant::now();
+        let mut dropped = 0;
+
+        loop {
+            let received = match interval {
+                Some(interval) => {
+                    let timeout = interval.saturating_sub(last_write.elapsed());
+                    rx.recv_timeout(timeout)
+                }
+                None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            let is_disconnected = match received {
+                Ok(entry) => {
+                    buffer.push(entry);
+                    false
+                }
+                Err(RecvTimeoutError::Timeout) => false,
+                Err(RecvTimeoutError::Disconnected) => true,
+            };
+
+            if is_disconnected
+                || should_write(
+                    &buffer,
+                    interval,
+                    last_write.elapsed(),
+                    config.max_buffer_size,
+                )
+            {
+                Self::write(&mut conn, &stream_key, &buffer, config.autotrim_mins);
+                buffer.clear();
+                last_write = Instant::now();
+                dropped = report_dropped(&stream_key, dropped, rx.metrics().dropped);
+            }
+
+            if is_disconnected {
+                break;
+            }
+        }
+    }
+
+    fn write(
+        conn: &mut Connection,
+        stream_key: &str,
+        buffer: &[StreamEntry],
+        autotrim_mins: Option<u64>,
+    ) {
+        if buffer.is_empty() {
+            return;
+        }
+
+        let mut pipe = Pipeline::with_capacity(buffer.len() + 1);
+        for entry in buffer {
+            pipe.cmd("XADD")
+                .arg(stream_key)
+                .arg("*")
+                .arg("topic")
+                .arg(&entry.topic)
+                .arg("payload")
+                .arg(&entry.payload)
+                .ignore();
+        }
+
+        if let Some(autotrim_mins) = autotrim_mins {
+            // Stream entry IDs are prefixed with UNIX milliseconds
+            pipe.cmd("XTRIM")
+                .arg(stream_key)
+                .arg("MINID")
+                .arg("~")
+                .arg(min_stream_id_ms(SystemTime::now(), autotrim_mins))
+                .ignore();
+        }
+
+        if let Err(e) = pipe.query::<()>(conn) {
+            eprintln!("Error writing to Redis stream '{stream_key}': {e}");
+        }
+    }
+
+    /// Flushes any buffered messages and stops the writer thread.
+    pub fn close(&mut self) {
+        // Dropping the sender hangs up the channel, ending the writer loop
+        self.tx = None;
+        if let Some(handle) = self.handle.take() {
+            if handle.join().is_err() {
+                eprintln!("Error joining Redis stream writer thread");
+            }
+        }
+    }
+}
+
+impl MessagePublisher for RedisStreamPublisher {
+    fn publish(&self, topic: &str, msg: &Message) {
+        let tx = match &self.tx {
+            Some(tx) => tx,
+            None => {
+                eprintln!("Cannot publish to Redis stream: publisher closed");
+                return;
+            }
+        };
+
+        let entry = StreamEntry {
+            topic: topic.to_string(),
+            payload: serde_json::to_string(msg).expect("Error serializing message"),
+        };
+        if let Err(e) = tx.send(entry) {
+            eprintln!("Error sending message to Redis stream writer: {e}");
+        }
+    }
+}
+
+impl Drop for RedisStreamPublisher {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// Returns whether the buffered messages should be written now.
+fn should_write(
+    buffer: &[StreamEntry],
+    interval: Option<Duration>,
+    elapsed: Duration,
+    max_buffer_size: usize,
+) -> bool {
+    if buffer.is_empty() {
+        return false;
+    }
+
+    match interval {
+        Some(interval) => elapsed >= interval || buffer.len() >= max_buffer_size,
+        None => true,
+    }
+}
+
+/// Reports any messages dropped from the writer queue since the `previous` count of
+/// dropped messages, returning the `current` count.
+fn report_dropped(stream_key: &str, previous: u64, current: u64) -> u64 {
+    if current > previous {
+        eprintln!(
+            "Dropped {} message(s) for Redis stream '{stream_key}' as the queue was full \
+            ({current} total)",
+            current - previous,
+        );
+    }
+    current
+}
+
+/// Returns the minimum stream entry ID (UNIX milliseconds) to retain when trimming
+/// entries older than `autotrim_mins`.
+fn min_stream_id_ms(now: SystemTime, autotrim_mins: u64) -> u64 {
+    let now_ms = now
+        .duration_since(UNIX_EPOCH)
+        .expect("System time before UNIX epoch")
+        .as_millis() as u64;
+    now_ms.saturating_sub(autotrim_mins * 60 * 1_000)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn entry() -> StreamEntry {
+        StreamEntry {
+            topic: String::from("events.order"),
+            payload: String::from("{}"),
+        }
+    }
+
+    #[rstest]
+    #[case(true, false, None, "trader-TRADER-001:stream")]
+    #[case(false, false, Some("events"), "events")]
+    #[case(
+        true,
+        true,
+        Some("events"),
+        "trader-TRADER-001:6ba7b810-9dad-11d1-80b4-00c04fd430c8:events"
+    )]
+    fn test_stream_key(
+        #[case] use_trader_id: bool,
+        #[case] use_instance_id: bool,
+        #[case] stream: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        let config = RedisStreamConfig {
+            stream: stream.map(String::from),
+            use_trader_id,
+            use_instance_id,
+            ..Default::default()
+        };
+
+        let key = config.stream_key(
+            &TraderId::new("TRADER-001"),
+            &UUID4::from("6ba7b810-9dad-11d1-80b4-00c04fd430c8"),
+        );
+
+        assert_eq!(key, expected);
+    }
+
+    #[test]
+    fn test_default_config_does_not_drop_messages() {
+        assert_eq!(
+            RedisStreamConfig::default().overflow_policy,
+            OverflowPolicy::Block
+        );
+    }
+
+    #[test]
+    fn test_should_write_when_unbuffered() {
+        assert!(!should_write(&[], None, Duration::ZERO, 10));
+        assert!(should_write(&[entry()], None, Duration::ZERO, 10));
+    }
+
+    #[test]
+    fn test_should_write_when_buffered() {
+        let interval = Some(Duration::from_millis(100));
+
+        assert!(!should_write(
+            &[entry()],
+            interval,
+            Duration::from_millis(50),
+            10
+        ));
+        assert!(should_write(
+            &[entry()],
+            interval,
+            Duration::from_millis(100),
+            10
+        ));
+        assert!(should_write(
+            &[entry(), entry()],
+            interval,
+            Duration::ZERO,
+            2
+        ));
+    }
+
+    #[test]
+    fn test_min_stream_id_ms() {
+        let now = UNIX_EPOCH + Duration::from_millis(10 * 60 * 1_000 + 1);
+
+        assert_eq!(min_stream_id_ms(now, 10), 1);
+        assert_eq!(min_stream_id_ms(now, 20), 0);
+    }
+}
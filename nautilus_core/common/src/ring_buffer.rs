@@ -0,0 +1,339 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A lock-free single-producer, single-consumer ring buffer.
+//!
+//! Intended for handing ticks from a network thread to the processing thread without
+//! taking a lock per value. The producer and consumer each own their index, and only
+//! read the other side's index (with acquire ordering) when their cached copy shows the
+//! buffer as full or empty. The indices are padded onto separate cache lines so the two
+//! threads do not contend on the same line (false sharing).
+
+use std::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use nautilus_core::correctness;
+
+/// Aligns the value to 128 bytes, covering its cache line (64 bytes on most targets) and
+/// the adjacent line which x86 prefetches in pairs.
+#[repr(align(128))]
+#[derive(Debug)]
+struct CachePadded<T>(T);
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+struct Inner<T> {
+    /// The index of the next slot to read, only written by the consumer.
+    head: CachePadded<AtomicUsize>,
+    /// The index of the next slot to write, only written by the producer.
+    tail: CachePadded<AtomicUsize>,
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    mask: usize,
+}
+
+// SAFETY: A slot is only accessed by the producer before its index is published through
+// `tail`, and only by the consumer after, so values are moved between the two threads
+// but never shared.
+unsafe impl<T: Send> Send for Inner<T> {}
+unsafe impl<T: Send> Sync for Inner<T> {}
+
+impl<T> Inner<T> {
+    fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    fn slot(&self, index: usize) -> *mut MaybeUninit<T> {
+        self.slots[index & self.mask].get()
+    }
+}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        let mut head = *self.head.0.get_mut();
+        let tail = *self.tail.0.get_mut();
+        while head != tail {
+            // SAFETY: Slots between `head` and `tail` are initialized and not yet read
+            unsafe { (*self.slot(head)).assume_init_drop() };
+            head = head.wrapping_add(1);
+        }
+    }
+}
+
+/// Creates a ring buffer holding up to `capacity` values (rounded up to a power of two).
+///
+/// # Panics
+///
+/// - If `capacity` is zero, or greater than `usize::MAX / 2`.
+#[must_use]
+pub fn spsc_ring_buffer<T>(capacity: usize) -> (RingProducer<T>, RingConsumer<T>) {
+    correctness::u64_in_range_inclusive(capacity as u64, 1, (usize::MAX / 2) as u64, "capacity");
+    let capacity = capacity.next_power_of_two();
+    let inner = Arc::new(Inner {
+        head: CachePadded(AtomicUsize::new(0)),
+        tail: CachePadded(AtomicUsize::new(0)),
+        slots: (0..capacity)
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect(),
+        mask: capacity - 1,
+    });
+    (
+        RingProducer {
+            inner: inner.clone(),
+            tail: 0,
+            cached_head: 0,
+        },
+        RingConsumer {
+            inner,
+            head: 0,
+            cached_tail: 0,
+        },
+    )
+}
+
+/// The writing half of a [`spsc_ring_buffer`].
+pub struct RingProducer<T> {
+    inner: Arc<Inner<T>>,
+    tail: usize,
+    cached_head: usize,
+}
+
+impl<T> RingProducer<T> {
+    /// Pushes the `value` onto the buffer.
+    ///
+    /// # Errors
+    ///
+    /// - If the buffer is full, returning the `value`.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        let capacity = self.inner.capacity();
+        if self.tail.wrapping_sub(self.cached_head) == capacity {
+            self.cached_head = self.inner.head.load(Ordering::Acquire);
+            if self.tail.wrapping_sub(self.cached_head) == capacity {
+                return Err(value);
+            }
+        }
+        // SAFETY: The slot is outside the readable range until `tail` is published
+        unsafe { (*self.inner.slot(self.tail)).write(value) };
+        self.tail = self.tail.wrapping_add(1);
+        self.inner.tail.store(self.tail, Ordering::Release);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Returns the number of values in the buffer (which may already be out of date).
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tail
+            .wrapping_sub(self.inner.head.load(Ordering::Acquire))
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the consumer has been dropped.
+    #[must_use]
+    pub fn is_disconnected(&self) -> bool {
+        Arc::strong_count(&self.inner) == 1
+    }
+}
+
+/// The reading half of a [`spsc_ring_buffer`].
+pub struct RingConsumer<T> {
+    inner: Arc<Inner<T>>,
+    head: usize,
+    cached_tail: usize,
+}
+
+impl<T> RingConsumer<T> {
+    /// Pops the oldest value from the buffer, or `None` if it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.head == self.cached_tail {
+            self.cached_tail = self.inner.tail.load(Ordering::Acquire);
+            if self.head == self.cached_tail {
+                return None;
+            }
+        }
+        // SAFETY: The slot was initialized before `tail` was published, and is not
+        // written again until `head` is published past it
+        let value = unsafe { (*self.inner.slot(self.head)).assume_init_read() };
+        self.head = self.head.wrapping_add(1);
+        self.inner.head.store(self.head, Ordering::Release);
+        Some(value)
+    }
+
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Returns the number of values in the buffer (which may already be out of date).
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner
+            .tail
+            .load(Ordering::Acquire)
+            .wrapping_sub(self.head)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the producer has been dropped (values may still be buffered).
+    #[must_use]
+    pub fn is_disconnected(&self) -> bool {
+        Arc::strong_count(&self.inner) == 1
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(3, 4)]
+    #[case(1024, 1024)]
+    fn test_capacity_rounded_to_power_of_two(#[case] capacity: usize, #[case] expected: usize) {
+        let (producer, consumer) = spsc_ring_buffer::<u64>(capacity);
+
+        assert_eq!(producer.capacity(), expected);
+        assert_eq!(consumer.capacity(), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_capacity_panics() {
+        let _ = spsc_ring_buffer::<u64>(0);
+    }
+
+    #[test]
+    fn test_push_pop_in_order() {
+        let (mut producer, mut consumer) = spsc_ring_buffer(4);
+
+        assert!(consumer.is_empty());
+        for i in 0..4 {
+            producer.push(i).unwrap();
+        }
+
+        assert_eq!(producer.push(4), Err(4));
+        assert_eq!(consumer.len(), 4);
+        assert_eq!(consumer.pop(), Some(0));
+        producer.push(4).unwrap();
+        let values: Vec<_> = std::iter::from_fn(|| consumer.pop()).collect();
+        assert_eq!(values, vec![1, 2, 3, 4]);
+        assert_eq!(consumer.pop(), None);
+    }
+
+    #[test]
+    fn test_wraps_around() {
+        let (mut producer, mut consumer) = spsc_ring_buffer(2);
+
+        for i in 0..1_000 {
+            producer.push(i).unwrap();
+            assert_eq!(consumer.pop(), Some(i));
+        }
+
+        assert!(producer.is_empty());
+    }
+
+    #[test]
+    fn test_unread_values_dropped() {
+        struct Counted(Arc<AtomicUsize>);
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let drops = Arc::new(AtomicUsize::new(0));
+        let (mut producer, mut consumer) = spsc_ring_buffer(4);
+        for _ in 0..3 {
+            assert!(producer.push(Counted(drops.clone())).is_ok());
+        }
+        drop(consumer.pop());
+
+        assert!(!producer.is_disconnected());
+        drop(consumer);
+        assert!(producer.is_disconnected());
+        drop(producer);
+
+        assert_eq!(drops.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn test_transfer_between_threads() {
+        const COUNT: u64 = 100_000;
+        let (mut producer, mut consumer) = spsc_ring_buffer(64);
+
+        let handle = thread::spawn(move || {
+            for i in 0..COUNT {
+                let mut value = i;
+                while let Err(v) = producer.push(value) {
+                    value = v;
+                    std::hint::spin_loop();
+                }
+            }
+        });
+
+        let mut expected = 0;
+        while expected < COUNT {
+            match consumer.pop() {
+                Some(value) => {
+                    assert_eq!(value, expected);
+                    expected += 1;
+                }
+                None => std::hint::spin_loop(),
+            }
+        }
+        handle.join().unwrap();
+
+        assert!(consumer.is_disconnected());
+        assert_eq!(consumer.pop(), None);
+    }
+}
@@ -0,0 +1,443 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Configuration of the threads of a live node for latency-sensitive deployments.
+//!
+//! The Tokio runtime threads (which run the network I/O) and the hot-path processing
+//! thread can each be pinned to a set of cores, and given a nice value or a real-time
+//! `SCHED_FIFO` priority. Pinning and priorities are only supported on Linux, elsewhere
+//! a non-default [`ThreadConfig`] fails with [`RuntimeError::Unsupported`].
+
+use pyo3::{exceptions::PyRuntimeError, prelude::*};
+use thiserror::Error;
+use tracing::error;
+
+/// The range of nice values, from the highest to the lowest priority.
+const NICE_RANGE: std::ops::RangeInclusive<i32> = -20..=19;
+/// The range of `SCHED_FIFO` priorities, from the lowest to the highest priority.
+const REALTIME_PRIORITY_RANGE: std::ops::RangeInclusive<u8> = 1..=99;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RuntimeError {
+    #[error("Failed to pin thread to cores {cores:?}: {reason}")]
+    Affinity { cores: Vec<usize>, reason: String },
+    #[error("Failed to set thread priority: {0}")]
+    Priority(String),
+    #[error("Thread pinning and priorities are not supported on this platform")]
+    Unsupported,
+}
+
+/// Configuration for the core affinity and priority of a thread.
+#[pyclass]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ThreadConfig {
+    /// The cores the thread may run on (any core if empty).
+    pub cores: Vec<usize>,
+    /// The nice value of the thread, from -20 (highest priority) to 19. Lowering the
+    /// value below the current one usually requires `CAP_SYS_NICE`.
+    pub nice: Option<i32>,
+    /// The real-time `SCHED_FIFO` priority of the thread, from 1 to 99, which usually
+    /// requires `CAP_SYS_NICE`. Takes precedence over the `nice` value.
+    pub realtime_priority: Option<u8>,
+}
+
+impl ThreadConfig {
+    /// Returns true if the configuration leaves the thread unchanged.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self.cores.is_empty() && self.nice.is_none() && self.realtime_priority.is_none()
+    }
+
+    /// Applies the configuration to the calling thread.
+    ///
+    /// # Errors
+    ///
+    /// - If the cores or priority are invalid, or not permitted for the process.
+    /// - If the configuration is not the default and the platform is not Linux.
+    pub fn apply_to_current_thread(&self) -> Result<(), RuntimeError> {
+        if self.is_default() {
+            return Ok(());
+        }
+        if !self.cores.is_empty() {
+            sys::set_affinity(&self.cores)?;
+        }
+        if let Some(priority) = self.realtime_priority {
+            if !REALTIME_PRIORITY_RANGE.contains(&priority) {
+                return Err(RuntimeError::Priority(format!(
+                    "real-time priority {priority} not within {REALTIME_PRIORITY_RANGE:?}"
+                )));
+            }
+            sys::set_realtime_priority(priority)
+        } else if let Some(nice) = self.nice {
+            if !NICE_RANGE.contains(&nice) {
+                return Err(RuntimeError::Priority(format!(
+                    "nice value {nice} not within {NICE_RANGE:?}"
+                )));
+            }
+            sys::set_nice(nice)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Configuration for the threads of a live node.
+#[pyclass]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RuntimeConfig {
+    /// The number of Tokio worker threads (one per core if `None`).
+    pub worker_threads: Option<usize>,
+    /// The configuration applied to every Tokio runtime thread, including the blocking
+    /// pool threads.
+    pub io_threads: ThreadConfig,
+    /// The configuration applied to the hot-path processing thread.
+    pub hot_thread: ThreadConfig,
+}
+
+impl RuntimeConfig {
+    /// Returns a multi-threaded Tokio runtime builder with the worker thread count, and
+    /// which applies the `io_threads` configuration as each thread starts.
+    ///
+    /// A thread which cannot be configured still runs, with the error logged.
+    #[must_use]
+    pub fn runtime_builder(&self) -> tokio::runtime::Builder {
+        let mut builder = tokio::runtime::Builder::new_multi_thread();
+        builder.enable_all().thread_name("nautilus-io");
+        if let Some(worker_threads) = self.worker_threads {
+            builder.worker_threads(worker_threads);
+        }
+        if !self.io_threads.is_default() {
+            let io_threads = self.io_threads.clone();
+            builder.on_thread_start(move || {
+                if let Err(e) = io_threads.apply_to_current_thread() {
+                    error!("Error configuring runtime thread: {e}");
+                }
+            });
+        }
+        builder
+    }
+
+    /// Sets the shared Tokio runtime to be built with this configuration.
+    ///
+    /// Must be called before the shared runtime is first used, otherwise it has no effect.
+    pub fn init_runtime(&self) {
+        pyo3_asyncio::tokio::init(self.runtime_builder());
+    }
+
+    /// Applies the `hot_thread` configuration to the calling thread.
+    ///
+    /// # Errors
+    ///
+    /// - If the configuration cannot be applied, see [`ThreadConfig::apply_to_current_thread`].
+    pub fn apply_hot_thread(&self) -> Result<(), RuntimeError> {
+        self.hot_thread.apply_to_current_thread()
+    }
+}
+
+/// Returns the cores the calling thread may run on.
+///
+/// # Errors
+///
+/// - If the affinity cannot be read, or the platform is not Linux.
+pub fn current_thread_cores() -> Result<Vec<usize>, RuntimeError> {
+    sys::get_affinity()
+}
+
+#[cfg(target_os = "linux")]
+mod sys {
+    use std::{io, mem};
+
+    use super::RuntimeError;
+
+    pub fn set_affinity(cores: &[usize]) -> Result<(), RuntimeError> {
+        let affinity_error = |reason: String| RuntimeError::Affinity {
+            cores: cores.to_vec(),
+            reason,
+        };
+        // SAFETY: A zeroed `cpu_set_t` is an empty set, and `sched_setaffinity` only reads it
+        unsafe {
+            let mut set: libc::cpu_set_t = mem::zeroed();
+            for &core in cores {
+                if core >= libc::CPU_SETSIZE as usize {
+                    return Err(affinity_error(format!("core {core} beyond CPU_SETSIZE")));
+                }
+                libc::CPU_SET(core, &mut set);
+            }
+            // A pid of 0 applies to the calling thread
+            if libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set) != 0 {
+                return Err(affinity_error(io::Error::last_os_error().to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_affinity() -> Result<Vec<usize>, RuntimeError> {
+        // SAFETY: `sched_getaffinity` writes at most the size of the set
+        unsafe {
+            let mut set: libc::cpu_set_t = mem::zeroed();
+            if libc::sched_getaffinity(0, mem::size_of::<libc::cpu_set_t>(), &mut set) != 0 {
+                return Err(RuntimeError::Affinity {
+                    cores: Vec::new(),
+                    reason: io::Error::last_os_error().to_string(),
+                });
+            }
+            Ok((0..libc::CPU_SETSIZE as usize)
+                .filter(|&core| libc::CPU_ISSET(core, &set))
+                .collect())
+        }
+    }
+
+    pub fn set_nice(nice: i32) -> Result<(), RuntimeError> {
+        // SAFETY: Only the calling thread's priority is changed
+        unsafe {
+            // On Linux the nice value is per thread, identified by its thread ID
+            let tid = libc::syscall(libc::SYS_gettid) as libc::id_t;
+            if libc::setpriority(libc::PRIO_PROCESS, tid, nice) != 0 {
+                return Err(RuntimeError::Priority(format!(
+                    "nice value {nice}: {}",
+                    io::Error::last_os_error()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn set_realtime_priority(priority: u8) -> Result<(), RuntimeError> {
+        // SAFETY: Only the calling thread's scheduling policy is changed
+        unsafe {
+            let mut param: libc::sched_param = mem::zeroed();
+            param.sched_priority = i32::from(priority);
+            let rc = libc::pthread_setschedparam(libc::pthread_self(), libc::SCHED_FIFO, &param);
+            if rc != 0 {
+                return Err(RuntimeError::Priority(format!(
+                    "real-time priority {priority}: {}",
+                    io::Error::from_raw_os_error(rc)
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod sys {
+    use super::RuntimeError;
+
+    pub fn set_affinity(_cores: &[usize]) -> Result<(), RuntimeError> {
+        Err(RuntimeError::Unsupported)
+    }
+
+    pub fn get_affinity() -> Result<Vec<usize>, RuntimeError> {
+        Err(RuntimeError::Unsupported)
+    }
+
+    pub fn set_nice(_nice: i32) -> Result<(), RuntimeError> {
+        Err(RuntimeError::Unsupported)
+    }
+
+    pub fn set_realtime_priority(_priority: u8) -> Result<(), RuntimeError> {
+        Err(RuntimeError::Unsupported)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+fn to_pyruntime_err(e: RuntimeError) -> PyErr {
+    PyRuntimeError::new_err(e.to_string())
+}
+
+#[pymethods]
+impl ThreadConfig {
+    #[new]
+    #[pyo3(signature = (cores=vec![], nice=None, realtime_priority=None))]
+    fn py_new(cores: Vec<usize>, nice: Option<i32>, realtime_priority: Option<u8>) -> Self {
+        Self {
+            cores,
+            nice,
+            realtime_priority,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "cores")]
+    fn py_cores(&self) -> Vec<usize> {
+        self.cores.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "nice")]
+    fn py_nice(&self) -> Option<i32> {
+        self.nice
+    }
+
+    #[getter]
+    #[pyo3(name = "realtime_priority")]
+    fn py_realtime_priority(&self) -> Option<u8> {
+        self.realtime_priority
+    }
+
+    #[pyo3(name = "apply_to_current_thread")]
+    fn py_apply_to_current_thread(&self) -> PyResult<()> {
+        self.apply_to_current_thread().map_err(to_pyruntime_err)
+    }
+}
+
+#[pymethods]
+impl RuntimeConfig {
+    #[new]
+    #[pyo3(signature = (worker_threads=None, io_threads=None, hot_thread=None))]
+    fn py_new(
+        worker_threads: Option<usize>,
+        io_threads: Option<ThreadConfig>,
+        hot_thread: Option<ThreadConfig>,
+    ) -> Self {
+        Self {
+            worker_threads,
+            io_threads: io_threads.unwrap_or_default(),
+            hot_thread: hot_thread.unwrap_or_default(),
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "worker_threads")]
+    fn py_worker_threads(&self) -> Option<usize> {
+        self.worker_threads
+    }
+
+    #[getter]
+    #[pyo3(name = "io_threads")]
+    fn py_io_threads(&self) -> ThreadConfig {
+        self.io_threads.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "hot_thread")]
+    fn py_hot_thread(&self) -> ThreadConfig {
+        self.hot_thread.clone()
+    }
+
+    #[pyo3(name = "init_runtime")]
+    fn py_init_runtime(&self) {
+        self.init_runtime();
+    }
+
+    #[pyo3(name = "apply_hot_thread")]
+    fn py_apply_hot_thread(&self) -> PyResult<()> {
+        self.apply_hot_thread().map_err(to_pyruntime_err)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_default_config_is_noop() {
+        let config = RuntimeConfig::default();
+
+        assert!(config.io_threads.is_default());
+        assert_eq!(config.apply_hot_thread(), Ok(()));
+    }
+
+    #[rstest]
+    #[case(ThreadConfig { nice: Some(20), ..Default::default() })]
+    #[case(ThreadConfig { nice: Some(-21), ..Default::default() })]
+    #[case(ThreadConfig { realtime_priority: Some(0), ..Default::default() })]
+    #[case(ThreadConfig { realtime_priority: Some(100), ..Default::default() })]
+    fn test_invalid_priority(#[case] config: ThreadConfig) {
+        let result = thread::spawn(move || config.apply_to_current_thread())
+            .join()
+            .unwrap();
+
+        assert!(matches!(
+            result,
+            Err(RuntimeError::Priority(_) | RuntimeError::Unsupported)
+        ));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_pin_thread() {
+        let pinned = thread::spawn(|| {
+            let core = current_thread_cores().unwrap()[0];
+            let config = ThreadConfig {
+                cores: vec![core],
+                ..Default::default()
+            };
+            config.apply_to_current_thread().unwrap();
+            (core, current_thread_cores().unwrap())
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(pinned.1, vec![pinned.0]);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_pin_thread_to_invalid_core() {
+        let config = ThreadConfig {
+            cores: vec![libc::CPU_SETSIZE as usize],
+            ..Default::default()
+        };
+
+        let result = thread::spawn(move || config.apply_to_current_thread())
+            .join()
+            .unwrap();
+
+        assert!(matches!(result, Err(RuntimeError::Affinity { .. })));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_raise_nice_value() {
+        // Lowering the priority of a thread never requires privileges
+        let config = ThreadConfig {
+            nice: Some(19),
+            ..Default::default()
+        };
+
+        let result = thread::spawn(move || config.apply_to_current_thread())
+            .join()
+            .unwrap();
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_runtime_builder_applies_io_config() {
+        let config = RuntimeConfig {
+            worker_threads: Some(2),
+            io_threads: ThreadConfig {
+                nice: Some(19),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let runtime = config.runtime_builder().build().unwrap();
+        let result = runtime.block_on(async { tokio::spawn(async { 1 + 1 }).await });
+
+        assert_eq!(result.unwrap(), 2);
+    }
+}
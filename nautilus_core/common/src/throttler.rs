@@ -0,0 +1,375 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use nautilus_core::time::UnixNanos;
+
+use crate::{clock::Clock, timer::TimeEvent};
+
+/// Provides a generic throttler which limits the rate of messages sent to `output_send`
+/// to at most `limit` within any `interval_ns` window.
+///
+/// Messages over the limit are either buffered, and released from a timer once the
+/// window allows, or passed to `output_drop` if a drop handler is given. The risk
+/// engine uses throttlers for its order submit and modify rate limits.
+///
+/// The throttler sets a time alert named [`Throttler::timer_name`] on the clock passed
+/// to [`Throttler::send`], using the default handler of the clock, which must route the
+/// event back to [`Throttler::on_time_event`].
+pub struct Throttler<T> {
+    name: String,
+    limit: usize,
+    interval_ns: u64,
+    buffer: VecDeque<T>,
+    timestamps: VecDeque<UnixNanos>,
+    output_send: Box<dyn FnMut(T)>,
+    output_drop: Option<Box<dyn FnMut(T)>>,
+    is_limiting: bool,
+    recv_count: usize,
+    sent_count: usize,
+}
+
+impl<T> Throttler<T> {
+    /// Creates a new [`Throttler`] instance.
+    ///
+    /// # Panics
+    ///
+    /// - If `limit` or `interval_ns` is zero.
+    #[must_use]
+    pub fn new(
+        name: &str,
+        limit: usize,
+        interval_ns: u64,
+        output_send: Box<dyn FnMut(T)>,
+        output_drop: Option<Box<dyn FnMut(T)>>,
+    ) -> Self {
+        assert!(limit > 0, "`limit` was zero");
+        assert!(interval_ns > 0, "`interval_ns` was zero");
+
+        Self {
+            name: name.to_string(),
+            limit,
+            interval_ns,
+            buffer: VecDeque::new(),
+            timestamps: VecDeque::with_capacity(limit),
+            output_send,
+            output_drop,
+            is_limiting: false,
+            recv_count: 0,
+            sent_count: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the name of the time alert used to release the throttler.
+    #[must_use]
+    pub fn timer_name(&self) -> String {
+        format!("{}-DEQUE", self.name)
+    }
+
+    /// Returns the number of messages currently buffered.
+    #[must_use]
+    pub fn qsize(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// If the throttler is currently limiting messages.
+    #[must_use]
+    pub fn is_limiting(&self) -> bool {
+        self.is_limiting
+    }
+
+    /// Returns the number of messages received by the throttler.
+    #[must_use]
+    pub fn recv_count(&self) -> usize {
+        self.recv_count
+    }
+
+    /// Returns the number of messages sent by the throttler.
+    #[must_use]
+    pub fn sent_count(&self) -> usize {
+        self.sent_count
+    }
+
+    /// Returns the percentage of the rate limit used at `ts_now`, from 0.0 to 1.0.
+    #[must_use]
+    pub fn used(&self, ts_now: UnixNanos) -> f64 {
+        let oldest = match self.timestamps.back() {
+            Some(&oldest) => oldest,
+            None => return 0.0,
+        };
+        let spread = ts_now.saturating_sub(oldest);
+        let diff = self.interval_ns.saturating_sub(spread);
+        let used = diff as f64 / self.interval_ns as f64;
+
+        if self.timestamps.len() < self.limit {
+            used * (self.timestamps.len() as f64 / self.limit as f64)
+        } else {
+            used
+        }
+    }
+
+    /// Sends the given `msg` if the rate limit allows, otherwise buffers or drops it.
+    pub fn send<C: Clock>(&mut self, msg: T, clock: &mut C) {
+        self.recv_count += 1;
+
+        let ts_now = clock.timestamp_ns();
+        if self.is_limiting || self.delta_next(ts_now) > 0 {
+            self.limit_msg(msg, clock, ts_now);
+        } else {
+            self.send_msg(msg, ts_now);
+        }
+    }
+
+    /// Handles the throttler time alert, releasing buffered messages (or resuming sends
+    /// when dropping).
+    pub fn on_time_event<C: Clock>(&mut self, _event: &TimeEvent, clock: &mut C) {
+        if self.output_drop.is_some() {
+            self.is_limiting = false;
+            return;
+        }
+
+        let ts_now = clock.timestamp_ns();
+        while let Some(msg) = self.buffer.pop_front() {
+            if self.delta_next(ts_now) > 0 {
+                self.buffer.push_front(msg);
+                self.set_timer(clock, ts_now);
+                return;
+            }
+            self.send_msg(msg, ts_now);
+        }
+        self.is_limiting = false;
+    }
+
+    /// Resets the throttler, clearing all buffered messages and counts.
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.timestamps.clear();
+        self.is_limiting = false;
+        self.recv_count = 0;
+        self.sent_count = 0;
+    }
+
+    /// Returns the nanoseconds from `ts_now` until the next message may be sent.
+    fn delta_next(&self, ts_now: UnixNanos) -> u64 {
+        if self.timestamps.len() < self.limit {
+            return 0;
+        }
+        let oldest = self.timestamps.back().copied().unwrap_or_default();
+        (oldest + self.interval_ns).saturating_sub(ts_now)
+    }
+
+    fn limit_msg<C: Clock>(&mut self, msg: T, clock: &mut C, ts_now: UnixNanos) {
+        match self.output_drop.as_mut() {
+            Some(output_drop) => output_drop(msg),
+            None => self.buffer.push_back(msg),
+        }
+
+        if !self.is_limiting {
+            self.set_timer(clock, ts_now);
+            self.is_limiting = true;
+        }
+    }
+
+    fn set_timer<C: Clock>(&self, clock: &mut C, ts_now: UnixNanos) {
+        clock.set_time_alert_ns(self.timer_name(), ts_now + self.delta_next(ts_now), None);
+    }
+
+    fn send_msg(&mut self, msg: T, ts_now: UnixNanos) {
+        self.timestamps.push_front(ts_now);
+        self.timestamps.truncate(self.limit);
+        (self.output_send)(msg);
+        self.sent_count += 1;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::clock::TestClock;
+
+    type Store = Rc<RefCell<Vec<u32>>>;
+
+    fn test_clock() -> TestClock {
+        let mut clock = TestClock::new();
+        clock.register_default_handler(Box::new(|_| {}));
+        clock
+    }
+
+    fn buffering_throttler(sent: &Store) -> Throttler<u32> {
+        let sent = sent.clone();
+        Throttler::new(
+            "BUFFER",
+            5,
+            1_000_000_000,
+            Box::new(move |msg| sent.borrow_mut().push(msg)),
+            None,
+        )
+    }
+
+    fn dropping_throttler(sent: &Store, dropped: &Store) -> Throttler<u32> {
+        let sent = sent.clone();
+        let dropped = dropped.clone();
+        Throttler::new(
+            "DROPPER",
+            5,
+            1_000_000_000,
+            Box::new(move |msg| sent.borrow_mut().push(msg)),
+            Some(Box::new(move |msg| dropped.borrow_mut().push(msg))),
+        )
+    }
+
+    fn advance(throttler: &mut Throttler<u32>, clock: &mut TestClock, to_time_ns: UnixNanos) {
+        for event in clock.advance_time(to_time_ns, true) {
+            if event.name.as_str() == throttler.timer_name() {
+                throttler.on_time_event(&event, clock);
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_send_within_limit() {
+        let sent = Store::default();
+        let mut clock = test_clock();
+        let mut throttler = buffering_throttler(&sent);
+
+        for i in 0..5 {
+            throttler.send(i, &mut clock);
+        }
+
+        assert_eq!(*sent.borrow(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(throttler.qsize(), 0);
+        assert!(!throttler.is_limiting());
+        assert_eq!(throttler.used(0), 1.0);
+        assert_eq!(clock.timer_count(), 0);
+    }
+
+    #[rstest]
+    fn test_used_when_not_warm() {
+        let sent = Store::default();
+        let mut clock = test_clock();
+        let mut throttler = buffering_throttler(&sent);
+
+        assert_eq!(throttler.used(0), 0.0);
+        throttler.send(0, &mut clock);
+        assert_eq!(throttler.used(0), 0.2);
+        assert_eq!(throttler.used(500_000_000), 0.1);
+    }
+
+    #[rstest]
+    fn test_buffers_then_releases_on_timer() {
+        let sent = Store::default();
+        let mut clock = test_clock();
+        let mut throttler = buffering_throttler(&sent);
+
+        for i in 0..7 {
+            throttler.send(i, &mut clock);
+        }
+
+        assert_eq!(sent.borrow().len(), 5);
+        assert_eq!(throttler.qsize(), 2);
+        assert!(throttler.is_limiting());
+        assert_eq!(clock.timer_names(), vec!["BUFFER-DEQUE"]);
+
+        advance(&mut throttler, &mut clock, 1_000_000_000);
+
+        assert_eq!(*sent.borrow(), vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(throttler.qsize(), 0);
+        assert!(!throttler.is_limiting());
+        assert_eq!(throttler.recv_count(), 7);
+        assert_eq!(throttler.sent_count(), 7);
+    }
+
+    #[rstest]
+    fn test_buffered_release_respects_window() {
+        let sent = Store::default();
+        let mut clock = test_clock();
+        let mut throttler = buffering_throttler(&sent);
+
+        for i in 0..5 {
+            clock.set_time(i * 100_000_000);
+            throttler.send(i as u32, &mut clock);
+        }
+        for i in 5..8 {
+            throttler.send(i, &mut clock);
+        }
+        assert_eq!(sent.borrow().len(), 5);
+
+        // Window allows one message per 100ms as the earliest sends expire
+        advance(&mut throttler, &mut clock, 1_000_000_000);
+        assert_eq!(*sent.borrow(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(throttler.qsize(), 2);
+
+        advance(&mut throttler, &mut clock, 1_100_000_000);
+        assert_eq!(*sent.borrow(), vec![0, 1, 2, 3, 4, 5, 6]);
+
+        advance(&mut throttler, &mut clock, 1_200_000_000);
+        assert_eq!(*sent.borrow(), vec![0, 1, 2, 3, 4, 5, 6, 7]);
+        assert!(!throttler.is_limiting());
+    }
+
+    #[rstest]
+    fn test_drops_then_resumes_on_timer() {
+        let sent = Store::default();
+        let dropped = Store::default();
+        let mut clock = test_clock();
+        let mut throttler = dropping_throttler(&sent, &dropped);
+
+        for i in 0..7 {
+            throttler.send(i, &mut clock);
+        }
+
+        assert_eq!(*sent.borrow(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(*dropped.borrow(), vec![5, 6]);
+        assert_eq!(throttler.qsize(), 0);
+        assert!(throttler.is_limiting());
+
+        advance(&mut throttler, &mut clock, 1_000_000_000);
+        throttler.send(7, &mut clock);
+
+        assert!(!throttler.is_limiting());
+        assert_eq!(dropped.borrow().len(), 2);
+        assert_eq!(*sent.borrow(), vec![0, 1, 2, 3, 4, 7]);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let sent = Store::default();
+        let mut clock = test_clock();
+        let mut throttler = buffering_throttler(&sent);
+
+        for i in 0..6 {
+            throttler.send(i, &mut clock);
+        }
+        throttler.reset();
+
+        assert_eq!(throttler.qsize(), 0);
+        assert_eq!(throttler.recv_count(), 0);
+        assert!(!throttler.is_limiting());
+        assert_eq!(throttler.used(0), 0.0);
+    }
+}
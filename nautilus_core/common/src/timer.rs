@@ -16,23 +16,29 @@
 use std::{
     cmp::Ordering,
     fmt::{Display, Formatter},
-    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering as AtomicOrdering},
+        Arc,
+    },
+    time::Duration,
 };
 
 use nautilus_core::{
     correctness,
-    time::{TimedeltaNanos, UnixNanos},
+    time::{duration_since_unix_epoch, TimedeltaNanos, UnixNanos},
     uuid::UUID4,
 };
-use pyo3::ffi;
+use pyo3::{ffi, prelude::*};
+use tokio::sync::oneshot;
 
 #[repr(C)]
+#[pyclass]
 #[derive(Clone, Debug)]
 #[allow(clippy::redundant_allocation)] // C ABI compatibility
 /// Represents a time event occurring at the event timestamp.
 pub struct TimeEvent {
     /// The event name.
-    pub name: Box<Rc<String>>,
+    pub name: Box<Arc<String>>,
     /// The event ID.
     pub event_id: UUID4,
     /// The message category
@@ -42,16 +48,31 @@ pub struct TimeEvent {
 }
 
 impl TimeEvent {
-    #[must_use]
-    pub fn new(name: String, event_id: UUID4, ts_event: UnixNanos, ts_init: UnixNanos) -> Self {
-        correctness::valid_string(&name, "`TimeEvent` name");
-
-        TimeEvent {
-            name: Box::new(Rc::new(name)),
+    /// Returns a new [`TimeEvent`], or an error if `name` is not a valid string.
+    pub fn try_new(
+        name: String,
+        event_id: UUID4,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Result<Self, String> {
+        correctness::check_valid_string(&name, "`TimeEvent` name")?;
+
+        Ok(TimeEvent {
+            name: Box::new(Arc::new(name)),
             event_id,
             ts_event,
             ts_init,
-        }
+        })
+    }
+
+    /// Returns a new [`TimeEvent`].
+    ///
+    /// # Panics
+    ///
+    /// - If `name` is not a valid string.
+    #[must_use]
+    pub fn new(name: String, event_id: UUID4, ts_event: UnixNanos, ts_init: UnixNanos) -> Self {
+        Self::try_new(name, event_id, ts_event, ts_init).unwrap_or_else(|e| panic!("{e}"))
     }
 }
 
@@ -71,6 +92,44 @@ impl PartialEq for TimeEvent {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[pymethods]
+impl TimeEvent {
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "event_id")]
+    fn py_event_id(&self) -> String {
+        self.event_id.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Debug)]
 /// Represents a time event and its associated handler.
@@ -150,7 +209,7 @@ impl TestTimer {
 
     pub fn pop_event(&self, event_id: UUID4, ts_init: UnixNanos) -> TimeEvent {
         TimeEvent {
-            name: Box::new(Rc::new(self.name.clone())),
+            name: Box::new(Arc::new(self.name.clone())),
             event_id,
             ts_event: self.next_time_ns,
             ts_init,
@@ -161,8 +220,12 @@ impl TestTimer {
     /// of events. A [TimeEvent] is appended for each time a next event is
     /// <= the given `to_time_ns`.
     pub fn advance(&mut self, to_time_ns: UnixNanos) -> impl Iterator<Item = TimeEvent> + '_ {
-        let advances =
-            to_time_ns.saturating_sub(self.next_time_ns - self.interval_ns) / self.interval_ns;
+        let advances = if self.interval_ns == 0 {
+            // A zero interval alert fires once at its start time
+            u64::from(self.next_time_ns <= to_time_ns)
+        } else {
+            to_time_ns.saturating_sub(self.next_time_ns - self.interval_ns) / self.interval_ns
+        };
         self.take(advances as usize).map(|(event, _)| event)
     }
 
@@ -181,7 +244,7 @@ impl Iterator for TestTimer {
         } else {
             let item = (
                 TimeEvent {
-                    name: Box::new(Rc::new(self.name.clone())),
+                    name: Box::new(Arc::new(self.name.clone())),
                     event_id: UUID4::new(),
                     ts_event: self.next_time_ns,
                     ts_init: self.next_time_ns,
@@ -203,12 +266,163 @@ impl Iterator for TestTimer {
     }
 }
 
+/// Provides a live timer which delivers its time events onto a Python asyncio event loop.
+///
+/// The timer runs as a task on the shared Tokio runtime and schedules each event with the
+/// loop's `call_soon_threadsafe`. Event times are advanced from the previous event time
+/// rather than from when the callback ran, so the timer does not drift under load.
+#[pyclass]
+pub struct LiveTimer {
+    pub name: String,
+    pub interval_ns: u64,
+    pub start_time_ns: UnixNanos,
+    pub stop_time_ns: Option<UnixNanos>,
+    next_time_ns: Arc<AtomicU64>,
+    is_expired: Arc<AtomicBool>,
+    canceler: Option<oneshot::Sender<()>>,
+}
+
+impl LiveTimer {
+    #[must_use]
+    pub fn new(
+        name: String,
+        interval_ns: u64,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+    ) -> Self {
+        correctness::valid_string(&name, "`LiveTimer` name");
+
+        LiveTimer {
+            name,
+            interval_ns,
+            start_time_ns,
+            stop_time_ns,
+            next_time_ns: Arc::new(AtomicU64::new(start_time_ns + interval_ns)),
+            is_expired: Arc::new(AtomicBool::new(false)),
+            canceler: None,
+        }
+    }
+
+    #[must_use]
+    pub fn next_time_ns(&self) -> UnixNanos {
+        self.next_time_ns.load(AtomicOrdering::SeqCst)
+    }
+
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.is_expired.load(AtomicOrdering::SeqCst)
+    }
+
+    /// Starts the timer, calling `callback` with each [`TimeEvent`] on `event_loop`.
+    ///
+    /// The timer expires once its stop time is reached, or if the event loop is closed.
+    pub fn start(&mut self, callback: PyObject, event_loop: PyObject) {
+        let (canceler, mut cancel_rx) = oneshot::channel();
+        self.canceler = Some(canceler);
+        self.is_expired.store(false, AtomicOrdering::SeqCst);
+
+        let name = self.name.clone();
+        let interval_ns = self.interval_ns;
+        let stop_time_ns = self.stop_time_ns;
+        let next_time_ns = self.next_time_ns.clone();
+        let is_expired = self.is_expired.clone();
+
+        pyo3_asyncio::tokio::get_runtime().spawn(async move {
+            loop {
+                let next_ns = next_time_ns.load(AtomicOrdering::SeqCst);
+                let now_ns = duration_since_unix_epoch().as_nanos() as u64;
+                let delay = Duration::from_nanos(next_ns.saturating_sub(now_ns));
+
+                tokio::select! {
+                    _ = tokio::time::sleep(delay) => {}
+                    _ = &mut cancel_rx => break,
+                }
+
+                let ts_init = duration_since_unix_epoch().as_nanos() as u64;
+                let event = TimeEvent::new(name.clone(), UUID4::new(), next_ns, ts_init);
+                let scheduled = Python::with_gil(|py| {
+                    event_loop.call_method1(
+                        py,
+                        "call_soon_threadsafe",
+                        (callback.clone_ref(py), event),
+                    )
+                });
+                if scheduled.is_err() {
+                    break; // Event loop closed
+                }
+
+                if interval_ns == 0 || stop_time_ns.map_or(false, |stop_ns| next_ns >= stop_ns) {
+                    break;
+                }
+                next_time_ns.store(next_ns + interval_ns, AtomicOrdering::SeqCst);
+            }
+            is_expired.store(true, AtomicOrdering::SeqCst);
+        });
+    }
+
+    /// Cancels the timer (the timer will not generate any further events).
+    pub fn cancel(&mut self) {
+        if let Some(canceler) = self.canceler.take() {
+            let _ = canceler.send(());
+        }
+        self.is_expired.store(true, AtomicOrdering::SeqCst);
+    }
+}
+
+impl Drop for LiveTimer {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+#[pymethods]
+impl LiveTimer {
+    #[new]
+    #[pyo3(signature = (name, interval_ns, start_time_ns, stop_time_ns=None))]
+    fn py_new(
+        name: String,
+        interval_ns: u64,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+    ) -> Self {
+        Self::new(name, interval_ns, start_time_ns, stop_time_ns)
+    }
+
+    #[pyo3(name = "start")]
+    fn py_start(&mut self, callback: PyObject, event_loop: PyObject) {
+        self.start(callback, event_loop);
+    }
+
+    #[pyo3(name = "cancel")]
+    fn py_cancel(&mut self) {
+        self.cancel();
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "next_time_ns")]
+    fn py_next_time_ns(&self) -> UnixNanos {
+        self.next_time_ns()
+    }
+
+    #[getter]
+    #[pyo3(name = "is_expired")]
+    fn py_is_expired(&self) -> bool {
+        self.is_expired()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::{TestTimer, TimeEvent};
+    use super::{LiveTimer, TestTimer, TimeEvent};
 
     #[test]
     fn test_pop_event() {
@@ -264,4 +478,28 @@ mod tests {
         assert_eq!(timer.advance(10).count(), 5);
         assert!(timer.is_expired);
     }
+
+    #[test]
+    fn test_advance_zero_interval_alert() {
+        let name = String::from("test_timer");
+        let mut timer = TestTimer::new(name, 0, 5, Some(5));
+        assert_eq!(timer.advance(4).count(), 0);
+        assert_eq!(timer.advance(5).count(), 1);
+        assert!(timer.is_expired);
+        assert_eq!(timer.advance(10).count(), 0);
+    }
+
+    #[test]
+    fn test_live_timer_initial_next_time() {
+        let timer = LiveTimer::new(String::from("live_timer"), 10, 5, None);
+        assert_eq!(timer.next_time_ns(), 15);
+        assert!(!timer.is_expired());
+    }
+
+    #[test]
+    fn test_live_timer_cancel_before_start() {
+        let mut timer = LiveTimer::new(String::from("live_timer"), 10, 5, Some(25));
+        timer.cancel();
+        assert!(timer.is_expired());
+    }
 }
@@ -16,23 +16,33 @@
 use std::ffi::c_char;
 
 use nautilus_core::{
-    string::{cstr_to_string, str_to_cstr},
+    ffi_error::{try_cstr_to_str, write_result, FFI_ERROR},
+    string::str_to_cstr,
     uuid::UUID4,
 };
 
 use crate::timer::TimeEvent;
 
+/// Writes a new [`TimeEvent`] to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set (in
+/// which case nothing is written to `out`).
+///
 /// # Safety
 ///
 /// - Assumes `name_ptr` is borrowed from a valid Python UTF-8 `str`.
+/// - Assumes `out` is a valid pointer to memory for a [`TimeEvent`].
 #[no_mangle]
 pub unsafe extern "C" fn time_event_new(
     name_ptr: *const c_char,
     event_id: UUID4,
     ts_event: u64,
     ts_init: u64,
-) -> TimeEvent {
-    TimeEvent::new(cstr_to_string(name_ptr), event_id, ts_event, ts_init)
+    out: *mut TimeEvent,
+) -> u8 {
+    let result = try_cstr_to_str(name_ptr, "`TimeEvent` name")
+        .and_then(|name| TimeEvent::try_new(name.to_string(), event_id, ts_event, ts_init));
+    write_result(result, out, |_| FFI_ERROR)
 }
 
 #[no_mangle]
@@ -13,22 +13,43 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-const FAILED: &str = "Condition check failed:";
+use alloc::{format, string::String};
+
+pub const FAILED: &str = "Condition check failed:";
 
 /// Check string `s` is valid.
 ///
-/// # Panics
+/// # Errors
 ///
 /// - If `s` is empty.
 /// - If `s` is all whitespace.
 /// - If `s` contains a Non-ASCII character.
-pub fn valid_string(s: &str, desc: &str) {
+pub fn check_valid_string(s: &str, desc: &str) -> Result<(), String> {
     if s.is_empty() {
-        panic!("{FAILED} invalid string for {desc}, was empty");
+        Err(format!("{FAILED} invalid string for {desc}, was empty"))
     } else if s.as_bytes().iter().all(u8::is_ascii_whitespace) {
-        panic!("{FAILED} invalid string for {desc}, was all whitespace");
+        Err(format!(
+            "{FAILED} invalid string for {desc}, was all whitespace"
+        ))
     } else if !s.is_ascii() {
-        panic!("{FAILED} invalid string for {desc} contained a non-ASCII char, was '{s}'");
+        Err(format!(
+            "{FAILED} invalid string for {desc} contained a non-ASCII char, was '{s}'"
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Check string `s` is valid.
+///
+/// # Panics
+///
+/// - If `s` is empty.
+/// - If `s` is all whitespace.
+/// - If `s` contains a Non-ASCII character.
+pub fn valid_string(s: &str, desc: &str) {
+    if let Err(e) = check_valid_string(s, desc) {
+        panic!("{e}");
     }
 }
 
@@ -54,14 +75,29 @@ pub fn u8_equal(lhs: u8, rhs: u8, lhs_param: &str, rhs_param: &str) {
     }
 }
 
+/// Check `u8` value is in range (inclusive).
+///
+/// # Errors
+///
+/// - If `value` is not within range [`l`, `r`].
+pub fn check_u8_in_range_inclusive(value: u8, l: u8, r: u8, desc: &str) -> Result<(), String> {
+    if value.ge(&l) && value.le(&r) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{FAILED} invalid u8 for {desc} not in range [{l}, {r}], was {value}"
+        ))
+    }
+}
+
 /// Check `u8` value is in range (inclusive).
 ///
 /// # Panics
 ///
 /// - If `value` is not within range [`l`, `r`].
 pub fn u8_in_range_inclusive(value: u8, l: u8, r: u8, desc: &str) {
-    if !(value.ge(&l) && value.le(&r)) {
-        panic!("{FAILED} invalid u8 for {desc} not in range [{l}, {r}], was {value}");
+    if let Err(e) = check_u8_in_range_inclusive(value, l, r, desc) {
+        panic!("{e}");
     }
 }
 
@@ -87,14 +123,29 @@ pub fn i64_in_range_inclusive(value: i64, l: i64, r: i64, desc: &str) {
     }
 }
 
+/// Check `f64` value is in range (inclusive).
+///
+/// # Errors
+///
+/// - If `value` is not within range [`l`, `r`] (including when `value` is NaN).
+pub fn check_f64_in_range_inclusive(value: f64, l: f64, r: f64, desc: &str) -> Result<(), String> {
+    if value.ge(&l) && value.le(&r) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{FAILED} invalid f64 for {desc} not in range [{l}, {r}], was {value}"
+        ))
+    }
+}
+
 /// Check `f64` value is in range (inclusive).
 ///
 /// # Panics
 ///
 /// - If `value` is not within range [`l`, `r`].
 pub fn f64_in_range_inclusive(value: f64, l: f64, r: f64, desc: &str) {
-    if !(value.ge(&l) && value.le(&r)) {
-        panic!("{FAILED} invalid f64 for {desc} not in range [{l}, {r}], was {value}");
+    if let Err(e) = check_f64_in_range_inclusive(value, l, r, desc) {
+        panic!("{e}");
     }
 }
 
@@ -134,6 +185,12 @@ mod tests {
         valid_string(s, "value");
     }
 
+    #[rstest(s, case(""), case(" "), case("🦀"))]
+    fn test_check_valid_string_with_invalid_values_returns_error(s: &str) {
+        let err = check_valid_string(s, "value").unwrap_err();
+        assert!(err.starts_with(FAILED));
+    }
+
     #[rstest]
     #[case("a", "a")]
     fn test_string_contains_when_it_does_contain(#[case] s: &str, #[case] pat: &str) {
@@ -184,6 +241,17 @@ mod tests {
         u8_equal(lhs, rhs, lhs_param, rhs_param);
     }
 
+    #[rstest]
+    #[case(0, 1, 2)]
+    #[case(3, 1, 2)]
+    fn test_check_u8_in_range_inclusive_when_invalid_values(
+        #[case] value: u8,
+        #[case] l: u8,
+        #[case] r: u8,
+    ) {
+        assert!(check_u8_in_range_inclusive(value, l, r, "value").is_err());
+    }
+
     #[rstest]
     #[case(0, 1, 2, "value")]
     #[case(3, 1, 2, "value")]
@@ -249,6 +317,29 @@ mod tests {
         i64_in_range_inclusive(value, l, r, desc);
     }
 
+    #[rstest]
+    #[case(0.0, 0.0, 1.0)]
+    #[case(1.0, 0.0, 1.0)]
+    fn test_check_f64_in_range_inclusive_when_valid_values(
+        #[case] value: f64,
+        #[case] l: f64,
+        #[case] r: f64,
+    ) {
+        assert!(check_f64_in_range_inclusive(value, l, r, "value").is_ok());
+    }
+
+    #[rstest]
+    #[case(-0.1, 0.0, 1.0)]
+    #[case(1.1, 0.0, 1.0)]
+    #[case(f64::NAN, 0.0, 1.0)]
+    fn test_check_f64_in_range_inclusive_when_invalid_values(
+        #[case] value: f64,
+        #[case] l: f64,
+        #[case] r: f64,
+    ) {
+        assert!(check_f64_in_range_inclusive(value, l, r, "value").is_err());
+    }
+
     #[rstest]
     #[case(0.0, "value")]
     #[case(1.0, "value")]
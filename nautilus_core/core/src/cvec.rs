@@ -16,11 +16,51 @@
 use std::{
     ffi::c_void,
     fmt::{Display, Formatter},
+    mem::{self, size_of},
     ptr::null,
+    slice,
 };
 
+/// The type of the elements of a [`CVec`], so a consumer can check what the
+/// opaque pointer refers to before casting it.
+///
+/// cbindgen:prefix-with-name
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum CVecElementType {
+    /// The element type is not tagged, and must be known by the consumer.
+    Unknown = 0,
+    U8 = 1,
+    U64 = 2,
+    F64 = 3,
+    Data = 10,
+    QuoteTick = 11,
+    TradeTick = 12,
+    Bar = 13,
+    OrderBookDelta = 14,
+    OrderFilled = 20,
+}
+
+/// A type which can be passed through a [`CVec`] with a tag for its type.
+pub trait CVecElement: Sized {
+    const ELEMENT_TYPE: CVecElementType;
+}
+
+impl CVecElement for u8 {
+    const ELEMENT_TYPE: CVecElementType = CVecElementType::U8;
+}
+
+impl CVecElement for u64 {
+    const ELEMENT_TYPE: CVecElementType = CVecElementType::U64;
+}
+
+impl CVecElement for f64 {
+    const ELEMENT_TYPE: CVecElementType = CVecElementType::F64;
+}
+
 /// `CVec` is a C compatible struct that stores an opaque pointer to a block of
-/// memory, it's length and the capacity of the vector it was allocated from.
+/// memory, it's length and the capacity of the vector it was allocated from,
+/// along with the type of the elements and the function which drops them.
 ///
 /// NOTE: Changing the values here may lead to undefined behaviour when the
 /// memory is dropped.
@@ -35,6 +75,13 @@ pub struct CVec {
     /// The capacity of vector from which it was allocated.
     /// Used when deallocating the memory
     pub cap: usize,
+    /// The type of the elements in the block.
+    pub element_type: CVecElementType,
+    /// The size of each element in bytes.
+    pub element_size: usize,
+    /// Drops the elements and deallocates the memory as the vector it was
+    /// allocated from. Null when the memory is not owned by Rust.
+    pub drop: Option<extern "C" fn(CVec)>,
 }
 
 /// Empty derivation for Send to satisfy `pyclass` requirements
@@ -49,15 +96,149 @@ impl CVec {
             ptr: null() as *const bool as *mut c_void,
             len: 0,
             cap: 0,
+            element_type: CVecElementType::Unknown,
+            element_size: 0,
+            drop: None,
         }
     }
+
+    /// Consumes and leaks the Vec as with `From<Vec<T>>`, tagging the
+    /// [`CVec`] with the type of the elements.
+    pub fn from_elements<T: CVecElement>(data: Vec<T>) -> Self {
+        let mut cvec: Self = data.into();
+        cvec.element_type = T::ELEMENT_TYPE;
+        cvec
+    }
+
+    /// Creates an empty [`CVec`] for elements of type `T`, allocated with room for
+    /// `capacity` elements, to be filled repeatedly with [`CVec::refill`].
+    #[must_use]
+    pub fn with_capacity<T: CVecElement>(capacity: usize) -> Self {
+        Self::from_buffer(Vec::<T>::with_capacity(capacity))
+    }
+
+    /// Clears the elements and calls `fill` to push new elements onto the vector they
+    /// were allocated from, reusing its allocation. Returns the number of elements.
+    ///
+    /// This lets a buffer allocated once be drained into on every poll, without a new
+    /// allocation (or boundary crossing to drop it) per call.
+    ///
+    /// # Safety
+    ///
+    /// - Assumes the [`CVec`] was created from a `Vec<T>` (or is empty), and has not been
+    ///   dropped or drained before.
+    ///
+    /// # Panics
+    ///
+    /// - If the elements are tagged as a type other than `T`.
+    pub unsafe fn refill<T: CVecElement>(&mut self, fill: impl FnOnce(&mut Vec<T>)) -> usize {
+        assert!(
+            self.cap == 0 || self.is_element_type::<T>(),
+            "Error: `CVec` element type was {:?}, expected {:?}",
+            self.element_type,
+            T::ELEMENT_TYPE,
+        );
+        let mut buffer = mem::replace(self, Self::empty()).into_vec::<T>();
+        buffer.clear();
+        fill(&mut buffer);
+        *self = Self::from_buffer(buffer);
+        self.len
+    }
+
+    /// Consumes and leaks the Vec as with [`CVec::from_elements`], keeping the pointer
+    /// to its allocation even when it holds no elements.
+    fn from_buffer<T: CVecElement>(data: Vec<T>) -> Self {
+        if data.capacity() == 0 {
+            return Self {
+                element_type: T::ELEMENT_TYPE,
+                element_size: size_of::<T>(),
+                ..Self::empty()
+            };
+        }
+        let mut data = mem::ManuallyDrop::new(data);
+        Self {
+            ptr: data.as_mut_ptr().cast::<c_void>(),
+            len: data.len(),
+            cap: data.capacity(),
+            element_type: T::ELEMENT_TYPE,
+            element_size: size_of::<T>(),
+            drop: Some(drop_vec::<T>),
+        }
+    }
+
+    /// Returns true if the elements are tagged as type `T`.
+    #[must_use]
+    pub fn is_element_type<T: CVecElement>(&self) -> bool {
+        self.element_type == T::ELEMENT_TYPE && self.element_size == size_of::<T>()
+    }
+
+    /// Returns a view of the elements.
+    ///
+    /// # Safety
+    ///
+    /// - Assumes the block of memory holds `len` elements of type `T`, and has
+    ///   not been dropped.
+    #[must_use]
+    pub unsafe fn as_slice<T>(&self) -> &[T] {
+        if self.len == 0 {
+            return &[];
+        }
+        debug_assert_eq!(self.element_size, size_of::<T>());
+        slice::from_raw_parts(self.ptr.cast::<T>(), self.len)
+    }
+
+    /// Consumes the [`CVec`], returning ownership of the elements as the
+    /// vector they were allocated from.
+    ///
+    /// # Safety
+    ///
+    /// - Assumes the [`CVec`] was created from a `Vec<T>`, and has not been
+    ///   dropped or drained before.
+    #[must_use]
+    pub unsafe fn into_vec<T>(self) -> Vec<T> {
+        if self.cap == 0 {
+            return Vec::new();
+        }
+        debug_assert_eq!(self.element_size, size_of::<T>());
+        Vec::from_raw_parts(self.ptr.cast::<T>(), self.len, self.cap)
+    }
+
+    /// Drains the elements if they are tagged as type `T`, otherwise returns
+    /// the [`CVec`] unchanged.
+    ///
+    /// # Safety
+    ///
+    /// - Assumes the element type tag is correct, and the [`CVec`] has not
+    ///   been dropped or drained before.
+    pub unsafe fn try_into_vec<T: CVecElement>(self) -> Result<Vec<T>, Self> {
+        if self.is_element_type::<T>() || self.cap == 0 {
+            Ok(self.into_vec())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Drops the elements with the drop function, if the memory is owned by Rust.
+    ///
+    /// # Safety
+    ///
+    /// - Assumes the [`CVec`] has not been dropped or drained before.
+    pub unsafe fn drop_elements(self) {
+        if let Some(drop_fn) = self.drop {
+            drop_fn(self);
+        }
+    }
+}
+
+extern "C" fn drop_vec<T>(cvec: CVec) {
+    drop(unsafe { cvec.into_vec::<T>() });
 }
 
 /// Consumes and leaks the Vec, returning a mutable pointer to the contents as
 /// a [`CVec`]. The memory has been leaked and now exists for the lifetime of the
 /// program unless dropped manually.
-/// Note: drop the memory by reconstructing the vec using `from_raw_parts` method
-/// as shown in the test below.
+/// Note: drop the memory with [`CVec::drop_elements`], or by reconstructing the
+/// vec using `from_raw_parts` method as shown in the test below.
 impl<T> From<Vec<T>> for CVec {
     fn from(data: Vec<T>) -> Self {
         if data.is_empty() {
@@ -69,6 +250,9 @@ impl<T> From<Vec<T>> for CVec {
                 ptr: (&mut data.leak()[0] as *mut T).cast::<c_void>(),
                 len,
                 cap,
+                element_type: CVecElementType::Unknown,
+                element_size: size_of::<T>(),
+                drop: Some(drop_vec::<T>),
             }
         }
     }
@@ -78,8 +262,8 @@ impl Display for CVec {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "CVec {{ ptr: {:?}, len: {}, cap: {} }}",
-            self.ptr, self.len, self.cap,
+            "CVec {{ ptr: {:?}, len: {}, cap: {}, element_type: {:?} }}",
+            self.ptr, self.len, self.cap, self.element_type,
         )
     }
 }
@@ -87,11 +271,11 @@ impl Display for CVec {
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
+/// Drops the elements of the [`CVec`] with its drop function, memory not
+/// owned by Rust (with a null drop function) is left to its owner.
 #[no_mangle]
 pub extern "C" fn cvec_drop(cvec: CVec) {
-    let CVec { ptr, len, cap } = cvec;
-    let data: Vec<u8> = unsafe { Vec::from_raw_parts(ptr.cast::<u8>(), len, cap) };
-    drop(data); // Memory freed here
+    unsafe { cvec.drop_elements() }; // Memory freed here
 }
 
 #[no_mangle]
@@ -103,7 +287,7 @@ pub extern "C" fn cvec_new() -> CVec {
 mod tests {
     use std::ptr::null;
 
-    use super::CVec;
+    use super::*;
 
     /// Access values from a vector converted into a [`CVec`].
     #[test]
@@ -119,7 +303,7 @@ mod tests {
             data.into()
         };
 
-        let CVec { ptr, len, cap } = cvec;
+        let CVec { ptr, len, cap, .. } = cvec;
         assert_eq!(len, vec_len);
         assert_eq!(cap, vec_cap);
 
@@ -148,7 +332,7 @@ mod tests {
             data.into()
         };
 
-        let CVec { ptr, len, cap } = cvec;
+        let CVec { ptr, len, cap, .. } = cvec;
         let data = ptr as *mut u64;
 
         unsafe {
@@ -163,6 +347,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_refill_reuses_allocation() {
+        let mut cvec = CVec::with_capacity::<u64>(4);
+        let ptr = cvec.ptr;
+        assert_eq!(cvec.len, 0);
+        assert_eq!(cvec.cap, 4);
+
+        let count = unsafe { cvec.refill(|buffer: &mut Vec<u64>| buffer.extend([1, 2, 3])) };
+        assert_eq!(count, 3);
+        assert_eq!(unsafe { cvec.as_slice::<u64>() }, &[1, 2, 3]);
+
+        let count = unsafe { cvec.refill(|buffer: &mut Vec<u64>| buffer.push(4)) };
+        assert_eq!(count, 1);
+        assert_eq!(unsafe { cvec.as_slice::<u64>() }, &[4]);
+
+        let count = unsafe { cvec.refill(|_: &mut Vec<u64>| {}) };
+        assert_eq!(count, 0);
+        assert_eq!(cvec.ptr, ptr);
+        assert_eq!(cvec.cap, 4);
+        cvec_drop(cvec);
+    }
+
+    #[test]
+    #[should_panic(expected = "element type")]
+    fn test_refill_with_other_element_type_panics() {
+        let mut cvec = CVec::with_capacity::<u64>(4);
+
+        let _ = unsafe { cvec.refill(|_: &mut Vec<f64>| {}) };
+    }
+
     /// An empty vector gets converted to a null pointer wrapped in a [`CVec`].
     #[test]
     fn empty_vec_should_give_null_ptr() {
@@ -170,4 +384,56 @@ mod tests {
         let cvec: CVec = data.into();
         assert_eq!(cvec.ptr as *mut u64, null() as *const u64 as *mut u64);
     }
+
+    #[test]
+    fn from_elements_tags_element_type() {
+        let cvec = CVec::from_elements(vec![1.0_f64, 2.0]);
+
+        assert_eq!(cvec.element_type, CVecElementType::F64);
+        assert_eq!(cvec.element_size, 8);
+        assert!(cvec.is_element_type::<f64>());
+        assert!(!cvec.is_element_type::<u64>());
+        unsafe { cvec.drop_elements() };
+    }
+
+    #[test]
+    fn into_vec_returns_elements() {
+        let cvec = CVec::from_elements(vec![1_u64, 2, 3]);
+
+        unsafe {
+            assert_eq!(cvec.as_slice::<u64>(), &[1, 2, 3]);
+            assert_eq!(cvec.into_vec::<u64>(), vec![1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn try_into_vec_with_wrong_element_type_returns_cvec() {
+        let cvec = CVec::from_elements(vec![1_u64, 2, 3]);
+
+        let result = unsafe { cvec.try_into_vec::<u8>() };
+
+        let cvec = result.unwrap_err();
+        assert_eq!(
+            unsafe { cvec.try_into_vec::<u64>() }.unwrap(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn untagged_vec_has_drop_function() {
+        let cvec: CVec = vec![1_u64, 2, 3].into();
+
+        assert_eq!(cvec.element_type, CVecElementType::Unknown);
+        assert!(cvec.drop.is_some());
+        cvec_drop(cvec);
+    }
+
+    #[test]
+    fn empty_cvec_drop_is_noop() {
+        let cvec = CVec::empty();
+
+        assert!(cvec.drop.is_none());
+        assert!(unsafe { cvec.as_slice::<u64>() }.is_empty());
+        cvec_drop(cvec);
+    }
 }
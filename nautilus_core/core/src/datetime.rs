@@ -13,11 +13,20 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::time::{Duration, UNIX_EPOCH};
+use std::{
+    ffi::{c_char, CStr},
+    fmt::{Display, Formatter},
+    time::{Duration, UNIX_EPOCH},
+};
 
 use chrono::{
     prelude::{DateTime, Utc},
-    SecondsFormat,
+    FixedOffset, NaiveDate, NaiveDateTime, SecondsFormat, TimeZone,
+};
+
+use crate::{
+    ffi_error::{set_last_error, write_result, FFI_ERROR},
+    string::str_to_cstr,
 };
 
 const MILLISECONDS_IN_SECOND: u64 = 1_000;
@@ -81,6 +90,147 @@ pub fn unix_nanos_to_iso8601(timestamp_ns: u64) -> String {
     dt.to_rfc3339_opts(SecondsFormat::Nanos, true)
 }
 
+/// Returns the ISO 8601 (RFC 3339) string for the UNIX nanoseconds in the fixed
+/// `utc_offset_secs` from UTC, with nanosecond precision, e.g. `2023-07-04T09:30:00.000000000-04:00`.
+///
+/// # Errors
+///
+/// - If `utc_offset_secs` is not within +/- 24 hours.
+pub fn unix_nanos_to_iso8601_with_offset(
+    timestamp_ns: u64,
+    utc_offset_secs: i32,
+) -> Result<String, String> {
+    let offset = FixedOffset::east_opt(utc_offset_secs).ok_or_else(|| {
+        format!("invalid UTC offset {utc_offset_secs}s, must be within +/- 24 hours")
+    })?;
+    let dt = DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_nanos(timestamp_ns));
+    Ok(dt
+        .with_timezone(&offset)
+        .to_rfc3339_opts(SecondsFormat::Nanos, false))
+}
+
+/// An error parsing an ISO 8601 string into UNIX nanoseconds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseTimestampError {
+    /// The string was not a recognized ISO 8601 format.
+    InvalidFormat(String),
+    /// The timestamp was before the UNIX epoch, or beyond the range of `u64` nanoseconds.
+    OutOfRange(String),
+}
+
+impl Display for ParseTimestampError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormat(s) => write!(f, "invalid ISO 8601 timestamp '{s}'"),
+            Self::OutOfRange(s) => write!(
+                f,
+                "ISO 8601 timestamp '{s}' out of range for UNIX nanoseconds"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseTimestampError {}
+
+/// Parses an ISO 8601 string into UNIX nanoseconds, without loss of precision.
+///
+/// Accepts RFC 3339 timestamps with a `Z` or `+HH:MM` offset (also `+HHMM`), with up
+/// to nine fractional digits. Timestamps without an offset, and plain dates (as
+/// midnight), are taken as UTC.
+pub fn iso8601_to_unix_nanos(s: &str) -> Result<u64, ParseTimestampError> {
+    let input = s.trim();
+    let dt = match parse_iso8601(input) {
+        Some(dt) => dt,
+        None => return Err(ParseTimestampError::InvalidFormat(input.to_string())),
+    };
+    datetime_to_unix_nanos(&dt).ok_or_else(|| ParseTimestampError::OutOfRange(input.to_string()))
+}
+
+fn parse_iso8601(input: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(input) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%dT%H:%M:%S%.f%z", "%Y-%m-%d %H:%M:%S%.f%z"] {
+        if let Ok(dt) = DateTime::parse_from_str(input, format) {
+            return Some(dt.with_timezone(&Utc));
+        }
+    }
+    for format in [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+    ] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(input, format) {
+            return Some(Utc.from_utc_datetime(&naive));
+        }
+    }
+    NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|naive| Utc.from_utc_datetime(&naive))
+}
+
+fn datetime_to_unix_nanos(dt: &DateTime<Utc>) -> Option<u64> {
+    let secs = u64::try_from(dt.timestamp()).ok()?;
+    secs.checked_mul(NANOSECONDS_IN_SECOND)?
+        .checked_add(u64::from(dt.timestamp_subsec_nanos()))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the ISO 8601 (RFC 3339) string for the UNIX nanoseconds in UTC, as a C string
+/// pointer.
+///
+/// The caller owns the returned string, and must free it with `cstr_drop`.
+#[no_mangle]
+pub extern "C" fn unix_nanos_to_iso8601_cstr(timestamp_ns: u64) -> *const c_char {
+    str_to_cstr(&unix_nanos_to_iso8601(timestamp_ns))
+}
+
+/// Writes the ISO 8601 (RFC 3339) string for the UNIX nanoseconds in the fixed
+/// `utc_offset_secs` from UTC to `out`, as a C string pointer.
+///
+/// Returns `FFI_OK` on success, otherwise `FFI_ERROR` with the last error set if
+/// `utc_offset_secs` is not within +/- 24 hours (in which case nothing is written to `out`).
+///
+/// The caller owns the written string, and must free it with `cstr_drop`.
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn unix_nanos_to_iso8601_with_offset_cstr(
+    timestamp_ns: u64,
+    utc_offset_secs: i32,
+    out: &mut *const c_char,
+) -> u8 {
+    let result = unix_nanos_to_iso8601_with_offset(timestamp_ns, utc_offset_secs)
+        .map(|value| str_to_cstr(&value));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Parses the ISO 8601 C string at `ptr` into UNIX nanoseconds, written to `out`.
+///
+/// Returns `FFI_OK` on success, otherwise `FFI_ERROR` with the last error set (in which
+/// case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn iso8601_to_unix_nanos_cstr(ptr: *const c_char, out: &mut u64) -> u8 {
+    if ptr.is_null() {
+        set_last_error("invalid ISO 8601 timestamp string, `ptr` was NULL");
+        return FFI_ERROR;
+    }
+    let result = match CStr::from_ptr(ptr).to_str() {
+        Ok(value) => iso8601_to_unix_nanos(value).map_err(|e| e.to_string()),
+        Err(e) => Err(format!("invalid ISO 8601 timestamp string: {e}")),
+    };
+    write_result(result, out, |_| FFI_ERROR)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -173,4 +323,91 @@ mod tests {
         let result = nanos_to_micros(value);
         assert_eq!(result, expected);
     }
+
+    #[rstest]
+    #[case(0, 0, "1970-01-01T00:00:00.000000000+00:00")]
+    #[case(1_688_477_400_000_000_001, -4 * 3600, "2023-07-04T09:30:00.000000001-04:00")]
+    #[case(1_688_477_400_123_456_789, 9 * 3600 + 1800, "2023-07-04T23:00:00.123456789+09:30")]
+    fn test_unix_nanos_to_iso8601_with_offset(
+        #[case] value: u64,
+        #[case] offset: i32,
+        #[case] expected: &str,
+    ) {
+        let result = unix_nanos_to_iso8601_with_offset(value, offset).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(24 * 3600)]
+    #[case(-24 * 3600)]
+    #[case(i32::MAX)]
+    fn test_unix_nanos_to_iso8601_with_invalid_offset(#[case] offset: i32) {
+        assert!(unix_nanos_to_iso8601_with_offset(0, offset).is_err());
+    }
+
+    #[rstest]
+    #[case("1970-01-01T00:00:00Z", 0)]
+    #[case("2023-07-04T13:30:00.000000001Z", 1_688_477_400_000_000_001)]
+    #[case("2023-07-04T09:30:00.000000001-04:00", 1_688_477_400_000_000_001)]
+    #[case("2023-07-04T09:30:00.000000001-0400", 1_688_477_400_000_000_001)]
+    #[case("2023-07-04 13:30:00.5+00:00", 1_688_477_400_500_000_000)]
+    #[case("2023-07-04T13:30:00.123", 1_688_477_400_123_000_000)]
+    #[case("2023-07-04 13:30:00", 1_688_477_400_000_000_000)]
+    #[case("2023-07-04T13:30", 1_688_477_400_000_000_000)]
+    #[case("2023-07-04", 1_688_428_800_000_000_000)]
+    #[case(" 2023-07-04T13:30:00Z ", 1_688_477_400_000_000_000)]
+    fn test_iso8601_to_unix_nanos(#[case] value: &str, #[case] expected: u64) {
+        let result = iso8601_to_unix_nanos(value).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(1_688_477_400_123_456_789)]
+    #[case(u64::MAX)]
+    fn test_iso8601_round_trip(#[case] value: u64) {
+        assert_eq!(
+            iso8601_to_unix_nanos(&unix_nanos_to_iso8601(value)),
+            Ok(value)
+        );
+        assert_eq!(
+            iso8601_to_unix_nanos(&unix_nanos_to_iso8601_with_offset(value, -5 * 3600).unwrap()),
+            Ok(value),
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("not a timestamp")]
+    #[case("2023-13-01T00:00:00Z")]
+    #[case("2023-07-04T25:00:00Z")]
+    fn test_iso8601_to_unix_nanos_invalid_format(#[case] value: &str) {
+        let result = iso8601_to_unix_nanos(value);
+        assert!(matches!(result, Err(ParseTimestampError::InvalidFormat(_))));
+    }
+
+    #[rstest]
+    #[case("1969-12-31T23:59:59.999999999Z")]
+    #[case("2600-01-01T00:00:00Z")]
+    fn test_iso8601_to_unix_nanos_out_of_range(#[case] value: &str) {
+        let result = iso8601_to_unix_nanos(value);
+        assert!(matches!(result, Err(ParseTimestampError::OutOfRange(_))));
+    }
+
+    #[rstest]
+    fn test_iso8601_to_unix_nanos_cstr() {
+        let mut out = 0;
+        let ptr = str_to_cstr("2023-07-04T09:30:00.000000001-04:00");
+        let status = unsafe { iso8601_to_unix_nanos_cstr(ptr, &mut out) };
+        assert_eq!(status, crate::ffi_error::FFI_OK);
+        assert_eq!(out, 1_688_477_400_000_000_001);
+        unsafe { crate::string::cstr_drop(ptr) };
+
+        let ptr = str_to_cstr("invalid");
+        let status = unsafe { iso8601_to_unix_nanos_cstr(ptr, &mut out) };
+        assert_eq!(status, FFI_ERROR);
+        assert_eq!(out, 1_688_477_400_000_000_001);
+        unsafe { crate::string::cstr_drop(ptr) };
+    }
 }
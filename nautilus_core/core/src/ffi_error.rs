@@ -0,0 +1,266 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The error model for the C API.
+//!
+//! Functions which can fail return a status code, zero on success, and record a message
+//! for the failure in a thread-local buffer, which is retrieved with [`last_error_cstr`].
+//! These functions must not panic, as release builds abort on panic (and unwinding across
+//! the FFI boundary is undefined behaviour), so every failure is returned as an error.
+
+use std::{
+    cell::RefCell,
+    ffi::{c_char, CStr},
+    fmt::Display,
+    ptr::null,
+    str::FromStr,
+};
+
+use crate::string::str_to_cstr;
+
+/// The status code for success.
+pub const FFI_OK: u8 = 0;
+/// The status code for a failure with no more specific code.
+pub const FFI_ERROR: u8 = 254;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<String>> = RefCell::new(None);
+}
+
+/// Records `err` as the last error for the current thread.
+pub fn set_last_error(err: impl Display) {
+    // Interior nul bytes cannot be represented in the C string
+    let message = err.to_string().replace('\0', "\\0");
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Clears the last error for the current thread.
+pub fn clear_last_error() {
+    LAST_ERROR.with(|last| *last.borrow_mut() = None);
+}
+
+/// Returns the message of the last error for the current thread.
+#[must_use]
+pub fn last_error() -> Option<String> {
+    LAST_ERROR.with(|last| last.borrow().clone())
+}
+
+/// Writes the value from `result` to `out` on success, otherwise records the error and
+/// returns the status code from `code`.
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a `T`, which is not read or dropped.
+pub unsafe fn write_result<T, E: Display>(
+    result: Result<T, E>,
+    out: *mut T,
+    code: impl FnOnce(&E) -> u8,
+) -> u8 {
+    match result {
+        Ok(value) => {
+            out.write(value);
+            FFI_OK
+        }
+        Err(e) => {
+            let status = code(&e);
+            set_last_error(e);
+            status
+        }
+    }
+}
+
+/// Returns the string slice of the C string at `ptr`.
+///
+/// # Errors
+///
+/// If `ptr` is null or the string is not valid UTF-8, with the `desc` naming the string
+/// in the message.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is either null or a valid C string pointer, which outlives `'a`.
+pub unsafe fn try_cstr_to_str<'a>(ptr: *const c_char, desc: &str) -> Result<&'a str, String> {
+    if ptr.is_null() {
+        return Err(format!("invalid {desc} string, `ptr` was NULL"));
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|e| format!("invalid {desc} string: {e}"))
+}
+
+/// Returns the string slice of the C string at `ptr`, or `None` if `ptr` is null.
+///
+/// # Errors
+///
+/// If the string is not valid UTF-8, with the `desc` naming the string in the message.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is either null or a valid C string pointer, which outlives `'a`.
+pub unsafe fn try_optional_cstr_to_str<'a>(
+    ptr: *const c_char,
+    desc: &str,
+) -> Result<Option<&'a str>, String> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+    try_cstr_to_str(ptr, desc).map(Some)
+}
+
+/// Parses a `T` from the C string at `ptr`, written to `out`.
+///
+/// Returns [`FFI_OK`] on success, otherwise [`FFI_ERROR`] with the last error set (in
+/// which case nothing is written to `out`). The `desc` names the type in the message.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a `T`, which is not read or dropped.
+pub unsafe fn write_parsed_cstr<T>(ptr: *const c_char, out: *mut T, desc: &str) -> u8
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let result = try_cstr_to_str(ptr, desc).and_then(|value| {
+        T::from_str(value).map_err(|e| format!("invalid {desc} string, was '{value}': {e}"))
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the message of the last error for the current thread as a C string pointer,
+/// or null if there is no error.
+///
+/// The caller owns the returned string, and must free it with `cstr_drop`.
+#[no_mangle]
+pub extern "C" fn last_error_cstr() -> *const c_char {
+    last_error().map_or(null(), |message| str_to_cstr(&message))
+}
+
+/// Clears the last error for the current thread.
+#[no_mangle]
+pub extern "C" fn last_error_clear() {
+    clear_last_error();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, mem::MaybeUninit, thread};
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::string::cstr_drop;
+
+    #[rstest]
+    fn test_last_error_is_thread_local() {
+        set_last_error("boom");
+
+        let other = thread::spawn(last_error).join().unwrap();
+
+        assert_eq!(last_error(), Some("boom".to_string()));
+        assert_eq!(other, None);
+        clear_last_error();
+        assert_eq!(last_error(), None);
+    }
+
+    #[rstest]
+    fn test_write_result_ok() {
+        let mut out = MaybeUninit::<u64>::uninit();
+
+        let status = unsafe { write_result(Ok::<u64, String>(1), out.as_mut_ptr(), |_| 1) };
+
+        assert_eq!(status, FFI_OK);
+        assert_eq!(unsafe { out.assume_init() }, 1);
+    }
+
+    #[rstest]
+    fn test_write_result_err_sets_last_error() {
+        let mut out = MaybeUninit::<u64>::uninit();
+
+        let status = unsafe { write_result(Err("bad value"), out.as_mut_ptr(), |_| 7) };
+
+        assert_eq!(status, 7);
+        assert_eq!(last_error(), Some("bad value".to_string()));
+    }
+
+    #[rstest]
+    #[case("1", FFI_OK)]
+    #[case("x", FFI_ERROR)]
+    fn test_write_parsed_cstr(#[case] input: &str, #[case] expected: u8) {
+        let input = CString::new(input).unwrap();
+        let mut out = MaybeUninit::<u64>::uninit();
+
+        let status = unsafe { write_parsed_cstr(input.as_ptr(), out.as_mut_ptr(), "`u64`") };
+
+        assert_eq!(status, expected);
+    }
+
+    #[rstest]
+    fn test_write_parsed_cstr_with_null_ptr() {
+        let mut out = MaybeUninit::<u64>::uninit();
+
+        let status = unsafe { write_parsed_cstr(null(), out.as_mut_ptr(), "`u64`") };
+
+        assert_eq!(status, FFI_ERROR);
+        assert_eq!(
+            last_error(),
+            Some("invalid `u64` string, `ptr` was NULL".to_string())
+        );
+    }
+
+    #[rstest]
+    fn test_try_cstr_to_str_with_invalid_utf8() {
+        let input = CString::new(vec![0xff, 0xfe]).unwrap();
+
+        let result = unsafe { try_cstr_to_str(input.as_ptr(), "`u64`") };
+
+        assert!(result.unwrap_err().starts_with("invalid `u64` string: "));
+    }
+
+    #[rstest]
+    fn test_try_optional_cstr_to_str() {
+        let input = CString::new("abc").unwrap();
+
+        assert_eq!(
+            unsafe { try_optional_cstr_to_str(input.as_ptr(), "`name`") },
+            Ok(Some("abc"))
+        );
+        assert_eq!(
+            unsafe { try_optional_cstr_to_str(null(), "`name`") },
+            Ok(None)
+        );
+    }
+
+    #[rstest]
+    fn test_last_error_cstr() {
+        set_last_error("invalid\0value");
+
+        let ptr = last_error_cstr();
+
+        assert_eq!(
+            unsafe { CStr::from_ptr(ptr) }.to_str().unwrap(),
+            "invalid\\0value"
+        );
+        unsafe { cstr_drop(ptr) };
+        last_error_clear();
+        assert!(last_error_cstr().is_null());
+    }
+}
@@ -13,10 +13,26 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+//! Without the `std` feature (on by default) the crate is `no_std`, and only the correctness
+//! checks and precision parsing are available (which need `alloc`).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod correctness;
+#[cfg(feature = "std")]
 pub mod cvec;
+#[cfg(feature = "std")]
 pub mod datetime;
+#[cfg(feature = "std")]
+pub mod ffi_error;
 pub mod parsing;
+#[cfg(feature = "std")]
+pub mod pool;
+#[cfg(feature = "std")]
 pub mod string;
+#[cfg(feature = "std")]
 pub mod time;
+#[cfg(feature = "std")]
 pub mod uuid;
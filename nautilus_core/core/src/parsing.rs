@@ -13,38 +13,60 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "std")]
 use std::{
     collections::HashMap,
     ffi::{c_char, CStr, CString},
 };
 
-use serde_json::{Result, Value};
+#[cfg(feature = "std")]
+use serde_json::Value;
 
-use crate::string::cstr_to_string;
+#[cfg(feature = "std")]
+use crate::ffi_error::{try_cstr_to_str, write_result, FFI_ERROR};
 
 /// Convert a C bytes pointer into an owned `Vec<String>`.
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+///
+/// # Panics
+///
+/// - If `ptr` is null, or not a valid UTF-8 JSON string.
+#[cfg(feature = "std")]
 pub unsafe fn bytes_to_string_vec(ptr: *const c_char) -> Vec<String> {
-    let c_str = CStr::from_ptr(ptr);
-    let bytes = c_str.to_bytes();
-    let json_string = std::str::from_utf8(bytes).unwrap();
-    let parsed_value: serde_json::Value = serde_json::from_str(json_string).unwrap();
+    try_bytes_to_string_vec(ptr).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Convert a C bytes pointer into an owned `Vec<String>`, or an error if `ptr` is null or
+/// not a valid UTF-8 JSON string.
+///
+/// Values of the JSON list which are not strings are skipped, and a JSON value which is not
+/// a list gives an empty vector.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer, or null.
+#[cfg(feature = "std")]
+pub unsafe fn try_bytes_to_string_vec(ptr: *const c_char) -> Result<Vec<String>, String> {
+    let json_string = try_cstr_to_str(ptr, "JSON list")?;
+    let parsed_value: serde_json::Value = serde_json::from_str(json_string)
+        .map_err(|e| format!("invalid JSON list string '{json_string}': {e}"))?;
 
     match parsed_value {
-        serde_json::Value::Array(arr) => arr
+        serde_json::Value::Array(arr) => Ok(arr
             .into_iter()
             .filter_map(|value| match value {
                 serde_json::Value::String(string_value) => Some(string_value),
                 _ => None,
             })
-            .collect(),
-        _ => Vec::new(),
+            .collect()),
+        _ => Ok(Vec::new()),
     }
 }
 
+#[cfg(feature = "std")]
 pub fn string_vec_to_bytes(strings: Vec<String>) -> *const c_char {
     let json_string = serde_json::to_string(&strings).unwrap();
     let c_string = CString::new(json_string).unwrap();
@@ -56,6 +78,7 @@ pub fn string_vec_to_bytes(strings: Vec<String>) -> *const c_char {
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+#[cfg(feature = "std")]
 #[must_use]
 pub unsafe fn optional_bytes_to_json(ptr: *const c_char) -> Option<HashMap<String, Value>> {
     if ptr.is_null() {
@@ -63,8 +86,14 @@ pub unsafe fn optional_bytes_to_json(ptr: *const c_char) -> Option<HashMap<Strin
     } else {
         let c_str = CStr::from_ptr(ptr);
         let bytes = c_str.to_bytes();
-        let json_string = std::str::from_utf8(bytes).unwrap();
-        let result: Result<HashMap<String, Value>> = serde_json::from_str(json_string);
+        let json_string = match std::str::from_utf8(bytes) {
+            Ok(json_string) => json_string,
+            Err(err) => {
+                eprintln!("Error parsing JSON: {}", err);
+                return None;
+            }
+        };
+        let result: serde_json::Result<HashMap<String, Value>> = serde_json::from_str(json_string);
         match result {
             Ok(map) => Some(map),
             Err(err) => {
@@ -88,18 +117,47 @@ pub fn precision_from_str(s: &str) -> u8 {
     return lower_s.split('.').last().unwrap().len() as u8;
 }
 
-/// Return the decimal precision inferred from the given C string.
+/// Return the decimal precision inferred from the given string, or `None` if the string is
+/// not a valid finite decimal number (or its precision cannot be represented).
 ///
-/// # Safety
+/// Unlike [`precision_from_str`] this never panics, so it is suitable for untrusted input
+/// such as venue instrument definitions.
+#[must_use]
+pub fn try_precision_from_str(s: &str) -> Option<u8> {
+    let s = s.trim();
+    if !s.parse::<f64>().map_or(false, f64::is_finite) {
+        return None;
+    }
+    let lower_s = s.to_lowercase();
+    match lower_s.split_once('e') {
+        Some((_, exponent)) => match exponent.strip_prefix('-') {
+            Some(exponent) => exponent.parse::<u8>().ok(),
+            None => Some(0),
+        },
+        None => match lower_s.split_once('.') {
+            Some((_, fraction)) => u8::try_from(fraction.len()).ok(),
+            None => Some(0),
+        },
+    }
+}
+
+/// Returns the decimal precision inferred from the given C string, written to `out`.
 ///
-/// - Assumes `ptr` is a valid C string pointer.
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `ptr` is not a valid C string of a finite decimal number (in which case nothing is
+/// written to `out`).
 ///
-/// # Panics
+/// # Safety
 ///
-/// - If `ptr` is null.
+/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a `u8`.
+#[cfg(feature = "std")]
 #[no_mangle]
-pub unsafe extern "C" fn precision_from_cstr(ptr: *const c_char) -> u8 {
-    precision_from_str(&cstr_to_string(ptr))
+pub unsafe extern "C" fn precision_from_cstr(ptr: *const c_char, out: *mut u8) -> u8 {
+    let result = try_cstr_to_str(ptr, "decimal").and_then(|s| {
+        try_precision_from_str(s).ok_or_else(|| format!("invalid decimal string, was '{s}'"))
+    });
+    write_result(result, out, |_| FFI_ERROR)
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -107,7 +165,7 @@ pub unsafe extern "C" fn precision_from_cstr(ptr: *const c_char) -> u8 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::ffi::CString;
+    use std::{ffi::CString, mem::MaybeUninit};
 
     use rstest::rstest;
 
@@ -164,6 +222,16 @@ mod tests {
         assert_eq!(result, expected_vec);
     }
 
+    #[test]
+    fn test_try_bytes_to_string_vec_with_invalid_input() {
+        let json_str = CString::new(r#"["value1", "#).unwrap();
+        let result = unsafe { try_bytes_to_string_vec(json_str.as_ptr()) };
+        assert!(result.unwrap_err().contains("invalid JSON list string"));
+
+        let result = unsafe { try_bytes_to_string_vec(std::ptr::null()) };
+        assert!(result.unwrap_err().contains("`ptr` was NULL"));
+    }
+
     #[test]
     fn test_bytes_to_string_vec_invalid() {
         let json_str = CString::new(r#"["value1", 42, "value3"]"#).unwrap();
@@ -200,6 +268,13 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    #[test]
+    fn test_optional_bytes_to_json_invalid_utf8() {
+        let json_str = CString::new(vec![b'{', 0xff, b'}']).unwrap();
+        let result = unsafe { optional_bytes_to_json(json_str.as_ptr()) };
+        assert_eq!(result, None);
+    }
+
     #[rstest(
         s,
         expected,
@@ -220,6 +295,25 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest(
+        s,
+        expected,
+        case("0", Some(0)),
+        case(" 1.250 ", Some(3)),
+        case("-0.05", Some(2)),
+        case("1E-4", Some(4)),
+        case("1e+8", Some(0)),
+        case("1e-300", None),
+        case("", None),
+        case("1.2.3", None),
+        case("abc", None),
+        case("inf", None),
+        case("NaN", None)
+    )]
+    fn test_try_precision_from_str(s: &str, expected: Option<u8>) {
+        assert_eq!(try_precision_from_str(s), expected);
+    }
+
     #[rstest(
         input,
         expected,
@@ -232,6 +326,33 @@ mod tests {
     )]
     fn test_precision_from_cstr(input: &str, expected: u8) {
         let c_str = CString::new(input).unwrap();
-        assert_eq!(unsafe { precision_from_cstr(c_str.as_ptr()) }, expected);
+        let mut out = MaybeUninit::<u8>::uninit();
+
+        let status = unsafe { precision_from_cstr(c_str.as_ptr(), out.as_mut_ptr()) };
+
+        assert_eq!(status, 0);
+        assert_eq!(unsafe { out.assume_init() }, expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("abc")]
+    #[case("1.0e-999")]
+    fn test_precision_from_cstr_with_invalid_input(#[case] input: &str) {
+        let c_str = CString::new(input).unwrap();
+        let mut out = MaybeUninit::<u8>::uninit();
+
+        let status = unsafe { precision_from_cstr(c_str.as_ptr(), out.as_mut_ptr()) };
+
+        assert_eq!(status, FFI_ERROR);
+    }
+
+    #[rstest]
+    fn test_precision_from_cstr_with_null_ptr() {
+        let mut out = MaybeUninit::<u8>::uninit();
+
+        let status = unsafe { precision_from_cstr(std::ptr::null(), out.as_mut_ptr()) };
+
+        assert_eq!(status, FFI_ERROR);
     }
 }
@@ -15,6 +15,7 @@
 
 use std::ffi::{c_char, CStr, CString};
 
+#[cfg(feature = "python")]
 use pyo3::{ffi, types::PyString, FromPyPointer, Python};
 
 /// Returns an owned string from a valid Python object pointer.
@@ -26,6 +27,7 @@ use pyo3::{ffi, types::PyString, FromPyPointer, Python};
 /// # Panics
 ///
 /// - If `ptr` is null.
+#[cfg(feature = "python")]
 #[must_use]
 pub unsafe fn pystr_to_string(ptr: *mut ffi::PyObject) -> String {
     assert!(!ptr.is_null(), "`ptr` was NULL");
@@ -70,18 +72,16 @@ pub fn str_to_cstr(s: &str) -> *const c_char {
     CString::new(s).expect("CString::new failed").into_raw()
 }
 
-/// Drops the C string memory at the pointer.
+/// Drops the C string memory at the pointer, doing nothing if `ptr` is null.
 ///
 /// # Safety
 ///
-/// - Assumes `ptr` is a valid C string pointer.
-///
-/// # Panics
-///
-/// - If `ptr` is null.
+/// - Assumes `ptr` is either null or a valid C string pointer.
 #[no_mangle]
 pub unsafe extern "C" fn cstr_drop(ptr: *const c_char) {
-    assert!(!ptr.is_null(), "`ptr` was NULL");
+    if ptr.is_null() {
+        return;
+    }
     let cstring = CString::from_raw(ptr as *mut c_char);
     drop(cstring);
 }
@@ -91,10 +91,12 @@ pub unsafe extern "C" fn cstr_drop(ptr: *const c_char) {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "python")]
     use pyo3::AsPyPointer;
 
     use super::*;
 
+    #[cfg(feature = "python")]
     #[test]
     fn test_pystr_to_string() {
         pyo3::prepare_freethreaded_python();
@@ -104,6 +106,7 @@ mod tests {
         assert_eq!(result, "test string1");
     }
 
+    #[cfg(feature = "python")]
     #[test]
     #[should_panic]
     fn test_pystr_to_string_with_null_ptr() {
@@ -156,6 +159,11 @@ mod tests {
         assert_eq!(result, s);
     }
 
+    #[test]
+    fn test_cstr_drop_with_null_ptr() {
+        unsafe { cstr_drop(std::ptr::null()) }; // No panic
+    }
+
     #[test]
     fn test_cstr_drop() {
         let c_string = CString::new("test string3").expect("CString::new failed");
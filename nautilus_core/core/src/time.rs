@@ -13,7 +13,13 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        OnceLock,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
 /// Represents a timestamp in nanoseconds since UNIX epoch.
 pub type UnixNanos = u64;
@@ -27,6 +33,81 @@ pub fn duration_since_unix_epoch() -> Duration {
         .expect("Error calling `SystemTime::now.duration_since`")
 }
 
+static ATOMIC_TIME: OnceLock<AtomicTime> = OnceLock::new();
+
+/// Returns a reference to the global [`AtomicTime`], initializing it on first use.
+pub fn get_atomic_clock() -> &'static AtomicTime {
+    ATOMIC_TIME.get_or_init(AtomicTime::new)
+}
+
+/// Provides a clock of UNIX nanoseconds which are guaranteed to be strictly increasing.
+///
+/// The wall clock is sampled once on initialization, after which time is advanced from a
+/// monotonic [`Instant`], so adjustments to the system clock (e.g. by NTP) never move time
+/// backwards. Timestamps returned across threads are unique.
+#[derive(Debug)]
+pub struct AtomicTime {
+    /// The UNIX nanoseconds at the anchor instant.
+    anchor_ns: UnixNanos,
+    /// The monotonic instant corresponding to `anchor_ns`.
+    anchor: Instant,
+    /// The last returned UNIX nanoseconds.
+    last_ns: AtomicU64,
+}
+
+impl AtomicTime {
+    /// Initializes a new `AtomicTime` instance anchored to the current wall clock time.
+    #[must_use]
+    pub fn new() -> Self {
+        let anchor_ns = duration_since_unix_epoch().as_nanos() as u64;
+        Self {
+            anchor_ns,
+            anchor: Instant::now(),
+            last_ns: AtomicU64::new(anchor_ns),
+        }
+    }
+
+    /// Returns the current UNIX nanoseconds, strictly greater than any value previously
+    /// returned by this clock.
+    pub fn get_time_ns(&self) -> UnixNanos {
+        let now_ns = self.anchor_ns + self.anchor.elapsed().as_nanos() as u64;
+        let mut last_ns = self.last_ns.load(Ordering::Acquire);
+        loop {
+            let next_ns = now_ns.max(last_ns + 1);
+            match self.last_ns.compare_exchange_weak(
+                last_ns,
+                next_ns,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return next_ns,
+                Err(actual_ns) => last_ns = actual_ns,
+            }
+        }
+    }
+
+    /// Returns the current UNIX microseconds (monotonic, but not necessarily unique).
+    pub fn get_time_us(&self) -> u64 {
+        self.get_time_ns() / 1_000
+    }
+
+    /// Returns the current UNIX milliseconds (monotonic, but not necessarily unique).
+    pub fn get_time_ms(&self) -> u64 {
+        self.get_time_ns() / 1_000_000
+    }
+
+    /// Returns the current UNIX seconds (monotonic, but not necessarily unique).
+    pub fn get_time(&self) -> f64 {
+        self.get_time_ns() as f64 / 1_000_000_000.0
+    }
+}
+
+impl Default for AtomicTime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
@@ -54,12 +135,26 @@ pub extern "C" fn unix_timestamp_ns() -> u64 {
     duration_since_unix_epoch().as_nanos() as u64
 }
 
+/// Returns the current nanoseconds since the UNIX epoch from the global atomic clock
+/// (unique and strictly increasing).
+#[no_mangle]
+pub extern "C" fn unix_nanos_now() -> u64 {
+    get_atomic_clock().get_time_ns()
+}
+
+/// Returns the current milliseconds since the UNIX epoch from the global atomic clock
+/// (monotonic).
+#[no_mangle]
+pub extern "C" fn unix_millis_now() -> u64 {
+    get_atomic_clock().get_time_ms()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::time::UNIX_EPOCH;
+    use std::{sync::Arc, thread, time::UNIX_EPOCH};
 
     use super::*;
 
@@ -144,4 +239,68 @@ mod tests {
         assert!(result5 >= result4);
         assert!(result1 > 1_650_000_000_000_000_000);
     }
+
+    #[test]
+    fn test_atomic_time_is_strictly_increasing() {
+        let clock = AtomicTime::new();
+        let mut last = clock.get_time_ns();
+        for _ in 0..10_000 {
+            let now = clock.get_time_ns();
+            assert!(now > last);
+            last = now;
+        }
+    }
+
+    #[test]
+    fn test_atomic_time_is_close_to_wall_clock() {
+        let clock = AtomicTime::new();
+        let wall_ns = duration_since_unix_epoch().as_nanos() as u64;
+        let time_ns = clock.get_time_ns();
+
+        assert!(time_ns.abs_diff(wall_ns) < 100_000_000);
+        assert!(clock.get_time_ms() >= 1_650_000_000_000);
+        assert!(clock.get_time_us() >= 1_650_000_000_000_000);
+        assert!(clock.get_time() > 1_650_000_000.0);
+    }
+
+    #[test]
+    fn test_atomic_time_is_unique_across_threads() {
+        let clock = Arc::new(AtomicTime::new());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let clock = clock.clone();
+                thread::spawn(move || (0..1_000).map(|_| clock.get_time_ns()).collect::<Vec<_>>())
+            })
+            .collect();
+
+        let mut timestamps: Vec<UnixNanos> = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect();
+        let count = timestamps.len();
+        timestamps.sort_unstable();
+        timestamps.dedup();
+
+        assert_eq!(timestamps.len(), count);
+    }
+
+    #[test]
+    fn test_unix_nanos_now_is_strictly_increasing() {
+        let result1 = unix_nanos_now();
+        let result2 = unix_nanos_now();
+        let result3 = unix_nanos_now();
+
+        assert!(result2 > result1);
+        assert!(result3 > result2);
+        assert!(result1 > 1_650_000_000_000_000_000);
+    }
+
+    #[test]
+    fn test_unix_millis_now_is_monotonic_increasing() {
+        let result1 = unix_millis_now();
+        let result2 = unix_millis_now();
+
+        assert!(result2 >= result1);
+        assert!(result1 >= 1_650_000_000_000);
+    }
 }
@@ -19,27 +19,54 @@ use std::{
     fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
     str::FromStr,
-    sync::Arc,
 };
 
+#[cfg(feature = "schema")]
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Schema, SchemaObject},
+    JsonSchema,
+};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use uuid::Uuid;
 
-use crate::string::str_to_cstr;
+use crate::ffi_error::write_parsed_cstr;
+
+/// The length of a UUID v4 C string, including the nul terminator.
+const UUID4_LEN: usize = 37;
 
+/// Represents a pseudo-random UUID (universally unique identifier) version 4.
+///
+/// The value is held as a nul terminated C string in a fixed sized buffer, so the type is
+/// `Copy` and generating a new UUID requires no heap allocation.
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
 pub struct UUID4 {
-    pub value: Box<Arc<String>>,
+    pub value: [u8; UUID4_LEN],
 }
 
 impl UUID4 {
     #[must_use]
     pub fn new() -> Self {
-        let uuid = Uuid::new_v4();
-        UUID4 {
-            value: Box::new(Arc::new(uuid.to_string())),
-        }
+        Self::from_uuid(Uuid::new_v4())
+    }
+
+    fn from_uuid(uuid: Uuid) -> Self {
+        let mut value = [0; UUID4_LEN];
+        uuid.hyphenated().encode_lower(&mut value[..UUID4_LEN - 1]);
+        Self { value }
+    }
+
+    /// Returns the value as a C string reference.
+    #[must_use]
+    pub fn to_cstr(&self) -> &CStr {
+        CStr::from_bytes_with_nul(&self.value).expect("`UUID4` value was not nul terminated")
+    }
+
+    /// Returns the value as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.value[..UUID4_LEN - 1]).expect("`UUID4` value was not UTF-8")
     }
 }
 
@@ -48,9 +75,7 @@ impl FromStr for UUID4 {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let uuid = Uuid::parse_str(s).map_err(|_| "Invalid UUID string")?;
-        Ok(Self {
-            value: Box::new(Arc::new(uuid.to_string())),
-        })
+        Ok(Self::from_uuid(uuid))
     }
 }
 
@@ -66,9 +91,15 @@ impl Default for UUID4 {
     }
 }
 
+impl Debug for UUID4 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}('{}')", stringify!(UUID4), self.as_str())
+    }
+}
+
 impl Display for UUID4 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value)
+        write!(f, "{}", self.as_str())
     }
 }
 
@@ -77,7 +108,7 @@ impl Serialize for UUID4 {
     where
         S: Serializer,
     {
-        self.value.serialize(serializer)
+        serializer.serialize_str(self.as_str())
     }
 }
 
@@ -91,6 +122,23 @@ impl<'de> Deserialize<'de> for UUID4 {
         Ok(uuid4)
     }
 }
+
+#[cfg(feature = "schema")]
+impl JsonSchema for UUID4 {
+    fn schema_name() -> String {
+        "UUID4".to_string()
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            format: Some("uuid".to_string()),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
@@ -101,35 +149,29 @@ pub extern "C" fn uuid4_new() -> UUID4 {
 
 #[no_mangle]
 pub extern "C" fn uuid4_clone(uuid4: &UUID4) -> UUID4 {
-    uuid4.clone()
+    *uuid4
 }
 
-#[no_mangle]
-pub extern "C" fn uuid4_drop(uuid4: UUID4) {
-    drop(uuid4); // Memory freed here
-}
-
-/// Returns a [`UUID4`] from C string pointer.
+/// Returns a [`UUID4`] from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
-///
-/// # Panics
-///
-/// - If `ptr` cannot be cast to a valid C string.
+/// - Assumes `out` is a valid pointer to memory for a [`UUID4`].
 #[no_mangle]
-pub unsafe extern "C" fn uuid4_from_cstr(ptr: *const c_char) -> UUID4 {
-    UUID4::from(
-        CStr::from_ptr(ptr)
-            .to_str()
-            .unwrap_or_else(|_| panic!("CStr::from_ptr failed")),
-    )
+pub unsafe extern "C" fn uuid4_try_from_cstr(ptr: *const c_char, out: *mut UUID4) -> u8 {
+    write_parsed_cstr(ptr, out, "`UUID4`")
 }
 
+/// Returns a pointer to the [`UUID4`] value as a C string.
+///
+/// The pointer is borrowed from `uuid` and must not be freed.
 #[no_mangle]
 pub extern "C" fn uuid4_to_cstr(uuid: &UUID4) -> *const c_char {
-    str_to_cstr(&uuid.value)
+    uuid.to_cstr().as_ptr()
 }
 
 #[no_mangle]
@@ -149,7 +191,7 @@ pub extern "C" fn uuid4_hash(uuid: &UUID4) -> u64 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::ffi::CString;
+    use std::{ffi::CString, mem::MaybeUninit};
 
     use uuid;
 
@@ -158,7 +200,7 @@ mod tests {
     #[test]
     fn test_uuid4_new() {
         let uuid = UUID4::new();
-        let uuid_string = uuid.value.to_string();
+        let uuid_string = uuid.to_string();
         let uuid_parsed = Uuid::parse_str(&uuid_string).expect("Uuid::parse_str failed");
         assert_eq!(uuid_parsed.get_version().unwrap(), uuid::Version::Random);
         assert_eq!(uuid_parsed.to_string().len(), 36);
@@ -167,7 +209,7 @@ mod tests {
     #[test]
     fn test_uuid4_default() {
         let uuid: UUID4 = UUID4::default();
-        let uuid_string = uuid.value.to_string();
+        let uuid_string = uuid.to_string();
         let uuid_parsed = Uuid::parse_str(&uuid_string).expect("Uuid::parse_str failed");
         assert_eq!(uuid_parsed.get_version().unwrap(), uuid::Version::Random);
     }
@@ -176,7 +218,7 @@ mod tests {
     fn test_uuid4_from_str() {
         let uuid_string = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
         let uuid = UUID4::from(uuid_string);
-        let result_string = uuid.value.to_string();
+        let result_string = uuid.to_string();
         let result_parsed = Uuid::parse_str(&result_string).expect("Uuid::parse_str failed");
         let expected_parsed = Uuid::parse_str(uuid_string).expect("Uuid::parse_str failed");
         assert_eq!(result_parsed, expected_parsed);
@@ -190,6 +232,41 @@ mod tests {
         assert_ne!(uuid1, uuid2);
     }
 
+    #[test]
+    fn test_uuid4_value_is_nul_terminated() {
+        let uuid = UUID4::from("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        assert_eq!(uuid.value[36], 0);
+        assert_eq!(uuid.as_str(), "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        assert_eq!(
+            uuid.to_cstr().to_str().unwrap(),
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8"
+        );
+    }
+
+    #[test]
+    fn test_uuid4_from_str_normalizes_case() {
+        let uuid = UUID4::from("6BA7B810-9DAD-11D1-80B4-00C04FD430C8");
+        assert_eq!(uuid.to_string(), "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    }
+
+    #[test]
+    fn test_uuid4_debug() {
+        let uuid = UUID4::from("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        assert_eq!(
+            format!("{uuid:?}"),
+            "UUID4('6ba7b810-9dad-11d1-80b4-00c04fd430c8')"
+        );
+    }
+
+    #[test]
+    fn test_uuid4_serde_round_trip() {
+        let uuid = UUID4::from("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        let json = serde_json::to_string(&uuid).unwrap();
+        assert_eq!(json, "\"6ba7b810-9dad-11d1-80b4-00c04fd430c8\"");
+        let deserialized: UUID4 = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, uuid);
+    }
+
     #[test]
     fn test_uuid4_display() {
         let uuid_string = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
@@ -201,7 +278,7 @@ mod tests {
     #[test]
     fn test_c_api_uuid4_new() {
         let uuid = uuid4_new();
-        let uuid_string = uuid.value.to_string();
+        let uuid_string = uuid.to_string();
         let uuid_parsed = Uuid::parse_str(&uuid_string).expect("Uuid::parse_str failed");
         assert_eq!(uuid_parsed.get_version().unwrap(), uuid::Version::Random);
     }
@@ -211,14 +288,7 @@ mod tests {
         let uuid_string = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
         let uuid = UUID4::from(uuid_string);
         let uuid_cloned = uuid4_clone(&uuid);
-        assert_eq!(uuid.value.to_string(), uuid_cloned.value.to_string());
-    }
-
-    #[test]
-    fn test_c_api_uuid4_drop() {
-        let uuid_string = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
-        let uuid = UUID4::from(uuid_string);
-        uuid4_drop(uuid);
+        assert_eq!(uuid.to_string(), uuid_cloned.to_string());
     }
 
     #[test]
@@ -226,8 +296,10 @@ mod tests {
         let uuid_string = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
         let uuid_cstring = CString::new(uuid_string).expect("CString::new failed");
         let uuid_ptr = uuid_cstring.as_ptr();
-        let uuid = unsafe { uuid4_from_cstr(uuid_ptr) };
-        assert_eq!(uuid_string, uuid.value.to_string());
+        let mut out = MaybeUninit::<UUID4>::uninit();
+        let status = unsafe { uuid4_try_from_cstr(uuid_ptr, out.as_mut_ptr()) };
+        assert_eq!(status, 0);
+        assert_eq!(uuid_string, unsafe { out.assume_init() }.to_string());
     }
 
     #[test]
@@ -0,0 +1,667 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{HashMap, VecDeque};
+
+use nautilus_common::{clock::Clock, timer::TimeEvent};
+use nautilus_core::{correctness, time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    commands::{CancelOrder, SubmitOrder, TradingCommand},
+    data::tick::TradeTick,
+    enums::OrderType,
+    events::order::{OrderEvent, OrderInitialized},
+    identifiers::{client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId},
+    orders::Order,
+    types::{fixed::RoundingMode, quantity::Quantity},
+};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ExecAlgorithmError {
+    #[error("Cannot execute order {0}: only implemented for MARKET orders, was {1}")]
+    InvalidOrderType(ClientOrderId, OrderType),
+    #[error("Cannot execute order {0}: already being executed")]
+    DuplicateOrder(ClientOrderId),
+    #[error("Cannot execute order {0}: {1}")]
+    InvalidSchedule(ClientOrderId, String),
+}
+
+/// Represents how a primary order is sliced into child orders over time.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SliceSchedule {
+    /// Equal slices at every `interval_ns` over the `horizon_ns` (time-weighted).
+    Twap { horizon_ns: u64, interval_ns: u64 },
+    /// Slices at every `interval_ns`, each weighted by the expected fraction of market
+    /// volume traded in that interval (volume-weighted).
+    Vwap {
+        interval_ns: u64,
+        volume_profile: Vec<f64>,
+    },
+}
+
+impl SliceSchedule {
+    #[must_use]
+    pub fn interval_ns(&self) -> u64 {
+        match self {
+            SliceSchedule::Twap { interval_ns, .. } | SliceSchedule::Vwap { interval_ns, .. } => {
+                *interval_ns
+            }
+        }
+    }
+
+    /// Returns the sizes of the child orders to slice `quantity` into, one per interval.
+    ///
+    /// Slice sizes are rounded down to the `quantity` precision. For TWAP any remainder is
+    /// scheduled as a final slice, and for VWAP it is added to the last slice.
+    ///
+    /// # Errors
+    ///
+    /// - If the interval is zero, or the TWAP horizon is less than the interval.
+    /// - If the TWAP slice size rounds down to zero.
+    /// - If the VWAP volume profile is empty, or has negative or non-finite weights, or
+    ///   the weights do not sum to a positive value.
+    pub fn slice_sizes(&self, quantity: Quantity) -> Result<Vec<Quantity>, String> {
+        let precision = quantity.precision;
+        let floor =
+            |raw: u64| Quantity::from_raw(raw, precision).round(precision, RoundingMode::Floor);
+
+        match self {
+            SliceSchedule::Twap {
+                horizon_ns,
+                interval_ns,
+            } => {
+                if *interval_ns == 0 || horizon_ns < interval_ns {
+                    return Err(format!(
+                        "invalid TWAP schedule, horizon_ns={horizon_ns} interval_ns={interval_ns}"
+                    ));
+                }
+                let num_intervals = horizon_ns / interval_ns;
+                let per_interval = floor(quantity.raw / num_intervals);
+                if per_interval.is_zero() {
+                    return Err(format!(
+                        "TWAP slice size of {quantity} over {num_intervals} intervals was zero"
+                    ));
+                }
+                let mut sizes = vec![per_interval; num_intervals as usize];
+                let remainder_raw = quantity.raw - per_interval.raw * num_intervals;
+                if remainder_raw > 0 {
+                    sizes.push(Quantity::from_raw(remainder_raw, precision));
+                }
+                Ok(sizes)
+            }
+            SliceSchedule::Vwap {
+                interval_ns,
+                volume_profile,
+            } => {
+                if *interval_ns == 0 {
+                    return Err("invalid VWAP schedule, interval_ns=0".to_string());
+                }
+                if volume_profile
+                    .iter()
+                    .any(|weight| !weight.is_finite() || *weight < 0.0)
+                {
+                    return Err(format!(
+                        "invalid VWAP volume profile {volume_profile:?}, weights must be finite and non-negative"
+                    ));
+                }
+                let total: f64 = volume_profile.iter().sum();
+                if total <= 0.0 {
+                    return Err(format!(
+                        "invalid VWAP volume profile {volume_profile:?}, weights must sum to a positive value"
+                    ));
+                }
+                let mut sizes: Vec<Quantity> = volume_profile
+                    .iter()
+                    .map(|weight| floor((quantity.raw as f64 * weight / total) as u64))
+                    .collect();
+                let allocated_raw: u64 = sizes.iter().map(|size| size.raw).sum();
+                if let Some(last) = sizes.last_mut() {
+                    last.raw += quantity.raw.saturating_sub(allocated_raw);
+                }
+                Ok(sizes)
+            }
+        }
+    }
+}
+
+/// The state of a primary order being worked by the algorithm.
+struct PrimaryExecution {
+    template: OrderInitialized,
+    scheduled: VecDeque<Quantity>,
+    carry_raw: u64,
+    volume_raw: u64,
+    spawn_count: usize,
+    open_children: Vec<ClientOrderId>,
+}
+
+/// Provides an execution algorithm which slices primary MARKET orders into child MARKET
+/// orders on a [`SliceSchedule`], such as TWAP or VWAP.
+///
+/// The first slice is spawned immediately, then one per interval from a timer named
+/// [`ScheduledExecAlgorithm::timer_name`], set on the clock passed to
+/// [`ScheduledExecAlgorithm::execute`] using the default handler of the clock, which must
+/// route the event back to [`ScheduledExecAlgorithm::on_time_event`].
+///
+/// With a `participation_rate`, each slice after the first is capped at that fraction of
+/// the market volume traded since the previous slice (from [`ScheduledExecAlgorithm::on_trade_tick`]),
+/// with any capped quantity carried into the following interval. Child orders are spawned
+/// with client order IDs of the form `{primary}-E{n}`.
+pub struct ScheduledExecAlgorithm {
+    pub id: ExecAlgorithmId,
+    pub participation_rate: Option<f64>,
+    executions: HashMap<ClientOrderId, PrimaryExecution>,
+    output: Box<dyn FnMut(TradingCommand)>,
+}
+
+impl ScheduledExecAlgorithm {
+    /// Initializes a new `ScheduledExecAlgorithm` instance, sending its commands to `output`.
+    ///
+    /// # Panics
+    ///
+    /// - If `participation_rate` is not in the range (0, 1].
+    #[must_use]
+    pub fn new(
+        id: ExecAlgorithmId,
+        participation_rate: Option<f64>,
+        output: Box<dyn FnMut(TradingCommand)>,
+    ) -> Self {
+        if let Some(rate) = participation_rate {
+            correctness::f64_in_range_inclusive(rate, 0.0, 1.0, "`participation_rate`");
+            assert!(rate > 0.0, "`participation_rate` was zero");
+        }
+        Self {
+            id,
+            participation_rate,
+            executions: HashMap::new(),
+            output,
+        }
+    }
+
+    /// Returns the name of the slice timer for the given primary order.
+    #[must_use]
+    pub fn timer_name(&self, primary_order_id: &ClientOrderId) -> String {
+        format!("{}-{primary_order_id}", self.id)
+    }
+
+    /// Returns the count of primary orders being executed.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.executions.len()
+    }
+
+    #[must_use]
+    pub fn is_executing(&self, primary_order_id: &ClientOrderId) -> bool {
+        self.executions.contains_key(primary_order_id)
+    }
+
+    /// Returns the quantity of the primary order not yet spawned as child orders.
+    #[must_use]
+    pub fn remaining_qty(&self, primary_order_id: &ClientOrderId) -> Option<Quantity> {
+        self.executions.get(primary_order_id).map(|execution| {
+            let scheduled_raw: u64 = execution.scheduled.iter().map(|size| size.raw).sum();
+            Quantity::from_raw(
+                scheduled_raw + execution.carry_raw,
+                execution.template.quantity.precision,
+            )
+        })
+    }
+
+    /// Returns the spawned child orders of the primary order which are still open.
+    #[must_use]
+    pub fn open_children(&self, primary_order_id: &ClientOrderId) -> Vec<ClientOrderId> {
+        self.executions
+            .get(primary_order_id)
+            .map(|execution| execution.open_children.clone())
+            .unwrap_or_default()
+    }
+
+    /// Starts executing the primary `order` on the given `schedule`.
+    ///
+    /// An execution completes once its final slice is spawned.
+    ///
+    /// # Errors
+    ///
+    /// - If `order` is not a MARKET order.
+    /// - If `order` is already being executed.
+    /// - If `schedule` is invalid for the order quantity.
+    pub fn execute<C: Clock>(
+        &mut self,
+        order: &Order,
+        schedule: SliceSchedule,
+        clock: &mut C,
+    ) -> Result<(), ExecAlgorithmError> {
+        let primary_order_id = order.client_order_id.clone();
+        if order.order_type != OrderType::Market {
+            return Err(ExecAlgorithmError::InvalidOrderType(
+                primary_order_id,
+                order.order_type,
+            ));
+        }
+        if self.executions.contains_key(&primary_order_id) {
+            return Err(ExecAlgorithmError::DuplicateOrder(primary_order_id));
+        }
+        let sizes = schedule
+            .slice_sizes(order.quantity)
+            .map_err(|e| ExecAlgorithmError::InvalidSchedule(primary_order_id.clone(), e))?;
+
+        let template = OrderInitialized {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: primary_order_id.clone(),
+            order_side: order.side,
+            order_type: OrderType::Market,
+            quantity: order.quantity,
+            time_in_force: order.time_in_force,
+            reduce_only: order.is_reduce_only,
+            tags: order.tags.clone(),
+            ..Default::default()
+        };
+        self.executions.insert(
+            primary_order_id.clone(),
+            PrimaryExecution {
+                template,
+                scheduled: sizes.into(),
+                carry_raw: 0,
+                volume_raw: 0,
+                spawn_count: 0,
+                open_children: Vec::new(),
+            },
+        );
+
+        let ts_now = clock.timestamp_ns();
+        if self.spawn_next(&primary_order_id, ts_now, false) {
+            self.executions.remove(&primary_order_id);
+        } else {
+            let timer_name = self.timer_name(&primary_order_id);
+            clock.set_timer_ns(timer_name, schedule.interval_ns(), ts_now, None, None);
+        }
+        Ok(())
+    }
+
+    /// Handles the time `event`, spawning the next slice if the event is from one of the
+    /// slice timers.
+    pub fn on_time_event<C: Clock>(&mut self, event: &TimeEvent, clock: &mut C) {
+        let primary_order_id = match self
+            .executions
+            .keys()
+            .find(|primary_order_id| self.timer_name(primary_order_id) == event.name.as_str())
+        {
+            Some(primary_order_id) => primary_order_id.clone(),
+            None => return,
+        };
+
+        if self.spawn_next(&primary_order_id, event.ts_event, true) {
+            clock.cancel_timer(event.name.as_str());
+            self.executions.remove(&primary_order_id);
+        }
+    }
+
+    /// Accumulates the market volume from the `tick`, for participation rate caps.
+    pub fn on_trade_tick(&mut self, tick: &TradeTick) {
+        for execution in self.executions.values_mut() {
+            if execution.template.instrument_id == tick.instrument_id {
+                execution.volume_raw += tick.size.raw;
+            }
+        }
+    }
+
+    /// Handles the order `event`, tracking which spawned child orders remain open.
+    pub fn on_order_event(&mut self, event: &OrderEvent) {
+        let is_closed = matches!(
+            event,
+            OrderEvent::OrderDenied(_)
+                | OrderEvent::OrderRejected(_)
+                | OrderEvent::OrderCanceled(_)
+                | OrderEvent::OrderExpired(_)
+                | OrderEvent::OrderFilled(_)
+        );
+        if !is_closed {
+            return;
+        }
+        let client_order_id = event.client_order_id();
+        for execution in self.executions.values_mut() {
+            execution
+                .open_children
+                .retain(|child_order_id| child_order_id != client_order_id);
+        }
+    }
+
+    /// Stops executing the primary order, canceling any of its open child orders.
+    ///
+    /// Returns `false` if the primary order was not being executed.
+    pub fn cancel<C: Clock>(&mut self, primary_order_id: &ClientOrderId, clock: &mut C) -> bool {
+        let execution = match self.executions.remove(primary_order_id) {
+            Some(execution) => execution,
+            None => return false,
+        };
+        clock.cancel_timer(&self.timer_name(primary_order_id));
+
+        let ts_now = clock.timestamp_ns();
+        let template = &execution.template;
+        for client_order_id in execution.open_children {
+            let command = CancelOrder {
+                trader_id: template.trader_id.clone(),
+                client_id: None,
+                strategy_id: template.strategy_id.clone(),
+                instrument_id: template.instrument_id.clone(),
+                client_order_id,
+                venue_order_id: None,
+                command_id: UUID4::new(),
+                ts_init: ts_now,
+            };
+            (self.output)(TradingCommand::CancelOrder(command));
+        }
+        true
+    }
+
+    /// Stops all executions, without canceling their open child orders.
+    pub fn reset<C: Clock>(&mut self, clock: &mut C) {
+        for primary_order_id in self.executions.keys() {
+            clock.cancel_timer(&self.timer_name(primary_order_id));
+        }
+        self.executions.clear();
+    }
+
+    /// Spawns the next slice of the primary order, returning whether the execution is
+    /// complete.
+    fn spawn_next(
+        &mut self,
+        primary_order_id: &ClientOrderId,
+        ts_now: UnixNanos,
+        is_capped: bool,
+    ) -> bool {
+        let execution = match self.executions.get_mut(primary_order_id) {
+            Some(execution) => execution,
+            None => return true,
+        };
+        let precision = execution.template.quantity.precision;
+
+        let scheduled_raw = execution.scheduled.pop_front().map_or(0, |size| size.raw);
+        let mut target_raw = scheduled_raw + execution.carry_raw;
+        if let (true, Some(rate)) = (is_capped, self.participation_rate) {
+            let cap_raw = (execution.volume_raw as f64 * rate) as u64;
+            let cap = Quantity::from_raw(cap_raw, precision).round(precision, RoundingMode::Floor);
+            target_raw = target_raw.min(cap.raw);
+        }
+        execution.carry_raw = scheduled_raw + execution.carry_raw - target_raw;
+        execution.volume_raw = 0;
+
+        if target_raw > 0 {
+            execution.spawn_count += 1;
+            let client_order_id =
+                ClientOrderId::new(&format!("{primary_order_id}-E{}", execution.spawn_count));
+            let order = OrderInitialized {
+                client_order_id: client_order_id.clone(),
+                quantity: Quantity::from_raw(target_raw, precision),
+                event_id: UUID4::new(),
+                ts_event: ts_now,
+                ts_init: ts_now,
+                ..execution.template.clone()
+            };
+            execution.open_children.push(client_order_id);
+
+            let command = SubmitOrder {
+                trader_id: order.trader_id.clone(),
+                client_id: None,
+                strategy_id: order.strategy_id.clone(),
+                order,
+                position_id: None,
+                command_id: UUID4::new(),
+                ts_init: ts_now,
+            };
+            (self.output)(TradingCommand::SubmitOrder(command));
+        }
+
+        execution.scheduled.is_empty() && execution.carry_raw == 0
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc, str::FromStr};
+
+    use nautilus_common::clock::TestClock;
+    use nautilus_model::{
+        enums::AggressorSide,
+        events::order::{OrderCanceled, OrderInitializedBuilder},
+        identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+        types::price::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    type Store = Rc<RefCell<Vec<TradingCommand>>>;
+
+    const SECOND: u64 = 1_000_000_000;
+
+    fn test_clock() -> TestClock {
+        let mut clock = TestClock::new();
+        clock.register_default_handler(Box::new(|_| {}));
+        clock
+    }
+
+    fn algorithm(participation_rate: Option<f64>, commands: &Store) -> ScheduledExecAlgorithm {
+        let commands = commands.clone();
+        ScheduledExecAlgorithm::new(
+            ExecAlgorithmId::new("TWAP"),
+            participation_rate,
+            Box::new(move |command| commands.borrow_mut().push(command)),
+        )
+    }
+
+    fn primary_order(quantity: &str) -> Order {
+        OrderInitializedBuilder::default()
+            .instrument_id(InstrumentId::from_str("ETHUSDT.BINANCE").unwrap())
+            .client_order_id(ClientOrderId::new("O-1"))
+            .quantity(Quantity::from(quantity))
+            .build()
+            .unwrap()
+            .into()
+    }
+
+    fn twap(horizon_secs: u64, interval_secs: u64) -> SliceSchedule {
+        SliceSchedule::Twap {
+            horizon_ns: horizon_secs * SECOND,
+            interval_ns: interval_secs * SECOND,
+        }
+    }
+
+    fn advance(algorithm: &mut ScheduledExecAlgorithm, clock: &mut TestClock, to_time_ns: u64) {
+        for event in clock.advance_time(to_time_ns, true) {
+            algorithm.on_time_event(&event, clock);
+        }
+    }
+
+    fn submitted_quantities(commands: &Store) -> Vec<Quantity> {
+        commands
+            .borrow()
+            .iter()
+            .filter_map(|command| match command {
+                TradingCommand::SubmitOrder(submit) => Some(submit.order.quantity),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case("10", 3, vec!["3", "3", "3", "1"])]
+    #[case("9", 3, vec!["3", "3", "3"])]
+    #[case("1.00", 3, vec!["0.33", "0.33", "0.33", "0.01"])]
+    fn test_twap_slice_sizes(
+        #[case] quantity: &str,
+        #[case] num_intervals: u64,
+        #[case] expected: Vec<&str>,
+    ) {
+        let sizes = twap(num_intervals * 10, 10)
+            .slice_sizes(Quantity::from(quantity))
+            .unwrap();
+        let expected: Vec<Quantity> = expected.into_iter().map(Quantity::from).collect();
+
+        assert_eq!(sizes, expected);
+    }
+
+    #[test]
+    fn test_vwap_slice_sizes_follow_volume_profile() {
+        let schedule = SliceSchedule::Vwap {
+            interval_ns: SECOND,
+            volume_profile: vec![1.0, 2.0, 1.0],
+        };
+        let sizes = schedule.slice_sizes(Quantity::from("10")).unwrap();
+
+        assert_eq!(
+            sizes,
+            vec![
+                Quantity::from("2"),
+                Quantity::from("5"),
+                Quantity::from("3")
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(twap(5, 10))]
+    #[case(twap(10, 0))]
+    #[case(twap(100, 1))]
+    #[case(SliceSchedule::Vwap { interval_ns: SECOND, volume_profile: vec![] })]
+    #[case(SliceSchedule::Vwap { interval_ns: SECOND, volume_profile: vec![1.0, -1.0] })]
+    fn test_invalid_schedules(#[case] schedule: SliceSchedule) {
+        assert!(schedule.slice_sizes(Quantity::from("10")).is_err());
+    }
+
+    #[test]
+    fn test_execute_rejects_non_market_order() {
+        let commands = Store::default();
+        let mut algorithm = algorithm(None, &commands);
+        let mut clock = test_clock();
+        let order: Order = OrderInitializedBuilder::default()
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00")))
+            .build()
+            .unwrap()
+            .into();
+
+        let result = algorithm.execute(&order, twap(30, 10), &mut clock);
+
+        assert!(matches!(
+            result,
+            Err(ExecAlgorithmError::InvalidOrderType(_, OrderType::Limit))
+        ));
+        assert!(commands.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_twap_spawns_slices_on_interval() {
+        let commands = Store::default();
+        let mut algorithm = algorithm(None, &commands);
+        let mut clock = test_clock();
+        let order = primary_order("10");
+
+        algorithm.execute(&order, twap(30, 10), &mut clock).unwrap();
+        assert_eq!(submitted_quantities(&commands), vec![Quantity::from("3")]);
+        assert_eq!(
+            algorithm.remaining_qty(&order.client_order_id),
+            Some(Quantity::from("7"))
+        );
+        assert!(algorithm.execute(&order, twap(30, 10), &mut clock).is_err());
+
+        advance(&mut algorithm, &mut clock, 30 * SECOND);
+
+        assert_eq!(
+            submitted_quantities(&commands),
+            vec![
+                Quantity::from("3"),
+                Quantity::from("3"),
+                Quantity::from("3"),
+                Quantity::from("1"),
+            ]
+        );
+        assert!(!algorithm.is_executing(&order.client_order_id));
+        assert_eq!(clock.timer_count(), 0);
+
+        match &commands.borrow()[3] {
+            TradingCommand::SubmitOrder(submit) => {
+                assert_eq!(submit.order.client_order_id, ClientOrderId::new("O-1-E4"));
+                assert_eq!(submit.order.ts_init, 30 * SECOND);
+            }
+            command => panic!("unexpected command {command:?}"),
+        }
+    }
+
+    #[test]
+    fn test_participation_rate_caps_slices() {
+        let commands = Store::default();
+        let mut algorithm = algorithm(Some(0.5), &commands);
+        let mut clock = test_clock();
+        let order = primary_order("10");
+        algorithm.execute(&order, twap(20, 10), &mut clock).unwrap();
+
+        // Only 4 traded in the interval, so the second slice is capped at 2
+        let tick = TradeTick::new(
+            order.instrument_id.clone(),
+            Price::from("100.00"),
+            Quantity::from("4"),
+            AggressorSide::Buyer,
+            TradeId::new("1"),
+            SECOND,
+            SECOND,
+        );
+        algorithm.on_trade_tick(&tick);
+        advance(&mut algorithm, &mut clock, 10 * SECOND);
+
+        assert_eq!(
+            submitted_quantities(&commands),
+            vec![Quantity::from("5"), Quantity::from("2")]
+        );
+        assert_eq!(
+            algorithm.remaining_qty(&order.client_order_id),
+            Some(Quantity::from("3"))
+        );
+    }
+
+    #[test]
+    fn test_cancel_cancels_open_children() {
+        let commands = Store::default();
+        let mut algorithm = algorithm(None, &commands);
+        let mut clock = test_clock();
+        let order = primary_order("10");
+        algorithm.execute(&order, twap(30, 10), &mut clock).unwrap();
+        advance(&mut algorithm, &mut clock, 10 * SECOND);
+
+        let canceled = OrderCanceled {
+            client_order_id: ClientOrderId::new("O-1-E1"),
+            ..Default::default()
+        };
+        algorithm.on_order_event(&OrderEvent::OrderCanceled(canceled));
+        assert_eq!(
+            algorithm.open_children(&order.client_order_id),
+            vec![ClientOrderId::new("O-1-E2")]
+        );
+
+        assert!(algorithm.cancel(&order.client_order_id, &mut clock));
+        assert!(!algorithm.cancel(&order.client_order_id, &mut clock));
+        assert_eq!(clock.timer_count(), 0);
+        match commands.borrow().last() {
+            Some(TradingCommand::CancelOrder(cancel)) => {
+                assert_eq!(cancel.client_order_id, ClientOrderId::new("O-1-E2"));
+            }
+            command => panic!("unexpected command {command:?}"),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Execution algorithms which work a primary order on behalf of a strategy, by spawning
+//! and managing child orders, and the reconciliation of execution state against venue
+//! reports.
+//!
+//! Algorithms emit their orders as [`TradingCommand`]s through a callback, in the same way
+//! a strategy sends commands to the execution engine.
+//!
+//! [`TradingCommand`]: nautilus_model::commands::TradingCommand
+
+pub mod algorithm;
+pub mod reconciliation;
+pub mod reconciliation_api;
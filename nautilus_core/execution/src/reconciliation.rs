@@ -0,0 +1,759 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Reconciliation of cached execution state against the reports of a trading venue.
+//!
+//! On connect (or reconnect) the reports of a venue are diffed against the cached orders
+//! and positions. Any events missed while disconnected are inferred and returned for the
+//! execution engine to apply, and anything which cannot be reconciled is flagged as a
+//! [`Discrepancy`].
+
+use std::collections::HashMap;
+
+use nautilus_common::cache::Cache;
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide, OrderStatus},
+    events::order::{
+        OrderAccepted, OrderCanceled, OrderEvent, OrderExpired, OrderFilled, OrderRejected,
+        OrderTriggered,
+    },
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, trade_id::TradeId,
+        venue_order_id::VenueOrderId,
+    },
+    instruments::BaseInstrument,
+    orders::{Order, OrderError},
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+use thiserror::Error;
+
+/// Represents a difference between the cached state and the venue reports which could not
+/// be reconciled by inferring events.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum Discrepancy {
+    #[error("Order {venue_order_id} for {instrument_id} not found in the cache")]
+    ExternalOrder {
+        instrument_id: InstrumentId,
+        venue_order_id: VenueOrderId,
+    },
+    #[error("Open order {0} has no report from the venue")]
+    MissingOrderReport(ClientOrderId),
+    #[error("Order {client_order_id} filled qty of {cached} exceeds the reported {reported}")]
+    FilledQtyMismatch {
+        client_order_id: ClientOrderId,
+        cached: Quantity,
+        reported: Quantity,
+    },
+    #[error("Cannot infer fill for order {0} without a reported average price")]
+    MissingAvgPx(ClientOrderId),
+    #[error("Instrument {0} not found in the cache")]
+    InstrumentNotFound(InstrumentId),
+    #[error("Cannot reconcile order {client_order_id}: {error}")]
+    InvalidTransition {
+        client_order_id: ClientOrderId,
+        error: OrderError,
+    },
+    #[error("Position for {instrument_id} of {cached} does not match the reported {reported}")]
+    PositionMismatch {
+        instrument_id: InstrumentId,
+        cached: f64,
+        reported: f64,
+    },
+}
+
+/// Provides reconciliation of the cached orders and positions against venue reports.
+///
+/// Inferred events are generated in the order they should be applied, and are validated
+/// against a copy of the cached order so that each one is a valid state transition.
+#[derive(Debug, Default)]
+pub struct ExecutionReconciler {
+    events: Vec<OrderEvent>,
+    discrepancies: Vec<Discrepancy>,
+}
+
+impl ExecutionReconciler {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the discrepancies flagged since the last call to `reset`.
+    #[must_use]
+    pub fn discrepancies(&self) -> &[Discrepancy] {
+        &self.discrepancies
+    }
+
+    /// Returns the inferred events generated since the last drain, in the order they
+    /// should be applied.
+    pub fn drain_events(&mut self) -> Vec<OrderEvent> {
+        self.events.drain(..).collect()
+    }
+
+    pub fn reset(&mut self) {
+        self.events.clear();
+        self.discrepancies.clear();
+    }
+
+    /// Reconciles the `mass_status` of a venue against the `cache`, returning whether all
+    /// of the reports were reconciled without any discrepancies.
+    ///
+    /// Position reports are compared against the cached positions adjusted for any fills
+    /// inferred from the order reports.
+    pub fn reconcile_mass_status(
+        &mut self,
+        cache: &Cache,
+        mass_status: &ExecutionMassStatus,
+        ts_init: UnixNanos,
+    ) -> bool {
+        let discrepancy_count = self.discrepancies.len();
+        let event_count = self.events.len();
+
+        let mut reported = Vec::new();
+        for report in mass_status.order_reports() {
+            let fills = mass_status.fill_reports(&report.venue_order_id);
+            if let Some(client_order_id) =
+                self.reconcile_order_report(cache, report, fills, ts_init)
+            {
+                reported.push(client_order_id);
+            }
+        }
+
+        for order in cache.orders_open(Some(&mass_status.venue), None, None, None) {
+            if !reported.contains(&order.client_order_id) {
+                self.discrepancies.push(Discrepancy::MissingOrderReport(
+                    order.client_order_id.clone(),
+                ));
+            }
+        }
+
+        let mut inferred_qty: HashMap<InstrumentId, f64> = HashMap::new();
+        for event in &self.events[event_count..] {
+            if let OrderEvent::OrderPartiallyFilled(fill) | OrderEvent::OrderFilled(fill) = event {
+                let qty = match fill.order_side {
+                    OrderSide::Sell => -fill.last_qty.as_f64(),
+                    _ => fill.last_qty.as_f64(),
+                };
+                *inferred_qty.entry(fill.instrument_id.clone()).or_default() += qty;
+            }
+        }
+        for report in mass_status.position_reports() {
+            let adjustment = inferred_qty
+                .get(&report.instrument_id)
+                .copied()
+                .unwrap_or_default();
+            self.reconcile_position_report(cache, report, adjustment);
+        }
+
+        self.discrepancies.len() == discrepancy_count
+    }
+
+    /// Reconciles the order `report` (with the `fills` reported for the order) against the
+    /// cached order, returning the client order ID of the order if found.
+    pub fn reconcile_order_report(
+        &mut self,
+        cache: &Cache,
+        report: &OrderStatusReport,
+        fills: &[FillReport],
+        ts_init: UnixNanos,
+    ) -> Option<ClientOrderId> {
+        let cached = report
+            .client_order_id
+            .as_ref()
+            .and_then(|client_order_id| cache.order(client_order_id))
+            .or_else(|| {
+                cache
+                    .orders(None, Some(&report.instrument_id), None, None)
+                    .into_iter()
+                    .find(|order| order.venue_order_id.as_ref() == Some(&report.venue_order_id))
+            });
+        let mut order = match cached {
+            Some(order) => order.clone(),
+            None => {
+                self.discrepancies.push(Discrepancy::ExternalOrder {
+                    instrument_id: report.instrument_id.clone(),
+                    venue_order_id: report.venue_order_id.clone(),
+                });
+                return None;
+            }
+        };
+        let client_order_id = order.client_order_id.clone();
+
+        if let Err(error) = self.reconcile_order(cache, &mut order, report, fills, ts_init) {
+            self.discrepancies.push(Discrepancy::InvalidTransition {
+                client_order_id: client_order_id.clone(),
+                error,
+            });
+        }
+        Some(client_order_id)
+    }
+
+    /// Reconciles the position `report` against the net quantity of the open cached
+    /// positions for the instrument, plus the `adjustment` for any inferred fills.
+    pub fn reconcile_position_report(
+        &mut self,
+        cache: &Cache,
+        report: &PositionStatusReport,
+        adjustment: f64,
+    ) -> bool {
+        let cached: f64 = cache
+            .positions_open(None, Some(&report.instrument_id), None, None)
+            .iter()
+            .map(|position| position.signed_qty)
+            .sum::<f64>()
+            + adjustment;
+        let reported = report.signed_qty();
+
+        // Compare to within half of the smallest increment of the reported size precision
+        let tolerance = 0.5 * 10f64.powi(-i32::from(report.quantity.precision));
+        if (cached - reported).abs() > tolerance {
+            self.discrepancies.push(Discrepancy::PositionMismatch {
+                instrument_id: report.instrument_id.clone(),
+                cached,
+                reported,
+            });
+            return false;
+        }
+        true
+    }
+
+    fn reconcile_order(
+        &mut self,
+        cache: &Cache,
+        order: &mut Order,
+        report: &OrderStatusReport,
+        fills: &[FillReport],
+        ts_init: UnixNanos,
+    ) -> Result<(), OrderError> {
+        if report.order_status == OrderStatus::Rejected {
+            if !order.is_closed() {
+                let reason = report
+                    .cancel_reason
+                    .clone()
+                    .unwrap_or_else(|| "UNKNOWN".to_string());
+                self.apply(order, rejected(order, report, reason, ts_init))?;
+            }
+            return Ok(());
+        }
+
+        if matches!(
+            order.status,
+            OrderStatus::Initialized | OrderStatus::Submitted
+        ) {
+            self.apply(order, accepted(order, report, ts_init))?;
+        }
+
+        if report.order_status == OrderStatus::Triggered && order.status != OrderStatus::Triggered {
+            self.apply(order, triggered(order, report, ts_init))?;
+        }
+
+        self.reconcile_fills(cache, order, report, fills, ts_init)?;
+
+        if !order.is_closed() {
+            match report.order_status {
+                OrderStatus::Canceled => self.apply(order, canceled(order, report, ts_init))?,
+                OrderStatus::Expired => self.apply(order, expired(order, report, ts_init))?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn reconcile_fills(
+        &mut self,
+        cache: &Cache,
+        order: &mut Order,
+        report: &OrderStatusReport,
+        fills: &[FillReport],
+        ts_init: UnixNanos,
+    ) -> Result<(), OrderError> {
+        let trade_ids = order.trade_ids();
+        let mut missing: Vec<&FillReport> = fills
+            .iter()
+            .filter(|fill| !trade_ids.contains(&fill.trade_id))
+            .collect();
+        if missing.is_empty() && report.filled_qty == order.filled_qty {
+            return Ok(());
+        }
+
+        let instrument = match cache.instrument(&order.instrument_id) {
+            Some(instrument) => instrument,
+            None => {
+                self.discrepancies
+                    .push(Discrepancy::InstrumentNotFound(order.instrument_id.clone()));
+                return Ok(());
+            }
+        };
+
+        missing.sort_by_key(|fill| fill.ts_event);
+        for fill in missing {
+            let event = filled(order, instrument, fill, ts_init);
+            self.apply(order, event)?;
+        }
+
+        if report.filled_qty < order.filled_qty {
+            self.discrepancies.push(Discrepancy::FilledQtyMismatch {
+                client_order_id: order.client_order_id.clone(),
+                cached: order.filled_qty,
+                reported: report.filled_qty,
+            });
+        } else if report.filled_qty > order.filled_qty {
+            match inferred_fill(order, instrument, report, ts_init) {
+                Some(event) => self.apply(order, event)?,
+                None => self
+                    .discrepancies
+                    .push(Discrepancy::MissingAvgPx(order.client_order_id.clone())),
+            }
+        }
+        Ok(())
+    }
+
+    fn apply(&mut self, order: &mut Order, event: OrderEvent) -> Result<(), OrderError> {
+        order.apply(event.clone())?;
+        self.events.push(event);
+        Ok(())
+    }
+}
+
+fn accepted(order: &Order, report: &OrderStatusReport, ts_init: UnixNanos) -> OrderEvent {
+    OrderEvent::OrderAccepted(OrderAccepted {
+        trader_id: order.trader_id.clone(),
+        strategy_id: order.strategy_id.clone(),
+        instrument_id: order.instrument_id.clone(),
+        client_order_id: order.client_order_id.clone(),
+        venue_order_id: report.venue_order_id.clone(),
+        account_id: report.account_id.clone(),
+        event_id: UUID4::new(),
+        ts_event: report.ts_accepted,
+        ts_init,
+        reconciliation: true,
+    })
+}
+
+fn rejected(
+    order: &Order,
+    report: &OrderStatusReport,
+    reason: String,
+    ts_init: UnixNanos,
+) -> OrderEvent {
+    OrderEvent::OrderRejected(OrderRejected {
+        trader_id: order.trader_id.clone(),
+        strategy_id: order.strategy_id.clone(),
+        instrument_id: order.instrument_id.clone(),
+        client_order_id: order.client_order_id.clone(),
+        venue_order_id: report.venue_order_id.clone(),
+        account_id: report.account_id.clone(),
+        reason,
+        event_id: UUID4::new(),
+        ts_event: report.ts_last,
+        ts_init,
+        reconciliation: true,
+    })
+}
+
+fn triggered(order: &Order, report: &OrderStatusReport, ts_init: UnixNanos) -> OrderEvent {
+    OrderEvent::OrderTriggered(OrderTriggered {
+        trader_id: order.trader_id.clone(),
+        strategy_id: order.strategy_id.clone(),
+        instrument_id: order.instrument_id.clone(),
+        client_order_id: order.client_order_id.clone(),
+        venue_order_id: Some(report.venue_order_id.clone()),
+        account_id: Some(report.account_id.clone()),
+        event_id: UUID4::new(),
+        ts_event: report.ts_triggered.unwrap_or(report.ts_last),
+        ts_init,
+        reconciliation: true,
+    })
+}
+
+fn canceled(order: &Order, report: &OrderStatusReport, ts_init: UnixNanos) -> OrderEvent {
+    OrderEvent::OrderCanceled(OrderCanceled {
+        trader_id: order.trader_id.clone(),
+        strategy_id: order.strategy_id.clone(),
+        instrument_id: order.instrument_id.clone(),
+        client_order_id: order.client_order_id.clone(),
+        venue_order_id: Some(report.venue_order_id.clone()),
+        account_id: Some(report.account_id.clone()),
+        event_id: UUID4::new(),
+        ts_event: report.ts_last,
+        ts_init,
+        reconciliation: true,
+    })
+}
+
+fn expired(order: &Order, report: &OrderStatusReport, ts_init: UnixNanos) -> OrderEvent {
+    OrderEvent::OrderExpired(OrderExpired {
+        trader_id: order.trader_id.clone(),
+        strategy_id: order.strategy_id.clone(),
+        instrument_id: order.instrument_id.clone(),
+        client_order_id: order.client_order_id.clone(),
+        venue_order_id: Some(report.venue_order_id.clone()),
+        account_id: Some(report.account_id.clone()),
+        event_id: UUID4::new(),
+        ts_event: report.ts_last,
+        ts_init,
+        reconciliation: true,
+    })
+}
+
+/// Returns the fill event for a fill of `last_qty`, which is partial if less than the
+/// leaves quantity of the `order`.
+fn fill_event(order: &Order, fill: OrderFilled) -> OrderEvent {
+    if fill.last_qty < order.leaves_qty {
+        OrderEvent::OrderPartiallyFilled(fill)
+    } else {
+        OrderEvent::OrderFilled(fill)
+    }
+}
+
+fn filled(
+    order: &Order,
+    instrument: &BaseInstrument,
+    report: &FillReport,
+    ts_init: UnixNanos,
+) -> OrderEvent {
+    let fill = OrderFilled {
+        trader_id: order.trader_id.clone(),
+        strategy_id: order.strategy_id.clone(),
+        instrument_id: order.instrument_id.clone(),
+        client_order_id: order.client_order_id.clone(),
+        venue_order_id: report.venue_order_id.clone(),
+        account_id: report.account_id.clone(),
+        trade_id: report.trade_id.clone(),
+        position_id: report.venue_position_id.clone(),
+        order_side: report.order_side,
+        order_type: order.order_type,
+        last_qty: report.last_qty,
+        last_px: report.last_px,
+        currency: instrument.quote_currency.clone(),
+        commission: report.commission.clone(),
+        liquidity_side: report.liquidity_side,
+        event_id: UUID4::new(),
+        ts_event: report.ts_event,
+        ts_init,
+        reconciliation: true,
+    };
+    fill_event(order, fill)
+}
+
+/// Returns a fill for the difference between the reported and cached filled quantities,
+/// at the price which brings the average price of the order to the reported average.
+fn inferred_fill(
+    order: &Order,
+    instrument: &BaseInstrument,
+    report: &OrderStatusReport,
+    ts_init: UnixNanos,
+) -> Option<OrderEvent> {
+    let report_avg_px = report.avg_px?;
+    let last_qty = report.filled_qty - order.filled_qty;
+    let last_px = match order.avg_px {
+        Some(avg_px) => {
+            (report_avg_px * report.filled_qty.as_f64() - avg_px * order.filled_qty.as_f64())
+                / last_qty.as_f64()
+        }
+        None => report_avg_px,
+    };
+
+    let fill = OrderFilled {
+        trader_id: order.trader_id.clone(),
+        strategy_id: order.strategy_id.clone(),
+        instrument_id: order.instrument_id.clone(),
+        client_order_id: order.client_order_id.clone(),
+        venue_order_id: report.venue_order_id.clone(),
+        account_id: report.account_id.clone(),
+        trade_id: TradeId::new(&UUID4::new().to_string()),
+        position_id: None,
+        order_side: order.side,
+        order_type: order.order_type,
+        last_qty,
+        last_px: Price::new(last_px, instrument.price_precision),
+        currency: instrument.quote_currency.clone(),
+        commission: Money::new(0.0, instrument.quote_currency.clone()),
+        liquidity_side: LiquiditySide::NoLiquiditySide,
+        event_id: UUID4::new(),
+        ts_event: report.ts_last,
+        ts_init,
+        reconciliation: true,
+    };
+    Some(fill_event(order, fill))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        currencies::USD,
+        enums::{OrderType, PositionSide, TimeInForce},
+        events::order::{OrderAcceptedBuilder, OrderInitializedBuilder, OrderSubmittedBuilder},
+        identifiers::{
+            account_id::AccountId, client_id::ClientId, position_id::PositionId, venue::Venue,
+        },
+    };
+    use nautilus_test_kit::instruments::audusd_sim;
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Returns a cache holding an order for 100,000 AUD/USD, accepted if `accepted`
+    /// otherwise submitted.
+    fn cache(accepted: bool) -> Cache {
+        let instrument_id = audusd_sim().id;
+        let client_order_id = ClientOrderId::new("O-1");
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id.clone())
+            .client_order_id(client_order_id.clone())
+            .build()
+            .unwrap();
+        let mut order = Order::from(init);
+        let submitted = OrderSubmittedBuilder::default()
+            .instrument_id(instrument_id.clone())
+            .client_order_id(client_order_id.clone())
+            .build()
+            .unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        if accepted {
+            let accepted = OrderAcceptedBuilder::default()
+                .instrument_id(instrument_id)
+                .client_order_id(client_order_id)
+                .venue_order_id(VenueOrderId::new("V-1"))
+                .build()
+                .unwrap();
+            order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        }
+
+        let mut cache = Cache::default();
+        cache.add_instrument(audusd_sim());
+        cache.add_order(order, None).unwrap();
+        cache
+    }
+
+    fn order_report(
+        status: OrderStatus,
+        filled_qty: &str,
+        avg_px: Option<f64>,
+    ) -> OrderStatusReport {
+        OrderStatusReport {
+            account_id: AccountId::new("SIM-001"),
+            instrument_id: audusd_sim().id,
+            client_order_id: Some(ClientOrderId::new("O-1")),
+            order_list_id: None,
+            venue_order_id: VenueOrderId::new("V-1"),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            contingency_type: None,
+            time_in_force: TimeInForce::Gtc,
+            expire_time: None,
+            order_status: status,
+            price: None,
+            trigger_price: None,
+            trigger_type: None,
+            limit_offset: None,
+            trailing_offset: None,
+            trailing_offset_type: None,
+            quantity: Quantity::from("100000"),
+            filled_qty: Quantity::from(filled_qty),
+            display_qty: None,
+            avg_px,
+            post_only: false,
+            reduce_only: false,
+            cancel_reason: None,
+            report_id: UUID4::new(),
+            ts_accepted: 1,
+            ts_triggered: None,
+            ts_last: 2,
+            ts_init: 2,
+        }
+    }
+
+    fn fill_report(trade_id: &str, last_qty: &str) -> FillReport {
+        FillReport {
+            account_id: AccountId::new("SIM-001"),
+            instrument_id: audusd_sim().id,
+            client_order_id: Some(ClientOrderId::new("O-1")),
+            venue_order_id: VenueOrderId::new("V-1"),
+            venue_position_id: Some(PositionId::new("P-1")),
+            trade_id: TradeId::new(trade_id),
+            order_side: OrderSide::Buy,
+            last_qty: Quantity::from(last_qty),
+            last_px: Price::from("1.00000"),
+            commission: Money::new(2.0, USD.clone()),
+            liquidity_side: LiquiditySide::Taker,
+            report_id: UUID4::new(),
+            ts_event: 2,
+            ts_init: 2,
+        }
+    }
+
+    fn position_report(side: PositionSide, quantity: &str) -> PositionStatusReport {
+        PositionStatusReport {
+            account_id: AccountId::new("SIM-001"),
+            instrument_id: audusd_sim().id,
+            position_side: side,
+            quantity: Quantity::from(quantity),
+            venue_position_id: None,
+            report_id: UUID4::new(),
+            ts_last: 2,
+            ts_init: 2,
+        }
+    }
+
+    fn mass_status() -> ExecutionMassStatus {
+        ExecutionMassStatus::new(
+            ClientId::new("SIM"),
+            AccountId::new("SIM-001"),
+            Venue::new("SIM"),
+            UUID4::new(),
+            2,
+        )
+    }
+
+    fn event_names(events: &[OrderEvent]) -> Vec<&'static str> {
+        events.iter().map(OrderEvent::name).collect()
+    }
+
+    #[rstest]
+    fn test_reconcile_applies_missing_fill_reports() {
+        let cache = cache(true);
+        let mut reconciler = ExecutionReconciler::new();
+        let report = order_report(OrderStatus::Filled, "100000", Some(1.0));
+        let fills = [fill_report("T-1", "40000"), fill_report("T-2", "60000")];
+
+        reconciler.reconcile_order_report(&cache, &report, &fills, 3);
+        let events = reconciler.drain_events();
+
+        assert!(reconciler.discrepancies().is_empty());
+        assert_eq!(
+            event_names(&events),
+            vec!["OrderPartiallyFilled", "OrderFilled"]
+        );
+        match &events[1] {
+            OrderEvent::OrderFilled(fill) => {
+                assert_eq!(fill.trade_id, TradeId::new("T-2"));
+                assert_eq!(fill.position_id, Some(PositionId::new("P-1")));
+                assert!(fill.reconciliation);
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_reconcile_infers_fill_for_unreported_qty() {
+        let cache = cache(true);
+        let mut reconciler = ExecutionReconciler::new();
+        let report = order_report(OrderStatus::PartiallyFilled, "40000", Some(1.00002));
+
+        reconciler.reconcile_order_report(&cache, &report, &[], 3);
+        let events = reconciler.drain_events();
+
+        assert_eq!(event_names(&events), vec!["OrderPartiallyFilled"]);
+        match &events[0] {
+            OrderEvent::OrderPartiallyFilled(fill) => {
+                assert_eq!(fill.last_qty, Quantity::from("40000"));
+                assert_eq!(fill.last_px, Price::from("1.00002"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::NoLiquiditySide);
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_reconcile_infer_fill_without_avg_px() {
+        let cache = cache(true);
+        let mut reconciler = ExecutionReconciler::new();
+        let report = order_report(OrderStatus::PartiallyFilled, "40000", None);
+
+        reconciler.reconcile_order_report(&cache, &report, &[], 3);
+
+        assert!(reconciler.drain_events().is_empty());
+        assert_eq!(
+            reconciler.discrepancies(),
+            &[Discrepancy::MissingAvgPx(ClientOrderId::new("O-1"))]
+        );
+    }
+
+    #[rstest]
+    #[case(true, OrderStatus::Canceled, vec!["OrderCanceled"])]
+    #[case(false, OrderStatus::Canceled, vec!["OrderAccepted", "OrderCanceled"])]
+    #[case(false, OrderStatus::Expired, vec!["OrderAccepted", "OrderExpired"])]
+    #[case(false, OrderStatus::Rejected, vec!["OrderRejected"])]
+    #[case(true, OrderStatus::Accepted, vec![])]
+    fn test_reconcile_order_status(
+        #[case] accepted: bool,
+        #[case] status: OrderStatus,
+        #[case] expected: Vec<&'static str>,
+    ) {
+        let cache = cache(accepted);
+        let mut reconciler = ExecutionReconciler::new();
+        let report = order_report(status, "0", None);
+
+        reconciler.reconcile_order_report(&cache, &report, &[], 3);
+
+        assert!(reconciler.discrepancies().is_empty());
+        assert_eq!(event_names(&reconciler.drain_events()), expected);
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_flags_unknown_and_missing_orders() {
+        let cache = cache(true);
+        let mut reconciler = ExecutionReconciler::new();
+        let mut report = order_report(OrderStatus::Accepted, "0", None);
+        report.client_order_id = None;
+        report.venue_order_id = VenueOrderId::new("V-2");
+        let mut mass_status = mass_status();
+        mass_status.add_order_reports(vec![report]);
+
+        let result = reconciler.reconcile_mass_status(&cache, &mass_status, 3);
+
+        assert!(!result);
+        assert_eq!(
+            reconciler.discrepancies(),
+            &[
+                Discrepancy::ExternalOrder {
+                    instrument_id: audusd_sim().id,
+                    venue_order_id: VenueOrderId::new("V-2"),
+                },
+                Discrepancy::MissingOrderReport(ClientOrderId::new("O-1")),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("100000", PositionSide::Long, "100000", true)]
+    #[case("0", PositionSide::Long, "100000", false)]
+    #[case("0", PositionSide::Flat, "0", true)]
+    fn test_reconcile_mass_status_positions(
+        #[case] filled_qty: &str,
+        #[case] side: PositionSide,
+        #[case] quantity: &str,
+        #[case] expected: bool,
+    ) {
+        let cache = cache(true);
+        let mut reconciler = ExecutionReconciler::new();
+        let mut mass_status = mass_status();
+        mass_status.add_order_reports(vec![order_report(
+            OrderStatus::Accepted,
+            filled_qty,
+            Some(1.0),
+        )]);
+        mass_status.add_position_reports(vec![position_report(side, quantity)]);
+
+        let result = reconciler.reconcile_mass_status(&cache, &mass_status, 3);
+
+        assert_eq!(result, expected);
+    }
+}
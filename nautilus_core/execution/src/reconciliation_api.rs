@@ -0,0 +1,126 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+    ptr::null,
+};
+
+use nautilus_common::cache_api::Cache_API;
+use nautilus_core::{ffi_error::set_last_error, string::str_to_cstr, time::UnixNanos};
+use nautilus_model::reports_api::ExecutionMassStatus_API;
+use nautilus_serialization::dict::DictSerializable;
+
+use crate::reconciliation::ExecutionReconciler;
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`ExecutionReconciler`].
+///
+/// This struct wraps `ExecutionReconciler` in a way that makes it compatible with C function
+/// calls, enabling interaction with `ExecutionReconciler` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of
+/// `ExecutionReconciler_API` to be dereferenced to `ExecutionReconciler`, providing access to
+/// `ExecutionReconciler`'s methods without having to manually access the underlying instance.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct ExecutionReconciler_API(Box<ExecutionReconciler>);
+
+impl Deref for ExecutionReconciler_API {
+    type Target = ExecutionReconciler;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ExecutionReconciler_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn execution_reconciler_new() -> ExecutionReconciler_API {
+    ExecutionReconciler_API(Box::new(ExecutionReconciler::new()))
+}
+
+#[no_mangle]
+pub extern "C" fn execution_reconciler_drop(reconciler: ExecutionReconciler_API) {
+    drop(reconciler); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn execution_reconciler_reset(reconciler: &mut ExecutionReconciler_API) {
+    reconciler.reset();
+}
+
+/// Returns 1 if the `mass_status` was reconciled against the `cache` without any
+/// discrepancies, otherwise 0.
+#[no_mangle]
+pub extern "C" fn execution_reconciler_reconcile_mass_status(
+    reconciler: &mut ExecutionReconciler_API,
+    cache: &Cache_API,
+    mass_status: &ExecutionMassStatus_API,
+    ts_init: UnixNanos,
+) -> u8 {
+    u8::from(reconciler.reconcile_mass_status(cache, mass_status, ts_init))
+}
+
+#[no_mangle]
+pub extern "C" fn execution_reconciler_discrepancy_count(
+    reconciler: &ExecutionReconciler_API,
+) -> usize {
+    reconciler.discrepancies().len()
+}
+
+/// Returns the discrepancies as a JSON array of their descriptions, as a C string pointer.
+///
+/// The caller owns the returned string, and must free it with `cstr_drop`.
+#[no_mangle]
+pub extern "C" fn execution_reconciler_discrepancies_to_cstr(
+    reconciler: &ExecutionReconciler_API,
+) -> *const c_char {
+    let discrepancies: Vec<String> = reconciler
+        .discrepancies()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    str_to_cstr(&serde_json::Value::from(discrepancies).to_string())
+}
+
+/// Drains the inferred events as a JSON array of their dicts, in the format of the Python
+/// `to_dict` methods, as a C string pointer.
+///
+/// The caller owns the returned string, and must free it with `cstr_drop`. Returns null
+/// with the last error set if the events fail to serialize.
+#[no_mangle]
+pub extern "C" fn execution_reconciler_drain_events_to_cstr(
+    reconciler: &mut ExecutionReconciler_API,
+) -> *const c_char {
+    let events: Vec<_> = reconciler
+        .drain_events()
+        .iter()
+        .map(DictSerializable::to_dict)
+        .collect();
+    match serde_json::to_string(&events) {
+        Ok(json) => str_to_cstr(&json),
+        Err(e) => {
+            set_last_error(format!("Error serializing reconciliation events: {e}"));
+            null()
+        }
+    }
+}
@@ -0,0 +1,37 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Feeds arbitrary bytes to the DBN decoder, both as a whole stream and as a single record.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nautilus_adapters::databento::{decode::DbnDecoder, parsing::DbnRecord};
+use nautilus_model::identifiers::venue::Venue;
+
+/// Bounds the records decoded from each input, which can't be longer than the input.
+const MAX_RECORDS: usize = 1024;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = DbnRecord::decode(data);
+
+    if let Ok(decoder) = DbnDecoder::new(data, &Venue::new("GLBX"), 2, 0) {
+        for result in decoder.take(MAX_RECORDS) {
+            if result.is_err() {
+                break;
+            }
+        }
+    }
+});
@@ -0,0 +1,74 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Feeds arbitrary C strings to the enum parsers of the FFI, checking that any parsed
+//! value round trips through its string.
+
+#![no_main]
+
+use std::{ffi::CString, mem::MaybeUninit, str::FromStr};
+
+use libfuzzer_sys::fuzz_target;
+use nautilus_model::enums::*;
+
+/// Calls the fallible FFI parser, checking the round trip when a value is written.
+macro_rules! try_parse {
+    ($ptr:expr, $parser:ident, $type:ty) => {{
+        let mut out = MaybeUninit::<$type>::uninit();
+        if unsafe { $parser($ptr, out.as_mut_ptr()) } == 0 {
+            let value = unsafe { out.assume_init() };
+            assert_eq!(<$type>::from_str(&value.to_string()).ok(), Some(value));
+        }
+    }};
+}
+
+fuzz_target!(|data: &[u8]| {
+    // C strings can't hold interior nul bytes
+    let s = match CString::new(data) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+    let ptr = s.as_ptr();
+
+    try_parse!(ptr, account_type_try_from_cstr, AccountType);
+    try_parse!(ptr, aggregation_source_try_from_cstr, AggregationSource);
+    try_parse!(ptr, aggressor_side_try_from_cstr, AggressorSide);
+    try_parse!(ptr, asset_class_try_from_cstr, AssetClass);
+    try_parse!(ptr, asset_type_try_from_cstr, AssetType);
+    try_parse!(ptr, bar_aggregation_try_from_cstr, BarAggregation);
+    try_parse!(ptr, book_action_try_from_cstr, BookAction);
+    try_parse!(ptr, book_checksum_type_try_from_cstr, BookChecksumType);
+    try_parse!(ptr, book_type_try_from_cstr, BookType);
+    try_parse!(ptr, contingency_type_try_from_cstr, ContingencyType);
+    try_parse!(ptr, currency_type_try_from_cstr, CurrencyType);
+    try_parse!(
+        ptr,
+        instrument_close_type_try_from_cstr,
+        InstrumentCloseType
+    );
+    try_parse!(ptr, liquidity_side_try_from_cstr, LiquiditySide);
+    try_parse!(ptr, market_status_try_from_cstr, MarketStatus);
+    try_parse!(ptr, oms_type_try_from_cstr, OmsType);
+    try_parse!(ptr, option_kind_try_from_cstr, OptionKind);
+    try_parse!(ptr, order_side_try_from_cstr, OrderSide);
+    try_parse!(ptr, order_status_try_from_cstr, OrderStatus);
+    try_parse!(ptr, order_type_try_from_cstr, OrderType);
+    try_parse!(ptr, position_side_try_from_cstr, PositionSide);
+    try_parse!(ptr, price_type_try_from_cstr, PriceType);
+    try_parse!(ptr, time_in_force_try_from_cstr, TimeInForce);
+    try_parse!(ptr, trading_state_try_from_cstr, TradingState);
+    try_parse!(ptr, trailing_offset_type_try_from_cstr, TrailingOffsetType);
+    try_parse!(ptr, trigger_type_try_from_cstr, TriggerType);
+});
@@ -0,0 +1,73 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Feeds arbitrary C strings to the identifier constructors of the FFI.
+
+#![no_main]
+
+use std::{ffi::CString, mem::MaybeUninit};
+
+use libfuzzer_sys::fuzz_target;
+use nautilus_model::identifiers::{
+    account_id::{account_id_try_new, AccountId},
+    client_id::{client_id_try_new, ClientId},
+    client_order_id::{client_order_id_try_new, ClientOrderId},
+    component_id::{component_id_try_new, ComponentId},
+    exec_algorithm_id::{exec_algorithm_id_try_new, ExecAlgorithmId},
+    instrument_id::{instrument_id_try_from_cstr, InstrumentId},
+    order_list_id::{order_list_id_try_new, OrderListId},
+    position_id::{position_id_try_new, PositionId},
+    strategy_id::{strategy_id_try_new, StrategyId},
+    symbol::{symbol_try_new, Symbol},
+    trade_id::{inline_trade_id_try_new, trade_id_try_new, InlineTradeId, TradeId},
+    trader_id::{trader_id_try_new, TraderId},
+    venue::{venue_try_new, Venue},
+    venue_order_id::{venue_order_id_try_new, VenueOrderId},
+};
+
+/// Calls the fallible FFI constructor, dropping the identifier when one is written.
+macro_rules! try_new {
+    ($ptr:expr, $constructor:ident, $type:ty) => {{
+        let mut out = MaybeUninit::<$type>::uninit();
+        if unsafe { $constructor($ptr, out.as_mut_ptr()) } == 0 {
+            drop(unsafe { out.assume_init() });
+        }
+    }};
+}
+
+fuzz_target!(|data: &[u8]| {
+    // C strings can't hold interior nul bytes
+    let s = match CString::new(data) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+    let ptr = s.as_ptr();
+
+    try_new!(ptr, account_id_try_new, AccountId);
+    try_new!(ptr, client_id_try_new, ClientId);
+    try_new!(ptr, client_order_id_try_new, ClientOrderId);
+    try_new!(ptr, component_id_try_new, ComponentId);
+    try_new!(ptr, exec_algorithm_id_try_new, ExecAlgorithmId);
+    try_new!(ptr, instrument_id_try_from_cstr, InstrumentId);
+    try_new!(ptr, order_list_id_try_new, OrderListId);
+    try_new!(ptr, position_id_try_new, PositionId);
+    try_new!(ptr, strategy_id_try_new, StrategyId);
+    try_new!(ptr, symbol_try_new, Symbol);
+    try_new!(ptr, trade_id_try_new, TradeId);
+    try_new!(ptr, inline_trade_id_try_new, InlineTradeId);
+    try_new!(ptr, trader_id_try_new, TraderId);
+    try_new!(ptr, venue_try_new, Venue);
+    try_new!(ptr, venue_order_id_try_new, VenueOrderId);
+});
@@ -0,0 +1,45 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Feeds arbitrary bytes to the MessagePack deserializer, checking that anything
+//! deserialized can be serialized again.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nautilus_model::{
+    commands::TradingCommand,
+    events::order::OrderEvent,
+    reports::{FillReport, OrderStatusReport, PositionStatusReport},
+};
+use nautilus_serialization::{dict::DictSerializable, msgpack::MsgPackSerializer};
+
+fn round_trip<T: DictSerializable>(serializer: &MsgPackSerializer, bytes: &[u8]) {
+    if let Ok(obj) = serializer.deserialize::<T>(bytes) {
+        serializer
+            .serialize(&obj)
+            .expect("deserialized object should serialize");
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let serializer = MsgPackSerializer::default();
+
+    round_trip::<OrderEvent>(&serializer, data);
+    round_trip::<TradingCommand>(&serializer, data);
+    round_trip::<OrderStatusReport>(&serializer, data);
+    round_trip::<FillReport>(&serializer, data);
+    round_trip::<PositionStatusReport>(&serializer, data);
+});
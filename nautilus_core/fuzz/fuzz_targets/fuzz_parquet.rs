@@ -0,0 +1,42 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Feeds arbitrary bytes to the Parquet reader, as a file of the data type selected by
+//! the first byte.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nautilus_persistence::parquet::{reader::ParquetReader, ParquetType};
+
+const CHUNK_SIZE: usize = 1000;
+
+fuzz_target!(|data: &[u8]| {
+    let (parquet_type, buffer) = match data.split_first() {
+        Some((0, buffer)) => (ParquetType::QuoteTick, buffer),
+        Some((1, buffer)) => (ParquetType::TradeTick, buffer),
+        Some((2, buffer)) => (ParquetType::Bar, buffer),
+        Some((_, buffer)) => (ParquetType::OrderBookDelta, buffer),
+        None => return,
+    };
+
+    if let Ok(reader) = ParquetReader::from_buffer(buffer.to_vec(), parquet_type, CHUNK_SIZE) {
+        for result in reader {
+            if result.is_err() {
+                break;
+            }
+        }
+    }
+});
@@ -0,0 +1,215 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::data::{
+    bar::Bar,
+    tick::{QuoteTick, TradeTick},
+};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use crate::{sma::SimpleMovingAverage, Indicator};
+
+/// The average true range, a simple moving average of the true range of each bar.
+///
+/// The true range is only defined for bars, so ticks are ignored.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "python", pyclass)]
+pub struct AverageTrueRange {
+    pub period: usize,
+    pub use_previous: bool,
+    pub value_floor: f64,
+    pub value: f64,
+    pub count: usize,
+    ma: SimpleMovingAverage,
+    previous_close: f64,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for AverageTrueRange {
+    fn name(&self) -> String {
+        stringify!(AverageTrueRange).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {}
+
+    fn handle_trade_tick(&mut self, _tick: &TradeTick) {}
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.high.as_f64(), bar.low.as_f64(), bar.close.as_f64())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.ma.reset();
+        self.previous_close = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl AverageTrueRange {
+    /// Creates a new [`AverageTrueRange`], where `use_previous` (default true) includes
+    /// the previous close in the true range, and the value is floored at `value_floor`
+    /// (default zero for no floor).
+    #[must_use]
+    pub fn new(period: usize, use_previous: Option<bool>, value_floor: Option<f64>) -> Self {
+        Self {
+            period,
+            use_previous: use_previous.unwrap_or(true),
+            value_floor: value_floor.unwrap_or(0.0),
+            value: 0.0,
+            count: 0,
+            ma: SimpleMovingAverage::new(period, None),
+            previous_close: 0.0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        if self.use_previous {
+            if !self._has_inputs {
+                self.previous_close = close;
+            }
+            self.ma.update_raw(
+                f64::max(self.previous_close, high) - f64::min(low, self.previous_close),
+            );
+            self.previous_close = close;
+        } else {
+            self.ma.update_raw(high - low);
+        }
+        self.count += 1;
+        self._has_inputs = true;
+
+        // Floor the value (a zero floor is no floor)
+        self.value = if self.value_floor == 0.0 {
+            self.ma.value
+        } else {
+            f64::max(self.ma.value, self.value_floor)
+        };
+
+        // Initialization logic
+        if !self._is_initialized && Indicator::is_initialized(&self.ma) {
+            self._is_initialized = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl AverageTrueRange {
+    #[new]
+    fn py_new(period: usize, use_previous: Option<bool>, value_floor: Option<f64>) -> Self {
+        Self::new(period, use_previous, value_floor)
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    fn value_py(&self) -> f64 {
+        self.value
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.update_raw(bar.high.as_f64(), bar.low.as_f64(), bar.close.as_f64())
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atr_update_raw_with_previous_close() {
+        let mut atr = AverageTrueRange::new(2, None, None);
+        atr.update_raw(2.0, 1.0, 1.5);
+        atr.update_raw(3.0, 2.0, 2.5);
+
+        // True ranges of 1.0, then 3.0 - 1.5 with the gap up from the previous close
+        assert!(atr.is_initialized());
+        assert_eq!(atr.value, 1.25);
+    }
+
+    #[test]
+    fn test_atr_update_raw_without_previous_close() {
+        let mut atr = AverageTrueRange::new(2, Some(false), None);
+        atr.update_raw(2.0, 1.0, 1.5);
+        atr.update_raw(3.0, 2.0, 2.5);
+
+        assert_eq!(atr.value, 1.0);
+    }
+
+    #[test]
+    fn test_atr_value_floor() {
+        let mut atr = AverageTrueRange::new(2, None, Some(5.0));
+        atr.update_raw(2.0, 1.0, 1.5);
+
+        assert!(!atr.is_initialized());
+        assert_eq!(atr.value, 5.0);
+    }
+
+    #[test]
+    fn test_atr_reset() {
+        let mut atr = AverageTrueRange::new(2, None, None);
+        atr.update_raw(2.0, 1.0, 1.5);
+
+        atr.reset();
+
+        assert_eq!(atr.count, 0);
+        assert_eq!(atr.value, 0.0);
+        assert!(!atr.has_inputs());
+        assert!(!atr.is_initialized());
+    }
+}
@@ -0,0 +1,241 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use nautilus_model::data::{
+    bar::Bar,
+    tick::{QuoteTick, TradeTick},
+};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use crate::{sma::SimpleMovingAverage, Indicator};
+
+/// The Bollinger Bands, an upper and lower band at `k` standard deviations from a
+/// simple moving average of the typical price `(high + low + close) / 3`.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "python", pyclass)]
+pub struct BollingerBands {
+    pub period: usize,
+    pub k: f64,
+    pub upper: f64,
+    pub middle: f64,
+    pub lower: f64,
+    ma: SimpleMovingAverage,
+    prices: VecDeque<f64>,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for BollingerBands {
+    fn name(&self) -> String {
+        stringify!(BollingerBands).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        let mid = (tick.bid.as_f64() + tick.ask.as_f64()) / 2.0;
+        self.update_raw(mid, mid, mid)
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = tick.price.as_f64();
+        self.update_raw(price, price, price)
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.high.as_f64(), bar.low.as_f64(), bar.close.as_f64())
+    }
+
+    fn reset(&mut self) {
+        self.upper = 0.0;
+        self.middle = 0.0;
+        self.lower = 0.0;
+        self.ma.reset();
+        self.prices.clear();
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl BollingerBands {
+    #[must_use]
+    pub fn new(period: usize, k: f64) -> Self {
+        Self {
+            period,
+            k,
+            upper: 0.0,
+            middle: 0.0,
+            lower: 0.0,
+            ma: SimpleMovingAverage::new(period, None),
+            prices: VecDeque::with_capacity(period),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        let typical = (high + low + close) / 3.0;
+
+        if self.prices.len() == self.period {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(typical);
+        self.ma.update_raw(typical);
+        self._has_inputs = true;
+
+        // Initialization logic
+        if !self._is_initialized && self.prices.len() >= self.period {
+            self._is_initialized = true;
+        }
+
+        // Population standard deviation about the moving average
+        let mean = self.ma.value;
+        let variance = self
+            .prices
+            .iter()
+            .map(|price| (price - mean).powi(2))
+            .sum::<f64>()
+            / self.prices.len() as f64;
+        let std = variance.sqrt();
+
+        self.upper = mean + (self.k * std);
+        self.middle = mean;
+        self.lower = mean - (self.k * std);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl BollingerBands {
+    #[new]
+    fn py_new(period: usize, k: f64) -> Self {
+        Self::new(period, k)
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "upper")]
+    fn upper_py(&self) -> f64 {
+        self.upper
+    }
+
+    #[getter]
+    #[pyo3(name = "middle")]
+    fn middle_py(&self) -> f64 {
+        self.middle
+    }
+
+    #[getter]
+    #[pyo3(name = "lower")]
+    fn lower_py(&self) -> f64 {
+        self.lower
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        Indicator::handle_quote_tick(self, tick)
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        Indicator::handle_trade_tick(self, tick)
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.update_raw(bar.high.as_f64(), bar.low.as_f64(), bar.close.as_f64())
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bollinger_bands_update_raw() {
+        let mut bb = BollingerBands::new(2, 2.0);
+        bb.update_raw(1.0, 1.0, 1.0);
+
+        assert!(bb.has_inputs());
+        assert!(!bb.is_initialized());
+        assert_eq!((bb.upper, bb.middle, bb.lower), (1.0, 1.0, 1.0));
+
+        bb.update_raw(3.0, 3.0, 3.0);
+
+        assert!(bb.is_initialized());
+        assert_eq!(bb.middle, 2.0);
+        assert_eq!(bb.upper, 4.0);
+        assert_eq!(bb.lower, 0.0);
+    }
+
+    #[test]
+    fn test_bollinger_bands_window() {
+        let mut bb = BollingerBands::new(2, 2.0);
+        bb.update_raw(1.0, 1.0, 1.0);
+        bb.update_raw(3.0, 3.0, 3.0);
+        bb.update_raw(3.0, 3.0, 3.0);
+
+        assert_eq!((bb.upper, bb.middle, bb.lower), (3.0, 3.0, 3.0));
+    }
+
+    #[test]
+    fn test_bollinger_bands_reset() {
+        let mut bb = BollingerBands::new(2, 2.0);
+        bb.update_raw(1.0, 1.0, 1.0);
+
+        bb.reset();
+
+        assert_eq!(bb.middle, 0.0);
+        assert!(!bb.has_inputs());
+        assert!(!bb.is_initialized());
+    }
+}
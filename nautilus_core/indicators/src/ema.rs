@@ -20,13 +20,14 @@ use nautilus_model::{
     },
     enums::PriceType,
 };
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
 use crate::Indicator;
 
 #[repr(C)]
 #[derive(Debug)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct ExponentialMovingAverage {
     pub period: usize,
     pub price_type: PriceType,
@@ -70,10 +71,8 @@ impl Indicator for ExponentialMovingAverage {
     }
 }
 
-#[pymethods]
 impl ExponentialMovingAverage {
     #[must_use]
-    #[new]
     pub fn new(period: usize, price_type: Option<PriceType>) -> Self {
         Self {
             period,
@@ -86,6 +85,33 @@ impl ExponentialMovingAverage {
         }
     }
 
+    pub fn update_raw(&mut self, value: f64) {
+        if !self._has_inputs {
+            self._has_inputs = true;
+            self.value = value;
+        }
+
+        self.value = self.alpha.mul_add(value, (1.0 - self.alpha) * self.value);
+        self.count += 1;
+
+        // Initialization logic
+        if !self._is_initialized && self.count >= self.period {
+            self._is_initialized = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl ExponentialMovingAverage {
+    #[new]
+    fn py_new(period: usize, price_type: Option<PriceType>) -> Self {
+        Self::new(period, price_type)
+    }
+
     #[getter]
     #[pyo3(name = "name")]
     #[must_use]
@@ -93,6 +119,12 @@ impl ExponentialMovingAverage {
         self.name()
     }
 
+    #[getter]
+    #[pyo3(name = "value")]
+    fn value_py(&self) -> f64 {
+        self.value
+    }
+
     #[pyo3(name = "has_inputs")]
     fn has_inputs_py(&self) -> bool {
         self.has_inputs()
@@ -122,21 +154,6 @@ impl ExponentialMovingAverage {
     fn reset_py(&mut self) {
         self.reset()
     }
-
-    pub fn update_raw(&mut self, value: f64) {
-        if !self._has_inputs {
-            self._has_inputs = true;
-            self.value = value;
-        }
-
-        self.value = self.alpha.mul_add(value, (1.0 - self.alpha) * self.value);
-        self.count += 1;
-
-        // Initialization logic
-        if !self._is_initialized && self.count >= self.period {
-            self._is_initialized = true;
-        }
-    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -13,18 +13,36 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+//! The Python bindings are behind the `python` feature (on by default). Building with
+//! `--no-default-features` leaves them out, so the crate compiles to `wasm32-unknown-unknown`.
+
+pub mod atr;
+pub mod bollinger_bands;
 pub mod ema;
+pub mod macd;
+pub mod rsi;
+pub mod sma;
+pub mod stats;
+pub mod vwap;
 
 use nautilus_model::data::{
     bar::Bar,
     tick::{QuoteTick, TradeTick},
 };
+#[cfg(feature = "python")]
 use pyo3::{prelude::*, types::PyModule, Python};
 
 /// Loaded as nautilus_pyo3.indicators
+#[cfg(feature = "python")]
 #[pymodule]
 pub fn indicators(_: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<atr::AverageTrueRange>()?;
+    m.add_class::<bollinger_bands::BollingerBands>()?;
     m.add_class::<ema::ExponentialMovingAverage>()?;
+    m.add_class::<macd::MovingAverageConvergenceDivergence>()?;
+    m.add_class::<rsi::RelativeStrengthIndex>()?;
+    m.add_class::<sma::SimpleMovingAverage>()?;
+    m.add_class::<vwap::VolumeWeightedAveragePrice>()?;
     Ok(())
 }
 
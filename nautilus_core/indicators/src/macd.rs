@@ -0,0 +1,214 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use crate::{ema::ExponentialMovingAverage, Indicator};
+
+/// The moving average convergence divergence, the difference between a fast and a slow
+/// exponential moving average.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "python", pyclass)]
+pub struct MovingAverageConvergenceDivergence {
+    pub fast_period: usize,
+    pub slow_period: usize,
+    pub price_type: PriceType,
+    pub value: f64,
+    fast_ma: ExponentialMovingAverage,
+    slow_ma: ExponentialMovingAverage,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for MovingAverageConvergenceDivergence {
+    fn name(&self) -> String {
+        stringify!(MovingAverageConvergenceDivergence).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(self.price_type).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl MovingAverageConvergenceDivergence {
+    /// # Panics
+    ///
+    /// - If `slow_period` is not greater than `fast_period`.
+    #[must_use]
+    pub fn new(fast_period: usize, slow_period: usize, price_type: Option<PriceType>) -> Self {
+        assert!(
+            slow_period > fast_period,
+            "`slow_period` was <= `fast_period`"
+        );
+        Self {
+            fast_period,
+            slow_period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            fast_ma: ExponentialMovingAverage::new(fast_period, price_type),
+            slow_ma: ExponentialMovingAverage::new(slow_period, price_type),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, close: f64) {
+        self.fast_ma.update_raw(close);
+        self.slow_ma.update_raw(close);
+        self.value = self.fast_ma.value - self.slow_ma.value;
+        self._has_inputs = true;
+
+        // Initialization logic
+        if !self._is_initialized
+            && Indicator::is_initialized(&self.fast_ma)
+            && Indicator::is_initialized(&self.slow_ma)
+        {
+            self._is_initialized = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl MovingAverageConvergenceDivergence {
+    #[new]
+    fn py_new(fast_period: usize, slow_period: usize, price_type: Option<PriceType>) -> Self {
+        Self::new(fast_period, slow_period, price_type)
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    fn value_py(&self) -> f64 {
+        self.value
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(self.price_type).into())
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "`slow_period` was <= `fast_period`")]
+    fn test_macd_new_with_invalid_periods() {
+        let _ = MovingAverageConvergenceDivergence::new(3, 3, None);
+    }
+
+    #[test]
+    fn test_macd_update_raw() {
+        let mut macd = MovingAverageConvergenceDivergence::new(3, 5, None);
+        for value in [1.0, 2.0, 3.0, 4.0] {
+            macd.update_raw(value);
+        }
+
+        assert!(macd.has_inputs());
+        assert!(!macd.is_initialized());
+        assert!(macd.value > 0.0);
+
+        macd.update_raw(5.0);
+
+        assert!(macd.is_initialized());
+        assert_eq!(macd.value, macd.fast_ma.value - macd.slow_ma.value);
+    }
+
+    #[test]
+    fn test_macd_reset() {
+        let mut macd = MovingAverageConvergenceDivergence::new(3, 5, None);
+        macd.update_raw(1.0);
+        macd.update_raw(2.0);
+
+        macd.reset();
+
+        assert_eq!(macd.value, 0.0);
+        assert_eq!(macd.fast_ma.count, 0);
+        assert!(!macd.has_inputs());
+        assert!(!macd.is_initialized());
+    }
+}
@@ -0,0 +1,231 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::data::{
+    bar::Bar,
+    tick::{QuoteTick, TradeTick},
+};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use crate::{ema::ExponentialMovingAverage, Indicator};
+
+/// The relative strength index, the ratio of average gains to average losses scaled
+/// to a value between 0.0 and 1.0.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "python", pyclass)]
+pub struct RelativeStrengthIndex {
+    pub period: usize,
+    pub value: f64,
+    pub count: usize,
+    average_gain: ExponentialMovingAverage,
+    average_loss: ExponentialMovingAverage,
+    last_value: f64,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+const RSI_MAX: f64 = 1.0;
+
+impl Indicator for RelativeStrengthIndex {
+    fn name(&self) -> String {
+        stringify!(RelativeStrengthIndex).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw((tick.bid.as_f64() + tick.ask.as_f64()) / 2.0)
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.average_gain.reset();
+        self.average_loss.reset();
+        self.last_value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl RelativeStrengthIndex {
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            value: 0.0,
+            count: 0,
+            average_gain: ExponentialMovingAverage::new(period, None),
+            average_loss: ExponentialMovingAverage::new(period, None),
+            last_value: 0.0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        if !self._has_inputs {
+            self._has_inputs = true;
+            self.last_value = value;
+        }
+
+        let gain = value - self.last_value;
+        if gain > 0.0 {
+            self.average_gain.update_raw(gain);
+            self.average_loss.update_raw(0.0);
+        } else if gain < 0.0 {
+            self.average_gain.update_raw(0.0);
+            self.average_loss.update_raw(-gain);
+        } else {
+            self.average_gain.update_raw(0.0);
+            self.average_loss.update_raw(0.0);
+        }
+        self.count += 1;
+
+        // Initialization logic
+        if !self._is_initialized
+            && Indicator::is_initialized(&self.average_gain)
+            && Indicator::is_initialized(&self.average_loss)
+        {
+            self._is_initialized = true;
+        }
+
+        self.last_value = value;
+
+        if self.average_loss.value == 0.0 {
+            self.value = RSI_MAX;
+            return;
+        }
+
+        let rs = self.average_gain.value / self.average_loss.value;
+        self.value = RSI_MAX - (RSI_MAX / (1.0 + rs));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl RelativeStrengthIndex {
+    #[new]
+    fn py_new(period: usize) -> Self {
+        Self::new(period)
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    fn value_py(&self) -> f64 {
+        self.value
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        Indicator::handle_quote_tick(self, tick)
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rsi_with_only_gains() {
+        let mut rsi = RelativeStrengthIndex::new(3);
+        for value in [1.0, 2.0, 3.0] {
+            rsi.update_raw(value);
+        }
+
+        assert!(rsi.has_inputs());
+        assert!(rsi.is_initialized());
+        assert_eq!(rsi.value, 1.0);
+    }
+
+    #[test]
+    fn test_rsi_with_gains_and_losses() {
+        let mut rsi = RelativeStrengthIndex::new(3);
+        for value in [1.0, 2.0, 1.0, 2.0, 1.5] {
+            rsi.update_raw(value);
+        }
+
+        assert!(rsi.value > 0.0 && rsi.value < 1.0);
+        assert_eq!(rsi.count, 5);
+    }
+
+    #[test]
+    fn test_rsi_reset() {
+        let mut rsi = RelativeStrengthIndex::new(3);
+        rsi.update_raw(1.0);
+        rsi.update_raw(2.0);
+
+        rsi.reset();
+
+        assert_eq!(rsi.count, 0);
+        assert_eq!(rsi.value, 0.0);
+        assert!(!rsi.has_inputs());
+        assert!(!rsi.is_initialized());
+    }
+}
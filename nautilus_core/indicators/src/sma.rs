@@ -0,0 +1,202 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use crate::Indicator;
+
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "python", pyclass)]
+pub struct SimpleMovingAverage {
+    pub period: usize,
+    pub price_type: PriceType,
+    pub value: f64,
+    pub count: usize,
+    inputs: VecDeque<f64>,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for SimpleMovingAverage {
+    fn name(&self) -> String {
+        stringify!(SimpleMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(self.price_type).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.inputs.clear();
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl SimpleMovingAverage {
+    #[must_use]
+    pub fn new(period: usize, price_type: Option<PriceType>) -> Self {
+        Self {
+            period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            count: 0,
+            inputs: VecDeque::with_capacity(period),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        if self.inputs.len() == self.period {
+            self.inputs.pop_front();
+        }
+        self.inputs.push_back(value);
+
+        self.value = self.inputs.iter().sum::<f64>() / self.inputs.len() as f64;
+        self.count += 1;
+        self._has_inputs = true;
+
+        // Initialization logic
+        if !self._is_initialized && self.count >= self.period {
+            self._is_initialized = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl SimpleMovingAverage {
+    #[new]
+    fn py_new(period: usize, price_type: Option<PriceType>) -> Self {
+        Self::new(period, price_type)
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    fn value_py(&self) -> f64 {
+        self.value
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(self.price_type).into())
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sma_update_raw() {
+        let mut sma = SimpleMovingAverage::new(3, None);
+        sma.update_raw(1.0);
+        sma.update_raw(2.0);
+
+        assert!(sma.has_inputs());
+        assert!(!sma.is_initialized());
+        assert_eq!(sma.value, 1.5);
+
+        sma.update_raw(3.0);
+        sma.update_raw(4.0);
+
+        assert!(sma.is_initialized());
+        assert_eq!(sma.count, 4);
+        assert_eq!(sma.value, 3.0);
+    }
+
+    #[test]
+    fn test_sma_reset() {
+        let mut sma = SimpleMovingAverage::new(3, None);
+        sma.update_raw(1.0);
+        sma.update_raw(2.0);
+        sma.update_raw(3.0);
+
+        sma.reset();
+
+        assert_eq!(sma.count, 0);
+        assert_eq!(sma.value, 0.0);
+        assert!(!sma.has_inputs());
+        assert!(!sma.is_initialized());
+    }
+}
@@ -0,0 +1,461 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Streaming statistics over a rolling window, with O(1) (amortized) updates.
+//!
+//! The variance and covariance use Welford's online algorithm, with each value leaving
+//! the window removed by reversing its update, so no pass over the window is needed.
+
+use std::collections::VecDeque;
+
+/// Provides the rolling mean and sample variance of the last `period` values.
+#[derive(Clone, Debug)]
+pub struct RollingVariance {
+    pub period: usize,
+    values: VecDeque<f64>,
+    mean: f64,
+    m2: f64,
+}
+
+impl RollingVariance {
+    /// # Panics
+    ///
+    /// - If `period` is zero.
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "`period` was zero");
+        Self {
+            period,
+            values: VecDeque::with_capacity(period),
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    /// Returns the number of values in the window.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.values.len()
+    }
+
+    /// If the window is full.
+    #[must_use]
+    pub fn is_initialized(&self) -> bool {
+        self.values.len() == self.period
+    }
+
+    #[must_use]
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the sample variance, or zero with fewer than two values.
+    #[must_use]
+    pub fn variance(&self) -> f64 {
+        let n = self.values.len();
+        if n < 2 {
+            return 0.0;
+        }
+        // Rounding can leave a tiny negative residual for a constant window
+        (self.m2 / (n - 1) as f64).max(0.0)
+    }
+
+    /// Returns the sample standard deviation.
+    #[must_use]
+    pub fn std(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    pub fn update(&mut self, value: f64) {
+        if self.values.len() == self.period {
+            if let Some(old) = self.values.pop_front() {
+                self.remove(old);
+            }
+        }
+        self.values.push_back(value);
+
+        let n = self.values.len() as f64;
+        let delta = value - self.mean;
+        self.mean += delta / n;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    pub fn reset(&mut self) {
+        self.values.clear();
+        self.mean = 0.0;
+        self.m2 = 0.0;
+    }
+
+    /// Reverses the update for `old`, after it has left the window.
+    fn remove(&mut self, old: f64) {
+        if self.values.is_empty() {
+            self.mean = 0.0;
+            self.m2 = 0.0;
+            return;
+        }
+        let n = self.values.len() as f64;
+        let mean = self.mean;
+        self.mean = (mean * (n + 1.0) - old) / n;
+        self.m2 -= (old - self.mean) * (old - mean);
+    }
+}
+
+/// Provides the rolling sample covariance and correlation of the last `period` pairs
+/// of values.
+#[derive(Clone, Debug)]
+pub struct RollingCovariance {
+    pub period: usize,
+    values: VecDeque<(f64, f64)>,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    c: f64,
+}
+
+impl RollingCovariance {
+    /// # Panics
+    ///
+    /// - If `period` is zero.
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "`period` was zero");
+        Self {
+            period,
+            values: VecDeque::with_capacity(period),
+            mean_x: 0.0,
+            mean_y: 0.0,
+            m2_x: 0.0,
+            m2_y: 0.0,
+            c: 0.0,
+        }
+    }
+
+    /// Returns the number of pairs in the window.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.values.len()
+    }
+
+    /// If the window is full.
+    #[must_use]
+    pub fn is_initialized(&self) -> bool {
+        self.values.len() == self.period
+    }
+
+    /// Returns the sample covariance, or zero with fewer than two pairs.
+    #[must_use]
+    pub fn covariance(&self) -> f64 {
+        let n = self.values.len();
+        if n < 2 {
+            return 0.0;
+        }
+        self.c / (n - 1) as f64
+    }
+
+    /// Returns the Pearson correlation, or zero if either series has no variance.
+    #[must_use]
+    pub fn correlation(&self) -> f64 {
+        let denominator = (self.m2_x * self.m2_y).sqrt();
+        if self.values.len() < 2 || denominator <= f64::EPSILON {
+            return 0.0;
+        }
+        (self.c / denominator).clamp(-1.0, 1.0)
+    }
+
+    pub fn update(&mut self, x: f64, y: f64) {
+        if self.values.len() == self.period {
+            if let Some(old) = self.values.pop_front() {
+                self.remove(old);
+            }
+        }
+        self.values.push_back((x, y));
+
+        let n = self.values.len() as f64;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x += dx / n;
+        self.mean_y += dy / n;
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+        self.c += dx * (y - self.mean_y);
+    }
+
+    pub fn reset(&mut self) {
+        self.values.clear();
+        self.mean_x = 0.0;
+        self.mean_y = 0.0;
+        self.m2_x = 0.0;
+        self.m2_y = 0.0;
+        self.c = 0.0;
+    }
+
+    /// Reverses the update for the pair `(x, y)`, after it has left the window.
+    fn remove(&mut self, (x, y): (f64, f64)) {
+        if self.values.is_empty() {
+            self.reset();
+            return;
+        }
+        let n = self.values.len() as f64;
+        let (mean_x, mean_y) = (self.mean_x, self.mean_y);
+        self.mean_x = (mean_x * (n + 1.0) - x) / n;
+        self.mean_y = (mean_y * (n + 1.0) - y) / n;
+        self.m2_x -= (x - self.mean_x) * (x - mean_x);
+        self.m2_y -= (y - self.mean_y) * (y - mean_y);
+        self.c -= (x - self.mean_x) * (y - mean_y);
+    }
+}
+
+/// Provides a monotonic deque of `(index, value)`, where `keep(a, b)` holds when an
+/// earlier value `a` can still be the extremum after a later value `b`.
+#[derive(Clone, Debug)]
+struct MonotonicDeque {
+    period: usize,
+    count: usize,
+    deque: VecDeque<(usize, f64)>,
+    keep: fn(f64, f64) -> bool,
+}
+
+impl MonotonicDeque {
+    fn new(period: usize, keep: fn(f64, f64) -> bool) -> Self {
+        assert!(period > 0, "`period` was zero");
+        Self {
+            period,
+            count: 0,
+            deque: VecDeque::with_capacity(period),
+            keep,
+        }
+    }
+
+    fn update(&mut self, value: f64) {
+        while let Some(&(_, back)) = self.deque.back() {
+            if (self.keep)(back, value) {
+                break;
+            }
+            self.deque.pop_back();
+        }
+        self.deque.push_back((self.count, value));
+        self.count += 1;
+
+        // Drop the front when it has left the window
+        while let Some(&(index, _)) = self.deque.front() {
+            if index + self.period > self.count - 1 {
+                break;
+            }
+            self.deque.pop_front();
+        }
+    }
+
+    fn value(&self) -> Option<f64> {
+        self.deque.front().map(|(_, value)| *value)
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.deque.clear();
+    }
+}
+
+/// Provides the rolling maximum of the last `period` values.
+#[derive(Clone, Debug)]
+pub struct RollingMax {
+    inner: MonotonicDeque,
+}
+
+impl RollingMax {
+    /// # Panics
+    ///
+    /// - If `period` is zero.
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        Self {
+            inner: MonotonicDeque::new(period, |earlier, later| earlier > later),
+        }
+    }
+
+    /// Returns the maximum in the window, or `None` before any update.
+    #[must_use]
+    pub fn value(&self) -> Option<f64> {
+        self.inner.value()
+    }
+
+    /// If the window is full.
+    #[must_use]
+    pub fn is_initialized(&self) -> bool {
+        self.inner.count >= self.inner.period
+    }
+
+    pub fn update(&mut self, value: f64) {
+        self.inner.update(value);
+    }
+
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+/// Provides the rolling minimum of the last `period` values.
+#[derive(Clone, Debug)]
+pub struct RollingMin {
+    inner: MonotonicDeque,
+}
+
+impl RollingMin {
+    /// # Panics
+    ///
+    /// - If `period` is zero.
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        Self {
+            inner: MonotonicDeque::new(period, |earlier, later| earlier < later),
+        }
+    }
+
+    /// Returns the minimum in the window, or `None` before any update.
+    #[must_use]
+    pub fn value(&self) -> Option<f64> {
+        self.inner.value()
+    }
+
+    /// If the window is full.
+    #[must_use]
+    pub fn is_initialized(&self) -> bool {
+        self.inner.count >= self.inner.period
+    }
+
+    pub fn update(&mut self, value: f64) {
+        self.inner.update(value);
+    }
+
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_approx_eq(left: f64, right: f64) {
+        assert!((left - right).abs() < 1e-9, "{left} != {right}");
+    }
+
+    fn naive_variance(values: &[f64]) -> f64 {
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64
+    }
+
+    #[test]
+    fn test_rolling_variance_matches_naive() {
+        let values = [1.0, 4.0, 2.0, 8.0, 5.0, 7.0, 3.0];
+        let mut variance = RollingVariance::new(3);
+
+        for (i, value) in values.iter().enumerate() {
+            variance.update(*value);
+            let window = &values[i.saturating_sub(2)..=i];
+            let expected_mean = window.iter().sum::<f64>() / window.len() as f64;
+
+            assert_approx_eq(variance.mean(), expected_mean);
+            if window.len() > 1 {
+                assert_approx_eq(variance.variance(), naive_variance(window));
+            }
+        }
+        assert!(variance.is_initialized());
+        assert_eq!(variance.count(), 3);
+    }
+
+    #[test]
+    fn test_rolling_variance_constant_values() {
+        let mut variance = RollingVariance::new(2);
+        for _ in 0..10 {
+            variance.update(0.1);
+        }
+
+        assert_approx_eq(variance.std(), 0.0);
+        assert!(variance.variance() >= 0.0);
+    }
+
+    #[test]
+    fn test_rolling_variance_reset() {
+        let mut variance = RollingVariance::new(2);
+        variance.update(1.0);
+        variance.update(2.0);
+        variance.reset();
+
+        assert_eq!(variance.count(), 0);
+        assert_eq!(variance.mean(), 0.0);
+        assert_eq!(variance.variance(), 0.0);
+    }
+
+    #[test]
+    fn test_rolling_covariance_and_correlation() {
+        let mut covariance = RollingCovariance::new(3);
+        covariance.update(100.0, 1.0);
+        covariance.update(1.0, 2.0);
+        covariance.update(2.0, 4.0);
+        covariance.update(3.0, 6.0);
+
+        // Window is (1, 2), (2, 4), (3, 6)
+        assert_approx_eq(covariance.covariance(), 2.0);
+        assert_approx_eq(covariance.correlation(), 1.0);
+
+        covariance.update(4.0, 2.0);
+
+        // Window is (2, 4), (3, 6), (4, 2)
+        assert_approx_eq(covariance.covariance(), -1.0);
+        assert_approx_eq(covariance.correlation(), -0.5);
+    }
+
+    #[test]
+    fn test_rolling_correlation_without_variance() {
+        let mut covariance = RollingCovariance::new(3);
+        covariance.update(1.0, 1.0);
+        covariance.update(1.0, 2.0);
+
+        assert_eq!(covariance.correlation(), 0.0);
+    }
+
+    #[test]
+    fn test_rolling_max() {
+        let mut max = RollingMax::new(3);
+        assert_eq!(max.value(), None);
+
+        let expected = [1.0, 3.0, 3.0, 3.0, 4.0, 4.0, 4.0];
+        for (value, expected) in [1.0, 3.0, 2.0, 1.0, 4.0, 0.0, 0.0].iter().zip(expected) {
+            max.update(*value);
+            assert_eq!(max.value(), Some(expected));
+        }
+        assert!(max.is_initialized());
+    }
+
+    #[test]
+    fn test_rolling_min() {
+        let mut min = RollingMin::new(2);
+        min.update(3.0);
+        assert!(!min.is_initialized());
+
+        let expected = [1.0, 1.0, 2.0, 2.0];
+        for (value, expected) in [1.0, 2.0, 2.0, 5.0].iter().zip(expected) {
+            min.update(*value);
+            assert_eq!(min.value(), Some(expected));
+        }
+
+        min.reset();
+        assert_eq!(min.value(), None);
+    }
+}
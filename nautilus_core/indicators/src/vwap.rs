@@ -0,0 +1,215 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::data::{
+    bar::Bar,
+    tick::{QuoteTick, TradeTick},
+};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use crate::Indicator;
+
+const NANOSECONDS_IN_DAY: u64 = 86_400_000_000_000;
+
+/// The volume weighted average price, which resets at the start of each UTC day.
+///
+/// Quote ticks carry no traded volume, so are ignored.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "python", pyclass)]
+pub struct VolumeWeightedAveragePrice {
+    pub value: f64,
+    day: u64,
+    price_volume: f64,
+    volume_total: f64,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for VolumeWeightedAveragePrice {
+    fn name(&self) -> String {
+        stringify!(VolumeWeightedAveragePrice).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {}
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw(tick.price.as_f64(), tick.size.as_f64(), tick.ts_event)
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.close.as_f64(), bar.volume.as_f64(), bar.ts_init)
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.day = 0;
+        self.price_volume = 0.0;
+        self.volume_total = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl VolumeWeightedAveragePrice {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            value: 0.0,
+            day: 0,
+            price_volume: 0.0,
+            volume_total: 0.0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, price: f64, volume: f64, ts: UnixNanos) {
+        // On a new day reset the indicator
+        let day = ts / NANOSECONDS_IN_DAY;
+        if !self._has_inputs || day != self.day {
+            self.reset();
+            self.day = day;
+            self.value = price;
+        }
+
+        // Initialization logic
+        self._has_inputs = true;
+        self._is_initialized = true;
+
+        // No weighting for this price (also avoiding divide by zero)
+        if volume == 0.0 {
+            return;
+        }
+
+        self.price_volume += price * volume;
+        self.volume_total += volume;
+        self.value = self.price_volume / self.volume_total;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl VolumeWeightedAveragePrice {
+    #[new]
+    fn py_new() -> Self {
+        Self::new()
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    fn value_py(&self) -> f64 {
+        self.value
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        Indicator::handle_trade_tick(self, tick)
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        Indicator::handle_bar(self, bar)
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset()
+    }
+}
+
+impl Default for VolumeWeightedAveragePrice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vwap_update_raw() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        vwap.update_raw(1.0, 1.0, 0);
+        vwap.update_raw(2.0, 3.0, 1);
+
+        assert!(vwap.has_inputs());
+        assert!(vwap.is_initialized());
+        assert_eq!(vwap.value, 1.75);
+    }
+
+    #[test]
+    fn test_vwap_with_zero_volume() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        vwap.update_raw(1.0, 0.0, 0);
+
+        assert_eq!(vwap.value, 1.0);
+    }
+
+    #[test]
+    fn test_vwap_resets_on_new_day() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        vwap.update_raw(1.0, 1.0, 0);
+        vwap.update_raw(3.0, 1.0, NANOSECONDS_IN_DAY);
+
+        assert_eq!(vwap.value, 3.0);
+    }
+
+    #[test]
+    fn test_vwap_reset() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        vwap.update_raw(1.0, 1.0, 0);
+
+        vwap.reset();
+
+        assert_eq!(vwap.value, 0.0);
+        assert!(!vwap.has_inputs());
+        assert!(!vwap.is_initialized());
+    }
+}
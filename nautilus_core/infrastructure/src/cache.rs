@@ -0,0 +1,361 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use nautilus_model::identifiers::{
+    account_id::AccountId, client_order_id::ClientOrderId, position_id::PositionId,
+};
+use strum::{Display, EnumString};
+use thiserror::Error;
+
+/// Represents a collection of keys within a cache database.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, Display, EnumString)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "snake_case")]
+pub enum CacheCollection {
+    General,
+    Currencies,
+    Instruments,
+    Accounts,
+    Orders,
+    Positions,
+    Actors,
+    Strategies,
+    Commands,
+}
+
+impl CacheCollection {
+    /// Returns whether entries in the collection are append-only logs of events
+    /// (accounts, orders and positions), rather than single values.
+    #[must_use]
+    pub fn is_event_log(&self) -> bool {
+        matches!(self, Self::Accounts | Self::Orders | Self::Positions)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum CacheDatabaseError {
+    #[error("Redis error: {0}")]
+    Redis(#[from] redis::RedisError),
+    #[error("Cannot append to '{0}': collection does not hold event logs")]
+    NotEventLog(CacheCollection),
+    #[error("Invalid cache collection '{0}'")]
+    InvalidCollection(String),
+}
+
+/// Provides a database for persisting cache state.
+///
+/// Each entry is identified by its collection and key. Entries in an event log
+/// collection hold every appended value in order, other entries hold a single value
+/// which is replaced on each insert.
+pub trait CacheDatabase {
+    /// Deletes all entries from the database.
+    fn flush(&mut self) -> Result<(), CacheDatabaseError>;
+
+    /// Returns all keys within the `collection`.
+    fn keys(&mut self, collection: CacheCollection) -> Result<Vec<String>, CacheDatabaseError>;
+
+    /// Returns all values for the entry (empty if no such entry).
+    fn read(
+        &mut self,
+        collection: CacheCollection,
+        key: &str,
+    ) -> Result<Vec<Vec<u8>>, CacheDatabaseError>;
+
+    /// Sets the entry to the single `value`, replacing any existing values.
+    fn insert(
+        &mut self,
+        collection: CacheCollection,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), CacheDatabaseError>;
+
+    /// Appends the `value` to the event log entry.
+    fn append(
+        &mut self,
+        collection: CacheCollection,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), CacheDatabaseError>;
+
+    /// Deletes the entry (a no-op if no such entry).
+    fn delete(&mut self, collection: CacheCollection, key: &str) -> Result<(), CacheDatabaseError>;
+
+    /// Returns all entries within the `collection`, keyed by entry key.
+    fn load(
+        &mut self,
+        collection: CacheCollection,
+    ) -> Result<HashMap<String, Vec<Vec<u8>>>, CacheDatabaseError> {
+        let mut entries = HashMap::new();
+        for key in self.keys(collection)? {
+            let values = self.read(collection, &key)?;
+            entries.insert(key, values);
+        }
+        Ok(entries)
+    }
+
+    /// Adds the account with its initial serialized `state`.
+    fn add_account(
+        &mut self,
+        account_id: &AccountId,
+        state: Vec<u8>,
+    ) -> Result<(), CacheDatabaseError> {
+        let key = account_id.to_string();
+        self.delete(CacheCollection::Accounts, &key)?;
+        self.append(CacheCollection::Accounts, &key, state)
+    }
+
+    /// Appends the serialized account `state` to the accounts event log.
+    fn update_account(
+        &mut self,
+        account_id: &AccountId,
+        state: Vec<u8>,
+    ) -> Result<(), CacheDatabaseError> {
+        self.append(CacheCollection::Accounts, &account_id.to_string(), state)
+    }
+
+    /// Adds the order with its serialized initialization `event`.
+    fn add_order(
+        &mut self,
+        client_order_id: &ClientOrderId,
+        event: Vec<u8>,
+    ) -> Result<(), CacheDatabaseError> {
+        let key = client_order_id.to_string();
+        self.delete(CacheCollection::Orders, &key)?;
+        self.append(CacheCollection::Orders, &key, event)
+    }
+
+    /// Appends the serialized order `event` to the orders event log.
+    fn update_order(
+        &mut self,
+        client_order_id: &ClientOrderId,
+        event: Vec<u8>,
+    ) -> Result<(), CacheDatabaseError> {
+        self.append(CacheCollection::Orders, &client_order_id.to_string(), event)
+    }
+
+    /// Adds the position with its serialized opening `event`.
+    fn add_position(
+        &mut self,
+        position_id: &PositionId,
+        event: Vec<u8>,
+    ) -> Result<(), CacheDatabaseError> {
+        let key = position_id.to_string();
+        self.delete(CacheCollection::Positions, &key)?;
+        self.append(CacheCollection::Positions, &key, event)
+    }
+
+    /// Appends the serialized position `event` to the positions event log.
+    fn update_position(
+        &mut self,
+        position_id: &PositionId,
+        event: Vec<u8>,
+    ) -> Result<(), CacheDatabaseError> {
+        self.append(CacheCollection::Positions, &position_id.to_string(), event)
+    }
+}
+
+/// Provides an in-memory [`CacheDatabase`], primarily for testing and backtesting.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryCacheDatabase {
+    entries: HashMap<CacheCollection, HashMap<String, Vec<Vec<u8>>>>,
+}
+
+impl InMemoryCacheDatabase {
+    /// Initializes a new empty `InMemoryCacheDatabase` instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CacheDatabase for InMemoryCacheDatabase {
+    fn flush(&mut self) -> Result<(), CacheDatabaseError> {
+        self.entries.clear();
+        Ok(())
+    }
+
+    fn keys(&mut self, collection: CacheCollection) -> Result<Vec<String>, CacheDatabaseError> {
+        let mut keys: Vec<String> = self
+            .entries
+            .get(&collection)
+            .map(|entries| entries.keys().cloned().collect())
+            .unwrap_or_default();
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn read(
+        &mut self,
+        collection: CacheCollection,
+        key: &str,
+    ) -> Result<Vec<Vec<u8>>, CacheDatabaseError> {
+        Ok(self
+            .entries
+            .get(&collection)
+            .and_then(|entries| entries.get(key))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn insert(
+        &mut self,
+        collection: CacheCollection,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), CacheDatabaseError> {
+        self.entries
+            .entry(collection)
+            .or_default()
+            .insert(key.to_string(), vec![value]);
+        Ok(())
+    }
+
+    fn append(
+        &mut self,
+        collection: CacheCollection,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), CacheDatabaseError> {
+        if !collection.is_event_log() {
+            return Err(CacheDatabaseError::NotEventLog(collection));
+        }
+
+        self.entries
+            .entry(collection)
+            .or_default()
+            .entry(key.to_string())
+            .or_default()
+            .push(value);
+        Ok(())
+    }
+
+    fn delete(&mut self, collection: CacheCollection, key: &str) -> Result<(), CacheDatabaseError> {
+        if let Some(entries) = self.entries.get_mut(&collection) {
+            entries.remove(key);
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(CacheCollection::General, "general")]
+    #[case(CacheCollection::Orders, "orders")]
+    #[case(CacheCollection::Strategies, "strategies")]
+    fn test_collection_display_and_parse(
+        #[case] collection: CacheCollection,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(collection.to_string(), expected);
+        assert_eq!(CacheCollection::from_str(expected).unwrap(), collection);
+    }
+
+    #[test]
+    fn test_insert_replaces_value() {
+        let mut db = InMemoryCacheDatabase::new();
+
+        db.insert(CacheCollection::General, "key", b"a".to_vec())
+            .unwrap();
+        db.insert(CacheCollection::General, "key", b"b".to_vec())
+            .unwrap();
+
+        assert_eq!(
+            db.read(CacheCollection::General, "key").unwrap(),
+            vec![b"b".to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_append_to_single_value_collection_returns_error() {
+        let mut db = InMemoryCacheDatabase::new();
+
+        let result = db.append(CacheCollection::Currencies, "AUD", b"a".to_vec());
+
+        assert!(matches!(
+            result,
+            Err(CacheDatabaseError::NotEventLog(CacheCollection::Currencies))
+        ));
+    }
+
+    #[test]
+    fn test_add_and_update_order() {
+        let mut db = InMemoryCacheDatabase::new();
+        let client_order_id = ClientOrderId::new("O-123456");
+
+        db.add_order(&client_order_id, b"initialized".to_vec())
+            .unwrap();
+        db.update_order(&client_order_id, b"submitted".to_vec())
+            .unwrap();
+
+        let orders = db.load(CacheCollection::Orders).unwrap();
+        assert_eq!(
+            orders.get("O-123456").unwrap(),
+            &vec![b"initialized".to_vec(), b"submitted".to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_add_position_resets_event_log() {
+        let mut db = InMemoryCacheDatabase::new();
+        let position_id = PositionId::new("P-123456");
+
+        db.add_position(&position_id, b"opened".to_vec()).unwrap();
+        db.update_position(&position_id, b"changed".to_vec())
+            .unwrap();
+        db.add_position(&position_id, b"opened".to_vec()).unwrap();
+
+        assert_eq!(
+            db.read(CacheCollection::Positions, "P-123456").unwrap(),
+            vec![b"opened".to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_keys_delete_and_flush() {
+        let mut db = InMemoryCacheDatabase::new();
+        let account_id = AccountId::new("SIM-001");
+
+        db.add_account(&account_id, b"state".to_vec()).unwrap();
+        db.insert(CacheCollection::General, "b", b"b".to_vec())
+            .unwrap();
+        db.insert(CacheCollection::General, "a", b"a".to_vec())
+            .unwrap();
+
+        assert_eq!(db.keys(CacheCollection::General).unwrap(), vec!["a", "b"]);
+        assert_eq!(db.keys(CacheCollection::Accounts).unwrap(), vec!["SIM-001"]);
+
+        db.delete(CacheCollection::General, "a").unwrap();
+        assert_eq!(db.keys(CacheCollection::General).unwrap(), vec!["b"]);
+
+        db.flush().unwrap();
+        assert!(db.keys(CacheCollection::General).unwrap().is_empty());
+        assert!(db
+            .read(CacheCollection::Accounts, "SIM-001")
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,290 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+use nautilus_model::identifiers::trader_id::TraderId;
+use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
+use redis::{Client, Commands, Connection};
+
+use crate::cache::{CacheCollection, CacheDatabase, CacheDatabaseError};
+
+/// Provides a [`CacheDatabase`] backed by Redis.
+///
+/// Keys follow the layout `trader-{trader_id}:{collection}:{key}`, compatible with the
+/// existing Python implementation. Event log entries are stored as Redis lists, all
+/// other entries as Redis strings.
+pub struct RedisCacheDatabase {
+    /// The trader ID for the database.
+    pub trader_id: TraderId,
+    trader_key: String,
+    conn: Connection,
+}
+
+impl RedisCacheDatabase {
+    /// Initializes a new `RedisCacheDatabase` instance connected to the given `url`.
+    ///
+    /// # Errors
+    ///
+    /// - If the `url` is not a valid Redis connection URL.
+    /// - If the connection to Redis fails.
+    pub fn new(trader_id: TraderId, url: &str) -> Result<Self, CacheDatabaseError> {
+        let client = Client::open(url)?;
+        let conn = client.get_connection()?;
+
+        Ok(Self {
+            trader_key: format!("trader-{trader_id}"),
+            trader_id,
+            conn,
+        })
+    }
+
+    fn collection_key(&self, collection: CacheCollection) -> String {
+        format!("{}:{}:", self.trader_key, collection)
+    }
+
+    fn entry_key(&self, collection: CacheCollection, key: &str) -> String {
+        format!("{}{}", self.collection_key(collection), key)
+    }
+}
+
+impl CacheDatabase for RedisCacheDatabase {
+    fn flush(&mut self) -> Result<(), CacheDatabaseError> {
+        let pattern = format!("{}:*", self.trader_key);
+        let keys: Vec<String> = self.conn.scan_match::<_, String>(pattern)?.collect();
+        if !keys.is_empty() {
+            self.conn.del::<_, ()>(keys)?;
+        }
+        Ok(())
+    }
+
+    fn keys(&mut self, collection: CacheCollection) -> Result<Vec<String>, CacheDatabaseError> {
+        let prefix = self.collection_key(collection);
+        let pattern = format!("{prefix}*");
+        let mut keys: Vec<String> = self
+            .conn
+            .scan_match::<_, String>(pattern)?
+            .filter_map(|key| key.strip_prefix(&prefix).map(String::from))
+            .collect();
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn read(
+        &mut self,
+        collection: CacheCollection,
+        key: &str,
+    ) -> Result<Vec<Vec<u8>>, CacheDatabaseError> {
+        let entry_key = self.entry_key(collection, key);
+        if collection.is_event_log() {
+            Ok(self.conn.lrange(entry_key, 0, -1)?)
+        } else {
+            let value: Option<Vec<u8>> = self.conn.get(entry_key)?;
+            Ok(value.into_iter().collect())
+        }
+    }
+
+    fn insert(
+        &mut self,
+        collection: CacheCollection,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), CacheDatabaseError> {
+        let entry_key = self.entry_key(collection, key);
+        if collection.is_event_log() {
+            // Replace the event log atomically
+            redis::pipe()
+                .atomic()
+                .del(&entry_key)
+                .ignore()
+                .rpush(&entry_key, value)
+                .ignore()
+                .query::<()>(&mut self.conn)?;
+        } else {
+            self.conn.set::<_, _, ()>(entry_key, value)?;
+        }
+        Ok(())
+    }
+
+    fn append(
+        &mut self,
+        collection: CacheCollection,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), CacheDatabaseError> {
+        if !collection.is_event_log() {
+            return Err(CacheDatabaseError::NotEventLog(collection));
+        }
+
+        let entry_key = self.entry_key(collection, key);
+        self.conn.rpush::<_, _, ()>(entry_key, value)?;
+        Ok(())
+    }
+
+    fn delete(&mut self, collection: CacheCollection, key: &str) -> Result<(), CacheDatabaseError> {
+        let entry_key = self.entry_key(collection, key);
+        self.conn.del::<_, ()>(entry_key)?;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+
+/// Provides a Python API for a [`RedisCacheDatabase`].
+///
+/// All methods return awaitables, with the blocking Redis I/O performed on the tokio
+/// blocking thread pool so the Python event loop is never stalled.
+#[pyclass(name = "RedisCacheDatabase")]
+#[derive(Clone)]
+pub struct PyRedisCacheDatabase {
+    inner: Arc<Mutex<RedisCacheDatabase>>,
+}
+
+fn to_py_err(e: impl std::fmt::Display) -> PyErr {
+    PyErr::new::<PyException, _>(format!("Error in cache database: {e}"))
+}
+
+fn parse_collection(collection: &str) -> PyResult<CacheCollection> {
+    CacheCollection::from_str(collection).map_err(|_| {
+        to_py_err(CacheDatabaseError::InvalidCollection(
+            collection.to_string(),
+        ))
+    })
+}
+
+impl PyRedisCacheDatabase {
+    /// Runs the given database operation on the blocking thread pool, returning a
+    /// Python awaitable for the result.
+    fn run<'py, F, T>(&self, py: Python<'py>, op: F) -> PyResult<&'py PyAny>
+    where
+        F: FnOnce(&mut RedisCacheDatabase) -> Result<T, CacheDatabaseError> + Send + 'static,
+        T: IntoPy<PyObject> + Send + 'static,
+    {
+        let inner = self.inner.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let result = tokio::task::spawn_blocking(move || {
+                let mut db = inner.lock().expect("Cache database mutex poisoned");
+                op(&mut db)
+            })
+            .await
+            .map_err(to_py_err)?;
+            result.map_err(to_py_err)
+        })
+    }
+}
+
+fn to_py_bytes(py: Python<'_>, values: Vec<Vec<u8>>) -> Vec<Py<PyBytes>> {
+    values
+        .iter()
+        .map(|value| PyBytes::new(py, value).into())
+        .collect()
+}
+
+#[pymethods]
+impl PyRedisCacheDatabase {
+    #[new]
+    fn py_new(trader_id: &str, url: &str) -> PyResult<Self> {
+        let db = RedisCacheDatabase::new(TraderId::new(trader_id), url).map_err(to_py_err)?;
+        Ok(Self {
+            inner: Arc::new(Mutex::new(db)),
+        })
+    }
+
+    fn flush<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        self.run(py, |db| db.flush())
+    }
+
+    fn keys<'py>(&self, py: Python<'py>, collection: &str) -> PyResult<&'py PyAny> {
+        let collection = parse_collection(collection)?;
+        self.run(py, move |db| db.keys(collection))
+    }
+
+    fn read<'py>(&self, py: Python<'py>, collection: &str, key: String) -> PyResult<&'py PyAny> {
+        let collection = parse_collection(collection)?;
+        self.run(py, move |db| {
+            let values = db.read(collection, &key)?;
+            Ok(Python::with_gil(|py| to_py_bytes(py, values)))
+        })
+    }
+
+    fn load<'py>(&self, py: Python<'py>, collection: &str) -> PyResult<&'py PyAny> {
+        let collection = parse_collection(collection)?;
+        self.run(py, move |db| {
+            let entries = db.load(collection)?;
+            Ok(Python::with_gil(|py| {
+                entries
+                    .into_iter()
+                    .map(|(key, values)| (key, to_py_bytes(py, values)))
+                    .collect::<HashMap<String, Vec<Py<PyBytes>>>>()
+            }))
+        })
+    }
+
+    fn insert<'py>(
+        &self,
+        py: Python<'py>,
+        collection: &str,
+        key: String,
+        value: &PyBytes,
+    ) -> PyResult<&'py PyAny> {
+        let collection = parse_collection(collection)?;
+        let value = value.as_bytes().to_vec();
+        self.run(py, move |db| db.insert(collection, &key, value))
+    }
+
+    fn append<'py>(
+        &self,
+        py: Python<'py>,
+        collection: &str,
+        key: String,
+        value: &PyBytes,
+    ) -> PyResult<&'py PyAny> {
+        let collection = parse_collection(collection)?;
+        let value = value.as_bytes().to_vec();
+        self.run(py, move |db| db.append(collection, &key, value))
+    }
+
+    fn delete<'py>(&self, py: Python<'py>, collection: &str, key: String) -> PyResult<&'py PyAny> {
+        let collection = parse_collection(collection)?;
+        self.run(py, move |db| db.delete(collection, &key))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_with_invalid_url_returns_error() {
+        let result = RedisCacheDatabase::new(TraderId::new("TRADER-001"), "not-a-url");
+
+        assert!(matches!(result, Err(CacheDatabaseError::Redis(_))));
+    }
+
+    #[test]
+    fn test_parse_collection() {
+        assert_eq!(parse_collection("ORDERS").unwrap(), CacheCollection::Orders);
+        assert!(parse_collection("unknown").is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use std::str::FromStr;
+
+use criterion::{black_box, criterion_group, BatchSize, Criterion};
+use nautilus_model::{
+    data::{
+        book::{BookOrder, OrderBookDelta},
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::{AggressorSide, BookAction, BookType, OrderSide},
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    orderbook::book::OrderBook,
+    types::{price::Price, quantity::Quantity},
+};
+
+const NUM_LEVELS: u64 = 100;
+
+/// Returns deltas adding `NUM_LEVELS` orders per side, then updating and deleting each of them.
+fn deltas(instrument_id: &InstrumentId) -> Vec<OrderBookDelta> {
+    let mut deltas = Vec::new();
+    let mut sequence = 0;
+    for action in [BookAction::Add, BookAction::Update, BookAction::Delete] {
+        for i in 0..NUM_LEVELS {
+            for (side, raw_price) in [
+                (OrderSide::Buy, 99_999 - i as i64),
+                (OrderSide::Sell, 100_001 + i as i64),
+            ] {
+                let order_id = raw_price as u64;
+                let size = if action == BookAction::Update {
+                    200
+                } else {
+                    100
+                };
+                sequence += 1;
+                deltas.push(OrderBookDelta::new(
+                    instrument_id.clone(),
+                    action,
+                    BookOrder::new(
+                        side,
+                        Price::from_raw(raw_price, 5),
+                        Quantity::from_raw(size, 0),
+                        order_id,
+                    ),
+                    0,
+                    sequence,
+                    sequence,
+                    sequence,
+                ));
+            }
+        }
+    }
+    deltas
+}
+
+pub fn criterion_data_benchmark(c: &mut Criterion) {
+    let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+
+    c.bench_function("quote_tick_new", |b| {
+        b.iter(|| {
+            QuoteTick::new(
+                black_box(instrument_id.clone()),
+                black_box(Price::from_raw(100_000, 5)),
+                black_box(Price::from_raw(100_002, 5)),
+                black_box(Quantity::from_raw(100_000, 0)),
+                black_box(Quantity::from_raw(100_000, 0)),
+                black_box(1),
+                black_box(1),
+            )
+        })
+    });
+
+    let trade_id = TradeId::new("123456789");
+    c.bench_function("trade_tick_new", |b| {
+        b.iter(|| {
+            TradeTick::new(
+                black_box(instrument_id.clone()),
+                black_box(Price::from_raw(100_000, 5)),
+                black_box(Quantity::from_raw(100_000, 0)),
+                black_box(AggressorSide::Buyer),
+                black_box(trade_id.clone()),
+                black_box(1),
+                black_box(1),
+            )
+        })
+    });
+
+    let deltas = deltas(&instrument_id);
+    c.bench_function("orderbook_apply_deltas_l3", |b| {
+        b.iter_batched(
+            || OrderBook::new(instrument_id.clone(), BookType::L3_MBO),
+            |mut book| {
+                for delta in &deltas {
+                    book.apply_delta(delta.clone());
+                }
+                book
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, criterion_data_benchmark);
+criterion::criterion_main!(benches);
@@ -0,0 +1,60 @@
+use std::{str::FromStr, sync::Arc};
+
+use criterion::{black_box, criterion_group, Criterion};
+use nautilus_model::identifiers::{
+    instrument_id::InstrumentId,
+    interner::intern,
+    symbol::Symbol,
+    trade_id::{InlineTradeId, TradeId},
+};
+
+pub fn criterion_identifier_benchmark(c: &mut Criterion) {
+    c.bench_function("symbol_new", |b| {
+        b.iter(|| Symbol::new(black_box("AUD/USD")))
+    });
+
+    let interned1 = Symbol::new("AUD/USD");
+    let interned2 = Symbol::new("AUD/USD");
+    c.bench_function("symbol_eq_interned", |b| {
+        b.iter(|| black_box(&interned1) == black_box(&interned2))
+    });
+
+    let separate1 = Symbol {
+        value: Box::new(Arc::new(String::from("AUD/USD"))),
+    };
+    let separate2 = Symbol {
+        value: Box::new(Arc::new(String::from("AUD/USD"))),
+    };
+    c.bench_function("symbol_eq_separate", |b| {
+        b.iter(|| black_box(&separate1) == black_box(&separate2))
+    });
+
+    c.bench_function("intern_existing", |b| {
+        b.iter(|| intern(black_box("AUD/USD")))
+    });
+
+    c.bench_function("instrument_id_from_str", |b| {
+        b.iter(|| InstrumentId::from_str(black_box("ETHUSDT-PERP.BINANCE")))
+    });
+
+    c.bench_function("trade_id_new", |b| {
+        b.iter(|| TradeId::new(black_box("1234567890")))
+    });
+
+    c.bench_function("inline_trade_id_new", |b| {
+        b.iter(|| InlineTradeId::new(black_box("1234567890")))
+    });
+
+    let trade_id = TradeId::new("1234567890");
+    c.bench_function("trade_id_clone", |b| {
+        b.iter(|| black_box(&trade_id).clone())
+    });
+
+    let inline_trade_id = InlineTradeId::new("1234567890");
+    c.bench_function("inline_trade_id_clone", |b| {
+        b.iter(|| *black_box(&inline_trade_id))
+    });
+}
+
+criterion_group!(benches, criterion_identifier_benchmark);
+criterion::criterion_main!(benches);
@@ -24,6 +24,11 @@ use std::{
 
 #[allow(clippy::expect_used)] // OK in build script
 fn main() {
+    // The C headers and Cython definitions are only generated along with the FFI
+    if env::var("CARGO_FEATURE_FFI").is_err() {
+        return;
+    }
+
     let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
 
     // Generate C headers
@@ -0,0 +1,81 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use super::{base::BaseAccount, cash::CashAccount, margin::MarginAccount};
+
+/// Wraps any of the concrete account types, so that accounts of different types can be
+/// stored and handled together while retaining their type specific accessors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountAny {
+    Cash(CashAccount),
+    Margin(MarginAccount),
+}
+
+impl Deref for AccountAny {
+    type Target = BaseAccount;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            AccountAny::Cash(account) => &account.core,
+            AccountAny::Margin(account) => &account.core,
+        }
+    }
+}
+
+impl DerefMut for AccountAny {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            AccountAny::Cash(account) => &mut account.core,
+            AccountAny::Margin(account) => &mut account.core,
+        }
+    }
+}
+
+impl From<CashAccount> for AccountAny {
+    fn from(value: CashAccount) -> Self {
+        AccountAny::Cash(value)
+    }
+}
+
+impl From<MarginAccount> for AccountAny {
+    fn from(value: MarginAccount) -> Self {
+        AccountAny::Margin(value)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::base::tests::{account_state, usd_balance},
+        enums::AccountType,
+        identifiers::account_id::AccountId,
+    };
+
+    #[test]
+    fn test_account_any_derefs_to_base_account() {
+        let event = account_state(AccountType::Cash, None, vec![usd_balance(1_000.0, 0.0)]);
+        let account = AccountAny::from(CashAccount::new(event, false).unwrap());
+
+        assert!(matches!(account, AccountAny::Cash(_)));
+        assert_eq!(account.id, AccountId::new("SIM-001"));
+        assert_eq!(account.account_type, AccountType::Cash);
+    }
+}
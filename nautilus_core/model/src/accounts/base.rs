@@ -0,0 +1,488 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+
+use crate::{
+    accounts::AccountError,
+    enums::{AccountType, LiquiditySide},
+    events::account::AccountState,
+    identifiers::account_id::AccountId,
+    instruments::Instrument,
+    types::{
+        balance::{AccountBalance, MarginBalance},
+        currency::Currency,
+        money::Money,
+        price::Price,
+        quantity::Quantity,
+    },
+};
+
+/// The state and accounting shared by all account types.
+#[derive(Clone, Debug)]
+pub struct BaseAccount {
+    pub id: AccountId,
+    pub account_type: AccountType,
+    pub base_currency: Option<Currency>,
+    pub calculate_account_state: bool,
+    events: Vec<AccountState>,
+    commissions: HashMap<Currency, Money>,
+    balances: HashMap<Currency, AccountBalance>,
+    balances_starting: HashMap<Currency, Money>,
+}
+
+impl BaseAccount {
+    /// Creates a new account from the initial account state `event`.
+    ///
+    /// # Errors
+    ///
+    /// If any of the initial balances are negative.
+    pub fn new(event: AccountState, calculate_account_state: bool) -> Result<Self, AccountError> {
+        let balances_starting = event
+            .balances
+            .iter()
+            .map(|b| (b.currency.clone(), b.total.clone()))
+            .collect();
+        let mut account = Self {
+            id: event.account_id.clone(),
+            account_type: event.account_type,
+            base_currency: event.base_currency.clone(),
+            calculate_account_state,
+            events: Vec::new(),
+            commissions: HashMap::new(),
+            balances: HashMap::new(),
+            balances_starting,
+        };
+        account.update_balances(event.balances.clone(), true)?;
+        account.events.push(event);
+        Ok(account)
+    }
+
+    #[must_use]
+    pub fn last_event(&self) -> &AccountState {
+        self.events.last().expect("account has no events")
+    }
+
+    #[must_use]
+    pub fn events(&self) -> &[AccountState] {
+        &self.events
+    }
+
+    #[must_use]
+    pub fn event_count(&self) -> usize {
+        self.events.len()
+    }
+
+    #[must_use]
+    pub fn currencies(&self) -> Vec<Currency> {
+        self.balances.keys().cloned().collect()
+    }
+
+    #[must_use]
+    pub fn starting_balances(&self) -> &HashMap<Currency, Money> {
+        &self.balances_starting
+    }
+
+    #[must_use]
+    pub fn balances(&self) -> &HashMap<Currency, AccountBalance> {
+        &self.balances
+    }
+
+    #[must_use]
+    pub fn commissions(&self) -> &HashMap<Currency, Money> {
+        &self.commissions
+    }
+
+    /// Returns the balance for the given `currency`, or for the accounts base
+    /// currency if `None`.
+    ///
+    /// # Panics
+    ///
+    /// If `currency` is `None` and the account has no base currency.
+    #[must_use]
+    pub fn balance(&self, currency: Option<&Currency>) -> Option<&AccountBalance> {
+        let currency = currency
+            .or(self.base_currency.as_ref())
+            .expect("`currency` was `None` for multi-currency account");
+        self.balances.get(currency)
+    }
+
+    #[must_use]
+    pub fn balance_total(&self, currency: Option<&Currency>) -> Option<Money> {
+        self.balance(currency).map(|b| b.total.clone())
+    }
+
+    #[must_use]
+    pub fn balance_free(&self, currency: Option<&Currency>) -> Option<Money> {
+        self.balance(currency).map(|b| b.free.clone())
+    }
+
+    #[must_use]
+    pub fn balance_locked(&self, currency: Option<&Currency>) -> Option<Money> {
+        self.balance(currency).map(|b| b.locked.clone())
+    }
+
+    #[must_use]
+    pub fn commission(&self, currency: &Currency) -> Option<Money> {
+        self.commissions.get(currency).cloned()
+    }
+
+    /// Applies the given account state `event` to the account.
+    ///
+    /// # Errors
+    ///
+    /// If any of the event balances are negative.
+    ///
+    /// # Panics
+    ///
+    /// If the event does not belong to this account, or a single-currency account
+    /// receives an update for more than its base currency.
+    pub fn apply(&mut self, event: AccountState) -> Result<(), AccountError> {
+        assert_eq!(
+            event.account_type, self.account_type,
+            "`account_type` mismatch"
+        );
+        assert_eq!(event.account_id, self.id, "`account_id` mismatch");
+        assert_eq!(
+            event.base_currency, self.base_currency,
+            "`base_currency` mismatch"
+        );
+
+        if let Some(base_currency) = &self.base_currency {
+            assert_eq!(
+                event.balances.len(),
+                1,
+                "single-currency account has multiple currency update"
+            );
+            assert_eq!(
+                &event.balances[0].currency, base_currency,
+                "`balances[0].currency` was not the base currency"
+            );
+        }
+
+        self.update_balances(event.balances.clone(), true)?;
+        self.events.push(event);
+        Ok(())
+    }
+
+    /// Updates the balances included in `balances`, leaving all other currencies as is.
+    ///
+    /// # Errors
+    ///
+    /// If a balance total is negative, or zero when `allow_zero` is false.
+    pub fn update_balances(
+        &mut self,
+        balances: Vec<AccountBalance>,
+        allow_zero: bool,
+    ) -> Result<(), AccountError> {
+        for balance in balances {
+            if balance.total.raw() < 0 {
+                return Err(AccountError::BalanceNegative(balance.total));
+            }
+            if balance.total.is_zero() && !allow_zero {
+                return Err(AccountError::BalanceZero(balance.currency));
+            }
+            self.balances.insert(balance.currency.clone(), balance);
+        }
+        Ok(())
+    }
+
+    /// Increments the cumulative commissions (a negative `commission` is a credit).
+    pub fn update_commissions(&mut self, commission: Money) {
+        if commission.is_zero() {
+            return; // Nothing to update
+        }
+
+        self.commissions
+            .entry(commission.currency.clone())
+            .and_modify(|total| *total += commission.clone())
+            .or_insert(commission);
+    }
+
+    /// Returns the commission for a transaction with the given parameters.
+    ///
+    /// The result is in quote currency for standard instruments, or base
+    /// currency for inverse instruments.
+    ///
+    /// # Panics
+    ///
+    /// If `liquidity_side` is `NoLiquiditySide`.
+    #[must_use]
+    pub fn calculate_commission<I: Instrument>(
+        &self,
+        instrument: &I,
+        last_qty: Quantity,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+        use_quote_for_inverse: bool,
+    ) -> Money {
+        instrument.calculate_commission(last_qty, last_px, liquidity_side, use_quote_for_inverse)
+    }
+
+    /// Adjusts the balances by the realized `pnls` of a fill less its `commission`.
+    ///
+    /// # Errors
+    ///
+    /// If there is no balance to deduct a loss or commission from, or the adjustment
+    /// would leave a balance negative or with no free margin.
+    pub fn apply_fill_pnls(
+        &mut self,
+        pnls: &[Money],
+        commission: &Money,
+    ) -> Result<(), AccountError> {
+        let mut balances: Vec<AccountBalance> = Vec::new();
+
+        for pnl in pnls {
+            let mut pnl = pnl.clone();
+            let currency = pnl.currency.clone();
+
+            if commission.currency != currency && !commission.is_zero() {
+                let mut balance = match self.balances.get(&commission.currency) {
+                    Some(balance) => balance.clone(),
+                    None if commission.raw() > 0 => {
+                        return Err(AccountError::NoBalance(commission.currency.clone()))
+                    }
+                    None => AccountBalance::zero(commission.currency.clone()),
+                };
+                balance.total = balance.total - commission.clone();
+                balance.free = balance.free - commission.clone();
+                balances.push(balance);
+            } else {
+                pnl = pnl - commission.clone();
+            }
+
+            if balances.is_empty() && pnl.is_zero() {
+                return Ok(()); // No adjustment
+            }
+
+            let new_balance = match self.balances.get(&currency) {
+                Some(balance) => {
+                    let total = balance.total.clone() + pnl.clone();
+                    let free = balance.free.clone() + pnl;
+                    if total.raw() < 0 {
+                        return Err(AccountError::BalanceNegative(total));
+                    }
+                    if free.raw() <= 0 {
+                        return Err(AccountError::MarginExceeded {
+                            balance: total,
+                            margin: balance.locked.clone(),
+                        });
+                    }
+                    AccountBalance::new(total, balance.locked.clone(), free)
+                }
+                None => {
+                    if pnl.raw() < 0 {
+                        return Err(AccountError::NoBalance(currency));
+                    }
+                    AccountBalance::new(pnl.clone(), Money::new(0.0, currency), pnl)
+                }
+            };
+            balances.push(new_balance);
+        }
+
+        if pnls.is_empty() && !commission.is_zero() {
+            let balance = self
+                .balances
+                .get(&commission.currency)
+                .ok_or_else(|| AccountError::NoBalance(commission.currency.clone()))?;
+            balances.push(AccountBalance::new(
+                balance.total.clone() - commission.clone(),
+                balance.locked.clone(),
+                balance.free.clone() - commission.clone(),
+            ));
+        }
+
+        if balances.is_empty() {
+            return Ok(()); // No adjustment
+        }
+
+        self.update_balances(balances, true)?;
+        self.update_commissions(commission.clone());
+        Ok(())
+    }
+
+    /// Generates an account state event from the current balances and given
+    /// `margins`, and appends it to the accounts events.
+    pub fn generate_account_state(
+        &mut self,
+        margins: Vec<MarginBalance>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> AccountState {
+        let event = AccountState {
+            account_id: self.id.clone(),
+            account_type: self.account_type,
+            base_currency: self.base_currency.clone(),
+            balances: self.balances.values().cloned().collect(),
+            margins,
+            is_reported: false,
+            event_id: UUID4::new(),
+            ts_event,
+            ts_init,
+        };
+        self.events.push(event.clone());
+        event
+    }
+
+    pub(crate) fn set_balance(&mut self, balance: AccountBalance) {
+        self.balances.insert(balance.currency.clone(), balance);
+    }
+}
+
+impl PartialEq for BaseAccount {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for BaseAccount {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{
+        currencies::{AUD, USD},
+        position::tests::audusd_sim,
+    };
+
+    pub fn account_state(
+        account_type: AccountType,
+        base_currency: Option<Currency>,
+        balances: Vec<AccountBalance>,
+    ) -> AccountState {
+        AccountState {
+            account_id: AccountId::new("SIM-001"),
+            account_type,
+            base_currency,
+            balances,
+            margins: Vec::new(),
+            is_reported: true,
+            event_id: UUID4::default(),
+            ts_event: 0,
+            ts_init: 0,
+        }
+    }
+
+    pub fn usd_balance(total: f64, locked: f64) -> AccountBalance {
+        AccountBalance::new(
+            Money::new(total, USD.clone()),
+            Money::new(locked, USD.clone()),
+            Money::new(total - locked, USD.clone()),
+        )
+    }
+
+    #[test]
+    fn test_new_sets_starting_balances() {
+        let event = account_state(AccountType::Cash, None, vec![usd_balance(1_000.0, 0.0)]);
+        let account = BaseAccount::new(event.clone(), false).unwrap();
+
+        assert_eq!(account.event_count(), 1);
+        assert_eq!(account.last_event(), &event);
+        assert_eq!(
+            account.starting_balances().get(&*USD),
+            Some(&Money::new(1_000.0, USD.clone()))
+        );
+        assert_eq!(
+            account.balance_total(Some(&*USD)),
+            Some(Money::new(1_000.0, USD.clone()))
+        );
+        assert_eq!(account.balance_total(Some(&*AUD)), None);
+    }
+
+    #[test]
+    fn test_update_balances_when_negative() {
+        let event = account_state(AccountType::Cash, None, vec![usd_balance(1_000.0, 0.0)]);
+        let mut account = BaseAccount::new(event, false).unwrap();
+        let negative = AccountBalance::new(
+            Money::new(-1.0, USD.clone()),
+            Money::new(0.0, USD.clone()),
+            Money::new(-1.0, USD.clone()),
+        );
+
+        let result = account.update_balances(vec![negative], true);
+
+        assert_eq!(
+            result,
+            Err(AccountError::BalanceNegative(Money::new(-1.0, USD.clone())))
+        );
+    }
+
+    #[test]
+    fn test_update_commissions_accumulates() {
+        let event = account_state(AccountType::Cash, None, vec![usd_balance(1_000.0, 0.0)]);
+        let mut account = BaseAccount::new(event, false).unwrap();
+
+        account.update_commissions(Money::new(1.5, USD.clone()));
+        account.update_commissions(Money::new(2.0, USD.clone()));
+        account.update_commissions(Money::new(0.0, AUD.clone()));
+
+        assert_eq!(account.commission(&USD), Some(Money::new(3.5, USD.clone())));
+        assert_eq!(account.commission(&AUD), None);
+    }
+
+    #[test]
+    fn test_calculate_commission() {
+        let event = account_state(AccountType::Cash, None, vec![usd_balance(1_000.0, 0.0)]);
+        let account = BaseAccount::new(event, false).unwrap();
+        let instrument = audusd_sim();
+
+        let commission = account.calculate_commission(
+            &instrument,
+            Quantity::from("100000"),
+            Price::from("1.00000"),
+            LiquiditySide::Taker,
+            false,
+        );
+
+        assert_eq!(commission, Money::new(2.0, USD.clone()));
+    }
+
+    #[test]
+    fn test_apply_fill_pnls_deducts_commission() {
+        let event = account_state(AccountType::Cash, None, vec![usd_balance(1_000.0, 0.0)]);
+        let mut account = BaseAccount::new(event, false).unwrap();
+
+        account
+            .apply_fill_pnls(
+                &[Money::new(10.0, USD.clone())],
+                &Money::new(2.0, USD.clone()),
+            )
+            .unwrap();
+
+        assert_eq!(
+            account.balance_total(Some(&*USD)),
+            Some(Money::new(1_008.0, USD.clone()))
+        );
+        assert_eq!(account.commission(&USD), Some(Money::new(2.0, USD.clone())));
+    }
+
+    #[test]
+    fn test_apply_fill_pnls_when_no_balance_for_loss() {
+        let event = account_state(AccountType::Cash, None, vec![usd_balance(1_000.0, 0.0)]);
+        let mut account = BaseAccount::new(event, false).unwrap();
+
+        let result = account.apply_fill_pnls(
+            &[Money::new(-10.0, AUD.clone())],
+            &Money::new(0.0, AUD.clone()),
+        );
+
+        assert_eq!(result, Err(AccountError::NoBalance(AUD.clone())));
+    }
+}
@@ -0,0 +1,311 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::time::UnixNanos;
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::{
+    accounts::{base::BaseAccount, AccountError},
+    enums::{AccountType, OrderSide},
+    events::{account::AccountState, order::OrderFilled},
+    identifiers::instrument_id::InstrumentId,
+    instruments::Instrument,
+    position::Position,
+    types::{
+        balance::AccountBalance, currency::Currency, money::Money, price::Price, quantity::Quantity,
+    },
+};
+
+/// Provides a cash account, where orders lock the balance required to settle them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CashAccount {
+    pub(super) core: BaseAccount,
+    balances_locked: HashMap<InstrumentId, Money>,
+}
+
+impl Deref for CashAccount {
+    type Target = BaseAccount;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for CashAccount {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl CashAccount {
+    /// Creates a new cash account from the initial account state `event`.
+    ///
+    /// # Errors
+    ///
+    /// If any of the initial balances are negative.
+    ///
+    /// # Panics
+    ///
+    /// If `event.account_type` is not `Cash`.
+    pub fn new(event: AccountState, calculate_account_state: bool) -> Result<Self, AccountError> {
+        assert_eq!(
+            event.account_type,
+            AccountType::Cash,
+            "`account_type` was not `Cash`"
+        );
+
+        Ok(Self {
+            core: BaseAccount::new(event, calculate_account_state)?,
+            balances_locked: HashMap::new(),
+        })
+    }
+
+    #[must_use]
+    pub fn is_unleveraged(&self, _instrument_id: &InstrumentId) -> bool {
+        true
+    }
+
+    #[must_use]
+    pub fn balances_locked(&self) -> &HashMap<InstrumentId, Money> {
+        &self.balances_locked
+    }
+
+    /// Sets the balance locked for open orders on the given instrument.
+    ///
+    /// # Panics
+    ///
+    /// If `locked` is negative.
+    pub fn update_balance_locked(&mut self, instrument_id: InstrumentId, locked: Money) {
+        assert!(locked.raw() >= 0, "`locked` was negative, was {locked}");
+
+        let currency = locked.currency.clone();
+        self.balances_locked.insert(instrument_id, locked);
+        self.recalculate_balance(&currency);
+    }
+
+    /// Clears the balance locked for open orders on the given instrument.
+    pub fn clear_balance_locked(&mut self, instrument_id: &InstrumentId) {
+        if let Some(locked) = self.balances_locked.remove(instrument_id) {
+            self.recalculate_balance(&locked.currency);
+        }
+    }
+
+    fn recalculate_balance(&mut self, currency: &Currency) {
+        let current = match self.core.balance(Some(currency)) {
+            Some(balance) => balance.clone(),
+            None => return, // No current balance to recalculate
+        };
+
+        let total_locked: i64 = self
+            .balances_locked
+            .values()
+            .filter(|locked| &locked.currency == currency)
+            .map(Money::raw)
+            .sum();
+
+        let new_balance = AccountBalance::new(
+            current.total.clone(),
+            Money::from_raw(total_locked, currency.clone()),
+            Money::from_raw(current.total.raw() - total_locked, currency.clone()),
+        );
+        self.core.set_balance(new_balance);
+    }
+
+    /// Returns the balance to lock for an order with the given parameters, including
+    /// the expected commission for the round trip.
+    ///
+    /// The result is in quote currency for buys, and base currency for sells (or
+    /// base currency for inverse instruments).
+    #[must_use]
+    pub fn calculate_balance_locked<I: Instrument>(
+        &self,
+        instrument: &I,
+        side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        use_quote_for_inverse: bool,
+    ) -> Money {
+        let quote_currency = instrument.quote_currency().clone();
+        let base_currency = instrument
+            .base_currency()
+            .cloned()
+            .unwrap_or_else(|| quote_currency.clone());
+
+        let notional = match side {
+            OrderSide::Buy => instrument
+                .calculate_notional_value(quantity, price, use_quote_for_inverse)
+                .as_f64(),
+            OrderSide::Sell => quantity.as_f64(),
+            OrderSide::NoOrderSide => panic!("invalid `OrderSide`, was {side}"),
+        };
+
+        // Add expected commission
+        let locked = notional + (notional * instrument.base().taker_fee.to_f64().unwrap() * 2.0);
+
+        if (instrument.is_inverse() && !use_quote_for_inverse) || side == OrderSide::Sell {
+            Money::new(locked, base_currency)
+        } else {
+            Money::new(locked, quote_currency)
+        }
+    }
+
+    /// Returns the balance changes from the given `fill`, not including commissions.
+    #[must_use]
+    pub fn calculate_pnls<I: Instrument>(
+        &self,
+        instrument: &I,
+        fill: &OrderFilled,
+        position: Option<&Position>,
+    ) -> Vec<Money> {
+        let mut fill_qty = fill.last_qty.as_f64();
+        let fill_px = fill.last_px.as_f64();
+
+        if let Some(position) = position {
+            // Only book open quantity towards realized PnL
+            fill_qty = fill_qty.min(position.quantity.as_f64());
+        }
+
+        let sign = match fill.order_side {
+            OrderSide::Buy => 1.0,
+            OrderSide::Sell => -1.0,
+            OrderSide::NoOrderSide => panic!("invalid `OrderSide`, was {}", fill.order_side),
+        };
+
+        let mut pnls = Vec::with_capacity(2);
+        if let Some(base_currency) = instrument.base_currency() {
+            if self.base_currency.is_none() {
+                pnls.push(Money::new(sign * fill_qty, base_currency.clone()));
+            }
+        }
+        pnls.push(Money::new(
+            -sign * fill_px * fill_qty,
+            instrument.quote_currency().clone(),
+        ));
+        pnls
+    }
+
+    /// Applies the balance changes and commission from the given `fill`, returning
+    /// the resulting account state.
+    ///
+    /// # Errors
+    ///
+    /// If the fill cannot be settled against the current balances.
+    pub fn apply_fill<I: Instrument>(
+        &mut self,
+        instrument: &I,
+        fill: &OrderFilled,
+        position: Option<&Position>,
+        ts_init: UnixNanos,
+    ) -> Result<AccountState, AccountError> {
+        let pnls = self.calculate_pnls(instrument, fill, position);
+        self.core.apply_fill_pnls(&pnls, &fill.commission)?;
+        Ok(self
+            .core
+            .generate_account_state(Vec::new(), fill.ts_event, ts_init))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::base::tests::{account_state, usd_balance},
+        currencies::{AUD, USD},
+        position::tests::{audusd_sim, order_filled},
+    };
+
+    fn cash_account() -> CashAccount {
+        let event = account_state(AccountType::Cash, None, vec![usd_balance(1_000_000.0, 0.0)]);
+        CashAccount::new(event, true).unwrap()
+    }
+
+    #[test]
+    #[should_panic(expected = "`account_type` was not `Cash`")]
+    fn test_new_with_margin_event() {
+        let event = account_state(AccountType::Margin, None, vec![usd_balance(1_000.0, 0.0)]);
+        let _ = CashAccount::new(event, false);
+    }
+
+    #[test]
+    fn test_update_and_clear_balance_locked() {
+        let mut account = cash_account();
+        let instrument = audusd_sim();
+
+        account.update_balance_locked(instrument.id.clone(), Money::new(100.0, USD.clone()));
+
+        let balance = account.balance(Some(&*USD)).unwrap();
+        assert_eq!(balance.locked, Money::new(100.0, USD.clone()));
+        assert_eq!(balance.free, Money::new(999_900.0, USD.clone()));
+
+        account.clear_balance_locked(&instrument.id);
+
+        let balance = account.balance(Some(&*USD)).unwrap();
+        assert_eq!(balance.locked, Money::new(0.0, USD.clone()));
+        assert_eq!(balance.free, Money::new(1_000_000.0, USD.clone()));
+    }
+
+    #[test]
+    fn test_calculate_balance_locked() {
+        let account = cash_account();
+        let instrument = audusd_sim();
+
+        let buy = account.calculate_balance_locked(
+            &instrument,
+            OrderSide::Buy,
+            Quantity::from("100000"),
+            Price::from("0.80000"),
+            false,
+        );
+        let sell = account.calculate_balance_locked(
+            &instrument,
+            OrderSide::Sell,
+            Quantity::from("100000"),
+            Price::from("0.80000"),
+            false,
+        );
+
+        assert_eq!(buy, Money::new(80_003.2, USD.clone()));
+        assert_eq!(sell, Money::new(100_004.0, AUD.clone()));
+    }
+
+    #[test]
+    fn test_apply_fill_settles_both_currencies() {
+        let mut account = cash_account();
+        let instrument = audusd_sim();
+        let fill = order_filled(&instrument, OrderSide::Buy, "1", "100000", "0.80000", 2.0);
+
+        let state = account.apply_fill(&instrument, &fill, None, 2).unwrap();
+
+        assert_eq!(
+            account.balance_total(Some(&*AUD)),
+            Some(Money::new(100_000.0, AUD.clone()))
+        );
+        assert_eq!(
+            account.balance_total(Some(&*USD)),
+            Some(Money::new(919_998.0, USD.clone()))
+        );
+        assert_eq!(account.commission(&USD), Some(Money::new(2.0, USD.clone())));
+        assert_eq!(state.balances.len(), 2);
+        assert_eq!(account.event_count(), 2);
+    }
+}
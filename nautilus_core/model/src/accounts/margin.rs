@@ -0,0 +1,496 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::time::UnixNanos;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use crate::{
+    accounts::{base::BaseAccount, AccountError},
+    enums::{AccountType, PositionSide},
+    events::{account::AccountState, order::OrderFilled},
+    identifiers::instrument_id::InstrumentId,
+    instruments::Instrument,
+    position::Position,
+    types::{
+        balance::{AccountBalance, MarginBalance},
+        currency::Currency,
+        money::Money,
+        price::Price,
+        quantity::Quantity,
+    },
+};
+
+/// Provides a margin account, where orders and positions reserve margin against
+/// the account balance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarginAccount {
+    pub(super) core: BaseAccount,
+    pub default_leverage: Decimal,
+    leverages: HashMap<InstrumentId, Decimal>,
+    margins: HashMap<InstrumentId, MarginBalance>,
+}
+
+impl Deref for MarginAccount {
+    type Target = BaseAccount;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for MarginAccount {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl MarginAccount {
+    /// Creates a new margin account from the initial account state `event`.
+    ///
+    /// # Errors
+    ///
+    /// If any of the initial balances are negative.
+    ///
+    /// # Panics
+    ///
+    /// If `event.account_type` is not `Margin`.
+    pub fn new(event: AccountState, calculate_account_state: bool) -> Result<Self, AccountError> {
+        assert_eq!(
+            event.account_type,
+            AccountType::Margin,
+            "`account_type` was not `Margin`"
+        );
+
+        let margins = event
+            .margins
+            .iter()
+            .map(|m| (m.instrument_id.clone(), m.clone()))
+            .collect();
+
+        Ok(Self {
+            core: BaseAccount::new(event, calculate_account_state)?,
+            default_leverage: Decimal::ONE,
+            leverages: HashMap::new(),
+            margins,
+        })
+    }
+
+    #[must_use]
+    pub fn margins(&self) -> &HashMap<InstrumentId, MarginBalance> {
+        &self.margins
+    }
+
+    #[must_use]
+    pub fn margin(&self, instrument_id: &InstrumentId) -> Option<&MarginBalance> {
+        self.margins.get(instrument_id)
+    }
+
+    #[must_use]
+    pub fn margin_init(&self, instrument_id: &InstrumentId) -> Option<Money> {
+        self.margin(instrument_id).map(|m| m.initial.clone())
+    }
+
+    #[must_use]
+    pub fn margin_maint(&self, instrument_id: &InstrumentId) -> Option<Money> {
+        self.margin(instrument_id).map(|m| m.maintenance.clone())
+    }
+
+    #[must_use]
+    pub fn leverages(&self) -> &HashMap<InstrumentId, Decimal> {
+        &self.leverages
+    }
+
+    #[must_use]
+    pub fn leverage(&self, instrument_id: &InstrumentId) -> Option<Decimal> {
+        self.leverages.get(instrument_id).copied()
+    }
+
+    /// Sets the leverage applied to instruments without a specific leverage.
+    ///
+    /// # Panics
+    ///
+    /// If `leverage` is less than 1.
+    pub fn set_default_leverage(&mut self, leverage: Decimal) {
+        assert!(leverage >= Decimal::ONE, "`leverage` was less than 1");
+        self.default_leverage = leverage;
+    }
+
+    /// Sets the leverage for the given instrument.
+    ///
+    /// # Panics
+    ///
+    /// If `leverage` is less than 1.
+    pub fn set_leverage(&mut self, instrument_id: InstrumentId, leverage: Decimal) {
+        assert!(leverage >= Decimal::ONE, "`leverage` was less than 1");
+        self.leverages.insert(instrument_id, leverage);
+    }
+
+    #[must_use]
+    pub fn is_unleveraged(&self, instrument_id: &InstrumentId) -> bool {
+        self.leverage(instrument_id)
+            .unwrap_or(self.default_leverage)
+            == Decimal::ONE
+    }
+
+    /// Sets the initial (order) margin for the given instrument.
+    ///
+    /// # Errors
+    ///
+    /// If the total margin would exceed the account balance.
+    pub fn update_margin_init(
+        &mut self,
+        instrument_id: InstrumentId,
+        margin_init: Money,
+    ) -> Result<(), AccountError> {
+        let margin = match self.margins.get(&instrument_id) {
+            Some(margin) => {
+                MarginBalance::new(margin_init, margin.maintenance.clone(), instrument_id)
+            }
+            None => {
+                let zero = Money::new(0.0, margin_init.currency.clone());
+                MarginBalance::new(margin_init, zero, instrument_id)
+            }
+        };
+        self.update_margin(margin)
+    }
+
+    /// Sets the maintenance (position) margin for the given instrument.
+    ///
+    /// # Errors
+    ///
+    /// If the total margin would exceed the account balance.
+    pub fn update_margin_maint(
+        &mut self,
+        instrument_id: InstrumentId,
+        margin_maint: Money,
+    ) -> Result<(), AccountError> {
+        let margin = match self.margins.get(&instrument_id) {
+            Some(margin) => MarginBalance::new(margin.initial.clone(), margin_maint, instrument_id),
+            None => {
+                let zero = Money::new(0.0, margin_maint.currency.clone());
+                MarginBalance::new(zero, margin_maint, instrument_id)
+            }
+        };
+        self.update_margin(margin)
+    }
+
+    /// Sets the margin balance for the margins instrument.
+    ///
+    /// # Errors
+    ///
+    /// If the total margin would exceed the account balance.
+    pub fn update_margin(&mut self, margin: MarginBalance) -> Result<(), AccountError> {
+        let currency = margin.currency.clone();
+        self.margins.insert(margin.instrument_id.clone(), margin);
+        self.recalculate_balance(&currency)
+    }
+
+    /// Clears the initial (order) margin for the given instrument.
+    ///
+    /// # Errors
+    ///
+    /// If there is no balance in the margins currency.
+    pub fn clear_margin_init(&mut self, instrument_id: &InstrumentId) -> Result<(), AccountError> {
+        match self.margin_maint(instrument_id) {
+            Some(margin_maint) => self.update_margin_init(
+                instrument_id.clone(),
+                Money::new(0.0, margin_maint.currency),
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /// Clears the maintenance (position) margin for the given instrument.
+    ///
+    /// # Errors
+    ///
+    /// If there is no balance in the margins currency.
+    pub fn clear_margin_maint(&mut self, instrument_id: &InstrumentId) -> Result<(), AccountError> {
+        match self.margin_init(instrument_id) {
+            Some(margin_init) => self
+                .update_margin_maint(instrument_id.clone(), Money::new(0.0, margin_init.currency)),
+            None => Ok(()),
+        }
+    }
+
+    /// Clears all margin for the given instrument.
+    ///
+    /// # Errors
+    ///
+    /// If there is no balance in the margins currency.
+    pub fn clear_margin(&mut self, instrument_id: &InstrumentId) -> Result<(), AccountError> {
+        match self.margins.remove(instrument_id) {
+            Some(margin) => self.recalculate_balance(&margin.currency),
+            None => Ok(()),
+        }
+    }
+
+    fn recalculate_balance(&mut self, currency: &Currency) -> Result<(), AccountError> {
+        let current = match self.core.balance(Some(currency)) {
+            Some(balance) => balance.clone(),
+            None => return Err(AccountError::NoBalance(currency.clone())),
+        };
+
+        let total_margin: i64 = self
+            .margins
+            .values()
+            .filter(|margin| &margin.currency == currency)
+            .map(|margin| margin.initial.raw() + margin.maintenance.raw())
+            .sum();
+
+        let total_free = current.total.raw() - total_margin;
+        if total_free <= 0 {
+            return Err(AccountError::MarginExceeded {
+                balance: current.total,
+                margin: Money::from_raw(total_margin, currency.clone()),
+            });
+        }
+
+        let new_balance = AccountBalance::new(
+            current.total,
+            Money::from_raw(total_margin, currency.clone()),
+            Money::from_raw(total_free, currency.clone()),
+        );
+        self.core.set_balance(new_balance);
+        Ok(())
+    }
+
+    fn leverage_f64(&self, instrument_id: &InstrumentId) -> f64 {
+        self.leverage(instrument_id)
+            .unwrap_or(self.default_leverage)
+            .to_f64()
+            .unwrap()
+    }
+
+    /// Returns the initial (order) margin for an order with the given parameters.
+    ///
+    /// The result is in quote currency for standard instruments, or base
+    /// currency for inverse instruments.
+    #[must_use]
+    pub fn calculate_margin_init<I: Instrument>(
+        &self,
+        instrument: &I,
+        quantity: Quantity,
+        price: Price,
+        use_quote_for_inverse: bool,
+    ) -> Money {
+        let leverage = self.leverage_f64(instrument.id());
+        instrument.calculate_margin_init(quantity, price, leverage, use_quote_for_inverse)
+    }
+
+    /// Returns the maintenance (position) margin for a position with the given parameters.
+    ///
+    /// The result is in quote currency for standard instruments, or base
+    /// currency for inverse instruments.
+    #[must_use]
+    pub fn calculate_margin_maint<I: Instrument>(
+        &self,
+        instrument: &I,
+        _side: PositionSide,
+        quantity: Quantity,
+        price: Price,
+        use_quote_for_inverse: bool,
+    ) -> Money {
+        let leverage = self.leverage_f64(instrument.id());
+        instrument.calculate_margin_maint(quantity, price, leverage, use_quote_for_inverse)
+    }
+
+    /// Returns the realized PnL from the given `fill`, not including commissions.
+    ///
+    /// PnL is only realized when the fill reduces the given `position`.
+    #[must_use]
+    pub fn calculate_pnls<I: Instrument>(
+        &self,
+        _instrument: &I,
+        fill: &OrderFilled,
+        position: Option<&Position>,
+    ) -> Vec<Money> {
+        match position {
+            Some(position) if position.entry != fill.order_side => vec![position.calculate_pnl(
+                position.avg_px_open,
+                fill.last_px.as_f64(),
+                fill.last_qty,
+            )],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Applies the realized PnL and commission from the given `fill`, returning the
+    /// resulting account state.
+    ///
+    /// The `position` should be the state of the position *before* the fill is applied.
+    ///
+    /// # Errors
+    ///
+    /// If the fill cannot be settled against the current balances.
+    pub fn apply_fill<I: Instrument>(
+        &mut self,
+        instrument: &I,
+        fill: &OrderFilled,
+        position: Option<&Position>,
+        ts_init: UnixNanos,
+    ) -> Result<AccountState, AccountError> {
+        let pnls = self.calculate_pnls(instrument, fill, position);
+        self.core.apply_fill_pnls(&pnls, &fill.commission)?;
+        let margins = self.margins.values().cloned().collect();
+        Ok(self
+            .core
+            .generate_account_state(margins, fill.ts_event, ts_init))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::base::tests::{account_state, usd_balance},
+        currencies::USD,
+        enums::OrderSide,
+        position::tests::{audusd_sim, order_filled},
+    };
+
+    fn margin_account() -> MarginAccount {
+        let event = account_state(
+            AccountType::Margin,
+            Some(USD.clone()),
+            vec![usd_balance(1_000_000.0, 0.0)],
+        );
+        MarginAccount::new(event, true).unwrap()
+    }
+
+    #[test]
+    fn test_leverage_defaults() {
+        let mut account = margin_account();
+        let instrument = audusd_sim();
+
+        assert!(account.is_unleveraged(&instrument.id));
+
+        account.set_leverage(instrument.id.clone(), Decimal::from(50));
+
+        assert!(!account.is_unleveraged(&instrument.id));
+        assert_eq!(account.leverage(&instrument.id), Some(Decimal::from(50)));
+    }
+
+    #[test]
+    fn test_calculate_margin_init_with_leverage() {
+        let mut account = margin_account();
+        let instrument = audusd_sim();
+        account.set_leverage(instrument.id.clone(), Decimal::from(50));
+
+        let margin = account.calculate_margin_init(
+            &instrument,
+            Quantity::from("100000"),
+            Price::from("0.80000"),
+            false,
+        );
+
+        // 80,000 / 50 = 1,600 notional, 3% margin plus 2x taker fee
+        assert_eq!(margin, Money::new(48.06, USD.clone()));
+    }
+
+    #[test]
+    fn test_calculate_margin_maint_with_leverage() {
+        let mut account = margin_account();
+        let instrument = audusd_sim();
+        account.set_leverage(instrument.id.clone(), Decimal::from(50));
+
+        let margin = account.calculate_margin_maint(
+            &instrument,
+            PositionSide::Long,
+            Quantity::from("100000"),
+            Price::from("0.80000"),
+            false,
+        );
+
+        assert_eq!(margin, Money::new(48.03, USD.clone()));
+    }
+
+    #[test]
+    fn test_update_margins_reserves_balance() {
+        let mut account = margin_account();
+        let instrument = audusd_sim();
+
+        account
+            .update_margin_init(instrument.id.clone(), Money::new(1_000.0, USD.clone()))
+            .unwrap();
+        account
+            .update_margin_maint(instrument.id.clone(), Money::new(500.0, USD.clone()))
+            .unwrap();
+
+        let balance = account.balance(None).unwrap();
+        assert_eq!(balance.locked, Money::new(1_500.0, USD.clone()));
+        assert_eq!(balance.free, Money::new(998_500.0, USD.clone()));
+
+        account.clear_margin_init(&instrument.id).unwrap();
+        assert_eq!(
+            account.balance_locked(None),
+            Some(Money::new(500.0, USD.clone()))
+        );
+
+        account.clear_margin(&instrument.id).unwrap();
+        assert!(account.margins().is_empty());
+        assert_eq!(
+            account.balance_free(None),
+            Some(Money::new(1_000_000.0, USD.clone()))
+        );
+    }
+
+    #[test]
+    fn test_update_margin_when_exceeded() {
+        let mut account = margin_account();
+        let instrument = audusd_sim();
+
+        let result =
+            account.update_margin_init(instrument.id, Money::new(1_000_000.0, USD.clone()));
+
+        assert_eq!(
+            result,
+            Err(AccountError::MarginExceeded {
+                balance: Money::new(1_000_000.0, USD.clone()),
+                margin: Money::new(1_000_000.0, USD.clone()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_apply_fill_realizes_pnl_on_reducing_fill() {
+        let mut account = margin_account();
+        let instrument = audusd_sim();
+        let open = order_filled(&instrument, OrderSide::Buy, "1", "100000", "0.80000", 0.0);
+        let close = order_filled(&instrument, OrderSide::Sell, "2", "100000", "0.80010", 2.0);
+        let position = Position::new(&instrument, open.clone());
+
+        assert!(account.calculate_pnls(&instrument, &open, None).is_empty());
+
+        let state = account
+            .apply_fill(&instrument, &close, Some(&position), 2)
+            .unwrap();
+
+        assert_eq!(
+            account.balance_total(None),
+            Some(Money::new(1_000_008.0, USD.clone()))
+        );
+        assert_eq!(state.balances.len(), 1);
+        assert_eq!(state.ts_init, 2);
+    }
+}
@@ -0,0 +1,35 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod any;
+pub mod base;
+pub mod cash;
+pub mod margin;
+
+use thiserror::Error;
+
+use crate::types::{currency::Currency, money::Money};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AccountError {
+    #[error("Account balance negative, was {0}")]
+    BalanceNegative(Money),
+    #[error("Account zero balance not allowed for {}", .0.code)]
+    BalanceZero(Currency),
+    #[error("Account blocked from a margin of {margin} against a balance of {balance}")]
+    MarginExceeded { balance: Money, margin: Money },
+    #[error("No {} balance for account", .0.code)]
+    NoBalance(Currency),
+}
@@ -0,0 +1,162 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trading commands sent from strategies to the execution engine and clients.
+//!
+//! Orders are carried by their [`OrderInitialized`] event, which is the same
+//! representation used when commands are serialized from Python.
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::OrderSide,
+    events::order::OrderInitialized,
+    identifiers::{
+        client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        order_list_id::OrderListId, position_id::PositionId, strategy_id::StrategyId,
+        trader_id::TraderId, venue_order_id::VenueOrderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum TradingCommand {
+    SubmitOrder(SubmitOrder),
+    SubmitOrderList(SubmitOrderList),
+    ModifyOrder(ModifyOrder),
+    CancelOrder(CancelOrder),
+    CancelAllOrders(CancelAllOrders),
+    QueryOrder(QueryOrder),
+}
+
+impl TradingCommand {
+    /// Returns the name of the command variant.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            TradingCommand::SubmitOrder(_) => "SubmitOrder",
+            TradingCommand::SubmitOrderList(_) => "SubmitOrderList",
+            TradingCommand::ModifyOrder(_) => "ModifyOrder",
+            TradingCommand::CancelOrder(_) => "CancelOrder",
+            TradingCommand::CancelAllOrders(_) => "CancelAllOrders",
+            TradingCommand::QueryOrder(_) => "QueryOrder",
+        }
+    }
+
+    #[must_use]
+    pub fn command_id(&self) -> UUID4 {
+        match self {
+            TradingCommand::SubmitOrder(command) => command.command_id,
+            TradingCommand::SubmitOrderList(command) => command.command_id,
+            TradingCommand::ModifyOrder(command) => command.command_id,
+            TradingCommand::CancelOrder(command) => command.command_id,
+            TradingCommand::CancelAllOrders(command) => command.command_id,
+            TradingCommand::QueryOrder(command) => command.command_id,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct SubmitOrder {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    pub order: OrderInitialized,
+    pub position_id: Option<PositionId>,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct SubmitOrderList {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    pub order_list_id: OrderListId,
+    pub orders: Vec<OrderInitialized>,
+    pub position_id: Option<PositionId>,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct ModifyOrder {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub quantity: Option<Quantity>,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct CancelOrder {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+/// Cancels all open orders for the instrument, on one side only unless the
+/// `order_side` is `NoOrderSide`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct CancelAllOrders {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub order_side: OrderSide,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct QueryOrder {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
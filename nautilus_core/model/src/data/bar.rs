@@ -21,9 +21,16 @@ use std::{
 };
 
 use nautilus_core::time::UnixNanos;
-use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::{basic::CompareOp, prelude::*, types::PyTuple, PyTypeInfo};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
+#[cfg(feature = "python")]
+use crate::python::{hash_of, richcmp_eq, to_pyvalue_err};
 use crate::{
     enums::{AggregationSource, BarAggregation, PriceType},
     identifiers::instrument_id::InstrumentId,
@@ -32,12 +39,72 @@ use crate::{
 
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct BarSpecification {
     pub step: u64,
     pub aggregation: BarAggregation,
     pub price_type: PriceType,
 }
 
+impl BarSpecification {
+    #[must_use]
+    pub fn new(step: u64, aggregation: BarAggregation, price_type: PriceType) -> Self {
+        Self {
+            step,
+            aggregation,
+            price_type,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Error parsing `BarSpecification` from '{input}', invalid token: '{token}' at position {position}")]
+pub struct BarSpecificationParseError {
+    input: String,
+    token: String,
+    position: usize,
+}
+
+impl FromStr for BarSpecification {
+    type Err = BarSpecificationParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pieces: Vec<&str> = s.split('-').collect();
+        if pieces.len() != 3 {
+            return Err(BarSpecificationParseError {
+                input: s.to_string(),
+                token: "".to_string(),
+                position: 0,
+            });
+        }
+
+        let step = pieces[0].parse().map_err(|_| BarSpecificationParseError {
+            input: s.to_string(),
+            token: pieces[0].to_string(),
+            position: 0,
+        })?;
+        let aggregation =
+            BarAggregation::from_str(pieces[1]).map_err(|_| BarSpecificationParseError {
+                input: s.to_string(),
+                token: pieces[1].to_string(),
+                position: 1,
+            })?;
+        let price_type =
+            PriceType::from_str(pieces[2]).map_err(|_| BarSpecificationParseError {
+                input: s.to_string(),
+                token: pieces[2].to_string(),
+                position: 2,
+            })?;
+
+        Ok(BarSpecification {
+            step,
+            aggregation,
+            price_type,
+        })
+    }
+}
+
 impl Display for BarSpecification {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}-{}-{}", self.step, self.aggregation, self.price_type)
@@ -74,6 +141,21 @@ pub struct BarType {
     pub aggregation_source: AggregationSource,
 }
 
+impl BarType {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        spec: BarSpecification,
+        aggregation_source: AggregationSource,
+    ) -> Self {
+        Self {
+            instrument_id,
+            spec,
+            aggregation_source,
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 #[error("Error parsing `BarType` from '{input}', invalid token: '{token}' at position {position}")]
 pub struct BarTypeParseError {
@@ -148,6 +230,8 @@ impl PartialEq for BarType {
     }
 }
 
+impl Eq for BarType {}
+
 impl Hash for BarType {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.spec.hash(state);
@@ -187,9 +271,32 @@ impl Display for BarType {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for BarType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for BarType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bar_type_str: &str = Deserialize::deserialize(deserializer)?;
+        BarType::from_str(bar_type_str).map_err(serde::de::Error::custom)
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Debug)]
-#[pyclass]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct Bar {
     pub bar_type: BarType,
     pub open: Price,
@@ -201,6 +308,32 @@ pub struct Bar {
     pub ts_init: UnixNanos,
 }
 
+impl Bar {
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        bar_type: BarType,
+        open: Price,
+        high: Price,
+        low: Price,
+        close: Price,
+        volume: Quantity,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            bar_type,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            ts_event,
+            ts_init,
+        }
+    }
+}
+
 impl Display for Bar {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -211,6 +344,157 @@ impl Display for Bar {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl Bar {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    fn py_new(
+        bar_type: &str,
+        open: Price,
+        high: Price,
+        low: Price,
+        close: Price,
+        volume: Quantity,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> PyResult<Self> {
+        let bar_type = BarType::from_str(bar_type).map_err(to_pyvalue_err)?;
+        Ok(Self::new(
+            bar_type, open, high, low, close, volume, ts_event, ts_init,
+        ))
+    }
+
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let (bar_type, open, high, low, close, volume, ts_event, ts_init): (
+            String,
+            Price,
+            Price,
+            Price,
+            Price,
+            Quantity,
+            UnixNanos,
+            UnixNanos,
+        ) = state.extract(py)?;
+        *self = Self {
+            bar_type: BarType::from_str(&bar_type).map_err(to_pyvalue_err)?,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            ts_event,
+            ts_init,
+        };
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyObject {
+        (
+            self.bar_type.to_string(),
+            self.open.into_py(py),
+            self.high.into_py(py),
+            self.low.into_py(py),
+            self.close.into_py(py),
+            self.volume.into_py(py),
+            self.ts_event,
+            self.ts_init,
+        )
+            .to_object(py)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py);
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> Self {
+        Self {
+            bar_type: BarType {
+                instrument_id: InstrumentId::default(),
+                spec: BarSpecification::new(1, BarAggregation::Minute, PriceType::Last),
+                aggregation_source: AggregationSource::External,
+            },
+            open: Price::default(),
+            high: Price::default(),
+            low: Price::default(),
+            close: Price::default(),
+            volume: Quantity::default(),
+            ts_event: 0,
+            ts_init: 0,
+        }
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        richcmp_eq(self, other, op, py)
+    }
+
+    fn __hash__(&self) -> isize {
+        hash_of(self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}({})", Self::NAME, self)
+    }
+
+    #[getter]
+    #[pyo3(name = "bar_type")]
+    fn py_bar_type(&self) -> String {
+        self.bar_type.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "open")]
+    fn py_open(&self) -> Price {
+        self.open
+    }
+
+    #[getter]
+    #[pyo3(name = "high")]
+    fn py_high(&self) -> Price {
+        self.high
+    }
+
+    #[getter]
+    #[pyo3(name = "low")]
+    fn py_low(&self) -> Price {
+        self.low
+    }
+
+    #[getter]
+    #[pyo3(name = "close")]
+    fn py_close(&self) -> Price {
+        self.close
+    }
+
+    #[getter]
+    #[pyo3(name = "volume")]
+    fn py_volume(&self) -> Quantity {
+        self.volume
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -282,6 +566,33 @@ mod tests {
         assert_eq!(format!("{bar_spec}"), "1-MINUTE-BID");
     }
 
+    #[test]
+    fn test_bar_spec_parse_valid() {
+        let bar_spec = BarSpecification::from_str("5-MINUTE-LAST").unwrap();
+        assert_eq!(
+            bar_spec,
+            BarSpecification::new(5, BarAggregation::Minute, PriceType::Last)
+        );
+        assert_eq!(bar_spec.to_string(), "5-MINUTE-LAST");
+    }
+
+    #[test]
+    fn test_bar_spec_parse_invalid_token_pos_1() {
+        let input = "5-INVALID-LAST";
+        let result = BarSpecification::from_str(input);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!("Error parsing `BarSpecification` from '{input}', invalid token: 'INVALID' at position 1")
+        );
+    }
+
+    #[test]
+    fn test_bar_spec_parse_wrong_number_of_tokens() {
+        assert!(BarSpecification::from_str("1-MINUTE").is_err());
+        assert!(BarSpecification::from_str("1-MINUTE-LAST-EXTERNAL").is_err());
+    }
+
     #[test]
     fn test_bar_type_parse_valid() {
         let input = "BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL";
@@ -479,4 +790,26 @@ mod tests {
         assert_eq!(bar1, bar1);
         assert_ne!(bar1, bar2);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_bar_json_round_trip() {
+        let bar_type = BarType::from_str("AUDUSD.SIM-1-MINUTE-BID-EXTERNAL").unwrap();
+        let bar = Bar {
+            bar_type,
+            open: Price::from("1.00001"),
+            high: Price::from("1.00004"),
+            low: Price::from("1.00002"),
+            close: Price::from("1.00003"),
+            volume: Quantity::from("100000"),
+            ts_event: 1,
+            ts_init: 2,
+        };
+
+        let json = serde_json::to_string(&bar).unwrap();
+        let deserialized: Bar = serde_json::from_str(&json).unwrap();
+
+        assert!(json.contains("\"bar_type\":\"AUDUSD.SIM-1-MINUTE-BID-EXTERNAL\""));
+        assert_eq!(deserialized, bar);
+    }
 }
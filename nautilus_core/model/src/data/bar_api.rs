@@ -17,9 +17,16 @@ use std::{
     collections::hash_map::DefaultHasher,
     ffi::c_char,
     hash::{Hash, Hasher},
+    str::FromStr,
 };
 
-use nautilus_core::{string::str_to_cstr, time::UnixNanos};
+use nautilus_core::{
+    ffi_error::{
+        set_last_error, try_cstr_to_str, write_parsed_cstr, write_result, FFI_ERROR, FFI_OK,
+    },
+    string::str_to_cstr,
+    time::UnixNanos,
+};
 
 use super::bar::{Bar, BarSpecification, BarType};
 use crate::{
@@ -28,20 +35,36 @@ use crate::{
     types::{price::Price, quantity::Quantity},
 };
 
+/// Returns a new [`BarSpecification`], written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`BarSpecification`].
 #[no_mangle]
-pub extern "C" fn bar_specification_new(
+pub unsafe extern "C" fn bar_specification_new(
     step: u64,
     aggregation: u8,
     price_type: u8,
-) -> BarSpecification {
-    let aggregation =
-        BarAggregation::from_repr(aggregation as usize).expect("cannot parse enum value");
-    let price_type = PriceType::from_repr(price_type as usize).expect("cannot parse enum value");
-    BarSpecification {
-        step,
-        aggregation,
-        price_type,
-    }
+    out: *mut BarSpecification,
+) -> u8 {
+    let result = match (
+        BarAggregation::from_repr(aggregation as usize),
+        PriceType::from_repr(price_type as usize),
+    ) {
+        (Some(aggregation), Some(price_type)) => Ok(BarSpecification {
+            step,
+            aggregation,
+            price_type,
+        }),
+        (None, _) => Err(format!(
+            "invalid `BarAggregation` enum value, was {aggregation}"
+        )),
+        (_, None) => Err(format!("invalid `PriceType` enum value, was {price_type}")),
+    };
+    write_result(result, out, |_| FFI_ERROR)
 }
 
 /// Returns a [`BarSpecification`] as a C string pointer.
@@ -82,21 +105,83 @@ pub extern "C" fn bar_specification_ge(lhs: &BarSpecification, rhs: &BarSpecific
     u8::from(lhs >= rhs)
 }
 
+/// Returns a new [`BarType`], written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`BarType`].
 #[no_mangle]
-pub extern "C" fn bar_type_new(
+pub unsafe extern "C" fn bar_type_new(
     instrument_id: InstrumentId,
     spec: BarSpecification,
     aggregation_source: u8,
-) -> BarType {
-    let aggregation_source = AggregationSource::from_repr(aggregation_source as usize)
-        .expect("Error converting enum from integer");
-    BarType {
-        instrument_id,
-        spec,
-        aggregation_source,
+    out: *mut BarType,
+) -> u8 {
+    let result = AggregationSource::from_repr(aggregation_source as usize)
+        .map(|aggregation_source| BarType {
+            instrument_id,
+            spec,
+            aggregation_source,
+        })
+        .ok_or_else(|| format!("invalid `AggregationSource` enum value, was {aggregation_source}"));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Checks the provided C string pointer parses as a [`BarType`].
+///
+/// Returns zero if the input parsed successfully, otherwise a non-zero status code with
+/// the parsing error set as the last error.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn bar_type_check_parsing(ptr: *const c_char) -> u8 {
+    let result = try_cstr_to_str(ptr, "`BarType`")
+        .and_then(|value| BarType::from_str(value).map_err(|e| e.to_string()));
+    match result {
+        Ok(_) => FFI_OK,
+        Err(e) => {
+            set_last_error(e);
+            FFI_ERROR
+        }
     }
 }
 
+/// Returns a [`BarType`] from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`BarType`].
+#[no_mangle]
+pub unsafe extern "C" fn bar_type_try_from_cstr(ptr: *const c_char, out: *mut BarType) -> u8 {
+    write_parsed_cstr(ptr, out, "`BarType`")
+}
+
+/// Returns a [`BarSpecification`] from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`BarSpecification`].
+#[no_mangle]
+pub unsafe extern "C" fn bar_specification_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut BarSpecification,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`BarSpecification`")
+}
+
 #[no_mangle]
 pub extern "C" fn bar_type_drop(bar_type: BarType) {
     drop(bar_type); // Memory freed here
@@ -220,3 +305,95 @@ pub extern "C" fn bar_hash(bar: &Bar) -> u64 {
     bar.hash(&mut h);
     h.finish()
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, mem::MaybeUninit};
+
+    use nautilus_core::ffi_error::last_error;
+
+    use super::*;
+
+    #[test]
+    fn test_bar_specification_new_with_invalid_price_type() {
+        let mut out = MaybeUninit::<BarSpecification>::uninit();
+
+        let status =
+            unsafe { bar_specification_new(1, BarAggregation::Minute as u8, 9, out.as_mut_ptr()) };
+
+        assert_eq!(status, FFI_ERROR);
+        assert_eq!(
+            last_error().unwrap(),
+            "invalid `PriceType` enum value, was 9"
+        );
+    }
+
+    #[test]
+    fn test_bar_type_try_from_cstr() {
+        let input = CString::new("BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL").unwrap();
+        let mut out = MaybeUninit::<BarType>::uninit();
+
+        let status = unsafe { bar_type_try_from_cstr(input.as_ptr(), out.as_mut_ptr()) };
+        let bar_type = unsafe { out.assume_init() };
+
+        assert_eq!(status, 0);
+        assert_eq!(
+            bar_type.instrument_id,
+            InstrumentId::from_str("BTCUSDT-PERP.BINANCE").unwrap()
+        );
+        assert_eq!(
+            bar_type.spec,
+            BarSpecification::new(1, BarAggregation::Minute, PriceType::Last)
+        );
+        assert_eq!(bar_type.aggregation_source, AggregationSource::External);
+    }
+
+    #[test]
+    fn test_bar_type_check_parsing_valid() {
+        let input = CString::new("BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL").unwrap();
+        let status = unsafe { bar_type_check_parsing(input.as_ptr()) };
+        assert_eq!(status, FFI_OK);
+    }
+
+    #[test]
+    fn test_bar_type_check_parsing_invalid() {
+        let input = CString::new("BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-INVALID").unwrap();
+        let status = unsafe { bar_type_check_parsing(input.as_ptr()) };
+        assert_eq!(status, FFI_ERROR);
+        assert_eq!(
+            last_error().unwrap(),
+            "Error parsing `BarType` from 'BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-INVALID', invalid token: 'INVALID' at position 4"
+        );
+    }
+
+    #[test]
+    fn test_bar_type_try_from_cstr_invalid() {
+        let input = CString::new("BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-INVALID").unwrap();
+        let mut out = MaybeUninit::<BarType>::uninit();
+
+        let status = unsafe { bar_type_try_from_cstr(input.as_ptr(), out.as_mut_ptr()) };
+
+        assert_ne!(status, 0);
+        assert_eq!(
+            last_error().unwrap(),
+            "invalid `BarType` string, was 'BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-INVALID': Error parsing `BarType` from 'BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-INVALID', invalid token: 'INVALID' at position 4"
+        );
+    }
+
+    #[test]
+    fn test_bar_specification_try_from_cstr() {
+        let input = CString::new("100-TICK-MID").unwrap();
+        let mut out = MaybeUninit::<BarSpecification>::uninit();
+
+        let status = unsafe { bar_specification_try_from_cstr(input.as_ptr(), out.as_mut_ptr()) };
+
+        assert_eq!(status, 0);
+        assert_eq!(
+            unsafe { out.assume_init() },
+            BarSpecification::new(100, BarAggregation::Tick, PriceType::Mid)
+        );
+    }
+}
@@ -19,6 +19,10 @@ use std::{
 };
 
 use nautilus_core::time::UnixNanos;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::tick::{QuoteTick, TradeTick};
 use crate::{
@@ -31,6 +35,8 @@ use crate::{
 /// Represents an order in a book.
 #[repr(C)]
 #[derive(Copy, Clone, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct BookOrder {
     pub side: OrderSide,
     pub price: Price,
@@ -126,6 +132,8 @@ impl Display for BookOrder {
 /// Represents a single change/delta in an order book.
 #[repr(C)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct OrderBookDelta {
     pub instrument_id: InstrumentId,
     pub action: BookAction,
@@ -176,6 +184,119 @@ impl Display for OrderBookDelta {
     }
 }
 
+/// Represents a grouped batch of `OrderBookDelta` updates for an `OrderBook`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct OrderBookDeltas {
+    pub instrument_id: InstrumentId,
+    pub deltas: Vec<OrderBookDelta>,
+    pub flags: u8,
+    pub sequence: u64,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl OrderBookDeltas {
+    /// Creates a new [`OrderBookDeltas`] instance, with the sequence and timestamps taken
+    /// from the last delta.
+    ///
+    /// # Panics
+    ///
+    /// - If `deltas` is empty.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, deltas: Vec<OrderBookDelta>) -> Self {
+        let last = deltas.last().expect("`deltas` was empty");
+        let flags = last.flags;
+        let sequence = last.sequence;
+        let ts_event = last.ts_event;
+        let ts_init = last.ts_init;
+
+        Self {
+            instrument_id,
+            deltas,
+            flags,
+            sequence,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.deltas.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.deltas.is_empty()
+    }
+}
+
+impl Display for OrderBookDeltas {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},len={},flags={},sequence={},ts_event={},ts_init={}",
+            self.instrument_id,
+            self.deltas.len(),
+            self.flags,
+            self.sequence,
+            self.ts_event,
+            self.ts_init
+        )
+    }
+}
+
+/// Represents a complete snapshot of the bid and ask orders in an `OrderBook`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct OrderBookSnapshot {
+    pub instrument_id: InstrumentId,
+    pub bids: Vec<BookOrder>,
+    pub asks: Vec<BookOrder>,
+    pub sequence: u64,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl OrderBookSnapshot {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        bids: Vec<BookOrder>,
+        asks: Vec<BookOrder>,
+        sequence: u64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            bids,
+            asks,
+            sequence,
+            ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl Display for OrderBookSnapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},bids={},asks={},sequence={},ts_event={},ts_init={}",
+            self.instrument_id,
+            self.bids.len(),
+            self.asks.len(),
+            self.sequence,
+            self.ts_event,
+            self.ts_init
+        )
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -387,4 +508,67 @@ mod tests {
         assert_eq!(book_order.size, tick.size);
         assert_eq!(book_order.order_id, tick.price.raw as u64);
     }
+
+    fn stub_delta(order_id: u64, sequence: u64) -> OrderBookDelta {
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from("10"),
+            order_id,
+        );
+        OrderBookDelta::new(
+            instrument_id,
+            BookAction::Add,
+            order,
+            0,
+            sequence,
+            sequence * 10,
+            sequence * 10 + 1,
+        )
+    }
+
+    #[test]
+    fn test_order_book_deltas_new() {
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+        let deltas = OrderBookDeltas::new(
+            instrument_id.clone(),
+            vec![stub_delta(1, 1), stub_delta(2, 2)],
+        );
+
+        assert_eq!(deltas.instrument_id, instrument_id);
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas.sequence, 2);
+        assert_eq!(deltas.ts_event, 20);
+        assert_eq!(deltas.ts_init, 21);
+        assert_eq!(
+            deltas.to_string(),
+            "AAPL.NASDAQ,len=2,flags=0,sequence=2,ts_event=20,ts_init=21"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`deltas` was empty")]
+    fn test_order_book_deltas_new_when_empty() {
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+        let _ = OrderBookDeltas::new(instrument_id, vec![]);
+    }
+
+    #[test]
+    fn test_order_book_snapshot_display() {
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+        let bid = BookOrder::new(OrderSide::Buy, Price::from("99.00"), Quantity::from("1"), 1);
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("101.00"),
+            Quantity::from("1"),
+            2,
+        );
+        let snapshot = OrderBookSnapshot::new(instrument_id, vec![bid], vec![ask], 3, 4, 5);
+
+        assert_eq!(
+            snapshot.to_string(),
+            "AAPL.NASDAQ,bids=1,asks=1,sequence=3,ts_event=4,ts_init=5"
+        );
+    }
 }
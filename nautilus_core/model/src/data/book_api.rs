@@ -14,20 +14,39 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
+    cell::RefCell,
     collections::hash_map::DefaultHasher,
     ffi::c_char,
     hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+    slice,
 };
 
-use nautilus_core::{string::str_to_cstr, time::UnixNanos};
+use nautilus_core::{
+    pool::{ObjectPool, PoolStats},
+    string::str_to_cstr,
+    time::UnixNanos,
+};
 
-use super::book::{BookOrder, OrderBookDelta};
+use super::book::{BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookSnapshot};
 use crate::{
     enums::{BookAction, OrderSide},
     identifiers::instrument_id::InstrumentId,
     types::{price::Price, quantity::Quantity},
 };
 
+/// The maximum number of idle delta buffers kept for reuse per thread.
+const DELTAS_POOL_MAX_IDLE: usize = 1_024;
+
+/// The maximum number of deltas an idle buffer keeps room for.
+const DELTAS_POOL_MAX_CAPACITY: usize = 1_024;
+
+thread_local! {
+    static DELTAS_POOL: RefCell<ObjectPool<Vec<OrderBookDelta>>> = RefCell::new(
+        ObjectPool::new(DELTAS_POOL_MAX_IDLE, Vec::new).with_max_capacity(DELTAS_POOL_MAX_CAPACITY),
+    );
+}
+
 #[no_mangle]
 pub extern "C" fn book_order_from_raw(
     order_side: OrderSide,
@@ -121,3 +140,307 @@ pub extern "C" fn orderbook_delta_hash(delta: &OrderBookDelta) -> u64 {
     delta.hash(&mut hasher);
     hasher.finish()
 }
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`OrderBookDeltas`].
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct OrderBookDeltas_API(Box<OrderBookDeltas>);
+
+impl Deref for OrderBookDeltas_API {
+    type Target = OrderBookDeltas;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for OrderBookDeltas_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Creates a new [`OrderBookDeltas`] from a C array of deltas.
+///
+/// # Safety
+///
+/// - Assumes `deltas` is either NULL (for no deltas) or points to a valid array of at least
+///   `len` [`OrderBookDelta`]s.
+#[no_mangle]
+pub unsafe extern "C" fn orderbook_deltas_new(
+    instrument_id: InstrumentId,
+    deltas: *const OrderBookDelta,
+    len: usize,
+) -> OrderBookDeltas_API {
+    let mut buffer = DELTAS_POOL.with(|pool| pool.borrow_mut().acquire());
+    if !deltas.is_null() {
+        buffer.extend_from_slice(slice::from_raw_parts(deltas, len));
+    }
+    OrderBookDeltas_API(Box::new(OrderBookDeltas::new(instrument_id, buffer)))
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_deltas_drop(deltas: OrderBookDeltas_API) {
+    drop(deltas); // Memory freed here
+}
+
+/// Drops the [`OrderBookDeltas`], returning its buffer to the pool of the calling thread
+/// for reuse by `orderbook_deltas_new`.
+#[no_mangle]
+pub extern "C" fn orderbook_deltas_recycle(deltas: OrderBookDeltas_API) {
+    let OrderBookDeltas { deltas, .. } = *deltas.0;
+    DELTAS_POOL.with(|pool| pool.borrow_mut().release(deltas));
+}
+
+/// Returns the statistics of the delta buffer pool of the calling thread.
+#[no_mangle]
+pub extern "C" fn orderbook_deltas_pool_stats() -> PoolStats {
+    DELTAS_POOL.with(|pool| pool.borrow().stats())
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_deltas_instrument_id(deltas: &OrderBookDeltas_API) -> InstrumentId {
+    deltas.instrument_id.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_deltas_len(deltas: &OrderBookDeltas_API) -> usize {
+    deltas.len()
+}
+
+/// Returns a clone of the delta at the given `index`.
+///
+/// # Panics
+///
+/// - If `index` is out of bounds.
+#[no_mangle]
+pub extern "C" fn orderbook_deltas_get(
+    deltas: &OrderBookDeltas_API,
+    index: usize,
+) -> OrderBookDelta {
+    deltas.deltas[index].clone()
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_deltas_flags(deltas: &OrderBookDeltas_API) -> u8 {
+    deltas.flags
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_deltas_sequence(deltas: &OrderBookDeltas_API) -> u64 {
+    deltas.sequence
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_deltas_ts_event(deltas: &OrderBookDeltas_API) -> UnixNanos {
+    deltas.ts_event
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_deltas_ts_init(deltas: &OrderBookDeltas_API) -> UnixNanos {
+    deltas.ts_init
+}
+
+/// Returns an [`OrderBookDeltas`] as a C string pointer.
+#[no_mangle]
+pub extern "C" fn orderbook_deltas_to_cstr(deltas: &OrderBookDeltas_API) -> *const c_char {
+    str_to_cstr(&deltas.to_string())
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`OrderBookSnapshot`].
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct OrderBookSnapshot_API(Box<OrderBookSnapshot>);
+
+impl Deref for OrderBookSnapshot_API {
+    type Target = OrderBookSnapshot;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for OrderBookSnapshot_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Creates a new [`OrderBookSnapshot`] from C arrays of bid and ask orders.
+///
+/// # Safety
+///
+/// - Assumes `bids` points to a valid array of at least `bids_len` [`BookOrder`]s.
+/// - Assumes `asks` points to a valid array of at least `asks_len` [`BookOrder`]s.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn orderbook_snapshot_new(
+    instrument_id: InstrumentId,
+    bids: *const BookOrder,
+    bids_len: usize,
+    asks: *const BookOrder,
+    asks_len: usize,
+    sequence: u64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> OrderBookSnapshot_API {
+    let bids = if bids_len == 0 {
+        Vec::new()
+    } else {
+        slice::from_raw_parts(bids, bids_len).to_vec()
+    };
+    let asks = if asks_len == 0 {
+        Vec::new()
+    } else {
+        slice::from_raw_parts(asks, asks_len).to_vec()
+    };
+    OrderBookSnapshot_API(Box::new(OrderBookSnapshot::new(
+        instrument_id,
+        bids,
+        asks,
+        sequence,
+        ts_event,
+        ts_init,
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_snapshot_drop(snapshot: OrderBookSnapshot_API) {
+    drop(snapshot); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_snapshot_instrument_id(
+    snapshot: &OrderBookSnapshot_API,
+) -> InstrumentId {
+    snapshot.instrument_id.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_snapshot_bids_len(snapshot: &OrderBookSnapshot_API) -> usize {
+    snapshot.bids.len()
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_snapshot_asks_len(snapshot: &OrderBookSnapshot_API) -> usize {
+    snapshot.asks.len()
+}
+
+/// Returns the bid order at the given `index`.
+///
+/// # Panics
+///
+/// - If `index` is out of bounds.
+#[no_mangle]
+pub extern "C" fn orderbook_snapshot_bid(
+    snapshot: &OrderBookSnapshot_API,
+    index: usize,
+) -> BookOrder {
+    snapshot.bids[index]
+}
+
+/// Returns the ask order at the given `index`.
+///
+/// # Panics
+///
+/// - If `index` is out of bounds.
+#[no_mangle]
+pub extern "C" fn orderbook_snapshot_ask(
+    snapshot: &OrderBookSnapshot_API,
+    index: usize,
+) -> BookOrder {
+    snapshot.asks[index]
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_snapshot_sequence(snapshot: &OrderBookSnapshot_API) -> u64 {
+    snapshot.sequence
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_snapshot_ts_event(snapshot: &OrderBookSnapshot_API) -> UnixNanos {
+    snapshot.ts_event
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_snapshot_ts_init(snapshot: &OrderBookSnapshot_API) -> UnixNanos {
+    snapshot.ts_init
+}
+
+/// Returns an [`OrderBookSnapshot`] as a C string pointer.
+#[no_mangle]
+pub extern "C" fn orderbook_snapshot_to_cstr(snapshot: &OrderBookSnapshot_API) -> *const c_char {
+    str_to_cstr(&snapshot.to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ptr, str::FromStr};
+
+    use super::*;
+
+    #[test]
+    fn test_orderbook_deltas_new_from_array() {
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+        let order = book_order_from_raw(OrderSide::Buy, 100_000_000_000, 2, 10_000_000_000, 0, 1);
+        let deltas: Vec<OrderBookDelta> = (1..=3)
+            .map(|i| orderbook_delta_new(instrument_id.clone(), BookAction::Add, order, 0, i, i, i))
+            .collect();
+
+        let api = unsafe { orderbook_deltas_new(instrument_id.clone(), deltas.as_ptr(), 3) };
+
+        assert_eq!(orderbook_deltas_instrument_id(&api), instrument_id);
+        assert_eq!(orderbook_deltas_len(&api), 3);
+        assert_eq!(orderbook_deltas_get(&api, 2), deltas[2]);
+        assert_eq!(orderbook_deltas_sequence(&api), 3);
+        orderbook_deltas_drop(api);
+    }
+
+    #[test]
+    fn test_orderbook_deltas_recycle_reuses_buffer() {
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+        let order = book_order_from_raw(OrderSide::Buy, 100_000_000_000, 2, 10_000_000_000, 0, 1);
+        let deltas: Vec<OrderBookDelta> = (1..=3)
+            .map(|i| orderbook_delta_new(instrument_id.clone(), BookAction::Add, order, 0, i, i, i))
+            .collect();
+        let before = orderbook_deltas_pool_stats();
+
+        let api = unsafe { orderbook_deltas_new(instrument_id.clone(), deltas.as_ptr(), 3) };
+        orderbook_deltas_recycle(api);
+        let api = unsafe { orderbook_deltas_new(instrument_id.clone(), deltas.as_ptr(), 2) };
+
+        let after = orderbook_deltas_pool_stats();
+        assert_eq!(orderbook_deltas_len(&api), 2);
+        assert_eq!(orderbook_deltas_get(&api, 1), deltas[1]);
+        assert_eq!(after.reused - before.reused, 1);
+        assert_eq!(after.released - before.released, 1);
+        orderbook_deltas_recycle(api);
+    }
+
+    #[test]
+    fn test_orderbook_snapshot_new_with_empty_asks() {
+        let instrument_id = InstrumentId::from_str("AAPL.NASDAQ").unwrap();
+        let bids = vec![book_order_from_raw(
+            OrderSide::Buy,
+            100_000_000_000,
+            2,
+            10_000_000_000,
+            0,
+            1,
+        )];
+
+        let api = unsafe {
+            orderbook_snapshot_new(instrument_id, bids.as_ptr(), 1, ptr::null(), 0, 1, 2, 3)
+        };
+
+        assert_eq!(orderbook_snapshot_bids_len(&api), 1);
+        assert_eq!(orderbook_snapshot_asks_len(&api), 0);
+        assert_eq!(orderbook_snapshot_bid(&api, 0), bids[0]);
+        assert_eq!(orderbook_snapshot_ts_init(&api), 3);
+        orderbook_snapshot_drop(api);
+    }
+}
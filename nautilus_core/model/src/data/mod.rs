@@ -14,13 +14,23 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod bar;
+#[cfg(feature = "ffi")]
 pub mod bar_api;
 pub mod book;
+#[cfg(feature = "ffi")]
 pub mod book_api;
 pub mod tick;
+#[cfg(feature = "ffi")]
 pub mod tick_api;
 
-use nautilus_core::time::UnixNanos;
+use nautilus_core::{
+    cvec::{CVecElement, CVecElementType},
+    time::UnixNanos,
+};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use self::{
     bar::Bar,
@@ -30,6 +40,9 @@ use self::{
 
 #[repr(C)]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub enum Data {
     Delta(OrderBookDelta),
     Quote(QuoteTick),
@@ -49,26 +62,120 @@ impl Data {
     }
 }
 
+/// Provides access to the `ts_init` timestamp of a data type.
+pub trait HasTsInit {
+    /// Returns the UNIX timestamp (nanoseconds) when the data was initialized.
+    fn get_ts_init(&self) -> UnixNanos;
+}
+
+impl HasTsInit for OrderBookDelta {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl HasTsInit for QuoteTick {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl HasTsInit for TradeTick {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl HasTsInit for Bar {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl CVecElement for Data {
+    const ELEMENT_TYPE: CVecElementType = CVecElementType::Data;
+}
+
+impl CVecElement for OrderBookDelta {
+    const ELEMENT_TYPE: CVecElementType = CVecElementType::OrderBookDelta;
+}
+
+impl CVecElement for QuoteTick {
+    const ELEMENT_TYPE: CVecElementType = CVecElementType::QuoteTick;
+}
+
+impl CVecElement for TradeTick {
+    const ELEMENT_TYPE: CVecElementType = CVecElementType::TradeTick;
+}
+
+impl CVecElement for Bar {
+    const ELEMENT_TYPE: CVecElementType = CVecElementType::Bar;
+}
+
 impl From<OrderBookDelta> for Data {
     fn from(value: OrderBookDelta) -> Self {
         Self::Delta(value)
     }
 }
 
+impl TryFrom<Data> for OrderBookDelta {
+    type Error = Data;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::Delta(x) => Ok(x),
+            _ => Err(value),
+        }
+    }
+}
+
 impl From<QuoteTick> for Data {
     fn from(value: QuoteTick) -> Self {
         Self::Quote(value)
     }
 }
 
+impl TryFrom<Data> for QuoteTick {
+    type Error = Data;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::Quote(x) => Ok(x),
+            _ => Err(value),
+        }
+    }
+}
+
 impl From<TradeTick> for Data {
     fn from(value: TradeTick) -> Self {
         Self::Trade(value)
     }
 }
 
+impl TryFrom<Data> for TradeTick {
+    type Error = Data;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::Trade(x) => Ok(x),
+            _ => Err(value),
+        }
+    }
+}
+
 impl From<Bar> for Data {
     fn from(value: Bar) -> Self {
         Self::Bar(value)
     }
 }
+
+impl TryFrom<Data> for Bar {
+    type Error = Data;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::Bar(x) => Ok(x),
+            _ => Err(value),
+        }
+    }
+}
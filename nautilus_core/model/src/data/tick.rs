@@ -19,8 +19,15 @@ use std::{
 };
 
 use nautilus_core::{correctness, time::UnixNanos};
-use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::{basic::CompareOp, prelude::*, types::PyTuple, PyTypeInfo};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "python")]
+use crate::python::{hash_of, richcmp_eq, to_pyvalue_err};
 use crate::{
     enums::{AggressorSide, PriceType},
     identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
@@ -30,7 +37,9 @@ use crate::{
 /// Represents a single quote tick in a financial market.
 #[repr(C)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-#[pyclass]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct QuoteTick {
     pub instrument_id: InstrumentId,
     pub bid: Price,
@@ -87,6 +96,19 @@ impl QuoteTick {
             _ => panic!("Cannot extract with price type {price_type}"),
         }
     }
+
+    #[must_use]
+    pub fn extract_volume(&self, price_type: PriceType) -> Quantity {
+        match price_type {
+            PriceType::Bid => self.bid_size,
+            PriceType::Ask => self.ask_size,
+            PriceType::Mid => Quantity::from_raw(
+                (self.bid_size.raw + self.ask_size.raw) / 2,
+                cmp::min(self.bid_size.precision + 1, FIXED_PRECISION),
+            ),
+            _ => panic!("Cannot extract with price type {price_type}"),
+        }
+    }
 }
 
 impl Display for QuoteTick {
@@ -102,7 +124,9 @@ impl Display for QuoteTick {
 /// Represents a single trade tick in a financial market.
 #[repr(C)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-#[pyclass]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct TradeTick {
     pub instrument_id: InstrumentId,
     pub price: Price,
@@ -151,6 +175,290 @@ impl Display for TradeTick {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl QuoteTick {
+    #[new]
+    fn py_new(
+        instrument_id: InstrumentId,
+        bid: Price,
+        ask: Price,
+        bid_size: Quantity,
+        ask_size: Quantity,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> PyResult<Self> {
+        if bid.precision != ask.precision || bid_size.precision != ask_size.precision {
+            return Err(to_pyvalue_err(
+                "invalid `QuoteTick`, bid and ask precisions must match",
+            ));
+        }
+        Ok(Self::new(
+            instrument_id,
+            bid,
+            ask,
+            bid_size,
+            ask_size,
+            ts_event,
+            ts_init,
+        ))
+    }
+
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let (instrument_id, bid, ask, bid_size, ask_size, ts_event, ts_init): (
+            InstrumentId,
+            Price,
+            Price,
+            Quantity,
+            Quantity,
+            UnixNanos,
+            UnixNanos,
+        ) = state.extract(py)?;
+        *self = Self {
+            instrument_id,
+            bid,
+            ask,
+            bid_size,
+            ask_size,
+            ts_event,
+            ts_init,
+        };
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyObject {
+        (
+            self.instrument_id.clone().into_py(py),
+            self.bid.into_py(py),
+            self.ask.into_py(py),
+            self.bid_size.into_py(py),
+            self.ask_size.into_py(py),
+            self.ts_event,
+            self.ts_init,
+        )
+            .to_object(py)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py);
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> Self {
+        Self {
+            instrument_id: InstrumentId::default(),
+            bid: Price::default(),
+            ask: Price::default(),
+            bid_size: Quantity::default(),
+            ask_size: Quantity::default(),
+            ts_event: 0,
+            ts_init: 0,
+        }
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        richcmp_eq(self, other, op, py)
+    }
+
+    fn __hash__(&self) -> isize {
+        hash_of(self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}({})", Self::NAME, self)
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "bid")]
+    fn py_bid(&self) -> Price {
+        self.bid
+    }
+
+    #[getter]
+    #[pyo3(name = "ask")]
+    fn py_ask(&self) -> Price {
+        self.ask
+    }
+
+    #[getter]
+    #[pyo3(name = "bid_size")]
+    fn py_bid_size(&self) -> Quantity {
+        self.bid_size
+    }
+
+    #[getter]
+    #[pyo3(name = "ask_size")]
+    fn py_ask_size(&self) -> Quantity {
+        self.ask_size
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl TradeTick {
+    #[new]
+    fn py_new(
+        instrument_id: InstrumentId,
+        price: Price,
+        size: Quantity,
+        aggressor_side: AggressorSide,
+        trade_id: TradeId,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new(
+            instrument_id,
+            price,
+            size,
+            aggressor_side,
+            trade_id,
+            ts_event,
+            ts_init,
+        )
+    }
+
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let (instrument_id, price, size, aggressor_side, trade_id, ts_event, ts_init): (
+            InstrumentId,
+            Price,
+            Quantity,
+            u8,
+            TradeId,
+            UnixNanos,
+            UnixNanos,
+        ) = state.extract(py)?;
+        *self = Self {
+            instrument_id,
+            price,
+            size,
+            aggressor_side: AggressorSide::from_repr(aggressor_side as usize).ok_or_else(|| {
+                to_pyvalue_err(format!("invalid `AggressorSide`, was {aggressor_side}"))
+            })?,
+            trade_id,
+            ts_event,
+            ts_init,
+        };
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyObject {
+        (
+            self.instrument_id.clone().into_py(py),
+            self.price.into_py(py),
+            self.size.into_py(py),
+            self.aggressor_side as u8,
+            self.trade_id.clone().into_py(py),
+            self.ts_event,
+            self.ts_init,
+        )
+            .to_object(py)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py);
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> Self {
+        Self {
+            instrument_id: InstrumentId::default(),
+            price: Price::default(),
+            size: Quantity::default(),
+            aggressor_side: AggressorSide::NoAggressor,
+            trade_id: TradeId::new("NULL"),
+            ts_event: 0,
+            ts_init: 0,
+        }
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        richcmp_eq(self, other, op, py)
+    }
+
+    fn __hash__(&self) -> isize {
+        hash_of(self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}({})", Self::NAME, self)
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "price")]
+    fn py_price(&self) -> Price {
+        self.price
+    }
+
+    #[getter]
+    #[pyo3(name = "size")]
+    fn py_size(&self) -> Quantity {
+        self.size
+    }
+
+    #[getter]
+    #[pyo3(name = "aggressor_side")]
+    fn py_aggressor_side(&self) -> AggressorSide {
+        self.aggressor_side
+    }
+
+    #[getter]
+    #[pyo3(name = "trade_id")]
+    fn py_trade_id(&self) -> TradeId {
+        self.trade_id.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -206,6 +514,28 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest(
+        input,
+        expected,
+        case(PriceType::Bid, 1_000_000_000),
+        case(PriceType::Ask, 2_000_000_000),
+        case(PriceType::Mid, 1_500_000_000)
+    )]
+    fn test_quote_tick_extract_volume(input: PriceType, expected: u64) {
+        let tick = QuoteTick {
+            instrument_id: InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap(),
+            bid: Price::new(10000.0, 4),
+            ask: Price::new(10001.0, 4),
+            bid_size: Quantity::new(1.0, 0),
+            ask_size: Quantity::new(2.0, 0),
+            ts_event: 0,
+            ts_init: 0,
+        };
+
+        let result = tick.extract_volume(input).raw;
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_trade_tick_to_string() {
         let tick = TradeTick {
@@ -13,9 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::ffi::c_char;
+use std::{cell::RefCell, ffi::c_char, slice};
 
-use nautilus_core::{string::str_to_cstr, time::UnixNanos};
+use nautilus_core::{
+    cvec::CVec,
+    pool::{ObjectPool, PoolStats},
+    string::str_to_cstr,
+    time::UnixNanos,
+};
 
 use super::{
     tick::{QuoteTick, TradeTick},
@@ -27,6 +32,21 @@ use crate::{
     types::{price::Price, quantity::Quantity},
 };
 
+/// The maximum number of idle tick vectors kept for reuse per thread, for each tick type.
+const TICKS_POOL_MAX_IDLE: usize = 64;
+
+/// The maximum number of ticks an idle vector keeps room for.
+const TICKS_POOL_MAX_CAPACITY: usize = 10_000;
+
+thread_local! {
+    static QUOTE_TICKS_POOL: RefCell<ObjectPool<Vec<QuoteTick>>> = RefCell::new(
+        ObjectPool::new(TICKS_POOL_MAX_IDLE, Vec::new).with_max_capacity(TICKS_POOL_MAX_CAPACITY),
+    );
+    static TRADE_TICKS_POOL: RefCell<ObjectPool<Vec<TradeTick>>> = RefCell::new(
+        ObjectPool::new(TICKS_POOL_MAX_IDLE, Vec::new).with_max_capacity(TICKS_POOL_MAX_CAPACITY),
+    );
+}
+
 #[no_mangle]
 pub extern "C" fn quote_tick_new(
     instrument_id: InstrumentId,
@@ -52,6 +72,56 @@ pub extern "C" fn quote_tick_new(
     )
 }
 
+/// Returns a vector of [`QuoteTick`]s for the given instrument, constructed from parallel
+/// arrays of raw values (all prices and sizes share the given precisions).
+///
+/// The returned vector must be freed with `vec_quote_ticks_drop`, or `vec_quote_ticks_recycle`
+/// to reuse its allocation for the next call.
+///
+/// # Safety
+///
+/// - Assumes each array pointer is valid for at least `len` elements.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn quote_tick_new_n(
+    instrument_id: InstrumentId,
+    bid_prices_raw: *const i64,
+    ask_prices_raw: *const i64,
+    price_prec: u8,
+    bid_sizes_raw: *const u64,
+    ask_sizes_raw: *const u64,
+    size_prec: u8,
+    ts_events: *const UnixNanos,
+    ts_inits: *const UnixNanos,
+    len: usize,
+) -> CVec {
+    if len == 0 {
+        return CVec::empty();
+    }
+
+    let bid_prices_raw = slice::from_raw_parts(bid_prices_raw, len);
+    let ask_prices_raw = slice::from_raw_parts(ask_prices_raw, len);
+    let bid_sizes_raw = slice::from_raw_parts(bid_sizes_raw, len);
+    let ask_sizes_raw = slice::from_raw_parts(ask_sizes_raw, len);
+    let ts_events = slice::from_raw_parts(ts_events, len);
+    let ts_inits = slice::from_raw_parts(ts_inits, len);
+
+    let mut ticks = QUOTE_TICKS_POOL.with(|pool| pool.borrow_mut().acquire());
+    ticks.extend((0..len).map(|i| {
+        QuoteTick::new(
+            instrument_id.clone(),
+            Price::from_raw(bid_prices_raw[i], price_prec),
+            Price::from_raw(ask_prices_raw[i], price_prec),
+            Quantity::from_raw(bid_sizes_raw[i], size_prec),
+            Quantity::from_raw(ask_sizes_raw[i], size_prec),
+            ts_events[i],
+            ts_inits[i],
+        )
+    }));
+
+    CVec::from_elements(ticks)
+}
+
 #[no_mangle]
 pub extern "C" fn quote_tick_drop(tick: QuoteTick) {
     drop(tick); // Memory freed here
@@ -91,6 +161,56 @@ pub extern "C" fn trade_tick_new(
     )
 }
 
+/// Returns a vector of [`TradeTick`]s for the given instrument, constructed from parallel
+/// arrays of raw values (all prices and sizes share the given precisions).
+///
+/// The returned vector must be freed with `vec_trade_ticks_drop`, or `vec_trade_ticks_recycle`
+/// to reuse its allocation for the next call.
+///
+/// # Safety
+///
+/// - Assumes each array pointer is valid for at least `len` elements.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn trade_tick_new_n(
+    instrument_id: InstrumentId,
+    prices_raw: *const i64,
+    price_prec: u8,
+    sizes_raw: *const u64,
+    size_prec: u8,
+    aggressor_sides: *const AggressorSide,
+    trade_ids: *const TradeId,
+    ts_events: *const UnixNanos,
+    ts_inits: *const UnixNanos,
+    len: usize,
+) -> CVec {
+    if len == 0 {
+        return CVec::empty();
+    }
+
+    let prices_raw = slice::from_raw_parts(prices_raw, len);
+    let sizes_raw = slice::from_raw_parts(sizes_raw, len);
+    let aggressor_sides = slice::from_raw_parts(aggressor_sides, len);
+    let trade_ids = slice::from_raw_parts(trade_ids, len);
+    let ts_events = slice::from_raw_parts(ts_events, len);
+    let ts_inits = slice::from_raw_parts(ts_inits, len);
+
+    let mut ticks = TRADE_TICKS_POOL.with(|pool| pool.borrow_mut().acquire());
+    ticks.extend((0..len).map(|i| {
+        TradeTick::new(
+            instrument_id.clone(),
+            Price::from_raw(prices_raw[i], price_prec),
+            Quantity::from_raw(sizes_raw[i], size_prec),
+            aggressor_sides[i],
+            trade_ids[i].clone(),
+            ts_events[i],
+            ts_inits[i],
+        )
+    }));
+
+    CVec::from_elements(ticks)
+}
+
 #[no_mangle]
 pub extern "C" fn trade_tick_drop(tick: TradeTick) {
     drop(tick); // Memory freed here
@@ -116,3 +236,194 @@ pub extern "C" fn data_drop(data: Data) {
 pub extern "C" fn data_clone(data: &Data) -> Data {
     data.clone()
 }
+
+#[no_mangle]
+pub extern "C" fn vec_quote_ticks_drop(v: CVec) {
+    let CVec { ptr, len, cap, .. } = v;
+    if ptr.is_null() {
+        return;
+    }
+    let data: Vec<QuoteTick> = unsafe { Vec::from_raw_parts(ptr as *mut QuoteTick, len, cap) };
+    drop(data); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn vec_trade_ticks_drop(v: CVec) {
+    let CVec { ptr, len, cap, .. } = v;
+    if ptr.is_null() {
+        return;
+    }
+    let data: Vec<TradeTick> = unsafe { Vec::from_raw_parts(ptr as *mut TradeTick, len, cap) };
+    drop(data); // Memory freed here
+}
+
+/// Drops the ticks, returning the vector to the pool of the calling thread for reuse by
+/// `quote_tick_new_n`.
+#[no_mangle]
+pub extern "C" fn vec_quote_ticks_recycle(v: CVec) {
+    if v.ptr.is_null() {
+        return;
+    }
+    let ticks: Vec<QuoteTick> = unsafe { v.into_vec() };
+    QUOTE_TICKS_POOL.with(|pool| pool.borrow_mut().release(ticks));
+}
+
+/// Drops the ticks, returning the vector to the pool of the calling thread for reuse by
+/// `trade_tick_new_n`.
+#[no_mangle]
+pub extern "C" fn vec_trade_ticks_recycle(v: CVec) {
+    if v.ptr.is_null() {
+        return;
+    }
+    let ticks: Vec<TradeTick> = unsafe { v.into_vec() };
+    TRADE_TICKS_POOL.with(|pool| pool.borrow_mut().release(ticks));
+}
+
+/// Returns the statistics of the quote tick vector pool of the calling thread.
+#[no_mangle]
+pub extern "C" fn vec_quote_ticks_pool_stats() -> PoolStats {
+    QUOTE_TICKS_POOL.with(|pool| pool.borrow().stats())
+}
+
+/// Returns the statistics of the trade tick vector pool of the calling thread.
+#[no_mangle]
+pub extern "C" fn vec_trade_ticks_pool_stats() -> PoolStats {
+    TRADE_TICKS_POOL.with(|pool| pool.borrow().stats())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_quote_tick_new_n() {
+        let instrument_id = InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap();
+        let bid_prices_raw = [10_000_000_000_000, 10_001_000_000_000];
+        let ask_prices_raw = [10_002_000_000_000, 10_003_000_000_000];
+        let bid_sizes_raw = [1_000_000_000, 2_000_000_000];
+        let ask_sizes_raw = [3_000_000_000, 4_000_000_000];
+        let ts_events = [1, 2];
+        let ts_inits = [3, 4];
+
+        let cvec = unsafe {
+            quote_tick_new_n(
+                instrument_id.clone(),
+                bid_prices_raw.as_ptr(),
+                ask_prices_raw.as_ptr(),
+                2,
+                bid_sizes_raw.as_ptr(),
+                ask_sizes_raw.as_ptr(),
+                0,
+                ts_events.as_ptr(),
+                ts_inits.as_ptr(),
+                2,
+            )
+        };
+
+        assert_eq!(cvec.len, 2);
+        let ticks = unsafe { slice::from_raw_parts(cvec.ptr.cast::<QuoteTick>(), cvec.len) };
+        assert_eq!(ticks[1].instrument_id, instrument_id);
+        assert_eq!(ticks[1].bid, Price::from("10001.00"));
+        assert_eq!(ticks[1].ask_size, Quantity::from("4"));
+        assert_eq!(ticks[1].ts_init, 4);
+        vec_quote_ticks_drop(cvec);
+    }
+
+    #[test]
+    fn test_trade_tick_new_n() {
+        let instrument_id = InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap();
+        let prices_raw = [10_000_000_000_000];
+        let sizes_raw = [1_000_000_000];
+        let aggressor_sides = [AggressorSide::Buyer];
+        let trade_ids = [TradeId::new("123456789")];
+        let ts_events = [1];
+        let ts_inits = [2];
+
+        let cvec = unsafe {
+            trade_tick_new_n(
+                instrument_id,
+                prices_raw.as_ptr(),
+                2,
+                sizes_raw.as_ptr(),
+                0,
+                aggressor_sides.as_ptr(),
+                trade_ids.as_ptr(),
+                ts_events.as_ptr(),
+                ts_inits.as_ptr(),
+                1,
+            )
+        };
+
+        assert_eq!(cvec.len, 1);
+        let ticks = unsafe { slice::from_raw_parts(cvec.ptr.cast::<TradeTick>(), cvec.len) };
+        assert_eq!(ticks[0].price, Price::from("10000.00"));
+        assert_eq!(ticks[0].trade_id, trade_ids[0]);
+        vec_trade_ticks_drop(cvec);
+    }
+
+    #[test]
+    fn test_vec_trade_ticks_recycle_reuses_vector() {
+        let instrument_id = InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap();
+        let prices_raw = [10_000_000_000_000, 10_001_000_000_000];
+        let sizes_raw = [1_000_000_000, 2_000_000_000];
+        let aggressor_sides = [AggressorSide::Buyer, AggressorSide::Seller];
+        let trade_ids = [TradeId::new("1"), TradeId::new("2")];
+        let ts_events = [1, 2];
+        let ts_inits = [3, 4];
+        let new_n = |len| unsafe {
+            trade_tick_new_n(
+                instrument_id.clone(),
+                prices_raw.as_ptr(),
+                2,
+                sizes_raw.as_ptr(),
+                0,
+                aggressor_sides.as_ptr(),
+                trade_ids.as_ptr(),
+                ts_events.as_ptr(),
+                ts_inits.as_ptr(),
+                len,
+            )
+        };
+        let before = vec_trade_ticks_pool_stats();
+
+        let cvec = new_n(2);
+        let ptr = cvec.ptr;
+        vec_trade_ticks_recycle(cvec);
+        let cvec = new_n(1);
+
+        let after = vec_trade_ticks_pool_stats();
+        assert_eq!(cvec.ptr, ptr);
+        assert_eq!(cvec.len, 1);
+        let ticks = unsafe { slice::from_raw_parts(cvec.ptr.cast::<TradeTick>(), cvec.len) };
+        assert_eq!(ticks[0].trade_id, trade_ids[0]);
+        assert_eq!(after.reused - before.reused, 1);
+        assert_eq!(after.released - before.released, 1);
+        vec_trade_ticks_recycle(cvec);
+    }
+
+    #[test]
+    fn test_quote_tick_new_n_empty() {
+        let instrument_id = InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap();
+        let cvec = unsafe {
+            quote_tick_new_n(
+                instrument_id,
+                std::ptr::null(),
+                std::ptr::null(),
+                2,
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+            )
+        };
+        assert_eq!(cvec.len, 0);
+        vec_quote_ticks_drop(cvec);
+    }
+}
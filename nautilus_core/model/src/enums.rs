@@ -14,14 +14,29 @@
 // -------------------------------------------------------------------------------------------------
 
 //! Defines enumerations for the trading domain model.
-
-use std::{ffi::c_char, fmt::Debug, str::FromStr};
-
-use nautilus_core::string::{cstr_to_string, str_to_cstr};
-use pyo3::prelude::*;
+//!
+//! The explicit discriminants are part of the C ABI shared with Cython (and persisted
+//! data), so existing values must never change; new variants take new values.
+
+#[cfg(feature = "ffi")]
+use core::ffi::c_char;
+use core::fmt::Debug;
+#[cfg(feature = "python")]
+use core::str::FromStr;
+
+#[cfg(feature = "ffi")]
+use nautilus_core::{ffi_error::write_parsed_cstr, string::str_to_cstr};
+#[cfg(feature = "python")]
+use pyo3::{prelude::*, types::PyTuple, PyTypeInfo};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, FromRepr};
 
+#[cfg(feature = "python")]
+use crate::python::to_pyvalue_err;
+
 pub trait FromU8 {
     fn from_u8(value: u8) -> Option<Self>
     where
@@ -30,12 +45,12 @@ pub trait FromU8 {
 
 /// An account type provided by a trading venue or broker.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum AccountType {
     /// An account with unleveraged cash assets only.
     Cash = 1,
@@ -47,12 +62,12 @@ pub enum AccountType {
 
 /// An aggregation source for derived data.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum AggregationSource {
     /// The data is externally aggregated (outside the Nautilus system boundary).
     External = 1,
@@ -62,12 +77,12 @@ pub enum AggregationSource {
 
 /// The side for the aggressing order of a trade in a market.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum AggressorSide {
     /// There was no specific aggressor for the trade.
     NoAggressor = 0, // Will be replaced by `Option`
@@ -79,12 +94,12 @@ pub enum AggressorSide {
 
 /// A broad financial market asset class.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[allow(non_camel_case_types)]
 pub enum AssetClass {
     /// Foreign exchange (FOREX) assets.
@@ -109,12 +124,12 @@ pub enum AssetClass {
 
 /// The asset type for a financial market product.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum AssetType {
     /// A spot market asset type. The current market price of an asset that is bought or sold for immediate delivery and payment.
     Spot = 1,
@@ -134,12 +149,12 @@ pub enum AssetType {
 
 /// The aggregation method through which a bar is generated and closed.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum BarAggregation {
     /// Based on a number of ticks.
     Tick = 1,
@@ -177,12 +192,12 @@ pub enum BarAggregation {
 
 /// The type of order book action for an order book event.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum BookAction {
     /// An order is added to the book.
     Add = 1,
@@ -206,15 +221,47 @@ impl FromU8 for BookAction {
     }
 }
 
+/// The venue specification of an order book checksum, used to verify the integrity of a book
+/// built from venue deltas.
+///
+/// Coinbase is not included as its order book feeds do not publish a checksum, the integrity
+/// of those books is instead verified by the message sequence numbers.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "python", pyclass)]
+pub enum BookChecksumType {
+    /// CRC32 over the top 10 asks then bids, as concatenated price and size digits (Kraken).
+    Kraken = 1,
+    /// Signed CRC32 over the top 25 levels, interleaved as `bid:size:ask:size` (OKX).
+    Okx = 2,
+    /// Signed CRC32 over the top 25 levels, interleaved with negative ask sizes (Bitfinex).
+    Bitfinex = 3,
+}
+
+impl FromU8 for BookChecksumType {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(BookChecksumType::Kraken),
+            2 => Some(BookChecksumType::Okx),
+            3 => Some(BookChecksumType::Bitfinex),
+            _ => None,
+        }
+    }
+}
+
 /// The order book type, representing the type of levels granularity and delta updating heuristics.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[allow(non_camel_case_types)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum BookType {
     /// Top-of-book best bid/offer, one level per side.
     L1_TBBO = 1,
@@ -239,12 +286,12 @@ impl FromU8 for BookType {
 ///
 /// [FIX 5.0 SP2 : ContingencyType <1385> field](https://www.onixs.biz/fix-dictionary/5.0.sp2/tagnum_1385.html).
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum ContingencyType {
     /// Not a contingent order.
     NoContingency = 0, // Will be replaced by `Option`
@@ -258,12 +305,12 @@ pub enum ContingencyType {
 
 /// The broad currency type.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum CurrencyType {
     /// A type of cryptocurrency or crypto token.
     Crypto = 1,
@@ -273,12 +320,12 @@ pub enum CurrencyType {
 
 /// The type of event for an instrument close.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum InstrumentCloseType {
     /// When the market session ended.
     EndOfSession = 1,
@@ -288,13 +335,13 @@ pub enum InstrumentCloseType {
 
 /// The liqudity side for a trade in a financial market.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[allow(clippy::enum_variant_names)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum LiquiditySide {
     /// No specific liqudity side.
     NoLiquiditySide = 0, // Will be replaced by `Option`
@@ -306,12 +353,12 @@ pub enum LiquiditySide {
 
 /// The status of an individual market on a trading venue.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum MarketStatus {
     /// The market is closed.
     Closed = 1,
@@ -327,12 +374,12 @@ pub enum MarketStatus {
 
 /// The order management system (OMS) type for a trading venue or trading strategy.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum OmsType {
     /// There is no specific type of order management specified (will defer to the venue).
     Unspecified = 0, // Will be replaced by `Option`
@@ -346,12 +393,12 @@ pub enum OmsType {
 
 /// The kind of options contract.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum OptionKind {
     /// A Call option gives the holder the right, but not the obligation, to buy an underlying asset at a specified strike price within a specified period of time.
     Call = 1,
@@ -361,13 +408,13 @@ pub enum OptionKind {
 
 /// The order side for a specific order, or action related to orders.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[allow(clippy::enum_variant_names)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum OrderSide {
     /// No order side is specified (only valid in the context of a filter for actions involving orders).
     NoOrderSide = 0, // Will be replaced by `Option`
@@ -410,12 +457,12 @@ impl FromU8 for OrderSide {
 ///  - `EXPIRED`
 ///  - `FILLED`
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum OrderStatus {
     /// The order is initialized (instantiated) within the Nautilus system.
     Initialized = 1,
@@ -445,12 +492,12 @@ pub enum OrderStatus {
 
 /// The type of order.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum OrderType {
     /// A market order to buy or sell at the best available price in the current market.
     Market = 1,
@@ -474,13 +521,13 @@ pub enum OrderType {
 
 /// The market side for a specific position, or action related to positions.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[allow(clippy::enum_variant_names)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum PositionSide {
     /// No position side is specified (only valid in the context of a filter for actions involving positions).
     NoPositionSide = 0, // Will be replaced by `Option`
@@ -494,12 +541,12 @@ pub enum PositionSide {
 
 /// The type of price for an instrument in a financial market.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum PriceType {
     /// A quoted order price where a buyer is willing to buy a quantity of an instrument.
     Bid = 1,
@@ -513,12 +560,12 @@ pub enum PriceType {
 
 /// The 'Time in Force' instruction for an order in the financial market.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum TimeInForce {
     /// Good Till Canceled (GTC) - the order remains active until canceled.
     Gtc = 1,
@@ -538,12 +585,12 @@ pub enum TimeInForce {
 
 /// The trading state for a node.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum TradingState {
     /// Normal trading operations.
     Active = 1,
@@ -555,12 +602,12 @@ pub enum TradingState {
 
 /// The trailing offset type for an order type which specifies a trailing stop/trigger or limit price.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum TrailingOffsetType {
     /// No trailing offset type is specified (invalid for trailing type orders).
     NoTrailingOffset = 0, // Will be replaced by `Option`
@@ -576,12 +623,12 @@ pub enum TrailingOffsetType {
 
 /// The trigger type for the stop/trigger price of an order.
 #[repr(C)]
-#[derive(
-    Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, Serialize, Deserialize,
-)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum TriggerType {
     /// No trigger type is specified (invalid for orders with a trigger).
     NoTrigger = 0, // Will be replaced by `Option`
@@ -605,389 +652,867 @@ pub enum TriggerType {
     IndexPrice = 9,
 }
 
+/// Implements the Python protocol methods for a `#[pyclass]` enum.
+///
+/// PyO3 already provides `__repr__` and `__richcmp__` for enums, the `$default` variant is
+/// only used as the placeholder when unpickling.
+macro_rules! enum_for_python {
+    ($ty:ty, $default:expr) => {
+        #[cfg(feature = "python")]
+        #[pymethods]
+        impl $ty {
+            fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+                let (value,): (u8,) = state.extract(py)?;
+                *self = Self::from_repr(value as usize).ok_or_else(|| {
+                    to_pyvalue_err(format!(
+                        "invalid `{}` enum value, was {value}",
+                        <Self as PyTypeInfo>::NAME
+                    ))
+                })?;
+                Ok(())
+            }
+
+            fn __getstate__(&self, py: Python) -> PyObject {
+                (*self as u8,).to_object(py)
+            }
+
+            fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+                let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+                let state = self.__getstate__(py);
+                Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+            }
+
+            #[staticmethod]
+            fn _safe_constructor() -> Self {
+                $default
+            }
+
+            #[staticmethod]
+            #[pyo3(name = "from_str")]
+            fn py_from_str(value: &str) -> PyResult<Self> {
+                Self::from_str(value).map_err(|_| {
+                    to_pyvalue_err(format!(
+                        "invalid `{}` enum string value, was '{value}'",
+                        <Self as PyTypeInfo>::NAME
+                    ))
+                })
+            }
+
+            fn __hash__(&self) -> isize {
+                *self as isize
+            }
+
+            fn __str__(&self) -> String {
+                self.to_string()
+            }
+
+            #[getter]
+            #[pyo3(name = "value")]
+            fn py_value(&self) -> u8 {
+                *self as u8
+            }
+        }
+    };
+}
+
+enum_for_python!(AccountType, AccountType::Cash);
+enum_for_python!(AggregationSource, AggregationSource::External);
+enum_for_python!(AggressorSide, AggressorSide::NoAggressor);
+enum_for_python!(AssetClass, AssetClass::FX);
+enum_for_python!(AssetType, AssetType::Spot);
+enum_for_python!(BarAggregation, BarAggregation::Tick);
+enum_for_python!(BookAction, BookAction::Add);
+enum_for_python!(BookChecksumType, BookChecksumType::Kraken);
+enum_for_python!(BookType, BookType::L1_TBBO);
+enum_for_python!(ContingencyType, ContingencyType::NoContingency);
+enum_for_python!(CurrencyType, CurrencyType::Crypto);
+enum_for_python!(InstrumentCloseType, InstrumentCloseType::EndOfSession);
+enum_for_python!(LiquiditySide, LiquiditySide::NoLiquiditySide);
+enum_for_python!(MarketStatus, MarketStatus::Closed);
+enum_for_python!(OmsType, OmsType::Unspecified);
+enum_for_python!(OptionKind, OptionKind::Call);
+enum_for_python!(OrderSide, OrderSide::NoOrderSide);
+enum_for_python!(OrderStatus, OrderStatus::Initialized);
+enum_for_python!(OrderType, OrderType::Market);
+enum_for_python!(PositionSide, PositionSide::NoPositionSide);
+enum_for_python!(PriceType, PriceType::Bid);
+enum_for_python!(TimeInForce, TimeInForce::Gtc);
+enum_for_python!(TradingState, TradingState::Active);
+enum_for_python!(TrailingOffsetType, TrailingOffsetType::NoTrailingOffset);
+enum_for_python!(TriggerType, TriggerType::NoTrigger);
+
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn account_type_to_cstr(value: AccountType) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`AccountType`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn account_type_from_cstr(ptr: *const c_char) -> AccountType {
-    let value = cstr_to_string(ptr);
-    AccountType::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `AccountType` enum string value, was '{value}'"))
+pub unsafe extern "C" fn account_type_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut AccountType,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`AccountType` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn aggregation_source_to_cstr(value: AggregationSource) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`AggregationSource`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn aggregation_source_from_cstr(ptr: *const c_char) -> AggregationSource {
-    let value = cstr_to_string(ptr);
-    AggregationSource::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `AggregationSource` enum string value, was '{value}'"))
+pub unsafe extern "C" fn aggregation_source_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut AggregationSource,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`AggregationSource` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn aggressor_side_to_cstr(value: AggressorSide) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`AggressorSide`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn aggressor_side_from_cstr(ptr: *const c_char) -> AggressorSide {
-    let value = cstr_to_string(ptr);
-    AggressorSide::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `AggressorSide` enum string value, was '{value}'"))
+pub unsafe extern "C" fn aggressor_side_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut AggressorSide,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`AggressorSide` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn asset_class_to_cstr(value: AssetClass) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`AssetClass`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn asset_class_from_cstr(ptr: *const c_char) -> AssetClass {
-    let value = cstr_to_string(ptr);
-    AssetClass::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `AssetClass` enum string value, was '{value}'"))
+pub unsafe extern "C" fn asset_class_try_from_cstr(ptr: *const c_char, out: *mut AssetClass) -> u8 {
+    write_parsed_cstr(ptr, out, "`AssetClass` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn asset_type_to_cstr(value: AssetType) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`AssetType`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn asset_type_from_cstr(ptr: *const c_char) -> AssetType {
-    let value = cstr_to_string(ptr);
-    AssetType::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `AssetType` enum string value, was '{value}'"))
+pub unsafe extern "C" fn asset_type_try_from_cstr(ptr: *const c_char, out: *mut AssetType) -> u8 {
+    write_parsed_cstr(ptr, out, "`AssetType` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn bar_aggregation_to_cstr(value: BarAggregation) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`BarAggregation`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn bar_aggregation_from_cstr(ptr: *const c_char) -> BarAggregation {
-    let value = cstr_to_string(ptr);
-    BarAggregation::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `BarAggregation` enum string value, was '{value}'"))
+pub unsafe extern "C" fn bar_aggregation_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut BarAggregation,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`BarAggregation` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn book_action_to_cstr(value: BookAction) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`BookAction`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn book_action_from_cstr(ptr: *const c_char) -> BookAction {
-    let value = cstr_to_string(ptr);
-    BookAction::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `BookAction` enum string value, was '{value}'"))
+pub unsafe extern "C" fn book_action_try_from_cstr(ptr: *const c_char, out: *mut BookAction) -> u8 {
+    write_parsed_cstr(ptr, out, "`BookAction` enum")
 }
 
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn book_checksum_type_to_cstr(value: BookChecksumType) -> *const c_char {
+    str_to_cstr(&value.to_string())
+}
+
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`BookChecksumType`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn book_checksum_type_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut BookChecksumType,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`BookChecksumType` enum")
+}
+
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn book_type_to_cstr(value: BookType) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`BookType`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn book_type_from_cstr(ptr: *const c_char) -> BookType {
-    let value = cstr_to_string(ptr);
-    BookType::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `BookType` enum string value, was '{value}'"))
+pub unsafe extern "C" fn book_type_try_from_cstr(ptr: *const c_char, out: *mut BookType) -> u8 {
+    write_parsed_cstr(ptr, out, "`BookType` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn contingency_type_to_cstr(value: ContingencyType) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`ContingencyType`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn contingency_type_from_cstr(ptr: *const c_char) -> ContingencyType {
-    let value = cstr_to_string(ptr);
-    ContingencyType::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `ContingencyType` enum string value, was '{value}'"))
+pub unsafe extern "C" fn contingency_type_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut ContingencyType,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`ContingencyType` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn currency_type_to_cstr(value: CurrencyType) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`CurrencyType`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn currency_type_from_cstr(ptr: *const c_char) -> CurrencyType {
-    let value = cstr_to_string(ptr);
-    CurrencyType::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `CurrencyType` enum string value, was '{value}'"))
+pub unsafe extern "C" fn currency_type_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut CurrencyType,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`CurrencyType` enum")
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`InstrumentCloseType`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn instrument_close_type_from_cstr(
+pub unsafe extern "C" fn instrument_close_type_try_from_cstr(
     ptr: *const c_char,
-) -> InstrumentCloseType {
-    let value = cstr_to_string(ptr);
-    InstrumentCloseType::from_str(&value).unwrap_or_else(|_| {
-        panic!("invalid `InstrumentCloseType` enum string value, was '{value}'")
-    })
+    out: *mut InstrumentCloseType,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`InstrumentCloseType` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn instrument_close_type_to_cstr(value: InstrumentCloseType) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn liquidity_side_to_cstr(value: LiquiditySide) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`LiquiditySide`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn liquidity_side_from_cstr(ptr: *const c_char) -> LiquiditySide {
-    let value = cstr_to_string(ptr);
-    LiquiditySide::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `LiquiditySide` enum string value, was '{value}'"))
+pub unsafe extern "C" fn liquidity_side_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut LiquiditySide,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`LiquiditySide` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn market_status_to_cstr(value: MarketStatus) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`MarketStatus`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn market_status_from_cstr(ptr: *const c_char) -> MarketStatus {
-    let value = cstr_to_string(ptr);
-    MarketStatus::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `MarketStatus` enum string value, was '{value}'"))
+pub unsafe extern "C" fn market_status_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut MarketStatus,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`MarketStatus` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn oms_type_to_cstr(value: OmsType) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`OmsType`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn oms_type_from_cstr(ptr: *const c_char) -> OmsType {
-    let value = cstr_to_string(ptr);
-    OmsType::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `OmsType` enum string value, was '{value}'"))
+pub unsafe extern "C" fn oms_type_try_from_cstr(ptr: *const c_char, out: *mut OmsType) -> u8 {
+    write_parsed_cstr(ptr, out, "`OmsType` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn option_kind_to_cstr(value: OptionKind) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`OptionKind`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn option_kind_from_cstr(ptr: *const c_char) -> OptionKind {
-    let value = cstr_to_string(ptr);
-    OptionKind::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `OptionKind` enum string value, was '{value}'"))
+pub unsafe extern "C" fn option_kind_try_from_cstr(ptr: *const c_char, out: *mut OptionKind) -> u8 {
+    write_parsed_cstr(ptr, out, "`OptionKind` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn order_side_to_cstr(value: OrderSide) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`OrderSide`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn order_side_from_cstr(ptr: *const c_char) -> OrderSide {
-    let value = cstr_to_string(ptr);
-    OrderSide::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `OrderSide` enum string value, was '{value}'"))
+pub unsafe extern "C" fn order_side_try_from_cstr(ptr: *const c_char, out: *mut OrderSide) -> u8 {
+    write_parsed_cstr(ptr, out, "`OrderSide` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn order_status_to_cstr(value: OrderStatus) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`OrderStatus`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn order_status_from_cstr(ptr: *const c_char) -> OrderStatus {
-    let value = cstr_to_string(ptr);
-    OrderStatus::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `OrderStatus` enum string value, was '{value}'"))
+pub unsafe extern "C" fn order_status_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut OrderStatus,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`OrderStatus` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn order_type_to_cstr(value: OrderType) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`OrderType`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn order_type_from_cstr(ptr: *const c_char) -> OrderType {
-    let value = cstr_to_string(ptr);
-    OrderType::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `OrderType` enum string value, was '{value}'"))
+pub unsafe extern "C" fn order_type_try_from_cstr(ptr: *const c_char, out: *mut OrderType) -> u8 {
+    write_parsed_cstr(ptr, out, "`OrderType` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn position_side_to_cstr(value: PositionSide) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`PositionSide`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn position_side_from_cstr(ptr: *const c_char) -> PositionSide {
-    let value = cstr_to_string(ptr);
-    PositionSide::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `PositionSide` enum string value, was '{value}'"))
+pub unsafe extern "C" fn position_side_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut PositionSide,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`PositionSide` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn price_type_to_cstr(value: PriceType) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`PriceType`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn price_type_from_cstr(ptr: *const c_char) -> PriceType {
-    let value = cstr_to_string(ptr);
-    PriceType::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `PriceType` enum string value, was '{value}'"))
+pub unsafe extern "C" fn price_type_try_from_cstr(ptr: *const c_char, out: *mut PriceType) -> u8 {
+    write_parsed_cstr(ptr, out, "`PriceType` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn time_in_force_to_cstr(value: TimeInForce) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`TimeInForce`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn time_in_force_from_cstr(ptr: *const c_char) -> TimeInForce {
-    let value = cstr_to_string(ptr);
-    TimeInForce::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `TimeInForce` enum string value, was '{value}'"))
+pub unsafe extern "C" fn time_in_force_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut TimeInForce,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`TimeInForce` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn trading_state_to_cstr(value: TradingState) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`TradingState`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn trading_state_from_cstr(ptr: *const c_char) -> TradingState {
-    let value = cstr_to_string(ptr);
-    TradingState::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `TradingState` enum string value, was '{value}'"))
+pub unsafe extern "C" fn trading_state_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut TradingState,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`TradingState` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn trailing_offset_type_to_cstr(value: TrailingOffsetType) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`TrailingOffsetType`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn trailing_offset_type_from_cstr(ptr: *const c_char) -> TrailingOffsetType {
-    let value = cstr_to_string(ptr);
-    TrailingOffsetType::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `TrailingOffsetType` enum string value, was '{value}'"))
+pub unsafe extern "C" fn trailing_offset_type_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut TrailingOffsetType,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`TrailingOffsetType` enum")
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn trigger_type_to_cstr(value: TriggerType) -> *const c_char {
     str_to_cstr(&value.to_string())
 }
 
-/// Returns an enum from a Python string.
+/// Returns an enum from a Python string, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`TriggerType`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn trigger_type_from_cstr(ptr: *const c_char) -> TriggerType {
-    let value = cstr_to_string(ptr);
-    TriggerType::from_str(&value)
-        .unwrap_or_else(|_| panic!("invalid `TriggerType` enum string value, was '{value}'"))
+pub unsafe extern "C" fn trigger_type_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut TriggerType,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`TriggerType` enum")
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, mem::MaybeUninit, str::FromStr};
+
+    use nautilus_core::string::cstr_drop;
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Asserts every variant of the enum survives a round trip through its C string form.
+    macro_rules! assert_cstr_round_trip {
+        ($ty:ident, $to_cstr:ident, $from_cstr:ident) => {
+            let mut count = 0;
+            for repr in 0..=usize::from(u8::MAX) {
+                if let Some(value) = $ty::from_repr(repr) {
+                    let ptr = $to_cstr(value);
+                    let mut result = MaybeUninit::<$ty>::uninit();
+                    let status = unsafe { $from_cstr(ptr, result.as_mut_ptr()) };
+                    unsafe { cstr_drop(ptr) };
+                    assert_eq!(status, 0);
+                    assert_eq!(unsafe { result.assume_init() }, value);
+                    count += 1;
+                }
+            }
+            assert!(count > 0, "no variants for `{}`", stringify!($ty));
+        };
+    }
+
+    #[rstest]
+    fn test_cstr_round_trip_all_enums() {
+        assert_cstr_round_trip!(
+            AccountType,
+            account_type_to_cstr,
+            account_type_try_from_cstr
+        );
+        assert_cstr_round_trip!(
+            AggregationSource,
+            aggregation_source_to_cstr,
+            aggregation_source_try_from_cstr
+        );
+        assert_cstr_round_trip!(
+            AggressorSide,
+            aggressor_side_to_cstr,
+            aggressor_side_try_from_cstr
+        );
+        assert_cstr_round_trip!(AssetClass, asset_class_to_cstr, asset_class_try_from_cstr);
+        assert_cstr_round_trip!(AssetType, asset_type_to_cstr, asset_type_try_from_cstr);
+        assert_cstr_round_trip!(
+            BarAggregation,
+            bar_aggregation_to_cstr,
+            bar_aggregation_try_from_cstr
+        );
+        assert_cstr_round_trip!(BookAction, book_action_to_cstr, book_action_try_from_cstr);
+        assert_cstr_round_trip!(
+            BookChecksumType,
+            book_checksum_type_to_cstr,
+            book_checksum_type_try_from_cstr
+        );
+        assert_cstr_round_trip!(BookType, book_type_to_cstr, book_type_try_from_cstr);
+        assert_cstr_round_trip!(
+            ContingencyType,
+            contingency_type_to_cstr,
+            contingency_type_try_from_cstr
+        );
+        assert_cstr_round_trip!(
+            CurrencyType,
+            currency_type_to_cstr,
+            currency_type_try_from_cstr
+        );
+        assert_cstr_round_trip!(
+            InstrumentCloseType,
+            instrument_close_type_to_cstr,
+            instrument_close_type_try_from_cstr
+        );
+        assert_cstr_round_trip!(
+            LiquiditySide,
+            liquidity_side_to_cstr,
+            liquidity_side_try_from_cstr
+        );
+        assert_cstr_round_trip!(
+            MarketStatus,
+            market_status_to_cstr,
+            market_status_try_from_cstr
+        );
+        assert_cstr_round_trip!(OmsType, oms_type_to_cstr, oms_type_try_from_cstr);
+        assert_cstr_round_trip!(OptionKind, option_kind_to_cstr, option_kind_try_from_cstr);
+        assert_cstr_round_trip!(OrderSide, order_side_to_cstr, order_side_try_from_cstr);
+        assert_cstr_round_trip!(
+            OrderStatus,
+            order_status_to_cstr,
+            order_status_try_from_cstr
+        );
+        assert_cstr_round_trip!(OrderType, order_type_to_cstr, order_type_try_from_cstr);
+        assert_cstr_round_trip!(
+            PositionSide,
+            position_side_to_cstr,
+            position_side_try_from_cstr
+        );
+        assert_cstr_round_trip!(PriceType, price_type_to_cstr, price_type_try_from_cstr);
+        assert_cstr_round_trip!(
+            TimeInForce,
+            time_in_force_to_cstr,
+            time_in_force_try_from_cstr
+        );
+        assert_cstr_round_trip!(
+            TradingState,
+            trading_state_to_cstr,
+            trading_state_try_from_cstr
+        );
+        assert_cstr_round_trip!(
+            TrailingOffsetType,
+            trailing_offset_type_to_cstr,
+            trailing_offset_type_try_from_cstr
+        );
+        assert_cstr_round_trip!(
+            TriggerType,
+            trigger_type_to_cstr,
+            trigger_type_try_from_cstr
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::NoOrderSide, 0)]
+    #[case(OrderSide::Buy, 1)]
+    #[case(OrderSide::Sell, 2)]
+    fn test_order_side_discriminants(#[case] value: OrderSide, #[case] expected: u8) {
+        assert_eq!(value as u8, expected);
+        assert_eq!(OrderSide::from_repr(expected as usize), Some(value));
+    }
+
+    #[rstest]
+    #[case(OrderType::Market, 1)]
+    #[case(OrderType::Limit, 2)]
+    #[case(OrderType::StopMarket, 3)]
+    #[case(OrderType::StopLimit, 4)]
+    #[case(OrderType::MarketToLimit, 5)]
+    #[case(OrderType::MarketIfTouched, 6)]
+    #[case(OrderType::LimitIfTouched, 7)]
+    #[case(OrderType::TrailingStopMarket, 8)]
+    #[case(OrderType::TrailingStopLimit, 9)]
+    fn test_order_type_discriminants(#[case] value: OrderType, #[case] expected: u8) {
+        assert_eq!(value as u8, expected);
+        assert_eq!(OrderType::from_repr(expected as usize), Some(value));
+    }
+
+    #[rstest]
+    #[case(TimeInForce::Gtc, 1)]
+    #[case(TimeInForce::Ioc, 2)]
+    #[case(TimeInForce::Fok, 3)]
+    #[case(TimeInForce::Gtd, 4)]
+    #[case(TimeInForce::Day, 5)]
+    #[case(TimeInForce::AtTheOpen, 6)]
+    #[case(TimeInForce::AtTheClose, 7)]
+    fn test_time_in_force_discriminants(#[case] value: TimeInForce, #[case] expected: u8) {
+        assert_eq!(value as u8, expected);
+        assert_eq!(TimeInForce::from_repr(expected as usize), Some(value));
+    }
+
+    #[rstest]
+    #[case(LiquiditySide::NoLiquiditySide, 0)]
+    #[case(LiquiditySide::Maker, 1)]
+    #[case(LiquiditySide::Taker, 2)]
+    fn test_liquidity_side_discriminants(#[case] value: LiquiditySide, #[case] expected: u8) {
+        assert_eq!(value as u8, expected);
+        assert_eq!(LiquiditySide::from_repr(expected as usize), Some(value));
+    }
+
+    #[rstest]
+    #[case(AggressorSide::NoAggressor, 0)]
+    #[case(AggressorSide::Buyer, 1)]
+    #[case(AggressorSide::Seller, 2)]
+    fn test_aggressor_side_discriminants(#[case] value: AggressorSide, #[case] expected: u8) {
+        assert_eq!(value as u8, expected);
+        assert_eq!(AggressorSide::from_repr(expected as usize), Some(value));
+    }
+
+    #[rstest]
+    #[case(BookAction::Add, 1)]
+    #[case(BookAction::Update, 2)]
+    #[case(BookAction::Delete, 3)]
+    #[case(BookAction::Clear, 4)]
+    fn test_book_action_discriminants(#[case] value: BookAction, #[case] expected: u8) {
+        assert_eq!(value as u8, expected);
+        assert_eq!(BookAction::from_repr(expected as usize), Some(value));
+    }
+
+    #[rstest]
+    #[case(TriggerType::NoTrigger, 0)]
+    #[case(TriggerType::Default, 1)]
+    #[case(TriggerType::BidAsk, 2)]
+    #[case(TriggerType::LastTrade, 3)]
+    #[case(TriggerType::DoubleLast, 4)]
+    #[case(TriggerType::DoubleBidAsk, 5)]
+    #[case(TriggerType::LastOrBidAsk, 6)]
+    #[case(TriggerType::MidPoint, 7)]
+    #[case(TriggerType::MarkPrice, 8)]
+    #[case(TriggerType::IndexPrice, 9)]
+    fn test_trigger_type_discriminants(#[case] value: TriggerType, #[case] expected: u8) {
+        assert_eq!(value as u8, expected);
+        assert_eq!(TriggerType::from_repr(expected as usize), Some(value));
+    }
+
+    #[rstest]
+    #[case("BUY", OrderSide::Buy)]
+    #[case("buy", OrderSide::Buy)]
+    #[case("NO_ORDER_SIDE", OrderSide::NoOrderSide)]
+    fn test_order_side_from_str(#[case] input: &str, #[case] expected: OrderSide) {
+        assert_eq!(OrderSide::from_str(input).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_try_from_cstr_with_invalid_value() {
+        let input = CString::new("SIDEWAYS").unwrap();
+        let mut out = MaybeUninit::<OrderSide>::uninit();
+
+        let status = unsafe { order_side_try_from_cstr(input.as_ptr(), out.as_mut_ptr()) };
+
+        assert_ne!(status, 0);
+        assert_eq!(
+            nautilus_core::ffi_error::last_error().unwrap(),
+            "invalid `OrderSide` enum string, was 'SIDEWAYS': Matching variant not found"
+        );
+    }
 }
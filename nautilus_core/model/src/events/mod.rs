@@ -13,6 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod account;
+pub mod book;
 pub mod order;
+#[cfg(feature = "ffi")]
 pub mod order_api;
 pub mod position;
@@ -13,12 +13,26 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::collections::BTreeMap;
+
 use derive_builder::{self, Builder};
-use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_core::{
+    cvec::{CVecElement, CVecElementType},
+    time::UnixNanos,
+    uuid::UUID4,
+};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    enums::{ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TriggerType},
+    enums::{
+        ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TrailingOffsetType,
+        TriggerType,
+    },
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         order_list_id::OrderListId, position_id::PositionId, strategy_id::StrategyId,
@@ -28,6 +42,8 @@ use crate::{
 };
 
 #[derive(Clone, Hash, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub enum OrderEvent {
     OrderInitialized(OrderInitialized),
     OrderDenied(OrderDenied),
@@ -46,10 +62,100 @@ pub enum OrderEvent {
     OrderFilled(OrderFilled),
 }
 
+impl OrderEvent {
+    /// Returns the name of the event variant.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            OrderEvent::OrderInitialized(_) => "OrderInitialized",
+            OrderEvent::OrderDenied(_) => "OrderDenied",
+            OrderEvent::OrderSubmitted(_) => "OrderSubmitted",
+            OrderEvent::OrderAccepted(_) => "OrderAccepted",
+            OrderEvent::OrderRejected(_) => "OrderRejected",
+            OrderEvent::OrderCanceled(_) => "OrderCanceled",
+            OrderEvent::OrderExpired(_) => "OrderExpired",
+            OrderEvent::OrderTriggered(_) => "OrderTriggered",
+            OrderEvent::OrderPendingUpdate(_) => "OrderPendingUpdate",
+            OrderEvent::OrderPendingCancel(_) => "OrderPendingCancel",
+            OrderEvent::OrderModifyRejected(_) => "OrderModifyRejected",
+            OrderEvent::OrderCancelRejected(_) => "OrderCancelRejected",
+            OrderEvent::OrderUpdated(_) => "OrderUpdated",
+            OrderEvent::OrderPartiallyFilled(_) => "OrderPartiallyFilled",
+            OrderEvent::OrderFilled(_) => "OrderFilled",
+        }
+    }
+
+    #[must_use]
+    pub fn client_order_id(&self) -> &ClientOrderId {
+        match self {
+            OrderEvent::OrderInitialized(event) => &event.client_order_id,
+            OrderEvent::OrderDenied(event) => &event.client_order_id,
+            OrderEvent::OrderSubmitted(event) => &event.client_order_id,
+            OrderEvent::OrderAccepted(event) => &event.client_order_id,
+            OrderEvent::OrderRejected(event) => &event.client_order_id,
+            OrderEvent::OrderCanceled(event) => &event.client_order_id,
+            OrderEvent::OrderExpired(event) => &event.client_order_id,
+            OrderEvent::OrderTriggered(event) => &event.client_order_id,
+            OrderEvent::OrderPendingUpdate(event) => &event.client_order_id,
+            OrderEvent::OrderPendingCancel(event) => &event.client_order_id,
+            OrderEvent::OrderModifyRejected(event) => &event.client_order_id,
+            OrderEvent::OrderCancelRejected(event) => &event.client_order_id,
+            OrderEvent::OrderUpdated(event) => &event.client_order_id,
+            OrderEvent::OrderPartiallyFilled(event) => &event.client_order_id,
+            OrderEvent::OrderFilled(event) => &event.client_order_id,
+        }
+    }
+
+    #[must_use]
+    pub fn ts_event(&self) -> UnixNanos {
+        match self {
+            OrderEvent::OrderInitialized(event) => event.ts_event,
+            OrderEvent::OrderDenied(event) => event.ts_event,
+            OrderEvent::OrderSubmitted(event) => event.ts_event,
+            OrderEvent::OrderAccepted(event) => event.ts_event,
+            OrderEvent::OrderRejected(event) => event.ts_event,
+            OrderEvent::OrderCanceled(event) => event.ts_event,
+            OrderEvent::OrderExpired(event) => event.ts_event,
+            OrderEvent::OrderTriggered(event) => event.ts_event,
+            OrderEvent::OrderPendingUpdate(event) => event.ts_event,
+            OrderEvent::OrderPendingCancel(event) => event.ts_event,
+            OrderEvent::OrderModifyRejected(event) => event.ts_event,
+            OrderEvent::OrderCancelRejected(event) => event.ts_event,
+            OrderEvent::OrderUpdated(event) => event.ts_event,
+            OrderEvent::OrderPartiallyFilled(event) => event.ts_event,
+            OrderEvent::OrderFilled(event) => event.ts_event,
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            OrderEvent::OrderInitialized(event) => event.ts_init,
+            OrderEvent::OrderDenied(event) => event.ts_init,
+            OrderEvent::OrderSubmitted(event) => event.ts_init,
+            OrderEvent::OrderAccepted(event) => event.ts_init,
+            OrderEvent::OrderRejected(event) => event.ts_init,
+            OrderEvent::OrderCanceled(event) => event.ts_init,
+            OrderEvent::OrderExpired(event) => event.ts_init,
+            OrderEvent::OrderTriggered(event) => event.ts_init,
+            OrderEvent::OrderPendingUpdate(event) => event.ts_init,
+            OrderEvent::OrderPendingCancel(event) => event.ts_init,
+            OrderEvent::OrderModifyRejected(event) => event.ts_init,
+            OrderEvent::OrderCancelRejected(event) => event.ts_init,
+            OrderEvent::OrderUpdated(event) => event.ts_init,
+            OrderEvent::OrderPartiallyFilled(event) => event.ts_init,
+            OrderEvent::OrderFilled(event) => event.ts_init,
+        }
+    }
+}
+
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Builder, Serialize, Deserialize)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct OrderInitialized {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -69,13 +175,19 @@ pub struct OrderInitialized {
     pub display_qty: Option<Quantity>,
     pub limit_offset: Option<Price>,
     pub trailing_offset: Option<Price>,
-    pub trailing_offset_type: Option<TriggerType>,
+    pub trailing_offset_type: Option<TrailingOffsetType>,
     pub emulation_trigger: Option<TriggerType>,
     pub contingency_type: Option<ContingencyType>,
     pub order_list_id: Option<OrderListId>,
     pub linked_order_ids: Option<Vec<ClientOrderId>>,
     pub parent_order_id: Option<ClientOrderId>,
     pub tags: Option<String>,
+    /// The custom string fields of the order, such as venue specific instructions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub metadata: BTreeMap<String, String>,
     pub event_id: UUID4,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
@@ -110,6 +222,7 @@ impl Default for OrderInitialized {
             linked_order_ids: Default::default(),
             parent_order_id: Default::default(),
             tags: Default::default(),
+            metadata: Default::default(),
             event_id: Default::default(),
             ts_event: Default::default(),
             ts_init: Default::default(),
@@ -119,9 +232,11 @@ impl Default for OrderInitialized {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderDenied {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -134,9 +249,11 @@ pub struct OrderDenied {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderSubmitted {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -149,9 +266,11 @@ pub struct OrderSubmitted {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderAccepted {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -166,9 +285,11 @@ pub struct OrderAccepted {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderRejected {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -184,9 +305,11 @@ pub struct OrderRejected {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderCanceled {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -201,9 +324,11 @@ pub struct OrderCanceled {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderExpired {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -218,9 +343,11 @@ pub struct OrderExpired {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderTriggered {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -235,9 +362,11 @@ pub struct OrderTriggered {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderPendingUpdate {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -252,9 +381,11 @@ pub struct OrderPendingUpdate {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderPendingCancel {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -269,9 +400,11 @@ pub struct OrderPendingCancel {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderModifyRejected {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -287,9 +420,11 @@ pub struct OrderModifyRejected {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderCancelRejected {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -305,9 +440,11 @@ pub struct OrderCancelRejected {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Default, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[builder(default)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderUpdated {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -325,8 +462,10 @@ pub struct OrderUpdated {
 }
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, Debug, Serialize, Deserialize, Builder)]
-#[serde(tag = "type")]
+#[derive(Clone, Hash, PartialEq, Eq, Debug, Builder)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub struct OrderFilled {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -348,3 +487,287 @@ pub struct OrderFilled {
     pub ts_init: UnixNanos,
     pub reconciliation: bool,
 }
+
+impl CVecElement for OrderFilled {
+    const ELEMENT_TYPE: CVecElementType = CVecElementType::OrderFilled;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl OrderInitialized {
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+
+    #[getter]
+    #[pyo3(name = "trader_id")]
+    fn py_trader_id(&self) -> TraderId {
+        self.trader_id.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "strategy_id")]
+    fn py_strategy_id(&self) -> StrategyId {
+        self.strategy_id.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "client_order_id")]
+    fn py_client_order_id(&self) -> ClientOrderId {
+        self.client_order_id.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "order_side")]
+    fn py_order_side(&self) -> OrderSide {
+        self.order_side
+    }
+
+    #[getter]
+    #[pyo3(name = "order_type")]
+    fn py_order_type(&self) -> OrderType {
+        self.order_type
+    }
+
+    #[getter]
+    #[pyo3(name = "quantity")]
+    fn py_quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "price")]
+    fn py_price(&self) -> Option<Price> {
+        self.price
+    }
+
+    #[getter]
+    #[pyo3(name = "trigger_price")]
+    fn py_trigger_price(&self) -> Option<Price> {
+        self.trigger_price
+    }
+
+    #[getter]
+    #[pyo3(name = "trigger_type")]
+    fn py_trigger_type(&self) -> Option<TriggerType> {
+        self.trigger_type
+    }
+
+    #[getter]
+    #[pyo3(name = "time_in_force")]
+    fn py_time_in_force(&self) -> TimeInForce {
+        self.time_in_force
+    }
+
+    #[getter]
+    #[pyo3(name = "expire_time")]
+    fn py_expire_time(&self) -> Option<UnixNanos> {
+        self.expire_time
+    }
+
+    #[getter]
+    #[pyo3(name = "post_only")]
+    fn py_post_only(&self) -> bool {
+        self.post_only
+    }
+
+    #[getter]
+    #[pyo3(name = "reduce_only")]
+    fn py_reduce_only(&self) -> bool {
+        self.reduce_only
+    }
+
+    #[getter]
+    #[pyo3(name = "quote_quantity")]
+    fn py_quote_quantity(&self) -> bool {
+        self.quote_quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "display_qty")]
+    fn py_display_qty(&self) -> Option<Quantity> {
+        self.display_qty
+    }
+
+    #[getter]
+    #[pyo3(name = "limit_offset")]
+    fn py_limit_offset(&self) -> Option<Price> {
+        self.limit_offset
+    }
+
+    #[getter]
+    #[pyo3(name = "trailing_offset")]
+    fn py_trailing_offset(&self) -> Option<Price> {
+        self.trailing_offset
+    }
+
+    #[getter]
+    #[pyo3(name = "trailing_offset_type")]
+    fn py_trailing_offset_type(&self) -> Option<TrailingOffsetType> {
+        self.trailing_offset_type
+    }
+
+    #[getter]
+    #[pyo3(name = "emulation_trigger")]
+    fn py_emulation_trigger(&self) -> Option<TriggerType> {
+        self.emulation_trigger
+    }
+
+    #[getter]
+    #[pyo3(name = "contingency_type")]
+    fn py_contingency_type(&self) -> Option<ContingencyType> {
+        self.contingency_type
+    }
+
+    #[getter]
+    #[pyo3(name = "order_list_id")]
+    fn py_order_list_id(&self) -> Option<OrderListId> {
+        self.order_list_id.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "linked_order_ids")]
+    fn py_linked_order_ids(&self) -> Option<Vec<ClientOrderId>> {
+        self.linked_order_ids.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "parent_order_id")]
+    fn py_parent_order_id(&self) -> Option<ClientOrderId> {
+        self.parent_order_id.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "tags")]
+    fn py_tags(&self) -> Option<String> {
+        self.tags.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "metadata")]
+    fn py_metadata(&self) -> BTreeMap<String, String> {
+        self.metadata.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "event_id")]
+    fn py_event_id(&self) -> String {
+        self.event_id.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    #[getter]
+    #[pyo3(name = "reconciliation")]
+    fn py_reconciliation(&self) -> bool {
+        self.reconciliation
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::currencies::USD;
+
+    fn order_filled() -> OrderFilled {
+        OrderFilled {
+            trader_id: TraderId::default(),
+            strategy_id: StrategyId::default(),
+            instrument_id: InstrumentId::default(),
+            client_order_id: ClientOrderId::default(),
+            venue_order_id: VenueOrderId::default(),
+            account_id: AccountId::default(),
+            trade_id: TradeId::new("1"),
+            position_id: Some(PositionId::new("P-001")),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            last_qty: Quantity::from("100"),
+            last_px: Price::from("1.00010"),
+            currency: USD.clone(),
+            commission: Money::new(2.0, USD.clone()),
+            liquidity_side: LiquiditySide::Taker,
+            event_id: UUID4::default(),
+            ts_event: 1,
+            ts_init: 2,
+            reconciliation: false,
+        }
+    }
+
+    #[test]
+    fn test_order_initialized_json_round_trip() {
+        let event = OrderInitializedBuilder::default()
+            .price(Some(Price::from("1.00000")))
+            .tags(Some("ENTRY".to_string()))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&event).unwrap();
+        let deserialized: OrderInitialized = serde_json::from_str(&json).unwrap();
+
+        assert!(json.starts_with("{\"type\":\"OrderInitialized\""));
+        assert_eq!(deserialized, event);
+    }
+
+    #[test]
+    fn test_order_filled_msgpack_round_trip() {
+        let event = order_filled();
+
+        let bytes = rmp_serde::to_vec_named(&event).unwrap();
+        let deserialized: OrderFilled = rmp_serde::from_slice(&bytes).unwrap();
+
+        assert_eq!(deserialized, event);
+    }
+
+    #[test]
+    fn test_order_event_json_round_trip() {
+        let events = vec![
+            OrderEvent::OrderInitialized(OrderInitializedBuilder::default().build().unwrap()),
+            OrderEvent::OrderSubmitted(OrderSubmittedBuilder::default().build().unwrap()),
+            OrderEvent::OrderAccepted(OrderAcceptedBuilder::default().build().unwrap()),
+            OrderEvent::OrderFilled(order_filled()),
+        ];
+
+        let json = serde_json::to_string(&events).unwrap();
+        let deserialized: Vec<OrderEvent> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, events);
+    }
+
+    #[test]
+    fn test_order_event_msgpack_round_trip() {
+        let event = OrderEvent::OrderRejected(
+            OrderRejectedBuilder::default()
+                .reason("INSUFFICIENT_MARGIN".to_string())
+                .build()
+                .unwrap(),
+        );
+
+        let bytes = rmp_serde::to_vec_named(&event).unwrap();
+        let deserialized: OrderEvent = rmp_serde::from_slice(&bytes).unwrap();
+
+        assert_eq!(deserialized, event);
+        assert_eq!(deserialized.name(), "OrderRejected");
+    }
+}
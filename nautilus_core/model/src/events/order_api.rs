@@ -16,18 +16,23 @@
 use std::ffi::c_char;
 
 use nautilus_core::{
-    string::{cstr_to_string, str_to_cstr},
+    ffi_error::{try_cstr_to_str, write_result, FFI_ERROR},
+    string::str_to_cstr,
     time::UnixNanos,
     uuid::UUID4,
 };
 
-// use crate::types::price::Price;
-// use crate::types::quantity::Quantity;
-use super::order::OrderDenied;
-// use crate::enums::{OrderSide, OrderType, TimeInForce, TriggerType};
-use crate::identifiers::client_order_id::ClientOrderId;
-use crate::identifiers::{
-    instrument_id::InstrumentId, strategy_id::StrategyId, trader_id::TraderId,
+use super::order::{
+    OrderAccepted, OrderCanceled, OrderDenied, OrderFilled, OrderRejected, OrderSubmitted,
+};
+use crate::{
+    enums::{LiquiditySide, OrderSide, OrderType},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
+        venue_order_id::VenueOrderId,
+    },
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
 // #[no_mangle]
@@ -89,9 +94,16 @@ use crate::identifiers::{
 //     }
 // }
 
+/// Returns a new [`OrderDenied`] event, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
 /// # Safety
 ///
 /// - Assumes `reason_ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for an [`OrderDenied`].
+#[allow(clippy::too_many_arguments)]
 #[no_mangle]
 pub unsafe extern "C" fn order_denied_new(
     trader_id: TraderId,
@@ -102,17 +114,19 @@ pub unsafe extern "C" fn order_denied_new(
     event_id: UUID4,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-) -> OrderDenied {
-    OrderDenied {
+    out: *mut OrderDenied,
+) -> u8 {
+    let result = try_cstr_to_str(reason_ptr, "reason").map(|reason| OrderDenied {
         trader_id,
         strategy_id,
         instrument_id,
         client_order_id,
-        reason: Box::new(cstr_to_string(reason_ptr)),
+        reason: Box::new(reason.to_string()),
         event_id,
         ts_event,
         ts_init,
-    }
+    });
+    write_result(result, out, |_| FFI_ERROR)
 }
 
 /// Frees the memory for the given `event` by dropping.
@@ -130,3 +144,378 @@ pub extern "C" fn order_denied_clone(event: &OrderDenied) -> OrderDenied {
 pub extern "C" fn order_denied_reason_to_cstr(event: &OrderDenied) -> *const c_char {
     str_to_cstr(&event.reason)
 }
+
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn order_submitted_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    account_id: AccountId,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> OrderSubmitted {
+    OrderSubmitted {
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        account_id,
+        event_id,
+        ts_event,
+        ts_init,
+    }
+}
+
+/// Frees the memory for the given `event` by dropping.
+#[no_mangle]
+pub extern "C" fn order_submitted_drop(event: OrderSubmitted) {
+    drop(event); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_submitted_clone(event: &OrderSubmitted) -> OrderSubmitted {
+    event.clone()
+}
+
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn order_accepted_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: VenueOrderId,
+    account_id: AccountId,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderAccepted {
+    OrderAccepted {
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        venue_order_id,
+        account_id,
+        event_id,
+        ts_event,
+        ts_init,
+        reconciliation: reconciliation != 0,
+    }
+}
+
+/// Frees the memory for the given `event` by dropping.
+#[no_mangle]
+pub extern "C" fn order_accepted_drop(event: OrderAccepted) {
+    drop(event); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_accepted_clone(event: &OrderAccepted) -> OrderAccepted {
+    event.clone()
+}
+
+/// Returns a new [`OrderRejected`] event, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `reason_ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for an [`OrderRejected`].
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn order_rejected_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: VenueOrderId,
+    account_id: AccountId,
+    reason_ptr: *const c_char,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+    out: *mut OrderRejected,
+) -> u8 {
+    let result = try_cstr_to_str(reason_ptr, "reason").map(|reason| OrderRejected {
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        venue_order_id,
+        account_id,
+        reason: reason.to_string(),
+        event_id,
+        ts_event,
+        ts_init,
+        reconciliation: reconciliation != 0,
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Frees the memory for the given `event` by dropping.
+#[no_mangle]
+pub extern "C" fn order_rejected_drop(event: OrderRejected) {
+    drop(event); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_rejected_clone(event: &OrderRejected) -> OrderRejected {
+    event.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn order_rejected_reason_to_cstr(event: &OrderRejected) -> *const c_char {
+    str_to_cstr(&event.reason)
+}
+
+/// # Safety
+///
+/// - Assumes `venue_order_id` and `account_id` are either null or valid pointers.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn order_canceled_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: *const VenueOrderId,
+    account_id: *const AccountId,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderCanceled {
+    OrderCanceled {
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        venue_order_id: venue_order_id.as_ref().cloned(),
+        account_id: account_id.as_ref().cloned(),
+        event_id,
+        ts_event,
+        ts_init,
+        reconciliation: reconciliation != 0,
+    }
+}
+
+/// Frees the memory for the given `event` by dropping.
+#[no_mangle]
+pub extern "C" fn order_canceled_drop(event: OrderCanceled) {
+    drop(event); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_canceled_clone(event: &OrderCanceled) -> OrderCanceled {
+    event.clone()
+}
+
+/// # Safety
+///
+/// - Assumes `position_id` is either null or a valid pointer.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn order_filled_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: VenueOrderId,
+    account_id: AccountId,
+    trade_id: TradeId,
+    position_id: *const PositionId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    last_qty: Quantity,
+    last_px: Price,
+    currency: Currency,
+    commission: Money,
+    liquidity_side: LiquiditySide,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderFilled {
+    OrderFilled {
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        venue_order_id,
+        account_id,
+        trade_id,
+        position_id: position_id.as_ref().cloned(),
+        order_side,
+        order_type,
+        last_qty,
+        last_px,
+        currency,
+        commission,
+        liquidity_side,
+        event_id,
+        ts_event,
+        ts_init,
+        reconciliation: reconciliation != 0,
+    }
+}
+
+/// Frees the memory for the given `event` by dropping.
+#[no_mangle]
+pub extern "C" fn order_filled_drop(event: OrderFilled) {
+    drop(event); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_clone(event: &OrderFilled) -> OrderFilled {
+    event.clone()
+}
+
+/// Returns the [`PositionId`] for the fill, or a null pointer if there is none.
+///
+/// The returned pointer borrows from `event`, and is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_filled_position_id(event: &OrderFilled) -> *const PositionId {
+    match &event.position_id {
+        Some(position_id) => position_id,
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_is_buy(event: &OrderFilled) -> u8 {
+    u8::from(event.order_side == OrderSide::Buy)
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_is_sell(event: &OrderFilled) -> u8 {
+    u8::from(event.order_side == OrderSide::Sell)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, mem::MaybeUninit};
+
+    use nautilus_core::ffi_error::last_error;
+
+    use super::*;
+    use crate::currencies::USD;
+
+    #[test]
+    fn test_order_canceled_new_with_null_ids() {
+        let event = unsafe {
+            order_canceled_new(
+                TraderId::default(),
+                StrategyId::default(),
+                InstrumentId::default(),
+                ClientOrderId::default(),
+                std::ptr::null(),
+                std::ptr::null(),
+                UUID4::default(),
+                1,
+                2,
+                0,
+            )
+        };
+
+        assert_eq!(event.venue_order_id, None);
+        assert_eq!(event.account_id, None);
+        assert!(!event.reconciliation);
+        order_canceled_drop(event);
+    }
+
+    #[test]
+    fn test_order_rejected_new() {
+        let reason = CString::new("INSUFFICIENT_MARGIN").unwrap();
+        let mut event = MaybeUninit::<OrderRejected>::uninit();
+        let status = unsafe {
+            order_rejected_new(
+                TraderId::default(),
+                StrategyId::default(),
+                InstrumentId::default(),
+                ClientOrderId::default(),
+                VenueOrderId::default(),
+                AccountId::default(),
+                reason.as_ptr(),
+                UUID4::default(),
+                1,
+                2,
+                1,
+                event.as_mut_ptr(),
+            )
+        };
+
+        assert_eq!(status, 0);
+        let event = unsafe { event.assume_init() };
+        assert_eq!(event.reason, "INSUFFICIENT_MARGIN");
+        assert!(event.reconciliation);
+    }
+
+    #[test]
+    fn test_order_denied_new_with_null_reason() {
+        let mut event = MaybeUninit::<OrderDenied>::uninit();
+        let status = unsafe {
+            order_denied_new(
+                TraderId::default(),
+                StrategyId::default(),
+                InstrumentId::default(),
+                ClientOrderId::default(),
+                std::ptr::null(),
+                UUID4::default(),
+                1,
+                2,
+                event.as_mut_ptr(),
+            )
+        };
+
+        assert_eq!(status, FFI_ERROR);
+        assert_eq!(
+            last_error(),
+            Some("invalid reason string, `ptr` was NULL".to_string())
+        );
+    }
+
+    #[test]
+    fn test_order_filled_new() {
+        let position_id = PositionId::new("P-001");
+        let event = unsafe {
+            order_filled_new(
+                TraderId::default(),
+                StrategyId::default(),
+                InstrumentId::default(),
+                ClientOrderId::default(),
+                VenueOrderId::default(),
+                AccountId::default(),
+                TradeId::new("1"),
+                &position_id,
+                OrderSide::Sell,
+                OrderType::Market,
+                Quantity::from("100"),
+                Price::from("1.00000"),
+                USD.clone(),
+                Money::new(1.0, USD.clone()),
+                LiquiditySide::Taker,
+                UUID4::default(),
+                1,
+                2,
+                0,
+            )
+        };
+
+        assert_eq!(event.position_id, Some(position_id.clone()));
+        assert_eq!(unsafe { &*order_filled_position_id(&event) }, &position_id);
+        assert_eq!(order_filled_is_sell(&event), 1);
+        assert_eq!(order_filled_is_buy(&event), 0);
+        order_filled_drop(event);
+    }
+}
@@ -14,16 +14,25 @@
 // -------------------------------------------------------------------------------------------------
 
 use nautilus_core::time::{TimedeltaNanos, UnixNanos};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::{
     enums::{OrderSide, PositionSide},
+    events::order::OrderFilled,
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         position_id::PositionId, strategy_id::StrategyId, trader_id::TraderId,
     },
+    position::Position,
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub enum PositionEvent {
     PositionOpened(PositionOpened),
     PositionChanged(PositionChanged),
@@ -32,6 +41,8 @@ pub enum PositionEvent {
 
 #[repr(C)]
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct PositionOpened {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -53,6 +64,8 @@ pub struct PositionOpened {
 
 #[repr(C)]
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct PositionChanged {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -69,7 +82,7 @@ pub struct PositionChanged {
     pub last_px: Price,
     pub currency: Currency,
     pub avg_px_open: f64,
-    pub avg_px_closed: f64,
+    pub avg_px_close: Option<f64>,
     pub realized_return: f64,
     pub realized_pnl: Money,
     pub unrealized_pnl: Money,
@@ -80,6 +93,8 @@ pub struct PositionChanged {
 
 #[repr(C)]
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct PositionClosed {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -97,7 +112,7 @@ pub struct PositionClosed {
     pub last_px: Price,
     pub currency: Currency,
     pub avg_px_open: f64,
-    pub avg_px_closed: f64,
+    pub avg_px_close: Option<f64>,
     pub realized_return: f64,
     pub realized_pnl: Money,
     pub unrealized_pnl: Money,
@@ -107,3 +122,97 @@ pub struct PositionClosed {
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
 }
+
+impl PositionOpened {
+    pub fn create(position: &Position, fill: &OrderFilled, ts_init: UnixNanos) -> Self {
+        Self {
+            trader_id: position.trader_id.clone(),
+            strategy_id: position.strategy_id.clone(),
+            instrument_id: position.instrument_id.clone(),
+            position_id: position.id.clone(),
+            account_id: position.account_id.clone(),
+            opening_order_id: position.opening_order_id.clone(),
+            entry: position.entry,
+            side: position.side,
+            signed_qty: position.signed_qty,
+            quantity: position.quantity,
+            last_qty: fill.last_qty,
+            last_px: fill.last_px,
+            currency: position.quote_currency.clone(),
+            avg_px_open: position.avg_px_open,
+            ts_event: fill.ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl PositionChanged {
+    pub fn create(position: &Position, fill: &OrderFilled, ts_init: UnixNanos) -> Self {
+        Self {
+            trader_id: position.trader_id.clone(),
+            strategy_id: position.strategy_id.clone(),
+            instrument_id: position.instrument_id.clone(),
+            position_id: position.id.clone(),
+            account_id: position.account_id.clone(),
+            opening_order_id: position.opening_order_id.clone(),
+            entry: position.entry,
+            side: position.side,
+            signed_qty: position.signed_qty,
+            quantity: position.quantity,
+            peak_quantity: position.peak_qty,
+            last_qty: fill.last_qty,
+            last_px: fill.last_px,
+            currency: position.quote_currency.clone(),
+            avg_px_open: position.avg_px_open,
+            avg_px_close: position.avg_px_close,
+            realized_return: position.realized_return.unwrap_or(0.0),
+            realized_pnl: realized_pnl(position),
+            unrealized_pnl: position.unrealized_pnl(fill.last_px),
+            ts_opened: position.ts_opened,
+            ts_event: fill.ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl PositionClosed {
+    pub fn create(position: &Position, fill: &OrderFilled, ts_init: UnixNanos) -> Self {
+        Self {
+            trader_id: position.trader_id.clone(),
+            strategy_id: position.strategy_id.clone(),
+            instrument_id: position.instrument_id.clone(),
+            position_id: position.id.clone(),
+            account_id: position.account_id.clone(),
+            opening_order_id: position.opening_order_id.clone(),
+            closing_order_id: position
+                .closing_order_id
+                .clone()
+                .expect("closed position without `closing_order_id`"),
+            entry: position.entry,
+            side: position.side,
+            signed_qty: position.signed_qty,
+            quantity: position.quantity,
+            peak_quantity: position.peak_qty,
+            last_qty: fill.last_qty,
+            last_px: fill.last_px,
+            currency: position.quote_currency.clone(),
+            avg_px_open: position.avg_px_open,
+            avg_px_close: position.avg_px_close,
+            realized_return: position.realized_return.unwrap_or(0.0),
+            realized_pnl: realized_pnl(position),
+            unrealized_pnl: position.unrealized_pnl(fill.last_px),
+            duration: position.duration_ns.unwrap_or(0) as TimedeltaNanos,
+            ts_opened: position.ts_opened,
+            ts_closed: position.ts_closed.unwrap_or(fill.ts_event),
+            ts_event: fill.ts_event,
+            ts_init,
+        }
+    }
+}
+
+fn realized_pnl(position: &Position) -> Money {
+    position
+        .realized_pnl
+        .clone()
+        .unwrap_or_else(|| Money::new(0.0, position.cost_currency.clone()))
+}
@@ -0,0 +1,209 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::time::UnixNanos;
+
+use super::get_datetime_tag;
+use crate::identifiers::{
+    client_order_id::ClientOrderId, strategy_id::StrategyId, trader_id::TraderId,
+};
+
+/// Provides a generator for unique [`ClientOrderId`]s.
+///
+/// Generated IDs take the form `O-{YYYYMMDD-HHMM}-{trader_tag}-{strategy_tag}-{count}`,
+/// matching the Python `ClientOrderIdGenerator`.
+#[derive(Clone, Debug)]
+pub struct ClientOrderIdGenerator {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    count: usize,
+}
+
+impl ClientOrderIdGenerator {
+    #[must_use]
+    pub fn new(trader_id: TraderId, strategy_id: StrategyId, initial_count: usize) -> Self {
+        Self {
+            trader_id,
+            strategy_id,
+            count: initial_count,
+        }
+    }
+
+    /// Sets the internal counter to the given `count` (used when restoring state).
+    pub fn set_count(&mut self, count: usize) {
+        self.count = count;
+    }
+
+    /// Resets the internal counter to zero.
+    pub fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns a unique client order ID for the given current timestamp `ts_now`.
+    pub fn generate(&mut self, ts_now: UnixNanos) -> ClientOrderId {
+        self.count += 1;
+        let value = format!(
+            "O-{}-{}-{}-{}",
+            get_datetime_tag(ts_now),
+            self.trader_id.get_tag(),
+            self.strategy_id.get_tag(),
+            self.count,
+        );
+        ClientOrderId::new(&value)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`ClientOrderIdGenerator`].
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct ClientOrderIdGenerator_API(Box<ClientOrderIdGenerator>);
+
+impl Deref for ClientOrderIdGenerator_API {
+    type Target = ClientOrderIdGenerator;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ClientOrderIdGenerator_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn client_order_id_generator_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    initial_count: usize,
+) -> ClientOrderIdGenerator_API {
+    ClientOrderIdGenerator_API(Box::new(ClientOrderIdGenerator::new(
+        trader_id,
+        strategy_id,
+        initial_count,
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn client_order_id_generator_drop(generator: ClientOrderIdGenerator_API) {
+    drop(generator); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn client_order_id_generator_count(generator: &ClientOrderIdGenerator_API) -> usize {
+    generator.count()
+}
+
+#[no_mangle]
+pub extern "C" fn client_order_id_generator_set_count(
+    generator: &mut ClientOrderIdGenerator_API,
+    count: usize,
+) {
+    generator.set_count(count);
+}
+
+#[no_mangle]
+pub extern "C" fn client_order_id_generator_reset(generator: &mut ClientOrderIdGenerator_API) {
+    generator.reset();
+}
+
+#[no_mangle]
+pub extern "C" fn client_order_id_generator_generate(
+    generator: &mut ClientOrderIdGenerator_API,
+    ts_now: UnixNanos,
+) -> ClientOrderId {
+    generator.generate(ts_now)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const TS_NOW: UnixNanos = 1_672_531_200_000_000_000; // 2023-01-01T00:00:00Z
+
+    #[fixture]
+    fn generator() -> ClientOrderIdGenerator {
+        ClientOrderIdGenerator::new(TraderId::new("TRADER-001"), StrategyId::new("S-001"), 0)
+    }
+
+    #[rstest]
+    fn test_initial_count(generator: ClientOrderIdGenerator) {
+        assert_eq!(generator.count(), 0);
+    }
+
+    #[rstest]
+    fn test_generate_ids(mut generator: ClientOrderIdGenerator) {
+        let id1 = generator.generate(TS_NOW);
+        let id2 = generator.generate(TS_NOW);
+        let id3 = generator.generate(TS_NOW);
+
+        assert_eq!(id1, ClientOrderId::new("O-20230101-0000-001-001-1"));
+        assert_eq!(id2, ClientOrderId::new("O-20230101-0000-001-001-2"));
+        assert_eq!(id3, ClientOrderId::new("O-20230101-0000-001-001-3"));
+    }
+
+    #[rstest]
+    fn test_generate_ids_with_initial_count() {
+        let mut generator =
+            ClientOrderIdGenerator::new(TraderId::new("TRADER-001"), StrategyId::new("S-001"), 5);
+        let id = generator.generate(TS_NOW);
+
+        assert_eq!(id, ClientOrderId::new("O-20230101-0000-001-001-6"));
+    }
+
+    #[rstest]
+    fn test_set_count_and_reset(mut generator: ClientOrderIdGenerator) {
+        generator.set_count(10);
+        assert_eq!(
+            generator.generate(TS_NOW).to_string(),
+            "O-20230101-0000-001-001-11"
+        );
+
+        generator.reset();
+        assert_eq!(generator.count(), 0);
+        assert_eq!(
+            generator.generate(TS_NOW).to_string(),
+            "O-20230101-0000-001-001-1"
+        );
+    }
+
+    #[rstest]
+    fn test_c_api_generate() {
+        let mut generator =
+            client_order_id_generator_new(TraderId::new("TRADER-001"), StrategyId::new("S-001"), 0);
+        let id = client_order_id_generator_generate(&mut generator, TS_NOW);
+
+        assert_eq!(id.to_string(), "O-20230101-0000-001-001-1");
+        assert_eq!(client_order_id_generator_count(&generator), 1);
+        client_order_id_generator_drop(generator);
+    }
+}
@@ -0,0 +1,58 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Generators for unique system identifiers.
+
+pub mod client_order_id;
+pub mod order_list_id;
+pub mod position_id;
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use nautilus_core::time::UnixNanos;
+
+/// Returns the datetime tag string (UTC) for the given UNIX timestamp, in the
+/// format `YYYYMMDD-HHMM`.
+#[must_use]
+pub fn get_datetime_tag(ts: UnixNanos) -> String {
+    let dt = DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_nanos(ts));
+    format!(
+        "{}{:02}{:02}-{:02}{:02}",
+        dt.year(),
+        dt.month(),
+        dt.day(),
+        dt.hour(),
+        dt.minute(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::get_datetime_tag;
+
+    #[rstest]
+    #[case(0, "19700101-0000")]
+    #[case(1_672_531_200_000_000_000, "20230101-0000")]
+    #[case(1_688_137_199_999_999_999, "20230630-1459")]
+    fn test_get_datetime_tag(#[case] ts: u64, #[case] expected: &str) {
+        assert_eq!(get_datetime_tag(ts), expected);
+    }
+}
@@ -0,0 +1,209 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::time::UnixNanos;
+
+use super::get_datetime_tag;
+use crate::identifiers::{
+    order_list_id::OrderListId, strategy_id::StrategyId, trader_id::TraderId,
+};
+
+/// Provides a generator for unique [`OrderListId`]s.
+///
+/// Generated IDs take the form `OL-{YYYYMMDD-HHMM}-{trader_tag}-{strategy_tag}-{count}`,
+/// matching the Python `OrderListIdGenerator`.
+#[derive(Clone, Debug)]
+pub struct OrderListIdGenerator {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    count: usize,
+}
+
+impl OrderListIdGenerator {
+    #[must_use]
+    pub fn new(trader_id: TraderId, strategy_id: StrategyId, initial_count: usize) -> Self {
+        Self {
+            trader_id,
+            strategy_id,
+            count: initial_count,
+        }
+    }
+
+    /// Sets the internal counter to the given `count` (used when restoring state).
+    pub fn set_count(&mut self, count: usize) {
+        self.count = count;
+    }
+
+    /// Resets the internal counter to zero.
+    pub fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns a unique order list ID for the given current timestamp `ts_now`.
+    pub fn generate(&mut self, ts_now: UnixNanos) -> OrderListId {
+        self.count += 1;
+        let value = format!(
+            "OL-{}-{}-{}-{}",
+            get_datetime_tag(ts_now),
+            self.trader_id.get_tag(),
+            self.strategy_id.get_tag(),
+            self.count,
+        );
+        OrderListId::new(&value)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`OrderListIdGenerator`].
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct OrderListIdGenerator_API(Box<OrderListIdGenerator>);
+
+impl Deref for OrderListIdGenerator_API {
+    type Target = OrderListIdGenerator;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for OrderListIdGenerator_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn order_list_id_generator_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    initial_count: usize,
+) -> OrderListIdGenerator_API {
+    OrderListIdGenerator_API(Box::new(OrderListIdGenerator::new(
+        trader_id,
+        strategy_id,
+        initial_count,
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn order_list_id_generator_drop(generator: OrderListIdGenerator_API) {
+    drop(generator); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_list_id_generator_count(generator: &OrderListIdGenerator_API) -> usize {
+    generator.count()
+}
+
+#[no_mangle]
+pub extern "C" fn order_list_id_generator_set_count(
+    generator: &mut OrderListIdGenerator_API,
+    count: usize,
+) {
+    generator.set_count(count);
+}
+
+#[no_mangle]
+pub extern "C" fn order_list_id_generator_reset(generator: &mut OrderListIdGenerator_API) {
+    generator.reset();
+}
+
+#[no_mangle]
+pub extern "C" fn order_list_id_generator_generate(
+    generator: &mut OrderListIdGenerator_API,
+    ts_now: UnixNanos,
+) -> OrderListId {
+    generator.generate(ts_now)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const TS_NOW: UnixNanos = 1_672_531_200_000_000_000; // 2023-01-01T00:00:00Z
+
+    #[fixture]
+    fn generator() -> OrderListIdGenerator {
+        OrderListIdGenerator::new(TraderId::new("TRADER-001"), StrategyId::new("S-001"), 0)
+    }
+
+    #[rstest]
+    fn test_initial_count(generator: OrderListIdGenerator) {
+        assert_eq!(generator.count(), 0);
+    }
+
+    #[rstest]
+    fn test_generate_ids(mut generator: OrderListIdGenerator) {
+        let id1 = generator.generate(TS_NOW);
+        let id2 = generator.generate(TS_NOW);
+        let id3 = generator.generate(TS_NOW);
+
+        assert_eq!(id1, OrderListId::new("OL-20230101-0000-001-001-1"));
+        assert_eq!(id2, OrderListId::new("OL-20230101-0000-001-001-2"));
+        assert_eq!(id3, OrderListId::new("OL-20230101-0000-001-001-3"));
+    }
+
+    #[rstest]
+    fn test_generate_ids_with_initial_count() {
+        let mut generator =
+            OrderListIdGenerator::new(TraderId::new("TRADER-001"), StrategyId::new("S-001"), 5);
+        let id = generator.generate(TS_NOW);
+
+        assert_eq!(id, OrderListId::new("OL-20230101-0000-001-001-6"));
+    }
+
+    #[rstest]
+    fn test_set_count_and_reset(mut generator: OrderListIdGenerator) {
+        generator.set_count(10);
+        assert_eq!(
+            generator.generate(TS_NOW).to_string(),
+            "OL-20230101-0000-001-001-11"
+        );
+
+        generator.reset();
+        assert_eq!(generator.count(), 0);
+        assert_eq!(
+            generator.generate(TS_NOW).to_string(),
+            "OL-20230101-0000-001-001-1"
+        );
+    }
+
+    #[rstest]
+    fn test_c_api_generate() {
+        let mut generator =
+            order_list_id_generator_new(TraderId::new("TRADER-001"), StrategyId::new("S-001"), 0);
+        let id = order_list_id_generator_generate(&mut generator, TS_NOW);
+
+        assert_eq!(id.to_string(), "OL-20230101-0000-001-001-1");
+        assert_eq!(order_list_id_generator_count(&generator), 1);
+        order_list_id_generator_drop(generator);
+    }
+}
@@ -0,0 +1,226 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::time::UnixNanos;
+
+use super::get_datetime_tag;
+use crate::identifiers::{position_id::PositionId, strategy_id::StrategyId, trader_id::TraderId};
+
+/// Provides a generator for unique [`PositionId`]s, with a separate counter per strategy.
+///
+/// Generated IDs take the form `P-{YYYYMMDD-HHMM}-{trader_tag}-{strategy_tag}-{count}`,
+/// with an `F` suffix for positions opened by a flip, matching the Python
+/// `PositionIdGenerator`.
+#[derive(Clone, Debug)]
+pub struct PositionIdGenerator {
+    trader_id: TraderId,
+    counts: HashMap<StrategyId, usize>,
+}
+
+impl PositionIdGenerator {
+    #[must_use]
+    pub fn new(trader_id: TraderId) -> Self {
+        Self {
+            trader_id,
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Sets the internal counter for the given `strategy_id` to `count` (used when
+    /// restoring state from the cache).
+    pub fn set_count(&mut self, strategy_id: StrategyId, count: usize) {
+        self.counts.insert(strategy_id, count);
+    }
+
+    /// Resets the internal counters for all strategies.
+    pub fn reset(&mut self) {
+        self.counts.clear();
+    }
+
+    /// Returns the current count for the given `strategy_id` (zero if none generated).
+    #[must_use]
+    pub fn count(&self, strategy_id: &StrategyId) -> usize {
+        self.counts.get(strategy_id).copied().unwrap_or(0)
+    }
+
+    /// Returns a unique position ID for the given `strategy_id` and current timestamp
+    /// `ts_now`, where `flipped` indicates the position was opened by a flip.
+    pub fn generate(
+        &mut self,
+        strategy_id: &StrategyId,
+        flipped: bool,
+        ts_now: UnixNanos,
+    ) -> PositionId {
+        let count = self.counts.entry(strategy_id.clone()).or_insert(0);
+        *count += 1;
+        let value = format!(
+            "P-{}-{}-{}-{}{}",
+            get_datetime_tag(ts_now),
+            self.trader_id.get_tag(),
+            strategy_id.get_tag(),
+            count,
+            if flipped { "F" } else { "" },
+        );
+        PositionId::new(&value)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`PositionIdGenerator`].
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct PositionIdGenerator_API(Box<PositionIdGenerator>);
+
+impl Deref for PositionIdGenerator_API {
+    type Target = PositionIdGenerator;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for PositionIdGenerator_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn position_id_generator_new(trader_id: TraderId) -> PositionIdGenerator_API {
+    PositionIdGenerator_API(Box::new(PositionIdGenerator::new(trader_id)))
+}
+
+#[no_mangle]
+pub extern "C" fn position_id_generator_drop(generator: PositionIdGenerator_API) {
+    drop(generator); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn position_id_generator_count(
+    generator: &PositionIdGenerator_API,
+    strategy_id: StrategyId,
+) -> usize {
+    generator.count(&strategy_id)
+}
+
+#[no_mangle]
+pub extern "C" fn position_id_generator_set_count(
+    generator: &mut PositionIdGenerator_API,
+    strategy_id: StrategyId,
+    count: usize,
+) {
+    generator.set_count(strategy_id, count);
+}
+
+#[no_mangle]
+pub extern "C" fn position_id_generator_reset(generator: &mut PositionIdGenerator_API) {
+    generator.reset();
+}
+
+#[no_mangle]
+pub extern "C" fn position_id_generator_generate(
+    generator: &mut PositionIdGenerator_API,
+    strategy_id: StrategyId,
+    flipped: u8,
+    ts_now: UnixNanos,
+) -> PositionId {
+    generator.generate(&strategy_id, flipped != 0, ts_now)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const TS_NOW: UnixNanos = 1_672_531_200_000_000_000; // 2023-01-01T00:00:00Z
+
+    #[fixture]
+    fn generator() -> PositionIdGenerator {
+        PositionIdGenerator::new(TraderId::new("TRADER-001"))
+    }
+
+    #[rstest]
+    fn test_initial_count(generator: PositionIdGenerator) {
+        assert_eq!(generator.count(&StrategyId::new("S-001")), 0);
+    }
+
+    #[rstest]
+    fn test_generate_ids(mut generator: PositionIdGenerator) {
+        let strategy_id = StrategyId::new("S-001");
+        let id1 = generator.generate(&strategy_id, false, TS_NOW);
+        let id2 = generator.generate(&strategy_id, false, TS_NOW);
+        let id3 = generator.generate(&strategy_id, true, TS_NOW);
+
+        assert_eq!(id1, PositionId::new("P-20230101-0000-001-001-1"));
+        assert_eq!(id2, PositionId::new("P-20230101-0000-001-001-2"));
+        assert_eq!(id3, PositionId::new("P-20230101-0000-001-001-3F"));
+    }
+
+    #[rstest]
+    fn test_counts_are_per_strategy(mut generator: PositionIdGenerator) {
+        let strategy1 = StrategyId::new("S-001");
+        let strategy2 = StrategyId::new("S-002");
+        generator.generate(&strategy1, false, TS_NOW);
+        generator.generate(&strategy1, false, TS_NOW);
+        let id = generator.generate(&strategy2, false, TS_NOW);
+
+        assert_eq!(id, PositionId::new("P-20230101-0000-001-002-1"));
+        assert_eq!(generator.count(&strategy1), 2);
+        assert_eq!(generator.count(&strategy2), 1);
+    }
+
+    #[rstest]
+    fn test_set_count_and_reset(mut generator: PositionIdGenerator) {
+        let strategy_id = StrategyId::new("S-001");
+        generator.set_count(strategy_id.clone(), 5);
+        assert_eq!(
+            generator.generate(&strategy_id, false, TS_NOW).to_string(),
+            "P-20230101-0000-001-001-6"
+        );
+
+        generator.reset();
+        assert_eq!(generator.count(&strategy_id), 0);
+        assert_eq!(
+            generator.generate(&strategy_id, false, TS_NOW).to_string(),
+            "P-20230101-0000-001-001-1"
+        );
+    }
+
+    #[rstest]
+    fn test_c_api_generate() {
+        let mut generator = position_id_generator_new(TraderId::new("TRADER-001"));
+        let id =
+            position_id_generator_generate(&mut generator, StrategyId::new("S-001"), 1, TS_NOW);
+
+        assert_eq!(id.to_string(), "P-20230101-0000-001-001-1F");
+        assert_eq!(
+            position_id_generator_count(&generator, StrategyId::new("S-001")),
+            1
+        );
+        position_id_generator_drop(generator);
+    }
+}
@@ -13,20 +13,32 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
 use std::{
     collections::hash_map::DefaultHasher,
     ffi::{c_char, CStr},
-    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
+};
+use std::{
+    fmt::{Debug, Display, Formatter},
     sync::Arc,
 };
 
-use nautilus_core::{correctness, string::str_to_cstr};
+use nautilus_core::correctness::FAILED;
+#[cfg(feature = "ffi")]
+use nautilus_core::string::str_to_cstr;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ffi")]
+use crate::identifiers::write_identifier_result;
+use crate::identifiers::{
+    check_string_contains, check_valid_string, interner::intern, IdentifierError,
+};
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct AccountId {
     pub value: Box<Arc<String>>,
 }
@@ -44,14 +56,24 @@ impl Display for AccountId {
 }
 
 impl AccountId {
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`AccountId` value")?;
+        check_string_contains(s, "-", "`AccountId` value")?;
+
+        Ok(Self {
+            value: Box::new(intern(s)),
+        })
+    }
+
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`AccountId` value");
-        correctness::string_contains(s, "-", "`TraderId` value");
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
+    }
 
-        Self {
-            value: Box::new(Arc::new(s.to_string())),
-        }
+    /// Returns the account issuer for this ID (the part preceding the first hyphen).
+    #[must_use]
+    pub fn get_issuer(&self) -> &str {
+        self.value.split('-').next().unwrap_or_default()
     }
 }
 
@@ -65,39 +87,49 @@ impl Default for AccountId {
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from a C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`AccountId`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn account_id_new(ptr: *const c_char) -> AccountId {
-    AccountId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
+pub unsafe extern "C" fn account_id_try_new(ptr: *const c_char, out: *mut AccountId) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(AccountId::try_new(&s), out)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn account_id_clone(account_id: &AccountId) -> AccountId {
     account_id.clone()
 }
 
 /// Frees the memory for the given `account_id` by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn account_id_drop(account_id: AccountId) {
     drop(account_id); // Memory freed here
 }
 
 /// Returns an [`AccountId`] as a C string pointer.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn account_id_to_cstr(account_id: &AccountId) -> *const c_char {
     str_to_cstr(&account_id.value)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn account_id_eq(lhs: &AccountId, rhs: &AccountId) -> u8 {
     u8::from(lhs == rhs)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn account_id_hash(account_id: &AccountId) -> u64 {
     let mut h = DefaultHasher::new();
@@ -110,10 +142,18 @@ pub extern "C" fn account_id_hash(account_id: &AccountId) -> u64 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::ffi::CString;
+    #[cfg(feature = "ffi")]
+    use std::{ffi::CString, mem::MaybeUninit};
 
     use super::*;
 
+    #[cfg(feature = "ffi")]
+    fn account_id_new_c(ptr: *const c_char) -> AccountId {
+        let mut out = MaybeUninit::<AccountId>::uninit();
+        assert_eq!(unsafe { account_id_try_new(ptr, out.as_mut_ptr()) }, 0);
+        unsafe { out.assume_init() }
+    }
+
     #[test]
     fn test_account_id_new_invalid_string() {
         let s = "";
@@ -128,6 +168,12 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_account_id_get_issuer() {
+        let id = AccountId::new("IB-U123456789");
+        assert_eq!(id.get_issuer(), "IB");
+    }
+
     #[test]
     fn test_account_id_new() {
         let s = "IB-U123456789";
@@ -157,77 +203,83 @@ mod tests {
         assert_eq!(id.to_string(), "IB-1234567890");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_account_id_drop_c() {
         let id = AccountId::new("IB-1234567890");
         account_id_drop(id); // No panic
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_account_id_new_c() {
         let s = "IB-U123456789";
         let c_string = CString::new(s).unwrap();
         let ptr = c_string.as_ptr();
-        let account_id = unsafe { account_id_new(ptr) };
+        let account_id = account_id_new_c(ptr);
         assert_eq!(account_id.value.as_ref().as_str(), s);
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_account_id_clone_c() {
         let s = "IB-U123456789";
         let c_string = CString::new(s).unwrap();
         let ptr = c_string.as_ptr();
-        let account_id = unsafe { account_id_new(ptr) };
+        let account_id = account_id_new_c(ptr);
         let cloned_account_id = account_id_clone(&account_id);
         assert_eq!(cloned_account_id.value.as_ref().as_str(), s);
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_account_id_to_cstr_c() {
         let s = "IB-U123456789";
         let c_string = CString::new(s).unwrap();
         let ptr = c_string.as_ptr();
-        let account_id = unsafe { account_id_new(ptr) };
+        let account_id = account_id_new_c(ptr);
         let cstr_ptr = account_id_to_cstr(&account_id);
         let c_str = unsafe { CStr::from_ptr(cstr_ptr) };
         assert_eq!(c_str.to_str().unwrap(), s);
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_account_id_eq_c() {
         let s1 = "IB-U123456789";
         let c_string1 = CString::new(s1).unwrap();
         let ptr1 = c_string1.as_ptr();
-        let account_id1 = unsafe { account_id_new(ptr1) };
+        let account_id1 = account_id_new_c(ptr1);
 
         let s2 = "IB-U123456789";
         let c_string2 = CString::new(s2).unwrap();
         let ptr2 = c_string2.as_ptr();
-        let account_id2 = unsafe { account_id_new(ptr2) };
+        let account_id2 = account_id_new_c(ptr2);
 
         let result1 = account_id_eq(&account_id1, &account_id2);
 
         let s3 = "IB-U993456789";
         let c_string3 = CString::new(s3).unwrap();
         let ptr3 = c_string3.as_ptr();
-        let account_id3 = unsafe { account_id_new(ptr3) };
+        let account_id3 = account_id_new_c(ptr3);
 
         let result2 = account_id_eq(&account_id1, &account_id3);
         assert_eq!(result1, 1);
         assert_eq!(result2, 0);
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_account_id_hash_c() {
         let s1 = "IB-U123456789";
         let c_string1 = CString::new(s1).unwrap();
         let ptr1 = c_string1.as_ptr();
-        let account_id1 = unsafe { account_id_new(ptr1) };
+        let account_id1 = account_id_new_c(ptr1);
 
         let s2 = "IB-U123456789";
         let c_string2 = CString::new(s2).unwrap();
         let ptr2 = c_string2.as_ptr();
-        let account_id2 = unsafe { account_id_new(ptr2) };
+        let account_id2 = account_id_new_c(ptr2);
 
         let hash1 = account_id_hash(&account_id1);
         let hash2 = account_id_hash(&account_id2);
@@ -235,7 +287,7 @@ mod tests {
         let s3 = "IB-U987456789";
         let c_string3 = CString::new(s3).unwrap();
         let ptr3 = c_string3.as_ptr();
-        let account_id3 = unsafe { account_id_new(ptr3) };
+        let account_id3 = account_id_new_c(ptr3);
 
         let hash3 = account_id_hash(&account_id3);
         assert_eq!(hash1, hash2);
@@ -13,20 +13,30 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
 use std::{
     collections::hash_map::DefaultHasher,
     ffi::{c_char, CStr},
-    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
+};
+use std::{
+    fmt::{Debug, Display, Formatter},
     sync::Arc,
 };
 
-use nautilus_core::{correctness, string::str_to_cstr};
+use nautilus_core::correctness::FAILED;
+#[cfg(feature = "ffi")]
+use nautilus_core::string::str_to_cstr;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ffi")]
+use crate::identifiers::write_identifier_result;
+use crate::identifiers::{check_valid_string, interner::intern, IdentifierError};
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct ClientId {
     pub value: Box<Arc<String>>,
 }
@@ -44,51 +54,65 @@ impl Display for ClientId {
 }
 
 impl ClientId {
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`ClientId` value")?;
+
+        Ok(Self {
+            value: Box::new(intern(s)),
+        })
+    }
+
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`ClientId` value");
-
-        Self {
-            value: Box::new(Arc::new(s.to_string())),
-        }
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`ClientId`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn client_id_new(ptr: *const c_char) -> ClientId {
-    ClientId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
+pub unsafe extern "C" fn client_id_try_new(ptr: *const c_char, out: *mut ClientId) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(ClientId::try_new(&s), out)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn client_id_clone(client_id: &ClientId) -> ClientId {
     client_id.clone()
 }
 
 /// Frees the memory for the given `client_id` by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn client_id_drop(client_id: ClientId) {
     drop(client_id); // Memory freed here
 }
 
 /// Returns a [`ClientId`] identifier as a C string pointer.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn client_id_to_cstr(client_id: &ClientId) -> *const c_char {
     str_to_cstr(&client_id.value)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn client_id_eq(lhs: &ClientId, rhs: &ClientId) -> u8 {
     u8::from(lhs == rhs)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn client_id_hash(client_id: &ClientId) -> u64 {
     let mut h = DefaultHasher::new();
@@ -124,6 +148,7 @@ mod tests {
         assert_eq!(id.value.as_str(), "BINANCE");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_client_id_clone_c() {
         let id = ClientId::new("BINANCE");
@@ -131,12 +156,14 @@ mod tests {
         assert_eq!(id, id_clone);
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_client_id_drop_c() {
         let id = ClientId::new("BINANCE");
         client_id_drop(id);
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_client_id_to_cstr_c() {
         let id = ClientId::new("BINANCE");
@@ -145,6 +172,7 @@ mod tests {
         assert_eq!(rust_string, "BINANCE");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_client_id_eq_c() {
         let id1 = ClientId::new("BINANCE");
@@ -154,6 +182,7 @@ mod tests {
         assert_eq!(client_id_eq(&id1, &id3), 0);
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_client_id_hash_c() {
         let id1 = ClientId::new("BINANCE");
@@ -13,20 +13,30 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
 use std::{
     collections::hash_map::DefaultHasher,
     ffi::{c_char, CStr},
-    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
+};
+use std::{
+    fmt::{Debug, Display, Formatter},
     sync::Arc,
 };
 
-use nautilus_core::{correctness, string::str_to_cstr};
+use nautilus_core::correctness::FAILED;
+#[cfg(feature = "ffi")]
+use nautilus_core::string::str_to_cstr;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ffi")]
+use crate::identifiers::write_identifier_result;
+use crate::identifiers::{check_valid_string, IdentifierError};
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct ClientOrderId {
     pub value: Box<Arc<String>>,
 }
@@ -52,51 +62,68 @@ impl Default for ClientOrderId {
 }
 
 impl ClientOrderId {
-    #[must_use]
-    pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`ClientOrderId` value");
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`ClientOrderId` value")?;
 
-        Self {
+        Ok(Self {
             value: Box::new(Arc::new(s.to_string())),
-        }
+        })
+    }
+
+    #[must_use]
+    pub fn new(s: &str) -> Self {
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from a C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`ClientOrderId`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn client_order_id_new(ptr: *const c_char) -> ClientOrderId {
-    ClientOrderId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
+pub unsafe extern "C" fn client_order_id_try_new(
+    ptr: *const c_char,
+    out: *mut ClientOrderId,
+) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(ClientOrderId::try_new(&s), out)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn client_order_id_clone(client_order_id: &ClientOrderId) -> ClientOrderId {
     client_order_id.clone()
 }
 
 /// Frees the memory for the given `client_order_id` by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn client_order_id_drop(client_order_id: ClientOrderId) {
     drop(client_order_id); // Memory freed here
 }
 
 /// Returns a [`ClientOrderId`] as a C string pointer.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn client_order_id_to_cstr(client_order_id: &ClientOrderId) -> *const c_char {
     str_to_cstr(&client_order_id.value)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn client_order_id_eq(lhs: &ClientOrderId, rhs: &ClientOrderId) -> u8 {
     u8::from(lhs == rhs)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn client_order_id_hash(client_order_id: &ClientOrderId) -> u64 {
     let mut h = DefaultHasher::new();
@@ -110,6 +137,7 @@ pub extern "C" fn client_order_id_hash(client_order_id: &ClientOrderId) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::ClientOrderId;
+    #[cfg(feature = "ffi")]
     use crate::identifiers::client_order_id::client_order_id_drop;
 
     #[test]
@@ -127,6 +155,7 @@ mod tests {
         assert_eq!(format!("{id}"), "O-20200814-102234-001-001-1");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_client_order_id_drop() {
         let id = ClientOrderId::new("O-20200814-102234-001-001-1");
@@ -13,20 +13,30 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
 use std::{
     collections::hash_map::DefaultHasher,
     ffi::{c_char, CStr},
-    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
+};
+use std::{
+    fmt::{Debug, Display, Formatter},
     sync::Arc,
 };
 
-use nautilus_core::{correctness, string::str_to_cstr};
+use nautilus_core::correctness::FAILED;
+#[cfg(feature = "ffi")]
+use nautilus_core::string::str_to_cstr;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ffi")]
+use crate::identifiers::write_identifier_result;
+use crate::identifiers::{check_valid_string, interner::intern, IdentifierError};
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct ExecAlgorithmId {
     pub value: Box<Arc<String>>,
 }
@@ -44,51 +54,68 @@ impl Display for ExecAlgorithmId {
 }
 
 impl ExecAlgorithmId {
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`ExecAlgorithmId` value")?;
+
+        Ok(Self {
+            value: Box::new(intern(s)),
+        })
+    }
+
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`ExecAlgorithmId` value");
-
-        Self {
-            value: Box::new(Arc::new(s.to_string())),
-        }
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from a C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`ExecAlgorithmId`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn exec_algorithm_id_new(ptr: *const c_char) -> ExecAlgorithmId {
-    ExecAlgorithmId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
+pub unsafe extern "C" fn exec_algorithm_id_try_new(
+    ptr: *const c_char,
+    out: *mut ExecAlgorithmId,
+) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(ExecAlgorithmId::try_new(&s), out)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn exec_algorithm_id_clone(exec_algorithm_id: &ExecAlgorithmId) -> ExecAlgorithmId {
     exec_algorithm_id.clone()
 }
 
 /// Frees the memory for the given `exec_algorithm_id` by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn exec_algorithm_id_drop(exec_algorithm_id: ExecAlgorithmId) {
     drop(exec_algorithm_id); // Memory freed here
 }
 
 /// Returns an [`ExecAlgorithmId`] identifier as a C string pointer.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn exec_algorithm_id_to_cstr(exec_algorithm_id: &ExecAlgorithmId) -> *const c_char {
     str_to_cstr(&exec_algorithm_id.value)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn exec_algorithm_id_eq(lhs: &ExecAlgorithmId, rhs: &ExecAlgorithmId) -> u8 {
     u8::from(lhs == rhs)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn exec_algorithm_id_hash(exec_algorithm_id: &ExecAlgorithmId) -> u64 {
     let mut h = DefaultHasher::new();
@@ -102,6 +129,7 @@ pub extern "C" fn exec_algorithm_id_hash(exec_algorithm_id: &ExecAlgorithmId) ->
 #[cfg(test)]
 mod tests {
     use super::ExecAlgorithmId;
+    #[cfg(feature = "ffi")]
     use crate::identifiers::exec_algorithm_id::exec_algorithm_id_drop;
 
     #[test]
@@ -119,6 +147,7 @@ mod tests {
         assert_eq!(format!("{id}"), "001");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_exec_algorithm_id_drop() {
         let id = ExecAlgorithmId::new("001");
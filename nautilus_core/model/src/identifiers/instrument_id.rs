@@ -13,24 +13,32 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
 use std::{
     collections::hash_map::DefaultHasher,
     ffi::c_char,
-    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
+};
+use std::{
+    fmt::{Debug, Display, Formatter},
     str::FromStr,
 };
 
-use nautilus_core::string::{cstr_to_string, str_to_cstr};
-use pyo3::prelude::*;
+#[cfg(feature = "ffi")]
+use nautilus_core::{ffi_error::write_parsed_cstr, string::str_to_cstr};
+#[cfg(feature = "python")]
+use pyo3::{basic::CompareOp, prelude::*, types::PyTuple, PyTypeInfo};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 
 use crate::identifiers::{symbol::Symbol, venue::Venue};
+#[cfg(feature = "python")]
+use crate::python::{hash_of, richcmp_eq, to_pyvalue_err};
 
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq, Default)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct InstrumentId {
     pub symbol: Symbol,
     pub venue: Venue,
@@ -46,15 +54,23 @@ impl FromStr for InstrumentId {
     type Err = InstrumentIdParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.rsplit_once('.') {
-            Some((symbol_part, venue_part)) => Ok(Self {
-                symbol: Symbol::new(symbol_part),
-                venue: Venue::new(venue_part),
-            }),
-            None => Err(InstrumentIdParseError {
-                input: s.to_string(),
-            }),
-        }
+        let parse_err = || InstrumentIdParseError {
+            input: s.to_string(),
+        };
+        let (symbol_part, venue_part) = s.rsplit_once('.').ok_or_else(parse_err)?;
+
+        Ok(Self {
+            symbol: Symbol::try_new(symbol_part).map_err(|_| parse_err())?,
+            venue: Venue::try_new(venue_part).map_err(|_| parse_err())?,
+        })
+    }
+}
+
+impl TryFrom<&str> for InstrumentId {
+    type Error = InstrumentIdParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
     }
 }
 
@@ -77,6 +93,7 @@ impl InstrumentId {
     }
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for InstrumentId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -86,6 +103,7 @@ impl Serialize for InstrumentId {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for InstrumentId {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -97,9 +115,84 @@ impl<'de> Deserialize<'de> for InstrumentId {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl InstrumentId {
+    #[new]
+    fn py_new(symbol: Symbol, venue: Venue) -> Self {
+        Self::new(symbol, venue)
+    }
+
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let (symbol, venue): (String, String) = state.extract(py)?;
+        self.symbol = Symbol::try_new(&symbol).map_err(to_pyvalue_err)?;
+        self.venue = Venue::try_new(&venue).map_err(to_pyvalue_err)?;
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyObject {
+        (self.symbol.to_string(), self.venue.to_string()).to_object(py)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py);
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> Self {
+        Self::default()
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(value: &str) -> PyResult<Self> {
+        Self::from_str(value).map_err(to_pyvalue_err)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        richcmp_eq(self, other, op, py)
+    }
+
+    fn __hash__(&self) -> isize {
+        hash_of(self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}('{}')", Self::NAME, self)
+    }
+
+    #[getter]
+    #[pyo3(name = "symbol")]
+    fn py_symbol(&self) -> Symbol {
+        self.symbol.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "venue")]
+    fn py_venue(&self) -> Venue {
+        self.venue.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    fn py_value(&self) -> String {
+        self.to_string()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn instrument_id_new(symbol: &Symbol, venue: &Venue) -> InstrumentId {
     let symbol = symbol.clone();
@@ -107,38 +200,65 @@ pub extern "C" fn instrument_id_new(symbol: &Symbol, venue: &Venue) -> Instrumen
     InstrumentId::new(symbol, venue)
 }
 
-/// Returns a Nautilus identifier from a C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for an [`InstrumentId`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn instrument_id_try_from_cstr(
+    ptr: *const c_char,
+    out: *mut InstrumentId,
+) -> u8 {
+    write_parsed_cstr(ptr, out, "`InstrumentId`")
+}
+
+/// Returns a clone of the [`Symbol`] component of the `instrument_id`.
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn instrument_id_new_from_cstr(ptr: *const c_char) -> InstrumentId {
-    InstrumentId::from_str(cstr_to_string(ptr).as_str()).unwrap()
+pub extern "C" fn instrument_id_symbol(instrument_id: &InstrumentId) -> Symbol {
+    instrument_id.symbol.clone()
 }
 
+/// Returns a clone of the [`Venue`] component of the `instrument_id`.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn instrument_id_venue(instrument_id: &InstrumentId) -> Venue {
+    instrument_id.venue.clone()
+}
+
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn instrument_id_clone(instrument_id: &InstrumentId) -> InstrumentId {
     instrument_id.clone()
 }
 
 /// Frees the memory for the given `instrument_id` by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn instrument_id_drop(instrument_id: InstrumentId) {
     drop(instrument_id); // Memory freed here
 }
 
 /// Returns an [`InstrumentId`] as a C string pointer.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn instrument_id_to_cstr(instrument_id: &InstrumentId) -> *const c_char {
     str_to_cstr(&instrument_id.to_string())
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn instrument_id_eq(lhs: &InstrumentId, rhs: &InstrumentId) -> u8 {
     u8::from(lhs == rhs)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn instrument_id_hash(instrument_id: &InstrumentId) -> u64 {
     let mut h = DefaultHasher::new();
@@ -151,11 +271,19 @@ pub extern "C" fn instrument_id_hash(instrument_id: &InstrumentId) -> u64 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::{ffi::CStr, str::FromStr};
+    use std::str::FromStr;
+    #[cfg(feature = "ffi")]
+    use std::{
+        ffi::{CStr, CString},
+        mem::MaybeUninit,
+    };
 
     use super::InstrumentId;
+    use crate::identifiers::instrument_id::InstrumentIdParseError;
+    #[cfg(feature = "ffi")]
     use crate::identifiers::instrument_id::{
-        instrument_id_drop, instrument_id_to_cstr, InstrumentIdParseError,
+        instrument_id_drop, instrument_id_symbol, instrument_id_to_cstr,
+        instrument_id_try_from_cstr, instrument_id_venue,
     };
 
     #[test]
@@ -165,6 +293,23 @@ mod tests {
         assert_eq!(instrument_id.venue.to_string(), "BINANCE");
     }
 
+    #[test]
+    fn test_instrument_id_parse_perpetual() {
+        let instrument_id = InstrumentId::try_from("ETHUSDT-PERP.BINANCE").unwrap();
+        assert_eq!(instrument_id.symbol.to_string(), "ETHUSDT-PERP");
+        assert_eq!(instrument_id.venue.to_string(), "BINANCE");
+    }
+
+    #[test]
+    fn test_instrument_id_parse_failure_empty_venue() {
+        let result = InstrumentId::from_str("ETHUSDT-PERP.");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Error parsing `InstrumentId` from 'ETHUSDT-PERP.'"
+        );
+    }
+
     #[test]
     fn test_instrument_id_parse_failure_no_dot() {
         let result = InstrumentId::from_str("ETHUSDT-BINANCE");
@@ -207,6 +352,7 @@ mod tests {
         assert_eq!(format!("{id}"), "ETH/USDT.BINANCE");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_to_cstr() {
         unsafe {
@@ -216,10 +362,26 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_instrument_id_drop() {
         let id = InstrumentId::from_str("ETH/USDT.BINANCE").unwrap();
 
         instrument_id_drop(id); // No panic
     }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_instrument_id_components_c_api() {
+        let ptr = CString::new("ETHUSDT-PERP.BINANCE").unwrap();
+        let mut out = MaybeUninit::<InstrumentId>::uninit();
+
+        let status = unsafe { instrument_id_try_from_cstr(ptr.as_ptr(), out.as_mut_ptr()) };
+        let id = unsafe { out.assume_init() };
+
+        assert_eq!(status, 0);
+
+        assert_eq!(instrument_id_symbol(&id).to_string(), "ETHUSDT-PERP");
+        assert_eq!(instrument_id_venue(&id).to_string(), "BINANCE");
+    }
 }
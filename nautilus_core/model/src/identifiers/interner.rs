@@ -0,0 +1,194 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A global interning pool for low-cardinality identifier values.
+//!
+//! Identical identifier strings resolve to the same backing `Arc<String>` allocation, which
+//! reduces memory for high-frequency streams and allows equality checks to short-circuit on
+//! pointer identity (`Arc` compares by pointer first when `T: Eq`).
+//!
+//! Only identifiers drawn from a small, mostly static set of values are interned (such as
+//! `Venue`, `Symbol` and therefore `InstrumentId`, `TraderId` and `StrategyId`). Identifiers
+//! which are unique per order, position or trade (such as `ClientOrderId`, `VenueOrderId` and
+//! `TradeId`) allocate their own value, so the pool stays bounded and is not locked on every
+//! order or tick.
+
+use std::{
+    borrow::Borrow,
+    collections::HashSet,
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+};
+
+lazy_static! {
+    static ref IDENTIFIER_POOL: IdentifierPool = IdentifierPool::default();
+}
+
+/// Wraps an interned value so the pool can be queried by `&str` without allocating.
+struct PoolEntry(Arc<String>);
+
+impl Hash for PoolEntry {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_str().hash(state);
+    }
+}
+
+impl PartialEq for PoolEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl Eq for PoolEntry {}
+
+impl Borrow<str> for PoolEntry {
+    fn borrow(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+/// A set of interned values, resolving identical strings to the same allocation.
+#[derive(Default)]
+struct IdentifierPool {
+    values: Mutex<HashSet<PoolEntry>>,
+}
+
+impl IdentifierPool {
+    fn intern(&self, s: &str) -> Arc<String> {
+        let mut values = self.values.lock().unwrap();
+        if let Some(entry) = values.get(s) {
+            return entry.0.clone();
+        }
+        let value = Arc::new(s.to_string());
+        values.insert(PoolEntry(value.clone()));
+        value
+    }
+
+    fn count(&self) -> usize {
+        self.values.lock().unwrap().len()
+    }
+
+    fn purge(&self) -> usize {
+        let mut values = self.values.lock().unwrap();
+        let before = values.len();
+        values.retain(|entry| Arc::strong_count(&entry.0) > 1);
+        before - values.len()
+    }
+}
+
+/// Returns the interned backing value for the given string, inserting it into the pool if
+/// it does not already exist.
+#[must_use]
+pub fn intern(s: &str) -> Arc<String> {
+    IDENTIFIER_POOL.intern(s)
+}
+
+/// Returns the number of distinct values currently held by the pool.
+#[must_use]
+pub fn pool_count() -> usize {
+    IDENTIFIER_POOL.count()
+}
+
+/// Removes all values from the pool which are no longer referenced by any identifier.
+///
+/// Returns the number of values removed.
+pub fn pool_purge() -> usize {
+    IDENTIFIER_POOL.purge()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the number of distinct identifier values held by the interning pool.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn identifier_pool_count() -> usize {
+    pool_count()
+}
+
+/// Removes all unreferenced identifier values from the interning pool.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn identifier_pool_purge() -> usize {
+    pool_purge()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::identifiers::{
+        client_order_id::ClientOrderId, strategy_id::StrategyId, venue::Venue,
+    };
+
+    #[rstest]
+    fn test_intern_returns_same_allocation() {
+        let value1 = intern("INTERN-TEST-001");
+        let value2 = intern("INTERN-TEST-001");
+        assert!(Arc::ptr_eq(&value1, &value2));
+    }
+
+    #[rstest]
+    fn test_intern_distinct_values() {
+        let value1 = intern("INTERN-TEST-002");
+        let value2 = intern("INTERN-TEST-003");
+        assert!(!Arc::ptr_eq(&value1, &value2));
+        assert_ne!(value1, value2);
+    }
+
+    #[rstest]
+    fn test_identifiers_share_backing_value() {
+        let id1 = StrategyId::new("S-INTERN");
+        let id2 = StrategyId::new("S-INTERN");
+        assert!(Arc::ptr_eq(&id1.value, &id2.value));
+        assert_eq!(id1, id2);
+    }
+
+    #[rstest]
+    fn test_different_identifier_types_share_backing_value() {
+        let venue = Venue::new("INTERN-VENUE");
+        let value = intern("INTERN-VENUE");
+        assert!(Arc::ptr_eq(&venue.value, &value));
+    }
+
+    #[rstest]
+    fn test_high_cardinality_identifiers_not_interned() {
+        let id1 = ClientOrderId::new("O-INTERN-001");
+        let id2 = ClientOrderId::new("O-INTERN-001");
+        assert!(!Arc::ptr_eq(&id1.value, &id2.value));
+        assert_eq!(id1, id2);
+    }
+
+    #[rstest]
+    fn test_pool_purge_removes_unreferenced_values() {
+        let pool = IdentifierPool::default();
+        let held = pool.intern("INTERN-TEST-HELD");
+        {
+            let _value = pool.intern("INTERN-TEST-PURGE");
+        }
+        assert_eq!(pool.count(), 2);
+
+        assert_eq!(pool.purge(), 1);
+        assert_eq!(pool.count(), 1);
+        assert!(Arc::ptr_eq(&held, &pool.intern("INTERN-TEST-HELD")));
+        assert_eq!(pool.count(), 1);
+    }
+}
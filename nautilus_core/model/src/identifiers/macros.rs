@@ -17,6 +17,7 @@
 
 macro_rules! impl_serialization_for_identifier {
     ($ty:ty) => {
+        #[cfg(feature = "serde")]
         impl Serialize for $ty {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
@@ -26,6 +27,7 @@ macro_rules! impl_serialization_for_identifier {
             }
         }
 
+        #[cfg(feature = "serde")]
         impl<'de> Deserialize<'de> for $ty {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
@@ -42,10 +44,92 @@ macro_rules! impl_serialization_for_identifier {
 macro_rules! impl_from_str_for_identifier {
     ($ty:ty) => {
         impl FromStr for $ty {
-            type Err = String;
+            type Err = IdentifierError;
 
             fn from_str(input: &str) -> Result<Self, Self::Err> {
-                Ok(Self::new(input))
+                Self::try_new(input)
+            }
+        }
+    };
+}
+
+macro_rules! impl_try_from_str_for_identifier {
+    ($ty:ty) => {
+        impl TryFrom<&str> for $ty {
+            type Error = IdentifierError;
+
+            fn try_from(input: &str) -> Result<Self, Self::Error> {
+                Self::try_new(input)
+            }
+        }
+    };
+}
+
+macro_rules! identifier_for_python {
+    ($ty:ty) => {
+        #[cfg(feature = "python")]
+        #[pyo3::pymethods]
+        impl $ty {
+            #[new]
+            fn py_new(value: &str) -> pyo3::PyResult<Self> {
+                Self::try_new(value).map_err($crate::python::to_pyvalue_err)
+            }
+
+            fn __setstate__(
+                &mut self,
+                py: pyo3::Python,
+                state: pyo3::PyObject,
+            ) -> pyo3::PyResult<()> {
+                let (value,): (String,) = state.extract(py)?;
+                *self = Self::try_new(&value).map_err($crate::python::to_pyvalue_err)?;
+                Ok(())
+            }
+
+            fn __getstate__(&self, py: pyo3::Python) -> pyo3::PyObject {
+                use pyo3::ToPyObject;
+                (self.value.to_string(),).to_object(py)
+            }
+
+            fn __reduce__(&self, py: pyo3::Python) -> pyo3::PyResult<pyo3::PyObject> {
+                use pyo3::ToPyObject;
+                let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+                let state = self.__getstate__(py);
+                Ok((safe_constructor, pyo3::types::PyTuple::empty(py), state).to_object(py))
+            }
+
+            #[staticmethod]
+            fn _safe_constructor() -> Self {
+                // Placeholder value, only ever replaced by `__setstate__` when unpickling
+                Self {
+                    value: Box::new($crate::identifiers::interner::intern("NULL")),
+                }
+            }
+
+            fn __richcmp__(
+                &self,
+                other: &Self,
+                op: pyo3::basic::CompareOp,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::Py<pyo3::PyAny> {
+                $crate::python::richcmp_eq(self, other, op, py)
+            }
+
+            fn __hash__(&self) -> isize {
+                $crate::python::hash_of(self)
+            }
+
+            fn __str__(&self) -> String {
+                self.value.to_string()
+            }
+
+            fn __repr__(&self) -> String {
+                format!("{}('{}')", <Self as pyo3::PyTypeInfo>::NAME, self.value)
+            }
+
+            #[getter]
+            #[pyo3(name = "value")]
+            fn py_value(&self) -> String {
+                self.value.to_string()
             }
         }
     };
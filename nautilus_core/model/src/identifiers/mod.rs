@@ -15,7 +15,11 @@
 
 use std::str::FromStr;
 
+#[cfg(feature = "ffi")]
+use nautilus_core::ffi_error::write_result;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
 
 #[macro_use]
 mod macros;
@@ -26,15 +30,114 @@ pub mod client_order_id;
 pub mod component_id;
 pub mod exec_algorithm_id;
 pub mod instrument_id;
+pub mod interner;
 pub mod order_list_id;
 pub mod position_id;
 pub mod strategy_id;
 pub mod symbol;
+pub mod symbology;
 pub mod trade_id;
 pub mod trader_id;
 pub mod venue;
 pub mod venue_order_id;
 
+/// Represents an error when constructing an identifier from an invalid string.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum IdentifierError {
+    #[error("invalid string for {desc}, was empty")]
+    Empty { desc: &'static str },
+    #[error("invalid string for {desc}, was all whitespace")]
+    Whitespace { desc: &'static str },
+    #[error("invalid string for {desc} contained a non-ASCII char, was '{value}'")]
+    NonAscii { desc: &'static str, value: String },
+    #[error("invalid string for {desc} did not contain '{pat}', was '{value}'")]
+    MissingPattern {
+        desc: &'static str,
+        pat: &'static str,
+        value: String,
+    },
+    #[error("invalid string for {desc} exceeded maximum length {max}, was '{value}'")]
+    TooLong {
+        desc: &'static str,
+        max: usize,
+        value: String,
+    },
+}
+
+impl IdentifierError {
+    /// Returns the error code for the C API (zero is reserved for success).
+    #[must_use]
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::Empty { .. } => 1,
+            Self::Whitespace { .. } => 2,
+            Self::NonAscii { .. } => 3,
+            Self::MissingPattern { .. } => 4,
+            Self::TooLong { .. } => 5,
+        }
+    }
+}
+
+/// Checks the string `s` is a valid identifier value.
+pub fn check_valid_string(s: &str, desc: &'static str) -> Result<(), IdentifierError> {
+    if s.is_empty() {
+        Err(IdentifierError::Empty { desc })
+    } else if s.as_bytes().iter().all(u8::is_ascii_whitespace) {
+        Err(IdentifierError::Whitespace { desc })
+    } else if !s.is_ascii() {
+        Err(IdentifierError::NonAscii {
+            desc,
+            value: s.to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks the string `s` contains the pattern `pat`.
+pub fn check_string_contains(
+    s: &str,
+    pat: &'static str,
+    desc: &'static str,
+) -> Result<(), IdentifierError> {
+    if s.contains(pat) {
+        Ok(())
+    } else {
+        Err(IdentifierError::MissingPattern {
+            desc,
+            pat,
+            value: s.to_string(),
+        })
+    }
+}
+
+/// Checks the string `s` is no longer than `max` bytes.
+pub fn check_max_len(s: &str, max: usize, desc: &'static str) -> Result<(), IdentifierError> {
+    if s.len() <= max {
+        Ok(())
+    } else {
+        Err(IdentifierError::TooLong {
+            desc,
+            max,
+            value: s.to_string(),
+        })
+    }
+}
+
+/// Writes the identifier from `result` to `out` on success, returning the C API error code
+/// (the error message is set as the last FFI error).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a `T`, which is not read or dropped.
+#[cfg(feature = "ffi")]
+pub(crate) unsafe fn write_identifier_result<T>(
+    result: Result<T, IdentifierError>,
+    out: *mut T,
+) -> u8 {
+    write_result(result, out, IdentifierError::code)
+}
+
 impl_from_str_for_identifier!(account_id::AccountId);
 impl_from_str_for_identifier!(client_id::ClientId);
 impl_from_str_for_identifier!(client_order_id::ClientOrderId);
@@ -62,3 +165,78 @@ impl_serialization_for_identifier!(trade_id::TradeId);
 impl_serialization_for_identifier!(trader_id::TraderId);
 impl_serialization_for_identifier!(venue::Venue);
 impl_serialization_for_identifier!(venue_order_id::VenueOrderId);
+
+impl_try_from_str_for_identifier!(account_id::AccountId);
+impl_try_from_str_for_identifier!(client_id::ClientId);
+impl_try_from_str_for_identifier!(client_order_id::ClientOrderId);
+impl_try_from_str_for_identifier!(component_id::ComponentId);
+impl_try_from_str_for_identifier!(exec_algorithm_id::ExecAlgorithmId);
+impl_try_from_str_for_identifier!(order_list_id::OrderListId);
+impl_try_from_str_for_identifier!(position_id::PositionId);
+impl_try_from_str_for_identifier!(strategy_id::StrategyId);
+impl_try_from_str_for_identifier!(symbol::Symbol);
+impl_try_from_str_for_identifier!(trade_id::TradeId);
+impl_try_from_str_for_identifier!(trader_id::TraderId);
+impl_try_from_str_for_identifier!(venue::Venue);
+impl_try_from_str_for_identifier!(venue_order_id::VenueOrderId);
+
+identifier_for_python!(account_id::AccountId);
+identifier_for_python!(client_id::ClientId);
+identifier_for_python!(client_order_id::ClientOrderId);
+identifier_for_python!(component_id::ComponentId);
+identifier_for_python!(exec_algorithm_id::ExecAlgorithmId);
+identifier_for_python!(order_list_id::OrderListId);
+identifier_for_python!(position_id::PositionId);
+identifier_for_python!(strategy_id::StrategyId);
+identifier_for_python!(symbol::Symbol);
+identifier_for_python!(trade_id::TradeId);
+identifier_for_python!(trader_id::TraderId);
+identifier_for_python!(venue::Venue);
+identifier_for_python!(venue_order_id::VenueOrderId);
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("", 1)]
+    #[case("   ", 2)]
+    #[case("ÄBC", 3)]
+    fn test_check_valid_string_errors(#[case] input: &str, #[case] expected_code: u8) {
+        let result = check_valid_string(input, "`Test` value");
+        assert_eq!(result.unwrap_err().code(), expected_code);
+    }
+
+    #[rstest]
+    fn test_check_string_contains_error() {
+        let error = check_string_contains("TRADER", "-", "`TraderId` value").unwrap_err();
+        assert_eq!(error.code(), 4);
+        assert_eq!(
+            error.to_string(),
+            "invalid string for `TraderId` value did not contain '-', was 'TRADER'"
+        );
+    }
+
+    #[rstest]
+    fn test_check_max_len_error() {
+        let error = check_max_len("ABCD", 3, "`Test` value").unwrap_err();
+        assert_eq!(error.code(), 5);
+        assert_eq!(
+            error.to_string(),
+            "invalid string for `Test` value exceeded maximum length 3, was 'ABCD'"
+        );
+        assert!(check_max_len("ABC", 3, "`Test` value").is_ok());
+    }
+
+    #[rstest]
+    fn test_try_from_str() {
+        let trader_id = trader_id::TraderId::try_from("TRADER-001").unwrap();
+        assert_eq!(trader_id.to_string(), "TRADER-001");
+        assert!(trader_id::TraderId::try_from("TRADER").is_err());
+    }
+}
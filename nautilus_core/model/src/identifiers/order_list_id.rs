@@ -13,20 +13,30 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
 use std::{
     collections::hash_map::DefaultHasher,
     ffi::{c_char, CStr},
-    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
+};
+use std::{
+    fmt::{Debug, Display, Formatter},
     sync::Arc,
 };
 
-use nautilus_core::{correctness, string::str_to_cstr};
+use nautilus_core::correctness::FAILED;
+#[cfg(feature = "ffi")]
+use nautilus_core::string::str_to_cstr;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ffi")]
+use crate::identifiers::write_identifier_result;
+use crate::identifiers::{check_valid_string, IdentifierError};
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct OrderListId {
     pub value: Box<Arc<String>>,
 }
@@ -44,51 +54,65 @@ impl Display for OrderListId {
 }
 
 impl OrderListId {
-    #[must_use]
-    pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`OrderListId` value");
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`OrderListId` value")?;
 
-        Self {
+        Ok(Self {
             value: Box::new(Arc::new(s.to_string())),
-        }
+        })
+    }
+
+    #[must_use]
+    pub fn new(s: &str) -> Self {
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from a C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`OrderListId`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn order_list_id_new(ptr: *const c_char) -> OrderListId {
-    OrderListId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
+pub unsafe extern "C" fn order_list_id_try_new(ptr: *const c_char, out: *mut OrderListId) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(OrderListId::try_new(&s), out)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn order_list_id_clone(order_list_id: &OrderListId) -> OrderListId {
     order_list_id.clone()
 }
 
 /// Frees the memory for the given `order_list_id` by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn order_list_id_drop(order_list_id: OrderListId) {
     drop(order_list_id); // Memory freed here
 }
 
 /// Returns an [`OrderListId`] as a C string pointer.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn order_list_id_to_cstr(order_list_id: &OrderListId) -> *const c_char {
     str_to_cstr(&order_list_id.value)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn order_list_id_eq(lhs: &OrderListId, rhs: &OrderListId) -> u8 {
     u8::from(lhs == rhs)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn order_list_id_hash(order_list_id: &OrderListId) -> u64 {
     let mut h = DefaultHasher::new();
@@ -102,6 +126,7 @@ pub extern "C" fn order_list_id_hash(order_list_id: &OrderListId) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::OrderListId;
+    #[cfg(feature = "ffi")]
     use crate::identifiers::order_list_id::order_list_id_drop;
 
     #[test]
@@ -119,6 +144,7 @@ mod tests {
         assert_eq!(format!("{id}"), "001");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_order_list_id_drop() {
         let id = OrderListId::new("001");
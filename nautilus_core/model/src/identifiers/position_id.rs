@@ -13,20 +13,30 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
 use std::{
     collections::hash_map::DefaultHasher,
     ffi::{c_char, CStr},
-    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
+};
+use std::{
+    fmt::{Debug, Display, Formatter},
     sync::Arc,
 };
 
-use nautilus_core::{correctness, string::str_to_cstr};
+use nautilus_core::correctness::FAILED;
+#[cfg(feature = "ffi")]
+use nautilus_core::string::str_to_cstr;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ffi")]
+use crate::identifiers::write_identifier_result;
+use crate::identifiers::{check_valid_string, IdentifierError};
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct PositionId {
     pub value: Box<Arc<String>>,
 }
@@ -43,51 +53,65 @@ impl Display for PositionId {
 }
 
 impl PositionId {
-    #[must_use]
-    pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`PositionId` value");
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`PositionId` value")?;
 
-        Self {
+        Ok(Self {
             value: Box::new(Arc::new(s.to_string())),
-        }
+        })
+    }
+
+    #[must_use]
+    pub fn new(s: &str) -> Self {
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from a C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`PositionId`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn position_id_new(ptr: *const c_char) -> PositionId {
-    PositionId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
+pub unsafe extern "C" fn position_id_try_new(ptr: *const c_char, out: *mut PositionId) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(PositionId::try_new(&s), out)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn position_id_clone(position_id: &PositionId) -> PositionId {
     position_id.clone()
 }
 
 /// Frees the memory for the given `position_id` by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn position_id_drop(position_id: PositionId) {
     drop(position_id); // Memory freed here
 }
 
 /// Returns a [`PositionId`] identifier as a C string pointer.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn position_id_to_cstr(position_id: &PositionId) -> *const c_char {
     str_to_cstr(&position_id.value)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn position_id_eq(lhs: &PositionId, rhs: &PositionId) -> u8 {
     u8::from(lhs == rhs)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn position_id_hash(position_id: &PositionId) -> u64 {
     let mut h = DefaultHasher::new();
@@ -101,6 +125,7 @@ pub extern "C" fn position_id_hash(position_id: &PositionId) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::PositionId;
+    #[cfg(feature = "ffi")]
     use crate::identifiers::position_id::position_id_drop;
 
     #[test]
@@ -118,6 +143,7 @@ mod tests {
         assert_eq!(format!("{id}"), "P-123456789");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_position_id_drop() {
         let id = PositionId::new("001");
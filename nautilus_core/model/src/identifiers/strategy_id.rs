@@ -13,18 +13,28 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
+use std::ffi::{c_char, CStr};
 use std::{
-    ffi::{c_char, CStr},
     fmt::{Debug, Display, Formatter},
     sync::Arc,
 };
 
-use nautilus_core::{correctness, string::str_to_cstr};
+use nautilus_core::correctness::FAILED;
+#[cfg(feature = "ffi")]
+use nautilus_core::string::str_to_cstr;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ffi")]
+use crate::identifiers::write_identifier_result;
+use crate::identifiers::{
+    check_string_contains, check_valid_string, interner::intern, IdentifierError,
+};
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct StrategyId {
     pub value: Box<Arc<String>>,
 }
@@ -50,44 +60,62 @@ impl Default for StrategyId {
 }
 
 impl StrategyId {
-    #[must_use]
-    pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`StrategyId` value");
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`StrategyId` value")?;
         if s != "EXTERNAL" {
-            correctness::string_contains(s, "-", "`StrategyId` value");
+            check_string_contains(s, "-", "`StrategyId` value")?;
         }
 
-        Self {
-            value: Box::new(Arc::new(s.to_string())),
-        }
+        Ok(Self {
+            value: Box::new(intern(s)),
+        })
+    }
+
+    #[must_use]
+    pub fn new(s: &str) -> Self {
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
+    }
+
+    /// Returns the order ID tag value for this ID (the part following the final hyphen).
+    #[must_use]
+    pub fn get_tag(&self) -> &str {
+        self.value.rsplit('-').next().unwrap_or_default()
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from a C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`StrategyId`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn strategy_id_new(ptr: *const c_char) -> StrategyId {
-    StrategyId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
+pub unsafe extern "C" fn strategy_id_try_new(ptr: *const c_char, out: *mut StrategyId) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(StrategyId::try_new(&s), out)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn strategy_id_clone(strategy_id: &StrategyId) -> StrategyId {
     strategy_id.clone()
 }
 
 /// Frees the memory for the given `strategy_id` by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn strategy_id_drop(strategy_id: StrategyId) {
     drop(strategy_id); // Memory freed here
 }
 
 /// Returns a [`StrategyId`] as a C string pointer.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn strategy_id_to_cstr(strategy_id: &StrategyId) -> *const c_char {
     str_to_cstr(&strategy_id.value)
@@ -99,6 +127,7 @@ pub extern "C" fn strategy_id_to_cstr(strategy_id: &StrategyId) -> *const c_char
 #[cfg(test)]
 mod tests {
     use super::StrategyId;
+    #[cfg(feature = "ffi")]
     use crate::identifiers::strategy_id::strategy_id_drop;
 
     #[test]
@@ -116,6 +145,7 @@ mod tests {
         assert_eq!(format!("{id}"), "EMACross-001");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_strategy_id_drop() {
         let id = StrategyId::new("EMACross-001");
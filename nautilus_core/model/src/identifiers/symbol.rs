@@ -13,20 +13,30 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
 use std::{
     collections::hash_map::DefaultHasher,
     ffi::{c_char, CStr},
-    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
+};
+use std::{
+    fmt::{Debug, Display, Formatter},
     sync::Arc,
 };
 
-use nautilus_core::{correctness, string::str_to_cstr};
+use nautilus_core::correctness::FAILED;
+#[cfg(feature = "ffi")]
+use nautilus_core::string::str_to_cstr;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ffi")]
+use crate::identifiers::write_identifier_result;
+use crate::identifiers::{check_valid_string, interner::intern, IdentifierError};
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct Symbol {
     pub value: Box<Arc<String>>,
 }
@@ -52,51 +62,65 @@ impl Default for Symbol {
 }
 
 impl Symbol {
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`Symbol` value")?;
+
+        Ok(Self {
+            value: Box::new(intern(s)),
+        })
+    }
+
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`Symbol` value");
-
-        Self {
-            value: Box::new(Arc::new(s.to_string())),
-        }
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from a C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`Symbol`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn symbol_new(ptr: *const c_char) -> Symbol {
-    Symbol::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
+pub unsafe extern "C" fn symbol_try_new(ptr: *const c_char, out: *mut Symbol) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(Symbol::try_new(&s), out)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn symbol_clone(symbol: &Symbol) -> Symbol {
     symbol.clone()
 }
 
 /// Frees the memory for the given [Symbol] by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn symbol_drop(symbol: Symbol) {
     drop(symbol); // Memory freed here
 }
 
 /// Returns a [`Symbol`] as a C string pointer.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn symbol_to_cstr(symbol: &Symbol) -> *const c_char {
     str_to_cstr(&symbol.value)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn symbol_eq(lhs: &Symbol, rhs: &Symbol) -> u8 {
     u8::from(lhs == rhs)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn symbol_hash(symbol: &Symbol) -> u64 {
     let mut h = DefaultHasher::new();
@@ -110,6 +134,7 @@ pub extern "C" fn symbol_hash(symbol: &Symbol) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::Symbol;
+    #[cfg(feature = "ffi")]
     use crate::identifiers::symbol::symbol_drop;
 
     #[test]
@@ -127,6 +152,7 @@ mod tests {
         assert_eq!(format!("{symbol}"), "ETH-PERP");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_symbol_drop() {
         let id = Symbol::new("ETH-PERP");
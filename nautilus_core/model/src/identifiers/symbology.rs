@@ -0,0 +1,588 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Normalization between venue-native symbols and Nautilus [`Symbol`]s.
+//!
+//! Each venue formats its symbols differently, so adapters register a [`SymbologyRule`] per
+//! venue with a [`SymbologyNormalizer`]. Venues without a rule use the native symbol as is.
+
+use std::collections::HashMap;
+#[cfg(feature = "ffi")]
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(feature = "ffi")]
+use nautilus_core::{
+    ffi_error::{set_last_error, try_cstr_to_str, write_result, FFI_ERROR, FFI_OK},
+    parsing::try_bytes_to_string_vec,
+    string::str_to_cstr,
+};
+use thiserror::Error;
+
+use crate::identifiers::{symbol::Symbol, venue::Venue, IdentifierError};
+
+/// Represents an error when converting between venue-native and Nautilus symbols.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum SymbologyError {
+    #[error("invalid symbol for {rule} rule, was '{value}'")]
+    InvalidFormat { rule: &'static str, value: String },
+    #[error(transparent)]
+    Identifier(#[from] IdentifierError),
+}
+
+/// Provides the conversion between venue-native symbols and Nautilus symbol values.
+pub trait SymbologyRule: Send + Sync {
+    /// Returns the Nautilus symbol value for the venue-native `native` symbol.
+    fn to_nautilus(&self, native: &str) -> Result<String, SymbologyError>;
+
+    /// Returns the venue-native symbol for the Nautilus `symbol` value.
+    fn to_native(&self, symbol: &str) -> Result<String, SymbologyError>;
+}
+
+/// Uses venue-native symbols unchanged.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IdentityRule;
+
+impl SymbologyRule for IdentityRule {
+    fn to_nautilus(&self, native: &str) -> Result<String, SymbologyError> {
+        Ok(native.to_string())
+    }
+
+    fn to_native(&self, symbol: &str) -> Result<String, SymbologyError> {
+        Ok(symbol.to_string())
+    }
+}
+
+/// Converts slash separated currency pairs (e.g. `BTC/USDT`) to and from concatenated
+/// symbols (e.g. `BTCUSDT`).
+///
+/// The pair is split again by matching the longest of the known `quotes` as a suffix.
+#[derive(Clone, Debug)]
+pub struct SlashPairRule {
+    quotes: Vec<String>,
+}
+
+impl SlashPairRule {
+    #[must_use]
+    pub fn new(quotes: Vec<String>) -> Self {
+        let mut quotes = quotes;
+        // Longest first so `BUSD` is matched before `USD`
+        quotes.sort_by_key(|quote| std::cmp::Reverse(quote.len()));
+        Self { quotes }
+    }
+
+    fn invalid(value: &str) -> SymbologyError {
+        SymbologyError::InvalidFormat {
+            rule: "slash pair",
+            value: value.to_string(),
+        }
+    }
+}
+
+impl SymbologyRule for SlashPairRule {
+    fn to_nautilus(&self, native: &str) -> Result<String, SymbologyError> {
+        match native.split_once('/') {
+            Some((base, quote)) if !base.is_empty() && !quote.is_empty() => {
+                Ok(format!("{base}{quote}"))
+            }
+            _ => Err(Self::invalid(native)),
+        }
+    }
+
+    fn to_native(&self, symbol: &str) -> Result<String, SymbologyError> {
+        self.quotes
+            .iter()
+            .find_map(|quote| {
+                symbol
+                    .strip_suffix(quote.as_str())
+                    .filter(|base| !base.is_empty())
+                    .map(|base| format!("{base}/{quote}"))
+            })
+            .ok_or_else(|| Self::invalid(symbol))
+    }
+}
+
+const FUTURES_MONTH_CODES: &str = "FGHJKMNQUVXZ";
+
+/// Converts futures symbols with a single digit year (e.g. `ESZ3`) to and from symbols with a
+/// two digit year (e.g. `ESZ23`).
+///
+/// A single digit year is resolved to the first matching year from the `reference_year`.
+#[derive(Clone, Copy, Debug)]
+pub struct FuturesMonthCodeRule {
+    reference_year: u16,
+}
+
+impl FuturesMonthCodeRule {
+    #[must_use]
+    pub fn new(reference_year: u16) -> Self {
+        Self { reference_year }
+    }
+
+    fn invalid(value: &str) -> SymbologyError {
+        SymbologyError::InvalidFormat {
+            rule: "futures month code",
+            value: value.to_string(),
+        }
+    }
+
+    /// Splits `s` into the root, month code and year digits.
+    fn split(s: &str, year_digits: usize) -> Option<(&str, char, &str)> {
+        if !s.is_ascii() || s.len() < year_digits + 2 {
+            return None;
+        }
+        let (rest, year) = s.split_at(s.len() - year_digits);
+        let (root, month) = rest.split_at(rest.len() - 1);
+        let month = month.chars().next()?;
+        if year.bytes().all(|b| b.is_ascii_digit()) && FUTURES_MONTH_CODES.contains(month) {
+            Some((root, month, year))
+        } else {
+            None
+        }
+    }
+}
+
+impl SymbologyRule for FuturesMonthCodeRule {
+    fn to_nautilus(&self, native: &str) -> Result<String, SymbologyError> {
+        let (root, month, year) = Self::split(native, 1).ok_or_else(|| Self::invalid(native))?;
+        let digit: u16 = year.parse().map_err(|_| Self::invalid(native))?;
+        let offset = (digit + 10 - self.reference_year % 10) % 10;
+        let year = (self.reference_year + offset) % 100;
+        Ok(format!("{root}{month}{year:02}"))
+    }
+
+    fn to_native(&self, symbol: &str) -> Result<String, SymbologyError> {
+        let (root, month, year) = Self::split(symbol, 2).ok_or_else(|| Self::invalid(symbol))?;
+        Ok(format!("{root}{month}{}", &year[1..]))
+    }
+}
+
+const OCC_ROOT_LEN: usize = 6;
+const OCC_SUFFIX_LEN: usize = 15;
+
+/// Converts OCC option symbols (e.g. `AAPL  230616C00150000`), where the root is padded with
+/// spaces, to and from symbols without the padding (e.g. `AAPL230616C00150000`).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OccOptionRule;
+
+impl OccOptionRule {
+    fn invalid(value: &str) -> SymbologyError {
+        SymbologyError::InvalidFormat {
+            rule: "OCC option",
+            value: value.to_string(),
+        }
+    }
+
+    /// Splits `s` into the root and the expiry, right and strike suffix.
+    fn split(s: &str) -> Option<(&str, &str)> {
+        if !s.is_ascii() || s.len() <= OCC_SUFFIX_LEN {
+            return None;
+        }
+        let (root, suffix) = s.split_at(s.len() - OCC_SUFFIX_LEN);
+        let root = root.trim_end();
+        let bytes = suffix.as_bytes();
+        let valid = !root.is_empty()
+            && root.len() <= OCC_ROOT_LEN
+            && bytes[..6].iter().all(u8::is_ascii_digit)
+            && matches!(bytes[6], b'C' | b'P')
+            && bytes[7..].iter().all(u8::is_ascii_digit);
+        valid.then_some((root, suffix))
+    }
+}
+
+impl SymbologyRule for OccOptionRule {
+    fn to_nautilus(&self, native: &str) -> Result<String, SymbologyError> {
+        let (root, suffix) = Self::split(native).ok_or_else(|| Self::invalid(native))?;
+        Ok(format!("{root}{suffix}"))
+    }
+
+    fn to_native(&self, symbol: &str) -> Result<String, SymbologyError> {
+        let (root, suffix) = Self::split(symbol).ok_or_else(|| Self::invalid(symbol))?;
+        Ok(format!("{root:<OCC_ROOT_LEN$}{suffix}"))
+    }
+}
+
+/// Converts between venue-native symbols and Nautilus [`Symbol`]s using the rules registered
+/// per venue.
+#[derive(Default)]
+pub struct SymbologyNormalizer {
+    rules: HashMap<Venue, Box<dyn SymbologyRule>>,
+}
+
+impl SymbologyNormalizer {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the `rule` for the `venue`, replacing any existing rule.
+    pub fn register(&mut self, venue: Venue, rule: Box<dyn SymbologyRule>) {
+        self.rules.insert(venue, rule);
+    }
+
+    /// Returns whether a rule is registered for the `venue`.
+    #[must_use]
+    pub fn has_rule(&self, venue: &Venue) -> bool {
+        self.rules.contains_key(venue)
+    }
+
+    /// Returns the Nautilus symbol for the venue-native `native` symbol.
+    pub fn normalize(&self, venue: &Venue, native: &str) -> Result<Symbol, SymbologyError> {
+        let value = match self.rules.get(venue) {
+            Some(rule) => rule.to_nautilus(native)?,
+            None => native.to_string(),
+        };
+        Ok(Symbol::try_new(&value)?)
+    }
+
+    /// Returns the venue-native symbol for the Nautilus `symbol`.
+    pub fn to_native(&self, venue: &Venue, symbol: &Symbol) -> Result<String, SymbologyError> {
+        match self.rules.get(venue) {
+            Some(rule) => rule.to_native(&symbol.value),
+            None => Ok(symbol.value.to_string()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`SymbologyNormalizer`].
+///
+/// This struct wraps `SymbologyNormalizer` in a way that makes it compatible with C function
+/// calls, enabling adapters to register rules and normalize symbols from a C environment.
+#[cfg(feature = "ffi")]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct SymbologyNormalizer_API(Box<SymbologyNormalizer>);
+
+#[cfg(feature = "ffi")]
+impl Deref for SymbologyNormalizer_API {
+    type Target = SymbologyNormalizer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "ffi")]
+impl DerefMut for SymbologyNormalizer_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn symbology_normalizer_new() -> SymbologyNormalizer_API {
+    SymbologyNormalizer_API(Box::new(SymbologyNormalizer::new()))
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn symbology_normalizer_drop(normalizer: SymbologyNormalizer_API) {
+    drop(normalizer); // Memory freed here
+}
+
+/// Registers a [`SlashPairRule`] for the `venue`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case no rule is registered).
+///
+/// # Safety
+///
+/// - Assumes `quotes_ptr` is a valid C string pointer of a JSON format list of strings.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn symbology_normalizer_register_slash_pair_rule(
+    normalizer: &mut SymbologyNormalizer_API,
+    venue: &Venue,
+    quotes_ptr: *const c_char,
+) -> u8 {
+    match try_bytes_to_string_vec(quotes_ptr) {
+        Ok(quotes) => {
+            normalizer.register(venue.clone(), Box::new(SlashPairRule::new(quotes)));
+            FFI_OK
+        }
+        Err(e) => {
+            set_last_error(e);
+            FFI_ERROR
+        }
+    }
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn symbology_normalizer_register_futures_month_code_rule(
+    normalizer: &mut SymbologyNormalizer_API,
+    venue: &Venue,
+    reference_year: u16,
+) {
+    normalizer.register(
+        venue.clone(),
+        Box::new(FuturesMonthCodeRule::new(reference_year)),
+    );
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn symbology_normalizer_register_occ_option_rule(
+    normalizer: &mut SymbologyNormalizer_API,
+    venue: &Venue,
+) {
+    normalizer.register(venue.clone(), Box::new(OccOptionRule));
+}
+
+/// Returns the Nautilus symbol for the venue-native symbol at `native_ptr`, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `native_ptr` is a valid C string pointer, or null.
+/// - Assumes `out` is a valid pointer to memory for a [`Symbol`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn symbology_normalizer_normalize(
+    normalizer: &SymbologyNormalizer_API,
+    venue: &Venue,
+    native_ptr: *const c_char,
+    out: *mut Symbol,
+) -> u8 {
+    let result = try_cstr_to_str(native_ptr, "native symbol").and_then(|native| {
+        normalizer
+            .normalize(venue, native)
+            .map_err(|e| e.to_string())
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Returns the venue-native symbol for the `symbol` as a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a C string pointer.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn symbology_normalizer_to_native(
+    normalizer: &SymbologyNormalizer_API,
+    venue: &Venue,
+    symbol: &Symbol,
+    out: *mut *const c_char,
+) -> u8 {
+    let result = normalizer
+        .to_native(venue, symbol)
+        .map(|native| str_to_cstr(&native));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "ffi")]
+    use std::{ffi::CString, mem::MaybeUninit};
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quotes() -> Vec<String> {
+        vec!["USD".to_string(), "USDT".to_string(), "BTC".to_string()]
+    }
+
+    #[rstest]
+    #[case("BTC/USDT", "BTCUSDT")]
+    #[case("ETH/BTC", "ETHBTC")]
+    #[case("EUR/USD", "EURUSD")]
+    fn test_slash_pair_rule(#[case] native: &str, #[case] expected: &str) {
+        let rule = SlashPairRule::new(quotes());
+        assert_eq!(rule.to_nautilus(native).unwrap(), expected);
+        assert_eq!(rule.to_native(expected).unwrap(), native);
+    }
+
+    #[rstest]
+    #[case("BTCUSDT-PERP")]
+    #[case("USDT")]
+    fn test_slash_pair_rule_to_native_invalid(#[case] symbol: &str) {
+        let rule = SlashPairRule::new(quotes());
+        assert!(rule.to_native(symbol).is_err());
+    }
+
+    #[rstest]
+    #[case("ESZ3", 2023, "ESZ23")]
+    #[case("ESH4", 2023, "ESH24")]
+    #[case("CLF0", 2028, "CLF30")]
+    #[case("6EM9", 2020, "6EM29")]
+    fn test_futures_month_code_rule(
+        #[case] native: &str,
+        #[case] reference_year: u16,
+        #[case] expected: &str,
+    ) {
+        let rule = FuturesMonthCodeRule::new(reference_year);
+        assert_eq!(rule.to_nautilus(native).unwrap(), expected);
+        assert_eq!(rule.to_native(expected).unwrap(), native);
+    }
+
+    #[rstest]
+    #[case("ESA3")]
+    #[case("ESZ")]
+    #[case("Z3")]
+    fn test_futures_month_code_rule_invalid(#[case] native: &str) {
+        let rule = FuturesMonthCodeRule::new(2023);
+        assert!(rule.to_nautilus(native).is_err());
+    }
+
+    #[rstest]
+    #[case("AAPL  230616C00150000", "AAPL230616C00150000")]
+    #[case("SPXW  231215P04500000", "SPXW231215P04500000")]
+    fn test_occ_option_rule(#[case] native: &str, #[case] expected: &str) {
+        let rule = OccOptionRule;
+        assert_eq!(rule.to_nautilus(native).unwrap(), expected);
+        assert_eq!(rule.to_native(expected).unwrap(), native);
+    }
+
+    #[rstest]
+    fn test_occ_option_rule_single_space() {
+        let rule = OccOptionRule;
+        assert_eq!(
+            rule.to_nautilus("AAPL 230616C00150000").unwrap(),
+            "AAPL230616C00150000"
+        );
+    }
+
+    #[rstest]
+    #[case("AAPL")]
+    #[case("AAPL  230616X00150000")]
+    #[case("TOOLONGROOT230616C00150000")]
+    fn test_occ_option_rule_invalid(#[case] native: &str) {
+        let rule = OccOptionRule;
+        assert_eq!(
+            rule.to_nautilus(native).unwrap_err(),
+            SymbologyError::InvalidFormat {
+                rule: "OCC option",
+                value: native.to_string(),
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_normalizer_with_registered_rule() {
+        let venue = Venue::new("BINANCE");
+        let mut normalizer = SymbologyNormalizer::new();
+        normalizer.register(venue.clone(), Box::new(SlashPairRule::new(quotes())));
+
+        let symbol = normalizer.normalize(&venue, "BTC/USDT").unwrap();
+
+        assert!(normalizer.has_rule(&venue));
+        assert_eq!(symbol, Symbol::new("BTCUSDT"));
+        assert_eq!(normalizer.to_native(&venue, &symbol).unwrap(), "BTC/USDT");
+    }
+
+    #[rstest]
+    fn test_normalizer_without_rule_uses_native_symbol() {
+        let venue = Venue::new("SIM");
+        let normalizer = SymbologyNormalizer::new();
+
+        let symbol = normalizer.normalize(&venue, "AUD/USD").unwrap();
+
+        assert_eq!(symbol, Symbol::new("AUD/USD"));
+        assert_eq!(normalizer.to_native(&venue, &symbol).unwrap(), "AUD/USD");
+    }
+
+    #[rstest]
+    fn test_normalizer_invalid_symbol() {
+        let venue = Venue::new("SIM");
+        let normalizer = SymbologyNormalizer::new();
+
+        let result = normalizer.normalize(&venue, " ");
+
+        assert!(matches!(result, Err(SymbologyError::Identifier(_))));
+    }
+
+    #[cfg(feature = "ffi")]
+    #[rstest]
+    fn test_normalizer_c_api() {
+        let venue = Venue::new("GLBX");
+        let mut normalizer = symbology_normalizer_new();
+        symbology_normalizer_register_futures_month_code_rule(&mut normalizer, &venue, 2023);
+        let native = CString::new("ESZ3").unwrap();
+        let mut symbol = MaybeUninit::<Symbol>::uninit();
+
+        let status = unsafe {
+            symbology_normalizer_normalize(
+                &normalizer,
+                &venue,
+                native.as_ptr(),
+                symbol.as_mut_ptr(),
+            )
+        };
+
+        assert_eq!(status, 0);
+        let symbol = unsafe { symbol.assume_init() };
+        assert_eq!(symbol, Symbol::new("ESZ23"));
+
+        let mut native_ptr = MaybeUninit::<*const c_char>::uninit();
+        let status = unsafe {
+            symbology_normalizer_to_native(&normalizer, &venue, &symbol, native_ptr.as_mut_ptr())
+        };
+
+        assert_eq!(status, 0);
+        let native_ptr = unsafe { native_ptr.assume_init() };
+        assert_eq!(
+            unsafe { nautilus_core::string::cstr_to_string(native_ptr) },
+            "ESZ3"
+        );
+        unsafe { nautilus_core::string::cstr_drop(native_ptr) };
+        symbology_normalizer_drop(normalizer);
+    }
+
+    #[cfg(feature = "ffi")]
+    #[rstest]
+    fn test_normalizer_c_api_with_invalid_input() {
+        let venue = Venue::new("BINANCE");
+        let mut normalizer = symbology_normalizer_new();
+        let quotes = CString::new(r#"["USDT", "#).unwrap();
+        let mut symbol = MaybeUninit::<Symbol>::uninit();
+
+        let status = unsafe {
+            symbology_normalizer_register_slash_pair_rule(&mut normalizer, &venue, quotes.as_ptr())
+        };
+        assert_eq!(status, FFI_ERROR);
+        assert!(!normalizer.has_rule(&venue));
+
+        let status = unsafe {
+            symbology_normalizer_normalize(
+                &normalizer,
+                &venue,
+                std::ptr::null(),
+                symbol.as_mut_ptr(),
+            )
+        };
+        assert_eq!(status, FFI_ERROR);
+        assert!(nautilus_core::ffi_error::last_error()
+            .unwrap()
+            .contains("invalid native symbol string"));
+        symbology_normalizer_drop(normalizer);
+    }
+}
@@ -13,20 +13,36 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
 use std::{
     collections::hash_map::DefaultHasher,
     ffi::{c_char, CStr},
-    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
+};
+use std::{
+    fmt::{Debug, Display, Formatter},
+    str::FromStr,
     sync::Arc,
 };
 
-use nautilus_core::{correctness, string::str_to_cstr};
+use nautilus_core::correctness::FAILED;
+#[cfg(feature = "ffi")]
+use nautilus_core::string::str_to_cstr;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "ffi")]
+use crate::identifiers::write_identifier_result;
+use crate::identifiers::{check_max_len, check_valid_string, IdentifierError};
+
+/// The maximum length of an [`InlineTradeId`] value.
+pub const INLINE_TRADE_ID_MAX_LEN: usize = 36;
 
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct TradeId {
     pub value: Box<Arc<String>>,
 }
@@ -44,51 +60,170 @@ impl Display for TradeId {
 }
 
 impl TradeId {
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`TradeId` value")?;
+
+        Ok(Self {
+            value: Box::new(Arc::new(s.to_string())),
+        })
+    }
+
+    #[must_use]
+    pub fn new(s: &str) -> Self {
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
+    }
+}
+
+/// Represents a valid trade match ID stored inline, without any heap allocation.
+///
+/// Trade IDs are created for every trade tick, so this avoids the heap allocation
+/// of [`TradeId`] when ingesting ticks. The value is NUL terminated and padded with
+/// zeros, so it can be borrowed as a C string and compared or hashed bytewise.
+#[repr(C)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+pub struct InlineTradeId {
+    pub value: [u8; INLINE_TRADE_ID_MAX_LEN + 1],
+}
+
+impl InlineTradeId {
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`InlineTradeId` value")?;
+        check_max_len(s, INLINE_TRADE_ID_MAX_LEN, "`InlineTradeId` value")?;
+
+        let mut value = [0; INLINE_TRADE_ID_MAX_LEN + 1];
+        value[..s.len()].copy_from_slice(s.as_bytes());
+        Ok(Self { value })
+    }
+
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`TradeId` value");
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
+    }
 
-        Self {
-            value: Box::new(Arc::new(s.to_string())),
-        }
+    /// Returns the value as a C string reference.
+    #[must_use]
+    pub fn to_cstr(&self) -> &CStr {
+        CStr::from_bytes_until_nul(&self.value)
+            .expect("`InlineTradeId` value was not nul terminated")
+    }
+
+    /// Returns the value as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.to_cstr()
+            .to_str()
+            .expect("`InlineTradeId` value was not UTF-8")
+    }
+}
+
+impl Debug for InlineTradeId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl Display for InlineTradeId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for InlineTradeId {
+    type Err = IdentifierError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::try_new(input)
+    }
+}
+
+impl TryFrom<&str> for InlineTradeId {
+    type Error = IdentifierError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Self::try_new(input)
+    }
+}
+
+impl TryFrom<&TradeId> for InlineTradeId {
+    type Error = IdentifierError;
+
+    fn try_from(trade_id: &TradeId) -> Result<Self, Self::Error> {
+        Self::try_new(&trade_id.value)
+    }
+}
+
+impl From<InlineTradeId> for TradeId {
+    fn from(trade_id: InlineTradeId) -> Self {
+        Self::new(trade_id.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for InlineTradeId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for InlineTradeId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value_str: &str = Deserialize::deserialize(deserializer)?;
+        Self::try_new(value_str).map_err(serde::de::Error::custom)
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from a C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`TradeId`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn trade_id_new(ptr: *const c_char) -> TradeId {
-    TradeId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
+pub unsafe extern "C" fn trade_id_try_new(ptr: *const c_char, out: *mut TradeId) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(TradeId::try_new(&s), out)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn trade_id_clone(trade_id: &TradeId) -> TradeId {
     trade_id.clone()
 }
 
 /// Frees the memory for the given `trade_id` by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn trade_id_drop(trade_id: TradeId) {
     drop(trade_id); // Memory freed here
 }
 
 /// Returns [`TradeId`] as a C string pointer.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn trade_id_to_cstr(trade_id: &TradeId) -> *const c_char {
     str_to_cstr(&trade_id.value)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn trade_id_eq(lhs: &TradeId, rhs: &TradeId) -> u8 {
     u8::from(lhs == rhs)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn trade_id_hash(trade_id: &TradeId) -> u64 {
     let mut h = DefaultHasher::new();
@@ -96,12 +231,62 @@ pub extern "C" fn trade_id_hash(trade_id: &TradeId) -> u64 {
     h.finish()
 }
 
+/// Returns an inline trade ID from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for an [`InlineTradeId`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn inline_trade_id_try_new(
+    ptr: *const c_char,
+    out: *mut InlineTradeId,
+) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(InlineTradeId::try_new(&s), out)
+}
+
+/// Returns a pointer to the [`InlineTradeId`] value as a C string.
+///
+/// The pointer is borrowed from `trade_id` and must not be freed.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn inline_trade_id_to_cstr(trade_id: &InlineTradeId) -> *const c_char {
+    trade_id.to_cstr().as_ptr()
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn inline_trade_id_eq(lhs: &InlineTradeId, rhs: &InlineTradeId) -> u8 {
+    u8::from(lhs == rhs)
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn inline_trade_id_hash(trade_id: &InlineTradeId) -> u64 {
+    let mut h = DefaultHasher::new();
+    trade_id.hash(&mut h);
+    h.finish()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::TradeId;
+    #[cfg(feature = "ffi")]
+    use std::ffi::CStr;
+
+    use rstest::rstest;
+
+    #[cfg(feature = "ffi")]
+    use super::inline_trade_id_to_cstr;
+    use super::{InlineTradeId, TradeId, INLINE_TRADE_ID_MAX_LEN};
+    #[cfg(feature = "ffi")]
     use crate::identifiers::trade_id::trade_id_drop;
 
     #[test]
@@ -119,9 +304,56 @@ mod tests {
         assert_eq!(format!("{trade_id}"), "1234567890");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_trade_id_drop() {
         let id = TradeId::new("123456789");
         trade_id_drop(id); // No panic
     }
+
+    #[cfg(feature = "ffi")]
+    #[rstest]
+    fn test_inline_trade_id() {
+        let trade_id = InlineTradeId::new("1234567890");
+        assert_eq!(trade_id.as_str(), "1234567890");
+        assert_eq!(trade_id.to_string(), "1234567890");
+        assert_eq!(format!("{trade_id:?}"), "\"1234567890\"");
+        assert_eq!(
+            unsafe { CStr::from_ptr(inline_trade_id_to_cstr(&trade_id)) }
+                .to_str()
+                .unwrap(),
+            "1234567890"
+        );
+    }
+
+    #[rstest]
+    fn test_inline_trade_id_equality() {
+        let trade_id1 = InlineTradeId::new("123456789");
+        let trade_id2 = InlineTradeId::new("1234567890");
+        assert_eq!(trade_id1, InlineTradeId::new("123456789"));
+        assert_ne!(trade_id1, trade_id2);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(INLINE_TRADE_ID_MAX_LEN)]
+    fn test_inline_trade_id_max_len(#[case] len: usize) {
+        let value = "1".repeat(len);
+        assert_eq!(InlineTradeId::new(&value).as_str(), value);
+    }
+
+    #[rstest]
+    #[case("", 1)]
+    #[case("1234567890123456789012345678901234567", 5)]
+    fn test_inline_trade_id_invalid(#[case] input: &str, #[case] expected_code: u8) {
+        let result = InlineTradeId::try_new(input);
+        assert_eq!(result.unwrap_err().code(), expected_code);
+    }
+
+    #[rstest]
+    fn test_inline_trade_id_to_trade_id_round_trip() {
+        let trade_id = TradeId::new("123456789");
+        let inline = InlineTradeId::try_from(&trade_id).unwrap();
+        assert_eq!(TradeId::from(inline), trade_id);
+    }
 }
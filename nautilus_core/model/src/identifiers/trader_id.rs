@@ -13,18 +13,28 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
+use std::ffi::{c_char, CStr};
 use std::{
-    ffi::{c_char, CStr},
     fmt::{Debug, Display, Formatter},
     sync::Arc,
 };
 
-use nautilus_core::{correctness, string::str_to_cstr};
+use nautilus_core::correctness::FAILED;
+#[cfg(feature = "ffi")]
+use nautilus_core::string::str_to_cstr;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ffi")]
+use crate::identifiers::write_identifier_result;
+use crate::identifiers::{
+    check_string_contains, check_valid_string, interner::intern, IdentifierError,
+};
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct TraderId {
     pub value: Box<Arc<String>>,
 }
@@ -50,42 +60,60 @@ impl Default for TraderId {
 }
 
 impl TraderId {
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`TraderId` value")?;
+        check_string_contains(s, "-", "`TraderId` value")?;
+
+        Ok(Self {
+            value: Box::new(intern(s)),
+        })
+    }
+
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`TraderId` value");
-        correctness::string_contains(s, "-", "`TraderId` value");
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
+    }
 
-        Self {
-            value: Box::new(Arc::new(s.to_string())),
-        }
+    /// Returns the order ID tag value for this ID (the part following the final hyphen).
+    #[must_use]
+    pub fn get_tag(&self) -> &str {
+        self.value.rsplit('-').next().unwrap_or_default()
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from a C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`TraderId`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn trader_id_new(ptr: *const c_char) -> TraderId {
-    TraderId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
+pub unsafe extern "C" fn trader_id_try_new(ptr: *const c_char, out: *mut TraderId) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(TraderId::try_new(&s), out)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn trader_id_clone(trader_id: &TraderId) -> TraderId {
     trader_id.clone()
 }
 
 /// Frees the memory for the given `trader_id` by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn trader_id_drop(trader_id: TraderId) {
     drop(trader_id); // Memory freed here
 }
 
 /// Returns a [`TraderId`] as a C string pointer.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn trader_id_to_cstr(trader_id: &TraderId) -> *const c_char {
     str_to_cstr(&trader_id.value)
@@ -96,8 +124,13 @@ pub extern "C" fn trader_id_to_cstr(trader_id: &TraderId) -> *const c_char {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "ffi")]
+    use std::{ffi::CString, mem::MaybeUninit};
+
     use super::TraderId;
-    use crate::identifiers::trader_id::trader_id_drop;
+    #[cfg(feature = "ffi")]
+    use crate::identifiers::trader_id::{trader_id_drop, trader_id_try_new};
+    use crate::identifiers::IdentifierError;
 
     #[test]
     fn test_equality() {
@@ -114,9 +147,38 @@ mod tests {
         assert_eq!(format!("{trader_id}"), "TRADER-001");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_trader_id_drop() {
         let id = TraderId::new("TRADER-001");
         trader_id_drop(id); // No panic
     }
+
+    #[test]
+    fn test_try_new_missing_hyphen() {
+        let result = TraderId::try_new("TRADER001");
+        assert!(matches!(
+            result,
+            Err(IdentifierError::MissingPattern { pat: "-", .. })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Condition check failed")]
+    fn test_new_with_empty_string_panics() {
+        let _ = TraderId::new("");
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_trader_id_try_new_c_api() {
+        let valid = CString::new("TRADER-001").unwrap();
+        let invalid = CString::new("TRADER001").unwrap();
+        let mut out = MaybeUninit::<TraderId>::uninit();
+        unsafe {
+            assert_eq!(trader_id_try_new(invalid.as_ptr(), out.as_mut_ptr()), 4);
+            assert_eq!(trader_id_try_new(valid.as_ptr(), out.as_mut_ptr()), 0);
+            assert_eq!(out.assume_init().to_string(), "TRADER-001");
+        }
+    }
 }
@@ -13,20 +13,30 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
 use std::{
     collections::hash_map::DefaultHasher,
     ffi::{c_char, CStr},
-    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
+};
+use std::{
+    fmt::{Debug, Display, Formatter},
     sync::Arc,
 };
 
-use nautilus_core::{correctness, string::str_to_cstr};
+use nautilus_core::correctness::FAILED;
+#[cfg(feature = "ffi")]
+use nautilus_core::string::str_to_cstr;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ffi")]
+use crate::identifiers::write_identifier_result;
+use crate::identifiers::{check_valid_string, interner::intern, IdentifierError};
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct Venue {
     pub value: Box<Arc<String>>,
 }
@@ -52,51 +62,65 @@ impl Default for Venue {
 }
 
 impl Venue {
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`Venue` value")?;
+
+        Ok(Self {
+            value: Box::new(intern(s)),
+        })
+    }
+
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`Venue` value");
-
-        Self {
-            value: Box::new(Arc::new(s.to_string())),
-        }
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from a C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`Venue`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn venue_new(ptr: *const c_char) -> Venue {
-    Venue::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
+pub unsafe extern "C" fn venue_try_new(ptr: *const c_char, out: *mut Venue) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(Venue::try_new(&s), out)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn venue_clone(venue: &Venue) -> Venue {
     venue.clone()
 }
 
 /// Frees the memory for the given `venue` by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn venue_drop(venue: Venue) {
     drop(venue); // Memory freed here
 }
 
 /// Returns a [`Venue`] identifier as a C string pointer.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn venue_to_cstr(venue: &Venue) -> *const c_char {
     str_to_cstr(&venue.value)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn venue_eq(lhs: &Venue, rhs: &Venue) -> u8 {
     u8::from(lhs == rhs)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn venue_hash(venue: &Venue) -> u64 {
     let mut h = DefaultHasher::new();
@@ -110,6 +134,7 @@ pub extern "C" fn venue_hash(venue: &Venue) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::Venue;
+    #[cfg(feature = "ffi")]
     use crate::identifiers::venue::venue_drop;
 
     #[test]
@@ -127,6 +152,7 @@ mod tests {
         assert_eq!(format!("{venue}"), "BINANCE");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_venue_drop() {
         let id = Venue::new("BINANCE");
@@ -13,20 +13,30 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "ffi")]
 use std::{
     collections::hash_map::DefaultHasher,
     ffi::{c_char, CStr},
-    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
+};
+use std::{
+    fmt::{Debug, Display, Formatter},
     sync::Arc,
 };
 
-use nautilus_core::{correctness, string::str_to_cstr};
+use nautilus_core::correctness::FAILED;
+#[cfg(feature = "ffi")]
+use nautilus_core::string::str_to_cstr;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ffi")]
+use crate::identifiers::write_identifier_result;
+use crate::identifiers::{check_valid_string, IdentifierError};
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct VenueOrderId {
     pub value: Box<Arc<String>>,
 }
@@ -44,13 +54,17 @@ impl Display for VenueOrderId {
 }
 
 impl VenueOrderId {
-    #[must_use]
-    pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`VenueOrderId` value");
+    pub fn try_new(s: &str) -> Result<Self, IdentifierError> {
+        check_valid_string(s, "`VenueOrderId` value")?;
 
-        Self {
+        Ok(Self {
             value: Box::new(Arc::new(s.to_string())),
-        }
+        })
+    }
+
+    #[must_use]
+    pub fn new(s: &str) -> Self {
+        Self::try_new(s).unwrap_or_else(|e| panic!("{FAILED} {e}"))
     }
 }
 
@@ -64,38 +78,48 @@ impl Default for VenueOrderId {
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from a C string pointer.
+/// Returns a Nautilus identifier from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise the `IdentifierError` code (in which case
+/// nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`VenueOrderId`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub unsafe extern "C" fn venue_order_id_new(ptr: *const c_char) -> VenueOrderId {
-    VenueOrderId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
+pub unsafe extern "C" fn venue_order_id_try_new(ptr: *const c_char, out: *mut VenueOrderId) -> u8 {
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    write_identifier_result(VenueOrderId::try_new(&s), out)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn venue_order_id_clone(venue_order_id: &VenueOrderId) -> VenueOrderId {
     venue_order_id.clone()
 }
 
 /// Frees the memory for the given `venue_order_id` by dropping.
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn venue_order_id_drop(venue_order_id: VenueOrderId) {
     drop(venue_order_id); // Memory freed here
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn venue_order_id_to_cstr(venue_order_id: &VenueOrderId) -> *const c_char {
     str_to_cstr(&venue_order_id.value)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn venue_order_id_eq(lhs: &VenueOrderId, rhs: &VenueOrderId) -> u8 {
     u8::from(lhs == rhs)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn venue_order_id_hash(venue_order_id: &VenueOrderId) -> u64 {
     let mut h = DefaultHasher::new();
@@ -109,6 +133,7 @@ pub extern "C" fn venue_order_id_hash(venue_order_id: &VenueOrderId) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::VenueOrderId;
+    #[cfg(feature = "ffi")]
     use crate::identifiers::venue_order_id::venue_order_id_drop;
 
     #[test]
@@ -127,6 +152,7 @@ mod tests {
         assert_eq!(format!("{id}"), "001");
     }
 
+    #[cfg(feature = "ffi")]
     #[test]
     fn test_venue_order_id() {
         let id = VenueOrderId::new("001");
@@ -0,0 +1,224 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use rust_decimal::Decimal;
+
+use super::{BaseInstrument, Instrument};
+use crate::{
+    enums::{AssetClass, AssetType},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+/// Represents a crypto perpetual futures contract instrument (a.k.a. perpetual swap).
+#[derive(Clone, Debug)]
+pub struct CryptoPerpetual {
+    pub(super) core: BaseInstrument,
+    pub settlement_currency: Currency,
+}
+
+impl Deref for CryptoPerpetual {
+    type Target = BaseInstrument;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for CryptoPerpetual {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl From<CryptoPerpetual> for BaseInstrument {
+    fn from(perpetual: CryptoPerpetual) -> Self {
+        perpetual.core
+    }
+}
+
+impl Instrument for CryptoPerpetual {
+    fn base(&self) -> &BaseInstrument {
+        &self.core
+    }
+}
+
+impl CryptoPerpetual {
+    /// # Panics
+    ///
+    /// If the specification is invalid (see [`BaseInstrument::validate`]).
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        native_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        settlement_currency: Currency,
+        is_inverse: bool,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+    ) -> Self {
+        Self::try_new(
+            id,
+            native_symbol,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            is_inverse,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+        )
+        .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Returns a new [`CryptoPerpetual`], or an error if the specification is invalid (see
+    /// [`BaseInstrument::check_valid`]).
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        id: InstrumentId,
+        native_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        settlement_currency: Currency,
+        is_inverse: bool,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+    ) -> Result<Self, String> {
+        let core = BaseInstrument {
+            id,
+            native_symbol,
+            asset_class: AssetClass::Cryptocurrency,
+            asset_type: AssetType::Swap,
+            quote_currency,
+            base_currency: Some(base_currency),
+            cost_currency: settlement_currency.clone(),
+            is_inverse,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            multiplier: Quantity::from(1),
+            lot_size: Some(Quantity::try_new(1.0, size_precision)?),
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+        };
+        core.check_valid()?;
+
+        Ok(Self {
+            core,
+            settlement_currency,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        currencies::{BTC, USD},
+        types::money::Money,
+    };
+
+    pub fn xbtusd_bitmex() -> CryptoPerpetual {
+        CryptoPerpetual::new(
+            InstrumentId::from_str("BTCUSD-PERP.BITMEX").unwrap(),
+            Symbol::new("XBTUSD"),
+            BTC.clone(),
+            USD.clone(),
+            BTC.clone(),
+            true,
+            1,
+            0,
+            Price::from("0.5"),
+            Quantity::from("1"),
+            None,
+            None,
+            Some(Price::from("1000000.0")),
+            Some(Price::from("0.5")),
+            Decimal::new(1, 2),
+            Decimal::new(35, 4),
+            Decimal::new(-25, 5),
+            Decimal::new(75, 5),
+        )
+    }
+
+    #[test]
+    fn test_crypto_perpetual_new() {
+        let perpetual = xbtusd_bitmex();
+
+        assert_eq!(perpetual.asset_class, AssetClass::Cryptocurrency);
+        assert_eq!(perpetual.asset_type, AssetType::Swap);
+        assert!(perpetual.is_inverse);
+        assert_eq!(perpetual.cost_currency, BTC.clone());
+        assert_eq!(perpetual.settlement_currency(), BTC.clone());
+    }
+
+    #[test]
+    fn test_crypto_perpetual_inverse_notional_value() {
+        let perpetual = xbtusd_bitmex();
+
+        let notional = perpetual.calculate_notional_value(
+            Quantity::from("100000"),
+            Price::from("50000.0"),
+            false,
+        );
+
+        assert_eq!(notional, Money::new(2.0, BTC.clone()));
+    }
+}
@@ -0,0 +1,167 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::ffi_error::{write_result, FFI_ERROR};
+
+use super::{crypto_perpetual::CryptoPerpetual, try_decimals_from_f64, Instrument};
+use crate::{
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`CryptoPerpetual`].
+///
+/// This struct wraps `CryptoPerpetual` in a way that makes it compatible with C function
+/// calls, enabling interaction with `CryptoPerpetual` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `CryptoPerpetual_API` to be
+/// dereferenced to `CryptoPerpetual`, providing access to `CryptoPerpetual`'s methods without
+/// having to manually access the underlying `CryptoPerpetual` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct CryptoPerpetual_API(Box<CryptoPerpetual>);
+
+impl Deref for CryptoPerpetual_API {
+    type Target = CryptoPerpetual;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for CryptoPerpetual_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Writes a new [`CryptoPerpetual`] to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `max_quantity`, `min_quantity`, `max_price` and `min_price` are each either
+///   null or a valid pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`CryptoPerpetual_API`].
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn crypto_perpetual_new(
+    id: InstrumentId,
+    native_symbol: Symbol,
+    base_currency: Currency,
+    quote_currency: Currency,
+    settlement_currency: Currency,
+    is_inverse: u8,
+    price_precision: u8,
+    size_precision: u8,
+    price_increment: Price,
+    size_increment: Quantity,
+    max_quantity: *const Quantity,
+    min_quantity: *const Quantity,
+    max_price: *const Price,
+    min_price: *const Price,
+    margin_init: f64,
+    margin_maint: f64,
+    maker_fee: f64,
+    taker_fee: f64,
+    out: *mut CryptoPerpetual_API,
+) -> u8 {
+    let result = try_decimals_from_f64([margin_init, margin_maint, maker_fee, taker_fee])
+        .and_then(|[margin_init, margin_maint, maker_fee, taker_fee]| {
+            CryptoPerpetual::try_new(
+                id,
+                native_symbol,
+                base_currency,
+                quote_currency,
+                settlement_currency,
+                is_inverse != 0,
+                price_precision,
+                size_precision,
+                price_increment,
+                size_increment,
+                max_quantity.as_ref().copied(),
+                min_quantity.as_ref().copied(),
+                max_price.as_ref().copied(),
+                min_price.as_ref().copied(),
+                margin_init,
+                margin_maint,
+                maker_fee,
+                taker_fee,
+            )
+        })
+        .map(|perpetual| CryptoPerpetual_API(Box::new(perpetual)));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[no_mangle]
+pub extern "C" fn crypto_perpetual_drop(instrument: CryptoPerpetual_API) {
+    drop(instrument); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn crypto_perpetual_id(instrument: &CryptoPerpetual_API) -> InstrumentId {
+    instrument.id.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn crypto_perpetual_price_precision(instrument: &CryptoPerpetual_API) -> u8 {
+    instrument.price_precision
+}
+
+#[no_mangle]
+pub extern "C" fn crypto_perpetual_size_precision(instrument: &CryptoPerpetual_API) -> u8 {
+    instrument.size_precision
+}
+
+#[no_mangle]
+pub extern "C" fn crypto_perpetual_make_price(
+    instrument: &CryptoPerpetual_API,
+    value: f64,
+) -> Price {
+    instrument.make_price(value)
+}
+
+#[no_mangle]
+pub extern "C" fn crypto_perpetual_make_qty(
+    instrument: &CryptoPerpetual_API,
+    value: f64,
+) -> Quantity {
+    instrument.make_qty(value)
+}
+
+#[no_mangle]
+pub extern "C" fn crypto_perpetual_notional_value(
+    instrument: &CryptoPerpetual_API,
+    quantity: Quantity,
+    price: Price,
+) -> Money {
+    instrument.calculate_notional_value(quantity, price, false)
+}
+
+#[no_mangle]
+pub extern "C" fn crypto_perpetual_is_inverse(instrument: &CryptoPerpetual_API) -> u8 {
+    u8::from(instrument.is_inverse)
+}
+
+#[no_mangle]
+pub extern "C" fn crypto_perpetual_settlement_currency(
+    instrument: &CryptoPerpetual_API,
+) -> Currency {
+    instrument.settlement_currency.clone()
+}
@@ -0,0 +1,258 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use rust_decimal::Decimal;
+
+use super::{BaseInstrument, Instrument};
+use crate::{
+    enums::{AssetClass, AssetType, CurrencyType},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+/// Represents a generic currency pair instrument in a spot/cash market.
+#[derive(Clone, Debug)]
+pub struct CurrencyPair {
+    pub(super) core: BaseInstrument,
+}
+
+impl Deref for CurrencyPair {
+    type Target = BaseInstrument;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for CurrencyPair {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl From<CurrencyPair> for BaseInstrument {
+    fn from(pair: CurrencyPair) -> Self {
+        pair.core
+    }
+}
+
+impl Instrument for CurrencyPair {
+    fn base(&self) -> &BaseInstrument {
+        &self.core
+    }
+}
+
+impl CurrencyPair {
+    /// Creates a new currency pair, with an asset class of `Cryptocurrency` when both
+    /// currencies are crypto, otherwise `FX`.
+    ///
+    /// # Panics
+    ///
+    /// If the specification is invalid (see [`BaseInstrument::validate`]).
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        native_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+    ) -> Self {
+        Self::try_new(
+            id,
+            native_symbol,
+            base_currency,
+            quote_currency,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+        )
+        .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Returns a new [`CurrencyPair`], or an error if the specification is invalid (see
+    /// [`BaseInstrument::check_valid`]).
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        id: InstrumentId,
+        native_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+    ) -> Result<Self, String> {
+        let asset_class = if base_currency.currency_type == CurrencyType::Crypto
+            && quote_currency.currency_type == CurrencyType::Crypto
+        {
+            AssetClass::Cryptocurrency
+        } else {
+            AssetClass::FX
+        };
+
+        let core = BaseInstrument {
+            id,
+            native_symbol,
+            asset_class,
+            asset_type: AssetType::Spot,
+            quote_currency: quote_currency.clone(),
+            base_currency: Some(base_currency),
+            cost_currency: quote_currency,
+            is_inverse: false,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            multiplier: Quantity::from(1),
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+        };
+        core.check_valid()?;
+
+        Ok(Self { core })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::currencies::{BTC, USD, USDT};
+
+    pub fn btcusdt_binance() -> CurrencyPair {
+        CurrencyPair::new(
+            InstrumentId::from_str("BTCUSDT.BINANCE").unwrap(),
+            Symbol::new("BTCUSDT"),
+            BTC.clone(),
+            USDT.clone(),
+            2,
+            6,
+            Price::from("0.01"),
+            Quantity::from("0.000001"),
+            None,
+            Some(Quantity::from("9000")),
+            Some(Quantity::from("0.00001")),
+            Some(Price::from("1000000.00")),
+            Some(Price::from("0.01")),
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::new(1, 3),
+            Decimal::new(1, 3),
+        )
+    }
+
+    #[test]
+    fn test_currency_pair_crypto_asset_class() {
+        let pair = btcusdt_binance();
+
+        assert_eq!(pair.asset_class, AssetClass::Cryptocurrency);
+        assert_eq!(pair.base_currency, Some(BTC.clone()));
+        assert_eq!(pair.make_qty(0.123_456_78), Quantity::from("0.123457"));
+    }
+
+    #[test]
+    fn test_currency_pair_fx_asset_class() {
+        let pair = CurrencyPair::new(
+            InstrumentId::from_str("BTC/USD.SIM").unwrap(),
+            Symbol::new("BTC/USD"),
+            BTC.clone(),
+            USD.clone(),
+            2,
+            6,
+            Price::from("0.01"),
+            Quantity::from("0.000001"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+        );
+
+        assert_eq!(pair.asset_class, AssetClass::FX);
+    }
+
+    #[test]
+    #[should_panic(expected = "`size_precision` did not match `size_increment` precision")]
+    fn test_currency_pair_new_when_size_precision_mismatch() {
+        let _ = CurrencyPair::new(
+            InstrumentId::from_str("BTCUSDT.BINANCE").unwrap(),
+            Symbol::new("BTCUSDT"),
+            BTC.clone(),
+            USDT.clone(),
+            2,
+            5,
+            Price::from("0.01"),
+            Quantity::from("0.000001"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+        );
+    }
+}
@@ -0,0 +1,196 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::ffi_error::{write_result, FFI_ERROR};
+
+use super::{currency_pair::CurrencyPair, try_decimals_from_f64, Instrument};
+use crate::{
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`CurrencyPair`].
+///
+/// This struct wraps `CurrencyPair` in a way that makes it compatible with C function
+/// calls, enabling interaction with `CurrencyPair` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `CurrencyPair_API` to be
+/// dereferenced to `CurrencyPair`, providing access to `CurrencyPair`'s methods without
+/// having to manually access the underlying `CurrencyPair` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct CurrencyPair_API(Box<CurrencyPair>);
+
+impl Deref for CurrencyPair_API {
+    type Target = CurrencyPair;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for CurrencyPair_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Writes a new [`CurrencyPair`] to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `lot_size`, `max_quantity`, `min_quantity`, `max_price` and `min_price` are
+///   each either null or a valid pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`CurrencyPair_API`].
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn currency_pair_new(
+    id: InstrumentId,
+    native_symbol: Symbol,
+    base_currency: Currency,
+    quote_currency: Currency,
+    price_precision: u8,
+    size_precision: u8,
+    price_increment: Price,
+    size_increment: Quantity,
+    lot_size: *const Quantity,
+    max_quantity: *const Quantity,
+    min_quantity: *const Quantity,
+    max_price: *const Price,
+    min_price: *const Price,
+    margin_init: f64,
+    margin_maint: f64,
+    maker_fee: f64,
+    taker_fee: f64,
+    out: *mut CurrencyPair_API,
+) -> u8 {
+    let result = try_decimals_from_f64([margin_init, margin_maint, maker_fee, taker_fee])
+        .and_then(|[margin_init, margin_maint, maker_fee, taker_fee]| {
+            CurrencyPair::try_new(
+                id,
+                native_symbol,
+                base_currency,
+                quote_currency,
+                price_precision,
+                size_precision,
+                price_increment,
+                size_increment,
+                lot_size.as_ref().copied(),
+                max_quantity.as_ref().copied(),
+                min_quantity.as_ref().copied(),
+                max_price.as_ref().copied(),
+                min_price.as_ref().copied(),
+                margin_init,
+                margin_maint,
+                maker_fee,
+                taker_fee,
+            )
+        })
+        .map(|pair| CurrencyPair_API(Box::new(pair)));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[no_mangle]
+pub extern "C" fn currency_pair_drop(instrument: CurrencyPair_API) {
+    drop(instrument); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn currency_pair_id(instrument: &CurrencyPair_API) -> InstrumentId {
+    instrument.id.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn currency_pair_price_precision(instrument: &CurrencyPair_API) -> u8 {
+    instrument.price_precision
+}
+
+#[no_mangle]
+pub extern "C" fn currency_pair_size_precision(instrument: &CurrencyPair_API) -> u8 {
+    instrument.size_precision
+}
+
+#[no_mangle]
+pub extern "C" fn currency_pair_make_price(instrument: &CurrencyPair_API, value: f64) -> Price {
+    instrument.make_price(value)
+}
+
+#[no_mangle]
+pub extern "C" fn currency_pair_make_qty(instrument: &CurrencyPair_API, value: f64) -> Quantity {
+    instrument.make_qty(value)
+}
+
+#[no_mangle]
+pub extern "C" fn currency_pair_notional_value(
+    instrument: &CurrencyPair_API,
+    quantity: Quantity,
+    price: Price,
+) -> Money {
+    instrument.calculate_notional_value(quantity, price, false)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{mem::MaybeUninit, ptr, str::FromStr};
+
+    use super::*;
+    use crate::currencies::{BTC, USDT};
+
+    #[test]
+    fn test_currency_pair_api_new() {
+        let max_quantity = Quantity::from("9000");
+        let mut pair = MaybeUninit::<CurrencyPair_API>::uninit();
+        let status = unsafe {
+            currency_pair_new(
+                InstrumentId::from_str("BTCUSDT.BINANCE").unwrap(),
+                Symbol::new("BTCUSDT"),
+                BTC.clone(),
+                USDT.clone(),
+                2,
+                6,
+                Price::from("0.01"),
+                Quantity::from("0.000001"),
+                ptr::null(),
+                &max_quantity,
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                0.0,
+                0.0,
+                0.001,
+                0.001,
+                pair.as_mut_ptr(),
+            )
+        };
+        assert_eq!(status, 0);
+        let pair = unsafe { pair.assume_init() };
+
+        assert_eq!(pair.max_quantity, Some(max_quantity));
+        assert_eq!(pair.min_quantity, None);
+        assert_eq!(
+            currency_pair_make_qty(&pair, 1.000_000_4),
+            Quantity::from("1.000000")
+        );
+        currency_pair_drop(pair);
+    }
+}
@@ -0,0 +1,182 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use rust_decimal::Decimal;
+
+use super::{BaseInstrument, Instrument};
+use crate::{
+    enums::{AssetClass, AssetType},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+/// Represents a generic equity (stock) instrument.
+#[derive(Clone, Debug)]
+pub struct Equity {
+    pub(super) core: BaseInstrument,
+    pub isin: Option<String>,
+}
+
+impl Deref for Equity {
+    type Target = BaseInstrument;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for Equity {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl From<Equity> for BaseInstrument {
+    fn from(equity: Equity) -> Self {
+        equity.core
+    }
+}
+
+impl Instrument for Equity {
+    fn base(&self) -> &BaseInstrument {
+        &self.core
+    }
+}
+
+impl Equity {
+    /// # Panics
+    ///
+    /// If the specification is invalid (see [`BaseInstrument::validate`]).
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        native_symbol: Symbol,
+        currency: Currency,
+        price_precision: u8,
+        price_increment: Price,
+        multiplier: Quantity,
+        lot_size: Option<Quantity>,
+        isin: Option<String>,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+    ) -> Self {
+        Self::try_new(
+            id,
+            native_symbol,
+            currency,
+            price_precision,
+            price_increment,
+            multiplier,
+            lot_size,
+            isin,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+        )
+        .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Returns a new [`Equity`], or an error if the specification is invalid (see
+    /// [`BaseInstrument::check_valid`]).
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        id: InstrumentId,
+        native_symbol: Symbol,
+        currency: Currency,
+        price_precision: u8,
+        price_increment: Price,
+        multiplier: Quantity,
+        lot_size: Option<Quantity>,
+        isin: Option<String>,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+    ) -> Result<Self, String> {
+        let core = BaseInstrument {
+            id,
+            native_symbol,
+            asset_class: AssetClass::Equity,
+            asset_type: AssetType::Spot,
+            quote_currency: currency.clone(),
+            base_currency: None,
+            cost_currency: currency,
+            is_inverse: false,
+            price_precision,
+            size_precision: 0,
+            price_increment,
+            size_increment: Quantity::from(1),
+            multiplier,
+            lot_size,
+            max_quantity: None,
+            min_quantity: Some(Quantity::from(1)),
+            max_price: None,
+            min_price: None,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+        };
+        core.check_valid()?;
+
+        Ok(Self { core, isin })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::currencies::USD;
+
+    pub fn aapl_nasdaq() -> Equity {
+        Equity::new(
+            InstrumentId::from_str("AAPL.NASDAQ").unwrap(),
+            Symbol::new("AAPL"),
+            USD.clone(),
+            2,
+            Price::from("0.01"),
+            Quantity::from(1),
+            Some(Quantity::from(100)),
+            Some(String::from("US0378331005")),
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+        )
+    }
+
+    #[test]
+    fn test_equity_new() {
+        let equity = aapl_nasdaq();
+
+        assert_eq!(equity.asset_class, AssetClass::Equity);
+        assert_eq!(equity.asset_type, AssetType::Spot);
+        assert_eq!(equity.cost_currency, USD.clone());
+        assert_eq!(equity.size_precision, 0);
+        assert_eq!(equity.isin.as_deref(), Some("US0378331005"));
+        assert_eq!(equity.make_price(187.456), Price::from("187.46"));
+    }
+}
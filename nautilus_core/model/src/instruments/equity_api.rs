@@ -0,0 +1,203 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::ffi_error::{try_optional_cstr_to_str, write_result, FFI_ERROR};
+
+use super::{equity::Equity, try_decimals_from_f64, Instrument};
+use crate::{
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Equity`].
+///
+/// This struct wraps `Equity` in a way that makes it compatible with C function
+/// calls, enabling interaction with `Equity` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `Equity_API` to be
+/// dereferenced to `Equity`, providing access to `Equity`'s methods without
+/// having to manually access the underlying `Equity` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct Equity_API(Box<Equity>);
+
+impl Deref for Equity_API {
+    type Target = Equity;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Equity_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Writes a new [`Equity`] to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `lot_size` is either null or a valid `Quantity` pointer.
+/// - Assumes `isin_ptr` is either null or a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for an [`Equity_API`].
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn equity_new(
+    id: InstrumentId,
+    native_symbol: Symbol,
+    currency: Currency,
+    price_precision: u8,
+    price_increment: Price,
+    multiplier: Quantity,
+    lot_size: *const Quantity,
+    isin_ptr: *const c_char,
+    margin_init: f64,
+    margin_maint: f64,
+    maker_fee: f64,
+    taker_fee: f64,
+    out: *mut Equity_API,
+) -> u8 {
+    let result = try_decimals_from_f64([margin_init, margin_maint, maker_fee, taker_fee])
+        .and_then(|[margin_init, margin_maint, maker_fee, taker_fee]| {
+            let isin = try_optional_cstr_to_str(isin_ptr, "ISIN")?;
+            Equity::try_new(
+                id,
+                native_symbol,
+                currency,
+                price_precision,
+                price_increment,
+                multiplier,
+                lot_size.as_ref().copied(),
+                isin.map(str::to_string),
+                margin_init,
+                margin_maint,
+                maker_fee,
+                taker_fee,
+            )
+        })
+        .map(|equity| Equity_API(Box::new(equity)));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[no_mangle]
+pub extern "C" fn equity_drop(instrument: Equity_API) {
+    drop(instrument); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn equity_id(instrument: &Equity_API) -> InstrumentId {
+    instrument.id.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn equity_price_precision(instrument: &Equity_API) -> u8 {
+    instrument.price_precision
+}
+
+#[no_mangle]
+pub extern "C" fn equity_size_precision(instrument: &Equity_API) -> u8 {
+    instrument.size_precision
+}
+
+#[no_mangle]
+pub extern "C" fn equity_make_price(instrument: &Equity_API, value: f64) -> Price {
+    instrument.make_price(value)
+}
+
+#[no_mangle]
+pub extern "C" fn equity_make_qty(instrument: &Equity_API, value: f64) -> Quantity {
+    instrument.make_qty(value)
+}
+
+#[no_mangle]
+pub extern "C" fn equity_notional_value(
+    instrument: &Equity_API,
+    quantity: Quantity,
+    price: Price,
+) -> Money {
+    instrument.calculate_notional_value(quantity, price, false)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{mem::MaybeUninit, ptr, str::FromStr};
+
+    use nautilus_core::ffi_error::last_error;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::currencies::USD;
+
+    unsafe fn new_equity(price_precision: u8, margin_init: f64, out: *mut Equity_API) -> u8 {
+        equity_new(
+            InstrumentId::from_str("AAPL.NASDAQ").unwrap(),
+            Symbol::new("AAPL"),
+            USD.clone(),
+            price_precision,
+            Price::from("0.01"),
+            Quantity::from(1),
+            ptr::null(),
+            ptr::null(),
+            margin_init,
+            0.0,
+            0.0,
+            0.0,
+            out,
+        )
+    }
+
+    #[test]
+    fn test_equity_api_new() {
+        let mut equity = MaybeUninit::<Equity_API>::uninit();
+        assert_eq!(unsafe { new_equity(2, 0.0, equity.as_mut_ptr()) }, 0);
+        let equity = unsafe { equity.assume_init() };
+
+        assert_eq!(equity_price_precision(&equity), 2);
+        assert_eq!(equity_make_price(&equity, 1.234), Price::from("1.23"));
+        assert!(equity.lot_size.is_none());
+        assert!(equity.isin.is_none());
+        equity_drop(equity);
+    }
+
+    #[rstest]
+    #[case(3, 0.0, "`price_precision` did not match `price_increment` precision")]
+    #[case(2, f64::NAN, "invalid decimal value NaN")]
+    #[case(2, -0.1, "`margin_init` was negative")]
+    fn test_equity_api_new_with_invalid_input(
+        #[case] price_precision: u8,
+        #[case] margin_init: f64,
+        #[case] expected: &str,
+    ) {
+        let mut equity = MaybeUninit::<Equity_API>::uninit();
+
+        let status = unsafe { new_equity(price_precision, margin_init, equity.as_mut_ptr()) };
+
+        assert_eq!(status, FFI_ERROR);
+        assert_eq!(last_error().unwrap(), expected);
+    }
+}
@@ -0,0 +1,235 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::time::UnixNanos;
+use rust_decimal::Decimal;
+
+use super::{BaseInstrument, Instrument};
+use crate::{
+    enums::{AssetClass, AssetType},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+/// Represents a generic deliverable futures contract instrument.
+#[derive(Clone, Debug)]
+pub struct FuturesContract {
+    pub(super) core: BaseInstrument,
+    pub underlying: String,
+    pub activation_ns: UnixNanos,
+    pub expiration_ns: UnixNanos,
+}
+
+impl Deref for FuturesContract {
+    type Target = BaseInstrument;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for FuturesContract {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl From<FuturesContract> for BaseInstrument {
+    fn from(contract: FuturesContract) -> Self {
+        contract.core
+    }
+}
+
+impl Instrument for FuturesContract {
+    fn base(&self) -> &BaseInstrument {
+        &self.core
+    }
+}
+
+impl FuturesContract {
+    /// # Panics
+    ///
+    /// If `expiration_ns` is not after `activation_ns`, or the specification is
+    /// otherwise invalid (see [`BaseInstrument::validate`]).
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        native_symbol: Symbol,
+        asset_class: AssetClass,
+        currency: Currency,
+        price_precision: u8,
+        price_increment: Price,
+        multiplier: Quantity,
+        lot_size: Option<Quantity>,
+        underlying: String,
+        activation_ns: UnixNanos,
+        expiration_ns: UnixNanos,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+    ) -> Self {
+        Self::try_new(
+            id,
+            native_symbol,
+            asset_class,
+            currency,
+            price_precision,
+            price_increment,
+            multiplier,
+            lot_size,
+            underlying,
+            activation_ns,
+            expiration_ns,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+        )
+        .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Returns a new [`FuturesContract`], or an error if `expiration_ns` is not after
+    /// `activation_ns`, or the specification is otherwise invalid (see
+    /// [`BaseInstrument::check_valid`]).
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        id: InstrumentId,
+        native_symbol: Symbol,
+        asset_class: AssetClass,
+        currency: Currency,
+        price_precision: u8,
+        price_increment: Price,
+        multiplier: Quantity,
+        lot_size: Option<Quantity>,
+        underlying: String,
+        activation_ns: UnixNanos,
+        expiration_ns: UnixNanos,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+    ) -> Result<Self, String> {
+        if expiration_ns <= activation_ns {
+            return Err("`expiration_ns` was not after `activation_ns`".to_string());
+        }
+
+        let core = BaseInstrument {
+            id,
+            native_symbol,
+            asset_class,
+            asset_type: AssetType::Future,
+            quote_currency: currency.clone(),
+            base_currency: None,
+            cost_currency: currency,
+            is_inverse: false,
+            price_precision,
+            size_precision: 0,
+            price_increment,
+            size_increment: Quantity::from(1),
+            multiplier,
+            lot_size,
+            max_quantity: None,
+            min_quantity: Some(Quantity::from(1)),
+            max_price: None,
+            min_price: None,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+        };
+        core.check_valid()?;
+
+        Ok(Self {
+            core,
+            underlying,
+            activation_ns,
+            expiration_ns,
+        })
+    }
+
+    /// Returns whether the contract is tradable at the given UNIX timestamp (nanoseconds).
+    #[must_use]
+    pub fn is_active(&self, ts: UnixNanos) -> bool {
+        self.activation_ns <= ts && ts < self.expiration_ns
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::currencies::USD;
+
+    pub fn esz3_cme() -> FuturesContract {
+        FuturesContract::new(
+            InstrumentId::from_str("ESZ3.CME").unwrap(),
+            Symbol::new("ESZ3"),
+            AssetClass::Index,
+            USD.clone(),
+            2,
+            Price::from("0.25"),
+            Quantity::from(50),
+            Some(Quantity::from(1)),
+            String::from("ES"),
+            1_686_787_200_000_000_000,
+            1_702_598_400_000_000_000,
+            Decimal::new(5, 2),
+            Decimal::new(4, 2),
+            Decimal::ZERO,
+            Decimal::ZERO,
+        )
+    }
+
+    #[test]
+    fn test_futures_contract_new() {
+        let contract = esz3_cme();
+
+        assert_eq!(contract.asset_type, AssetType::Future);
+        assert_eq!(contract.underlying, "ES");
+        assert_eq!(contract.multiplier, Quantity::from(50));
+        assert!(contract.is_active(1_700_000_000_000_000_000));
+        assert!(!contract.is_active(1_702_598_400_000_000_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "`expiration_ns` was not after `activation_ns`")]
+    fn test_futures_contract_new_when_expired_before_activation() {
+        let _ = FuturesContract::new(
+            InstrumentId::from_str("ESZ3.CME").unwrap(),
+            Symbol::new("ESZ3"),
+            AssetClass::Index,
+            USD.clone(),
+            2,
+            Price::from("0.25"),
+            Quantity::from(50),
+            None,
+            String::from("ES"),
+            2,
+            1,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+        );
+    }
+}
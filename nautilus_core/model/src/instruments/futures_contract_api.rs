@@ -0,0 +1,167 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::{
+    ffi_error::{try_cstr_to_str, write_result, FFI_ERROR},
+    time::UnixNanos,
+};
+
+use super::{futures_contract::FuturesContract, try_decimals_from_f64, Instrument};
+use crate::{
+    enums::AssetClass,
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`FuturesContract`].
+///
+/// This struct wraps `FuturesContract` in a way that makes it compatible with C function
+/// calls, enabling interaction with `FuturesContract` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `FuturesContract_API` to be
+/// dereferenced to `FuturesContract`, providing access to `FuturesContract`'s methods without
+/// having to manually access the underlying `FuturesContract` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct FuturesContract_API(Box<FuturesContract>);
+
+impl Deref for FuturesContract_API {
+    type Target = FuturesContract;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for FuturesContract_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Writes a new [`FuturesContract`] to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `lot_size` is either null or a valid `Quantity` pointer.
+/// - Assumes `underlying_ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`FuturesContract_API`].
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn futures_contract_new(
+    id: InstrumentId,
+    native_symbol: Symbol,
+    asset_class: AssetClass,
+    currency: Currency,
+    price_precision: u8,
+    price_increment: Price,
+    multiplier: Quantity,
+    lot_size: *const Quantity,
+    underlying_ptr: *const c_char,
+    activation_ns: UnixNanos,
+    expiration_ns: UnixNanos,
+    margin_init: f64,
+    margin_maint: f64,
+    maker_fee: f64,
+    taker_fee: f64,
+    out: *mut FuturesContract_API,
+) -> u8 {
+    let result = try_decimals_from_f64([margin_init, margin_maint, maker_fee, taker_fee])
+        .and_then(|[margin_init, margin_maint, maker_fee, taker_fee]| {
+            let underlying = try_cstr_to_str(underlying_ptr, "underlying")?;
+            FuturesContract::try_new(
+                id,
+                native_symbol,
+                asset_class,
+                currency,
+                price_precision,
+                price_increment,
+                multiplier,
+                lot_size.as_ref().copied(),
+                underlying.to_string(),
+                activation_ns,
+                expiration_ns,
+                margin_init,
+                margin_maint,
+                maker_fee,
+                taker_fee,
+            )
+        })
+        .map(|contract| FuturesContract_API(Box::new(contract)));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[no_mangle]
+pub extern "C" fn futures_contract_drop(instrument: FuturesContract_API) {
+    drop(instrument); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn futures_contract_id(instrument: &FuturesContract_API) -> InstrumentId {
+    instrument.id.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn futures_contract_price_precision(instrument: &FuturesContract_API) -> u8 {
+    instrument.price_precision
+}
+
+#[no_mangle]
+pub extern "C" fn futures_contract_size_precision(instrument: &FuturesContract_API) -> u8 {
+    instrument.size_precision
+}
+
+#[no_mangle]
+pub extern "C" fn futures_contract_make_price(
+    instrument: &FuturesContract_API,
+    value: f64,
+) -> Price {
+    instrument.make_price(value)
+}
+
+#[no_mangle]
+pub extern "C" fn futures_contract_make_qty(
+    instrument: &FuturesContract_API,
+    value: f64,
+) -> Quantity {
+    instrument.make_qty(value)
+}
+
+#[no_mangle]
+pub extern "C" fn futures_contract_notional_value(
+    instrument: &FuturesContract_API,
+    quantity: Quantity,
+    price: Price,
+) -> Money {
+    instrument.calculate_notional_value(quantity, price, false)
+}
+
+#[no_mangle]
+pub extern "C" fn futures_contract_activation_ns(instrument: &FuturesContract_API) -> UnixNanos {
+    instrument.activation_ns
+}
+
+#[no_mangle]
+pub extern "C" fn futures_contract_expiration_ns(instrument: &FuturesContract_API) -> UnixNanos {
+    instrument.expiration_ns
+}
@@ -13,18 +13,41 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod crypto_perpetual;
+#[cfg(feature = "ffi")]
+pub mod crypto_perpetual_api;
+pub mod currency_pair;
+#[cfg(feature = "ffi")]
+pub mod currency_pair_api;
+pub mod equity;
+#[cfg(feature = "ffi")]
+pub mod equity_api;
+pub mod futures_contract;
+#[cfg(feature = "ffi")]
+pub mod futures_contract_api;
+pub mod options_contract;
+#[cfg(feature = "ffi")]
+pub mod options_contract_api;
 mod synthetic;
+#[cfg(feature = "ffi")]
 mod synthetic_api;
 
-use rust_decimal::Decimal;
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
 
 use crate::{
-    enums::{AssetClass, AssetType},
+    enums::{AssetClass, AssetType, LiquiditySide},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol},
-    types::{currency::Currency, price::Price, quantity::Quantity},
+    types::{
+        currency::Currency, fixed::RoundingMode, money::Money, price::Price, quantity::Quantity,
+    },
 };
 
-pub struct Instrument {
+/// The specification shared by all tradable instruments.
+#[derive(Clone, Debug)]
+pub struct BaseInstrument {
     pub id: InstrumentId,
     pub native_symbol: Symbol,
     pub asset_class: AssetClass,
@@ -48,3 +71,399 @@ pub struct Instrument {
     pub maker_fee: Decimal,
     pub taker_fee: Decimal,
 }
+
+impl BaseInstrument {
+    /// Checks the specification is internally consistent.
+    ///
+    /// # Panics
+    ///
+    /// If the increments do not match the precisions, the multiplier is not positive,
+    /// or any margin or limit is invalid.
+    pub(crate) fn validate(&self) {
+        if let Err(e) = self.check_valid() {
+            panic!("{e}");
+        }
+    }
+
+    /// Checks the specification is internally consistent, returning an error naming the
+    /// first inconsistency (see [`BaseInstrument::validate`]).
+    pub(crate) fn check_valid(&self) -> Result<(), String> {
+        if self.price_precision != self.price_increment.precision {
+            return Err("`price_precision` did not match `price_increment` precision".to_string());
+        }
+        if self.size_precision != self.size_increment.precision {
+            return Err("`size_precision` did not match `size_increment` precision".to_string());
+        }
+        if self.price_increment.raw <= 0 {
+            return Err("`price_increment` was not positive".to_string());
+        }
+        if self.size_increment.raw == 0 {
+            return Err("`size_increment` was not positive".to_string());
+        }
+        if self.multiplier.raw == 0 {
+            return Err("`multiplier` was not positive".to_string());
+        }
+        if self.margin_init.is_sign_negative() {
+            return Err("`margin_init` was negative".to_string());
+        }
+        if self.margin_maint.is_sign_negative() {
+            return Err("`margin_maint` was negative".to_string());
+        }
+        if let (Some(max_quantity), Some(min_quantity)) = (self.max_quantity, self.min_quantity) {
+            if min_quantity > max_quantity {
+                return Err("`min_quantity` was greater than `max_quantity`".to_string());
+            }
+        }
+        if let (Some(max_price), Some(min_price)) = (self.max_price, self.min_price) {
+            if min_price > max_price {
+                return Err("`min_price` was greater than `max_price`".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Provides the specification and calculations common to all instrument kinds, so that
+/// risk and matching code can be written generically over them.
+pub trait Instrument {
+    /// Returns the underlying specification shared by all instruments.
+    fn base(&self) -> &BaseInstrument;
+
+    fn id(&self) -> &InstrumentId {
+        &self.base().id
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.base().price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.base().size_precision
+    }
+
+    fn multiplier(&self) -> Quantity {
+        self.base().multiplier
+    }
+
+    fn is_inverse(&self) -> bool {
+        self.base().is_inverse
+    }
+
+    fn quote_currency(&self) -> &Currency {
+        &self.base().quote_currency
+    }
+
+    fn base_currency(&self) -> Option<&Currency> {
+        self.base().base_currency.as_ref()
+    }
+
+    fn cost_currency(&self) -> &Currency {
+        &self.base().cost_currency
+    }
+
+    /// Returns the currency used for settlement (base for inverse, otherwise quote).
+    fn settlement_currency(&self) -> Currency {
+        let base = self.base();
+        if base.is_inverse {
+            base.base_currency
+                .clone()
+                .unwrap_or_else(|| base.quote_currency.clone())
+        } else {
+            base.quote_currency.clone()
+        }
+    }
+
+    /// Returns a price from the given `value`, rounded to the instruments price precision.
+    fn make_price(&self, value: f64) -> Price {
+        Price::new(value, self.price_precision())
+    }
+
+    /// Returns a quantity from the given `value`, rounded to the instruments size precision.
+    fn make_qty(&self, value: f64) -> Quantity {
+        Quantity::new(value, self.size_precision())
+    }
+
+    /// Returns the `price` re-quantized to the instruments price increment using the
+    /// rounding `mode`.
+    fn round_price(&self, price: Price, mode: RoundingMode) -> Price {
+        price.round_to_increment(self.base().price_increment, mode)
+    }
+
+    /// Returns the `quantity` re-quantized to the instruments size increment using the
+    /// rounding `mode`.
+    fn round_qty(&self, quantity: Quantity, mode: RoundingMode) -> Quantity {
+        quantity.round_to_increment(self.base().size_increment, mode)
+    }
+
+    /// Returns true if the `price` conforms to the instruments price increment (tick size).
+    fn is_valid_price(&self, price: Price) -> bool {
+        price.is_multiple_of(self.base().price_increment)
+    }
+
+    /// Returns true if the `quantity` conforms to the instruments size increment, and to
+    /// the lot size (if any).
+    fn is_valid_qty(&self, quantity: Quantity) -> bool {
+        let base = self.base();
+        quantity.is_multiple_of(base.size_increment)
+            && base
+                .lot_size
+                .map_or(true, |lot_size| quantity.is_multiple_of(lot_size))
+    }
+
+    /// Returns the notional value for the given `quantity` and `price`.
+    ///
+    /// The result is in quote currency for standard instruments, or base currency
+    /// for inverse instruments (unless `use_quote_for_inverse`, in which case the
+    /// quantity is taken to already be the notional in quote currency).
+    fn calculate_notional_value(
+        &self,
+        quantity: Quantity,
+        price: Price,
+        use_quote_for_inverse: bool,
+    ) -> Money {
+        let base = self.base();
+        if base.is_inverse {
+            if use_quote_for_inverse {
+                Money::new(quantity.as_f64(), base.quote_currency.clone())
+            } else {
+                let amount = quantity.as_f64() * base.multiplier.as_f64() * (1.0 / price.as_f64());
+                Money::new(amount, settlement_base_currency(base))
+            }
+        } else {
+            let amount = quantity.as_f64() * base.multiplier.as_f64() * price.as_f64();
+            Money::new(amount, base.quote_currency.clone())
+        }
+    }
+
+    /// Returns the equivalent base asset quantity for the given quote `quantity` at `last_px`.
+    fn calculate_base_quantity(&self, quantity: Quantity, last_px: Price) -> Quantity {
+        let value = quantity.as_f64() * (1.0 / last_px.as_f64());
+        Quantity::new(value, self.size_precision())
+    }
+
+    /// Returns the initial (order) margin for the given `quantity` and `price` at `leverage`,
+    /// including the expected commission for the round trip.
+    fn calculate_margin_init(
+        &self,
+        quantity: Quantity,
+        price: Price,
+        leverage: f64,
+        use_quote_for_inverse: bool,
+    ) -> Money {
+        let base = self.base();
+        let notional = self
+            .calculate_notional_value(quantity, price, use_quote_for_inverse)
+            .as_f64();
+        let adjusted_notional = notional / leverage;
+        let mut margin = adjusted_notional * decimal_to_f64(base.margin_init);
+        margin += adjusted_notional * decimal_to_f64(base.taker_fee) * 2.0;
+        self.margin_money(margin, use_quote_for_inverse)
+    }
+
+    /// Returns the maintenance (position) margin for the given `quantity` and `price` at
+    /// `leverage`, including the expected commission to close.
+    fn calculate_margin_maint(
+        &self,
+        quantity: Quantity,
+        price: Price,
+        leverage: f64,
+        use_quote_for_inverse: bool,
+    ) -> Money {
+        let base = self.base();
+        let notional = self
+            .calculate_notional_value(quantity, price, use_quote_for_inverse)
+            .as_f64();
+        let adjusted_notional = notional / leverage;
+        let mut margin = adjusted_notional * decimal_to_f64(base.margin_maint);
+        margin += adjusted_notional * decimal_to_f64(base.taker_fee);
+        self.margin_money(margin, use_quote_for_inverse)
+    }
+
+    /// Returns the commission for a transaction with the given parameters.
+    ///
+    /// # Panics
+    ///
+    /// If `liquidity_side` is `NoLiquiditySide`.
+    fn calculate_commission(
+        &self,
+        last_qty: Quantity,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+        use_quote_for_inverse: bool,
+    ) -> Money {
+        let base = self.base();
+        let notional = self
+            .calculate_notional_value(last_qty, last_px, use_quote_for_inverse)
+            .as_f64();
+        let fee = match liquidity_side {
+            LiquiditySide::Maker => base.maker_fee,
+            LiquiditySide::Taker => base.taker_fee,
+            LiquiditySide::NoLiquiditySide => {
+                panic!("invalid `LiquiditySide`, was {liquidity_side}")
+            }
+        };
+        self.margin_money(notional * decimal_to_f64(fee), use_quote_for_inverse)
+    }
+
+    /// Returns `amount` in base currency for inverse instruments, otherwise quote currency.
+    fn margin_money(&self, amount: f64, use_quote_for_inverse: bool) -> Money {
+        let base = self.base();
+        if base.is_inverse && !use_quote_for_inverse {
+            Money::new(amount, settlement_base_currency(base))
+        } else {
+            Money::new(amount, base.quote_currency.clone())
+        }
+    }
+}
+
+impl Instrument for BaseInstrument {
+    fn base(&self) -> &BaseInstrument {
+        self
+    }
+}
+
+fn settlement_base_currency(base: &BaseInstrument) -> Currency {
+    base.base_currency
+        .clone()
+        .expect("inverse instrument without base currency")
+}
+
+fn decimal_to_f64(value: Decimal) -> f64 {
+    value.to_f64().expect("decimal out of `f64` range")
+}
+
+/// Converts the FFI `f64` margin and fee rates to `Decimal`s, or returns an error naming the
+/// first rate which is not a valid decimal.
+fn try_decimals_from_f64<const N: usize>(values: [f64; N]) -> Result<[Decimal; N], String> {
+    let mut decimals = [Decimal::ZERO; N];
+    for (decimal, value) in decimals.iter_mut().zip(values) {
+        *decimal =
+            Decimal::from_f64(value).ok_or_else(|| format!("invalid decimal value {value}"))?;
+    }
+    Ok(decimals)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        currencies::{BTC, USD},
+        instruments::{crypto_perpetual::tests::xbtusd_bitmex, equity::tests::aapl_nasdaq},
+        position::tests::audusd_sim,
+    };
+
+    fn notional<I: Instrument>(instrument: &I, quantity: &str, price: &str) -> Money {
+        instrument.calculate_notional_value(Quantity::from(quantity), Price::from(price), false)
+    }
+
+    #[rstest]
+    #[case(1.000_014, "1.00001")]
+    #[case(1.000_016, "1.00002")]
+    #[case(0.0, "0.00000")]
+    fn test_make_price(#[case] value: f64, #[case] expected: &str) {
+        let instrument = audusd_sim();
+        assert_eq!(instrument.make_price(value), Price::from(expected));
+    }
+
+    #[rstest]
+    #[case(100_000.4, "100000")]
+    #[case(1.6, "2")]
+    fn test_make_qty(#[case] value: f64, #[case] expected: &str) {
+        let instrument = audusd_sim();
+        assert_eq!(instrument.make_qty(value), Quantity::from(expected));
+    }
+
+    #[rstest]
+    #[case("1.000014", RoundingMode::HalfEven, "1.00001")]
+    #[case("1.000014", RoundingMode::Ceiling, "1.00002")]
+    #[case("1.00001", RoundingMode::Floor, "1.00001")]
+    fn test_round_price(#[case] input: &str, #[case] mode: RoundingMode, #[case] expected: &str) {
+        let instrument = audusd_sim();
+        let price = instrument.round_price(Price::from(input), mode);
+        assert_eq!(price, Price::from(expected));
+        assert_eq!(price.precision, instrument.price_precision());
+        assert!(instrument.is_valid_price(price));
+    }
+
+    #[test]
+    fn test_is_valid_price_and_qty() {
+        let instrument = audusd_sim();
+        assert!(!instrument.is_valid_price(Price::from("1.000014")));
+        assert!(instrument.is_valid_qty(Quantity::from(100_000)));
+        assert!(!instrument.is_valid_qty(Quantity::from("100000.5")));
+        assert!(!instrument.is_valid_qty(Quantity::from(100_500))); // Not a round lot
+        assert_eq!(
+            instrument.round_qty(Quantity::from("100000.5"), RoundingMode::Floor),
+            Quantity::from(100_000)
+        );
+    }
+
+    #[test]
+    fn test_notional_value() {
+        let instrument = audusd_sim();
+        let notional = instrument.calculate_notional_value(
+            Quantity::from("100000"),
+            Price::from("0.80000"),
+            false,
+        );
+        assert_eq!(notional, Money::new(80_000.0, USD.clone()));
+    }
+
+    #[test]
+    #[should_panic(expected = "`price_precision` did not match `price_increment` precision")]
+    fn test_validate_when_price_precision_mismatch() {
+        let mut instrument = audusd_sim();
+        instrument.price_precision = 4;
+        instrument.validate();
+    }
+
+    #[test]
+    fn test_notional_value_generic_over_kinds() {
+        assert_eq!(
+            notional(&aapl_nasdaq(), "100", "150.00"),
+            Money::new(15_000.0, USD.clone())
+        );
+        assert_eq!(
+            notional(&xbtusd_bitmex(), "100000", "50000.0"),
+            Money::new(2.0, BTC.clone())
+        );
+    }
+
+    #[test]
+    fn test_calculate_base_quantity() {
+        let instrument = xbtusd_bitmex();
+        let quantity =
+            instrument.calculate_base_quantity(Quantity::from("100000"), Price::from("50000.0"));
+        assert_eq!(quantity, Quantity::from("2"));
+    }
+
+    #[test]
+    fn test_calculate_margin_init_inverse() {
+        let instrument = xbtusd_bitmex();
+        let margin = instrument.calculate_margin_init(
+            Quantity::from("100000"),
+            Price::from("50000.0"),
+            1.0,
+            false,
+        );
+        // 2 BTC notional at 1% margin plus 2x 0.075% taker fee
+        assert_eq!(margin, Money::new(0.023, BTC.clone()));
+    }
+
+    #[test]
+    fn test_calculate_commission_maker_rebate() {
+        let instrument = xbtusd_bitmex();
+        let commission = instrument.calculate_commission(
+            Quantity::from("100000"),
+            Price::from("50000.0"),
+            LiquiditySide::Maker,
+            false,
+        );
+        assert_eq!(commission, Money::new(-0.0005, BTC.clone()));
+    }
+}
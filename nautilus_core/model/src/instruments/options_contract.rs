@@ -0,0 +1,228 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::time::UnixNanos;
+use rust_decimal::Decimal;
+
+use super::{BaseInstrument, Instrument};
+use crate::{
+    enums::{AssetClass, AssetType, OptionKind},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+/// Represents a generic options contract instrument.
+#[derive(Clone, Debug)]
+pub struct OptionsContract {
+    pub(super) core: BaseInstrument,
+    pub underlying: String,
+    pub kind: OptionKind,
+    pub activation_ns: UnixNanos,
+    pub expiration_ns: UnixNanos,
+    pub strike_price: Price,
+}
+
+impl Deref for OptionsContract {
+    type Target = BaseInstrument;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for OptionsContract {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl From<OptionsContract> for BaseInstrument {
+    fn from(contract: OptionsContract) -> Self {
+        contract.core
+    }
+}
+
+impl Instrument for OptionsContract {
+    fn base(&self) -> &BaseInstrument {
+        &self.core
+    }
+}
+
+impl OptionsContract {
+    /// # Panics
+    ///
+    /// If `expiration_ns` is not after `activation_ns`, `strike_price` is not positive,
+    /// or the specification is otherwise invalid (see [`BaseInstrument::validate`]).
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        native_symbol: Symbol,
+        asset_class: AssetClass,
+        currency: Currency,
+        price_precision: u8,
+        price_increment: Price,
+        multiplier: Quantity,
+        lot_size: Option<Quantity>,
+        underlying: String,
+        kind: OptionKind,
+        activation_ns: UnixNanos,
+        expiration_ns: UnixNanos,
+        strike_price: Price,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+    ) -> Self {
+        Self::try_new(
+            id,
+            native_symbol,
+            asset_class,
+            currency,
+            price_precision,
+            price_increment,
+            multiplier,
+            lot_size,
+            underlying,
+            kind,
+            activation_ns,
+            expiration_ns,
+            strike_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+        )
+        .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Returns a new [`OptionsContract`], or an error if `expiration_ns` is not after
+    /// `activation_ns`, `strike_price` is not positive, or the specification is otherwise invalid
+    /// (see [`BaseInstrument::check_valid`]).
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        id: InstrumentId,
+        native_symbol: Symbol,
+        asset_class: AssetClass,
+        currency: Currency,
+        price_precision: u8,
+        price_increment: Price,
+        multiplier: Quantity,
+        lot_size: Option<Quantity>,
+        underlying: String,
+        kind: OptionKind,
+        activation_ns: UnixNanos,
+        expiration_ns: UnixNanos,
+        strike_price: Price,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+    ) -> Result<Self, String> {
+        if expiration_ns <= activation_ns {
+            return Err("`expiration_ns` was not after `activation_ns`".to_string());
+        }
+        if strike_price.raw <= 0 {
+            return Err("`strike_price` was not positive".to_string());
+        }
+
+        let core = BaseInstrument {
+            id,
+            native_symbol,
+            asset_class,
+            asset_type: AssetType::Option,
+            quote_currency: currency.clone(),
+            base_currency: None,
+            cost_currency: currency,
+            is_inverse: false,
+            price_precision,
+            size_precision: 0,
+            price_increment,
+            size_increment: Quantity::from(1),
+            multiplier,
+            lot_size,
+            max_quantity: None,
+            min_quantity: Some(Quantity::from(1)),
+            max_price: None,
+            min_price: Some(Price::try_new(0.0, price_precision)?),
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+        };
+        core.check_valid()?;
+
+        Ok(Self {
+            core,
+            underlying,
+            kind,
+            activation_ns,
+            expiration_ns,
+            strike_price,
+        })
+    }
+
+    /// Returns whether the contract is tradable at the given UNIX timestamp (nanoseconds).
+    #[must_use]
+    pub fn is_active(&self, ts: UnixNanos) -> bool {
+        self.activation_ns <= ts && ts < self.expiration_ns
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::currencies::USD;
+
+    pub fn aapl_call_option() -> OptionsContract {
+        OptionsContract::new(
+            InstrumentId::from_str("AAPL211217C00150000.OPRA").unwrap(),
+            Symbol::new("AAPL211217C00150000"),
+            AssetClass::Equity,
+            USD.clone(),
+            2,
+            Price::from("0.01"),
+            Quantity::from(100),
+            Some(Quantity::from(1)),
+            String::from("AAPL"),
+            OptionKind::Call,
+            1_631_836_800_000_000_000,
+            1_639_699_200_000_000_000,
+            Price::from("149.00"),
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+        )
+    }
+
+    #[test]
+    fn test_options_contract_new() {
+        let option = aapl_call_option();
+
+        assert_eq!(option.asset_type, AssetType::Option);
+        assert_eq!(option.kind, OptionKind::Call);
+        assert_eq!(option.strike_price, Price::from("149.00"));
+        assert_eq!(option.min_price, Some(Price::from("0.00")));
+        assert!(option.is_active(1_635_000_000_000_000_000));
+    }
+}
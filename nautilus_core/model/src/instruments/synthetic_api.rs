@@ -21,8 +21,9 @@ use std::{
 
 use nautilus_core::{
     cvec::CVec,
-    parsing::{bytes_to_string_vec, string_vec_to_bytes},
-    string::{cstr_to_string, str_to_cstr},
+    ffi_error::{try_cstr_to_str, write_result, FFI_ERROR},
+    parsing::string_vec_to_bytes,
+    string::str_to_cstr,
 };
 
 use super::synthetic::SyntheticInstrument;
@@ -58,26 +59,46 @@ impl DerefMut for SyntheticInstrument_API {
     }
 }
 
+/// Returns a new synthetic instrument, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
 /// # Safety
 ///
 /// - Assumes `components_ptr` is a valid C string pointer of a JSON format list of strings.
 /// - Assumes `formula_ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`SyntheticInstrument_API`].
 #[no_mangle]
-pub unsafe extern "C" fn synthetic_instrument_new(
+pub unsafe extern "C" fn synthetic_instrument_try_new(
     symbol: Symbol,
     precision: u8,
     components_ptr: *const c_char,
     formula_ptr: *const c_char,
-) -> SyntheticInstrument_API {
-    // TODO: There is absolutely no error handling here yet
-    let components = bytes_to_string_vec(components_ptr)
-        .into_iter()
-        .map(|s| InstrumentId::from_str(&s).unwrap())
-        .collect::<Vec<InstrumentId>>();
-    let formula = cstr_to_string(formula_ptr);
-    let synth = SyntheticInstrument::new(symbol, precision, components, formula);
-
-    SyntheticInstrument_API(Box::new(synth.unwrap()))
+    out: *mut SyntheticInstrument_API,
+) -> u8 {
+    let result = parse_components(components_ptr).and_then(|components| {
+        let formula = try_cstr_to_str(formula_ptr, "formula")?;
+        SyntheticInstrument::new(symbol, precision, components, formula.to_string())
+            .map(|synth| SyntheticInstrument_API(Box::new(synth)))
+            .map_err(|e| e.to_string())
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Parses the instrument IDs from the JSON format list of strings at `ptr`.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+unsafe fn parse_components(ptr: *const c_char) -> Result<Vec<InstrumentId>, String> {
+    let json = try_cstr_to_str(ptr, "components")?;
+    let components: Vec<String> =
+        serde_json::from_str(json).map_err(|e| format!("invalid components JSON: {e}"))?;
+    components
+        .iter()
+        .map(|s| InstrumentId::from_str(s).map_err(|e| e.to_string()))
+        .collect()
 }
 
 #[no_mangle]
@@ -123,31 +144,48 @@ pub unsafe extern "C" fn synthetic_instrument_is_valid_formula(
     synth: &SyntheticInstrument_API,
     formula_ptr: *const c_char,
 ) -> u8 {
-    let formula = cstr_to_string(formula_ptr);
-    synth.is_valid_formula(&formula) as u8
+    try_cstr_to_str(formula_ptr, "formula")
+        .map_or(0, |formula| u8::from(synth.is_valid_formula(formula)))
 }
 
+/// Changes the formula of the synthetic instrument.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case the formula is unchanged).
+///
 /// # Safety
 ///
 /// - Assumes `formula_ptr` is a valid C string pointer.
 #[no_mangle]
-pub unsafe extern "C" fn synthetic_instrument_change_formula(
+pub unsafe extern "C" fn synthetic_instrument_try_change_formula(
     synth: &mut SyntheticInstrument_API,
     formula_ptr: *const c_char,
-) {
-    // TODO: There is absolutely no error handling here yet
-    let formula = cstr_to_string(formula_ptr);
-    synth.change_formula(formula).unwrap();
+) -> u8 {
+    let result = try_cstr_to_str(formula_ptr, "formula").and_then(|formula| {
+        synth
+            .change_formula(formula.to_string())
+            .map_err(|e| e.to_string())
+    });
+    write_result(result, &mut (), |_| FFI_ERROR)
 }
 
+/// Calculates the price of the synthetic instrument from the given `inputs`, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `inputs_ptr` is a valid [`CVec`] of `f64` values.
+/// - Assumes `out` is a valid pointer to memory for a [`Price`].
 #[no_mangle]
-pub extern "C" fn synthetic_instrument_calculate(
+pub unsafe extern "C" fn synthetic_instrument_try_calculate(
     synth: &mut SyntheticInstrument_API,
     inputs_ptr: &CVec,
-) -> Price {
+    out: *mut Price,
+) -> u8 {
     let CVec { ptr, len, .. } = inputs_ptr;
-    let inputs: &[f64] = unsafe { std::slice::from_raw_parts(*ptr as *mut f64, *len) };
+    let inputs: &[f64] = std::slice::from_raw_parts(*ptr as *mut f64, *len);
 
-    // TODO: There is absolutely no error handling here yet
-    synth.calculate(inputs).unwrap()
+    write_result(synth.calculate(inputs), out, |_| FFI_ERROR)
 }
@@ -13,30 +13,98 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+//! The `ffi` feature (on by default) builds the C API wrappers in the `*_api` modules, and
+//! generates the C headers and Cython definitions. Building with `--no-default-features
+//! --features serde` leaves these and the Python bindings out, so the crate compiles to
+//! `wasm32-unknown-unknown` for reuse of the price, quantity and order book logic in the
+//! browser.
+//!
+//! Without the `std` feature the crate is `no_std` (needing only `alloc`), and is reduced to
+//! the `enums` and the fixed-point `Price` and `Quantity` value types, so that embedded
+//! gateway binaries can share their definitions. The identifiers are interned so remain
+//! `std` only.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "256"]
+#[cfg(feature = "std")]
 #[macro_use]
 extern crate lazy_static;
 
+extern crate alloc;
+
+#[cfg(feature = "python")]
 use pyo3::{prelude::*, PyResult, Python};
 
+#[cfg(feature = "std")]
+pub mod accounts;
+#[cfg(feature = "std")]
+pub mod commands;
+#[cfg(feature = "std")]
 pub mod currencies;
+#[cfg(feature = "std")]
 pub mod data;
 pub mod enums;
+#[cfg(feature = "std")]
 pub mod events;
+#[cfg(feature = "std")]
+pub mod generators;
+#[cfg(feature = "std")]
 pub mod identifiers;
+#[cfg(feature = "std")]
 pub mod instruments;
+#[cfg(feature = "std")]
 pub mod orderbook;
+#[cfg(feature = "std")]
 pub mod orders;
+#[cfg(feature = "std")]
 pub mod position;
+#[cfg(feature = "ffi")]
+pub mod position_api;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "std")]
+pub mod reports;
+#[cfg(feature = "ffi")]
+pub mod reports_api;
+#[cfg(feature = "schema")]
+pub mod schema;
+#[cfg(any(test, feature = "testkit"))]
+pub mod testkit;
 pub mod types;
 
 /// Loaded as nautilus_pyo3.model
+#[cfg(feature = "python")]
 #[pymodule]
 pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<enums::AccountType>()?;
     m.add_class::<enums::AggregationSource>()?;
+    m.add_class::<enums::AggressorSide>()?;
+    m.add_class::<enums::AssetClass>()?;
+    m.add_class::<enums::AssetType>()?;
     m.add_class::<enums::BarAggregation>()?;
+    m.add_class::<enums::BookAction>()?;
+    m.add_class::<enums::BookChecksumType>()?;
+    m.add_class::<enums::BookType>()?;
+    m.add_class::<enums::ContingencyType>()?;
+    m.add_class::<enums::CurrencyType>()?;
+    m.add_class::<enums::InstrumentCloseType>()?;
+    m.add_class::<enums::LiquiditySide>()?;
+    m.add_class::<enums::MarketStatus>()?;
+    m.add_class::<enums::OmsType>()?;
+    m.add_class::<enums::OptionKind>()?;
+    m.add_class::<enums::OrderSide>()?;
+    m.add_class::<enums::OrderStatus>()?;
+    m.add_class::<enums::OrderType>()?;
+    m.add_class::<enums::PositionSide>()?;
     m.add_class::<enums::PriceType>()?;
+    m.add_class::<enums::TimeInForce>()?;
+    m.add_class::<enums::TradingState>()?;
+    m.add_class::<enums::TrailingOffsetType>()?;
+    m.add_class::<enums::TriggerType>()?;
+    m.add_class::<data::bar::Bar>()?;
+    m.add_class::<data::tick::QuoteTick>()?;
+    m.add_class::<data::tick::TradeTick>()?;
+    m.add_class::<events::order::OrderInitialized>()?;
     m.add_class::<identifiers::account_id::AccountId>()?;
     m.add_class::<identifiers::client_id::ClientId>()?;
     m.add_class::<identifiers::client_order_id::ClientOrderId>()?;
@@ -51,6 +119,7 @@ pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<identifiers::trader_id::TraderId>()?;
     m.add_class::<identifiers::venue::Venue>()?;
     m.add_class::<identifiers::venue_order_id::VenueOrderId>()?;
+    m.add_class::<orders::factory::OrderFactory>()?;
     m.add_class::<types::currency::Currency>()?;
     m.add_class::<types::money::Money>()?;
     m.add_class::<types::price::Price>()?;
@@ -16,16 +16,21 @@
 use tabled::{settings::Style, Table, Tabled};
 use thiserror::Error;
 
-use super::{ladder::BookPrice, level::Level};
+use super::{
+    checksum::{checksum_depth, compute_checksum},
+    ladder::BookPrice,
+    level::Level,
+};
 use crate::{
     data::{
-        book::{BookOrder, OrderBookDelta},
+        book::{BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookSnapshot},
         tick::{QuoteTick, TradeTick},
     },
-    enums::{BookAction, BookType, OrderSide},
+    enums::{BookAction, BookChecksumType, BookType, OrderSide},
+    events::book::OrderBookChecksumFailed,
     identifiers::instrument_id::InstrumentId,
     orderbook::ladder::Ladder,
-    types::{price::Price, quantity::Quantity},
+    types::{fixed::RoundingMode, price::Price, quantity::Quantity},
 };
 
 pub struct OrderBook {
@@ -33,6 +38,8 @@ pub struct OrderBook {
     asks: Ladder,
     pub instrument_id: InstrumentId,
     pub book_type: BookType,
+    /// The venue checksum verified by `verify_checksum`, if any.
+    pub checksum_type: Option<BookChecksumType>,
     pub sequence: u64,
     pub ts_last: u64,
     pub count: u64,
@@ -58,6 +65,8 @@ pub enum BookIntegrityError {
     TooManyOrders(OrderSide, usize),
     #[error("Integrity error: number of {0} levels > 1 for L1_TBBO book, was {1}")]
     TooManyLevels(OrderSide, usize),
+    #[error("Integrity error: checksum mismatch, expected {0}, was {1}")]
+    ChecksumMismatch(u32, u32),
 }
 
 #[derive(Tabled)]
@@ -75,6 +84,7 @@ impl OrderBook {
             asks: Ladder::new(OrderSide::Sell),
             instrument_id,
             book_type,
+            checksum_type: None,
             sequence: 0,
             ts_last: 0,
             count: 0,
@@ -165,6 +175,273 @@ impl OrderBook {
         }
     }
 
+    pub fn apply_deltas(&mut self, deltas: OrderBookDeltas) {
+        for delta in deltas.deltas {
+            self.apply_delta(delta)
+        }
+    }
+
+    /// Replaces the entire contents of the book with the given `snapshot`.
+    pub fn apply_snapshot(&mut self, snapshot: OrderBookSnapshot) {
+        self.bids.clear();
+        self.asks.clear();
+
+        for order in snapshot.bids.into_iter().chain(snapshot.asks) {
+            let order = match self.book_type {
+                BookType::L3_MBO => order, // No order pre-processing
+                BookType::L2_MBP | BookType::L1_TBBO => self.pre_process_order(order),
+            };
+
+            match order.side {
+                OrderSide::Buy => self.bids.add(order),
+                OrderSide::Sell => self.asks.add(order),
+                _ => panic!("{}", BookIntegrityError::NoOrderSide),
+            }
+        }
+
+        self.increment(snapshot.ts_event, snapshot.sequence);
+    }
+
+    /// Returns the bid levels, ordered from the best (highest) price.
+    pub fn bids(&self) -> Vec<&Level> {
+        self.bids.levels.values().collect()
+    }
+
+    /// Returns the ask levels, ordered from the best (lowest) price.
+    pub fn asks(&self) -> Vec<&Level> {
+        self.asks.levels.values().collect()
+    }
+
+    /// Returns the aggregated price and size for up to `num_levels` levels of the given `side`,
+    /// ordered from the top of the book.
+    pub fn get_depth(&self, side: OrderSide, num_levels: usize) -> Vec<(Price, Quantity)> {
+        self.get_ladder(side)
+            .levels
+            .values()
+            .take(num_levels)
+            .map(|level| (level.price.value, level.size()))
+            .collect()
+    }
+
+    /// Returns the size on the given `side` aggregated into `num_bands` contiguous price bands
+    /// of `band_width`, starting from the band containing the best price.
+    ///
+    /// Band prices are aligned to multiples of `band_width` (bid bands cover
+    /// `[price, price + band_width)` and ask bands `(price - band_width, price]`), so the bands
+    /// stay stable as the top of the book moves. Bands without any levels have zero size.
+    ///
+    /// # Panics
+    ///
+    /// - If `band_width` is not positive.
+    pub fn get_depth_bands(
+        &self,
+        side: OrderSide,
+        band_width: Price,
+        num_bands: usize,
+    ) -> Vec<(Price, Quantity)> {
+        assert!(band_width.raw > 0, "`band_width` was not positive");
+
+        let mut levels = self.get_ladder(side).levels.values().peekable();
+        let (best_price, size_precision) = match levels.peek() {
+            Some(level) => (level.price.value, level.size().precision),
+            None => return Vec::new(),
+        };
+        let is_bid = side == OrderSide::Buy;
+        let mode = if is_bid {
+            RoundingMode::Floor
+        } else {
+            RoundingMode::Ceiling
+        };
+        let first_band = best_price.round_to_increment(band_width, mode);
+
+        let mut bands = Vec::with_capacity(num_bands);
+        let mut band_price = first_band;
+        for _ in 0..num_bands {
+            let mut size = Quantity::zero(size_precision);
+            while let Some(level) = levels.next_if(|level| {
+                if is_bid {
+                    level.price.value >= band_price
+                } else {
+                    level.price.value <= band_price
+                }
+            }) {
+                size += level.size();
+            }
+            bands.push((band_price, size));
+
+            let next = if is_bid {
+                band_price.checked_sub(band_width)
+            } else {
+                band_price.checked_add(band_width)
+            };
+            match next {
+                Some(next) => band_price = Price::from_raw(next.raw, first_band.precision),
+                None => break,
+            }
+        }
+        bands
+    }
+
+    /// Returns the cumulative size available on the given `side` at prices equal to or better
+    /// than `price`.
+    pub fn get_quantity_for_price(&self, price: Price, side: OrderSide) -> f64 {
+        self.get_ladder(side)
+            .levels
+            .values()
+            .take_while(|level| match side {
+                OrderSide::Buy => level.price.value >= price,
+                _ => level.price.value <= price,
+            })
+            .map(Level::volume)
+            .sum()
+    }
+
+    /// Returns the size queued ahead of the book order with the given `order_id` at its price
+    /// level on the given `side` (for L3_MBO books).
+    ///
+    /// Returns `None` if the order is not in the book.
+    pub fn get_queue_position(&self, side: OrderSide, order_id: u64) -> Option<Quantity> {
+        let ladder = self.get_ladder(side);
+        let price = ladder.cache.get(&order_id)?;
+        ladder.levels.get(price)?.size_ahead(order_id)
+    }
+
+    /// Returns the volume imbalance over the top `depth` levels of each side, calculated as
+    /// `(bid_volume - ask_volume) / (bid_volume + ask_volume)` in the range [-1, 1].
+    ///
+    /// Returns `None` if both sides are empty.
+    pub fn get_volume_imbalance(&self, depth: usize) -> Option<f64> {
+        let bid_volume: f64 = self
+            .bids
+            .levels
+            .values()
+            .take(depth)
+            .map(Level::volume)
+            .sum();
+        let ask_volume: f64 = self
+            .asks
+            .levels
+            .values()
+            .take(depth)
+            .map(Level::volume)
+            .sum();
+        let total = bid_volume + ask_volume;
+        if total <= 0.0 {
+            return None;
+        }
+        Some((bid_volume - ask_volume) / total)
+    }
+
+    /// Returns the top of book microprice, being the midpoint weighted by the size on the
+    /// opposite side: `(bid * ask_size + ask * bid_size) / (bid_size + ask_size)`.
+    ///
+    /// Returns `None` if there is no bid or ask.
+    pub fn get_microprice(&self) -> Option<f64> {
+        match (self.bids.top(), self.asks.top()) {
+            (Some(bid), Some(ask)) => {
+                let bid_size = bid.volume();
+                let ask_size = ask.volume();
+                let total = bid_size + ask_size;
+                if total <= 0.0 {
+                    return None;
+                }
+                Some(
+                    (bid.price.value.as_f64() * ask_size + ask.price.value.as_f64() * bid_size)
+                        / total,
+                )
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the average fill price from consuming `qty` of liquidity for an order on the
+    /// given `order_side` (a buy consumes the asks, a sell consumes the bids).
+    ///
+    /// If there is insufficient liquidity then the average is taken over the available size,
+    /// and if the opposite side is empty then returns zero.
+    pub fn get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
+        let ladder = match order_side {
+            OrderSide::Buy => &self.asks,
+            OrderSide::Sell => &self.bids,
+            _ => panic!("{}", BookIntegrityError::NoOrderSide),
+        };
+
+        let mut remaining = qty.as_f64();
+        let mut cumulative_size = 0.0;
+        let mut cumulative_notional = 0.0;
+        for level in ladder.levels.values() {
+            if remaining <= 0.0 {
+                break;
+            }
+            let size = level.volume().min(remaining);
+            cumulative_size += size;
+            cumulative_notional += level.price.value.as_f64() * size;
+            remaining -= size;
+        }
+
+        if cumulative_size == 0.0 {
+            0.0
+        } else {
+            cumulative_notional / cumulative_size
+        }
+    }
+
+    /// Returns a CRC32 checksum over the top `depth` levels of the book.
+    ///
+    /// The checksum input is each bid level followed by each ask level formatted as
+    /// `{price}:{size}`, joined by commas.
+    pub fn checksum(&self, depth: usize) -> u32 {
+        let input = self
+            .get_depth(OrderSide::Buy, depth)
+            .into_iter()
+            .chain(self.get_depth(OrderSide::Sell, depth))
+            .map(|(price, size)| format!("{price}:{size}"))
+            .collect::<Vec<String>>()
+            .join(",");
+
+        crc32fast::hash(input.as_bytes())
+    }
+
+    pub fn check_checksum(&self, expected: u32, depth: usize) -> Result<(), BookIntegrityError> {
+        let actual = self.checksum(depth);
+        if actual != expected {
+            return Err(BookIntegrityError::ChecksumMismatch(expected, actual));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the checksum of the book per the venue specification of `checksum_type`.
+    pub fn venue_checksum(&self, checksum_type: BookChecksumType) -> u32 {
+        let depth = checksum_depth(checksum_type);
+        compute_checksum(
+            checksum_type,
+            &self.get_depth(OrderSide::Buy, depth),
+            &self.get_depth(OrderSide::Sell, depth),
+        )
+    }
+
+    /// Verifies the book against the `expected` checksum published by the venue, returning
+    /// an event if they do not match.
+    ///
+    /// Returns `None` without checking when the book has no `checksum_type`.
+    pub fn verify_checksum(&self, expected: u32) -> Option<OrderBookChecksumFailed> {
+        let checksum_type = self.checksum_type?;
+        let actual = self.venue_checksum(checksum_type);
+        if actual == expected {
+            return None;
+        }
+
+        Some(OrderBookChecksumFailed {
+            instrument_id: self.instrument_id.clone(),
+            checksum_type,
+            expected,
+            actual,
+            sequence: self.sequence,
+            ts_event: self.ts_last,
+        })
+    }
+
     pub fn has_bid(&self) -> bool {
         match self.bids.top() {
             Some(top) => !top.orders.is_empty(),
@@ -350,6 +627,14 @@ impl OrderBook {
         Ok(())
     }
 
+    fn get_ladder(&self, side: OrderSide) -> &Ladder {
+        match side {
+            OrderSide::Buy => &self.bids,
+            OrderSide::Sell => &self.asks,
+            _ => panic!("{}", BookIntegrityError::NoOrderSide),
+        }
+    }
+
     fn increment(&mut self, ts_event: u64, sequence: u64) {
         self.ts_last = ts_event;
         self.sequence = sequence;
@@ -549,6 +834,395 @@ mod tests {
         assert_eq!(book.spread(), Some(1.0));
     }
 
+    fn create_stub_book_with_levels() -> OrderBook {
+        let mut book = create_stub_book(BookType::L2_MBP);
+        let orders = [
+            (OrderSide::Buy, "1.00", "3.0", 1),
+            (OrderSide::Buy, "0.99", "1.0", 2),
+            (OrderSide::Sell, "1.01", "1.0", 3),
+            (OrderSide::Sell, "1.02", "2.0", 4),
+        ];
+        for (sequence, (side, price, size, order_id)) in orders.into_iter().enumerate() {
+            book.add(
+                BookOrder::new(side, Price::from(price), Quantity::from(size), order_id),
+                100,
+                sequence as u64 + 1,
+            );
+        }
+        book
+    }
+
+    #[test]
+    fn test_get_queue_position() {
+        let mut book = create_stub_book(BookType::L3_MBO);
+        for (order_id, size) in [(1, "1.0"), (2, "2.0"), (3, "3.0")] {
+            book.add(
+                BookOrder::new(
+                    OrderSide::Buy,
+                    Price::from("1.00"),
+                    Quantity::from(size),
+                    order_id,
+                ),
+                100,
+                order_id,
+            );
+        }
+
+        assert_eq!(
+            book.get_queue_position(OrderSide::Buy, 3),
+            Some(Quantity::from("3.0"))
+        );
+        book.delete(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.00"),
+                Quantity::from("1.0"),
+                1,
+            ),
+            200,
+            4,
+        );
+        assert_eq!(
+            book.get_queue_position(OrderSide::Buy, 3),
+            Some(Quantity::from("2.0"))
+        );
+        assert_eq!(book.get_queue_position(OrderSide::Buy, 1), None);
+        assert_eq!(book.get_queue_position(OrderSide::Sell, 3), None);
+    }
+
+    #[test]
+    fn test_volume_imbalance_with_no_bids_or_asks() {
+        let book = create_stub_book(BookType::L2_MBP);
+        assert_eq!(book.get_volume_imbalance(5), None);
+    }
+
+    #[test]
+    fn test_volume_imbalance_at_depth() {
+        let book = create_stub_book_with_levels();
+        assert_eq!(book.get_volume_imbalance(1), Some(0.5));
+        assert_eq!(book.get_volume_imbalance(2), Some(1.0 / 7.0));
+    }
+
+    #[test]
+    fn test_microprice_with_no_bids_or_asks() {
+        let book = create_stub_book(BookType::L2_MBP);
+        assert_eq!(book.get_microprice(), None);
+    }
+
+    #[test]
+    fn test_microprice_with_bids_and_asks() {
+        let book = create_stub_book_with_levels();
+        let microprice = book.get_microprice().unwrap();
+        assert!((microprice - 1.0075).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_avg_px_for_quantity_with_empty_book() {
+        let book = create_stub_book(BookType::L2_MBP);
+        assert_eq!(
+            book.get_avg_px_for_quantity(Quantity::from("1.0"), OrderSide::Buy),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_avg_px_for_quantity_walks_levels() {
+        let book = create_stub_book_with_levels();
+        let buy_px = book.get_avg_px_for_quantity(Quantity::from("2.0"), OrderSide::Buy);
+        let sell_px = book.get_avg_px_for_quantity(Quantity::from("3.0"), OrderSide::Sell);
+        let sweep_px = book.get_avg_px_for_quantity(Quantity::from("10.0"), OrderSide::Buy);
+        assert!((buy_px - 1.015).abs() < 1e-9);
+        assert!((sell_px - 1.00).abs() < 1e-9);
+        assert!((sweep_px - (1.01 + 2.0 * 1.02) / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_get_depth_and_quantity_for_price() {
+        let mut book = create_stub_book(BookType::L3_MBO);
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.00"),
+                Quantity::from("1.0"),
+                1,
+            ),
+            100,
+            1,
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.00"),
+                Quantity::from("2.0"),
+                2,
+            ),
+            100,
+            2,
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("0.99"),
+                Quantity::from("3.0"),
+                3,
+            ),
+            100,
+            3,
+        );
+
+        let depth = book.get_depth(OrderSide::Buy, 5);
+        assert_eq!(
+            depth,
+            vec![
+                (Price::from("1.00"), Quantity::from("3.0")),
+                (Price::from("0.99"), Quantity::from("3.0")),
+            ]
+        );
+        assert_eq!(book.get_depth(OrderSide::Buy, 1).len(), 1);
+        assert_eq!(
+            book.get_quantity_for_price(Price::from("1.00"), OrderSide::Buy),
+            3.0
+        );
+        assert_eq!(
+            book.get_quantity_for_price(Price::from("0.99"), OrderSide::Buy),
+            6.0
+        );
+        assert!(book.get_depth(OrderSide::Sell, 5).is_empty());
+    }
+
+    #[test]
+    fn test_get_depth_bands() {
+        let mut book = create_stub_book(BookType::L2_MBP);
+        for (i, (side, price, size)) in [
+            (OrderSide::Buy, "1.03", "1.0"),
+            (OrderSide::Buy, "1.01", "2.0"),
+            (OrderSide::Buy, "0.98", "3.0"),
+            (OrderSide::Sell, "1.04", "4.0"),
+            (OrderSide::Sell, "1.05", "5.0"),
+            (OrderSide::Sell, "1.11", "6.0"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+            book.add(order, 100, i as u64);
+        }
+
+        let width = Price::from("0.05");
+        assert_eq!(
+            book.get_depth_bands(OrderSide::Buy, width, 3),
+            vec![
+                (Price::from("1.00"), Quantity::from("3.0")),
+                (Price::from("0.95"), Quantity::from("3.0")),
+                (Price::from("0.90"), Quantity::from("0.0")),
+            ]
+        );
+        assert_eq!(
+            book.get_depth_bands(OrderSide::Sell, width, 4),
+            vec![
+                (Price::from("1.05"), Quantity::from("9.0")),
+                (Price::from("1.10"), Quantity::from("0.0")),
+                (Price::from("1.15"), Quantity::from("6.0")),
+                (Price::from("1.20"), Quantity::from("0.0")),
+            ]
+        );
+        assert_eq!(book.get_depth_bands(OrderSide::Sell, width, 0), vec![]);
+    }
+
+    #[test]
+    fn test_get_depth_bands_empty_side() {
+        let book = create_stub_book(BookType::L2_MBP);
+        assert!(book
+            .get_depth_bands(OrderSide::Buy, Price::from("0.01"), 10)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_delete_order_added_to_existing_level() {
+        let mut book = create_stub_book(BookType::L3_MBO);
+        let order1 = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("2.00"),
+            Quantity::from("1.0"),
+            1,
+        );
+        let order2 = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("2.00"),
+            Quantity::from("2.0"),
+            2,
+        );
+        book.add(order1, 100, 1);
+        book.add(order2, 100, 2);
+        book.delete(order2, 200, 3);
+
+        assert_eq!(book.asks().len(), 1);
+        assert_eq!(book.best_ask_size(), Some(Quantity::from("1.0")));
+    }
+
+    #[test]
+    fn test_apply_snapshot_replaces_book() {
+        let mut book = create_stub_book(BookType::L2_MBP);
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("0.50"),
+                Quantity::from("1.0"),
+                1,
+            ),
+            100,
+            1,
+        );
+
+        let bid = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.00"),
+            Quantity::from("1.0"),
+            0,
+        );
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("2.00"),
+            Quantity::from("2.0"),
+            0,
+        );
+        let snapshot = OrderBookSnapshot::new(
+            book.instrument_id.clone(),
+            vec![bid],
+            vec![ask],
+            2,
+            200,
+            201,
+        );
+        book.apply_snapshot(snapshot);
+
+        assert_eq!(book.bids().len(), 1);
+        assert_eq!(book.best_bid_price(), Some(Price::from("1.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("2.00")));
+        assert_eq!(book.sequence, 2);
+        assert_eq!(book.ts_last, 200);
+    }
+
+    #[test]
+    fn test_apply_deltas() {
+        let mut book = create_stub_book(BookType::L3_MBO);
+        let deltas = OrderBookDeltas::new(
+            book.instrument_id.clone(),
+            vec![
+                OrderBookDelta::new(
+                    book.instrument_id.clone(),
+                    BookAction::Add,
+                    BookOrder::new(
+                        OrderSide::Buy,
+                        Price::from("1.00"),
+                        Quantity::from("1.0"),
+                        1,
+                    ),
+                    0,
+                    1,
+                    100,
+                    101,
+                ),
+                OrderBookDelta::new(
+                    book.instrument_id.clone(),
+                    BookAction::Add,
+                    BookOrder::new(
+                        OrderSide::Sell,
+                        Price::from("2.00"),
+                        Quantity::from("1.0"),
+                        2,
+                    ),
+                    0,
+                    2,
+                    200,
+                    201,
+                ),
+            ],
+        );
+        book.apply_deltas(deltas);
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("1.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("2.00")));
+        assert_eq!(book.sequence, 2);
+        assert_eq!(book.count, 2);
+    }
+
+    #[test]
+    fn test_checksum() {
+        let mut book = create_stub_book(BookType::L2_MBP);
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.00"),
+                Quantity::from("1.0"),
+                0,
+            ),
+            100,
+            1,
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("2.00"),
+                Quantity::from("2.0"),
+                0,
+            ),
+            100,
+            2,
+        );
+
+        let expected = crc32fast::hash(b"1.00:1.0,2.00:2.0");
+        assert_eq!(book.checksum(10), expected);
+        assert!(book.check_checksum(expected, 10).is_ok());
+        assert!(matches!(
+            book.check_checksum(expected + 1, 10),
+            Err(BookIntegrityError::ChecksumMismatch(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_verify_checksum() {
+        let mut book = create_stub_book(BookType::L2_MBP);
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.00"),
+                Quantity::from("1.0"),
+                0,
+            ),
+            100,
+            1,
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("2.00"),
+                Quantity::from("2.0"),
+                0,
+            ),
+            200,
+            2,
+        );
+        let expected = crc32fast::hash(b"1.00:1.0:2.00:2.0");
+
+        assert!(book.verify_checksum(expected + 1).is_none()); // No checksum type
+
+        book.checksum_type = Some(BookChecksumType::Okx);
+        assert_eq!(book.venue_checksum(BookChecksumType::Okx), expected);
+        assert!(book.verify_checksum(expected).is_none());
+        assert_eq!(
+            book.verify_checksum(expected + 1),
+            Some(OrderBookChecksumFailed {
+                instrument_id: book.instrument_id.clone(),
+                checksum_type: BookChecksumType::Okx,
+                expected: expected + 1,
+                actual: expected,
+                sequence: 2,
+                ts_event: 200,
+            })
+        );
+    }
+
     #[test]
     fn test_midpoint_with_no_bids_or_asks() {
         let book = create_stub_book(BookType::L2_MBP);
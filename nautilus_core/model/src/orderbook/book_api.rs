@@ -18,15 +18,20 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use nautilus_core::{cvec::CVec, string::str_to_cstr};
+use nautilus_core::{
+    cvec::CVec,
+    ffi_error::{set_last_error, write_result, FFI_ERROR, FFI_OK},
+    string::str_to_cstr,
+};
 
-use super::book::OrderBook;
+use super::book::{BookIntegrityError, OrderBook};
 use crate::{
     data::{
         book::{BookOrder, OrderBookDelta},
+        book_api::{OrderBookDeltas_API, OrderBookSnapshot_API},
         tick::{QuoteTick, TradeTick},
     },
-    enums::BookType,
+    enums::{BookChecksumType, BookType, OrderSide},
     identifiers::instrument_id::InstrumentId,
     types::{price::Price, quantity::Quantity},
 };
@@ -147,6 +152,19 @@ pub extern "C" fn orderbook_apply_delta(book: &mut OrderBook_API, delta: OrderBo
     book.apply_delta(delta)
 }
 
+#[no_mangle]
+pub extern "C" fn orderbook_apply_deltas(book: &mut OrderBook_API, deltas: &OrderBookDeltas_API) {
+    book.apply_deltas(deltas.deref().clone())
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_apply_snapshot(
+    book: &mut OrderBook_API,
+    snapshot: &OrderBookSnapshot_API,
+) {
+    book.apply_snapshot(snapshot.deref().clone())
+}
+
 #[no_mangle]
 pub extern "C" fn orderbook_has_bid(book: &mut OrderBook_API) -> u8 {
     book.has_bid() as u8
@@ -157,40 +175,100 @@ pub extern "C" fn orderbook_has_ask(book: &mut OrderBook_API) -> u8 {
     book.has_ask() as u8
 }
 
+/// Returns the best bid price, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Price`].
 #[no_mangle]
-pub extern "C" fn orderbook_best_bid_price(book: &mut OrderBook_API) -> Price {
-    book.best_bid_price()
-        .expect("Error: No bid orders for best bid price")
+pub unsafe extern "C" fn orderbook_best_bid_price(book: &OrderBook_API, out: *mut Price) -> u8 {
+    let result = book
+        .best_bid_price()
+        .ok_or("No bid orders for best bid price");
+    write_result(result, out, |_| FFI_ERROR)
 }
 
+/// Returns the best ask price, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Price`].
 #[no_mangle]
-pub extern "C" fn orderbook_best_ask_price(book: &mut OrderBook_API) -> Price {
-    book.best_ask_price()
-        .expect("Error: No ask orders for best ask price")
+pub unsafe extern "C" fn orderbook_best_ask_price(book: &OrderBook_API, out: *mut Price) -> u8 {
+    let result = book
+        .best_ask_price()
+        .ok_or("No ask orders for best ask price");
+    write_result(result, out, |_| FFI_ERROR)
 }
 
+/// Returns the best bid size, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Quantity`].
 #[no_mangle]
-pub extern "C" fn orderbook_best_bid_size(book: &mut OrderBook_API) -> Quantity {
-    book.best_bid_size()
-        .expect("Error: No bid orders for best bid size")
+pub unsafe extern "C" fn orderbook_best_bid_size(book: &OrderBook_API, out: *mut Quantity) -> u8 {
+    let result = book
+        .best_bid_size()
+        .ok_or("No bid orders for best bid size");
+    write_result(result, out, |_| FFI_ERROR)
 }
 
+/// Returns the best ask size, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Quantity`].
 #[no_mangle]
-pub extern "C" fn orderbook_best_ask_size(book: &mut OrderBook_API) -> Quantity {
-    book.best_ask_size()
-        .expect("Error: No ask orders for best ask size")
+pub unsafe extern "C" fn orderbook_best_ask_size(book: &OrderBook_API, out: *mut Quantity) -> u8 {
+    let result = book
+        .best_ask_size()
+        .ok_or("No ask orders for best ask size");
+    write_result(result, out, |_| FFI_ERROR)
 }
 
+/// Returns the top of book spread, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`f64`].
 #[no_mangle]
-pub extern "C" fn orderbook_spread(book: &mut OrderBook_API) -> f64 {
-    book.spread()
-        .expect("Error: Unable to calculate `spread` (no bid or ask)")
+pub unsafe extern "C" fn orderbook_spread(book: &OrderBook_API, out: *mut f64) -> u8 {
+    let result = book
+        .spread()
+        .ok_or("Unable to calculate `spread` (no bid or ask)");
+    write_result(result, out, |_| FFI_ERROR)
 }
 
+/// Returns the mid point, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`f64`].
 #[no_mangle]
-pub extern "C" fn orderbook_midpoint(book: &mut OrderBook_API) -> f64 {
-    book.midpoint()
-        .expect("Error: Unable to calculate `midpoint` (no bid or ask)")
+pub unsafe extern "C" fn orderbook_midpoint(book: &OrderBook_API, out: *mut f64) -> u8 {
+    let result = book
+        .midpoint()
+        .ok_or("Unable to calculate `midpoint` (no bid or ask)");
+    write_result(result, out, |_| FFI_ERROR)
 }
 
 #[no_mangle]
@@ -208,9 +286,141 @@ pub extern "C" fn orderbook_simulate_fills(book: &OrderBook_API, order: BookOrde
     book.simulate_fills(&order).into()
 }
 
+/// Checks the integrity of the `book`.
+///
+/// Returns zero if every check passes, otherwise a non-zero status code with the last
+/// error set.
+#[no_mangle]
+pub extern "C" fn orderbook_check_integrity(book: &OrderBook_API) -> u8 {
+    match book.check_integrity() {
+        Ok(()) => FFI_OK,
+        Err(e) => {
+            set_last_error(e);
+            FFI_ERROR
+        }
+    }
+}
+
+/// Returns the aggregated price and size for up to `num_levels` bid levels.
+///
+/// The returned vector must be freed with `vec_fills_drop`.
+#[no_mangle]
+pub extern "C" fn orderbook_bids_depth(book: &OrderBook_API, num_levels: usize) -> CVec {
+    book.get_depth(OrderSide::Buy, num_levels).into()
+}
+
+/// Returns the aggregated price and size for up to `num_levels` ask levels.
+///
+/// The returned vector must be freed with `vec_fills_drop`.
+#[no_mangle]
+pub extern "C" fn orderbook_asks_depth(book: &OrderBook_API, num_levels: usize) -> CVec {
+    book.get_depth(OrderSide::Sell, num_levels).into()
+}
+
+/// Returns the size aggregated into `num_bands` price bands of `band_width` on the given
+/// `side`, starting from the band containing the best price.
+///
+/// The returned vector must be freed with `vec_fills_drop`.
+#[no_mangle]
+pub extern "C" fn orderbook_depth_bands(
+    book: &OrderBook_API,
+    side: OrderSide,
+    band_width: Price,
+    num_bands: usize,
+) -> CVec {
+    book.get_depth_bands(side, band_width, num_bands).into()
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_get_quantity_for_price(
+    book: &OrderBook_API,
+    price: Price,
+    side: OrderSide,
+) -> f64 {
+    book.get_quantity_for_price(price, side)
+}
+
+/// Returns the volume imbalance over the top `depth` levels (0.0 if the book is empty).
+#[no_mangle]
+pub extern "C" fn orderbook_volume_imbalance(book: &OrderBook_API, depth: usize) -> f64 {
+    book.get_volume_imbalance(depth).unwrap_or(0.0)
+}
+
+/// Returns the size weighted microprice, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`f64`].
+#[no_mangle]
+pub unsafe extern "C" fn orderbook_microprice(book: &OrderBook_API, out: *mut f64) -> u8 {
+    let result = book
+        .get_microprice()
+        .ok_or("Unable to calculate `microprice` (no bid or ask)");
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Returns the average fill price from consuming `qty` of liquidity for an order on the
+/// given `order_side`, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`f64`].
+#[no_mangle]
+pub unsafe extern "C" fn orderbook_get_avg_px_for_quantity(
+    book: &OrderBook_API,
+    qty: Quantity,
+    order_side: OrderSide,
+    out: *mut f64,
+) -> u8 {
+    let result = match order_side {
+        OrderSide::Buy | OrderSide::Sell => Ok(book.get_avg_px_for_quantity(qty, order_side)),
+        _ => Err(BookIntegrityError::NoOrderSide),
+    };
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_checksum(book: &OrderBook_API, depth: usize) -> u32 {
+    book.checksum(depth)
+}
+
+/// Returns 1 if the checksum over the top `depth` levels matches `expected`, otherwise 0.
+#[no_mangle]
+pub extern "C" fn orderbook_check_checksum(
+    book: &OrderBook_API,
+    expected: u32,
+    depth: usize,
+) -> u8 {
+    u8::from(book.check_checksum(expected, depth).is_ok())
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_set_checksum_type(
+    book: &mut OrderBook_API,
+    checksum_type: BookChecksumType,
+) {
+    book.checksum_type = Some(checksum_type);
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_venue_checksum(
+    book: &OrderBook_API,
+    checksum_type: BookChecksumType,
+) -> u32 {
+    book.venue_checksum(checksum_type)
+}
+
+/// Returns 1 if the book matches the `expected` venue checksum, or has no checksum type,
+/// otherwise 0.
 #[no_mangle]
-pub extern "C" fn orderbook_check_integrity(book: &OrderBook_API) {
-    book.check_integrity().unwrap()
+pub extern "C" fn orderbook_verify_checksum(book: &OrderBook_API, expected: u32) -> u8 {
+    u8::from(book.verify_checksum(expected).is_none())
 }
 
 // TODO: This struct implementation potentially leaks memory
@@ -218,7 +428,7 @@ pub extern "C" fn orderbook_check_integrity(book: &OrderBook_API) {
 #[allow(clippy::drop_non_drop)]
 #[no_mangle]
 pub extern "C" fn vec_fills_drop(v: CVec) {
-    let CVec { ptr, len, cap } = v;
+    let CVec { ptr, len, cap, .. } = v;
     let data: Vec<(Price, Quantity)> =
         unsafe { Vec::from_raw_parts(ptr as *mut (Price, Quantity), len, cap) };
     drop(data); // Memory freed here
@@ -0,0 +1,197 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Venue order book checksum algorithms.
+//!
+//! Each venue computes a CRC32 over a fixed number of top levels, with prices and sizes
+//! formatted as the venue sends them. The levels are formatted here at the price and size
+//! precisions of the book, so these must match the venue's precisions for the checksums
+//! to agree. Venues which specify a signed 32-bit checksum are compared on the same bits,
+//! so a signed expected value should be cast with `as u32`.
+
+use crate::{
+    enums::BookChecksumType,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Returns the number of levels per side covered by the checksum.
+#[must_use]
+pub const fn checksum_depth(checksum_type: BookChecksumType) -> usize {
+    match checksum_type {
+        BookChecksumType::Kraken => 10,
+        BookChecksumType::Okx | BookChecksumType::Bitfinex => 25,
+    }
+}
+
+/// Returns the checksum of the given levels, ordered from the top of the book and
+/// truncated to the depth of the `checksum_type`.
+#[must_use]
+pub fn compute_checksum(
+    checksum_type: BookChecksumType,
+    bids: &[(Price, Quantity)],
+    asks: &[(Price, Quantity)],
+) -> u32 {
+    let depth = checksum_depth(checksum_type);
+    let bids = &bids[..bids.len().min(depth)];
+    let asks = &asks[..asks.len().min(depth)];
+
+    let input = match checksum_type {
+        BookChecksumType::Kraken => kraken_input(bids, asks),
+        BookChecksumType::Okx => interleaved_input(bids, asks, ""),
+        BookChecksumType::Bitfinex => interleaved_input(bids, asks, "-"),
+    };
+
+    crc32fast::hash(input.as_bytes())
+}
+
+/// Concatenates the asks then the bids as `{price}{size}`, each with the decimal point and
+/// leading zeros removed.
+fn kraken_input(bids: &[(Price, Quantity)], asks: &[(Price, Quantity)]) -> String {
+    let mut input = String::new();
+    for (price, size) in asks.iter().chain(bids) {
+        input.push_str(&kraken_digits(&price.to_string()));
+        input.push_str(&kraken_digits(&size.to_string()));
+    }
+    input
+}
+
+fn kraken_digits(value: &str) -> String {
+    value.replace('.', "").trim_start_matches('0').to_string()
+}
+
+/// Interleaves the bid and ask levels as `{price}:{size}` joined by colons, continuing with
+/// the deeper side once the shallower side is exhausted.
+fn interleaved_input(
+    bids: &[(Price, Quantity)],
+    asks: &[(Price, Quantity)],
+    ask_size_prefix: &str,
+) -> String {
+    let mut parts = Vec::with_capacity(bids.len() + asks.len());
+    for i in 0..bids.len().max(asks.len()) {
+        if let Some((price, size)) = bids.get(i) {
+            parts.push(format!("{price}:{size}"));
+        }
+        if let Some((price, size)) = asks.get(i) {
+            parts.push(format!("{price}:{ask_size_prefix}{size}"));
+        }
+    }
+    parts.join(":")
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn levels(values: &[(&str, &str)]) -> Vec<(Price, Quantity)> {
+        values
+            .iter()
+            .map(|(price, size)| (Price::from(*price), Quantity::from(*size)))
+            .collect()
+    }
+
+    #[rstest]
+    #[case("0.05005", "5005")]
+    #[case("1.00000", "100000")]
+    #[case("0.00000500", "500")]
+    #[case("34500.1", "345001")]
+    fn test_kraken_digits(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(kraken_digits(value), expected);
+    }
+
+    #[test]
+    fn test_kraken_checksum() {
+        // Example from the Kraken WebSocket API book checksum documentation
+        let asks = levels(&[
+            ("0.05005", "0.00000500"),
+            ("0.05010", "0.00000500"),
+            ("0.05015", "0.00000500"),
+            ("0.05020", "0.00000500"),
+            ("0.05025", "0.00000500"),
+            ("0.05030", "0.00000500"),
+            ("0.05035", "0.00000500"),
+            ("0.05040", "0.00000500"),
+            ("0.05045", "0.00000500"),
+            ("0.05050", "0.00000500"),
+        ]);
+        let bids = levels(&[
+            ("0.05000", "0.00000500"),
+            ("0.04995", "0.00000500"),
+            ("0.04990", "0.00000500"),
+            ("0.04980", "0.00000500"),
+            ("0.04975", "0.00000500"),
+            ("0.04970", "0.00000500"),
+            ("0.04965", "0.00000500"),
+            ("0.04960", "0.00000500"),
+            ("0.04955", "0.00000500"),
+            ("0.04950", "0.00000500"),
+        ]);
+
+        assert_eq!(
+            compute_checksum(BookChecksumType::Kraken, &bids, &asks),
+            974_947_235
+        );
+    }
+
+    #[test]
+    fn test_okx_checksum() {
+        // Example from the OKX API v5 order book checksum documentation
+        let bids = levels(&[("3366.1", "7"), ("3366", "6")]);
+        let asks = levels(&[("3366.8", "9"), ("3368", "8")]);
+
+        assert_eq!(
+            compute_checksum(BookChecksumType::Okx, &bids, &asks),
+            -1_881_014_294_i32 as u32
+        );
+    }
+
+    #[test]
+    fn test_okx_input_continues_with_deeper_side() {
+        let bids = levels(&[("3366.1", "7"), ("3366.0", "6")]);
+        let asks = levels(&[("3366.8", "9")]);
+
+        assert_eq!(
+            interleaved_input(&bids, &asks, ""),
+            "3366.1:7:3366.8:9:3366.0:6"
+        );
+    }
+
+    #[test]
+    fn test_bitfinex_input_negates_ask_sizes() {
+        let bids = levels(&[("100.0", "1.5")]);
+        let asks = levels(&[("100.5", "2.0")]);
+
+        assert_eq!(interleaved_input(&bids, &asks, "-"), "100.0:1.5:100.5:-2.0");
+    }
+
+    #[test]
+    fn test_checksum_truncates_to_depth() {
+        let bids: Vec<(Price, Quantity)> = (0..12)
+            .map(|i| (Price::new(100.0 - f64::from(i), 1), Quantity::from("1")))
+            .collect();
+        let asks: Vec<(Price, Quantity)> = (0..12)
+            .map(|i| (Price::new(101.0 + f64::from(i), 1), Quantity::from("1")))
+            .collect();
+
+        assert_eq!(
+            compute_checksum(BookChecksumType::Kraken, &bids, &asks),
+            compute_checksum(BookChecksumType::Kraken, &bids[..10], &asks[..10])
+        );
+    }
+}
@@ -111,14 +111,13 @@ impl Ladder {
 
     pub fn add(&mut self, order: BookOrder) {
         let book_price = order.to_book_price();
+        self.cache.insert(order.order_id, book_price);
         match self.levels.get_mut(&book_price) {
             Some(level) => {
                 level.add(order);
             }
             None => {
-                let order_id = order.order_id;
                 let level = Level::from_order(order);
-                self.cache.insert(order_id, book_price);
                 self.levels.insert(book_price, level);
             }
         }
@@ -18,6 +18,7 @@ use std::cmp::Ordering;
 use crate::{
     data::book::BookOrder,
     orderbook::{book::BookIntegrityError, ladder::BookPrice},
+    types::quantity::Quantity,
 };
 
 #[derive(Clone, Debug, Eq)]
@@ -97,6 +98,25 @@ impl Level {
         self.orders.remove(index);
     }
 
+    /// Returns the total size of all orders at the level.
+    #[must_use]
+    pub fn size(&self) -> Quantity {
+        let precision = self.orders.first().map_or(0, |o| o.size.precision);
+        let raw = self.orders.iter().map(|o| o.size.raw).sum();
+        Quantity::from_raw(raw, precision)
+    }
+
+    /// Returns the total size of the orders queued ahead of the order with the given `order_id`.
+    ///
+    /// Returns `None` if the order is not at the level.
+    #[must_use]
+    pub fn size_ahead(&self, order_id: u64) -> Option<Quantity> {
+        let index = self.orders.iter().position(|o| o.order_id == order_id)?;
+        let precision = self.orders[index].size.precision;
+        let raw = self.orders[..index].iter().map(|o| o.size.raw).sum();
+        Some(Quantity::from_raw(raw, precision))
+    }
+
     #[must_use]
     pub fn volume(&self) -> f64 {
         let mut sum: f64 = 0.0;
@@ -317,6 +337,25 @@ mod tests {
         assert_eq!(level.exposure(), 10.0);
     }
 
+    #[test]
+    fn test_size_ahead() {
+        let mut level = Level::new(BookPrice::new(Price::new(1.00, 2), OrderSide::Buy));
+        for (order_id, size) in [(0, 10.0), (1, 20.0), (2, 30.0)] {
+            level.add(BookOrder::new(
+                OrderSide::Buy,
+                Price::new(1.00, 2),
+                Quantity::new(size, 0),
+                order_id,
+            ));
+        }
+
+        assert_eq!(level.size_ahead(0), Some(Quantity::new(0.0, 0)));
+        assert_eq!(level.size_ahead(2), Some(Quantity::new(30.0, 0)));
+        level.remove(1);
+        assert_eq!(level.size_ahead(2), Some(Quantity::new(10.0, 0)));
+        assert_eq!(level.size_ahead(1), None);
+    }
+
     #[test]
     fn test_add_bulk_orders() {
         let mut level = Level::new(BookPrice::new(Price::new(2.00, 2), OrderSide::Buy));
@@ -14,6 +14,8 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod book;
+#[cfg(feature = "ffi")]
 pub mod book_api;
+pub mod checksum;
 pub mod ladder;
 pub mod level;
@@ -0,0 +1,186 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use super::{
+    limit::LimitOrder, market::MarketOrder, stop_limit::StopLimitOrder,
+    stop_market::StopMarketOrder, trailing_stop_limit::TrailingStopLimitOrder,
+    trailing_stop_market::TrailingStopMarketOrder, Order, OrderError,
+};
+use crate::enums::OrderType;
+
+/// Wraps any of the concrete order types, so that orders of different types can be
+/// stored and handled together while retaining their type specific accessors.
+pub enum OrderAny {
+    Market(MarketOrder),
+    Limit(LimitOrder),
+    StopMarket(StopMarketOrder),
+    StopLimit(StopLimitOrder),
+    TrailingStopMarket(TrailingStopMarketOrder),
+    TrailingStopLimit(TrailingStopLimitOrder),
+}
+
+impl Deref for OrderAny {
+    type Target = Order;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            OrderAny::Market(order) => &order.core,
+            OrderAny::Limit(order) => &order.core,
+            OrderAny::StopMarket(order) => &order.core,
+            OrderAny::StopLimit(order) => &order.core,
+            OrderAny::TrailingStopMarket(order) => &order.core,
+            OrderAny::TrailingStopLimit(order) => &order.core,
+        }
+    }
+}
+
+impl DerefMut for OrderAny {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            OrderAny::Market(order) => &mut order.core,
+            OrderAny::Limit(order) => &mut order.core,
+            OrderAny::StopMarket(order) => &mut order.core,
+            OrderAny::StopLimit(order) => &mut order.core,
+            OrderAny::TrailingStopMarket(order) => &mut order.core,
+            OrderAny::TrailingStopLimit(order) => &mut order.core,
+        }
+    }
+}
+
+impl PartialEq for OrderAny {
+    fn eq(&self, other: &Self) -> bool {
+        self.client_order_id == other.client_order_id
+    }
+}
+
+impl Eq for OrderAny {}
+
+impl TryFrom<Order> for OrderAny {
+    type Error = OrderError;
+
+    fn try_from(core: Order) -> Result<Self, Self::Error> {
+        match core.order_type {
+            OrderType::Market => Ok(OrderAny::Market(MarketOrder { core })),
+            OrderType::Limit => Ok(OrderAny::Limit(LimitOrder { core })),
+            OrderType::StopMarket => Ok(OrderAny::StopMarket(StopMarketOrder { core })),
+            OrderType::StopLimit => Ok(OrderAny::StopLimit(StopLimitOrder { core })),
+            OrderType::TrailingStopMarket => {
+                Ok(OrderAny::TrailingStopMarket(TrailingStopMarketOrder {
+                    core,
+                }))
+            }
+            OrderType::TrailingStopLimit => {
+                Ok(OrderAny::TrailingStopLimit(TrailingStopLimitOrder { core }))
+            }
+            order_type => Err(OrderError::UnsupportedOrderType(order_type)),
+        }
+    }
+}
+
+impl From<OrderAny> for Order {
+    fn from(value: OrderAny) -> Self {
+        match value {
+            OrderAny::Market(order) => order.into(),
+            OrderAny::Limit(order) => order.into(),
+            OrderAny::StopMarket(order) => order.into(),
+            OrderAny::StopLimit(order) => order.into(),
+            OrderAny::TrailingStopMarket(order) => order.into(),
+            OrderAny::TrailingStopLimit(order) => order.into(),
+        }
+    }
+}
+
+macro_rules! impl_from_order_type_for_order_any {
+    ($order_type:ident, $variant:ident) => {
+        impl From<$order_type> for OrderAny {
+            fn from(value: $order_type) -> Self {
+                OrderAny::$variant(value)
+            }
+        }
+    };
+}
+
+impl_from_order_type_for_order_any!(MarketOrder, Market);
+impl_from_order_type_for_order_any!(LimitOrder, Limit);
+impl_from_order_type_for_order_any!(StopMarketOrder, StopMarket);
+impl_from_order_type_for_order_any!(StopLimitOrder, StopLimit);
+impl_from_order_type_for_order_any!(TrailingStopMarketOrder, TrailingStopMarket);
+impl_from_order_type_for_order_any!(TrailingStopLimitOrder, TrailingStopLimit);
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        enums::{OrderSide, OrderStatus},
+        events::order::{OrderEvent, OrderInitializedBuilder, OrderSubmittedBuilder},
+        types::{price::Price, quantity::Quantity},
+    };
+
+    #[test]
+    fn test_order_any_try_from_order() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .build()
+            .unwrap();
+        let order: Order = init.into();
+
+        let order_any = OrderAny::try_from(order).unwrap();
+
+        match &order_any {
+            OrderAny::Limit(order) => assert_eq!(order.price(), Price::from("1.00000")),
+            _ => panic!("expected `OrderAny::Limit`"),
+        }
+        assert_eq!(order_any.side, OrderSide::Buy);
+    }
+
+    #[test]
+    fn test_order_any_try_from_unsupported_order_type() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::MarketToLimit)
+            .build()
+            .unwrap();
+        let order: Order = init.into();
+
+        let result = OrderAny::try_from(order);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::UnsupportedOrderType(OrderType::MarketToLimit))
+        ));
+    }
+
+    #[test]
+    fn test_order_any_applies_events_to_inner_order() {
+        let init = OrderInitializedBuilder::default()
+            .quantity(Quantity::from("100"))
+            .build()
+            .unwrap();
+        let mut order_any = OrderAny::try_from(Order::from(init)).unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+
+        order_any
+            .apply(OrderEvent::OrderSubmitted(submitted))
+            .unwrap();
+
+        assert!(matches!(order_any, OrderAny::Market(_)));
+        assert_eq!(order_any.status, OrderStatus::Submitted);
+    }
+}
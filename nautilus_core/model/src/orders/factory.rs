@@ -0,0 +1,902 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::{
+    limit::LimitOrder, market::MarketOrder, stop_limit::StopLimitOrder,
+    stop_market::StopMarketOrder, trailing_stop_limit::TrailingStopLimitOrder,
+    trailing_stop_market::TrailingStopMarketOrder,
+};
+#[cfg(feature = "python")]
+use crate::events::order::OrderInitialized;
+use crate::{
+    enums::{OrderSide, TimeInForce, TrailingOffsetType, TriggerType},
+    generators::{client_order_id::ClientOrderIdGenerator, order_list_id::OrderListIdGenerator},
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+        strategy_id::StrategyId, trader_id::TraderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Provides a factory for creating the orders of a single strategy, with generated client
+/// order IDs, matching the Python `OrderFactory`.
+///
+/// Each order is created at the given `ts_init`, which is used for its initialization event
+/// and the timestamp tag of its client order ID.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "python", pyclass)]
+pub struct OrderFactory {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    order_id_generator: ClientOrderIdGenerator,
+    order_list_id_generator: OrderListIdGenerator,
+}
+
+impl OrderFactory {
+    /// Initializes a new `OrderFactory` instance, with the initial counts of the ID
+    /// generators (used when restoring state).
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        initial_order_id_count: usize,
+        initial_order_list_id_count: usize,
+    ) -> Self {
+        Self {
+            order_id_generator: ClientOrderIdGenerator::new(
+                trader_id.clone(),
+                strategy_id.clone(),
+                initial_order_id_count,
+            ),
+            order_list_id_generator: OrderListIdGenerator::new(
+                trader_id.clone(),
+                strategy_id.clone(),
+                initial_order_list_id_count,
+            ),
+            trader_id,
+            strategy_id,
+        }
+    }
+
+    /// Returns a unique client order ID for the given current timestamp `ts_now`.
+    pub fn generate_client_order_id(&mut self, ts_now: UnixNanos) -> ClientOrderId {
+        self.order_id_generator.generate(ts_now)
+    }
+
+    /// Returns a unique order list ID for the given current timestamp `ts_now`.
+    pub fn generate_order_list_id(&mut self, ts_now: UnixNanos) -> OrderListId {
+        self.order_list_id_generator.generate(ts_now)
+    }
+
+    /// Sets the internal client order ID count to `count` (used when restoring state).
+    pub fn set_client_order_id_count(&mut self, count: usize) {
+        self.order_id_generator.set_count(count);
+    }
+
+    /// Sets the internal order list ID count to `count` (used when restoring state).
+    pub fn set_order_list_id_count(&mut self, count: usize) {
+        self.order_list_id_generator.set_count(count);
+    }
+
+    /// Resets the factory, so IDs are generated from a count of zero.
+    pub fn reset(&mut self) {
+        self.order_id_generator.reset();
+        self.order_list_id_generator.reset();
+    }
+
+    /// Returns a new market order.
+    ///
+    /// # Panics
+    ///
+    /// - If `time_in_force` is `GTD` or `AT_THE_OPEN`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        time_in_force: TimeInForce,
+        reduce_only: bool,
+        quote_quantity: bool,
+        tags: Option<String>,
+        ts_init: UnixNanos,
+    ) -> MarketOrder {
+        MarketOrder::new(
+            self.trader_id.clone(),
+            self.strategy_id.clone(),
+            instrument_id,
+            self.generate_client_order_id(ts_init),
+            order_side,
+            quantity,
+            time_in_force,
+            reduce_only,
+            quote_quantity,
+            None,
+            None,
+            None,
+            None,
+            tags,
+            UUID4::new(),
+            ts_init,
+        )
+    }
+
+    /// Returns a new limit order.
+    ///
+    /// # Panics
+    ///
+    /// - If `time_in_force` is `GTD` with no `expire_time`.
+    /// - If `display_qty` is greater than `quantity`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        tags: Option<String>,
+        ts_init: UnixNanos,
+    ) -> LimitOrder {
+        LimitOrder::new(
+            self.trader_id.clone(),
+            self.strategy_id.clone(),
+            instrument_id,
+            self.generate_client_order_id(ts_init),
+            order_side,
+            quantity,
+            price,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            None,
+            None,
+            None,
+            None,
+            tags,
+            UUID4::new(),
+            ts_init,
+        )
+    }
+
+    /// Returns a new stop-market order.
+    ///
+    /// # Panics
+    ///
+    /// - If `time_in_force` is `GTD` with no `expire_time`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn stop_market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Price,
+        trigger_type: TriggerType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        reduce_only: bool,
+        quote_quantity: bool,
+        emulation_trigger: Option<TriggerType>,
+        tags: Option<String>,
+        ts_init: UnixNanos,
+    ) -> StopMarketOrder {
+        StopMarketOrder::new(
+            self.trader_id.clone(),
+            self.strategy_id.clone(),
+            instrument_id,
+            self.generate_client_order_id(ts_init),
+            order_side,
+            quantity,
+            trigger_price,
+            trigger_type,
+            time_in_force,
+            expire_time,
+            reduce_only,
+            quote_quantity,
+            emulation_trigger,
+            None,
+            None,
+            None,
+            None,
+            tags,
+            UUID4::new(),
+            ts_init,
+        )
+    }
+
+    /// Returns a new stop-limit order.
+    ///
+    /// # Panics
+    ///
+    /// - If `time_in_force` is `GTD` with no `expire_time`.
+    /// - If `display_qty` is greater than `quantity`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn stop_limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Price,
+        trigger_type: TriggerType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        tags: Option<String>,
+        ts_init: UnixNanos,
+    ) -> StopLimitOrder {
+        StopLimitOrder::new(
+            self.trader_id.clone(),
+            self.strategy_id.clone(),
+            instrument_id,
+            self.generate_client_order_id(ts_init),
+            order_side,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            None,
+            None,
+            None,
+            None,
+            tags,
+            UUID4::new(),
+            ts_init,
+        )
+    }
+
+    /// Returns a new trailing stop-market order.
+    ///
+    /// # Panics
+    ///
+    /// - If `time_in_force` is `GTD` with no `expire_time`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn trailing_stop_market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Option<Price>,
+        trigger_type: TriggerType,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        reduce_only: bool,
+        quote_quantity: bool,
+        emulation_trigger: Option<TriggerType>,
+        tags: Option<String>,
+        ts_init: UnixNanos,
+    ) -> TrailingStopMarketOrder {
+        TrailingStopMarketOrder::new(
+            self.trader_id.clone(),
+            self.strategy_id.clone(),
+            instrument_id,
+            self.generate_client_order_id(ts_init),
+            order_side,
+            quantity,
+            trigger_price,
+            trigger_type,
+            trailing_offset,
+            trailing_offset_type,
+            time_in_force,
+            expire_time,
+            reduce_only,
+            quote_quantity,
+            emulation_trigger,
+            None,
+            None,
+            None,
+            None,
+            tags,
+            UUID4::new(),
+            ts_init,
+        )
+    }
+
+    /// Returns a new trailing stop-limit order.
+    ///
+    /// # Panics
+    ///
+    /// - If `time_in_force` is `GTD` with no `expire_time`.
+    /// - If `display_qty` is greater than `quantity`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn trailing_stop_limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        trigger_type: TriggerType,
+        limit_offset: Price,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        tags: Option<String>,
+        ts_init: UnixNanos,
+    ) -> TrailingStopLimitOrder {
+        TrailingStopLimitOrder::new(
+            self.trader_id.clone(),
+            self.strategy_id.clone(),
+            instrument_id,
+            self.generate_client_order_id(ts_init),
+            order_side,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type,
+            limit_offset,
+            trailing_offset,
+            trailing_offset_type,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            None,
+            None,
+            None,
+            None,
+            tags,
+            UUID4::new(),
+            ts_init,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl OrderFactory {
+    #[new]
+    #[pyo3(signature = (trader_id, strategy_id, initial_order_id_count=0, initial_order_list_id_count=0))]
+    fn py_new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        initial_order_id_count: usize,
+        initial_order_list_id_count: usize,
+    ) -> Self {
+        Self::new(
+            trader_id,
+            strategy_id,
+            initial_order_id_count,
+            initial_order_list_id_count,
+        )
+    }
+
+    #[getter]
+    #[pyo3(name = "trader_id")]
+    fn py_trader_id(&self) -> TraderId {
+        self.trader_id.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "strategy_id")]
+    fn py_strategy_id(&self) -> StrategyId {
+        self.strategy_id.clone()
+    }
+
+    #[pyo3(name = "generate_client_order_id")]
+    fn py_generate_client_order_id(&mut self, ts_now: UnixNanos) -> ClientOrderId {
+        self.generate_client_order_id(ts_now)
+    }
+
+    #[pyo3(name = "generate_order_list_id")]
+    fn py_generate_order_list_id(&mut self, ts_now: UnixNanos) -> OrderListId {
+        self.generate_order_list_id(ts_now)
+    }
+
+    #[pyo3(name = "set_client_order_id_count")]
+    fn py_set_client_order_id_count(&mut self, count: usize) {
+        self.set_client_order_id_count(count);
+    }
+
+    #[pyo3(name = "set_order_list_id_count")]
+    fn py_set_order_list_id_count(&mut self, count: usize) {
+        self.set_order_list_id_count(count);
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "market")]
+    #[pyo3(signature = (
+        instrument_id,
+        order_side,
+        quantity,
+        ts_init,
+        time_in_force=TimeInForce::Gtc,
+        reduce_only=false,
+        quote_quantity=false,
+        tags=None,
+    ))]
+    fn py_market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        ts_init: UnixNanos,
+        time_in_force: TimeInForce,
+        reduce_only: bool,
+        quote_quantity: bool,
+        tags: Option<String>,
+    ) -> OrderInitialized {
+        let order = self.market(
+            instrument_id,
+            order_side,
+            quantity,
+            time_in_force,
+            reduce_only,
+            quote_quantity,
+            tags,
+            ts_init,
+        );
+        order.init_event()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "limit")]
+    #[pyo3(signature = (
+        instrument_id,
+        order_side,
+        quantity,
+        price,
+        ts_init,
+        time_in_force=TimeInForce::Gtc,
+        expire_time=None,
+        post_only=false,
+        reduce_only=false,
+        quote_quantity=false,
+        display_qty=None,
+        emulation_trigger=None,
+        tags=None,
+    ))]
+    fn py_limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        ts_init: UnixNanos,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        tags: Option<String>,
+    ) -> OrderInitialized {
+        let order = self.limit(
+            instrument_id,
+            order_side,
+            quantity,
+            price,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            tags,
+            ts_init,
+        );
+        order.init_event()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "stop_market")]
+    #[pyo3(signature = (
+        instrument_id,
+        order_side,
+        quantity,
+        trigger_price,
+        ts_init,
+        trigger_type=TriggerType::Default,
+        time_in_force=TimeInForce::Gtc,
+        expire_time=None,
+        reduce_only=false,
+        quote_quantity=false,
+        emulation_trigger=None,
+        tags=None,
+    ))]
+    fn py_stop_market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Price,
+        ts_init: UnixNanos,
+        trigger_type: TriggerType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        reduce_only: bool,
+        quote_quantity: bool,
+        emulation_trigger: Option<TriggerType>,
+        tags: Option<String>,
+    ) -> OrderInitialized {
+        let order = self.stop_market(
+            instrument_id,
+            order_side,
+            quantity,
+            trigger_price,
+            trigger_type,
+            time_in_force,
+            expire_time,
+            reduce_only,
+            quote_quantity,
+            emulation_trigger,
+            tags,
+            ts_init,
+        );
+        order.init_event()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "stop_limit")]
+    #[pyo3(signature = (
+        instrument_id,
+        order_side,
+        quantity,
+        price,
+        trigger_price,
+        ts_init,
+        trigger_type=TriggerType::Default,
+        time_in_force=TimeInForce::Gtc,
+        expire_time=None,
+        post_only=false,
+        reduce_only=false,
+        quote_quantity=false,
+        display_qty=None,
+        emulation_trigger=None,
+        tags=None,
+    ))]
+    fn py_stop_limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Price,
+        ts_init: UnixNanos,
+        trigger_type: TriggerType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        tags: Option<String>,
+    ) -> OrderInitialized {
+        let order = self.stop_limit(
+            instrument_id,
+            order_side,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            tags,
+            ts_init,
+        );
+        order.init_event()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "trailing_stop_market")]
+    #[pyo3(signature = (
+        instrument_id,
+        order_side,
+        quantity,
+        trailing_offset,
+        ts_init,
+        trailing_offset_type=TrailingOffsetType::Price,
+        trigger_price=None,
+        trigger_type=TriggerType::Default,
+        time_in_force=TimeInForce::Gtc,
+        expire_time=None,
+        reduce_only=false,
+        quote_quantity=false,
+        emulation_trigger=None,
+        tags=None,
+    ))]
+    fn py_trailing_stop_market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trailing_offset: Price,
+        ts_init: UnixNanos,
+        trailing_offset_type: TrailingOffsetType,
+        trigger_price: Option<Price>,
+        trigger_type: TriggerType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        reduce_only: bool,
+        quote_quantity: bool,
+        emulation_trigger: Option<TriggerType>,
+        tags: Option<String>,
+    ) -> OrderInitialized {
+        let order = self.trailing_stop_market(
+            instrument_id,
+            order_side,
+            quantity,
+            trigger_price,
+            trigger_type,
+            trailing_offset,
+            trailing_offset_type,
+            time_in_force,
+            expire_time,
+            reduce_only,
+            quote_quantity,
+            emulation_trigger,
+            tags,
+            ts_init,
+        );
+        order.init_event()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "trailing_stop_limit")]
+    #[pyo3(signature = (
+        instrument_id,
+        order_side,
+        quantity,
+        limit_offset,
+        trailing_offset,
+        ts_init,
+        trailing_offset_type=TrailingOffsetType::Price,
+        price=None,
+        trigger_price=None,
+        trigger_type=TriggerType::Default,
+        time_in_force=TimeInForce::Gtc,
+        expire_time=None,
+        post_only=false,
+        reduce_only=false,
+        quote_quantity=false,
+        display_qty=None,
+        emulation_trigger=None,
+        tags=None,
+    ))]
+    fn py_trailing_stop_limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        limit_offset: Price,
+        trailing_offset: Price,
+        ts_init: UnixNanos,
+        trailing_offset_type: TrailingOffsetType,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        trigger_type: TriggerType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        tags: Option<String>,
+    ) -> OrderInitialized {
+        let order = self.trailing_stop_limit(
+            instrument_id,
+            order_side,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type,
+            limit_offset,
+            trailing_offset,
+            trailing_offset_type,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            tags,
+            ts_init,
+        );
+        order.init_event()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::enums::{OrderStatus, OrderType};
+
+    #[fixture]
+    fn factory() -> OrderFactory {
+        OrderFactory::new(TraderId::new("TRADER-001"), StrategyId::new("S-001"), 0, 0)
+    }
+
+    fn audusd() -> InstrumentId {
+        InstrumentId::from_str("AUD/USD.SIM").unwrap()
+    }
+
+    #[rstest]
+    fn test_market(mut factory: OrderFactory) {
+        let order = factory.market(
+            audusd(),
+            OrderSide::Buy,
+            Quantity::from("100000"),
+            TimeInForce::Ioc,
+            false,
+            false,
+            Some("ENTRY".to_string()),
+            1_000,
+        );
+
+        assert_eq!(order.order_type, OrderType::Market);
+        assert_eq!(order.status, OrderStatus::Initialized);
+        assert_eq!(order.trader_id, TraderId::new("TRADER-001"));
+        assert_eq!(order.strategy_id, StrategyId::new("S-001"));
+        assert_eq!(
+            order.client_order_id,
+            ClientOrderId::new("O-19700101-0000-001-001-1")
+        );
+        assert_eq!(order.tags, Some("ENTRY".to_string()));
+        assert_eq!(order.ts_init, 1_000);
+    }
+
+    #[rstest]
+    fn test_orders_have_unique_client_order_ids(mut factory: OrderFactory) {
+        let order1 = factory.limit(
+            audusd(),
+            OrderSide::Buy,
+            Quantity::from("100000"),
+            Price::from("0.80000"),
+            TimeInForce::Gtc,
+            None,
+            true,
+            false,
+            false,
+            None,
+            None,
+            None,
+            0,
+        );
+        let order2 = factory.stop_market(
+            audusd(),
+            OrderSide::Sell,
+            Quantity::from("100000"),
+            Price::from("0.79000"),
+            TriggerType::Default,
+            TimeInForce::Gtc,
+            None,
+            true,
+            false,
+            None,
+            None,
+            0,
+        );
+
+        assert_eq!(order1.price(), Price::from("0.80000"));
+        assert!(order1.is_post_only());
+        assert!(order2.is_reduce_only);
+        assert_eq!(
+            order2.client_order_id,
+            ClientOrderId::new("O-19700101-0000-001-001-2")
+        );
+    }
+
+    #[rstest]
+    fn test_trailing_stop_limit(mut factory: OrderFactory) {
+        let order = factory.trailing_stop_limit(
+            audusd(),
+            OrderSide::Sell,
+            Quantity::from("100000"),
+            None,
+            None,
+            TriggerType::LastTrade,
+            Price::from("0.00010"),
+            Price::from("0.00050"),
+            TrailingOffsetType::Price,
+            TimeInForce::Gtc,
+            None,
+            false,
+            false,
+            false,
+            None,
+            None,
+            None,
+            0,
+        );
+
+        assert_eq!(order.order_type, OrderType::TrailingStopLimit);
+        assert_eq!(order.limit_offset, Some(Price::from("0.00010")));
+        assert_eq!(order.trailing_offset, Some(Price::from("0.00050")));
+    }
+
+    #[rstest]
+    fn test_reset_and_restore_counts(mut factory: OrderFactory) {
+        factory.generate_client_order_id(0);
+        factory.generate_order_list_id(0);
+
+        factory.reset();
+        assert_eq!(
+            factory.generate_client_order_id(0),
+            ClientOrderId::new("O-19700101-0000-001-001-1")
+        );
+
+        factory.set_client_order_id_count(10);
+        factory.set_order_list_id_count(5);
+        assert_eq!(
+            factory.generate_client_order_id(0),
+            ClientOrderId::new("O-19700101-0000-001-001-11")
+        );
+        assert_eq!(
+            factory.generate_order_list_id(0),
+            OrderListId::new("OL-19700101-0000-001-001-6")
+        );
+    }
+}
@@ -13,11 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::ops::{Deref, DerefMut};
+
 use nautilus_core::{time::UnixNanos, uuid::UUID4};
 
 use super::Order;
 use crate::{
-    enums::{ContingencyType, OrderSide, OrderStatus, OrderType, TimeInForce, TriggerType},
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TriggerType},
+    events::order::OrderInitialized,
     identifiers::{
         client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
         strategy_id::StrategyId, trader_id::TraderId,
@@ -25,38 +28,19 @@ use crate::{
     types::{price::Price, quantity::Quantity},
 };
 
-pub trait LimitOrder {
-    #[must_use]
-    #[allow(clippy::too_many_arguments)]
-    fn new(
-        trader_id: TraderId,
-        strategy_id: StrategyId,
-        instrument_id: InstrumentId,
-        client_order_id: ClientOrderId,
-        order_side: OrderSide,
-        quantity: Quantity,
-        price: Price,
-        time_in_force: TimeInForce,
-        expire_time: Option<UnixNanos>,
-        post_only: bool,
-        reduce_only: bool,
-        quote_quantity: bool,
-        display_qty: Option<Quantity>,
-        emulation_trigger: Option<TriggerType>,
-        contingency_type: Option<ContingencyType>,
-        order_list_id: Option<OrderListId>,
-        linked_order_ids: Option<Vec<ClientOrderId>>,
-        parent_order_id: Option<ClientOrderId>,
-        tags: Option<String>,
-        init_id: UUID4,
-        ts_init: UnixNanos,
-    ) -> Self;
-
-    fn price(&self) -> &Price;
+/// Represents an order to buy or sell at a specified price or better.
+pub struct LimitOrder {
+    pub(super) core: Order,
 }
 
-impl LimitOrder for Order {
-    fn new(
+impl LimitOrder {
+    /// # Panics
+    ///
+    /// If `time_in_force` is `GTD` with no `expire_time`, or `display_qty` is greater
+    /// than `quantity`.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
         trader_id: TraderId,
         strategy_id: StrategyId,
         instrument_id: InstrumentId,
@@ -79,22 +63,23 @@ impl LimitOrder for Order {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
-        Self {
-            events: Vec::new(),
-            venue_order_ids: Vec::new(),
-            trade_ids: Vec::new(),
-            previous_status: None,
-            triggered_price: None,
-            status: OrderStatus::Initialized,
+        assert!(
+            time_in_force != TimeInForce::Gtd || expire_time.is_some(),
+            "Invalid `LimitOrder`: `expire_time` was `None` for GTD order"
+        );
+        if let Some(display_qty) = display_qty {
+            assert!(
+                display_qty <= quantity,
+                "Invalid `LimitOrder`: `display_qty` was greater than `quantity`"
+            );
+        }
+
+        let init = OrderInitialized {
             trader_id,
             strategy_id,
             instrument_id,
             client_order_id,
-            venue_order_id: None,
-            position_id: None,
-            account_id: None,
-            last_trade_id: None,
-            side: order_side,
+            order_side,
             order_type: OrderType::Limit,
             quantity,
             price: Some(price),
@@ -102,10 +87,9 @@ impl LimitOrder for Order {
             trigger_type: None,
             time_in_force,
             expire_time,
-            liquidity_side: None,
-            is_post_only: post_only,
-            is_reduce_only: reduce_only,
-            is_quote_quantity: quote_quantity,
+            post_only,
+            reduce_only,
+            quote_quantity,
             display_qty,
             limit_offset: None,
             trailing_offset: None,
@@ -116,21 +100,119 @@ impl LimitOrder for Order {
             linked_order_ids,
             parent_order_id,
             tags,
-            filled_qty: Quantity::new(0.0, 0),
-            leaves_qty: quantity,
-            avg_px: None,
-            slippage: None,
-            init_id,
-            ts_triggered: None,
+            metadata: Default::default(),
+            event_id: init_id,
+            ts_event: ts_init,
             ts_init,
-            ts_last: ts_init,
-        }
+            reconciliation: false,
+        };
+
+        Self { core: init.into() }
     }
 
-    fn price(&self) -> &Price {
-        match &self.price {
-            Some(price) => price,
-            _ => panic!("Invalid `LimitOrder`: did not have a price"),
-        }
+    #[must_use]
+    pub fn price(&self) -> Price {
+        self.core
+            .price
+            .unwrap_or_else(|| panic!("Invalid `LimitOrder`: did not have a price"))
+    }
+
+    #[must_use]
+    pub fn expire_time(&self) -> Option<UnixNanos> {
+        self.core.expire_time
+    }
+
+    #[must_use]
+    pub fn display_qty(&self) -> Option<Quantity> {
+        self.core.display_qty
+    }
+
+    #[must_use]
+    pub fn is_post_only(&self) -> bool {
+        self.core.is_post_only
+    }
+}
+
+impl Deref for LimitOrder {
+    type Target = Order;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for LimitOrder {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl From<LimitOrder> for Order {
+    fn from(value: LimitOrder) -> Self {
+        value.core
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::OrderStatus;
+
+    fn limit_order(
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        display_qty: Option<Quantity>,
+    ) -> LimitOrder {
+        LimitOrder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::default(),
+            ClientOrderId::default(),
+            OrderSide::Buy,
+            Quantity::from("100"),
+            Price::from("1.00000"),
+            time_in_force,
+            expire_time,
+            true,
+            false,
+            false,
+            display_qty,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::default(),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_limit_order_new() {
+        let order = limit_order(TimeInForce::Gtd, Some(1_000), Some(Quantity::from("10")));
+
+        assert_eq!(order.order_type, OrderType::Limit);
+        assert_eq!(order.status, OrderStatus::Initialized);
+        assert_eq!(order.price(), Price::from("1.00000"));
+        assert_eq!(order.expire_time(), Some(1_000));
+        assert_eq!(order.display_qty(), Some(Quantity::from("10")));
+        assert!(order.is_post_only());
+        assert!(order.is_passive());
+    }
+
+    #[test]
+    #[should_panic(expected = "`expire_time` was `None` for GTD order")]
+    fn test_limit_order_gtd_without_expire_time_panics() {
+        let _ = limit_order(TimeInForce::Gtd, None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "`display_qty` was greater than `quantity`")]
+    fn test_limit_order_display_qty_greater_than_quantity_panics() {
+        let _ = limit_order(TimeInForce::Gtc, None, Some(Quantity::from("101")));
     }
 }
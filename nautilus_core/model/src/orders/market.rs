@@ -0,0 +1,168 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+
+use super::Order;
+use crate::{
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce},
+    events::order::OrderInitialized,
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+        strategy_id::StrategyId, trader_id::TraderId,
+    },
+    types::quantity::Quantity,
+};
+
+/// Represents an order to buy or sell at the best available price.
+pub struct MarketOrder {
+    pub(super) core: Order,
+}
+
+impl MarketOrder {
+    /// # Panics
+    ///
+    /// If `time_in_force` is `GTD` or `AT_THE_OPEN`.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        time_in_force: TimeInForce,
+        reduce_only: bool,
+        quote_quantity: bool,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        assert!(
+            time_in_force != TimeInForce::Gtd && time_in_force != TimeInForce::AtTheOpen,
+            "Invalid `MarketOrder`: `time_in_force` cannot be {time_in_force}"
+        );
+
+        let init = OrderInitialized {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            order_type: OrderType::Market,
+            quantity,
+            price: None,
+            trigger_price: None,
+            trigger_type: None,
+            time_in_force,
+            expire_time: None,
+            post_only: false,
+            reduce_only,
+            quote_quantity,
+            display_qty: None,
+            limit_offset: None,
+            trailing_offset: None,
+            trailing_offset_type: None,
+            emulation_trigger: None,
+            contingency_type,
+            order_list_id,
+            linked_order_ids,
+            parent_order_id,
+            tags,
+            metadata: Default::default(),
+            event_id: init_id,
+            ts_event: ts_init,
+            ts_init,
+            reconciliation: false,
+        };
+
+        Self { core: init.into() }
+    }
+}
+
+impl Deref for MarketOrder {
+    type Target = Order;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for MarketOrder {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl From<MarketOrder> for Order {
+    fn from(value: MarketOrder) -> Self {
+        value.core
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::OrderStatus;
+
+    fn market_order(time_in_force: TimeInForce) -> MarketOrder {
+        MarketOrder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::default(),
+            ClientOrderId::default(),
+            OrderSide::Buy,
+            Quantity::from("100"),
+            time_in_force,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::default(),
+            1,
+        )
+    }
+
+    #[test]
+    fn test_market_order_new() {
+        let order = market_order(TimeInForce::Ioc);
+
+        assert_eq!(order.order_type, OrderType::Market);
+        assert_eq!(order.status, OrderStatus::Initialized);
+        assert_eq!(order.leaves_qty, Quantity::from("100"));
+        assert_eq!(order.price, None);
+        assert!(order.is_aggressive());
+        assert_eq!(order.ts_init, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid `MarketOrder`")]
+    fn test_market_order_with_gtd_panics() {
+        let _ = market_order(TimeInForce::Gtd);
+    }
+}
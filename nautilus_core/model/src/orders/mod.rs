@@ -15,7 +15,19 @@
 
 #![allow(dead_code)]
 
+pub mod any;
+pub mod factory;
 pub mod limit;
+pub mod market;
+#[cfg(feature = "ffi")]
+pub mod order_api;
+pub mod stop_limit;
+pub mod stop_market;
+pub mod trailing;
+pub mod trailing_stop_limit;
+pub mod trailing_stop_market;
+
+use std::collections::BTreeMap;
 
 use nautilus_core::{time::UnixNanos, uuid::UUID4};
 use thiserror::Error;
@@ -23,7 +35,7 @@ use thiserror::Error;
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, PositionSide,
-        TimeInForce, TriggerType,
+        TimeInForce, TrailingOffsetType, TriggerType,
     },
     events::order::{
         OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEvent, OrderExpired,
@@ -38,17 +50,53 @@ use crate::{
     types::{fixed::fixed_i64_to_f64, price::Price, quantity::Quantity},
 };
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum OrderError {
-    #[error("Invalid state transition")]
-    InvalidStateTransition,
-    #[error("Unrecognized event")]
-    UnrecognizedEvent,
+    #[error("Invalid state transition from {status} on {event}")]
+    InvalidStateTransition {
+        status: OrderStatus,
+        event: &'static str,
+    },
+    #[error("Unrecognized event {0}")]
+    UnrecognizedEvent(&'static str),
+    #[error("Event {event} for {event_client_order_id} does not apply to order {client_order_id}")]
+    ClientOrderIdMismatch {
+        event: &'static str,
+        event_client_order_id: ClientOrderId,
+        client_order_id: ClientOrderId,
+    },
+    #[error("Duplicate {0}")]
+    DuplicateTradeId(TradeId),
+    #[error(
+        "Fill of {last_qty} would overfill order {client_order_id} with leaves of {leaves_qty}"
+    )]
+    Overfill {
+        client_order_id: ClientOrderId,
+        last_qty: Quantity,
+        leaves_qty: Quantity,
+    },
+    #[error("Unsupported order type {0}")]
+    UnsupportedOrderType(OrderType),
+}
+
+impl OrderError {
+    /// Returns the error code for passing across the FFI boundary (0 is reserved for success).
+    #[must_use]
+    pub fn code(&self) -> u8 {
+        match self {
+            OrderError::InvalidStateTransition { .. } => 1,
+            OrderError::UnrecognizedEvent(_) => 2,
+            OrderError::ClientOrderIdMismatch { .. } => 3,
+            OrderError::DuplicateTradeId(_) => 4,
+            OrderError::Overfill { .. } => 5,
+            OrderError::UnsupportedOrderType(_) => 6,
+        }
+    }
 }
 
 impl OrderStatus {
     #[rustfmt::skip]
-    pub fn transition(&mut self, event: &OrderEvent) -> Result<OrderStatus, OrderError> {
+    pub fn transition(&self, event: &OrderEvent) -> Result<OrderStatus, OrderError> {
         let new_state = match (self, event) {
             (OrderStatus::Initialized, OrderEvent::OrderDenied(_)) => OrderStatus::Denied,
             (OrderStatus::Initialized, OrderEvent::OrderSubmitted(_)) => OrderStatus::Submitted,
@@ -103,13 +151,19 @@ impl OrderStatus {
             (OrderStatus::PartiallyFilled, OrderEvent::OrderExpired(_)) => OrderStatus::Expired,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,
-            _ => return Err(OrderError::InvalidStateTransition),
+            _ => {
+                return Err(OrderError::InvalidStateTransition {
+                    status: *self,
+                    event: event.name(),
+                })
+            }
         };
         Ok(new_state)
     }
 }
 
-struct Order {
+#[derive(Clone, Debug)]
+pub struct Order {
     events: Vec<OrderEvent>,
     venue_order_ids: Vec<VenueOrderId>, // TODO(cs): Should be `Vec<&VenueOrderId>` or similar
     trade_ids: Vec<TradeId>,            // TODO(cs): Should be `Vec<&TradeId>` or similar
@@ -139,13 +193,14 @@ struct Order {
     pub display_qty: Option<Quantity>,
     pub limit_offset: Option<Price>,
     pub trailing_offset: Option<Price>,
-    pub trailing_offset_type: Option<TriggerType>,
+    pub trailing_offset_type: Option<TrailingOffsetType>,
     pub emulation_trigger: Option<TriggerType>,
     pub contingency_type: Option<ContingencyType>,
     pub order_list_id: Option<OrderListId>,
     pub linked_order_ids: Option<Vec<ClientOrderId>>,
     pub parent_order_id: Option<ClientOrderId>,
     pub tags: Option<String>,
+    pub metadata: BTreeMap<String, String>,
     pub filled_qty: Quantity,
     pub leaves_qty: Quantity,
     pub avg_px: Option<f64>,
@@ -188,22 +243,23 @@ impl From<OrderInitialized> for Order {
             trigger_price: value.trigger_price,
             trigger_type: value.trigger_type,
             time_in_force: value.time_in_force,
-            expire_time: None,
+            expire_time: value.expire_time,
             liquidity_side: None,
             is_post_only: value.post_only,
             is_reduce_only: value.reduce_only,
             is_quote_quantity: value.quote_quantity,
-            display_qty: None,
-            limit_offset: None,
-            trailing_offset: None,
-            trailing_offset_type: None,
+            display_qty: value.display_qty,
+            limit_offset: value.limit_offset,
+            trailing_offset: value.trailing_offset,
+            trailing_offset_type: value.trailing_offset_type,
             emulation_trigger: value.emulation_trigger,
             contingency_type: value.contingency_type,
             order_list_id: value.order_list_id,
             linked_order_ids: value.linked_order_ids,
             parent_order_id: value.parent_order_id,
             tags: value.tags,
-            filled_qty: Quantity::new(0.0, 0),
+            metadata: value.metadata,
+            filled_qty: Quantity::zero(value.quantity.precision),
             leaves_qty: value.quantity,
             avg_px: None,
             slippage: None,
@@ -226,7 +282,7 @@ impl From<&Order> for OrderInitialized {
             order_type: value.order_type,
             quantity: value.quantity,
             price: value.price,
-            trigger_price: value.triggered_price,
+            trigger_price: value.trigger_price,
             trigger_type: value.trigger_type,
             time_in_force: value.time_in_force,
             expire_time: value.expire_time,
@@ -243,7 +299,8 @@ impl From<&Order> for OrderInitialized {
             linked_order_ids: value.linked_order_ids.clone(),
             parent_order_id: value.parent_order_id.clone(),
             tags: value.tags.clone(),
-            event_id: value.init_id.clone(),
+            metadata: value.metadata.clone(),
+            event_id: value.init_id,
             ts_event: value.ts_init,
             ts_init: value.ts_init,
             reconciliation: false,
@@ -252,6 +309,73 @@ impl From<&Order> for OrderInitialized {
 }
 
 impl Order {
+    /// Reconstructs an order by replaying the given `events`.
+    ///
+    /// # Errors
+    ///
+    /// If `events` does not begin with an `OrderInitialized` event, or any subsequent
+    /// event fails to apply.
+    pub fn from_events(events: Vec<OrderEvent>) -> Result<Self, OrderError> {
+        let mut iter = events.into_iter();
+        let mut order: Order = match iter.next() {
+            Some(OrderEvent::OrderInitialized(init)) => init.into(),
+            Some(event) => return Err(OrderError::UnrecognizedEvent(event.name())),
+            None => return Err(OrderError::UnrecognizedEvent("None")),
+        };
+
+        for event in iter {
+            order.apply(event)?;
+        }
+        Ok(order)
+    }
+
+    /// Returns the initialization event of the order, built from its current fields.
+    ///
+    /// # Panics
+    ///
+    /// - If the order is not in an `INITIALIZED` state (its fields may have been updated).
+    #[must_use]
+    pub fn init_event(&self) -> OrderInitialized {
+        assert_eq!(
+            self.status,
+            OrderStatus::Initialized,
+            "Order {} is no longer in an initialized state",
+            self.client_order_id
+        );
+        OrderInitialized {
+            trader_id: self.trader_id.clone(),
+            strategy_id: self.strategy_id.clone(),
+            instrument_id: self.instrument_id.clone(),
+            client_order_id: self.client_order_id.clone(),
+            order_side: self.side,
+            order_type: self.order_type,
+            quantity: self.quantity,
+            price: self.price,
+            trigger_price: self.trigger_price,
+            trigger_type: self.trigger_type,
+            time_in_force: self.time_in_force,
+            expire_time: self.expire_time,
+            post_only: self.is_post_only,
+            reduce_only: self.is_reduce_only,
+            quote_quantity: self.is_quote_quantity,
+            display_qty: self.display_qty,
+            limit_offset: self.limit_offset,
+            trailing_offset: self.trailing_offset,
+            trailing_offset_type: self.trailing_offset_type,
+            emulation_trigger: self.emulation_trigger,
+            contingency_type: self.contingency_type,
+            order_list_id: self.order_list_id.clone(),
+            linked_order_ids: self.linked_order_ids.clone(),
+            parent_order_id: self.parent_order_id.clone(),
+            tags: self.tags.clone(),
+            metadata: self.metadata.clone(),
+            event_id: self.init_id,
+            ts_event: self.ts_init,
+            ts_init: self.ts_init,
+            reconciliation: false,
+        }
+    }
+
     pub fn last_event(&self) -> Option<&OrderEvent> {
         self.events.last()
     }
@@ -260,6 +384,20 @@ impl Order {
         self.events.clone()
     }
 
+    #[must_use]
+    pub fn get_metadata(&self, key: &str) -> Option<&str> {
+        self.metadata.get(key).map(String::as_str)
+    }
+
+    /// Sets the metadata `value` for the `key`, returning the previous value (if any).
+    pub fn set_metadata(&mut self, key: &str, value: &str) -> Option<String> {
+        self.metadata.insert(key.to_string(), value.to_string())
+    }
+
+    pub fn remove_metadata(&mut self, key: &str) -> Option<String> {
+        self.metadata.remove(key)
+    }
+
     pub fn event_count(&self) -> usize {
         self.events.len()
     }
@@ -374,10 +512,37 @@ impl Order {
         }
     }
 
+    /// Apply the given `event` to the order, transitioning its status.
+    ///
+    /// # Errors
+    ///
+    /// If the event is for a different order, is a duplicate or overfilling fill, or is not a
+    /// valid transition from the current status. The order is left unchanged on error.
     pub fn apply(&mut self, event: OrderEvent) -> Result<(), OrderError> {
-        let new_status = self.status.transition(&event)?;
-        self.previous_status = Some(self.status);
-        self.status = new_status;
+        if event.client_order_id() != &self.client_order_id {
+            return Err(OrderError::ClientOrderIdMismatch {
+                event: event.name(),
+                event_client_order_id: event.client_order_id().clone(),
+                client_order_id: self.client_order_id.clone(),
+            });
+        }
+
+        if let OrderEvent::OrderPartiallyFilled(fill) | OrderEvent::OrderFilled(fill) = &event {
+            self.check_fill(fill)?;
+        }
+
+        let new_status = match &event {
+            OrderEvent::OrderUpdated(_) | OrderEvent::OrderModifyRejected(_) => {
+                self.restore_status(OrderStatus::PendingUpdate)
+            }
+            OrderEvent::OrderCancelRejected(_) => self.restore_status(OrderStatus::PendingCancel),
+            _ => self.status.transition(&event)?,
+        };
+
+        if new_status != self.status {
+            self.previous_status = Some(self.status);
+            self.status = new_status;
+        }
 
         match &event {
             OrderEvent::OrderDenied(event) => self.denied(event),
@@ -392,13 +557,44 @@ impl Order {
             OrderEvent::OrderTriggered(event) => self.triggered(event),
             OrderEvent::OrderCanceled(event) => self.canceled(event),
             OrderEvent::OrderExpired(event) => self.expired(event),
-            _ => return Err(OrderError::UnrecognizedEvent),
+            OrderEvent::OrderPartiallyFilled(event) => self.filled(event),
+            OrderEvent::OrderFilled(event) => self.filled(event),
+            OrderEvent::OrderInitialized(_) => {
+                return Err(OrderError::UnrecognizedEvent(event.name()))
+            }
         }
 
+        self.ts_last = event.ts_event();
         self.events.push(event);
         Ok(())
     }
 
+    /// Returns the status prior to a pending request if the order is currently `pending`,
+    /// otherwise the current status.
+    fn restore_status(&self, pending: OrderStatus) -> OrderStatus {
+        if self.status == pending {
+            self.previous_status.unwrap_or(self.status)
+        } else {
+            self.status
+        }
+    }
+
+    fn check_fill(&self, fill: &OrderFilled) -> Result<(), OrderError> {
+        if self.trade_ids.contains(&fill.trade_id) {
+            return Err(OrderError::DuplicateTradeId(fill.trade_id.clone()));
+        }
+
+        if fill.last_qty.raw > self.leaves_qty.raw {
+            return Err(OrderError::Overfill {
+                client_order_id: self.client_order_id.clone(),
+                last_qty: fill.last_qty,
+                leaves_qty: self.leaves_qty,
+            });
+        }
+
+        Ok(())
+    }
+
     fn denied(&self, _event: &OrderDenied) {
         // Do nothing else
     }
@@ -423,15 +619,17 @@ impl Order {
         // Do nothing else
     }
 
-    fn modify_rejected(&mut self, _event: &OrderModifyRejected) {
-        self.status = self.previous_status.unwrap();
+    fn modify_rejected(&self, _event: &OrderModifyRejected) {
+        // Do nothing else
     }
 
-    fn cancel_rejected(&mut self, _event: &OrderCancelRejected) {
-        self.status = self.previous_status.unwrap();
+    fn cancel_rejected(&self, _event: &OrderCancelRejected) {
+        // Do nothing else
     }
 
-    fn triggered(&mut self, _event: &OrderTriggered) {}
+    fn triggered(&mut self, event: &OrderTriggered) {
+        self.ts_triggered = Some(event.ts_event);
+    }
 
     fn canceled(&mut self, _event: &OrderCanceled) {}
 
@@ -478,26 +676,22 @@ impl Order {
         self.trade_ids.push(event.trade_id.clone());
         self.last_trade_id = Some(event.trade_id.clone());
         self.liquidity_side = Some(event.liquidity_side);
+        self.set_avg_px(&event.last_qty, &event.last_px);
         self.filled_qty += &event.last_qty;
         self.leaves_qty -= &event.last_qty;
-        self.ts_last = event.ts_event;
-        self.set_avg_px(&event.last_qty, &event.last_px);
         self.set_slippage();
     }
 
+    /// Must be called prior to updating `filled_qty` for the fill.
     fn set_avg_px(&mut self, last_qty: &Quantity, last_px: &Price) {
-        if self.avg_px.is_none() {
-            self.avg_px = Some(last_px.as_f64());
-        }
-
-        let filled_qty = self.filled_qty.as_f64();
-        let total_qty = filled_qty + last_qty.as_f64();
-
-        let avg_px = self
-            .avg_px
-            .unwrap()
-            .mul_add(filled_qty, last_px.as_f64() * last_qty.as_f64())
-            / total_qty;
+        let avg_px = match self.avg_px {
+            None => last_px.as_f64(),
+            Some(avg_px) => {
+                let filled_qty = self.filled_qty.as_f64();
+                let total_qty = filled_qty + last_qty.as_f64();
+                avg_px.mul_add(filled_qty, last_px.as_f64() * last_qty.as_f64()) / total_qty
+            }
+        };
         self.avg_px = Some(avg_px);
     }
 
@@ -524,11 +718,13 @@ mod tests {
 
     use super::*;
     use crate::{
+        currencies::USD,
         enums::{OrderSide, OrderStatus, PositionSide},
         events::order::{
             OrderAcceptedBuilder, OrderDeniedBuilder, OrderEvent, OrderInitializedBuilder,
-            OrderSubmittedBuilder,
+            OrderModifyRejectedBuilder, OrderPendingUpdateBuilder, OrderSubmittedBuilder,
         },
+        types::money::Money,
     };
 
     #[test]
@@ -555,6 +751,35 @@ mod tests {
         assert!(!order.is_pending_cancel());
     }
 
+    #[test]
+    fn test_order_init_event_round_trip() {
+        let init = OrderInitializedBuilder::default()
+            .tags(Some("ENTRY".to_string()))
+            .build()
+            .unwrap();
+        let order: Order = init.clone().into();
+
+        assert_eq!(order.init_event(), init);
+    }
+
+    #[test]
+    fn test_order_metadata() {
+        let mut order: Order = OrderInitializedBuilder::default().build().unwrap().into();
+
+        assert_eq!(order.set_metadata("exec_inst", "ALO"), None);
+        assert_eq!(
+            order.set_metadata("exec_inst", "POST_ONLY"),
+            Some("ALO".to_string())
+        );
+        assert_eq!(order.get_metadata("exec_inst"), Some("POST_ONLY"));
+        assert_eq!(order.init_event().metadata, order.metadata);
+        assert_eq!(
+            order.remove_metadata("exec_inst"),
+            Some("POST_ONLY".to_string())
+        );
+        assert_eq!(order.get_metadata("exec_inst"), None);
+    }
+
     #[rstest(
         order_side,
         expected_side,
@@ -627,31 +852,194 @@ mod tests {
         assert_eq!(order.last_event(), Some(&event));
     }
 
-    #[test]
-    fn test_buy_order_life_cyle_to_filled() {
-        // TODO: We should be able to derive defaults for the below?
-        let init = OrderInitializedBuilder::default().build().unwrap();
+    fn fill_event(order: &Order, trade_id: &str, last_qty: &str, last_px: &str) -> OrderFilled {
+        OrderFilled {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: VenueOrderId::default(),
+            account_id: AccountId::default(),
+            trade_id: TradeId::new(trade_id),
+            position_id: None,
+            order_side: order.side,
+            order_type: order.order_type,
+            last_qty: Quantity::from(last_qty),
+            last_px: Price::from(last_px),
+            currency: USD.clone(),
+            commission: Money::new(0.0, USD.clone()),
+            liquidity_side: LiquiditySide::Taker,
+            event_id: UUID4::default(),
+            ts_event: 1,
+            ts_init: 1,
+            reconciliation: false,
+        }
+    }
+
+    fn accepted_order(quantity: &str) -> Order {
+        let init = OrderInitializedBuilder::default()
+            .quantity(Quantity::from(quantity))
+            .build()
+            .unwrap();
         let submitted = OrderSubmittedBuilder::default().build().unwrap();
         let accepted = OrderAcceptedBuilder::default().build().unwrap();
-        // let filled = OrderFilledBuilder::default()
-        //     .ids(init.ids.clone())
-        //     .account_id(AccountId::default())
-        //     .venue_order_id(VenueOrderId::default())
-        //     .position_id(None)
-        //     .trade_id(TradeId::new("001"))
-        //     .event_id(UUID4::default())
-        //     .ts_event(UnixNanos::default())
-        //     .ts_init(UnixNanos::default())
-        //     .reconciliation(false)
-        //     .build()
-        //     .unwrap();
-
-        let client_order_id = init.client_order_id.clone();
+
         let mut order: Order = init.into();
-        let _ = order.apply(OrderEvent::OrderSubmitted(submitted));
-        let _ = order.apply(OrderEvent::OrderAccepted(accepted));
-        // let _ = order.apply(OrderEvent::OrderFilled(filled));
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+    }
+
+    #[test]
+    fn test_buy_order_life_cyle_to_filled() {
+        let mut order = accepted_order("100");
+        let filled = fill_event(&order, "001", "100", "1.00000");
+        order.apply(OrderEvent::OrderFilled(filled)).unwrap();
+
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(order.previous_status, Some(OrderStatus::Accepted));
+        assert_eq!(order.filled_qty, Quantity::from("100"));
+        assert_eq!(order.leaves_qty, Quantity::from("0"));
+        assert_eq!(order.avg_px, Some(1.0));
+        assert_eq!(order.trade_ids, vec![TradeId::new("001")]);
+        assert_eq!(order.event_count(), 2);
+        assert_eq!(order.ts_last, 1);
+        assert!(order.is_closed());
+    }
+
+    #[test]
+    fn test_order_partial_fills_calculate_avg_px() {
+        let mut order = accepted_order("100");
+        let fill1 = fill_event(&order, "001", "50", "1.00000");
+        let fill2 = fill_event(&order, "002", "50", "2.00000");
+        order
+            .apply(OrderEvent::OrderPartiallyFilled(fill1))
+            .unwrap();
+        assert_eq!(order.status, OrderStatus::PartiallyFilled);
+        assert_eq!(order.leaves_qty, Quantity::from("50"));
+
+        order.apply(OrderEvent::OrderFilled(fill2)).unwrap();
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(order.avg_px, Some(1.5));
+    }
+
+    #[test]
+    fn test_order_invalid_state_transition() {
+        let mut order: Order = OrderInitializedBuilder::default().build().unwrap().into();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        order
+            .apply(OrderEvent::OrderAccepted(accepted.clone()))
+            .unwrap();
+
+        let result = order.apply(OrderEvent::OrderSubmitted(
+            OrderSubmittedBuilder::default().build().unwrap(),
+        ));
+
+        assert_eq!(
+            result,
+            Err(OrderError::InvalidStateTransition {
+                status: OrderStatus::Accepted,
+                event: "OrderSubmitted",
+            })
+        );
+        assert_eq!(order.status, OrderStatus::Accepted);
+        assert_eq!(order.event_count(), 1);
+    }
 
-        assert_eq!(order.client_order_id, client_order_id);
+    #[test]
+    fn test_order_duplicate_trade_id() {
+        let mut order = accepted_order("100");
+        let fill = fill_event(&order, "001", "50", "1.00000");
+        order
+            .apply(OrderEvent::OrderPartiallyFilled(fill.clone()))
+            .unwrap();
+
+        let result = order.apply(OrderEvent::OrderPartiallyFilled(fill));
+
+        assert_eq!(
+            result,
+            Err(OrderError::DuplicateTradeId(TradeId::new("001")))
+        );
+        assert_eq!(order.filled_qty, Quantity::from("50"));
+    }
+
+    #[test]
+    fn test_order_overfill() {
+        let mut order = accepted_order("100");
+        let fill = fill_event(&order, "001", "101", "1.00000");
+
+        let result = order.apply(OrderEvent::OrderFilled(fill));
+
+        assert_eq!(result.unwrap_err().code(), 5);
+        assert_eq!(order.status, OrderStatus::Accepted);
+    }
+
+    #[test]
+    fn test_order_modify_rejected_restores_previous_status() {
+        let mut order = accepted_order("100");
+        let pending = OrderPendingUpdateBuilder::default().build().unwrap();
+        let rejected = OrderModifyRejectedBuilder::default().build().unwrap();
+
+        order
+            .apply(OrderEvent::OrderPendingUpdate(pending))
+            .unwrap();
+        assert!(order.is_pending_update());
+
+        order
+            .apply(OrderEvent::OrderModifyRejected(rejected))
+            .unwrap();
+        assert_eq!(order.status, OrderStatus::Accepted);
+    }
+
+    #[test]
+    fn test_order_from_events() {
+        let init = OrderInitializedBuilder::default()
+            .quantity(Quantity::from("100"))
+            .build()
+            .unwrap();
+        let mut order: Order = init.clone().into();
+        let filled = fill_event(&order, "001", "100", "1.00000");
+        let events = vec![
+            OrderEvent::OrderInitialized(init),
+            OrderEvent::OrderSubmitted(OrderSubmittedBuilder::default().build().unwrap()),
+            OrderEvent::OrderAccepted(OrderAcceptedBuilder::default().build().unwrap()),
+            OrderEvent::OrderFilled(filled),
+        ];
+        for event in events.iter().skip(1) {
+            order.apply(event.clone()).unwrap();
+        }
+
+        let replayed = Order::from_events(events).unwrap();
+
+        assert_eq!(replayed.status, OrderStatus::Filled);
+        assert_eq!(replayed.filled_qty, order.filled_qty);
+        assert_eq!(replayed.avg_px, order.avg_px);
+        assert_eq!(replayed.events(), order.events());
+    }
+
+    #[test]
+    fn test_order_from_events_without_initialized() {
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+
+        let result = Order::from_events(vec![OrderEvent::OrderSubmitted(submitted)]);
+
+        assert_eq!(
+            result.err(),
+            Some(OrderError::UnrecognizedEvent("OrderSubmitted"))
+        );
+    }
+
+    #[test]
+    fn test_order_rejects_event_for_other_order() {
+        let mut order: Order = OrderInitializedBuilder::default().build().unwrap().into();
+        let submitted = OrderSubmittedBuilder::default()
+            .client_order_id(ClientOrderId::new("O-999"))
+            .build()
+            .unwrap();
+
+        let result = order.apply(OrderEvent::OrderSubmitted(submitted));
+
+        assert_eq!(result.unwrap_err().code(), 3);
+        assert_eq!(order.status, OrderStatus::Initialized);
     }
 }
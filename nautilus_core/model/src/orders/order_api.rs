@@ -0,0 +1,503 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::BTreeMap,
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::{
+    ffi_error::{set_last_error, try_cstr_to_str, write_result, FFI_ERROR, FFI_OK},
+    string::str_to_cstr,
+};
+
+use super::{trailing::trailing_stop_calculate, Order, OrderError};
+use crate::{
+    enums::{OrderSide, OrderStatus, OrderType},
+    events::order::{
+        OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEvent, OrderExpired,
+        OrderFilled, OrderInitialized, OrderModifyRejected, OrderPendingCancel, OrderPendingUpdate,
+        OrderRejected, OrderSubmitted, OrderTriggered, OrderUpdated,
+    },
+    identifiers::{client_order_id::ClientOrderId, instrument_id::InstrumentId},
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Order`].
+///
+/// This struct wraps `Order` in a way that makes it compatible with C function
+/// calls, enabling interaction with `Order` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `Order_API` to be
+/// dereferenced to `Order`, providing access to `Order`'s methods without
+/// having to manually access the underlying `Order` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct Order_API(Box<Order>);
+
+impl Deref for Order_API {
+    type Target = Order;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Order_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Applies the `event` to the `order`, returning 0 on success or the [`OrderError`] code.
+fn apply_event(order: &mut Order_API, event: OrderEvent) -> u8 {
+    order
+        .apply(event)
+        .map_or_else(|e: OrderError| e.code(), |_| 0)
+}
+
+#[no_mangle]
+pub extern "C" fn order_new(init: &OrderInitialized) -> Order_API {
+    Order_API(Box::new(Order::from(init.clone())))
+}
+
+#[no_mangle]
+pub extern "C" fn order_drop(order: Order_API) {
+    drop(order); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_status(order: &Order_API) -> OrderStatus {
+    order.status
+}
+
+#[no_mangle]
+pub extern "C" fn order_side(order: &Order_API) -> OrderSide {
+    order.side
+}
+
+#[no_mangle]
+pub extern "C" fn order_type(order: &Order_API) -> OrderType {
+    order.order_type
+}
+
+#[no_mangle]
+pub extern "C" fn order_client_order_id(order: &Order_API) -> ClientOrderId {
+    order.client_order_id.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn order_instrument_id(order: &Order_API) -> InstrumentId {
+    order.instrument_id.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn order_quantity(order: &Order_API) -> Quantity {
+    order.quantity
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_qty(order: &Order_API) -> Quantity {
+    order.filled_qty
+}
+
+#[no_mangle]
+pub extern "C" fn order_leaves_qty(order: &Order_API) -> Quantity {
+    order.leaves_qty
+}
+
+/// Returns the average fill price for the order, or 0.0 if it has not been filled.
+#[no_mangle]
+pub extern "C" fn order_avg_px(order: &Order_API) -> f64 {
+    order.avg_px.unwrap_or(0.0)
+}
+
+#[no_mangle]
+pub extern "C" fn order_event_count(order: &Order_API) -> usize {
+    order.event_count()
+}
+
+#[no_mangle]
+pub extern "C" fn order_is_open(order: &Order_API) -> u8 {
+    u8::from(order.is_open())
+}
+
+#[no_mangle]
+pub extern "C" fn order_is_closed(order: &Order_API) -> u8 {
+    u8::from(order.is_closed())
+}
+
+#[no_mangle]
+pub extern "C" fn order_is_inflight(order: &Order_API) -> u8 {
+    u8::from(order.is_inflight())
+}
+
+#[no_mangle]
+pub extern "C" fn order_is_pending_update(order: &Order_API) -> u8 {
+    u8::from(order.is_pending_update())
+}
+
+#[no_mangle]
+pub extern "C" fn order_is_pending_cancel(order: &Order_API) -> u8 {
+    u8::from(order.is_pending_cancel())
+}
+
+#[no_mangle]
+pub extern "C" fn order_apply_denied(order: &mut Order_API, event: OrderDenied) -> u8 {
+    apply_event(order, OrderEvent::OrderDenied(event))
+}
+
+#[no_mangle]
+pub extern "C" fn order_apply_submitted(order: &mut Order_API, event: OrderSubmitted) -> u8 {
+    apply_event(order, OrderEvent::OrderSubmitted(event))
+}
+
+#[no_mangle]
+pub extern "C" fn order_apply_accepted(order: &mut Order_API, event: OrderAccepted) -> u8 {
+    apply_event(order, OrderEvent::OrderAccepted(event))
+}
+
+#[no_mangle]
+pub extern "C" fn order_apply_rejected(order: &mut Order_API, event: OrderRejected) -> u8 {
+    apply_event(order, OrderEvent::OrderRejected(event))
+}
+
+#[no_mangle]
+pub extern "C" fn order_apply_canceled(order: &mut Order_API, event: OrderCanceled) -> u8 {
+    apply_event(order, OrderEvent::OrderCanceled(event))
+}
+
+#[no_mangle]
+pub extern "C" fn order_apply_expired(order: &mut Order_API, event: OrderExpired) -> u8 {
+    apply_event(order, OrderEvent::OrderExpired(event))
+}
+
+#[no_mangle]
+pub extern "C" fn order_apply_triggered(order: &mut Order_API, event: OrderTriggered) -> u8 {
+    apply_event(order, OrderEvent::OrderTriggered(event))
+}
+
+#[no_mangle]
+pub extern "C" fn order_apply_pending_update(
+    order: &mut Order_API,
+    event: OrderPendingUpdate,
+) -> u8 {
+    apply_event(order, OrderEvent::OrderPendingUpdate(event))
+}
+
+#[no_mangle]
+pub extern "C" fn order_apply_pending_cancel(
+    order: &mut Order_API,
+    event: OrderPendingCancel,
+) -> u8 {
+    apply_event(order, OrderEvent::OrderPendingCancel(event))
+}
+
+#[no_mangle]
+pub extern "C" fn order_apply_modify_rejected(
+    order: &mut Order_API,
+    event: OrderModifyRejected,
+) -> u8 {
+    apply_event(order, OrderEvent::OrderModifyRejected(event))
+}
+
+#[no_mangle]
+pub extern "C" fn order_apply_cancel_rejected(
+    order: &mut Order_API,
+    event: OrderCancelRejected,
+) -> u8 {
+    apply_event(order, OrderEvent::OrderCancelRejected(event))
+}
+
+#[no_mangle]
+pub extern "C" fn order_apply_updated(order: &mut Order_API, event: OrderUpdated) -> u8 {
+    apply_event(order, OrderEvent::OrderUpdated(event))
+}
+
+/// Applies the fill `event` to the `order`, as either a partial or complete fill depending
+/// on the orders remaining quantity.
+#[no_mangle]
+pub extern "C" fn order_apply_filled(order: &mut Order_API, event: OrderFilled) -> u8 {
+    if event.last_qty.raw < order.leaves_qty.raw {
+        apply_event(order, OrderEvent::OrderPartiallyFilled(event))
+    } else {
+        apply_event(order, OrderEvent::OrderFilled(event))
+    }
+}
+
+/// Returns the metadata of the `order` as a JSON object, as a C string pointer.
+#[no_mangle]
+pub extern "C" fn order_metadata_to_cstr(order: &Order_API) -> *const c_char {
+    let json: serde_json::Map<String, serde_json::Value> = order
+        .metadata
+        .iter()
+        .map(|(key, value)| (key.clone(), serde_json::Value::from(value.as_str())))
+        .collect();
+    str_to_cstr(&serde_json::Value::Object(json).to_string())
+}
+
+/// Replaces the metadata of the `order` with the JSON object of string values at `ptr`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case the metadata is unchanged).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_set_metadata_json(order: &mut Order_API, ptr: *const c_char) -> u8 {
+    let result = try_cstr_to_str(ptr, "order metadata").and_then(|json| {
+        serde_json::from_str::<BTreeMap<String, String>>(json)
+            .map_err(|e| format!("invalid order metadata JSON: {e}"))
+    });
+    match result {
+        Ok(metadata) => {
+            order.metadata = metadata;
+            FFI_OK
+        }
+        Err(e) => {
+            set_last_error(e);
+            FFI_ERROR
+        }
+    }
+}
+
+/// Sets the metadata `value` for the `key` of the `order`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case the metadata is unchanged).
+///
+/// # Safety
+///
+/// - Assumes `key_ptr` and `value_ptr` are valid C string pointers, or null.
+#[no_mangle]
+pub unsafe extern "C" fn order_set_metadata(
+    order: &mut Order_API,
+    key_ptr: *const c_char,
+    value_ptr: *const c_char,
+) -> u8 {
+    let result = try_cstr_to_str(key_ptr, "order metadata key").and_then(|key| {
+        try_cstr_to_str(value_ptr, "order metadata value").map(|value| (key, value))
+    });
+    match result {
+        Ok((key, value)) => {
+            order.set_metadata(key, value);
+            FFI_OK
+        }
+        Err(e) => {
+            set_last_error(e);
+            FFI_ERROR
+        }
+    }
+}
+
+/// Removes the metadata for the `key` of the `order`, writing 1 to `removed` if the order
+/// had metadata for the key, otherwise 0.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `removed`).
+///
+/// # Safety
+///
+/// - Assumes `key_ptr` is a valid C string pointer, or null.
+/// - Assumes `removed` is a valid pointer to write the result to.
+#[no_mangle]
+pub unsafe extern "C" fn order_remove_metadata(
+    order: &mut Order_API,
+    key_ptr: *const c_char,
+    removed: *mut u8,
+) -> u8 {
+    let result = try_cstr_to_str(key_ptr, "order metadata key")
+        .map(|key| u8::from(order.remove_metadata(key).is_some()));
+    write_result(result, removed, |_| FFI_ERROR)
+}
+
+/// Represents new trailing stop prices, flagged for whether each has moved.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TrailingStopPrices {
+    pub has_trigger_price: u8,
+    pub trigger_price: Price,
+    pub has_price: u8,
+    pub price: Price,
+}
+
+/// Calculates new trailing stop prices for the `order` at the current market, written to `out`.
+///
+/// Any of `bid`, `ask` and `last` may be NULL where the price is not available.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `bid`, `ask` and `last` are either NULL or valid pointers to a [`Price`].
+/// - Assumes `out` is a valid pointer to memory for a [`TrailingStopPrices`].
+#[no_mangle]
+pub unsafe extern "C" fn order_trailing_stop_calculate(
+    order: &Order_API,
+    price_increment: Price,
+    bid: *const Price,
+    ask: *const Price,
+    last: *const Price,
+    out: *mut TrailingStopPrices,
+) -> u8 {
+    let result = trailing_stop_calculate(
+        price_increment,
+        order,
+        bid.as_ref().copied(),
+        ask.as_ref().copied(),
+        last.as_ref().copied(),
+    )
+    .map(|(trigger_price, price)| TrailingStopPrices {
+        has_trigger_price: u8::from(trigger_price.is_some()),
+        trigger_price: trigger_price.unwrap_or_default(),
+        has_price: u8::from(price.is_some()),
+        price: price.unwrap_or_default(),
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::order::{OrderInitializedBuilder, OrderSubmittedBuilder};
+
+    #[test]
+    fn test_order_api_apply_events() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let mut order = order_new(&init);
+
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        assert_eq!(order_apply_submitted(&mut order, submitted.clone()), 0);
+        assert_eq!(order_status(&order), OrderStatus::Submitted);
+        assert_eq!(order_is_inflight(&order), 1);
+
+        // Invalid transition from SUBMITTED
+        assert_eq!(order_apply_submitted(&mut order, submitted), 1);
+        assert_eq!(order_event_count(&order), 1);
+        order_drop(order);
+    }
+
+    #[test]
+    fn test_order_api_metadata_round_trip() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let mut order = order_new(&init);
+        let json = std::ffi::CString::new(r#"{"exec_inst":"ALO","route":"SMART"}"#).unwrap();
+
+        assert_eq!(
+            unsafe { order_set_metadata_json(&mut order, json.as_ptr()) },
+            0
+        );
+        assert_eq!(order.get_metadata("route"), Some("SMART"));
+
+        let key = std::ffi::CString::new("route").unwrap();
+        let mut removed = 0;
+        assert_eq!(
+            unsafe { order_remove_metadata(&mut order, key.as_ptr(), &mut removed) },
+            0
+        );
+        assert_eq!(removed, 1);
+        assert_eq!(
+            unsafe { order_remove_metadata(&mut order, key.as_ptr(), &mut removed) },
+            0
+        );
+        assert_eq!(removed, 0);
+        let ptr = order_metadata_to_cstr(&order);
+        let result = unsafe { std::ffi::CStr::from_ptr(ptr) }.to_str().unwrap();
+        assert_eq!(result, r#"{"exec_inst":"ALO"}"#);
+
+        let invalid = std::ffi::CString::new("[1, 2]").unwrap();
+        assert_eq!(
+            unsafe { order_set_metadata_json(&mut order, invalid.as_ptr()) },
+            FFI_ERROR
+        );
+        assert_eq!(order.metadata.len(), 1);
+        order_drop(order);
+    }
+
+    #[test]
+    fn test_order_api_set_metadata() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let mut order = order_new(&init);
+        let key = std::ffi::CString::new("route").unwrap();
+        let value = std::ffi::CString::new("SMART").unwrap();
+
+        assert_eq!(
+            unsafe { order_set_metadata(&mut order, key.as_ptr(), value.as_ptr()) },
+            0
+        );
+        assert_eq!(order.get_metadata("route"), Some("SMART"));
+        assert_eq!(
+            unsafe { order_set_metadata(&mut order, key.as_ptr(), std::ptr::null()) },
+            FFI_ERROR
+        );
+        assert_eq!(order.get_metadata("route"), Some("SMART"));
+
+        let mut removed = 0;
+        assert_eq!(
+            unsafe { order_remove_metadata(&mut order, std::ptr::null(), &mut removed) },
+            FFI_ERROR
+        );
+        assert_eq!(order.metadata.len(), 1);
+        order_drop(order);
+    }
+
+    #[test]
+    fn test_order_api_trailing_stop_calculate() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::TrailingStopMarket)
+            .trailing_offset(Some(Price::from("1.00")))
+            .trailing_offset_type(Some(crate::enums::TrailingOffsetType::Price))
+            .build()
+            .unwrap();
+        let order = order_new(&init);
+        let last = Price::from("100.00");
+        let mut out = TrailingStopPrices::default();
+
+        let status = unsafe {
+            order_trailing_stop_calculate(
+                &order,
+                Price::from("0.01"),
+                std::ptr::null(),
+                std::ptr::null(),
+                &last,
+                &mut out,
+            )
+        };
+        assert_eq!(status, 0);
+        assert_eq!(out.has_trigger_price, 1);
+        assert_eq!(out.trigger_price, Price::from("101.00"));
+        assert_eq!(out.has_price, 0);
+
+        // No LAST price available
+        let status = unsafe {
+            order_trailing_stop_calculate(
+                &order,
+                Price::from("0.01"),
+                std::ptr::null(),
+                std::ptr::null(),
+                std::ptr::null(),
+                &mut out,
+            )
+        };
+        assert_eq!(status, FFI_ERROR);
+        order_drop(order);
+    }
+}
@@ -0,0 +1,460 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides trailing stop trigger and limit price calculations, as used by the backtest
+//! matching engine and for emulating trailing stops on venues which do not support them.
+
+use thiserror::Error;
+
+use super::Order;
+use crate::{
+    enums::{OrderSide, OrderType, TrailingOffsetType, TriggerType},
+    identifiers::instrument_id::InstrumentId,
+    types::price::Price,
+};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TrailingStopError {
+    #[error("Invalid `OrderType` for trailing stop calculation, was {0}")]
+    InvalidOrderType(OrderType),
+    #[error("Invalid `OrderSide` for trailing stop calculation, was {0}")]
+    InvalidOrderSide(OrderSide),
+    #[error("Cannot process trailing stop, no `{0}` for order")]
+    MissingOffset(&'static str),
+    #[error("Cannot process trailing stop, no {0} price for {1}")]
+    NoMarketPrice(&'static str, InstrumentId),
+    #[error("Cannot process trailing stop, `TrailingOffsetType` {0} not currently supported")]
+    UnsupportedOffsetType(TrailingOffsetType),
+    #[error("Cannot process trailing stop, `TriggerType` {0} not currently supported")]
+    UnsupportedTriggerType(TriggerType),
+}
+
+/// Returns the trailing price at `offset` from the `last` price.
+///
+/// # Errors
+///
+/// - If `trailing_offset_type` is not `Price`, `BasisPoints` or `Ticks`.
+/// - If `side` is not `Buy` or `Sell`.
+pub fn calculate_with_last(
+    price_increment: Price,
+    trailing_offset_type: TrailingOffsetType,
+    side: OrderSide,
+    offset: f64,
+    last: Price,
+) -> Result<Price, TrailingStopError> {
+    let last = last.as_f64();
+    let offset = absolute_offset(price_increment, trailing_offset_type, offset, last)?;
+    match side {
+        OrderSide::Buy => Ok(Price::new(last + offset, price_increment.precision)),
+        OrderSide::Sell => Ok(Price::new(last - offset, price_increment.precision)),
+        OrderSide::NoOrderSide => Err(TrailingStopError::InvalidOrderSide(side)),
+    }
+}
+
+/// Returns the trailing price at `offset` from the ask (for buys) or bid (for sells).
+///
+/// # Errors
+///
+/// - If `trailing_offset_type` is not `Price`, `BasisPoints` or `Ticks`.
+/// - If `side` is not `Buy` or `Sell`.
+pub fn calculate_with_bid_ask(
+    price_increment: Price,
+    trailing_offset_type: TrailingOffsetType,
+    side: OrderSide,
+    offset: f64,
+    bid: Price,
+    ask: Price,
+) -> Result<Price, TrailingStopError> {
+    match side {
+        OrderSide::Buy => {
+            let ask = ask.as_f64();
+            let offset = absolute_offset(price_increment, trailing_offset_type, offset, ask)?;
+            Ok(Price::new(ask + offset, price_increment.precision))
+        }
+        OrderSide::Sell => {
+            let bid = bid.as_f64();
+            let offset = absolute_offset(price_increment, trailing_offset_type, offset, bid)?;
+            Ok(Price::new(bid - offset, price_increment.precision))
+        }
+        OrderSide::NoOrderSide => Err(TrailingStopError::InvalidOrderSide(side)),
+    }
+}
+
+/// Returns the new trigger price and new limit price (if either has moved) for the given
+/// trailing stop `order` at the current market.
+///
+/// A trailing price only ever moves in the direction which tightens the stop: down for buy
+/// orders and up for sell orders. Order `trigger_type` determines which market prices are used,
+/// with `Default` and `MarkPrice` treated as `LastTrade`.
+///
+/// # Errors
+///
+/// - If `order` is not a trailing stop order with the required offsets.
+/// - If a market price required by the orders `trigger_type` is not available.
+/// - If the orders `trigger_type` or `trailing_offset_type` is not supported.
+pub fn trailing_stop_calculate(
+    price_increment: Price,
+    order: &Order,
+    bid: Option<Price>,
+    ask: Option<Price>,
+    last: Option<Price>,
+) -> Result<(Option<Price>, Option<Price>), TrailingStopError> {
+    let is_limit = match order.order_type {
+        OrderType::TrailingStopMarket => false,
+        OrderType::TrailingStopLimit => true,
+        order_type => return Err(TrailingStopError::InvalidOrderType(order_type)),
+    };
+    let trailing_offset = order
+        .trailing_offset
+        .ok_or(TrailingStopError::MissingOffset("trailing_offset"))?
+        .as_f64();
+    let limit_offset = if is_limit {
+        order
+            .limit_offset
+            .ok_or(TrailingStopError::MissingOffset("limit_offset"))?
+            .as_f64()
+    } else {
+        0.0
+    };
+    let offset_type = order
+        .trailing_offset_type
+        .unwrap_or(TrailingOffsetType::NoTrailingOffset);
+    let side = order.side;
+
+    let require = |price: Option<Price>, name: &'static str| {
+        price.ok_or_else(|| TrailingStopError::NoMarketPrice(name, order.instrument_id.clone()))
+    };
+
+    let mut trailing = TrailingPrices {
+        side,
+        trigger_price: order.trigger_price,
+        price: order.price,
+        new_trigger_price: None,
+        new_price: None,
+    };
+
+    match order.trigger_type.unwrap_or(TriggerType::Default) {
+        TriggerType::Default | TriggerType::LastTrade | TriggerType::MarkPrice => {
+            let last = require(last, "LAST")?;
+            trailing.update_trigger(calculate_with_last(
+                price_increment,
+                offset_type,
+                side,
+                trailing_offset,
+                last,
+            )?);
+            if is_limit {
+                trailing.update_price(calculate_with_last(
+                    price_increment,
+                    offset_type,
+                    side,
+                    limit_offset,
+                    last,
+                )?);
+            }
+        }
+        TriggerType::BidAsk => {
+            let bid = require(bid, "BID")?;
+            let ask = require(ask, "ASK")?;
+            trailing.update_trigger(calculate_with_bid_ask(
+                price_increment,
+                offset_type,
+                side,
+                trailing_offset,
+                bid,
+                ask,
+            )?);
+            if is_limit {
+                trailing.update_price(calculate_with_bid_ask(
+                    price_increment,
+                    offset_type,
+                    side,
+                    limit_offset,
+                    bid,
+                    ask,
+                )?);
+            }
+        }
+        TriggerType::LastOrBidAsk => {
+            let last = require(last, "LAST")?;
+            let bid = require(bid, "BID")?;
+            let ask = require(ask, "ASK")?;
+            trailing.update_trigger(calculate_with_last(
+                price_increment,
+                offset_type,
+                side,
+                trailing_offset,
+                last,
+            )?);
+            trailing.update_trigger(calculate_with_bid_ask(
+                price_increment,
+                offset_type,
+                side,
+                trailing_offset,
+                bid,
+                ask,
+            )?);
+            if is_limit {
+                trailing.update_price(calculate_with_last(
+                    price_increment,
+                    offset_type,
+                    side,
+                    limit_offset,
+                    last,
+                )?);
+                trailing.update_price(calculate_with_bid_ask(
+                    price_increment,
+                    offset_type,
+                    side,
+                    limit_offset,
+                    bid,
+                    ask,
+                )?);
+            }
+        }
+        trigger_type => return Err(TrailingStopError::UnsupportedTriggerType(trigger_type)),
+    }
+
+    Ok((trailing.new_trigger_price, trailing.new_price))
+}
+
+/// Converts a trailing `offset` of the given type to an absolute price offset from `market`.
+fn absolute_offset(
+    price_increment: Price,
+    trailing_offset_type: TrailingOffsetType,
+    offset: f64,
+    market: f64,
+) -> Result<f64, TrailingStopError> {
+    match trailing_offset_type {
+        TrailingOffsetType::Price => Ok(offset),
+        TrailingOffsetType::BasisPoints => Ok(market * (offset / 100.0) / 100.0),
+        TrailingOffsetType::Ticks => Ok(offset * price_increment.as_f64()),
+        _ => Err(TrailingStopError::UnsupportedOffsetType(
+            trailing_offset_type,
+        )),
+    }
+}
+
+/// Tracks the current and any newly calculated trailing prices of an order.
+struct TrailingPrices {
+    side: OrderSide,
+    trigger_price: Option<Price>,
+    price: Option<Price>,
+    new_trigger_price: Option<Price>,
+    new_price: Option<Price>,
+}
+
+impl TrailingPrices {
+    fn update_trigger(&mut self, candidate: Price) {
+        if self.is_tighter(self.trigger_price, candidate) {
+            self.trigger_price = Some(candidate);
+            self.new_trigger_price = Some(candidate);
+        }
+    }
+
+    fn update_price(&mut self, candidate: Price) {
+        if self.is_tighter(self.price, candidate) {
+            self.price = Some(candidate);
+            self.new_price = Some(candidate);
+        }
+    }
+
+    fn is_tighter(&self, current: Option<Price>, candidate: Price) -> bool {
+        match current {
+            None => true,
+            Some(current) => match self.side {
+                OrderSide::Buy => current.raw > candidate.raw,
+                _ => current.raw < candidate.raw,
+            },
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::events::order::OrderInitializedBuilder;
+
+    fn trailing_order(
+        order_type: OrderType,
+        side: OrderSide,
+        trigger_type: TriggerType,
+        trailing_offset_type: TrailingOffsetType,
+        trigger_price: Option<&str>,
+        price: Option<&str>,
+    ) -> Order {
+        OrderInitializedBuilder::default()
+            .order_type(order_type)
+            .order_side(side)
+            .trigger_type(Some(trigger_type))
+            .trailing_offset_type(Some(trailing_offset_type))
+            .trailing_offset(Some(Price::from("1.00")))
+            .limit_offset(Some(Price::from("2.00")))
+            .trigger_price(trigger_price.map(Price::from))
+            .price(price.map(Price::from))
+            .build()
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn test_calculate_with_invalid_order_type() {
+        let order: Order = OrderInitializedBuilder::default().build().unwrap().into();
+        let result = trailing_stop_calculate(Price::from("0.01"), &order, None, None, None);
+
+        assert_eq!(
+            result,
+            Err(TrailingStopError::InvalidOrderType(OrderType::Market))
+        );
+    }
+
+    #[test]
+    fn test_calculate_with_no_last_price() {
+        let order = trailing_order(
+            OrderType::TrailingStopMarket,
+            OrderSide::Buy,
+            TriggerType::LastTrade,
+            TrailingOffsetType::Price,
+            None,
+            None,
+        );
+        let result = trailing_stop_calculate(Price::from("0.01"), &order, None, None, None);
+
+        assert!(matches!(
+            result,
+            Err(TrailingStopError::NoMarketPrice("LAST", _))
+        ));
+    }
+
+    #[rstest]
+    #[case(TrailingOffsetType::Price, "101.00")]
+    #[case(TrailingOffsetType::BasisPoints, "100.01")]
+    #[case(TrailingOffsetType::Ticks, "100.01")]
+    fn test_buy_trailing_stop_market_with_last(
+        #[case] offset_type: TrailingOffsetType,
+        #[case] expected: &str,
+    ) {
+        let order = trailing_order(
+            OrderType::TrailingStopMarket,
+            OrderSide::Buy,
+            TriggerType::LastTrade,
+            offset_type,
+            None,
+            None,
+        );
+        let result = trailing_stop_calculate(
+            Price::from("0.01"),
+            &order,
+            None,
+            None,
+            Some(Price::from("100.00")),
+        );
+
+        assert_eq!(result, Ok((Some(Price::from(expected)), None)));
+    }
+
+    #[test]
+    fn test_trailing_stop_does_not_loosen() {
+        let order = trailing_order(
+            OrderType::TrailingStopMarket,
+            OrderSide::Sell,
+            TriggerType::BidAsk,
+            TrailingOffsetType::Price,
+            Some("99.50"),
+            None,
+        );
+        let bid = Some(Price::from("100.00"));
+        let ask = Some(Price::from("100.02"));
+
+        // Bid falling leaves the sell trigger unchanged
+        let result = trailing_stop_calculate(Price::from("0.01"), &order, bid, ask, None);
+        assert_eq!(result, Ok((None, None)));
+
+        // Bid rising moves the sell trigger up
+        let bid = Some(Price::from("101.00"));
+        let result = trailing_stop_calculate(Price::from("0.01"), &order, bid, ask, None);
+        assert_eq!(result, Ok((Some(Price::from("100.00")), None)));
+    }
+
+    #[test]
+    fn test_trailing_stop_limit_updates_trigger_and_price() {
+        let order = trailing_order(
+            OrderType::TrailingStopLimit,
+            OrderSide::Buy,
+            TriggerType::BidAsk,
+            TrailingOffsetType::Price,
+            None,
+            None,
+        );
+        let result = trailing_stop_calculate(
+            Price::from("0.01"),
+            &order,
+            Some(Price::from("99.98")),
+            Some(Price::from("100.00")),
+            None,
+        );
+
+        assert_eq!(
+            result,
+            Ok((Some(Price::from("101.00")), Some(Price::from("102.00"))))
+        );
+    }
+
+    #[test]
+    fn test_last_or_bid_ask_uses_tightest_of_both() {
+        let order = trailing_order(
+            OrderType::TrailingStopMarket,
+            OrderSide::Sell,
+            TriggerType::LastOrBidAsk,
+            TrailingOffsetType::Price,
+            None,
+            None,
+        );
+        let result = trailing_stop_calculate(
+            Price::from("0.01"),
+            &order,
+            Some(Price::from("100.50")),
+            Some(Price::from("100.52")),
+            Some(Price::from("100.00")),
+        );
+
+        assert_eq!(result, Ok((Some(Price::from("99.50")), None)));
+    }
+
+    #[test]
+    fn test_unsupported_trigger_type() {
+        let order = trailing_order(
+            OrderType::TrailingStopMarket,
+            OrderSide::Buy,
+            TriggerType::IndexPrice,
+            TrailingOffsetType::Price,
+            None,
+            None,
+        );
+        let result = trailing_stop_calculate(Price::from("0.01"), &order, None, None, None);
+
+        assert_eq!(
+            result,
+            Err(TrailingStopError::UnsupportedTriggerType(
+                TriggerType::IndexPrice
+            ))
+        );
+    }
+}
@@ -0,0 +1,231 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+
+use super::Order;
+use crate::{
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TrailingOffsetType, TriggerType},
+    events::order::OrderInitialized,
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+        strategy_id::StrategyId, trader_id::TraderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents a stop limit order with trigger and limit prices which trail the market by offsets.
+pub struct TrailingStopLimitOrder {
+    pub(super) core: Order,
+}
+
+impl TrailingStopLimitOrder {
+    /// # Panics
+    ///
+    /// If `time_in_force` is `GTD` with no `expire_time`, or `display_qty` is greater
+    /// than `quantity`.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        trigger_type: TriggerType,
+        limit_offset: Price,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        assert!(
+            time_in_force != TimeInForce::Gtd || expire_time.is_some(),
+            "Invalid `TrailingStopLimitOrder`: `expire_time` was `None` for GTD order"
+        );
+        if let Some(display_qty) = display_qty {
+            assert!(
+                display_qty <= quantity,
+                "Invalid `TrailingStopLimitOrder`: `display_qty` was greater than `quantity`"
+            );
+        }
+
+        let init = OrderInitialized {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            order_type: OrderType::TrailingStopLimit,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type: Some(trigger_type),
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            limit_offset: Some(limit_offset),
+            trailing_offset: Some(trailing_offset),
+            trailing_offset_type: Some(trailing_offset_type),
+            emulation_trigger,
+            contingency_type,
+            order_list_id,
+            linked_order_ids,
+            parent_order_id,
+            tags,
+            metadata: Default::default(),
+            event_id: init_id,
+            ts_event: ts_init,
+            ts_init,
+            reconciliation: false,
+        };
+
+        Self { core: init.into() }
+    }
+
+    #[must_use]
+    pub fn price(&self) -> Option<Price> {
+        self.core.price
+    }
+
+    #[must_use]
+    pub fn trigger_price(&self) -> Option<Price> {
+        self.core.trigger_price
+    }
+
+    #[must_use]
+    pub fn trigger_type(&self) -> TriggerType {
+        self.core.trigger_type.unwrap_or_else(|| {
+            panic!("Invalid `TrailingStopLimitOrder`: did not have a trigger type")
+        })
+    }
+
+    #[must_use]
+    pub fn limit_offset(&self) -> Price {
+        self.core.limit_offset.unwrap_or_else(|| {
+            panic!("Invalid `TrailingStopLimitOrder`: did not have a limit offset")
+        })
+    }
+
+    #[must_use]
+    pub fn trailing_offset(&self) -> Price {
+        self.core.trailing_offset.unwrap_or_else(|| {
+            panic!("Invalid `TrailingStopLimitOrder`: did not have a trailing offset")
+        })
+    }
+
+    #[must_use]
+    pub fn trailing_offset_type(&self) -> TrailingOffsetType {
+        self.core.trailing_offset_type.unwrap_or_else(|| {
+            panic!("Invalid `TrailingStopLimitOrder`: did not have a trailing offset type")
+        })
+    }
+
+    #[must_use]
+    pub fn expire_time(&self) -> Option<UnixNanos> {
+        self.core.expire_time
+    }
+
+    #[must_use]
+    pub fn display_qty(&self) -> Option<Quantity> {
+        self.core.display_qty
+    }
+}
+
+impl Deref for TrailingStopLimitOrder {
+    type Target = Order;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for TrailingStopLimitOrder {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl From<TrailingStopLimitOrder> for Order {
+    fn from(value: TrailingStopLimitOrder) -> Self {
+        value.core
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trailing_stop_limit_order_new() {
+        let order = TrailingStopLimitOrder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::default(),
+            ClientOrderId::default(),
+            OrderSide::Buy,
+            Quantity::from("100"),
+            None,
+            None,
+            TriggerType::BidAsk,
+            Price::from("0.00050"),
+            Price::from("0.00100"),
+            TrailingOffsetType::Price,
+            TimeInForce::Gtc,
+            None,
+            false,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::default(),
+            0,
+        );
+
+        assert_eq!(order.order_type, OrderType::TrailingStopLimit);
+        assert_eq!(order.price(), None);
+        assert_eq!(order.limit_offset(), Price::from("0.00050"));
+        assert_eq!(order.trailing_offset(), Price::from("0.00100"));
+        assert_eq!(order.trigger_type(), TriggerType::BidAsk);
+    }
+}
@@ -13,30 +13,31 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-// TODO: Liberal use of cloning to get things compiling initially
-#![allow(dead_code)]
-
 use std::collections::HashMap;
 
 use nautilus_core::time::UnixNanos;
 
 use crate::{
     enums::{OrderSide, PositionSide},
-    events::order::OrderFilled,
+    events::{
+        order::OrderFilled,
+        position::{PositionChanged, PositionClosed, PositionEvent, PositionOpened},
+    },
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
         venue_order_id::VenueOrderId,
     },
     instruments::Instrument,
-    types::{currency::Currency, money::Money, quantity::Quantity},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
 /// Represents a position in a financial market.
 ///
 /// The position ID may be assigned at the trading venue, or can be system
 /// generated depending on a strategies OMS (Order Management System) settings.
-struct Position {
+#[derive(Clone, Debug)]
+pub struct Position {
     events: Vec<OrderFilled>,
     client_order_ids: Vec<ClientOrderId>,
     venue_order_ids: Vec<VenueOrderId>,
@@ -75,12 +76,13 @@ struct Position {
 }
 
 impl Position {
-    pub fn new(instrument: &Instrument, fill: &OrderFilled) -> Self {
+    pub fn new<I: Instrument>(instrument: &I, fill: OrderFilled) -> Self {
+        let instrument = instrument.base();
         assert_eq!(instrument.id, fill.instrument_id);
         assert!(fill.position_id.is_some());
         assert!(fill.order_side != OrderSide::NoOrderSide);
 
-        Self {
+        let mut position = Self {
             events: Vec::<OrderFilled>::new(),
             client_order_ids: Vec::<ClientOrderId>::new(),
             venue_order_ids: Vec::<VenueOrderId>::new(),
@@ -98,8 +100,8 @@ impl Position {
             entry: fill.order_side,
             side: PositionSide::Flat,
             signed_qty: 0.0,
-            quantity: fill.last_qty,
-            peak_qty: fill.last_qty,
+            quantity: Quantity::zero(instrument.size_precision),
+            peak_qty: Quantity::zero(instrument.size_precision),
             price_precision: instrument.price_precision,
             size_precision: instrument.size_precision,
             multiplier: instrument.multiplier,
@@ -116,7 +118,9 @@ impl Position {
             avg_px_close: None,
             realized_return: None,
             realized_pnl: None,
-        }
+        };
+        position.apply(fill);
+        position
     }
 
     pub fn apply(&mut self, fill: OrderFilled) {
@@ -160,8 +164,8 @@ impl Position {
 
         // Calculate avg prices, points, return, PnL
         match fill.order_side {
-            OrderSide::Buy => {}
-            OrderSide::Sell => {}
+            OrderSide::Buy => self.handle_buy_order_fill(&fill),
+            OrderSide::Sell => self.handle_sell_order_fill(&fill),
             _ => panic!("invalid order side"),
         }
 
@@ -187,6 +191,237 @@ impl Position {
 
         self.ts_last = fill.ts_event;
     }
+
+    fn handle_buy_order_fill(&mut self, fill: &OrderFilled) {
+        let mut realized_pnl = self.initial_fill_pnl(fill);
+        let last_px = fill.last_px.as_f64();
+        let last_qty = fill.last_qty.as_f64();
+
+        if self.signed_qty > 0.0 {
+            self.avg_px_open = self.calculate_avg_px_open_px(last_px, last_qty);
+        } else if self.signed_qty < 0.0 {
+            let avg_px_close = self.calculate_avg_px_close_px(last_px, last_qty);
+            self.avg_px_close = Some(avg_px_close);
+            self.realized_return = Some(self.calculate_return(self.avg_px_open, avg_px_close));
+            realized_pnl += self.calculate_pnl_raw(self.avg_px_open, last_px, last_qty);
+        }
+
+        self.add_realized_pnl(realized_pnl);
+        self.buy_qty = self.buy_qty + fill.last_qty;
+        self.signed_qty = round_to_precision(self.signed_qty + last_qty, self.size_precision);
+    }
+
+    fn handle_sell_order_fill(&mut self, fill: &OrderFilled) {
+        let mut realized_pnl = self.initial_fill_pnl(fill);
+        let last_px = fill.last_px.as_f64();
+        let last_qty = fill.last_qty.as_f64();
+
+        if self.signed_qty < 0.0 {
+            self.avg_px_open = self.calculate_avg_px_open_px(last_px, last_qty);
+        } else if self.signed_qty > 0.0 {
+            let avg_px_close = self.calculate_avg_px_close_px(last_px, last_qty);
+            self.avg_px_close = Some(avg_px_close);
+            self.realized_return = Some(self.calculate_return(self.avg_px_open, avg_px_close));
+            realized_pnl += self.calculate_pnl_raw(self.avg_px_open, last_px, last_qty);
+        }
+
+        self.add_realized_pnl(realized_pnl);
+        self.sell_qty = self.sell_qty + fill.last_qty;
+        self.signed_qty = round_to_precision(self.signed_qty - last_qty, self.size_precision);
+    }
+
+    /// Commissions are only booked towards realized PnL when charged in the cost currency.
+    fn initial_fill_pnl(&self, fill: &OrderFilled) -> f64 {
+        if fill.commission.currency == self.cost_currency {
+            -fill.commission.as_f64()
+        } else {
+            0.0
+        }
+    }
+
+    fn add_realized_pnl(&mut self, realized_pnl: f64) {
+        let total = match &self.realized_pnl {
+            Some(existing) => existing.as_f64() + realized_pnl,
+            None => realized_pnl,
+        };
+        self.realized_pnl = Some(Money::new(total, self.cost_currency.clone()));
+    }
+
+    fn calculate_avg_px_open_px(&self, last_px: f64, last_qty: f64) -> f64 {
+        calculate_avg_px(self.quantity.as_f64(), self.avg_px_open, last_px, last_qty)
+    }
+
+    fn calculate_avg_px_close_px(&self, last_px: f64, last_qty: f64) -> f64 {
+        match self.avg_px_close {
+            Some(avg_px_close) => {
+                let close_qty = if self.side == PositionSide::Long {
+                    self.sell_qty
+                } else {
+                    self.buy_qty
+                };
+                calculate_avg_px(close_qty.as_f64(), avg_px_close, last_px, last_qty)
+            }
+            None => last_px,
+        }
+    }
+
+    fn calculate_points(&self, avg_px_open: f64, avg_px_close: f64) -> f64 {
+        match self.side {
+            PositionSide::Long => avg_px_close - avg_px_open,
+            PositionSide::Short => avg_px_open - avg_px_close,
+            _ => 0.0, // FLAT
+        }
+    }
+
+    fn calculate_points_inverse(&self, avg_px_open: f64, avg_px_close: f64) -> f64 {
+        match self.side {
+            PositionSide::Long => (1.0 / avg_px_open) - (1.0 / avg_px_close),
+            PositionSide::Short => (1.0 / avg_px_close) - (1.0 / avg_px_open),
+            _ => 0.0, // FLAT
+        }
+    }
+
+    fn calculate_return(&self, avg_px_open: f64, avg_px_close: f64) -> f64 {
+        self.calculate_points(avg_px_open, avg_px_close) / avg_px_open
+    }
+
+    fn calculate_pnl_raw(&self, avg_px_open: f64, avg_px_close: f64, quantity: f64) -> f64 {
+        // Only book open quantity towards PnL
+        let quantity = quantity.min(self.signed_qty.abs());
+
+        if self.is_inverse {
+            // In base currency
+            quantity
+                * self.multiplier.as_f64()
+                * self.calculate_points_inverse(avg_px_open, avg_px_close)
+        } else {
+            // In quote currency
+            quantity * self.multiplier.as_f64() * self.calculate_points(avg_px_open, avg_px_close)
+        }
+    }
+
+    /// Returns the PnL for the given open and close prices and quantity.
+    ///
+    /// The result is in quote currency for standard instruments, or base
+    /// currency for inverse instruments.
+    pub fn calculate_pnl(&self, avg_px_open: f64, avg_px_close: f64, quantity: Quantity) -> Money {
+        let pnl = self.calculate_pnl_raw(avg_px_open, avg_px_close, quantity.as_f64());
+        Money::new(pnl, self.settlement_currency())
+    }
+
+    /// Returns the unrealized PnL for the position at the given `last` price.
+    pub fn unrealized_pnl(&self, last: Price) -> Money {
+        if self.side == PositionSide::Flat {
+            return Money::new(0.0, self.settlement_currency());
+        }
+
+        let pnl = self.calculate_pnl_raw(self.avg_px_open, last.as_f64(), self.quantity.as_f64());
+        Money::new(pnl, self.settlement_currency())
+    }
+
+    /// Returns the total PnL (realized plus unrealized) at the given `last` price.
+    pub fn total_pnl(&self, last: Price) -> Money {
+        let realized_pnl = self.realized_pnl.as_ref().map_or(0.0, |pnl| pnl.as_f64());
+        Money::new(
+            realized_pnl + self.unrealized_pnl(last).as_f64(),
+            self.settlement_currency(),
+        )
+    }
+
+    /// Returns the notional value of the position at the given `last` price.
+    pub fn notional_value(&self, last: Price) -> Money {
+        if self.is_inverse {
+            Money::new(
+                self.quantity.as_f64() * self.multiplier.as_f64() * (1.0 / last.as_f64()),
+                self.base_currency
+                    .clone()
+                    .expect("inverse instrument without base currency"),
+            )
+        } else {
+            Money::new(
+                self.quantity.as_f64() * self.multiplier.as_f64() * last.as_f64(),
+                self.quote_currency.clone(),
+            )
+        }
+    }
+
+    /// Returns the currency PnL is settled in.
+    fn settlement_currency(&self) -> Currency {
+        if self.is_inverse {
+            self.base_currency
+                .clone()
+                .unwrap_or_else(|| self.cost_currency.clone())
+        } else {
+            self.cost_currency.clone()
+        }
+    }
+
+    /// Returns the cumulative commissions for the position, one entry per currency.
+    pub fn commissions(&self) -> Vec<Money> {
+        self.commissions.values().cloned().collect()
+    }
+
+    /// Returns the fill events applied to the position since it was last opened.
+    pub fn events(&self) -> &[OrderFilled] {
+        &self.events
+    }
+
+    pub fn event_count(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn last_event(&self) -> Option<&OrderFilled> {
+        self.events.last()
+    }
+
+    pub fn trade_ids(&self) -> &[TradeId] {
+        &self.trade_ids
+    }
+
+    pub fn is_opposite_side(&self, side: OrderSide) -> bool {
+        self.entry != side
+    }
+
+    pub fn is_long(&self) -> bool {
+        self.side == PositionSide::Long
+    }
+
+    pub fn is_short(&self) -> bool {
+        self.side == PositionSide::Short
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.side != PositionSide::Flat
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.side == PositionSide::Flat
+    }
+
+    /// Applies the given fill and returns the resulting position event.
+    pub fn apply_and_emit(&mut self, fill: OrderFilled, ts_init: UnixNanos) -> PositionEvent {
+        let was_flat = self.is_closed();
+        self.apply(fill.clone());
+
+        if self.is_closed() {
+            PositionEvent::PositionClosed(PositionClosed::create(self, &fill, ts_init))
+        } else if was_flat {
+            PositionEvent::PositionOpened(PositionOpened::create(self, &fill, ts_init))
+        } else {
+            PositionEvent::PositionChanged(PositionChanged::create(self, &fill, ts_init))
+        }
+    }
+}
+
+fn calculate_avg_px(qty: f64, avg_px: f64, last_px: f64, last_qty: f64) -> f64 {
+    let start_cost = avg_px * qty;
+    let event_cost = last_px * last_qty;
+    (start_cost + event_cost) / (qty + last_qty)
+}
+
+fn round_to_precision(value: f64, precision: u8) -> f64 {
+    let factor = 10f64.powi(precision as i32);
+    (value * factor).round() / factor
 }
 
 impl PartialEq<Self> for Position {
@@ -200,3 +435,197 @@ impl Eq for Position {}
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::str::FromStr;
+
+    use nautilus_core::uuid::UUID4;
+    use rust_decimal::Decimal;
+
+    use super::*;
+    use crate::{
+        currencies::{AUD, USD},
+        enums::{AssetClass, AssetType, LiquiditySide, OrderType},
+        identifiers::{symbol::Symbol, venue_order_id::VenueOrderId},
+        instruments::BaseInstrument,
+        types::price::Price,
+    };
+
+    pub fn audusd_sim() -> BaseInstrument {
+        BaseInstrument {
+            id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            native_symbol: Symbol::new("AUD/USD"),
+            asset_class: AssetClass::FX,
+            asset_type: AssetType::Spot,
+            quote_currency: USD.clone(),
+            base_currency: Some(AUD.clone()),
+            cost_currency: USD.clone(),
+            is_inverse: false,
+            price_precision: 5,
+            size_precision: 0,
+            price_increment: Price::from("0.00001"),
+            size_increment: Quantity::from("1"),
+            multiplier: Quantity::from("1"),
+            lot_size: Some(Quantity::from("1000")),
+            max_quantity: None,
+            min_quantity: None,
+            max_price: None,
+            min_price: None,
+            margin_init: Decimal::new(3, 2),
+            margin_maint: Decimal::new(3, 2),
+            maker_fee: Decimal::new(2, 5),
+            taker_fee: Decimal::new(2, 5),
+        }
+    }
+
+    pub fn order_filled(
+        instrument: &BaseInstrument,
+        side: OrderSide,
+        trade_id: &str,
+        last_qty: &str,
+        last_px: &str,
+        commission: f64,
+    ) -> OrderFilled {
+        OrderFilled {
+            trader_id: TraderId::default(),
+            strategy_id: StrategyId::default(),
+            instrument_id: instrument.id.clone(),
+            client_order_id: ClientOrderId::new(&format!("O-{trade_id}")),
+            venue_order_id: VenueOrderId::default(),
+            account_id: AccountId::default(),
+            trade_id: TradeId::new(trade_id),
+            position_id: Some(PositionId::new("P-1")),
+            order_side: side,
+            order_type: OrderType::Market,
+            last_qty: Quantity::from(last_qty),
+            last_px: Price::from(last_px),
+            currency: instrument.quote_currency.clone(),
+            commission: Money::new(commission, instrument.quote_currency.clone()),
+            liquidity_side: LiquiditySide::Taker,
+            event_id: UUID4::default(),
+            ts_event: 1_000_000_000,
+            ts_init: 1_000_000_000,
+            reconciliation: false,
+        }
+    }
+
+    #[test]
+    fn test_position_opened_long() {
+        let instrument = audusd_sim();
+        let fill = order_filled(&instrument, OrderSide::Buy, "1", "100000", "1.00001", 2.0);
+
+        let position = Position::new(&instrument, fill);
+
+        assert_eq!(position.side, PositionSide::Long);
+        assert_eq!(position.entry, OrderSide::Buy);
+        assert_eq!(position.signed_qty, 100_000.0);
+        assert_eq!(position.quantity, Quantity::from("100000"));
+        assert_eq!(position.peak_qty, Quantity::from("100000"));
+        assert_eq!(position.avg_px_open, 1.00001);
+        assert_eq!(position.avg_px_close, None);
+        assert_eq!(position.realized_pnl, Some(Money::new(-2.0, USD.clone())));
+        assert_eq!(position.commissions(), vec![Money::new(2.0, USD.clone())]);
+        assert_eq!(position.event_count(), 1);
+        assert!(position.is_open());
+        assert!(position.is_long());
+        assert!(!position.is_short());
+    }
+
+    #[test]
+    fn test_position_averages_open_price_when_adding() {
+        let instrument = audusd_sim();
+        let fill1 = order_filled(&instrument, OrderSide::Sell, "1", "50000", "1.00000", 0.0);
+        let fill2 = order_filled(&instrument, OrderSide::Sell, "2", "50000", "1.00002", 0.0);
+
+        let mut position = Position::new(&instrument, fill1);
+        position.apply(fill2);
+
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.signed_qty, -100_000.0);
+        assert!((position.avg_px_open - 1.00001).abs() < 1e-9);
+        assert_eq!(position.realized_pnl, Some(Money::new(0.0, USD.clone())));
+    }
+
+    #[test]
+    fn test_position_closed_realizes_pnl_net_of_commissions() {
+        let instrument = audusd_sim();
+        let fill1 = order_filled(&instrument, OrderSide::Buy, "1", "100000", "1.00000", 2.0);
+        let fill2 = order_filled(&instrument, OrderSide::Sell, "2", "100000", "1.00010", 2.0);
+
+        let mut position = Position::new(&instrument, fill1);
+        let event = position.apply_and_emit(fill2, 2);
+
+        assert_eq!(position.side, PositionSide::Flat);
+        assert!(position.is_closed());
+        assert_eq!(position.avg_px_close, Some(1.0001));
+        assert_eq!(position.realized_pnl, Some(Money::new(6.0, USD.clone())));
+        assert_eq!(position.closing_order_id, Some(ClientOrderId::new("O-2")));
+        assert_eq!(position.duration_ns, Some(0));
+        match event {
+            PositionEvent::PositionClosed(closed) => {
+                assert_eq!(closed.realized_pnl, Money::new(6.0, USD.clone()));
+                assert_eq!(closed.ts_init, 2);
+            }
+            _ => panic!("expected `PositionClosed`"),
+        }
+    }
+
+    #[test]
+    fn test_position_partial_close_emits_changed() {
+        let instrument = audusd_sim();
+        let fill1 = order_filled(&instrument, OrderSide::Buy, "1", "100000", "1.00000", 0.0);
+        let fill2 = order_filled(&instrument, OrderSide::Sell, "2", "40000", "1.00010", 0.0);
+
+        let mut position = Position::new(&instrument, fill1);
+        let event = position.apply_and_emit(fill2, 2);
+
+        assert_eq!(position.side, PositionSide::Long);
+        assert_eq!(position.quantity, Quantity::from("60000"));
+        assert_eq!(position.peak_qty, Quantity::from("100000"));
+        assert_eq!(position.realized_pnl, Some(Money::new(4.0, USD.clone())));
+        assert!(matches!(event, PositionEvent::PositionChanged(_)));
+    }
+
+    #[test]
+    fn test_position_reopened_after_flat_emits_opened() {
+        let instrument = audusd_sim();
+        let fill1 = order_filled(&instrument, OrderSide::Buy, "1", "100000", "1.00000", 0.0);
+        let fill2 = order_filled(&instrument, OrderSide::Sell, "2", "100000", "1.00000", 0.0);
+        let fill3 = order_filled(&instrument, OrderSide::Sell, "3", "10000", "1.00005", 0.0);
+
+        let mut position = Position::new(&instrument, fill1);
+        position.apply(fill2);
+        let event = position.apply_and_emit(fill3, 3);
+
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.event_count(), 1);
+        assert_eq!(position.avg_px_open, 1.00005);
+        assert!(matches!(event, PositionEvent::PositionOpened(_)));
+    }
+
+    #[test]
+    fn test_unrealized_and_total_pnl() {
+        let instrument = audusd_sim();
+        let fill = order_filled(&instrument, OrderSide::Buy, "1", "100000", "1.00000", 2.0);
+
+        let position = Position::new(&instrument, fill);
+        let last = Price::from("1.00050");
+
+        assert_eq!(position.unrealized_pnl(last), Money::new(50.0, USD.clone()));
+        assert_eq!(position.total_pnl(last), Money::new(48.0, USD.clone()));
+        assert_eq!(
+            position.notional_value(last),
+            Money::new(100_050.0, USD.clone())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`fill.trade_id` already contained in `trade_ids")]
+    fn test_apply_duplicate_trade_id_panics() {
+        let instrument = audusd_sim();
+        let fill = order_filled(&instrument, OrderSide::Buy, "1", "100000", "1.00000", 0.0);
+
+        let mut position = Position::new(&instrument, fill.clone());
+        position.apply(fill);
+    }
+}
@@ -0,0 +1,209 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use crate::{
+    enums::{OrderSide, PositionSide},
+    events::order::OrderFilled,
+    identifiers::{instrument_id::InstrumentId, position_id::PositionId},
+    instruments::BaseInstrument,
+    position::Position,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Position`].
+///
+/// This struct wraps `Position` in a way that makes it compatible with C function
+/// calls, enabling interaction with `Position` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `Position_API` to be
+/// dereferenced to `Position`, providing access to `Position`'s methods without
+/// having to manually access the underlying `Position` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct Position_API(Box<Position>);
+
+impl Deref for Position_API {
+    type Target = Position;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Position_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn position_new(instrument: &BaseInstrument, fill: OrderFilled) -> Position_API {
+    Position_API(Box::new(Position::new(instrument, fill)))
+}
+
+#[no_mangle]
+pub extern "C" fn position_drop(position: Position_API) {
+    drop(position); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn position_apply(position: &mut Position_API, fill: OrderFilled) {
+    position.apply(fill);
+}
+
+#[no_mangle]
+pub extern "C" fn position_id(position: &Position_API) -> PositionId {
+    position.id.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn position_instrument_id(position: &Position_API) -> InstrumentId {
+    position.instrument_id.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn position_entry(position: &Position_API) -> OrderSide {
+    position.entry
+}
+
+#[no_mangle]
+pub extern "C" fn position_side(position: &Position_API) -> PositionSide {
+    position.side
+}
+
+#[no_mangle]
+pub extern "C" fn position_signed_qty(position: &Position_API) -> f64 {
+    position.signed_qty
+}
+
+#[no_mangle]
+pub extern "C" fn position_quantity(position: &Position_API) -> Quantity {
+    position.quantity
+}
+
+#[no_mangle]
+pub extern "C" fn position_peak_qty(position: &Position_API) -> Quantity {
+    position.peak_qty
+}
+
+#[no_mangle]
+pub extern "C" fn position_avg_px_open(position: &Position_API) -> f64 {
+    position.avg_px_open
+}
+
+/// Returns the average close price for the position, or 0.0 if it has not been reduced.
+#[no_mangle]
+pub extern "C" fn position_avg_px_close(position: &Position_API) -> f64 {
+    position.avg_px_close.unwrap_or(0.0)
+}
+
+/// Returns the realized return for the position, or 0.0 if it has not been reduced.
+#[no_mangle]
+pub extern "C" fn position_realized_return(position: &Position_API) -> f64 {
+    position.realized_return.unwrap_or(0.0)
+}
+
+/// Returns the realized PnL for the position, or zero in the cost currency if none.
+#[no_mangle]
+pub extern "C" fn position_realized_pnl(position: &Position_API) -> Money {
+    position
+        .realized_pnl
+        .clone()
+        .unwrap_or_else(|| Money::new(0.0, position.cost_currency.clone()))
+}
+
+#[no_mangle]
+pub extern "C" fn position_unrealized_pnl(position: &Position_API, last: Price) -> Money {
+    position.unrealized_pnl(last)
+}
+
+#[no_mangle]
+pub extern "C" fn position_total_pnl(position: &Position_API, last: Price) -> Money {
+    position.total_pnl(last)
+}
+
+#[no_mangle]
+pub extern "C" fn position_notional_value(position: &Position_API, last: Price) -> Money {
+    position.notional_value(last)
+}
+
+#[no_mangle]
+pub extern "C" fn position_event_count(position: &Position_API) -> usize {
+    position.event_count()
+}
+
+#[no_mangle]
+pub extern "C" fn position_is_opposite_side(position: &Position_API, side: OrderSide) -> u8 {
+    u8::from(position.is_opposite_side(side))
+}
+
+#[no_mangle]
+pub extern "C" fn position_is_long(position: &Position_API) -> u8 {
+    u8::from(position.is_long())
+}
+
+#[no_mangle]
+pub extern "C" fn position_is_short(position: &Position_API) -> u8 {
+    u8::from(position.is_short())
+}
+
+#[no_mangle]
+pub extern "C" fn position_is_open(position: &Position_API) -> u8 {
+    u8::from(position.is_open())
+}
+
+#[no_mangle]
+pub extern "C" fn position_is_closed(position: &Position_API) -> u8 {
+    u8::from(position.is_closed())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        currencies::USD,
+        position::tests::{audusd_sim, order_filled},
+    };
+
+    #[test]
+    fn test_position_api_round_trip() {
+        let instrument = audusd_sim();
+        let fill1 = order_filled(&instrument, OrderSide::Buy, "1", "100000", "1.00000", 0.0);
+        let fill2 = order_filled(&instrument, OrderSide::Sell, "2", "100000", "1.00020", 0.0);
+
+        let mut position = position_new(&instrument, fill1);
+        assert_eq!(position_side(&position), PositionSide::Long);
+        assert_eq!(position_is_open(&position), 1);
+        assert_eq!(position_avg_px_close(&position), 0.0);
+        assert_eq!(
+            position_unrealized_pnl(&position, Price::from("1.00010")),
+            Money::new(10.0, USD.clone())
+        );
+
+        position_apply(&mut position, fill2);
+        assert_eq!(position_is_closed(&position), 1);
+        assert_eq!(position_avg_px_close(&position), 1.0002);
+        assert_eq!(
+            position_realized_pnl(&position),
+            Money::new(20.0, USD.clone())
+        );
+        position_drop(position);
+    }
+}
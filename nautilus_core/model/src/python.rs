@@ -0,0 +1,57 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Helpers shared by the Python (PyO3) bindings for the model types.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*};
+
+/// Converts the error `e` to a Python `ValueError`.
+pub fn to_pyvalue_err(e: impl Display) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+/// Returns the Python hash of `value`.
+pub fn hash_of<T: Hash>(value: &T) -> isize {
+    let mut h = DefaultHasher::new();
+    value.hash(&mut h);
+    h.finish() as isize
+}
+
+/// Compares `lhs` and `rhs` for equality, returning `NotImplemented` for any ordering `op`.
+pub fn richcmp_eq<T: PartialEq>(lhs: &T, rhs: &T, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+    match op {
+        CompareOp::Eq => (lhs == rhs).into_py(py),
+        CompareOp::Ne => (lhs != rhs).into_py(py),
+        _ => py.NotImplemented(),
+    }
+}
+
+/// Compares `lhs` and `rhs` with the comparison `op`.
+pub fn richcmp_ord<T: PartialOrd>(lhs: &T, rhs: &T, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+    match op {
+        CompareOp::Lt => (lhs < rhs).into_py(py),
+        CompareOp::Le => (lhs <= rhs).into_py(py),
+        CompareOp::Eq => (lhs == rhs).into_py(py),
+        CompareOp::Ne => (lhs != rhs).into_py(py),
+        CompareOp::Gt => (lhs > rhs).into_py(py),
+        CompareOp::Ge => (lhs >= rhs).into_py(py),
+    }
+}
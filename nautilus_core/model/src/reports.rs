@@ -0,0 +1,182 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Reports of the state of orders, fills and positions as reported by a trading venue.
+
+use std::collections::HashMap;
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+
+use crate::{
+    enums::{
+        ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, PositionSide,
+        TimeInForce, TrailingOffsetType, TriggerType,
+    },
+    identifiers::{
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        instrument_id::InstrumentId, order_list_id::OrderListId, position_id::PositionId,
+        trade_id::TradeId, venue::Venue, venue_order_id::VenueOrderId,
+    },
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+/// Represents the status of an order at a point in time, as reported by the venue.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: Option<ClientOrderId>,
+    pub order_list_id: Option<OrderListId>,
+    pub venue_order_id: VenueOrderId,
+    pub order_side: OrderSide,
+    pub order_type: OrderType,
+    pub contingency_type: Option<ContingencyType>,
+    pub time_in_force: TimeInForce,
+    pub expire_time: Option<UnixNanos>,
+    pub order_status: OrderStatus,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+    pub trigger_type: Option<TriggerType>,
+    pub limit_offset: Option<Price>,
+    pub trailing_offset: Option<Price>,
+    pub trailing_offset_type: Option<TrailingOffsetType>,
+    pub quantity: Quantity,
+    pub filled_qty: Quantity,
+    pub display_qty: Option<Quantity>,
+    pub avg_px: Option<f64>,
+    pub post_only: bool,
+    pub reduce_only: bool,
+    pub cancel_reason: Option<String>,
+    pub report_id: UUID4,
+    pub ts_accepted: UnixNanos,
+    pub ts_triggered: Option<UnixNanos>,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+/// Represents a fill of an order, as reported by the venue.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FillReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: Option<ClientOrderId>,
+    pub venue_order_id: VenueOrderId,
+    pub venue_position_id: Option<PositionId>,
+    pub trade_id: TradeId,
+    pub order_side: OrderSide,
+    pub last_qty: Quantity,
+    pub last_px: Price,
+    pub commission: Money,
+    pub liquidity_side: LiquiditySide,
+    pub report_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+/// Represents the status of a position at a point in time, as reported by the venue.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub position_side: PositionSide,
+    pub quantity: Quantity,
+    pub venue_position_id: Option<PositionId>,
+    pub report_id: UUID4,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl PositionStatusReport {
+    /// Returns the position quantity, signed negative for a short position.
+    #[must_use]
+    pub fn signed_qty(&self) -> f64 {
+        match self.position_side {
+            PositionSide::Long => self.quantity.as_f64(),
+            PositionSide::Short => -self.quantity.as_f64(),
+            PositionSide::Flat | PositionSide::NoPositionSide => 0.0,
+        }
+    }
+}
+
+/// Represents the execution state of an account at a venue, made up of the status reports
+/// of its orders and positions along with the fills of its orders.
+#[derive(Clone, Debug)]
+pub struct ExecutionMassStatus {
+    pub client_id: ClientId,
+    pub account_id: AccountId,
+    pub venue: Venue,
+    pub report_id: UUID4,
+    pub ts_init: UnixNanos,
+    order_reports: Vec<OrderStatusReport>,
+    fill_reports: HashMap<VenueOrderId, Vec<FillReport>>,
+    position_reports: Vec<PositionStatusReport>,
+}
+
+impl ExecutionMassStatus {
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        account_id: AccountId,
+        venue: Venue,
+        report_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            client_id,
+            account_id,
+            venue,
+            report_id,
+            ts_init,
+            order_reports: Vec::new(),
+            fill_reports: HashMap::new(),
+            position_reports: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn order_reports(&self) -> &[OrderStatusReport] {
+        &self.order_reports
+    }
+
+    /// Returns the fill reports for the `venue_order_id`, in the order they were added.
+    #[must_use]
+    pub fn fill_reports(&self, venue_order_id: &VenueOrderId) -> &[FillReport] {
+        self.fill_reports
+            .get(venue_order_id)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    #[must_use]
+    pub fn position_reports(&self) -> &[PositionStatusReport] {
+        &self.position_reports
+    }
+
+    pub fn add_order_reports(&mut self, reports: Vec<OrderStatusReport>) {
+        self.order_reports.extend(reports);
+    }
+
+    pub fn add_fill_reports(&mut self, reports: Vec<FillReport>) {
+        for report in reports {
+            self.fill_reports
+                .entry(report.venue_order_id.clone())
+                .or_default()
+                .push(report);
+        }
+    }
+
+    pub fn add_position_reports(&mut self, reports: Vec<PositionStatusReport>) {
+        self.position_reports.extend(reports);
+    }
+}
@@ -0,0 +1,534 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::{
+    ffi_error::{try_optional_cstr_to_str, write_result, FFI_ERROR},
+    time::UnixNanos,
+    uuid::UUID4,
+};
+
+use crate::{
+    enums::{
+        ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, PositionSide,
+        TimeInForce, TrailingOffsetType, TriggerType,
+    },
+    identifiers::{
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        instrument_id::InstrumentId, order_list_id::OrderListId, position_id::PositionId,
+        trade_id::TradeId, venue::Venue, venue_order_id::VenueOrderId,
+    },
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`OrderStatusReport`].
+///
+/// This struct wraps `OrderStatusReport` in a way that makes it compatible with C function
+/// calls, enabling interaction with `OrderStatusReport` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of
+/// `OrderStatusReport_API` to be dereferenced to `OrderStatusReport`, providing access to
+/// `OrderStatusReport`'s fields without having to manually access the underlying instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct OrderStatusReport_API(Box<OrderStatusReport>);
+
+impl Deref for OrderStatusReport_API {
+    type Target = OrderStatusReport;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for OrderStatusReport_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`FillReport`].
+///
+/// This struct wraps `FillReport` in a way that makes it compatible with C function
+/// calls, enabling interaction with `FillReport` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `FillReport_API`
+/// to be dereferenced to `FillReport`, providing access to `FillReport`'s fields without
+/// having to manually access the underlying instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct FillReport_API(Box<FillReport>);
+
+impl Deref for FillReport_API {
+    type Target = FillReport;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for FillReport_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`PositionStatusReport`].
+///
+/// This struct wraps `PositionStatusReport` in a way that makes it compatible with C function
+/// calls, enabling interaction with `PositionStatusReport` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of
+/// `PositionStatusReport_API` to be dereferenced to `PositionStatusReport`, providing access
+/// to `PositionStatusReport`'s fields without having to manually access the underlying
+/// instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct PositionStatusReport_API(Box<PositionStatusReport>);
+
+impl Deref for PositionStatusReport_API {
+    type Target = PositionStatusReport;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for PositionStatusReport_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Returns `None` for the Python "no value" variant of an enum.
+fn enum_opt<T: PartialEq>(value: T, none: T) -> Option<T> {
+    (value != none).then_some(value)
+}
+
+/// Returns `None` for a zero timestamp.
+fn ts_opt(value: UnixNanos) -> Option<UnixNanos> {
+    (value != 0).then_some(value)
+}
+
+/// Returns `None` for a NaN average price.
+fn px_opt(value: f64) -> Option<f64> {
+    (!value.is_nan()).then_some(value)
+}
+
+/// Writes a new [`OrderStatusReport`] to `out`.
+///
+/// The optional enums take their `NO_*` variant for no value, `expire_time` and
+/// `ts_triggered` take zero, and `avg_px` takes NaN.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `client_order_id`, `order_list_id`, `price`, `trigger_price`, `limit_offset`,
+///   `trailing_offset` and `display_qty` are each either null or a valid pointer.
+/// - Assumes `cancel_reason` is either null or a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for an [`OrderStatusReport_API`].
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn order_status_report_new(
+    account_id: AccountId,
+    instrument_id: InstrumentId,
+    client_order_id: *const ClientOrderId,
+    order_list_id: *const OrderListId,
+    venue_order_id: VenueOrderId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    contingency_type: ContingencyType,
+    time_in_force: TimeInForce,
+    expire_time: UnixNanos,
+    order_status: OrderStatus,
+    price: *const Price,
+    trigger_price: *const Price,
+    trigger_type: TriggerType,
+    limit_offset: *const Price,
+    trailing_offset: *const Price,
+    trailing_offset_type: TrailingOffsetType,
+    quantity: Quantity,
+    filled_qty: Quantity,
+    display_qty: *const Quantity,
+    avg_px: f64,
+    post_only: u8,
+    reduce_only: u8,
+    cancel_reason: *const c_char,
+    report_id: UUID4,
+    ts_accepted: UnixNanos,
+    ts_triggered: UnixNanos,
+    ts_last: UnixNanos,
+    ts_init: UnixNanos,
+    out: *mut OrderStatusReport_API,
+) -> u8 {
+    let result = try_optional_cstr_to_str(cancel_reason, "cancel reason").map(|cancel_reason| {
+        OrderStatusReport_API(Box::new(OrderStatusReport {
+            account_id,
+            instrument_id,
+            client_order_id: client_order_id.as_ref().cloned(),
+            order_list_id: order_list_id.as_ref().cloned(),
+            venue_order_id,
+            order_side,
+            order_type,
+            contingency_type: enum_opt(contingency_type, ContingencyType::NoContingency),
+            time_in_force,
+            expire_time: ts_opt(expire_time),
+            order_status,
+            price: price.as_ref().copied(),
+            trigger_price: trigger_price.as_ref().copied(),
+            trigger_type: enum_opt(trigger_type, TriggerType::NoTrigger),
+            limit_offset: limit_offset.as_ref().copied(),
+            trailing_offset: trailing_offset.as_ref().copied(),
+            trailing_offset_type: enum_opt(
+                trailing_offset_type,
+                TrailingOffsetType::NoTrailingOffset,
+            ),
+            quantity,
+            filled_qty,
+            display_qty: display_qty.as_ref().copied(),
+            avg_px: px_opt(avg_px),
+            post_only: post_only != 0,
+            reduce_only: reduce_only != 0,
+            cancel_reason: cancel_reason.map(str::to_string),
+            report_id,
+            ts_accepted,
+            ts_triggered: ts_opt(ts_triggered),
+            ts_last,
+            ts_init,
+        }))
+    });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[no_mangle]
+pub extern "C" fn order_status_report_drop(report: OrderStatusReport_API) {
+    drop(report); // Memory freed here
+}
+
+/// Returns a new [`FillReport`].
+///
+/// # Safety
+///
+/// - Assumes `client_order_id` and `venue_position_id` are each either null or a valid
+///   pointer.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn fill_report_new(
+    account_id: AccountId,
+    instrument_id: InstrumentId,
+    client_order_id: *const ClientOrderId,
+    venue_order_id: VenueOrderId,
+    venue_position_id: *const PositionId,
+    trade_id: TradeId,
+    order_side: OrderSide,
+    last_qty: Quantity,
+    last_px: Price,
+    commission: Money,
+    liquidity_side: LiquiditySide,
+    report_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> FillReport_API {
+    FillReport_API(Box::new(FillReport {
+        account_id,
+        instrument_id,
+        client_order_id: client_order_id.as_ref().cloned(),
+        venue_order_id,
+        venue_position_id: venue_position_id.as_ref().cloned(),
+        trade_id,
+        order_side,
+        last_qty,
+        last_px,
+        commission,
+        liquidity_side,
+        report_id,
+        ts_event,
+        ts_init,
+    }))
+}
+
+#[no_mangle]
+pub extern "C" fn fill_report_drop(report: FillReport_API) {
+    drop(report); // Memory freed here
+}
+
+/// Returns a new [`PositionStatusReport`].
+///
+/// # Safety
+///
+/// - Assumes `venue_position_id` is either null or a valid pointer.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn position_status_report_new(
+    account_id: AccountId,
+    instrument_id: InstrumentId,
+    position_side: PositionSide,
+    quantity: Quantity,
+    venue_position_id: *const PositionId,
+    report_id: UUID4,
+    ts_last: UnixNanos,
+    ts_init: UnixNanos,
+) -> PositionStatusReport_API {
+    PositionStatusReport_API(Box::new(PositionStatusReport {
+        account_id,
+        instrument_id,
+        position_side,
+        quantity,
+        venue_position_id: venue_position_id.as_ref().cloned(),
+        report_id,
+        ts_last,
+        ts_init,
+    }))
+}
+
+#[no_mangle]
+pub extern "C" fn position_status_report_drop(report: PositionStatusReport_API) {
+    drop(report); // Memory freed here
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`ExecutionMassStatus`].
+///
+/// This struct wraps `ExecutionMassStatus` in a way that makes it compatible with C function
+/// calls, enabling interaction with `ExecutionMassStatus` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of
+/// `ExecutionMassStatus_API` to be dereferenced to `ExecutionMassStatus`, providing access to
+/// `ExecutionMassStatus`'s methods without having to manually access the underlying instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct ExecutionMassStatus_API(Box<ExecutionMassStatus>);
+
+impl Deref for ExecutionMassStatus_API {
+    type Target = ExecutionMassStatus;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ExecutionMassStatus_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn execution_mass_status_new(
+    client_id: ClientId,
+    account_id: AccountId,
+    venue: Venue,
+    report_id: UUID4,
+    ts_init: UnixNanos,
+) -> ExecutionMassStatus_API {
+    ExecutionMassStatus_API(Box::new(ExecutionMassStatus::new(
+        client_id, account_id, venue, report_id, ts_init,
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn execution_mass_status_drop(mass_status: ExecutionMassStatus_API) {
+    drop(mass_status); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn execution_mass_status_order_report_count(
+    mass_status: &ExecutionMassStatus_API,
+) -> usize {
+    mass_status.order_reports().len()
+}
+
+#[no_mangle]
+pub extern "C" fn execution_mass_status_position_report_count(
+    mass_status: &ExecutionMassStatus_API,
+) -> usize {
+    mass_status.position_reports().len()
+}
+
+#[no_mangle]
+pub extern "C" fn execution_mass_status_add_order_report(
+    mass_status: &mut ExecutionMassStatus_API,
+    report: &OrderStatusReport_API,
+) {
+    mass_status.add_order_reports(vec![report.deref().clone()]);
+}
+
+#[no_mangle]
+pub extern "C" fn execution_mass_status_add_fill_report(
+    mass_status: &mut ExecutionMassStatus_API,
+    report: &FillReport_API,
+) {
+    mass_status.add_fill_reports(vec![report.deref().clone()]);
+}
+
+#[no_mangle]
+pub extern "C" fn execution_mass_status_add_position_report(
+    mass_status: &mut ExecutionMassStatus_API,
+    report: &PositionStatusReport_API,
+) {
+    mass_status.add_position_reports(vec![report.deref().clone()]);
+}
+
+#[no_mangle]
+pub extern "C" fn execution_mass_status_fill_report_count(
+    mass_status: &ExecutionMassStatus_API,
+    venue_order_id: &VenueOrderId,
+) -> usize {
+    mass_status.fill_reports(venue_order_id).len()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, mem::MaybeUninit, ptr, str::FromStr};
+
+    use nautilus_core::ffi_error::last_error;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::types::currency::Currency;
+
+    unsafe fn order_status_report(
+        cancel_reason: *const c_char,
+        out: *mut OrderStatusReport_API,
+    ) -> u8 {
+        order_status_report_new(
+            AccountId::new("SIM-001"),
+            InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            ptr::null(),
+            ptr::null(),
+            VenueOrderId::new("1"),
+            OrderSide::Buy,
+            OrderType::Market,
+            ContingencyType::NoContingency,
+            TimeInForce::Gtc,
+            0,
+            OrderStatus::Accepted,
+            ptr::null(),
+            ptr::null(),
+            TriggerType::NoTrigger,
+            ptr::null(),
+            ptr::null(),
+            TrailingOffsetType::NoTrailingOffset,
+            Quantity::from_str("100000").unwrap(),
+            Quantity::from_str("0").unwrap(),
+            ptr::null(),
+            f64::NAN,
+            0,
+            1,
+            cancel_reason,
+            UUID4::new(),
+            1,
+            0,
+            2,
+            3,
+            out,
+        )
+    }
+
+    #[rstest]
+    fn test_order_status_report_new_with_null_options() {
+        let mut report = MaybeUninit::<OrderStatusReport_API>::uninit();
+        let status = unsafe { order_status_report(ptr::null(), report.as_mut_ptr()) };
+        assert_eq!(status, 0);
+        let report = unsafe { report.assume_init() };
+
+        assert_eq!(report.client_order_id, None);
+        assert_eq!(report.contingency_type, None);
+        assert_eq!(report.expire_time, None);
+        assert_eq!(report.price, None);
+        assert_eq!(report.avg_px, None);
+        assert!(report.reduce_only);
+        assert_eq!(report.cancel_reason, None);
+        assert_eq!(report.ts_triggered, None);
+        order_status_report_drop(report);
+    }
+
+    #[rstest]
+    fn test_order_status_report_new_with_invalid_cancel_reason() {
+        let cancel_reason = CString::new(vec![0xff, 0xfe]).unwrap();
+        let mut report = MaybeUninit::<OrderStatusReport_API>::uninit();
+
+        let status = unsafe { order_status_report(cancel_reason.as_ptr(), report.as_mut_ptr()) };
+
+        assert_eq!(status, FFI_ERROR);
+        assert!(last_error()
+            .unwrap()
+            .starts_with("invalid cancel reason string"));
+    }
+
+    #[rstest]
+    fn test_execution_mass_status_add_reports() {
+        let mut mass_status = execution_mass_status_new(
+            ClientId::new("SIM"),
+            AccountId::new("SIM-001"),
+            Venue::new("SIM"),
+            UUID4::new(),
+            0,
+        );
+        let venue_order_id = VenueOrderId::new("1");
+        let fill = unsafe {
+            fill_report_new(
+                AccountId::new("SIM-001"),
+                InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+                ptr::null(),
+                venue_order_id.clone(),
+                ptr::null(),
+                TradeId::new("E-1"),
+                OrderSide::Buy,
+                Quantity::from_str("100000").unwrap(),
+                Price::from_str("1.00000").unwrap(),
+                Money::new(2.0, Currency::from("USD")),
+                LiquiditySide::Taker,
+                UUID4::new(),
+                1,
+                2,
+            )
+        };
+        let position = unsafe {
+            position_status_report_new(
+                AccountId::new("SIM-001"),
+                InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+                PositionSide::Long,
+                Quantity::from_str("100000").unwrap(),
+                ptr::null(),
+                UUID4::new(),
+                1,
+                2,
+            )
+        };
+
+        execution_mass_status_add_fill_report(&mut mass_status, &fill);
+        execution_mass_status_add_position_report(&mut mass_status, &position);
+
+        assert_eq!(
+            execution_mass_status_fill_report_count(&mass_status, &venue_order_id),
+            1
+        );
+        assert_eq!(execution_mass_status_position_report_count(&mass_status), 1);
+        assert_eq!(execution_mass_status_order_report_count(&mass_status), 0);
+    }
+}
@@ -0,0 +1,233 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! JSON schemas of the model events, commands and data types.
+//!
+//! The schemas describe the serde representation of each type, so that external systems
+//! can validate the messages published on the external message bus.
+
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Metadata, RootSchema, Schema, SchemaObject},
+    schema_for, JsonSchema,
+};
+
+use crate::{
+    commands::{
+        CancelAllOrders, CancelOrder, ModifyOrder, QueryOrder, SubmitOrder, SubmitOrderList,
+        TradingCommand,
+    },
+    data::{
+        bar::{Bar, BarType},
+        book::{OrderBookDelta, OrderBookDeltas, OrderBookSnapshot},
+        tick::{QuoteTick, TradeTick},
+        Data,
+    },
+    events::{
+        account::AccountState,
+        book::OrderBookChecksumFailed,
+        order::{
+            OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEvent,
+            OrderExpired, OrderFilled, OrderInitialized, OrderModifyRejected, OrderPendingCancel,
+            OrderPendingUpdate, OrderRejected, OrderSubmitted, OrderTriggered, OrderUpdated,
+        },
+        position::{PositionChanged, PositionClosed, PositionEvent, PositionOpened},
+    },
+    identifiers::{
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        component_id::ComponentId, exec_algorithm_id::ExecAlgorithmId, instrument_id::InstrumentId,
+        order_list_id::OrderListId, position_id::PositionId, strategy_id::StrategyId,
+        symbol::Symbol, trade_id::TradeId, trader_id::TraderId, venue::Venue,
+        venue_order_id::VenueOrderId,
+    },
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// Implements [`JsonSchema`] for a type which is serialized as a string.
+macro_rules! impl_string_schema {
+    ($ty:ident, $description:literal) => {
+        impl JsonSchema for $ty {
+            fn schema_name() -> String {
+                stringify!($ty).to_string()
+            }
+
+            fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+                SchemaObject {
+                    instance_type: Some(InstanceType::String.into()),
+                    metadata: Some(Box::new(Metadata {
+                        description: Some($description.to_string()),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                }
+                .into()
+            }
+        }
+    };
+}
+
+impl_string_schema!(AccountId, "An account ID, e.g. \"SIM-001\".");
+impl_string_schema!(ClientId, "A client ID, e.g. \"BINANCE\".");
+impl_string_schema!(
+    ClientOrderId,
+    "A client order ID, e.g. \"O-20230101-000-001-1\"."
+);
+impl_string_schema!(ComponentId, "A component ID, e.g. \"RiskEngine\".");
+impl_string_schema!(ExecAlgorithmId, "An execution algorithm ID, e.g. \"TWAP\".");
+impl_string_schema!(InstrumentId, "An instrument ID as \"{symbol}.{venue}\".");
+impl_string_schema!(OrderListId, "An order list ID, e.g. \"OL-001\".");
+impl_string_schema!(PositionId, "A position ID, e.g. \"P-001\".");
+impl_string_schema!(StrategyId, "A strategy ID, e.g. \"EMACross-001\".");
+impl_string_schema!(Symbol, "A symbol, e.g. \"AUD/USD\".");
+impl_string_schema!(TradeId, "A trade match ID assigned by the venue.");
+impl_string_schema!(TraderId, "A trader ID, e.g. \"TRADER-001\".");
+impl_string_schema!(Venue, "A venue, e.g. \"BINANCE\".");
+impl_string_schema!(VenueOrderId, "An order ID assigned by the venue.");
+impl_string_schema!(
+    BarType,
+    "A bar type as \"{instrument_id}-{step}-{aggregation}-{price_type}-{source}\"."
+);
+impl_string_schema!(Currency, "A currency code, e.g. \"USD\".");
+impl_string_schema!(
+    Money,
+    "An amount of money as \"{amount} {currency}\", e.g. \"1.00 USD\"."
+);
+impl_string_schema!(Price, "A price as a decimal string at its precision.");
+impl_string_schema!(Quantity, "A quantity as a decimal string at its precision.");
+
+/// Returns the JSON schemas of the model events, commands and data types, keyed by type name.
+#[must_use]
+pub fn model_schemas() -> BTreeMap<&'static str, RootSchema> {
+    BTreeMap::from([
+        // Order events
+        ("OrderEvent", schema_for!(OrderEvent)),
+        ("OrderInitialized", schema_for!(OrderInitialized)),
+        ("OrderDenied", schema_for!(OrderDenied)),
+        ("OrderSubmitted", schema_for!(OrderSubmitted)),
+        ("OrderAccepted", schema_for!(OrderAccepted)),
+        ("OrderRejected", schema_for!(OrderRejected)),
+        ("OrderCanceled", schema_for!(OrderCanceled)),
+        ("OrderExpired", schema_for!(OrderExpired)),
+        ("OrderTriggered", schema_for!(OrderTriggered)),
+        ("OrderPendingUpdate", schema_for!(OrderPendingUpdate)),
+        ("OrderPendingCancel", schema_for!(OrderPendingCancel)),
+        ("OrderModifyRejected", schema_for!(OrderModifyRejected)),
+        ("OrderCancelRejected", schema_for!(OrderCancelRejected)),
+        ("OrderUpdated", schema_for!(OrderUpdated)),
+        ("OrderFilled", schema_for!(OrderFilled)),
+        // Position events
+        ("PositionEvent", schema_for!(PositionEvent)),
+        ("PositionOpened", schema_for!(PositionOpened)),
+        ("PositionChanged", schema_for!(PositionChanged)),
+        ("PositionClosed", schema_for!(PositionClosed)),
+        // Other events
+        ("AccountState", schema_for!(AccountState)),
+        (
+            "OrderBookChecksumFailed",
+            schema_for!(OrderBookChecksumFailed),
+        ),
+        // Commands
+        ("TradingCommand", schema_for!(TradingCommand)),
+        ("SubmitOrder", schema_for!(SubmitOrder)),
+        ("SubmitOrderList", schema_for!(SubmitOrderList)),
+        ("ModifyOrder", schema_for!(ModifyOrder)),
+        ("CancelOrder", schema_for!(CancelOrder)),
+        ("CancelAllOrders", schema_for!(CancelAllOrders)),
+        ("QueryOrder", schema_for!(QueryOrder)),
+        // Data
+        ("Data", schema_for!(Data)),
+        ("QuoteTick", schema_for!(QuoteTick)),
+        ("TradeTick", schema_for!(TradeTick)),
+        ("Bar", schema_for!(Bar)),
+        ("OrderBookDelta", schema_for!(OrderBookDelta)),
+        ("OrderBookDeltas", schema_for!(OrderBookDeltas)),
+        ("OrderBookSnapshot", schema_for!(OrderBookSnapshot)),
+    ])
+}
+
+/// Writes the schemas from [`model_schemas`] to `{name}.json` files in the given directory,
+/// creating the directory if it does not exist.
+pub fn write_schemas(dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    for (name, schema) in model_schemas() {
+        let json = serde_json::to_string_pretty(&schema)?;
+        fs::write(dir.join(format!("{name}.json")), json)?;
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::Value;
+
+    use super::*;
+
+    fn schema_json(name: &str) -> Value {
+        serde_json::to_value(&model_schemas()[name]).unwrap()
+    }
+
+    #[rstest]
+    fn test_order_filled_schema() {
+        let schema = schema_json("OrderFilled");
+
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&Value::from("commission")));
+        assert!(required.contains(&Value::from("ts_event")));
+        assert_eq!(
+            schema["properties"]["last_px"]["$ref"],
+            "#/definitions/Price"
+        );
+        assert_eq!(schema["definitions"]["Price"]["type"], "string");
+        assert_eq!(schema["definitions"]["UUID4"]["format"], "uuid");
+    }
+
+    #[rstest]
+    fn test_order_event_schema_is_one_of_events() {
+        let schema = schema_json("OrderEvent");
+
+        assert_eq!(schema["oneOf"].as_array().unwrap().len(), 15);
+    }
+
+    #[rstest]
+    fn test_submit_order_schema_references_order_initialized() {
+        let schema = schema_json("SubmitOrder");
+
+        assert_eq!(
+            schema["properties"]["order"]["$ref"],
+            "#/definitions/OrderInitialized"
+        );
+    }
+
+    #[rstest]
+    fn test_write_schemas() {
+        let dir = tempfile::tempdir().unwrap();
+
+        write_schemas(dir.path()).unwrap();
+
+        let json = fs::read_to_string(dir.path().join("QuoteTick.json")).unwrap();
+        let schema: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(schema["title"], "QuoteTick");
+        assert_eq!(
+            fs::read_dir(dir.path()).unwrap().count(),
+            model_schemas().len()
+        );
+    }
+}
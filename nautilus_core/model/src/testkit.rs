@@ -0,0 +1,409 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Property-based testing strategies for model types, built on [`proptest`].
+//!
+//! The strategies generate values which are valid for the model (e.g. prices are exact at
+//! their precision, and books are never crossed), so properties can focus on behavior. They
+//! are available to other crates with the `testkit` feature.
+
+use std::str::FromStr;
+
+use nautilus_core::uuid::UUID4;
+use proptest::{collection::vec, prelude::*};
+
+use crate::{
+    data::book::BookOrder,
+    enums::{BookType, LiquiditySide, OrderSide, OrderType, TimeInForce},
+    events::order::{
+        OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEvent, OrderExpired,
+        OrderFilled, OrderInitialized, OrderModifyRejected, OrderPendingCancel, OrderPendingUpdate,
+        OrderRejected, OrderSubmitted, OrderTriggered, OrderUpdated,
+    },
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        trade_id::TradeId, venue_order_id::VenueOrderId,
+    },
+    orderbook::book::OrderBook,
+    types::{
+        currency::Currency, fixed::FIXED_PRECISION, money::Money, price::Price, quantity::Quantity,
+    },
+};
+
+/// The largest number of units (at the precision) generated for a price or quantity.
+const MAX_UNITS: i64 = 1_000_000_000;
+
+pub fn precision() -> impl Strategy<Value = u8> {
+    0..=FIXED_PRECISION
+}
+
+/// Returns a strategy for positive prices at the `precision`.
+pub fn price(precision: u8) -> impl Strategy<Value = Price> {
+    let step = 10_i64.pow(u32::from(FIXED_PRECISION - precision));
+    (1..MAX_UNITS).prop_map(move |units| Price::from_raw(units * step, precision))
+}
+
+/// Returns a strategy for positive quantities at the `precision`.
+pub fn quantity(precision: u8) -> impl Strategy<Value = Quantity> {
+    let step = 10_u64.pow(u32::from(FIXED_PRECISION - precision));
+    (1..MAX_UNITS as u64).prop_map(move |units| Quantity::from_raw(units * step, precision))
+}
+
+pub fn order_side() -> impl Strategy<Value = OrderSide> {
+    prop_oneof![Just(OrderSide::Buy), Just(OrderSide::Sell)]
+}
+
+/// Returns a strategy for an order book of the `book_type` with up to `max_orders` orders.
+///
+/// Bids are priced below a random mid price and asks above it, so the book is never crossed.
+/// Orders are added to an `L3_MBO` book, and otherwise update their price level, so an
+/// `L1_TBBO` book holds only the last order on each side.
+pub fn order_book(
+    instrument_id: InstrumentId,
+    book_type: BookType,
+    max_orders: usize,
+) -> impl Strategy<Value = OrderBook> {
+    let orders = vec((order_side(), 1..100_i64, 1..10_000_u64), 0..=max_orders);
+    (1_000..100_000_i64, orders).prop_map(move |(mid, orders)| {
+        let mut book = OrderBook::new(instrument_id.clone(), book_type);
+        for (i, (side, offset, size)) in orders.into_iter().enumerate() {
+            let ticks = match side {
+                OrderSide::Buy => mid - offset,
+                _ => mid + offset,
+            };
+            let order = BookOrder::new(
+                side,
+                Price::from_raw(ticks * 10_000, 5),
+                Quantity::from_raw(size * 1_000_000_000, 0),
+                i as u64 + 1,
+            );
+            let sequence = i as u64 + 1;
+            match book_type {
+                BookType::L3_MBO => book.add(order, sequence, sequence),
+                _ => book.update(order, sequence, sequence),
+            }
+        }
+        book
+    })
+}
+
+/// Returns a strategy for the initialization of a market or limit order.
+pub fn order_initialized() -> impl Strategy<Value = OrderInitialized> {
+    (
+        order_side(),
+        any::<bool>(),
+        quantity(0),
+        price(5),
+        0..1_000_000_u32,
+    )
+        .prop_map(|(side, is_limit, quantity, price, id)| OrderInitialized {
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            client_order_id: ClientOrderId::new(&format!("O-{id}")),
+            order_side: side,
+            order_type: if is_limit {
+                OrderType::Limit
+            } else {
+                OrderType::Market
+            },
+            quantity,
+            price: is_limit.then_some(price),
+            time_in_force: TimeInForce::Gtc,
+            event_id: UUID4::new(),
+            ..Default::default()
+        })
+}
+
+/// Returns a strategy for up to `max_len` lifecycle events of the order initialized by `init`.
+///
+/// The events are of any kind other than `OrderInitialized`, in any order, so a sequence will
+/// usually include invalid transitions. Fills have unique trade IDs and are for between 1% and
+/// 100% of the order quantity, so a sequence may also overfill the order. The timestamps of
+/// the events are increasing.
+pub fn order_events(
+    init: &OrderInitialized,
+    max_len: usize,
+) -> impl Strategy<Value = Vec<OrderEvent>> {
+    let init = init.clone();
+    vec((0..14_u8, 1..=100_u64), 0..=max_len).prop_map(move |kinds| {
+        kinds
+            .into_iter()
+            .enumerate()
+            .map(|(i, (kind, percent))| order_event(&init, kind, percent, i as u64 + 1))
+            .collect()
+    })
+}
+
+fn order_event(init: &OrderInitialized, kind: u8, percent: u64, ts: u64) -> OrderEvent {
+    let trader_id = init.trader_id.clone();
+    let strategy_id = init.strategy_id.clone();
+    let instrument_id = init.instrument_id.clone();
+    let client_order_id = init.client_order_id.clone();
+    let account_id = AccountId::new("SIM-001");
+    let venue_order_id = VenueOrderId::new("V-1");
+    let event_id = UUID4::new();
+
+    match kind {
+        0 => OrderEvent::OrderDenied(OrderDenied {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            reason: Box::new("DENIED".to_string()),
+            event_id,
+            ts_event: ts,
+            ts_init: ts,
+        }),
+        1 => OrderEvent::OrderSubmitted(OrderSubmitted {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            account_id,
+            event_id,
+            ts_event: ts,
+            ts_init: ts,
+        }),
+        2 => OrderEvent::OrderAccepted(OrderAccepted {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            account_id,
+            event_id,
+            ts_event: ts,
+            ts_init: ts,
+            ..Default::default()
+        }),
+        3 => OrderEvent::OrderRejected(OrderRejected {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            account_id,
+            reason: "REJECTED".to_string(),
+            event_id,
+            ts_event: ts,
+            ts_init: ts,
+            ..Default::default()
+        }),
+        4 => OrderEvent::OrderPendingUpdate(OrderPendingUpdate {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            account_id,
+            event_id,
+            ts_event: ts,
+            ts_init: ts,
+            ..Default::default()
+        }),
+        5 => OrderEvent::OrderPendingCancel(OrderPendingCancel {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            account_id,
+            event_id,
+            ts_event: ts,
+            ts_init: ts,
+            ..Default::default()
+        }),
+        6 => OrderEvent::OrderModifyRejected(OrderModifyRejected {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            event_id,
+            ts_event: ts,
+            ts_init: ts,
+            ..Default::default()
+        }),
+        7 => OrderEvent::OrderCancelRejected(OrderCancelRejected {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            event_id,
+            ts_event: ts,
+            ts_init: ts,
+            ..Default::default()
+        }),
+        // Updates keep the quantity, which can't be reduced below the filled quantity
+        8 => OrderEvent::OrderUpdated(OrderUpdated {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            quantity: init.quantity,
+            event_id,
+            ts_event: ts,
+            ts_init: ts,
+            ..Default::default()
+        }),
+        9 => OrderEvent::OrderTriggered(OrderTriggered {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            event_id,
+            ts_event: ts,
+            ts_init: ts,
+            ..Default::default()
+        }),
+        10 => OrderEvent::OrderCanceled(OrderCanceled {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            event_id,
+            ts_event: ts,
+            ts_init: ts,
+            ..Default::default()
+        }),
+        11 => OrderEvent::OrderExpired(OrderExpired {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            event_id,
+            ts_event: ts,
+            ts_init: ts,
+            ..Default::default()
+        }),
+        _ => {
+            let quantity = init.quantity;
+            let last_qty =
+                Quantity::from_raw((quantity.raw / 100 * percent).max(1), quantity.precision);
+            let fill = OrderFilled {
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                venue_order_id,
+                account_id,
+                trade_id: TradeId::new(&format!("T-{ts}")),
+                position_id: None,
+                order_side: init.order_side,
+                order_type: init.order_type,
+                last_qty,
+                last_px: Price::from("1.00000"),
+                currency: Currency::from("USD"),
+                commission: Money::new(2.0, Currency::from("USD")),
+                liquidity_side: LiquiditySide::Taker,
+                event_id,
+                ts_event: ts,
+                ts_init: ts,
+                reconciliation: false,
+            };
+            if kind == 12 {
+                OrderEvent::OrderPartiallyFilled(fill)
+            } else {
+                OrderEvent::OrderFilled(fill)
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{enums::OrderStatus, orders::Order};
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from_str("AUD/USD.SIM").unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn test_price_string_round_trip(price in precision().prop_flat_map(price)) {
+            let result = Price::from_str(&price.to_string()).unwrap();
+
+            prop_assert_eq!(result, price);
+            prop_assert_eq!(result.precision, price.precision);
+        }
+
+        #[test]
+        fn test_quantity_add_sub_round_trip(
+            (a, b) in precision().prop_flat_map(|p| (quantity(p), quantity(p)))
+        ) {
+            prop_assert_eq!(a + b - b, a);
+        }
+
+        #[test]
+        fn test_order_book_is_never_crossed(
+            book in prop_oneof![
+                order_book(instrument_id(), BookType::L1_TBBO, 50),
+                order_book(instrument_id(), BookType::L2_MBP, 50),
+                order_book(instrument_id(), BookType::L3_MBO, 50),
+            ]
+        ) {
+            prop_assert!(book.check_integrity().is_ok());
+        }
+
+        /// Applies each event of the sequence to the order as a state machine, checking that
+        /// rejected events leave the order unchanged, and accepted events follow the
+        /// transition table and keep the filled quantity consistent.
+        #[test]
+        fn test_order_lifecycle(
+            (init, events) in order_initialized()
+                .prop_flat_map(|init| (Just(init.clone()), order_events(&init, 20)))
+        ) {
+            let mut order = Order::from(init);
+
+            for event in events {
+                let before = order.clone();
+                let result = order.apply(event.clone());
+
+                prop_assert_eq!(order.filled_qty + order.leaves_qty, order.quantity);
+                match result {
+                    Err(_) => {
+                        prop_assert_eq!(order.status, before.status);
+                        prop_assert_eq!(order.filled_qty, before.filled_qty);
+                        prop_assert_eq!(order.event_count(), before.event_count());
+                    }
+                    Ok(()) => {
+                        prop_assert_eq!(order.event_count(), before.event_count() + 1);
+                        match &event {
+                            // Responses to pending requests restore the prior status
+                            OrderEvent::OrderUpdated(_)
+                            | OrderEvent::OrderModifyRejected(_)
+                            | OrderEvent::OrderCancelRejected(_) => {}
+                            _ => {
+                                // Only fills may follow a cancel, and no transition may
+                                // follow the other closed states
+                                if before.is_closed() {
+                                    prop_assert_eq!(before.status, OrderStatus::Canceled);
+                                }
+                                prop_assert_eq!(
+                                    Ok(order.status),
+                                    before.status.transition(&event).map_err(|e| e.to_string())
+                                );
+                            }
+                        }
+                        if let OrderEvent::OrderPartiallyFilled(fill)
+                        | OrderEvent::OrderFilled(fill) = &event
+                        {
+                            prop_assert_eq!(order.filled_qty, before.filled_qty + fill.last_qty);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
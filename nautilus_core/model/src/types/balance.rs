@@ -15,8 +15,19 @@
 
 use std::fmt::{Display, Formatter};
 
-use crate::types::{currency::Currency, money::Money};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
+use crate::{
+    identifiers::instrument_id::InstrumentId,
+    types::{currency::Currency, money::Money},
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AccountBalance {
     pub currency: Currency,
     pub total: Money,
@@ -24,6 +35,39 @@ pub struct AccountBalance {
     pub free: Money,
 }
 
+impl AccountBalance {
+    #[must_use]
+    pub fn new(total: Money, locked: Money, free: Money) -> Self {
+        assert_eq!(
+            total.currency, locked.currency,
+            "`locked` currency mismatch"
+        );
+        assert_eq!(total.currency, free.currency, "`free` currency mismatch");
+        assert_eq!(
+            total.raw(),
+            locked.raw() + free.raw(),
+            "`total` was not equal to `locked` + `free`"
+        );
+
+        Self {
+            currency: total.currency.clone(),
+            total,
+            locked,
+            free,
+        }
+    }
+
+    /// Returns a zero balance in the given `currency`.
+    #[must_use]
+    pub fn zero(currency: Currency) -> Self {
+        Self::new(
+            Money::new(0.0, currency.clone()),
+            Money::new(0.0, currency.clone()),
+            Money::new(0.0, currency),
+        )
+    }
+}
+
 impl Display for AccountBalance {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -33,3 +77,71 @@ impl Display for AccountBalance {
         )
     }
 }
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct MarginBalance {
+    pub initial: Money,
+    pub maintenance: Money,
+    pub currency: Currency,
+    pub instrument_id: InstrumentId,
+}
+
+impl MarginBalance {
+    #[must_use]
+    pub fn new(initial: Money, maintenance: Money, instrument_id: InstrumentId) -> Self {
+        assert_eq!(
+            initial.currency, maintenance.currency,
+            "`maintenance` currency mismatch"
+        );
+
+        Self {
+            currency: initial.currency.clone(),
+            initial,
+            maintenance,
+            instrument_id,
+        }
+    }
+}
+
+impl Display for MarginBalance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.instrument_id, self.currency.code, self.initial, self.maintenance,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::currencies::USD;
+
+    #[test]
+    fn test_account_balance_new() {
+        let balance = AccountBalance::new(
+            Money::new(100.0, USD.clone()),
+            Money::new(25.0, USD.clone()),
+            Money::new(75.0, USD.clone()),
+        );
+
+        assert_eq!(balance.currency, USD.clone());
+        assert_eq!(balance.free, Money::new(75.0, USD.clone()));
+    }
+
+    #[test]
+    #[should_panic(expected = "`total` was not equal to `locked` + `free`")]
+    fn test_account_balance_new_when_inconsistent() {
+        let _ = AccountBalance::new(
+            Money::new(100.0, USD.clone()),
+            Money::new(25.0, USD.clone()),
+            Money::new(70.0, USD.clone()),
+        );
+    }
+}
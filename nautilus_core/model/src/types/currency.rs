@@ -23,16 +23,24 @@ use std::{
 
 use nautilus_core::{
     correctness,
-    string::{cstr_to_string, str_to_cstr},
+    ffi_error::{try_cstr_to_str, write_parsed_cstr, write_result, FFI_ERROR},
+    string::str_to_cstr,
 };
-use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::{basic::CompareOp, prelude::*, types::PyTuple, PyTypeInfo};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize, Serializer};
 
 use crate::{currencies::CURRENCY_MAP, enums::CurrencyType};
+#[cfg(feature = "python")]
+use crate::{
+    currencies::USD,
+    python::{hash_of, richcmp_eq, to_pyvalue_err},
+};
 
 #[repr(C)]
 #[derive(Clone, Debug, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct Currency {
     pub code: Box<Arc<String>>,
     pub precision: u8,
@@ -42,25 +50,72 @@ pub struct Currency {
 }
 
 impl Currency {
-    #[must_use]
-    pub fn new(
+    /// Returns a new [`Currency`], or an error if `code` or `name` is not a valid string or
+    /// `precision` exceeds the maximum fixed precision.
+    pub fn try_new(
         code: &str,
         precision: u8,
         iso4217: u16,
         name: &str,
         currency_type: CurrencyType,
-    ) -> Self {
-        correctness::valid_string(code, "`Currency` code");
-        correctness::valid_string(name, "`Currency` name");
-        correctness::u8_in_range_inclusive(precision, 0, 9, "`Currency` precision");
+    ) -> Result<Self, String> {
+        correctness::check_valid_string(code, "`Currency` code")?;
+        correctness::check_valid_string(name, "`Currency` name")?;
+        correctness::check_u8_in_range_inclusive(precision, 0, 9, "`Currency` precision")?;
 
-        Self {
+        Ok(Self {
             code: Box::new(Arc::new(code.to_string())),
             precision,
             iso4217,
             name: Box::new(Arc::new(name.to_string())),
             currency_type,
+        })
+    }
+
+    /// Returns a new [`Currency`].
+    ///
+    /// # Panics
+    ///
+    /// - If `code` or `name` is not a valid string.
+    /// - If `precision` exceeds the maximum fixed precision.
+    #[must_use]
+    pub fn new(
+        code: &str,
+        precision: u8,
+        iso4217: u16,
+        name: &str,
+        currency_type: CurrencyType,
+    ) -> Self {
+        Self::try_new(code, precision, iso4217, name, currency_type)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Registers the given `currency` in the global currency registry.
+    ///
+    /// If a currency with the same code is already registered it is only replaced
+    /// when `overwrite` is true.
+    pub fn register(currency: Self, overwrite: bool) {
+        let mut map = CURRENCY_MAP.lock().unwrap();
+        if !overwrite && map.contains_key(currency.code.as_str()) {
+            return;
         }
+        map.insert(currency.code.to_string(), currency);
+    }
+
+    /// Returns whether a currency with the given `code` exists in the global registry.
+    #[must_use]
+    pub fn is_registered(code: &str) -> bool {
+        CURRENCY_MAP.lock().unwrap().contains_key(code)
+    }
+
+    #[must_use]
+    pub fn is_fiat(&self) -> bool {
+        self.currency_type == CurrencyType::Fiat
+    }
+
+    #[must_use]
+    pub fn is_crypto(&self) -> bool {
+        self.currency_type == CurrencyType::Crypto
     }
 }
 
@@ -95,6 +150,7 @@ impl From<&str> for Currency {
     }
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for Currency {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -104,6 +160,7 @@ impl Serialize for Currency {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Currency {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -114,15 +171,134 @@ impl<'de> Deserialize<'de> for Currency {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl Currency {
+    #[new]
+    fn py_new(
+        code: &str,
+        precision: u8,
+        iso4217: u16,
+        name: &str,
+        currency_type: CurrencyType,
+    ) -> PyResult<Self> {
+        if code.trim().is_empty() || name.trim().is_empty() {
+            return Err(to_pyvalue_err(
+                "invalid `Currency`, `code` and `name` must not be empty",
+            ));
+        }
+        if precision > 9 {
+            return Err(to_pyvalue_err(format!(
+                "invalid `Currency` precision, was {precision} (maximum 9)"
+            )));
+        }
+        Ok(Self::new(code, precision, iso4217, name, currency_type))
+    }
+
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let (code, precision, iso4217, name, currency_type): (String, u8, u16, String, u8) =
+            state.extract(py)?;
+        self.code = Box::new(Arc::new(code));
+        self.precision = precision;
+        self.iso4217 = iso4217;
+        self.name = Box::new(Arc::new(name));
+        self.currency_type = CurrencyType::from_repr(currency_type as usize).ok_or_else(|| {
+            to_pyvalue_err(format!("invalid `CurrencyType`, was {currency_type}"))
+        })?;
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyObject {
+        (
+            self.code.to_string(),
+            self.precision,
+            self.iso4217,
+            self.name.to_string(),
+            self.currency_type as u8,
+        )
+            .to_object(py)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py);
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> Self {
+        USD.clone()
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(value: &str) -> PyResult<Self> {
+        Self::from_str(value).map_err(to_pyvalue_err)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        richcmp_eq(self, other, op, py)
+    }
+
+    fn __hash__(&self) -> isize {
+        hash_of(self)
+    }
+
+    fn __str__(&self) -> String {
+        self.code.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}('{}')", Self::NAME, self.code)
+    }
+
+    #[getter]
+    #[pyo3(name = "code")]
+    fn py_code(&self) -> String {
+        self.code.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "precision")]
+    fn py_precision(&self) -> u8 {
+        self.precision
+    }
+
+    #[getter]
+    #[pyo3(name = "iso4217")]
+    fn py_iso4217(&self) -> u16 {
+        self.iso4217
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "currency_type")]
+    fn py_currency_type(&self) -> CurrencyType {
+        self.currency_type
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
-/// Returns a [`Currency`] from pointers and primitives.
+/// Returns a [`Currency`] from pointers and primitives, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
 ///
 /// # Safety
 ///
 /// - Assumes `code_ptr` is a valid C string pointer.
 /// - Assumes `name_ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`Currency`].
 #[no_mangle]
 pub unsafe extern "C" fn currency_from_py(
     code_ptr: *const c_char,
@@ -130,14 +306,13 @@ pub unsafe extern "C" fn currency_from_py(
     iso4217: u16,
     name_ptr: *const c_char,
     currency_type: CurrencyType,
-) -> Currency {
-    Currency {
-        code: Box::from(Arc::new(cstr_to_string(code_ptr))),
-        precision,
-        iso4217,
-        name: Box::from(Arc::new(cstr_to_string(name_ptr))),
-        currency_type,
-    }
+    out: *mut Currency,
+) -> u8 {
+    let result = try_cstr_to_str(code_ptr, "`Currency` code").and_then(|code| {
+        let name = try_cstr_to_str(name_ptr, "`Currency` name")?;
+        Currency::try_new(code, precision, iso4217, name, currency_type)
+    });
+    write_result(result, out, |_| FFI_ERROR)
 }
 
 #[no_mangle]
@@ -179,28 +354,47 @@ pub extern "C" fn currency_hash(currency: &Currency) -> u64 {
 
 #[no_mangle]
 pub extern "C" fn currency_register(currency: Currency) {
-    CURRENCY_MAP
-        .lock()
-        .unwrap()
-        .insert(currency.code.to_string(), currency);
+    Currency::register(currency, true);
 }
 
+/// Writes 1 to `out` if a currency with the code at `code_ptr` is registered, otherwise 0.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
 /// # Safety
 ///
 /// - Assumes `code_ptr` is borrowed from a valid Python UTF-8 `str`.
+/// - Assumes `out` is a valid pointer to memory for a `u8`.
+#[no_mangle]
+pub unsafe extern "C" fn currency_exists(code_ptr: *const c_char, out: *mut u8) -> u8 {
+    let result = try_cstr_to_str(code_ptr, "`Currency` code")
+        .map(|code| u8::from(Currency::is_registered(code)));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[no_mangle]
+pub extern "C" fn currency_is_fiat(currency: &Currency) -> u8 {
+    u8::from(currency.is_fiat())
+}
+
 #[no_mangle]
-pub unsafe extern "C" fn currency_exists(code_ptr: *const c_char) -> u8 {
-    let code = cstr_to_string(code_ptr);
-    u8::from(CURRENCY_MAP.lock().unwrap().contains_key(&code))
+pub extern "C" fn currency_is_crypto(currency: &Currency) -> u8 {
+    u8::from(currency.is_crypto())
 }
 
+/// Returns the registered [`Currency`] for the code at `code_ptr`, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
 /// # Safety
 ///
 /// - Assumes `code_ptr` is borrowed from a valid Python UTF-8 `str`.
+/// - Assumes `out` is a valid pointer to memory for a [`Currency`].
 #[no_mangle]
-pub unsafe extern "C" fn currency_from_cstr(code_ptr: *const c_char) -> Currency {
-    let code = cstr_to_string(code_ptr);
-    Currency::from_str(&code).unwrap()
+pub unsafe extern "C" fn currency_try_from_cstr(code_ptr: *const c_char, out: *mut Currency) -> u8 {
+    write_parsed_cstr(code_ptr, out, "`Currency`")
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -208,11 +402,29 @@ pub unsafe extern "C" fn currency_from_cstr(code_ptr: *const c_char) -> Currency
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::{ffi::CString, mem::MaybeUninit};
+
+    use nautilus_core::ffi_error::{last_error, FFI_ERROR};
+
     use crate::{
         enums::CurrencyType,
-        types::currency::{currency_eq, Currency},
+        types::currency::{currency_eq, currency_try_from_cstr, Currency},
     };
 
+    #[test]
+    fn test_currency_try_from_cstr_with_unknown_code() {
+        let code = CString::new("ZZZ").unwrap();
+        let mut out = MaybeUninit::<Currency>::uninit();
+
+        let status = unsafe { currency_try_from_cstr(code.as_ptr(), out.as_mut_ptr()) };
+
+        assert_eq!(status, FFI_ERROR);
+        assert_eq!(
+            last_error().unwrap(),
+            "invalid `Currency` string, was 'ZZZ': Unknown currency: ZZZ"
+        );
+    }
+
     #[test]
     fn test_currency_equality() {
         let currency1 = Currency::new("AUD", 2, 36, "Australian dollar", CurrencyType::Fiat);
@@ -246,4 +458,35 @@ mod tests {
         assert_eq!(currency.name.as_str(), "Ether");
         assert_eq!(currency.currency_type, CurrencyType::Crypto);
     }
+
+    #[test]
+    fn test_register_without_overwrite_keeps_existing() {
+        let currency = Currency::new("TST1", 4, 0, "Test currency one", CurrencyType::Crypto);
+        Currency::register(currency, false);
+        let replacement = Currency::new("TST1", 2, 0, "Test currency one", CurrencyType::Crypto);
+        Currency::register(replacement, false);
+
+        assert!(Currency::is_registered("TST1"));
+        assert_eq!(Currency::from("TST1").precision, 4);
+    }
+
+    #[test]
+    fn test_register_with_overwrite_replaces_existing() {
+        let currency = Currency::new("TST2", 4, 0, "Test currency two", CurrencyType::Crypto);
+        Currency::register(currency, false);
+        let replacement = Currency::new("TST2", 2, 0, "Test currency two", CurrencyType::Crypto);
+        Currency::register(replacement, true);
+
+        assert_eq!(Currency::from("TST2").precision, 2);
+    }
+
+    #[test]
+    fn test_is_fiat_and_is_crypto() {
+        let aud = Currency::new("AUD", 2, 36, "Australian dollar", CurrencyType::Fiat);
+        let eth = Currency::new("ETH", 8, 0, "Ether", CurrencyType::Crypto);
+        assert!(aud.is_fiat());
+        assert!(!aud.is_crypto());
+        assert!(eth.is_crypto());
+        assert!(!eth.is_fiat());
+    }
 }
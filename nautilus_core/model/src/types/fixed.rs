@@ -16,12 +16,38 @@
 pub const FIXED_PRECISION: u8 = 9;
 pub const FIXED_SCALAR: f64 = 1_000_000_000.0; // 10.0**FIXED_PRECISION
 
+/// Rounds `value` half away from zero.
+#[cfg(feature = "std")]
+#[inline]
+fn round_f64(value: f64) -> f64 {
+    value.round()
+}
+
+/// Rounds `value` half away from zero, as `f64::round` (which is only available with `std`).
+#[cfg(not(feature = "std"))]
+#[inline]
+fn round_f64(value: f64) -> f64 {
+    // Values of at least 2^52 in magnitude have no fractional part
+    if !(-4_503_599_627_370_496.0..4_503_599_627_370_496.0).contains(&value) {
+        return value;
+    }
+    let truncated = value as i64 as f64;
+    let fraction = value - truncated;
+    if fraction >= 0.5 {
+        truncated + 1.0
+    } else if fraction <= -0.5 {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
 #[must_use]
 pub fn f64_to_fixed_i64(value: f64, precision: u8) -> i64 {
     assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
     let pow1 = 10_i64.pow(u32::from(precision));
     let pow2 = 10_i64.pow(u32::from(FIXED_PRECISION - precision));
-    let rounded = (value * pow1 as f64).round() as i64;
+    let rounded = round_f64(value * pow1 as f64) as i64;
     rounded * pow2
 }
 
@@ -30,7 +56,7 @@ pub fn f64_to_fixed_u64(value: f64, precision: u8) -> u64 {
     assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
     let pow1 = 10_u64.pow(u32::from(precision));
     let pow2 = 10_u64.pow(u32::from(FIXED_PRECISION - precision));
-    let rounded = (value * pow1 as f64).round() as u64;
+    let rounded = round_f64(value * pow1 as f64) as u64;
     rounded * pow2
 }
 
@@ -44,6 +70,129 @@ pub fn fixed_u64_to_f64(value: u64) -> f64 {
     (value as f64) * 0.000_000_001
 }
 
+/// The rounding mode applied when reducing the precision of a fixed-point value.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to the nearest value, with ties rounded away from zero (as `f64::round`).
+    #[default]
+    HalfAwayFromZero = 1,
+    /// Round to the nearest value, with ties rounded to the nearest even digit.
+    HalfEven = 2,
+    /// Round towards negative infinity.
+    Floor = 3,
+    /// Round towards positive infinity.
+    Ceiling = 4,
+    /// Round towards zero (truncate).
+    TowardZero = 5,
+    /// Round away from zero.
+    AwayFromZero = 6,
+}
+
+#[must_use]
+fn round_fixed_i128(value: i128, precision: u8, mode: RoundingMode) -> i128 {
+    assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
+    round_to_step_i128(
+        value,
+        10_i128.pow(u32::from(FIXED_PRECISION - precision)),
+        mode,
+    )
+}
+
+#[must_use]
+fn round_to_step_i128(value: i128, step: i128, mode: RoundingMode) -> i128 {
+    assert!(step > 0, "rounding step was not positive");
+    let floor = value.div_euclid(step) * step;
+    let remainder = value.rem_euclid(step);
+    if remainder == 0 {
+        return value;
+    }
+    let ceiling = floor + step;
+    let is_negative = value < 0;
+    match mode {
+        RoundingMode::Floor => floor,
+        RoundingMode::Ceiling => ceiling,
+        RoundingMode::TowardZero => {
+            if is_negative {
+                ceiling
+            } else {
+                floor
+            }
+        }
+        RoundingMode::AwayFromZero => {
+            if is_negative {
+                floor
+            } else {
+                ceiling
+            }
+        }
+        RoundingMode::HalfAwayFromZero | RoundingMode::HalfEven => {
+            match (remainder * 2).cmp(&step) {
+                core::cmp::Ordering::Less => floor,
+                core::cmp::Ordering::Greater => ceiling,
+                core::cmp::Ordering::Equal => match mode {
+                    RoundingMode::HalfAwayFromZero if is_negative => floor,
+                    RoundingMode::HalfAwayFromZero => ceiling,
+                    _ if (floor / step) % 2 == 0 => floor,
+                    _ => ceiling,
+                },
+            }
+        }
+    }
+}
+
+/// Rounds the fixed-point `value` to the given `precision` using the rounding `mode`.
+///
+/// Returns `None` if the rounded value overflows an `i64`.
+#[must_use]
+pub fn round_fixed_i64(value: i64, precision: u8, mode: RoundingMode) -> Option<i64> {
+    i64::try_from(round_fixed_i128(i128::from(value), precision, mode)).ok()
+}
+
+/// Rounds the fixed-point `value` to the given `precision` using the rounding `mode`.
+///
+/// Returns `None` if the rounded value overflows a `u64`.
+#[must_use]
+pub fn round_fixed_u64(value: u64, precision: u8, mode: RoundingMode) -> Option<u64> {
+    u64::try_from(round_fixed_i128(i128::from(value), precision, mode)).ok()
+}
+
+/// Rounds the fixed-point `value` to a multiple of the fixed-point `increment` using the
+/// rounding `mode`.
+///
+/// Returns `None` if the rounded value overflows an `i64`.
+///
+/// # Panics
+///
+/// - If `increment` is not positive.
+#[must_use]
+pub fn round_fixed_to_increment_i64(value: i64, increment: i64, mode: RoundingMode) -> Option<i64> {
+    i64::try_from(round_to_step_i128(
+        i128::from(value),
+        i128::from(increment),
+        mode,
+    ))
+    .ok()
+}
+
+/// Rounds the fixed-point `value` to a multiple of the fixed-point `increment` using the
+/// rounding `mode`.
+///
+/// Returns `None` if the rounded value overflows a `u64`.
+///
+/// # Panics
+///
+/// - If `increment` is zero.
+#[must_use]
+pub fn round_fixed_to_increment_u64(value: u64, increment: u64, mode: RoundingMode) -> Option<u64> {
+    u64::try_from(round_to_step_i128(
+        i128::from(value),
+        i128::from(increment),
+        mode,
+    ))
+    .ok()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -181,4 +330,76 @@ mod tests {
         let result = fixed_u64_to_f64(value);
         assert_eq!(result, (value as f64) * 0.000_000_001);
     }
+
+    #[rstest(value, precision, mode, expected,
+        case(1_250_000_000, 1, RoundingMode::HalfAwayFromZero, 1_300_000_000),
+        case(-1_250_000_000, 1, RoundingMode::HalfAwayFromZero, -1_300_000_000),
+        case(1_250_000_000, 1, RoundingMode::HalfEven, 1_200_000_000),
+        case(1_350_000_000, 1, RoundingMode::HalfEven, 1_400_000_000),
+        case(-1_250_000_000, 1, RoundingMode::HalfEven, -1_200_000_000),
+        case(1_249_999_999, 1, RoundingMode::HalfEven, 1_200_000_000),
+        case(1_210_000_000, 1, RoundingMode::Floor, 1_200_000_000),
+        case(-1_210_000_000, 1, RoundingMode::Floor, -1_300_000_000),
+        case(1_210_000_000, 1, RoundingMode::Ceiling, 1_300_000_000),
+        case(-1_210_000_000, 1, RoundingMode::Ceiling, -1_200_000_000),
+        case(-1_290_000_000, 1, RoundingMode::TowardZero, -1_200_000_000),
+        case(-1_210_000_000, 1, RoundingMode::AwayFromZero, -1_300_000_000),
+        case(1_200_000_000, 1, RoundingMode::Ceiling, 1_200_000_000),
+        case(1_234_567_891, 9, RoundingMode::Floor, 1_234_567_891),
+    )]
+    fn test_round_fixed_i64(value: i64, precision: u8, mode: RoundingMode, expected: i64) {
+        assert_eq!(round_fixed_i64(value, precision, mode), Some(expected));
+    }
+
+    #[rstest]
+    fn test_round_fixed_i64_overflow() {
+        assert_eq!(round_fixed_i64(i64::MAX, 0, RoundingMode::Ceiling), None);
+    }
+
+    #[rstest]
+    fn test_round_fixed_u64() {
+        assert_eq!(
+            round_fixed_u64(5_555_000_000, 2, RoundingMode::HalfEven),
+            Some(5_560_000_000)
+        );
+        assert_eq!(
+            round_fixed_u64(5_555_000_000, 2, RoundingMode::TowardZero),
+            Some(5_550_000_000)
+        );
+    }
+
+    #[rstest(value, increment, mode, expected,
+        case(1_130_000_000, 250_000_000, RoundingMode::HalfAwayFromZero, 1_250_000_000),
+        case(1_120_000_000, 250_000_000, RoundingMode::HalfAwayFromZero, 1_000_000_000),
+        case(1_125_000_000, 250_000_000, RoundingMode::HalfEven, 1_000_000_000),
+        case(1_375_000_000, 250_000_000, RoundingMode::HalfEven, 1_500_000_000),
+        case(-1_130_000_000, 250_000_000, RoundingMode::Floor, -1_250_000_000),
+        case(1_130_000_000, 50_000_000, RoundingMode::Ceiling, 1_150_000_000),
+        case(1_150_000_000, 50_000_000, RoundingMode::Ceiling, 1_150_000_000),
+    )]
+    fn test_round_fixed_to_increment_i64(
+        value: i64,
+        increment: i64,
+        mode: RoundingMode,
+        expected: i64,
+    ) {
+        assert_eq!(
+            round_fixed_to_increment_i64(value, increment, mode),
+            Some(expected)
+        );
+    }
+
+    #[rstest]
+    fn test_round_fixed_to_increment_u64_overflow() {
+        assert_eq!(
+            round_fixed_to_increment_u64(u64::MAX, 1_000_000_000, RoundingMode::Ceiling),
+            None
+        );
+    }
+
+    #[rstest]
+    #[should_panic(expected = "rounding step was not positive")]
+    fn test_round_fixed_to_increment_zero_increment() {
+        let _ = round_fixed_to_increment_i64(1, 0, RoundingMode::Floor);
+    }
 }
@@ -13,9 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "std")]
 pub mod balance;
+#[cfg(feature = "std")]
 pub mod currency;
 pub mod fixed;
+#[cfg(feature = "std")]
 pub mod money;
 pub mod price;
 pub mod quantity;
+#[cfg(feature = "high-precision")]
+pub mod quantity128;
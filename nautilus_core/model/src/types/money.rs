@@ -15,19 +15,34 @@
 
 use std::{
     cmp::Ordering,
+    ffi::c_char,
     fmt::{Display, Formatter},
     hash::{Hash, Hasher},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
 
-use nautilus_core::correctness;
-use pyo3::prelude::*;
+use nautilus_core::{
+    correctness,
+    ffi_error::{write_result, FFI_ERROR},
+    string::str_to_cstr,
+};
+#[cfg(feature = "python")]
+use pyo3::{basic::CompareOp, prelude::*, types::PyTuple, PyTypeInfo};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::types::{
     currency::Currency,
-    fixed::{f64_to_fixed_i64, fixed_i64_to_f64},
+    fixed::{
+        f64_to_fixed_i64, fixed_i64_to_f64, round_fixed_i64, RoundingMode, FIXED_PRECISION,
+        FIXED_SCALAR,
+    },
+};
+#[cfg(feature = "python")]
+use crate::{
+    currencies::USD,
+    python::{hash_of, richcmp_ord, to_pyvalue_err},
 };
 
 pub const MONEY_MAX: f64 = 9_223_372_036.0;
@@ -35,21 +50,39 @@ pub const MONEY_MIN: f64 = -9_223_372_036.0;
 
 #[repr(C)]
 #[derive(Eq, Clone, Debug)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct Money {
     raw: i64,
     pub currency: Currency,
 }
 
 impl Money {
-    #[must_use]
-    pub fn new(amount: f64, currency: Currency) -> Self {
-        correctness::f64_in_range_inclusive(amount, MONEY_MIN, MONEY_MAX, "`Money` amount");
+    /// Returns a new [`Money`], or an error if `amount` is not within [`MONEY_MIN`, `MONEY_MAX`]
+    /// or the `currency` precision exceeds the maximum fixed precision.
+    pub fn try_new(amount: f64, currency: Currency) -> Result<Self, String> {
+        correctness::check_f64_in_range_inclusive(amount, MONEY_MIN, MONEY_MAX, "`Money` amount")?;
+        if currency.precision > FIXED_PRECISION {
+            return Err(format!(
+                "invalid `Money` currency precision, was {} (maximum {FIXED_PRECISION})",
+                currency.precision
+            ));
+        }
 
-        Self {
+        Ok(Self {
             raw: f64_to_fixed_i64(amount, currency.precision),
             currency,
-        }
+        })
+    }
+
+    /// Returns a new [`Money`].
+    ///
+    /// # Panics
+    ///
+    /// - If `amount` is not within [`MONEY_MIN`, `MONEY_MAX`].
+    /// - If the `currency` precision exceeds the maximum fixed precision.
+    #[must_use]
+    pub fn new(amount: f64, currency: Currency) -> Self {
+        Self::try_new(amount, currency).unwrap_or_else(|e| panic!("{e}"))
     }
 
     #[must_use]
@@ -65,6 +98,71 @@ impl Money {
     pub fn as_f64(&self) -> f64 {
         fixed_i64_to_f64(self.raw)
     }
+
+    #[must_use]
+    pub fn raw(&self) -> i64 {
+        self.raw
+    }
+
+    /// Returns the sum of `self` and `rhs`, or `None` if the currencies differ or the
+    /// result overflows.
+    #[must_use]
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        if self.currency != rhs.currency {
+            return None;
+        }
+        self.raw
+            .checked_add(rhs.raw)
+            .map(|raw| Self::from_raw(raw, self.currency.clone()))
+    }
+
+    /// Returns the difference of `self` and `rhs`, or `None` if the currencies differ or
+    /// the result overflows.
+    #[must_use]
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        if self.currency != rhs.currency {
+            return None;
+        }
+        self.raw
+            .checked_sub(rhs.raw)
+            .map(|raw| Self::from_raw(raw, self.currency.clone()))
+    }
+
+    /// Returns the amount formatted to the currency precision with `_` thousands
+    /// separators, followed by the currency code (e.g. `"1_000_000.00 USD"`).
+    #[must_use]
+    pub fn to_formatted_string(&self) -> String {
+        // Formatted from the raw value rounded to the currency precision, rather than via
+        // `f64`, so large amounts keep every digit
+        let precision = self.currency.precision;
+        let raw = round_fixed_i64(self.raw, precision, RoundingMode::HalfAwayFromZero)
+            .unwrap_or(self.raw);
+        let scalar = 10_u64.pow(u32::from(FIXED_PRECISION));
+        let integer = (raw.unsigned_abs() / scalar).to_string();
+        let fraction = format!(
+            "{:0width$}",
+            raw.unsigned_abs() % scalar,
+            width = usize::from(FIXED_PRECISION)
+        );
+
+        let mut grouped =
+            String::with_capacity(integer.len() + integer.len() / 3 + usize::from(precision) + 2);
+        if raw < 0 {
+            grouped.push('-');
+        }
+        for (i, c) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push('_');
+            }
+            grouped.push(c);
+        }
+        if precision > 0 {
+            grouped.push('.');
+            grouped.push_str(&fraction[..usize::from(precision)]);
+        }
+
+        format!("{grouped} {}", self.currency.code)
+    }
 }
 
 impl Hash for Money {
@@ -80,35 +178,20 @@ impl PartialEq for Money {
     }
 }
 
+/// Money is only ordered against money of the same currency, comparing amounts of
+/// different currencies panics (as for the arithmetic operators).
 impl PartialOrd for Money {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.raw.partial_cmp(&other.raw)
-    }
-
-    fn lt(&self, other: &Self) -> bool {
-        assert_eq!(self.currency, other.currency);
-        self.raw.lt(&other.raw)
-    }
-
-    fn le(&self, other: &Self) -> bool {
-        assert_eq!(self.currency, other.currency);
-        self.raw.le(&other.raw)
-    }
-
-    fn gt(&self, other: &Self) -> bool {
-        assert_eq!(self.currency, other.currency);
-        self.raw.gt(&other.raw)
-    }
-
-    fn ge(&self, other: &Self) -> bool {
-        assert_eq!(self.currency, other.currency);
-        self.raw.ge(&other.raw)
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Money {
     fn cmp(&self, other: &Self) -> Ordering {
-        assert_eq!(self.currency, other.currency);
+        assert_eq!(
+            self.currency, other.currency,
+            "cannot compare `Money` of different currencies"
+        );
         self.raw.cmp(&other.raw)
     }
 }
@@ -150,12 +233,22 @@ impl Mul for Money {
     fn mul(self, rhs: Self) -> Self {
         assert_eq!(self.currency, rhs.currency);
         Self {
-            raw: self.raw * rhs.raw,
+            raw: mul_raw(self.raw, rhs.raw),
             currency: self.currency,
         }
     }
 }
 
+/// Returns the fixed-point product of the raw values.
+///
+/// # Panics
+///
+/// - If the product overflows.
+fn mul_raw(a: i64, b: i64) -> i64 {
+    let raw = i128::from(a) * i128::from(b) / (FIXED_SCALAR as i128);
+    i64::try_from(raw).unwrap_or_else(|_| panic!("`Money` overflowed when multiplying"))
+}
+
 impl AddAssign for Money {
     fn add_assign(&mut self, other: Self) {
         assert_eq!(self.currency, other.currency);
@@ -172,7 +265,8 @@ impl SubAssign for Money {
 
 impl MulAssign for Money {
     fn mul_assign(&mut self, multiplier: Self) {
-        self.raw *= multiplier.raw;
+        assert_eq!(self.currency, multiplier.currency);
+        self.raw = mul_raw(self.raw, multiplier.raw);
     }
 }
 
@@ -209,6 +303,7 @@ impl Display for Money {
     }
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for Money {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -218,6 +313,7 @@ impl Serialize for Money {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Money {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -244,12 +340,110 @@ impl<'de> Deserialize<'de> for Money {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl Money {
+    #[new]
+    fn py_new(amount: f64, currency: Currency) -> PyResult<Self> {
+        if !(MONEY_MIN..=MONEY_MAX).contains(&amount) {
+            return Err(to_pyvalue_err(format!(
+                "invalid `Money` amount, was {amount} (expected in range [{MONEY_MIN}, {MONEY_MAX}])"
+            )));
+        }
+        Ok(Self::new(amount, currency))
+    }
+
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let (raw, currency): (i64, Currency) = state.extract(py)?;
+        self.raw = raw;
+        self.currency = currency;
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyObject {
+        (self.raw, self.currency.clone().into_py(py)).to_object(py)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py);
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> Self {
+        Self::from_raw(0, USD.clone())
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_raw")]
+    fn py_from_raw(raw: i64, currency: Currency) -> Self {
+        Self::from_raw(raw, currency)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        if self.currency != other.currency {
+            return Err(to_pyvalue_err(format!(
+                "cannot compare `Money` of different currencies, {} and {}",
+                self.currency.code, other.currency.code
+            )));
+        }
+        Ok(richcmp_ord(self, other, op, py))
+    }
+
+    fn __hash__(&self) -> isize {
+        hash_of(self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "{}({:.*}, {})",
+            Self::NAME,
+            self.currency.precision as usize,
+            self.as_f64(),
+            self.currency.code
+        )
+    }
+
+    #[getter]
+    #[pyo3(name = "raw")]
+    fn py_raw(&self) -> i64 {
+        self.raw
+    }
+
+    #[getter]
+    #[pyo3(name = "currency")]
+    fn py_currency(&self) -> Currency {
+        self.currency.clone()
+    }
+
+    #[pyo3(name = "as_double")]
+    fn py_as_double(&self) -> f64 {
+        self.as_f64()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
+/// Writes a new [`Money`] to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set (in
+/// which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Money`].
 #[no_mangle]
-pub extern "C" fn money_new(amount: f64, currency: Currency) -> Money {
-    Money::new(amount, currency)
+pub unsafe extern "C" fn money_new(amount: f64, currency: Currency, out: *mut Money) -> u8 {
+    write_result(Money::try_new(amount, currency), out, |_| FFI_ERROR)
 }
 
 #[no_mangle]
@@ -267,6 +461,11 @@ pub extern "C" fn money_as_f64(money: &Money) -> f64 {
     money.as_f64()
 }
 
+#[no_mangle]
+pub extern "C" fn money_to_cstr(money: &Money) -> *const c_char {
+    str_to_cstr(&money.to_formatted_string())
+}
+
 #[no_mangle]
 pub extern "C" fn money_add_assign(mut a: Money, b: Money) {
     a.add_assign(b);
@@ -282,9 +481,25 @@ pub extern "C" fn money_sub_assign(mut a: Money, b: Money) {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::mem::MaybeUninit;
+
+    use nautilus_core::ffi_error::last_error;
+
     use super::*;
     use crate::currencies::{BTC, USD};
 
+    #[test]
+    fn test_money_new_c_api() {
+        let mut out = MaybeUninit::<Money>::uninit();
+        assert_eq!(unsafe { money_new(10.5, USD.clone(), out.as_mut_ptr()) }, 0);
+        assert_eq!(unsafe { out.assume_init() }, Money::new(10.5, USD.clone()));
+        assert_eq!(
+            unsafe { money_new(f64::INFINITY, USD.clone(), out.as_mut_ptr()) },
+            FFI_ERROR
+        );
+        assert!(last_error().unwrap().contains("`Money` amount"));
+    }
+
     #[test]
     fn test_money_new_usd() {
         let money = Money::new(1000.0, USD.clone());
@@ -301,6 +516,74 @@ mod tests {
         assert_eq!(money.to_string(), "10.30000000 BTC");
     }
 
+    #[test]
+    fn test_money_checked_add_same_currency() {
+        let money = Money::new(1.5, USD.clone())
+            .checked_add(&Money::new(2.25, USD.clone()))
+            .unwrap();
+        assert_eq!(money, Money::new(3.75, USD.clone()));
+    }
+
+    #[test]
+    fn test_money_checked_add_different_currency_returns_none() {
+        let result = Money::new(1.0, USD.clone()).checked_add(&Money::new(1.0, BTC.clone()));
+        assert!(result.is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_money_add_different_currency_panics() {
+        let _ = Money::new(1.0, USD.clone()) + Money::new(1.0, BTC.clone());
+    }
+
+    #[test]
+    fn test_money_mul_is_scaled() {
+        let money = Money::new(2.0, USD.clone()) * Money::new(1.5, USD.clone());
+        assert_eq!(money, Money::new(3.0, USD.clone()));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot compare `Money` of different currencies")]
+    fn test_money_partial_cmp_different_currency() {
+        let lhs = Money::new(1.0, USD.clone());
+        let rhs = Money::new(1.0, BTC.clone());
+        let _ = lhs.partial_cmp(&rhs);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot compare `Money` of different currencies")]
+    fn test_money_lt_different_currency() {
+        let _ = Money::new(1.0, USD.clone()) < Money::new(1.0, BTC.clone());
+    }
+
+    #[test]
+    fn test_money_to_formatted_string() {
+        assert_eq!(
+            Money::new(1_000_000.0, USD.clone()).to_formatted_string(),
+            "1_000_000.00 USD"
+        );
+        assert_eq!(
+            Money::new(-1_234.5, USD.clone()).to_formatted_string(),
+            "-1_234.50 USD"
+        );
+        assert_eq!(
+            Money::new(100.0, USD.clone()).to_formatted_string(),
+            "100.00 USD"
+        );
+        assert_eq!(
+            Money::new(10.3, BTC.clone()).to_formatted_string(),
+            "10.30000000 BTC"
+        );
+        assert_eq!(
+            Money::from_raw(9_000_000_000_123_456_780, BTC.clone()).to_formatted_string(),
+            "9_000_000_000.12345678 BTC"
+        );
+        assert_eq!(
+            Money::from_raw(-1, USD.clone()).to_formatted_string(),
+            "0.00 USD"
+        );
+    }
+
     // #[test]
     // fn test_account_balance() {
     //     let usd = Currency {
@@ -13,7 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{
+use alloc::{format, string::String};
+#[cfg(feature = "ffi")]
+use core::ffi::c_char;
+use core::{
     cmp::Ordering,
     fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
@@ -21,33 +24,62 @@ use std::{
     str::FromStr,
 };
 
-use nautilus_core::{correctness, parsing::precision_from_str};
-use pyo3::prelude::*;
+use nautilus_core::{correctness, parsing::try_precision_from_str};
+#[cfg(feature = "ffi")]
+use nautilus_core::{
+    ffi_error::{write_parsed_cstr, write_result, FFI_ERROR},
+    string::str_to_cstr,
+};
+#[cfg(feature = "python")]
+use pyo3::{basic::CompareOp, prelude::*, types::PyTuple, PyTypeInfo};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize};
 
 use super::fixed::FIXED_SCALAR;
-use crate::types::fixed::{f64_to_fixed_i64, fixed_i64_to_f64};
+#[cfg(feature = "python")]
+use crate::python::{hash_of, richcmp_ord, to_pyvalue_err};
+use crate::types::fixed::{
+    f64_to_fixed_i64, fixed_i64_to_f64, round_fixed_i64, round_fixed_to_increment_i64,
+    RoundingMode, FIXED_PRECISION,
+};
 
 pub const PRICE_MAX: f64 = 9_223_372_036.0;
 pub const PRICE_MIN: f64 = -9_223_372_036.0;
 
 #[repr(C)]
 #[derive(Copy, Clone, Eq, Default)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct Price {
     pub raw: i64,
     pub precision: u8,
 }
 
 impl Price {
-    #[must_use]
-    pub fn new(value: f64, precision: u8) -> Self {
-        correctness::f64_in_range_inclusive(value, PRICE_MIN, PRICE_MAX, "`Price` value");
+    /// Returns a new [`Price`], or an error if `value` is not within [`PRICE_MIN`, `PRICE_MAX`]
+    /// or `precision` exceeds the maximum fixed precision.
+    pub fn try_new(value: f64, precision: u8) -> Result<Self, String> {
+        correctness::check_f64_in_range_inclusive(value, PRICE_MIN, PRICE_MAX, "`Price` value")?;
+        if precision > FIXED_PRECISION {
+            return Err(format!(
+                "invalid `Price` precision, was {precision} (maximum {FIXED_PRECISION})"
+            ));
+        }
 
-        Self {
+        Ok(Self {
             raw: f64_to_fixed_i64(value, precision),
             precision,
-        }
+        })
+    }
+
+    /// Returns a new [`Price`].
+    ///
+    /// # Panics
+    ///
+    /// - If `value` is not within [`PRICE_MIN`, `PRICE_MAX`].
+    /// - If `precision` exceeds the maximum fixed precision.
+    #[must_use]
+    pub fn new(value: f64, precision: u8) -> Self {
+        Self::try_new(value, precision).unwrap_or_else(|e| panic!("{e}"))
     }
 
     #[must_use]
@@ -64,6 +96,105 @@ impl Price {
     pub fn as_f64(&self) -> f64 {
         fixed_i64_to_f64(self.raw)
     }
+
+    /// Returns the sum of `self` and `rhs`, or `None` if the result overflows.
+    ///
+    /// The result takes the greater precision of the two operands.
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.raw.checked_add(rhs.raw).map(|raw| Self {
+            raw,
+            precision: self.precision.max(rhs.precision),
+        })
+    }
+
+    /// Returns the difference of `self` and `rhs`, or `None` if the result overflows.
+    ///
+    /// The result takes the greater precision of the two operands.
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.raw.checked_sub(rhs.raw).map(|raw| Self {
+            raw,
+            precision: self.precision.max(rhs.precision),
+        })
+    }
+
+    /// Returns the product of `self` and `rhs`, or `None` if the result overflows.
+    ///
+    /// The result takes the greater precision of the two operands.
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let raw = i128::from(self.raw) * i128::from(rhs.raw) / (FIXED_SCALAR as i128);
+        i64::try_from(raw).ok().map(|raw| Self {
+            raw,
+            precision: self.precision.max(rhs.precision),
+        })
+    }
+
+    /// Returns `self` rounded to the given `precision` using the rounding `mode`.
+    ///
+    /// # Panics
+    ///
+    /// - If `precision` exceeds the maximum fixed precision.
+    /// - If the rounded value overflows.
+    #[must_use]
+    pub fn round(&self, precision: u8, mode: RoundingMode) -> Self {
+        assert!(
+            precision <= FIXED_PRECISION,
+            "precision exceeded maximum {FIXED_PRECISION}"
+        );
+        self.checked_round(precision, mode)
+            .unwrap_or_else(|| panic!("`Price` overflowed when rounding {self}"))
+    }
+
+    /// Returns `self` rounded to the given `precision` using the rounding `mode`, or `None` if
+    /// `precision` exceeds the maximum fixed precision or the result overflows.
+    #[must_use]
+    pub fn checked_round(&self, precision: u8, mode: RoundingMode) -> Option<Self> {
+        if precision > FIXED_PRECISION {
+            return None;
+        }
+        round_fixed_i64(self.raw, precision, mode).map(|raw| Self { raw, precision })
+    }
+
+    /// Returns `self` rounded to a multiple of `increment` using the rounding `mode`, or
+    /// `None` if `increment` is not positive or the result overflows.
+    ///
+    /// The result takes the precision of `increment`.
+    #[must_use]
+    pub fn checked_round_to_increment(&self, increment: Self, mode: RoundingMode) -> Option<Self> {
+        if increment.raw <= 0 {
+            return None;
+        }
+        round_fixed_to_increment_i64(self.raw, increment.raw, mode).map(|raw| Self {
+            raw,
+            precision: increment.precision,
+        })
+    }
+
+    /// Returns `self` rounded to a multiple of `increment` using the rounding `mode`.
+    ///
+    /// The result takes the precision of `increment`.
+    ///
+    /// # Panics
+    ///
+    /// - If `increment` is not positive.
+    /// - If the rounded value overflows.
+    #[must_use]
+    pub fn round_to_increment(&self, increment: Self, mode: RoundingMode) -> Self {
+        assert!(
+            increment.raw > 0,
+            "`Price` increment was not positive, was {increment}"
+        );
+        self.checked_round_to_increment(increment, mode)
+            .unwrap_or_else(|| panic!("`Price` overflowed when rounding {self} to {increment}"))
+    }
+
+    /// Returns true if `self` is an exact multiple of `increment` (which must be positive).
+    #[must_use]
+    pub fn is_multiple_of(&self, increment: Self) -> bool {
+        increment.raw > 0 && self.raw % increment.raw == 0
+    }
 }
 
 impl FromStr for Price {
@@ -74,7 +205,9 @@ impl FromStr for Price {
             .parse::<f64>()
             .map_err(|err| format!("Cannot parse `input` string '{}' as f64: {}", input, err))?;
 
-        Ok(Self::new(float_from_input, precision_from_str(input)))
+        let precision = try_precision_from_str(input)
+            .ok_or_else(|| format!("Cannot parse precision from `input` string '{input}'"))?;
+        Self::try_new(float_from_input, precision)
     }
 }
 
@@ -177,8 +310,10 @@ impl Sub for Price {
 impl Mul for Price {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
+        let raw = i128::from(self.raw) * i128::from(rhs.raw) / (FIXED_SCALAR as i128);
         Self {
-            raw: (self.raw * rhs.raw) / (FIXED_SCALAR as i64),
+            raw: i64::try_from(raw)
+                .unwrap_or_else(|_| panic!("`Price` overflowed when multiplying {self} by {rhs}")),
             precision: self.precision,
         }
     }
@@ -198,7 +333,7 @@ impl SubAssign for Price {
 
 impl MulAssign for Price {
     fn mul_assign(&mut self, multiplier: Self) {
-        self.raw *= multiplier.raw;
+        *self = *self * multiplier;
     }
 }
 
@@ -224,17 +359,18 @@ impl Mul<f64> for Price {
 }
 
 impl Debug for Price {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:.*}", self.precision as usize, self.as_f64())
     }
 }
 
 impl Display for Price {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:.*}", self.precision as usize, self.as_f64())
     }
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for Price {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -244,6 +380,7 @@ impl Serialize for Price {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Price {
     fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
     where
@@ -255,40 +392,274 @@ impl<'de> Deserialize<'de> for Price {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl Price {
+    #[new]
+    fn py_new(value: f64, precision: u8) -> PyResult<Self> {
+        if !(PRICE_MIN..=PRICE_MAX).contains(&value) {
+            return Err(to_pyvalue_err(format!(
+                "invalid `Price` value, was {value} (expected in range [{}, {}])",
+                PRICE_MIN, PRICE_MAX
+            )));
+        }
+        if precision > FIXED_PRECISION {
+            return Err(to_pyvalue_err(format!(
+                "invalid `Price` precision, was {precision} (maximum {FIXED_PRECISION})"
+            )));
+        }
+        Ok(Self::new(value, precision))
+    }
+
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let (raw, precision): (i64, u8) = state.extract(py)?;
+        self.raw = raw;
+        self.precision = precision;
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyObject {
+        (self.raw, self.precision).to_object(py)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py);
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> Self {
+        Self::default()
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_raw")]
+    fn py_from_raw(raw: i64, precision: u8) -> Self {
+        Self::from_raw(raw, precision)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(value: &str) -> PyResult<Self> {
+        Self::from_str(value).map_err(to_pyvalue_err)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        richcmp_ord(self, other, op, py)
+    }
+
+    fn __hash__(&self) -> isize {
+        hash_of(self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}('{self}')", Self::NAME)
+    }
+
+    #[getter]
+    #[pyo3(name = "raw")]
+    fn py_raw(&self) -> i64 {
+        self.raw
+    }
+
+    #[getter]
+    #[pyo3(name = "precision")]
+    fn py_precision(&self) -> u8 {
+        self.precision
+    }
+
+    #[pyo3(name = "as_double")]
+    fn py_as_double(&self) -> f64 {
+        self.as_f64()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
+/// Writes a new [`Price`] to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set (in
+/// which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Price`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub extern "C" fn price_new(value: f64, precision: u8) -> Price {
-    Price::new(value, precision)
+pub unsafe extern "C" fn price_new(value: f64, precision: u8, out: *mut Price) -> u8 {
+    write_result(Price::try_new(value, precision), out, |_| FFI_ERROR)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn price_from_raw(raw: i64, precision: u8) -> Price {
     Price::from_raw(raw, precision)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn price_as_f64(price: &Price) -> f64 {
     price.as_f64()
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn price_add_assign(mut a: Price, b: Price) {
     a.add_assign(b);
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn price_sub_assign(mut a: Price, b: Price) {
     a.sub_assign(b);
 }
 
+/// Parses a [`Price`] from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set (in
+/// which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`Price`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn price_from_cstr(ptr: *const c_char, out: *mut Price) -> u8 {
+    write_parsed_cstr(ptr, out, "`Price`")
+}
+
+/// Returns a [`Price`] as a C string pointer.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn price_to_cstr(price: &Price) -> *const c_char {
+    str_to_cstr(&price.to_string())
+}
+
+/// Writes the sum of `a` and `b` to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if the
+/// result overflowed (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Price`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn price_checked_add(a: Price, b: Price, out: *mut Price) -> u8 {
+    let result = a
+        .checked_add(b)
+        .ok_or_else(|| format!("`Price` overflowed when adding {a} and {b}"));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Writes the difference of `a` and `b` to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if the
+/// result overflowed (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Price`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn price_checked_sub(a: Price, b: Price, out: *mut Price) -> u8 {
+    let result = a
+        .checked_sub(b)
+        .ok_or_else(|| format!("`Price` overflowed when subtracting {a} and {b}"));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Writes the product of `a` and `b` to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if the
+/// result overflowed (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Price`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn price_checked_mul(a: Price, b: Price, out: *mut Price) -> u8 {
+    let result = a
+        .checked_mul(b)
+        .ok_or_else(|| format!("`Price` overflowed when multiplying {a} and {b}"));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Writes `price` rounded to the given `precision` to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `precision` exceeds the maximum fixed precision or the result overflowed (in which case
+/// nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Price`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn price_round(
+    price: Price,
+    precision: u8,
+    mode: RoundingMode,
+    out: *mut Price,
+) -> u8 {
+    let result = price
+        .checked_round(precision, mode)
+        .ok_or_else(|| format!("`Price` could not round {price} to precision {precision}"));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Writes `price` rounded to a multiple of `increment` to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `increment` is not positive or the result overflowed (in which case nothing is written
+/// to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Price`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn price_round_to_increment(
+    price: Price,
+    increment: Price,
+    mode: RoundingMode,
+    out: *mut Price,
+) -> u8 {
+    let result = price
+        .checked_round_to_increment(increment, mode)
+        .ok_or_else(|| format!("`Price` could not round {price} to increment {increment}"));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn price_is_multiple_of(price: Price, increment: Price) -> u8 {
+    u8::from(price.is_multiple_of(increment))
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
+    #[cfg(feature = "ffi")]
+    use std::{ffi::CString, mem::MaybeUninit};
+
+    #[cfg(feature = "ffi")]
+    use nautilus_core::ffi_error::last_error;
+    use rstest::rstest;
 
     use super::*;
 
@@ -340,7 +711,7 @@ mod tests {
     #[test]
     fn test_price_from_str_valid_input() {
         let input = "10.5";
-        let expected_price = Price::new(10.5, precision_from_str(input));
+        let expected_price = Price::new(10.5, 1);
         let result = Price::from_str(input).unwrap();
         assert_eq!(result, expected_price);
     }
@@ -410,7 +781,7 @@ mod tests {
         let mut price1 = Price::new(1.000, 3);
         let price2 = Price::new(1.011, 3);
         price1 *= price2;
-        assert_eq!(price1.raw, 1_011_000_000_000_000_000);
+        assert_eq!(price1.raw, 1_011_000_000);
     }
 
     #[test]
@@ -432,4 +803,228 @@ mod tests {
         assert_eq!(price.as_f64(), 44.123_456_000_000_004);
         assert_eq!(price.to_string(), input_string);
     }
+
+    #[test]
+    fn test_checked_add() {
+        let price = Price::new(1.5, 1).checked_add(Price::new(0.25, 2)).unwrap();
+        assert_eq!(price, Price::new(1.75, 2));
+        assert_eq!(price.precision, 2);
+        assert!(Price::from_raw(i64::MAX, 9)
+            .checked_add(Price::from_raw(1, 9))
+            .is_none());
+    }
+
+    #[test]
+    fn test_checked_sub() {
+        let price = Price::new(1.5, 1).checked_sub(Price::new(2.0, 1)).unwrap();
+        assert_eq!(price, Price::new(-0.5, 1));
+        assert!(Price::from_raw(i64::MIN, 9)
+            .checked_sub(Price::from_raw(1, 9))
+            .is_none());
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        let price = Price::new(100.0, 0)
+            .checked_mul(Price::new(1.5, 1))
+            .unwrap();
+        assert_eq!(price, Price::new(150.0, 1));
+        assert!(Price::new(1_000_000.0, 0)
+            .checked_mul(Price::new(1_000_000.0, 0))
+            .is_none());
+    }
+
+    #[test]
+    fn test_mul_large_values_does_not_overflow() {
+        let price = Price::new(100.0, 0) * Price::new(2.0, 0);
+        assert_eq!(price, Price::new(200.0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "`Price` overflowed when multiplying")]
+    fn test_mul_overflow_panics() {
+        let _ = Price::new(1_000_000.0, 0) * Price::new(1_000_000.0, 0);
+    }
+
+    #[rstest]
+    #[case(RoundingMode::HalfAwayFromZero, "1.01")]
+    #[case(RoundingMode::HalfEven, "1.00")]
+    #[case(RoundingMode::Floor, "1.00")]
+    #[case(RoundingMode::Ceiling, "1.01")]
+    fn test_round(#[case] mode: RoundingMode, #[case] expected: &str) {
+        let price = Price::from("1.005").round(2, mode);
+        assert_eq!(price.precision, 2);
+        assert_eq!(price.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("100.13", "0.25", RoundingMode::HalfAwayFromZero, "100.25")]
+    #[case("100.12", "0.25", RoundingMode::HalfAwayFromZero, "100.00")]
+    #[case("100.24", "0.25", RoundingMode::Floor, "100.00")]
+    #[case("-100.01", "0.05", RoundingMode::TowardZero, "-100.00")]
+    #[case("1.23456", "0.001", RoundingMode::HalfEven, "1.235")]
+    fn test_round_to_increment(
+        #[case] input: &str,
+        #[case] increment: &str,
+        #[case] mode: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        let price = Price::from(input).round_to_increment(Price::from(increment), mode);
+        assert_eq!(price.precision, Price::from(increment).precision);
+        assert_eq!(price.to_string(), expected);
+        assert!(price.is_multiple_of(Price::from(increment)));
+    }
+
+    #[test]
+    fn test_is_multiple_of() {
+        assert!(Price::from("100.50").is_multiple_of(Price::from("0.25")));
+        assert!(Price::from("-100.50").is_multiple_of(Price::from("0.25")));
+        assert!(!Price::from("100.55").is_multiple_of(Price::from("0.25")));
+        assert!(!Price::from("100.50").is_multiple_of(Price::from("0.00")));
+    }
+
+    #[test]
+    fn test_checked_round_to_increment() {
+        let price = Price::from("100.13");
+        let mode = RoundingMode::HalfAwayFromZero;
+        assert_eq!(
+            price.checked_round_to_increment(Price::from("0.25"), mode),
+            Some(Price::from("100.25"))
+        );
+        assert_eq!(
+            price.checked_round_to_increment(Price::from("0.00"), mode),
+            None
+        );
+        assert_eq!(
+            price.checked_round_to_increment(Price::from("-0.25"), mode),
+            None
+        );
+        assert_eq!(
+            Price::from_raw(i64::MAX, 9)
+                .checked_round_to_increment(Price::from_raw(2, 9), RoundingMode::Ceiling),
+            None
+        );
+    }
+
+    #[test]
+    fn test_checked_round() {
+        let price = Price::from("1.005");
+        assert_eq!(
+            price.checked_round(2, RoundingMode::Ceiling),
+            Some(Price::from("1.01"))
+        );
+        assert_eq!(
+            price.checked_round(FIXED_PRECISION + 1, RoundingMode::Ceiling),
+            None
+        );
+        assert_eq!(
+            Price::from_raw(i64::MAX, 9).checked_round(0, RoundingMode::Ceiling),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "precision exceeded maximum")]
+    fn test_round_with_excessive_precision() {
+        let _ = Price::from("1.005").round(FIXED_PRECISION + 1, RoundingMode::Floor);
+    }
+
+    #[test]
+    #[should_panic(expected = "`Price` increment was not positive")]
+    fn test_round_to_increment_with_zero_increment() {
+        let _ = Price::from("100.13").round_to_increment(Price::from("0.00"), RoundingMode::Floor);
+    }
+
+    #[rstest]
+    #[case(f64::NAN, 2)]
+    #[case(PRICE_MAX + 1.0, 2)]
+    #[case(1.0, FIXED_PRECISION + 1)]
+    fn test_try_new_with_invalid_input(#[case] value: f64, #[case] precision: u8) {
+        assert!(Price::try_new(value, precision).is_err());
+    }
+
+    #[test]
+    fn test_price_from_str_with_excessive_precision() {
+        assert!(Price::from_str("1.0000000001").is_err());
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_price_new_c_api() {
+        let mut out = MaybeUninit::<Price>::uninit();
+        assert_eq!(unsafe { price_new(1.5, 1, out.as_mut_ptr()) }, 0);
+        assert_eq!(unsafe { out.assume_init() }, Price::new(1.5, 1));
+        assert_eq!(
+            unsafe { price_new(f64::NAN, 1, out.as_mut_ptr()) },
+            FFI_ERROR
+        );
+        assert!(last_error().unwrap().contains("`Price` value"));
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_price_round_c_api() {
+        let mut out = MaybeUninit::<Price>::uninit();
+        let price = Price::from("1.005");
+        assert_eq!(
+            unsafe { price_round(price, 2, RoundingMode::Floor, out.as_mut_ptr()) },
+            0
+        );
+        assert_eq!(unsafe { out.assume_init() }, Price::from("1.00"));
+        assert_eq!(
+            unsafe {
+                price_round(
+                    price,
+                    FIXED_PRECISION + 1,
+                    RoundingMode::Floor,
+                    out.as_mut_ptr(),
+                )
+            },
+            FFI_ERROR
+        );
+        assert!(last_error().unwrap().contains("could not round"));
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_price_from_cstr_c_api() {
+        let mut out = MaybeUninit::<Price>::uninit();
+        let valid = CString::new("1.25").unwrap();
+        assert_eq!(
+            unsafe { price_from_cstr(valid.as_ptr(), out.as_mut_ptr()) },
+            0
+        );
+        assert_eq!(unsafe { out.assume_init() }, Price::from("1.25"));
+        let invalid = CString::new("abc").unwrap();
+        assert_eq!(
+            unsafe { price_from_cstr(invalid.as_ptr(), out.as_mut_ptr()) },
+            FFI_ERROR
+        );
+        assert_eq!(
+            unsafe { price_from_cstr(std::ptr::null(), out.as_mut_ptr()) },
+            FFI_ERROR
+        );
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_checked_add_c_api() {
+        let mut out = MaybeUninit::<Price>::uninit();
+        assert_eq!(
+            unsafe { price_checked_add(Price::new(1.0, 1), Price::new(2.0, 1), out.as_mut_ptr()) },
+            0
+        );
+        assert_eq!(unsafe { out.assume_init() }, Price::new(3.0, 1));
+        assert_eq!(
+            unsafe {
+                price_checked_add(
+                    Price::from_raw(i64::MAX, 9),
+                    Price::from_raw(1, 9),
+                    out.as_mut_ptr(),
+                )
+            },
+            FFI_ERROR
+        );
+        assert!(last_error().unwrap().contains("overflowed when adding"));
+    }
 }
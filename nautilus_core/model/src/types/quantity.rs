@@ -13,7 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{
+use alloc::{format, string::String};
+#[cfg(feature = "ffi")]
+use core::ffi::c_char;
+use core::{
     cmp::Ordering,
     fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
@@ -21,33 +24,67 @@ use std::{
     str::FromStr,
 };
 
-use nautilus_core::{correctness, parsing::precision_from_str};
-use pyo3::prelude::*;
+use nautilus_core::{correctness, parsing::try_precision_from_str};
+#[cfg(feature = "ffi")]
+use nautilus_core::{
+    ffi_error::{write_parsed_cstr, write_result, FFI_ERROR},
+    string::str_to_cstr,
+};
+#[cfg(feature = "python")]
+use pyo3::{basic::CompareOp, prelude::*, types::PyTuple, PyTypeInfo};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize};
 
 use super::fixed::FIXED_SCALAR;
-use crate::types::fixed::{f64_to_fixed_u64, fixed_u64_to_f64};
+#[cfg(feature = "python")]
+use crate::python::{hash_of, richcmp_ord, to_pyvalue_err};
+use crate::types::fixed::{
+    f64_to_fixed_u64, fixed_u64_to_f64, round_fixed_to_increment_u64, round_fixed_u64,
+    RoundingMode, FIXED_PRECISION,
+};
 
 pub const QUANTITY_MAX: f64 = 18_446_744_073.0;
 pub const QUANTITY_MIN: f64 = 0.0;
 
 #[repr(C)]
 #[derive(Copy, Clone, Eq, Default)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct Quantity {
     pub raw: u64,
     pub precision: u8,
 }
 
 impl Quantity {
-    #[must_use]
-    pub fn new(value: f64, precision: u8) -> Self {
-        correctness::f64_in_range_inclusive(value, QUANTITY_MIN, QUANTITY_MAX, "`Quantity` value");
+    /// Returns a new [`Quantity`], or an error if `value` is not within [`QUANTITY_MIN`,
+    /// `QUANTITY_MAX`] or `precision` exceeds the maximum fixed precision.
+    pub fn try_new(value: f64, precision: u8) -> Result<Self, String> {
+        correctness::check_f64_in_range_inclusive(
+            value,
+            QUANTITY_MIN,
+            QUANTITY_MAX,
+            "`Quantity` value",
+        )?;
+        if precision > FIXED_PRECISION {
+            return Err(format!(
+                "invalid `Quantity` precision, was {precision} (maximum {FIXED_PRECISION})"
+            ));
+        }
 
-        Self {
+        Ok(Self {
             raw: f64_to_fixed_u64(value, precision),
             precision,
-        }
+        })
+    }
+
+    /// Returns a new [`Quantity`].
+    ///
+    /// # Panics
+    ///
+    /// - If `value` is not within [`QUANTITY_MIN`, `QUANTITY_MAX`].
+    /// - If `precision` exceeds the maximum fixed precision.
+    #[must_use]
+    pub fn new(value: f64, precision: u8) -> Self {
+        Self::try_new(value, precision).unwrap_or_else(|e| panic!("{e}"))
     }
 
     #[must_use]
@@ -68,6 +105,102 @@ impl Quantity {
     pub fn as_f64(&self) -> f64 {
         fixed_u64_to_f64(self.raw)
     }
+
+    /// Returns the sum of `self` and `rhs`, or `None` if the result overflows.
+    ///
+    /// The result takes the greater precision of the two operands.
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.raw.checked_add(rhs.raw).map(|raw| Self {
+            raw,
+            precision: self.precision.max(rhs.precision),
+        })
+    }
+
+    /// Returns the difference of `self` and `rhs`, or `None` if the result overflows.
+    ///
+    /// The result takes the greater precision of the two operands.
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.raw.checked_sub(rhs.raw).map(|raw| Self {
+            raw,
+            precision: self.precision.max(rhs.precision),
+        })
+    }
+
+    /// Returns the product of `self` and `rhs`, or `None` if the result overflows.
+    ///
+    /// The result takes the greater precision of the two operands.
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let raw = i128::from(self.raw) * i128::from(rhs.raw) / (FIXED_SCALAR as i128);
+        u64::try_from(raw).ok().map(|raw| Self {
+            raw,
+            precision: self.precision.max(rhs.precision),
+        })
+    }
+
+    /// Returns `self` rounded to the given `precision` using the rounding `mode`.
+    ///
+    /// # Panics
+    ///
+    /// - If `precision` exceeds the maximum fixed precision.
+    /// - If the rounded value overflows.
+    #[must_use]
+    pub fn round(&self, precision: u8, mode: RoundingMode) -> Self {
+        assert!(
+            precision <= FIXED_PRECISION,
+            "precision exceeded maximum {FIXED_PRECISION}"
+        );
+        self.checked_round(precision, mode)
+            .unwrap_or_else(|| panic!("`Quantity` overflowed when rounding {self}"))
+    }
+
+    /// Returns `self` rounded to the given `precision` using the rounding `mode`, or `None` if
+    /// `precision` exceeds the maximum fixed precision or the result overflows.
+    #[must_use]
+    pub fn checked_round(&self, precision: u8, mode: RoundingMode) -> Option<Self> {
+        if precision > FIXED_PRECISION {
+            return None;
+        }
+        round_fixed_u64(self.raw, precision, mode).map(|raw| Self { raw, precision })
+    }
+
+    /// Returns `self` rounded to a multiple of `increment` using the rounding `mode`, or
+    /// `None` if `increment` is zero or the result overflows.
+    ///
+    /// The result takes the precision of `increment`.
+    #[must_use]
+    pub fn checked_round_to_increment(&self, increment: Self, mode: RoundingMode) -> Option<Self> {
+        if increment.raw == 0 {
+            return None;
+        }
+        round_fixed_to_increment_u64(self.raw, increment.raw, mode).map(|raw| Self {
+            raw,
+            precision: increment.precision,
+        })
+    }
+
+    /// Returns `self` rounded to a multiple of `increment` using the rounding `mode`.
+    ///
+    /// The result takes the precision of `increment`.
+    ///
+    /// # Panics
+    ///
+    /// - If `increment` is zero.
+    /// - If the rounded value overflows.
+    #[must_use]
+    pub fn round_to_increment(&self, increment: Self, mode: RoundingMode) -> Self {
+        assert!(increment.raw != 0, "`Quantity` increment was zero");
+        self.checked_round_to_increment(increment, mode)
+            .unwrap_or_else(|| panic!("`Quantity` overflowed when rounding {self} to {increment}"))
+    }
+
+    /// Returns true if `self` is an exact multiple of `increment` (which must be positive).
+    #[must_use]
+    pub fn is_multiple_of(&self, increment: Self) -> bool {
+        increment.raw > 0 && self.raw % increment.raw == 0
+    }
 }
 
 impl From<Quantity> for f64 {
@@ -90,7 +223,9 @@ impl FromStr for Quantity {
             .parse::<f64>()
             .map_err(|err| format!("Cannot parse `input` string '{}' as f64: {}", input, err))?;
 
-        Ok(Self::new(float_from_input, precision_from_str(input)))
+        let precision = try_precision_from_str(input)
+            .ok_or_else(|| format!("Cannot parse precision from `input` string '{input}'"))?;
+        Self::try_new(float_from_input, precision)
     }
 }
 
@@ -177,8 +312,11 @@ impl Sub for Quantity {
 impl Mul for Quantity {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
+        let raw = i128::from(self.raw) * i128::from(rhs.raw) / (FIXED_SCALAR as i128);
         Self {
-            raw: (self.raw * rhs.raw) / (FIXED_SCALAR as u64),
+            raw: u64::try_from(raw).unwrap_or_else(|_| {
+                panic!("`Quantity` overflowed when multiplying {self} by {rhs}")
+            }),
             precision: self.precision,
         }
     }
@@ -208,24 +346,33 @@ impl<T: Into<u64>> SubAssign<T> for Quantity {
     }
 }
 
-impl<T: Into<u64>> MulAssign<T> for Quantity {
-    fn mul_assign(&mut self, other: T) {
-        self.raw *= other.into();
+impl MulAssign for Quantity {
+    fn mul_assign(&mut self, multiplier: Self) {
+        *self = *self * multiplier;
+    }
+}
+
+impl MulAssign<u64> for Quantity {
+    fn mul_assign(&mut self, multiplier: u64) {
+        self.raw = self.raw.checked_mul(multiplier).unwrap_or_else(|| {
+            panic!("`Quantity` overflowed when multiplying {self} by {multiplier}")
+        });
     }
 }
 
 impl Debug for Quantity {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:.*}", self.precision as usize, self.as_f64())
     }
 }
 
 impl Display for Quantity {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:.*}", self.precision as usize, self.as_f64())
     }
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for Quantity {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -235,6 +382,7 @@ impl Serialize for Quantity {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Quantity {
     fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
     where
@@ -246,50 +394,284 @@ impl<'de> Deserialize<'de> for Quantity {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "python")]
+#[pymethods]
+impl Quantity {
+    #[new]
+    fn py_new(value: f64, precision: u8) -> PyResult<Self> {
+        if !(QUANTITY_MIN..=QUANTITY_MAX).contains(&value) {
+            return Err(to_pyvalue_err(format!(
+                "invalid `Quantity` value, was {value} (expected in range [{}, {}])",
+                QUANTITY_MIN, QUANTITY_MAX
+            )));
+        }
+        if precision > FIXED_PRECISION {
+            return Err(to_pyvalue_err(format!(
+                "invalid `Quantity` precision, was {precision} (maximum {FIXED_PRECISION})"
+            )));
+        }
+        Ok(Self::new(value, precision))
+    }
+
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let (raw, precision): (u64, u8) = state.extract(py)?;
+        self.raw = raw;
+        self.precision = precision;
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyObject {
+        (self.raw, self.precision).to_object(py)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py);
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> Self {
+        Self::default()
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_raw")]
+    fn py_from_raw(raw: u64, precision: u8) -> Self {
+        Self::from_raw(raw, precision)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(value: &str) -> PyResult<Self> {
+        Self::from_str(value).map_err(to_pyvalue_err)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        richcmp_ord(self, other, op, py)
+    }
+
+    fn __hash__(&self) -> isize {
+        hash_of(self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}('{self}')", Self::NAME)
+    }
+
+    #[getter]
+    #[pyo3(name = "raw")]
+    fn py_raw(&self) -> u64 {
+        self.raw
+    }
+
+    #[getter]
+    #[pyo3(name = "precision")]
+    fn py_precision(&self) -> u8 {
+        self.precision
+    }
+
+    #[pyo3(name = "as_double")]
+    fn py_as_double(&self) -> f64 {
+        self.as_f64()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
+/// Writes a new [`Quantity`] to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set (in
+/// which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+#[cfg(feature = "ffi")]
 #[no_mangle]
-pub extern "C" fn quantity_new(value: f64, precision: u8) -> Quantity {
-    Quantity::new(value, precision)
+pub unsafe extern "C" fn quantity_new(value: f64, precision: u8, out: *mut Quantity) -> u8 {
+    write_result(Quantity::try_new(value, precision), out, |_| FFI_ERROR)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn quantity_from_raw(raw: u64, precision: u8) -> Quantity {
     Quantity::from_raw(raw, precision)
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn quantity_as_f64(qty: &Quantity) -> f64 {
     qty.as_f64()
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn quantity_add_assign(mut a: Quantity, b: Quantity) {
     a.add_assign(b);
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn quantity_add_assign_u64(mut a: Quantity, b: u64) {
     a.add_assign(b);
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn quantity_sub_assign(mut a: Quantity, b: Quantity) {
     a.sub_assign(b);
 }
 
+#[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn quantity_sub_assign_u64(mut a: Quantity, b: u64) {
     a.sub_assign(b);
 }
 
+/// Parses a [`Quantity`] from a C string pointer, written to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set (in
+/// which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn quantity_from_cstr(ptr: *const c_char, out: *mut Quantity) -> u8 {
+    write_parsed_cstr(ptr, out, "`Quantity`")
+}
+
+/// Returns a [`Quantity`] as a C string pointer.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn quantity_to_cstr(qty: &Quantity) -> *const c_char {
+    str_to_cstr(&qty.to_string())
+}
+
+/// Writes the sum of `a` and `b` to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if the
+/// result overflowed (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn quantity_checked_add(a: Quantity, b: Quantity, out: *mut Quantity) -> u8 {
+    let result = a
+        .checked_add(b)
+        .ok_or_else(|| format!("`Quantity` overflowed when adding {a} and {b}"));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Writes the difference of `a` and `b` to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if the
+/// result overflowed (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn quantity_checked_sub(a: Quantity, b: Quantity, out: *mut Quantity) -> u8 {
+    let result = a
+        .checked_sub(b)
+        .ok_or_else(|| format!("`Quantity` overflowed when subtracting {a} and {b}"));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Writes the product of `a` and `b` to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if the
+/// result overflowed (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn quantity_checked_mul(a: Quantity, b: Quantity, out: *mut Quantity) -> u8 {
+    let result = a
+        .checked_mul(b)
+        .ok_or_else(|| format!("`Quantity` overflowed when multiplying {a} and {b}"));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Writes `qty` rounded to the given `precision` to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `precision` exceeds the maximum fixed precision or the result overflowed (in which case
+/// nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn quantity_round(
+    qty: Quantity,
+    precision: u8,
+    mode: RoundingMode,
+    out: *mut Quantity,
+) -> u8 {
+    let result = qty
+        .checked_round(precision, mode)
+        .ok_or_else(|| format!("`Quantity` could not round {qty} to precision {precision}"));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+/// Writes `qty` rounded to a multiple of `increment` to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set if
+/// `increment` is zero or the result overflowed (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn quantity_round_to_increment(
+    qty: Quantity,
+    increment: Quantity,
+    mode: RoundingMode,
+    out: *mut Quantity,
+) -> u8 {
+    let result = qty
+        .checked_round_to_increment(increment, mode)
+        .ok_or_else(|| format!("`Quantity` could not round {qty} to increment {increment}"));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn quantity_is_multiple_of(qty: Quantity, increment: Quantity) -> u8 {
+    u8::from(qty.is_multiple_of(increment))
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
+    #[cfg(feature = "ffi")]
+    use std::{ffi::CString, mem::MaybeUninit};
+
+    #[cfg(feature = "ffi")]
+    use nautilus_core::ffi_error::last_error;
 
     use super::*;
 
@@ -356,7 +738,7 @@ mod tests {
     #[test]
     fn test_quantity_from_str_valid_input() {
         let input = "1000.25";
-        let expected_quantity = Quantity::new(1000.25, precision_from_str(input));
+        let expected_quantity = Quantity::new(1000.25, 2);
         let result = Quantity::from_str(input).unwrap();
         assert_eq!(result, expected_quantity);
     }
@@ -408,6 +790,12 @@ mod tests {
         assert_eq!(quantity3.raw, 4_000_000_000);
     }
 
+    #[test]
+    #[should_panic(expected = "`Quantity` overflowed when multiplying")]
+    fn test_mul_overflow_panics() {
+        let _ = Quantity::new(1_000_000.0, 0) * Quantity::new(1_000_000.0, 0);
+    }
+
     #[test]
     fn test_quantity_mul_assign() {
         let mut q = Quantity::from_raw(100, 0);
@@ -416,6 +804,21 @@ mod tests {
         assert_eq!(q.raw, 200);
     }
 
+    #[test]
+    fn test_quantity_mul_assign_quantity() {
+        let mut q = Quantity::new(2.0, 1);
+        q *= Quantity::new(1.5, 1);
+
+        assert_eq!(q, Quantity::new(3.0, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "`Quantity` overflowed when multiplying")]
+    fn test_quantity_mul_assign_overflow_panics() {
+        let mut q = Quantity::from_raw(u64::MAX, 0);
+        q *= 2u64;
+    }
+
     #[test]
     fn test_qty_equality() {
         assert_eq!(Quantity::new(1.0, 1), Quantity::new(1.0, 1));
@@ -441,4 +844,159 @@ mod tests {
         assert_eq!(res, input_string);
         assert_eq!(qty.to_string(), input_string);
     }
+
+    #[test]
+    fn test_checked_sub_underflow() {
+        let qty = Quantity::new(1.0, 0).checked_sub(Quantity::new(2.0, 0));
+        assert!(qty.is_none());
+    }
+
+    #[test]
+    fn test_checked_add_and_mul() {
+        let qty = Quantity::new(1.5, 1)
+            .checked_add(Quantity::new(0.25, 2))
+            .unwrap();
+        assert_eq!(qty, Quantity::new(1.75, 2));
+        let qty = Quantity::new(100.0, 0)
+            .checked_mul(Quantity::new(2.5, 1))
+            .unwrap();
+        assert_eq!(qty, Quantity::new(250.0, 1));
+        assert!(Quantity::new(1_000_000.0, 0)
+            .checked_mul(Quantity::new(1_000_000.0, 0))
+            .is_none());
+    }
+
+    #[test]
+    fn test_round() {
+        let qty = Quantity::from("2.55").round(1, RoundingMode::HalfEven);
+        assert_eq!(qty.to_string(), "2.6");
+        let qty = Quantity::from("2.55").round(1, RoundingMode::TowardZero);
+        assert_eq!(qty.to_string(), "2.5");
+    }
+
+    #[test]
+    fn test_checked_round() {
+        let qty = Quantity::from("2.55");
+        assert_eq!(
+            qty.checked_round(1, RoundingMode::Floor),
+            Some(Quantity::from("2.5"))
+        );
+        assert_eq!(
+            qty.checked_round(FIXED_PRECISION + 1, RoundingMode::Floor),
+            None
+        );
+        assert_eq!(
+            Quantity::from_raw(u64::MAX, 9).checked_round(0, RoundingMode::Ceiling),
+            None
+        );
+    }
+
+    #[test]
+    fn test_round_to_increment() {
+        let lot = Quantity::from(100);
+        let qty = Quantity::from("1049.5").round_to_increment(lot, RoundingMode::Floor);
+        assert_eq!(qty, Quantity::from(1000));
+        assert_eq!(qty.precision, 0);
+        assert!(qty.is_multiple_of(lot));
+        assert!(!Quantity::from(1049).is_multiple_of(lot));
+        assert!(!Quantity::from(1000).is_multiple_of(Quantity::zero(0)));
+    }
+
+    #[test]
+    fn test_checked_round_to_increment() {
+        let lot = Quantity::from(100);
+        assert_eq!(
+            Quantity::from("1049.5").checked_round_to_increment(lot, RoundingMode::Ceiling),
+            Some(Quantity::from(1100))
+        );
+        assert_eq!(
+            Quantity::from(1000).checked_round_to_increment(Quantity::zero(0), RoundingMode::Floor),
+            None
+        );
+        assert_eq!(
+            Quantity::from_raw(u64::MAX, 9)
+                .checked_round_to_increment(Quantity::from_raw(2, 9), RoundingMode::Ceiling),
+            None
+        );
+    }
+
+    #[test]
+    fn test_try_new_with_invalid_input() {
+        assert!(Quantity::try_new(-1.0, 0).is_err());
+        assert!(Quantity::try_new(f64::NAN, 0).is_err());
+        assert!(Quantity::try_new(1.0, FIXED_PRECISION + 1).is_err());
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_quantity_new_c_api() {
+        let mut out = MaybeUninit::<Quantity>::uninit();
+        assert_eq!(unsafe { quantity_new(1.5, 1, out.as_mut_ptr()) }, 0);
+        assert_eq!(unsafe { out.assume_init() }, Quantity::new(1.5, 1));
+        assert_eq!(
+            unsafe { quantity_new(-1.0, 1, out.as_mut_ptr()) },
+            FFI_ERROR
+        );
+        assert!(last_error().unwrap().contains("`Quantity` value"));
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_quantity_round_c_api() {
+        let mut out = MaybeUninit::<Quantity>::uninit();
+        let qty = Quantity::from("2.55");
+        assert_eq!(
+            unsafe { quantity_round(qty, 1, RoundingMode::Floor, out.as_mut_ptr()) },
+            0
+        );
+        assert_eq!(unsafe { out.assume_init() }, Quantity::from("2.5"));
+        assert_eq!(
+            unsafe {
+                quantity_round(
+                    qty,
+                    FIXED_PRECISION + 1,
+                    RoundingMode::Floor,
+                    out.as_mut_ptr(),
+                )
+            },
+            FFI_ERROR
+        );
+        assert!(last_error().unwrap().contains("could not round"));
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_quantity_from_cstr_c_api() {
+        let mut out = MaybeUninit::<Quantity>::uninit();
+        let valid = CString::new("100.5").unwrap();
+        assert_eq!(
+            unsafe { quantity_from_cstr(valid.as_ptr(), out.as_mut_ptr()) },
+            0
+        );
+        assert_eq!(unsafe { out.assume_init() }, Quantity::from("100.5"));
+        let invalid = CString::new("-1").unwrap();
+        assert_eq!(
+            unsafe { quantity_from_cstr(invalid.as_ptr(), out.as_mut_ptr()) },
+            FFI_ERROR
+        );
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_checked_sub_c_api() {
+        let mut out = MaybeUninit::<Quantity>::uninit();
+        assert_eq!(
+            unsafe {
+                quantity_checked_sub(
+                    Quantity::new(1.0, 0),
+                    Quantity::new(2.0, 0),
+                    out.as_mut_ptr(),
+                )
+            },
+            FFI_ERROR
+        );
+        assert!(last_error()
+            .unwrap()
+            .contains("overflowed when subtracting"));
+    }
 }
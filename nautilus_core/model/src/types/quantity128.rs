@@ -0,0 +1,455 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A 128-bit fixed-point quantity for instruments (such as ERC-20 tokens) quoted with up to
+//! 18 decimal places, where the 64-bit [`Quantity`] would overflow or lose precision.
+
+use alloc::{format, string::String};
+#[cfg(feature = "ffi")]
+use core::ffi::c_char;
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+#[cfg(feature = "ffi")]
+use nautilus_core::ffi_error::{try_cstr_to_str, write_result, FFI_ERROR};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::{
+    fixed::{RoundingMode, FIXED_PRECISION},
+    quantity::Quantity,
+};
+
+pub const FIXED_PRECISION_128: u8 = 18;
+pub const FIXED_SCALAR_128: u128 = 1_000_000_000_000_000_000; // 10**FIXED_PRECISION_128
+
+/// The factor between the 64-bit and 128-bit fixed-point raw values.
+const RAW_SCALE: u128 = 1_000_000_000; // 10**(FIXED_PRECISION_128 - FIXED_PRECISION)
+
+/// Represents a quantity with up to 18 decimal places, held exactly as a 128-bit raw value.
+///
+/// Values are parsed from decimal strings rather than `f64`, and are converted to a 64-bit
+/// [`Quantity`] (with explicit rounding) when passed across the FFI boundary.
+#[derive(Copy, Clone, Eq, Default)]
+pub struct Quantity128 {
+    pub raw: u128,
+    pub precision: u8,
+}
+
+impl Quantity128 {
+    /// # Panics
+    ///
+    /// - If `precision` exceeds `FIXED_PRECISION_128`.
+    #[must_use]
+    pub fn from_raw(raw: u128, precision: u8) -> Self {
+        assert!(
+            precision <= FIXED_PRECISION_128,
+            "precision exceeded maximum 18"
+        );
+        Self { raw, precision }
+    }
+
+    #[must_use]
+    pub fn zero(precision: u8) -> Self {
+        Self::from_raw(0, precision)
+    }
+
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    #[must_use]
+    pub fn as_f64(&self) -> f64 {
+        self.raw as f64 / FIXED_SCALAR_128 as f64
+    }
+
+    /// Returns the sum of `self` and `rhs`, or `None` if the result overflows.
+    ///
+    /// The result takes the greater precision of the two operands.
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.raw.checked_add(rhs.raw).map(|raw| Self {
+            raw,
+            precision: self.precision.max(rhs.precision),
+        })
+    }
+
+    /// Returns the difference of `self` and `rhs`, or `None` if the result overflows.
+    ///
+    /// The result takes the greater precision of the two operands.
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.raw.checked_sub(rhs.raw).map(|raw| Self {
+            raw,
+            precision: self.precision.max(rhs.precision),
+        })
+    }
+
+    /// Returns `self` rounded to the given `precision` using the rounding `mode`.
+    ///
+    /// # Panics
+    ///
+    /// - If `precision` exceeds `FIXED_PRECISION_128`.
+    /// - If the rounded value overflows.
+    #[must_use]
+    pub fn round(&self, precision: u8, mode: RoundingMode) -> Self {
+        let raw = round_fixed_u128(self.raw, precision, mode)
+            .unwrap_or_else(|| panic!("`Quantity128` overflowed when rounding {self}"));
+        Self { raw, precision }
+    }
+
+    /// Returns `self` as a 64-bit [`Quantity`], rounding to at most 9 decimal places using
+    /// the rounding `mode`.
+    ///
+    /// Returns `None` if the value exceeds the range of a `Quantity`.
+    #[must_use]
+    pub fn to_quantity(&self, mode: RoundingMode) -> Option<Quantity> {
+        let precision = self.precision.min(FIXED_PRECISION);
+        let raw = round_fixed_u128(self.raw, precision, mode)? / RAW_SCALE;
+        u64::try_from(raw)
+            .ok()
+            .map(|raw| Quantity::from_raw(raw, precision))
+    }
+}
+
+#[must_use]
+fn round_fixed_u128(value: u128, precision: u8, mode: RoundingMode) -> Option<u128> {
+    assert!(
+        precision <= FIXED_PRECISION_128,
+        "precision exceeded maximum 18"
+    );
+    let step = 10_u128.pow(u32::from(FIXED_PRECISION_128 - precision));
+    let remainder = value % step;
+    if remainder == 0 {
+        return Some(value);
+    }
+    let floor = value - remainder;
+    let ceiling = floor.checked_add(step);
+    match mode {
+        RoundingMode::Floor | RoundingMode::TowardZero => Some(floor),
+        RoundingMode::Ceiling | RoundingMode::AwayFromZero => ceiling,
+        RoundingMode::HalfAwayFromZero | RoundingMode::HalfEven => {
+            match (remainder * 2).cmp(&step) {
+                Ordering::Less => Some(floor),
+                Ordering::Greater => ceiling,
+                Ordering::Equal => match mode {
+                    RoundingMode::HalfEven if (floor / step) % 2 == 0 => Some(floor),
+                    _ => ceiling,
+                },
+            }
+        }
+    }
+}
+
+impl From<Quantity> for Quantity128 {
+    fn from(value: Quantity) -> Self {
+        Self {
+            raw: u128::from(value.raw) * RAW_SCALE,
+            precision: value.precision,
+        }
+    }
+}
+
+impl FromStr for Quantity128 {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+        let unsigned = trimmed.strip_prefix('+').unwrap_or(trimmed);
+        let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if (whole.is_empty() && fraction.is_empty())
+            || !whole
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(format!(
+                "Cannot parse `input` string '{input}' as a decimal quantity"
+            ));
+        }
+
+        let precision = u8::try_from(fraction.len())
+            .ok()
+            .filter(|precision| *precision <= FIXED_PRECISION_128)
+            .ok_or_else(|| {
+                format!("Cannot parse `input` string '{input}': precision exceeded maximum 18")
+            })?;
+
+        let overflow = || format!("Cannot parse `input` string '{input}': value overflowed");
+        let whole_raw = match whole {
+            "" => 0,
+            _ => whole.parse::<u128>().map_err(|_| overflow())?,
+        };
+        let fraction_raw = match fraction {
+            "" => 0,
+            _ => fraction.parse::<u128>().map_err(|_| overflow())?,
+        } * 10_u128.pow(u32::from(FIXED_PRECISION_128 - precision));
+
+        let raw = whole_raw
+            .checked_mul(FIXED_SCALAR_128)
+            .and_then(|raw| raw.checked_add(fraction_raw))
+            .ok_or_else(overflow)?;
+
+        Ok(Self { raw, precision })
+    }
+}
+
+impl From<&str> for Quantity128 {
+    fn from(input: &str) -> Self {
+        input.parse().unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl Hash for Quantity128 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state)
+    }
+}
+
+impl PartialEq for Quantity128 {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl PartialOrd for Quantity128 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Quantity128 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl Add for Quantity128 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            raw: self.raw + rhs.raw,
+            precision: self.precision,
+        }
+    }
+}
+
+impl Sub for Quantity128 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            raw: self.raw - rhs.raw,
+            precision: self.precision,
+        }
+    }
+}
+
+impl Debug for Quantity128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Quantity128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let whole = self.raw / FIXED_SCALAR_128;
+        if self.precision == 0 {
+            return write!(f, "{whole}");
+        }
+        let fraction = (self.raw % FIXED_SCALAR_128)
+            / 10_u128.pow(u32::from(FIXED_PRECISION_128 - self.precision));
+        write!(
+            f,
+            "{whole}.{fraction:0width$}",
+            width = self.precision as usize
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Quantity128 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&format!("{}", self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Quantity128 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let qty_str: &str = Deserialize::deserialize(deserializer)?;
+        qty_str.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Parses the decimal string at `ptr` with up to 18 decimal places, and writes it to `out`
+/// as a [`Quantity`] rounded to at most 9 decimal places using the rounding `mode`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set (in
+/// which case `out` is unchanged).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer, or null.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn quantity128_cstr_to_quantity(
+    ptr: *const c_char,
+    mode: RoundingMode,
+    out: &mut Quantity,
+) -> u8 {
+    let result = try_cstr_to_str(ptr, "`Quantity128`")
+        .and_then(Quantity128::from_str)
+        .and_then(|qty| {
+            qty.to_quantity(mode)
+                .ok_or_else(|| format!("`Quantity128` {qty} exceeded the range of `Quantity`"))
+        });
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "ffi")]
+    use nautilus_core::ffi_error::{last_error, FFI_OK};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("0", 0)]
+    #[case("1.5", 1)]
+    #[case("0.000000000000000001", 18)]
+    #[case("123456789012.123456789012345678", 18)]
+    #[case("340282366920938463463.374607431768211455", 18)]
+    fn test_from_str_display_round_trip(#[case] input: &str, #[case] precision: u8) {
+        let qty = Quantity128::from(input);
+        assert_eq!(qty.precision, precision);
+        assert_eq!(qty.to_string(), input);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case(".")]
+    #[case("-1")]
+    #[case("1.2.3")]
+    #[case("1e-18")]
+    #[case("0.0000000000000000001")]
+    #[case("340282366920938463464")]
+    fn test_from_str_invalid(#[case] input: &str) {
+        assert!(Quantity128::from_str(input).is_err());
+    }
+
+    #[test]
+    fn test_round() {
+        let qty = Quantity128::from("1.000000000000000005");
+        assert_eq!(
+            qty.round(17, RoundingMode::HalfEven).to_string(),
+            "1.00000000000000000"
+        );
+        assert_eq!(
+            qty.round(17, RoundingMode::HalfAwayFromZero).to_string(),
+            "1.00000000000000001"
+        );
+        assert_eq!(qty.round(0, RoundingMode::Ceiling).to_string(), "2");
+    }
+
+    #[test]
+    fn test_to_quantity() {
+        let qty = Quantity128::from("1.123456789500000001");
+        assert_eq!(
+            qty.to_quantity(RoundingMode::Floor),
+            Some(Quantity::from("1.123456789"))
+        );
+        assert_eq!(
+            qty.to_quantity(RoundingMode::HalfEven),
+            Some(Quantity::from("1.123456790"))
+        );
+        assert_eq!(
+            Quantity128::from("18446744074").to_quantity(RoundingMode::Floor),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_quantity_is_exact() {
+        let qty = Quantity::from("2.25");
+        let qty128 = Quantity128::from(qty);
+        assert_eq!(qty128, Quantity128::from("2.25"));
+        assert_eq!(qty128.to_quantity(RoundingMode::Floor), Some(qty));
+    }
+
+    #[test]
+    fn test_checked_arithmetic_and_ordering() {
+        let a = Quantity128::from("0.000000000000000001");
+        let b = Quantity128::from("1.5");
+        let sum = a.checked_add(b).unwrap();
+        assert_eq!(sum.to_string(), "1.500000000000000001");
+        assert!(a < b);
+        assert_eq!(a.checked_sub(b), None);
+        assert_eq!(Quantity128::from_raw(u128::MAX, 18).checked_add(a), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let qty = Quantity128::from("0.123456789012345678");
+        let json = serde_json::to_string(&qty).unwrap();
+        assert_eq!(json, "\"0.123456789012345678\"");
+        assert_eq!(serde_json::from_str::<Quantity128>(&json).unwrap(), qty);
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_quantity128_cstr_to_quantity() {
+        let input = std::ffi::CString::new("0.123456789987654321").unwrap();
+        let mut out = Quantity::default();
+        let result = unsafe {
+            quantity128_cstr_to_quantity(input.as_ptr(), RoundingMode::Ceiling, &mut out)
+        };
+        assert_eq!(result, FFI_OK);
+        assert_eq!(out, Quantity::from("0.123456790"));
+
+        let invalid = std::ffi::CString::new("abc").unwrap();
+        let result = unsafe {
+            quantity128_cstr_to_quantity(invalid.as_ptr(), RoundingMode::Ceiling, &mut out)
+        };
+        assert_eq!(result, FFI_ERROR);
+        assert_eq!(out, Quantity::from("0.123456790"));
+
+        let result = unsafe {
+            quantity128_cstr_to_quantity(std::ptr::null(), RoundingMode::Ceiling, &mut out)
+        };
+        assert_eq!(result, FFI_ERROR);
+        assert!(last_error().unwrap().contains("`ptr` was NULL"));
+        assert_eq!(out, Quantity::from("0.123456790"));
+    }
+}
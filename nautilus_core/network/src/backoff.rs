@@ -0,0 +1,126 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::time::Duration;
+
+/// Exponential backoff policy applied between failed reconnection attempts.
+///
+/// The first reconnection attempt is made immediately after the connection
+/// is detected as closed. Each following attempt waits `initial_delay`
+/// multiplied by `factor` for every previous failure, capped at `max_delay`.
+/// If `max_attempts` is `None` the client keeps retrying until it is
+/// explicitly disconnected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExponentialBackoff {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub factor: f64,
+    pub max_attempts: Option<u32>,
+}
+
+impl ExponentialBackoff {
+    #[must_use]
+    pub fn new(
+        initial_delay: Duration,
+        max_delay: Duration,
+        factor: f64,
+        max_attempts: Option<u32>,
+    ) -> Self {
+        Self {
+            initial_delay,
+            max_delay,
+            factor,
+            max_attempts,
+        }
+    }
+
+    /// Returns the delay to wait after the given number of failed attempts.
+    #[must_use]
+    pub fn delay(&self, failures: u32) -> Duration {
+        let factor = self.factor.max(1.0);
+        let exponent = i32::try_from(failures.saturating_sub(1)).unwrap_or(i32::MAX);
+        let delay = self.initial_delay.as_secs_f64() * factor.powi(exponent);
+        Duration::from_secs_f64(delay.min(self.max_delay.as_secs_f64()))
+    }
+
+    /// Returns true if no more attempts should be made after the given
+    /// number of failed attempts.
+    #[must_use]
+    pub fn is_exhausted(&self, failures: u32) -> bool {
+        self.max_attempts.map_or(false, |max| failures >= max)
+    }
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            factor: 2.0,
+            max_attempts: None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_backoff_delay() {
+        let backoff = ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+            2.0,
+            None,
+        );
+
+        assert_eq!(backoff.delay(1), Duration::from_millis(100));
+        assert_eq!(backoff.delay(2), Duration::from_millis(200));
+        assert_eq!(backoff.delay(4), Duration::from_millis(800));
+        assert_eq!(backoff.delay(5), Duration::from_secs(1));
+        assert_eq!(backoff.delay(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_backoff_factor_below_one_does_not_shrink() {
+        let backoff = ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+            0.5,
+            None,
+        );
+
+        assert_eq!(backoff.delay(3), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_backoff_is_exhausted() {
+        let unlimited = ExponentialBackoff::default();
+        let limited = ExponentialBackoff {
+            max_attempts: Some(3),
+            ..Default::default()
+        };
+
+        assert!(!unlimited.is_exhausted(1_000));
+        assert!(!limited.is_exhausted(2));
+        assert!(limited.is_exhausted(3));
+    }
+}
@@ -0,0 +1,359 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Estimation of the offset between a venue's clock and the local clock.
+//!
+//! Samples are taken from venue timestamps, either a time endpoint bracketed by the
+//! local send and receive times (as NTP does), or heartbeats received from the venue.
+//! The offset is smoothed with an exponentially weighted moving average, so event
+//! timestamps from the venue can be mapped onto the local clock.
+
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use nautilus_core::time::{get_atomic_clock, UnixNanos};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use tokio::time::interval;
+use tracing::debug;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SkewEstimate {
+    offset_ns: f64,
+    samples: u64,
+}
+
+/// Maintains an estimate of how far ahead of the local clock a venue's clock is.
+///
+/// The first sample sets the offset, each following sample moves it by `alpha` of the
+/// difference. Round trip samples slower than `max_rtt` are discarded, as the venue
+/// time could have been taken anywhere within the round trip. Heartbeat samples also
+/// include the one-way network latency, so they skew the estimate by that amount and
+/// are best used when no time endpoint is available.
+#[derive(Debug)]
+pub struct ClockSync {
+    alpha: f64,
+    max_rtt: Option<Duration>,
+    estimate: Mutex<Option<SkewEstimate>>,
+}
+
+impl ClockSync {
+    /// Creates a new [`ClockSync`] instance.
+    ///
+    /// # Panics
+    ///
+    /// - If `alpha` is not within (0, 1].
+    #[must_use]
+    pub fn new(alpha: f64, max_rtt: Option<Duration>) -> Self {
+        assert!(
+            alpha > 0.0 && alpha <= 1.0,
+            "`alpha` was not within (0, 1], was {alpha}"
+        );
+        Self {
+            alpha,
+            max_rtt,
+            estimate: Mutex::new(None),
+        }
+    }
+
+    /// Records a sample from a request sent at `local_send_ns` and answered with
+    /// `venue_ns`, received at `local_recv_ns`.
+    ///
+    /// The venue time is assumed to be taken halfway through the round trip. Returns
+    /// false if the sample was discarded.
+    pub fn record_round_trip(
+        &self,
+        local_send_ns: UnixNanos,
+        venue_ns: UnixNanos,
+        local_recv_ns: UnixNanos,
+    ) -> bool {
+        if local_recv_ns < local_send_ns {
+            return false;
+        }
+        let rtt_ns = local_recv_ns - local_send_ns;
+        if self
+            .max_rtt
+            .map_or(false, |max_rtt| u128::from(rtt_ns) > max_rtt.as_nanos())
+        {
+            debug!("Discarded clock sample with round trip of {rtt_ns}ns");
+            return false;
+        }
+        let midpoint_ns = local_send_ns + rtt_ns / 2;
+        self.record_offset(venue_ns as f64 - midpoint_ns as f64);
+        true
+    }
+
+    /// Records a sample from a heartbeat stamped with `venue_ns`, received at
+    /// `local_recv_ns`.
+    pub fn record_heartbeat(&self, venue_ns: UnixNanos, local_recv_ns: UnixNanos) {
+        self.record_offset(venue_ns as f64 - local_recv_ns as f64);
+    }
+
+    /// Returns the estimated nanoseconds the venue clock is ahead of the local clock
+    /// (negative if behind), or `None` if no samples have been recorded.
+    #[must_use]
+    pub fn offset_ns(&self) -> Option<i64> {
+        self.estimate
+            .lock()
+            .unwrap()
+            .map(|estimate| estimate.offset_ns.round() as i64)
+    }
+
+    /// Returns the number of samples in the estimate.
+    #[must_use]
+    pub fn sample_count(&self) -> u64 {
+        self.estimate
+            .lock()
+            .unwrap()
+            .map_or(0, |estimate| estimate.samples)
+    }
+
+    /// Returns the venue timestamp `venue_ts` adjusted onto the local clock.
+    ///
+    /// The timestamp is returned unchanged if no samples have been recorded.
+    #[must_use]
+    pub fn to_local_ns(&self, venue_ts: UnixNanos) -> UnixNanos {
+        shift(venue_ts, self.offset_ns().map_or(0, |offset| -offset))
+    }
+
+    /// Returns the local timestamp `local_ts` adjusted onto the venue clock.
+    ///
+    /// The timestamp is returned unchanged if no samples have been recorded.
+    #[must_use]
+    pub fn to_venue_ns(&self, local_ts: UnixNanos) -> UnixNanos {
+        shift(local_ts, self.offset_ns().unwrap_or(0))
+    }
+
+    /// Discards the estimate, for example after reconnecting to a different server.
+    pub fn reset(&self) {
+        *self.estimate.lock().unwrap() = None;
+    }
+
+    /// Measures the offset every `period` by calling `fetch_venue_time`, which returns
+    /// the venue's current time (or `None` if the request failed).
+    ///
+    /// Runs until the returned future is dropped.
+    pub async fn sync_periodically<F, Fut>(&self, period: Duration, mut fetch_venue_time: F)
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Option<UnixNanos>>,
+    {
+        let clock = get_atomic_clock();
+        let mut ticker = interval(period);
+        loop {
+            ticker.tick().await;
+            let local_send_ns = clock.get_time_ns();
+            if let Some(venue_ns) = fetch_venue_time().await {
+                self.record_round_trip(local_send_ns, venue_ns, clock.get_time_ns());
+            }
+        }
+    }
+
+    fn record_offset(&self, sample_ns: f64) {
+        let mut estimate = self.estimate.lock().unwrap();
+        *estimate = Some(match *estimate {
+            Some(SkewEstimate { offset_ns, samples }) => SkewEstimate {
+                offset_ns: offset_ns + self.alpha * (sample_ns - offset_ns),
+                samples: samples + 1,
+            },
+            None => SkewEstimate {
+                offset_ns: sample_ns,
+                samples: 1,
+            },
+        });
+    }
+}
+
+impl Default for ClockSync {
+    /// Creates a new default [`ClockSync`] instance, with an `alpha` of 0.1 and no limit
+    /// on the round trip time.
+    fn default() -> Self {
+        Self::new(0.1, None)
+    }
+}
+
+fn shift(ts: UnixNanos, offset_ns: i64) -> UnixNanos {
+    if offset_ns >= 0 {
+        ts.saturating_add(offset_ns.unsigned_abs())
+    } else {
+        ts.saturating_sub(offset_ns.unsigned_abs())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[pyclass(name = "ClockSync")]
+pub struct PyClockSync {
+    inner: Arc<ClockSync>,
+}
+
+#[pymethods]
+impl PyClockSync {
+    #[new]
+    #[pyo3(signature = (alpha=0.1, max_rtt_ms=None))]
+    fn py_new(alpha: f64, max_rtt_ms: Option<u64>) -> PyResult<Self> {
+        if !(alpha > 0.0 && alpha <= 1.0) {
+            return Err(PyValueError::new_err(format!(
+                "`alpha` was not within (0, 1], was {alpha}"
+            )));
+        }
+        Ok(Self {
+            inner: Arc::new(ClockSync::new(alpha, max_rtt_ms.map(Duration::from_millis))),
+        })
+    }
+
+    /// Records a round trip sample, returning false if it was discarded.
+    fn record_round_trip(
+        &self,
+        local_send_ns: UnixNanos,
+        venue_ns: UnixNanos,
+        local_recv_ns: UnixNanos,
+    ) -> bool {
+        self.inner
+            .record_round_trip(local_send_ns, venue_ns, local_recv_ns)
+    }
+
+    fn record_heartbeat(&self, venue_ns: UnixNanos, local_recv_ns: UnixNanos) {
+        self.inner.record_heartbeat(venue_ns, local_recv_ns);
+    }
+
+    #[getter]
+    fn offset_ns(&self) -> Option<i64> {
+        self.inner.offset_ns()
+    }
+
+    #[getter]
+    fn sample_count(&self) -> u64 {
+        self.inner.sample_count()
+    }
+
+    fn to_local_ns(&self, venue_ts: UnixNanos) -> UnixNanos {
+        self.inner.to_local_ns(venue_ts)
+    }
+
+    fn to_venue_ns(&self, local_ts: UnixNanos) -> UnixNanos {
+        self.inner.to_venue_ns(local_ts)
+    }
+
+    fn reset(&self) {
+        self.inner.reset();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_no_samples() {
+        let sync = ClockSync::default();
+
+        assert_eq!(sync.offset_ns(), None);
+        assert_eq!(sync.sample_count(), 0);
+        assert_eq!(sync.to_local_ns(1_000), 1_000);
+        assert_eq!(sync.to_venue_ns(1_000), 1_000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_alpha_panics() {
+        let _ = ClockSync::new(0.0, None);
+    }
+
+    #[test]
+    fn test_round_trip_uses_midpoint() {
+        let sync = ClockSync::default();
+
+        // The venue time is taken as of the local midpoint 1_000_250
+        assert!(sync.record_round_trip(1_000_000, 1_500_250, 1_000_500));
+
+        assert_eq!(sync.offset_ns(), Some(500_000));
+        assert_eq!(sync.sample_count(), 1);
+    }
+
+    #[rstest]
+    #[case(1_000, 3_000, false)]
+    #[case(1_000, 2_000, true)]
+    #[case(2_000, 1_000, false)]
+    fn test_round_trip_filtering(
+        #[case] local_send_ns: UnixNanos,
+        #[case] local_recv_ns: UnixNanos,
+        #[case] expected: bool,
+    ) {
+        let sync = ClockSync::new(0.5, Some(Duration::from_nanos(1_000)));
+
+        let result = sync.record_round_trip(local_send_ns, 5_000, local_recv_ns);
+
+        assert_eq!(result, expected);
+        assert_eq!(sync.sample_count(), u64::from(expected));
+    }
+
+    #[test]
+    fn test_ewma() {
+        let sync = ClockSync::new(0.5, None);
+
+        sync.record_heartbeat(1_100, 1_000);
+        sync.record_heartbeat(2_300, 2_000);
+        sync.record_heartbeat(2_700, 3_000);
+
+        // 100, then 100 + (300 - 100) / 2, then 200 + (-300 - 200) / 2
+        assert_eq!(sync.offset_ns(), Some(-50));
+        assert_eq!(sync.sample_count(), 3);
+    }
+
+    #[test]
+    fn test_adjust_timestamps() {
+        let sync = ClockSync::default();
+        sync.record_heartbeat(10_000, 9_000);
+
+        assert_eq!(sync.to_local_ns(20_000), 19_000);
+        assert_eq!(sync.to_venue_ns(19_000), 20_000);
+        assert_eq!(sync.to_venue_ns(UnixNanos::MAX), UnixNanos::MAX);
+
+        sync.reset();
+        sync.record_heartbeat(9_000, 10_000);
+
+        assert_eq!(sync.to_local_ns(500), 1_500);
+        assert_eq!(sync.to_venue_ns(500), 0);
+    }
+
+    #[tokio::test]
+    async fn test_sync_periodically() {
+        let sync = ClockSync::new(1.0, Some(Duration::from_secs(1)));
+        let clock = get_atomic_clock();
+
+        let _ = tokio::time::timeout(
+            Duration::from_millis(50),
+            sync.sync_periodically(Duration::from_millis(10), || async {
+                Some(clock.get_time_ns() + 1_000_000_000)
+            }),
+        )
+        .await;
+
+        let offset = sync.offset_ns().unwrap();
+        assert!(sync.sample_count() >= 2);
+        assert!((offset - 1_000_000_000).abs() < 10_000_000);
+    }
+}
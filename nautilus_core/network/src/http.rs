@@ -13,17 +13,34 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, str::FromStr, sync::Arc, time::Duration};
 
 use hyper::{Body, Client, Method, Request, Response};
 use hyper_tls::HttpsConnector;
-use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
+use pyo3::{
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+    types::PyBytes,
+};
+
+use crate::ratelimiter::{Quota, RateLimiter};
+
+type HttpError = Box<dyn std::error::Error + Send + Sync>;
 
 /// Provides a high-performance HttpClient for HTTP requests.
 ///
 /// The client is backed by a hyper Client which keeps connections alive and
-/// can be cloned cheaply. The client also has a list of header fields to
-/// extract from the response.
+/// can be cloned cheaply. Idle connections are pooled per host, the pool can
+/// be tuned with an idle timeout and a maximum number of idle connections.
+/// The client also has a list of header fields to extract from the response.
+///
+/// Default headers are sent with every request, headers given with a request
+/// take precedence over them. Requests are aborted if they do not complete
+/// within the timeout, which can be set for the client and overridden for
+/// each request.
+///
+/// Requests made from Python can name rate limit keys, the request then
+/// waits until the client's [RateLimiter] has a token for every key.
 ///
 /// The client returns an [HttpResponse]. The client filters only the key value
 /// for the give `header_keys`.
@@ -32,6 +49,9 @@ use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
 pub struct HttpClient {
     client: Client<HttpsConnector<hyper::client::HttpConnector>>,
     header_keys: Vec<String>,
+    default_headers: HashMap<String, String>,
+    timeout: Option<Duration>,
+    rate_limiter: Arc<RateLimiter<String>>,
 }
 
 /// HttpResponse contains relevant data from a HTTP request.
@@ -47,11 +67,12 @@ pub struct HttpResponse {
 
 impl Default for HttpClient {
     fn default() -> Self {
-        let https = HttpsConnector::new();
-        let client = Client::builder().build::<_, hyper::Body>(https);
         Self {
-            client,
+            client: HttpClient::build_client(None, None),
             header_keys: Default::default(),
+            default_headers: Default::default(),
+            timeout: None,
+            rate_limiter: Arc::new(RateLimiter::new(None, vec![])),
         }
     }
 }
@@ -62,124 +83,168 @@ impl HttpResponse {
     fn get_body(&self, py: Python) -> PyResult<Py<PyBytes>> {
         Ok(PyBytes::new(py, &self.body).into())
     }
+
+    /// Returns true if the status code is in the 2xx range.
+    #[getter]
+    fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
 }
 
 #[pymethods]
 impl HttpClient {
     #[new]
-    #[pyo3(signature=(header_keys=[].to_vec()))]
+    #[pyo3(signature=(
+        header_keys=[].to_vec(),
+        default_headers=HashMap::new(),
+        timeout_secs=None,
+        pool_idle_timeout_secs=None,
+        pool_max_idle_per_host=None,
+        keyed_quotas=[].to_vec(),
+        default_quota=None,
+    ))]
     #[must_use]
-    pub fn new(header_keys: Vec<String>) -> Self {
-        let https = HttpsConnector::new();
-        let client = Client::builder().build::<_, hyper::Body>(https);
+    pub fn new(
+        header_keys: Vec<String>,
+        default_headers: HashMap<String, String>,
+        timeout_secs: Option<u64>,
+        pool_idle_timeout_secs: Option<u64>,
+        pool_max_idle_per_host: Option<usize>,
+        keyed_quotas: Vec<(String, Quota)>,
+        default_quota: Option<Quota>,
+    ) -> Self {
+        let client = HttpClient::build_client(
+            pool_idle_timeout_secs.map(Duration::from_secs),
+            pool_max_idle_per_host,
+        );
 
         Self {
             client,
             header_keys,
+            default_headers,
+            timeout: timeout_secs.map(Duration::from_secs),
+            rate_limiter: Arc::new(RateLimiter::new(default_quota, keyed_quotas)),
         }
     }
 
+    #[pyo3(signature = (method_str, url, headers, body=None, timeout_secs=None, keys=None))]
+    #[allow(clippy::too_many_arguments)]
     pub fn request<'py>(
         slf: PyRef<'_, Self>,
         method_str: String,
         url: String,
         headers: HashMap<String, String>,
         body: Option<&'py PyBytes>,
+        timeout_secs: Option<u64>,
+        keys: Option<Vec<String>>,
         py: Python<'py>,
     ) -> PyResult<&'py PyAny> {
         let method: Method = Method::from_str(&method_str.to_uppercase())
-            .unwrap_or_else(|_| panic!("Invalid HTTP method {method_str}"));
-
-        let body_vec = body.map(|py_bytes| py_bytes.as_bytes().to_vec());
-        let client = slf.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            match client.send_request(method, url, headers, body_vec).await {
-                Ok(res) => Ok(res),
-                Err(e) => Err(PyErr::new::<PyException, _>(format!(
-                    "Error handling repsonse: {e}"
-                ))),
-            }
-        })
+            .map_err(|_| PyValueError::new_err(format!("Invalid HTTP method {method_str}")))?;
+        slf.py_request(method, url, headers, body, timeout_secs, keys, py)
     }
 
+    #[pyo3(signature = (url, headers, body=None, timeout_secs=None, keys=None))]
     pub fn get<'py>(
         slf: PyRef<'_, Self>,
         url: String,
         headers: HashMap<String, String>,
         body: Option<&'py PyBytes>,
+        timeout_secs: Option<u64>,
+        keys: Option<Vec<String>>,
         py: Python<'py>,
     ) -> PyResult<&'py PyAny> {
-        let body_vec = body.map(|py_bytes| py_bytes.as_bytes().to_vec());
-        let client = slf.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            match client
-                .send_request(Method::GET, url, headers, body_vec)
-                .await
-            {
-                Ok(res) => Ok(res),
-                Err(e) => Err(PyErr::new::<PyException, _>(format!(
-                    "Error handling repsonse: {e}"
-                ))),
-            }
-        })
+        slf.py_request(Method::GET, url, headers, body, timeout_secs, keys, py)
     }
 
+    #[pyo3(signature = (url, headers, body=None, timeout_secs=None, keys=None))]
     pub fn post<'py>(
         slf: PyRef<'_, Self>,
         url: String,
         headers: HashMap<String, String>,
         body: Option<&'py PyBytes>,
+        timeout_secs: Option<u64>,
+        keys: Option<Vec<String>>,
         py: Python<'py>,
     ) -> PyResult<&'py PyAny> {
-        let body_vec = body.map(|py_bytes| py_bytes.as_bytes().to_vec());
-        let client = slf.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            match client
-                .send_request(Method::POST, url, headers, body_vec)
-                .await
-            {
-                Ok(res) => Ok(res),
-                Err(e) => Err(PyErr::new::<PyException, _>(format!(
-                    "Error handling repsonse: {e}"
-                ))),
-            }
-        })
+        slf.py_request(Method::POST, url, headers, body, timeout_secs, keys, py)
+    }
+
+    #[pyo3(signature = (url, headers, body=None, timeout_secs=None, keys=None))]
+    pub fn put<'py>(
+        slf: PyRef<'_, Self>,
+        url: String,
+        headers: HashMap<String, String>,
+        body: Option<&'py PyBytes>,
+        timeout_secs: Option<u64>,
+        keys: Option<Vec<String>>,
+        py: Python<'py>,
+    ) -> PyResult<&'py PyAny> {
+        slf.py_request(Method::PUT, url, headers, body, timeout_secs, keys, py)
     }
 
+    #[pyo3(signature = (url, headers, body=None, timeout_secs=None, keys=None))]
     pub fn patch<'py>(
         slf: PyRef<'_, Self>,
         url: String,
         headers: HashMap<String, String>,
         body: Option<&'py PyBytes>,
+        timeout_secs: Option<u64>,
+        keys: Option<Vec<String>>,
         py: Python<'py>,
     ) -> PyResult<&'py PyAny> {
-        let body_vec = body.map(|py_bytes| py_bytes.as_bytes().to_vec());
-        let client = slf.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            match client
-                .send_request(Method::PATCH, url, headers, body_vec)
-                .await
-            {
-                Ok(res) => Ok(res),
-                Err(e) => Err(PyErr::new::<PyException, _>(format!(
-                    "Error handling repsonse: {e}"
-                ))),
-            }
-        })
+        slf.py_request(Method::PATCH, url, headers, body, timeout_secs, keys, py)
     }
 
+    #[pyo3(signature = (url, headers, body=None, timeout_secs=None, keys=None))]
     pub fn delete<'py>(
         slf: PyRef<'_, Self>,
         url: String,
         headers: HashMap<String, String>,
         body: Option<&'py PyBytes>,
+        timeout_secs: Option<u64>,
+        keys: Option<Vec<String>>,
+        py: Python<'py>,
+    ) -> PyResult<&'py PyAny> {
+        slf.py_request(Method::DELETE, url, headers, body, timeout_secs, keys, py)
+    }
+}
+
+impl HttpClient {
+    /// Builds the pooled hyper client shared by all clones of an [HttpClient].
+    fn build_client(
+        pool_idle_timeout: Option<Duration>,
+        pool_max_idle_per_host: Option<usize>,
+    ) -> Client<HttpsConnector<hyper::client::HttpConnector>> {
+        let https = HttpsConnector::new();
+        let mut builder = Client::builder();
+        builder.pool_idle_timeout(pool_idle_timeout);
+        if let Some(max_idle) = pool_max_idle_per_host {
+            builder.pool_max_idle_per_host(max_idle);
+        }
+        builder.build::<_, hyper::Body>(https)
+    }
+
+    /// Spawns the request on the tokio runtime and returns a Python awaitable.
+    #[allow(clippy::too_many_arguments)]
+    fn py_request<'py>(
+        &self,
+        method: Method,
+        url: String,
+        headers: HashMap<String, String>,
+        body: Option<&'py PyBytes>,
+        timeout_secs: Option<u64>,
+        keys: Option<Vec<String>>,
         py: Python<'py>,
     ) -> PyResult<&'py PyAny> {
         let body_vec = body.map(|py_bytes| py_bytes.as_bytes().to_vec());
-        let client = slf.clone();
+        let timeout = timeout_secs.map(Duration::from_secs);
+        let keys = keys.unwrap_or_default();
+        let client = self.clone();
         pyo3_asyncio::tokio::future_into_py(py, async move {
+            client.until_keys_ready(&keys).await;
             match client
-                .send_request(Method::DELETE, url, headers, body_vec)
+                .send_request_with_timeout(method, url, headers, body_vec, timeout)
                 .await
             {
                 Ok(res) => Ok(res),
@@ -189,18 +254,47 @@ impl HttpClient {
             }
         })
     }
-}
 
-impl HttpClient {
+    /// Wait until the rate limiter has a token for every key and take them.
+    pub async fn until_keys_ready(&self, keys: &[String]) {
+        self.rate_limiter.until_keys_ready(keys, 1).await;
+    }
+
+    /// Sends a request using the client timeout, if any.
     pub async fn send_request(
         &self,
         method: Method,
         url: String,
         headers: HashMap<String, String>,
         body: Option<Vec<u8>>,
-    ) -> Result<HttpResponse, Box<dyn std::error::Error + Send + Sync>> {
+    ) -> Result<HttpResponse, HttpError> {
+        self.send_request_with_timeout(method, url, headers, body, None)
+            .await
+    }
+
+    /// Sends a request which is aborted after `timeout`, falling back to the
+    /// client timeout when `None`.
+    ///
+    /// The timeout covers both waiting for the response and reading its body.
+    pub async fn send_request_with_timeout(
+        &self,
+        method: Method,
+        url: String,
+        headers: HashMap<String, String>,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<HttpResponse, HttpError> {
         let mut req_builder = Request::builder().method(method).uri(url);
 
+        for (header_name, header_value) in &self.default_headers {
+            if !headers
+                .keys()
+                .any(|key| key.eq_ignore_ascii_case(header_name))
+            {
+                req_builder = req_builder.header(header_name, header_value);
+            }
+        }
+
         for (header_name, header_value) in &headers {
             req_builder = req_builder.header(header_name, header_value);
         }
@@ -211,14 +305,20 @@ impl HttpClient {
             req_builder.body(Body::empty())?
         };
 
-        let res = self.client.request(req).await?;
-        self.to_response(res).await
+        let fut = async {
+            let res = self.client.request(req).await?;
+            self.to_response(res).await
+        };
+
+        match timeout.or(self.timeout) {
+            Some(timeout) => tokio::time::timeout(timeout, fut)
+                .await
+                .map_err(|_| format!("Request timed out after {timeout:?}"))?,
+            None => fut.await,
+        }
     }
 
-    pub async fn to_response(
-        &self,
-        res: Response<Body>,
-    ) -> Result<HttpResponse, Box<dyn std::error::Error + Send + Sync>> {
+    pub async fn to_response(&self, res: Response<Body>) -> Result<HttpResponse, HttpError> {
         let headers: HashMap<String, String> = self
             .header_keys
             .iter()
@@ -261,6 +361,25 @@ mod tests {
                 let response = Response::new(Body::from("hello-world!"));
                 Ok(response)
             }
+            (&Method::GET, "/headers") => {
+                let value = req
+                    .headers()
+                    .get("x-api-key")
+                    .and_then(|val| val.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                let response = Response::builder()
+                    .status(StatusCode::OK)
+                    .header("x-request-id", "abc-123")
+                    .header("x-ignored", "ignored")
+                    .body(Body::from(value))
+                    .unwrap();
+                Ok(response)
+            }
+            (&Method::GET, "/slow") => {
+                tokio::time::sleep(Duration::from_secs(2)).await;
+                Ok(Response::new(Body::empty()))
+            }
             (&Method::POST, "/post") => {
                 let response = Response::builder()
                     .status(StatusCode::OK)
@@ -421,4 +540,112 @@ mod tests {
 
         assert_eq!(response.status, StatusCode::OK);
     }
+
+    #[tokio::test]
+    async fn test_default_headers_and_header_keys() {
+        let (addr, _shutdown_tx) = start_test_server();
+        let url = format!("http://{}:{}", addr.ip(), addr.port());
+
+        let default_headers = HashMap::from([("x-api-key".to_string(), "default".to_string())]);
+        let client = HttpClient::new(
+            vec!["x-request-id".to_string()],
+            default_headers,
+            None,
+            None,
+            None,
+            vec![],
+            None,
+        );
+
+        let response = client
+            .send_request(Method::GET, format!("{url}/headers"), HashMap::new(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(String::from_utf8_lossy(&response.body), "default");
+        assert_eq!(
+            response.headers,
+            HashMap::from([("x-request-id".to_string(), "abc-123".to_string())])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_headers_override_default_headers() {
+        let (addr, _shutdown_tx) = start_test_server();
+        let url = format!("http://{}:{}", addr.ip(), addr.port());
+
+        let default_headers = HashMap::from([("x-api-key".to_string(), "default".to_string())]);
+        let client = HttpClient::new(vec![], default_headers, None, None, None, vec![], None);
+
+        let headers = HashMap::from([("X-API-KEY".to_string(), "override".to_string())]);
+        let response = client
+            .send_request(Method::GET, format!("{url}/headers"), headers, None)
+            .await
+            .unwrap();
+
+        assert_eq!(String::from_utf8_lossy(&response.body), "override");
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout() {
+        let (addr, _shutdown_tx) = start_test_server();
+        let url = format!("http://{}:{}", addr.ip(), addr.port());
+
+        let client = HttpClient::default();
+        let result = client
+            .send_request_with_timeout(
+                Method::GET,
+                format!("{url}/slow"),
+                HashMap::new(),
+                None,
+                Some(Duration::from_millis(100)),
+            )
+            .await;
+
+        assert!(result.unwrap_err().to_string().contains("timed out"));
+    }
+
+    #[tokio::test]
+    async fn test_client_timeout() {
+        let (addr, _shutdown_tx) = start_test_server();
+        let url = format!("http://{}:{}", addr.ip(), addr.port());
+
+        let client = HttpClient::new(vec![], HashMap::new(), Some(1), None, Some(4), vec![], None);
+        let result = client
+            .send_request(Method::GET, format!("{url}/slow"), HashMap::new(), None)
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_requests() {
+        let (addr, _shutdown_tx) = start_test_server();
+        let url = format!("http://{}:{}", addr.ip(), addr.port());
+
+        let quota = Quota::with_period(Duration::from_millis(100));
+        let client = HttpClient::new(
+            vec![],
+            HashMap::new(),
+            None,
+            None,
+            None,
+            vec![("get".to_string(), quota)],
+            None,
+        );
+
+        let keys = vec!["get".to_string()];
+        let start = std::time::Instant::now();
+        for _ in 0..3 {
+            client.until_keys_ready(&keys).await;
+            let response = client
+                .send_request(Method::GET, format!("{url}/get"), HashMap::new(), None)
+                .await
+                .unwrap();
+            assert_eq!(response.status, StatusCode::OK);
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
 }
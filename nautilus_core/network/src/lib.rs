@@ -13,12 +13,17 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod backoff;
+pub mod clocksync;
 pub mod http;
+pub mod ratelimiter;
 pub mod socket;
 pub mod websocket;
 
+use clocksync::PyClockSync;
 use http::{HttpClient, HttpResponse};
 use pyo3::prelude::*;
+use ratelimiter::{PyRateLimiter, Quota};
 use socket::SocketClient;
 use websocket::WebSocketClient;
 
@@ -27,7 +32,10 @@ use websocket::WebSocketClient;
 pub fn network(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<HttpClient>()?;
     m.add_class::<HttpResponse>()?;
+    m.add_class::<Quota>()?;
+    m.add_class::<PyRateLimiter>()?;
     m.add_class::<WebSocketClient>()?;
     m.add_class::<SocketClient>()?;
+    m.add_class::<PyClockSync>()?;
     Ok(())
 }
@@ -0,0 +1,451 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A keyed token-bucket rate limiter.
+//!
+//! Each key (for example an endpoint or an exchange wide weight limit) gets
+//! its own bucket. A request can wait on several keys at once, in which case
+//! tokens are only taken once every bucket can serve the request.
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    slice,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use nautilus_core::correctness;
+use pyo3::prelude::*;
+use rand::Rng;
+use tokio::time::sleep;
+
+/// The rate at which a bucket is replenished and the number of tokens it can hold.
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quota {
+    replenish_interval: Duration,
+    burst: u32,
+}
+
+impl Quota {
+    /// Creates a quota allowing `n` requests per second, all of which may burst.
+    #[must_use]
+    pub fn per_second(n: u32) -> Self {
+        Self::per_period(Duration::from_secs(1), n)
+    }
+
+    /// Creates a quota allowing `n` requests per minute, all of which may burst.
+    #[must_use]
+    pub fn per_minute(n: u32) -> Self {
+        Self::per_period(Duration::from_secs(60), n)
+    }
+
+    /// Creates a quota allowing `n` requests per hour, all of which may burst.
+    #[must_use]
+    pub fn per_hour(n: u32) -> Self {
+        Self::per_period(Duration::from_secs(3600), n)
+    }
+
+    /// Creates a quota replenishing a single token every `period`, with no burst.
+    ///
+    /// # Panics
+    ///
+    /// - If `period` is zero.
+    #[must_use]
+    pub fn with_period(period: Duration) -> Self {
+        correctness::u64_in_range_inclusive(
+            u64::try_from(period.as_nanos()).unwrap_or(u64::MAX),
+            1,
+            u64::MAX,
+            "period",
+        );
+        Self {
+            replenish_interval: period,
+            burst: 1,
+        }
+    }
+
+    /// Returns the quota with the given burst size.
+    ///
+    /// # Panics
+    ///
+    /// - If `burst` is zero.
+    #[must_use]
+    pub fn allow_burst(self, burst: u32) -> Self {
+        correctness::u64_in_range_inclusive(burst.into(), 1, u32::MAX.into(), "burst");
+        Self { burst, ..self }
+    }
+
+    #[must_use]
+    pub fn replenish_interval(&self) -> Duration {
+        self.replenish_interval
+    }
+
+    #[must_use]
+    pub fn burst_size(&self) -> u32 {
+        self.burst
+    }
+
+    fn per_period(period: Duration, n: u32) -> Self {
+        correctness::u64_in_range_inclusive(n.into(), 1, u32::MAX.into(), "n");
+        Self::with_period(period / n).allow_burst(n)
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    quota: Quota,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(quota: Quota, now: Instant) -> Self {
+        Self {
+            quota,
+            tokens: f64::from(quota.burst),
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        let replenished = elapsed.as_secs_f64() / self.quota.replenish_interval.as_secs_f64();
+        self.tokens = (self.tokens + replenished).min(f64::from(self.quota.burst));
+        self.last_refill = now;
+    }
+
+    /// Returns how long to wait until `cost` tokens are available, if at all.
+    ///
+    /// The cost is capped at the burst size so an oversized request waits for
+    /// a full bucket rather than forever.
+    fn wait_time(&self, cost: u32) -> Option<Duration> {
+        let cost = f64::from(cost.min(self.quota.burst));
+        let missing = cost - self.tokens;
+        if missing <= 0.0 {
+            None
+        } else {
+            Some(self.quota.replenish_interval.mul_f64(missing))
+        }
+    }
+
+    fn consume(&mut self, cost: u32) {
+        self.tokens -= f64::from(cost.min(self.quota.burst));
+    }
+}
+
+/// Rate limits requests with a token bucket for every key.
+///
+/// Keys without a quota fall back to the default quota, each key still
+/// getting a bucket of its own. Keys with neither are not limited. A global
+/// limit is expressed by passing a shared key along with every request.
+///
+/// When a request has to wait, a random jitter of up to `jitter` is added to
+/// the delay so concurrent waiters do not all wake at the same instant.
+#[derive(Debug)]
+pub struct RateLimiter<K> {
+    default_quota: Option<Quota>,
+    quotas: HashMap<K, Quota>,
+    buckets: Mutex<HashMap<K, TokenBucket>>,
+    jitter: Duration,
+}
+
+impl<K> RateLimiter<K>
+where
+    K: Hash + Eq + Clone,
+{
+    #[must_use]
+    pub fn new(default_quota: Option<Quota>, keyed_quotas: Vec<(K, Quota)>) -> Self {
+        Self {
+            default_quota,
+            quotas: keyed_quotas.into_iter().collect(),
+            buckets: Mutex::new(HashMap::new()),
+            jitter: Duration::ZERO,
+        }
+    }
+
+    /// Returns the rate limiter with the given maximum jitter added to waits.
+    #[must_use]
+    pub fn with_jitter(self, jitter: Duration) -> Self {
+        Self { jitter, ..self }
+    }
+
+    /// Returns the quota applied to `key`, if any.
+    #[must_use]
+    pub fn quota(&self, key: &K) -> Option<Quota> {
+        self.quotas.get(key).copied().or(self.default_quota)
+    }
+
+    /// Take a single token for `key` if one is available, otherwise return
+    /// the time to wait before retrying.
+    pub fn check_key(&self, key: &K) -> Result<(), Duration> {
+        self.check_keys(slice::from_ref(key), 1)
+    }
+
+    /// Take `cost` tokens from the bucket of every key if all of them can
+    /// serve the request, otherwise return the longest time to wait before
+    /// retrying. No tokens are taken unless every bucket has enough.
+    pub fn check_keys(&self, keys: &[K], cost: u32) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+
+        let mut wait: Option<Duration> = None;
+        for key in keys {
+            if let Some(quota) = self.quota(key) {
+                let bucket = buckets
+                    .entry(key.clone())
+                    .or_insert_with(|| TokenBucket::new(quota, now));
+                bucket.refill(now);
+                if let Some(key_wait) = bucket.wait_time(cost) {
+                    wait = Some(wait.map_or(key_wait, |w| w.max(key_wait)));
+                }
+            }
+        }
+
+        if let Some(wait) = wait {
+            return Err(wait);
+        }
+
+        for key in keys {
+            if let Some(bucket) = buckets.get_mut(key) {
+                bucket.consume(cost);
+            }
+        }
+        Ok(())
+    }
+
+    /// Wait until a single token for `key` is available and take it.
+    pub async fn until_key_ready(&self, key: &K) {
+        self.until_keys_ready(slice::from_ref(key), 1).await;
+    }
+
+    /// Wait until `cost` tokens are available for every key and take them.
+    pub async fn until_keys_ready(&self, keys: &[K], cost: u32) {
+        while let Err(wait) = self.check_keys(keys, cost) {
+            sleep(wait + self.random_jitter()).await;
+        }
+    }
+
+    fn random_jitter(&self) -> Duration {
+        if self.jitter.is_zero() {
+            Duration::ZERO
+        } else {
+            rand::thread_rng().gen_range(Duration::ZERO..=self.jitter)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[pymethods]
+impl Quota {
+    #[staticmethod]
+    #[pyo3(name = "rate_per_second")]
+    fn py_per_second(n: u32) -> Self {
+        Self::per_second(n)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "rate_per_minute")]
+    fn py_per_minute(n: u32) -> Self {
+        Self::per_minute(n)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "rate_per_hour")]
+    fn py_per_hour(n: u32) -> Self {
+        Self::per_hour(n)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "with_period_ms")]
+    fn py_with_period_ms(period_ms: u64) -> Self {
+        Self::with_period(Duration::from_millis(period_ms))
+    }
+
+    #[pyo3(name = "allow_burst")]
+    fn py_allow_burst(&self, burst: u32) -> Self {
+        self.allow_burst(burst)
+    }
+
+    #[getter]
+    #[pyo3(name = "burst_size")]
+    fn py_burst_size(&self) -> u32 {
+        self.burst
+    }
+
+    #[getter]
+    #[pyo3(name = "replenish_interval_ms")]
+    fn py_replenish_interval_ms(&self) -> f64 {
+        self.replenish_interval.as_secs_f64() * 1_000.0
+    }
+}
+
+#[pyclass(name = "RateLimiter")]
+pub struct PyRateLimiter {
+    inner: Arc<RateLimiter<String>>,
+}
+
+#[pymethods]
+impl PyRateLimiter {
+    #[new]
+    #[pyo3(signature = (keyed_quotas=vec![], default_quota=None, jitter_ms=0))]
+    fn py_new(
+        keyed_quotas: Vec<(String, Quota)>,
+        default_quota: Option<Quota>,
+        jitter_ms: u64,
+    ) -> Self {
+        let limiter = RateLimiter::new(default_quota, keyed_quotas)
+            .with_jitter(Duration::from_millis(jitter_ms));
+        Self {
+            inner: Arc::new(limiter),
+        }
+    }
+
+    /// Take `cost` tokens for every key if available.
+    ///
+    /// Returns `None` if the tokens were taken, otherwise the number of
+    /// seconds to wait before retrying.
+    #[pyo3(signature = (keys, cost=1))]
+    fn check(&self, keys: Vec<String>, cost: u32) -> Option<f64> {
+        self.inner
+            .check_keys(&keys, cost)
+            .err()
+            .map(|wait| wait.as_secs_f64())
+    }
+
+    /// Wait until `cost` tokens are available for every key and take them.
+    #[pyo3(signature = (keys, cost=1))]
+    fn until_ready<'py>(
+        &self,
+        keys: Vec<String>,
+        cost: u32,
+        py: Python<'py>,
+    ) -> PyResult<&'py PyAny> {
+        let limiter = self.inner.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            limiter.until_keys_ready(&keys, cost).await;
+            Ok(())
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Quota::per_second(10), Duration::from_millis(100), 10)]
+    #[case(Quota::per_minute(30), Duration::from_secs(2), 30)]
+    #[case(Quota::per_hour(1), Duration::from_secs(3600), 1)]
+    #[case(
+        Quota::with_period(Duration::from_millis(250)),
+        Duration::from_millis(250),
+        1
+    )]
+    #[case(Quota::per_second(2).allow_burst(5), Duration::from_millis(500), 5)]
+    fn test_quota(#[case] quota: Quota, #[case] interval: Duration, #[case] burst: u32) {
+        assert_eq!(quota.replenish_interval(), interval);
+        assert_eq!(quota.burst_size(), burst);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_quota_zero_rate_panics() {
+        let _ = Quota::per_second(0);
+    }
+
+    #[test]
+    fn test_burst_then_limited() {
+        let limiter = RateLimiter::new(None, vec![("orders", Quota::per_second(3))]);
+
+        for _ in 0..3 {
+            assert!(limiter.check_key(&"orders").is_ok());
+        }
+        let wait = limiter.check_key(&"orders").unwrap_err();
+        assert!(wait > Duration::ZERO);
+        assert!(wait <= Duration::from_millis(334));
+    }
+
+    #[test]
+    fn test_keys_without_quota_are_unlimited() {
+        let limiter = RateLimiter::new(None, vec![("orders", Quota::per_second(1))]);
+
+        for _ in 0..100 {
+            assert!(limiter.check_key(&"other").is_ok());
+        }
+    }
+
+    #[test]
+    fn test_default_quota_is_per_key() {
+        let limiter: RateLimiter<&str> = RateLimiter::new(Some(Quota::per_second(1)), vec![]);
+
+        assert!(limiter.check_key(&"a").is_ok());
+        assert!(limiter.check_key(&"b").is_ok());
+        assert!(limiter.check_key(&"a").is_err());
+    }
+
+    #[test]
+    fn test_multiple_keys_take_tokens_only_when_all_ready() {
+        let limiter = RateLimiter::new(
+            None,
+            vec![
+                ("global", Quota::per_second(2)),
+                ("orders", Quota::per_second(1)),
+            ],
+        );
+
+        assert!(limiter.check_keys(&["global", "orders"], 1).is_ok());
+        assert!(limiter.check_keys(&["global", "orders"], 1).is_err());
+
+        // The failed request above must not have taken a global token
+        assert!(limiter.check_key(&"global").is_ok());
+        assert!(limiter.check_key(&"global").is_err());
+    }
+
+    #[test]
+    fn test_weighted_cost() {
+        let limiter = RateLimiter::new(None, vec![("weight", Quota::per_minute(10))]);
+
+        assert!(limiter.check_keys(&["weight"], 6).is_ok());
+        assert!(limiter.check_keys(&["weight"], 5).is_err());
+        assert!(limiter.check_keys(&["weight"], 4).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_until_ready_waits_for_refill() {
+        let limiter = RateLimiter::new(
+            None,
+            vec![("orders", Quota::with_period(Duration::from_millis(50)))],
+        )
+        .with_jitter(Duration::from_millis(5));
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.until_key_ready(&"orders").await;
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+}
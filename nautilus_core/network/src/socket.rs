@@ -13,84 +13,159 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{io, sync::Arc};
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
-use pyo3::{prelude::*, types::PyBytes, PyObject, Python};
+use pyo3::{exceptions::PyException, prelude::*, types::PyBytes, PyObject, Python};
 use tokio::{
-    io::{AsyncReadExt, AsyncWriteExt},
+    io::{AsyncReadExt, AsyncWriteExt, ReadHalf, WriteHalf},
     net::TcpStream,
     sync::Mutex,
     task,
+    time::sleep,
 };
 use tokio_tungstenite::{
     tls::tcp_tls,
     tungstenite::{client::IntoClientRequest, stream::Mode},
     MaybeTlsStream,
 };
-use tracing::debug;
+use tracing::{debug, error};
+
+use crate::backoff::ExponentialBackoff;
+
+type TcpWriter = WriteHalf<MaybeTlsStream<TcpStream>>;
+type SharedTcpWriter = Arc<Mutex<WriteHalf<MaybeTlsStream<TcpStream>>>>;
+type TcpReader = ReadHalf<MaybeTlsStream<TcpStream>>;
+
+/// Drain every complete message ending with `delimiter` from the front of
+/// `buf`, returning them without the delimiter.
+///
+/// Any trailing partial message is left in `buf` to be completed by the
+/// next read.
+pub fn split_messages(buf: &mut Vec<u8>, delimiter: &[u8]) -> Vec<Vec<u8>> {
+    let mut messages = Vec::new();
+    let mut start = 0;
+    let mut pos = 0;
+
+    while pos + delimiter.len() <= buf.len() {
+        if &buf[pos..pos + delimiter.len()] == delimiter {
+            messages.push(buf[start..pos].to_vec());
+            pos += delimiter.len();
+            start = pos;
+        } else {
+            pos += 1;
+        }
+    }
 
-#[pyclass]
-pub struct SocketClient {
-    read_task: task::JoinHandle<io::Result<()>>,
-    inner: Arc<Mutex<MaybeTlsStream<TcpStream>>>,
-    suffix: Box<[u8]>,
+    buf.drain(0..start);
+    messages
 }
 
-impl SocketClient {
+/// SocketClientInner owns the read task of a raw TCP or TLS connection.
+///
+/// The connection is split into read and write halves. The read half is
+/// moved into a tokio task which passes received data to a Python handler,
+/// either split on a delimiter - for line based protocols such as FIX - or
+/// as raw chunks when no delimiter is given, leaving framing of binary
+/// feeds to the handler. The write half is shared so it can be swapped on
+/// reconnect without invalidating the writers held by the outer client.
+struct SocketClientInner {
+    read_task: task::JoinHandle<()>,
+    writer: SharedTcpWriter,
+    url: String,
+    mode: Mode,
+    delimiter: Option<Vec<u8>>,
+    handler: PyObject,
+}
+
+impl SocketClientInner {
     pub async fn connect_url(
         url: &str,
         handler: PyObject,
         mode: Mode,
-        suffix: Vec<u8>,
+        delimiter: Option<Vec<u8>>,
     ) -> io::Result<Self> {
+        let (reader, writer) = SocketClientInner::tls_connect_with_server(url, mode).await?;
+        let writer = Arc::new(Mutex::new(writer));
+
+        // Keep receiving messages from socket pass them as arguments to handler
+        let read_task =
+            SocketClientInner::spawn_read_task(reader, handler.clone(), delimiter.clone());
+
+        Ok(Self {
+            read_task,
+            writer,
+            url: url.to_string(),
+            mode,
+            delimiter,
+            handler,
+        })
+    }
+
+    /// Connects with the server, wrapping the stream with TLS when required.
+    pub async fn tls_connect_with_server(
+        url: &str,
+        mode: Mode,
+    ) -> io::Result<(TcpReader, TcpWriter)> {
         debug!("socket: Connecting to server");
         let stream = TcpStream::connect(url).await?;
 
-        let request = url.into_client_request().unwrap();
+        let request = url
+            .into_client_request()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
         debug!("socket: {:?}", request);
-        let inner = Arc::new(Mutex::new(
-            tcp_tls(&request, mode, stream, None).await.unwrap(),
-        ));
-        let reader = inner.clone();
+        let stream = tcp_tls(&request, mode, stream, None)
+            .await
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
 
-        let suffix_slice = suffix.clone().into_boxed_slice();
+        Ok(tokio::io::split(stream))
+    }
 
-        // Keep receiving messages from socket pass them as arguments to handler
-        let read_task = task::spawn(async move {
+    /// Keep receiving data from socket and pass it as arguments to handler.
+    pub fn spawn_read_task(
+        mut reader: TcpReader,
+        handler: PyObject,
+        delimiter: Option<Vec<u8>>,
+    ) -> task::JoinHandle<()> {
+        task::spawn(async move {
             let mut buf = Vec::new();
 
             loop {
-                let mut locked_reader = reader.lock().await;
-                let bytes = locked_reader.read_buf(&mut buf).await?;
-                drop(locked_reader);
+                let bytes = match reader.read_buf(&mut buf).await {
+                    Ok(bytes) => bytes,
+                    Err(err) => {
+                        error!("socket: Read failed. Terminating. {err}");
+                        break;
+                    }
+                };
                 debug!("socket: Received {bytes} bytes of data");
 
                 // Terminate if 0 bytes have been read
                 // Connection has been terminated or vector buffer is completely
                 if bytes == 0 {
+                    error!("socket: Connection closed by server. Terminating");
                     break;
-                } else {
-                    // While received data has a line break,
-                    // drain and write it to the stream.
-                    while let Some((i, _)) = &buf
-                        .windows(suffix.len())
-                        .enumerate()
-                        .find(|(_, pair)| pair.eq(&suffix))
-                    {
-                        let mut data: Vec<u8> = buf.drain(0..i + suffix.len()).collect();
-                        data.truncate(data.len() - suffix.len());
+                }
 
-                        Python::with_gil(|py| handler.call1(py, (data.as_slice(),))).unwrap();
+                let messages = match delimiter {
+                    Some(ref delimiter) => split_messages(&mut buf, delimiter),
+                    None => vec![std::mem::take(&mut buf)],
+                };
+
+                for data in messages {
+                    if let Err(err) =
+                        Python::with_gil(|py| handler.call1(py, (PyBytes::new(py, &data),)))
+                    {
+                        error!("socket: Call to handler failed: {err}");
                     }
                 }
             }
-            Ok(())
-        });
-
-        Ok(Self {
-            read_task,
-            inner,
-            suffix: suffix_slice,
         })
     }
 
@@ -101,16 +176,39 @@ impl SocketClient {
     /// Closing the connection is an async call which cannot be done by the
     /// drop method so it must be done explicitly.
     pub async fn shutdown(&mut self) {
-        self.read_task.abort();
+        if !self.read_task.is_finished() {
+            self.read_task.abort();
+            debug!("socket: Aborted read task");
+        }
 
-        let mut inner = self.inner.lock().await;
-        inner.shutdown().await.unwrap();
+        let mut writer = self.writer.lock().await;
+        if let Err(err) = writer.shutdown().await {
+            debug!("socket: Connection already closed {err}");
+        }
     }
 
-    pub async fn send_bytes(&mut self, data: &[u8]) {
-        let mut writer = self.inner.lock().await;
-        writer.write_all(data).await.unwrap();
-        writer.write_all(&self.suffix).await.unwrap();
+    /// Reconnect with server.
+    ///
+    /// Make a new connection with server. Use the new read and write halves
+    /// to update the shared writer and the read task.
+    pub async fn reconnect(&mut self) -> io::Result<()> {
+        if !self.read_task.is_finished() {
+            self.read_task.abort();
+        }
+
+        let (reader, new_writer) =
+            SocketClientInner::tls_connect_with_server(&self.url, self.mode).await?;
+        let mut guard = self.writer.lock().await;
+        *guard = new_writer;
+        drop(guard);
+
+        self.read_task = SocketClientInner::spawn_read_task(
+            reader,
+            self.handler.clone(),
+            self.delimiter.clone(),
+        );
+
+        Ok(())
     }
 
     /// Checks if the client is still connected.
@@ -120,69 +218,321 @@ impl SocketClient {
     }
 }
 
+impl Drop for SocketClientInner {
+    fn drop(&mut self) {
+        // Cancel reading task
+        self.read_task.abort();
+    }
+}
+
+/// SocketClient sends and receives data over a raw TCP or TLS connection.
+///
+/// The `suffix` is appended to every message sent. Received data is split on
+/// the `delimiter`, or passed on in raw chunks if there is none. A controller
+/// task reconnects to the server with exponential backoff whenever the
+/// connection drops, calling the `post_reconnection` handler after each
+/// successful reconnect, until the client is disconnected or the reconnect
+/// attempts are exhausted.
+#[pyclass]
+pub struct SocketClient {
+    writer: SharedTcpWriter,
+    controller_task: task::JoinHandle<()>,
+    disconnect_mode: Arc<Mutex<bool>>,
+    retry_count: Arc<AtomicU32>,
+    suffix: Arc<[u8]>,
+}
+
+impl SocketClient {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn connect_url(
+        url: &str,
+        handler: PyObject,
+        mode: Mode,
+        suffix: Vec<u8>,
+        delimiter: Option<Vec<u8>>,
+        backoff: ExponentialBackoff,
+        post_reconnection: Option<PyObject>,
+        post_disconnection: Option<PyObject>,
+    ) -> io::Result<Self> {
+        let delimiter = delimiter.filter(|delimiter| !delimiter.is_empty());
+        let inner = SocketClientInner::connect_url(url, handler, mode, delimiter).await?;
+        let writer = inner.writer.clone();
+        let disconnect_mode = Arc::new(Mutex::new(false));
+        let retry_count = Arc::new(AtomicU32::new(0));
+        let controller_task = SocketClient::spawn_controller_task(
+            inner,
+            backoff,
+            disconnect_mode.clone(),
+            retry_count.clone(),
+            post_reconnection,
+            post_disconnection,
+        );
+
+        Ok(Self {
+            writer,
+            controller_task,
+            disconnect_mode,
+            retry_count,
+            suffix: suffix.into(),
+        })
+    }
+
+    /// Set disconnect mode to true.
+    ///
+    /// Controller task will periodically check the disconnect mode
+    /// and shutdown the client if it is alive
+    pub async fn disconnect_client(&self) {
+        *self.disconnect_mode.lock().await = true;
+    }
+
+    /// Send `data` followed by the message suffix.
+    pub async fn send_bytes(&self, data: &[u8]) -> io::Result<()> {
+        SocketClient::write_message(&self.writer, data, &self.suffix).await
+    }
+
+    /// Checks if the client is still connected or reconnecting.
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        !self.controller_task.is_finished()
+    }
+
+    /// Returns the total number of reconnection attempts made by the client.
+    pub fn retry_count(&self) -> u32 {
+        self.retry_count.load(Ordering::SeqCst)
+    }
+
+    async fn write_message(writer: &SharedTcpWriter, data: &[u8], suffix: &[u8]) -> io::Result<()> {
+        let mut writer = writer.lock().await;
+        writer.write_all(data).await?;
+        writer.write_all(suffix).await
+    }
+
+    /// Keep trying to reconnect, waiting between failed attempts as given
+    /// by the backoff policy.
+    ///
+    /// Returns true once reconnected, or false if the attempts are exhausted
+    /// or the client was asked to disconnect in the meantime.
+    async fn reconnect_with_backoff(
+        inner: &mut SocketClientInner,
+        backoff: &ExponentialBackoff,
+        disconnect_mode: &Mutex<bool>,
+        retry_count: &AtomicU32,
+    ) -> bool {
+        let mut failures = 0;
+        loop {
+            if *disconnect_mode.lock().await || backoff.is_exhausted(failures) {
+                return false;
+            }
+
+            retry_count.fetch_add(1, Ordering::SeqCst);
+            match inner.reconnect().await {
+                Ok(_) => {
+                    debug!("socket: Reconnected successfully");
+                    return true;
+                }
+                Err(err) => {
+                    failures += 1;
+                    if backoff.is_exhausted(failures) {
+                        error!("socket: Reconnect failed after {failures} attempts: {err}");
+                        return false;
+                    }
+                    let delay = backoff.delay(failures);
+                    error!("socket: Reconnect failed {err}, retrying in {delay:?}");
+                    sleep(delay).await;
+                }
+            }
+        }
+    }
+
+    fn spawn_controller_task(
+        mut inner: SocketClientInner,
+        backoff: ExponentialBackoff,
+        disconnect_mode: Arc<Mutex<bool>>,
+        retry_count: Arc<AtomicU32>,
+        post_reconnection: Option<PyObject>,
+        post_disconnection: Option<PyObject>,
+    ) -> task::JoinHandle<()> {
+        task::spawn(async move {
+            let mut disconnect_flag;
+            loop {
+                sleep(Duration::from_millis(100)).await;
+
+                // Check if client needs to disconnect
+                let guard = disconnect_mode.lock().await;
+                disconnect_flag = *guard;
+                drop(guard);
+
+                match (disconnect_flag, inner.is_alive()) {
+                    (false, false) => {
+                        if SocketClient::reconnect_with_backoff(
+                            &mut inner,
+                            &backoff,
+                            &disconnect_mode,
+                            &retry_count,
+                        )
+                        .await
+                        {
+                            // Lets the caller log on or resubscribe on the new connection
+                            call_handler(post_reconnection.as_ref(), "post_reconnection");
+                        } else {
+                            inner.shutdown().await;
+                            call_handler(post_disconnection.as_ref(), "post_disconnection");
+                            break;
+                        }
+                    }
+                    (true, _) => {
+                        debug!("socket: Shutting down inner client");
+                        inner.shutdown().await;
+                        call_handler(post_disconnection.as_ref(), "post_disconnection");
+                        break;
+                    }
+                    _ => (),
+                }
+            }
+        })
+    }
+}
+
+impl Drop for SocketClient {
+    fn drop(&mut self) {
+        // Stop reconnecting, dropping the inner client aborts its read task
+        self.controller_task.abort();
+    }
+}
+
+/// Call an optional Python callback, logging any failure.
+fn call_handler(handler: Option<&PyObject>, name: &str) {
+    if let Some(handler) = handler {
+        Python::with_gil(|py| match handler.call0(py) {
+            Ok(_) => debug!("socket: Called {name} handler"),
+            Err(err) => error!("socket: {name} handler failed because: {err}"),
+        })
+    }
+}
+
 #[pymethods]
 impl SocketClient {
+    /// Create a socket client.
+    ///
+    /// The `suffix` is appended to every message sent. Received data is split
+    /// on the `delimiter`, which defaults to the suffix. Pass an empty
+    /// delimiter to receive raw chunks. Reconnection delays are given in
+    /// milliseconds.
+    ///
+    /// # Safety
+    /// - Throws an Exception if it is unable to connect to the server
     #[staticmethod]
+    #[pyo3(signature = (
+        url,
+        handler,
+        ssl,
+        suffix,
+        delimiter=None,
+        post_reconnection=None,
+        post_disconnection=None,
+        reconnect_delay_initial_ms=500,
+        reconnect_delay_max_ms=30_000,
+        reconnect_backoff_factor=2.0,
+        reconnect_max_attempts=None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
     fn connect(
         url: String,
         handler: PyObject,
         ssl: bool,
         suffix: Py<PyBytes>,
+        delimiter: Option<Py<PyBytes>>,
+        post_reconnection: Option<PyObject>,
+        post_disconnection: Option<PyObject>,
+        reconnect_delay_initial_ms: u64,
+        reconnect_delay_max_ms: u64,
+        reconnect_backoff_factor: f64,
+        reconnect_max_attempts: Option<u32>,
         py: Python<'_>,
     ) -> PyResult<&PyAny> {
         let mode = if ssl { Mode::Tls } else { Mode::Plain };
         let suffix = suffix.as_ref(py).as_bytes().to_vec();
+        let delimiter = match delimiter {
+            Some(delimiter) => delimiter.as_ref(py).as_bytes().to_vec(),
+            None => suffix.clone(),
+        };
+        let backoff = ExponentialBackoff::new(
+            Duration::from_millis(reconnect_delay_initial_ms),
+            Duration::from_millis(reconnect_delay_max_ms),
+            reconnect_backoff_factor,
+            reconnect_max_attempts,
+        );
 
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            Ok(Self::connect_url(&url, handler, mode, suffix)
-                .await
-                .unwrap())
+            Self::connect_url(
+                &url,
+                handler,
+                mode,
+                suffix,
+                Some(delimiter),
+                backoff,
+                post_reconnection,
+                post_disconnection,
+            )
+            .await
+            .map_err(|err| {
+                PyException::new_err(format!(
+                    "Unable to connect to server because of error: {err}"
+                ))
+            })
         })
     }
 
+    /// Send bytes data followed by the message suffix.
+    ///
+    /// # Safety
+    /// - Throws an Exception if it is not able to send data
     fn send<'py>(slf: PyRef<'_, Self>, data: Vec<u8>, py: Python<'py>) -> PyResult<&'py PyAny> {
-        let inner = slf.inner.clone();
+        let writer = slf.writer.clone();
+        let suffix = slf.suffix.clone();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let mut writer = inner.lock().await;
-            writer.write_all(&data).await?;
-            Ok(())
+            SocketClient::write_message(&writer, &data, &suffix)
+                .await
+                .map_err(|err| {
+                    PyException::new_err(format!("Unable to send data because of error: {err}"))
+                })
         })
     }
 
-    /// Closing the client aborts the reading task and shuts down the connection.
+    /// Disconnecting stops reconnection, aborts the reading task and shuts
+    /// down the connection.
     ///
     /// # Safety
     ///
-    /// - The client should not send after being closed
-    /// - The client should be dropped after being closed
-    fn close<'py>(slf: PyRef<'_, Self>, py: Python<'py>) -> PyResult<&'py PyAny> {
-        // cancel reading task
-        slf.read_task.abort();
-
-        // Shut down writer
-        let inner = slf.inner.clone();
+    /// - The client should not send after being disconnected
+    /// - The client should be dropped after being disconnected
+    fn disconnect<'py>(slf: PyRef<'_, Self>, py: Python<'py>) -> PyResult<&'py PyAny> {
+        let disconnect_mode = slf.disconnect_mode.clone();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let mut inner = inner.lock().await;
-            inner.shutdown().await.unwrap();
+            *disconnect_mode.lock().await = true;
             Ok(())
         })
     }
 
+    #[getter]
     fn is_connected(slf: PyRef<'_, Self>) -> bool {
         slf.is_alive()
     }
-}
 
-impl Drop for SocketClient {
-    fn drop(&mut self) {
-        // Cancel reading task
-        self.read_task.abort();
+    /// The total number of reconnection attempts made by the client.
+    #[getter]
+    fn connection_retry_count(slf: PyRef<'_, Self>) -> u32 {
+        slf.retry_count()
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
     use pyo3::{prelude::*, prepare_freethreaded_python};
+    use rstest::rstest;
     use tokio::{
         io::{AsyncReadExt, AsyncWriteExt},
         net::TcpListener,
@@ -193,7 +543,10 @@ mod tests {
     use tracing::debug;
     use tracing_test::traced_test;
 
-    use crate::socket::SocketClient;
+    use crate::{
+        backoff::ExponentialBackoff,
+        socket::{split_messages, SocketClient},
+    };
 
     struct TestServer {
         handle: JoinHandle<()>,
@@ -278,11 +631,15 @@ counter = Counter()",
             (counter, handler)
         });
 
-        let mut client = SocketClient::connect_url(
+        let client = SocketClient::connect_url(
             &format!("127.0.0.1:{}", server.port),
             handler.clone(),
             Mode::Plain,
             b"\r\n".to_vec(),
+            Some(b"\r\n".to_vec()),
+            ExponentialBackoff::default(),
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -292,13 +649,15 @@ counter = Counter()",
 
         // Send messages that increment the count
         for _ in 0..N {
-            client.send_bytes(b"ping".as_slice()).await;
+            client.send_bytes(b"ping".as_slice()).await.unwrap();
         }
 
         sleep(Duration::from_secs(1)).await;
-        // Shutdown client and wait for read task to terminate
-        client.shutdown().await;
+        // Shutdown client and wait for controller task to terminate
+        client.disconnect_client().await;
         server.handle.abort();
+        sleep(Duration::from_millis(500)).await;
+        assert!(!client.is_alive());
 
         let count_value: usize = Python::with_gil(|py| {
             counter
@@ -313,4 +672,98 @@ counter = Counter()",
         // Check count is same as number messages sent
         assert_eq!(count_value, N);
     }
+
+    #[rstest]
+    #[case(b"a\r\nb\r\n".to_vec(), vec![b"a".to_vec(), b"b".to_vec()], b"".to_vec())]
+    #[case(b"a\r\nb\r".to_vec(), vec![b"a".to_vec()], b"b\r".to_vec())]
+    #[case(b"partial".to_vec(), vec![], b"partial".to_vec())]
+    #[case(b"\r\n\r\n".to_vec(), vec![vec![], vec![]], b"".to_vec())]
+    fn test_split_messages(
+        #[case] mut buf: Vec<u8>,
+        #[case] expected: Vec<Vec<u8>>,
+        #[case] remaining: Vec<u8>,
+    ) {
+        let messages = split_messages(&mut buf, b"\r\n");
+
+        assert_eq!(messages, expected);
+        assert_eq!(buf, remaining);
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_reconnects_after_server_closes() {
+        prepare_freethreaded_python();
+
+        // Server greets every connection then closes it
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = TcpListener::local_addr(&server).unwrap().port();
+        let handle = task::spawn(async move {
+            loop {
+                let (mut stream, _) = server.accept().await.unwrap();
+                stream.write_all(b"hello\x01").await.unwrap();
+                sleep(Duration::from_millis(100)).await;
+                drop(stream);
+            }
+        });
+
+        let (store, handler, post_reconnection) = Python::with_gil(|py| {
+            let pymod = PyModule::from_code(
+                py,
+                r"
+class Store:
+    def __init__(self):
+        self.messages = []
+        self.reconnects = 0
+
+    def handler(self, data):
+        self.messages.append(data)
+
+    def reconnected(self):
+        self.reconnects = self.reconnects + 1
+
+store = Store()",
+                "",
+                "",
+            )
+            .unwrap();
+
+            let store = pymod.getattr("store").unwrap().into_py(py);
+            let handler = store.getattr(py, "handler").unwrap().into_py(py);
+            let post_reconnection = store.getattr(py, "reconnected").unwrap().into_py(py);
+
+            (store, handler, post_reconnection)
+        });
+
+        let client = SocketClient::connect_url(
+            &format!("127.0.0.1:{port}"),
+            handler,
+            Mode::Plain,
+            b"\x01".to_vec(),
+            Some(b"\x01".to_vec()),
+            ExponentialBackoff::default(),
+            Some(post_reconnection),
+            None,
+        )
+        .await
+        .unwrap();
+
+        sleep(Duration::from_secs(1)).await;
+        let (messages, reconnects): (Vec<Vec<u8>>, usize) = Python::with_gil(|py| {
+            let messages = store.getattr(py, "messages").unwrap().extract(py).unwrap();
+            let reconnects = store
+                .getattr(py, "reconnects")
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            (messages, reconnects)
+        });
+
+        assert!(reconnects >= 1);
+        assert!(client.retry_count() >= 1);
+        assert!(messages.len() >= 2);
+        assert!(messages.iter().all(|msg| msg == b"hello"));
+
+        client.disconnect_client().await;
+        handle.abort();
+    }
 }
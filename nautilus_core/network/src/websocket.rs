@@ -13,7 +13,13 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
 use futures_util::{
     stream::{SplitSink, SplitStream},
@@ -28,10 +34,38 @@ use tokio_tungstenite::{
 };
 use tracing::{debug, error};
 
+use crate::backoff::ExponentialBackoff;
+
 type MessageWriter = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
 type SharedMessageWriter =
     Arc<Mutex<SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>>>;
 type MessageReader = SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>;
+type LastReceived = Arc<std::sync::Mutex<Instant>>;
+
+/// Heartbeat settings for keeping the connection alive.
+///
+/// A heartbeat is sent every `interval` seconds, either as a ping frame or
+/// as a text `message` for servers which expect an application level ping.
+/// If `timeout` is set and nothing - including pong frames - has been
+/// received from the server for that many seconds, the connection is
+/// treated as dead and the client reconnects.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeartbeatConfig {
+    pub interval: u64,
+    pub message: Option<String>,
+    pub timeout: Option<u64>,
+}
+
+impl HeartbeatConfig {
+    #[must_use]
+    pub fn new(interval: u64, message: Option<String>, timeout: Option<u64>) -> Self {
+        Self {
+            interval,
+            message,
+            timeout,
+        }
+    }
+}
 
 /// WebSocketClient connects to a websocket server to read and send messages.
 ///
@@ -54,7 +88,8 @@ struct WebSocketClientInner {
     writer: SharedMessageWriter,
     url: String,
     handler: PyObject,
-    heartbeat: Option<u64>,
+    heartbeat: Option<HeartbeatConfig>,
+    last_received: LastReceived,
 }
 
 impl WebSocketClientInner {
@@ -62,16 +97,22 @@ impl WebSocketClientInner {
     pub async fn connect_url(
         url: &str,
         handler: PyObject,
-        heartbeat: Option<u64>,
+        heartbeat: Option<HeartbeatConfig>,
     ) -> Result<Self, Error> {
         let (writer, reader) = WebSocketClientInner::connect_with_server(url).await?;
         let writer = Arc::new(Mutex::new(writer));
         let handler_clone = handler.clone();
+        let last_received = Arc::new(std::sync::Mutex::new(Instant::now()));
 
         // Keep receiving messages from socket and pass them as arguments to handler
-        let read_task = WebSocketClientInner::spawn_read_task(reader, handler);
+        let read_task =
+            WebSocketClientInner::spawn_read_task(reader, handler, last_received.clone());
 
-        let heartbeat_task = WebSocketClientInner::spawn_heartbeat_task(heartbeat, writer.clone());
+        let heartbeat_task = WebSocketClientInner::spawn_heartbeat_task(
+            heartbeat.clone(),
+            writer.clone(),
+            last_received.clone(),
+        );
 
         Ok(Self {
             read_task,
@@ -80,6 +121,7 @@ impl WebSocketClientInner {
             url: url.to_string(),
             handler: handler_clone,
             heartbeat,
+            last_received,
         })
     }
 
@@ -90,18 +132,37 @@ impl WebSocketClientInner {
     }
 
     /// Optionally spawn a hearbeat task to periodically ping the server.
+    ///
+    /// When a timeout is configured the task finishes once the server has
+    /// been silent for longer than the timeout, which marks the connection
+    /// as dead.
     pub fn spawn_heartbeat_task(
-        heartbeat: Option<u64>,
+        heartbeat: Option<HeartbeatConfig>,
         writer: SharedMessageWriter,
+        last_received: LastReceived,
     ) -> Option<task::JoinHandle<()>> {
-        heartbeat.map(|duration| {
+        heartbeat.map(|config| {
             task::spawn(async move {
-                let duration = Duration::from_secs(duration);
+                let interval = Duration::from_secs(config.interval);
+                let timeout = config.timeout.map(Duration::from_secs);
                 loop {
-                    sleep(duration).await;
+                    sleep(interval).await;
+
+                    if let Some(timeout) = timeout {
+                        let silence = last_received.lock().unwrap().elapsed();
+                        if silence > timeout {
+                            error!("No message received for {silence:?}. Terminating");
+                            break;
+                        }
+                    }
+
                     debug!("Sending heartbeat");
+                    let msg = match config.message {
+                        Some(ref text) => Message::Text(text.clone()),
+                        None => Message::Ping(vec![]),
+                    };
                     let mut guard = writer.lock().await;
-                    match guard.send(Message::Ping(vec![])).await {
+                    match guard.send(msg).await {
                         Ok(_) => debug!("Sent heartbeat"),
                         Err(err) => error!("Failed to send heartbeat: {}", err),
                     }
@@ -111,11 +172,20 @@ impl WebSocketClientInner {
     }
 
     /// Keep receiving messages from socket and pass them as arguments to handler.
-    pub fn spawn_read_task(mut reader: MessageReader, handler: PyObject) -> task::JoinHandle<()> {
+    pub fn spawn_read_task(
+        mut reader: MessageReader,
+        handler: PyObject,
+        last_received: LastReceived,
+    ) -> task::JoinHandle<()> {
         task::spawn(async move {
             loop {
                 debug!("Receiving message");
-                match reader.next().await {
+                let msg = reader.next().await;
+                if let Some(Ok(_)) = msg {
+                    *last_received.lock().unwrap() = Instant::now();
+                }
+
+                match msg {
                     Some(Ok(Message::Binary(data))) => {
                         debug!("Received binary message");
                         if let Err(err) =
@@ -134,6 +204,7 @@ impl WebSocketClientInner {
                             break;
                         }
                     }
+                    Some(Ok(Message::Pong(_))) => debug!("Received pong"),
                     Some(Ok(Message::Close(_))) => {
                         error!("Received close message. Terminating.");
                         break;
@@ -154,15 +225,8 @@ impl WebSocketClientInner {
         })
     }
 
-    /// Shutdown read and hearbeat task and the connection.
-    ///
-    /// The client must be explicitly shutdown before dropping otherwise
-    /// the connection might still be alive for some time before terminating.
-    /// Closing the connection is an async call which cannot be done by the
-    /// drop method so it must be done explicitly.
-    pub async fn shutdown(&mut self) {
-        debug!("Closing connection");
-
+    /// Abort the read and heartbeat tasks if they are still running.
+    fn abort_tasks(&mut self) {
         if !self.read_task.is_finished() {
             self.read_task.abort();
             debug!("Aborted message read task");
@@ -175,26 +239,50 @@ impl WebSocketClientInner {
                 handle.abort();
             }
         }
+    }
+
+    /// Shutdown read and hearbeat task and the connection.
+    ///
+    /// The client must be explicitly shutdown before dropping otherwise
+    /// the connection might still be alive for some time before terminating.
+    /// Closing the connection is an async call which cannot be done by the
+    /// drop method so it must be done explicitly.
+    pub async fn shutdown(&mut self) {
+        debug!("Closing connection");
+        self.abort_tasks();
 
         debug!("Closing writer");
         let mut write_half = self.writer.lock().await;
-        write_half.close().await.unwrap();
+        if let Err(err) = write_half.close().await {
+            debug!("Connection already closed {err}");
+        }
         debug!("Closed connection");
     }
 
     /// Reconnect with server
     ///
     /// Make a new connection with server. Use the new read and write halves
-    /// to update self writer and read and heartbeat tasks.
+    /// to update self writer and read and heartbeat tasks. Any tasks still
+    /// running for the previous connection are aborted first.
     pub async fn reconnect(&mut self) -> Result<(), Error> {
+        self.abort_tasks();
         let (new_writer, reader) = WebSocketClientInner::connect_with_server(&self.url).await?;
+
         let mut guard = self.writer.lock().await;
         *guard = new_writer;
         drop(guard);
 
-        self.read_task = WebSocketClientInner::spawn_read_task(reader, self.handler.clone());
-        self.heartbeat_task =
-            WebSocketClientInner::spawn_heartbeat_task(self.heartbeat, self.writer.clone());
+        *self.last_received.lock().unwrap() = Instant::now();
+        self.read_task = WebSocketClientInner::spawn_read_task(
+            reader,
+            self.handler.clone(),
+            self.last_received.clone(),
+        );
+        self.heartbeat_task = WebSocketClientInner::spawn_heartbeat_task(
+            self.heartbeat.clone(),
+            self.writer.clone(),
+            self.last_received.clone(),
+        );
 
         Ok(())
     }
@@ -206,23 +294,21 @@ impl WebSocketClientInner {
     /// shutdown or will receive a `Close` frame which will finish it. There
     /// might be some delay between the connection being closed and the client
     /// detecting.
+    ///
+    /// If a heartbeat timeout is configured, the connection is also considered
+    /// dead once the heartbeat task has finished because the server went silent.
     pub fn is_alive(&self) -> bool {
-        !self.read_task.is_finished()
+        let heartbeat_alive = self
+            .heartbeat_task
+            .as_ref()
+            .map_or(true, |handle| !handle.is_finished());
+        !self.read_task.is_finished() && heartbeat_alive
     }
 }
 
 impl Drop for WebSocketClientInner {
     fn drop(&mut self) {
-        if !self.read_task.is_finished() {
-            self.read_task.abort();
-        }
-
-        // Cancel heart beat task
-        if let Some(ref handle) = self.heartbeat_task.take() {
-            if !handle.is_finished() {
-                handle.abort();
-            }
-        }
+        self.abort_tasks();
     }
 }
 
@@ -231,6 +317,7 @@ pub struct WebSocketClient {
     writer: SharedMessageWriter,
     controller_task: task::JoinHandle<()>,
     disconnect_mode: Arc<Mutex<bool>>,
+    retry_count: Arc<AtomicU32>,
 }
 
 impl WebSocketClient {
@@ -241,7 +328,8 @@ impl WebSocketClient {
     pub async fn connect_client(
         url: &str,
         handler: PyObject,
-        heartbeat: Option<u64>,
+        heartbeat: Option<HeartbeatConfig>,
+        backoff: ExponentialBackoff,
         post_connection: Option<PyObject>,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
@@ -249,24 +337,23 @@ impl WebSocketClient {
         let inner = WebSocketClientInner::connect_url(url, handler, heartbeat).await?;
         let writer = inner.writer.clone();
         let disconnect_mode = Arc::new(Mutex::new(false));
+        let retry_count = Arc::new(AtomicU32::new(0));
         let controller_task = WebSocketClient::spawn_controller_task(
             inner,
+            backoff,
             disconnect_mode.clone(),
+            retry_count.clone(),
             post_reconnection,
             post_disconnection,
         );
 
-        if let Some(handler) = post_connection {
-            Python::with_gil(|py| match handler.call0(py) {
-                Ok(_) => debug!("Called post_connection handler"),
-                Err(err) => error!("post_connection handler failed because: {}", err),
-            })
-        }
+        call_handler(post_connection.as_ref(), "post_connection");
 
         Ok(Self {
             writer,
             controller_task,
             disconnect_mode,
+            retry_count,
         })
     }
 
@@ -287,6 +374,11 @@ impl WebSocketClient {
         self.controller_task.is_finished()
     }
 
+    /// Returns the total number of reconnection attempts made by the client.
+    pub fn retry_count(&self) -> u32 {
+        self.retry_count.load(Ordering::SeqCst)
+    }
+
     pub async fn send_close_message(&self) {
         let mut guard = self.writer.lock().await;
         match guard.send(Message::Close(None)).await {
@@ -295,9 +387,48 @@ impl WebSocketClient {
         }
     }
 
+    /// Keep trying to reconnect, waiting between failed attempts as given
+    /// by the backoff policy.
+    ///
+    /// Returns true once reconnected, or false if the attempts are exhausted
+    /// or the client was asked to disconnect in the meantime.
+    async fn reconnect_with_backoff(
+        inner: &mut WebSocketClientInner,
+        backoff: &ExponentialBackoff,
+        disconnect_mode: &Mutex<bool>,
+        retry_count: &AtomicU32,
+    ) -> bool {
+        let mut failures = 0;
+        loop {
+            if *disconnect_mode.lock().await || backoff.is_exhausted(failures) {
+                return false;
+            }
+
+            retry_count.fetch_add(1, Ordering::SeqCst);
+            match inner.reconnect().await {
+                Ok(_) => {
+                    debug!("Reconnected successfully");
+                    return true;
+                }
+                Err(err) => {
+                    failures += 1;
+                    if backoff.is_exhausted(failures) {
+                        error!("Reconnect failed after {failures} attempts: {err}");
+                        return false;
+                    }
+                    let delay = backoff.delay(failures);
+                    error!("Reconnect failed {err}, retrying in {delay:?}");
+                    sleep(delay).await;
+                }
+            }
+        }
+    }
+
     fn spawn_controller_task(
         mut inner: WebSocketClientInner,
+        backoff: ExponentialBackoff,
         disconnect_mode: Arc<Mutex<bool>>,
+        retry_count: Arc<AtomicU32>,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
     ) -> task::JoinHandle<()> {
@@ -312,34 +443,27 @@ impl WebSocketClient {
                 drop(guard);
 
                 match (disconnect_flag, inner.is_alive()) {
-                    (false, false) => match inner.reconnect().await {
-                        Ok(_) => {
-                            debug!("Reconnected successfully");
-                            if let Some(ref handler) = post_reconnection {
-                                Python::with_gil(|py| match handler.call0(py) {
-                                    Ok(_) => debug!("Called post_reconnection handler"),
-                                    Err(err) => {
-                                        error!("post_reconnection handler failed because: {}", err)
-                                    }
-                                })
-                            }
-                        }
-                        Err(err) => {
-                            error!("Reconnect failed {}", err);
+                    (false, false) => {
+                        if WebSocketClient::reconnect_with_backoff(
+                            &mut inner,
+                            &backoff,
+                            &disconnect_mode,
+                            &retry_count,
+                        )
+                        .await
+                        {
+                            // Lets the caller resubscribe on the new connection
+                            call_handler(post_reconnection.as_ref(), "post_reconnection");
+                        } else {
+                            inner.shutdown().await;
+                            call_handler(post_disconnection.as_ref(), "post_disconnection");
                             break;
                         }
-                    },
+                    }
                     (true, true) => {
                         debug!("Shutting down inner client");
                         inner.shutdown().await;
-                        if let Some(ref handler) = post_disconnection {
-                            Python::with_gil(|py| match handler.call0(py) {
-                                Ok(_) => debug!("Called post_reconnection handler"),
-                                Err(err) => {
-                                    error!("post_reconnection handler failed because: {}", err)
-                                }
-                            })
-                        }
+                        call_handler(post_disconnection.as_ref(), "post_disconnection");
                         break;
                     }
                     (true, false) => break,
@@ -350,13 +474,43 @@ impl WebSocketClient {
     }
 }
 
+/// Call an optional Python callback, logging any failure.
+fn call_handler(handler: Option<&PyObject>, name: &str) {
+    if let Some(handler) = handler {
+        Python::with_gil(|py| match handler.call0(py) {
+            Ok(_) => debug!("Called {name} handler"),
+            Err(err) => error!("{name} handler failed because: {err}"),
+        })
+    }
+}
+
 #[pymethods]
 impl WebSocketClient {
     /// Create a websocket client.
     ///
+    /// The `heartbeat` interval and `pong_timeout` are given in seconds and
+    /// the reconnection delays in milliseconds. The `post_reconnection`
+    /// handler is called after every successful reconnect, which is where
+    /// subscriptions should be restored.
+    ///
     /// # Safety
     /// - Throws an Exception if it is unable to make websocket connection
     #[staticmethod]
+    #[pyo3(signature = (
+        url,
+        handler,
+        heartbeat=None,
+        post_connection=None,
+        post_reconnection=None,
+        post_disconnection=None,
+        heartbeat_msg=None,
+        pong_timeout=None,
+        reconnect_delay_initial_ms=500,
+        reconnect_delay_max_ms=30_000,
+        reconnect_backoff_factor=2.0,
+        reconnect_max_attempts=None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
     fn connect(
         url: String,
         handler: PyObject,
@@ -364,13 +518,28 @@ impl WebSocketClient {
         post_connection: Option<PyObject>,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
+        heartbeat_msg: Option<String>,
+        pong_timeout: Option<u64>,
+        reconnect_delay_initial_ms: u64,
+        reconnect_delay_max_ms: u64,
+        reconnect_backoff_factor: f64,
+        reconnect_max_attempts: Option<u32>,
         py: Python<'_>,
     ) -> PyResult<&PyAny> {
+        let heartbeat =
+            heartbeat.map(|interval| HeartbeatConfig::new(interval, heartbeat_msg, pong_timeout));
+        let backoff = ExponentialBackoff::new(
+            Duration::from_millis(reconnect_delay_initial_ms),
+            Duration::from_millis(reconnect_delay_max_ms),
+            reconnect_backoff_factor,
+            reconnect_max_attempts,
+        );
         pyo3_asyncio::tokio::future_into_py(py, async move {
             WebSocketClient::connect_client(
                 &url,
                 handler,
                 heartbeat,
+                backoff,
                 post_connection,
                 post_reconnection,
                 post_disconnection,
@@ -399,6 +568,20 @@ impl WebSocketClient {
         })
     }
 
+    /// Send text data to the connection.
+    ///
+    /// # Safety
+    /// - Throws an Exception if it is not able to send data
+    fn send_text<'py>(slf: PyRef<'_, Self>, data: String, py: Python<'py>) -> PyResult<&'py PyAny> {
+        let writer = slf.writer.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let mut guard = writer.lock().await;
+            guard.send(Message::Text(data)).await.map_err(|err| {
+                PyException::new_err(format!("Unable to send data because of error: {}", err))
+            })
+        })
+    }
+
     /// Closes the client heart beat and reader task.
     ///
     /// The connection is not completely closed the till all references
@@ -419,8 +602,8 @@ impl WebSocketClient {
     /// Check if the client is still alive.
     ///
     /// Even if the connection is disconnected the client will still be alive
-    /// and try to reconnect. Only when reconnect fails the client will
-    /// terminate.
+    /// and try to reconnect. Only when the reconnection attempts are exhausted
+    /// will the client terminate.
     ///
     /// This is particularly useful for check why a `send` failed. It could
     /// because the connection disconnected and the client is still alive
@@ -430,6 +613,12 @@ impl WebSocketClient {
     fn is_alive(slf: PyRef<'_, Self>) -> bool {
         !slf.controller_task.is_finished()
     }
+
+    /// The total number of reconnection attempts made by the client.
+    #[getter]
+    fn connection_retry_count(slf: PyRef<'_, Self>) -> u32 {
+        slf.retry_count()
+    }
 }
 
 #[cfg(test)]
@@ -445,7 +634,10 @@ mod tests {
     use tracing::debug;
     use tracing_test::traced_test;
 
-    use crate::websocket::WebSocketClient;
+    use crate::{
+        backoff::ExponentialBackoff,
+        websocket::{HeartbeatConfig, WebSocketClient},
+    };
 
     struct TestServer {
         task: JoinHandle<()>,
@@ -491,6 +683,121 @@ mod tests {
         }
     }
 
+    /// Accepts connections but never reads from them, so pings are never
+    /// answered and no messages are ever sent back.
+    async fn setup_silent_server() -> (JoinHandle<()>, u16) {
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = TcpListener::local_addr(&server).unwrap().port();
+
+        let task = task::spawn(async move {
+            let mut connections = Vec::new();
+            loop {
+                let (conn, _) = server.accept().await.unwrap();
+                connections.push(accept_async(conn).await.unwrap());
+            }
+        });
+
+        (task, port)
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_heartbeat_timeout_reconnects() {
+        prepare_freethreaded_python();
+
+        let (server, port) = setup_silent_server().await;
+
+        let (counter, handler, post_reconnection) = Python::with_gil(|py| {
+            let pymod = PyModule::from_code(
+                py,
+                r"
+class Counter:
+    def __init__(self):
+        self.count = 0
+
+    def handler(self, bytes):
+        pass
+
+    def reconnected(self):
+        self.count = self.count + 1
+
+counter = Counter()",
+                "",
+                "",
+            )
+            .unwrap();
+
+            let counter = pymod.getattr("counter").unwrap().into_py(py);
+            let handler = counter.getattr(py, "handler").unwrap().into_py(py);
+            let post_reconnection = counter.getattr(py, "reconnected").unwrap().into_py(py);
+
+            (counter, handler, post_reconnection)
+        });
+
+        let client = WebSocketClient::connect_client(
+            &format!("ws://127.0.0.1:{port}"),
+            handler,
+            Some(HeartbeatConfig::new(1, None, Some(1))),
+            ExponentialBackoff::default(),
+            None,
+            Some(post_reconnection),
+            None,
+        )
+        .await
+        .unwrap();
+
+        // Server never responds so the heartbeat times out and the client reconnects
+        sleep(Duration::from_secs(4)).await;
+        let reconnects: usize =
+            Python::with_gil(|py| counter.getattr(py, "count").unwrap().extract(py).unwrap());
+        assert!(reconnects >= 1);
+        assert!(client.retry_count() >= 1);
+        assert!(!client.is_disconnected());
+
+        client.disconnect_client().await;
+        sleep(Duration::from_secs(2)).await;
+        assert!(client.is_disconnected());
+        server.abort();
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_gives_up_after_max_attempts() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::setup().await;
+        let handler = Python::with_gil(|py| {
+            let pymod = PyModule::from_code(py, "def handler(bytes):\n    pass", "", "").unwrap();
+            pymod.getattr("handler").unwrap().into_py(py)
+        });
+
+        let backoff = ExponentialBackoff::new(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            2.0,
+            Some(2),
+        );
+        let client = WebSocketClient::connect_client(
+            &format!("ws://127.0.0.1:{}", server.port),
+            handler,
+            None,
+            backoff,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        // Take the server down then close the connection so reconnects fail
+        drop(server);
+        client.send_close_message().await;
+
+        sleep(Duration::from_secs(3)).await;
+        assert!(client.is_disconnected());
+        assert_eq!(client.retry_count(), 2);
+    }
+
     #[tokio::test]
     #[traced_test]
     async fn basic_client_test() {
@@ -534,6 +841,7 @@ counter = Counter()",
             &format!("ws://127.0.0.1:{}", server.port),
             handler.clone(),
             None,
+            ExponentialBackoff::default(),
             None,
             None,
             None,
@@ -0,0 +1,84 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr};
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use nautilus_model::{
+    data::tick::QuoteTick,
+    identifiers::instrument_id::InstrumentId,
+    types::{price::Price, quantity::Quantity},
+};
+use nautilus_persistence::parquet::{
+    reader::ParquetReader,
+    writer::{ParquetWriter, ParquetWriterConfig},
+    ParquetType,
+};
+
+const NUM_QUOTES: u64 = 100_000;
+const CHUNK_SIZE: usize = 5000;
+
+/// Returns an in-memory Parquet file of synthetic quotes, so the benchmark needs no data files.
+fn quotes_buffer() -> Vec<u8> {
+    let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+    let quotes: Vec<QuoteTick> = (0..NUM_QUOTES)
+        .map(|i| QuoteTick {
+            instrument_id: instrument_id.clone(),
+            bid: Price::from_raw(100_000 + (i % 100) as i64, 5),
+            ask: Price::from_raw(100_002 + (i % 100) as i64, 5),
+            bid_size: Quantity::from_raw(100_000 + i, 0),
+            ask_size: Quantity::from_raw(100_000 + i, 0),
+            ts_event: i,
+            ts_init: i,
+        })
+        .collect();
+
+    let metadata = HashMap::from([
+        ("instrument_id".to_string(), instrument_id.to_string()),
+        ("price_precision".to_string(), "5".to_string()),
+        ("size_precision".to_string(), "0".to_string()),
+    ]);
+    let mut buffer = Vec::new();
+    let mut writer =
+        ParquetWriter::<QuoteTick, _>::new(&mut buffer, metadata, ParquetWriterConfig::default())
+            .unwrap();
+    writer.write(&quotes).unwrap();
+    writer.close().unwrap();
+    buffer
+}
+
+fn parquet_decode_bench(c: &mut Criterion) {
+    let buffer = quotes_buffer();
+    let mut group = c.benchmark_group("parquet_decode");
+    group.sample_size(20);
+    group.throughput(Throughput::Elements(NUM_QUOTES));
+
+    group.bench_function("quote_ticks", |b| {
+        b.iter_batched(
+            || buffer.clone(),
+            |buffer| {
+                let reader =
+                    ParquetReader::from_buffer(buffer, ParquetType::QuoteTick, CHUNK_SIZE).unwrap();
+                let count: usize = reader.map(|chunk| chunk.unwrap().len()).sum();
+                assert_eq!(count, NUM_QUOTES as usize);
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parquet_decode_bench);
+criterion_main!(benches);
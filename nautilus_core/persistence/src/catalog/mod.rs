@@ -0,0 +1,525 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod write;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use arrow::pyarrow::PyArrowConvert;
+use datafusion::{
+    arrow::record_batch::RecordBatch, error::Result, physical_plan::SendableRecordBatchStream,
+    prelude::*,
+};
+use futures::executor::block_on;
+use nautilus_model::data::bar::BarType;
+use pyo3::{
+    exceptions::{PyRuntimeError, PyValueError},
+    prelude::*,
+};
+use pyo3_asyncio::tokio::get_runtime;
+
+use self::write::WriteMode;
+use crate::{
+    parquet::{writer::ParquetWriterConfig, ParquetType},
+    resample::ResampleWindow,
+};
+
+/// Characters which are illegal in Windows paths, replaced when partitioning by key.
+const INVALID_WINDOWS_CHARS: &str = r#"<>:"/\|?* "#;
+
+/// Represents the filters and projection of a catalog query.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CatalogQuery {
+    /// The instrument IDs to query, all instruments are queried if empty.
+    pub instrument_ids: Vec<String>,
+    /// The inclusive lower bound on `ts_init` (UNIX nanoseconds).
+    pub start: Option<u64>,
+    /// The inclusive upper bound on `ts_init` (UNIX nanoseconds).
+    pub end: Option<u64>,
+    /// The columns to return, all columns are returned if `None`.
+    pub columns: Option<Vec<String>>,
+}
+
+impl CatalogQuery {
+    /// Returns the `ts_init` filter expression for the time range, if bounded.
+    #[must_use]
+    pub fn time_filter(&self) -> Option<Expr> {
+        let start = self.start.map(|start| col("ts_init").gt_eq(lit(start)));
+        let end = self.end.map(|end| col("ts_init").lt_eq(lit(end)));
+        match (start, end) {
+            (Some(start), Some(end)) => Some(start.and(end)),
+            (start, end) => start.or(end),
+        }
+    }
+}
+
+/// Provides a query engine over a Parquet data catalog, backed by DataFusion.
+///
+/// The catalog follows the same layout as the Python `ParquetDataCatalog`, with
+/// each data type under `{path}/data/{type}.parquet`, partitioned by
+/// `instrument_id={id}`. Time range filters are pushed down to the Parquet
+/// scan, so row groups outside the range are skipped using column statistics.
+pub struct DataCatalog {
+    path: PathBuf,
+    session_ctx: SessionContext,
+}
+
+impl DataCatalog {
+    #[must_use]
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        let config = SessionConfig::new()
+            .with_parquet_pruning(true)
+            .set_bool("datafusion.execution.parquet.pushdown_filters", true)
+            .set_bool("datafusion.execution.parquet.reorder_filters", true);
+
+        Self {
+            path: path.as_ref().to_path_buf(),
+            session_ctx: SessionContext::with_config(config),
+        }
+    }
+
+    /// Returns the root path of the catalog.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the directory for the given `parquet_type`, and `instrument_id`
+    /// partition if specified.
+    #[must_use]
+    pub fn make_path(&self, parquet_type: ParquetType, instrument_id: Option<&str>) -> PathBuf {
+        let mut path = self
+            .path
+            .join("data")
+            .join(format!("{}.parquet", parquet_type.filename()));
+        if let Some(instrument_id) = instrument_id {
+            path.push(format!("instrument_id={}", clean_key(instrument_id)));
+        }
+        path
+    }
+
+    /// Builds the data frame for a single `path`, with the query filters,
+    /// ordering and projection applied.
+    async fn query_path(&self, path: &Path, query: &CatalogQuery) -> Result<DataFrame> {
+        let parquet_options = ParquetReadOptions::<'_> {
+            skip_metadata: Some(false),
+            ..Default::default()
+        };
+        let mut df = self
+            .session_ctx
+            .read_parquet(path.to_string_lossy().as_ref(), parquet_options)
+            .await?;
+
+        if let Some(filter) = query.time_filter() {
+            df = df.filter(filter)?;
+        }
+        df = df.sort(vec![col("ts_init").sort(true, true)])?;
+        if let Some(columns) = &query.columns {
+            let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+            df = df.select_columns(&columns)?;
+        }
+        Ok(df)
+    }
+
+    /// Returns the paths to scan for the `query`, skipping missing partitions.
+    ///
+    /// Partitions are scanned separately as their schema metadata differs, when
+    /// no instrument IDs are specified every partition of the type is scanned.
+    fn query_paths(&self, parquet_type: ParquetType, query: &CatalogQuery) -> Vec<PathBuf> {
+        if !query.instrument_ids.is_empty() {
+            return query
+                .instrument_ids
+                .iter()
+                .map(|instrument_id| self.make_path(parquet_type, Some(instrument_id)))
+                .filter(|path| path.exists())
+                .collect();
+        }
+
+        let type_path = self.make_path(parquet_type, None);
+        let entries = match fs::read_dir(&type_path) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+        let mut partitions: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.is_dir()
+                    && path.file_name().map_or(false, |name| {
+                        name.to_string_lossy().starts_with("instrument_id=")
+                    })
+            })
+            .collect();
+        partitions.sort();
+
+        if partitions.is_empty() {
+            // Data is not partitioned by instrument
+            vec![type_path]
+        } else {
+            partitions
+        }
+    }
+
+    /// Executes the `query` against the catalog, returning one stream of record
+    /// batches per instrument partition, each ordered by `ts_init`.
+    ///
+    /// # Errors
+    ///
+    /// - If a partition cannot be read or the query is invalid for its schema.
+    pub async fn query_streams(
+        &self,
+        parquet_type: ParquetType,
+        query: &CatalogQuery,
+    ) -> Result<Vec<SendableRecordBatchStream>> {
+        let mut streams = Vec::new();
+        for path in self.query_paths(parquet_type, query) {
+            let df = self.query_path(&path, query).await?;
+            streams.push(df.execute_stream().await?);
+        }
+        Ok(streams)
+    }
+
+    /// Executes the `query` against the catalog, collecting all record batches.
+    ///
+    /// Batches are grouped by instrument partition (in the order of
+    /// `query.instrument_ids`), and ordered by `ts_init` within each partition.
+    /// Each batch keeps the schema metadata of its partition.
+    ///
+    /// # Errors
+    ///
+    /// - If a partition cannot be read or the query is invalid for its schema.
+    pub async fn query(
+        &self,
+        parquet_type: ParquetType,
+        query: &CatalogQuery,
+    ) -> Result<Vec<RecordBatch>> {
+        let mut batches = Vec::new();
+        for path in self.query_paths(parquet_type, query) {
+            let df = self.query_path(&path, query).await?;
+            batches.extend(df.collect().await?);
+        }
+        Ok(batches)
+    }
+}
+
+/// Replaces characters which are illegal on Windows in the given key `s`.
+#[must_use]
+pub fn clean_key(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if INVALID_WINDOWS_CHARS.contains(c) {
+                '-'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[pyclass(name = "DataCatalog")]
+pub struct PyDataCatalog {
+    catalog: DataCatalog,
+}
+
+// Note: Intended to be used on a single python thread
+unsafe impl Send for PyDataCatalog {}
+
+#[pymethods]
+impl PyDataCatalog {
+    #[new]
+    fn py_new(path: &str) -> Self {
+        // Initialize runtime here
+        get_runtime();
+        Self {
+            catalog: DataCatalog::new(path),
+        }
+    }
+
+    #[getter]
+    fn path(&self) -> String {
+        self.catalog.path().to_string_lossy().to_string()
+    }
+
+    /// Query the catalog for data of `parquet_type`, returning a list of
+    /// `pyarrow.RecordBatch` ordered by `ts_init` within each instrument.
+    #[pyo3(signature = (parquet_type, instrument_ids=None, start=None, end=None, columns=None))]
+    fn query(
+        &self,
+        py: Python<'_>,
+        parquet_type: ParquetType,
+        instrument_ids: Option<Vec<String>>,
+        start: Option<u64>,
+        end: Option<u64>,
+        columns: Option<Vec<String>>,
+    ) -> PyResult<Vec<PyObject>> {
+        let query = CatalogQuery {
+            instrument_ids: instrument_ids.unwrap_or_default(),
+            start,
+            end,
+            columns,
+        };
+
+        let rt = get_runtime();
+        let _guard = rt.enter();
+
+        let batches = block_on(self.catalog.query(parquet_type, &query))
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        batches.iter().map(|batch| batch.to_pyarrow(py)).collect()
+    }
+
+    /// Resample the bars stored for the `source` bar type into bars of the `target` bar
+    /// type, replacing any bars stored for the target. Returns the number of bars written.
+    ///
+    /// Bars are grouped into fixed windows of the target interval shifted by `offset_ns`,
+    /// or into the trading `sessions` as `(open, close)` UNIX nanosecond pairs if given.
+    #[pyo3(signature = (source, target, offset_ns=0, sessions=None))]
+    fn resample_bars(
+        &self,
+        source: &str,
+        target: &str,
+        offset_ns: u64,
+        sessions: Option<Vec<(u64, u64)>>,
+    ) -> PyResult<usize> {
+        let parse = |value: &str| {
+            BarType::from_str(value).map_err(|e| PyValueError::new_err(e.to_string()))
+        };
+        let (source, target) = (parse(source)?, parse(target)?);
+        let window = match sessions {
+            Some(sessions) => ResampleWindow::Sessions(sessions),
+            None => ResampleWindow::from_bar_type(&target, offset_ns)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?,
+        };
+
+        let entry = self
+            .catalog
+            .resample_bars(
+                &source,
+                &target,
+                &window,
+                WriteMode::Overwrite,
+                ParquetWriterConfig::default(),
+            )
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        Ok(entry.map_or(0, |entry| entry.rows))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, fs::File, str::FromStr};
+
+    use datafusion::arrow::array::{Array, UInt64Array};
+    use nautilus_model::{
+        data::tick::QuoteTick,
+        identifiers::instrument_id::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+    use tempfile::{tempdir, TempDir};
+
+    use super::*;
+    use crate::parquet::writer::{ParquetWriter, ParquetWriterConfig};
+
+    fn write_quotes(catalog: &DataCatalog, instrument_id: &str, ts_inits: &[u64]) {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("price_precision".to_string(), "2".to_string());
+        metadata.insert("size_precision".to_string(), "0".to_string());
+
+        let data: Vec<QuoteTick> = ts_inits
+            .iter()
+            .map(|&ts_init| QuoteTick {
+                instrument_id: InstrumentId::from_str(instrument_id).unwrap(),
+                bid: Price::from_raw(10_000, 2),
+                ask: Price::from_raw(10_100, 2),
+                bid_size: Quantity::from_raw(100, 0),
+                ask_size: Quantity::from_raw(100, 0),
+                ts_event: ts_init,
+                ts_init,
+            })
+            .collect();
+
+        let dir = catalog.make_path(ParquetType::QuoteTick, Some(instrument_id));
+        fs::create_dir_all(&dir).unwrap();
+        let config = ParquetWriterConfig {
+            row_group_size: 2,
+            ..Default::default()
+        };
+        let file = File::create(dir.join("0.parquet")).unwrap();
+        let mut writer = ParquetWriter::<QuoteTick, File>::new(file, metadata, config).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+    }
+
+    fn setup_catalog() -> (TempDir, DataCatalog) {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let catalog = DataCatalog::new(temp_dir.path());
+        write_quotes(&catalog, "AAPL.NASDAQ", &[5, 1, 3, 7, 9]);
+        write_quotes(&catalog, "EUR/USD.SIM", &[2, 4, 6]);
+        (temp_dir, catalog)
+    }
+
+    fn ts_inits(batches: &[RecordBatch]) -> Vec<u64> {
+        batches
+            .iter()
+            .flat_map(|batch| {
+                let column = batch.column_by_name("ts_init").unwrap();
+                let values = column.as_any().downcast_ref::<UInt64Array>().unwrap();
+                values.values().to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_make_path() {
+        let catalog = DataCatalog::new("/catalog");
+
+        assert_eq!(
+            catalog.make_path(ParquetType::TradeTick, None),
+            PathBuf::from("/catalog/data/trade_tick.parquet")
+        );
+        assert_eq!(
+            catalog.make_path(ParquetType::QuoteTick, Some("EUR/USD.SIM")),
+            PathBuf::from("/catalog/data/quote_tick.parquet/instrument_id=EUR-USD.SIM")
+        );
+    }
+
+    #[rstest]
+    #[case(None, None, None)]
+    #[case(Some(10), None, Some(col("ts_init").gt_eq(lit(10_u64))))]
+    #[case(None, Some(20), Some(col("ts_init").lt_eq(lit(20_u64))))]
+    #[case(
+        Some(10),
+        Some(20),
+        Some(col("ts_init").gt_eq(lit(10_u64)).and(col("ts_init").lt_eq(lit(20_u64))))
+    )]
+    fn test_time_filter(
+        #[case] start: Option<u64>,
+        #[case] end: Option<u64>,
+        #[case] expected: Option<Expr>,
+    ) {
+        let query = CatalogQuery {
+            start,
+            end,
+            ..Default::default()
+        };
+
+        assert_eq!(query.time_filter(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_query_instrument_sorted_by_ts_init() {
+        let (_temp_dir, catalog) = setup_catalog();
+        let query = CatalogQuery {
+            instrument_ids: vec!["AAPL.NASDAQ".to_string()],
+            ..Default::default()
+        };
+
+        let batches = catalog.query(ParquetType::QuoteTick, &query).await.unwrap();
+
+        assert_eq!(ts_inits(&batches), vec![1, 3, 5, 7, 9]);
+        assert_eq!(
+            batches[0].schema().metadata().get("instrument_id"),
+            Some(&"AAPL.NASDAQ".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_time_range_and_columns() {
+        let (_temp_dir, catalog) = setup_catalog();
+        let query = CatalogQuery {
+            instrument_ids: vec!["AAPL.NASDAQ".to_string(), "EUR/USD.SIM".to_string()],
+            start: Some(3),
+            end: Some(6),
+            columns: Some(vec!["bid".to_string(), "ts_init".to_string()]),
+        };
+
+        let batches = catalog.query(ParquetType::QuoteTick, &query).await.unwrap();
+
+        assert_eq!(ts_inits(&batches), vec![3, 5, 4, 6]);
+        for batch in &batches {
+            assert_eq!(batch.num_columns(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query_all_instruments() {
+        let (_temp_dir, catalog) = setup_catalog();
+
+        let batches = catalog
+            .query(ParquetType::QuoteTick, &CatalogQuery::default())
+            .await
+            .unwrap();
+
+        // Partitions are scanned in path order
+        assert_eq!(ts_inits(&batches), vec![1, 3, 5, 7, 9, 2, 4, 6]);
+    }
+
+    #[tokio::test]
+    async fn test_query_streams_per_instrument() {
+        let (_temp_dir, catalog) = setup_catalog();
+        let query = CatalogQuery {
+            instrument_ids: vec!["AAPL.NASDAQ".to_string(), "EUR/USD.SIM".to_string()],
+            ..Default::default()
+        };
+
+        let streams = catalog
+            .query_streams(ParquetType::QuoteTick, &query)
+            .await
+            .unwrap();
+
+        assert_eq!(streams.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_query_missing_data_returns_empty() {
+        let (_temp_dir, catalog) = setup_catalog();
+        let query = CatalogQuery {
+            instrument_ids: vec!["MSFT.NASDAQ".to_string()],
+            ..Default::default()
+        };
+
+        let quotes = catalog.query(ParquetType::QuoteTick, &query).await.unwrap();
+        let trades = catalog
+            .query(ParquetType::TradeTick, &CatalogQuery::default())
+            .await
+            .unwrap();
+
+        assert!(quotes.is_empty());
+        assert!(trades.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_query_invalid_column_returns_error() {
+        let (_temp_dir, catalog) = setup_catalog();
+        let query = CatalogQuery {
+            columns: Some(vec!["volume".to_string()]),
+            ..Default::default()
+        };
+
+        let result = catalog.query(ParquetType::QuoteTick, &query).await;
+
+        assert!(result.is_err());
+    }
+}
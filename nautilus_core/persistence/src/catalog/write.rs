@@ -0,0 +1,542 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+};
+
+use datafusion::{arrow::error::ArrowError, parquet::errors::ParquetError};
+use nautilus_model::data::{Data, HasTsInit};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::DataCatalog;
+use crate::parquet::{
+    reader::ParquetReader,
+    writer::{ParquetWriter, ParquetWriterConfig},
+    EncodeToRecordBatch, ParquetType,
+};
+
+/// The filename of the manifest stored in each partition directory.
+pub const MANIFEST_FILENAME: &str = "_manifest.json";
+
+/// The number of rows decoded at a time when reading back existing files.
+const READ_CHUNK_SIZE: usize = 10_000;
+
+#[derive(Debug, Error)]
+pub enum CatalogError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Parquet error: {0}")]
+    Parquet(#[from] ParquetError),
+    #[error("Arrow error: {0}")]
+    Arrow(#[from] ArrowError),
+    #[error("Manifest error: {0}")]
+    Manifest(#[from] serde_json::Error),
+    #[error("Data overlaps existing file '{filename}' covering {start}-{end}")]
+    Overlap {
+        filename: String,
+        start: u64,
+        end: u64,
+    },
+    #[error("File '{0}' does not hold the expected data type")]
+    TypeMismatch(String),
+}
+
+/// The behavior when written data overlaps the time range of existing files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteMode {
+    /// Merges overlapping files with the new data, deduplicating rows.
+    Append,
+    /// Fails with [`CatalogError::Overlap`] if any existing file overlaps.
+    NewFile,
+    /// Replaces all existing files in the partition.
+    Overwrite,
+}
+
+/// Represents a single Parquet file in a catalog partition.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub filename: String,
+    /// The `ts_init` of the first row (UNIX nanoseconds).
+    pub start: u64,
+    /// The `ts_init` of the last row (UNIX nanoseconds).
+    pub end: u64,
+    pub rows: usize,
+}
+
+impl ManifestEntry {
+    #[must_use]
+    pub fn overlaps(&self, start: u64, end: u64) -> bool {
+        !(self.end < start || end < self.start)
+    }
+}
+
+/// Represents the manifest of the files in a catalog partition, ordered by `start`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartitionManifest {
+    pub files: Vec<ManifestEntry>,
+}
+
+impl PartitionManifest {
+    fn load(dir: &Path) -> Result<Self, CatalogError> {
+        let path = dir.join(MANIFEST_FILENAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(&fs::read(path)?)?)
+    }
+
+    /// Saves the manifest, replacing the previous manifest atomically.
+    fn save(&self, dir: &Path) -> Result<(), CatalogError> {
+        let tmp_path = dir.join(format!("{MANIFEST_FILENAME}.tmp"));
+        fs::write(&tmp_path, serde_json::to_vec_pretty(self)?)?;
+        fs::rename(tmp_path, dir.join(MANIFEST_FILENAME))?;
+        Ok(())
+    }
+
+    /// Returns the total number of rows over all files.
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.files.iter().map(|entry| entry.rows).sum()
+    }
+}
+
+impl DataCatalog {
+    /// Returns the manifest for the partition of `parquet_type` and `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// - If an existing manifest cannot be read or parsed.
+    pub fn manifest(
+        &self,
+        parquet_type: ParquetType,
+        instrument_id: &str,
+    ) -> Result<PartitionManifest, CatalogError> {
+        PartitionManifest::load(&self.make_path(parquet_type, Some(instrument_id)))
+    }
+
+    /// Reads all the data in the partition of `parquet_type` and `instrument_id`, ordered
+    /// by `ts_init`.
+    ///
+    /// # Errors
+    ///
+    /// - If the manifest or any of its files cannot be read.
+    /// - If a file does not hold data of type `T`.
+    pub fn read_data<T: TryFrom<Data>>(
+        &self,
+        parquet_type: ParquetType,
+        instrument_id: &str,
+    ) -> Result<Vec<T>, CatalogError> {
+        let dir = self.make_path(parquet_type, Some(instrument_id));
+        let manifest = PartitionManifest::load(&dir)?;
+        let mut data = Vec::new();
+        for entry in &manifest.files {
+            data.extend(read_file::<T>(&dir.join(&entry.filename), parquet_type)?);
+        }
+        Ok(data)
+    }
+
+    /// Writes the `data` to the partition of `parquet_type` and `instrument_id`,
+    /// as a single new file named by its `ts_init` range.
+    ///
+    /// The `data` is sorted by `ts_init` and duplicate rows are removed before
+    /// writing. Existing files overlapping the range are handled per the `mode`.
+    /// Returns the manifest entry of the written file, or `None` if there was
+    /// no data to write.
+    ///
+    /// # Errors
+    ///
+    /// - If `mode` is [`WriteMode::NewFile`] and an existing file overlaps the data.
+    /// - If reading existing files, writing the file or the manifest fails.
+    pub fn write_data<T>(
+        &self,
+        parquet_type: ParquetType,
+        instrument_id: &str,
+        metadata: HashMap<String, String>,
+        mut data: Vec<T>,
+        mode: WriteMode,
+        config: ParquetWriterConfig,
+    ) -> Result<Option<ManifestEntry>, CatalogError>
+    where
+        T: EncodeToRecordBatch + HasTsInit + PartialEq + TryFrom<Data>,
+    {
+        let dir = self.make_path(parquet_type, Some(instrument_id));
+        fs::create_dir_all(&dir)?;
+        let mut manifest = PartitionManifest::load(&dir)?;
+
+        if mode == WriteMode::Overwrite {
+            for entry in manifest.files.drain(..) {
+                remove_file_if_exists(&dir.join(entry.filename))?;
+            }
+        }
+
+        sort_and_dedup(&mut data);
+        let (start, end) = match (data.first(), data.last()) {
+            (Some(first), Some(last)) => (first.get_ts_init(), last.get_ts_init()),
+            _ => {
+                manifest.save(&dir)?;
+                return Ok(None);
+            }
+        };
+
+        let (overlapping, disjoint): (Vec<ManifestEntry>, Vec<ManifestEntry>) = manifest
+            .files
+            .into_iter()
+            .partition(|entry| entry.overlaps(start, end));
+
+        if mode == WriteMode::NewFile {
+            if let Some(entry) = overlapping.first() {
+                return Err(CatalogError::Overlap {
+                    filename: entry.filename.clone(),
+                    start: entry.start,
+                    end: entry.end,
+                });
+            }
+        }
+
+        // Merge the overlapping files, which may extend the range of the new file
+        for entry in &overlapping {
+            data.extend(read_file::<T>(&dir.join(&entry.filename), parquet_type)?);
+        }
+        if !overlapping.is_empty() {
+            sort_and_dedup(&mut data);
+        }
+        let start = data.first().map_or(start, HasTsInit::get_ts_init);
+        let end = data.last().map_or(end, HasTsInit::get_ts_init);
+
+        let entry = ManifestEntry {
+            filename: format!("{start:019}-{end:019}-0.parquet"),
+            start,
+            end,
+            rows: data.len(),
+        };
+        write_file(&dir.join(&entry.filename), metadata, &data, config)?;
+
+        for old in &overlapping {
+            if old.filename != entry.filename {
+                remove_file_if_exists(&dir.join(&old.filename))?;
+            }
+        }
+
+        manifest.files = disjoint;
+        manifest.files.push(entry.clone());
+        manifest.files.sort_by_key(|entry| entry.start);
+        manifest.save(&dir)?;
+
+        Ok(Some(entry))
+    }
+}
+
+/// Sorts the `data` by `ts_init` (preserving the order of equal timestamps),
+/// and removes duplicate rows.
+pub fn sort_and_dedup<T: HasTsInit + PartialEq>(data: &mut Vec<T>) {
+    data.sort_by_key(HasTsInit::get_ts_init);
+
+    let mut deduped: Vec<T> = Vec::with_capacity(data.len());
+    let mut group_start = 0;
+    for item in data.drain(..) {
+        let is_new_group = deduped
+            .last()
+            .map_or(true, |last| last.get_ts_init() != item.get_ts_init());
+        if is_new_group {
+            group_start = deduped.len();
+        }
+        // Duplicates can only share the same timestamp
+        if !deduped[group_start..].contains(&item) {
+            deduped.push(item);
+        }
+    }
+    *data = deduped;
+}
+
+/// Writes the `data` to a temporary file which is then moved to `path`, so an
+/// existing file at `path` is only replaced once the write succeeds.
+fn write_file<T: EncodeToRecordBatch>(
+    path: &Path,
+    metadata: HashMap<String, String>,
+    data: &[T],
+    config: ParquetWriterConfig,
+) -> Result<(), CatalogError> {
+    let mut tmp_path = PathBuf::from(path);
+    tmp_path.set_extension("parquet.tmp");
+
+    let mut writer = ParquetWriter::<T, File>::new(File::create(&tmp_path)?, metadata, config)?;
+    writer.write(data)?;
+    writer.close()?;
+
+    fs::rename(tmp_path, path)?;
+    Ok(())
+}
+
+fn read_file<T: TryFrom<Data>>(
+    path: &Path,
+    parquet_type: ParquetType,
+) -> Result<Vec<T>, CatalogError> {
+    let mut data = Vec::new();
+    for chunk in ParquetReader::from_file(path, parquet_type, READ_CHUNK_SIZE)? {
+        for item in chunk? {
+            let item = T::try_from(item)
+                .map_err(|_| CatalogError::TypeMismatch(path.to_string_lossy().to_string()))?;
+            data.push(item);
+        }
+    }
+    Ok(data)
+}
+
+fn remove_file_if_exists(path: &Path) -> Result<(), CatalogError> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{
+        data::tick::QuoteTick,
+        identifiers::instrument_id::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+    use tempfile::{tempdir, TempDir};
+
+    use super::*;
+    use crate::catalog::CatalogQuery;
+
+    const INSTRUMENT_ID: &str = "AAPL.NASDAQ";
+
+    fn metadata() -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), INSTRUMENT_ID.to_string());
+        metadata.insert("price_precision".to_string(), "2".to_string());
+        metadata.insert("size_precision".to_string(), "0".to_string());
+        metadata
+    }
+
+    fn quote(ts_init: u64, bid: i64) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from_str(INSTRUMENT_ID).unwrap(),
+            bid: Price::from_raw(bid, 2),
+            ask: Price::from_raw(bid + 100, 2),
+            bid_size: Quantity::from_raw(100, 0),
+            ask_size: Quantity::from_raw(100, 0),
+            ts_event: ts_init,
+            ts_init,
+        }
+    }
+
+    fn quotes(ts_inits: &[u64]) -> Vec<QuoteTick> {
+        ts_inits.iter().map(|&ts| quote(ts, 10_000)).collect()
+    }
+
+    fn write(
+        catalog: &DataCatalog,
+        data: Vec<QuoteTick>,
+        mode: WriteMode,
+    ) -> Result<Option<ManifestEntry>, CatalogError> {
+        catalog.write_data(
+            ParquetType::QuoteTick,
+            INSTRUMENT_ID,
+            metadata(),
+            data,
+            mode,
+            ParquetWriterConfig::default(),
+        )
+    }
+
+    fn setup_catalog() -> (TempDir, DataCatalog) {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let catalog = DataCatalog::new(temp_dir.path());
+        (temp_dir, catalog)
+    }
+
+    fn parquet_files(catalog: &DataCatalog) -> Vec<String> {
+        let dir = catalog.make_path(ParquetType::QuoteTick, Some(INSTRUMENT_ID));
+        let mut files: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .filter(|name| name.ends_with(".parquet"))
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[rstest]
+    #[case(vec![3, 1, 2], vec![1, 2, 3])]
+    #[case(vec![1, 1, 2, 2], vec![1, 2])]
+    #[case(vec![], vec![])]
+    fn test_sort_and_dedup(#[case] ts_inits: Vec<u64>, #[case] expected: Vec<u64>) {
+        let mut data = quotes(&ts_inits);
+
+        sort_and_dedup(&mut data);
+
+        assert_eq!(data, quotes(&expected));
+    }
+
+    #[test]
+    fn test_sort_and_dedup_keeps_distinct_rows_with_same_timestamp() {
+        let mut data = vec![quote(1, 10_000), quote(1, 10_001), quote(1, 10_000)];
+
+        sort_and_dedup(&mut data);
+
+        assert_eq!(data, vec![quote(1, 10_000), quote(1, 10_001)]);
+    }
+
+    #[test]
+    fn test_write_new_file_and_manifest() {
+        let (_temp_dir, catalog) = setup_catalog();
+
+        let entry = write(&catalog, quotes(&[3, 1, 2]), WriteMode::NewFile)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            entry.filename,
+            "0000000000000000001-0000000000000000003-0.parquet"
+        );
+        assert_eq!((entry.start, entry.end, entry.rows), (1, 3, 3));
+        assert_eq!(
+            catalog
+                .manifest(ParquetType::QuoteTick, INSTRUMENT_ID)
+                .unwrap(),
+            PartitionManifest {
+                files: vec![entry.clone()]
+            }
+        );
+        assert_eq!(parquet_files(&catalog), vec![entry.filename]);
+    }
+
+    #[test]
+    fn test_write_empty_data_writes_nothing() {
+        let (_temp_dir, catalog) = setup_catalog();
+
+        let entry = write(&catalog, vec![], WriteMode::Append).unwrap();
+
+        assert!(entry.is_none());
+        assert!(parquet_files(&catalog).is_empty());
+    }
+
+    #[test]
+    fn test_write_new_file_overlap_returns_error() {
+        let (_temp_dir, catalog) = setup_catalog();
+        write(&catalog, quotes(&[1, 5]), WriteMode::NewFile).unwrap();
+
+        let result = write(&catalog, quotes(&[4, 6]), WriteMode::NewFile);
+
+        assert!(matches!(
+            result,
+            Err(CatalogError::Overlap {
+                start: 1,
+                end: 5,
+                ..
+            })
+        ));
+        assert_eq!(parquet_files(&catalog).len(), 1);
+    }
+
+    #[test]
+    fn test_write_disjoint_ranges_keeps_files_ordered() {
+        let (_temp_dir, catalog) = setup_catalog();
+        write(&catalog, quotes(&[10, 20]), WriteMode::NewFile).unwrap();
+        write(&catalog, quotes(&[1, 5]), WriteMode::NewFile).unwrap();
+
+        let manifest = catalog
+            .manifest(ParquetType::QuoteTick, INSTRUMENT_ID)
+            .unwrap();
+
+        let ranges: Vec<(u64, u64)> = manifest.files.iter().map(|e| (e.start, e.end)).collect();
+        assert_eq!(ranges, vec![(1, 5), (10, 20)]);
+        assert_eq!(manifest.rows(), 4);
+    }
+
+    #[test]
+    fn test_write_append_merges_and_dedups_overlap() {
+        let (_temp_dir, catalog) = setup_catalog();
+        write(&catalog, quotes(&[1, 2, 3]), WriteMode::Append).unwrap();
+        write(&catalog, quotes(&[10, 11]), WriteMode::Append).unwrap();
+
+        let entry = write(&catalog, quotes(&[3, 4, 5]), WriteMode::Append)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!((entry.start, entry.end, entry.rows), (1, 5, 5));
+        let manifest = catalog
+            .manifest(ParquetType::QuoteTick, INSTRUMENT_ID)
+            .unwrap();
+        assert_eq!(manifest.files.len(), 2);
+        assert_eq!(manifest.rows(), 7);
+        assert_eq!(parquet_files(&catalog).len(), 2);
+
+        let path = catalog.make_path(ParquetType::QuoteTick, Some(INSTRUMENT_ID));
+        let data: Vec<QuoteTick> =
+            read_file(&path.join(&entry.filename), ParquetType::QuoteTick).unwrap();
+        assert_eq!(data, quotes(&[1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn test_write_append_identical_data_is_idempotent() {
+        let (_temp_dir, catalog) = setup_catalog();
+        write(&catalog, quotes(&[1, 2, 3]), WriteMode::Append).unwrap();
+
+        write(&catalog, quotes(&[1, 2, 3]), WriteMode::Append).unwrap();
+
+        let manifest = catalog
+            .manifest(ParquetType::QuoteTick, INSTRUMENT_ID)
+            .unwrap();
+        assert_eq!(manifest.rows(), 3);
+        assert_eq!(parquet_files(&catalog).len(), 1);
+    }
+
+    #[test]
+    fn test_write_overwrite_replaces_partition() {
+        let (_temp_dir, catalog) = setup_catalog();
+        write(&catalog, quotes(&[1, 2]), WriteMode::Append).unwrap();
+        write(&catalog, quotes(&[10, 11]), WriteMode::Append).unwrap();
+
+        let entry = write(&catalog, quotes(&[5]), WriteMode::Overwrite)
+            .unwrap()
+            .unwrap();
+
+        let manifest = catalog
+            .manifest(ParquetType::QuoteTick, INSTRUMENT_ID)
+            .unwrap();
+        assert_eq!(manifest.files, vec![entry.clone()]);
+        assert_eq!(parquet_files(&catalog), vec![entry.filename]);
+    }
+
+    #[tokio::test]
+    async fn test_written_data_is_queryable() {
+        let (_temp_dir, catalog) = setup_catalog();
+        write(&catalog, quotes(&[3, 4]), WriteMode::Append).unwrap();
+        write(&catalog, quotes(&[1, 2]), WriteMode::Append).unwrap();
+
+        let batches = catalog
+            .query(ParquetType::QuoteTick, &CatalogQuery::default())
+            .await
+            .unwrap();
+
+        let rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+        assert_eq!(rows, 4);
+    }
+}
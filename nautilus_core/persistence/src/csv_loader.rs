@@ -0,0 +1,1056 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{Read, Write},
+    str::FromStr,
+};
+
+use chrono::{DateTime, NaiveDateTime};
+use csv::{ReaderBuilder, StringRecord, Trim};
+use datafusion::parquet::errors::ParquetError;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::tick::{QuoteTick, TradeTick},
+    enums::AggressorSide,
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    types::{price::Price, quantity::Quantity},
+};
+use pyo3::{
+    exceptions::{PyIOError, PyValueError},
+    prelude::*,
+};
+use thiserror::Error;
+
+use crate::parquet::{
+    writer::{ParquetWriter, ParquetWriterConfig},
+    EncodeToRecordBatch,
+};
+
+#[derive(Debug, Error)]
+pub enum CsvLoaderError {
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Parquet error: {0}")]
+    Parquet(#[from] ParquetError),
+    #[error("Column '{0}' not found in headers")]
+    MissingColumn(String),
+    #[error("Invalid value '{value}' for column '{column}' on line {line}: {reason}")]
+    InvalidField {
+        line: u64,
+        column: String,
+        value: String,
+        reason: String,
+    },
+}
+
+/// The format of timestamp columns in a CSV file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// Integer UNIX nanoseconds.
+    UnixNanos,
+    /// UNIX microseconds, with an optional fractional part.
+    UnixMicros,
+    /// UNIX milliseconds, with an optional fractional part.
+    UnixMillis,
+    /// UNIX seconds, with an optional fractional part.
+    UnixSeconds,
+    /// An RFC 3339 datetime with offset, e.g. `2023-01-01T00:00:00.123Z`.
+    Rfc3339,
+    /// A `chrono` format string for a naive datetime, interpreted as UTC.
+    Custom(String),
+}
+
+impl FromStr for TimestampFormat {
+    type Err = String;
+
+    /// Parses the format from its snake case name, any string containing `%`
+    /// is taken as a custom format.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unix_nanos" => Ok(Self::UnixNanos),
+            "unix_micros" => Ok(Self::UnixMicros),
+            "unix_millis" => Ok(Self::UnixMillis),
+            "unix_seconds" => Ok(Self::UnixSeconds),
+            "rfc3339" => Ok(Self::Rfc3339),
+            _ if s.contains('%') => Ok(Self::Custom(s.to_string())),
+            _ => Err(format!("Invalid timestamp format '{s}'")),
+        }
+    }
+}
+
+impl TimestampFormat {
+    /// Parses the given `value` as UNIX nanoseconds.
+    ///
+    /// # Errors
+    ///
+    /// - If the `value` is not valid for the format, or is before the UNIX epoch.
+    pub fn parse(&self, value: &str) -> Result<UnixNanos, String> {
+        match self {
+            Self::UnixNanos => value.parse::<u64>().map_err(|e| e.to_string()),
+            Self::UnixMicros => parse_unix_decimal(value, 3),
+            Self::UnixMillis => parse_unix_decimal(value, 6),
+            Self::UnixSeconds => parse_unix_decimal(value, 9),
+            Self::Rfc3339 => {
+                let datetime = DateTime::parse_from_rfc3339(value).map_err(|e| e.to_string())?;
+                nanos_from_i64(datetime.timestamp_nanos())
+            }
+            Self::Custom(format) => {
+                let datetime =
+                    NaiveDateTime::parse_from_str(value, format).map_err(|e| e.to_string())?;
+                nanos_from_i64(datetime.timestamp_nanos())
+            }
+        }
+    }
+}
+
+/// Parses a non-negative decimal `value` in a unit of `10^digits` nanoseconds,
+/// without any floating point rounding.
+fn parse_unix_decimal(value: &str, digits: u32) -> Result<UnixNanos, String> {
+    let (int_part, frac_part) = value.split_once('.').unwrap_or((value, ""));
+    if frac_part.len() > digits as usize {
+        return Err(format!("more than {digits} decimal places"));
+    }
+
+    let int_value: u64 = int_part.parse().map_err(|e| format!("{e}"))?;
+    let frac_value: u64 = if frac_part.is_empty() {
+        0
+    } else {
+        let padded = format!("{frac_part:0<width$}", width = digits as usize);
+        padded.parse().map_err(|e| format!("{e}"))?
+    };
+
+    int_value
+        .checked_mul(10_u64.pow(digits))
+        .and_then(|nanos| nanos.checked_add(frac_value))
+        .ok_or_else(|| "timestamp overflow".to_string())
+}
+
+fn nanos_from_i64(nanos: i64) -> Result<UnixNanos, String> {
+    u64::try_from(nanos).map_err(|_| "timestamp before UNIX epoch".to_string())
+}
+
+/// Configuration for a [`CsvLoader`].
+#[derive(Debug, Clone)]
+pub struct CsvLoaderConfig {
+    pub instrument_id: InstrumentId,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    pub timestamp_format: TimestampFormat,
+    pub delimiter: u8,
+    /// The column names for files without a header row, if `None` the first
+    /// row of the file is used.
+    pub headers: Option<Vec<String>>,
+    /// The number of rows converted per chunk.
+    pub chunk_size: usize,
+}
+
+impl CsvLoaderConfig {
+    /// Initializes a new `CsvLoaderConfig` for comma delimited files with a
+    /// header row and UNIX nanosecond timestamps.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, price_precision: u8, size_precision: u8) -> Self {
+        Self {
+            instrument_id,
+            price_precision,
+            size_precision,
+            timestamp_format: TimestampFormat::UnixNanos,
+            delimiter: b',',
+            headers: None,
+            chunk_size: 100_000,
+        }
+    }
+}
+
+/// The mapping of `TradeTick` fields to CSV column names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradeColumns {
+    pub ts_event: String,
+    /// Defaults to the `ts_event` if not mapped.
+    pub ts_init: Option<String>,
+    pub price: String,
+    pub size: String,
+    /// Defaults to `NO_AGGRESSOR` if not mapped.
+    pub aggressor_side: Option<String>,
+    /// Defaults to the row number if not mapped.
+    pub trade_id: Option<String>,
+}
+
+/// The mapping of `QuoteTick` fields to CSV column names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuoteColumns {
+    pub ts_event: String,
+    /// Defaults to the `ts_event` if not mapped.
+    pub ts_init: Option<String>,
+    pub bid: String,
+    pub ask: String,
+    /// Defaults to zero if not mapped.
+    pub bid_size: Option<String>,
+    /// Defaults to zero if not mapped.
+    pub ask_size: Option<String>,
+}
+
+/// Parses a single CSV record into a value.
+pub trait RowParser {
+    type Output;
+
+    /// Parses the `record`, where `row` is the 1-based index of the data row.
+    fn parse_row(&self, record: &StringRecord, row: u64) -> Result<Self::Output, CsvLoaderError>;
+}
+
+/// A column resolved to its index in the CSV record.
+#[derive(Debug, Clone)]
+struct Column {
+    name: String,
+    index: usize,
+}
+
+impl Column {
+    fn resolve(headers: &StringRecord, name: &str) -> Result<Self, CsvLoaderError> {
+        headers
+            .iter()
+            .position(|header| header == name)
+            .map(|index| Self {
+                name: name.to_string(),
+                index,
+            })
+            .ok_or_else(|| CsvLoaderError::MissingColumn(name.to_string()))
+    }
+
+    fn resolve_opt(
+        headers: &StringRecord,
+        name: &Option<String>,
+    ) -> Result<Option<Self>, CsvLoaderError> {
+        name.as_deref()
+            .map(|name| Self::resolve(headers, name))
+            .transpose()
+    }
+
+    fn get<'a>(&self, record: &'a StringRecord) -> Result<&'a str, CsvLoaderError> {
+        record
+            .get(self.index)
+            .ok_or_else(|| self.invalid(record, "", "missing field".to_string()))
+    }
+
+    fn invalid(&self, record: &StringRecord, value: &str, reason: String) -> CsvLoaderError {
+        CsvLoaderError::InvalidField {
+            line: record.position().map_or(0, csv::Position::line),
+            column: self.name.clone(),
+            value: value.to_string(),
+            reason,
+        }
+    }
+
+    fn parse_timestamp(
+        &self,
+        record: &StringRecord,
+        format: &TimestampFormat,
+    ) -> Result<UnixNanos, CsvLoaderError> {
+        let value = self.get(record)?;
+        format
+            .parse(value)
+            .map_err(|reason| self.invalid(record, value, reason))
+    }
+
+    fn parse_f64(&self, record: &StringRecord, non_negative: bool) -> Result<f64, CsvLoaderError> {
+        let value = self.get(record)?;
+        let parsed: f64 = value
+            .parse()
+            .map_err(|e| self.invalid(record, value, format!("{e}")))?;
+        if !parsed.is_finite() || (non_negative && parsed < 0.0) {
+            return Err(self.invalid(record, value, "out of range".to_string()));
+        }
+        Ok(parsed)
+    }
+
+    fn parse_price(&self, record: &StringRecord, precision: u8) -> Result<Price, CsvLoaderError> {
+        Ok(Price::new(self.parse_f64(record, false)?, precision))
+    }
+
+    fn parse_quantity(
+        &self,
+        record: &StringRecord,
+        precision: u8,
+    ) -> Result<Quantity, CsvLoaderError> {
+        Ok(Quantity::new(self.parse_f64(record, true)?, precision))
+    }
+}
+
+fn parse_aggressor_side(value: &str) -> AggressorSide {
+    match value.to_ascii_lowercase().as_str() {
+        "b" | "buy" | "buyer" | "1" => AggressorSide::Buyer,
+        "s" | "sell" | "seller" | "2" => AggressorSide::Seller,
+        _ => AggressorSide::NoAggressor,
+    }
+}
+
+pub struct TradeParser {
+    config: CsvLoaderConfig,
+    ts_event: Column,
+    ts_init: Option<Column>,
+    price: Column,
+    size: Column,
+    aggressor_side: Option<Column>,
+    trade_id: Option<Column>,
+}
+
+impl TradeParser {
+    fn new(
+        config: CsvLoaderConfig,
+        headers: &StringRecord,
+        columns: &TradeColumns,
+    ) -> Result<Self, CsvLoaderError> {
+        Ok(Self {
+            config,
+            ts_event: Column::resolve(headers, &columns.ts_event)?,
+            ts_init: Column::resolve_opt(headers, &columns.ts_init)?,
+            price: Column::resolve(headers, &columns.price)?,
+            size: Column::resolve(headers, &columns.size)?,
+            aggressor_side: Column::resolve_opt(headers, &columns.aggressor_side)?,
+            trade_id: Column::resolve_opt(headers, &columns.trade_id)?,
+        })
+    }
+}
+
+impl RowParser for TradeParser {
+    type Output = TradeTick;
+
+    fn parse_row(&self, record: &StringRecord, row: u64) -> Result<TradeTick, CsvLoaderError> {
+        let format = &self.config.timestamp_format;
+        let ts_event = self.ts_event.parse_timestamp(record, format)?;
+        let ts_init = match &self.ts_init {
+            Some(column) => column.parse_timestamp(record, format)?,
+            None => ts_event,
+        };
+        let aggressor_side = match &self.aggressor_side {
+            Some(column) => parse_aggressor_side(column.get(record)?),
+            None => AggressorSide::NoAggressor,
+        };
+        let trade_id = match &self.trade_id {
+            Some(column) => {
+                let value = column.get(record)?;
+                TradeId::try_new(value).map_err(|e| column.invalid(record, value, e.to_string()))?
+            }
+            None => TradeId::new(&row.to_string()),
+        };
+
+        Ok(TradeTick {
+            instrument_id: self.config.instrument_id.clone(),
+            price: self
+                .price
+                .parse_price(record, self.config.price_precision)?,
+            size: self
+                .size
+                .parse_quantity(record, self.config.size_precision)?,
+            aggressor_side,
+            trade_id,
+            ts_event,
+            ts_init,
+        })
+    }
+}
+
+pub struct QuoteParser {
+    config: CsvLoaderConfig,
+    ts_event: Column,
+    ts_init: Option<Column>,
+    bid: Column,
+    ask: Column,
+    bid_size: Option<Column>,
+    ask_size: Option<Column>,
+}
+
+impl QuoteParser {
+    fn new(
+        config: CsvLoaderConfig,
+        headers: &StringRecord,
+        columns: &QuoteColumns,
+    ) -> Result<Self, CsvLoaderError> {
+        Ok(Self {
+            config,
+            ts_event: Column::resolve(headers, &columns.ts_event)?,
+            ts_init: Column::resolve_opt(headers, &columns.ts_init)?,
+            bid: Column::resolve(headers, &columns.bid)?,
+            ask: Column::resolve(headers, &columns.ask)?,
+            bid_size: Column::resolve_opt(headers, &columns.bid_size)?,
+            ask_size: Column::resolve_opt(headers, &columns.ask_size)?,
+        })
+    }
+
+    fn parse_size(
+        &self,
+        column: &Option<Column>,
+        record: &StringRecord,
+    ) -> Result<Quantity, CsvLoaderError> {
+        match column {
+            Some(column) => column.parse_quantity(record, self.config.size_precision),
+            None => Ok(Quantity::from_raw(0, self.config.size_precision)),
+        }
+    }
+}
+
+impl RowParser for QuoteParser {
+    type Output = QuoteTick;
+
+    fn parse_row(&self, record: &StringRecord, _row: u64) -> Result<QuoteTick, CsvLoaderError> {
+        let format = &self.config.timestamp_format;
+        let ts_event = self.ts_event.parse_timestamp(record, format)?;
+        let ts_init = match &self.ts_init {
+            Some(column) => column.parse_timestamp(record, format)?,
+            None => ts_event,
+        };
+
+        Ok(QuoteTick {
+            instrument_id: self.config.instrument_id.clone(),
+            bid: self.bid.parse_price(record, self.config.price_precision)?,
+            ask: self.ask.parse_price(record, self.config.price_precision)?,
+            bid_size: self.parse_size(&self.bid_size, record)?,
+            ask_size: self.parse_size(&self.ask_size, record)?,
+            ts_event,
+            ts_init,
+        })
+    }
+}
+
+/// Provides an iterator over chunks of values parsed from a CSV reader.
+///
+/// Iteration stops after the first error.
+pub struct CsvChunks<R: Read, P: RowParser> {
+    reader: csv::Reader<R>,
+    parser: P,
+    chunk_size: usize,
+    record: StringRecord,
+    row: u64,
+    is_done: bool,
+}
+
+impl<R: Read, P: RowParser> Iterator for CsvChunks<R, P> {
+    type Item = Result<Vec<P::Output>, CsvLoaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        let mut chunk = Vec::with_capacity(self.chunk_size);
+        while chunk.len() < self.chunk_size {
+            let parsed = match self.reader.read_record(&mut self.record) {
+                Ok(true) => {
+                    self.row += 1;
+                    self.parser.parse_row(&self.record, self.row)
+                }
+                Ok(false) => {
+                    self.is_done = true;
+                    break;
+                }
+                Err(e) => Err(e.into()),
+            };
+
+            match parsed {
+                Ok(value) => chunk.push(value),
+                Err(e) => {
+                    self.is_done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(Ok(chunk))
+        }
+    }
+}
+
+/// Provides a loader which converts raw CSV data into `TradeTick` and
+/// `QuoteTick` values, in chunks of a configured size.
+///
+/// Fields are located by the column names of the header row (or the configured
+/// headers), and values are validated as they are parsed, with errors reporting
+/// the line and column of the invalid field.
+pub struct CsvLoader {
+    config: CsvLoaderConfig,
+}
+
+impl CsvLoader {
+    #[must_use]
+    pub fn new(config: CsvLoaderConfig) -> Self {
+        Self { config }
+    }
+
+    #[must_use]
+    pub fn config(&self) -> &CsvLoaderConfig {
+        &self.config
+    }
+
+    /// Returns the Parquet metadata for the configured instrument.
+    #[must_use]
+    pub fn metadata(&self) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert(
+            "instrument_id".to_string(),
+            self.config.instrument_id.to_string(),
+        );
+        metadata.insert(
+            "price_precision".to_string(),
+            self.config.price_precision.to_string(),
+        );
+        metadata.insert(
+            "size_precision".to_string(),
+            self.config.size_precision.to_string(),
+        );
+        metadata
+    }
+
+    fn open<R: Read>(&self, input: R) -> Result<(csv::Reader<R>, StringRecord), CsvLoaderError> {
+        let mut reader = ReaderBuilder::new()
+            .delimiter(self.config.delimiter)
+            .has_headers(self.config.headers.is_none())
+            .trim(Trim::All)
+            .from_reader(input);
+
+        let headers = match &self.config.headers {
+            Some(headers) => StringRecord::from(headers.clone()),
+            None => reader.headers()?.clone(),
+        };
+        Ok((reader, headers))
+    }
+
+    fn chunks<R: Read, P: RowParser>(&self, reader: csv::Reader<R>, parser: P) -> CsvChunks<R, P> {
+        CsvChunks {
+            reader,
+            parser,
+            chunk_size: self.config.chunk_size.max(1),
+            record: StringRecord::new(),
+            row: 0,
+            is_done: false,
+        }
+    }
+
+    /// Returns an iterator over chunks of trades parsed from the `input`.
+    ///
+    /// # Errors
+    ///
+    /// - If the headers cannot be read, or a mapped column is not found.
+    pub fn trades<R: Read>(
+        &self,
+        input: R,
+        columns: &TradeColumns,
+    ) -> Result<CsvChunks<R, TradeParser>, CsvLoaderError> {
+        let (reader, headers) = self.open(input)?;
+        let parser = TradeParser::new(self.config.clone(), &headers, columns)?;
+        Ok(self.chunks(reader, parser))
+    }
+
+    /// Returns an iterator over chunks of quotes parsed from the `input`.
+    ///
+    /// # Errors
+    ///
+    /// - If the headers cannot be read, or a mapped column is not found.
+    pub fn quotes<R: Read>(
+        &self,
+        input: R,
+        columns: &QuoteColumns,
+    ) -> Result<CsvChunks<R, QuoteParser>, CsvLoaderError> {
+        let (reader, headers) = self.open(input)?;
+        let parser = QuoteParser::new(self.config.clone(), &headers, columns)?;
+        Ok(self.chunks(reader, parser))
+    }
+
+    /// Converts the trades in the `input` to Parquet written to the `output`,
+    /// one chunk at a time. Returns the number of rows written.
+    ///
+    /// # Errors
+    ///
+    /// - If any row fails to parse, or writing the output fails.
+    pub fn trades_to_parquet<R: Read, W: Write + Send>(
+        &self,
+        input: R,
+        columns: &TradeColumns,
+        output: W,
+        writer_config: ParquetWriterConfig,
+    ) -> Result<usize, CsvLoaderError> {
+        let chunks = self.trades(input, columns)?;
+        self.write_parquet(chunks, output, writer_config)
+    }
+
+    /// Converts the quotes in the `input` to Parquet written to the `output`,
+    /// one chunk at a time. Returns the number of rows written.
+    ///
+    /// # Errors
+    ///
+    /// - If any row fails to parse, or writing the output fails.
+    pub fn quotes_to_parquet<R: Read, W: Write + Send>(
+        &self,
+        input: R,
+        columns: &QuoteColumns,
+        output: W,
+        writer_config: ParquetWriterConfig,
+    ) -> Result<usize, CsvLoaderError> {
+        let chunks = self.quotes(input, columns)?;
+        self.write_parquet(chunks, output, writer_config)
+    }
+
+    fn write_parquet<T, I, W>(
+        &self,
+        chunks: I,
+        output: W,
+        writer_config: ParquetWriterConfig,
+    ) -> Result<usize, CsvLoaderError>
+    where
+        T: EncodeToRecordBatch,
+        I: Iterator<Item = Result<Vec<T>, CsvLoaderError>>,
+        W: Write + Send,
+    {
+        let mut writer = ParquetWriter::<T, W>::new(output, self.metadata(), writer_config)?;
+        for chunk in chunks {
+            writer.write(&chunk?)?;
+        }
+        let count = writer.count();
+        writer.close()?;
+        Ok(count)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[pyclass(name = "CsvLoader")]
+pub struct PyCsvLoader {
+    loader: CsvLoader,
+}
+
+fn to_py_err(e: CsvLoaderError) -> PyErr {
+    match e {
+        CsvLoaderError::Io(e) => PyIOError::new_err(e.to_string()),
+        e => PyValueError::new_err(e.to_string()),
+    }
+}
+
+#[pymethods]
+impl PyCsvLoader {
+    #[new]
+    #[pyo3(signature = (
+        instrument_id,
+        price_precision,
+        size_precision,
+        timestamp_format="unix_nanos",
+        delimiter=',',
+        headers=None,
+        chunk_size=100_000,
+    ))]
+    fn py_new(
+        instrument_id: &str,
+        price_precision: u8,
+        size_precision: u8,
+        timestamp_format: &str,
+        delimiter: char,
+        headers: Option<Vec<String>>,
+        chunk_size: usize,
+    ) -> PyResult<Self> {
+        let instrument_id = InstrumentId::from_str(instrument_id)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let delimiter = u8::try_from(delimiter)
+            .map_err(|_| PyValueError::new_err("Delimiter must be ASCII"))?;
+
+        let mut config = CsvLoaderConfig::new(instrument_id, price_precision, size_precision);
+        config.timestamp_format = timestamp_format.parse().map_err(PyValueError::new_err)?;
+        config.delimiter = delimiter;
+        config.headers = headers;
+        config.chunk_size = chunk_size;
+        Ok(Self {
+            loader: CsvLoader::new(config),
+        })
+    }
+
+    /// Convert the trades in the CSV file at `input_path` to a Parquet file at
+    /// `output_path`, returning the number of rows written.
+    #[pyo3(signature = (
+        input_path,
+        output_path,
+        ts_event,
+        price,
+        size,
+        aggressor_side=None,
+        trade_id=None,
+        ts_init=None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn trades_to_parquet(
+        &self,
+        input_path: &str,
+        output_path: &str,
+        ts_event: String,
+        price: String,
+        size: String,
+        aggressor_side: Option<String>,
+        trade_id: Option<String>,
+        ts_init: Option<String>,
+    ) -> PyResult<usize> {
+        let columns = TradeColumns {
+            ts_event,
+            ts_init,
+            price,
+            size,
+            aggressor_side,
+            trade_id,
+        };
+        let input = File::open(input_path).map_err(|e| PyIOError::new_err(e.to_string()))?;
+        let output = File::create(output_path).map_err(|e| PyIOError::new_err(e.to_string()))?;
+        self.loader
+            .trades_to_parquet(input, &columns, output, ParquetWriterConfig::default())
+            .map_err(to_py_err)
+    }
+
+    /// Convert the quotes in the CSV file at `input_path` to a Parquet file at
+    /// `output_path`, returning the number of rows written.
+    #[pyo3(signature = (
+        input_path,
+        output_path,
+        ts_event,
+        bid,
+        ask,
+        bid_size=None,
+        ask_size=None,
+        ts_init=None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn quotes_to_parquet(
+        &self,
+        input_path: &str,
+        output_path: &str,
+        ts_event: String,
+        bid: String,
+        ask: String,
+        bid_size: Option<String>,
+        ask_size: Option<String>,
+        ts_init: Option<String>,
+    ) -> PyResult<usize> {
+        let columns = QuoteColumns {
+            ts_event,
+            ts_init,
+            bid,
+            ask,
+            bid_size,
+            ask_size,
+        };
+        let input = File::open(input_path).map_err(|e| PyIOError::new_err(e.to_string()))?;
+        let output = File::create(output_path).map_err(|e| PyIOError::new_err(e.to_string()))?;
+        self.loader
+            .quotes_to_parquet(input, &columns, output, ParquetWriterConfig::default())
+            .map_err(to_py_err)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::Data;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::parquet::{reader::ParquetReader, ParquetType};
+
+    const TRADES_CSV: &str = "\
+timestamp,price,amount,side,id
+1672531200000,16500.5,0.25,buy,t1
+1672531200001,16500.0,1.5,sell,t2
+1672531200002,16499.5,0.75,,t3
+";
+
+    fn config() -> CsvLoaderConfig {
+        let mut config =
+            CsvLoaderConfig::new(InstrumentId::from_str("BTCUSDT.BINANCE").unwrap(), 1, 2);
+        config.timestamp_format = TimestampFormat::UnixMillis;
+        config
+    }
+
+    fn trade_columns() -> TradeColumns {
+        TradeColumns {
+            ts_event: "timestamp".to_string(),
+            ts_init: None,
+            price: "price".to_string(),
+            size: "amount".to_string(),
+            aggressor_side: Some("side".to_string()),
+            trade_id: Some("id".to_string()),
+        }
+    }
+
+    fn quote_columns() -> QuoteColumns {
+        QuoteColumns {
+            ts_event: "time".to_string(),
+            ts_init: None,
+            bid: "bid".to_string(),
+            ask: "ask".to_string(),
+            bid_size: None,
+            ask_size: None,
+        }
+    }
+
+    #[rstest]
+    #[case(
+        TimestampFormat::UnixNanos,
+        "1672531200000000001",
+        1_672_531_200_000_000_001
+    )]
+    #[case(
+        TimestampFormat::UnixMicros,
+        "1672531200000000.5",
+        1_672_531_200_000_000_500
+    )]
+    #[case(
+        TimestampFormat::UnixMillis,
+        "1672531200000",
+        1_672_531_200_000_000_000
+    )]
+    #[case(
+        TimestampFormat::UnixSeconds,
+        "1672531200.123456789",
+        1_672_531_200_123_456_789
+    )]
+    #[case(
+        TimestampFormat::UnixSeconds,
+        "1672531200.1",
+        1_672_531_200_100_000_000
+    )]
+    #[case(
+        TimestampFormat::Rfc3339,
+        "2023-01-01T00:00:00.5Z",
+        1_672_531_200_500_000_000
+    )]
+    #[case(
+        TimestampFormat::Rfc3339,
+        "2023-01-01T01:00:00+01:00",
+        1_672_531_200_000_000_000
+    )]
+    #[case(
+        TimestampFormat::Custom("%Y-%m-%d %H:%M:%S%.f".to_string()),
+        "2023-01-01 00:00:00.25",
+        1_672_531_200_250_000_000
+    )]
+    fn test_timestamp_format_parse(
+        #[case] format: TimestampFormat,
+        #[case] value: &str,
+        #[case] expected: UnixNanos,
+    ) {
+        assert_eq!(format.parse(value).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(TimestampFormat::UnixNanos, "1.5")]
+    #[case(TimestampFormat::UnixMillis, "1.0000001")]
+    #[case(TimestampFormat::UnixSeconds, "-1")]
+    #[case(TimestampFormat::UnixSeconds, "99999999999999999999")]
+    #[case(TimestampFormat::Rfc3339, "1969-12-31T23:59:59Z")]
+    #[case(TimestampFormat::Rfc3339, "2023-01-01")]
+    fn test_timestamp_format_parse_invalid(#[case] format: TimestampFormat, #[case] value: &str) {
+        assert!(format.parse(value).is_err());
+    }
+
+    #[rstest]
+    #[case("unix_nanos", TimestampFormat::UnixNanos)]
+    #[case("rfc3339", TimestampFormat::Rfc3339)]
+    #[case("%Y%m%d %H:%M:%S", TimestampFormat::Custom("%Y%m%d %H:%M:%S".to_string()))]
+    fn test_timestamp_format_from_str(#[case] input: &str, #[case] expected: TimestampFormat) {
+        assert_eq!(TimestampFormat::from_str(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_timestamp_format_from_str_invalid() {
+        assert!(TimestampFormat::from_str("unix_hours").is_err());
+    }
+
+    #[test]
+    fn test_load_trades() {
+        let loader = CsvLoader::new(config());
+
+        let trades: Vec<TradeTick> = loader
+            .trades(TRADES_CSV.as_bytes(), &trade_columns())
+            .unwrap()
+            .flat_map(Result::unwrap)
+            .collect();
+
+        assert_eq!(trades.len(), 3);
+        assert_eq!(trades[0].price, Price::new(16500.5, 1));
+        assert_eq!(trades[0].size, Quantity::new(0.25, 2));
+        assert_eq!(trades[0].aggressor_side, AggressorSide::Buyer);
+        assert_eq!(trades[0].trade_id, TradeId::new("t1"));
+        assert_eq!(trades[0].ts_event, 1_672_531_200_000_000_000);
+        assert_eq!(trades[0].ts_init, trades[0].ts_event);
+        assert_eq!(trades[1].aggressor_side, AggressorSide::Seller);
+        assert_eq!(trades[2].aggressor_side, AggressorSide::NoAggressor);
+    }
+
+    #[test]
+    fn test_load_trades_defaults_trade_id_to_row() {
+        let loader = CsvLoader::new(config());
+        let columns = TradeColumns {
+            aggressor_side: None,
+            trade_id: None,
+            ..trade_columns()
+        };
+
+        let trades: Vec<TradeTick> = loader
+            .trades(TRADES_CSV.as_bytes(), &columns)
+            .unwrap()
+            .flat_map(Result::unwrap)
+            .collect();
+
+        let trade_ids: Vec<TradeId> = trades.iter().map(|t| t.trade_id.clone()).collect();
+        assert_eq!(
+            trade_ids,
+            vec![TradeId::new("1"), TradeId::new("2"), TradeId::new("3")]
+        );
+    }
+
+    #[test]
+    fn test_load_in_chunks() {
+        let mut config = config();
+        config.chunk_size = 2;
+        let loader = CsvLoader::new(config);
+
+        let chunks: Vec<Vec<TradeTick>> = loader
+            .trades(TRADES_CSV.as_bytes(), &trade_columns())
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_load_quotes_headerless_with_delimiter() {
+        let csv = "20230101 00:00:01;16500.0;16500.5\n20230101 00:00:02;16501.0;16501.5\n";
+        let mut config = config();
+        config.delimiter = b';';
+        config.timestamp_format = TimestampFormat::Custom("%Y%m%d %H:%M:%S".to_string());
+        config.headers = Some(vec![
+            "time".to_string(),
+            "bid".to_string(),
+            "ask".to_string(),
+        ]);
+        let loader = CsvLoader::new(config);
+
+        let quotes: Vec<QuoteTick> = loader
+            .quotes(csv.as_bytes(), &quote_columns())
+            .unwrap()
+            .flat_map(Result::unwrap)
+            .collect();
+
+        assert_eq!(quotes.len(), 2);
+        assert_eq!(quotes[0].bid, Price::new(16500.0, 1));
+        assert_eq!(quotes[0].ask, Price::new(16500.5, 1));
+        assert_eq!(quotes[0].bid_size, Quantity::from_raw(0, 2));
+        assert_eq!(quotes[1].ts_event, 1_672_531_202_000_000_000);
+    }
+
+    #[test]
+    fn test_missing_column_returns_error() {
+        let loader = CsvLoader::new(config());
+        let columns = TradeColumns {
+            size: "qty".to_string(),
+            ..trade_columns()
+        };
+
+        let result = loader.trades(TRADES_CSV.as_bytes(), &columns);
+
+        assert!(matches!(result, Err(CsvLoaderError::MissingColumn(column)) if column == "qty"));
+    }
+
+    #[test]
+    fn test_invalid_field_reports_line_and_stops() {
+        let csv =
+            "timestamp,price,amount,side,id\n1,1.0,1.0,buy,a\n2,abc,1.0,buy,b\n3,1.0,1.0,buy,c\n";
+        let mut config = config();
+        config.chunk_size = 1;
+        let loader = CsvLoader::new(config);
+
+        let results: Vec<_> = loader
+            .trades(csv.as_bytes(), &trade_columns())
+            .unwrap()
+            .collect();
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        match &results[1] {
+            Err(CsvLoaderError::InvalidField {
+                line,
+                column,
+                value,
+                ..
+            }) => {
+                assert_eq!(*line, 3);
+                assert_eq!(column, "price");
+                assert_eq!(value, "abc");
+            }
+            _ => panic!("Expected invalid field error"),
+        }
+    }
+
+    #[test]
+    fn test_negative_size_returns_error() {
+        let csv = "timestamp,price,amount,side,id\n1,1.0,-1.0,buy,a\n";
+        let loader = CsvLoader::new(config());
+
+        let result = loader
+            .trades(csv.as_bytes(), &trade_columns())
+            .unwrap()
+            .next()
+            .unwrap();
+
+        assert!(matches!(result, Err(CsvLoaderError::InvalidField { .. })));
+    }
+
+    #[test]
+    fn test_trades_to_parquet_round_trip() {
+        let mut config = config();
+        config.chunk_size = 2;
+        let loader = CsvLoader::new(config);
+        let mut buffer = Vec::new();
+
+        let count = loader
+            .trades_to_parquet(
+                TRADES_CSV.as_bytes(),
+                &trade_columns(),
+                &mut buffer,
+                ParquetWriterConfig::default(),
+            )
+            .unwrap();
+
+        let reader = ParquetReader::from_buffer(buffer, ParquetType::TradeTick, 10).unwrap();
+        assert_eq!(reader.metadata(), &loader.metadata());
+        let decoded: Vec<Data> = reader.flat_map(Result::unwrap).collect();
+        let expected: Vec<TradeTick> = loader
+            .trades(TRADES_CSV.as_bytes(), &trade_columns())
+            .unwrap()
+            .flat_map(Result::unwrap)
+            .collect();
+        assert_eq!(count, 3);
+        assert_eq!(decoded.len(), 3);
+        for (trade, decoded) in expected.iter().zip(decoded) {
+            match decoded {
+                Data::Trade(decoded) => assert_eq!(&decoded, trade),
+                _ => panic!("Expected trade"),
+            }
+        }
+    }
+}
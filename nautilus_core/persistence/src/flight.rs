@@ -0,0 +1,518 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{net::SocketAddr, pin::Pin, sync::Arc};
+
+use arrow_flight::{
+    encode::FlightDataEncoderBuilder, error::FlightError, flight_service_server::FlightService,
+    flight_service_server::FlightServiceServer, Action, ActionType, Criteria, Empty, FlightData,
+    FlightDescriptor, FlightInfo, HandshakeRequest, HandshakeResponse, PutResult, SchemaResult,
+    Ticket,
+};
+use datafusion::arrow::record_batch::RecordBatch;
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use tonic::{metadata::MetadataMap, transport::Server, Request, Response, Status, Streaming};
+
+use crate::{
+    catalog::{CatalogQuery, DataCatalog},
+    parquet::ParquetType,
+};
+
+type BoxedFlightStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + 'static>>;
+
+/// Represents a catalog query sent as the ticket of a Flight `DoGet` request,
+/// encoded as JSON.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlightQuery {
+    /// The catalog filename stem of the data type, e.g. `quote_tick`.
+    pub data_type: String,
+    #[serde(default)]
+    pub instrument_ids: Vec<String>,
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+    pub columns: Option<Vec<String>>,
+    /// The maximum number of rows to return (further capped by the server limit).
+    pub limit: Option<usize>,
+}
+
+impl FlightQuery {
+    /// Parses the query from the JSON bytes of a ticket.
+    ///
+    /// # Errors
+    ///
+    /// - If the ticket is not a valid query, or the data type is unknown.
+    pub fn from_ticket(
+        ticket: &[u8],
+    ) -> Result<(ParquetType, CatalogQuery, Option<usize>), Status> {
+        let query: Self = serde_json::from_slice(ticket)
+            .map_err(|e| Status::invalid_argument(format!("Invalid ticket: {e}")))?;
+        let parquet_type = parse_parquet_type(&query.data_type)?;
+
+        let catalog_query = CatalogQuery {
+            instrument_ids: query.instrument_ids,
+            start: query.start,
+            end: query.end,
+            columns: query.columns,
+        };
+        Ok((parquet_type, catalog_query, query.limit))
+    }
+
+    /// Encodes the query as the bytes of a ticket.
+    #[must_use]
+    pub fn to_ticket(&self) -> Ticket {
+        Ticket {
+            ticket: serde_json::to_vec(self)
+                .expect("Failed to serialize query")
+                .into(),
+        }
+    }
+}
+
+fn parse_parquet_type(data_type: &str) -> Result<ParquetType, Status> {
+    [
+        ParquetType::OrderBookDelta,
+        ParquetType::QuoteTick,
+        ParquetType::TradeTick,
+        ParquetType::Bar,
+    ]
+    .into_iter()
+    .find(|parquet_type| parquet_type.filename() == data_type)
+    .ok_or_else(|| Status::invalid_argument(format!("Unknown data type '{data_type}'")))
+}
+
+/// Provides a hook to authenticate Flight requests.
+///
+/// The `token` is the bearer token of the `authorization` request header, if any.
+pub trait FlightAuthenticator: Send + Sync {
+    /// Returns an error status (typically `unauthenticated`) to reject the request.
+    fn authenticate(&self, token: Option<&str>) -> Result<(), Status>;
+}
+
+/// Accepts all requests.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoAuthenticator;
+
+impl FlightAuthenticator for NoAuthenticator {
+    fn authenticate(&self, _token: Option<&str>) -> Result<(), Status> {
+        Ok(())
+    }
+}
+
+/// Accepts requests with a bearer token matching one of the configured tokens.
+#[derive(Debug, Clone, Default)]
+pub struct StaticTokenAuthenticator {
+    tokens: Vec<String>,
+}
+
+impl StaticTokenAuthenticator {
+    #[must_use]
+    pub fn new(tokens: Vec<String>) -> Self {
+        Self { tokens }
+    }
+}
+
+impl FlightAuthenticator for StaticTokenAuthenticator {
+    fn authenticate(&self, token: Option<&str>) -> Result<(), Status> {
+        match token {
+            Some(token) if self.tokens.iter().any(|t| t == token) => Ok(()),
+            Some(_) => Err(Status::unauthenticated("Invalid token")),
+            None => Err(Status::unauthenticated("Missing bearer token")),
+        }
+    }
+}
+
+fn bearer_token(metadata: &MetadataMap) -> Option<&str> {
+    metadata
+        .get("authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+}
+
+/// Returns the leading batches holding at most `limit` rows in total.
+#[must_use]
+pub fn limit_batches(batches: Vec<RecordBatch>, limit: usize) -> Vec<RecordBatch> {
+    let mut remaining = limit;
+    let mut limited = Vec::new();
+    for batch in batches {
+        if remaining == 0 {
+            break;
+        }
+        let rows = batch.num_rows().min(remaining);
+        remaining -= rows;
+        limited.push(batch.slice(0, rows));
+    }
+    limited
+}
+
+/// Provides an Arrow Flight service streaming data from a [`DataCatalog`].
+///
+/// Clients send a [`FlightQuery`] as the ticket of a `DoGet` request, and
+/// receive the matching record batches ordered by `ts_init` per instrument.
+/// Every request is checked by the configured [`FlightAuthenticator`].
+pub struct CatalogFlightService {
+    catalog: Arc<DataCatalog>,
+    authenticator: Arc<dyn FlightAuthenticator>,
+    max_rows: Option<usize>,
+}
+
+impl CatalogFlightService {
+    /// Initializes a new `CatalogFlightService` instance, with `max_rows`
+    /// limiting the rows returned per query (`None` for unlimited).
+    #[must_use]
+    pub fn new(
+        catalog: Arc<DataCatalog>,
+        authenticator: Arc<dyn FlightAuthenticator>,
+        max_rows: Option<usize>,
+    ) -> Self {
+        Self {
+            catalog,
+            authenticator,
+            max_rows,
+        }
+    }
+
+    /// Returns the effective row limit for a query requesting `limit` rows.
+    #[must_use]
+    pub fn row_limit(&self, limit: Option<usize>) -> Option<usize> {
+        match (limit, self.max_rows) {
+            (Some(limit), Some(max_rows)) => Some(limit.min(max_rows)),
+            (limit, max_rows) => limit.or(max_rows),
+        }
+    }
+
+    /// Serves the service on `addr` until the returned future is dropped.
+    ///
+    /// # Errors
+    ///
+    /// - If the server fails to bind to or serve on the address.
+    pub async fn serve(self, addr: SocketAddr) -> Result<(), tonic::transport::Error> {
+        Server::builder()
+            .add_service(FlightServiceServer::new(self))
+            .serve(addr)
+            .await
+    }
+
+    fn authenticate<T>(&self, request: &Request<T>) -> Result<(), Status> {
+        self.authenticator
+            .authenticate(bearer_token(request.metadata()))
+    }
+}
+
+#[tonic::async_trait]
+impl FlightService for CatalogFlightService {
+    type HandshakeStream = BoxedFlightStream<HandshakeResponse>;
+    type ListFlightsStream = BoxedFlightStream<FlightInfo>;
+    type DoGetStream = BoxedFlightStream<FlightData>;
+    type DoPutStream = BoxedFlightStream<PutResult>;
+    type DoActionStream = BoxedFlightStream<arrow_flight::Result>;
+    type ListActionsStream = BoxedFlightStream<ActionType>;
+    type DoExchangeStream = BoxedFlightStream<FlightData>;
+
+    async fn handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        self.authenticate(&request)?;
+
+        let response = HandshakeResponse {
+            protocol_version: 0,
+            payload: bearer_token(request.metadata())
+                .unwrap_or_default()
+                .as_bytes()
+                .to_vec()
+                .into(),
+        };
+        Ok(Response::new(Box::pin(stream::iter([Ok(response)]))))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        self.authenticate(&request)?;
+
+        let (parquet_type, query, limit) = FlightQuery::from_ticket(&request.get_ref().ticket)?;
+        let mut batches = self
+            .catalog
+            .query(parquet_type, &query)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        if let Some(limit) = self.row_limit(limit) {
+            batches = limit_batches(batches, limit);
+        }
+
+        let batch_stream = stream::iter(batches.into_iter().map(Ok::<_, FlightError>));
+        let flight_stream = FlightDataEncoderBuilder::new()
+            .build(batch_stream)
+            .map_err(|e| Status::internal(e.to_string()));
+        Ok(Response::new(flight_stream.boxed()))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        Err(Status::unimplemented("`list_flights` is not supported"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("`get_flight_info` is not supported"))
+    }
+
+    async fn get_schema(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        Err(Status::unimplemented("`get_schema` is not supported"))
+    }
+
+    async fn do_put(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        Err(Status::unimplemented("The catalog is read only"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("`do_action` is not supported"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        Ok(Response::new(Box::pin(stream::empty())))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("`do_exchange` is not supported"))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, str::FromStr};
+
+    use arrow_flight::decode::FlightRecordBatchStream;
+    use datafusion::arrow::{
+        array::UInt64Array,
+        datatypes::{DataType, Field, Schema},
+    };
+    use nautilus_model::{
+        data::tick::QuoteTick,
+        identifiers::instrument_id::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+    use tempfile::{tempdir, TempDir};
+    use tonic::Code;
+
+    use super::*;
+    use crate::{catalog::write::WriteMode, parquet::writer::ParquetWriterConfig};
+
+    const INSTRUMENT_ID: &str = "AAPL.NASDAQ";
+
+    fn setup_catalog() -> (TempDir, Arc<DataCatalog>) {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let catalog = DataCatalog::new(temp_dir.path());
+
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), INSTRUMENT_ID.to_string());
+        metadata.insert("price_precision".to_string(), "2".to_string());
+        metadata.insert("size_precision".to_string(), "0".to_string());
+        let data: Vec<QuoteTick> = (1..=10)
+            .map(|ts_init| QuoteTick {
+                instrument_id: InstrumentId::from_str(INSTRUMENT_ID).unwrap(),
+                bid: Price::from_raw(10_000, 2),
+                ask: Price::from_raw(10_100, 2),
+                bid_size: Quantity::from_raw(100, 0),
+                ask_size: Quantity::from_raw(100, 0),
+                ts_event: ts_init,
+                ts_init,
+            })
+            .collect();
+        catalog
+            .write_data(
+                ParquetType::QuoteTick,
+                INSTRUMENT_ID,
+                metadata,
+                data,
+                WriteMode::NewFile,
+                ParquetWriterConfig::default(),
+            )
+            .unwrap();
+
+        (temp_dir, Arc::new(catalog))
+    }
+
+    fn ticket(limit: Option<usize>) -> Ticket {
+        FlightQuery {
+            data_type: "quote_tick".to_string(),
+            instrument_ids: vec![INSTRUMENT_ID.to_string()],
+            start: Some(3),
+            limit,
+            ..Default::default()
+        }
+        .to_ticket()
+    }
+
+    async fn do_get_ts_inits(
+        service: &CatalogFlightService,
+        request: Request<Ticket>,
+    ) -> Result<Vec<u64>, Status> {
+        let response = service.do_get(request).await?;
+        let stream = response.into_inner().map_err(FlightError::from);
+        let batches: Vec<RecordBatch> = FlightRecordBatchStream::new_from_flight_data(stream)
+            .try_collect()
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(batches
+            .iter()
+            .flat_map(|batch| {
+                let column = batch.column_by_name("ts_init").unwrap();
+                let values = column.as_any().downcast_ref::<UInt64Array>().unwrap();
+                values.values().to_vec()
+            })
+            .collect())
+    }
+
+    fn batch(rows: u64) -> RecordBatch {
+        let schema = Schema::new(vec![Field::new("ts_init", DataType::UInt64, false)]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(UInt64Array::from_iter_values(0..rows))],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_ticket_round_trip() {
+        let ticket = ticket(Some(5));
+
+        let (parquet_type, query, limit) = FlightQuery::from_ticket(&ticket.ticket).unwrap();
+
+        assert_eq!(parquet_type.filename(), "quote_tick");
+        assert_eq!(query.instrument_ids, vec![INSTRUMENT_ID.to_string()]);
+        assert_eq!(query.start, Some(3));
+        assert_eq!(limit, Some(5));
+    }
+
+    #[rstest]
+    #[case(b"not json".as_slice())]
+    #[case(br#"{"data_type": "order_book"}"#.as_slice())]
+    fn test_invalid_ticket_returns_invalid_argument(#[case] ticket: &[u8]) {
+        let result = FlightQuery::from_ticket(ticket);
+
+        assert_eq!(result.unwrap_err().code(), Code::InvalidArgument);
+    }
+
+    #[rstest]
+    #[case(vec![3, 4], 5, vec![3, 2])]
+    #[case(vec![3, 4], 3, vec![3])]
+    #[case(vec![3, 4], 10, vec![3, 4])]
+    #[case(vec![3, 4], 0, vec![])]
+    fn test_limit_batches(
+        #[case] rows: Vec<u64>,
+        #[case] limit: usize,
+        #[case] expected: Vec<usize>,
+    ) {
+        let batches = rows.into_iter().map(batch).collect();
+
+        let limited = limit_batches(batches, limit);
+
+        assert_eq!(
+            limited
+                .iter()
+                .map(RecordBatch::num_rows)
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(None, None, None)]
+    #[case(Some(5), None, Some(5))]
+    #[case(None, Some(5), Some(5))]
+    #[case(Some(3), Some(5), Some(3))]
+    #[case(Some(8), Some(5), Some(5))]
+    fn test_row_limit(
+        #[case] limit: Option<usize>,
+        #[case] max_rows: Option<usize>,
+        #[case] expected: Option<usize>,
+    ) {
+        let (_temp_dir, catalog) = setup_catalog();
+        let service = CatalogFlightService::new(catalog, Arc::new(NoAuthenticator), max_rows);
+
+        assert_eq!(service.row_limit(limit), expected);
+    }
+
+    #[rstest]
+    #[case(None, Code::Unauthenticated)]
+    #[case(Some("wrong"), Code::Unauthenticated)]
+    fn test_static_token_rejects(#[case] token: Option<&str>, #[case] expected: Code) {
+        let authenticator = StaticTokenAuthenticator::new(vec!["secret".to_string()]);
+
+        let result = authenticator.authenticate(token);
+
+        assert_eq!(result.unwrap_err().code(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_do_get_streams_query_with_limits() {
+        let (_temp_dir, catalog) = setup_catalog();
+        let service = CatalogFlightService::new(catalog, Arc::new(NoAuthenticator), Some(4));
+
+        let all = do_get_ts_inits(&service, Request::new(ticket(None)))
+            .await
+            .unwrap();
+        let limited = do_get_ts_inits(&service, Request::new(ticket(Some(2))))
+            .await
+            .unwrap();
+
+        assert_eq!(all, vec![3, 4, 5, 6]);
+        assert_eq!(limited, vec![3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_do_get_checks_bearer_token() {
+        let (_temp_dir, catalog) = setup_catalog();
+        let authenticator = StaticTokenAuthenticator::new(vec!["secret".to_string()]);
+        let service = CatalogFlightService::new(catalog, Arc::new(authenticator), None);
+
+        let unauthenticated = do_get_ts_inits(&service, Request::new(ticket(None))).await;
+        let mut request = Request::new(ticket(None));
+        request
+            .metadata_mut()
+            .insert("authorization", "Bearer secret".parse().unwrap());
+        let authenticated = do_get_ts_inits(&service, request).await;
+
+        assert_eq!(unauthenticated.unwrap_err().code(), Code::Unauthenticated);
+        assert_eq!(authenticated.unwrap(), (3..=10).collect::<Vec<_>>());
+    }
+}
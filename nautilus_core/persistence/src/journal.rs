@@ -0,0 +1,547 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Event sourced persistence of trading state.
+//!
+//! State is journaled as the stream of [`JournalRecord`]s which produced it, written as
+//! length prefixed MessagePack records. Replaying a journal into an empty [`Cache`] rebuilds
+//! the orders, positions, accounts and market data deterministically, so a journal can be
+//! used both to recover from a crash and to resume a backtest from a point in time.
+//!
+//! Instruments are reference data and are not journaled, they must be added to the cache
+//! before replaying.
+
+use std::io::{self, Read, Write};
+
+use nautilus_common::cache::{Cache, CacheError};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    accounts::{any::AccountAny, cash::CashAccount, margin::MarginAccount, AccountError},
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::AccountType,
+    events::{
+        account::AccountState,
+        order::{OrderEvent, OrderFilled, OrderInitialized},
+    },
+    identifiers::{client_order_id::ClientOrderId, instrument_id::InstrumentId},
+    orders::{Order, OrderError},
+    position::Position,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum JournalError {
+    #[error("Journal IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Failed to encode record: {0}")]
+    Encode(#[from] rmp_serde::encode::Error),
+    #[error("Failed to decode record: {0}")]
+    Decode(#[from] rmp_serde::decode::Error),
+    #[error("Journal truncated within record {0}")]
+    Truncated(usize),
+    #[error(transparent)]
+    Cache(#[from] CacheError),
+    #[error(transparent)]
+    Order(#[from] OrderError),
+    #[error(transparent)]
+    Account(#[from] AccountError),
+    #[error("Instrument {0} not found in the cache")]
+    InstrumentNotFound(InstrumentId),
+    #[error("Fill for order {0} has no position ID")]
+    MissingPositionId(ClientOrderId),
+    #[error("Unsupported account type {0}")]
+    UnsupportedAccountType(AccountType),
+}
+
+/// Represents a single record of a state journal.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum JournalRecord {
+    Order(OrderEvent),
+    Account(AccountState),
+    Quote(QuoteTick),
+    Trade(TradeTick),
+    Bar(Bar),
+}
+
+impl JournalRecord {
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            JournalRecord::Order(event) => event.ts_init(),
+            JournalRecord::Account(state) => state.ts_init,
+            JournalRecord::Quote(tick) => tick.ts_init,
+            JournalRecord::Trade(tick) => tick.ts_init,
+            JournalRecord::Bar(bar) => bar.ts_init,
+        }
+    }
+}
+
+/// Provides an append only writer of journal records.
+///
+/// Each record is written as its encoded length (a little endian `u32`) followed by the
+/// MessagePack encoding of the record.
+pub struct JournalWriter<W: Write> {
+    writer: W,
+    count: usize,
+}
+
+impl<W: Write> JournalWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer, count: 0 }
+    }
+
+    /// Returns the count of records written.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn append(&mut self, record: &JournalRecord) -> Result<(), JournalError> {
+        let bytes = rmp_serde::to_vec_named(record)?;
+        let len = u32::try_from(bytes.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "record too large"))?;
+        self.writer.write_all(&len.to_le_bytes())?;
+        self.writer.write_all(&bytes)?;
+        self.count += 1;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), JournalError> {
+        Ok(self.writer.flush()?)
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Provides an iterator over the records of a journal written by a [`JournalWriter`].
+///
+/// A record which was only partially written (for instance on a crash) is returned as a
+/// [`JournalError::Truncated`] error, after which iteration ends.
+pub struct JournalReader<R: Read> {
+    reader: R,
+    count: usize,
+    done: bool,
+}
+
+impl<R: Read> JournalReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            count: 0,
+            done: false,
+        }
+    }
+
+    fn read_record(&mut self) -> Result<Option<JournalRecord>, JournalError> {
+        let mut len = [0u8; 4];
+        let mut read = 0;
+        while read < len.len() {
+            match self.reader.read(&mut len[read..])? {
+                0 if read == 0 => return Ok(None), // End of journal
+                0 => return Err(JournalError::Truncated(self.count)),
+                n => read += n,
+            }
+        }
+
+        let mut bytes = vec![0u8; u32::from_le_bytes(len) as usize];
+        self.reader
+            .read_exact(&mut bytes)
+            .map_err(|e| match e.kind() {
+                io::ErrorKind::UnexpectedEof => JournalError::Truncated(self.count),
+                _ => JournalError::Io(e),
+            })?;
+        self.count += 1;
+        Ok(Some(rmp_serde::from_slice(&bytes)?))
+    }
+}
+
+impl<R: Read> Iterator for JournalReader<R> {
+    type Item = Result<JournalRecord, JournalError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.read_record().transpose();
+        if !matches!(result, Some(Ok(_))) {
+            self.done = true;
+        }
+        result
+    }
+}
+
+/// Returns the records which rebuild the orders, positions, accounts and market data held
+/// by the `cache`, in `ts_init` order.
+///
+/// The initialization event of each order is rebuilt from its current fields, which is
+/// equivalent once its subsequent events (including any updates) have been replayed.
+#[must_use]
+pub fn snapshot_records(cache: &Cache) -> Vec<JournalRecord> {
+    let snapshot = cache.snapshot();
+    let mut records = Vec::new();
+
+    for account in &snapshot.accounts {
+        records.extend(account.events().iter().cloned().map(JournalRecord::Account));
+    }
+
+    let mut orders = snapshot.orders;
+    orders.sort_by(|a, b| {
+        a.ts_init
+            .cmp(&b.ts_init)
+            .then_with(|| a.client_order_id.value.cmp(&b.client_order_id.value))
+    });
+    for order in &orders {
+        let init = OrderEvent::OrderInitialized(OrderInitialized::from(order));
+        records.push(JournalRecord::Order(init));
+        records.extend(order.events().into_iter().map(JournalRecord::Order));
+    }
+
+    // Market data is held newest first
+    for (_, ticks) in snapshot.quote_ticks {
+        records.extend(ticks.into_iter().rev().map(JournalRecord::Quote));
+    }
+    for (_, ticks) in snapshot.trade_ticks {
+        records.extend(ticks.into_iter().rev().map(JournalRecord::Trade));
+    }
+    for (_, bars) in snapshot.bars {
+        records.extend(bars.into_iter().rev().map(JournalRecord::Bar));
+    }
+
+    // Stable, so the order of records with equal timestamps is retained
+    records.sort_by_key(JournalRecord::ts_init);
+    records
+}
+
+/// Writes the snapshot records of the `cache` to the `writer`, returning the count written.
+pub fn write_snapshot<W: Write>(
+    cache: &Cache,
+    writer: &mut JournalWriter<W>,
+) -> Result<usize, JournalError> {
+    let records = snapshot_records(cache);
+    for record in &records {
+        writer.append(record)?;
+    }
+    writer.flush()?;
+    Ok(records.len())
+}
+
+/// Replays the `records` into the `cache`, returning the count of records applied.
+///
+/// Records are expected in `ts_init` order. If `until` is given then replay stops at the
+/// first record with a `ts_init` after it, leaving the cache in its state as at `until`.
+pub fn replay(
+    cache: &mut Cache,
+    records: impl IntoIterator<Item = Result<JournalRecord, JournalError>>,
+    until: Option<UnixNanos>,
+) -> Result<usize, JournalError> {
+    let mut count = 0;
+    for record in records {
+        let record = record?;
+        if until.map_or(false, |until| record.ts_init() > until) {
+            break;
+        }
+        apply_record(cache, record)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Applies the single `record` to the `cache`.
+pub fn apply_record(cache: &mut Cache, record: JournalRecord) -> Result<(), JournalError> {
+    match record {
+        JournalRecord::Order(event) => apply_order_event(cache, event),
+        JournalRecord::Account(state) => apply_account_state(cache, state),
+        JournalRecord::Quote(tick) => {
+            cache.add_quote_tick(tick);
+            Ok(())
+        }
+        JournalRecord::Trade(tick) => {
+            cache.add_trade_tick(tick);
+            Ok(())
+        }
+        JournalRecord::Bar(bar) => {
+            cache.add_bar(bar);
+            Ok(())
+        }
+    }
+}
+
+fn apply_order_event(cache: &mut Cache, event: OrderEvent) -> Result<(), JournalError> {
+    if let OrderEvent::OrderInitialized(init) = event {
+        cache.add_order(Order::from(init), None)?;
+        return Ok(());
+    }
+
+    let client_order_id = event.client_order_id().clone();
+    let mut order = cache
+        .order(&client_order_id)
+        .cloned()
+        .ok_or(CacheError::OrderNotFound(client_order_id))?;
+    let fill = match &event {
+        OrderEvent::OrderPartiallyFilled(fill) | OrderEvent::OrderFilled(fill) => {
+            Some(fill.clone())
+        }
+        _ => None,
+    };
+    order.apply(event)?;
+    cache.update_order(order)?;
+
+    if let Some(fill) = fill {
+        apply_fill(cache, fill)?;
+    }
+    Ok(())
+}
+
+fn apply_fill(cache: &mut Cache, fill: OrderFilled) -> Result<(), JournalError> {
+    let position_id = fill
+        .position_id
+        .clone()
+        .ok_or_else(|| JournalError::MissingPositionId(fill.client_order_id.clone()))?;
+
+    match cache.position(&position_id).cloned() {
+        Some(mut position) => {
+            position.apply(fill);
+            cache.update_position(position)?;
+        }
+        None => {
+            let instrument = cache
+                .instrument(&fill.instrument_id)
+                .ok_or_else(|| JournalError::InstrumentNotFound(fill.instrument_id.clone()))?;
+            let position = Position::new(instrument, fill);
+            cache.add_position(position)?;
+        }
+    }
+    Ok(())
+}
+
+/// Account states are recorded as reported, so are not recalculated on replay.
+fn apply_account_state(cache: &mut Cache, state: AccountState) -> Result<(), JournalError> {
+    let account = match cache.account(&state.account_id).cloned() {
+        Some(mut account) => {
+            account.apply(state)?;
+            account
+        }
+        None => match state.account_type {
+            AccountType::Cash => AccountAny::from(CashAccount::new(state, false)?),
+            AccountType::Margin => AccountAny::from(MarginAccount::new(state, false)?),
+            account_type => return Err(JournalError::UnsupportedAccountType(account_type)),
+        },
+    };
+    cache.add_account(account);
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        currencies::USD,
+        enums::{LiquiditySide, OrderSide, OrderStatus, OrderType},
+        events::order::{OrderAcceptedBuilder, OrderInitializedBuilder, OrderSubmittedBuilder},
+        identifiers::{
+            account_id::AccountId, position_id::PositionId, trade_id::TradeId,
+            venue_order_id::VenueOrderId,
+        },
+        types::{money::Money, price::Price, quantity::Quantity},
+    };
+    use nautilus_test_kit::instruments::audusd_sim;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(ts: UnixNanos) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            bid: Price::from("1.00000"),
+            ask: Price::from("1.00001"),
+            bid_size: Quantity::from("1000000"),
+            ask_size: Quantity::from("1000000"),
+            ts_event: ts,
+            ts_init: ts,
+        }
+    }
+
+    fn fill(client_order_id: &ClientOrderId, side: OrderSide, ts: UnixNanos) -> OrderEvent {
+        OrderEvent::OrderFilled(OrderFilled {
+            trader_id: Default::default(),
+            strategy_id: Default::default(),
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            client_order_id: client_order_id.clone(),
+            venue_order_id: VenueOrderId::default(),
+            account_id: AccountId::default(),
+            trade_id: TradeId::new(&format!("T-{ts}")),
+            position_id: Some(PositionId::new("P-1")),
+            order_side: side,
+            order_type: OrderType::Market,
+            last_qty: Quantity::from("100000"),
+            last_px: Price::from("1.00001"),
+            currency: USD.clone(),
+            commission: Money::new(0.0, USD.clone()),
+            liquidity_side: LiquiditySide::Taker,
+            event_id: UUID4::default(),
+            ts_event: ts,
+            ts_init: ts,
+            reconciliation: false,
+        })
+    }
+
+    /// Returns the records for an order of `side` being submitted, accepted and filled.
+    fn order_records(client_order_id: &str, side: OrderSide, ts: UnixNanos) -> Vec<JournalRecord> {
+        let client_order_id = ClientOrderId::new(client_order_id);
+        let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id.clone())
+            .client_order_id(client_order_id.clone())
+            .order_side(side)
+            .ts_event(ts)
+            .ts_init(ts)
+            .build()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default()
+            .instrument_id(instrument_id.clone())
+            .client_order_id(client_order_id.clone())
+            .ts_init(ts)
+            .build()
+            .unwrap();
+        let accepted = OrderAcceptedBuilder::default()
+            .instrument_id(instrument_id)
+            .client_order_id(client_order_id.clone())
+            .ts_init(ts)
+            .build()
+            .unwrap();
+        vec![
+            JournalRecord::Order(OrderEvent::OrderInitialized(init)),
+            JournalRecord::Order(OrderEvent::OrderSubmitted(submitted)),
+            JournalRecord::Order(OrderEvent::OrderAccepted(accepted)),
+            JournalRecord::Order(fill(&client_order_id, side, ts + 1)),
+        ]
+    }
+
+    fn records() -> Vec<JournalRecord> {
+        let mut records = vec![JournalRecord::Quote(quote(1))];
+        records.extend(order_records("O-1", OrderSide::Buy, 2));
+        records.push(JournalRecord::Quote(quote(4)));
+        records.extend(order_records("O-2", OrderSide::Sell, 5));
+        records
+    }
+
+    fn cache() -> Cache {
+        let mut cache = Cache::default();
+        cache.add_instrument(audusd_sim());
+        cache
+    }
+
+    fn write(records: &[JournalRecord]) -> Vec<u8> {
+        let mut writer = JournalWriter::new(Vec::new());
+        for record in records {
+            writer.append(record).unwrap();
+        }
+        writer.into_inner()
+    }
+
+    #[rstest]
+    fn test_journal_round_trip() {
+        let records = records();
+        let bytes = write(&records);
+
+        let result: Vec<JournalRecord> = JournalReader::new(bytes.as_slice())
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(result, records);
+    }
+
+    #[rstest]
+    fn test_journal_truncated_record() {
+        let mut bytes = write(&records());
+        bytes.truncate(bytes.len() - 1);
+
+        let result: Vec<Result<JournalRecord, JournalError>> =
+            JournalReader::new(bytes.as_slice()).collect();
+
+        assert_eq!(result.len(), records().len());
+        assert!(matches!(
+            result.last(),
+            Some(Err(JournalError::Truncated(count))) if *count == records().len() - 1
+        ));
+    }
+
+    #[rstest]
+    fn test_replay_rebuilds_orders_and_positions() {
+        let mut cache = cache();
+
+        let count = replay(&mut cache, records().into_iter().map(Ok), None).unwrap();
+
+        assert_eq!(count, 10);
+        assert_eq!(cache.orders_closed_count(None, None, None, None), 2);
+        assert_eq!(cache.positions_closed_count(None, None, None, None), 1);
+        assert_eq!(cache.quote_tick(&audusd_sim().id).unwrap().ts_init, 4);
+        assert_eq!(
+            cache.order(&ClientOrderId::new("O-1")).unwrap().position_id,
+            Some(PositionId::new("P-1"))
+        );
+    }
+
+    #[rstest]
+    fn test_replay_until_timestamp() {
+        let mut cache = cache();
+
+        let count = replay(&mut cache, records().into_iter().map(Ok), Some(4)).unwrap();
+
+        assert_eq!(count, 6);
+        assert_eq!(cache.orders_count(None, None, None, None), 1);
+        let position = cache.position(&PositionId::new("P-1")).unwrap();
+        assert!(position.is_open());
+        assert_eq!(position.signed_qty, 100_000.0);
+    }
+
+    #[rstest]
+    fn test_replay_fill_without_instrument() {
+        let mut cache = Cache::default();
+
+        let result = replay(&mut cache, records().into_iter().map(Ok), None);
+
+        assert!(matches!(result, Err(JournalError::InstrumentNotFound(_))));
+    }
+
+    #[rstest]
+    fn test_snapshot_replay_is_deterministic() {
+        let mut cache = cache();
+        replay(&mut cache, records().into_iter().map(Ok), Some(4)).unwrap();
+        let mut writer = JournalWriter::new(Vec::new());
+
+        let count = write_snapshot(&cache, &mut writer).unwrap();
+        let bytes = writer.into_inner();
+        let mut restored = self::cache();
+        replay(&mut restored, JournalReader::new(bytes.as_slice()), None).unwrap();
+
+        assert_eq!(count, 6);
+        assert_eq!(snapshot_records(&restored), snapshot_records(&cache));
+        let order = restored.order(&ClientOrderId::new("O-1")).unwrap();
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(restored.positions_open_count(None, None, None, None), 1);
+    }
+}
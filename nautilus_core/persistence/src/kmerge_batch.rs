@@ -38,17 +38,18 @@ where
         // and add the new element to the heap. No new element is added
         // to the heap if the stream is empty. Keep polling the stream
         // for a batch that is non-empty.
-        let next_batch = stream.next().await;
-        if let Some(mut batch) = next_batch {
-            batch.next().map(|next_item| Self {
-                item: next_item,
-                batch,
-                stream,
-            })
-        } else {
-            // Stream is empty, no new batch
-            None
+        while let Some(mut batch) = stream.next().await {
+            if let Some(next_item) = batch.next() {
+                return Some(Self {
+                    item: next_item,
+                    batch,
+                    stream,
+                });
+            }
         }
+
+        // Stream is empty, no new batch
+        None
     }
 }
 
@@ -189,4 +190,21 @@ mod tests {
             vec![1, 2, 3, 4, 4, 5, 7, 8, 9, 12, 12, 24, 35, 56, 90]
         )
     }
+
+    #[tokio::test]
+    async fn test_empty_batches_are_skipped() {
+        let stream_a = iter(vec![
+            vec![].into_iter(),
+            vec![1, 3].into_iter(),
+            vec![].into_iter(),
+            vec![5].into_iter(),
+        ]);
+        let stream_b = iter(vec![vec![2, 4].into_iter()]);
+        let mut kmerge: KMerge<_, i32, _> = KMerge::new(OrdComparator);
+        kmerge.push_stream(stream_a).await;
+        kmerge.push_stream(stream_b).await;
+
+        let values: Vec<i32> = kmerge.collect().await;
+        assert_eq!(values, vec![1, 2, 3, 4, 5])
+    }
 }
@@ -13,11 +13,20 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod catalog;
+pub mod csv_loader;
+#[cfg(feature = "flight")]
+pub mod flight;
+pub mod journal;
 mod kmerge_batch;
+pub mod merge;
 pub mod parquet;
+pub mod resample;
 pub mod session;
 
-use parquet::ParquetType;
+use catalog::PyDataCatalog;
+use csv_loader::PyCsvLoader;
+use parquet::{reader::PyParquetReader, ParquetReaderType, ParquetType};
 use pyo3::prelude::*;
 use session::{DataBackendSession, DataQueryResult};
 
@@ -25,7 +34,11 @@ use session::{DataBackendSession, DataQueryResult};
 #[pymodule]
 pub fn persistence(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<ParquetType>()?;
+    m.add_class::<ParquetReaderType>()?;
+    m.add_class::<PyParquetReader>()?;
     m.add_class::<DataBackendSession>()?;
     m.add_class::<DataQueryResult>()?;
+    m.add_class::<PyDataCatalog>()?;
+    m.add_class::<PyCsvLoader>()?;
     Ok(())
 }
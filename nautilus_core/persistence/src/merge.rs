@@ -0,0 +1,334 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{cmp::Ordering, collections::BinaryHeap, vec::IntoIter};
+
+use nautilus_model::data::Data;
+
+/// Represents the next element of a stream held in the merge heap, along with
+/// the remainder of its current batch and the stream itself.
+///
+/// Elements are ordered by `ts_init`, with ties broken by the order in which
+/// the streams were added, so the merge is stable.
+pub struct HeapedData<I>
+where
+    I: Iterator<Item = Vec<Data>>,
+{
+    pub item: Data,
+    index: usize,
+    batch: IntoIter<Data>,
+    stream: I,
+}
+
+impl<I> HeapedData<I>
+where
+    I: Iterator<Item = Vec<Data>>,
+{
+    /// Pulls batches from the `stream` until one is non-empty, returning `None`
+    /// if the stream is exhausted.
+    fn from_stream(mut stream: I, index: usize) -> Option<Self> {
+        loop {
+            let mut batch = stream.next()?.into_iter();
+            if let Some(item) = batch.next() {
+                return Some(Self {
+                    item,
+                    index,
+                    batch,
+                    stream,
+                });
+            }
+        }
+    }
+
+    /// Advances to the next element, pulling a new batch from the stream when
+    /// the current one is exhausted. Returns the current element, and the heap
+    /// element for the stream if it has more data.
+    fn advance(mut self) -> (Data, Option<Self>) {
+        match self.batch.next() {
+            Some(next_item) => {
+                let item = std::mem::replace(&mut self.item, next_item);
+                (item, Some(self))
+            }
+            None => (self.item, Self::from_stream(self.stream, self.index)),
+        }
+    }
+
+    fn key(&self) -> (u64, usize) {
+        (self.item.get_ts_init(), self.index)
+    }
+}
+
+impl<I> PartialEq for HeapedData<I>
+where
+    I: Iterator<Item = Vec<Data>>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<I> Eq for HeapedData<I> where I: Iterator<Item = Vec<Data>> {}
+
+impl<I> PartialOrd for HeapedData<I>
+where
+    I: Iterator<Item = Vec<Data>>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I> Ord for HeapedData<I>
+where
+    I: Iterator<Item = Vec<Data>>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Max heap ordering must be reversed
+        self.key().cmp(&other.key()).reverse()
+    }
+}
+
+/// Provides a k-way merge of multiple data streams into a single stream ordered
+/// by `ts_init`.
+///
+/// Each stream yields batches of [`Data`] which must already be sorted by
+/// `ts_init`, and may hold any mix of data types. Only the current batch of each
+/// stream is held in memory.
+pub struct DataMerge<I>
+where
+    I: Iterator<Item = Vec<Data>>,
+{
+    heap: BinaryHeap<HeapedData<I>>,
+    stream_count: usize,
+}
+
+impl<I> Default for DataMerge<I>
+where
+    I: Iterator<Item = Vec<Data>>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I> DataMerge<I>
+where
+    I: Iterator<Item = Vec<Data>>,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            stream_count: 0,
+        }
+    }
+
+    /// Adds the `stream` to the merge, empty streams are ignored.
+    pub fn push_stream(&mut self, stream: I) {
+        if let Some(heap_elem) = HeapedData::from_stream(stream, self.stream_count) {
+            self.heap.push(heap_elem);
+        }
+        self.stream_count += 1;
+    }
+
+    /// Returns the number of streams which still have data.
+    #[must_use]
+    pub fn active_streams(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns the `ts_init` of the next element, if any.
+    #[must_use]
+    pub fn peek_ts_init(&self) -> Option<u64> {
+        self.heap
+            .peek()
+            .map(|heap_elem| heap_elem.item.get_ts_init())
+    }
+
+    /// Returns the next chunk of up to `chunk_size` elements in `ts_init` order,
+    /// or `None` if all streams are exhausted.
+    pub fn next_chunk(&mut self, chunk_size: usize) -> Option<Vec<Data>> {
+        let chunk: Vec<Data> = self.by_ref().take(chunk_size).collect();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+}
+
+impl<I> Iterator for DataMerge<I>
+where
+    I: Iterator<Item = Vec<Data>>,
+{
+    type Item = Data;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, heap_elem) = self.heap.pop()?.advance();
+        if let Some(heap_elem) = heap_elem {
+            self.heap.push(heap_elem);
+        }
+        Some(item)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, str::FromStr};
+
+    use nautilus_model::{
+        data::tick::{QuoteTick, TradeTick},
+        enums::AggressorSide,
+        identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+        types::{price::Price, quantity::Quantity},
+    };
+
+    use super::*;
+    use crate::parquet::{
+        reader::ParquetReader,
+        writer::{ParquetWriter, ParquetWriterConfig},
+        EncodeToRecordBatch, ParquetType,
+    };
+
+    fn quote(ts_init: u64) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from_str("AAPL.NASDAQ").unwrap(),
+            bid: Price::from_raw(10_000, 2),
+            ask: Price::from_raw(10_100, 2),
+            bid_size: Quantity::from_raw(100, 0),
+            ask_size: Quantity::from_raw(100, 0),
+            ts_event: ts_init,
+            ts_init,
+        }
+    }
+
+    fn trade(ts_init: u64) -> TradeTick {
+        TradeTick {
+            instrument_id: InstrumentId::from_str("AAPL.NASDAQ").unwrap(),
+            price: Price::from_raw(10_050, 2),
+            size: Quantity::from_raw(10, 0),
+            aggressor_side: AggressorSide::Buyer,
+            trade_id: TradeId::new(&ts_init.to_string()),
+            ts_event: ts_init,
+            ts_init,
+        }
+    }
+
+    fn quote_batches(batches: Vec<Vec<u64>>) -> IntoIter<Vec<Data>> {
+        batches
+            .into_iter()
+            .map(|batch| batch.into_iter().map(|ts| quote(ts).into()).collect())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn ts_inits(data: &[Data]) -> Vec<u64> {
+        data.iter().map(Data::get_ts_init).collect()
+    }
+
+    fn write_buffer<T: EncodeToRecordBatch>(data: &[T]) -> Vec<u8> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), "AAPL.NASDAQ".to_string());
+        metadata.insert("price_precision".to_string(), "2".to_string());
+        metadata.insert("size_precision".to_string(), "0".to_string());
+
+        let mut buffer = Vec::new();
+        let mut writer = ParquetWriter::<T, &mut Vec<u8>>::new(
+            &mut buffer,
+            metadata,
+            ParquetWriterConfig::default(),
+        )
+        .unwrap();
+        writer.write(data).unwrap();
+        writer.close().unwrap();
+        buffer
+    }
+
+    #[test]
+    fn test_merge_interleaved_streams() {
+        let mut merge = DataMerge::new();
+        merge.push_stream(quote_batches(vec![vec![1, 2, 6], vec![7, 8, 9]]));
+        merge.push_stream(quote_batches(vec![vec![3, 4, 5, 6]]));
+
+        let data: Vec<Data> = merge.collect();
+
+        assert_eq!(ts_inits(&data), vec![1, 2, 3, 4, 5, 6, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_merge_skips_empty_batches_and_streams() {
+        let mut merge = DataMerge::new();
+        merge.push_stream(quote_batches(vec![vec![], vec![2], vec![], vec![4]]));
+        merge.push_stream(quote_batches(vec![]));
+        merge.push_stream(quote_batches(vec![vec![1, 3]]));
+
+        assert_eq!(merge.active_streams(), 2);
+        assert_eq!(merge.peek_ts_init(), Some(1));
+
+        let data: Vec<Data> = merge.collect();
+        assert_eq!(ts_inits(&data), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_merge_ties_are_stable_by_stream_order() {
+        let trades = vec![vec![Data::from(trade(1)), Data::from(trade(2))]].into_iter();
+        let mut merge = DataMerge::new();
+        merge.push_stream(quote_batches(vec![vec![1, 2]]));
+        merge.push_stream(trades);
+
+        let data: Vec<Data> = merge.collect();
+
+        assert!(matches!(data[0], Data::Quote(_)));
+        assert!(matches!(data[1], Data::Trade(_)));
+        assert!(matches!(data[2], Data::Quote(_)));
+        assert!(matches!(data[3], Data::Trade(_)));
+    }
+
+    #[test]
+    fn test_merge_next_chunk() {
+        let mut merge = DataMerge::new();
+        merge.push_stream(quote_batches(vec![vec![1, 3, 5]]));
+        merge.push_stream(quote_batches(vec![vec![2, 4]]));
+
+        assert_eq!(ts_inits(&merge.next_chunk(2).unwrap()), vec![1, 2]);
+        assert_eq!(ts_inits(&merge.next_chunk(2).unwrap()), vec![3, 4]);
+        assert_eq!(ts_inits(&merge.next_chunk(2).unwrap()), vec![5]);
+        assert!(merge.next_chunk(2).is_none());
+    }
+
+    #[test]
+    fn test_merge_parquet_readers_of_mixed_types() {
+        let quotes: Vec<QuoteTick> = (0..10).map(|i| quote(i * 2)).collect();
+        let trades: Vec<TradeTick> = (0..10).map(|i| trade(i * 2 + 1)).collect();
+        let quote_reader =
+            ParquetReader::from_buffer(write_buffer(&quotes), ParquetType::QuoteTick, 3).unwrap();
+        let trade_reader =
+            ParquetReader::from_buffer(write_buffer(&trades), ParquetType::TradeTick, 4).unwrap();
+
+        let mut merge = DataMerge::new();
+        merge.push_stream(quote_reader.map(Result::unwrap));
+        merge.push_stream(trade_reader.map(Result::unwrap));
+
+        let data: Vec<Data> = merge.collect();
+        assert_eq!(ts_inits(&data), (0..20).collect::<Vec<_>>());
+        assert!(data
+            .iter()
+            .all(|d| matches!(d, Data::Quote(q) if q.ts_init % 2 == 0)
+                || matches!(d, Data::Trade(t) if t.ts_init % 2 == 1)));
+    }
+}
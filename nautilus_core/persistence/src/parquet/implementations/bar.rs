@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use datafusion::arrow::{
     array::{Array, Int64Array, UInt64Array},
@@ -25,7 +25,7 @@ use nautilus_model::{
     types::{price::Price, quantity::Quantity},
 };
 
-use crate::parquet::{Data, DecodeDataFromRecordBatch};
+use crate::parquet::{Data, DecodeDataFromRecordBatch, EncodeToRecordBatch};
 
 impl DecodeDataFromRecordBatch for Bar {
     fn decode_batch(metadata: &HashMap<String, String>, record_batch: RecordBatch) -> Vec<Data> {
@@ -85,6 +85,44 @@ impl DecodeDataFromRecordBatch for Bar {
     }
 }
 
+impl EncodeToRecordBatch for Bar {
+    fn encode_batch(metadata: &HashMap<String, String>, data: &[Self]) -> RecordBatch {
+        // Create array builders
+        let mut open_builder = Int64Array::builder(data.len());
+        let mut high_builder = Int64Array::builder(data.len());
+        let mut low_builder = Int64Array::builder(data.len());
+        let mut close_builder = Int64Array::builder(data.len());
+        let mut volume_builder = UInt64Array::builder(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        // Iterate over data
+        for bar in data {
+            open_builder.append_value(bar.open.raw);
+            high_builder.append_value(bar.high.raw);
+            low_builder.append_value(bar.low.raw);
+            close_builder.append_value(bar.close.raw);
+            volume_builder.append_value(bar.volume.raw);
+            ts_event_builder.append_value(bar.ts_event);
+            ts_init_builder.append_value(bar.ts_init);
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(metadata.clone()),
+            vec![
+                Arc::new(open_builder.finish()),
+                Arc::new(high_builder.finish()),
+                Arc::new(low_builder.finish()),
+                Arc::new(close_builder.finish()),
+                Arc::new(volume_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
+
 fn parse_metadata(metadata: &HashMap<String, String>) -> (BarType, u8, u8) {
     let bar_type = BarType::from_str(metadata.get("bar_type").unwrap().as_str()).unwrap();
     let price_precision = metadata
@@ -106,26 +144,16 @@ fn parse_metadata(metadata: &HashMap<String, String>) -> (BarType, u8, u8) {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap, sync::Arc};
+    use std::sync::Arc;
 
     use datafusion::arrow::record_batch::RecordBatch;
 
     use super::*;
-
-    fn create_metadata() -> HashMap<String, String> {
-        let mut metadata = HashMap::new();
-        metadata.insert(
-            "bar_type".to_string(),
-            "AAPL.NASDAQ-1-MINUTE-LAST-INTERNAL".to_string(),
-        );
-        metadata.insert("price_precision".to_string(), "2".to_string());
-        metadata.insert("size_precision".to_string(), "0".to_string());
-        metadata
-    }
+    use crate::parquet::stubs::create_bar_metadata;
 
     #[test]
     fn test_get_schema() {
-        let metadata = create_metadata();
+        let metadata = create_bar_metadata();
         let schema = Bar::get_schema(metadata.clone());
         let expected_fields = vec![
             Field::new("open", DataType::Int64, false),
@@ -142,7 +170,7 @@ mod tests {
 
     #[test]
     fn test_decode_batch() {
-        let metadata = create_metadata();
+        let metadata = create_bar_metadata();
 
         let open = Int64Array::from(vec![10010, 10000]);
         let high = Int64Array::from(vec![10200, 10000]);
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use datafusion::arrow::{
     array::{Array, Int64Array, UInt64Array, UInt8Array},
@@ -27,7 +27,7 @@ use nautilus_model::{
     types::{price::Price, quantity::Quantity},
 };
 
-use crate::parquet::{Data, DecodeDataFromRecordBatch};
+use crate::parquet::{Data, DecodeDataFromRecordBatch, EncodeToRecordBatch};
 
 impl DecodeDataFromRecordBatch for OrderBookDelta {
     fn decode_batch(metadata: &HashMap<String, String>, record_batch: RecordBatch) -> Vec<Data> {
@@ -100,6 +100,50 @@ impl DecodeDataFromRecordBatch for OrderBookDelta {
     }
 }
 
+impl EncodeToRecordBatch for OrderBookDelta {
+    fn encode_batch(metadata: &HashMap<String, String>, data: &[Self]) -> RecordBatch {
+        // Create array builders
+        let mut action_builder = UInt8Array::builder(data.len());
+        let mut side_builder = UInt8Array::builder(data.len());
+        let mut price_builder = Int64Array::builder(data.len());
+        let mut size_builder = UInt64Array::builder(data.len());
+        let mut order_id_builder = UInt64Array::builder(data.len());
+        let mut flags_builder = UInt8Array::builder(data.len());
+        let mut sequence_builder = UInt64Array::builder(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        // Iterate over data
+        for delta in data {
+            action_builder.append_value(delta.action as u8);
+            side_builder.append_value(delta.order.side as u8);
+            price_builder.append_value(delta.order.price.raw);
+            size_builder.append_value(delta.order.size.raw);
+            order_id_builder.append_value(delta.order.order_id);
+            flags_builder.append_value(delta.flags);
+            sequence_builder.append_value(delta.sequence);
+            ts_event_builder.append_value(delta.ts_event);
+            ts_init_builder.append_value(delta.ts_init);
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(metadata.clone()),
+            vec![
+                Arc::new(action_builder.finish()),
+                Arc::new(side_builder.finish()),
+                Arc::new(price_builder.finish()),
+                Arc::new(size_builder.finish()),
+                Arc::new(order_id_builder.finish()),
+                Arc::new(flags_builder.finish()),
+                Arc::new(sequence_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
+
 fn parse_metadata(metadata: &HashMap<String, String>) -> (InstrumentId, u8, u8) {
     let instrument_id =
         InstrumentId::from_str(metadata.get("instrument_id").unwrap().as_str()).unwrap();
@@ -125,24 +169,16 @@ fn parse_metadata(metadata: &HashMap<String, String>) -> (InstrumentId, u8, u8)
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap, sync::Arc};
+    use std::sync::Arc;
 
     use datafusion::arrow::record_batch::RecordBatch;
 
     use super::*;
-
-    fn create_metadata() -> HashMap<String, String> {
-        let mut metadata = HashMap::new();
-        metadata.insert("instrument_id".to_string(), "AAPL.NASDAQ".to_string());
-        metadata.insert("book_type".to_string(), "2".to_string());
-        metadata.insert("price_precision".to_string(), "2".to_string());
-        metadata.insert("size_precision".to_string(), "0".to_string());
-        metadata
-    }
+    use crate::parquet::stubs::create_delta_metadata;
 
     #[test]
     fn test_get_schema() {
-        let metadata = create_metadata();
+        let metadata = create_delta_metadata();
         let schema = OrderBookDelta::get_schema(metadata.clone());
         let expected_fields = vec![
             Field::new("action", DataType::UInt8, false),
@@ -161,7 +197,7 @@ mod tests {
 
     #[test]
     fn test_decode_batch() {
-        let metadata = create_metadata();
+        let metadata = create_delta_metadata();
 
         let action = UInt8Array::from(vec![1, 2]);
         let side = UInt8Array::from(vec![1, 1]);
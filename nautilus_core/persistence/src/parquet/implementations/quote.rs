@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use datafusion::arrow::{
     array::{Array, Int64Array, UInt64Array},
@@ -26,7 +26,7 @@ use nautilus_model::{
     types::{price::Price, quantity::Quantity},
 };
 
-use crate::parquet::{Data, DecodeDataFromRecordBatch};
+use crate::parquet::{Data, DecodeDataFromRecordBatch, EncodeToRecordBatch};
 
 impl DecodeDataFromRecordBatch for QuoteTick {
     fn decode_batch(metadata: &HashMap<String, String>, record_batch: RecordBatch) -> Vec<Data> {
@@ -37,8 +37,8 @@ impl DecodeDataFromRecordBatch for QuoteTick {
         let cols = record_batch.columns();
         let bid_values = cols[0].as_any().downcast_ref::<Int64Array>().unwrap();
         let ask_values = cols[1].as_any().downcast_ref::<Int64Array>().unwrap();
-        let ask_size_values = cols[2].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let bid_size_values = cols[3].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let bid_size_values = cols[2].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let ask_size_values = cols[3].as_any().downcast_ref::<UInt64Array>().unwrap();
         let ts_event_values = cols[4].as_any().downcast_ref::<UInt64Array>().unwrap();
         let ts_init_values = cols[5].as_any().downcast_ref::<UInt64Array>().unwrap();
 
@@ -46,12 +46,12 @@ impl DecodeDataFromRecordBatch for QuoteTick {
         let values = bid_values
             .into_iter()
             .zip(ask_values.iter())
-            .zip(ask_size_values.iter())
             .zip(bid_size_values.iter())
+            .zip(ask_size_values.iter())
             .zip(ts_event_values.iter())
             .zip(ts_init_values.iter())
             .map(
-                |(((((bid, ask), ask_size), bid_size), ts_event), ts_init)| {
+                |(((((bid, ask), bid_size), ask_size), ts_event), ts_init)| {
                     Self {
                         instrument_id: instrument_id.clone(),
                         bid: Price::from_raw(bid.unwrap(), price_precision),
@@ -82,6 +82,41 @@ impl DecodeDataFromRecordBatch for QuoteTick {
     }
 }
 
+impl EncodeToRecordBatch for QuoteTick {
+    fn encode_batch(metadata: &HashMap<String, String>, data: &[Self]) -> RecordBatch {
+        // Create array builders
+        let mut bid_builder = Int64Array::builder(data.len());
+        let mut ask_builder = Int64Array::builder(data.len());
+        let mut bid_size_builder = UInt64Array::builder(data.len());
+        let mut ask_size_builder = UInt64Array::builder(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        // Iterate over data
+        for quote in data {
+            bid_builder.append_value(quote.bid.raw);
+            ask_builder.append_value(quote.ask.raw);
+            bid_size_builder.append_value(quote.bid_size.raw);
+            ask_size_builder.append_value(quote.ask_size.raw);
+            ts_event_builder.append_value(quote.ts_event);
+            ts_init_builder.append_value(quote.ts_init);
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(metadata.clone()),
+            vec![
+                Arc::new(bid_builder.finish()),
+                Arc::new(ask_builder.finish()),
+                Arc::new(bid_size_builder.finish()),
+                Arc::new(ask_size_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
+
 fn parse_metadata(metadata: &HashMap<String, String>) -> (InstrumentId, u8, u8) {
     let instrument_id =
         InstrumentId::from_str(metadata.get("instrument_id").unwrap().as_str()).unwrap();
@@ -104,19 +139,12 @@ fn parse_metadata(metadata: &HashMap<String, String>) -> (InstrumentId, u8, u8)
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap, sync::Arc};
+    use std::sync::Arc;
 
     use datafusion::arrow::record_batch::RecordBatch;
 
     use super::*;
-
-    fn create_metadata() -> HashMap<String, String> {
-        let mut metadata = HashMap::new();
-        metadata.insert("instrument_id".to_string(), "AAPL.NASDAQ".to_string());
-        metadata.insert("price_precision".to_string(), "2".to_string());
-        metadata.insert("size_precision".to_string(), "0".to_string());
-        metadata
-    }
+    use crate::parquet::stubs::create_metadata;
 
     #[test]
     fn test_get_schema() {
@@ -13,10 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use datafusion::arrow::{
-    array::{Array, Int64Array, StringArray, UInt64Array, UInt8Array},
+    array::{Array, Int64Array, StringArray, StringBuilder, UInt64Array, UInt8Array},
     datatypes::{DataType, Field, Schema, SchemaRef},
     record_batch::RecordBatch,
 };
@@ -27,7 +27,7 @@ use nautilus_model::{
     types::{price::Price, quantity::Quantity},
 };
 
-use crate::parquet::{Data, DecodeDataFromRecordBatch};
+use crate::parquet::{Data, DecodeDataFromRecordBatch, EncodeToRecordBatch};
 
 impl DecodeDataFromRecordBatch for TradeTick {
     fn decode_batch(metadata: &HashMap<String, String>, record_batch: RecordBatch) -> Vec<Data> {
@@ -84,6 +84,41 @@ impl DecodeDataFromRecordBatch for TradeTick {
     }
 }
 
+impl EncodeToRecordBatch for TradeTick {
+    fn encode_batch(metadata: &HashMap<String, String>, data: &[Self]) -> RecordBatch {
+        // Create array builders
+        let mut price_builder = Int64Array::builder(data.len());
+        let mut size_builder = UInt64Array::builder(data.len());
+        let mut aggressor_side_builder = UInt8Array::builder(data.len());
+        let mut trade_id_builder = StringBuilder::new();
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        // Iterate over data
+        for trade in data {
+            price_builder.append_value(trade.price.raw);
+            size_builder.append_value(trade.size.raw);
+            aggressor_side_builder.append_value(trade.aggressor_side as u8);
+            trade_id_builder.append_value(trade.trade_id.value.as_str());
+            ts_event_builder.append_value(trade.ts_event);
+            ts_init_builder.append_value(trade.ts_init);
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(metadata.clone()),
+            vec![
+                Arc::new(price_builder.finish()),
+                Arc::new(size_builder.finish()),
+                Arc::new(aggressor_side_builder.finish()),
+                Arc::new(trade_id_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
+
 fn parse_metadata(metadata: &HashMap<String, String>) -> (InstrumentId, u8, u8) {
     let instrument_id =
         InstrumentId::from_str(metadata.get("instrument_id").unwrap().as_str()).unwrap();
@@ -106,7 +141,7 @@ fn parse_metadata(metadata: &HashMap<String, String>) -> (InstrumentId, u8, u8)
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap, sync::Arc};
+    use std::sync::Arc;
 
     use datafusion::arrow::{
         array::{Int64Array, StringArray, UInt64Array, UInt8Array},
@@ -114,14 +149,7 @@ mod tests {
     };
 
     use super::*;
-
-    fn create_metadata() -> HashMap<String, String> {
-        let mut metadata = HashMap::new();
-        metadata.insert("instrument_id".to_string(), "AAPL.NASDAQ".to_string());
-        metadata.insert("price_precision".to_string(), "2".to_string());
-        metadata.insert("size_precision".to_string(), "0".to_string());
-        metadata
-    }
+    use crate::parquet::stubs::create_metadata;
 
     #[test]
     fn test_get_schema() {
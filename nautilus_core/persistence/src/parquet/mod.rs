@@ -14,6 +14,10 @@
 // -------------------------------------------------------------------------------------------------
 
 mod implementations;
+pub mod reader;
+#[cfg(test)]
+mod stubs;
+pub mod writer;
 
 use std::collections::HashMap;
 
@@ -47,3 +51,24 @@ where
     fn decode_batch(metadata: &HashMap<String, String>, record_batch: RecordBatch) -> Vec<Data>;
     fn get_schema(metadata: HashMap<String, String>) -> SchemaRef;
 }
+
+pub trait EncodeToRecordBatch
+where
+    Self: Sized + DecodeDataFromRecordBatch,
+{
+    /// Encodes the given `data` into a record batch, with a schema including the `metadata`.
+    fn encode_batch(metadata: &HashMap<String, String>, data: &[Self]) -> RecordBatch;
+}
+
+impl ParquetType {
+    /// Returns the catalog filename stem for the data type.
+    #[must_use]
+    pub fn filename(&self) -> &'static str {
+        match self {
+            Self::OrderBookDelta => "order_book_delta",
+            Self::QuoteTick => "quote_tick",
+            Self::TradeTick => "trade_tick",
+            Self::Bar => "bar",
+        }
+    }
+}
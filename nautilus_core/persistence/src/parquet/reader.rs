@@ -0,0 +1,377 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, fs::File, mem, path::Path};
+
+use bytes::Bytes;
+use datafusion::{
+    arrow::{error::ArrowError, record_batch::RecordBatch},
+    parquet::{
+        arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder},
+        errors::ParquetError,
+        file::reader::ChunkReader,
+    },
+};
+use nautilus_core::cvec::CVec;
+use nautilus_model::data::{
+    bar::Bar,
+    book::OrderBookDelta,
+    tick::{QuoteTick, TradeTick},
+    Data,
+};
+use pyo3::{exceptions::PyIOError, prelude::*, types::PyCapsule};
+
+use super::{DecodeDataFromRecordBatch, ParquetReaderType, ParquetType};
+
+type DecodeFn = fn(&HashMap<String, String>, RecordBatch) -> Vec<Data>;
+
+/// Provides a streaming reader which decodes a Parquet file, from disk or an
+/// in-memory buffer, into chunks of [`Data`].
+///
+/// Record batches are read lazily, so only around one chunk of decoded data is
+/// held in memory at any time. Every chunk has exactly `chunk_size` elements,
+/// except the last which holds the remainder.
+pub struct ParquetReader {
+    reader: ParquetRecordBatchReader,
+    metadata: HashMap<String, String>,
+    decode: DecodeFn,
+    chunk_size: usize,
+    buffer: Vec<Data>,
+}
+
+impl ParquetReader {
+    /// Initializes a new `ParquetReader` instance decoding values of type `T`
+    /// from the given `reader`.
+    ///
+    /// # Errors
+    ///
+    /// - If the Parquet file metadata cannot be read.
+    ///
+    /// # Panics
+    ///
+    /// - If `chunk_size` is zero.
+    pub fn new<T, R>(reader: R, chunk_size: usize) -> Result<Self, ParquetError>
+    where
+        T: DecodeDataFromRecordBatch,
+        R: ChunkReader + 'static,
+    {
+        assert!(chunk_size > 0, "`chunk_size` must be positive");
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(reader)?;
+        let metadata = builder.schema().metadata().clone();
+        let reader = builder.with_batch_size(chunk_size).build()?;
+
+        Ok(Self {
+            reader,
+            metadata,
+            decode: T::decode_batch,
+            chunk_size,
+            buffer: Vec::with_capacity(chunk_size),
+        })
+    }
+
+    /// Initializes a new `ParquetReader` instance for the given `parquet_type`,
+    /// dispatching to the matching data decoder.
+    ///
+    /// # Errors
+    ///
+    /// - If the Parquet file metadata cannot be read.
+    pub fn with_parquet_type<R>(
+        reader: R,
+        parquet_type: ParquetType,
+        chunk_size: usize,
+    ) -> Result<Self, ParquetError>
+    where
+        R: ChunkReader + 'static,
+    {
+        match parquet_type {
+            ParquetType::OrderBookDelta => Self::new::<OrderBookDelta, R>(reader, chunk_size),
+            ParquetType::QuoteTick => Self::new::<QuoteTick, R>(reader, chunk_size),
+            ParquetType::TradeTick => Self::new::<TradeTick, R>(reader, chunk_size),
+            ParquetType::Bar => Self::new::<Bar, R>(reader, chunk_size),
+        }
+    }
+
+    /// Initializes a new `ParquetReader` instance streaming from the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// - If the file cannot be opened or its metadata cannot be read.
+    pub fn from_file<P: AsRef<Path>>(
+        path: P,
+        parquet_type: ParquetType,
+        chunk_size: usize,
+    ) -> Result<Self, ParquetError> {
+        Self::with_parquet_type(File::open(path)?, parquet_type, chunk_size)
+    }
+
+    /// Initializes a new `ParquetReader` instance streaming from an in-memory `buffer`.
+    ///
+    /// # Errors
+    ///
+    /// - If the buffer metadata cannot be read.
+    pub fn from_buffer<B: Into<Bytes>>(
+        buffer: B,
+        parquet_type: ParquetType,
+        chunk_size: usize,
+    ) -> Result<Self, ParquetError> {
+        Self::with_parquet_type(buffer.into(), parquet_type, chunk_size)
+    }
+
+    /// Returns the metadata stored with the file schema.
+    #[must_use]
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    /// Returns the number of elements in each chunk.
+    #[must_use]
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+}
+
+impl Iterator for ParquetReader {
+    type Item = Result<Vec<Data>, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.len() < self.chunk_size {
+            match self.reader.next() {
+                Some(Ok(batch)) => self.buffer.extend((self.decode)(&self.metadata, batch)),
+                Some(Err(e)) => return Some(Err(e)),
+                None => break,
+            }
+        }
+
+        if self.buffer.is_empty() {
+            return None;
+        }
+
+        // Batches are read at most `chunk_size` rows at a time, so any
+        // overflow carried over to the next chunk stays small
+        let rest = if self.buffer.len() > self.chunk_size {
+            self.buffer.split_off(self.chunk_size)
+        } else {
+            Vec::with_capacity(self.chunk_size)
+        };
+        Some(Ok(mem::replace(&mut self.buffer, rest)))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+#[pyclass(name = "ParquetReader")]
+pub struct PyParquetReader {
+    reader: ParquetReader,
+    chunk: Option<CVec>,
+}
+
+#[pymethods]
+impl PyParquetReader {
+    /// The `source` is a file path for `ParquetReaderType.File`, or the raw
+    /// bytes of a Parquet file for `ParquetReaderType.Buffer`.
+    #[new]
+    fn py_new(
+        source: &PyAny,
+        parquet_type: ParquetType,
+        reader_type: ParquetReaderType,
+        chunk_size: usize,
+    ) -> PyResult<Self> {
+        let result = match reader_type {
+            ParquetReaderType::File => {
+                ParquetReader::from_file(source.extract::<&str>()?, parquet_type, chunk_size)
+            }
+            ParquetReaderType::Buffer => ParquetReader::from_buffer(
+                Bytes::copy_from_slice(source.extract::<&[u8]>()?),
+                parquet_type,
+                chunk_size,
+            ),
+        };
+
+        let reader = result.map_err(|e| PyIOError::new_err(e.to_string()))?;
+        Ok(Self {
+            reader,
+            chunk: None,
+        })
+    }
+
+    /// The reader implements an iterator.
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Each iteration returns a chunk of values read from the parquet file.
+    ///
+    /// The chunk is owned by the reader and remains valid until the next
+    /// iteration, or until the reader is dropped.
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<PyObject>> {
+        slf.drop_chunk();
+
+        match slf.reader.next() {
+            Some(Ok(chunk)) => {
+                let cvec = CVec::from_elements(chunk);
+                slf.chunk = Some(cvec);
+                Python::with_gil(|py| Ok(Some(PyCapsule::new::<CVec>(py, cvec, None)?.into_py(py))))
+            }
+            Some(Err(e)) => Err(PyIOError::new_err(e.to_string())),
+            None => Ok(None),
+        }
+    }
+}
+
+// Note: Intended to be used on a single python thread
+unsafe impl Send for PyParquetReader {}
+
+impl PyParquetReader {
+    /// Chunks generated by iteration must be dropped after use, otherwise
+    /// it will leak memory. Current chunk is held by the reader,
+    /// drop if exists and reset the field.
+    fn drop_chunk(&mut self) {
+        if let Some(cvec) = self.chunk.take() {
+            unsafe { cvec.drop_elements() };
+        }
+    }
+}
+
+impl Drop for PyParquetReader {
+    fn drop(&mut self) {
+        self.drop_chunk();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{
+        identifiers::instrument_id::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::parquet::{
+        stubs::create_metadata,
+        writer::{ParquetWriter, ParquetWriterConfig},
+    };
+
+    fn quotes(count: u64) -> Vec<QuoteTick> {
+        (0..count)
+            .map(|i| QuoteTick {
+                instrument_id: InstrumentId::from_str("AAPL.NASDAQ").unwrap(),
+                bid: Price::from_raw(10_000 + i as i64, 2),
+                ask: Price::from_raw(10_100 + i as i64, 2),
+                bid_size: Quantity::from_raw(100 + i, 0),
+                ask_size: Quantity::from_raw(200 + i, 0),
+                ts_event: i,
+                ts_init: i,
+            })
+            .collect()
+    }
+
+    fn write_quotes(data: &[QuoteTick], row_group_size: usize) -> Vec<u8> {
+        let config = ParquetWriterConfig {
+            row_group_size,
+            ..Default::default()
+        };
+        let mut buffer = Vec::new();
+        let mut writer =
+            ParquetWriter::<QuoteTick, &mut Vec<u8>>::new(&mut buffer, create_metadata(), config)
+                .unwrap();
+        writer.write(data).unwrap();
+        writer.close().unwrap();
+        buffer
+    }
+
+    fn ts_inits(chunks: &[Vec<Data>]) -> Vec<u64> {
+        chunks
+            .iter()
+            .flatten()
+            .map(|data| match data {
+                Data::Quote(quote) => quote.ts_init,
+                _ => panic!("Expected quote"),
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(4, 3, vec![4, 4, 2])]
+    #[case(4, 100, vec![4, 4, 2])]
+    #[case(5, 3, vec![5, 5])]
+    #[case(20, 3, vec![10])]
+    fn test_read_buffer_in_fixed_size_chunks(
+        #[case] chunk_size: usize,
+        #[case] row_group_size: usize,
+        #[case] expected: Vec<usize>,
+    ) {
+        let buffer = write_quotes(&quotes(10), row_group_size);
+
+        let reader =
+            ParquetReader::from_buffer(buffer, ParquetType::QuoteTick, chunk_size).unwrap();
+        let chunks: Vec<Vec<Data>> = reader.map(Result::unwrap).collect();
+
+        assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), expected);
+        assert_eq!(ts_inits(&chunks), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_read_file_decodes_values() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_path = temp_dir.path().join("quotes.parquet");
+        let data = quotes(7);
+        std::fs::write(&file_path, write_quotes(&data, 2)).unwrap();
+
+        let reader = ParquetReader::from_file(&file_path, ParquetType::QuoteTick, 3).unwrap();
+        assert_eq!(reader.metadata(), &create_metadata());
+        assert_eq!(reader.chunk_size(), 3);
+
+        let decoded: Vec<Data> = reader.flat_map(Result::unwrap).collect();
+        assert_eq!(decoded.len(), data.len());
+        for (quote, decoded) in data.iter().zip(decoded) {
+            match decoded {
+                Data::Quote(decoded) => assert_eq!(&decoded, quote),
+                _ => panic!("Expected quote"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_empty_file_yields_no_chunks() {
+        let buffer = write_quotes(&[], 4);
+
+        let mut reader = ParquetReader::from_buffer(buffer, ParquetType::QuoteTick, 4).unwrap();
+
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_read_missing_file_returns_error() {
+        let result = ParquetReader::from_file("does/not/exist.parquet", ParquetType::QuoteTick, 4);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_invalid_buffer_returns_error() {
+        let result = ParquetReader::from_buffer(vec![0u8; 16], ParquetType::QuoteTick, 4);
+
+        assert!(result.is_err());
+    }
+}
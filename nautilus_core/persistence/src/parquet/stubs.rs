@@ -0,0 +1,46 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Parquet metadata fixtures shared by the unit tests of the readers, writers and encodings.
+
+use std::collections::HashMap;
+
+/// Returns the metadata for ticks of `AAPL.NASDAQ`.
+pub fn create_metadata() -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+    metadata.insert("instrument_id".to_string(), "AAPL.NASDAQ".to_string());
+    metadata.insert("price_precision".to_string(), "2".to_string());
+    metadata.insert("size_precision".to_string(), "0".to_string());
+    metadata
+}
+
+/// Returns the metadata for L2 order book deltas of `AAPL.NASDAQ`.
+pub fn create_delta_metadata() -> HashMap<String, String> {
+    let mut metadata = create_metadata();
+    metadata.insert("book_type".to_string(), "2".to_string());
+    metadata
+}
+
+/// Returns the metadata for one-minute bars of `AAPL.NASDAQ`.
+pub fn create_bar_metadata() -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+    metadata.insert(
+        "bar_type".to_string(),
+        "AAPL.NASDAQ-1-MINUTE-LAST-INTERNAL".to_string(),
+    );
+    metadata.insert("price_precision".to_string(), "2".to_string());
+    metadata.insert("size_precision".to_string(), "0".to_string());
+    metadata
+}
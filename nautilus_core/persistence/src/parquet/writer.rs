@@ -0,0 +1,241 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, io::Write, marker::PhantomData};
+
+use datafusion::parquet::{
+    arrow::ArrowWriter,
+    basic::{Compression, ZstdLevel},
+    errors::ParquetError,
+    file::properties::WriterProperties,
+    format::FileMetaData,
+};
+
+use super::EncodeToRecordBatch;
+
+/// The compression codec for written Parquet files.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParquetCompression {
+    Uncompressed = 0,
+    Zstd = 1,
+    Lz4 = 2,
+}
+
+/// Configuration for a [`ParquetWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParquetWriterConfig {
+    /// The maximum number of rows in each row group.
+    pub row_group_size: usize,
+    /// The compression codec for column data.
+    pub compression: ParquetCompression,
+    /// The ZSTD compression level (ignored for other codecs).
+    pub zstd_level: i32,
+}
+
+impl Default for ParquetWriterConfig {
+    fn default() -> Self {
+        Self {
+            row_group_size: 1024 * 1024,
+            compression: ParquetCompression::Zstd,
+            zstd_level: 3,
+        }
+    }
+}
+
+impl ParquetWriterConfig {
+    fn writer_properties(&self) -> Result<WriterProperties, ParquetError> {
+        let compression = match self.compression {
+            ParquetCompression::Uncompressed => Compression::UNCOMPRESSED,
+            ParquetCompression::Zstd => Compression::ZSTD(ZstdLevel::try_new(self.zstd_level)?),
+            // Uses the raw LZ4 block format (the legacy framed codec is deprecated)
+            ParquetCompression::Lz4 => Compression::LZ4_RAW,
+        };
+
+        Ok(WriterProperties::builder()
+            .set_max_row_group_size(self.row_group_size)
+            .set_compression(compression)
+            .build())
+    }
+}
+
+/// Provides a writer which encodes data of a single type `T` to Arrow record batches,
+/// and writes them as a compressed Parquet file.
+///
+/// The `metadata` (e.g. instrument ID and precisions) is stored with the file schema,
+/// so the file can be decoded without any other context.
+pub struct ParquetWriter<T, W>
+where
+    T: EncodeToRecordBatch,
+    W: Write + Send,
+{
+    metadata: HashMap<String, String>,
+    writer: ArrowWriter<W>,
+    count: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T, W> ParquetWriter<T, W>
+where
+    T: EncodeToRecordBatch,
+    W: Write + Send,
+{
+    /// Initializes a new `ParquetWriter` instance writing to the given `writer`.
+    ///
+    /// # Errors
+    ///
+    /// - If the `config.zstd_level` is not a valid ZSTD compression level.
+    /// - If the file header cannot be written.
+    pub fn new(
+        writer: W,
+        metadata: HashMap<String, String>,
+        config: ParquetWriterConfig,
+    ) -> Result<Self, ParquetError> {
+        let schema = T::get_schema(metadata.clone());
+        let writer = ArrowWriter::try_new(writer, schema, Some(config.writer_properties()?))?;
+
+        Ok(Self {
+            metadata,
+            writer,
+            count: 0,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns the number of rows written so far.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Encodes and writes the given `data`, flushing row groups as they fill.
+    ///
+    /// # Errors
+    ///
+    /// - If writing to the underlying writer fails.
+    pub fn write(&mut self, data: &[T]) -> Result<(), ParquetError> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let batch = T::encode_batch(&self.metadata, data);
+        self.writer.write(&batch)?;
+        self.count += data.len();
+        Ok(())
+    }
+
+    /// Flushes any buffered rows and writes the file footer.
+    ///
+    /// # Errors
+    ///
+    /// - If writing to the underlying writer fails.
+    pub fn close(self) -> Result<FileMetaData, ParquetError> {
+        self.writer.close()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, str::FromStr};
+
+    use datafusion::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use nautilus_model::{
+        data::tick::QuoteTick,
+        identifiers::instrument_id::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::parquet::{stubs::create_metadata, Data, DecodeDataFromRecordBatch};
+
+    fn quotes(count: u64) -> Vec<QuoteTick> {
+        (0..count)
+            .map(|i| QuoteTick {
+                instrument_id: InstrumentId::from_str("AAPL.NASDAQ").unwrap(),
+                bid: Price::from_raw(10_000 + i as i64, 2),
+                ask: Price::from_raw(10_100 + i as i64, 2),
+                bid_size: Quantity::from_raw(100 + i, 0),
+                ask_size: Quantity::from_raw(200 + i, 0),
+                ts_event: i,
+                ts_init: i + 1,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(ParquetCompression::Uncompressed)]
+    #[case(ParquetCompression::Zstd)]
+    #[case(ParquetCompression::Lz4)]
+    fn test_write_quotes_round_trip(#[case] compression: ParquetCompression) {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_path = temp_dir.path().join("quotes.parquet");
+        let config = ParquetWriterConfig {
+            row_group_size: 4,
+            compression,
+            ..Default::default()
+        };
+        let data = quotes(10);
+
+        let mut writer = ParquetWriter::<QuoteTick, File>::new(
+            File::create(&file_path).unwrap(),
+            create_metadata(),
+            config,
+        )
+        .unwrap();
+        writer.write(&data[..6]).unwrap();
+        writer.write(&data[6..]).unwrap();
+        assert_eq!(writer.count(), 10);
+        let file_metadata = writer.close().unwrap();
+
+        assert_eq!(file_metadata.num_rows, 10);
+        assert_eq!(file_metadata.row_groups.len(), 3);
+
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new(File::open(&file_path).unwrap()).unwrap();
+        let metadata = builder.schema().metadata().clone();
+        let decoded: Vec<Data> = builder
+            .build()
+            .unwrap()
+            .flat_map(|batch| QuoteTick::decode_batch(&metadata, batch.unwrap()))
+            .collect();
+
+        assert_eq!(metadata, create_metadata());
+        assert_eq!(decoded.len(), 10);
+        for (quote, decoded) in data.iter().zip(decoded) {
+            match decoded {
+                Data::Quote(decoded) => assert_eq!(&decoded, quote),
+                _ => panic!("Expected quote"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_zstd_level_returns_error() {
+        let config = ParquetWriterConfig {
+            zstd_level: 100,
+            ..Default::default()
+        };
+
+        let result =
+            ParquetWriter::<QuoteTick, Vec<u8>>::new(Vec::new(), create_metadata(), config);
+
+        assert!(result.is_err());
+    }
+}
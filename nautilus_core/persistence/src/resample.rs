@@ -0,0 +1,390 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Resampling of stored bars into bars of a higher timeframe.
+
+use std::collections::HashMap;
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::bar::{Bar, BarType},
+    enums::BarAggregation,
+};
+use thiserror::Error;
+
+use crate::{
+    catalog::{
+        write::{CatalogError, ManifestEntry, WriteMode},
+        DataCatalog,
+    },
+    parquet::{writer::ParquetWriterConfig, ParquetType},
+};
+
+const NANOSECONDS_IN_SECOND: u64 = 1_000_000_000;
+const NANOSECONDS_IN_MINUTE: u64 = 60 * NANOSECONDS_IN_SECOND;
+const NANOSECONDS_IN_HOUR: u64 = 60 * NANOSECONDS_IN_MINUTE;
+const NANOSECONDS_IN_DAY: u64 = 24 * NANOSECONDS_IN_HOUR;
+
+#[derive(Debug, Error)]
+pub enum ResampleError {
+    #[error("Catalog error: {0}")]
+    Catalog(#[from] CatalogError),
+    #[error("Invalid resample target {target}: {reason}")]
+    InvalidTarget { target: String, reason: String },
+}
+
+/// Represents the windows which source bars are grouped into.
+///
+/// As bars are stamped at the close of their interval, a source bar belongs to the window
+/// `(start, end]` containing its `ts_event`, and each resampled bar is stamped at `end`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResampleWindow {
+    /// Fixed length windows of `interval_ns` aligned to the UNIX epoch, shifted by `offset_ns`
+    /// (for example a daily session close of 21:00 UTC).
+    Fixed { interval_ns: u64, offset_ns: u64 },
+    /// The trading sessions as `(open, close)` UNIX nanosecond pairs ordered by `open`, with
+    /// one bar built per session. Source bars outside every session are dropped.
+    Sessions(Vec<(UnixNanos, UnixNanos)>),
+}
+
+impl ResampleWindow {
+    /// Returns fixed windows for the time based `bar_type`, shifted by `offset_ns`.
+    ///
+    /// # Errors
+    ///
+    /// - If the bar type is not aggregated by seconds, minutes, hours or days.
+    pub fn from_bar_type(bar_type: &BarType, offset_ns: u64) -> Result<Self, ResampleError> {
+        Ok(Self::Fixed {
+            interval_ns: interval_ns(bar_type)?,
+            offset_ns,
+        })
+    }
+
+    /// Returns the end of the window containing `ts_event`, or `None` if not in any window.
+    fn window_end(&self, ts_event: UnixNanos) -> Option<UnixNanos> {
+        match self {
+            Self::Fixed {
+                interval_ns,
+                offset_ns,
+            } => {
+                let offset = offset_ns % interval_ns;
+                match ts_event.checked_sub(offset) {
+                    Some(shifted) => {
+                        let windows = (shifted + interval_ns - 1) / interval_ns;
+                        Some(windows * interval_ns + offset)
+                    }
+                    None => Some(offset), // Within the first window after the epoch
+                }
+            }
+            Self::Sessions(sessions) => {
+                let index = sessions.partition_point(|(open, _)| *open < ts_event);
+                let (_, close) = sessions[..index].last()?;
+                (ts_event <= *close).then_some(*close)
+            }
+        }
+    }
+}
+
+fn interval_ns(bar_type: &BarType) -> Result<u64, ResampleError> {
+    let unit = match bar_type.spec.aggregation {
+        BarAggregation::Second => NANOSECONDS_IN_SECOND,
+        BarAggregation::Minute => NANOSECONDS_IN_MINUTE,
+        BarAggregation::Hour => NANOSECONDS_IN_HOUR,
+        BarAggregation::Day => NANOSECONDS_IN_DAY,
+        aggregation => {
+            return Err(ResampleError::InvalidTarget {
+                target: bar_type.to_string(),
+                reason: format!("cannot resample to fixed windows of {aggregation}"),
+            })
+        }
+    };
+    Ok(bar_type.spec.step * unit)
+}
+
+/// Resamples the `bars` (ordered by `ts_event`) into bars of `bar_type` over the `window`.
+///
+/// Each resampled bar opens at the first source bar in its window and closes at the last,
+/// with the high and low over all of them and the sum of their volumes. Windows without
+/// source bars are skipped rather than filled.
+#[must_use]
+pub fn resample_bars(bars: &[Bar], bar_type: &BarType, window: &ResampleWindow) -> Vec<Bar> {
+    let mut resampled: Vec<Bar> = Vec::new();
+    let mut current_end = None;
+
+    for bar in bars {
+        let end = match window.window_end(bar.ts_event) {
+            Some(end) => end,
+            None => continue,
+        };
+        match resampled.last_mut() {
+            Some(last) if current_end == Some(end) => {
+                last.high = last.high.max(bar.high);
+                last.low = last.low.min(bar.low);
+                last.close = bar.close;
+                last.volume += bar.volume;
+                last.ts_init = last.ts_init.max(bar.ts_init);
+            }
+            _ => {
+                current_end = Some(end);
+                resampled.push(Bar::new(
+                    bar_type.clone(),
+                    bar.open,
+                    bar.high,
+                    bar.low,
+                    bar.close,
+                    bar.volume,
+                    end,
+                    end.max(bar.ts_init),
+                ));
+            }
+        }
+    }
+
+    resampled
+}
+
+impl DataCatalog {
+    /// Resamples the bars stored for the `source` bar type into bars of the `target` bar
+    /// type over the `window`, writing them to the catalog per the `mode`.
+    ///
+    /// Returns the manifest entry of the written file, or `None` if there were no bars.
+    ///
+    /// # Errors
+    ///
+    /// - If the `target` is for a different instrument or price type than the `source`.
+    /// - If fixed windows are not a whole multiple of a time based `source` interval.
+    /// - If reading the source bars or writing the resampled bars fails.
+    pub fn resample_bars(
+        &self,
+        source: &BarType,
+        target: &BarType,
+        window: &ResampleWindow,
+        mode: WriteMode,
+        config: ParquetWriterConfig,
+    ) -> Result<Option<ManifestEntry>, ResampleError> {
+        let invalid = |reason: &str| ResampleError::InvalidTarget {
+            target: target.to_string(),
+            reason: reason.to_string(),
+        };
+        if target.instrument_id != source.instrument_id {
+            return Err(invalid("instrument ID does not match the source"));
+        }
+        if target.spec.price_type != source.spec.price_type {
+            return Err(invalid("price type does not match the source"));
+        }
+        if let (ResampleWindow::Fixed { interval_ns, .. }, Ok(source_interval)) =
+            (window, interval_ns(source))
+        {
+            if interval_ns % source_interval != 0 {
+                return Err(invalid("window is not a multiple of the source interval"));
+            }
+        }
+
+        let bars: Vec<Bar> = self.read_data(ParquetType::Bar, &source.to_string())?;
+        let resampled = resample_bars(&bars, target, window);
+        let (price_precision, size_precision) = match resampled.first() {
+            Some(bar) => (bar.close.precision, bar.volume.precision),
+            None => return Ok(None),
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("bar_type".to_string(), target.to_string());
+        metadata.insert("price_precision".to_string(), price_precision.to_string());
+        metadata.insert("size_precision".to_string(), size_precision.to_string());
+
+        Ok(self.write_data(
+            ParquetType::Bar,
+            &target.to_string(),
+            metadata,
+            resampled,
+            mode,
+            config,
+        )?)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::types::{price::Price, quantity::Quantity};
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    const SOURCE: &str = "AAPL.NASDAQ-1-MINUTE-LAST-EXTERNAL";
+
+    fn minute_bars(closes: &[i64]) -> Vec<Bar> {
+        let bar_type = BarType::from_str(SOURCE).unwrap();
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| {
+                let ts = (i as u64 + 1) * NANOSECONDS_IN_MINUTE;
+                Bar::new(
+                    bar_type.clone(),
+                    Price::from_raw(close * 1_000_000_000 - 1_000_000_000, 2),
+                    Price::from_raw(close * 1_000_000_000 + 2_000_000_000, 2),
+                    Price::from_raw(close * 1_000_000_000 - 2_000_000_000, 2),
+                    Price::from_raw(close * 1_000_000_000, 2),
+                    Quantity::from(10),
+                    ts,
+                    ts,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_resample_fixed_windows() {
+        let bars = minute_bars(&[100, 101, 102, 103, 104, 105, 106]);
+        let target = BarType::from_str("AAPL.NASDAQ-5-MINUTE-LAST-EXTERNAL").unwrap();
+        let window = ResampleWindow::from_bar_type(&target, 0).unwrap();
+
+        let resampled = resample_bars(&bars, &target, &window);
+
+        assert_eq!(resampled.len(), 2);
+        let first = &resampled[0];
+        assert_eq!(first.bar_type, target);
+        assert_eq!(first.open, Price::from("99.00"));
+        assert_eq!(first.high, Price::from("106.00"));
+        assert_eq!(first.low, Price::from("98.00"));
+        assert_eq!(first.close, Price::from("104.00"));
+        assert_eq!(first.volume, Quantity::from(50));
+        assert_eq!(first.ts_event, 5 * NANOSECONDS_IN_MINUTE);
+        assert_eq!(resampled[1].close, Price::from("106.00"));
+        assert_eq!(resampled[1].volume, Quantity::from(20));
+        assert_eq!(resampled[1].ts_event, 10 * NANOSECONDS_IN_MINUTE);
+    }
+
+    #[test]
+    fn test_resample_fixed_windows_with_offset() {
+        let bars = minute_bars(&[100, 101, 102, 103]);
+        let window = ResampleWindow::Fixed {
+            interval_ns: 2 * NANOSECONDS_IN_MINUTE,
+            offset_ns: NANOSECONDS_IN_MINUTE,
+        };
+        let target = BarType::from_str("AAPL.NASDAQ-2-MINUTE-LAST-EXTERNAL").unwrap();
+
+        let resampled = resample_bars(&bars, &target, &window);
+
+        let ts_events: Vec<u64> = resampled.iter().map(|bar| bar.ts_event).collect();
+        assert_eq!(
+            ts_events,
+            vec![
+                NANOSECONDS_IN_MINUTE,
+                3 * NANOSECONDS_IN_MINUTE,
+                5 * NANOSECONDS_IN_MINUTE
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resample_sessions_drops_bars_outside_sessions() {
+        let bars = minute_bars(&[100, 101, 102, 103, 104, 105]);
+        let window = ResampleWindow::Sessions(vec![
+            (NANOSECONDS_IN_MINUTE, 3 * NANOSECONDS_IN_MINUTE),
+            (4 * NANOSECONDS_IN_MINUTE, 5 * NANOSECONDS_IN_MINUTE),
+        ]);
+        let target = BarType::from_str("AAPL.NASDAQ-1-DAY-LAST-EXTERNAL").unwrap();
+
+        let resampled = resample_bars(&bars, &target, &window);
+
+        assert_eq!(resampled.len(), 2);
+        assert_eq!(resampled[0].open, Price::from("100.00"));
+        assert_eq!(resampled[0].close, Price::from("102.00"));
+        assert_eq!(resampled[0].ts_event, 3 * NANOSECONDS_IN_MINUTE);
+        assert_eq!(resampled[1].volume, Quantity::from(10));
+        assert_eq!(resampled[1].ts_event, 5 * NANOSECONDS_IN_MINUTE);
+    }
+
+    #[rstest]
+    #[case("AAPL.NASDAQ-100-TICK-LAST-EXTERNAL")]
+    #[case("AAPL.NASDAQ-1-MONTH-LAST-EXTERNAL")]
+    fn test_window_from_bar_type_not_fixed(#[case] bar_type: &str) {
+        let bar_type = BarType::from_str(bar_type).unwrap();
+        assert!(ResampleWindow::from_bar_type(&bar_type, 0).is_err());
+    }
+
+    #[test]
+    fn test_catalog_resample_bars() {
+        let dir = tempdir().unwrap();
+        let catalog = DataCatalog::new(dir.path());
+        let source = BarType::from_str(SOURCE).unwrap();
+        let mut metadata = HashMap::new();
+        metadata.insert("bar_type".to_string(), SOURCE.to_string());
+        metadata.insert("price_precision".to_string(), "2".to_string());
+        metadata.insert("size_precision".to_string(), "0".to_string());
+        catalog
+            .write_data(
+                ParquetType::Bar,
+                SOURCE,
+                metadata,
+                minute_bars(&[100, 101, 102, 103, 104, 105, 106]),
+                WriteMode::NewFile,
+                ParquetWriterConfig::default(),
+            )
+            .unwrap();
+
+        let target = BarType::from_str("AAPL.NASDAQ-5-MINUTE-LAST-EXTERNAL").unwrap();
+        let window = ResampleWindow::from_bar_type(&target, 0).unwrap();
+        let entry = catalog
+            .resample_bars(
+                &source,
+                &target,
+                &window,
+                WriteMode::Overwrite,
+                ParquetWriterConfig::default(),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(entry.rows, 2);
+        let stored: Vec<Bar> = catalog
+            .read_data(ParquetType::Bar, &target.to_string())
+            .unwrap();
+        assert_eq!(
+            stored,
+            resample_bars(
+                &minute_bars(&[100, 101, 102, 103, 104, 105, 106]),
+                &target,
+                &window
+            )
+        );
+    }
+
+    #[test]
+    fn test_catalog_resample_bars_invalid_window() {
+        let dir = tempdir().unwrap();
+        let catalog = DataCatalog::new(dir.path());
+        let source = BarType::from_str(SOURCE).unwrap();
+        let target = BarType::from_str("AAPL.NASDAQ-90-SECOND-LAST-EXTERNAL").unwrap();
+        let window = ResampleWindow::from_bar_type(&target, 0).unwrap();
+
+        let result = catalog.resample_bars(
+            &source,
+            &target,
+            &window,
+            WriteMode::Overwrite,
+            ParquetWriterConfig::default(),
+        );
+
+        assert!(matches!(result, Err(ResampleError::InvalidTarget { .. })));
+    }
+}
@@ -312,7 +312,8 @@ impl DataQueryResult {
         let _guard = rt.enter();
 
         slf.result.next().map(|chunk| {
-            let cvec = chunk.into();
+            let cvec = CVec::from_elements(chunk);
+            slf.chunk = Some(cvec);
             Python::with_gil(|py| PyCapsule::new::<CVec>(py, cvec, None).unwrap().into_py(py))
         })
     }
@@ -332,10 +333,8 @@ impl DataQueryResult {
     /// it will leak memory. Current chunk is held by the reader,
     /// drop if exists and reset the field.
     fn drop_chunk(&mut self) {
-        if let Some(CVec { ptr, len, cap }) = self.chunk.take() {
-            let data: Vec<Data> =
-                unsafe { Vec::from_raw_parts(ptr.cast::<nautilus_model::data::Data>(), len, cap) };
-            drop(data);
+        if let Some(cvec) = self.chunk.take() {
+            unsafe { cvec.drop_elements() };
         }
     }
 }
@@ -16,7 +16,10 @@
 import os
 
 from nautilus_trader import PACKAGE_ROOT
+from nautilus_trader.core.nautilus_pyo3.persistence import CsvLoader
 from nautilus_trader.core.nautilus_pyo3.persistence import DataBackendSession
+from nautilus_trader.core.nautilus_pyo3.persistence import ParquetReader
+from nautilus_trader.core.nautilus_pyo3.persistence import ParquetReaderType
 from nautilus_trader.core.nautilus_pyo3.persistence import ParquetType
 from nautilus_trader.persistence.wranglers import list_from_capsule
 
@@ -67,3 +70,63 @@ def test_python_catalog_quotes():
     assert str(ticks[-1]) == "EUR/USD.SIM,1.12130,1.12132,0,0,1577919652000000125"
     is_ascending = all(ticks[i].ts_init <= ticks[i].ts_init for i in range(len(ticks) - 1))
     assert is_ascending
+
+
+def test_parquet_reader_file_chunks():
+    parquet_data_path = os.path.join(PACKAGE_ROOT, "tests/test_data/quote_tick_data.parquet")
+    reader = ParquetReader(parquet_data_path, ParquetType.QuoteTick, ParquetReaderType.File, 1000)
+
+    ticks = []
+    chunk_sizes = []
+    for chunk in reader:
+        chunk_ticks = list_from_capsule(chunk)
+        chunk_sizes.append(len(chunk_ticks))
+        ticks.extend(chunk_ticks)
+
+    assert len(ticks) == 9500
+    assert chunk_sizes == [1000] * 9 + [500]
+    assert str(ticks[-1]) == "EUR/USD.SIM,1.12130,1.12132,0,0,1577919652000000125"
+
+
+def test_parquet_reader_buffer():
+    parquet_data_path = os.path.join(PACKAGE_ROOT, "tests/test_data/trade_tick_data.parquet")
+    with open(parquet_data_path, "rb") as f:
+        buffer = f.read()
+
+    reader = ParquetReader(buffer, ParquetType.TradeTick, ParquetReaderType.Buffer, 1000)
+
+    ticks = []
+    for chunk in reader:
+        ticks.extend(list_from_capsule(chunk))
+
+    assert len(ticks) == 100
+
+
+def test_csv_loader_trades_to_parquet(tmp_path):
+    input_path = tmp_path / "trades.csv"
+    input_path.write_text(
+        "timestamp,price,amount,side,id\n"
+        "1672531200000,16500.5,0.25,buy,t1\n"
+        "1672531200001,16500.0,1.5,sell,t2\n",
+    )
+    output_path = tmp_path / "trades.parquet"
+    loader = CsvLoader("BTCUSDT.BINANCE", 1, 2, timestamp_format="unix_millis")
+
+    count = loader.trades_to_parquet(
+        str(input_path),
+        str(output_path),
+        ts_event="timestamp",
+        price="price",
+        size="amount",
+        aggressor_side="side",
+        trade_id="id",
+    )
+
+    reader = ParquetReader(str(output_path), ParquetType.TradeTick, ParquetReaderType.File, 10)
+    ticks = []
+    for chunk in reader:
+        ticks.extend(list_from_capsule(chunk))
+
+    assert count == 2
+    assert len(ticks) == 2
+    assert ticks[0].ts_init == 1672531200000000000
@@ -21,6 +21,46 @@ use pyo3::{prelude::*, types::PyDict};
 /// refer: https://github.com/PyO3/pyo3/issues/2644
 #[pymodule]
 fn nautilus_pyo3(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    // Adapters
+    let submodule = pyo3::wrap_pymodule!(nautilus_adapters::adapters);
+    m.add_wrapped(submodule)?;
+    let sys = PyModule::import(py, "sys")?;
+    let sys_modules: &PyDict = sys.getattr("modules")?.downcast()?;
+    sys_modules.set_item(
+        "nautilus_trader.core.nautilus_pyo3.adapters",
+        m.getattr("adapters")?,
+    )?;
+
+    // Analysis
+    let submodule = pyo3::wrap_pymodule!(nautilus_analysis::analysis);
+    m.add_wrapped(submodule)?;
+    let sys = PyModule::import(py, "sys")?;
+    let sys_modules: &PyDict = sys.getattr("modules")?.downcast()?;
+    sys_modules.set_item(
+        "nautilus_trader.core.nautilus_pyo3.analysis",
+        m.getattr("analysis")?,
+    )?;
+
+    // Backtest
+    let submodule = pyo3::wrap_pymodule!(nautilus_backtest::backtest);
+    m.add_wrapped(submodule)?;
+    let sys = PyModule::import(py, "sys")?;
+    let sys_modules: &PyDict = sys.getattr("modules")?.downcast()?;
+    sys_modules.set_item(
+        "nautilus_trader.core.nautilus_pyo3.backtest",
+        m.getattr("backtest")?,
+    )?;
+
+    // Common
+    let submodule = pyo3::wrap_pymodule!(nautilus_common::common);
+    m.add_wrapped(submodule)?;
+    let sys = PyModule::import(py, "sys")?;
+    let sys_modules: &PyDict = sys.getattr("modules")?.downcast()?;
+    sys_modules.set_item(
+        "nautilus_trader.core.nautilus_pyo3.common",
+        m.getattr("common")?,
+    )?;
+
     // Indicators
     let submodule = pyo3::wrap_pymodule!(nautilus_indicators::indicators);
     m.add_wrapped(submodule)?;
@@ -31,6 +71,16 @@ fn nautilus_pyo3(py: Python<'_>, m: &PyModule) -> PyResult<()> {
         m.getattr("indicators")?,
     )?;
 
+    // Infrastructure
+    let submodule = pyo3::wrap_pymodule!(nautilus_infrastructure::infrastructure);
+    m.add_wrapped(submodule)?;
+    let sys = PyModule::import(py, "sys")?;
+    let sys_modules: &PyDict = sys.getattr("modules")?.downcast()?;
+    sys_modules.set_item(
+        "nautilus_trader.core.nautilus_pyo3.infrastructure",
+        m.getattr("infrastructure")?,
+    )?;
+
     // Model
     let submodule = pyo3::wrap_pymodule!(nautilus_model::model);
     m.add_wrapped(submodule)?;
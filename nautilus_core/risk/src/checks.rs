@@ -0,0 +1,276 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::types::{money::Money, price::Price, quantity::Quantity};
+
+use crate::{check_status, RiskError};
+
+/// Checks the `price` precision does not exceed the instruments `max_precision`, and
+/// that the price is positive unless `allow_non_positive` (as for options).
+pub fn check_price(
+    price: &Price,
+    max_precision: u8,
+    allow_non_positive: bool,
+) -> Result<(), RiskError> {
+    if price.precision > max_precision {
+        return Err(RiskError::PricePrecision {
+            price: price.to_string(),
+            precision: price.precision,
+            max_precision,
+        });
+    }
+    if !allow_non_positive && price.raw <= 0 {
+        return Err(RiskError::PriceNotPositive {
+            price: price.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks the `quantity` precision does not exceed the instruments `max_precision`, and
+/// that the quantity is within any `max_quantity` and `min_quantity` trade size limits.
+pub fn check_quantity(
+    quantity: &Quantity,
+    max_precision: u8,
+    max_quantity: Option<&Quantity>,
+    min_quantity: Option<&Quantity>,
+) -> Result<(), RiskError> {
+    if quantity.precision > max_precision {
+        return Err(RiskError::QuantityPrecision {
+            quantity: quantity.to_string(),
+            precision: quantity.precision,
+            max_precision,
+        });
+    }
+    if let Some(max_quantity) = max_quantity {
+        if quantity.raw > max_quantity.raw {
+            return Err(RiskError::QuantityAboveMax {
+                quantity: quantity.to_string(),
+                max_quantity: max_quantity.to_string(),
+            });
+        }
+    }
+    if let Some(min_quantity) = min_quantity {
+        if quantity.raw < min_quantity.raw {
+            return Err(RiskError::QuantityBelowMin {
+                quantity: quantity.to_string(),
+                min_quantity: min_quantity.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks the order `notional` does not exceed the `max_notional` per order.
+pub fn check_max_notional(notional: &Money, max_notional: &Money) -> Result<(), RiskError> {
+    check_same_currency(notional, max_notional)?;
+    if notional.raw() > max_notional.raw() {
+        return Err(RiskError::NotionalExceedsMaxPerOrder {
+            max_notional: max_notional.to_formatted_string(),
+            notional: notional.to_formatted_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks the order `notional` does not exceed the `free` account balance.
+pub fn check_free_balance(notional: &Money, free: &Money) -> Result<(), RiskError> {
+    check_same_currency(notional, free)?;
+    if notional.raw() > free.raw() {
+        return Err(RiskError::NotionalExceedsFreeBalance {
+            free: free.to_formatted_string(),
+            notional: notional.to_formatted_string(),
+        });
+    }
+    Ok(())
+}
+
+fn check_same_currency(notional: &Money, limit: &Money) -> Result<(), RiskError> {
+    if notional.currency != limit.currency {
+        return Err(RiskError::CurrencyMismatch {
+            notional: notional.currency.code.to_string(),
+            limit: limit.currency.code.to_string(),
+        });
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns zero if the price check passes, otherwise the [`RiskError`] code with the
+/// denial reason set as the last error.
+#[no_mangle]
+pub extern "C" fn risk_check_price(price: &Price, max_precision: u8, allow_non_positive: u8) -> u8 {
+    check_status(check_price(price, max_precision, allow_non_positive != 0))
+}
+
+/// Returns zero if the quantity check passes, otherwise the [`RiskError`] code with the
+/// denial reason set as the last error.
+///
+/// # Safety
+///
+/// - Assumes `max_quantity` and `min_quantity` are either NULL (no limit) or valid pointers.
+#[no_mangle]
+pub unsafe extern "C" fn risk_check_quantity(
+    quantity: &Quantity,
+    max_precision: u8,
+    max_quantity: *const Quantity,
+    min_quantity: *const Quantity,
+) -> u8 {
+    check_status(check_quantity(
+        quantity,
+        max_precision,
+        max_quantity.as_ref(),
+        min_quantity.as_ref(),
+    ))
+}
+
+/// Returns zero if the max notional check passes, otherwise the [`RiskError`] code with
+/// the denial reason set as the last error.
+#[no_mangle]
+pub extern "C" fn risk_check_max_notional(notional: &Money, max_notional: &Money) -> u8 {
+    check_status(check_max_notional(notional, max_notional))
+}
+
+/// Returns zero if the free balance check passes, otherwise the [`RiskError`] code with
+/// the denial reason set as the last error.
+#[no_mangle]
+pub extern "C" fn risk_check_free_balance(notional: &Money, free: &Money) -> u8 {
+    check_status(check_free_balance(notional, free))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ptr::null;
+
+    use nautilus_core::ffi_error::{last_error, FFI_OK};
+    use nautilus_model::currencies::{EUR, USD};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_check_price_valid() {
+        assert!(check_price(&Price::new(1.5, 2), 2, false).is_ok());
+    }
+
+    #[rstest]
+    fn test_check_price_precision_exceeded() {
+        let err = check_price(&Price::new(1.005, 3), 2, false).unwrap_err();
+        assert_eq!(err.to_string(), "price 1.005 invalid (precision 3 > 2)");
+    }
+
+    #[rstest]
+    #[case(false, false)]
+    #[case(true, true)]
+    fn test_check_price_not_positive(#[case] allow_non_positive: bool, #[case] expected: bool) {
+        let result = check_price(&Price::new(-1.0, 1), 1, allow_non_positive);
+        assert_eq!(result.is_ok(), expected);
+    }
+
+    #[rstest]
+    fn test_check_quantity_valid() {
+        let max = Quantity::new(100.0, 0);
+        let min = Quantity::new(1.0, 0);
+        assert!(check_quantity(&Quantity::new(10.0, 0), 0, Some(&max), Some(&min)).is_ok());
+    }
+
+    #[rstest]
+    fn test_check_quantity_precision_exceeded() {
+        let err = check_quantity(&Quantity::new(1.5, 1), 0, None, None).unwrap_err();
+        assert_eq!(err.to_string(), "quantity 1.5 invalid (precision 1 > 0)");
+    }
+
+    #[rstest]
+    fn test_check_quantity_above_max() {
+        let max = Quantity::new(100.0, 0);
+        let err = check_quantity(&Quantity::new(101.0, 0), 0, Some(&max), None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "quantity 101 invalid (> maximum trade size of 100)"
+        );
+    }
+
+    #[rstest]
+    fn test_check_quantity_below_min() {
+        let min = Quantity::new(10.0, 0);
+        let err = check_quantity(&Quantity::new(5.0, 0), 0, None, Some(&min)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "quantity 5 invalid (< minimum trade size of 10)"
+        );
+    }
+
+    #[rstest]
+    fn test_check_max_notional() {
+        let max_notional = Money::new(1_000_000.0, USD.clone());
+        assert!(check_max_notional(&Money::new(1_000_000.0, USD.clone()), &max_notional).is_ok());
+
+        let err =
+            check_max_notional(&Money::new(1_100_000.0, USD.clone()), &max_notional).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "NOTIONAL_EXCEEDS_MAX_PER_ORDER 1_000_000.00 USD @ 1_100_000.00 USD"
+        );
+    }
+
+    #[rstest]
+    fn test_check_free_balance() {
+        let free = Money::new(1_000.0, USD.clone());
+        let err = check_free_balance(&Money::new(1_000.01, USD.clone()), &free).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "NOTIONAL_EXCEEDS_FREE_BALANCE 1_000.00 USD @ 1_000.01 USD"
+        );
+    }
+
+    #[rstest]
+    fn test_check_free_balance_currency_mismatch() {
+        let err = check_free_balance(&Money::new(1.0, USD.clone()), &Money::new(1.0, EUR.clone()))
+            .unwrap_err();
+        assert!(matches!(err, RiskError::CurrencyMismatch { .. }));
+    }
+
+    #[rstest]
+    fn test_c_api_risk_check_quantity() {
+        let max = Quantity::new(100.0, 0);
+        let status = unsafe { risk_check_quantity(&Quantity::new(10.0, 0), 0, &max, null()) };
+        assert_eq!(status, FFI_OK);
+
+        let status = unsafe { risk_check_quantity(&Quantity::new(101.0, 0), 0, &max, null()) };
+        assert_eq!(status, 4);
+        assert_eq!(
+            last_error().unwrap(),
+            "quantity 101 invalid (> maximum trade size of 100)"
+        );
+    }
+
+    #[rstest]
+    fn test_c_api_risk_check_max_notional() {
+        let status = risk_check_max_notional(
+            &Money::new(2_000.0, USD.clone()),
+            &Money::new(1_000.0, USD.clone()),
+        );
+        assert_eq!(status, 6);
+        assert_eq!(
+            last_error().unwrap(),
+            "NOTIONAL_EXCEEDS_MAX_PER_ORDER 1_000.00 USD @ 2_000.00 USD"
+        );
+    }
+}
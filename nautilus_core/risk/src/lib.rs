@@ -0,0 +1,97 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Pre-trade risk checks for the `RiskEngine`.
+//!
+//! The checks mirror those of the Python `RiskEngine`, with denial reasons formatted
+//! identically, and are callable over the C API so that order submission does not pay
+//! for the validation in Python.
+
+pub mod checks;
+pub mod throttler;
+
+use nautilus_core::ffi_error::{set_last_error, FFI_OK};
+use thiserror::Error;
+
+/// Represents the reason an order was denied by a pre-trade risk check.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RiskError {
+    #[error("price {price} invalid (precision {precision} > {max_precision})")]
+    PricePrecision {
+        price: String,
+        precision: u8,
+        max_precision: u8,
+    },
+    #[error("price {price} invalid (not positive)")]
+    PriceNotPositive { price: String },
+    #[error("quantity {quantity} invalid (precision {precision} > {max_precision})")]
+    QuantityPrecision {
+        quantity: String,
+        precision: u8,
+        max_precision: u8,
+    },
+    #[error("quantity {quantity} invalid (> maximum trade size of {max_quantity})")]
+    QuantityAboveMax {
+        quantity: String,
+        max_quantity: String,
+    },
+    #[error("quantity {quantity} invalid (< minimum trade size of {min_quantity})")]
+    QuantityBelowMin {
+        quantity: String,
+        min_quantity: String,
+    },
+    #[error("NOTIONAL_EXCEEDS_MAX_PER_ORDER {max_notional} @ {notional}")]
+    NotionalExceedsMaxPerOrder {
+        max_notional: String,
+        notional: String,
+    },
+    #[error("NOTIONAL_EXCEEDS_FREE_BALANCE {free} @ {notional}")]
+    NotionalExceedsFreeBalance { free: String, notional: String },
+    #[error("notional currency {notional} does not match limit currency {limit}")]
+    CurrencyMismatch { notional: String, limit: String },
+    #[error("Exceeded {name}")]
+    RateExceeded { name: String },
+}
+
+impl RiskError {
+    /// Returns the C API status code for the error (success is zero).
+    #[must_use]
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::PricePrecision { .. } => 1,
+            Self::PriceNotPositive { .. } => 2,
+            Self::QuantityPrecision { .. } => 3,
+            Self::QuantityAboveMax { .. } => 4,
+            Self::QuantityBelowMin { .. } => 5,
+            Self::NotionalExceedsMaxPerOrder { .. } => 6,
+            Self::NotionalExceedsFreeBalance { .. } => 7,
+            Self::CurrencyMismatch { .. } => 8,
+            Self::RateExceeded { .. } => 9,
+        }
+    }
+}
+
+/// Returns the C API status code for the check `result`, recording any denial reason
+/// as the last error.
+pub(crate) fn check_status(result: Result<(), RiskError>) -> u8 {
+    match result {
+        Ok(()) => FFI_OK,
+        Err(e) => {
+            let status = e.code();
+            set_last_error(e);
+            status
+        }
+    }
+}
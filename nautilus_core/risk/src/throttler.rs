@@ -0,0 +1,238 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::{
+    ffi_error::{try_cstr_to_str, write_result, FFI_ERROR},
+    time::UnixNanos,
+};
+
+use crate::{check_status, RiskError};
+
+/// Provides a sliding window order rate limit, allowing at most `limit` orders within
+/// any `interval_ns` window (e.g. `MAX_ORDER_SUBMIT_RATE` of 100 per second).
+#[derive(Clone, Debug)]
+pub struct OrderThrottler {
+    pub name: String,
+    pub limit: usize,
+    pub interval_ns: u64,
+    timestamps: VecDeque<UnixNanos>,
+}
+
+impl OrderThrottler {
+    #[must_use]
+    pub fn new(name: &str, limit: usize, interval_ns: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            limit,
+            interval_ns,
+            timestamps: VecDeque::with_capacity(limit),
+        }
+    }
+
+    /// Returns the number of orders counted within the window ending at `ts_now`.
+    pub fn count(&mut self, ts_now: UnixNanos) -> usize {
+        self.expire(ts_now);
+        self.timestamps.len()
+    }
+
+    /// Counts an order at `ts_now` if the rate limit allows it, otherwise returns
+    /// [`RiskError::RateExceeded`] without counting the order.
+    pub fn try_submit(&mut self, ts_now: UnixNanos) -> Result<(), RiskError> {
+        self.expire(ts_now);
+        if self.timestamps.len() >= self.limit {
+            return Err(RiskError::RateExceeded {
+                name: self.name.clone(),
+            });
+        }
+        self.timestamps.push_back(ts_now);
+        Ok(())
+    }
+
+    /// Resets the throttler, clearing all counted orders.
+    pub fn reset(&mut self) {
+        self.timestamps.clear();
+    }
+
+    fn expire(&mut self, ts_now: UnixNanos) {
+        while let Some(&ts) = self.timestamps.front() {
+            if ts.saturating_add(self.interval_ns) > ts_now {
+                break;
+            }
+            self.timestamps.pop_front();
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying
+/// [`OrderThrottler`].
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct OrderThrottler_API(Box<OrderThrottler>);
+
+impl Deref for OrderThrottler_API {
+    type Target = OrderThrottler;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for OrderThrottler_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Writes a new throttler allowing at most `limit` orders within any `interval_ns` window
+/// to `out`.
+///
+/// Returns zero on success, otherwise a non-zero status code with the last error set
+/// (in which case nothing is written to `out`).
+///
+/// # Safety
+///
+/// - Assumes `name_ptr` is a valid C string pointer, or null.
+/// - Assumes `out` is a valid pointer to memory for an [`OrderThrottler_API`].
+#[no_mangle]
+pub unsafe extern "C" fn order_throttler_new(
+    name_ptr: *const c_char,
+    limit: usize,
+    interval_ns: u64,
+    out: *mut OrderThrottler_API,
+) -> u8 {
+    let result = try_cstr_to_str(name_ptr, "throttler name")
+        .map(|name| OrderThrottler_API(Box::new(OrderThrottler::new(name, limit, interval_ns))));
+    write_result(result, out, |_| FFI_ERROR)
+}
+
+#[no_mangle]
+pub extern "C" fn order_throttler_drop(throttler: OrderThrottler_API) {
+    drop(throttler); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_throttler_count(
+    throttler: &mut OrderThrottler_API,
+    ts_now: UnixNanos,
+) -> usize {
+    throttler.count(ts_now)
+}
+
+/// Returns zero if the order is within the rate limit, otherwise the [`RiskError`] code
+/// with the denial reason set as the last error.
+#[no_mangle]
+pub extern "C" fn order_throttler_try_submit(
+    throttler: &mut OrderThrottler_API,
+    ts_now: UnixNanos,
+) -> u8 {
+    check_status(throttler.try_submit(ts_now))
+}
+
+#[no_mangle]
+pub extern "C" fn order_throttler_reset(throttler: &mut OrderThrottler_API) {
+    throttler.reset();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, mem::MaybeUninit};
+
+    use nautilus_core::ffi_error::{last_error, FFI_OK};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn throttler() -> OrderThrottler {
+        OrderThrottler::new("MAX_ORDER_SUBMIT_RATE", 2, 1_000)
+    }
+
+    #[rstest]
+    fn test_try_submit_within_limit(mut throttler: OrderThrottler) {
+        assert!(throttler.try_submit(0).is_ok());
+        assert!(throttler.try_submit(1).is_ok());
+        assert_eq!(throttler.count(1), 2);
+    }
+
+    #[rstest]
+    fn test_try_submit_exceeds_limit(mut throttler: OrderThrottler) {
+        throttler.try_submit(0).unwrap();
+        throttler.try_submit(1).unwrap();
+        let err = throttler.try_submit(2).unwrap_err();
+
+        assert_eq!(err.to_string(), "Exceeded MAX_ORDER_SUBMIT_RATE");
+        assert_eq!(throttler.count(2), 2);
+    }
+
+    #[rstest]
+    fn test_window_slides(mut throttler: OrderThrottler) {
+        throttler.try_submit(0).unwrap();
+        throttler.try_submit(500).unwrap();
+
+        assert!(throttler.try_submit(999).is_err());
+        assert!(throttler.try_submit(1_000).is_ok());
+        assert_eq!(throttler.count(1_500), 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut throttler: OrderThrottler) {
+        throttler.try_submit(0).unwrap();
+        throttler.try_submit(1).unwrap();
+        throttler.reset();
+
+        assert_eq!(throttler.count(2), 0);
+        assert!(throttler.try_submit(2).is_ok());
+    }
+
+    #[rstest]
+    fn test_c_api_try_submit() {
+        let name = CString::new("MAX_ORDER_MODIFY_RATE").unwrap();
+        let mut throttler = MaybeUninit::<OrderThrottler_API>::uninit();
+        let status =
+            unsafe { order_throttler_new(name.as_ptr(), 1, 1_000, throttler.as_mut_ptr()) };
+        assert_eq!(status, FFI_OK);
+        let mut throttler = unsafe { throttler.assume_init() };
+
+        assert_eq!(order_throttler_try_submit(&mut throttler, 0), FFI_OK);
+        assert_eq!(order_throttler_try_submit(&mut throttler, 1), 9);
+        assert_eq!(last_error().unwrap(), "Exceeded MAX_ORDER_MODIFY_RATE");
+        order_throttler_drop(throttler);
+    }
+
+    #[rstest]
+    fn test_c_api_new_with_null_name() {
+        let mut throttler = MaybeUninit::<OrderThrottler_API>::uninit();
+        let status =
+            unsafe { order_throttler_new(std::ptr::null(), 1, 1_000, throttler.as_mut_ptr()) };
+
+        assert_eq!(status, FFI_ERROR);
+        assert_eq!(
+            last_error().unwrap(),
+            "invalid throttler name string, `ptr` was NULL"
+        );
+    }
+}
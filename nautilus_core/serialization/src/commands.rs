@@ -0,0 +1,328 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Dict conversions for trading commands, with the field names and value formats of
+//! the Python `to_dict` methods in `nautilus_trader.execution.messages`.
+
+use nautilus_model::{
+    commands::{
+        CancelAllOrders, CancelOrder, ModifyOrder, QueryOrder, SubmitOrder, SubmitOrderList,
+        TradingCommand,
+    },
+    events::order::OrderInitialized,
+};
+
+use crate::{
+    dict::{Dict, DictSerializable},
+    events::{check_type, opt_string, typed_dict},
+    SerializationError,
+};
+
+/// Returns the JSON encoded bytes of the dict, as nested with `msgspec.json.encode`.
+fn json_bytes<T: serde::Serialize + ?Sized>(value: &T) -> Vec<u8> {
+    serde_json::to_vec(value).expect("dict is always JSON serializable")
+}
+
+impl DictSerializable for SubmitOrder {
+    fn to_dict(&self) -> Dict {
+        typed_dict("SubmitOrder")
+            .with("client_id", opt_string(self.client_id.as_ref()))
+            .with("trader_id", self.trader_id.to_string())
+            .with("strategy_id", self.strategy_id.to_string())
+            .with("order", json_bytes(&self.order.to_dict()))
+            .with("position_id", opt_string(self.position_id.as_ref()))
+            .with("command_id", self.command_id.to_string())
+            .with("ts_init", self.ts_init)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "SubmitOrder")?;
+        let order: Dict = serde_json::from_slice(dict.get_bytes("order")?)?;
+        Ok(Self {
+            trader_id: dict.parse("trader_id")?,
+            client_id: dict.parse_opt("client_id")?,
+            strategy_id: dict.parse("strategy_id")?,
+            order: OrderInitialized::from_dict(&order)?,
+            position_id: dict.parse_opt("position_id")?,
+            command_id: dict.parse("command_id")?,
+            ts_init: dict.get_u64("ts_init")?,
+        })
+    }
+}
+
+impl DictSerializable for SubmitOrderList {
+    fn to_dict(&self) -> Dict {
+        let orders: Vec<Dict> = self.orders.iter().map(DictSerializable::to_dict).collect();
+        typed_dict("SubmitOrderList")
+            .with("client_id", opt_string(self.client_id.as_ref()))
+            .with("trader_id", self.trader_id.to_string())
+            .with("strategy_id", self.strategy_id.to_string())
+            .with("order_list_id", self.order_list_id.to_string())
+            .with("orders", json_bytes(&orders))
+            .with("position_id", opt_string(self.position_id.as_ref()))
+            .with("command_id", self.command_id.to_string())
+            .with("ts_init", self.ts_init)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "SubmitOrderList")?;
+        let orders: Vec<Dict> = serde_json::from_slice(dict.get_bytes("orders")?)?;
+        Ok(Self {
+            trader_id: dict.parse("trader_id")?,
+            client_id: dict.parse_opt("client_id")?,
+            strategy_id: dict.parse("strategy_id")?,
+            order_list_id: dict.parse("order_list_id")?,
+            orders: orders
+                .iter()
+                .map(OrderInitialized::from_dict)
+                .collect::<Result<Vec<_>, _>>()?,
+            position_id: dict.parse_opt("position_id")?,
+            command_id: dict.parse("command_id")?,
+            ts_init: dict.get_u64("ts_init")?,
+        })
+    }
+}
+
+impl DictSerializable for ModifyOrder {
+    fn to_dict(&self) -> Dict {
+        typed_dict("ModifyOrder")
+            .with("client_id", opt_string(self.client_id.as_ref()))
+            .with("trader_id", self.trader_id.to_string())
+            .with("strategy_id", self.strategy_id.to_string())
+            .with("instrument_id", self.instrument_id.to_string())
+            .with("client_order_id", self.client_order_id.to_string())
+            .with("venue_order_id", opt_string(self.venue_order_id.as_ref()))
+            .with("quantity", opt_string(self.quantity.as_ref()))
+            .with("price", opt_string(self.price.as_ref()))
+            .with("trigger_price", opt_string(self.trigger_price.as_ref()))
+            .with("command_id", self.command_id.to_string())
+            .with("ts_init", self.ts_init)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "ModifyOrder")?;
+        Ok(Self {
+            trader_id: dict.parse("trader_id")?,
+            client_id: dict.parse_opt("client_id")?,
+            strategy_id: dict.parse("strategy_id")?,
+            instrument_id: dict.parse("instrument_id")?,
+            client_order_id: dict.parse("client_order_id")?,
+            venue_order_id: dict.parse_opt("venue_order_id")?,
+            quantity: dict.parse_opt("quantity")?,
+            price: dict.parse_opt("price")?,
+            trigger_price: dict.parse_opt("trigger_price")?,
+            command_id: dict.parse("command_id")?,
+            ts_init: dict.get_u64("ts_init")?,
+        })
+    }
+}
+
+/// Implements [`DictSerializable`] for the commands targeting a single order.
+macro_rules! impl_order_command {
+    ($ty:ident) => {
+        impl DictSerializable for $ty {
+            fn to_dict(&self) -> Dict {
+                typed_dict(stringify!($ty))
+                    .with("client_id", opt_string(self.client_id.as_ref()))
+                    .with("trader_id", self.trader_id.to_string())
+                    .with("strategy_id", self.strategy_id.to_string())
+                    .with("instrument_id", self.instrument_id.to_string())
+                    .with("client_order_id", self.client_order_id.to_string())
+                    .with("venue_order_id", opt_string(self.venue_order_id.as_ref()))
+                    .with("command_id", self.command_id.to_string())
+                    .with("ts_init", self.ts_init)
+            }
+
+            fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+                check_type(dict, stringify!($ty))?;
+                Ok(Self {
+                    trader_id: dict.parse("trader_id")?,
+                    client_id: dict.parse_opt("client_id")?,
+                    strategy_id: dict.parse("strategy_id")?,
+                    instrument_id: dict.parse("instrument_id")?,
+                    client_order_id: dict.parse("client_order_id")?,
+                    venue_order_id: dict.parse_opt("venue_order_id")?,
+                    command_id: dict.parse("command_id")?,
+                    ts_init: dict.get_u64("ts_init")?,
+                })
+            }
+        }
+    };
+}
+
+impl_order_command!(CancelOrder);
+impl_order_command!(QueryOrder);
+
+impl DictSerializable for CancelAllOrders {
+    fn to_dict(&self) -> Dict {
+        typed_dict("CancelAllOrders")
+            .with("client_id", opt_string(self.client_id.as_ref()))
+            .with("trader_id", self.trader_id.to_string())
+            .with("strategy_id", self.strategy_id.to_string())
+            .with("instrument_id", self.instrument_id.to_string())
+            .with("order_side", self.order_side.to_string())
+            .with("command_id", self.command_id.to_string())
+            .with("ts_init", self.ts_init)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "CancelAllOrders")?;
+        Ok(Self {
+            trader_id: dict.parse("trader_id")?,
+            client_id: dict.parse_opt("client_id")?,
+            strategy_id: dict.parse("strategy_id")?,
+            instrument_id: dict.parse("instrument_id")?,
+            order_side: dict.parse("order_side")?,
+            command_id: dict.parse("command_id")?,
+            ts_init: dict.get_u64("ts_init")?,
+        })
+    }
+}
+
+impl DictSerializable for TradingCommand {
+    fn to_dict(&self) -> Dict {
+        match self {
+            TradingCommand::SubmitOrder(command) => command.to_dict(),
+            TradingCommand::SubmitOrderList(command) => command.to_dict(),
+            TradingCommand::ModifyOrder(command) => command.to_dict(),
+            TradingCommand::CancelOrder(command) => command.to_dict(),
+            TradingCommand::CancelAllOrders(command) => command.to_dict(),
+            TradingCommand::QueryOrder(command) => command.to_dict(),
+        }
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        let command = match dict.get_str("type")? {
+            "SubmitOrder" => TradingCommand::SubmitOrder(SubmitOrder::from_dict(dict)?),
+            "SubmitOrderList" => TradingCommand::SubmitOrderList(SubmitOrderList::from_dict(dict)?),
+            "ModifyOrder" => TradingCommand::ModifyOrder(ModifyOrder::from_dict(dict)?),
+            "CancelOrder" => TradingCommand::CancelOrder(CancelOrder::from_dict(dict)?),
+            "CancelAllOrders" => TradingCommand::CancelAllOrders(CancelAllOrders::from_dict(dict)?),
+            "QueryOrder" => TradingCommand::QueryOrder(QueryOrder::from_dict(dict)?),
+            other => return Err(SerializationError::UnknownType(other.to_string())),
+        };
+        Ok(command)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        identifiers::{
+            client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+            order_list_id::OrderListId, strategy_id::StrategyId, trader_id::TraderId,
+        },
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn order(client_order_id: &str) -> OrderInitialized {
+        OrderInitialized {
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            client_order_id: ClientOrderId::new(client_order_id),
+            order_type: OrderType::Limit,
+            price: Some(Price::from_str("1.00000").unwrap()),
+            event_id: UUID4::new(),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn test_submit_order_round_trip() {
+        let command = SubmitOrder {
+            trader_id: TraderId::default(),
+            client_id: Some(ClientId::new("SIM")),
+            strategy_id: StrategyId::default(),
+            order: order("O-1"),
+            position_id: None,
+            command_id: UUID4::new(),
+            ts_init: 1,
+        };
+        let dict = command.to_dict();
+
+        let result = SubmitOrder::from_dict(&dict).unwrap();
+
+        assert!(dict
+            .get_bytes("order")
+            .unwrap()
+            .starts_with(br#"{"type":"OrderInitialized""#));
+        assert_eq!(result, command);
+    }
+
+    #[rstest]
+    fn test_submit_order_list_round_trip() {
+        let command = TradingCommand::SubmitOrderList(SubmitOrderList {
+            trader_id: TraderId::default(),
+            client_id: None,
+            strategy_id: StrategyId::default(),
+            order_list_id: OrderListId::new("OL-1"),
+            orders: vec![order("O-1"), order("O-2")],
+            position_id: None,
+            command_id: UUID4::new(),
+            ts_init: 1,
+        });
+
+        let result = TradingCommand::from_dict(&command.to_dict()).unwrap();
+
+        assert_eq!(result, command);
+    }
+
+    #[rstest]
+    fn test_modify_order_round_trip() {
+        let command = TradingCommand::ModifyOrder(ModifyOrder {
+            trader_id: TraderId::default(),
+            client_id: None,
+            strategy_id: StrategyId::default(),
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            client_order_id: ClientOrderId::new("O-1"),
+            venue_order_id: None,
+            quantity: Some(Quantity::from_str("100").unwrap()),
+            price: None,
+            trigger_price: Some(Price::from_str("1.00010").unwrap()),
+            command_id: UUID4::new(),
+            ts_init: 1,
+        });
+
+        let result = TradingCommand::from_dict(&command.to_dict()).unwrap();
+
+        assert_eq!(result, command);
+    }
+
+    #[rstest]
+    fn test_cancel_all_orders_to_dict() {
+        let command = CancelAllOrders {
+            trader_id: TraderId::default(),
+            client_id: None,
+            strategy_id: StrategyId::default(),
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            order_side: OrderSide::NoOrderSide,
+            command_id: UUID4::new(),
+            ts_init: 1,
+        };
+        let dict = command.to_dict();
+
+        assert_eq!(dict.get_str("order_side").unwrap(), "NO_ORDER_SIDE");
+        assert_eq!(CancelAllOrders::from_dict(&dict).unwrap(), command);
+    }
+}
@@ -0,0 +1,403 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An ordered dictionary of primitive values, matching the dicts produced by the
+//! `to_dict` methods of the Python objects.
+//!
+//! The Python `MsgPackSerializer` encodes these dicts with keys in insertion order,
+//! so a [`Dict`] keeps its entries in order to round trip the same bytes.
+
+use std::{fmt, str::FromStr};
+
+use serde::{
+    de::{self, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::SerializationError;
+
+/// A primitive value of a serialized dict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Nil,
+    Bool(bool),
+    UInt(u64),
+    Str(String),
+    /// Raw bytes, such as nested objects already encoded as JSON.
+    Bin(Vec<u8>),
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Self::UInt(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::Str(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::Str(value)
+    }
+}
+
+impl From<Vec<u8>> for Value {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bin(value)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Nil, Into::into)
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Nil => serializer.serialize_unit(),
+            Self::Bool(value) => serializer.serialize_bool(*value),
+            Self::UInt(value) => serializer.serialize_u64(*value),
+            Self::Str(value) => serializer.serialize_str(value),
+            Self::Bin(value) => serializer.serialize_bytes(value),
+        }
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a nil, bool, unsigned integer, string or bytes value")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Nil)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Nil)
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Value, E> {
+        Ok(Value::UInt(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Value, E> {
+        u64::try_from(value)
+            .map(Value::UInt)
+            .map_err(|_| E::custom(format!("negative integer {value}")))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Value, E> {
+        Ok(Value::Str(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Value, E> {
+        Ok(Value::Str(value))
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Value, E> {
+        Ok(Value::Bin(value.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<Value, E> {
+        Ok(Value::Bin(value))
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+/// An ordered dictionary of primitive values keyed by field name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Dict(Vec<(String, Value)>);
+
+impl Dict {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the dict with the entry appended.
+    #[must_use]
+    pub fn with(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    /// Sets the value for the `key`, keeping the position of an existing entry.
+    pub fn insert(&mut self, key: &str, value: impl Into<Value>) {
+        let value = value.into();
+        match self.0.iter_mut().find(|(k, _)| k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.0.push((key.to_string(), value)),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    #[must_use]
+    pub fn keys(&self) -> Vec<&str> {
+        self.0.iter().map(|(k, _)| k.as_str()).collect()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn required(&self, key: &str) -> Result<&Value, SerializationError> {
+        self.get(key)
+            .ok_or_else(|| SerializationError::MissingField(key.to_string()))
+    }
+
+    pub fn get_str(&self, key: &str) -> Result<&str, SerializationError> {
+        match self.required(key)? {
+            Value::Str(value) => Ok(value),
+            other => Err(invalid_type(key, "string", other)),
+        }
+    }
+
+    /// Returns the string for the `key`, or `None` when missing or nil.
+    pub fn get_opt_str(&self, key: &str) -> Result<Option<&str>, SerializationError> {
+        match self.get(key) {
+            None | Some(Value::Nil) => Ok(None),
+            Some(Value::Str(value)) => Ok(Some(value)),
+            Some(other) => Err(invalid_type(key, "string", other)),
+        }
+    }
+
+    /// Returns the unsigned integer for the `key`, also accepting the decimal
+    /// strings written for timestamps as strings.
+    pub fn get_u64(&self, key: &str) -> Result<u64, SerializationError> {
+        match self.required(key)? {
+            Value::UInt(value) => Ok(*value),
+            Value::Str(value) => value
+                .parse()
+                .map_err(|e| SerializationError::invalid_field(key, e)),
+            other => Err(invalid_type(key, "unsigned integer", other)),
+        }
+    }
+
+    /// Returns the unsigned integer for the `key`, or `None` when missing or nil.
+    pub fn get_opt_u64(&self, key: &str) -> Result<Option<u64>, SerializationError> {
+        match self.get(key) {
+            None | Some(Value::Nil) => Ok(None),
+            Some(_) => self.get_u64(key).map(Some),
+        }
+    }
+
+    pub fn get_bool(&self, key: &str) -> Result<bool, SerializationError> {
+        match self.required(key)? {
+            Value::Bool(value) => Ok(*value),
+            other => Err(invalid_type(key, "bool", other)),
+        }
+    }
+
+    /// Returns the bool for the `key`, or the `default` when missing.
+    pub fn get_bool_or(&self, key: &str, default: bool) -> Result<bool, SerializationError> {
+        match self.get(key) {
+            None => Ok(default),
+            Some(_) => self.get_bool(key),
+        }
+    }
+
+    pub fn get_bytes(&self, key: &str) -> Result<&[u8], SerializationError> {
+        match self.required(key)? {
+            Value::Bin(value) => Ok(value),
+            other => Err(invalid_type(key, "bytes", other)),
+        }
+    }
+
+    /// Parses the string for the `key`.
+    pub fn parse<T>(&self, key: &str) -> Result<T, SerializationError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.get_str(key)?
+            .parse()
+            .map_err(|e| SerializationError::invalid_field(key, e))
+    }
+
+    /// Parses the string for the `key`, or returns `None` when missing or nil.
+    pub fn parse_opt<T>(&self, key: &str) -> Result<Option<T>, SerializationError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.get_opt_str(key)?
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|e| SerializationError::invalid_field(key, e))
+            })
+            .transpose()
+    }
+}
+
+/// Converts an object to and from the dict of its Python `to_dict` method.
+pub trait DictSerializable: Sized {
+    fn to_dict(&self) -> Dict;
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError>;
+}
+
+fn invalid_type(key: &str, expected: &str, value: &Value) -> SerializationError {
+    SerializationError::invalid_field(key, format!("expected {expected}, was {value:?}"))
+}
+
+impl Serialize for Dict {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+struct DictVisitor;
+
+impl<'de> Visitor<'de> for DictVisitor {
+    type Value = Dict;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of string keys to primitive values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Dict, A::Error> {
+        let mut entries = Vec::with_capacity(access.size_hint().unwrap_or(0));
+        while let Some((key, value)) = access.next_entry::<String, Value>()? {
+            entries.push((key, value));
+        }
+        Ok(Dict(entries))
+    }
+}
+
+impl<'de> Deserialize<'de> for Dict {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(DictVisitor)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dict() -> Dict {
+        Dict::new()
+            .with("type", "CancelOrder")
+            .with("client_id", None::<String>)
+            .with("ts_init", 1_u64)
+            .with("reconciliation", false)
+            .with("info", b"{}".to_vec())
+    }
+
+    #[test]
+    fn test_dict_keeps_insertion_order() {
+        let mut dict = dict();
+        dict.insert("type", "QueryOrder");
+
+        assert_eq!(
+            dict.keys(),
+            vec!["type", "client_id", "ts_init", "reconciliation", "info"]
+        );
+        assert_eq!(dict.get_str("type").unwrap(), "QueryOrder");
+    }
+
+    #[test]
+    fn test_dict_msgpack_round_trip() {
+        let dict = dict();
+
+        let bytes = rmp_serde::to_vec(&dict).unwrap();
+        let decoded: Dict = rmp_serde::from_slice(&bytes).unwrap();
+
+        assert_eq!(bytes[0], 0x85); // fixmap of 5 entries
+        assert_eq!(decoded, dict);
+    }
+
+    #[test]
+    fn test_dict_json_round_trip() {
+        let dict = Dict::new()
+            .with("type", "OrderInitialized")
+            .with("tags", None::<String>)
+            .with("ts_init", 2_u64)
+            .with("post_only", true);
+
+        let json = serde_json::to_string(&dict).unwrap();
+        let decoded: Dict = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"type":"OrderInitialized","tags":null,"ts_init":2,"post_only":true}"#
+        );
+        assert_eq!(decoded, dict);
+    }
+
+    #[test]
+    fn test_dict_getters() {
+        let dict = dict().with("ts_event", "3");
+
+        assert_eq!(dict.get_opt_str("client_id").unwrap(), None);
+        assert_eq!(dict.get_opt_str("missing").unwrap(), None);
+        assert_eq!(dict.get_u64("ts_init").unwrap(), 1);
+        assert_eq!(dict.get_u64("ts_event").unwrap(), 3);
+        assert!(!dict.get_bool_or("reconciliation", true).unwrap());
+        assert!(dict.get_bool_or("missing", true).unwrap());
+        assert_eq!(dict.get_bytes("info").unwrap(), b"{}");
+        assert!(matches!(
+            dict.get_str("missing"),
+            Err(SerializationError::MissingField(_))
+        ));
+        assert!(matches!(
+            dict.get_str("ts_init"),
+            Err(SerializationError::InvalidField { .. })
+        ));
+    }
+}
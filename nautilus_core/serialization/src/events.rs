@@ -0,0 +1,895 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Dict conversions for order events, with the field names and value formats of
+//! the Python `to_dict` methods in `nautilus_trader.model.events.order`.
+
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use nautilus_model::{
+    enums::{ContingencyType, OrderType, TrailingOffsetType, TriggerType},
+    events::order::{
+        OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEvent, OrderExpired,
+        OrderFilled, OrderInitialized, OrderModifyRejected, OrderPendingCancel, OrderPendingUpdate,
+        OrderRejected, OrderSubmitted, OrderTriggered, OrderUpdated,
+    },
+    identifiers::{client_order_id::ClientOrderId, venue_order_id::VenueOrderId},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+use serde_json::json;
+
+use crate::{
+    dict::{Dict, DictSerializable},
+    SerializationError,
+};
+
+/// Returns a dict starting with the `type` of the object.
+pub(crate) fn typed_dict(type_name: &str) -> Dict {
+    Dict::new().with("type", type_name)
+}
+
+/// Returns an error unless the `type` of the dict is `expected`.
+pub(crate) fn check_type(dict: &Dict, expected: &str) -> Result<(), SerializationError> {
+    let type_name = dict.get_str("type")?;
+    if type_name != expected {
+        return Err(SerializationError::UnknownType(type_name.to_string()));
+    }
+    Ok(())
+}
+
+pub(crate) fn opt_string<T: Display>(value: Option<&T>) -> Option<String> {
+    value.map(ToString::to_string)
+}
+
+/// Returns the string of an optional enum, where `None` is the Python "no value" variant.
+pub(crate) fn enum_string<T: Display>(value: Option<T>, none: T) -> String {
+    value.unwrap_or(none).to_string()
+}
+
+pub(crate) fn parse_enum_opt<T>(
+    dict: &Dict,
+    key: &str,
+    none: T,
+) -> Result<Option<T>, SerializationError>
+where
+    T: FromStr + PartialEq,
+    T::Err: Display,
+{
+    let value: T = dict.parse(key)?;
+    Ok((value != none).then_some(value))
+}
+
+pub(crate) fn parse_money(dict: &Dict, key: &str) -> Result<Money, SerializationError> {
+    let value = dict.get_str(key)?;
+    let (amount, code) = value
+        .split_once(' ')
+        .ok_or_else(|| SerializationError::invalid_field(key, value))?;
+    let amount: f64 = amount
+        .parse()
+        .map_err(|e| SerializationError::invalid_field(key, e))?;
+    let currency =
+        Currency::from_str(code).map_err(|e| SerializationError::invalid_field(key, e))?;
+    Ok(Money::new(amount, currency))
+}
+
+/// Returns the order type specific options of the event as the string of the Python
+/// `json.dumps`, which separates items with `", "` and keys with `": "`.
+fn options_json(event: &OrderInitialized) -> String {
+    let price = json!(opt_string(event.price.as_ref()));
+    let trigger_price = json!(opt_string(event.trigger_price.as_ref()));
+    let trigger_type = json!(enum_string(event.trigger_type, TriggerType::NoTrigger));
+    let expire_time_ns = json!(event.expire_time.unwrap_or_default());
+    let display_qty = json!(opt_string(event.display_qty.as_ref()));
+    let limit_offset = json!(opt_string(event.limit_offset.as_ref()));
+    let trailing_offset = json!(opt_string(event.trailing_offset.as_ref()));
+    let trailing_offset_type = json!(enum_string(
+        event.trailing_offset_type,
+        TrailingOffsetType::NoTrailingOffset
+    ));
+
+    let options = match event.order_type {
+        OrderType::Market => vec![],
+        OrderType::Limit => vec![
+            ("price", price),
+            ("display_qty", display_qty),
+            ("expire_time_ns", expire_time_ns),
+        ],
+        OrderType::StopLimit | OrderType::LimitIfTouched => vec![
+            ("price", price),
+            ("trigger_price", trigger_price),
+            ("trigger_type", trigger_type),
+            ("expire_time_ns", expire_time_ns),
+            ("display_qty", display_qty),
+        ],
+        OrderType::StopMarket | OrderType::MarketIfTouched => vec![
+            ("trigger_price", trigger_price),
+            ("trigger_type", trigger_type),
+            ("expire_time_ns", expire_time_ns),
+        ],
+        OrderType::MarketToLimit => vec![
+            ("display_qty", display_qty),
+            ("expire_time_ns", expire_time_ns),
+        ],
+        OrderType::TrailingStopLimit => vec![
+            ("price", price),
+            ("trigger_price", trigger_price),
+            ("trigger_type", trigger_type),
+            ("limit_offset", limit_offset),
+            ("trailing_offset", trailing_offset),
+            ("trailing_offset_type", trailing_offset_type),
+            ("expire_time_ns", expire_time_ns),
+            ("display_qty", display_qty),
+        ],
+        OrderType::TrailingStopMarket => vec![
+            ("trigger_price", trigger_price),
+            ("trigger_type", trigger_type),
+            ("trailing_offset", trailing_offset),
+            ("trailing_offset_type", trailing_offset_type),
+            ("expire_time_ns", expire_time_ns),
+        ],
+    };
+
+    let items: Vec<String> = options
+        .into_iter()
+        .map(|(key, value)| format!("\"{key}\": {value}"))
+        .collect();
+    format!("{{{}}}", items.join(", "))
+}
+
+/// The order type specific options of an `OrderInitialized` event.
+#[derive(Default)]
+struct Options {
+    price: Option<Price>,
+    trigger_price: Option<Price>,
+    trigger_type: Option<TriggerType>,
+    expire_time: Option<u64>,
+    display_qty: Option<Quantity>,
+    limit_offset: Option<Price>,
+    trailing_offset: Option<Price>,
+    trailing_offset_type: Option<TrailingOffsetType>,
+}
+
+fn parse_json_opt<T>(
+    values: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<Option<T>, SerializationError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    values
+        .get(key)
+        .and_then(serde_json::Value::as_str)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|e| SerializationError::invalid_field(key, e))
+        })
+        .transpose()
+}
+
+fn parse_options(json: &str) -> Result<Options, SerializationError> {
+    let values: serde_json::Map<String, serde_json::Value> = serde_json::from_str(json)?;
+    let trigger_type =
+        parse_json_opt(&values, "trigger_type")?.filter(|value| *value != TriggerType::NoTrigger);
+    let trailing_offset_type = parse_json_opt(&values, "trailing_offset_type")?
+        .filter(|value| *value != TrailingOffsetType::NoTrailingOffset);
+    let expire_time = values
+        .get("expire_time_ns")
+        .and_then(serde_json::Value::as_u64)
+        .filter(|ns| *ns > 0);
+
+    Ok(Options {
+        price: parse_json_opt(&values, "price")?,
+        trigger_price: parse_json_opt(&values, "trigger_price")?,
+        trigger_type,
+        expire_time,
+        display_qty: parse_json_opt(&values, "display_qty")?,
+        limit_offset: parse_json_opt(&values, "limit_offset")?,
+        trailing_offset: parse_json_opt(&values, "trailing_offset")?,
+        trailing_offset_type,
+    })
+}
+
+/// Returns the metadata as a JSON object string, or `None` if empty (in which case it is
+/// omitted, so the dict matches that of the Python event).
+fn metadata_json(metadata: &BTreeMap<String, String>) -> Option<String> {
+    (!metadata.is_empty()).then(|| json!(metadata).to_string())
+}
+
+impl DictSerializable for OrderInitialized {
+    fn to_dict(&self) -> Dict {
+        let linked_order_ids = self.linked_order_ids.as_ref().map(|ids| {
+            ids.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join(",")
+        });
+        let mut dict = typed_dict("OrderInitialized")
+            .with("trader_id", self.trader_id.to_string())
+            .with("strategy_id", self.strategy_id.to_string())
+            .with("instrument_id", self.instrument_id.to_string())
+            .with("client_order_id", self.client_order_id.to_string())
+            .with("order_side", self.order_side.to_string())
+            .with("order_type", self.order_type.to_string())
+            .with("quantity", self.quantity.to_string())
+            .with("time_in_force", self.time_in_force.to_string())
+            .with("post_only", self.post_only)
+            .with("reduce_only", self.reduce_only)
+            .with("quote_quantity", self.quote_quantity)
+            .with("options", options_json(self))
+            .with(
+                "emulation_trigger",
+                enum_string(self.emulation_trigger, TriggerType::NoTrigger),
+            )
+            .with("trigger_instrument_id", None::<String>)
+            .with(
+                "contingency_type",
+                enum_string(self.contingency_type, ContingencyType::NoContingency),
+            )
+            .with("order_list_id", opt_string(self.order_list_id.as_ref()))
+            .with("linked_order_ids", linked_order_ids)
+            .with("parent_order_id", opt_string(self.parent_order_id.as_ref()))
+            .with("exec_algorithm_id", None::<String>)
+            .with("exec_algorithm_params", "null")
+            .with("exec_spawn_id", None::<String>)
+            .with("tags", self.tags.clone())
+            .with("event_id", self.event_id.to_string())
+            .with("ts_init", self.ts_init)
+            .with("reconciliation", self.reconciliation);
+        if let Some(metadata) = metadata_json(&self.metadata) {
+            dict.insert("metadata", metadata);
+        }
+        dict
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "OrderInitialized")?;
+        let options = parse_options(dict.get_str("options")?)?;
+        let linked_order_ids = dict
+            .get_opt_str("linked_order_ids")?
+            .map(|ids| {
+                ids.split(',')
+                    .map(|id| {
+                        ClientOrderId::from_str(id)
+                            .map_err(|e| SerializationError::invalid_field("linked_order_ids", e))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        let ts_init = dict.get_u64("ts_init")?;
+
+        Ok(Self {
+            trader_id: dict.parse("trader_id")?,
+            strategy_id: dict.parse("strategy_id")?,
+            instrument_id: dict.parse("instrument_id")?,
+            client_order_id: dict.parse("client_order_id")?,
+            order_side: dict.parse("order_side")?,
+            order_type: dict.parse("order_type")?,
+            quantity: dict.parse("quantity")?,
+            price: options.price,
+            trigger_price: options.trigger_price,
+            trigger_type: options.trigger_type,
+            time_in_force: dict.parse("time_in_force")?,
+            expire_time: options.expire_time,
+            post_only: dict.get_bool("post_only")?,
+            reduce_only: dict.get_bool("reduce_only")?,
+            quote_quantity: dict.get_bool("quote_quantity")?,
+            display_qty: options.display_qty,
+            limit_offset: options.limit_offset,
+            trailing_offset: options.trailing_offset,
+            trailing_offset_type: options.trailing_offset_type,
+            emulation_trigger: parse_enum_opt(dict, "emulation_trigger", TriggerType::NoTrigger)?,
+            contingency_type: parse_enum_opt(
+                dict,
+                "contingency_type",
+                ContingencyType::NoContingency,
+            )?,
+            order_list_id: dict.parse_opt("order_list_id")?,
+            linked_order_ids,
+            parent_order_id: dict.parse_opt("parent_order_id")?,
+            tags: dict.get_opt_str("tags")?.map(ToString::to_string),
+            metadata: dict
+                .get_opt_str("metadata")?
+                .map(serde_json::from_str)
+                .transpose()?
+                .unwrap_or_default(),
+            event_id: dict.parse("event_id")?,
+            ts_event: ts_init,
+            ts_init,
+            reconciliation: dict.get_bool_or("reconciliation", false)?,
+        })
+    }
+}
+
+impl DictSerializable for OrderDenied {
+    fn to_dict(&self) -> Dict {
+        typed_dict("OrderDenied")
+            .with("trader_id", self.trader_id.to_string())
+            .with("strategy_id", self.strategy_id.to_string())
+            .with("instrument_id", self.instrument_id.to_string())
+            .with("client_order_id", self.client_order_id.to_string())
+            .with("reason", self.reason.to_string())
+            .with("event_id", self.event_id.to_string())
+            .with("ts_event", self.ts_event)
+            .with("ts_init", self.ts_init)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "OrderDenied")?;
+        Ok(Self {
+            trader_id: dict.parse("trader_id")?,
+            strategy_id: dict.parse("strategy_id")?,
+            instrument_id: dict.parse("instrument_id")?,
+            client_order_id: dict.parse("client_order_id")?,
+            reason: Box::new(dict.get_str("reason")?.to_string()),
+            event_id: dict.parse("event_id")?,
+            ts_event: dict.get_u64("ts_event")?,
+            ts_init: dict.get_u64("ts_init")?,
+        })
+    }
+}
+
+impl DictSerializable for OrderSubmitted {
+    fn to_dict(&self) -> Dict {
+        typed_dict("OrderSubmitted")
+            .with("trader_id", self.trader_id.to_string())
+            .with("strategy_id", self.strategy_id.to_string())
+            .with("instrument_id", self.instrument_id.to_string())
+            .with("client_order_id", self.client_order_id.to_string())
+            .with("account_id", self.account_id.to_string())
+            .with("event_id", self.event_id.to_string())
+            .with("ts_event", self.ts_event)
+            .with("ts_init", self.ts_init)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "OrderSubmitted")?;
+        Ok(Self {
+            trader_id: dict.parse("trader_id")?,
+            strategy_id: dict.parse("strategy_id")?,
+            instrument_id: dict.parse("instrument_id")?,
+            client_order_id: dict.parse("client_order_id")?,
+            account_id: dict.parse("account_id")?,
+            event_id: dict.parse("event_id")?,
+            ts_event: dict.get_u64("ts_event")?,
+            ts_init: dict.get_u64("ts_init")?,
+        })
+    }
+}
+
+impl DictSerializable for OrderAccepted {
+    fn to_dict(&self) -> Dict {
+        typed_dict("OrderAccepted")
+            .with("trader_id", self.trader_id.to_string())
+            .with("strategy_id", self.strategy_id.to_string())
+            .with("instrument_id", self.instrument_id.to_string())
+            .with("client_order_id", self.client_order_id.to_string())
+            .with("venue_order_id", self.venue_order_id.to_string())
+            .with("account_id", self.account_id.to_string())
+            .with("event_id", self.event_id.to_string())
+            .with("ts_event", self.ts_event)
+            .with("ts_init", self.ts_init)
+            .with("reconciliation", self.reconciliation)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "OrderAccepted")?;
+        Ok(Self {
+            trader_id: dict.parse("trader_id")?,
+            strategy_id: dict.parse("strategy_id")?,
+            instrument_id: dict.parse("instrument_id")?,
+            client_order_id: dict.parse("client_order_id")?,
+            venue_order_id: dict.parse("venue_order_id")?,
+            account_id: dict.parse("account_id")?,
+            event_id: dict.parse("event_id")?,
+            ts_event: dict.get_u64("ts_event")?,
+            ts_init: dict.get_u64("ts_init")?,
+            reconciliation: dict.get_bool_or("reconciliation", false)?,
+        })
+    }
+}
+
+impl DictSerializable for OrderRejected {
+    /// The Python event has no venue order ID, so it is not serialized.
+    fn to_dict(&self) -> Dict {
+        typed_dict("OrderRejected")
+            .with("trader_id", self.trader_id.to_string())
+            .with("strategy_id", self.strategy_id.to_string())
+            .with("instrument_id", self.instrument_id.to_string())
+            .with("client_order_id", self.client_order_id.to_string())
+            .with("account_id", self.account_id.to_string())
+            .with("reason", self.reason.clone())
+            .with("event_id", self.event_id.to_string())
+            .with("ts_event", self.ts_event)
+            .with("ts_init", self.ts_init)
+            .with("reconciliation", self.reconciliation)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "OrderRejected")?;
+        Ok(Self {
+            trader_id: dict.parse("trader_id")?,
+            strategy_id: dict.parse("strategy_id")?,
+            instrument_id: dict.parse("instrument_id")?,
+            client_order_id: dict.parse("client_order_id")?,
+            venue_order_id: VenueOrderId::default(),
+            account_id: dict.parse("account_id")?,
+            reason: dict.get_str("reason")?.to_string(),
+            event_id: dict.parse("event_id")?,
+            ts_event: dict.get_u64("ts_event")?,
+            ts_init: dict.get_u64("ts_init")?,
+            reconciliation: dict.get_bool_or("reconciliation", false)?,
+        })
+    }
+}
+
+/// Implements [`DictSerializable`] for the events of a transition at the venue, which
+/// share the same fields with an optional venue order ID and account ID.
+macro_rules! impl_venue_event {
+    ($ty:ident) => {
+        impl DictSerializable for $ty {
+            fn to_dict(&self) -> Dict {
+                typed_dict(stringify!($ty))
+                    .with("trader_id", self.trader_id.to_string())
+                    .with("strategy_id", self.strategy_id.to_string())
+                    .with("instrument_id", self.instrument_id.to_string())
+                    .with("client_order_id", self.client_order_id.to_string())
+                    .with("venue_order_id", opt_string(self.venue_order_id.as_ref()))
+                    .with("account_id", opt_string(self.account_id.as_ref()))
+                    .with("event_id", self.event_id.to_string())
+                    .with("ts_event", self.ts_event)
+                    .with("ts_init", self.ts_init)
+                    .with("reconciliation", self.reconciliation)
+            }
+
+            fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+                check_type(dict, stringify!($ty))?;
+                Ok(Self {
+                    trader_id: dict.parse("trader_id")?,
+                    strategy_id: dict.parse("strategy_id")?,
+                    instrument_id: dict.parse("instrument_id")?,
+                    client_order_id: dict.parse("client_order_id")?,
+                    venue_order_id: dict.parse_opt("venue_order_id")?,
+                    account_id: dict.parse_opt("account_id")?,
+                    event_id: dict.parse("event_id")?,
+                    ts_event: dict.get_u64("ts_event")?,
+                    ts_init: dict.get_u64("ts_init")?,
+                    reconciliation: dict.get_bool_or("reconciliation", false)?,
+                })
+            }
+        }
+    };
+}
+
+impl_venue_event!(OrderCanceled);
+impl_venue_event!(OrderExpired);
+impl_venue_event!(OrderTriggered);
+
+/// Implements [`DictSerializable`] for the pending events, which require an account ID.
+macro_rules! impl_pending_event {
+    ($ty:ident) => {
+        impl DictSerializable for $ty {
+            fn to_dict(&self) -> Dict {
+                typed_dict(stringify!($ty))
+                    .with("trader_id", self.trader_id.to_string())
+                    .with("strategy_id", self.strategy_id.to_string())
+                    .with("instrument_id", self.instrument_id.to_string())
+                    .with("client_order_id", self.client_order_id.to_string())
+                    .with("venue_order_id", opt_string(self.venue_order_id.as_ref()))
+                    .with("account_id", self.account_id.to_string())
+                    .with("event_id", self.event_id.to_string())
+                    .with("ts_event", self.ts_event)
+                    .with("ts_init", self.ts_init)
+                    .with("reconciliation", self.reconciliation)
+            }
+
+            fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+                check_type(dict, stringify!($ty))?;
+                Ok(Self {
+                    trader_id: dict.parse("trader_id")?,
+                    strategy_id: dict.parse("strategy_id")?,
+                    instrument_id: dict.parse("instrument_id")?,
+                    client_order_id: dict.parse("client_order_id")?,
+                    venue_order_id: dict.parse_opt("venue_order_id")?,
+                    account_id: dict.parse("account_id")?,
+                    event_id: dict.parse("event_id")?,
+                    ts_event: dict.get_u64("ts_event")?,
+                    ts_init: dict.get_u64("ts_init")?,
+                    reconciliation: dict.get_bool_or("reconciliation", false)?,
+                })
+            }
+        }
+    };
+}
+
+impl_pending_event!(OrderPendingUpdate);
+impl_pending_event!(OrderPendingCancel);
+
+/// Implements [`DictSerializable`] for the rejections of a modify or cancel request.
+macro_rules! impl_request_rejected_event {
+    ($ty:ident) => {
+        impl DictSerializable for $ty {
+            fn to_dict(&self) -> Dict {
+                typed_dict(stringify!($ty))
+                    .with("trader_id", self.trader_id.to_string())
+                    .with("strategy_id", self.strategy_id.to_string())
+                    .with("instrument_id", self.instrument_id.to_string())
+                    .with("client_order_id", self.client_order_id.to_string())
+                    .with("venue_order_id", opt_string(self.venue_order_id.as_ref()))
+                    .with("account_id", opt_string(self.account_id.as_ref()))
+                    .with("reason", self.reason.to_string())
+                    .with("event_id", self.event_id.to_string())
+                    .with("ts_event", self.ts_event)
+                    .with("ts_init", self.ts_init)
+                    .with("reconciliation", self.reconciliation)
+            }
+
+            fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+                check_type(dict, stringify!($ty))?;
+                Ok(Self {
+                    trader_id: dict.parse("trader_id")?,
+                    strategy_id: dict.parse("strategy_id")?,
+                    instrument_id: dict.parse("instrument_id")?,
+                    client_order_id: dict.parse("client_order_id")?,
+                    venue_order_id: dict.parse_opt("venue_order_id")?,
+                    account_id: dict.parse_opt("account_id")?,
+                    reason: Box::new(dict.get_str("reason")?.to_string()),
+                    event_id: dict.parse("event_id")?,
+                    ts_event: dict.get_u64("ts_event")?,
+                    ts_init: dict.get_u64("ts_init")?,
+                    reconciliation: dict.get_bool_or("reconciliation", false)?,
+                })
+            }
+        }
+    };
+}
+
+impl_request_rejected_event!(OrderModifyRejected);
+impl_request_rejected_event!(OrderCancelRejected);
+
+impl DictSerializable for OrderUpdated {
+    fn to_dict(&self) -> Dict {
+        typed_dict("OrderUpdated")
+            .with("trader_id", self.trader_id.to_string())
+            .with("strategy_id", self.strategy_id.to_string())
+            .with("instrument_id", self.instrument_id.to_string())
+            .with("client_order_id", self.client_order_id.to_string())
+            .with("venue_order_id", opt_string(self.venue_order_id.as_ref()))
+            .with("account_id", opt_string(self.account_id.as_ref()))
+            .with("quantity", self.quantity.to_string())
+            .with("price", opt_string(self.price.as_ref()))
+            .with("trigger_price", opt_string(self.trigger_price.as_ref()))
+            .with("event_id", self.event_id.to_string())
+            .with("ts_event", self.ts_event)
+            .with("ts_init", self.ts_init)
+            .with("reconciliation", self.reconciliation)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "OrderUpdated")?;
+        Ok(Self {
+            trader_id: dict.parse("trader_id")?,
+            strategy_id: dict.parse("strategy_id")?,
+            instrument_id: dict.parse("instrument_id")?,
+            client_order_id: dict.parse("client_order_id")?,
+            venue_order_id: dict.parse_opt("venue_order_id")?,
+            account_id: dict.parse_opt("account_id")?,
+            quantity: dict.parse("quantity")?,
+            price: dict.parse_opt("price")?,
+            trigger_price: dict.parse_opt("trigger_price")?,
+            event_id: dict.parse("event_id")?,
+            ts_event: dict.get_u64("ts_event")?,
+            ts_init: dict.get_u64("ts_init")?,
+            reconciliation: dict.get_bool_or("reconciliation", false)?,
+        })
+    }
+}
+
+impl DictSerializable for OrderFilled {
+    /// The Python event carries venue specific `info`, which is serialized as empty.
+    fn to_dict(&self) -> Dict {
+        typed_dict("OrderFilled")
+            .with("trader_id", self.trader_id.to_string())
+            .with("strategy_id", self.strategy_id.to_string())
+            .with("instrument_id", self.instrument_id.to_string())
+            .with("client_order_id", self.client_order_id.to_string())
+            .with("venue_order_id", self.venue_order_id.to_string())
+            .with("account_id", self.account_id.to_string())
+            .with("trade_id", self.trade_id.to_string())
+            .with("position_id", opt_string(self.position_id.as_ref()))
+            .with("order_side", self.order_side.to_string())
+            .with("order_type", self.order_type.to_string())
+            .with("last_qty", self.last_qty.to_string())
+            .with("last_px", self.last_px.to_string())
+            .with("currency", self.currency.code.to_string())
+            .with("commission", self.commission.to_string())
+            .with("liquidity_side", self.liquidity_side.to_string())
+            .with("event_id", self.event_id.to_string())
+            .with("ts_event", self.ts_event)
+            .with("ts_init", self.ts_init)
+            .with("info", b"{}".to_vec())
+            .with("reconciliation", self.reconciliation)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "OrderFilled")?;
+        Ok(Self {
+            trader_id: dict.parse("trader_id")?,
+            strategy_id: dict.parse("strategy_id")?,
+            instrument_id: dict.parse("instrument_id")?,
+            client_order_id: dict.parse("client_order_id")?,
+            venue_order_id: dict.parse("venue_order_id")?,
+            account_id: dict.parse("account_id")?,
+            trade_id: dict.parse("trade_id")?,
+            position_id: dict.parse_opt("position_id")?,
+            order_side: dict.parse("order_side")?,
+            order_type: dict.parse("order_type")?,
+            last_qty: dict.parse("last_qty")?,
+            last_px: dict.parse("last_px")?,
+            currency: Currency::from_str(dict.get_str("currency")?)
+                .map_err(|e| SerializationError::invalid_field("currency", e))?,
+            commission: parse_money(dict, "commission")?,
+            liquidity_side: dict.parse("liquidity_side")?,
+            event_id: dict.parse("event_id")?,
+            ts_event: dict.get_u64("ts_event")?,
+            ts_init: dict.get_u64("ts_init")?,
+            reconciliation: dict.get_bool_or("reconciliation", false)?,
+        })
+    }
+}
+
+impl DictSerializable for OrderEvent {
+    /// Partial fills are serialized as `OrderFilled`, as there is no Python equivalent.
+    fn to_dict(&self) -> Dict {
+        match self {
+            OrderEvent::OrderInitialized(event) => event.to_dict(),
+            OrderEvent::OrderDenied(event) => event.to_dict(),
+            OrderEvent::OrderSubmitted(event) => event.to_dict(),
+            OrderEvent::OrderAccepted(event) => event.to_dict(),
+            OrderEvent::OrderRejected(event) => event.to_dict(),
+            OrderEvent::OrderCanceled(event) => event.to_dict(),
+            OrderEvent::OrderExpired(event) => event.to_dict(),
+            OrderEvent::OrderTriggered(event) => event.to_dict(),
+            OrderEvent::OrderPendingUpdate(event) => event.to_dict(),
+            OrderEvent::OrderPendingCancel(event) => event.to_dict(),
+            OrderEvent::OrderModifyRejected(event) => event.to_dict(),
+            OrderEvent::OrderCancelRejected(event) => event.to_dict(),
+            OrderEvent::OrderUpdated(event) => event.to_dict(),
+            OrderEvent::OrderPartiallyFilled(event) => event.to_dict(),
+            OrderEvent::OrderFilled(event) => event.to_dict(),
+        }
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        let event = match dict.get_str("type")? {
+            "OrderInitialized" => OrderEvent::OrderInitialized(OrderInitialized::from_dict(dict)?),
+            "OrderDenied" => OrderEvent::OrderDenied(OrderDenied::from_dict(dict)?),
+            "OrderSubmitted" => OrderEvent::OrderSubmitted(OrderSubmitted::from_dict(dict)?),
+            "OrderAccepted" => OrderEvent::OrderAccepted(OrderAccepted::from_dict(dict)?),
+            "OrderRejected" => OrderEvent::OrderRejected(OrderRejected::from_dict(dict)?),
+            "OrderCanceled" => OrderEvent::OrderCanceled(OrderCanceled::from_dict(dict)?),
+            "OrderExpired" => OrderEvent::OrderExpired(OrderExpired::from_dict(dict)?),
+            "OrderTriggered" => OrderEvent::OrderTriggered(OrderTriggered::from_dict(dict)?),
+            "OrderPendingUpdate" => {
+                OrderEvent::OrderPendingUpdate(OrderPendingUpdate::from_dict(dict)?)
+            }
+            "OrderPendingCancel" => {
+                OrderEvent::OrderPendingCancel(OrderPendingCancel::from_dict(dict)?)
+            }
+            "OrderModifyRejected" => {
+                OrderEvent::OrderModifyRejected(OrderModifyRejected::from_dict(dict)?)
+            }
+            "OrderCancelRejected" => {
+                OrderEvent::OrderCancelRejected(OrderCancelRejected::from_dict(dict)?)
+            }
+            "OrderUpdated" => OrderEvent::OrderUpdated(OrderUpdated::from_dict(dict)?),
+            "OrderFilled" => OrderEvent::OrderFilled(OrderFilled::from_dict(dict)?),
+            other => return Err(SerializationError::UnknownType(other.to_string())),
+        };
+        Ok(event)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        enums::{LiquiditySide, OrderSide, TimeInForce},
+        identifiers::{account_id::AccountId, instrument_id::InstrumentId, trade_id::TradeId},
+        testkit::{order_events, order_initialized},
+    };
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from_str("AUD/USD.SIM").unwrap()
+    }
+
+    fn limit_order_initialized() -> OrderInitialized {
+        OrderInitialized {
+            instrument_id: instrument_id(),
+            client_order_id: ClientOrderId::new("O-123456"),
+            order_type: OrderType::Limit,
+            quantity: Quantity::from_str("100000").unwrap(),
+            price: Some(Price::from_str("1.00000").unwrap()),
+            time_in_force: TimeInForce::Gtc,
+            linked_order_ids: Some(vec![ClientOrderId::new("O-1"), ClientOrderId::new("O-2")]),
+            event_id: UUID4::new(),
+            ts_event: 1,
+            ts_init: 1,
+            ..Default::default()
+        }
+    }
+
+    fn order_filled() -> OrderFilled {
+        OrderFilled {
+            trader_id: Default::default(),
+            strategy_id: Default::default(),
+            instrument_id: instrument_id(),
+            client_order_id: ClientOrderId::new("O-123456"),
+            venue_order_id: VenueOrderId::new("1"),
+            account_id: AccountId::new("SIM-001"),
+            trade_id: TradeId::new("E-1"),
+            position_id: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            last_qty: Quantity::from_str("100000").unwrap(),
+            last_px: Price::from_str("1.00000").unwrap(),
+            currency: Currency::from("USD"),
+            commission: Money::new(2.0, Currency::from("USD")),
+            liquidity_side: LiquiditySide::Maker,
+            event_id: UUID4::new(),
+            ts_event: 1,
+            ts_init: 2,
+            reconciliation: false,
+        }
+    }
+
+    #[rstest]
+    fn test_order_initialized_to_dict_keys() {
+        let dict = limit_order_initialized().to_dict();
+
+        assert_eq!(
+            dict.keys(),
+            vec![
+                "type",
+                "trader_id",
+                "strategy_id",
+                "instrument_id",
+                "client_order_id",
+                "order_side",
+                "order_type",
+                "quantity",
+                "time_in_force",
+                "post_only",
+                "reduce_only",
+                "quote_quantity",
+                "options",
+                "emulation_trigger",
+                "trigger_instrument_id",
+                "contingency_type",
+                "order_list_id",
+                "linked_order_ids",
+                "parent_order_id",
+                "exec_algorithm_id",
+                "exec_algorithm_params",
+                "exec_spawn_id",
+                "tags",
+                "event_id",
+                "ts_init",
+                "reconciliation",
+            ]
+        );
+        assert_eq!(
+            dict.get_str("options").unwrap(),
+            r#"{"price": "1.00000", "display_qty": null, "expire_time_ns": 0}"#
+        );
+        assert_eq!(dict.get_str("emulation_trigger").unwrap(), "NO_TRIGGER");
+        assert_eq!(dict.get_str("linked_order_ids").unwrap(), "O-1,O-2");
+    }
+
+    #[rstest]
+    fn test_order_initialized_round_trip() {
+        let event = limit_order_initialized();
+
+        let result = OrderInitialized::from_dict(&event.to_dict()).unwrap();
+
+        assert_eq!(result, event);
+    }
+
+    #[rstest]
+    fn test_order_initialized_with_metadata_round_trip() {
+        let mut event = limit_order_initialized();
+        event
+            .metadata
+            .insert("exec_inst".to_string(), "ALO".to_string());
+        let dict = event.to_dict();
+
+        let result = OrderInitialized::from_dict(&dict).unwrap();
+
+        assert_eq!(dict.get_str("metadata").unwrap(), r#"{"exec_inst":"ALO"}"#);
+        assert_eq!(result, event);
+    }
+
+    #[rstest]
+    fn test_order_filled_round_trip() {
+        let event = order_filled();
+        let dict = event.to_dict();
+
+        let result = OrderFilled::from_dict(&dict).unwrap();
+
+        assert_eq!(dict.get_str("commission").unwrap(), "2.00 USD");
+        assert_eq!(result, event);
+    }
+
+    #[rstest]
+    fn test_order_event_partially_filled_decodes_as_filled() {
+        let fill = order_filled();
+        let event = OrderEvent::OrderPartiallyFilled(fill.clone());
+
+        let result = OrderEvent::from_dict(&event.to_dict()).unwrap();
+
+        assert_eq!(result, OrderEvent::OrderFilled(fill));
+    }
+
+    #[rstest]
+    fn test_order_event_round_trip() {
+        let event = OrderEvent::OrderCanceled(OrderCanceled {
+            instrument_id: instrument_id(),
+            venue_order_id: Some(VenueOrderId::new("1")),
+            event_id: UUID4::new(),
+            ts_event: 1,
+            ts_init: 2,
+            ..Default::default()
+        });
+
+        let result = OrderEvent::from_dict(&event.to_dict()).unwrap();
+
+        assert_eq!(result, event);
+    }
+
+    #[rstest]
+    fn test_from_dict_with_wrong_type_returns_error() {
+        let dict = OrderSubmitted::default().to_dict();
+
+        let result = OrderAccepted::from_dict(&dict);
+
+        assert!(matches!(result, Err(SerializationError::UnknownType(t)) if t == "OrderSubmitted"));
+    }
+
+    proptest! {
+        #[test]
+        fn test_order_event_round_trip_for_any_lifecycle(
+            events in order_initialized().prop_flat_map(|init| order_events(&init, 20))
+        ) {
+            for event in events {
+                let expected = match &event {
+                    OrderEvent::OrderPartiallyFilled(fill) => OrderEvent::OrderFilled(fill.clone()),
+                    other => other.clone(),
+                };
+
+                let result = OrderEvent::from_dict(&event.to_dict()).unwrap();
+
+                prop_assert_eq!(result, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Serialization of trading commands, order events and execution reports, compatible with
+//! the Python `MsgPackSerializer` so that messages can be exchanged between Rust and Python.
+
+pub mod commands;
+pub mod dict;
+pub mod events;
+pub mod msgpack;
+pub mod reports;
+
+use std::fmt::Display;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SerializationError {
+    #[error("Failed to encode: {0}")]
+    Encode(#[from] rmp_serde::encode::Error),
+    #[error("Failed to decode: {0}")]
+    Decode(#[from] rmp_serde::decode::Error),
+    #[error("Invalid nested JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Missing field '{0}'")]
+    MissingField(String),
+    #[error("Invalid field '{field}': {reason}")]
+    InvalidField { field: String, reason: String },
+    #[error("Unknown type '{0}'")]
+    UnknownType(String),
+}
+
+impl SerializationError {
+    pub fn invalid_field(field: &str, reason: impl Display) -> Self {
+        Self::InvalidField {
+            field: field.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
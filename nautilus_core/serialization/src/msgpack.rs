@@ -0,0 +1,182 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{
+    dict::{DictSerializable, Value},
+    SerializationError,
+};
+
+/// Provides serialization to MessagePack, producing the same bytes as the Python
+/// `MsgPackSerializer`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MsgPackSerializer {
+    /// If the `ts_event` and `ts_init` timestamps are serialized as strings.
+    pub timestamps_as_str: bool,
+}
+
+impl MsgPackSerializer {
+    #[must_use]
+    pub fn new(timestamps_as_str: bool) -> Self {
+        Self { timestamps_as_str }
+    }
+
+    pub fn serialize<T: DictSerializable>(&self, obj: &T) -> Result<Vec<u8>, SerializationError> {
+        let mut dict = obj.to_dict();
+        if self.timestamps_as_str {
+            for key in ["ts_event", "ts_init"] {
+                if let Some(Value::UInt(ts)) = dict.get(key) {
+                    let ts = ts.to_string();
+                    dict.insert(key, ts);
+                }
+            }
+        }
+        Ok(rmp_serde::to_vec(&dict)?)
+    }
+
+    /// Timestamps are accepted as either integers or strings.
+    pub fn deserialize<T: DictSerializable>(&self, bytes: &[u8]) -> Result<T, SerializationError> {
+        let dict = rmp_serde::from_slice(bytes)?;
+        T::from_dict(&dict)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        commands::{CancelOrder, TradingCommand},
+        events::order::{OrderAccepted, OrderEvent},
+        identifiers::{
+            account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+            strategy_id::StrategyId, trader_id::TraderId, venue_order_id::VenueOrderId,
+        },
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::dict::Dict;
+
+    const COMMAND_ID: &str = "2d89666b-1a1e-4a75-b193-4eb3b454c757";
+
+    fn cancel_order() -> CancelOrder {
+        CancelOrder {
+            trader_id: TraderId::new("T-1"),
+            client_id: None,
+            strategy_id: StrategyId::new("S-1"),
+            instrument_id: InstrumentId::from_str("A.B").unwrap(),
+            client_order_id: ClientOrderId::new("O-1"),
+            venue_order_id: None,
+            command_id: UUID4::from(COMMAND_ID),
+            ts_init: 1,
+        }
+    }
+
+    /// Returns the MessagePack fixstr encoding of the string.
+    fn fixstr(s: &str) -> Vec<u8> {
+        let mut bytes = vec![0xa0 | s.len() as u8];
+        bytes.extend_from_slice(s.as_bytes());
+        bytes
+    }
+
+    #[rstest]
+    fn test_serialize_cancel_order_bytes() {
+        let serializer = MsgPackSerializer::default();
+
+        let result = serializer.serialize(&cancel_order()).unwrap();
+
+        let mut expected = vec![0x89];
+        for (key, value) in [
+            ("type", Some("CancelOrder")),
+            ("client_id", None),
+            ("trader_id", Some("T-1")),
+            ("strategy_id", Some("S-1")),
+            ("instrument_id", Some("A.B")),
+            ("client_order_id", Some("O-1")),
+            ("venue_order_id", None),
+        ] {
+            expected.extend(fixstr(key));
+            match value {
+                Some(value) => expected.extend(fixstr(value)),
+                None => expected.push(0xc0),
+            }
+        }
+        expected.extend(fixstr("command_id"));
+        expected.push(0xd9);
+        expected.push(COMMAND_ID.len() as u8);
+        expected.extend_from_slice(COMMAND_ID.as_bytes());
+        expected.extend(fixstr("ts_init"));
+        expected.push(0x01);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_serialize_with_timestamps_as_str() {
+        let serializer = MsgPackSerializer::new(true);
+        let bytes = serializer.serialize(&cancel_order()).unwrap();
+
+        let dict: Dict = rmp_serde::from_slice(&bytes).unwrap();
+        let result: CancelOrder = serializer.deserialize(&bytes).unwrap();
+
+        assert_eq!(dict.get_str("ts_init").unwrap(), "1");
+        assert_eq!(result, cancel_order());
+    }
+
+    #[rstest]
+    fn test_command_round_trip() {
+        let serializer = MsgPackSerializer::default();
+        let command = TradingCommand::CancelOrder(cancel_order());
+
+        let bytes = serializer.serialize(&command).unwrap();
+        let result: TradingCommand = serializer.deserialize(&bytes).unwrap();
+
+        assert_eq!(result, command);
+    }
+
+    #[rstest]
+    fn test_event_round_trip() {
+        let serializer = MsgPackSerializer::default();
+        let event = OrderEvent::OrderAccepted(OrderAccepted {
+            trader_id: TraderId::new("T-1"),
+            strategy_id: StrategyId::new("S-1"),
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            client_order_id: ClientOrderId::new("O-1"),
+            venue_order_id: VenueOrderId::new("1"),
+            account_id: AccountId::new("SIM-001"),
+            event_id: UUID4::new(),
+            ts_event: 1,
+            ts_init: 2,
+            reconciliation: true,
+        });
+
+        let bytes = serializer.serialize(&event).unwrap();
+        let result: OrderEvent = serializer.deserialize(&bytes).unwrap();
+
+        assert_eq!(result, event);
+    }
+
+    #[rstest]
+    fn test_deserialize_invalid_bytes_returns_error() {
+        let serializer = MsgPackSerializer::default();
+
+        let result: Result<CancelOrder, _> = serializer.deserialize(b"\x01");
+
+        assert!(result.is_err());
+    }
+}
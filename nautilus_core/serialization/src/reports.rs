@@ -0,0 +1,326 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Dict conversions for execution reports, with the field names of the Python `to_dict`
+//! methods in `nautilus_trader.execution.reports`.
+//!
+//! Timestamps are written as UNIX nanoseconds, where the Python reports write `expire_time`
+//! and `ts_triggered` as ISO 8601 strings.
+
+use nautilus_model::{
+    enums::{ContingencyType, TrailingOffsetType, TriggerType},
+    reports::{FillReport, OrderStatusReport, PositionStatusReport},
+};
+
+use crate::{
+    dict::{Dict, DictSerializable},
+    events::{check_type, enum_string, opt_string, parse_enum_opt, parse_money, typed_dict},
+    SerializationError,
+};
+
+impl DictSerializable for OrderStatusReport {
+    /// The `leaves_qty` is written for Python, and ignored when decoding.
+    fn to_dict(&self) -> Dict {
+        typed_dict("OrderStatusReport")
+            .with("account_id", self.account_id.to_string())
+            .with("instrument_id", self.instrument_id.to_string())
+            .with("client_order_id", opt_string(self.client_order_id.as_ref()))
+            .with("order_list_id", opt_string(self.order_list_id.as_ref()))
+            .with("venue_order_id", self.venue_order_id.to_string())
+            .with("order_side", self.order_side.to_string())
+            .with("order_type", self.order_type.to_string())
+            .with(
+                "contingency_type",
+                enum_string(self.contingency_type, ContingencyType::NoContingency),
+            )
+            .with("time_in_force", self.time_in_force.to_string())
+            .with("expire_time", self.expire_time)
+            .with("order_status", self.order_status.to_string())
+            .with("price", opt_string(self.price.as_ref()))
+            .with("trigger_price", opt_string(self.trigger_price.as_ref()))
+            .with(
+                "trigger_type",
+                enum_string(self.trigger_type, TriggerType::NoTrigger),
+            )
+            .with("limit_offset", opt_string(self.limit_offset.as_ref()))
+            .with("trailing_offset", opt_string(self.trailing_offset.as_ref()))
+            .with(
+                "trailing_offset_type",
+                enum_string(
+                    self.trailing_offset_type,
+                    TrailingOffsetType::NoTrailingOffset,
+                ),
+            )
+            .with("quantity", self.quantity.to_string())
+            .with("filled_qty", self.filled_qty.to_string())
+            .with(
+                "leaves_qty",
+                (self.quantity - self.filled_qty.min(self.quantity)).to_string(),
+            )
+            .with("display_qty", opt_string(self.display_qty.as_ref()))
+            .with("avg_px", opt_string(self.avg_px.as_ref()))
+            .with("post_only", self.post_only)
+            .with("reduce_only", self.reduce_only)
+            .with("cancel_reason", self.cancel_reason.clone())
+            .with("report_id", self.report_id.to_string())
+            .with("ts_accepted", self.ts_accepted)
+            .with("ts_triggered", self.ts_triggered)
+            .with("ts_last", self.ts_last)
+            .with("ts_init", self.ts_init)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "OrderStatusReport")?;
+        Ok(Self {
+            account_id: dict.parse("account_id")?,
+            instrument_id: dict.parse("instrument_id")?,
+            client_order_id: dict.parse_opt("client_order_id")?,
+            order_list_id: dict.parse_opt("order_list_id")?,
+            venue_order_id: dict.parse("venue_order_id")?,
+            order_side: dict.parse("order_side")?,
+            order_type: dict.parse("order_type")?,
+            contingency_type: parse_enum_opt(
+                dict,
+                "contingency_type",
+                ContingencyType::NoContingency,
+            )?,
+            time_in_force: dict.parse("time_in_force")?,
+            expire_time: dict.get_opt_u64("expire_time")?,
+            order_status: dict.parse("order_status")?,
+            price: dict.parse_opt("price")?,
+            trigger_price: dict.parse_opt("trigger_price")?,
+            trigger_type: parse_enum_opt(dict, "trigger_type", TriggerType::NoTrigger)?,
+            limit_offset: dict.parse_opt("limit_offset")?,
+            trailing_offset: dict.parse_opt("trailing_offset")?,
+            trailing_offset_type: parse_enum_opt(
+                dict,
+                "trailing_offset_type",
+                TrailingOffsetType::NoTrailingOffset,
+            )?,
+            quantity: dict.parse("quantity")?,
+            filled_qty: dict.parse("filled_qty")?,
+            display_qty: dict.parse_opt("display_qty")?,
+            avg_px: dict.parse_opt("avg_px")?,
+            post_only: dict.get_bool("post_only")?,
+            reduce_only: dict.get_bool("reduce_only")?,
+            cancel_reason: dict.get_opt_str("cancel_reason")?.map(ToString::to_string),
+            report_id: dict.parse("report_id")?,
+            ts_accepted: dict.get_u64("ts_accepted")?,
+            ts_triggered: dict.get_opt_u64("ts_triggered")?,
+            ts_last: dict.get_u64("ts_last")?,
+            ts_init: dict.get_u64("ts_init")?,
+        })
+    }
+}
+
+impl DictSerializable for FillReport {
+    fn to_dict(&self) -> Dict {
+        typed_dict("FillReport")
+            .with("account_id", self.account_id.to_string())
+            .with("instrument_id", self.instrument_id.to_string())
+            .with("client_order_id", opt_string(self.client_order_id.as_ref()))
+            .with("venue_order_id", self.venue_order_id.to_string())
+            .with(
+                "venue_position_id",
+                opt_string(self.venue_position_id.as_ref()),
+            )
+            .with("trade_id", self.trade_id.to_string())
+            .with("order_side", self.order_side.to_string())
+            .with("last_qty", self.last_qty.to_string())
+            .with("last_px", self.last_px.to_string())
+            .with("commission", self.commission.to_string())
+            .with("liquidity_side", self.liquidity_side.to_string())
+            .with("report_id", self.report_id.to_string())
+            .with("ts_event", self.ts_event)
+            .with("ts_init", self.ts_init)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "FillReport")?;
+        Ok(Self {
+            account_id: dict.parse("account_id")?,
+            instrument_id: dict.parse("instrument_id")?,
+            client_order_id: dict.parse_opt("client_order_id")?,
+            venue_order_id: dict.parse("venue_order_id")?,
+            venue_position_id: dict.parse_opt("venue_position_id")?,
+            trade_id: dict.parse("trade_id")?,
+            order_side: dict.parse("order_side")?,
+            last_qty: dict.parse("last_qty")?,
+            last_px: dict.parse("last_px")?,
+            commission: parse_money(dict, "commission")?,
+            liquidity_side: dict.parse("liquidity_side")?,
+            report_id: dict.parse("report_id")?,
+            ts_event: dict.get_u64("ts_event")?,
+            ts_init: dict.get_u64("ts_init")?,
+        })
+    }
+}
+
+impl DictSerializable for PositionStatusReport {
+    fn to_dict(&self) -> Dict {
+        typed_dict("PositionStatusReport")
+            .with("account_id", self.account_id.to_string())
+            .with("instrument_id", self.instrument_id.to_string())
+            .with(
+                "venue_position_id",
+                opt_string(self.venue_position_id.as_ref()),
+            )
+            .with("position_side", self.position_side.to_string())
+            .with("quantity", self.quantity.to_string())
+            .with("report_id", self.report_id.to_string())
+            .with("ts_last", self.ts_last)
+            .with("ts_init", self.ts_init)
+    }
+
+    fn from_dict(dict: &Dict) -> Result<Self, SerializationError> {
+        check_type(dict, "PositionStatusReport")?;
+        Ok(Self {
+            account_id: dict.parse("account_id")?,
+            instrument_id: dict.parse("instrument_id")?,
+            position_side: dict.parse("position_side")?,
+            quantity: dict.parse("quantity")?,
+            venue_position_id: dict.parse_opt("venue_position_id")?,
+            report_id: dict.parse("report_id")?,
+            ts_last: dict.get_u64("ts_last")?,
+            ts_init: dict.get_u64("ts_init")?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        enums::{LiquiditySide, OrderSide, OrderStatus, OrderType, PositionSide, TimeInForce},
+        identifiers::{
+            account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+            trade_id::TradeId, venue_order_id::VenueOrderId,
+        },
+        types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::msgpack::MsgPackSerializer;
+
+    fn order_status_report() -> OrderStatusReport {
+        OrderStatusReport {
+            account_id: AccountId::new("SIM-001"),
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            client_order_id: Some(ClientOrderId::new("O-123456")),
+            order_list_id: None,
+            venue_order_id: VenueOrderId::new("1"),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::StopLimit,
+            contingency_type: None,
+            time_in_force: TimeInForce::Gtd,
+            expire_time: Some(10),
+            order_status: OrderStatus::PartiallyFilled,
+            price: Some(Price::from_str("1.00000").unwrap()),
+            trigger_price: Some(Price::from_str("1.00010").unwrap()),
+            trigger_type: Some(TriggerType::BidAsk),
+            limit_offset: None,
+            trailing_offset: None,
+            trailing_offset_type: None,
+            quantity: Quantity::from_str("100000").unwrap(),
+            filled_qty: Quantity::from_str("40000").unwrap(),
+            display_qty: None,
+            avg_px: Some(1.00001),
+            post_only: false,
+            reduce_only: true,
+            cancel_reason: None,
+            report_id: UUID4::new(),
+            ts_accepted: 1,
+            ts_triggered: Some(2),
+            ts_last: 3,
+            ts_init: 4,
+        }
+    }
+
+    #[rstest]
+    fn test_order_status_report_round_trip() {
+        let report = order_status_report();
+        let dict = report.to_dict();
+
+        let result = OrderStatusReport::from_dict(&dict).unwrap();
+
+        assert_eq!(dict.get_str("leaves_qty").unwrap(), "60000");
+        assert_eq!(dict.get_str("contingency_type").unwrap(), "NO_CONTINGENCY");
+        assert_eq!(dict.get_str("avg_px").unwrap(), "1.00001");
+        assert_eq!(result, report);
+    }
+
+    #[rstest]
+    fn test_order_status_report_msgpack_round_trip() {
+        let serializer = MsgPackSerializer::new(true);
+        let report = order_status_report();
+
+        let bytes = serializer.serialize(&report).unwrap();
+        let result: OrderStatusReport = serializer.deserialize(&bytes).unwrap();
+
+        assert_eq!(result, report);
+    }
+
+    #[rstest]
+    fn test_fill_report_msgpack_round_trip() {
+        let serializer = MsgPackSerializer::default();
+        let report = FillReport {
+            account_id: AccountId::new("SIM-001"),
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            client_order_id: None,
+            venue_order_id: VenueOrderId::new("1"),
+            venue_position_id: None,
+            trade_id: TradeId::new("E-1"),
+            order_side: OrderSide::Sell,
+            last_qty: Quantity::from_str("40000").unwrap(),
+            last_px: Price::from_str("1.00001").unwrap(),
+            commission: Money::new(2.0, Currency::from("USD")),
+            liquidity_side: LiquiditySide::Taker,
+            report_id: UUID4::new(),
+            ts_event: 1,
+            ts_init: 2,
+        };
+
+        let bytes = serializer.serialize(&report).unwrap();
+        let result: FillReport = serializer.deserialize(&bytes).unwrap();
+
+        assert_eq!(result, report);
+    }
+
+    #[rstest]
+    fn test_position_status_report_msgpack_round_trip() {
+        let serializer = MsgPackSerializer::default();
+        let report = PositionStatusReport {
+            account_id: AccountId::new("SIM-001"),
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            position_side: PositionSide::Short,
+            quantity: Quantity::from_str("40000").unwrap(),
+            venue_position_id: None,
+            report_id: UUID4::new(),
+            ts_last: 1,
+            ts_init: 2,
+        };
+
+        let bytes = serializer.serialize(&report).unwrap();
+        let result: PositionStatusReport = serializer.deserialize(&bytes).unwrap();
+
+        assert_eq!(result, report);
+    }
+}
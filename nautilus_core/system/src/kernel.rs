@@ -0,0 +1,473 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::sync::Arc;
+
+use nautilus_common::{
+    cache::{Cache, CacheConfig},
+    clock::{Clock, LiveClock},
+    component::{ComponentError, ComponentFsm},
+    enums::{ComponentState, ComponentTrigger},
+    msgbus::MessageBus,
+    runtime::{RuntimeError, ThreadConfig},
+};
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::identifiers::trader_id::TraderId;
+use thiserror::Error;
+use tokio::sync::Notify;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum KernelError {
+    #[error(transparent)]
+    Lifecycle(#[from] ComponentError),
+    #[error("Engine {0} is already registered")]
+    DuplicateEngine(String),
+    #[error("Engine {0} cannot be registered once the kernel has started")]
+    AlreadyStarted(String),
+    #[error("Engine {engine} failed to start: {reason}")]
+    EngineStartFailed { engine: String, reason: String },
+    #[error(transparent)]
+    Runtime(#[from] RuntimeError),
+}
+
+/// Configuration for a [`NautilusKernel`].
+#[derive(Clone, Debug)]
+pub struct KernelConfig {
+    /// The trader ID for the node.
+    pub trader_id: TraderId,
+    /// The instance ID for the node (generated if `None`).
+    pub instance_id: Option<UUID4>,
+    /// The configuration for the cache.
+    pub cache: CacheConfig,
+    /// The core affinity and priority applied to the thread the kernel is started on.
+    pub hot_thread: ThreadConfig,
+}
+
+impl KernelConfig {
+    #[must_use]
+    pub fn new(trader_id: TraderId) -> Self {
+        Self {
+            trader_id,
+            instance_id: None,
+            cache: CacheConfig::default(),
+            hot_thread: ThreadConfig::default(),
+        }
+    }
+}
+
+/// The shared state of the kernel passed to its engines and hooks.
+pub struct KernelContext<'a> {
+    pub clock: &'a mut LiveClock,
+    pub msgbus: &'a mut MessageBus,
+    pub cache: &'a mut Cache,
+}
+
+/// An engine which is owned and driven through its lifecycle by a [`NautilusKernel`].
+pub trait Engine {
+    /// Returns the unique ID of the engine.
+    fn id(&self) -> &str;
+    /// Called once when the engine is registered, for it to subscribe to the message bus.
+    fn register(&mut self, _ctx: &mut KernelContext<'_>) {}
+    fn start(&mut self, ctx: &mut KernelContext<'_>) -> Result<(), String>;
+    fn stop(&mut self, ctx: &mut KernelContext<'_>);
+    fn dispose(&mut self) {}
+}
+
+pub type KernelHook = Box<dyn FnMut(&mut KernelContext<'_>)>;
+
+/// Provides the core of a Nautilus system, owning the clock, message bus and cache along with
+/// the engines registered with it.
+///
+/// Engines are started in the order they were registered, and stopped and disposed of in the
+/// reverse order. Startup hooks run once all engines have started, and shutdown hooks run
+/// before any engine is stopped.
+pub struct NautilusKernel {
+    pub name: String,
+    pub trader_id: TraderId,
+    pub instance_id: UUID4,
+    pub ts_created: UnixNanos,
+    fsm: ComponentFsm,
+    clock: LiveClock,
+    msgbus: MessageBus,
+    cache: Cache,
+    hot_thread: ThreadConfig,
+    engines: Vec<Box<dyn Engine>>,
+    startup_hooks: Vec<KernelHook>,
+    shutdown_hooks: Vec<KernelHook>,
+    shutdown: Arc<Notify>,
+}
+
+impl NautilusKernel {
+    /// Initializes a new `NautilusKernel` instance, ready to be started.
+    #[must_use]
+    pub fn new(config: KernelConfig) -> Self {
+        let mut clock = LiveClock::new();
+        let ts_created = clock.timestamp_ns();
+        let mut fsm = ComponentFsm::new();
+        fsm.trigger(ComponentTrigger::Initialize)
+            .expect("A new component can always be initialized");
+
+        Self {
+            name: stringify!(NautilusKernel).to_string(),
+            msgbus: MessageBus::new(config.trader_id.clone(), None),
+            trader_id: config.trader_id,
+            instance_id: config.instance_id.unwrap_or_default(),
+            ts_created,
+            fsm,
+            clock,
+            cache: Cache::new(config.cache),
+            hot_thread: config.hot_thread,
+            engines: Vec::new(),
+            startup_hooks: Vec::new(),
+            shutdown_hooks: Vec::new(),
+            shutdown: Arc::new(Notify::new()),
+        }
+    }
+
+    #[must_use]
+    pub fn state(&self) -> ComponentState {
+        self.fsm.state()
+    }
+
+    pub fn clock(&mut self) -> &mut LiveClock {
+        &mut self.clock
+    }
+
+    pub fn msgbus(&mut self) -> &mut MessageBus {
+        &mut self.msgbus
+    }
+
+    pub fn cache(&mut self) -> &mut Cache {
+        &mut self.cache
+    }
+
+    /// Returns the IDs of the registered engines, in the order they are started.
+    #[must_use]
+    pub fn engine_ids(&self) -> Vec<&str> {
+        self.engines.iter().map(|engine| engine.id()).collect()
+    }
+
+    /// Registers the `engine` with the kernel, which must not yet have been started.
+    pub fn register_engine(&mut self, mut engine: Box<dyn Engine>) -> Result<(), KernelError> {
+        if self.fsm.state() != ComponentState::Ready {
+            return Err(KernelError::AlreadyStarted(engine.id().to_string()));
+        }
+        if self.engines.iter().any(|e| e.id() == engine.id()) {
+            return Err(KernelError::DuplicateEngine(engine.id().to_string()));
+        }
+
+        let mut ctx = KernelContext {
+            clock: &mut self.clock,
+            msgbus: &mut self.msgbus,
+            cache: &mut self.cache,
+        };
+        engine.register(&mut ctx);
+        self.engines.push(engine);
+        Ok(())
+    }
+
+    /// Adds a hook to run each time the kernel has started all of its engines.
+    pub fn add_startup_hook(&mut self, hook: KernelHook) {
+        self.startup_hooks.push(hook);
+    }
+
+    /// Adds a hook to run each time the kernel is stopped, before any engine is stopped.
+    pub fn add_shutdown_hook(&mut self, hook: KernelHook) {
+        self.shutdown_hooks.push(hook);
+    }
+
+    /// Returns a handle which can be notified to end [`NautilusKernel::run_until_signal`].
+    #[must_use]
+    pub fn shutdown_handle(&self) -> Arc<Notify> {
+        self.shutdown.clone()
+    }
+
+    /// Starts all engines, then runs the startup hooks.
+    ///
+    /// The hot thread configuration is first applied to the calling thread, which goes
+    /// on to process the events of the engines. If an engine fails to start then the
+    /// engines already started are stopped, and the kernel is faulted.
+    pub fn start(&mut self) -> Result<(), KernelError> {
+        self.fsm.trigger(ComponentTrigger::Start)?;
+        if let Err(e) = self.hot_thread.apply_to_current_thread() {
+            self.fsm.trigger(ComponentTrigger::Fault)?;
+            self.fsm.trigger(ComponentTrigger::FaultCompleted)?;
+            return Err(e.into());
+        }
+
+        let mut ctx = KernelContext {
+            clock: &mut self.clock,
+            msgbus: &mut self.msgbus,
+            cache: &mut self.cache,
+        };
+
+        let mut failure = None;
+        for (i, engine) in self.engines.iter_mut().enumerate() {
+            if let Err(reason) = engine.start(&mut ctx) {
+                let e = KernelError::EngineStartFailed {
+                    engine: engine.id().to_string(),
+                    reason,
+                };
+                failure = Some((i, e));
+                break;
+            }
+        }
+        if let Some((started, e)) = failure {
+            for engine in self.engines[..started].iter_mut().rev() {
+                engine.stop(&mut ctx);
+            }
+            self.fsm.trigger(ComponentTrigger::Fault)?;
+            self.fsm.trigger(ComponentTrigger::FaultCompleted)?;
+            return Err(e);
+        }
+
+        for hook in self.startup_hooks.iter_mut() {
+            hook(&mut ctx);
+        }
+
+        self.fsm.trigger(ComponentTrigger::StartCompleted)?;
+        Ok(())
+    }
+
+    /// Runs the shutdown hooks, then stops all engines in reverse order.
+    pub fn stop(&mut self) -> Result<(), KernelError> {
+        self.fsm.trigger(ComponentTrigger::Stop)?;
+
+        let mut ctx = KernelContext {
+            clock: &mut self.clock,
+            msgbus: &mut self.msgbus,
+            cache: &mut self.cache,
+        };
+        for hook in self.shutdown_hooks.iter_mut() {
+            hook(&mut ctx);
+        }
+        for engine in self.engines.iter_mut().rev() {
+            engine.stop(&mut ctx);
+        }
+
+        self.fsm.trigger(ComponentTrigger::StopCompleted)?;
+        Ok(())
+    }
+
+    /// Disposes of all engines in reverse order, stopping the kernel first if running.
+    pub fn dispose(&mut self) -> Result<(), KernelError> {
+        if self.fsm.is_running() {
+            self.stop()?;
+        }
+        self.fsm.trigger(ComponentTrigger::Dispose)?;
+
+        for engine in self.engines.iter_mut().rev() {
+            engine.dispose();
+        }
+        self.clock.cancel_timers();
+
+        self.fsm.trigger(ComponentTrigger::DisposeCompleted)?;
+        Ok(())
+    }
+
+    /// Starts the kernel and runs until either a SIGINT (Ctrl-C) is received or the
+    /// [`NautilusKernel::shutdown_handle`] is notified, then stops and disposes of it.
+    pub async fn run_until_signal(&mut self) -> Result<(), KernelError> {
+        self.start()?;
+
+        let shutdown = self.shutdown.clone();
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = shutdown.notified() => {}
+        }
+
+        self.stop()?;
+        self.dispose()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use rstest::rstest;
+
+    use super::*;
+
+    struct RecordingEngine {
+        id: String,
+        fail_start: bool,
+        calls: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl Engine for RecordingEngine {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn start(&mut self, _ctx: &mut KernelContext<'_>) -> Result<(), String> {
+            if self.fail_start {
+                return Err("boom".to_string());
+            }
+            self.calls.borrow_mut().push(format!("{}.start", self.id));
+            Ok(())
+        }
+
+        fn stop(&mut self, _ctx: &mut KernelContext<'_>) {
+            self.calls.borrow_mut().push(format!("{}.stop", self.id));
+        }
+
+        fn dispose(&mut self) {
+            self.calls.borrow_mut().push(format!("{}.dispose", self.id));
+        }
+    }
+
+    fn engine(id: &str, calls: &Rc<RefCell<Vec<String>>>) -> Box<RecordingEngine> {
+        Box::new(RecordingEngine {
+            id: id.to_string(),
+            fail_start: false,
+            calls: calls.clone(),
+        })
+    }
+
+    fn kernel() -> NautilusKernel {
+        NautilusKernel::new(KernelConfig::new(TraderId::new("TRADER-001")))
+    }
+
+    #[rstest]
+    fn test_new_kernel_is_ready() {
+        let kernel = kernel();
+        assert_eq!(kernel.state(), ComponentState::Ready);
+        assert_eq!(kernel.msgbus.trader_id, TraderId::new("TRADER-001"));
+    }
+
+    #[rstest]
+    fn test_lifecycle_ordering() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut kernel = kernel();
+        kernel
+            .register_engine(engine("DataEngine", &calls))
+            .unwrap();
+        kernel
+            .register_engine(engine("ExecEngine", &calls))
+            .unwrap();
+        let hook_calls = calls.clone();
+        kernel.add_startup_hook(Box::new(move |_| {
+            hook_calls.borrow_mut().push("started".into())
+        }));
+        let hook_calls = calls.clone();
+        kernel.add_shutdown_hook(Box::new(move |_| {
+            hook_calls.borrow_mut().push("stopping".into())
+        }));
+
+        kernel.start().unwrap();
+        assert_eq!(kernel.state(), ComponentState::Running);
+        kernel.dispose().unwrap();
+
+        assert_eq!(kernel.state(), ComponentState::Disposed);
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                "DataEngine.start",
+                "ExecEngine.start",
+                "started",
+                "stopping",
+                "ExecEngine.stop",
+                "DataEngine.stop",
+                "ExecEngine.dispose",
+                "DataEngine.dispose",
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_failed_engine_start_stops_started_engines_and_faults() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut kernel = kernel();
+        kernel
+            .register_engine(engine("DataEngine", &calls))
+            .unwrap();
+        let mut failing = engine("ExecEngine", &calls);
+        failing.fail_start = true;
+        kernel.register_engine(failing).unwrap();
+
+        let result = kernel.start();
+
+        assert_eq!(
+            result,
+            Err(KernelError::EngineStartFailed {
+                engine: "ExecEngine".to_string(),
+                reason: "boom".to_string(),
+            })
+        );
+        assert_eq!(kernel.state(), ComponentState::Faulted);
+        assert_eq!(*calls.borrow(), vec!["DataEngine.start", "DataEngine.stop"]);
+    }
+
+    #[rstest]
+    fn test_invalid_hot_thread_config_faults() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut config = KernelConfig::new(TraderId::new("TRADER-001"));
+        config.hot_thread.nice = Some(100);
+        let mut kernel = NautilusKernel::new(config);
+        kernel
+            .register_engine(engine("DataEngine", &calls))
+            .unwrap();
+
+        let result = kernel.start();
+
+        assert!(matches!(result, Err(KernelError::Runtime(_))));
+        assert_eq!(kernel.state(), ComponentState::Faulted);
+        assert!(calls.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_register_duplicate_engine() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut kernel = kernel();
+        kernel
+            .register_engine(engine("DataEngine", &calls))
+            .unwrap();
+
+        let result = kernel.register_engine(engine("DataEngine", &calls));
+
+        assert_eq!(
+            result,
+            Err(KernelError::DuplicateEngine("DataEngine".to_string()))
+        );
+    }
+
+    #[rstest]
+    fn test_register_engine_after_start() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut kernel = kernel();
+        kernel.start().unwrap();
+
+        let result = kernel.register_engine(engine("DataEngine", &calls));
+
+        assert_eq!(
+            result,
+            Err(KernelError::AlreadyStarted("DataEngine".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_until_shutdown_notified() {
+        let mut kernel = kernel();
+        kernel.shutdown_handle().notify_one();
+
+        kernel.run_until_signal().await.unwrap();
+
+        assert_eq!(kernel.state(), ComponentState::Disposed);
+    }
+}
@@ -0,0 +1,167 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::str::FromStr;
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarSpecification, BarType},
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::{AggregationSource, AggressorSide, BarAggregation, PriceType},
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Returns a quote tick for the instrument, with sizes of 100,000 on both sides.
+#[must_use]
+pub fn quote_tick(instrument_id: &InstrumentId, bid: &str, ask: &str, ts: UnixNanos) -> QuoteTick {
+    QuoteTick {
+        instrument_id: instrument_id.clone(),
+        bid: Price::from(bid),
+        ask: Price::from(ask),
+        bid_size: Quantity::from("100000"),
+        ask_size: Quantity::from("100000"),
+        ts_event: ts,
+        ts_init: ts,
+    }
+}
+
+/// Returns a quote tick for AUD/USD.SIM of 1.00000/1.00001.
+#[must_use]
+pub fn quote_tick_audusd() -> QuoteTick {
+    let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+    quote_tick(&instrument_id, "1.00000", "1.00001", 0)
+}
+
+#[must_use]
+pub fn trade_tick(
+    instrument_id: &InstrumentId,
+    price: &str,
+    size: &str,
+    aggressor_side: AggressorSide,
+    trade_id: &str,
+    ts: UnixNanos,
+) -> TradeTick {
+    TradeTick {
+        instrument_id: instrument_id.clone(),
+        price: Price::from(price),
+        size: Quantity::from(size),
+        aggressor_side,
+        trade_id: TradeId::new(trade_id),
+        ts_event: ts,
+        ts_init: ts,
+    }
+}
+
+/// Returns a trade tick for AUD/USD.SIM of 100,000 at 1.00000 with a buyer aggressor.
+#[must_use]
+pub fn trade_tick_audusd() -> TradeTick {
+    let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+    trade_tick(
+        &instrument_id,
+        "1.00000",
+        "100000",
+        AggressorSide::Buyer,
+        "123456",
+        0,
+    )
+}
+
+/// Returns the externally aggregated bar type for the instrument of `step` minutes of the
+/// `price_type`.
+#[must_use]
+pub fn bar_type(instrument_id: &InstrumentId, step: u64, price_type: PriceType) -> BarType {
+    BarType::new(
+        instrument_id.clone(),
+        BarSpecification::new(step, BarAggregation::Minute, price_type),
+        AggregationSource::External,
+    )
+}
+
+/// Returns the `AUD/USD.SIM-1-MINUTE-BID-EXTERNAL` bar type.
+#[must_use]
+pub fn bar_type_audusd_1min_bid() -> BarType {
+    let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+    bar_type(&instrument_id, 1, PriceType::Bid)
+}
+
+#[allow(clippy::too_many_arguments)]
+#[must_use]
+pub fn bar(
+    bar_type: &BarType,
+    open: &str,
+    high: &str,
+    low: &str,
+    close: &str,
+    volume: &str,
+    ts: UnixNanos,
+) -> Bar {
+    Bar {
+        bar_type: bar_type.clone(),
+        open: Price::from(open),
+        high: Price::from(high),
+        low: Price::from(low),
+        close: Price::from(close),
+        volume: Quantity::from(volume),
+        ts_event: ts,
+        ts_init: ts,
+    }
+}
+
+/// Returns a 1 minute AUD/USD.SIM bid bar of 1.00002/1.00004/1.00001/1.00003 with a volume
+/// of 100,000.
+#[must_use]
+pub fn bar_audusd_5decimal() -> Bar {
+    bar(
+        &bar_type_audusd_1min_bid(),
+        "1.00002",
+        "1.00004",
+        "1.00001",
+        "1.00003",
+        "100000",
+        0,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_bar_type_audusd_1min_bid() {
+        let bar_type = bar_type_audusd_1min_bid();
+
+        assert_eq!(
+            bar_type,
+            BarType::from_str("AUD/USD.SIM-1-MINUTE-BID-EXTERNAL").unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_bar_audusd_5decimal() {
+        let bar = bar_audusd_5decimal();
+
+        assert!(bar.low <= bar.open && bar.open <= bar.high);
+        assert!(bar.low <= bar.close && bar.close <= bar.high);
+        assert_eq!(bar.open.precision, 5);
+    }
+}
@@ -0,0 +1,182 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::str::FromStr;
+
+use nautilus_model::{
+    currencies::{BTC, ETH, USD, USDT},
+    enums::{AssetClass, AssetType},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    instruments::BaseInstrument,
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+use rust_decimal::Decimal;
+
+/// Returns the AUD/USD spot FX instrument at the simulated `SIM` venue.
+#[must_use]
+pub fn audusd_sim() -> BaseInstrument {
+    default_fx_ccy("AUD/USD", "SIM")
+}
+
+/// Returns a spot FX instrument for the `symbol` (e.g. "GBP/USD") at the `venue`.
+///
+/// JPY quoted pairs have a price precision of 3, and all others of 5, with a lot size of
+/// 1,000 units, margins of 3% and maker and taker fees of 0.002%.
+///
+/// # Panics
+///
+/// - If `symbol` is not made up of two known currency codes separated by a slash.
+#[must_use]
+pub fn default_fx_ccy(symbol: &str, venue: &str) -> BaseInstrument {
+    let (base, quote) = symbol
+        .split_once('/')
+        .expect("FX symbol should be of the form 'BASE/QUOTE'");
+    let quote_currency = Currency::from_str(quote).unwrap();
+    let price_precision = if quote == "JPY" { 3 } else { 5 };
+
+    BaseInstrument {
+        id: InstrumentId::from_str(&format!("{symbol}.{venue}")).unwrap(),
+        native_symbol: Symbol::new(symbol),
+        asset_class: AssetClass::FX,
+        asset_type: AssetType::Spot,
+        quote_currency: quote_currency.clone(),
+        base_currency: Some(Currency::from_str(base).unwrap()),
+        cost_currency: quote_currency,
+        is_inverse: false,
+        price_precision,
+        size_precision: 0,
+        price_increment: Price::new(10_f64.powi(-i32::from(price_precision)), price_precision),
+        size_increment: Quantity::from("1"),
+        multiplier: Quantity::from("1"),
+        lot_size: Some(Quantity::from("1000")),
+        max_quantity: None,
+        min_quantity: None,
+        max_price: None,
+        min_price: None,
+        margin_init: Decimal::new(3, 2),
+        margin_maint: Decimal::new(3, 2),
+        maker_fee: Decimal::new(2, 5),
+        taker_fee: Decimal::new(2, 5),
+    }
+}
+
+/// Returns the BTCUSDT spot crypto instrument at the `BINANCE` venue.
+#[must_use]
+pub fn btcusdt_binance() -> BaseInstrument {
+    BaseInstrument {
+        id: InstrumentId::from_str("BTCUSDT.BINANCE").unwrap(),
+        native_symbol: Symbol::new("BTCUSDT"),
+        asset_class: AssetClass::Cryptocurrency,
+        asset_type: AssetType::Spot,
+        quote_currency: USDT.clone(),
+        base_currency: Some(BTC.clone()),
+        cost_currency: USDT.clone(),
+        is_inverse: false,
+        price_precision: 2,
+        size_precision: 6,
+        price_increment: Price::from("0.01"),
+        size_increment: Quantity::from("0.000001"),
+        multiplier: Quantity::from("1"),
+        lot_size: None,
+        max_quantity: Some(Quantity::from("9000.000000")),
+        min_quantity: Some(Quantity::from("0.000001")),
+        max_price: Some(Price::from("1000000.00")),
+        min_price: Some(Price::from("0.01")),
+        margin_init: Decimal::ZERO,
+        margin_maint: Decimal::ZERO,
+        maker_fee: Decimal::new(1, 3),
+        taker_fee: Decimal::new(1, 3),
+    }
+}
+
+/// Returns the ETHUSDT spot crypto instrument at the `BINANCE` venue.
+#[must_use]
+pub fn ethusdt_binance() -> BaseInstrument {
+    BaseInstrument {
+        id: InstrumentId::from_str("ETHUSDT.BINANCE").unwrap(),
+        native_symbol: Symbol::new("ETHUSDT"),
+        base_currency: Some(ETH.clone()),
+        size_precision: 5,
+        size_increment: Quantity::from("0.00001"),
+        max_quantity: Some(Quantity::from("9000.00000")),
+        min_quantity: Some(Quantity::from("0.00001")),
+        ..btcusdt_binance()
+    }
+}
+
+/// Returns the ETH/USD inverse perpetual instrument at the `BITMEX` venue, which is
+/// margined and settled in ETH.
+#[must_use]
+pub fn ethusd_bitmex() -> BaseInstrument {
+    BaseInstrument {
+        id: InstrumentId::from_str("ETHUSD.BITMEX").unwrap(),
+        native_symbol: Symbol::new("ETHUSD"),
+        asset_class: AssetClass::Cryptocurrency,
+        asset_type: AssetType::Swap,
+        quote_currency: USD.clone(),
+        base_currency: Some(ETH.clone()),
+        cost_currency: ETH.clone(),
+        is_inverse: true,
+        price_precision: 2,
+        size_precision: 0,
+        price_increment: Price::from("0.05"),
+        size_increment: Quantity::from("1"),
+        multiplier: Quantity::from("1"),
+        lot_size: Some(Quantity::from("1")),
+        max_quantity: Some(Quantity::from("10000000")),
+        min_quantity: Some(Quantity::from("1")),
+        max_price: Some(Price::from("1000000.00")),
+        min_price: Some(Price::from("0.05")),
+        margin_init: Decimal::new(1, 2),
+        margin_maint: Decimal::new(35, 4),
+        maker_fee: Decimal::new(-25, 5),
+        taker_fee: Decimal::new(75, 5),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::instruments::Instrument;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("AUD/USD", 5, "0.00001")]
+    #[case("USD/JPY", 3, "0.001")]
+    fn test_default_fx_ccy_precision(
+        #[case] symbol: &str,
+        #[case] precision: u8,
+        #[case] increment: &str,
+    ) {
+        let instrument = default_fx_ccy(symbol, "SIM");
+
+        assert_eq!(instrument.price_precision(), precision);
+        assert_eq!(instrument.price_increment, Price::from(increment));
+        assert_eq!(instrument.id.to_string(), format!("{symbol}.SIM"));
+    }
+
+    #[rstest]
+    fn test_ethusdt_binance() {
+        let instrument = ethusdt_binance();
+
+        assert_eq!(instrument.base_currency, Some(ETH.clone()));
+        assert_eq!(instrument.quote_currency, USDT.clone());
+        assert_eq!(instrument.size_precision, 5);
+    }
+}
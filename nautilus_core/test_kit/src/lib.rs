@@ -0,0 +1,28 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Builders of canonical test data for unit tests of the Rust crates, ported from the
+//! Python `TestInstrumentProvider`, `TestDataStubs`, `TestExecStubs` and `TestEventStubs`.
+//!
+//! The builders return the same values on every call, so tests across crates share one set
+//! of fixtures rather than each hand rolling its own. Prices and quantities are given as
+//! strings at the precision of the instrument.
+//!
+//! The model crate can't depend on this crate for its own unit tests, as its types would then
+//! be compiled twice.
+
+pub mod data;
+pub mod instruments;
+pub mod orders;
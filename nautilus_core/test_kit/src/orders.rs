@@ -0,0 +1,270 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide, OrderType},
+    events::order::{
+        OrderAccepted, OrderEvent, OrderFilled, OrderInitializedBuilder, OrderSubmitted,
+    },
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
+        venue_order_id::VenueOrderId,
+    },
+    instruments::BaseInstrument,
+    orders::Order,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+use rust_decimal::prelude::ToPrimitive;
+
+/// Returns the `TRADER-001` trader ID.
+#[must_use]
+pub fn trader_id() -> TraderId {
+    TraderId::new("TRADER-001")
+}
+
+/// Returns the `S-001` strategy ID.
+#[must_use]
+pub fn strategy_id() -> StrategyId {
+    StrategyId::new("S-001")
+}
+
+/// Returns the `SIM-001` account ID.
+#[must_use]
+pub fn account_id() -> AccountId {
+    AccountId::new("SIM-001")
+}
+
+/// Returns an initialized market order for the instrument.
+#[must_use]
+pub fn market_order(
+    instrument_id: &InstrumentId,
+    client_order_id: &str,
+    side: OrderSide,
+    quantity: &str,
+) -> Order {
+    let init = OrderInitializedBuilder::default()
+        .trader_id(trader_id())
+        .strategy_id(strategy_id())
+        .instrument_id(instrument_id.clone())
+        .client_order_id(ClientOrderId::new(client_order_id))
+        .order_side(side)
+        .order_type(OrderType::Market)
+        .quantity(Quantity::from(quantity))
+        .build()
+        .unwrap();
+    Order::from(init)
+}
+
+/// Returns an initialized limit order for the instrument.
+#[must_use]
+pub fn limit_order(
+    instrument_id: &InstrumentId,
+    client_order_id: &str,
+    side: OrderSide,
+    quantity: &str,
+    price: &str,
+) -> Order {
+    let init = OrderInitializedBuilder::default()
+        .trader_id(trader_id())
+        .strategy_id(strategy_id())
+        .instrument_id(instrument_id.clone())
+        .client_order_id(ClientOrderId::new(client_order_id))
+        .order_side(side)
+        .order_type(OrderType::Limit)
+        .quantity(Quantity::from(quantity))
+        .price(Some(Price::from(price)))
+        .build()
+        .unwrap();
+    Order::from(init)
+}
+
+#[must_use]
+pub fn order_submitted(order: &Order, ts: UnixNanos) -> OrderSubmitted {
+    OrderSubmitted {
+        trader_id: order.trader_id.clone(),
+        strategy_id: order.strategy_id.clone(),
+        instrument_id: order.instrument_id.clone(),
+        client_order_id: order.client_order_id.clone(),
+        account_id: account_id(),
+        event_id: UUID4::new(),
+        ts_event: ts,
+        ts_init: ts,
+    }
+}
+
+/// Returns the acceptance of the order, with a venue order ID of `V-` followed by the
+/// client order ID.
+#[must_use]
+pub fn order_accepted(order: &Order, ts: UnixNanos) -> OrderAccepted {
+    OrderAccepted {
+        trader_id: order.trader_id.clone(),
+        strategy_id: order.strategy_id.clone(),
+        instrument_id: order.instrument_id.clone(),
+        client_order_id: order.client_order_id.clone(),
+        venue_order_id: venue_order_id(order),
+        account_id: account_id(),
+        event_id: UUID4::new(),
+        ts_event: ts,
+        ts_init: ts,
+        reconciliation: false,
+    }
+}
+
+/// Returns a taker fill of `last_qty` at `last_px` for the order, with a commission of the
+/// instrument taker fee in its quote currency.
+///
+/// The whole of the quantity left is filled if `last_qty` is `None`.
+#[must_use]
+pub fn order_filled(
+    order: &Order,
+    instrument: &BaseInstrument,
+    trade_id: &str,
+    last_qty: Option<&str>,
+    last_px: &str,
+    ts: UnixNanos,
+) -> OrderFilled {
+    let last_qty = last_qty.map_or(order.leaves_qty, Quantity::from);
+    let last_px = Price::from(last_px);
+    let notional = last_qty.as_f64() * last_px.as_f64() * instrument.multiplier.as_f64();
+    let commission = notional * instrument.taker_fee.to_f64().unwrap();
+
+    OrderFilled {
+        trader_id: order.trader_id.clone(),
+        strategy_id: order.strategy_id.clone(),
+        instrument_id: order.instrument_id.clone(),
+        client_order_id: order.client_order_id.clone(),
+        venue_order_id: order
+            .venue_order_id
+            .clone()
+            .unwrap_or_else(|| venue_order_id(order)),
+        account_id: account_id(),
+        trade_id: TradeId::new(trade_id),
+        position_id: None,
+        order_side: order.side,
+        order_type: order.order_type,
+        last_qty,
+        last_px,
+        currency: instrument.quote_currency.clone(),
+        commission: Money::new(commission, instrument.quote_currency.clone()),
+        liquidity_side: LiquiditySide::Taker,
+        event_id: UUID4::new(),
+        ts_event: ts,
+        ts_init: ts,
+        reconciliation: false,
+    }
+}
+
+/// Returns the order after it has been submitted.
+///
+/// # Panics
+///
+/// - If the order is not initialized.
+#[must_use]
+pub fn make_submitted_order(mut order: Order, ts: UnixNanos) -> Order {
+    let submitted = order_submitted(&order, ts);
+    order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+    order
+}
+
+/// Returns the order after it has been submitted and accepted.
+///
+/// # Panics
+///
+/// - If the order is not initialized.
+#[must_use]
+pub fn make_accepted_order(order: Order, ts: UnixNanos) -> Order {
+    let mut order = make_submitted_order(order, ts);
+    let accepted = order_accepted(&order, ts);
+    order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+    order
+}
+
+/// Returns the order after it has been submitted, accepted and filled in full at `last_px`.
+///
+/// # Panics
+///
+/// - If the order is not initialized.
+#[must_use]
+pub fn make_filled_order(
+    order: Order,
+    instrument: &BaseInstrument,
+    last_px: &str,
+    ts: UnixNanos,
+) -> Order {
+    let mut order = make_accepted_order(order, ts);
+    let trade_id = format!("E-{}", order.client_order_id);
+    let filled = order_filled(&order, instrument, &trade_id, None, last_px, ts);
+    order.apply(OrderEvent::OrderFilled(filled)).unwrap();
+    order
+}
+
+fn venue_order_id(order: &Order) -> VenueOrderId {
+    VenueOrderId::new(&format!("V-{}", order.client_order_id))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::enums::OrderStatus;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::instruments::audusd_sim;
+
+    #[rstest]
+    fn test_make_accepted_order() {
+        let instrument = audusd_sim();
+        let order = limit_order(&instrument.id, "O-1", OrderSide::Buy, "100000", "1.00000");
+
+        let order = make_accepted_order(order, 0);
+
+        assert_eq!(order.status, OrderStatus::Accepted);
+        assert_eq!(order.account_id, Some(account_id()));
+        assert_eq!(order.venue_order_id, Some(VenueOrderId::new("V-O-1")));
+    }
+
+    #[rstest]
+    fn test_make_filled_order() {
+        let instrument = audusd_sim();
+        let order = market_order(&instrument.id, "O-1", OrderSide::Sell, "100000");
+
+        let order = make_filled_order(order, &instrument, "1.00000", 1);
+
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(order.filled_qty, Quantity::from("100000"));
+        assert_eq!(order.event_count(), 4);
+    }
+
+    #[rstest]
+    fn test_order_filled_commission() {
+        let instrument = audusd_sim();
+        let order = make_accepted_order(
+            market_order(&instrument.id, "O-1", OrderSide::Buy, "100000"),
+            0,
+        );
+
+        let filled = order_filled(&order, &instrument, "E-1", Some("50000"), "1.00000", 1);
+
+        assert_eq!(filled.last_qty, Quantity::from("50000"));
+        assert_eq!(
+            filled.commission,
+            Money::new(1.0, instrument.quote_currency)
+        );
+    }
+}
@@ -0,0 +1,178 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    commands::{CancelOrder, SubmitOrder, TradingCommand},
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, strategy_id::StrategyId,
+        trader_id::TraderId,
+    },
+    orders::{factory::OrderFactory, Order},
+};
+
+/// Provides a strategy with the current time, its net positions and an order factory, and
+/// buffers the commands it sends.
+///
+/// The runtime driving the strategy sets the time and positions before each callback, then
+/// drains the commands to route them for execution.
+#[derive(Debug)]
+pub struct TradingContext {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    ts_now: UnixNanos,
+    order_factory: OrderFactory,
+    net_positions: HashMap<InstrumentId, f64>,
+    commands: Vec<TradingCommand>,
+}
+
+impl TradingContext {
+    /// Initializes a new `TradingContext` instance.
+    #[must_use]
+    pub fn new(trader_id: TraderId, strategy_id: StrategyId) -> Self {
+        Self {
+            order_factory: OrderFactory::new(trader_id.clone(), strategy_id.clone(), 0, 0),
+            trader_id,
+            strategy_id,
+            ts_now: 0,
+            net_positions: HashMap::new(),
+            commands: Vec::new(),
+        }
+    }
+
+    /// Returns the current time (UNIX nanoseconds).
+    #[must_use]
+    pub fn timestamp_ns(&self) -> UnixNanos {
+        self.ts_now
+    }
+
+    pub fn set_time(&mut self, ts_now: UnixNanos) {
+        self.ts_now = ts_now;
+    }
+
+    pub fn order_factory(&mut self) -> &mut OrderFactory {
+        &mut self.order_factory
+    }
+
+    /// Returns the signed quantity of the strategies position for `instrument_id` (zero if
+    /// flat).
+    #[must_use]
+    pub fn net_position(&self, instrument_id: &InstrumentId) -> f64 {
+        self.net_positions
+            .get(instrument_id)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    pub fn set_net_position(&mut self, instrument_id: InstrumentId, signed_qty: f64) {
+        self.net_positions.insert(instrument_id, signed_qty);
+    }
+
+    /// Sends a command to submit the given `order`.
+    ///
+    /// # Panics
+    ///
+    /// - If `order` is not in an `INITIALIZED` state.
+    pub fn submit_order(&mut self, order: &Order) {
+        let command = SubmitOrder {
+            trader_id: self.trader_id.clone(),
+            client_id: None,
+            strategy_id: self.strategy_id.clone(),
+            order: order.init_event(),
+            position_id: None,
+            command_id: UUID4::new(),
+            ts_init: self.ts_now,
+        };
+        self.commands.push(TradingCommand::SubmitOrder(command));
+    }
+
+    /// Sends a command to cancel the order with the given `client_order_id`.
+    pub fn cancel_order(&mut self, instrument_id: InstrumentId, client_order_id: ClientOrderId) {
+        let command = CancelOrder {
+            trader_id: self.trader_id.clone(),
+            client_id: None,
+            strategy_id: self.strategy_id.clone(),
+            instrument_id,
+            client_order_id,
+            venue_order_id: None,
+            command_id: UUID4::new(),
+            ts_init: self.ts_now,
+        };
+        self.commands.push(TradingCommand::CancelOrder(command));
+    }
+
+    /// Drains the commands sent since the last drain, in the order they were sent.
+    pub fn drain_commands(&mut self) -> Vec<TradingCommand> {
+        self.commands.drain(..).collect()
+    }
+
+    /// Resets the context to its initial state.
+    pub fn reset(&mut self) {
+        self.ts_now = 0;
+        self.order_factory.reset();
+        self.net_positions.clear();
+        self.commands.clear();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{
+        enums::{OrderSide, TimeInForce},
+        types::quantity::Quantity,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_commands_drained_in_order() {
+        let mut ctx = TradingContext::new(TraderId::new("TRADER-001"), StrategyId::new("S-001"));
+        ctx.set_time(5);
+        let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        let order = ctx.order_factory().market(
+            instrument_id.clone(),
+            OrderSide::Buy,
+            Quantity::from("100000"),
+            TimeInForce::Gtc,
+            false,
+            false,
+            None,
+            5,
+        );
+        let client_order_id = order.client_order_id.clone();
+
+        ctx.submit_order(&order);
+        ctx.cancel_order(instrument_id, client_order_id.clone());
+        let commands = ctx.drain_commands();
+
+        assert_eq!(commands.len(), 2);
+        assert!(matches!(
+            &commands[0],
+            TradingCommand::SubmitOrder(submit) if submit.ts_init == 5
+        ));
+        assert!(matches!(
+            &commands[1],
+            TradingCommand::CancelOrder(cancel) if cancel.client_order_id == client_order_id
+        ));
+        assert!(ctx.drain_commands().is_empty());
+    }
+}
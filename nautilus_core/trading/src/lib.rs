@@ -0,0 +1,27 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Strategies written in pure Rust.
+//!
+//! A [`Strategy`] receives data and order events along with a [`TradingContext`], through
+//! which it creates orders with the [`OrderFactory`] and sends commands. The runtime driving
+//! the strategy (a backtest or live engine) drains and routes those commands.
+//!
+//! [`Strategy`]: strategy::Strategy
+//! [`TradingContext`]: context::TradingContext
+//! [`OrderFactory`]: nautilus_model::orders::factory::OrderFactory
+
+pub mod context;
+pub mod strategy;
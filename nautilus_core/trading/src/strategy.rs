@@ -0,0 +1,37 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    events::order::OrderEvent,
+};
+
+use crate::context::TradingContext;
+
+/// The callbacks of a strategy written in pure Rust.
+///
+/// Orders are created with the contexts order factory and sent through the context, so the
+/// same strategy runs unchanged in backtest and live contexts.
+pub trait Strategy {
+    fn on_start(&mut self, _ctx: &mut TradingContext) {}
+    fn on_quote_tick(&mut self, _ctx: &mut TradingContext, _tick: &QuoteTick) {}
+    fn on_trade_tick(&mut self, _ctx: &mut TradingContext, _tick: &TradeTick) {}
+    fn on_bar(&mut self, _ctx: &mut TradingContext, _bar: &Bar) {}
+    fn on_event(&mut self, _ctx: &mut TradingContext, _event: &OrderEvent) {}
+    fn on_stop(&mut self, _ctx: &mut TradingContext) {}
+}
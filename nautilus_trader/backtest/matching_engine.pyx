@@ -28,13 +28,14 @@ from nautilus_trader.common.logging cimport LogColor
 from nautilus_trader.common.logging cimport Logger
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.rust.model cimport Price_t
 from nautilus_trader.core.rust.model cimport orderbook_best_ask_price
 from nautilus_trader.core.rust.model cimport orderbook_best_bid_price
 from nautilus_trader.core.rust.model cimport orderbook_has_ask
 from nautilus_trader.core.rust.model cimport orderbook_has_bid
 from nautilus_trader.core.rust.model cimport price_new
-from nautilus_trader.core.rust.model cimport trade_id_new
+from nautilus_trader.core.rust.model cimport trade_id_try_new
 from nautilus_trader.core.string cimport pystr_to_cstr
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.execution.matching_core cimport MatchingCore
@@ -544,7 +545,7 @@ cdef class OrderMatchingEngine:
             tick._mem.price = bar._mem.high  # Direct memory assignment
             tick._mem.aggressor_side = AggressorSide.BUYER  # Direct memory assignment
             trade_id_str = self._generate_trade_id_str()
-            tick._mem.trade_id = trade_id_new(pystr_to_cstr(trade_id_str))
+            check_ffi(trade_id_try_new(pystr_to_cstr(trade_id_str), &tick._mem.trade_id))
             self._book.update_trade_tick(tick)
             self.iterate(tick.ts_init)
             self._core.set_last_raw(bar._mem.high.raw)
@@ -554,7 +555,7 @@ cdef class OrderMatchingEngine:
             tick._mem.price = bar._mem.low  # Direct memory assignment
             tick._mem.aggressor_side = AggressorSide.SELLER
             trade_id_str = self._generate_trade_id_str()
-            tick._mem.trade_id = trade_id_new(pystr_to_cstr(trade_id_str))
+            check_ffi(trade_id_try_new(pystr_to_cstr(trade_id_str), &tick._mem.trade_id))
             self._book.update_trade_tick(tick)
             self.iterate(tick.ts_init)
             self._core.set_last_raw(bar._mem.low.raw)
@@ -564,7 +565,7 @@ cdef class OrderMatchingEngine:
             tick._mem.price = bar._mem.close  # Direct memory assignment
             tick._mem.aggressor_side = AggressorSide.BUYER if bar._mem.close.raw > self._core.last_raw else AggressorSide.SELLER
             trade_id_str = self._generate_trade_id_str()
-            tick._mem.trade_id = trade_id_new(pystr_to_cstr(trade_id_str))
+            check_ffi(trade_id_try_new(pystr_to_cstr(trade_id_str), &tick._mem.trade_id))
             self._book.update_trade_tick(tick)
             self.iterate(tick.ts_init)
             self._core.set_last_raw(bar._mem.close.raw)
@@ -1126,10 +1127,10 @@ cdef class OrderMatchingEngine:
         cdef Price_t ask
 
         if orderbook_has_bid(&self._book._mem):
-            bid = orderbook_best_bid_price(&self._book._mem)
+            check_ffi(orderbook_best_bid_price(&self._book._mem, &bid))
             self._core.set_bid_raw(bid.raw)
         if orderbook_has_ask(&self._book._mem):
-            ask = orderbook_best_ask_price(&self._book._mem)
+            check_ffi(orderbook_best_ask_price(&self._book._mem, &ask))
             self._core.set_ask_raw(ask.raw)
 
         self._core.iterate(timestamp_ns)
@@ -33,6 +33,7 @@ from nautilus_trader.common.timer cimport LoopTimer
 from nautilus_trader.common.timer cimport ThreadTimer
 from nautilus_trader.common.timer cimport TimeEventHandler
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.datetime cimport dt_to_unix_nanos
 from nautilus_trader.core.datetime cimport maybe_dt_to_unix_nanos
 from nautilus_trader.core.rust.common cimport TimeEventHandler_t
@@ -468,7 +469,7 @@ cdef class TestClock(Clock):
     cpdef void register_default_handler(self, callback: Callable[[TimeEvent], None]):
         Condition.callable(callback, "callback")
 
-        test_clock_register_default_handler(&self._mem, <PyObject *>callback)
+        check_ffi(test_clock_register_default_handler(&self._mem, <PyObject *>callback))
 
     cpdef void set_time_alert_ns(
         self,
@@ -479,12 +480,12 @@ cdef class TestClock(Clock):
         Condition.valid_string(name, "name")
         Condition.not_in(name, self.timer_names, "name", "self.timer_names")
 
-        test_clock_set_time_alert_ns(
+        check_ffi(test_clock_set_time_alert_ns(
             &self._mem,
             pystr_to_cstr(name),
             alert_time_ns,
             <PyObject *>callback,
-        )
+        ))
 
     cpdef void set_timer_ns(
         self,
@@ -505,24 +506,26 @@ cdef class TestClock(Clock):
             Condition.true(stop_time_ns > ts_now, "`stop_time_ns` was < `ts_now`")
             Condition.true(start_time_ns + interval_ns <= stop_time_ns, "`start_time_ns` + `interval_ns` was > `stop_time_ns`")
 
-        test_clock_set_timer_ns(
+        check_ffi(test_clock_set_timer_ns(
             &self._mem,
             pystr_to_cstr(name),
             interval_ns,
             start_time_ns,
             stop_time_ns,
             <PyObject *>callback,
-        )
+        ))
 
     cpdef uint64_t next_time_ns(self, str name):
         Condition.valid_string(name, "name")
-        return test_clock_next_time_ns(&self._mem, pystr_to_cstr(name))
+        cdef uint64_t next_time_ns
+        check_ffi(test_clock_next_time_ns(&self._mem, pystr_to_cstr(name), &next_time_ns))
+        return next_time_ns
 
     cpdef void cancel_timer(self, str name):
         Condition.valid_string(name, "name")
         Condition.is_in(name, self.timer_names, "name", "self.timer_names")
 
-        test_clock_cancel_timer(&self._mem, pystr_to_cstr(name))
+        check_ffi(test_clock_cancel_timer(&self._mem, pystr_to_cstr(name)))
 
     cpdef void cancel_timers(self):
         test_clock_cancel_timers(&self._mem)
@@ -13,24 +13,27 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.rust.common cimport ComponentState
 from nautilus_trader.core.rust.common cimport ComponentTrigger
 from nautilus_trader.core.rust.common cimport LogColor
 from nautilus_trader.core.rust.common cimport LogLevel
-from nautilus_trader.core.rust.common cimport component_state_from_cstr
 from nautilus_trader.core.rust.common cimport component_state_to_cstr
-from nautilus_trader.core.rust.common cimport component_trigger_from_cstr
+from nautilus_trader.core.rust.common cimport component_state_try_from_cstr
 from nautilus_trader.core.rust.common cimport component_trigger_to_cstr
-from nautilus_trader.core.rust.common cimport log_color_from_cstr
+from nautilus_trader.core.rust.common cimport component_trigger_try_from_cstr
 from nautilus_trader.core.rust.common cimport log_color_to_cstr
-from nautilus_trader.core.rust.common cimport log_level_from_cstr
+from nautilus_trader.core.rust.common cimport log_color_try_from_cstr
 from nautilus_trader.core.rust.common cimport log_level_to_cstr
+from nautilus_trader.core.rust.common cimport log_level_try_from_cstr
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
 
 
 cpdef ComponentState component_state_from_str(str value):
-    return component_state_from_cstr(pystr_to_cstr(value))
+    cdef ComponentState out
+    check_ffi(component_state_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str component_state_to_str(ComponentState value):
@@ -38,7 +41,9 @@ cpdef str component_state_to_str(ComponentState value):
 
 
 cpdef ComponentTrigger component_trigger_from_str(str value):
-    return component_trigger_from_cstr(pystr_to_cstr(value))
+    cdef ComponentTrigger out
+    check_ffi(component_trigger_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str component_trigger_to_str(ComponentTrigger value):
@@ -46,7 +51,9 @@ cpdef str component_trigger_to_str(ComponentTrigger value):
 
 
 cpdef LogColor log_color_from_str(str value):
-    return log_color_from_cstr(pystr_to_cstr(value))
+    cdef LogColor out
+    check_ffi(log_color_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str log_color_to_str(LogColor value):
@@ -54,7 +61,9 @@ cpdef str log_color_to_str(LogColor value):
 
 
 cpdef LogLevel log_level_from_str(str value):
-    return log_level_from_cstr(pystr_to_cstr(value))
+    cdef LogLevel out
+    check_ffi(log_level_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str log_level_to_str(LogLevel value):
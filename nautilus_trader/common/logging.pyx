@@ -30,11 +30,13 @@ import pytz
 
 from nautilus_trader import __version__
 
+from libc.stdint cimport uint32_t
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.common.clock cimport Clock
 from nautilus_trader.common.logging cimport Logger
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.rust.common cimport LogColor
 from nautilus_trader.core.rust.common cimport LogLevel
 from nautilus_trader.core.rust.common cimport logger_drop
@@ -93,6 +95,14 @@ cdef class Logger:
     component_levels : dict[ComponentId, LogLevel]
         The additional per component log level filters, where keys are component
         IDs (e.g. actor/strategy IDs) and values are log levels.
+    colors : bool, default True
+        If ANSI color codes are included in the console output.
+    rate_limit : int, default 0
+        The maximum number of log messages written per second (errors are never rate limited).
+        If zero then no rate limit is applied.
+    max_file_size : int, default 0
+        The maximum size (bytes) of a log file before rotating to a new file.
+        If zero then log files are only rotated on a change of UTC date.
     bypass : bool
         If the log output is bypassed.
     """
@@ -110,6 +120,9 @@ cdef class Logger:
         str file_name = None,
         str file_format = None,
         dict component_levels: dict[ComponentId, LogLevel] = None,
+        bint colors = True,
+        uint32_t rate_limit = 0,
+        uint64_t max_file_size = 0,
         bint bypass = False,
     ):
         if trader_id is None:
@@ -123,7 +136,7 @@ cdef class Logger:
 
         cdef str trader_id_str = trader_id.to_str()
         cdef str instance_id_str = instance_id.to_str()
-        self._mem = logger_new(
+        check_ffi(logger_new(
             pystr_to_cstr(trader_id_str),
             pystr_to_cstr(machine_id),
             pystr_to_cstr(instance_id_str),
@@ -134,8 +147,12 @@ cdef class Logger:
             pystr_to_cstr(file_name) if file_name else NULL,
             pystr_to_cstr(file_format) if file_format else NULL,
             pybytes_to_cstr(msgspec.json.encode(component_levels)) if component_levels is not None else NULL,
+            colors,
+            rate_limit,
+            max_file_size,
             bypass,
-        )
+            &self._mem,
+        ))
 
     def __del__(self) -> None:
         if self._mem._0 != NULL:
@@ -229,14 +246,14 @@ cdef class Logger:
         str message,
         dict annotations,
     ):
-        logger_log(
+        check_ffi(logger_log(
             &self._mem,
             timestamp,
             level,
             color,
             pystr_to_cstr(component),
             pystr_to_cstr(message),
-        )
+        ))
 
 
 cdef class LoggerAdapter:
@@ -20,15 +20,17 @@ from libc.stdint cimport uint64_t
 
 from nautilus_trader.common.timer cimport TimeEvent
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.message cimport Event
 from nautilus_trader.core.rust.common cimport time_event_clone
 from nautilus_trader.core.rust.common cimport time_event_drop
 from nautilus_trader.core.rust.common cimport time_event_name_to_cstr
 from nautilus_trader.core.rust.common cimport time_event_new
 from nautilus_trader.core.rust.common cimport time_event_to_cstr
+from nautilus_trader.core.rust.core cimport UUID4_t
 from nautilus_trader.core.rust.core cimport nanos_to_secs
 from nautilus_trader.core.rust.core cimport uuid4_clone
-from nautilus_trader.core.rust.core cimport uuid4_from_cstr
+from nautilus_trader.core.rust.core cimport uuid4_try_from_cstr
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
 from nautilus_trader.core.uuid cimport UUID4
@@ -60,12 +62,13 @@ cdef class TimeEvent(Event):
         # Precondition: `name` validated in Rust
         super().__init__(event_id, ts_event, ts_init)
 
-        self._mem = time_event_new(
+        check_ffi(time_event_new(
             pystr_to_cstr(name),
             uuid4_clone(&event_id._mem),
             ts_event,
             ts_init,
-        )
+            &self._mem,
+        ))
 
     def __del__(self) -> None:
         if self._mem.name != NULL:
@@ -82,12 +85,15 @@ cdef class TimeEvent(Event):
     def __setstate__(self, state):
         self.ts_event = state[2]
         self.ts_init = state[3]
-        self._mem = time_event_new(
+        cdef UUID4_t event_id
+        check_ffi(uuid4_try_from_cstr(pystr_to_cstr(state[1]), &event_id))
+        check_ffi(time_event_new(
             pystr_to_cstr(state[0]),
-            uuid4_from_cstr(pystr_to_cstr(state[1])),
+            event_id,
             self.ts_event,
             self.ts_init,
-        )
+            &self._mem,
+        ))
 
     cdef str to_str(self):
         return cstr_to_pystr(time_event_name_to_cstr(&self._mem))
@@ -0,0 +1,34 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from libc.stdint cimport uint8_t
+
+from nautilus_trader.core.rust.core cimport last_error_cstr
+from nautilus_trader.core.string cimport cstr_to_pystr
+
+
+cdef inline str last_ffi_error():
+    cdef const char* ptr = last_error_cstr()
+    if ptr == NULL:
+        return "unknown error"
+    return cstr_to_pystr(ptr)
+
+
+cdef inline int check_ffi(uint8_t status) except -1:
+    # Raises a `ValueError` with the last error message if the Rust FFI call
+    # returned a non-zero status code
+    if status != 0:
+        raise ValueError(last_ffi_error())
+    return 0
@@ -133,6 +133,20 @@ typedef enum ComponentTrigger {
     FAULT_COMPLETED = 15,
 } ComponentTrigger;
 
+/**
+ * The health of a data or execution client, as determined by the time since its last message.
+ */
+typedef enum HealthStatus {
+    /**
+     * The client has received a message within its stale threshold.
+     */
+    HEALTHY = 1,
+    /**
+     * The client has not received a message within its stale threshold.
+     */
+    DEGRADED = 2,
+} HealthStatus;
+
 /**
  * The log color for log messages.
  */
@@ -193,6 +207,46 @@ typedef enum LogLevel {
     CRITICAL = 50,
 } LogLevel;
 
+/**
+ * Provides a central in-memory cache of trading state.
+ *
+ * Orders and positions are indexed by venue, instrument and strategy, and by whether they
+ * are open or closed. Query results are sorted by `ts_init` (then by identifier) so that
+ * they are deterministic.
+ */
+typedef struct Cache Cache;
+
+/**
+ * A point in time copy of the state held by a [`Cache`].
+ *
+ * Market data is held newest first, in the same order as returned by the cache.
+ */
+typedef struct CacheSnapshot CacheSnapshot;
+
+typedef struct ComponentFsm ComponentFsm;
+
+/**
+ * Provides expiry timer management for GTD (good till date) orders, for execution
+ * clients whose venues do not expire orders themselves.
+ *
+ * Registering an order sets a time alert named [`GtdExpiryTimers::timer_name`] on the clock,
+ * using the default handler of the clock, which must route the event back to
+ * [`GtdExpiryTimers::on_time_event`]. The caller then cancels (or expires) the returned
+ * order at the venue.
+ */
+typedef struct GtdExpiryTimers GtdExpiryTimers;
+
+/**
+ * Provides health monitoring of data and execution clients from the time since their last
+ * message.
+ *
+ * Each [`HealthMonitor::check`] publishes an alert event on the message bus for every client
+ * which has stalled beyond its stale threshold since the previous check, on the topic
+ * `health.degraded.<client_id>`, and for every degraded client which has since received a
+ * message, on the topic `health.recovered.<client_id>`.
+ */
+typedef struct HealthMonitor HealthMonitor;
+
 typedef struct LiveClock LiveClock;
 
 /**
@@ -203,10 +257,31 @@ typedef struct LiveClock LiveClock;
  */
 typedef struct Logger_t Logger_t;
 
-typedef struct Rc_String Rc_String;
+typedef struct Arc_String Arc_String;
+
+typedef struct MessageBus_t MessageBus_t;
 
 typedef struct TestClock TestClock;
 
+/**
+ * Provides a means of building time bars from ticks with an internal timer.
+ *
+ * When the timer fires (see [`TimeBarAggregator::on_time_event`]) a bar is
+ * created and sent to the handler.
+ */
+typedef struct TimeBarAggregator TimeBarAggregator;
+
+/**
+ * Provides the trading sessions of a venue, with holidays and half-days, for queries in
+ * UNIX nanoseconds.
+ *
+ * Sessions are defined per weekday in the venues local time, given as a fixed offset from
+ * UTC, and may not span midnight. Adjacent sessions (including across midnight) are treated
+ * as one continuous session, so a market which never closes within the search horizon
+ * has no next open or close.
+ */
+typedef struct TradingCalendar TradingCalendar;
+
 /**
  * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`TestClock`].
  *
@@ -236,6 +311,20 @@ typedef struct LiveClock_API {
     struct LiveClock *_0;
 } LiveClock_API;
 
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`ComponentFsm`].
+ *
+ * This struct wraps `ComponentFsm` in a way that makes it compatible with C function
+ * calls, enabling interaction with `ComponentFsm` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `ComponentFsm_API` to
+ * be dereferenced to `ComponentFsm`, providing access to `ComponentFsm`'s methods without
+ * having to manually access the underlying `ComponentFsm` instance.
+ */
+typedef struct ComponentFsm_API {
+    struct ComponentFsm *_0;
+} ComponentFsm_API;
+
 /**
  * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Logger`].
  *
@@ -250,6 +339,78 @@ typedef struct Logger_API {
     struct Logger_t *_0;
 } Logger_API;
 
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`MessageBus`].
+ *
+ * This struct wraps `MessageBus` in a way that makes it compatible with C function
+ * calls, enabling interaction with `MessageBus` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `MessageBus_API` to be
+ * dereferenced to `MessageBus`, providing access to `MessageBus`'s methods without
+ * having to manually access the underlying `MessageBus` instance.
+ */
+typedef struct MessageBus_API {
+    struct MessageBus_t *_0;
+} MessageBus_API;
+
+typedef enum Message_t_Tag {
+    COMMAND,
+    DOCUMENT,
+    EVENT,
+    REQUEST,
+    RESPONSE,
+} Message_t_Tag;
+
+typedef struct Command_Body {
+    struct UUID4_t id;
+    uint64_t ts_init;
+} Command_Body;
+
+typedef struct Document_Body {
+    struct UUID4_t id;
+    uint64_t ts_init;
+} Document_Body;
+
+typedef struct Event_Body {
+    struct UUID4_t id;
+    uint64_t ts_init;
+    uint64_t ts_event;
+} Event_Body;
+
+typedef struct Request_Body {
+    struct UUID4_t id;
+    uint64_t ts_init;
+} Request_Body;
+
+typedef struct Response_Body {
+    struct UUID4_t id;
+    uint64_t ts_init;
+    struct UUID4_t correlation_id;
+} Response_Body;
+
+/**
+ * Represents a message which can be sent, published, requested or responded to
+ * over the [`MessageBus`].
+ */
+typedef struct Message_t {
+    Message_t_Tag tag;
+    union {
+        Command_Body command;
+        Document_Body document;
+        Event_Body event;
+        Request_Body request;
+        Response_Body response;
+    };
+} Message_t;
+
+/**
+ * A C callback for handling messages from the [`MessageBus`].
+ *
+ * The `context` pointer is passed back unchanged on every call, allowing the caller
+ * to route the message to the owning object (e.g. a Cython actor).
+ */
+typedef void (*MessageCallback)(void *context, const struct Message_t *msg);
+
 /**
  * Represents a time event occurring at the event timestamp.
  */
@@ -257,7 +418,7 @@ typedef struct TimeEvent_t {
     /**
      * The event name.
      */
-    struct Rc_String *name;
+    struct Arc_String *name;
     /**
      * The event ID.
      */
@@ -286,15 +447,361 @@ typedef struct TimeEventHandler_t {
     PyObject *callback_ptr;
 } TimeEventHandler_t;
 
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying tick,
+ * volume or value [`BarAggregator`].
+ *
+ * This struct wraps the aggregator in a way that makes it compatible with C function
+ * calls, enabling interaction with the aggregator in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `BarAggregator_API`
+ * to be dereferenced to the underlying aggregator, providing access to its methods without
+ * having to manually access the underlying instance.
+ */
+typedef struct BarAggregator_API {
+    void *_0;
+} BarAggregator_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`TimeBarAggregator`].
+ *
+ * This struct wraps `TimeBarAggregator` in a way that makes it compatible with C function
+ * calls, enabling interaction with `TimeBarAggregator` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `TimeBarAggregator_API`
+ * to be dereferenced to `TimeBarAggregator`, providing access to `TimeBarAggregator`'s methods
+ * without having to manually access the underlying `TimeBarAggregator` instance.
+ */
+typedef struct TimeBarAggregator_API {
+    struct TimeBarAggregator * _0;
+} TimeBarAggregator_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying
+ * [`GtdExpiryTimers`].
+ *
+ * This struct wraps `GtdExpiryTimers` in a way that makes it compatible with C function
+ * calls, enabling interaction with `GtdExpiryTimers` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of
+ * `GtdExpiryTimers_API` to be dereferenced to `GtdExpiryTimers`, providing access to
+ * `GtdExpiryTimers`'s methods without having to manually access the underlying instance.
+ */
+typedef struct GtdExpiryTimers_API {
+    struct GtdExpiryTimers * _0;
+} GtdExpiryTimers_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Cache`].
+ *
+ * This struct wraps `Cache` in a way that makes it compatible with C function
+ * calls, enabling interaction with `Cache` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `Cache_API` to be
+ * dereferenced to `Cache`, providing access to `Cache`'s methods without having to manually
+ * access the underlying `Cache` instance.
+ */
+typedef struct Cache_API {
+    struct Cache * _0;
+} Cache_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying
+ * [`CacheSnapshot`].
+ */
+typedef struct CacheSnapshot_API {
+    struct CacheSnapshot * _0;
+} CacheSnapshot_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`HealthMonitor`].
+ *
+ * This struct wraps `HealthMonitor` in a way that makes it compatible with C function
+ * calls, enabling interaction with `HealthMonitor` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `HealthMonitor_API`
+ * to be dereferenced to `HealthMonitor`, providing access to `HealthMonitor`'s methods
+ * without having to manually access the underlying `HealthMonitor` instance.
+ */
+typedef struct HealthMonitor_API {
+    struct HealthMonitor * _0;
+} HealthMonitor_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`TradingCalendar`].
+ *
+ * This struct wraps `TradingCalendar` in a way that makes it compatible with C function
+ * calls, enabling interaction with `TradingCalendar` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `TradingCalendar_API`
+ * to be dereferenced to `TradingCalendar`, providing access to `TradingCalendar`'s methods
+ * without having to manually access the underlying `TradingCalendar` instance.
+ */
+typedef struct TradingCalendar_API {
+    struct TradingCalendar * _0;
+} TradingCalendar_API;
+
+/**
+ * Writes a new bar aggregator for the tick, volume or value based `bar_type` to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `callback_ptr` is NULL or `None`, or the bar aggregation of `bar_type` is not tick,
+ * volume or value based.
+ *
+ * # Safety
+ *
+ * - Assumes `callback_ptr` is a valid PyCallable pointer.
+ * - Assumes `out` is a valid pointer to write the aggregator to.
+ */
+uint8_t bar_aggregator_new(BarType_t bar_type,
+                           uint8_t price_precision,
+                           uint8_t size_precision,
+                           PyObject *callback_ptr,
+                           struct BarAggregator_API *out);
+
+void bar_aggregator_drop(struct BarAggregator_API aggregator);
+
+BarType_t bar_aggregator_bar_type(const struct BarAggregator_API *aggregator);
+
+void bar_aggregator_handle_quote_tick(struct BarAggregator_API *aggregator,
+                                      const QuoteTick_t *tick);
+
+void bar_aggregator_handle_trade_tick(struct BarAggregator_API *aggregator,
+                                      const TradeTick_t *tick);
+
+/**
+ * Writes a new time bar aggregator for the time based `bar_type` to `out`.
+ *
+ * The caller is responsible for setting a timer with the aggregators interval, and
+ * routing each resulting [`TimeEvent`] to `time_bar_aggregator_on_time_event`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `callback_ptr` is NULL or `None`.
+ *
+ * # Safety
+ *
+ * - Assumes `callback_ptr` is a valid PyCallable pointer.
+ * - Assumes `build_with_no_updates` is a correct `uint8_t` of either 0 or 1.
+ * - Assumes `out` is a valid pointer to write the aggregator to.
+ */
+uint8_t time_bar_aggregator_new(BarType_t bar_type,
+                                uint8_t price_precision,
+                                uint8_t size_precision,
+                                uint8_t build_with_no_updates,
+                                PyObject *callback_ptr,
+                                struct TimeBarAggregator_API *out);
+
+void time_bar_aggregator_drop(struct TimeBarAggregator_API aggregator);
+
+BarType_t time_bar_aggregator_bar_type(const struct TimeBarAggregator_API *aggregator);
+
+uint64_t time_bar_aggregator_interval_ns(const struct TimeBarAggregator_API *aggregator);
+
+uint64_t time_bar_aggregator_next_close_ns(const struct TimeBarAggregator_API *aggregator);
+
+uint64_t time_bar_aggregator_start_time_ns(const struct TimeBarAggregator_API *aggregator,
+                                           uint64_t ts_now);
+
+void time_bar_aggregator_handle_quote_tick(struct TimeBarAggregator_API *aggregator,
+                                           const QuoteTick_t *tick);
+
+void time_bar_aggregator_handle_trade_tick(struct TimeBarAggregator_API *aggregator,
+                                           const TradeTick_t *tick);
+
+void time_bar_aggregator_on_time_event(struct TimeBarAggregator_API *aggregator,
+                                       const struct TimeEvent_t *event);
+
+struct Cache_API cache_new(uintptr_t tick_capacity, uintptr_t bar_capacity);
+
+void cache_drop(struct Cache_API cache);
+
+void cache_reset(struct Cache_API *cache);
+
+void cache_add_quote_tick(struct Cache_API *cache, const QuoteTick_t *tick);
+
+void cache_add_trade_tick(struct Cache_API *cache, const TradeTick_t *tick);
+
+void cache_add_bar(struct Cache_API *cache, const Bar_t *bar);
+
+/**
+ * Writes the latest quote tick for the `instrument_id` to `out`.
+ *
+ * Returns 1 if a quote tick was written, otherwise 0.
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to a `QuoteTick`.
+ */
+uint8_t cache_quote_tick(const struct Cache_API *cache,
+                         const InstrumentId_t *instrument_id,
+                         QuoteTick_t *out);
+
+/**
+ * Writes the latest trade tick for the `instrument_id` to `out`.
+ *
+ * Returns 1 if a trade tick was written, otherwise 0.
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to a `TradeTick`.
+ */
+uint8_t cache_trade_tick(const struct Cache_API *cache,
+                         const InstrumentId_t *instrument_id,
+                         TradeTick_t *out);
+
+/**
+ * Writes the latest bar for the `bar_type` to `out`.
+ *
+ * Returns 1 if a bar was written, otherwise 0.
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to a `Bar`.
+ */
+uint8_t cache_bar(const struct Cache_API *cache, const BarType_t *bar_type, Bar_t *out);
+
+uintptr_t cache_quote_tick_count(const struct Cache_API *cache,
+                                 const InstrumentId_t *instrument_id);
+
+uintptr_t cache_trade_tick_count(const struct Cache_API *cache,
+                                 const InstrumentId_t *instrument_id);
+
+uintptr_t cache_bar_count(const struct Cache_API *cache, const BarType_t *bar_type);
+
+uint8_t cache_order_exists(const struct Cache_API *cache, const ClientOrderId_t *client_order_id);
+
+uint8_t cache_position_exists(const struct Cache_API *cache, const PositionId_t *position_id);
+
+/**
+ * Returns the count of open orders matching the filters, where a `side` of
+ * `NO_ORDER_SIDE` applies no side filter.
+ *
+ * # Safety
+ *
+ * - Assumes each filter is either NULL (no filter) or a valid pointer.
+ */
+uintptr_t cache_orders_open_count(const struct Cache_API *cache,
+                                  const Venue_t *venue,
+                                  const InstrumentId_t *instrument_id,
+                                  const StrategyId_t *strategy_id,
+                                  OrderSide side);
+
+/**
+ * Returns the count of closed orders matching the filters, where a `side` of
+ * `NO_ORDER_SIDE` applies no side filter.
+ *
+ * # Safety
+ *
+ * - Assumes each filter is either NULL (no filter) or a valid pointer.
+ */
+uintptr_t cache_orders_closed_count(const struct Cache_API *cache,
+                                    const Venue_t *venue,
+                                    const InstrumentId_t *instrument_id,
+                                    const StrategyId_t *strategy_id,
+                                    OrderSide side);
+
+/**
+ * Returns the count of open positions matching the filters, where a `side` of
+ * `NO_POSITION_SIDE` applies no side filter.
+ *
+ * # Safety
+ *
+ * - Assumes each filter is either NULL (no filter) or a valid pointer.
+ */
+uintptr_t cache_positions_open_count(const struct Cache_API *cache,
+                                     const Venue_t *venue,
+                                     const InstrumentId_t *instrument_id,
+                                     const StrategyId_t *strategy_id,
+                                     PositionSide side);
+
+/**
+ * Returns the count of closed positions matching the filters, where a `side` of
+ * `NO_POSITION_SIDE` applies no side filter.
+ *
+ * # Safety
+ *
+ * - Assumes each filter is either NULL (no filter) or a valid pointer.
+ */
+uintptr_t cache_positions_closed_count(const struct Cache_API *cache,
+                                       const Venue_t *venue,
+                                       const InstrumentId_t *instrument_id,
+                                       const StrategyId_t *strategy_id,
+                                       PositionSide side);
+
+/**
+ * Returns a snapshot of the cache state, which must be freed with `cache_snapshot_drop`.
+ */
+struct CacheSnapshot_API cache_snapshot(const struct Cache_API *cache);
+
+void cache_restore(struct Cache_API *cache, const struct CacheSnapshot_API *snapshot);
+
+void cache_snapshot_drop(struct CacheSnapshot_API snapshot);
+
+struct TradingCalendar_API trading_calendar_new(const Venue_t *venue, int32_t utc_offset_secs);
+
+struct TradingCalendar_API trading_calendar_always_open(const Venue_t *venue);
+
+void trading_calendar_drop(struct TradingCalendar_API calendar);
+
+/**
+ * Adds a session on the `weekday` (0 for Monday to 6 for Sunday), in seconds from local
+ * midnight.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `weekday` is greater than 6, or the session is invalid or overlaps another session.
+ */
+uint8_t trading_calendar_add_session(struct TradingCalendar_API *calendar,
+                                     uint8_t weekday,
+                                     uint32_t open_secs,
+                                     uint32_t close_secs);
+
+/**
+ * Adds a holiday on the local date.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * the date is invalid.
+ */
+uint8_t trading_calendar_add_holiday(struct TradingCalendar_API *calendar,
+                                     int32_t year,
+                                     uint32_t month,
+                                     uint32_t day);
+
+/**
+ * Adds a half-day on the local date, closing at `close_secs` from local midnight.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * the date is invalid.
+ */
+uint8_t trading_calendar_add_half_day(struct TradingCalendar_API *calendar,
+                                      int32_t year,
+                                      uint32_t month,
+                                      uint32_t day,
+                                      uint32_t close_secs);
+
+uint8_t trading_calendar_is_open(const struct TradingCalendar_API *calendar, uint64_t ts);
+
+/**
+ * Returns the next session open after `ts`, or 0 if there is none.
+ */
+uint64_t trading_calendar_next_open(const struct TradingCalendar_API *calendar, uint64_t ts);
+
+/**
+ * Returns the next session close after `ts`, or 0 if there is none.
+ */
+uint64_t trading_calendar_next_close(const struct TradingCalendar_API *calendar, uint64_t ts);
+
 struct TestClock_API test_clock_new(void);
 
 void test_clock_drop(struct TestClock_API clock);
 
 /**
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `callback_ptr` is NULL or `None`.
+ *
  * # Safety
  * - Assumes `callback_ptr` is a valid PyCallable pointer.
  */
-void test_clock_register_default_handler(struct TestClock_API *clock, PyObject *callback_ptr);
+uint8_t test_clock_register_default_handler(struct TestClock_API *clock, PyObject *callback_ptr);
 
 void test_clock_set_time(struct TestClock_API *clock, uint64_t to_time_ns);
 
@@ -311,28 +818,36 @@ PyObject *test_clock_timer_names(const struct TestClock_API *clock);
 uintptr_t test_clock_timer_count(struct TestClock_API *clock);
 
 /**
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `name_ptr` is not a valid timer name, `callback_ptr` is NULL, `callback_ptr` is `None`
+ * with no default handler registered, or `alert_time_ns` is before the current time.
+ *
  * # Safety
  *
  * - Assumes `name_ptr` is a valid C string pointer.
  * - Assumes `callback_ptr` is a valid PyCallable pointer.
  */
-void test_clock_set_time_alert_ns(struct TestClock_API *clock,
-                                  const char *name_ptr,
-                                  uint64_t alert_time_ns,
-                                  PyObject *callback_ptr);
+uint8_t test_clock_set_time_alert_ns(struct TestClock_API *clock,
+                                     const char *name_ptr,
+                                     uint64_t alert_time_ns,
+                                     PyObject *callback_ptr);
 
 /**
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `name_ptr` is not a valid timer name, `callback_ptr` is NULL, or `callback_ptr` is
+ * `None` with no default handler registered.
+ *
  * # Safety
  *
  * - Assumes `name_ptr` is a valid C string pointer.
  * - Assumes `callback_ptr` is a valid PyCallable pointer.
  */
-void test_clock_set_timer_ns(struct TestClock_API *clock,
-                             const char *name_ptr,
-                             uint64_t interval_ns,
-                             uint64_t start_time_ns,
-                             uint64_t stop_time_ns,
-                             PyObject *callback_ptr);
+uint8_t test_clock_set_timer_ns(struct TestClock_API *clock,
+                                const char *name_ptr,
+                                uint64_t interval_ns,
+                                uint64_t start_time_ns,
+                                uint64_t stop_time_ns,
+                                PyObject *callback_ptr);
 
 /**
  * # Safety
@@ -344,18 +859,27 @@ CVec test_clock_advance_time(struct TestClock_API *clock, uint64_t to_time_ns, u
 void vec_time_event_handlers_drop(CVec v);
 
 /**
+ * Writes the next time of the timer named `name_ptr` to `out` (zero if no such timer).
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `name_ptr` is not a valid C string (in which case nothing is written to `out`).
+ *
  * # Safety
  *
  * - Assumes `name_ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`UnixNanos`].
  */
-uint64_t test_clock_next_time_ns(struct TestClock_API *clock, const char *name_ptr);
+uint8_t test_clock_next_time_ns(struct TestClock_API *clock, const char *name_ptr, uint64_t *out);
 
 /**
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `name_ptr` is not a valid C string.
+ *
  * # Safety
  *
  * - Assumes `name_ptr` is a valid C string pointer.
  */
-void test_clock_cancel_timer(struct TestClock_API *clock, const char *name_ptr);
+uint8_t test_clock_cancel_timer(struct TestClock_API *clock, const char *name_ptr);
 
 void test_clock_cancel_timers(struct TestClock_API *clock);
 
@@ -371,66 +895,227 @@ uint64_t live_clock_timestamp_us(struct LiveClock_API *clock);
 
 uint64_t live_clock_timestamp_ns(struct LiveClock_API *clock);
 
+struct ComponentFsm_API component_fsm_new(void);
+
+void component_fsm_drop(struct ComponentFsm_API fsm);
+
+enum ComponentState component_fsm_state(const struct ComponentFsm_API *fsm);
+
+/**
+ * Applies the `trigger` to the `fsm`, returning 0 on success or the [`ComponentError`] code.
+ */
+uint8_t component_fsm_trigger(struct ComponentFsm_API *fsm, enum ComponentTrigger trigger);
+
+/**
+ * Returns 1 if `trigger` is valid from `state`, otherwise 0.
+ */
+uint8_t component_state_can_transition(enum ComponentState state, enum ComponentTrigger trigger);
+
+uint8_t component_fsm_is_initialized(const struct ComponentFsm_API *fsm);
+
+uint8_t component_fsm_is_running(const struct ComponentFsm_API *fsm);
+
+uint8_t component_fsm_is_stopped(const struct ComponentFsm_API *fsm);
+
+uint8_t component_fsm_is_disposed(const struct ComponentFsm_API *fsm);
+
+uint8_t component_fsm_is_degraded(const struct ComponentFsm_API *fsm);
+
+uint8_t component_fsm_is_faulted(const struct ComponentFsm_API *fsm);
+
 const char *component_state_to_cstr(enum ComponentState value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`ComponentState`].
  */
-enum ComponentState component_state_from_cstr(const char *ptr);
+uint8_t component_state_try_from_cstr(const char *ptr, enum ComponentState *out);
 
 const char *component_trigger_to_cstr(enum ComponentTrigger value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`ComponentTrigger`].
+ */
+uint8_t component_trigger_try_from_cstr(const char *ptr, enum ComponentTrigger *out);
+
+const char *health_status_to_cstr(enum HealthStatus value);
+
+/**
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`HealthStatus`].
  */
-enum ComponentTrigger component_trigger_from_cstr(const char *ptr);
+uint8_t health_status_try_from_cstr(const char *ptr, enum HealthStatus *out);
 
 const char *log_level_to_cstr(enum LogLevel value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`LogLevel`].
  */
-enum LogLevel log_level_from_cstr(const char *ptr);
+uint8_t log_level_try_from_cstr(const char *ptr, enum LogLevel *out);
 
 const char *log_color_to_cstr(enum LogColor value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`LogColor`].
+ */
+uint8_t log_color_try_from_cstr(const char *ptr, enum LogColor *out);
+
+struct GtdExpiryTimers_API gtd_expiry_timers_new(void);
+
+void gtd_expiry_timers_drop(struct GtdExpiryTimers_API timers);
+
+uintptr_t gtd_expiry_timers_count(const struct GtdExpiryTimers_API *timers);
+
+uint8_t gtd_expiry_timers_is_pending(const struct GtdExpiryTimers_API *timers,
+                                     const ClientOrderId_t *client_order_id);
+
+/**
+ * Returns the earliest pending expire time, or 0 if there are no pending expirations.
+ */
+uint64_t gtd_expiry_timers_next_expire_time_ns(const struct GtdExpiryTimers_API *timers);
+
+/**
+ * Returns the pending expirations sorted by expire time.
+ *
+ * The returned vector must be freed with `vec_gtd_expirations_drop`.
  */
-enum LogColor log_color_from_cstr(const char *ptr);
+CVec gtd_expiry_timers_pending(const struct GtdExpiryTimers_API *timers);
+
+void vec_gtd_expirations_drop(CVec v);
+
+void gtd_expiry_timers_register(struct GtdExpiryTimers_API *timers,
+                                struct LiveClock_API *clock,
+                                const ClientOrderId_t *client_order_id,
+                                uint64_t expire_time_ns);
+
+/**
+ * Returns 1 if a pending expiration was canceled, otherwise 0.
+ */
+uint8_t gtd_expiry_timers_cancel(struct GtdExpiryTimers_API *timers,
+                                 struct LiveClock_API *clock,
+                                 const ClientOrderId_t *client_order_id);
 
 /**
- * Creates a new logger.
+ * Handles the time `event`, writing the client order ID of the expired order to `out`.
+ *
+ * Returns 1 if the event was from a pending expiry timer, otherwise 0 (in which case
+ * nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`ClientOrderId`], which is not read
+ *   or dropped.
+ */
+uint8_t gtd_expiry_timers_on_time_event(struct GtdExpiryTimers_API *timers,
+                                        const struct TimeEvent_t *event,
+                                        ClientOrderId_t *out);
+
+void gtd_expiry_timers_reset(struct GtdExpiryTimers_API *timers, struct LiveClock_API *clock);
+
+struct HealthMonitor_API health_monitor_new(uint64_t default_stale_threshold_ns);
+
+void health_monitor_drop(struct HealthMonitor_API monitor);
+
+/**
+ * Registers the client to monitor, using the default stale threshold if
+ * `stale_threshold_ns` is 0.
+ */
+void health_monitor_register_client(struct HealthMonitor_API *monitor,
+                                    const ClientId_t *client_id,
+                                    uint64_t stale_threshold_ns,
+                                    uint64_t ts_now);
+
+void health_monitor_deregister_client(struct HealthMonitor_API *monitor,
+                                      const ClientId_t *client_id);
+
+void health_monitor_record_message(struct HealthMonitor_API *monitor,
+                                   const ClientId_t *client_id,
+                                   uint64_t ts);
+
+/**
+ * Returns the count of clients which degraded, publishing alerts on the `msgbus`.
+ */
+uintptr_t health_monitor_check(struct HealthMonitor_API *monitor,
+                               struct MessageBus_API *msgbus,
+                               uint64_t ts_now);
+
+uint8_t health_monitor_is_healthy(const struct HealthMonitor_API *monitor);
+
+/**
+ * Returns the health of every client, sorted by client ID.
+ *
+ * The returned vector must be freed with `vec_client_health_drop`.
+ */
+CVec health_monitor_snapshot(const struct HealthMonitor_API *monitor);
+
+void vec_client_health_drop(CVec v);
+
+/**
+ * Creates a new logger, written to `out`.
+ *
+ * A `rate_limit` or `max_file_size` of zero disables rate limiting or size based file
+ * rotation respectively.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * any of the ID strings are invalid (in which case nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `trader_id_ptr` is a valid C string pointer.
  * - Assumes `machine_id_ptr` is a valid C string pointer.
  * - Assumes `instance_id_ptr` is a valid C string pointer.
+ * - Assumes `directory_ptr`, `file_name_ptr`, `file_format_ptr` and `component_levels_ptr`
+ *   are either null or valid C string pointers.
+ * - Assumes `out` is a valid pointer to memory for a [`Logger_API`].
  */
-struct Logger_API logger_new(const char *trader_id_ptr,
-                             const char *machine_id_ptr,
-                             const char *instance_id_ptr,
-                             enum LogLevel level_stdout,
-                             enum LogLevel level_file,
-                             uint8_t file_logging,
-                             const char *directory_ptr,
-                             const char *file_name_ptr,
-                             const char *file_format_ptr,
-                             const char *component_levels_ptr,
-                             uint8_t is_bypassed);
+uint8_t logger_new(const char *trader_id_ptr,
+                   const char *machine_id_ptr,
+                   const char *instance_id_ptr,
+                   enum LogLevel level_stdout,
+                   enum LogLevel level_file,
+                   uint8_t file_logging,
+                   const char *directory_ptr,
+                   const char *file_name_ptr,
+                   const char *file_format_ptr,
+                   const char *component_levels_ptr,
+                   uint8_t is_colored,
+                   uint32_t rate_limit,
+                   uint64_t max_file_size,
+                   uint8_t is_bypassed,
+                   struct Logger_API *out);
 
 void logger_drop(struct Logger_API logger);
 
@@ -442,32 +1127,252 @@ UUID4_t logger_get_instance_id(const struct Logger_API *logger);
 
 uint8_t logger_is_bypassed(const struct Logger_API *logger);
 
+/**
+ * Returns the number of log events queued for the writer thread.
+ */
+uintptr_t logger_queue_depth(const struct Logger_API *logger);
+
 /**
  * Create a new log event.
  *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `component_ptr` or `message_ptr` is not a valid C string.
+ *
  * # Safety
  *
  * - Assumes `component_ptr` is a valid C string pointer.
  * - Assumes `message_ptr` is a valid C string pointer.
  */
-void logger_log(struct Logger_API *logger,
-                uint64_t timestamp_ns,
-                enum LogLevel level,
-                enum LogColor color,
-                const char *component_ptr,
-                const char *message_ptr);
+uint8_t logger_log(struct Logger_API *logger,
+                   uint64_t timestamp_ns,
+                   enum LogLevel level,
+                   enum LogColor color,
+                   const char *component_ptr,
+                   const char *message_ptr);
+
+/**
+ * Starts serving the metrics at `http://0.0.0.0:<port>/metrics`.
+ *
+ * Returns 1 if the server was started, otherwise 0 (if the port could not be bound).
+ */
+uint8_t metrics_start_server(uint16_t port);
+
+void metrics_inc_orders_submitted(void);
+
+void metrics_inc_orders_filled(void);
+
+void metrics_inc_ticks_processed(uint64_t count);
+
+void metrics_observe_event_loop_latency_ns(uint64_t latency_ns);
+
+/**
+ * Returns the metrics in the Prometheus text format, as a C string pointer.
+ */
+const char *metrics_render_to_cstr(void);
+
+/**
+ * Creates a new message bus, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `trader_id_ptr` is not a valid trader ID or `name_ptr` is not a valid C string (in which
+ * case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `trader_id_ptr` is a valid C string pointer.
+ * - Assumes `name_ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer to memory for a [`MessageBus_API`].
+ */
+uint8_t msgbus_new(const char *trader_id_ptr, const char *name_ptr, struct MessageBus_API *out);
+
+void msgbus_drop(struct MessageBus_API bus);
+
+uint64_t msgbus_sent_count(const struct MessageBus_API *bus);
+
+uint64_t msgbus_req_count(const struct MessageBus_API *bus);
+
+uint64_t msgbus_res_count(const struct MessageBus_API *bus);
+
+uint64_t msgbus_pub_count(const struct MessageBus_API *bus);
+
+/**
+ * Registers the C `callback` to receive messages at the `endpoint` address.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * a string is invalid or the endpoint was already registered.
+ *
+ * # Safety
+ *
+ * - Assumes `endpoint_ptr` is a valid C string pointer.
+ * - Assumes `handler_id_ptr` is a valid C string pointer.
+ * - Assumes `context` remains valid until the endpoint is deregistered.
+ */
+uint8_t msgbus_register(struct MessageBus_API *bus,
+                        const char *endpoint_ptr,
+                        const char *handler_id_ptr,
+                        MessageCallback callback,
+                        void *context);
+
+/**
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `endpoint_ptr` is not a valid C string or no handler was registered at the endpoint.
+ *
+ * # Safety
+ *
+ * - Assumes `endpoint_ptr` is a valid C string pointer.
+ */
+uint8_t msgbus_deregister(struct MessageBus_API *bus, const char *endpoint_ptr);
+
+/**
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `endpoint_ptr` is not a valid C string or no handler was registered at the endpoint.
+ *
+ * # Safety
+ *
+ * - Assumes `endpoint_ptr` is a valid C string pointer.
+ */
+uint8_t msgbus_send(struct MessageBus_API *bus,
+                    const char *endpoint_ptr,
+                    const struct Message_t *msg);
+
+/**
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * a string is invalid or the request was not handled.
+ *
+ * # Safety
+ *
+ * - Assumes `endpoint_ptr` is a valid C string pointer.
+ * - Assumes `handler_id_ptr` is a valid C string pointer.
+ * - Assumes `context` remains valid until the response is received.
+ */
+uint8_t msgbus_request(struct MessageBus_API *bus,
+                       const char *endpoint_ptr,
+                       const struct Message_t *request,
+                       const char *handler_id_ptr,
+                       MessageCallback callback,
+                       void *context);
+
+/**
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * the response was not handled.
+ */
+uint8_t msgbus_response(struct MessageBus_API *bus, const struct Message_t *response);
+
+/**
+ * Subscribes the C `callback` to the `topic`, which may include wildcard characters.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `topic_ptr` or `handler_id_ptr` is not a valid C string.
+ *
+ * # Safety
+ *
+ * - Assumes `topic_ptr` is a valid C string pointer.
+ * - Assumes `handler_id_ptr` is a valid C string pointer.
+ * - Assumes `context` remains valid until the handler is unsubscribed.
+ */
+uint8_t msgbus_subscribe(struct MessageBus_API *bus,
+                         const char *topic_ptr,
+                         const char *handler_id_ptr,
+                         MessageCallback callback,
+                         void *context,
+                         uint8_t priority);
+
+/**
+ * Writes 1 to `out` if unsubscribed, or 0 if no such subscription existed.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `topic_ptr` or `handler_id_ptr` is not a valid C string (in which case nothing is
+ * written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `topic_ptr` is a valid C string pointer.
+ * - Assumes `handler_id_ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a `u8`.
+ */
+uint8_t msgbus_unsubscribe(struct MessageBus_API *bus,
+                           const char *topic_ptr,
+                           const char *handler_id_ptr,
+                           uint8_t *out);
+
+/**
+ * Writes 1 to `out` if the handler is subscribed to the `topic`, otherwise 0.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `topic_ptr` or `handler_id_ptr` is not a valid C string (in which case nothing is
+ * written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `topic_ptr` is a valid C string pointer.
+ * - Assumes `handler_id_ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a `u8`.
+ */
+uint8_t msgbus_is_subscribed(const struct MessageBus_API *bus,
+                             const char *topic_ptr,
+                             const char *handler_id_ptr,
+                             uint8_t *out);
+
+/**
+ * Writes 1 to `out` if there are subscribers matching the `pattern`, otherwise 0.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `pattern_ptr` is not a valid C string (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `pattern_ptr` is a valid C string pointer or NULL (for all topics).
+ * - Assumes `out` is a valid pointer to memory for a `u8`.
+ */
+uint8_t msgbus_has_subscribers(const struct MessageBus_API *bus,
+                               const char *pattern_ptr,
+                               uint8_t *out);
+
+/**
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `topic_ptr` is not a valid C string.
+ *
+ * # Safety
+ *
+ * - Assumes `topic_ptr` is a valid C string pointer.
+ */
+uint8_t msgbus_publish(struct MessageBus_API *bus,
+                       const char *topic_ptr,
+                       const struct Message_t *msg);
+
+/**
+ * Writes 1 to `out` if the `topic` matches the `pattern`, otherwise 0.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `topic_ptr` or `pattern_ptr` is not a valid C string (in which case nothing is written
+ * to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `topic_ptr` is a valid C string pointer.
+ * - Assumes `pattern_ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a `u8`.
+ */
+uint8_t msgbus_is_matching(const char *topic_ptr, const char *pattern_ptr, uint8_t *out);
 
 struct TimeEventHandler_t dummy(struct TimeEventHandler_t v);
 
 /**
+ * Writes a new [`TimeEvent`] to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set (in
+ * which case nothing is written to `out`).
+ *
  * # Safety
  *
  * - Assumes `name_ptr` is borrowed from a valid Python UTF-8 `str`.
+ * - Assumes `out` is a valid pointer to memory for a [`TimeEvent`].
  */
-struct TimeEvent_t time_event_new(const char *name_ptr,
-                                  UUID4_t event_id,
-                                  uint64_t ts_event,
-                                  uint64_t ts_init);
+uint8_t time_event_new(const char *name_ptr,
+                       UUID4_t event_id,
+                       uint64_t ts_event,
+                       uint64_t ts_init,
+                       struct TimeEvent_t *out);
 
 struct TimeEvent_t time_event_clone(const struct TimeEvent_t *event);
 
@@ -5,11 +5,31 @@
 #include <stdint.h>
 #include <Python.h>
 
-typedef struct Arc_String Arc_String;
+/**
+ * The type of the elements of a [`CVec`], so a consumer can check what the
+ * opaque pointer refers to before casting it.
+ *
+ */
+typedef enum CVecElementType {
+    /**
+     * The element type is not tagged, and must be known by the consumer.
+     */
+    CVecElementType_UNKNOWN = 0,
+    CVecElementType_U8 = 1,
+    CVecElementType_U64 = 2,
+    CVecElementType_F64 = 3,
+    CVecElementType_DATA = 10,
+    CVecElementType_QUOTE_TICK = 11,
+    CVecElementType_TRADE_TICK = 12,
+    CVecElementType_BAR = 13,
+    CVecElementType_ORDER_BOOK_DELTA = 14,
+    CVecElementType_ORDER_FILLED = 20,
+} CVecElementType;
 
 /**
  * `CVec` is a C compatible struct that stores an opaque pointer to a block of
- * memory, it's length and the capacity of the vector it was allocated from.
+ * memory, it's length and the capacity of the vector it was allocated from,
+ * along with the type of the elements and the function which drops them.
  *
  * NOTE: Changing the values here may lead to undefined behaviour when the
  * memory is dropped.
@@ -29,12 +49,51 @@ typedef struct CVec {
      * Used when deallocating the memory
      */
     uintptr_t cap;
+    /**
+     * The type of the elements in the block.
+     */
+    enum CVecElementType element_type;
+    /**
+     * The size of each element in bytes.
+     */
+    uintptr_t element_size;
+    /**
+     * Drops the elements and deallocates the memory as the vector it was
+     * allocated from. Null when the memory is not owned by Rust.
+     */
+    void (*drop)(struct CVec);
 } CVec;
 
+/**
+ * Represents the counts of the operations of an [`ObjectPool`].
+ */
+typedef struct PoolStats {
+    /**
+     * The count of objects newly allocated by `acquire`.
+     */
+    uint64_t allocated;
+    /**
+     * The count of objects reused by `acquire`.
+     */
+    uint64_t reused;
+    /**
+     * The count of objects released back into the pool.
+     */
+    uint64_t released;
+    /**
+     * The count of released objects dropped because the pool was full.
+     */
+    uint64_t discarded;
+} PoolStats;
+
 typedef struct UUID4_t {
-    struct Arc_String *value;
+    uint8_t value[37];
 } UUID4_t;
 
+/**
+ * Drops the elements of the [`CVec`] with its drop function, memory not
+ * owned by Rust (with a null drop function) is left to its owner.
+ */
 void cvec_drop(struct CVec cvec);
 
 struct CVec cvec_new(void);
@@ -75,28 +134,75 @@ uint64_t nanos_to_millis(uint64_t nanos);
 uint64_t nanos_to_micros(uint64_t nanos);
 
 /**
- * Return the decimal precision inferred from the given C string.
+ * Returns the ISO 8601 (RFC 3339) string for the UNIX nanoseconds in UTC, as a C string
+ * pointer.
+ *
+ * The caller owns the returned string, and must free it with `cstr_drop`.
+ */
+const char *unix_nanos_to_iso8601_cstr(uint64_t timestamp_ns);
+
+/**
+ * Writes the ISO 8601 (RFC 3339) string for the UNIX nanoseconds in the fixed
+ * `utc_offset_secs` from UTC to `out`, as a C string pointer.
+ *
+ * Returns `FFI_OK` on success, otherwise `FFI_ERROR` with the last error set if
+ * `utc_offset_secs` is not within +/- 24 hours (in which case nothing is written to `out`).
+ *
+ * The caller owns the written string, and must free it with `cstr_drop`.
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a C string pointer.
+ */
+uint8_t unix_nanos_to_iso8601_with_offset_cstr(uint64_t timestamp_ns,
+                                                int32_t utc_offset_secs,
+                                                const char **out);
+
+/**
+ * Parses the ISO 8601 C string at `ptr` into UNIX nanoseconds, written to `out`.
  *
- * # Panics
+ * Returns `FFI_OK` on success, otherwise `FFI_ERROR` with the last error set (in which
+ * case nothing is written to `out`).
  *
- * - If `ptr` is null.
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
  */
-uint8_t precision_from_cstr(const char *ptr);
+uint8_t iso8601_to_unix_nanos_cstr(const char *ptr, uint64_t *out);
 
 /**
- * Drops the C string memory at the pointer.
+ * Returns the message of the last error for the current thread as a C string pointer,
+ * or null if there is no error.
+ *
+ * The caller owns the returned string, and must free it with `cstr_drop`.
+ */
+const char *last_error_cstr(void);
+
+/**
+ * Clears the last error for the current thread.
+ */
+void last_error_clear(void);
+
+/**
+ * Returns the decimal precision inferred from the given C string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `ptr` is not a valid C string of a finite decimal number (in which case nothing is
+ * written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a `u8`.
+ */
+uint8_t precision_from_cstr(const char *ptr, uint8_t *out);
+
+/**
+ * Drops the C string memory at the pointer, doing nothing if `ptr` is null.
  *
- * # Panics
+ * # Safety
  *
- * - If `ptr` is null.
+ * - Assumes `ptr` is either null or a valid C string pointer.
  */
 void cstr_drop(const char *ptr);
 
@@ -120,24 +226,34 @@ uint64_t unix_timestamp_us(void);
  */
 uint64_t unix_timestamp_ns(void);
 
+/**
+ * Returns the current nanoseconds since the UNIX epoch from the global atomic clock
+ * (unique and strictly increasing).
+ */
+uint64_t unix_nanos_now(void);
+
+/**
+ * Returns the current milliseconds since the UNIX epoch from the global atomic clock
+ * (monotonic).
+ */
+uint64_t unix_millis_now(void);
+
 struct UUID4_t uuid4_new(void);
 
 struct UUID4_t uuid4_clone(const struct UUID4_t *uuid4);
 
-void uuid4_drop(struct UUID4_t uuid4);
-
 /**
- * Returns a [`UUID4`] from C string pointer.
+ * Returns a [`UUID4`] from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
- *
- * # Panics
- *
- * - If `ptr` cannot be cast to a valid C string.
+ * - Assumes `out` is a valid pointer to memory for a [`UUID4`].
  */
-struct UUID4_t uuid4_from_cstr(const char *ptr);
+uint8_t uuid4_try_from_cstr(const char *ptr, struct UUID4_t *out);
 
 const char *uuid4_to_cstr(const struct UUID4_t *uuid);
 
@@ -0,0 +1,62 @@
+/* Generated with cbindgen:0.24.5 */
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <stdint.h>
+#include <Python.h>
+
+/**
+ * Provides reconciliation of the cached orders and positions against venue reports.
+ *
+ * Inferred events are generated in the order they should be applied, and are validated
+ * against a copy of the cached order so that each one is a valid state transition.
+ */
+typedef struct ExecutionReconciler ExecutionReconciler;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying
+ * [`ExecutionReconciler`].
+ *
+ * This struct wraps `ExecutionReconciler` in a way that makes it compatible with C function
+ * calls, enabling interaction with `ExecutionReconciler` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of
+ * `ExecutionReconciler_API` to be dereferenced to `ExecutionReconciler`, providing access to
+ * `ExecutionReconciler`'s methods without having to manually access the underlying instance.
+ */
+typedef struct ExecutionReconciler_API {
+    struct ExecutionReconciler * _0;
+} ExecutionReconciler_API;
+
+struct ExecutionReconciler_API execution_reconciler_new(void);
+
+void execution_reconciler_drop(struct ExecutionReconciler_API reconciler);
+
+void execution_reconciler_reset(struct ExecutionReconciler_API *reconciler);
+
+/**
+ * Returns 1 if the `mass_status` was reconciled against the `cache` without any
+ * discrepancies, otherwise 0.
+ */
+uint8_t execution_reconciler_reconcile_mass_status(struct ExecutionReconciler_API *reconciler,
+                                                   const Cache_API *cache,
+                                                   const ExecutionMassStatus_API *mass_status,
+                                                   uint64_t ts_init);
+
+uintptr_t execution_reconciler_discrepancy_count(const struct ExecutionReconciler_API *reconciler);
+
+/**
+ * Returns the discrepancies as a JSON array of their descriptions, as a C string pointer.
+ *
+ * The caller owns the returned string, and must free it with `cstr_drop`.
+ */
+const char *execution_reconciler_discrepancies_to_cstr(const struct ExecutionReconciler_API *reconciler);
+
+/**
+ * Drains the inferred events as a JSON array of their dicts, in the format of the Python
+ * `to_dict` methods, as a C string pointer.
+ *
+ * The caller owns the returned string, and must free it with `cstr_drop`. Returns null
+ * with the last error set if the events fail to serialize.
+ */
+const char *execution_reconciler_drain_events_to_cstr(struct ExecutionReconciler_API *reconciler);
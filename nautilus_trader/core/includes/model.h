@@ -169,6 +169,28 @@ typedef enum BookAction {
     CLEAR = 4,
 } BookAction;
 
+/**
+ * The venue specification of an order book checksum, used to verify the integrity of a book
+ * built from venue deltas.
+ *
+ * Coinbase is not included as its order book feeds do not publish a checksum, the integrity
+ * of those books is instead verified by the message sequence numbers.
+ */
+typedef enum BookChecksumType {
+    /**
+     * CRC32 over the top 10 asks then bids, as concatenated price and size digits (Kraken).
+     */
+    KRAKEN = 1,
+    /**
+     * Signed CRC32 over the top 25 levels, interleaved as `bid:size:ask:size` (OKX).
+     */
+    OKX = 2,
+    /**
+     * Signed CRC32 over the top 25 levels, interleaved with negative ask sizes (Bitfinex).
+     */
+    BITFINEX = 3,
+} BookChecksumType;
+
 /**
  * The order book type, representing the type of levels granularity and delta updating heuristics.
  */
@@ -494,6 +516,36 @@ typedef enum PriceType {
     LAST = 4,
 } PriceType;
 
+/**
+ * The rounding mode applied when reducing the precision of a fixed-point value.
+ */
+typedef enum RoundingMode {
+    /**
+     * Round to the nearest value, with ties rounded away from zero (as `f64::round`).
+     */
+    HALF_AWAY_FROM_ZERO = 1,
+    /**
+     * Round to the nearest value, with ties rounded to the nearest even digit.
+     */
+    HALF_EVEN = 2,
+    /**
+     * Round towards negative infinity.
+     */
+    FLOOR = 3,
+    /**
+     * Round towards positive infinity.
+     */
+    CEILING = 4,
+    /**
+     * Round towards zero (truncate).
+     */
+    TOWARD_ZERO = 5,
+    /**
+     * Round away from zero.
+     */
+    AWAY_FROM_ZERO = 6,
+} RoundingMode;
+
 /**
  * The 'Time in Force' instruction for an order in the financial market.
  */
@@ -620,10 +672,143 @@ typedef enum TriggerType {
 
 typedef struct Arc_String Arc_String;
 
+/**
+ * The specification shared by all tradable instruments.
+ */
+typedef struct BaseInstrument BaseInstrument;
+
+/**
+ * Provides a generator for unique [`ClientOrderId`]s.
+ *
+ * Generated IDs take the form `O-{YYYYMMDD-HHMM}-{trader_tag}-{strategy_tag}-{count}`,
+ * matching the Python `ClientOrderIdGenerator`.
+ */
+typedef struct ClientOrderIdGenerator ClientOrderIdGenerator;
+
+/**
+ * Represents a crypto perpetual futures contract instrument (a.k.a. perpetual swap).
+ */
+typedef struct CryptoPerpetual CryptoPerpetual;
+
+/**
+ * Represents a generic currency pair instrument in a spot/cash market.
+ */
+typedef struct CurrencyPair CurrencyPair;
+
+/**
+ * Represents a generic equity (stock) instrument.
+ */
+typedef struct Equity Equity;
+
+/**
+ * Represents the execution state of an account at a venue, made up of the status reports
+ * of its orders and positions along with the fills of its orders.
+ */
+typedef struct ExecutionMassStatus ExecutionMassStatus;
+
+/**
+ * Represents a fill of an order, as reported by the venue.
+ */
+typedef struct FillReport FillReport;
+
+/**
+ * Represents a generic deliverable futures contract instrument.
+ */
+typedef struct FuturesContract FuturesContract;
+
+/**
+ * Represents a valid trade match ID stored inline, without any heap allocation.
+ *
+ * Trade IDs are created for every trade tick, so this avoids the heap allocation
+ * of [`TradeId`] when ingesting ticks. The value is NUL terminated and padded with
+ * zeros, so it can be borrowed as a C string and compared or hashed bytewise.
+ */
+typedef struct InlineTradeId InlineTradeId;
+
+/**
+ * Represents a generic options contract instrument.
+ */
+typedef struct OptionsContract OptionsContract;
+
+typedef struct Order Order;
+
 typedef struct OrderBook OrderBook;
 
+/**
+ * Represents a grouped batch of `OrderBookDelta` updates for an `OrderBook`.
+ */
+typedef struct OrderBookDeltas OrderBookDeltas;
+
+/**
+ * Represents a complete snapshot of the bid and ask orders in an `OrderBook`.
+ */
+typedef struct OrderBookSnapshot OrderBookSnapshot;
+
+typedef struct OrderCancelRejected OrderCancelRejected;
+
+typedef struct OrderCanceled OrderCanceled;
+
+typedef struct OrderExpired OrderExpired;
+
+typedef struct OrderFilled OrderFilled;
+
+typedef struct OrderInitialized OrderInitialized;
+
+/**
+ * Provides a generator for unique [`OrderListId`]s.
+ *
+ * Generated IDs take the form `OL-{YYYYMMDD-HHMM}-{trader_tag}-{strategy_tag}-{count}`,
+ * matching the Python `OrderListIdGenerator`.
+ */
+typedef struct OrderListIdGenerator OrderListIdGenerator;
+
+typedef struct OrderModifyRejected OrderModifyRejected;
+
+typedef struct OrderPendingCancel OrderPendingCancel;
+
+typedef struct OrderPendingUpdate OrderPendingUpdate;
+
+typedef struct OrderRejected OrderRejected;
+
+/**
+ * Represents the status of an order at a point in time, as reported by the venue.
+ */
+typedef struct OrderStatusReport OrderStatusReport;
+
+typedef struct OrderTriggered OrderTriggered;
+
+typedef struct OrderUpdated OrderUpdated;
+
+/**
+ * Represents a position in a financial market.
+ *
+ * The position ID may be assigned at the trading venue, or can be system
+ * generated depending on a strategies OMS (Order Management System) settings.
+ */
+typedef struct Position Position;
+
+/**
+ * Provides a generator for unique [`PositionId`]s, with a separate counter per strategy.
+ *
+ * Generated IDs take the form `P-{YYYYMMDD-HHMM}-{trader_tag}-{strategy_tag}-{count}`,
+ * with an `F` suffix for positions opened by a flip, matching the Python
+ * `PositionIdGenerator`.
+ */
+typedef struct PositionIdGenerator PositionIdGenerator;
+
+/**
+ * Represents the status of a position at a point in time, as reported by the venue.
+ */
+typedef struct PositionStatusReport PositionStatusReport;
+
 typedef struct String String;
 
+/**
+ * Converts between venue-native symbols and Nautilus [`Symbol`]s using the rules registered
+ * per venue.
+ */
+typedef struct SymbologyNormalizer SymbologyNormalizer;
+
 /**
  * Represents a synthetic instrument with prices derived from component instruments using a
  * formula.
@@ -847,9 +1032,262 @@ typedef struct Money_t {
     struct Currency_t currency;
 } Money_t;
 
-struct BarSpecification_t bar_specification_new(uint64_t step,
-                                                uint8_t aggregation,
-                                                uint8_t price_type);
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Order`].
+ *
+ * This struct wraps `Order` in a way that makes it compatible with C function
+ * calls, enabling interaction with `Order` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `Order_API` to be
+ * dereferenced to `Order`, providing access to `Order`'s methods without
+ * having to manually access the underlying `Order` instance.
+ */
+typedef struct Order_API {
+    struct Order * _0;
+} Order_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying
+ * [`SymbologyNormalizer`].
+ *
+ * This struct wraps `SymbologyNormalizer` in a way that makes it compatible with C function
+ * calls, enabling adapters to register rules and normalize symbols from a C environment.
+ */
+typedef struct SymbologyNormalizer_API {
+    struct SymbologyNormalizer * _0;
+} SymbologyNormalizer_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying
+ * [`OrderStatusReport`].
+ *
+ * This struct wraps `OrderStatusReport` in a way that makes it compatible with C function
+ * calls, enabling interaction with `OrderStatusReport` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of
+ * `OrderStatusReport_API` to be dereferenced to `OrderStatusReport`, providing access to
+ * `OrderStatusReport`'s fields without having to manually access the underlying instance.
+ */
+typedef struct OrderStatusReport_API {
+    struct OrderStatusReport * _0;
+} OrderStatusReport_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`FillReport`].
+ *
+ * This struct wraps `FillReport` in a way that makes it compatible with C function
+ * calls, enabling interaction with `FillReport` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `FillReport_API`
+ * to be dereferenced to `FillReport`, providing access to `FillReport`'s fields without
+ * having to manually access the underlying instance.
+ */
+typedef struct FillReport_API {
+    struct FillReport * _0;
+} FillReport_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying
+ * [`PositionStatusReport`].
+ *
+ * This struct wraps `PositionStatusReport` in a way that makes it compatible with C function
+ * calls, enabling interaction with `PositionStatusReport` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of
+ * `PositionStatusReport_API` to be dereferenced to `PositionStatusReport`, providing access
+ * to `PositionStatusReport`'s fields without having to manually access the underlying
+ * instance.
+ */
+typedef struct PositionStatusReport_API {
+    struct PositionStatusReport * _0;
+} PositionStatusReport_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying
+ * [`ExecutionMassStatus`].
+ *
+ * This struct wraps `ExecutionMassStatus` in a way that makes it compatible with C function
+ * calls, enabling interaction with `ExecutionMassStatus` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of
+ * `ExecutionMassStatus_API` to be dereferenced to `ExecutionMassStatus`, providing access to
+ * `ExecutionMassStatus`'s methods without having to manually access the underlying instance.
+ */
+typedef struct ExecutionMassStatus_API {
+    struct ExecutionMassStatus * _0;
+} ExecutionMassStatus_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`CurrencyPair`].
+ *
+ * This struct wraps `CurrencyPair` in a way that makes it compatible with C function
+ * calls, enabling interaction with `CurrencyPair` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `CurrencyPair_API` to be
+ * dereferenced to `CurrencyPair`, providing access to `CurrencyPair`'s methods without
+ * having to manually access the underlying `CurrencyPair` instance.
+ */
+typedef struct CurrencyPair_API {
+    struct CurrencyPair * _0;
+} CurrencyPair_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`OptionsContract`].
+ *
+ * This struct wraps `OptionsContract` in a way that makes it compatible with C function
+ * calls, enabling interaction with `OptionsContract` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `OptionsContract_API` to be
+ * dereferenced to `OptionsContract`, providing access to `OptionsContract`'s methods without
+ * having to manually access the underlying `OptionsContract` instance.
+ */
+typedef struct OptionsContract_API {
+    struct OptionsContract * _0;
+} OptionsContract_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`FuturesContract`].
+ *
+ * This struct wraps `FuturesContract` in a way that makes it compatible with C function
+ * calls, enabling interaction with `FuturesContract` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `FuturesContract_API` to be
+ * dereferenced to `FuturesContract`, providing access to `FuturesContract`'s methods without
+ * having to manually access the underlying `FuturesContract` instance.
+ */
+typedef struct FuturesContract_API {
+    struct FuturesContract * _0;
+} FuturesContract_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Equity`].
+ *
+ * This struct wraps `Equity` in a way that makes it compatible with C function
+ * calls, enabling interaction with `Equity` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `Equity_API` to be
+ * dereferenced to `Equity`, providing access to `Equity`'s methods without
+ * having to manually access the underlying `Equity` instance.
+ */
+typedef struct Equity_API {
+    struct Equity * _0;
+} Equity_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`CryptoPerpetual`].
+ *
+ * This struct wraps `CryptoPerpetual` in a way that makes it compatible with C function
+ * calls, enabling interaction with `CryptoPerpetual` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `CryptoPerpetual_API` to be
+ * dereferenced to `CryptoPerpetual`, providing access to `CryptoPerpetual`'s methods without
+ * having to manually access the underlying `CryptoPerpetual` instance.
+ */
+typedef struct CryptoPerpetual_API {
+    struct CryptoPerpetual * _0;
+} CryptoPerpetual_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying
+ * [`ClientOrderIdGenerator`].
+ */
+typedef struct ClientOrderIdGenerator_API {
+    struct ClientOrderIdGenerator * _0;
+} ClientOrderIdGenerator_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`OrderBookDeltas`].
+ */
+typedef struct OrderBookDeltas_API {
+    struct OrderBookDeltas * _0;
+} OrderBookDeltas_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying
+ * [`OrderBookSnapshot`].
+ */
+typedef struct OrderBookSnapshot_API {
+    struct OrderBookSnapshot * _0;
+} OrderBookSnapshot_API;
+
+typedef struct OrderSubmitted {
+    struct TraderId_t trader_id;
+    struct StrategyId_t strategy_id;
+    struct InstrumentId_t instrument_id;
+    struct ClientOrderId_t client_order_id;
+    struct AccountId_t account_id;
+    UUID4_t event_id;
+    uint64_t ts_event;
+    uint64_t ts_init;
+} OrderSubmitted;
+
+typedef struct OrderAccepted {
+    struct TraderId_t trader_id;
+    struct StrategyId_t strategy_id;
+    struct InstrumentId_t instrument_id;
+    struct ClientOrderId_t client_order_id;
+    struct VenueOrderId_t venue_order_id;
+    struct AccountId_t account_id;
+    UUID4_t event_id;
+    uint64_t ts_event;
+    uint64_t ts_init;
+    bool reconciliation;
+} OrderAccepted;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Position`].
+ *
+ * This struct wraps `Position` in a way that makes it compatible with C function
+ * calls, enabling interaction with `Position` in a C environment.
+ *
+ * It implements the `Deref` and `DerefMut` traits, allowing instances of `Position_API` to be
+ * dereferenced to `Position`, providing access to `Position`'s methods without
+ * having to manually access the underlying `Position` instance.
+ */
+typedef struct Position_API {
+    struct Position * _0;
+} Position_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying
+ * [`OrderListIdGenerator`].
+ */
+typedef struct OrderListIdGenerator_API {
+    struct OrderListIdGenerator * _0;
+} OrderListIdGenerator_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying
+ * [`PositionIdGenerator`].
+ */
+typedef struct PositionIdGenerator_API {
+    struct PositionIdGenerator * _0;
+} PositionIdGenerator_API;
+
+/**
+ * Represents new trailing stop prices, flagged for whether each has moved.
+ */
+typedef struct TrailingStopPrices {
+    uint8_t has_trigger_price;
+    struct Price_t trigger_price;
+    uint8_t has_price;
+    struct Price_t price;
+} TrailingStopPrices;
+
+/**
+ * Returns a new [`BarSpecification`], written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`BarSpecification`].
+ */
+uint8_t bar_specification_new(uint64_t step,
+                              uint8_t aggregation,
+                              uint8_t price_type,
+                              struct BarSpecification_t *out);
 
 /**
  * Returns a [`BarSpecification`] as a C string pointer.
@@ -873,9 +1311,58 @@ uint8_t bar_specification_gt(const struct BarSpecification_t *lhs,
 uint8_t bar_specification_ge(const struct BarSpecification_t *lhs,
                              const struct BarSpecification_t *rhs);
 
-struct BarType_t bar_type_new(struct InstrumentId_t instrument_id,
-                              struct BarSpecification_t spec,
-                              uint8_t aggregation_source);
+/**
+ * Returns a new [`BarType`], written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`BarType`].
+ */
+uint8_t bar_type_new(struct InstrumentId_t instrument_id,
+                     struct BarSpecification_t spec,
+                     uint8_t aggregation_source,
+                     struct BarType_t *out);
+
+/**
+ * Checks the provided C string pointer parses as a [`BarType`].
+ *
+ * Returns zero if the input parsed successfully, otherwise a non-zero status code with
+ * the parsing error set as the last error.
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ */
+uint8_t bar_type_check_parsing(const char *ptr);
+
+/**
+ * Returns a [`BarType`] from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`BarType`].
+ */
+uint8_t bar_type_try_from_cstr(const char *ptr, struct BarType_t *out);
+
+/**
+ * Returns a [`BarSpecification`] from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`BarSpecification`].
+ */
+uint8_t bar_specification_try_from_cstr(const char *ptr, struct BarSpecification_t *out);
 
 void bar_type_drop(struct BarType_t bar_type);
 
@@ -972,26 +1459,156 @@ uint8_t orderbook_delta_eq(const struct OrderBookDelta_t *lhs, const struct Orde
 
 uint64_t orderbook_delta_hash(const struct OrderBookDelta_t *delta);
 
-struct QuoteTick_t quote_tick_new(struct InstrumentId_t instrument_id,
-                                  int64_t bid_price_raw,
-                                  int64_t ask_price_raw,
-                                  uint8_t bid_price_prec,
-                                  uint8_t ask_price_prec,
-                                  uint64_t bid_size_raw,
-                                  uint64_t ask_size_raw,
-                                  uint8_t bid_size_prec,
-                                  uint8_t ask_size_prec,
-                                  uint64_t ts_event,
-                                  uint64_t ts_init);
-
-void quote_tick_drop(struct QuoteTick_t tick);
+/**
+ * Creates a new [`OrderBookDeltas`] from a C array of deltas.
+ *
+ * # Safety
+ *
+ * - Assumes `deltas` is either NULL (for no deltas) or points to a valid array of at least
+ *   `len` [`OrderBookDelta`]s.
+ */
+struct OrderBookDeltas_API orderbook_deltas_new(struct InstrumentId_t instrument_id,
+                                                const struct OrderBookDelta_t *deltas,
+                                                uintptr_t len);
 
-struct QuoteTick_t quote_tick_clone(const struct QuoteTick_t *tick);
+void orderbook_deltas_drop(struct OrderBookDeltas_API deltas);
 
 /**
- * Returns a [`QuoteTick`] as a C string pointer.
+ * Drops the [`OrderBookDeltas`], returning its buffer to the pool of the calling thread
+ * for reuse by `orderbook_deltas_new`.
  */
-const char *quote_tick_to_cstr(const struct QuoteTick_t *tick);
+void orderbook_deltas_recycle(struct OrderBookDeltas_API deltas);
+
+/**
+ * Returns the statistics of the delta buffer pool of the calling thread.
+ */
+PoolStats orderbook_deltas_pool_stats(void);
+
+struct InstrumentId_t orderbook_deltas_instrument_id(const struct OrderBookDeltas_API *deltas);
+
+uintptr_t orderbook_deltas_len(const struct OrderBookDeltas_API *deltas);
+
+/**
+ * Returns a clone of the delta at the given `index`.
+ *
+ * # Panics
+ *
+ * - If `index` is out of bounds.
+ */
+struct OrderBookDelta_t orderbook_deltas_get(const struct OrderBookDeltas_API *deltas,
+                                             uintptr_t index);
+
+uint8_t orderbook_deltas_flags(const struct OrderBookDeltas_API *deltas);
+
+uint64_t orderbook_deltas_sequence(const struct OrderBookDeltas_API *deltas);
+
+uint64_t orderbook_deltas_ts_event(const struct OrderBookDeltas_API *deltas);
+
+uint64_t orderbook_deltas_ts_init(const struct OrderBookDeltas_API *deltas);
+
+/**
+ * Returns an [`OrderBookDeltas`] as a C string pointer.
+ */
+const char *orderbook_deltas_to_cstr(const struct OrderBookDeltas_API *deltas);
+
+/**
+ * Creates a new [`OrderBookSnapshot`] from C arrays of bid and ask orders.
+ *
+ * # Safety
+ *
+ * - Assumes `bids` points to a valid array of at least `bids_len` [`BookOrder`]s.
+ * - Assumes `asks` points to a valid array of at least `asks_len` [`BookOrder`]s.
+ */
+struct OrderBookSnapshot_API orderbook_snapshot_new(struct InstrumentId_t instrument_id,
+                                                    const struct BookOrder_t *bids,
+                                                    uintptr_t bids_len,
+                                                    const struct BookOrder_t *asks,
+                                                    uintptr_t asks_len,
+                                                    uint64_t sequence,
+                                                    uint64_t ts_event,
+                                                    uint64_t ts_init);
+
+void orderbook_snapshot_drop(struct OrderBookSnapshot_API snapshot);
+
+struct InstrumentId_t orderbook_snapshot_instrument_id(const struct OrderBookSnapshot_API *snapshot);
+
+uintptr_t orderbook_snapshot_bids_len(const struct OrderBookSnapshot_API *snapshot);
+
+uintptr_t orderbook_snapshot_asks_len(const struct OrderBookSnapshot_API *snapshot);
+
+/**
+ * Returns the bid order at the given `index`.
+ *
+ * # Panics
+ *
+ * - If `index` is out of bounds.
+ */
+struct BookOrder_t orderbook_snapshot_bid(const struct OrderBookSnapshot_API *snapshot,
+                                          uintptr_t index);
+
+/**
+ * Returns the ask order at the given `index`.
+ *
+ * # Panics
+ *
+ * - If `index` is out of bounds.
+ */
+struct BookOrder_t orderbook_snapshot_ask(const struct OrderBookSnapshot_API *snapshot,
+                                          uintptr_t index);
+
+uint64_t orderbook_snapshot_sequence(const struct OrderBookSnapshot_API *snapshot);
+
+uint64_t orderbook_snapshot_ts_event(const struct OrderBookSnapshot_API *snapshot);
+
+uint64_t orderbook_snapshot_ts_init(const struct OrderBookSnapshot_API *snapshot);
+
+/**
+ * Returns an [`OrderBookSnapshot`] as a C string pointer.
+ */
+const char *orderbook_snapshot_to_cstr(const struct OrderBookSnapshot_API *snapshot);
+
+struct QuoteTick_t quote_tick_new(struct InstrumentId_t instrument_id,
+                                  int64_t bid_price_raw,
+                                  int64_t ask_price_raw,
+                                  uint8_t bid_price_prec,
+                                  uint8_t ask_price_prec,
+                                  uint64_t bid_size_raw,
+                                  uint64_t ask_size_raw,
+                                  uint8_t bid_size_prec,
+                                  uint8_t ask_size_prec,
+                                  uint64_t ts_event,
+                                  uint64_t ts_init);
+
+/**
+ * Returns a vector of [`QuoteTick`]s for the given instrument, constructed from parallel
+ * arrays of raw values (all prices and sizes share the given precisions).
+ *
+ * The returned vector must be freed with `vec_quote_ticks_drop`, or `vec_quote_ticks_recycle`
+ * to reuse its allocation for the next call.
+ *
+ * # Safety
+ *
+ * - Assumes each array pointer is valid for at least `len` elements.
+ */
+CVec quote_tick_new_n(struct InstrumentId_t instrument_id,
+                      const int64_t *bid_prices_raw,
+                      const int64_t *ask_prices_raw,
+                      uint8_t price_prec,
+                      const uint64_t *bid_sizes_raw,
+                      const uint64_t *ask_sizes_raw,
+                      uint8_t size_prec,
+                      const uint64_t *ts_events,
+                      const uint64_t *ts_inits,
+                      uintptr_t len);
+
+void quote_tick_drop(struct QuoteTick_t tick);
+
+struct QuoteTick_t quote_tick_clone(const struct QuoteTick_t *tick);
+
+/**
+ * Returns a [`QuoteTick`] as a C string pointer.
+ */
+const char *quote_tick_to_cstr(const struct QuoteTick_t *tick);
 
 struct TradeTick_t trade_tick_new(struct InstrumentId_t instrument_id,
                                   int64_t price_raw,
@@ -1003,6 +1620,28 @@ struct TradeTick_t trade_tick_new(struct InstrumentId_t instrument_id,
                                   uint64_t ts_event,
                                   uint64_t ts_init);
 
+/**
+ * Returns a vector of [`TradeTick`]s for the given instrument, constructed from parallel
+ * arrays of raw values (all prices and sizes share the given precisions).
+ *
+ * The returned vector must be freed with `vec_trade_ticks_drop`, or `vec_trade_ticks_recycle`
+ * to reuse its allocation for the next call.
+ *
+ * # Safety
+ *
+ * - Assumes each array pointer is valid for at least `len` elements.
+ */
+CVec trade_tick_new_n(struct InstrumentId_t instrument_id,
+                      const int64_t *prices_raw,
+                      uint8_t price_prec,
+                      const uint64_t *sizes_raw,
+                      uint8_t size_prec,
+                      const enum AggressorSide *aggressor_sides,
+                      const struct TradeId_t *trade_ids,
+                      const uint64_t *ts_events,
+                      const uint64_t *ts_inits,
+                      uintptr_t len);
+
 void trade_tick_drop(struct TradeTick_t tick);
 
 struct TradeTick_t trade_tick_clone(const struct TradeTick_t *tick);
@@ -1016,259 +1655,402 @@ void data_drop(struct Data_t data);
 
 struct Data_t data_clone(const struct Data_t *data);
 
+void vec_quote_ticks_drop(CVec v);
+
+void vec_trade_ticks_drop(CVec v);
+
+/**
+ * Drops the ticks, returning the vector to the pool of the calling thread for reuse by
+ * `quote_tick_new_n`.
+ */
+void vec_quote_ticks_recycle(CVec v);
+
+/**
+ * Drops the ticks, returning the vector to the pool of the calling thread for reuse by
+ * `trade_tick_new_n`.
+ */
+void vec_trade_ticks_recycle(CVec v);
+
+/**
+ * Returns the statistics of the quote tick vector pool of the calling thread.
+ */
+PoolStats vec_quote_ticks_pool_stats(void);
+
+/**
+ * Returns the statistics of the trade tick vector pool of the calling thread.
+ */
+PoolStats vec_trade_ticks_pool_stats(void);
+
 const char *account_type_to_cstr(enum AccountType value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`AccountType`].
  */
-enum AccountType account_type_from_cstr(const char *ptr);
+uint8_t account_type_try_from_cstr(const char *ptr, enum AccountType *out);
 
 const char *aggregation_source_to_cstr(enum AggregationSource value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`AggregationSource`].
  */
-enum AggregationSource aggregation_source_from_cstr(const char *ptr);
+uint8_t aggregation_source_try_from_cstr(const char *ptr, enum AggregationSource *out);
 
 const char *aggressor_side_to_cstr(enum AggressorSide value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`AggressorSide`].
  */
-enum AggressorSide aggressor_side_from_cstr(const char *ptr);
+uint8_t aggressor_side_try_from_cstr(const char *ptr, enum AggressorSide *out);
 
 const char *asset_class_to_cstr(enum AssetClass value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`AssetClass`].
  */
-enum AssetClass asset_class_from_cstr(const char *ptr);
+uint8_t asset_class_try_from_cstr(const char *ptr, enum AssetClass *out);
 
 const char *asset_type_to_cstr(enum AssetType value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`AssetType`].
  */
-enum AssetType asset_type_from_cstr(const char *ptr);
+uint8_t asset_type_try_from_cstr(const char *ptr, enum AssetType *out);
 
 const char *bar_aggregation_to_cstr(uint8_t value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`BarAggregation`].
  */
-uint8_t bar_aggregation_from_cstr(const char *ptr);
+uint8_t bar_aggregation_try_from_cstr(const char *ptr, uint8_t *out);
 
 const char *book_action_to_cstr(enum BookAction value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`BookAction`].
+ */
+uint8_t book_action_try_from_cstr(const char *ptr, enum BookAction *out);
+
+const char *book_checksum_type_to_cstr(enum BookChecksumType value);
+
+/**
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`BookChecksumType`].
  */
-enum BookAction book_action_from_cstr(const char *ptr);
+uint8_t book_checksum_type_try_from_cstr(const char *ptr, enum BookChecksumType *out);
 
 const char *book_type_to_cstr(enum BookType value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`BookType`].
  */
-enum BookType book_type_from_cstr(const char *ptr);
+uint8_t book_type_try_from_cstr(const char *ptr, enum BookType *out);
 
 const char *contingency_type_to_cstr(enum ContingencyType value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`ContingencyType`].
  */
-enum ContingencyType contingency_type_from_cstr(const char *ptr);
+uint8_t contingency_type_try_from_cstr(const char *ptr, enum ContingencyType *out);
 
 const char *currency_type_to_cstr(enum CurrencyType value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`CurrencyType`].
  */
-enum CurrencyType currency_type_from_cstr(const char *ptr);
+uint8_t currency_type_try_from_cstr(const char *ptr, enum CurrencyType *out);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`InstrumentCloseType`].
  */
-enum InstrumentCloseType instrument_close_type_from_cstr(const char *ptr);
+uint8_t instrument_close_type_try_from_cstr(const char *ptr, enum InstrumentCloseType *out);
 
 const char *instrument_close_type_to_cstr(enum InstrumentCloseType value);
 
 const char *liquidity_side_to_cstr(enum LiquiditySide value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`LiquiditySide`].
  */
-enum LiquiditySide liquidity_side_from_cstr(const char *ptr);
+uint8_t liquidity_side_try_from_cstr(const char *ptr, enum LiquiditySide *out);
 
 const char *market_status_to_cstr(enum MarketStatus value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`MarketStatus`].
  */
-enum MarketStatus market_status_from_cstr(const char *ptr);
+uint8_t market_status_try_from_cstr(const char *ptr, enum MarketStatus *out);
 
 const char *oms_type_to_cstr(enum OmsType value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`OmsType`].
  */
-enum OmsType oms_type_from_cstr(const char *ptr);
+uint8_t oms_type_try_from_cstr(const char *ptr, enum OmsType *out);
 
 const char *option_kind_to_cstr(enum OptionKind value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`OptionKind`].
  */
-enum OptionKind option_kind_from_cstr(const char *ptr);
+uint8_t option_kind_try_from_cstr(const char *ptr, enum OptionKind *out);
 
 const char *order_side_to_cstr(enum OrderSide value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`OrderSide`].
  */
-enum OrderSide order_side_from_cstr(const char *ptr);
+uint8_t order_side_try_from_cstr(const char *ptr, enum OrderSide *out);
 
 const char *order_status_to_cstr(enum OrderStatus value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`OrderStatus`].
  */
-enum OrderStatus order_status_from_cstr(const char *ptr);
+uint8_t order_status_try_from_cstr(const char *ptr, enum OrderStatus *out);
 
 const char *order_type_to_cstr(enum OrderType value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`OrderType`].
  */
-enum OrderType order_type_from_cstr(const char *ptr);
+uint8_t order_type_try_from_cstr(const char *ptr, enum OrderType *out);
 
 const char *position_side_to_cstr(enum PositionSide value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`PositionSide`].
  */
-enum PositionSide position_side_from_cstr(const char *ptr);
+uint8_t position_side_try_from_cstr(const char *ptr, enum PositionSide *out);
 
 const char *price_type_to_cstr(enum PriceType value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`PriceType`].
  */
-enum PriceType price_type_from_cstr(const char *ptr);
+uint8_t price_type_try_from_cstr(const char *ptr, enum PriceType *out);
 
 const char *time_in_force_to_cstr(enum TimeInForce value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`TimeInForce`].
  */
-enum TimeInForce time_in_force_from_cstr(const char *ptr);
+uint8_t time_in_force_try_from_cstr(const char *ptr, enum TimeInForce *out);
 
 const char *trading_state_to_cstr(enum TradingState value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`TradingState`].
  */
-enum TradingState trading_state_from_cstr(const char *ptr);
+uint8_t trading_state_try_from_cstr(const char *ptr, enum TradingState *out);
 
 const char *trailing_offset_type_to_cstr(enum TrailingOffsetType value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`TrailingOffsetType`].
  */
-enum TrailingOffsetType trailing_offset_type_from_cstr(const char *ptr);
+uint8_t trailing_offset_type_try_from_cstr(const char *ptr, enum TrailingOffsetType *out);
 
 const char *trigger_type_to_cstr(enum TriggerType value);
 
 /**
- * Returns an enum from a Python string.
+ * Returns an enum from a Python string, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`TriggerType`].
  */
-enum TriggerType trigger_type_from_cstr(const char *ptr);
+uint8_t trigger_type_try_from_cstr(const char *ptr, enum TriggerType *out);
 
 /**
+ * Returns a new [`OrderDenied`] event, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
  * # Safety
  *
  * - Assumes `reason_ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for an [`OrderDenied`].
  */
-struct OrderDenied_t order_denied_new(struct TraderId_t trader_id,
-                                      struct StrategyId_t strategy_id,
-                                      struct InstrumentId_t instrument_id,
-                                      struct ClientOrderId_t client_order_id,
-                                      const char *reason_ptr,
-                                      UUID4_t event_id,
-                                      uint64_t ts_event,
-                                      uint64_t ts_init);
+uint8_t order_denied_new(struct TraderId_t trader_id,
+                         struct StrategyId_t strategy_id,
+                         struct InstrumentId_t instrument_id,
+                         struct ClientOrderId_t client_order_id,
+                         const char *reason_ptr,
+                         UUID4_t event_id,
+                         uint64_t ts_event,
+                         uint64_t ts_init,
+                         struct OrderDenied_t *out);
 
 /**
  * Frees the memory for the given `event` by dropping.
@@ -1279,14 +2061,200 @@ struct OrderDenied_t order_denied_clone(const struct OrderDenied_t *event);
 
 const char *order_denied_reason_to_cstr(const struct OrderDenied_t *event);
 
+struct OrderSubmitted order_submitted_new(struct TraderId_t trader_id,
+                                          struct StrategyId_t strategy_id,
+                                          struct InstrumentId_t instrument_id,
+                                          struct ClientOrderId_t client_order_id,
+                                          struct AccountId_t account_id,
+                                          UUID4_t event_id,
+                                          uint64_t ts_event,
+                                          uint64_t ts_init);
+
+/**
+ * Frees the memory for the given `event` by dropping.
+ */
+void order_submitted_drop(struct OrderSubmitted event);
+
+struct OrderSubmitted order_submitted_clone(const struct OrderSubmitted *event);
+
+struct OrderAccepted order_accepted_new(struct TraderId_t trader_id,
+                                        struct StrategyId_t strategy_id,
+                                        struct InstrumentId_t instrument_id,
+                                        struct ClientOrderId_t client_order_id,
+                                        struct VenueOrderId_t venue_order_id,
+                                        struct AccountId_t account_id,
+                                        UUID4_t event_id,
+                                        uint64_t ts_event,
+                                        uint64_t ts_init,
+                                        uint8_t reconciliation);
+
+/**
+ * Frees the memory for the given `event` by dropping.
+ */
+void order_accepted_drop(struct OrderAccepted event);
+
+struct OrderAccepted order_accepted_clone(const struct OrderAccepted *event);
+
+/**
+ * Returns a new [`OrderRejected`] event, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `reason_ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for an [`OrderRejected`].
+ */
+uint8_t order_rejected_new(struct TraderId_t trader_id,
+                           struct StrategyId_t strategy_id,
+                           struct InstrumentId_t instrument_id,
+                           struct ClientOrderId_t client_order_id,
+                           struct VenueOrderId_t venue_order_id,
+                           struct AccountId_t account_id,
+                           const char *reason_ptr,
+                           UUID4_t event_id,
+                           uint64_t ts_event,
+                           uint64_t ts_init,
+                           uint8_t reconciliation,
+                           struct OrderRejected *out);
+
+/**
+ * Frees the memory for the given `event` by dropping.
+ */
+void order_rejected_drop(struct OrderRejected event);
+
+struct OrderRejected order_rejected_clone(const struct OrderRejected *event);
+
+const char *order_rejected_reason_to_cstr(const struct OrderRejected *event);
+
+/**
+ * # Safety
+ *
+ * - Assumes `venue_order_id` and `account_id` are either null or valid pointers.
+ */
+struct OrderCanceled order_canceled_new(struct TraderId_t trader_id,
+                                        struct StrategyId_t strategy_id,
+                                        struct InstrumentId_t instrument_id,
+                                        struct ClientOrderId_t client_order_id,
+                                        const struct VenueOrderId_t *venue_order_id,
+                                        const struct AccountId_t *account_id,
+                                        UUID4_t event_id,
+                                        uint64_t ts_event,
+                                        uint64_t ts_init,
+                                        uint8_t reconciliation);
+
+/**
+ * Frees the memory for the given `event` by dropping.
+ */
+void order_canceled_drop(struct OrderCanceled event);
+
+struct OrderCanceled order_canceled_clone(const struct OrderCanceled *event);
+
+/**
+ * # Safety
+ *
+ * - Assumes `position_id` is either null or a valid pointer.
+ */
+struct OrderFilled order_filled_new(struct TraderId_t trader_id,
+                                    struct StrategyId_t strategy_id,
+                                    struct InstrumentId_t instrument_id,
+                                    struct ClientOrderId_t client_order_id,
+                                    struct VenueOrderId_t venue_order_id,
+                                    struct AccountId_t account_id,
+                                    struct TradeId_t trade_id,
+                                    const struct PositionId_t *position_id,
+                                    enum OrderSide order_side,
+                                    enum OrderType order_type,
+                                    struct Quantity_t last_qty,
+                                    struct Price_t last_px,
+                                    struct Currency_t currency,
+                                    struct Money_t commission,
+                                    enum LiquiditySide liquidity_side,
+                                    UUID4_t event_id,
+                                    uint64_t ts_event,
+                                    uint64_t ts_init,
+                                    uint8_t reconciliation);
+
+/**
+ * Frees the memory for the given `event` by dropping.
+ */
+void order_filled_drop(struct OrderFilled event);
+
+struct OrderFilled order_filled_clone(const struct OrderFilled *event);
+
+/**
+ * Returns the [`PositionId`] for the fill, or a null pointer if there is none.
+ *
+ * The returned pointer borrows from `event`, and is only valid while `event` is alive.
+ */
+const struct PositionId_t *order_filled_position_id(const struct OrderFilled *event);
+
+uint8_t order_filled_is_buy(const struct OrderFilled *event);
+
+uint8_t order_filled_is_sell(const struct OrderFilled *event);
+
+struct ClientOrderIdGenerator_API client_order_id_generator_new(struct TraderId_t trader_id,
+                                                                struct StrategyId_t strategy_id,
+                                                                uintptr_t initial_count);
+
+void client_order_id_generator_drop(struct ClientOrderIdGenerator_API generator);
+
+uintptr_t client_order_id_generator_count(const struct ClientOrderIdGenerator_API *generator);
+
+void client_order_id_generator_set_count(struct ClientOrderIdGenerator_API *generator,
+                                         uintptr_t count);
+
+void client_order_id_generator_reset(struct ClientOrderIdGenerator_API *generator);
+
+struct ClientOrderId_t client_order_id_generator_generate(struct ClientOrderIdGenerator_API *generator,
+                                                          uint64_t ts_now);
+
+struct OrderListIdGenerator_API order_list_id_generator_new(struct TraderId_t trader_id,
+                                                            struct StrategyId_t strategy_id,
+                                                            uintptr_t initial_count);
+
+void order_list_id_generator_drop(struct OrderListIdGenerator_API generator);
+
+uintptr_t order_list_id_generator_count(const struct OrderListIdGenerator_API *generator);
+
+void order_list_id_generator_set_count(struct OrderListIdGenerator_API *generator, uintptr_t count);
+
+void order_list_id_generator_reset(struct OrderListIdGenerator_API *generator);
+
+struct OrderListId_t order_list_id_generator_generate(struct OrderListIdGenerator_API *generator,
+                                                      uint64_t ts_now);
+
+struct PositionIdGenerator_API position_id_generator_new(struct TraderId_t trader_id);
+
+void position_id_generator_drop(struct PositionIdGenerator_API generator);
+
+uintptr_t position_id_generator_count(const struct PositionIdGenerator_API *generator,
+                                      struct StrategyId_t strategy_id);
+
+void position_id_generator_set_count(struct PositionIdGenerator_API *generator,
+                                     struct StrategyId_t strategy_id,
+                                     uintptr_t count);
+
+void position_id_generator_reset(struct PositionIdGenerator_API *generator);
+
+struct PositionId_t position_id_generator_generate(struct PositionIdGenerator_API *generator,
+                                                   struct StrategyId_t strategy_id,
+                                                   uint8_t flipped,
+                                                   uint64_t ts_now);
+
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`AccountId`].
  */
-struct AccountId_t account_id_new(const char *ptr);
+uint8_t account_id_try_new(const char *ptr, struct AccountId_t *out);
 
 struct AccountId_t account_id_clone(const struct AccountId_t *account_id);
 
@@ -1305,13 +2273,17 @@ uint8_t account_id_eq(const struct AccountId_t *lhs, const struct AccountId_t *r
 uint64_t account_id_hash(const struct AccountId_t *account_id);
 
 /**
- * Returns a Nautilus identifier from C string pointer.
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`ClientId`].
  */
-struct ClientId_t client_id_new(const char *ptr);
+uint8_t client_id_try_new(const char *ptr, struct ClientId_t *out);
 
 struct ClientId_t client_id_clone(const struct ClientId_t *client_id);
 
@@ -1330,13 +2302,17 @@ uint8_t client_id_eq(const struct ClientId_t *lhs, const struct ClientId_t *rhs)
 uint64_t client_id_hash(const struct ClientId_t *client_id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`ClientOrderId`].
  */
-struct ClientOrderId_t client_order_id_new(const char *ptr);
+uint8_t client_order_id_try_new(const char *ptr, struct ClientOrderId_t *out);
 
 struct ClientOrderId_t client_order_id_clone(const struct ClientOrderId_t *client_order_id);
 
@@ -1355,13 +2331,17 @@ uint8_t client_order_id_eq(const struct ClientOrderId_t *lhs, const struct Clien
 uint64_t client_order_id_hash(const struct ClientOrderId_t *client_order_id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`ComponentId`].
  */
-struct ComponentId_t component_id_new(const char *ptr);
+uint8_t component_id_try_new(const char *ptr, struct ComponentId_t *out);
 
 struct ComponentId_t component_id_clone(const struct ComponentId_t *component_id);
 
@@ -1380,13 +2360,17 @@ uint8_t component_id_eq(const struct ComponentId_t *lhs, const struct ComponentI
 uint64_t component_id_hash(const struct ComponentId_t *component_id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`ExecAlgorithmId`].
  */
-struct ExecAlgorithmId_t exec_algorithm_id_new(const char *ptr);
+uint8_t exec_algorithm_id_try_new(const char *ptr, struct ExecAlgorithmId_t *out);
 
 struct ExecAlgorithmId_t exec_algorithm_id_clone(const struct ExecAlgorithmId_t *exec_algorithm_id);
 
@@ -1408,13 +2392,27 @@ uint64_t exec_algorithm_id_hash(const struct ExecAlgorithmId_t *exec_algorithm_i
 struct InstrumentId_t instrument_id_new(const struct Symbol_t *symbol, const struct Venue_t *venue);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for an [`InstrumentId`].
+ */
+uint8_t instrument_id_try_from_cstr(const char *ptr, struct InstrumentId_t *out);
+
+/**
+ * Returns a clone of the [`Symbol`] component of the `instrument_id`.
+ */
+struct Symbol_t instrument_id_symbol(const struct InstrumentId_t *instrument_id);
+
+/**
+ * Returns a clone of the [`Venue`] component of the `instrument_id`.
  */
-struct InstrumentId_t instrument_id_new_from_cstr(const char *ptr);
+struct Venue_t instrument_id_venue(const struct InstrumentId_t *instrument_id);
 
 struct InstrumentId_t instrument_id_clone(const struct InstrumentId_t *instrument_id);
 
@@ -1433,13 +2431,27 @@ uint8_t instrument_id_eq(const struct InstrumentId_t *lhs, const struct Instrume
 uint64_t instrument_id_hash(const struct InstrumentId_t *instrument_id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns the number of distinct identifier values held by the interning pool.
+ */
+uintptr_t identifier_pool_count(void);
+
+/**
+ * Removes all unreferenced identifier values from the interning pool.
+ */
+uintptr_t identifier_pool_purge(void);
+
+/**
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`OrderListId`].
  */
-struct OrderListId_t order_list_id_new(const char *ptr);
+uint8_t order_list_id_try_new(const char *ptr, struct OrderListId_t *out);
 
 struct OrderListId_t order_list_id_clone(const struct OrderListId_t *order_list_id);
 
@@ -1458,13 +2470,17 @@ uint8_t order_list_id_eq(const struct OrderListId_t *lhs, const struct OrderList
 uint64_t order_list_id_hash(const struct OrderListId_t *order_list_id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`PositionId`].
  */
-struct PositionId_t position_id_new(const char *ptr);
+uint8_t position_id_try_new(const char *ptr, struct PositionId_t *out);
 
 struct PositionId_t position_id_clone(const struct PositionId_t *position_id);
 
@@ -1483,13 +2499,17 @@ uint8_t position_id_eq(const struct PositionId_t *lhs, const struct PositionId_t
 uint64_t position_id_hash(const struct PositionId_t *position_id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`StrategyId`].
  */
-struct StrategyId_t strategy_id_new(const char *ptr);
+uint8_t strategy_id_try_new(const char *ptr, struct StrategyId_t *out);
 
 struct StrategyId_t strategy_id_clone(const struct StrategyId_t *strategy_id);
 
@@ -1504,13 +2524,17 @@ void strategy_id_drop(struct StrategyId_t strategy_id);
 const char *strategy_id_to_cstr(const struct StrategyId_t *strategy_id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`Symbol`].
  */
-struct Symbol_t symbol_new(const char *ptr);
+uint8_t symbol_try_new(const char *ptr, struct Symbol_t *out);
 
 struct Symbol_t symbol_clone(const struct Symbol_t *symbol);
 
@@ -1528,14 +2552,74 @@ uint8_t symbol_eq(const struct Symbol_t *lhs, const struct Symbol_t *rhs);
 
 uint64_t symbol_hash(const struct Symbol_t *symbol);
 
+struct SymbologyNormalizer_API symbology_normalizer_new(void);
+
+void symbology_normalizer_drop(struct SymbologyNormalizer_API normalizer);
+
+/**
+ * Registers a [`SlashPairRule`] for the `venue`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case no rule is registered).
+ *
+ * # Safety
+ *
+ * - Assumes `quotes_ptr` is a valid C string pointer of a JSON format list of strings.
+ */
+uint8_t symbology_normalizer_register_slash_pair_rule(struct SymbologyNormalizer_API *normalizer,
+                                                      const struct Venue_t *venue,
+                                                      const char *quotes_ptr);
+
+void symbology_normalizer_register_futures_month_code_rule(struct SymbologyNormalizer_API *normalizer,
+                                                           const struct Venue_t *venue,
+                                                           uint16_t reference_year);
+
+void symbology_normalizer_register_occ_option_rule(struct SymbologyNormalizer_API *normalizer,
+                                                   const struct Venue_t *venue);
+
+/**
+ * Returns the Nautilus symbol for the venue-native symbol at `native_ptr`, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `native_ptr` is a valid C string pointer, or null.
+ * - Assumes `out` is a valid pointer to memory for a [`Symbol`].
+ */
+uint8_t symbology_normalizer_normalize(const struct SymbologyNormalizer_API *normalizer,
+                                       const struct Venue_t *venue,
+                                       const char *native_ptr,
+                                       struct Symbol_t *out);
+
+/**
+ * Returns the venue-native symbol for the `symbol` as a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a C string pointer.
+ */
+uint8_t symbology_normalizer_to_native(const struct SymbologyNormalizer_API *normalizer,
+                                       const struct Venue_t *venue,
+                                       const struct Symbol_t *symbol,
+                                       const char **out);
+
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`TradeId`].
  */
-struct TradeId_t trade_id_new(const char *ptr);
+uint8_t trade_id_try_new(const char *ptr, struct TradeId_t *out);
 
 struct TradeId_t trade_id_clone(const struct TradeId_t *trade_id);
 
@@ -1554,34 +2638,66 @@ uint8_t trade_id_eq(const struct TradeId_t *lhs, const struct TradeId_t *rhs);
 uint64_t trade_id_hash(const struct TradeId_t *trade_id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns an inline trade ID from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for an [`InlineTradeId`].
  */
-struct TraderId_t trader_id_new(const char *ptr);
-
-struct TraderId_t trader_id_clone(const struct TraderId_t *trader_id);
+uint8_t inline_trade_id_try_new(const char *ptr, struct InlineTradeId *out);
 
 /**
- * Frees the memory for the given `trader_id` by dropping.
+ * Returns a pointer to the [`InlineTradeId`] value as a C string.
+ *
+ * The pointer is borrowed from `trade_id` and must not be freed.
  */
-void trader_id_drop(struct TraderId_t trader_id);
+const char *inline_trade_id_to_cstr(const struct InlineTradeId *trade_id);
 
-/**
- * Returns a [`TraderId`] as a C string pointer.
- */
-const char *trader_id_to_cstr(const struct TraderId_t *trader_id);
+uint8_t inline_trade_id_eq(const struct InlineTradeId *lhs, const struct InlineTradeId *rhs);
+
+uint64_t inline_trade_id_hash(const struct InlineTradeId *trade_id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`TraderId`].
+ */
+uint8_t trader_id_try_new(const char *ptr, struct TraderId_t *out);
+
+struct TraderId_t trader_id_clone(const struct TraderId_t *trader_id);
+
+/**
+ * Frees the memory for the given `trader_id` by dropping.
+ */
+void trader_id_drop(struct TraderId_t trader_id);
+
+/**
+ * Returns a [`TraderId`] as a C string pointer.
+ */
+const char *trader_id_to_cstr(const struct TraderId_t *trader_id);
+
+/**
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`Venue`].
  */
-struct Venue_t venue_new(const char *ptr);
+uint8_t venue_try_new(const char *ptr, struct Venue_t *out);
 
 struct Venue_t venue_clone(const struct Venue_t *venue);
 
@@ -1600,13 +2716,17 @@ uint8_t venue_eq(const struct Venue_t *lhs, const struct Venue_t *rhs);
 uint64_t venue_hash(const struct Venue_t *venue);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns a Nautilus identifier from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise the `IdentifierError` code (in which case
+ * nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`VenueOrderId`].
  */
-struct VenueOrderId_t venue_order_id_new(const char *ptr);
+uint8_t venue_order_id_try_new(const char *ptr, struct VenueOrderId_t *out);
 
 struct VenueOrderId_t venue_order_id_clone(const struct VenueOrderId_t *venue_order_id);
 
@@ -1622,15 +2742,273 @@ uint8_t venue_order_id_eq(const struct VenueOrderId_t *lhs, const struct VenueOr
 uint64_t venue_order_id_hash(const struct VenueOrderId_t *venue_order_id);
 
 /**
+ * Writes a new [`CryptoPerpetual`] to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `max_quantity`, `min_quantity`, `max_price` and `min_price` are each either
+ *   null or a valid pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`CryptoPerpetual_API`].
+ */
+uint8_t crypto_perpetual_new(struct InstrumentId_t id,
+                             struct Symbol_t native_symbol,
+                             struct Currency_t base_currency,
+                             struct Currency_t quote_currency,
+                             struct Currency_t settlement_currency,
+                             uint8_t is_inverse,
+                             uint8_t price_precision,
+                             uint8_t size_precision,
+                             struct Price_t price_increment,
+                             struct Quantity_t size_increment,
+                             const struct Quantity_t *max_quantity,
+                             const struct Quantity_t *min_quantity,
+                             const struct Price_t *max_price,
+                             const struct Price_t *min_price,
+                             double margin_init,
+                             double margin_maint,
+                             double maker_fee,
+                             double taker_fee,
+                             struct CryptoPerpetual_API *out);
+
+void crypto_perpetual_drop(struct CryptoPerpetual_API instrument);
+
+struct InstrumentId_t crypto_perpetual_id(const struct CryptoPerpetual_API *instrument);
+
+uint8_t crypto_perpetual_price_precision(const struct CryptoPerpetual_API *instrument);
+
+uint8_t crypto_perpetual_size_precision(const struct CryptoPerpetual_API *instrument);
+
+struct Price_t crypto_perpetual_make_price(const struct CryptoPerpetual_API *instrument,
+                                           double value);
+
+struct Quantity_t crypto_perpetual_make_qty(const struct CryptoPerpetual_API *instrument,
+                                            double value);
+
+struct Money_t crypto_perpetual_notional_value(const struct CryptoPerpetual_API *instrument,
+                                               struct Quantity_t quantity,
+                                               struct Price_t price);
+
+uint8_t crypto_perpetual_is_inverse(const struct CryptoPerpetual_API *instrument);
+
+struct Currency_t crypto_perpetual_settlement_currency(const struct CryptoPerpetual_API *instrument);
+
+/**
+ * Writes a new [`CurrencyPair`] to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `lot_size`, `max_quantity`, `min_quantity`, `max_price` and `min_price` are
+ *   each either null or a valid pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`CurrencyPair_API`].
+ */
+uint8_t currency_pair_new(struct InstrumentId_t id,
+                          struct Symbol_t native_symbol,
+                          struct Currency_t base_currency,
+                          struct Currency_t quote_currency,
+                          uint8_t price_precision,
+                          uint8_t size_precision,
+                          struct Price_t price_increment,
+                          struct Quantity_t size_increment,
+                          const struct Quantity_t *lot_size,
+                          const struct Quantity_t *max_quantity,
+                          const struct Quantity_t *min_quantity,
+                          const struct Price_t *max_price,
+                          const struct Price_t *min_price,
+                          double margin_init,
+                          double margin_maint,
+                          double maker_fee,
+                          double taker_fee,
+                          struct CurrencyPair_API *out);
+
+void currency_pair_drop(struct CurrencyPair_API instrument);
+
+struct InstrumentId_t currency_pair_id(const struct CurrencyPair_API *instrument);
+
+uint8_t currency_pair_price_precision(const struct CurrencyPair_API *instrument);
+
+uint8_t currency_pair_size_precision(const struct CurrencyPair_API *instrument);
+
+struct Price_t currency_pair_make_price(const struct CurrencyPair_API *instrument, double value);
+
+struct Quantity_t currency_pair_make_qty(const struct CurrencyPair_API *instrument, double value);
+
+struct Money_t currency_pair_notional_value(const struct CurrencyPair_API *instrument,
+                                            struct Quantity_t quantity,
+                                            struct Price_t price);
+
+/**
+ * Writes a new [`Equity`] to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `lot_size` is either null or a valid `Quantity` pointer.
+ * - Assumes `isin_ptr` is either null or a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for an [`Equity_API`].
+ */
+uint8_t equity_new(struct InstrumentId_t id,
+                   struct Symbol_t native_symbol,
+                   struct Currency_t currency,
+                   uint8_t price_precision,
+                   struct Price_t price_increment,
+                   struct Quantity_t multiplier,
+                   const struct Quantity_t *lot_size,
+                   const char *isin_ptr,
+                   double margin_init,
+                   double margin_maint,
+                   double maker_fee,
+                   double taker_fee,
+                   struct Equity_API *out);
+
+void equity_drop(struct Equity_API instrument);
+
+struct InstrumentId_t equity_id(const struct Equity_API *instrument);
+
+uint8_t equity_price_precision(const struct Equity_API *instrument);
+
+uint8_t equity_size_precision(const struct Equity_API *instrument);
+
+struct Price_t equity_make_price(const struct Equity_API *instrument, double value);
+
+struct Quantity_t equity_make_qty(const struct Equity_API *instrument, double value);
+
+struct Money_t equity_notional_value(const struct Equity_API *instrument,
+                                     struct Quantity_t quantity,
+                                     struct Price_t price);
+
+/**
+ * Writes a new [`FuturesContract`] to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `lot_size` is either null or a valid `Quantity` pointer.
+ * - Assumes `underlying_ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`FuturesContract_API`].
+ */
+uint8_t futures_contract_new(struct InstrumentId_t id,
+                             struct Symbol_t native_symbol,
+                             enum AssetClass asset_class,
+                             struct Currency_t currency,
+                             uint8_t price_precision,
+                             struct Price_t price_increment,
+                             struct Quantity_t multiplier,
+                             const struct Quantity_t *lot_size,
+                             const char *underlying_ptr,
+                             uint64_t activation_ns,
+                             uint64_t expiration_ns,
+                             double margin_init,
+                             double margin_maint,
+                             double maker_fee,
+                             double taker_fee,
+                             struct FuturesContract_API *out);
+
+void futures_contract_drop(struct FuturesContract_API instrument);
+
+struct InstrumentId_t futures_contract_id(const struct FuturesContract_API *instrument);
+
+uint8_t futures_contract_price_precision(const struct FuturesContract_API *instrument);
+
+uint8_t futures_contract_size_precision(const struct FuturesContract_API *instrument);
+
+struct Price_t futures_contract_make_price(const struct FuturesContract_API *instrument,
+                                           double value);
+
+struct Quantity_t futures_contract_make_qty(const struct FuturesContract_API *instrument,
+                                            double value);
+
+struct Money_t futures_contract_notional_value(const struct FuturesContract_API *instrument,
+                                               struct Quantity_t quantity,
+                                               struct Price_t price);
+
+uint64_t futures_contract_activation_ns(const struct FuturesContract_API *instrument);
+
+uint64_t futures_contract_expiration_ns(const struct FuturesContract_API *instrument);
+
+/**
+ * Writes a new [`OptionsContract`] to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `lot_size` is either null or a valid `Quantity` pointer.
+ * - Assumes `underlying_ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for an [`OptionsContract_API`].
+ */
+uint8_t options_contract_new(struct InstrumentId_t id,
+                             struct Symbol_t native_symbol,
+                             enum AssetClass asset_class,
+                             struct Currency_t currency,
+                             uint8_t price_precision,
+                             struct Price_t price_increment,
+                             struct Quantity_t multiplier,
+                             const struct Quantity_t *lot_size,
+                             const char *underlying_ptr,
+                             enum OptionKind kind,
+                             uint64_t activation_ns,
+                             uint64_t expiration_ns,
+                             struct Price_t strike_price,
+                             double margin_init,
+                             double margin_maint,
+                             double maker_fee,
+                             double taker_fee,
+                             struct OptionsContract_API *out);
+
+void options_contract_drop(struct OptionsContract_API instrument);
+
+struct InstrumentId_t options_contract_id(const struct OptionsContract_API *instrument);
+
+uint8_t options_contract_price_precision(const struct OptionsContract_API *instrument);
+
+uint8_t options_contract_size_precision(const struct OptionsContract_API *instrument);
+
+struct Price_t options_contract_make_price(const struct OptionsContract_API *instrument,
+                                           double value);
+
+struct Quantity_t options_contract_make_qty(const struct OptionsContract_API *instrument,
+                                            double value);
+
+struct Money_t options_contract_notional_value(const struct OptionsContract_API *instrument,
+                                               struct Quantity_t quantity,
+                                               struct Price_t price);
+
+enum OptionKind options_contract_kind(const struct OptionsContract_API *instrument);
+
+struct Price_t options_contract_strike_price(const struct OptionsContract_API *instrument);
+
+uint64_t options_contract_activation_ns(const struct OptionsContract_API *instrument);
+
+uint64_t options_contract_expiration_ns(const struct OptionsContract_API *instrument);
+
+/**
+ * Returns a new synthetic instrument, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
  * # Safety
  *
  * - Assumes `components_ptr` is a valid C string pointer of a JSON format list of strings.
  * - Assumes `formula_ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`SyntheticInstrument_API`].
  */
-struct SyntheticInstrument_API synthetic_instrument_new(struct Symbol_t symbol,
-                                                        uint8_t precision,
-                                                        const char *components_ptr,
-                                                        const char *formula_ptr);
+uint8_t synthetic_instrument_try_new(struct Symbol_t symbol,
+                                     uint8_t precision,
+                                     const char *components_ptr,
+                                     const char *formula_ptr,
+                                     struct SyntheticInstrument_API *out);
 
 void synthetic_instrument_drop(struct SyntheticInstrument_API synth);
 
@@ -1651,15 +3029,32 @@ uint8_t synthetic_instrument_is_valid_formula(const struct SyntheticInstrument_A
                                               const char *formula_ptr);
 
 /**
+ * Changes the formula of the synthetic instrument.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case the formula is unchanged).
+ *
  * # Safety
  *
  * - Assumes `formula_ptr` is a valid C string pointer.
  */
-void synthetic_instrument_change_formula(struct SyntheticInstrument_API *synth,
-                                         const char *formula_ptr);
+uint8_t synthetic_instrument_try_change_formula(struct SyntheticInstrument_API *synth,
+                                                const char *formula_ptr);
 
-struct Price_t synthetic_instrument_calculate(struct SyntheticInstrument_API *synth,
-                                              const CVec *inputs_ptr);
+/**
+ * Calculates the price of the synthetic instrument from the given `inputs`, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `inputs_ptr` is a valid [`CVec`] of `f64` values.
+ * - Assumes `out` is a valid pointer to memory for a [`Price`].
+ */
+uint8_t synthetic_instrument_try_calculate(struct SyntheticInstrument_API *synth,
+                                           const CVec *inputs_ptr,
+                                           struct Price_t *out);
 
 struct OrderBook_API orderbook_new(struct InstrumentId_t instrument_id, enum BookType book_type);
 
@@ -1700,21 +3095,86 @@ void orderbook_clear_asks(struct OrderBook_API *book, uint64_t ts_event, uint64_
 
 void orderbook_apply_delta(struct OrderBook_API *book, struct OrderBookDelta_t delta);
 
+void orderbook_apply_deltas(struct OrderBook_API *book, const struct OrderBookDeltas_API *deltas);
+
+void orderbook_apply_snapshot(struct OrderBook_API *book,
+                              const struct OrderBookSnapshot_API *snapshot);
+
 uint8_t orderbook_has_bid(struct OrderBook_API *book);
 
 uint8_t orderbook_has_ask(struct OrderBook_API *book);
 
-struct Price_t orderbook_best_bid_price(struct OrderBook_API *book);
+/**
+ * Returns the best bid price, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Price`].
+ */
+uint8_t orderbook_best_bid_price(const struct OrderBook_API *book, struct Price_t *out);
 
-struct Price_t orderbook_best_ask_price(struct OrderBook_API *book);
+/**
+ * Returns the best ask price, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Price`].
+ */
+uint8_t orderbook_best_ask_price(const struct OrderBook_API *book, struct Price_t *out);
 
-struct Quantity_t orderbook_best_bid_size(struct OrderBook_API *book);
+/**
+ * Returns the best bid size, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+ */
+uint8_t orderbook_best_bid_size(const struct OrderBook_API *book, struct Quantity_t *out);
 
-struct Quantity_t orderbook_best_ask_size(struct OrderBook_API *book);
+/**
+ * Returns the best ask size, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+ */
+uint8_t orderbook_best_ask_size(const struct OrderBook_API *book, struct Quantity_t *out);
 
-double orderbook_spread(struct OrderBook_API *book);
+/**
+ * Returns the top of book spread, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`f64`].
+ */
+uint8_t orderbook_spread(const struct OrderBook_API *book, double *out);
 
-double orderbook_midpoint(struct OrderBook_API *book);
+/**
+ * Returns the mid point, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`f64`].
+ */
+uint8_t orderbook_midpoint(const struct OrderBook_API *book, double *out);
 
 void orderbook_update_quote_tick(struct OrderBook_API *book, const struct QuoteTick_t *tick);
 
@@ -1722,7 +3182,95 @@ void orderbook_update_trade_tick(struct OrderBook_API *book, const struct TradeT
 
 CVec orderbook_simulate_fills(const struct OrderBook_API *book, struct BookOrder_t order);
 
-void orderbook_check_integrity(const struct OrderBook_API *book);
+/**
+ * Checks the integrity of the `book`.
+ *
+ * Returns zero if every check passes, otherwise a non-zero status code with the last
+ * error set.
+ */
+uint8_t orderbook_check_integrity(const struct OrderBook_API *book);
+
+/**
+ * Returns the aggregated price and size for up to `num_levels` bid levels.
+ *
+ * The returned vector must be freed with `vec_fills_drop`.
+ */
+CVec orderbook_bids_depth(const struct OrderBook_API *book, uintptr_t num_levels);
+
+/**
+ * Returns the aggregated price and size for up to `num_levels` ask levels.
+ *
+ * The returned vector must be freed with `vec_fills_drop`.
+ */
+CVec orderbook_asks_depth(const struct OrderBook_API *book, uintptr_t num_levels);
+
+/**
+ * Returns the size aggregated into `num_bands` price bands of `band_width` on the given
+ * `side`, starting from the band containing the best price.
+ *
+ * The returned vector must be freed with `vec_fills_drop`.
+ */
+CVec orderbook_depth_bands(const struct OrderBook_API *book,
+                           enum OrderSide side,
+                           struct Price_t band_width,
+                           uintptr_t num_bands);
+
+double orderbook_get_quantity_for_price(const struct OrderBook_API *book,
+                                        struct Price_t price,
+                                        enum OrderSide side);
+
+/**
+ * Returns the volume imbalance over the top `depth` levels (0.0 if the book is empty).
+ */
+double orderbook_volume_imbalance(const struct OrderBook_API *book, uintptr_t depth);
+
+/**
+ * Returns the size weighted microprice, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`f64`].
+ */
+uint8_t orderbook_microprice(const struct OrderBook_API *book, double *out);
+
+/**
+ * Returns the average fill price from consuming `qty` of liquidity for an order on the
+ * given `order_side`, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`f64`].
+ */
+uint8_t orderbook_get_avg_px_for_quantity(const struct OrderBook_API *book,
+                                          struct Quantity_t qty,
+                                          enum OrderSide order_side,
+                                          double *out);
+
+uint32_t orderbook_checksum(const struct OrderBook_API *book, uintptr_t depth);
+
+/**
+ * Returns 1 if the checksum over the top `depth` levels matches `expected`, otherwise 0.
+ */
+uint8_t orderbook_check_checksum(const struct OrderBook_API *book,
+                                 uint32_t expected,
+                                 uintptr_t depth);
+
+void orderbook_set_checksum_type(struct OrderBook_API *book, enum BookChecksumType checksum_type);
+
+uint32_t orderbook_venue_checksum(const struct OrderBook_API *book,
+                                  enum BookChecksumType checksum_type);
+
+/**
+ * Returns 1 if the book matches the `expected` venue checksum, or has no checksum type,
+ * otherwise 0.
+ */
+uint8_t orderbook_verify_checksum(const struct OrderBook_API *book, uint32_t expected);
 
 void vec_fills_drop(CVec v);
 
@@ -1731,19 +3279,325 @@ void vec_fills_drop(CVec v);
  */
 const char *orderbook_pprint_to_cstr(const struct OrderBook_API *book, uintptr_t num_levels);
 
+struct Order_API order_new(const struct OrderInitialized *init);
+
+void order_drop(struct Order_API order);
+
+enum OrderStatus order_status(const struct Order_API *order);
+
+enum OrderSide order_side(const struct Order_API *order);
+
+enum OrderType order_type(const struct Order_API *order);
+
+struct ClientOrderId_t order_client_order_id(const struct Order_API *order);
+
+struct InstrumentId_t order_instrument_id(const struct Order_API *order);
+
+struct Quantity_t order_quantity(const struct Order_API *order);
+
+struct Quantity_t order_filled_qty(const struct Order_API *order);
+
+struct Quantity_t order_leaves_qty(const struct Order_API *order);
+
 /**
- * Returns a [`Currency`] from pointers and primitives.
+ * Returns the average fill price for the order, or 0.0 if it has not been filled.
+ */
+double order_avg_px(const struct Order_API *order);
+
+uintptr_t order_event_count(const struct Order_API *order);
+
+uint8_t order_is_open(const struct Order_API *order);
+
+uint8_t order_is_closed(const struct Order_API *order);
+
+uint8_t order_is_inflight(const struct Order_API *order);
+
+uint8_t order_is_pending_update(const struct Order_API *order);
+
+uint8_t order_is_pending_cancel(const struct Order_API *order);
+
+uint8_t order_apply_denied(struct Order_API *order, struct OrderDenied_t event);
+
+uint8_t order_apply_submitted(struct Order_API *order, struct OrderSubmitted event);
+
+uint8_t order_apply_accepted(struct Order_API *order, struct OrderAccepted event);
+
+uint8_t order_apply_rejected(struct Order_API *order, struct OrderRejected event);
+
+uint8_t order_apply_canceled(struct Order_API *order, struct OrderCanceled event);
+
+uint8_t order_apply_expired(struct Order_API *order, struct OrderExpired event);
+
+uint8_t order_apply_triggered(struct Order_API *order, struct OrderTriggered event);
+
+uint8_t order_apply_pending_update(struct Order_API *order, struct OrderPendingUpdate event);
+
+uint8_t order_apply_pending_cancel(struct Order_API *order, struct OrderPendingCancel event);
+
+uint8_t order_apply_modify_rejected(struct Order_API *order, struct OrderModifyRejected event);
+
+uint8_t order_apply_cancel_rejected(struct Order_API *order, struct OrderCancelRejected event);
+
+uint8_t order_apply_updated(struct Order_API *order, struct OrderUpdated event);
+
+/**
+ * Applies the fill `event` to the `order`, as either a partial or complete fill depending
+ * on the orders remaining quantity.
+ */
+uint8_t order_apply_filled(struct Order_API *order, struct OrderFilled event);
+
+/**
+ * Returns the metadata of the `order` as a JSON object, as a C string pointer.
+ */
+const char *order_metadata_to_cstr(const struct Order_API *order);
+
+/**
+ * Replaces the metadata of the `order` with the JSON object of string values at `ptr`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case the metadata is unchanged).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ */
+uint8_t order_set_metadata_json(struct Order_API *order, const char *ptr);
+
+/**
+ * Sets the metadata `value` for the `key` of the `order`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case the metadata is unchanged).
+ *
+ * # Safety
+ *
+ * - Assumes `key_ptr` and `value_ptr` are valid C string pointers, or null.
+ */
+uint8_t order_set_metadata(struct Order_API *order, const char *key_ptr, const char *value_ptr);
+
+/**
+ * Removes the metadata for the `key` of the `order`, writing 1 to `removed` if the order
+ * had metadata for the key, otherwise 0.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `removed`).
+ *
+ * # Safety
+ *
+ * - Assumes `key_ptr` is a valid C string pointer, or null.
+ * - Assumes `removed` is a valid pointer to write the result to.
+ */
+uint8_t order_remove_metadata(struct Order_API *order, const char *key_ptr, uint8_t *removed);
+
+/**
+ * Calculates new trailing stop prices for the `order` at the current market, written to `out`.
+ *
+ * Any of `bid`, `ask` and `last` may be NULL where the price is not available.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `bid`, `ask` and `last` are either NULL or valid pointers to a [`Price`].
+ * - Assumes `out` is a valid pointer to memory for a [`TrailingStopPrices`].
+ */
+uint8_t order_trailing_stop_calculate(const struct Order_API *order,
+                                      struct Price_t price_increment,
+                                      const struct Price_t *bid,
+                                      const struct Price_t *ask,
+                                      const struct Price_t *last,
+                                      struct TrailingStopPrices *out);
+
+struct Position_API position_new(const struct BaseInstrument *instrument, struct OrderFilled fill);
+
+void position_drop(struct Position_API position);
+
+void position_apply(struct Position_API *position, struct OrderFilled fill);
+
+struct PositionId_t position_id(const struct Position_API *position);
+
+struct InstrumentId_t position_instrument_id(const struct Position_API *position);
+
+enum OrderSide position_entry(const struct Position_API *position);
+
+enum PositionSide position_side(const struct Position_API *position);
+
+double position_signed_qty(const struct Position_API *position);
+
+struct Quantity_t position_quantity(const struct Position_API *position);
+
+struct Quantity_t position_peak_qty(const struct Position_API *position);
+
+double position_avg_px_open(const struct Position_API *position);
+
+/**
+ * Returns the average close price for the position, or 0.0 if it has not been reduced.
+ */
+double position_avg_px_close(const struct Position_API *position);
+
+/**
+ * Returns the realized return for the position, or 0.0 if it has not been reduced.
+ */
+double position_realized_return(const struct Position_API *position);
+
+/**
+ * Returns the realized PnL for the position, or zero in the cost currency if none.
+ */
+struct Money_t position_realized_pnl(const struct Position_API *position);
+
+struct Money_t position_unrealized_pnl(const struct Position_API *position, struct Price_t last);
+
+struct Money_t position_total_pnl(const struct Position_API *position, struct Price_t last);
+
+struct Money_t position_notional_value(const struct Position_API *position, struct Price_t last);
+
+uintptr_t position_event_count(const struct Position_API *position);
+
+uint8_t position_is_opposite_side(const struct Position_API *position, enum OrderSide side);
+
+uint8_t position_is_long(const struct Position_API *position);
+
+uint8_t position_is_short(const struct Position_API *position);
+
+uint8_t position_is_open(const struct Position_API *position);
+
+uint8_t position_is_closed(const struct Position_API *position);
+
+/**
+ * Writes a new [`OrderStatusReport`] to `out`.
+ *
+ * The optional enums take their `NO_*` variant for no value, `expire_time` and
+ * `ts_triggered` take zero, and `avg_px` takes NaN.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `client_order_id`, `order_list_id`, `price`, `trigger_price`, `limit_offset`,
+ *   `trailing_offset` and `display_qty` are each either null or a valid pointer.
+ * - Assumes `cancel_reason` is either null or a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for an [`OrderStatusReport_API`].
+ */
+uint8_t order_status_report_new(struct AccountId_t account_id,
+                                struct InstrumentId_t instrument_id,
+                                const struct ClientOrderId_t *client_order_id,
+                                const struct OrderListId_t *order_list_id,
+                                struct VenueOrderId_t venue_order_id,
+                                enum OrderSide order_side,
+                                enum OrderType order_type,
+                                enum ContingencyType contingency_type,
+                                enum TimeInForce time_in_force,
+                                uint64_t expire_time,
+                                enum OrderStatus order_status,
+                                const struct Price_t *price,
+                                const struct Price_t *trigger_price,
+                                enum TriggerType trigger_type,
+                                const struct Price_t *limit_offset,
+                                const struct Price_t *trailing_offset,
+                                enum TrailingOffsetType trailing_offset_type,
+                                struct Quantity_t quantity,
+                                struct Quantity_t filled_qty,
+                                const struct Quantity_t *display_qty,
+                                double avg_px,
+                                uint8_t post_only,
+                                uint8_t reduce_only,
+                                const char *cancel_reason,
+                                UUID4_t report_id,
+                                uint64_t ts_accepted,
+                                uint64_t ts_triggered,
+                                uint64_t ts_last,
+                                uint64_t ts_init,
+                                struct OrderStatusReport_API *out);
+
+void order_status_report_drop(struct OrderStatusReport_API report);
+
+/**
+ * Returns a new [`FillReport`].
+ *
+ * # Safety
+ *
+ * - Assumes `client_order_id` and `venue_position_id` are each either null or a valid
+ *   pointer.
+ */
+struct FillReport_API fill_report_new(struct AccountId_t account_id,
+                                      struct InstrumentId_t instrument_id,
+                                      const struct ClientOrderId_t *client_order_id,
+                                      struct VenueOrderId_t venue_order_id,
+                                      const struct PositionId_t *venue_position_id,
+                                      struct TradeId_t trade_id,
+                                      enum OrderSide order_side,
+                                      struct Quantity_t last_qty,
+                                      struct Price_t last_px,
+                                      struct Money_t commission,
+                                      enum LiquiditySide liquidity_side,
+                                      UUID4_t report_id,
+                                      uint64_t ts_event,
+                                      uint64_t ts_init);
+
+void fill_report_drop(struct FillReport_API report);
+
+/**
+ * Returns a new [`PositionStatusReport`].
+ *
+ * # Safety
+ *
+ * - Assumes `venue_position_id` is either null or a valid pointer.
+ */
+struct PositionStatusReport_API position_status_report_new(struct AccountId_t account_id,
+                                                           struct InstrumentId_t instrument_id,
+                                                           enum PositionSide position_side,
+                                                           struct Quantity_t quantity,
+                                                           const struct PositionId_t *venue_position_id,
+                                                           UUID4_t report_id,
+                                                           uint64_t ts_last,
+                                                           uint64_t ts_init);
+
+void position_status_report_drop(struct PositionStatusReport_API report);
+
+struct ExecutionMassStatus_API execution_mass_status_new(struct ClientId_t client_id,
+                                                         struct AccountId_t account_id,
+                                                         struct Venue_t venue,
+                                                         UUID4_t report_id,
+                                                         uint64_t ts_init);
+
+void execution_mass_status_drop(struct ExecutionMassStatus_API mass_status);
+
+uintptr_t execution_mass_status_order_report_count(const struct ExecutionMassStatus_API *mass_status);
+
+uintptr_t execution_mass_status_position_report_count(const struct ExecutionMassStatus_API *mass_status);
+
+void execution_mass_status_add_order_report(struct ExecutionMassStatus_API *mass_status,
+                                            const struct OrderStatusReport_API *report);
+
+void execution_mass_status_add_fill_report(struct ExecutionMassStatus_API *mass_status,
+                                           const struct FillReport_API *report);
+
+void execution_mass_status_add_position_report(struct ExecutionMassStatus_API *mass_status,
+                                               const struct PositionStatusReport_API *report);
+
+uintptr_t execution_mass_status_fill_report_count(const struct ExecutionMassStatus_API *mass_status,
+                                                  const struct VenueOrderId_t *venue_order_id);
+
+/**
+ * Returns a [`Currency`] from pointers and primitives, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
  *
  * # Safety
  *
  * - Assumes `code_ptr` is a valid C string pointer.
  * - Assumes `name_ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`Currency`].
  */
-struct Currency_t currency_from_py(const char *code_ptr,
-                                   uint8_t precision,
-                                   uint16_t iso4217,
-                                   const char *name_ptr,
-                                   enum CurrencyType currency_type);
+uint8_t currency_from_py(const char *code_ptr,
+                         uint8_t precision,
+                         uint16_t iso4217,
+                         const char *name_ptr,
+                         enum CurrencyType currency_type,
+                         struct Currency_t *out);
 
 struct Currency_t currency_clone(const struct Currency_t *currency);
 
@@ -1762,20 +3616,46 @@ uint64_t currency_hash(const struct Currency_t *currency);
 void currency_register(struct Currency_t currency);
 
 /**
+ * Writes 1 to `out` if a currency with the code at `code_ptr` is registered, otherwise 0.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
  * # Safety
  *
  * - Assumes `code_ptr` is borrowed from a valid Python UTF-8 `str`.
+ * - Assumes `out` is a valid pointer to memory for a `u8`.
  */
-uint8_t currency_exists(const char *code_ptr);
+uint8_t currency_exists(const char *code_ptr, uint8_t *out);
+
+uint8_t currency_is_fiat(const struct Currency_t *currency);
+
+uint8_t currency_is_crypto(const struct Currency_t *currency);
 
 /**
+ * Returns the registered [`Currency`] for the code at `code_ptr`, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set
+ * (in which case nothing is written to `out`).
+ *
  * # Safety
  *
  * - Assumes `code_ptr` is borrowed from a valid Python UTF-8 `str`.
+ * - Assumes `out` is a valid pointer to memory for a [`Currency`].
  */
-struct Currency_t currency_from_cstr(const char *code_ptr);
+uint8_t currency_try_from_cstr(const char *code_ptr, struct Currency_t *out);
 
-struct Money_t money_new(double amount, struct Currency_t currency);
+/**
+ * Writes a new [`Money`] to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set (in
+ * which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Money`].
+ */
+uint8_t money_new(double amount, struct Currency_t currency, struct Money_t *out);
 
 struct Money_t money_from_raw(int64_t raw, struct Currency_t currency);
 
@@ -1783,11 +3663,23 @@ void money_drop(struct Money_t money);
 
 double money_as_f64(const struct Money_t *money);
 
+const char *money_to_cstr(const struct Money_t *money);
+
 void money_add_assign(struct Money_t a, struct Money_t b);
 
 void money_sub_assign(struct Money_t a, struct Money_t b);
 
-struct Price_t price_new(double value, uint8_t precision);
+/**
+ * Writes a new [`Price`] to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set (in
+ * which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Price`].
+ */
+uint8_t price_new(double value, uint8_t precision, struct Price_t *out);
 
 struct Price_t price_from_raw(int64_t raw, uint8_t precision);
 
@@ -1797,7 +3689,105 @@ void price_add_assign(struct Price_t a, struct Price_t b);
 
 void price_sub_assign(struct Price_t a, struct Price_t b);
 
-struct Quantity_t quantity_new(double value, uint8_t precision);
+/**
+ * Parses a [`Price`] from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set (in
+ * which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`Price`].
+ */
+uint8_t price_from_cstr(const char *ptr, struct Price_t *out);
+
+/**
+ * Returns a [`Price`] as a C string pointer.
+ */
+const char *price_to_cstr(const struct Price_t *price);
+
+/**
+ * Writes the sum of `a` and `b` to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if the
+ * result overflowed (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Price`].
+ */
+uint8_t price_checked_add(struct Price_t a, struct Price_t b, struct Price_t *out);
+
+/**
+ * Writes the difference of `a` and `b` to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if the
+ * result overflowed (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Price`].
+ */
+uint8_t price_checked_sub(struct Price_t a, struct Price_t b, struct Price_t *out);
+
+/**
+ * Writes the product of `a` and `b` to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if the
+ * result overflowed (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Price`].
+ */
+uint8_t price_checked_mul(struct Price_t a, struct Price_t b, struct Price_t *out);
+
+/**
+ * Writes `price` rounded to the given `precision` to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `precision` exceeds the maximum fixed precision or the result overflowed (in which case
+ * nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Price`].
+ */
+uint8_t price_round(struct Price_t price,
+                    uint8_t precision,
+                    enum RoundingMode mode,
+                    struct Price_t *out);
+
+/**
+ * Writes `price` rounded to a multiple of `increment` to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `increment` is not positive or the result overflowed (in which case nothing is written
+ * to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Price`].
+ */
+uint8_t price_round_to_increment(struct Price_t price,
+                                 struct Price_t increment,
+                                 enum RoundingMode mode,
+                                 struct Price_t *out);
+
+uint8_t price_is_multiple_of(struct Price_t price, struct Price_t increment);
+
+/**
+ * Writes a new [`Quantity`] to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set (in
+ * which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+ */
+uint8_t quantity_new(double value, uint8_t precision, struct Quantity_t *out);
 
 struct Quantity_t quantity_from_raw(uint64_t raw, uint8_t precision);
 
@@ -1810,3 +3800,105 @@ void quantity_add_assign_u64(struct Quantity_t a, uint64_t b);
 void quantity_sub_assign(struct Quantity_t a, struct Quantity_t b);
 
 void quantity_sub_assign_u64(struct Quantity_t a, uint64_t b);
+
+/**
+ * Parses a [`Quantity`] from a C string pointer, written to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set (in
+ * which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+ */
+uint8_t quantity_from_cstr(const char *ptr, struct Quantity_t *out);
+
+/**
+ * Returns a [`Quantity`] as a C string pointer.
+ */
+const char *quantity_to_cstr(const struct Quantity_t *qty);
+
+/**
+ * Writes the sum of `a` and `b` to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if the
+ * result overflowed (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+ */
+uint8_t quantity_checked_add(struct Quantity_t a, struct Quantity_t b, struct Quantity_t *out);
+
+/**
+ * Writes the difference of `a` and `b` to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if the
+ * result overflowed (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+ */
+uint8_t quantity_checked_sub(struct Quantity_t a, struct Quantity_t b, struct Quantity_t *out);
+
+/**
+ * Writes the product of `a` and `b` to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if the
+ * result overflowed (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+ */
+uint8_t quantity_checked_mul(struct Quantity_t a, struct Quantity_t b, struct Quantity_t *out);
+
+/**
+ * Writes `qty` rounded to the given `precision` to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `precision` exceeds the maximum fixed precision or the result overflowed (in which case
+ * nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+ */
+uint8_t quantity_round(struct Quantity_t qty,
+                       uint8_t precision,
+                       enum RoundingMode mode,
+                       struct Quantity_t *out);
+
+/**
+ * Writes `qty` rounded to a multiple of `increment` to `out`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set if
+ * `increment` is zero or the result overflowed (in which case nothing is written to `out`).
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+ */
+uint8_t quantity_round_to_increment(struct Quantity_t qty,
+                                    struct Quantity_t increment,
+                                    enum RoundingMode mode,
+                                    struct Quantity_t *out);
+
+uint8_t quantity_is_multiple_of(struct Quantity_t qty, struct Quantity_t increment);
+
+/**
+ * Parses the decimal string at `ptr` with up to 18 decimal places, and writes it to `out`
+ * as a [`Quantity`] rounded to at most 9 decimal places using the rounding `mode`.
+ *
+ * Returns zero on success, otherwise a non-zero status code with the last error set (in
+ * which case `out` is unchanged).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer, or null.
+ */
+uint8_t quantity128_cstr_to_quantity(const char *ptr,
+                                     enum RoundingMode mode,
+                                     struct Quantity_t *out);
@@ -2,11 +2,25 @@
 
 from cpython.object cimport PyObject
 from libc.stdint cimport uint8_t, uint64_t, uintptr_t
-from nautilus_trader.core.rust.common cimport TestClock_API, LiveClock_API
-from nautilus_trader.core.rust.core cimport CVec, UUID4_t
+from nautilus_trader.core.rust.common cimport TestClock_API, LiveClock_API, TradingCalendar_API
+from nautilus_trader.core.rust.core cimport CVec, UUID4_t, PoolStats
+from nautilus_trader.core.rust.model cimport AccountId_t, Bar_t, BookType, ClientOrderId_t, OrderBookDelta_t, QuoteTick_t, TradeTick_t, BaseInstrument, OrderInitialized
 
 cdef extern from "../includes/backtest.h":
 
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`OrderMatchingEngine`].
+    #
+    # This struct wraps `OrderMatchingEngine` in a way that makes it compatible with C function
+    # calls, enabling interaction with `OrderMatchingEngine` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of
+    # `OrderMatchingEngine_API` to be dereferenced to `OrderMatchingEngine`, providing access
+    # to `OrderMatchingEngine`'s methods without having to manually access the underlying
+    # `OrderMatchingEngine` instance.
+    cdef struct OrderMatchingEngine_API:
+        pass
+
     # Provides a means of accumulating and draining time event handlers.
     cdef struct TimeEventAccumulator:
         pass
@@ -24,3 +38,106 @@ cdef extern from "../includes/backtest.h":
                                               uint8_t set_time);
 
     CVec time_event_accumulator_drain(TimeEventAccumulatorAPI *accumulator);
+
+    # # Safety
+    #
+    # - Assumes `random_seed` is either null or a valid pointer to a `u64`.
+    OrderMatchingEngine_API order_matching_engine_new(const BaseInstrument *instrument,
+                                                      BookType book_type,
+                                                      AccountId_t account_id,
+                                                      uint8_t bar_execution,
+                                                      uint8_t reject_stop_orders,
+                                                      uint8_t use_queue_position,
+                                                      uint8_t support_gtd_orders,
+                                                      double prob_fill_on_limit,
+                                                      double prob_fill_on_stop,
+                                                      double prob_slippage,
+                                                      const uint64_t *random_seed);
+
+    void order_matching_engine_drop(OrderMatchingEngine_API engine);
+
+    void order_matching_engine_reset(OrderMatchingEngine_API *engine);
+
+    void order_matching_engine_set_calendar(OrderMatchingEngine_API *engine,
+                                            const TradingCalendar_API *calendar);
+
+    # Returns zero on success, otherwise a non-zero status code with the last error set.
+    uint8_t order_matching_engine_process_order_book_delta(OrderMatchingEngine_API *engine,
+                                                           OrderBookDelta_t delta);
+
+    # Returns zero on success, otherwise a non-zero status code with the last error set.
+    uint8_t order_matching_engine_process_quote_tick(OrderMatchingEngine_API *engine,
+                                                     const QuoteTick_t *tick);
+
+    # Returns zero on success, otherwise a non-zero status code with the last error set.
+    uint8_t order_matching_engine_process_trade_tick(OrderMatchingEngine_API *engine,
+                                                     const TradeTick_t *tick);
+
+    # Returns zero on success, otherwise a non-zero status code with the last error set.
+    uint8_t order_matching_engine_process_bar(OrderMatchingEngine_API *engine, const Bar_t *bar);
+
+    # Submits the order initialized by `init` to the engine for the engines account.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # the order cannot be submitted.
+    uint8_t order_matching_engine_process_order(OrderMatchingEngine_API *engine,
+                                                const OrderInitialized *init,
+                                                uint64_t ts_now);
+
+    # Cancels the open order, writing 1 to `canceled` if it was canceled, otherwise 0.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set.
+    #
+    # # Safety
+    #
+    # - Assumes `canceled` is a valid pointer to write the result to.
+    uint8_t order_matching_engine_cancel_order(OrderMatchingEngine_API *engine,
+                                               const ClientOrderId_t *client_order_id,
+                                               uint64_t ts_now,
+                                               uint8_t *canceled);
+
+    # Returns zero on success, otherwise a non-zero status code with the last error set.
+    uint8_t order_matching_engine_iterate(OrderMatchingEngine_API *engine, uint64_t ts_now);
+
+    uintptr_t order_matching_engine_open_order_count(const OrderMatchingEngine_API *engine);
+
+    # Returns the earliest expire time of the open GTD orders, or 0 if there are none.
+    uint64_t order_matching_engine_next_expire_time_ns(const OrderMatchingEngine_API *engine);
+
+    # Drains the buffered fill events into a vector from the pool of the calling thread.
+    #
+    # The returned vector must be freed with `vec_order_filled_drop`, or `vec_order_filled_recycle`
+    # to reuse its allocation for the next call.
+    CVec order_matching_engine_drain_fills(OrderMatchingEngine_API *engine);
+
+    # Drains up to `max` buffered fill events into the `buffer`, reusing its allocation, and
+    # writes the number of fills drained to `count`.
+    #
+    # The fills from the previous call are dropped, so a single buffer created with
+    # `vec_order_filled_new` can be polled repeatedly, then freed with `vec_order_filled_drop`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `buffer` holds elements other than fills (in which case nothing is written to `count`).
+    #
+    # # Safety
+    #
+    # - Assumes `buffer` was created with `vec_order_filled_new` or from fills, and has not been
+    #   dropped.
+    # - Assumes `count` is a valid pointer to write the result to.
+    uint8_t order_matching_engine_drain_fills_into(OrderMatchingEngine_API *engine,
+                                                   CVec *buffer,
+                                                   uintptr_t max,
+                                                   uintptr_t *count);
+
+    # Returns an empty vector of fills allocated for `capacity` fills, to pass to
+    # `order_matching_engine_drain_fills_into`.
+    CVec vec_order_filled_new(uintptr_t capacity);
+
+    void vec_order_filled_drop(CVec v);
+
+    # Drops the fills, returning the vector to the pool of the calling thread for reuse by
+    # `order_matching_engine_drain_fills`.
+    void vec_order_filled_recycle(CVec v);
+
+    # Returns the statistics of the fill vector pool of the calling thread.
+    PoolStats vec_order_filled_pool_stats();
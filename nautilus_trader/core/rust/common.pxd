@@ -1,8 +1,9 @@
 # Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
 
 from cpython.object cimport PyObject
-from libc.stdint cimport uint8_t, uint64_t, uintptr_t
+from libc.stdint cimport int32_t, uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
 from nautilus_trader.core.rust.core cimport CVec, UUID4_t
+from nautilus_trader.core.rust.model cimport Bar_t, BarType_t, QuoteTick_t, TradeTick_t, ClientOrderId_t, InstrumentId_t, OrderSide, PositionId_t, PositionSide, StrategyId_t, Venue_t, ClientId_t
 
 cdef extern from "../includes/common.h":
 
@@ -70,6 +71,13 @@ cdef extern from "../includes/common.h":
         # A trigger when the component has successfully faulted.
         FAULT_COMPLETED # = 15,
 
+    # The health of a data or execution client, as determined by the time since its last message.
+    cpdef enum HealthStatus:
+        # The client has received a message within its stale threshold.
+        HEALTHY # = 1,
+        # The client has not received a message within its stale threshold.
+        DEGRADED # = 2,
+
     # The log color for log messages.
     cpdef enum LogColor:
         # The default/normal log color.
@@ -100,6 +108,43 @@ cdef extern from "../includes/common.h":
         # The **CRT** critical log level.
         CRITICAL # = 50,
 
+    # Provides a central in-memory cache of trading state.
+    #
+    # Orders and positions are indexed by venue, instrument and strategy, and by whether they
+    # are open or closed. Query results are sorted by `ts_init` (then by identifier) so that
+    # they are deterministic.
+    cdef struct Cache:
+        pass
+
+    # A point in time copy of the state held by a [`Cache`].
+    #
+    # Market data is held newest first, in the same order as returned by the cache.
+    cdef struct CacheSnapshot:
+        pass
+
+    cdef struct ComponentFsm:
+        pass
+
+    # Provides expiry timer management for GTD (good till date) orders, for execution
+    # clients whose venues do not expire orders themselves.
+    #
+    # Registering an order sets a time alert named [`GtdExpiryTimers::timer_name`] on the clock,
+    # using the default handler of the clock, which must route the event back to
+    # [`GtdExpiryTimers::on_time_event`]. The caller then cancels (or expires) the returned
+    # order at the venue.
+    cdef struct GtdExpiryTimers:
+        pass
+
+    # Provides health monitoring of data and execution clients from the time since their last
+    # message.
+    #
+    # Each [`HealthMonitor::check`] publishes an alert event on the message bus for every client
+    # which has stalled beyond its stale threshold since the previous check, on the topic
+    # `health.degraded.<client_id>`, and for every degraded client which has since received a
+    # message, on the topic `health.recovered.<client_id>`.
+    cdef struct HealthMonitor:
+        pass
+
     cdef struct LiveClock:
         pass
 
@@ -110,12 +155,32 @@ cdef extern from "../includes/common.h":
     cdef struct Logger_t:
         pass
 
-    cdef struct Rc_String:
+    cdef struct MessageBus_t:
+        pass
+
+    cdef struct Arc_String:
         pass
 
     cdef struct TestClock:
         pass
 
+    # Provides a means of building time bars from ticks with an internal timer.
+    #
+    # When the timer fires (see [`TimeBarAggregator::on_time_event`]) a bar is
+    # created and sent to the handler.
+    cdef struct TimeBarAggregator:
+        pass
+
+    # Provides the trading sessions of a venue, with holidays and half-days, for queries in
+    # UNIX nanoseconds.
+    #
+    # Sessions are defined per weekday in the venues local time, given as a fixed offset from
+    # UTC, and may not span midnight. Adjacent sessions (including across midnight) are treated
+    # as one continuous session, so a market which never closes within the search horizon
+    # has no next open or close.
+    cdef struct TradingCalendar:
+        pass
+
     # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`TestClock`].
     #
     # This struct wraps `TestClock` in a way that makes it compatible with C function
@@ -139,6 +204,17 @@ cdef extern from "../includes/common.h":
     cdef struct LiveClock_API:
         LiveClock *_0;
 
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`ComponentFsm`].
+    #
+    # This struct wraps `ComponentFsm` in a way that makes it compatible with C function
+    # calls, enabling interaction with `ComponentFsm` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `ComponentFsm_API` to
+    # be dereferenced to `ComponentFsm`, providing access to `ComponentFsm`'s methods without
+    # having to manually access the underlying `ComponentFsm` instance.
+    cdef struct ComponentFsm_API:
+        ComponentFsm *_0;
+
     # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Logger`].
     #
     # This struct wraps `Logger` in a way that makes it compatible with C function
@@ -150,10 +226,66 @@ cdef extern from "../includes/common.h":
     cdef struct Logger_API:
         Logger_t *_0;
 
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`MessageBus`].
+    #
+    # This struct wraps `MessageBus` in a way that makes it compatible with C function
+    # calls, enabling interaction with `MessageBus` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `MessageBus_API` to be
+    # dereferenced to `MessageBus`, providing access to `MessageBus`'s methods without
+    # having to manually access the underlying `MessageBus` instance.
+    cdef struct MessageBus_API:
+        MessageBus_t *_0;
+
+    cdef enum Message_t_Tag:
+        COMMAND,
+        DOCUMENT,
+        EVENT,
+        REQUEST,
+        RESPONSE,
+
+    cdef struct Command_Body:
+        UUID4_t id;
+        uint64_t ts_init;
+
+    cdef struct Document_Body:
+        UUID4_t id;
+        uint64_t ts_init;
+
+    cdef struct Event_Body:
+        UUID4_t id;
+        uint64_t ts_init;
+        uint64_t ts_event;
+
+    cdef struct Request_Body:
+        UUID4_t id;
+        uint64_t ts_init;
+
+    cdef struct Response_Body:
+        UUID4_t id;
+        uint64_t ts_init;
+        UUID4_t correlation_id;
+
+    # Represents a message which can be sent, published, requested or responded to
+    # over the [`MessageBus`].
+    cdef struct Message_t:
+        Message_t_Tag tag;
+        Command_Body command;
+        Document_Body document;
+        Event_Body event;
+        Request_Body request;
+        Response_Body response;
+
+    # A C callback for handling messages from the [`MessageBus`].
+    #
+    # The `context` pointer is passed back unchanged on every call, allowing the caller
+    # to route the message to the owning object (e.g. a Cython actor).
+    ctypedef void (*MessageCallback)(void *context, const Message_t *msg);
+
     # Represents a time event occurring at the event timestamp.
     cdef struct TimeEvent_t:
         # The event name.
-        Rc_String *name;
+        Arc_String *name;
         # The event ID.
         UUID4_t event_id;
         # The message category
@@ -168,13 +300,304 @@ cdef extern from "../includes/common.h":
         # The event ID.
         PyObject *callback_ptr;
 
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying tick,
+    # volume or value [`BarAggregator`].
+    #
+    # This struct wraps the aggregator in a way that makes it compatible with C function
+    # calls, enabling interaction with the aggregator in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `BarAggregator_API`
+    # to be dereferenced to the underlying aggregator, providing access to its methods without
+    # having to manually access the underlying instance.
+    cdef struct BarAggregator_API:
+        void *_0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`TimeBarAggregator`].
+    #
+    # This struct wraps `TimeBarAggregator` in a way that makes it compatible with C function
+    # calls, enabling interaction with `TimeBarAggregator` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `TimeBarAggregator_API`
+    # to be dereferenced to `TimeBarAggregator`, providing access to `TimeBarAggregator`'s methods
+    # without having to manually access the underlying `TimeBarAggregator` instance.
+    cdef struct TimeBarAggregator_API:
+        TimeBarAggregator * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`GtdExpiryTimers`].
+    #
+    # This struct wraps `GtdExpiryTimers` in a way that makes it compatible with C function
+    # calls, enabling interaction with `GtdExpiryTimers` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of
+    # `GtdExpiryTimers_API` to be dereferenced to `GtdExpiryTimers`, providing access to
+    # `GtdExpiryTimers`'s methods without having to manually access the underlying instance.
+    cdef struct GtdExpiryTimers_API:
+        GtdExpiryTimers * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Cache`].
+    #
+    # This struct wraps `Cache` in a way that makes it compatible with C function
+    # calls, enabling interaction with `Cache` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `Cache_API` to be
+    # dereferenced to `Cache`, providing access to `Cache`'s methods without having to manually
+    # access the underlying `Cache` instance.
+    cdef struct Cache_API:
+        Cache * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`CacheSnapshot`].
+    cdef struct CacheSnapshot_API:
+        CacheSnapshot * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`HealthMonitor`].
+    #
+    # This struct wraps `HealthMonitor` in a way that makes it compatible with C function
+    # calls, enabling interaction with `HealthMonitor` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `HealthMonitor_API`
+    # to be dereferenced to `HealthMonitor`, providing access to `HealthMonitor`'s methods
+    # without having to manually access the underlying `HealthMonitor` instance.
+    cdef struct HealthMonitor_API:
+        HealthMonitor * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`TradingCalendar`].
+    #
+    # This struct wraps `TradingCalendar` in a way that makes it compatible with C function
+    # calls, enabling interaction with `TradingCalendar` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `TradingCalendar_API`
+    # to be dereferenced to `TradingCalendar`, providing access to `TradingCalendar`'s methods
+    # without having to manually access the underlying `TradingCalendar` instance.
+    cdef struct TradingCalendar_API:
+        TradingCalendar * _0;
+
+    # Writes a new bar aggregator for the tick, volume or value based `bar_type` to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `callback_ptr` is NULL or `None`, or the bar aggregation of `bar_type` is not tick,
+    # volume or value based.
+    #
+    # # Safety
+    #
+    # - Assumes `callback_ptr` is a valid PyCallable pointer.
+    # - Assumes `out` is a valid pointer to write the aggregator to.
+    uint8_t bar_aggregator_new(BarType_t bar_type,
+                               uint8_t price_precision,
+                               uint8_t size_precision,
+                               PyObject *callback_ptr,
+                               BarAggregator_API *out);
+
+    void bar_aggregator_drop(BarAggregator_API aggregator);
+
+    BarType_t bar_aggregator_bar_type(const BarAggregator_API *aggregator);
+
+    void bar_aggregator_handle_quote_tick(BarAggregator_API *aggregator, const QuoteTick_t *tick);
+
+    void bar_aggregator_handle_trade_tick(BarAggregator_API *aggregator, const TradeTick_t *tick);
+
+    # Writes a new time bar aggregator for the time based `bar_type` to `out`.
+    #
+    # The caller is responsible for setting a timer with the aggregators interval, and
+    # routing each resulting [`TimeEvent`] to `time_bar_aggregator_on_time_event`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `callback_ptr` is NULL or `None`.
+    #
+    # # Safety
+    #
+    # - Assumes `callback_ptr` is a valid PyCallable pointer.
+    # - Assumes `build_with_no_updates` is a correct `uint8_t` of either 0 or 1.
+    # - Assumes `out` is a valid pointer to write the aggregator to.
+    uint8_t time_bar_aggregator_new(BarType_t bar_type,
+                                    uint8_t price_precision,
+                                    uint8_t size_precision,
+                                    uint8_t build_with_no_updates,
+                                    PyObject *callback_ptr,
+                                    TimeBarAggregator_API *out);
+
+    void time_bar_aggregator_drop(TimeBarAggregator_API aggregator);
+
+    BarType_t time_bar_aggregator_bar_type(const TimeBarAggregator_API *aggregator);
+
+    uint64_t time_bar_aggregator_interval_ns(const TimeBarAggregator_API *aggregator);
+
+    uint64_t time_bar_aggregator_next_close_ns(const TimeBarAggregator_API *aggregator);
+
+    uint64_t time_bar_aggregator_start_time_ns(const TimeBarAggregator_API *aggregator,
+                                               uint64_t ts_now);
+
+    void time_bar_aggregator_handle_quote_tick(TimeBarAggregator_API *aggregator,
+                                               const QuoteTick_t *tick);
+
+    void time_bar_aggregator_handle_trade_tick(TimeBarAggregator_API *aggregator,
+                                               const TradeTick_t *tick);
+
+    void time_bar_aggregator_on_time_event(TimeBarAggregator_API *aggregator,
+                                           const TimeEvent_t *event);
+
+    Cache_API cache_new(uintptr_t tick_capacity, uintptr_t bar_capacity);
+
+    void cache_drop(Cache_API cache);
+
+    void cache_reset(Cache_API *cache);
+
+    void cache_add_quote_tick(Cache_API *cache, const QuoteTick_t *tick);
+
+    void cache_add_trade_tick(Cache_API *cache, const TradeTick_t *tick);
+
+    void cache_add_bar(Cache_API *cache, const Bar_t *bar);
+
+    # Writes the latest quote tick for the `instrument_id` to `out`.
+    #
+    # Returns 1 if a quote tick was written, otherwise 0.
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to a `QuoteTick`.
+    uint8_t cache_quote_tick(const Cache_API *cache,
+                             const InstrumentId_t *instrument_id,
+                             QuoteTick_t *out);
+
+    # Writes the latest trade tick for the `instrument_id` to `out`.
+    #
+    # Returns 1 if a trade tick was written, otherwise 0.
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to a `TradeTick`.
+    uint8_t cache_trade_tick(const Cache_API *cache,
+                             const InstrumentId_t *instrument_id,
+                             TradeTick_t *out);
+
+    # Writes the latest bar for the `bar_type` to `out`.
+    #
+    # Returns 1 if a bar was written, otherwise 0.
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to a `Bar`.
+    uint8_t cache_bar(const Cache_API *cache, const BarType_t *bar_type, Bar_t *out);
+
+    uintptr_t cache_quote_tick_count(const Cache_API *cache, const InstrumentId_t *instrument_id);
+
+    uintptr_t cache_trade_tick_count(const Cache_API *cache, const InstrumentId_t *instrument_id);
+
+    uintptr_t cache_bar_count(const Cache_API *cache, const BarType_t *bar_type);
+
+    uint8_t cache_order_exists(const Cache_API *cache, const ClientOrderId_t *client_order_id);
+
+    uint8_t cache_position_exists(const Cache_API *cache, const PositionId_t *position_id);
+
+    # Returns the count of open orders matching the filters, where a `side` of
+    # `NO_ORDER_SIDE` applies no side filter.
+    #
+    # # Safety
+    #
+    # - Assumes each filter is either NULL (no filter) or a valid pointer.
+    uintptr_t cache_orders_open_count(const Cache_API *cache,
+                                      const Venue_t *venue,
+                                      const InstrumentId_t *instrument_id,
+                                      const StrategyId_t *strategy_id,
+                                      OrderSide side);
+
+    # Returns the count of closed orders matching the filters, where a `side` of
+    # `NO_ORDER_SIDE` applies no side filter.
+    #
+    # # Safety
+    #
+    # - Assumes each filter is either NULL (no filter) or a valid pointer.
+    uintptr_t cache_orders_closed_count(const Cache_API *cache,
+                                        const Venue_t *venue,
+                                        const InstrumentId_t *instrument_id,
+                                        const StrategyId_t *strategy_id,
+                                        OrderSide side);
+
+    # Returns the count of open positions matching the filters, where a `side` of
+    # `NO_POSITION_SIDE` applies no side filter.
+    #
+    # # Safety
+    #
+    # - Assumes each filter is either NULL (no filter) or a valid pointer.
+    uintptr_t cache_positions_open_count(const Cache_API *cache,
+                                         const Venue_t *venue,
+                                         const InstrumentId_t *instrument_id,
+                                         const StrategyId_t *strategy_id,
+                                         PositionSide side);
+
+    # Returns the count of closed positions matching the filters, where a `side` of
+    # `NO_POSITION_SIDE` applies no side filter.
+    #
+    # # Safety
+    #
+    # - Assumes each filter is either NULL (no filter) or a valid pointer.
+    uintptr_t cache_positions_closed_count(const Cache_API *cache,
+                                           const Venue_t *venue,
+                                           const InstrumentId_t *instrument_id,
+                                           const StrategyId_t *strategy_id,
+                                           PositionSide side);
+
+    # Returns a snapshot of the cache state, which must be freed with `cache_snapshot_drop`.
+    CacheSnapshot_API cache_snapshot(const Cache_API *cache);
+
+    void cache_restore(Cache_API *cache, const CacheSnapshot_API *snapshot);
+
+    void cache_snapshot_drop(CacheSnapshot_API snapshot);
+
+    TradingCalendar_API trading_calendar_new(const Venue_t *venue, int32_t utc_offset_secs);
+
+    TradingCalendar_API trading_calendar_always_open(const Venue_t *venue);
+
+    void trading_calendar_drop(TradingCalendar_API calendar);
+
+    # Adds a session on the `weekday` (0 for Monday to 6 for Sunday), in seconds from local
+    # midnight.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `weekday` is greater than 6, or the session is invalid or overlaps another session.
+    uint8_t trading_calendar_add_session(TradingCalendar_API *calendar,
+                                         uint8_t weekday,
+                                         uint32_t open_secs,
+                                         uint32_t close_secs);
+
+    # Adds a holiday on the local date.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # the date is invalid.
+    uint8_t trading_calendar_add_holiday(TradingCalendar_API *calendar,
+                                         int32_t year,
+                                         uint32_t month,
+                                         uint32_t day);
+
+    # Adds a half-day on the local date, closing at `close_secs` from local midnight.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # the date is invalid.
+    uint8_t trading_calendar_add_half_day(TradingCalendar_API *calendar,
+                                          int32_t year,
+                                          uint32_t month,
+                                          uint32_t day,
+                                          uint32_t close_secs);
+
+    uint8_t trading_calendar_is_open(const TradingCalendar_API *calendar, uint64_t ts);
+
+    # Returns the next session open after `ts`, or 0 if there is none.
+    uint64_t trading_calendar_next_open(const TradingCalendar_API *calendar, uint64_t ts);
+
+    # Returns the next session close after `ts`, or 0 if there is none.
+    uint64_t trading_calendar_next_close(const TradingCalendar_API *calendar, uint64_t ts);
+
     TestClock_API test_clock_new();
 
     void test_clock_drop(TestClock_API clock);
 
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `callback_ptr` is NULL or `None`.
+    #
     # # Safety
     # - Assumes `callback_ptr` is a valid PyCallable pointer.
-    void test_clock_register_default_handler(TestClock_API *clock, PyObject *callback_ptr);
+    uint8_t test_clock_register_default_handler(TestClock_API *clock, PyObject *callback_ptr);
 
     void test_clock_set_time(TestClock_API *clock, uint64_t to_time_ns);
 
@@ -190,25 +613,33 @@ cdef extern from "../includes/common.h":
 
     uintptr_t test_clock_timer_count(TestClock_API *clock);
 
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `name_ptr` is not a valid timer name, `callback_ptr` is NULL, `callback_ptr` is `None`
+    # with no default handler registered, or `alert_time_ns` is before the current time.
+    #
     # # Safety
     #
     # - Assumes `name_ptr` is a valid C string pointer.
     # - Assumes `callback_ptr` is a valid PyCallable pointer.
-    void test_clock_set_time_alert_ns(TestClock_API *clock,
-                                      const char *name_ptr,
-                                      uint64_t alert_time_ns,
-                                      PyObject *callback_ptr);
+    uint8_t test_clock_set_time_alert_ns(TestClock_API *clock,
+                                         const char *name_ptr,
+                                         uint64_t alert_time_ns,
+                                         PyObject *callback_ptr);
 
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `name_ptr` is not a valid timer name, `callback_ptr` is NULL, or `callback_ptr` is
+    # `None` with no default handler registered.
+    #
     # # Safety
     #
     # - Assumes `name_ptr` is a valid C string pointer.
     # - Assumes `callback_ptr` is a valid PyCallable pointer.
-    void test_clock_set_timer_ns(TestClock_API *clock,
-                                 const char *name_ptr,
-                                 uint64_t interval_ns,
-                                 uint64_t start_time_ns,
-                                 uint64_t stop_time_ns,
-                                 PyObject *callback_ptr);
+    uint8_t test_clock_set_timer_ns(TestClock_API *clock,
+                                    const char *name_ptr,
+                                    uint64_t interval_ns,
+                                    uint64_t start_time_ns,
+                                    uint64_t stop_time_ns,
+                                    PyObject *callback_ptr);
 
     # # Safety
     #
@@ -217,15 +648,24 @@ cdef extern from "../includes/common.h":
 
     void vec_time_event_handlers_drop(CVec v);
 
+    # Writes the next time of the timer named `name_ptr` to `out` (zero if no such timer).
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `name_ptr` is not a valid C string (in which case nothing is written to `out`).
+    #
     # # Safety
     #
     # - Assumes `name_ptr` is a valid C string pointer.
-    uint64_t test_clock_next_time_ns(TestClock_API *clock, const char *name_ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`UnixNanos`].
+    uint8_t test_clock_next_time_ns(TestClock_API *clock, const char *name_ptr, uint64_t *out);
 
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `name_ptr` is not a valid C string.
+    #
     # # Safety
     #
     # - Assumes `name_ptr` is a valid C string pointer.
-    void test_clock_cancel_timer(TestClock_API *clock, const char *name_ptr);
+    uint8_t test_clock_cancel_timer(TestClock_API *clock, const char *name_ptr);
 
     void test_clock_cancel_timers(TestClock_API *clock);
 
@@ -241,56 +681,196 @@ cdef extern from "../includes/common.h":
 
     uint64_t live_clock_timestamp_ns(LiveClock_API *clock);
 
+    ComponentFsm_API component_fsm_new();
+
+    void component_fsm_drop(ComponentFsm_API fsm);
+
+    ComponentState component_fsm_state(const ComponentFsm_API *fsm);
+
+    # Applies the `trigger` to the `fsm`, returning 0 on success or the [`ComponentError`] code.
+    uint8_t component_fsm_trigger(ComponentFsm_API *fsm, ComponentTrigger trigger);
+
+    # Returns 1 if `trigger` is valid from `state`, otherwise 0.
+    uint8_t component_state_can_transition(ComponentState state, ComponentTrigger trigger);
+
+    uint8_t component_fsm_is_initialized(const ComponentFsm_API *fsm);
+
+    uint8_t component_fsm_is_running(const ComponentFsm_API *fsm);
+
+    uint8_t component_fsm_is_stopped(const ComponentFsm_API *fsm);
+
+    uint8_t component_fsm_is_disposed(const ComponentFsm_API *fsm);
+
+    uint8_t component_fsm_is_degraded(const ComponentFsm_API *fsm);
+
+    uint8_t component_fsm_is_faulted(const ComponentFsm_API *fsm);
+
     const char *component_state_to_cstr(ComponentState value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    ComponentState component_state_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`ComponentState`].
+    uint8_t component_state_try_from_cstr(const char *ptr, ComponentState *out);
 
     const char *component_trigger_to_cstr(ComponentTrigger value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    ComponentTrigger component_trigger_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`ComponentTrigger`].
+    uint8_t component_trigger_try_from_cstr(const char *ptr, ComponentTrigger *out);
+
+    const char *health_status_to_cstr(HealthStatus value);
+
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    # - Assumes `ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for a [`HealthStatus`].
+    uint8_t health_status_try_from_cstr(const char *ptr, HealthStatus *out);
 
     const char *log_level_to_cstr(LogLevel value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    LogLevel log_level_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`LogLevel`].
+    uint8_t log_level_try_from_cstr(const char *ptr, LogLevel *out);
 
     const char *log_color_to_cstr(LogColor value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    LogColor log_color_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`LogColor`].
+    uint8_t log_color_try_from_cstr(const char *ptr, LogColor *out);
+
+    GtdExpiryTimers_API gtd_expiry_timers_new();
+
+    void gtd_expiry_timers_drop(GtdExpiryTimers_API timers);
 
-    # Creates a new logger.
+    uintptr_t gtd_expiry_timers_count(const GtdExpiryTimers_API *timers);
+
+    uint8_t gtd_expiry_timers_is_pending(const GtdExpiryTimers_API *timers,
+                                         const ClientOrderId_t *client_order_id);
+
+    # Returns the earliest pending expire time, or 0 if there are no pending expirations.
+    uint64_t gtd_expiry_timers_next_expire_time_ns(const GtdExpiryTimers_API *timers);
+
+    # Returns the pending expirations sorted by expire time.
+    #
+    # The returned vector must be freed with `vec_gtd_expirations_drop`.
+    CVec gtd_expiry_timers_pending(const GtdExpiryTimers_API *timers);
+
+    void vec_gtd_expirations_drop(CVec v);
+
+    void gtd_expiry_timers_register(GtdExpiryTimers_API *timers,
+                                    LiveClock_API *clock,
+                                    const ClientOrderId_t *client_order_id,
+                                    uint64_t expire_time_ns);
+
+    # Returns 1 if a pending expiration was canceled, otherwise 0.
+    uint8_t gtd_expiry_timers_cancel(GtdExpiryTimers_API *timers,
+                                     LiveClock_API *clock,
+                                     const ClientOrderId_t *client_order_id);
+
+    # Handles the time `event`, writing the client order ID of the expired order to `out`.
+    #
+    # Returns 1 if the event was from a pending expiry timer, otherwise 0 (in which case
+    # nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`ClientOrderId`], which is not read
+    #   or dropped.
+    uint8_t gtd_expiry_timers_on_time_event(GtdExpiryTimers_API *timers,
+                                            const TimeEvent_t *event,
+                                            ClientOrderId_t *out);
+
+    void gtd_expiry_timers_reset(GtdExpiryTimers_API *timers, LiveClock_API *clock);
+
+    HealthMonitor_API health_monitor_new(uint64_t default_stale_threshold_ns);
+
+    void health_monitor_drop(HealthMonitor_API monitor);
+
+    # Registers the client to monitor, using the default stale threshold if
+    # `stale_threshold_ns` is 0.
+    void health_monitor_register_client(HealthMonitor_API *monitor,
+                                        const ClientId_t *client_id,
+                                        uint64_t stale_threshold_ns,
+                                        uint64_t ts_now);
+
+    void health_monitor_deregister_client(HealthMonitor_API *monitor, const ClientId_t *client_id);
+
+    void health_monitor_record_message(HealthMonitor_API *monitor,
+                                       const ClientId_t *client_id,
+                                       uint64_t ts);
+
+    # Returns the count of clients which degraded, publishing alerts on the `msgbus`.
+    uintptr_t health_monitor_check(HealthMonitor_API *monitor,
+                                   MessageBus_API *msgbus,
+                                   uint64_t ts_now);
+
+    uint8_t health_monitor_is_healthy(const HealthMonitor_API *monitor);
+
+    # Returns the health of every client, sorted by client ID.
+    #
+    # The returned vector must be freed with `vec_client_health_drop`.
+    CVec health_monitor_snapshot(const HealthMonitor_API *monitor);
+
+    void vec_client_health_drop(CVec v);
+
+    # Creates a new logger, written to `out`.
+    #
+    # A `rate_limit` or `max_file_size` of zero disables rate limiting or size based file
+    # rotation respectively.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # any of the ID strings are invalid (in which case nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `trader_id_ptr` is a valid C string pointer.
     # - Assumes `machine_id_ptr` is a valid C string pointer.
     # - Assumes `instance_id_ptr` is a valid C string pointer.
-    Logger_API logger_new(const char *trader_id_ptr,
-                          const char *machine_id_ptr,
-                          const char *instance_id_ptr,
-                          LogLevel level_stdout,
-                          LogLevel level_file,
-                          uint8_t file_logging,
-                          const char *directory_ptr,
-                          const char *file_name_ptr,
-                          const char *file_format_ptr,
-                          const char *component_levels_ptr,
-                          uint8_t is_bypassed);
+    # - Assumes `directory_ptr`, `file_name_ptr`, `file_format_ptr` and `component_levels_ptr`
+    #   are either null or valid C string pointers.
+    # - Assumes `out` is a valid pointer to memory for a [`Logger_API`].
+    uint8_t logger_new(const char *trader_id_ptr,
+                       const char *machine_id_ptr,
+                       const char *instance_id_ptr,
+                       LogLevel level_stdout,
+                       LogLevel level_file,
+                       uint8_t file_logging,
+                       const char *directory_ptr,
+                       const char *file_name_ptr,
+                       const char *file_format_ptr,
+                       const char *component_levels_ptr,
+                       uint8_t is_colored,
+                       uint32_t rate_limit,
+                       uint64_t max_file_size,
+                       uint8_t is_bypassed,
+                       Logger_API *out);
 
     void logger_drop(Logger_API logger);
 
@@ -302,28 +882,214 @@ cdef extern from "../includes/common.h":
 
     uint8_t logger_is_bypassed(const Logger_API *logger);
 
+    # Returns the number of log events queued for the writer thread.
+    uintptr_t logger_queue_depth(const Logger_API *logger);
+
     # Create a new log event.
     #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `component_ptr` or `message_ptr` is not a valid C string.
+    #
     # # Safety
     #
     # - Assumes `component_ptr` is a valid C string pointer.
     # - Assumes `message_ptr` is a valid C string pointer.
-    void logger_log(Logger_API *logger,
-                    uint64_t timestamp_ns,
-                    LogLevel level,
-                    LogColor color,
-                    const char *component_ptr,
-                    const char *message_ptr);
+    uint8_t logger_log(Logger_API *logger,
+                       uint64_t timestamp_ns,
+                       LogLevel level,
+                       LogColor color,
+                       const char *component_ptr,
+                       const char *message_ptr);
+
+    # Starts serving the metrics at `http://0.0.0.0:<port>/metrics`.
+    #
+    # Returns 1 if the server was started, otherwise 0 (if the port could not be bound).
+    uint8_t metrics_start_server(uint16_t port);
+
+    void metrics_inc_orders_submitted();
+
+    void metrics_inc_orders_filled();
+
+    void metrics_inc_ticks_processed(uint64_t count);
+
+    void metrics_observe_event_loop_latency_ns(uint64_t latency_ns);
+
+    # Returns the metrics in the Prometheus text format, as a C string pointer.
+    const char *metrics_render_to_cstr();
+
+    # Creates a new message bus, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `trader_id_ptr` is not a valid trader ID or `name_ptr` is not a valid C string (in which
+    # case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `trader_id_ptr` is a valid C string pointer.
+    # - Assumes `name_ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer to memory for a [`MessageBus_API`].
+    uint8_t msgbus_new(const char *trader_id_ptr, const char *name_ptr, MessageBus_API *out);
+
+    void msgbus_drop(MessageBus_API bus);
+
+    uint64_t msgbus_sent_count(const MessageBus_API *bus);
+
+    uint64_t msgbus_req_count(const MessageBus_API *bus);
+
+    uint64_t msgbus_res_count(const MessageBus_API *bus);
+
+    uint64_t msgbus_pub_count(const MessageBus_API *bus);
+
+    # Registers the C `callback` to receive messages at the `endpoint` address.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # a string is invalid or the endpoint was already registered.
+    #
+    # # Safety
+    #
+    # - Assumes `endpoint_ptr` is a valid C string pointer.
+    # - Assumes `handler_id_ptr` is a valid C string pointer.
+    # - Assumes `context` remains valid until the endpoint is deregistered.
+    uint8_t msgbus_register(MessageBus_API *bus,
+                            const char *endpoint_ptr,
+                            const char *handler_id_ptr,
+                            MessageCallback callback,
+                            void *context);
+
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `endpoint_ptr` is not a valid C string or no handler was registered at the endpoint.
+    #
+    # # Safety
+    #
+    # - Assumes `endpoint_ptr` is a valid C string pointer.
+    uint8_t msgbus_deregister(MessageBus_API *bus, const char *endpoint_ptr);
+
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `endpoint_ptr` is not a valid C string or no handler was registered at the endpoint.
+    #
+    # # Safety
+    #
+    # - Assumes `endpoint_ptr` is a valid C string pointer.
+    uint8_t msgbus_send(MessageBus_API *bus, const char *endpoint_ptr, const Message_t *msg);
+
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # a string is invalid or the request was not handled.
+    #
+    # # Safety
+    #
+    # - Assumes `endpoint_ptr` is a valid C string pointer.
+    # - Assumes `handler_id_ptr` is a valid C string pointer.
+    # - Assumes `context` remains valid until the response is received.
+    uint8_t msgbus_request(MessageBus_API *bus,
+                           const char *endpoint_ptr,
+                           const Message_t *request,
+                           const char *handler_id_ptr,
+                           MessageCallback callback,
+                           void *context);
+
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # the response was not handled.
+    uint8_t msgbus_response(MessageBus_API *bus, const Message_t *response);
+
+    # Subscribes the C `callback` to the `topic`, which may include wildcard characters.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `topic_ptr` or `handler_id_ptr` is not a valid C string.
+    #
+    # # Safety
+    #
+    # - Assumes `topic_ptr` is a valid C string pointer.
+    # - Assumes `handler_id_ptr` is a valid C string pointer.
+    # - Assumes `context` remains valid until the handler is unsubscribed.
+    uint8_t msgbus_subscribe(MessageBus_API *bus,
+                             const char *topic_ptr,
+                             const char *handler_id_ptr,
+                             MessageCallback callback,
+                             void *context,
+                             uint8_t priority);
+
+    # Writes 1 to `out` if unsubscribed, or 0 if no such subscription existed.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `topic_ptr` or `handler_id_ptr` is not a valid C string (in which case nothing is
+    # written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `topic_ptr` is a valid C string pointer.
+    # - Assumes `handler_id_ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for a `u8`.
+    uint8_t msgbus_unsubscribe(MessageBus_API *bus,
+                               const char *topic_ptr,
+                               const char *handler_id_ptr,
+                               uint8_t *out);
+
+    # Writes 1 to `out` if the handler is subscribed to the `topic`, otherwise 0.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `topic_ptr` or `handler_id_ptr` is not a valid C string (in which case nothing is
+    # written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `topic_ptr` is a valid C string pointer.
+    # - Assumes `handler_id_ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for a `u8`.
+    uint8_t msgbus_is_subscribed(const MessageBus_API *bus,
+                                 const char *topic_ptr,
+                                 const char *handler_id_ptr,
+                                 uint8_t *out);
+
+    # Writes 1 to `out` if there are subscribers matching the `pattern`, otherwise 0.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `pattern_ptr` is not a valid C string (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `pattern_ptr` is a valid C string pointer or NULL (for all topics).
+    # - Assumes `out` is a valid pointer to memory for a `u8`.
+    uint8_t msgbus_has_subscribers(const MessageBus_API *bus,
+                                   const char *pattern_ptr,
+                                   uint8_t *out);
+
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `topic_ptr` is not a valid C string.
+    #
+    # # Safety
+    #
+    # - Assumes `topic_ptr` is a valid C string pointer.
+    uint8_t msgbus_publish(MessageBus_API *bus, const char *topic_ptr, const Message_t *msg);
+
+    # Writes 1 to `out` if the `topic` matches the `pattern`, otherwise 0.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `topic_ptr` or `pattern_ptr` is not a valid C string (in which case nothing is written
+    # to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `topic_ptr` is a valid C string pointer.
+    # - Assumes `pattern_ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for a `u8`.
+    uint8_t msgbus_is_matching(const char *topic_ptr, const char *pattern_ptr, uint8_t *out);
 
     TimeEventHandler_t dummy(TimeEventHandler_t v);
 
+    # Writes a new [`TimeEvent`] to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set (in
+    # which case nothing is written to `out`).
+    #
     # # Safety
     #
     # - Assumes `name_ptr` is borrowed from a valid Python UTF-8 `str`.
-    TimeEvent_t time_event_new(const char *name_ptr,
-                               UUID4_t event_id,
-                               uint64_t ts_event,
-                               uint64_t ts_init);
+    # - Assumes `out` is a valid pointer to memory for a [`TimeEvent`].
+    uint8_t time_event_new(const char *name_ptr,
+                           UUID4_t event_id,
+                           uint64_t ts_event,
+                           uint64_t ts_init,
+                           TimeEvent_t *out);
 
     TimeEvent_t time_event_clone(const TimeEvent_t *event);
 
@@ -0,0 +1,52 @@
+# Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+from libc.stdint cimport uint8_t, uint64_t, uintptr_t
+from nautilus_trader.core.rust.common cimport Cache_API
+from nautilus_trader.core.rust.model cimport ExecutionMassStatus_API
+
+cdef extern from "../includes/execution.h":
+    # Provides reconciliation of the cached orders and positions against venue reports.
+    #
+    # Inferred events are generated in the order they should be applied, and are validated
+    # against a copy of the cached order so that each one is a valid state transition.
+    cdef struct ExecutionReconciler:
+        pass
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`ExecutionReconciler`].
+    #
+    # This struct wraps `ExecutionReconciler` in a way that makes it compatible with C function
+    # calls, enabling interaction with `ExecutionReconciler` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of
+    # `ExecutionReconciler_API` to be dereferenced to `ExecutionReconciler`, providing access to
+    # `ExecutionReconciler`'s methods without having to manually access the underlying instance.
+    cdef struct ExecutionReconciler_API:
+        ExecutionReconciler * _0;
+
+    ExecutionReconciler_API execution_reconciler_new();
+
+    void execution_reconciler_drop(ExecutionReconciler_API reconciler);
+
+    void execution_reconciler_reset(ExecutionReconciler_API *reconciler);
+
+    # Returns 1 if the `mass_status` was reconciled against the `cache` without any
+    # discrepancies, otherwise 0.
+    uint8_t execution_reconciler_reconcile_mass_status(ExecutionReconciler_API *reconciler,
+                                                       const Cache_API *cache,
+                                                       const ExecutionMassStatus_API *mass_status,
+                                                       uint64_t ts_init);
+
+    uintptr_t execution_reconciler_discrepancy_count(const ExecutionReconciler_API *reconciler);
+
+    # Returns the discrepancies as a JSON array of their descriptions, as a C string pointer.
+    #
+    # The caller owns the returned string, and must free it with `cstr_drop`.
+    const char *execution_reconciler_discrepancies_to_cstr(const ExecutionReconciler_API *reconciler);
+
+    # Drains the inferred events as a JSON array of their dicts, in the format of the Python
+    # `to_dict` methods, as a C string pointer.
+    #
+    # The caller owns the returned string, and must free it with `cstr_drop`. Returns null
+    # with the last error set if the events fail to serialize.
+    const char *execution_reconciler_drain_events_to_cstr(ExecutionReconciler_API *reconciler);
@@ -1,7 +1,7 @@
 # Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
 
-from libc.stdint cimport uint8_t, uint16_t, uint64_t, uintptr_t, int64_t
-from nautilus_trader.core.rust.core cimport CVec, UUID4_t
+from libc.stdint cimport int64_t, uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+from nautilus_trader.core.rust.core cimport CVec, UUID4_t, PoolStats
 
 cdef extern from "../includes/model.h":
 
@@ -95,6 +95,19 @@ cdef extern from "../includes/model.h":
         # The state of the order book is cleared.
         CLEAR # = 4,
 
+    # The venue specification of an order book checksum, used to verify the integrity of a book
+    # built from venue deltas.
+    #
+    # Coinbase is not included as its order book feeds do not publish a checksum, the integrity
+    # of those books is instead verified by the message sequence numbers.
+    cpdef enum BookChecksumType:
+        # CRC32 over the top 10 asks then bids, as concatenated price and size digits (Kraken).
+        KRAKEN # = 1,
+        # Signed CRC32 over the top 25 levels, interleaved as `bid:size:ask:size` (OKX).
+        OKX # = 2,
+        # Signed CRC32 over the top 25 levels, interleaved with negative ask sizes (Bitfinex).
+        BITFINEX # = 3,
+
     # The order book type, representing the type of levels granularity and delta updating heuristics.
     cpdef enum BookType:
         # Top-of-book best bid/offer, one level per side.
@@ -269,6 +282,21 @@ cdef extern from "../includes/model.h":
         # The last price at which a trade was made for an instrument.
         LAST # = 4,
 
+    # The rounding mode applied when reducing the precision of a fixed-point value.
+    cpdef enum RoundingMode:
+        # Round to the nearest value, with ties rounded away from zero (as `f64::round`).
+        HALF_AWAY_FROM_ZERO # = 1,
+        # Round to the nearest value, with ties rounded to the nearest even digit.
+        HALF_EVEN # = 2,
+        # Round towards negative infinity.
+        FLOOR # = 3,
+        # Round towards positive infinity.
+        CEILING # = 4,
+        # Round towards zero (truncate).
+        TOWARD_ZERO # = 5,
+        # Round away from zero.
+        AWAY_FROM_ZERO # = 6,
+
     # The 'Time in Force' instruction for an order in the financial market.
     cpdef enum TimeInForce:
         # Good Till Canceled (GTC) - the order remains active until canceled.
@@ -334,12 +362,139 @@ cdef extern from "../includes/model.h":
     cdef struct Arc_String:
         pass
 
+    # The specification shared by all tradable instruments.
+    cdef struct BaseInstrument:
+        pass
+
+    # Provides a generator for unique [`ClientOrderId`]s.
+    #
+    # Generated IDs take the form `O-{YYYYMMDD-HHMM}-{trader_tag}-{strategy_tag}-{count}`,
+    # matching the Python `ClientOrderIdGenerator`.
+    cdef struct ClientOrderIdGenerator:
+        pass
+
+    # Represents a crypto perpetual futures contract instrument (a.k.a. perpetual swap).
+    cdef struct CryptoPerpetual:
+        pass
+
+    # Represents a generic currency pair instrument in a spot/cash market.
+    cdef struct CurrencyPair:
+        pass
+
+    # Represents a generic equity (stock) instrument.
+    cdef struct Equity:
+        pass
+
+    # Represents the execution state of an account at a venue, made up of the status reports
+    # of its orders and positions along with the fills of its orders.
+    cdef struct ExecutionMassStatus:
+        pass
+
+    # Represents a fill of an order, as reported by the venue.
+    cdef struct FillReport:
+        pass
+
+    # Represents a generic deliverable futures contract instrument.
+    cdef struct FuturesContract:
+        pass
+
+    # Represents a valid trade match ID stored inline, without any heap allocation.
+    #
+    # Trade IDs are created for every trade tick, so this avoids the heap allocation
+    # of [`TradeId`] when ingesting ticks. The value is NUL terminated and padded with
+    # zeros, so it can be borrowed as a C string and compared or hashed bytewise.
+    cdef struct InlineTradeId:
+        pass
+
+    # Represents a generic options contract instrument.
+    cdef struct OptionsContract:
+        pass
+
+    cdef struct Order:
+        pass
+
     cdef struct OrderBook:
         pass
 
+    # Represents a grouped batch of `OrderBookDelta` updates for an `OrderBook`.
+    cdef struct OrderBookDeltas:
+        pass
+
+    # Represents a complete snapshot of the bid and ask orders in an `OrderBook`.
+    cdef struct OrderBookSnapshot:
+        pass
+
+    cdef struct OrderCancelRejected:
+        pass
+
+    cdef struct OrderCanceled:
+        pass
+
+    cdef struct OrderExpired:
+        pass
+
+    cdef struct OrderFilled:
+        pass
+
+    cdef struct OrderInitialized:
+        pass
+
+    # Provides a generator for unique [`OrderListId`]s.
+    #
+    # Generated IDs take the form `OL-{YYYYMMDD-HHMM}-{trader_tag}-{strategy_tag}-{count}`,
+    # matching the Python `OrderListIdGenerator`.
+    cdef struct OrderListIdGenerator:
+        pass
+
+    cdef struct OrderModifyRejected:
+        pass
+
+    cdef struct OrderPendingCancel:
+        pass
+
+    cdef struct OrderPendingUpdate:
+        pass
+
+    cdef struct OrderRejected:
+        pass
+
+    # Represents the status of an order at a point in time, as reported by the venue.
+    cdef struct OrderStatusReport:
+        pass
+
+    cdef struct OrderTriggered:
+        pass
+
+    cdef struct OrderUpdated:
+        pass
+
+    # Represents a position in a financial market.
+    #
+    # The position ID may be assigned at the trading venue, or can be system
+    # generated depending on a strategies OMS (Order Management System) settings.
+    cdef struct Position:
+        pass
+
+    # Provides a generator for unique [`PositionId`]s, with a separate counter per strategy.
+    #
+    # Generated IDs take the form `P-{YYYYMMDD-HHMM}-{trader_tag}-{strategy_tag}-{count}`,
+    # with an `F` suffix for positions opened by a flip, matching the Python
+    # `PositionIdGenerator`.
+    cdef struct PositionIdGenerator:
+        pass
+
+    # Represents the status of a position at a point in time, as reported by the venue.
+    cdef struct PositionStatusReport:
+        pass
+
     cdef struct String:
         pass
 
+    # Converts between venue-native symbols and Nautilus [`Symbol`]s using the rules registered
+    # per venue.
+    cdef struct SymbologyNormalizer:
+        pass
+
     # Represents a synthetic instrument with prices derived from component instruments using a
     # formula.
     cdef struct SyntheticInstrument:
@@ -510,9 +665,204 @@ cdef extern from "../includes/model.h":
         int64_t raw;
         Currency_t currency;
 
-    BarSpecification_t bar_specification_new(uint64_t step,
-                                             uint8_t aggregation,
-                                             uint8_t price_type);
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Order`].
+    #
+    # This struct wraps `Order` in a way that makes it compatible with C function
+    # calls, enabling interaction with `Order` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `Order_API` to be
+    # dereferenced to `Order`, providing access to `Order`'s methods without
+    # having to manually access the underlying `Order` instance.
+    cdef struct Order_API:
+        Order * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`SymbologyNormalizer`].
+    #
+    # This struct wraps `SymbologyNormalizer` in a way that makes it compatible with C function
+    # calls, enabling adapters to register rules and normalize symbols from a C environment.
+    cdef struct SymbologyNormalizer_API:
+        SymbologyNormalizer * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`OrderStatusReport`].
+    #
+    # This struct wraps `OrderStatusReport` in a way that makes it compatible with C function
+    # calls, enabling interaction with `OrderStatusReport` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of
+    # `OrderStatusReport_API` to be dereferenced to `OrderStatusReport`, providing access to
+    # `OrderStatusReport`'s fields without having to manually access the underlying instance.
+    cdef struct OrderStatusReport_API:
+        OrderStatusReport * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`FillReport`].
+    #
+    # This struct wraps `FillReport` in a way that makes it compatible with C function
+    # calls, enabling interaction with `FillReport` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `FillReport_API`
+    # to be dereferenced to `FillReport`, providing access to `FillReport`'s fields without
+    # having to manually access the underlying instance.
+    cdef struct FillReport_API:
+        FillReport * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`PositionStatusReport`].
+    #
+    # This struct wraps `PositionStatusReport` in a way that makes it compatible with C function
+    # calls, enabling interaction with `PositionStatusReport` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of
+    # `PositionStatusReport_API` to be dereferenced to `PositionStatusReport`, providing access
+    # to `PositionStatusReport`'s fields without having to manually access the underlying
+    # instance.
+    cdef struct PositionStatusReport_API:
+        PositionStatusReport * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`ExecutionMassStatus`].
+    #
+    # This struct wraps `ExecutionMassStatus` in a way that makes it compatible with C function
+    # calls, enabling interaction with `ExecutionMassStatus` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of
+    # `ExecutionMassStatus_API` to be dereferenced to `ExecutionMassStatus`, providing access to
+    # `ExecutionMassStatus`'s methods without having to manually access the underlying instance.
+    cdef struct ExecutionMassStatus_API:
+        ExecutionMassStatus * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`CurrencyPair`].
+    #
+    # This struct wraps `CurrencyPair` in a way that makes it compatible with C function
+    # calls, enabling interaction with `CurrencyPair` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `CurrencyPair_API` to be
+    # dereferenced to `CurrencyPair`, providing access to `CurrencyPair`'s methods without
+    # having to manually access the underlying `CurrencyPair` instance.
+    cdef struct CurrencyPair_API:
+        CurrencyPair * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`OptionsContract`].
+    #
+    # This struct wraps `OptionsContract` in a way that makes it compatible with C function
+    # calls, enabling interaction with `OptionsContract` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `OptionsContract_API` to be
+    # dereferenced to `OptionsContract`, providing access to `OptionsContract`'s methods without
+    # having to manually access the underlying `OptionsContract` instance.
+    cdef struct OptionsContract_API:
+        OptionsContract * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`FuturesContract`].
+    #
+    # This struct wraps `FuturesContract` in a way that makes it compatible with C function
+    # calls, enabling interaction with `FuturesContract` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `FuturesContract_API` to be
+    # dereferenced to `FuturesContract`, providing access to `FuturesContract`'s methods without
+    # having to manually access the underlying `FuturesContract` instance.
+    cdef struct FuturesContract_API:
+        FuturesContract * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Equity`].
+    #
+    # This struct wraps `Equity` in a way that makes it compatible with C function
+    # calls, enabling interaction with `Equity` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `Equity_API` to be
+    # dereferenced to `Equity`, providing access to `Equity`'s methods without
+    # having to manually access the underlying `Equity` instance.
+    cdef struct Equity_API:
+        Equity * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`CryptoPerpetual`].
+    #
+    # This struct wraps `CryptoPerpetual` in a way that makes it compatible with C function
+    # calls, enabling interaction with `CryptoPerpetual` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `CryptoPerpetual_API` to be
+    # dereferenced to `CryptoPerpetual`, providing access to `CryptoPerpetual`'s methods without
+    # having to manually access the underlying `CryptoPerpetual` instance.
+    cdef struct CryptoPerpetual_API:
+        CryptoPerpetual * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`ClientOrderIdGenerator`].
+    cdef struct ClientOrderIdGenerator_API:
+        ClientOrderIdGenerator * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`OrderBookDeltas`].
+    cdef struct OrderBookDeltas_API:
+        OrderBookDeltas * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`OrderBookSnapshot`].
+    cdef struct OrderBookSnapshot_API:
+        OrderBookSnapshot * _0;
+
+    cdef struct OrderSubmitted:
+        TraderId_t trader_id;
+        StrategyId_t strategy_id;
+        InstrumentId_t instrument_id;
+        ClientOrderId_t client_order_id;
+        AccountId_t account_id;
+        UUID4_t event_id;
+        uint64_t ts_event;
+        uint64_t ts_init;
+
+    cdef struct OrderAccepted:
+        TraderId_t trader_id;
+        StrategyId_t strategy_id;
+        InstrumentId_t instrument_id;
+        ClientOrderId_t client_order_id;
+        VenueOrderId_t venue_order_id;
+        AccountId_t account_id;
+        UUID4_t event_id;
+        uint64_t ts_event;
+        uint64_t ts_init;
+        bool reconciliation;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`Position`].
+    #
+    # This struct wraps `Position` in a way that makes it compatible with C function
+    # calls, enabling interaction with `Position` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `Position_API` to be
+    # dereferenced to `Position`, providing access to `Position`'s methods without
+    # having to manually access the underlying `Position` instance.
+    cdef struct Position_API:
+        Position * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`OrderListIdGenerator`].
+    cdef struct OrderListIdGenerator_API:
+        OrderListIdGenerator * _0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`PositionIdGenerator`].
+    cdef struct PositionIdGenerator_API:
+        PositionIdGenerator * _0;
+
+    # Represents new trailing stop prices, flagged for whether each has moved.
+    cdef struct TrailingStopPrices:
+        uint8_t has_trigger_price;
+        Price_t trigger_price;
+        uint8_t has_price;
+        Price_t price;
+
+    # Returns a new [`BarSpecification`], written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`BarSpecification`].
+    uint8_t bar_specification_new(uint64_t step,
+                                  uint8_t aggregation,
+                                  uint8_t price_type,
+                                  BarSpecification_t *out);
 
     # Returns a [`BarSpecification`] as a C string pointer.
     const char *bar_specification_to_cstr(const BarSpecification_t *bar_spec);
@@ -529,9 +879,50 @@ cdef extern from "../includes/model.h":
 
     uint8_t bar_specification_ge(const BarSpecification_t *lhs, const BarSpecification_t *rhs);
 
-    BarType_t bar_type_new(InstrumentId_t instrument_id,
-                           BarSpecification_t spec,
-                           uint8_t aggregation_source);
+    # Returns a new [`BarType`], written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`BarType`].
+    uint8_t bar_type_new(InstrumentId_t instrument_id,
+                         BarSpecification_t spec,
+                         uint8_t aggregation_source,
+                         BarType_t *out);
+
+    # Checks the provided C string pointer parses as a [`BarType`].
+    #
+    # Returns zero if the input parsed successfully, otherwise a non-zero status code with
+    # the parsing error set as the last error.
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    uint8_t bar_type_check_parsing(const char *ptr);
+
+    # Returns a [`BarType`] from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for a [`BarType`].
+    uint8_t bar_type_try_from_cstr(const char *ptr, BarType_t *out);
+
+    # Returns a [`BarSpecification`] from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for a [`BarSpecification`].
+    uint8_t bar_specification_try_from_cstr(const char *ptr, BarSpecification_t *out);
 
     void bar_type_drop(BarType_t bar_type);
 
@@ -620,6 +1011,93 @@ cdef extern from "../includes/model.h":
 
     uint64_t orderbook_delta_hash(const OrderBookDelta_t *delta);
 
+    # Creates a new [`OrderBookDeltas`] from a C array of deltas.
+    #
+    # # Safety
+    #
+    # - Assumes `deltas` is either NULL (for no deltas) or points to a valid array of at least
+    #   `len` [`OrderBookDelta`]s.
+    OrderBookDeltas_API orderbook_deltas_new(InstrumentId_t instrument_id,
+                                             const OrderBookDelta_t *deltas,
+                                             uintptr_t len);
+
+    void orderbook_deltas_drop(OrderBookDeltas_API deltas);
+
+    # Drops the [`OrderBookDeltas`], returning its buffer to the pool of the calling thread
+    # for reuse by `orderbook_deltas_new`.
+    void orderbook_deltas_recycle(OrderBookDeltas_API deltas);
+
+    # Returns the statistics of the delta buffer pool of the calling thread.
+    PoolStats orderbook_deltas_pool_stats();
+
+    InstrumentId_t orderbook_deltas_instrument_id(const OrderBookDeltas_API *deltas);
+
+    uintptr_t orderbook_deltas_len(const OrderBookDeltas_API *deltas);
+
+    # Returns a clone of the delta at the given `index`.
+    #
+    # # Panics
+    #
+    # - If `index` is out of bounds.
+    OrderBookDelta_t orderbook_deltas_get(const OrderBookDeltas_API *deltas, uintptr_t index);
+
+    uint8_t orderbook_deltas_flags(const OrderBookDeltas_API *deltas);
+
+    uint64_t orderbook_deltas_sequence(const OrderBookDeltas_API *deltas);
+
+    uint64_t orderbook_deltas_ts_event(const OrderBookDeltas_API *deltas);
+
+    uint64_t orderbook_deltas_ts_init(const OrderBookDeltas_API *deltas);
+
+    # Returns an [`OrderBookDeltas`] as a C string pointer.
+    const char *orderbook_deltas_to_cstr(const OrderBookDeltas_API *deltas);
+
+    # Creates a new [`OrderBookSnapshot`] from C arrays of bid and ask orders.
+    #
+    # # Safety
+    #
+    # - Assumes `bids` points to a valid array of at least `bids_len` [`BookOrder`]s.
+    # - Assumes `asks` points to a valid array of at least `asks_len` [`BookOrder`]s.
+    OrderBookSnapshot_API orderbook_snapshot_new(InstrumentId_t instrument_id,
+                                                 const BookOrder_t *bids,
+                                                 uintptr_t bids_len,
+                                                 const BookOrder_t *asks,
+                                                 uintptr_t asks_len,
+                                                 uint64_t sequence,
+                                                 uint64_t ts_event,
+                                                 uint64_t ts_init);
+
+    void orderbook_snapshot_drop(OrderBookSnapshot_API snapshot);
+
+    InstrumentId_t orderbook_snapshot_instrument_id(const OrderBookSnapshot_API *snapshot);
+
+    uintptr_t orderbook_snapshot_bids_len(const OrderBookSnapshot_API *snapshot);
+
+    uintptr_t orderbook_snapshot_asks_len(const OrderBookSnapshot_API *snapshot);
+
+    # Returns the bid order at the given `index`.
+    #
+    # # Panics
+    #
+    # - If `index` is out of bounds.
+    BookOrder_t orderbook_snapshot_bid(const OrderBookSnapshot_API *snapshot, uintptr_t index);
+
+    # Returns the ask order at the given `index`.
+    #
+    # # Panics
+    #
+    # - If `index` is out of bounds.
+    BookOrder_t orderbook_snapshot_ask(const OrderBookSnapshot_API *snapshot, uintptr_t index);
+
+    uint64_t orderbook_snapshot_sequence(const OrderBookSnapshot_API *snapshot);
+
+    uint64_t orderbook_snapshot_ts_event(const OrderBookSnapshot_API *snapshot);
+
+    uint64_t orderbook_snapshot_ts_init(const OrderBookSnapshot_API *snapshot);
+
+    # Returns an [`OrderBookSnapshot`] as a C string pointer.
+    const char *orderbook_snapshot_to_cstr(const OrderBookSnapshot_API *snapshot);
+
     QuoteTick_t quote_tick_new(InstrumentId_t instrument_id,
                                int64_t bid_price_raw,
                                int64_t ask_price_raw,
@@ -632,6 +1110,26 @@ cdef extern from "../includes/model.h":
                                uint64_t ts_event,
                                uint64_t ts_init);
 
+    # Returns a vector of [`QuoteTick`]s for the given instrument, constructed from parallel
+    # arrays of raw values (all prices and sizes share the given precisions).
+    #
+    # The returned vector must be freed with `vec_quote_ticks_drop`, or `vec_quote_ticks_recycle`
+    # to reuse its allocation for the next call.
+    #
+    # # Safety
+    #
+    # - Assumes each array pointer is valid for at least `len` elements.
+    CVec quote_tick_new_n(InstrumentId_t instrument_id,
+                          const int64_t *bid_prices_raw,
+                          const int64_t *ask_prices_raw,
+                          uint8_t price_prec,
+                          const uint64_t *bid_sizes_raw,
+                          const uint64_t *ask_sizes_raw,
+                          uint8_t size_prec,
+                          const uint64_t *ts_events,
+                          const uint64_t *ts_inits,
+                          uintptr_t len);
+
     void quote_tick_drop(QuoteTick_t tick);
 
     QuoteTick_t quote_tick_clone(const QuoteTick_t *tick);
@@ -649,6 +1147,26 @@ cdef extern from "../includes/model.h":
                                uint64_t ts_event,
                                uint64_t ts_init);
 
+    # Returns a vector of [`TradeTick`]s for the given instrument, constructed from parallel
+    # arrays of raw values (all prices and sizes share the given precisions).
+    #
+    # The returned vector must be freed with `vec_trade_ticks_drop`, or `vec_trade_ticks_recycle`
+    # to reuse its allocation for the next call.
+    #
+    # # Safety
+    #
+    # - Assumes each array pointer is valid for at least `len` elements.
+    CVec trade_tick_new_n(InstrumentId_t instrument_id,
+                          const int64_t *prices_raw,
+                          uint8_t price_prec,
+                          const uint64_t *sizes_raw,
+                          uint8_t size_prec,
+                          const AggressorSide *aggressor_sides,
+                          const TradeId_t *trade_ids,
+                          const uint64_t *ts_events,
+                          const uint64_t *ts_inits,
+                          uintptr_t len);
+
     void trade_tick_drop(TradeTick_t tick);
 
     TradeTick_t trade_tick_clone(const TradeTick_t *tick);
@@ -660,209 +1178,342 @@ cdef extern from "../includes/model.h":
 
     Data_t data_clone(const Data_t *data);
 
+    void vec_quote_ticks_drop(CVec v);
+
+    void vec_trade_ticks_drop(CVec v);
+
+    # Drops the ticks, returning the vector to the pool of the calling thread for reuse by
+    # `quote_tick_new_n`.
+    void vec_quote_ticks_recycle(CVec v);
+
+    # Drops the ticks, returning the vector to the pool of the calling thread for reuse by
+    # `trade_tick_new_n`.
+    void vec_trade_ticks_recycle(CVec v);
+
+    # Returns the statistics of the quote tick vector pool of the calling thread.
+    PoolStats vec_quote_ticks_pool_stats();
+
+    # Returns the statistics of the trade tick vector pool of the calling thread.
+    PoolStats vec_trade_ticks_pool_stats();
+
     const char *account_type_to_cstr(AccountType value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    AccountType account_type_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`AccountType`].
+    uint8_t account_type_try_from_cstr(const char *ptr, AccountType *out);
 
     const char *aggregation_source_to_cstr(AggregationSource value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    AggregationSource aggregation_source_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`AggregationSource`].
+    uint8_t aggregation_source_try_from_cstr(const char *ptr, AggregationSource *out);
 
     const char *aggressor_side_to_cstr(AggressorSide value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    AggressorSide aggressor_side_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`AggressorSide`].
+    uint8_t aggressor_side_try_from_cstr(const char *ptr, AggressorSide *out);
 
     const char *asset_class_to_cstr(AssetClass value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    AssetClass asset_class_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`AssetClass`].
+    uint8_t asset_class_try_from_cstr(const char *ptr, AssetClass *out);
 
     const char *asset_type_to_cstr(AssetType value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    AssetType asset_type_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`AssetType`].
+    uint8_t asset_type_try_from_cstr(const char *ptr, AssetType *out);
 
     const char *bar_aggregation_to_cstr(uint8_t value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    uint8_t bar_aggregation_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`BarAggregation`].
+    uint8_t bar_aggregation_try_from_cstr(const char *ptr, uint8_t *out);
 
     const char *book_action_to_cstr(BookAction value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    # - Assumes `ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for a [`BookAction`].
+    uint8_t book_action_try_from_cstr(const char *ptr, BookAction *out);
+
+    const char *book_checksum_type_to_cstr(BookChecksumType value);
+
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    BookAction book_action_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`BookChecksumType`].
+    uint8_t book_checksum_type_try_from_cstr(const char *ptr, BookChecksumType *out);
 
     const char *book_type_to_cstr(BookType value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    BookType book_type_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`BookType`].
+    uint8_t book_type_try_from_cstr(const char *ptr, BookType *out);
 
     const char *contingency_type_to_cstr(ContingencyType value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    ContingencyType contingency_type_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`ContingencyType`].
+    uint8_t contingency_type_try_from_cstr(const char *ptr, ContingencyType *out);
 
     const char *currency_type_to_cstr(CurrencyType value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    CurrencyType currency_type_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`CurrencyType`].
+    uint8_t currency_type_try_from_cstr(const char *ptr, CurrencyType *out);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    InstrumentCloseType instrument_close_type_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`InstrumentCloseType`].
+    uint8_t instrument_close_type_try_from_cstr(const char *ptr, InstrumentCloseType *out);
 
     const char *instrument_close_type_to_cstr(InstrumentCloseType value);
 
     const char *liquidity_side_to_cstr(LiquiditySide value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    LiquiditySide liquidity_side_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`LiquiditySide`].
+    uint8_t liquidity_side_try_from_cstr(const char *ptr, LiquiditySide *out);
 
     const char *market_status_to_cstr(MarketStatus value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    MarketStatus market_status_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`MarketStatus`].
+    uint8_t market_status_try_from_cstr(const char *ptr, MarketStatus *out);
 
     const char *oms_type_to_cstr(OmsType value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    OmsType oms_type_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`OmsType`].
+    uint8_t oms_type_try_from_cstr(const char *ptr, OmsType *out);
 
     const char *option_kind_to_cstr(OptionKind value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    OptionKind option_kind_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`OptionKind`].
+    uint8_t option_kind_try_from_cstr(const char *ptr, OptionKind *out);
 
     const char *order_side_to_cstr(OrderSide value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    OrderSide order_side_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`OrderSide`].
+    uint8_t order_side_try_from_cstr(const char *ptr, OrderSide *out);
 
     const char *order_status_to_cstr(OrderStatus value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    OrderStatus order_status_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`OrderStatus`].
+    uint8_t order_status_try_from_cstr(const char *ptr, OrderStatus *out);
 
     const char *order_type_to_cstr(OrderType value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    OrderType order_type_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`OrderType`].
+    uint8_t order_type_try_from_cstr(const char *ptr, OrderType *out);
 
     const char *position_side_to_cstr(PositionSide value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    PositionSide position_side_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`PositionSide`].
+    uint8_t position_side_try_from_cstr(const char *ptr, PositionSide *out);
 
     const char *price_type_to_cstr(PriceType value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    PriceType price_type_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`PriceType`].
+    uint8_t price_type_try_from_cstr(const char *ptr, PriceType *out);
 
     const char *time_in_force_to_cstr(TimeInForce value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    TimeInForce time_in_force_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`TimeInForce`].
+    uint8_t time_in_force_try_from_cstr(const char *ptr, TimeInForce *out);
 
     const char *trading_state_to_cstr(TradingState value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    TradingState trading_state_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`TradingState`].
+    uint8_t trading_state_try_from_cstr(const char *ptr, TradingState *out);
 
     const char *trailing_offset_type_to_cstr(TrailingOffsetType value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    TrailingOffsetType trailing_offset_type_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`TrailingOffsetType`].
+    uint8_t trailing_offset_type_try_from_cstr(const char *ptr, TrailingOffsetType *out);
 
     const char *trigger_type_to_cstr(TriggerType value);
 
-    # Returns an enum from a Python string.
+    # Returns an enum from a Python string, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
-    TriggerType trigger_type_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`TriggerType`].
+    uint8_t trigger_type_try_from_cstr(const char *ptr, TriggerType *out);
 
+    # Returns a new [`OrderDenied`] event, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
     # # Safety
     #
     # - Assumes `reason_ptr` is a valid C string pointer.
-    OrderDenied_t order_denied_new(TraderId_t trader_id,
-                                   StrategyId_t strategy_id,
-                                   InstrumentId_t instrument_id,
-                                   ClientOrderId_t client_order_id,
-                                   const char *reason_ptr,
-                                   UUID4_t event_id,
-                                   uint64_t ts_event,
-                                   uint64_t ts_init);
+    # - Assumes `out` is a valid pointer to memory for an [`OrderDenied`].
+    uint8_t order_denied_new(TraderId_t trader_id,
+                             StrategyId_t strategy_id,
+                             InstrumentId_t instrument_id,
+                             ClientOrderId_t client_order_id,
+                             const char *reason_ptr,
+                             UUID4_t event_id,
+                             uint64_t ts_event,
+                             uint64_t ts_init,
+                             OrderDenied_t *out);
 
     # Frees the memory for the given `event` by dropping.
     void order_denied_drop(OrderDenied_t event);
@@ -871,31 +1522,203 @@ cdef extern from "../includes/model.h":
 
     const char *order_denied_reason_to_cstr(const OrderDenied_t *event);
 
-    # Returns a Nautilus identifier from a C string pointer.
-    #
-    # # Safety
-    #
-    # - Assumes `ptr` is a valid C string pointer.
-    AccountId_t account_id_new(const char *ptr);
+    OrderSubmitted order_submitted_new(TraderId_t trader_id,
+                                       StrategyId_t strategy_id,
+                                       InstrumentId_t instrument_id,
+                                       ClientOrderId_t client_order_id,
+                                       AccountId_t account_id,
+                                       UUID4_t event_id,
+                                       uint64_t ts_event,
+                                       uint64_t ts_init);
 
-    AccountId_t account_id_clone(const AccountId_t *account_id);
+    # Frees the memory for the given `event` by dropping.
+    void order_submitted_drop(OrderSubmitted event);
 
-    # Frees the memory for the given `account_id` by dropping.
-    void account_id_drop(AccountId_t account_id);
+    OrderSubmitted order_submitted_clone(const OrderSubmitted *event);
 
-    # Returns an [`AccountId`] as a C string pointer.
-    const char *account_id_to_cstr(const AccountId_t *account_id);
+    OrderAccepted order_accepted_new(TraderId_t trader_id,
+                                     StrategyId_t strategy_id,
+                                     InstrumentId_t instrument_id,
+                                     ClientOrderId_t client_order_id,
+                                     VenueOrderId_t venue_order_id,
+                                     AccountId_t account_id,
+                                     UUID4_t event_id,
+                                     uint64_t ts_event,
+                                     uint64_t ts_init,
+                                     uint8_t reconciliation);
+
+    # Frees the memory for the given `event` by dropping.
+    void order_accepted_drop(OrderAccepted event);
+
+    OrderAccepted order_accepted_clone(const OrderAccepted *event);
+
+    # Returns a new [`OrderRejected`] event, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `reason_ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for an [`OrderRejected`].
+    uint8_t order_rejected_new(TraderId_t trader_id,
+                               StrategyId_t strategy_id,
+                               InstrumentId_t instrument_id,
+                               ClientOrderId_t client_order_id,
+                               VenueOrderId_t venue_order_id,
+                               AccountId_t account_id,
+                               const char *reason_ptr,
+                               UUID4_t event_id,
+                               uint64_t ts_event,
+                               uint64_t ts_init,
+                               uint8_t reconciliation,
+                               OrderRejected *out);
+
+    # Frees the memory for the given `event` by dropping.
+    void order_rejected_drop(OrderRejected event);
+
+    OrderRejected order_rejected_clone(const OrderRejected *event);
+
+    const char *order_rejected_reason_to_cstr(const OrderRejected *event);
+
+    # # Safety
+    #
+    # - Assumes `venue_order_id` and `account_id` are either null or valid pointers.
+    OrderCanceled order_canceled_new(TraderId_t trader_id,
+                                     StrategyId_t strategy_id,
+                                     InstrumentId_t instrument_id,
+                                     ClientOrderId_t client_order_id,
+                                     const VenueOrderId_t *venue_order_id,
+                                     const AccountId_t *account_id,
+                                     UUID4_t event_id,
+                                     uint64_t ts_event,
+                                     uint64_t ts_init,
+                                     uint8_t reconciliation);
+
+    # Frees the memory for the given `event` by dropping.
+    void order_canceled_drop(OrderCanceled event);
+
+    OrderCanceled order_canceled_clone(const OrderCanceled *event);
+
+    # # Safety
+    #
+    # - Assumes `position_id` is either null or a valid pointer.
+    OrderFilled order_filled_new(TraderId_t trader_id,
+                                 StrategyId_t strategy_id,
+                                 InstrumentId_t instrument_id,
+                                 ClientOrderId_t client_order_id,
+                                 VenueOrderId_t venue_order_id,
+                                 AccountId_t account_id,
+                                 TradeId_t trade_id,
+                                 const PositionId_t *position_id,
+                                 OrderSide order_side,
+                                 OrderType order_type,
+                                 Quantity_t last_qty,
+                                 Price_t last_px,
+                                 Currency_t currency,
+                                 Money_t commission,
+                                 LiquiditySide liquidity_side,
+                                 UUID4_t event_id,
+                                 uint64_t ts_event,
+                                 uint64_t ts_init,
+                                 uint8_t reconciliation);
+
+    # Frees the memory for the given `event` by dropping.
+    void order_filled_drop(OrderFilled event);
+
+    OrderFilled order_filled_clone(const OrderFilled *event);
+
+    # Returns the [`PositionId`] for the fill, or a null pointer if there is none.
+    #
+    # The returned pointer borrows from `event`, and is only valid while `event` is alive.
+    const PositionId_t *order_filled_position_id(const OrderFilled *event);
+
+    uint8_t order_filled_is_buy(const OrderFilled *event);
+
+    uint8_t order_filled_is_sell(const OrderFilled *event);
+
+    ClientOrderIdGenerator_API client_order_id_generator_new(TraderId_t trader_id,
+                                                             StrategyId_t strategy_id,
+                                                             uintptr_t initial_count);
+
+    void client_order_id_generator_drop(ClientOrderIdGenerator_API generator);
+
+    uintptr_t client_order_id_generator_count(const ClientOrderIdGenerator_API *generator);
+
+    void client_order_id_generator_set_count(ClientOrderIdGenerator_API *generator,
+                                             uintptr_t count);
+
+    void client_order_id_generator_reset(ClientOrderIdGenerator_API *generator);
+
+    ClientOrderId_t client_order_id_generator_generate(ClientOrderIdGenerator_API *generator,
+                                                       uint64_t ts_now);
+
+    OrderListIdGenerator_API order_list_id_generator_new(TraderId_t trader_id,
+                                                         StrategyId_t strategy_id,
+                                                         uintptr_t initial_count);
+
+    void order_list_id_generator_drop(OrderListIdGenerator_API generator);
+
+    uintptr_t order_list_id_generator_count(const OrderListIdGenerator_API *generator);
+
+    void order_list_id_generator_set_count(OrderListIdGenerator_API *generator, uintptr_t count);
+
+    void order_list_id_generator_reset(OrderListIdGenerator_API *generator);
+
+    OrderListId_t order_list_id_generator_generate(OrderListIdGenerator_API *generator,
+                                                   uint64_t ts_now);
+
+    PositionIdGenerator_API position_id_generator_new(TraderId_t trader_id);
+
+    void position_id_generator_drop(PositionIdGenerator_API generator);
+
+    uintptr_t position_id_generator_count(const PositionIdGenerator_API *generator,
+                                          StrategyId_t strategy_id);
+
+    void position_id_generator_set_count(PositionIdGenerator_API *generator,
+                                         StrategyId_t strategy_id,
+                                         uintptr_t count);
+
+    void position_id_generator_reset(PositionIdGenerator_API *generator);
+
+    PositionId_t position_id_generator_generate(PositionIdGenerator_API *generator,
+                                                StrategyId_t strategy_id,
+                                                uint8_t flipped,
+                                                uint64_t ts_now);
+
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for a [`AccountId`].
+    uint8_t account_id_try_new(const char *ptr, AccountId_t *out);
+
+    AccountId_t account_id_clone(const AccountId_t *account_id);
+
+    # Frees the memory for the given `account_id` by dropping.
+    void account_id_drop(AccountId_t account_id);
+
+    # Returns an [`AccountId`] as a C string pointer.
+    const char *account_id_to_cstr(const AccountId_t *account_id);
 
     uint8_t account_id_eq(const AccountId_t *lhs, const AccountId_t *rhs);
 
     uint64_t account_id_hash(const AccountId_t *account_id);
 
-    # Returns a Nautilus identifier from C string pointer.
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    ClientId_t client_id_new(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`ClientId`].
+    uint8_t client_id_try_new(const char *ptr, ClientId_t *out);
 
     ClientId_t client_id_clone(const ClientId_t *client_id);
 
@@ -909,12 +1732,16 @@ cdef extern from "../includes/model.h":
 
     uint64_t client_id_hash(const ClientId_t *client_id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    ClientOrderId_t client_order_id_new(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`ClientOrderId`].
+    uint8_t client_order_id_try_new(const char *ptr, ClientOrderId_t *out);
 
     ClientOrderId_t client_order_id_clone(const ClientOrderId_t *client_order_id);
 
@@ -928,12 +1755,16 @@ cdef extern from "../includes/model.h":
 
     uint64_t client_order_id_hash(const ClientOrderId_t *client_order_id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    ComponentId_t component_id_new(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`ComponentId`].
+    uint8_t component_id_try_new(const char *ptr, ComponentId_t *out);
 
     ComponentId_t component_id_clone(const ComponentId_t *component_id);
 
@@ -947,12 +1778,16 @@ cdef extern from "../includes/model.h":
 
     uint64_t component_id_hash(const ComponentId_t *component_id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    ExecAlgorithmId_t exec_algorithm_id_new(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`ExecAlgorithmId`].
+    uint8_t exec_algorithm_id_try_new(const char *ptr, ExecAlgorithmId_t *out);
 
     ExecAlgorithmId_t exec_algorithm_id_clone(const ExecAlgorithmId_t *exec_algorithm_id);
 
@@ -968,12 +1803,22 @@ cdef extern from "../includes/model.h":
 
     InstrumentId_t instrument_id_new(const Symbol_t *symbol, const Venue_t *venue);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    InstrumentId_t instrument_id_new_from_cstr(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for an [`InstrumentId`].
+    uint8_t instrument_id_try_from_cstr(const char *ptr, InstrumentId_t *out);
+
+    # Returns a clone of the [`Symbol`] component of the `instrument_id`.
+    Symbol_t instrument_id_symbol(const InstrumentId_t *instrument_id);
+
+    # Returns a clone of the [`Venue`] component of the `instrument_id`.
+    Venue_t instrument_id_venue(const InstrumentId_t *instrument_id);
 
     InstrumentId_t instrument_id_clone(const InstrumentId_t *instrument_id);
 
@@ -987,12 +1832,22 @@ cdef extern from "../includes/model.h":
 
     uint64_t instrument_id_hash(const InstrumentId_t *instrument_id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Returns the number of distinct identifier values held by the interning pool.
+    uintptr_t identifier_pool_count();
+
+    # Removes all unreferenced identifier values from the interning pool.
+    uintptr_t identifier_pool_purge();
+
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    OrderListId_t order_list_id_new(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`OrderListId`].
+    uint8_t order_list_id_try_new(const char *ptr, OrderListId_t *out);
 
     OrderListId_t order_list_id_clone(const OrderListId_t *order_list_id);
 
@@ -1006,12 +1861,16 @@ cdef extern from "../includes/model.h":
 
     uint64_t order_list_id_hash(const OrderListId_t *order_list_id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    PositionId_t position_id_new(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`PositionId`].
+    uint8_t position_id_try_new(const char *ptr, PositionId_t *out);
 
     PositionId_t position_id_clone(const PositionId_t *position_id);
 
@@ -1025,12 +1884,16 @@ cdef extern from "../includes/model.h":
 
     uint64_t position_id_hash(const PositionId_t *position_id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    StrategyId_t strategy_id_new(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`StrategyId`].
+    uint8_t strategy_id_try_new(const char *ptr, StrategyId_t *out);
 
     StrategyId_t strategy_id_clone(const StrategyId_t *strategy_id);
 
@@ -1040,12 +1903,16 @@ cdef extern from "../includes/model.h":
     # Returns a [`StrategyId`] as a C string pointer.
     const char *strategy_id_to_cstr(const StrategyId_t *strategy_id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    Symbol_t symbol_new(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`Symbol`].
+    uint8_t symbol_try_new(const char *ptr, Symbol_t *out);
 
     Symbol_t symbol_clone(const Symbol_t *symbol);
 
@@ -1059,12 +1926,66 @@ cdef extern from "../includes/model.h":
 
     uint64_t symbol_hash(const Symbol_t *symbol);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    SymbologyNormalizer_API symbology_normalizer_new();
+
+    void symbology_normalizer_drop(SymbologyNormalizer_API normalizer);
+
+    # Registers a [`SlashPairRule`] for the `venue`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case no rule is registered).
+    #
+    # # Safety
+    #
+    # - Assumes `quotes_ptr` is a valid C string pointer of a JSON format list of strings.
+    uint8_t symbology_normalizer_register_slash_pair_rule(SymbologyNormalizer_API *normalizer,
+                                                          const Venue_t *venue,
+                                                          const char *quotes_ptr);
+
+    void symbology_normalizer_register_futures_month_code_rule(SymbologyNormalizer_API *normalizer,
+                                                               const Venue_t *venue,
+                                                               uint16_t reference_year);
+
+    void symbology_normalizer_register_occ_option_rule(SymbologyNormalizer_API *normalizer,
+                                                       const Venue_t *venue);
+
+    # Returns the Nautilus symbol for the venue-native symbol at `native_ptr`, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `native_ptr` is a valid C string pointer, or null.
+    # - Assumes `out` is a valid pointer to memory for a [`Symbol`].
+    uint8_t symbology_normalizer_normalize(const SymbologyNormalizer_API *normalizer,
+                                           const Venue_t *venue,
+                                           const char *native_ptr,
+                                           Symbol_t *out);
+
+    # Returns the venue-native symbol for the `symbol` as a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a C string pointer.
+    uint8_t symbology_normalizer_to_native(const SymbologyNormalizer_API *normalizer,
+                                           const Venue_t *venue,
+                                           const Symbol_t *symbol,
+                                           const char **out);
+
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    TradeId_t trade_id_new(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`TradeId`].
+    uint8_t trade_id_try_new(const char *ptr, TradeId_t *out);
 
     TradeId_t trade_id_clone(const TradeId_t *trade_id);
 
@@ -1078,12 +1999,36 @@ cdef extern from "../includes/model.h":
 
     uint64_t trade_id_hash(const TradeId_t *trade_id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Returns an inline trade ID from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    TraderId_t trader_id_new(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for an [`InlineTradeId`].
+    uint8_t inline_trade_id_try_new(const char *ptr, InlineTradeId *out);
+
+    # Returns a pointer to the [`InlineTradeId`] value as a C string.
+    #
+    # The pointer is borrowed from `trade_id` and must not be freed.
+    const char *inline_trade_id_to_cstr(const InlineTradeId *trade_id);
+
+    uint8_t inline_trade_id_eq(const InlineTradeId *lhs, const InlineTradeId *rhs);
+
+    uint64_t inline_trade_id_hash(const InlineTradeId *trade_id);
+
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for a [`TraderId`].
+    uint8_t trader_id_try_new(const char *ptr, TraderId_t *out);
 
     TraderId_t trader_id_clone(const TraderId_t *trader_id);
 
@@ -1093,12 +2038,16 @@ cdef extern from "../includes/model.h":
     # Returns a [`TraderId`] as a C string pointer.
     const char *trader_id_to_cstr(const TraderId_t *trader_id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    Venue_t venue_new(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`Venue`].
+    uint8_t venue_try_new(const char *ptr, Venue_t *out);
 
     Venue_t venue_clone(const Venue_t *venue);
 
@@ -1112,12 +2061,16 @@ cdef extern from "../includes/model.h":
 
     uint64_t venue_hash(const Venue_t *venue);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Returns a Nautilus identifier from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise the `IdentifierError` code (in which case
+    # nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `ptr` is a valid C string pointer.
-    VenueOrderId_t venue_order_id_new(const char *ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`VenueOrderId`].
+    uint8_t venue_order_id_try_new(const char *ptr, VenueOrderId_t *out);
 
     VenueOrderId_t venue_order_id_clone(const VenueOrderId_t *venue_order_id);
 
@@ -1130,14 +2083,254 @@ cdef extern from "../includes/model.h":
 
     uint64_t venue_order_id_hash(const VenueOrderId_t *venue_order_id);
 
+    # Writes a new [`CryptoPerpetual`] to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `max_quantity`, `min_quantity`, `max_price` and `min_price` are each either
+    #   null or a valid pointer.
+    # - Assumes `out` is a valid pointer to memory for a [`CryptoPerpetual_API`].
+    uint8_t crypto_perpetual_new(InstrumentId_t id,
+                                 Symbol_t native_symbol,
+                                 Currency_t base_currency,
+                                 Currency_t quote_currency,
+                                 Currency_t settlement_currency,
+                                 uint8_t is_inverse,
+                                 uint8_t price_precision,
+                                 uint8_t size_precision,
+                                 Price_t price_increment,
+                                 Quantity_t size_increment,
+                                 const Quantity_t *max_quantity,
+                                 const Quantity_t *min_quantity,
+                                 const Price_t *max_price,
+                                 const Price_t *min_price,
+                                 double margin_init,
+                                 double margin_maint,
+                                 double maker_fee,
+                                 double taker_fee,
+                                 CryptoPerpetual_API *out);
+
+    void crypto_perpetual_drop(CryptoPerpetual_API instrument);
+
+    InstrumentId_t crypto_perpetual_id(const CryptoPerpetual_API *instrument);
+
+    uint8_t crypto_perpetual_price_precision(const CryptoPerpetual_API *instrument);
+
+    uint8_t crypto_perpetual_size_precision(const CryptoPerpetual_API *instrument);
+
+    Price_t crypto_perpetual_make_price(const CryptoPerpetual_API *instrument, double value);
+
+    Quantity_t crypto_perpetual_make_qty(const CryptoPerpetual_API *instrument, double value);
+
+    Money_t crypto_perpetual_notional_value(const CryptoPerpetual_API *instrument,
+                                            Quantity_t quantity,
+                                            Price_t price);
+
+    uint8_t crypto_perpetual_is_inverse(const CryptoPerpetual_API *instrument);
+
+    Currency_t crypto_perpetual_settlement_currency(const CryptoPerpetual_API *instrument);
+
+    # Writes a new [`CurrencyPair`] to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `lot_size`, `max_quantity`, `min_quantity`, `max_price` and `min_price` are
+    #   each either null or a valid pointer.
+    # - Assumes `out` is a valid pointer to memory for a [`CurrencyPair_API`].
+    uint8_t currency_pair_new(InstrumentId_t id,
+                              Symbol_t native_symbol,
+                              Currency_t base_currency,
+                              Currency_t quote_currency,
+                              uint8_t price_precision,
+                              uint8_t size_precision,
+                              Price_t price_increment,
+                              Quantity_t size_increment,
+                              const Quantity_t *lot_size,
+                              const Quantity_t *max_quantity,
+                              const Quantity_t *min_quantity,
+                              const Price_t *max_price,
+                              const Price_t *min_price,
+                              double margin_init,
+                              double margin_maint,
+                              double maker_fee,
+                              double taker_fee,
+                              CurrencyPair_API *out);
+
+    void currency_pair_drop(CurrencyPair_API instrument);
+
+    InstrumentId_t currency_pair_id(const CurrencyPair_API *instrument);
+
+    uint8_t currency_pair_price_precision(const CurrencyPair_API *instrument);
+
+    uint8_t currency_pair_size_precision(const CurrencyPair_API *instrument);
+
+    Price_t currency_pair_make_price(const CurrencyPair_API *instrument, double value);
+
+    Quantity_t currency_pair_make_qty(const CurrencyPair_API *instrument, double value);
+
+    Money_t currency_pair_notional_value(const CurrencyPair_API *instrument,
+                                         Quantity_t quantity,
+                                         Price_t price);
+
+    # Writes a new [`Equity`] to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `lot_size` is either null or a valid `Quantity` pointer.
+    # - Assumes `isin_ptr` is either null or a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for an [`Equity_API`].
+    uint8_t equity_new(InstrumentId_t id,
+                       Symbol_t native_symbol,
+                       Currency_t currency,
+                       uint8_t price_precision,
+                       Price_t price_increment,
+                       Quantity_t multiplier,
+                       const Quantity_t *lot_size,
+                       const char *isin_ptr,
+                       double margin_init,
+                       double margin_maint,
+                       double maker_fee,
+                       double taker_fee,
+                       Equity_API *out);
+
+    void equity_drop(Equity_API instrument);
+
+    InstrumentId_t equity_id(const Equity_API *instrument);
+
+    uint8_t equity_price_precision(const Equity_API *instrument);
+
+    uint8_t equity_size_precision(const Equity_API *instrument);
+
+    Price_t equity_make_price(const Equity_API *instrument, double value);
+
+    Quantity_t equity_make_qty(const Equity_API *instrument, double value);
+
+    Money_t equity_notional_value(const Equity_API *instrument, Quantity_t quantity, Price_t price);
+
+    # Writes a new [`FuturesContract`] to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `lot_size` is either null or a valid `Quantity` pointer.
+    # - Assumes `underlying_ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for a [`FuturesContract_API`].
+    uint8_t futures_contract_new(InstrumentId_t id,
+                                 Symbol_t native_symbol,
+                                 AssetClass asset_class,
+                                 Currency_t currency,
+                                 uint8_t price_precision,
+                                 Price_t price_increment,
+                                 Quantity_t multiplier,
+                                 const Quantity_t *lot_size,
+                                 const char *underlying_ptr,
+                                 uint64_t activation_ns,
+                                 uint64_t expiration_ns,
+                                 double margin_init,
+                                 double margin_maint,
+                                 double maker_fee,
+                                 double taker_fee,
+                                 FuturesContract_API *out);
+
+    void futures_contract_drop(FuturesContract_API instrument);
+
+    InstrumentId_t futures_contract_id(const FuturesContract_API *instrument);
+
+    uint8_t futures_contract_price_precision(const FuturesContract_API *instrument);
+
+    uint8_t futures_contract_size_precision(const FuturesContract_API *instrument);
+
+    Price_t futures_contract_make_price(const FuturesContract_API *instrument, double value);
+
+    Quantity_t futures_contract_make_qty(const FuturesContract_API *instrument, double value);
+
+    Money_t futures_contract_notional_value(const FuturesContract_API *instrument,
+                                            Quantity_t quantity,
+                                            Price_t price);
+
+    uint64_t futures_contract_activation_ns(const FuturesContract_API *instrument);
+
+    uint64_t futures_contract_expiration_ns(const FuturesContract_API *instrument);
+
+    # Writes a new [`OptionsContract`] to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `lot_size` is either null or a valid `Quantity` pointer.
+    # - Assumes `underlying_ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for an [`OptionsContract_API`].
+    uint8_t options_contract_new(InstrumentId_t id,
+                                 Symbol_t native_symbol,
+                                 AssetClass asset_class,
+                                 Currency_t currency,
+                                 uint8_t price_precision,
+                                 Price_t price_increment,
+                                 Quantity_t multiplier,
+                                 const Quantity_t *lot_size,
+                                 const char *underlying_ptr,
+                                 OptionKind kind,
+                                 uint64_t activation_ns,
+                                 uint64_t expiration_ns,
+                                 Price_t strike_price,
+                                 double margin_init,
+                                 double margin_maint,
+                                 double maker_fee,
+                                 double taker_fee,
+                                 OptionsContract_API *out);
+
+    void options_contract_drop(OptionsContract_API instrument);
+
+    InstrumentId_t options_contract_id(const OptionsContract_API *instrument);
+
+    uint8_t options_contract_price_precision(const OptionsContract_API *instrument);
+
+    uint8_t options_contract_size_precision(const OptionsContract_API *instrument);
+
+    Price_t options_contract_make_price(const OptionsContract_API *instrument, double value);
+
+    Quantity_t options_contract_make_qty(const OptionsContract_API *instrument, double value);
+
+    Money_t options_contract_notional_value(const OptionsContract_API *instrument,
+                                            Quantity_t quantity,
+                                            Price_t price);
+
+    OptionKind options_contract_kind(const OptionsContract_API *instrument);
+
+    Price_t options_contract_strike_price(const OptionsContract_API *instrument);
+
+    uint64_t options_contract_activation_ns(const OptionsContract_API *instrument);
+
+    uint64_t options_contract_expiration_ns(const OptionsContract_API *instrument);
+
+    # Returns a new synthetic instrument, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
     # # Safety
     #
     # - Assumes `components_ptr` is a valid C string pointer of a JSON format list of strings.
     # - Assumes `formula_ptr` is a valid C string pointer.
-    SyntheticInstrument_API synthetic_instrument_new(Symbol_t symbol,
-                                                     uint8_t precision,
-                                                     const char *components_ptr,
-                                                     const char *formula_ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`SyntheticInstrument_API`].
+    uint8_t synthetic_instrument_try_new(Symbol_t symbol,
+                                         uint8_t precision,
+                                         const char *components_ptr,
+                                         const char *formula_ptr,
+                                         SyntheticInstrument_API *out);
 
     void synthetic_instrument_drop(SyntheticInstrument_API synth);
 
@@ -1155,13 +2348,29 @@ cdef extern from "../includes/model.h":
     uint8_t synthetic_instrument_is_valid_formula(const SyntheticInstrument_API *synth,
                                                   const char *formula_ptr);
 
+    # Changes the formula of the synthetic instrument.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case the formula is unchanged).
+    #
     # # Safety
     #
     # - Assumes `formula_ptr` is a valid C string pointer.
-    void synthetic_instrument_change_formula(SyntheticInstrument_API *synth,
-                                             const char *formula_ptr);
+    uint8_t synthetic_instrument_try_change_formula(SyntheticInstrument_API *synth,
+                                                    const char *formula_ptr);
 
-    Price_t synthetic_instrument_calculate(SyntheticInstrument_API *synth, const CVec *inputs_ptr);
+    # Calculates the price of the synthetic instrument from the given `inputs`, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `inputs_ptr` is a valid [`CVec`] of `f64` values.
+    # - Assumes `out` is a valid pointer to memory for a [`Price`].
+    uint8_t synthetic_instrument_try_calculate(SyntheticInstrument_API *synth,
+                                               const CVec *inputs_ptr,
+                                               Price_t *out);
 
     OrderBook_API orderbook_new(InstrumentId_t instrument_id, BookType book_type);
 
@@ -1202,21 +2411,73 @@ cdef extern from "../includes/model.h":
 
     void orderbook_apply_delta(OrderBook_API *book, OrderBookDelta_t delta);
 
+    void orderbook_apply_deltas(OrderBook_API *book, const OrderBookDeltas_API *deltas);
+
+    void orderbook_apply_snapshot(OrderBook_API *book, const OrderBookSnapshot_API *snapshot);
+
     uint8_t orderbook_has_bid(OrderBook_API *book);
 
     uint8_t orderbook_has_ask(OrderBook_API *book);
 
-    Price_t orderbook_best_bid_price(OrderBook_API *book);
+    # Returns the best bid price, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Price`].
+    uint8_t orderbook_best_bid_price(const OrderBook_API *book, Price_t *out);
 
-    Price_t orderbook_best_ask_price(OrderBook_API *book);
+    # Returns the best ask price, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Price`].
+    uint8_t orderbook_best_ask_price(const OrderBook_API *book, Price_t *out);
 
-    Quantity_t orderbook_best_bid_size(OrderBook_API *book);
+    # Returns the best bid size, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+    uint8_t orderbook_best_bid_size(const OrderBook_API *book, Quantity_t *out);
 
-    Quantity_t orderbook_best_ask_size(OrderBook_API *book);
+    # Returns the best ask size, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+    uint8_t orderbook_best_ask_size(const OrderBook_API *book, Quantity_t *out);
 
-    double orderbook_spread(OrderBook_API *book);
+    # Returns the top of book spread, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`f64`].
+    uint8_t orderbook_spread(const OrderBook_API *book, double *out);
 
-    double orderbook_midpoint(OrderBook_API *book);
+    # Returns the mid point, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`f64`].
+    uint8_t orderbook_midpoint(const OrderBook_API *book, double *out);
 
     void orderbook_update_quote_tick(OrderBook_API *book, const QuoteTick_t *tick);
 
@@ -1224,24 +2485,371 @@ cdef extern from "../includes/model.h":
 
     CVec orderbook_simulate_fills(const OrderBook_API *book, BookOrder_t order);
 
-    void orderbook_check_integrity(const OrderBook_API *book);
+    # Checks the integrity of the `book`.
+    #
+    # Returns zero if every check passes, otherwise a non-zero status code with the last
+    # error set.
+    uint8_t orderbook_check_integrity(const OrderBook_API *book);
+
+    # Returns the aggregated price and size for up to `num_levels` bid levels.
+    #
+    # The returned vector must be freed with `vec_fills_drop`.
+    CVec orderbook_bids_depth(const OrderBook_API *book, uintptr_t num_levels);
+
+    # Returns the aggregated price and size for up to `num_levels` ask levels.
+    #
+    # The returned vector must be freed with `vec_fills_drop`.
+    CVec orderbook_asks_depth(const OrderBook_API *book, uintptr_t num_levels);
+
+    # Returns the size aggregated into `num_bands` price bands of `band_width` on the given
+    # `side`, starting from the band containing the best price.
+    #
+    # The returned vector must be freed with `vec_fills_drop`.
+    CVec orderbook_depth_bands(const OrderBook_API *book,
+                               OrderSide side,
+                               Price_t band_width,
+                               uintptr_t num_bands);
+
+    double orderbook_get_quantity_for_price(const OrderBook_API *book,
+                                            Price_t price,
+                                            OrderSide side);
+
+    # Returns the volume imbalance over the top `depth` levels (0.0 if the book is empty).
+    double orderbook_volume_imbalance(const OrderBook_API *book, uintptr_t depth);
+
+    # Returns the size weighted microprice, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`f64`].
+    uint8_t orderbook_microprice(const OrderBook_API *book, double *out);
+
+    # Returns the average fill price from consuming `qty` of liquidity for an order on the
+    # given `order_side`, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`f64`].
+    uint8_t orderbook_get_avg_px_for_quantity(const OrderBook_API *book,
+                                              Quantity_t qty,
+                                              OrderSide order_side,
+                                              double *out);
+
+    uint32_t orderbook_checksum(const OrderBook_API *book, uintptr_t depth);
+
+    # Returns 1 if the checksum over the top `depth` levels matches `expected`, otherwise 0.
+    uint8_t orderbook_check_checksum(const OrderBook_API *book, uint32_t expected, uintptr_t depth);
+
+    void orderbook_set_checksum_type(OrderBook_API *book, BookChecksumType checksum_type);
+
+    uint32_t orderbook_venue_checksum(const OrderBook_API *book, BookChecksumType checksum_type);
+
+    # Returns 1 if the book matches the `expected` venue checksum, or has no checksum type,
+    # otherwise 0.
+    uint8_t orderbook_verify_checksum(const OrderBook_API *book, uint32_t expected);
 
     void vec_fills_drop(CVec v);
 
     # Returns a pretty printed [`OrderBook`] number of levels per side, as a C string pointer.
     const char *orderbook_pprint_to_cstr(const OrderBook_API *book, uintptr_t num_levels);
 
-    # Returns a [`Currency`] from pointers and primitives.
+    Order_API order_new(const OrderInitialized *init);
+
+    void order_drop(Order_API order);
+
+    OrderStatus order_status(const Order_API *order);
+
+    OrderSide order_side(const Order_API *order);
+
+    OrderType order_type(const Order_API *order);
+
+    ClientOrderId_t order_client_order_id(const Order_API *order);
+
+    InstrumentId_t order_instrument_id(const Order_API *order);
+
+    Quantity_t order_quantity(const Order_API *order);
+
+    Quantity_t order_filled_qty(const Order_API *order);
+
+    Quantity_t order_leaves_qty(const Order_API *order);
+
+    # Returns the average fill price for the order, or 0.0 if it has not been filled.
+    double order_avg_px(const Order_API *order);
+
+    uintptr_t order_event_count(const Order_API *order);
+
+    uint8_t order_is_open(const Order_API *order);
+
+    uint8_t order_is_closed(const Order_API *order);
+
+    uint8_t order_is_inflight(const Order_API *order);
+
+    uint8_t order_is_pending_update(const Order_API *order);
+
+    uint8_t order_is_pending_cancel(const Order_API *order);
+
+    uint8_t order_apply_denied(Order_API *order, OrderDenied_t event);
+
+    uint8_t order_apply_submitted(Order_API *order, OrderSubmitted event);
+
+    uint8_t order_apply_accepted(Order_API *order, OrderAccepted event);
+
+    uint8_t order_apply_rejected(Order_API *order, OrderRejected event);
+
+    uint8_t order_apply_canceled(Order_API *order, OrderCanceled event);
+
+    uint8_t order_apply_expired(Order_API *order, OrderExpired event);
+
+    uint8_t order_apply_triggered(Order_API *order, OrderTriggered event);
+
+    uint8_t order_apply_pending_update(Order_API *order, OrderPendingUpdate event);
+
+    uint8_t order_apply_pending_cancel(Order_API *order, OrderPendingCancel event);
+
+    uint8_t order_apply_modify_rejected(Order_API *order, OrderModifyRejected event);
+
+    uint8_t order_apply_cancel_rejected(Order_API *order, OrderCancelRejected event);
+
+    uint8_t order_apply_updated(Order_API *order, OrderUpdated event);
+
+    # Applies the fill `event` to the `order`, as either a partial or complete fill depending
+    # on the orders remaining quantity.
+    uint8_t order_apply_filled(Order_API *order, OrderFilled event);
+
+    # Returns the metadata of the `order` as a JSON object, as a C string pointer.
+    const char *order_metadata_to_cstr(const Order_API *order);
+
+    # Replaces the metadata of the `order` with the JSON object of string values at `ptr`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case the metadata is unchanged).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    uint8_t order_set_metadata_json(Order_API *order, const char *ptr);
+
+    # Sets the metadata `value` for the `key` of the `order`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case the metadata is unchanged).
+    #
+    # # Safety
+    #
+    # - Assumes `key_ptr` and `value_ptr` are valid C string pointers, or null.
+    uint8_t order_set_metadata(Order_API *order, const char *key_ptr, const char *value_ptr);
+
+    # Removes the metadata for the `key` of the `order`, writing 1 to `removed` if the order
+    # had metadata for the key, otherwise 0.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `removed`).
+    #
+    # # Safety
+    #
+    # - Assumes `key_ptr` is a valid C string pointer, or null.
+    # - Assumes `removed` is a valid pointer to write the result to.
+    uint8_t order_remove_metadata(Order_API *order, const char *key_ptr, uint8_t *removed);
+
+    # Calculates new trailing stop prices for the `order` at the current market, written to `out`.
+    #
+    # Any of `bid`, `ask` and `last` may be NULL where the price is not available.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `bid`, `ask` and `last` are either NULL or valid pointers to a [`Price`].
+    # - Assumes `out` is a valid pointer to memory for a [`TrailingStopPrices`].
+    uint8_t order_trailing_stop_calculate(const Order_API *order,
+                                          Price_t price_increment,
+                                          const Price_t *bid,
+                                          const Price_t *ask,
+                                          const Price_t *last,
+                                          TrailingStopPrices *out);
+
+    Position_API position_new(const BaseInstrument *instrument, OrderFilled fill);
+
+    void position_drop(Position_API position);
+
+    void position_apply(Position_API *position, OrderFilled fill);
+
+    PositionId_t position_id(const Position_API *position);
+
+    InstrumentId_t position_instrument_id(const Position_API *position);
+
+    OrderSide position_entry(const Position_API *position);
+
+    PositionSide position_side(const Position_API *position);
+
+    double position_signed_qty(const Position_API *position);
+
+    Quantity_t position_quantity(const Position_API *position);
+
+    Quantity_t position_peak_qty(const Position_API *position);
+
+    double position_avg_px_open(const Position_API *position);
+
+    # Returns the average close price for the position, or 0.0 if it has not been reduced.
+    double position_avg_px_close(const Position_API *position);
+
+    # Returns the realized return for the position, or 0.0 if it has not been reduced.
+    double position_realized_return(const Position_API *position);
+
+    # Returns the realized PnL for the position, or zero in the cost currency if none.
+    Money_t position_realized_pnl(const Position_API *position);
+
+    Money_t position_unrealized_pnl(const Position_API *position, Price_t last);
+
+    Money_t position_total_pnl(const Position_API *position, Price_t last);
+
+    Money_t position_notional_value(const Position_API *position, Price_t last);
+
+    uintptr_t position_event_count(const Position_API *position);
+
+    uint8_t position_is_opposite_side(const Position_API *position, OrderSide side);
+
+    uint8_t position_is_long(const Position_API *position);
+
+    uint8_t position_is_short(const Position_API *position);
+
+    uint8_t position_is_open(const Position_API *position);
+
+    uint8_t position_is_closed(const Position_API *position);
+
+    # Writes a new [`OrderStatusReport`] to `out`.
+    #
+    # The optional enums take their `NO_*` variant for no value, `expire_time` and
+    # `ts_triggered` take zero, and `avg_px` takes NaN.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `client_order_id`, `order_list_id`, `price`, `trigger_price`, `limit_offset`,
+    #   `trailing_offset` and `display_qty` are each either null or a valid pointer.
+    # - Assumes `cancel_reason` is either null or a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for an [`OrderStatusReport_API`].
+    uint8_t order_status_report_new(AccountId_t account_id,
+                                    InstrumentId_t instrument_id,
+                                    const ClientOrderId_t *client_order_id,
+                                    const OrderListId_t *order_list_id,
+                                    VenueOrderId_t venue_order_id,
+                                    OrderSide order_side,
+                                    OrderType order_type,
+                                    ContingencyType contingency_type,
+                                    TimeInForce time_in_force,
+                                    uint64_t expire_time,
+                                    OrderStatus order_status,
+                                    const Price_t *price,
+                                    const Price_t *trigger_price,
+                                    TriggerType trigger_type,
+                                    const Price_t *limit_offset,
+                                    const Price_t *trailing_offset,
+                                    TrailingOffsetType trailing_offset_type,
+                                    Quantity_t quantity,
+                                    Quantity_t filled_qty,
+                                    const Quantity_t *display_qty,
+                                    double avg_px,
+                                    uint8_t post_only,
+                                    uint8_t reduce_only,
+                                    const char *cancel_reason,
+                                    UUID4_t report_id,
+                                    uint64_t ts_accepted,
+                                    uint64_t ts_triggered,
+                                    uint64_t ts_last,
+                                    uint64_t ts_init,
+                                    OrderStatusReport_API *out);
+
+    void order_status_report_drop(OrderStatusReport_API report);
+
+    # Returns a new [`FillReport`].
+    #
+    # # Safety
+    #
+    # - Assumes `client_order_id` and `venue_position_id` are each either null or a valid
+    #   pointer.
+    FillReport_API fill_report_new(AccountId_t account_id,
+                                   InstrumentId_t instrument_id,
+                                   const ClientOrderId_t *client_order_id,
+                                   VenueOrderId_t venue_order_id,
+                                   const PositionId_t *venue_position_id,
+                                   TradeId_t trade_id,
+                                   OrderSide order_side,
+                                   Quantity_t last_qty,
+                                   Price_t last_px,
+                                   Money_t commission,
+                                   LiquiditySide liquidity_side,
+                                   UUID4_t report_id,
+                                   uint64_t ts_event,
+                                   uint64_t ts_init);
+
+    void fill_report_drop(FillReport_API report);
+
+    # Returns a new [`PositionStatusReport`].
+    #
+    # # Safety
+    #
+    # - Assumes `venue_position_id` is either null or a valid pointer.
+    PositionStatusReport_API position_status_report_new(AccountId_t account_id,
+                                                        InstrumentId_t instrument_id,
+                                                        PositionSide position_side,
+                                                        Quantity_t quantity,
+                                                        const PositionId_t *venue_position_id,
+                                                        UUID4_t report_id,
+                                                        uint64_t ts_last,
+                                                        uint64_t ts_init);
+
+    void position_status_report_drop(PositionStatusReport_API report);
+
+    ExecutionMassStatus_API execution_mass_status_new(ClientId_t client_id,
+                                                      AccountId_t account_id,
+                                                      Venue_t venue,
+                                                      UUID4_t report_id,
+                                                      uint64_t ts_init);
+
+    void execution_mass_status_drop(ExecutionMassStatus_API mass_status);
+
+    uintptr_t execution_mass_status_order_report_count(const ExecutionMassStatus_API *mass_status);
+
+    uintptr_t execution_mass_status_position_report_count(const ExecutionMassStatus_API *mass_status);
+
+    void execution_mass_status_add_order_report(ExecutionMassStatus_API *mass_status,
+                                                const OrderStatusReport_API *report);
+
+    void execution_mass_status_add_fill_report(ExecutionMassStatus_API *mass_status,
+                                               const FillReport_API *report);
+
+    void execution_mass_status_add_position_report(ExecutionMassStatus_API *mass_status,
+                                                   const PositionStatusReport_API *report);
+
+    uintptr_t execution_mass_status_fill_report_count(const ExecutionMassStatus_API *mass_status,
+                                                      const VenueOrderId_t *venue_order_id);
+
+    # Returns a [`Currency`] from pointers and primitives, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
     #
     # # Safety
     #
     # - Assumes `code_ptr` is a valid C string pointer.
     # - Assumes `name_ptr` is a valid C string pointer.
-    Currency_t currency_from_py(const char *code_ptr,
-                                uint8_t precision,
-                                uint16_t iso4217,
-                                const char *name_ptr,
-                                CurrencyType currency_type);
+    # - Assumes `out` is a valid pointer to memory for a [`Currency`].
+    uint8_t currency_from_py(const char *code_ptr,
+                             uint8_t precision,
+                             uint16_t iso4217,
+                             const char *name_ptr,
+                             CurrencyType currency_type,
+                             Currency_t *out);
 
     Currency_t currency_clone(const Currency_t *currency);
 
@@ -1259,17 +2867,41 @@ cdef extern from "../includes/model.h":
 
     void currency_register(Currency_t currency);
 
+    # Writes 1 to `out` if a currency with the code at `code_ptr` is registered, otherwise 0.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
     # # Safety
     #
     # - Assumes `code_ptr` is borrowed from a valid Python UTF-8 `str`.
-    uint8_t currency_exists(const char *code_ptr);
+    # - Assumes `out` is a valid pointer to memory for a `u8`.
+    uint8_t currency_exists(const char *code_ptr, uint8_t *out);
 
+    uint8_t currency_is_fiat(const Currency_t *currency);
+
+    uint8_t currency_is_crypto(const Currency_t *currency);
+
+    # Returns the registered [`Currency`] for the code at `code_ptr`, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
     # # Safety
     #
     # - Assumes `code_ptr` is borrowed from a valid Python UTF-8 `str`.
-    Currency_t currency_from_cstr(const char *code_ptr);
+    # - Assumes `out` is a valid pointer to memory for a [`Currency`].
+    uint8_t currency_try_from_cstr(const char *code_ptr, Currency_t *out);
 
-    Money_t money_new(double amount, Currency_t currency);
+    # Writes a new [`Money`] to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set (in
+    # which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Money`].
+    uint8_t money_new(double amount, Currency_t currency, Money_t *out);
 
     Money_t money_from_raw(int64_t raw, Currency_t currency);
 
@@ -1277,11 +2909,21 @@ cdef extern from "../includes/model.h":
 
     double money_as_f64(const Money_t *money);
 
+    const char *money_to_cstr(const Money_t *money);
+
     void money_add_assign(Money_t a, Money_t b);
 
     void money_sub_assign(Money_t a, Money_t b);
 
-    Price_t price_new(double value, uint8_t precision);
+    # Writes a new [`Price`] to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set (in
+    # which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Price`].
+    uint8_t price_new(double value, uint8_t precision, Price_t *out);
 
     Price_t price_from_raw(int64_t raw, uint8_t precision);
 
@@ -1291,7 +2933,86 @@ cdef extern from "../includes/model.h":
 
     void price_sub_assign(Price_t a, Price_t b);
 
-    Quantity_t quantity_new(double value, uint8_t precision);
+    # Parses a [`Price`] from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set (in
+    # which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for a [`Price`].
+    uint8_t price_from_cstr(const char *ptr, Price_t *out);
+
+    # Returns a [`Price`] as a C string pointer.
+    const char *price_to_cstr(const Price_t *price);
+
+    # Writes the sum of `a` and `b` to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if the
+    # result overflowed (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Price`].
+    uint8_t price_checked_add(Price_t a, Price_t b, Price_t *out);
+
+    # Writes the difference of `a` and `b` to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if the
+    # result overflowed (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Price`].
+    uint8_t price_checked_sub(Price_t a, Price_t b, Price_t *out);
+
+    # Writes the product of `a` and `b` to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if the
+    # result overflowed (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Price`].
+    uint8_t price_checked_mul(Price_t a, Price_t b, Price_t *out);
+
+    # Writes `price` rounded to the given `precision` to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `precision` exceeds the maximum fixed precision or the result overflowed (in which case
+    # nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Price`].
+    uint8_t price_round(Price_t price, uint8_t precision, RoundingMode mode, Price_t *out);
+
+    # Writes `price` rounded to a multiple of `increment` to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `increment` is not positive or the result overflowed (in which case nothing is written
+    # to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Price`].
+    uint8_t price_round_to_increment(Price_t price,
+                                     Price_t increment,
+                                     RoundingMode mode,
+                                     Price_t *out);
+
+    uint8_t price_is_multiple_of(Price_t price, Price_t increment);
+
+    # Writes a new [`Quantity`] to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set (in
+    # which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+    uint8_t quantity_new(double value, uint8_t precision, Quantity_t *out);
 
     Quantity_t quantity_from_raw(uint64_t raw, uint8_t precision);
 
@@ -1304,3 +3025,84 @@ cdef extern from "../includes/model.h":
     void quantity_sub_assign(Quantity_t a, Quantity_t b);
 
     void quantity_sub_assign_u64(Quantity_t a, uint64_t b);
+
+    # Parses a [`Quantity`] from a C string pointer, written to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set (in
+    # which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    # - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+    uint8_t quantity_from_cstr(const char *ptr, Quantity_t *out);
+
+    # Returns a [`Quantity`] as a C string pointer.
+    const char *quantity_to_cstr(const Quantity_t *qty);
+
+    # Writes the sum of `a` and `b` to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if the
+    # result overflowed (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+    uint8_t quantity_checked_add(Quantity_t a, Quantity_t b, Quantity_t *out);
+
+    # Writes the difference of `a` and `b` to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if the
+    # result overflowed (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+    uint8_t quantity_checked_sub(Quantity_t a, Quantity_t b, Quantity_t *out);
+
+    # Writes the product of `a` and `b` to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if the
+    # result overflowed (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+    uint8_t quantity_checked_mul(Quantity_t a, Quantity_t b, Quantity_t *out);
+
+    # Writes `qty` rounded to the given `precision` to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `precision` exceeds the maximum fixed precision or the result overflowed (in which case
+    # nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+    uint8_t quantity_round(Quantity_t qty, uint8_t precision, RoundingMode mode, Quantity_t *out);
+
+    # Writes `qty` rounded to a multiple of `increment` to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set if
+    # `increment` is zero or the result overflowed (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to memory for a [`Quantity`].
+    uint8_t quantity_round_to_increment(Quantity_t qty,
+                                        Quantity_t increment,
+                                        RoundingMode mode,
+                                        Quantity_t *out);
+
+    uint8_t quantity_is_multiple_of(Quantity_t qty, Quantity_t increment);
+
+    # Parses the decimal string at `ptr` with up to 18 decimal places, and writes it to `out`
+    # as a [`Quantity`] rounded to at most 9 decimal places using the rounding `mode`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set (in
+    # which case `out` is unchanged).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer, or null.
+    uint8_t quantity128_cstr_to_quantity(const char *ptr, RoundingMode mode, Quantity_t *out);
@@ -0,0 +1,66 @@
+# Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+from libc.stdint cimport uint8_t, uint64_t, uintptr_t
+from nautilus_trader.core.rust.model cimport Money_t, Price_t, Quantity_t
+
+cdef extern from "../includes/risk.h":
+
+    # Provides a sliding window order rate limit, allowing at most `limit` orders within
+    # any `interval_ns` window (e.g. `MAX_ORDER_SUBMIT_RATE` of 100 per second).
+    cdef struct OrderThrottler:
+        pass
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying
+    # [`OrderThrottler`].
+    cdef struct OrderThrottler_API:
+        OrderThrottler * _0;
+
+    # Returns zero if the price check passes, otherwise the [`RiskError`] code with the
+    # denial reason set as the last error.
+    uint8_t risk_check_price(const Price_t *price,
+                             uint8_t max_precision,
+                             uint8_t allow_non_positive);
+
+    # Returns zero if the quantity check passes, otherwise the [`RiskError`] code with the
+    # denial reason set as the last error.
+    #
+    # # Safety
+    #
+    # - Assumes `max_quantity` and `min_quantity` are either NULL (no limit) or valid pointers.
+    uint8_t risk_check_quantity(const Quantity_t *quantity,
+                                uint8_t max_precision,
+                                const Quantity_t *max_quantity,
+                                const Quantity_t *min_quantity);
+
+    # Returns zero if the max notional check passes, otherwise the [`RiskError`] code with
+    # the denial reason set as the last error.
+    uint8_t risk_check_max_notional(const Money_t *notional, const Money_t *max_notional);
+
+    # Returns zero if the free balance check passes, otherwise the [`RiskError`] code with
+    # the denial reason set as the last error.
+    uint8_t risk_check_free_balance(const Money_t *notional, const Money_t *free);
+
+    # Writes a new throttler allowing at most `limit` orders within any `interval_ns` window
+    # to `out`.
+    #
+    # Returns zero on success, otherwise a non-zero status code with the last error set
+    # (in which case nothing is written to `out`).
+    #
+    # # Safety
+    #
+    # - Assumes `name_ptr` is a valid C string pointer, or null.
+    # - Assumes `out` is a valid pointer to memory for an [`OrderThrottler_API`].
+    uint8_t order_throttler_new(const char *name_ptr,
+                                uintptr_t limit,
+                                uint64_t interval_ns,
+                                OrderThrottler_API *out);
+
+    void order_throttler_drop(OrderThrottler_API throttler);
+
+    uintptr_t order_throttler_count(OrderThrottler_API *throttler, uint64_t ts_now);
+
+    # Returns zero if the order is within the rate limit, otherwise the [`RiskError`] code
+    # with the denial reason set as the last error.
+    uint8_t order_throttler_try_submit(OrderThrottler_API *throttler, uint64_t ts_now);
+
+    void order_throttler_reset(OrderThrottler_API *throttler);
@@ -13,15 +13,16 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from cpython.unicode cimport PyUnicode_FromString
+
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.rust.core cimport UUID4_t
 from nautilus_trader.core.rust.core cimport uuid4_clone
-from nautilus_trader.core.rust.core cimport uuid4_drop
 from nautilus_trader.core.rust.core cimport uuid4_eq
-from nautilus_trader.core.rust.core cimport uuid4_from_cstr
 from nautilus_trader.core.rust.core cimport uuid4_hash
 from nautilus_trader.core.rust.core cimport uuid4_new
 from nautilus_trader.core.rust.core cimport uuid4_to_cstr
-from nautilus_trader.core.string cimport cstr_to_pystr
+from nautilus_trader.core.rust.core cimport uuid4_try_from_cstr
 from nautilus_trader.core.string cimport pystr_to_cstr
 
 
@@ -50,17 +51,13 @@ cdef class UUID4:
             self._mem = uuid4_new()  # `UUID4_t` owned from Rust
         else:
             # `value` borrowed by Rust, `UUID4_t` owned from Rust
-            self._mem = uuid4_from_cstr(pystr_to_cstr(value))
-
-    def __del__(self) -> None:
-        if self._mem.value != NULL:
-            uuid4_drop(self._mem)  # `self._mem` moved to Rust (then dropped)
+            check_ffi(uuid4_try_from_cstr(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = uuid4_from_cstr(pystr_to_cstr(state))
+        check_ffi(uuid4_try_from_cstr(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, UUID4 other) -> bool:
         return uuid4_eq(&self._mem, &other._mem)
@@ -75,7 +72,7 @@ cdef class UUID4:
         return f"{type(self).__name__}('{self}')"
 
     cdef str to_str(self):
-        return cstr_to_pystr(uuid4_to_cstr(&self._mem))
+        return PyUnicode_FromString(uuid4_to_cstr(&self._mem))  # `self._mem` buffer borrowed
 
     @property
     def value(self) -> str:
@@ -17,17 +17,18 @@ from libc.stdint cimport uint8_t
 from libc.stdint cimport uint16_t
 
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.rust.model cimport currency_clone
 from nautilus_trader.core.rust.model cimport currency_code_to_cstr
 from nautilus_trader.core.rust.model cimport currency_drop
 from nautilus_trader.core.rust.model cimport currency_eq
 from nautilus_trader.core.rust.model cimport currency_exists
-from nautilus_trader.core.rust.model cimport currency_from_cstr
 from nautilus_trader.core.rust.model cimport currency_from_py
 from nautilus_trader.core.rust.model cimport currency_hash
 from nautilus_trader.core.rust.model cimport currency_name_to_cstr
 from nautilus_trader.core.rust.model cimport currency_register
 from nautilus_trader.core.rust.model cimport currency_to_cstr
+from nautilus_trader.core.rust.model cimport currency_try_from_cstr
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
 from nautilus_trader.model.enums_c cimport CurrencyType
@@ -77,13 +78,14 @@ cdef class Currency:
         Condition.valid_string(name, "name")
         Condition.true(precision <= 9, f"invalid `precision` greater than max 9, was {precision}")
 
-        self._mem = currency_from_py(
+        check_ffi(currency_from_py(
             pystr_to_cstr(code),
             precision,
             iso4217,
             pystr_to_cstr(name),
             currency_type,
-        )
+            &self._mem,
+        ))
 
     def __del__(self) -> None:
         if self._mem.code != NULL:
@@ -99,13 +101,14 @@ cdef class Currency:
         )
 
     def __setstate__(self, state):
-        self._mem = currency_from_py(
+        check_ffi(currency_from_py(
             pystr_to_cstr(state[0]),
             state[1],
             state[2],
             pystr_to_cstr(state[3]),
             state[4],
-        )
+            &self._mem,
+        ))
 
     def __eq__(self, Currency other) -> bool:
         return currency_eq(&self._mem, &other._mem)
@@ -192,10 +195,12 @@ cdef class Currency:
     @staticmethod
     cdef Currency from_internal_map_c(str code):
         cdef const char* code_ptr = pystr_to_cstr(code)
-        if not currency_exists(code_ptr):
+        cdef uint8_t exists
+        check_ffi(currency_exists(code_ptr, &exists))
+        if not exists:
             return None
         cdef Currency currency = Currency.__new__(Currency)
-        currency._mem = currency_from_cstr(code_ptr)
+        check_ffi(currency_try_from_cstr(code_ptr, &currency._mem))
         return currency
 
     @staticmethod
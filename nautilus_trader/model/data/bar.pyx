@@ -18,8 +18,11 @@ from libc.stdint cimport uint64_t
 
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.rust.model cimport BarSpecification_t
 from nautilus_trader.core.rust.model cimport BarType_t
+from nautilus_trader.core.rust.model cimport InstrumentId_t
+from nautilus_trader.core.rust.model cimport bar_clone
 from nautilus_trader.core.rust.model cimport bar_drop
 from nautilus_trader.core.rust.model cimport bar_eq
 from nautilus_trader.core.rust.model cimport bar_hash
@@ -45,7 +48,7 @@ from nautilus_trader.core.rust.model cimport bar_type_lt
 from nautilus_trader.core.rust.model cimport bar_type_new
 from nautilus_trader.core.rust.model cimport bar_type_to_cstr
 from nautilus_trader.core.rust.model cimport instrument_id_clone
-from nautilus_trader.core.rust.model cimport instrument_id_new_from_cstr
+from nautilus_trader.core.rust.model cimport instrument_id_try_from_cstr
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
 from nautilus_trader.model.data.bar_aggregation cimport BarAggregation
@@ -88,11 +91,12 @@ cdef class BarSpecification:
     ):
         Condition.positive_int(step, 'step')
 
-        self._mem = bar_specification_new(
+        check_ffi(bar_specification_new(
             step,
             aggregation,
-            price_type
-        )
+            price_type,
+            &self._mem,
+        ))
 
     def __getstate__(self):
         return (
@@ -102,11 +106,12 @@ cdef class BarSpecification:
         )
 
     def __setstate__(self, state):
-        self._mem = bar_specification_new(
+        check_ffi(bar_specification_new(
             state[0],
             state[1],
-            state[2]
-        )
+            state[2],
+            &self._mem,
+        ))
 
     cdef str to_str(self):
         return cstr_to_pystr(bar_specification_to_cstr(&self._mem))
@@ -479,11 +484,12 @@ cdef class BarType:
         BarSpecification bar_spec not None,
         AggregationSource aggregation_source=AggregationSource.EXTERNAL,
     ):
-        self._mem = bar_type_new(
+        check_ffi(bar_type_new(
             instrument_id_clone(&instrument_id._mem),
             bar_spec._mem,
-            aggregation_source
-        )
+            aggregation_source,
+            &self._mem,
+        ))
 
     def __getstate__(self):
         return (
@@ -495,17 +501,11 @@ cdef class BarType:
         )
 
     def __setstate__(self, state):
-        self._mem = bar_type_new(
-            instrument_id_new_from_cstr(
-                pystr_to_cstr(state[0]),
-            ),
-            bar_specification_new(
-                state[1],
-                state[2],
-                state[3]
-            ),
-            state[4],
-        )
+        cdef InstrumentId_t instrument_id
+        cdef BarSpecification_t spec
+        check_ffi(instrument_id_try_from_cstr(pystr_to_cstr(state[0]), &instrument_id))
+        check_ffi(bar_specification_new(state[1], state[2], state[3], &spec))
+        check_ffi(bar_type_new(instrument_id, spec, state[4], &self._mem))
 
     def __del__(self) -> None:
         if self._mem.instrument_id.symbol.value != NULL:
@@ -732,18 +732,14 @@ cdef class Bar(Data):
         )
 
     def __setstate__(self, state):
+        cdef InstrumentId_t instrument_id
+        cdef BarSpecification_t spec
+        cdef BarType_t bar_type
+        check_ffi(instrument_id_try_from_cstr(pystr_to_cstr(state[0]), &instrument_id))
+        check_ffi(bar_specification_new(state[1], state[2], state[3], &spec))
+        check_ffi(bar_type_new(instrument_id, spec, state[4], &bar_type))
         self._mem = bar_new_from_raw(
-            bar_type_new(
-                instrument_id_new_from_cstr(
-                    pystr_to_cstr(state[0]),
-                ),
-                bar_specification_new(
-                    state[1],
-                    state[2],
-                    state[3],
-                ),
-                state[4],
-            ),
+            bar_type,
             state[5],
             state[6],
             state[7],
@@ -774,6 +770,13 @@ cdef class Bar(Data):
     def __repr__(self) -> str:
         return f"{type(self).__name__}({self})"
 
+    @staticmethod
+    cdef Bar from_mem_c(Bar_t mem):
+        cdef Bar bar = Bar.__new__(Bar)
+        bar._mem = bar_clone(&mem)
+        bar.is_revision = False
+        return bar
+
     @staticmethod
     cdef Bar from_dict_c(dict values):
         Condition.not_none(values, "values")
@@ -19,15 +19,19 @@ from libc.stdint cimport uint64_t
 
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.rust.core cimport CVec
+from nautilus_trader.core.rust.core cimport CVecElementType
+from nautilus_trader.core.rust.model cimport InstrumentId_t
+from nautilus_trader.core.rust.model cimport TradeId_t
 from nautilus_trader.core.rust.model cimport instrument_id_clone
-from nautilus_trader.core.rust.model cimport instrument_id_new_from_cstr
+from nautilus_trader.core.rust.model cimport instrument_id_try_from_cstr
 from nautilus_trader.core.rust.model cimport quote_tick_clone
 from nautilus_trader.core.rust.model cimport quote_tick_drop
 from nautilus_trader.core.rust.model cimport quote_tick_new
 from nautilus_trader.core.rust.model cimport quote_tick_to_cstr
 from nautilus_trader.core.rust.model cimport trade_id_clone
-from nautilus_trader.core.rust.model cimport trade_id_new
+from nautilus_trader.core.rust.model cimport trade_id_try_new
 from nautilus_trader.core.rust.model cimport trade_tick_clone
 from nautilus_trader.core.rust.model cimport trade_tick_drop
 from nautilus_trader.core.rust.model cimport trade_tick_new
@@ -122,10 +126,10 @@ cdef class QuoteTick(Data):
         )
 
     def __setstate__(self, state):
+        cdef InstrumentId_t instrument_id
+        check_ffi(instrument_id_try_from_cstr(pystr_to_cstr(state[0]), &instrument_id))
         self._mem = quote_tick_new(
-            instrument_id_new_from_cstr(
-                pystr_to_cstr(state[0]),
-            ),
+            instrument_id,
             state[1],
             state[2],
             state[3],
@@ -331,6 +335,9 @@ cdef class QuoteTick(Data):
         cvec.ptr = data
         cvec.len = len_
         cvec.cap = len_
+        cvec.element_type = CVecElementType.CVecElementType_QUOTE_TICK
+        cvec.element_size = sizeof(QuoteTick_t)
+        cvec.drop = NULL  # Memory is owned by Python
 
         # create PyCapsule
         return PyCapsule_New(cvec, NULL, <PyCapsule_Destructor>capsule_destructor)
@@ -560,16 +567,18 @@ cdef class TradeTick(Data):
         )
 
     def __setstate__(self, state):
+        cdef InstrumentId_t instrument_id
+        cdef TradeId_t trade_id
+        check_ffi(instrument_id_try_from_cstr(pystr_to_cstr(state[0]), &instrument_id))
+        check_ffi(trade_id_try_new(pystr_to_cstr(state[6]), &trade_id))
         self._mem = trade_tick_new(
-            instrument_id_new_from_cstr(
-                pystr_to_cstr(state[0]),
-            ),
+            instrument_id,
             state[1],
             state[2],
             state[3],
             state[4],
             state[5],
-            trade_id_new(pystr_to_cstr(state[6])),
+            trade_id,
             state[7],
             state[8],
         )
@@ -736,6 +745,9 @@ cdef class TradeTick(Data):
         cvec.ptr = data
         cvec.len = len_
         cvec.cap = len_
+        cvec.element_type = CVecElementType.CVecElementType_TRADE_TICK
+        cvec.element_size = sizeof(TradeTick_t)
+        cvec.drop = NULL  # Memory is owned by Python
 
         # Create PyCapsule
         return PyCapsule_New(cvec, NULL, <PyCapsule_Destructor>capsule_destructor)
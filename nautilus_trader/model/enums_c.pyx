@@ -15,6 +15,7 @@
 
 from libc.stdint cimport uint8_t
 
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.rust.model cimport AccountType
 from nautilus_trader.core.rust.model cimport AggregationSource
 from nautilus_trader.core.rust.model cimport AggressorSide
@@ -38,61 +39,63 @@ from nautilus_trader.core.rust.model cimport TimeInForce
 from nautilus_trader.core.rust.model cimport TradingState
 from nautilus_trader.core.rust.model cimport TrailingOffsetType
 from nautilus_trader.core.rust.model cimport TriggerType
-from nautilus_trader.core.rust.model cimport account_type_from_cstr
 from nautilus_trader.core.rust.model cimport account_type_to_cstr
-from nautilus_trader.core.rust.model cimport aggregation_source_from_cstr
+from nautilus_trader.core.rust.model cimport account_type_try_from_cstr
 from nautilus_trader.core.rust.model cimport aggregation_source_to_cstr
-from nautilus_trader.core.rust.model cimport aggressor_side_from_cstr
+from nautilus_trader.core.rust.model cimport aggregation_source_try_from_cstr
 from nautilus_trader.core.rust.model cimport aggressor_side_to_cstr
-from nautilus_trader.core.rust.model cimport asset_class_from_cstr
+from nautilus_trader.core.rust.model cimport aggressor_side_try_from_cstr
 from nautilus_trader.core.rust.model cimport asset_class_to_cstr
-from nautilus_trader.core.rust.model cimport asset_type_from_cstr
+from nautilus_trader.core.rust.model cimport asset_class_try_from_cstr
 from nautilus_trader.core.rust.model cimport asset_type_to_cstr
-from nautilus_trader.core.rust.model cimport bar_aggregation_from_cstr
+from nautilus_trader.core.rust.model cimport asset_type_try_from_cstr
 from nautilus_trader.core.rust.model cimport bar_aggregation_to_cstr
-from nautilus_trader.core.rust.model cimport book_action_from_cstr
+from nautilus_trader.core.rust.model cimport bar_aggregation_try_from_cstr
 from nautilus_trader.core.rust.model cimport book_action_to_cstr
-from nautilus_trader.core.rust.model cimport book_type_from_cstr
+from nautilus_trader.core.rust.model cimport book_action_try_from_cstr
 from nautilus_trader.core.rust.model cimport book_type_to_cstr
-from nautilus_trader.core.rust.model cimport contingency_type_from_cstr
+from nautilus_trader.core.rust.model cimport book_type_try_from_cstr
 from nautilus_trader.core.rust.model cimport contingency_type_to_cstr
-from nautilus_trader.core.rust.model cimport currency_type_from_cstr
+from nautilus_trader.core.rust.model cimport contingency_type_try_from_cstr
 from nautilus_trader.core.rust.model cimport currency_type_to_cstr
-from nautilus_trader.core.rust.model cimport instrument_close_type_from_cstr
+from nautilus_trader.core.rust.model cimport currency_type_try_from_cstr
 from nautilus_trader.core.rust.model cimport instrument_close_type_to_cstr
-from nautilus_trader.core.rust.model cimport liquidity_side_from_cstr
+from nautilus_trader.core.rust.model cimport instrument_close_type_try_from_cstr
 from nautilus_trader.core.rust.model cimport liquidity_side_to_cstr
-from nautilus_trader.core.rust.model cimport market_status_from_cstr
+from nautilus_trader.core.rust.model cimport liquidity_side_try_from_cstr
 from nautilus_trader.core.rust.model cimport market_status_to_cstr
-from nautilus_trader.core.rust.model cimport oms_type_from_cstr
+from nautilus_trader.core.rust.model cimport market_status_try_from_cstr
 from nautilus_trader.core.rust.model cimport oms_type_to_cstr
-from nautilus_trader.core.rust.model cimport option_kind_from_cstr
+from nautilus_trader.core.rust.model cimport oms_type_try_from_cstr
 from nautilus_trader.core.rust.model cimport option_kind_to_cstr
-from nautilus_trader.core.rust.model cimport order_side_from_cstr
+from nautilus_trader.core.rust.model cimport option_kind_try_from_cstr
 from nautilus_trader.core.rust.model cimport order_side_to_cstr
-from nautilus_trader.core.rust.model cimport order_status_from_cstr
+from nautilus_trader.core.rust.model cimport order_side_try_from_cstr
 from nautilus_trader.core.rust.model cimport order_status_to_cstr
-from nautilus_trader.core.rust.model cimport order_type_from_cstr
+from nautilus_trader.core.rust.model cimport order_status_try_from_cstr
 from nautilus_trader.core.rust.model cimport order_type_to_cstr
-from nautilus_trader.core.rust.model cimport position_side_from_cstr
+from nautilus_trader.core.rust.model cimport order_type_try_from_cstr
 from nautilus_trader.core.rust.model cimport position_side_to_cstr
-from nautilus_trader.core.rust.model cimport price_type_from_cstr
+from nautilus_trader.core.rust.model cimport position_side_try_from_cstr
 from nautilus_trader.core.rust.model cimport price_type_to_cstr
-from nautilus_trader.core.rust.model cimport time_in_force_from_cstr
+from nautilus_trader.core.rust.model cimport price_type_try_from_cstr
 from nautilus_trader.core.rust.model cimport time_in_force_to_cstr
-from nautilus_trader.core.rust.model cimport trading_state_from_cstr
+from nautilus_trader.core.rust.model cimport time_in_force_try_from_cstr
 from nautilus_trader.core.rust.model cimport trading_state_to_cstr
-from nautilus_trader.core.rust.model cimport trailing_offset_type_from_cstr
+from nautilus_trader.core.rust.model cimport trading_state_try_from_cstr
 from nautilus_trader.core.rust.model cimport trailing_offset_type_to_cstr
-from nautilus_trader.core.rust.model cimport trigger_type_from_cstr
+from nautilus_trader.core.rust.model cimport trailing_offset_type_try_from_cstr
 from nautilus_trader.core.rust.model cimport trigger_type_to_cstr
+from nautilus_trader.core.rust.model cimport trigger_type_try_from_cstr
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
 from nautilus_trader.model.data.bar_aggregation cimport BarAggregation
 
 
 cpdef AccountType account_type_from_str(str value):
-    return account_type_from_cstr(pystr_to_cstr(value))
+    cdef AccountType out
+    check_ffi(account_type_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str account_type_to_str(AccountType value):
@@ -100,7 +103,9 @@ cpdef str account_type_to_str(AccountType value):
 
 
 cpdef AggregationSource aggregation_source_from_str(str value):
-    return aggregation_source_from_cstr(pystr_to_cstr(value))
+    cdef AggregationSource out
+    check_ffi(aggregation_source_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str aggregation_source_to_str(AggregationSource value):
@@ -108,7 +113,9 @@ cpdef str aggregation_source_to_str(AggregationSource value):
 
 
 cpdef AggressorSide aggressor_side_from_str(str value):
-    return aggressor_side_from_cstr(pystr_to_cstr(value))
+    cdef AggressorSide out
+    check_ffi(aggressor_side_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str aggressor_side_to_str(AggressorSide value):
@@ -116,7 +123,9 @@ cpdef str aggressor_side_to_str(AggressorSide value):
 
 
 cpdef AssetClass asset_class_from_str(str value):
-    return asset_class_from_cstr(pystr_to_cstr(value))
+    cdef AssetClass out
+    check_ffi(asset_class_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str asset_class_to_str(AssetClass value):
@@ -124,7 +133,9 @@ cpdef str asset_class_to_str(AssetClass value):
 
 
 cpdef AssetType asset_type_from_str(str value):
-    return asset_type_from_cstr(pystr_to_cstr(value))
+    cdef AssetType out
+    check_ffi(asset_type_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str asset_type_to_str(AssetType value):
@@ -132,7 +143,9 @@ cpdef str asset_type_to_str(AssetType value):
 
 
 cpdef BarAggregation bar_aggregation_from_str(str value):
-    return <BarAggregation>bar_aggregation_from_cstr(pystr_to_cstr(value))
+    cdef uint8_t out
+    check_ffi(bar_aggregation_try_from_cstr(pystr_to_cstr(value), &out))
+    return <BarAggregation>out
 
 
 cpdef str bar_aggregation_to_str(BarAggregation value):
@@ -140,7 +153,9 @@ cpdef str bar_aggregation_to_str(BarAggregation value):
 
 
 cpdef BookAction book_action_from_str(str value):
-    return book_action_from_cstr(pystr_to_cstr(value))
+    cdef BookAction out
+    check_ffi(book_action_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str book_action_to_str(BookAction value):
@@ -148,7 +163,9 @@ cpdef str book_action_to_str(BookAction value):
 
 
 cpdef BookType book_type_from_str(str value):
-    return book_type_from_cstr(pystr_to_cstr(value))
+    cdef BookType out
+    check_ffi(book_type_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str book_type_to_str(BookType value):
@@ -156,7 +173,9 @@ cpdef str book_type_to_str(BookType value):
 
 
 cpdef ContingencyType contingency_type_from_str(str value):
-    return contingency_type_from_cstr(pystr_to_cstr(value))
+    cdef ContingencyType out
+    check_ffi(contingency_type_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str contingency_type_to_str(ContingencyType value):
@@ -164,7 +183,9 @@ cpdef str contingency_type_to_str(ContingencyType value):
 
 
 cpdef CurrencyType currency_type_from_str(str value):
-    return currency_type_from_cstr(pystr_to_cstr(value))
+    cdef CurrencyType out
+    check_ffi(currency_type_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str currency_type_to_str(CurrencyType value):
@@ -172,7 +193,9 @@ cpdef str currency_type_to_str(CurrencyType value):
 
 
 cpdef InstrumentCloseType instrument_close_type_from_str(str value):
-    return instrument_close_type_from_cstr(pystr_to_cstr(value))
+    cdef InstrumentCloseType out
+    check_ffi(instrument_close_type_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str instrument_close_type_to_str(InstrumentCloseType value):
@@ -180,7 +203,9 @@ cpdef str instrument_close_type_to_str(InstrumentCloseType value):
 
 
 cpdef LiquiditySide liquidity_side_from_str(str value):
-    return liquidity_side_from_cstr(pystr_to_cstr(value))
+    cdef LiquiditySide out
+    check_ffi(liquidity_side_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str liquidity_side_to_str(LiquiditySide value):
@@ -188,7 +213,9 @@ cpdef str liquidity_side_to_str(LiquiditySide value):
 
 
 cpdef MarketStatus market_status_from_str(str value):
-    return market_status_from_cstr(pystr_to_cstr(value))
+    cdef MarketStatus out
+    check_ffi(market_status_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str market_status_to_str(MarketStatus value):
@@ -196,7 +223,9 @@ cpdef str market_status_to_str(MarketStatus value):
 
 
 cpdef OmsType oms_type_from_str(str value):
-    return oms_type_from_cstr(pystr_to_cstr(value))
+    cdef OmsType out
+    check_ffi(oms_type_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str oms_type_to_str(OmsType value):
@@ -204,7 +233,9 @@ cpdef str oms_type_to_str(OmsType value):
 
 
 cpdef OptionKind option_kind_from_str(str value):
-    return option_kind_from_cstr(pystr_to_cstr(value))
+    cdef OptionKind out
+    check_ffi(option_kind_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str option_kind_to_str(OptionKind value):
@@ -212,7 +243,9 @@ cpdef str option_kind_to_str(OptionKind value):
 
 
 cpdef OrderSide order_side_from_str(str value):
-    return order_side_from_cstr(pystr_to_cstr(value))
+    cdef OrderSide out
+    check_ffi(order_side_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str order_side_to_str(OrderSide value):
@@ -220,7 +253,9 @@ cpdef str order_side_to_str(OrderSide value):
 
 
 cpdef OrderStatus order_status_from_str(str value):
-    return order_status_from_cstr(pystr_to_cstr(value))
+    cdef OrderStatus out
+    check_ffi(order_status_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str order_status_to_str(OrderStatus value):
@@ -228,7 +263,9 @@ cpdef str order_status_to_str(OrderStatus value):
 
 
 cpdef OrderType order_type_from_str(str value):
-    return order_type_from_cstr(pystr_to_cstr(value))
+    cdef OrderType out
+    check_ffi(order_type_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str order_type_to_str(OrderType value):
@@ -236,7 +273,9 @@ cpdef str order_type_to_str(OrderType value):
 
 
 cpdef PositionSide position_side_from_str(str value):
-    return position_side_from_cstr(pystr_to_cstr(value))
+    cdef PositionSide out
+    check_ffi(position_side_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str position_side_to_str(PositionSide value):
@@ -244,7 +283,9 @@ cpdef str position_side_to_str(PositionSide value):
 
 
 cpdef PriceType price_type_from_str(str value):
-    return price_type_from_cstr(pystr_to_cstr(value))
+    cdef PriceType out
+    check_ffi(price_type_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str price_type_to_str(PriceType value):
@@ -252,7 +293,9 @@ cpdef str price_type_to_str(PriceType value):
 
 
 cpdef TimeInForce time_in_force_from_str(str value):
-    return time_in_force_from_cstr(pystr_to_cstr(value))
+    cdef TimeInForce out
+    check_ffi(time_in_force_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str time_in_force_to_str(TimeInForce value):
@@ -260,7 +303,9 @@ cpdef str time_in_force_to_str(TimeInForce value):
 
 
 cpdef TradingState trading_state_from_str(str value):
-    return trading_state_from_cstr(pystr_to_cstr(value))
+    cdef TradingState out
+    check_ffi(trading_state_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str trading_state_to_str(TradingState value):
@@ -268,7 +313,9 @@ cpdef str trading_state_to_str(TradingState value):
 
 
 cpdef TrailingOffsetType trailing_offset_type_from_str(str value):
-    return trailing_offset_type_from_cstr(pystr_to_cstr(value))
+    cdef TrailingOffsetType out
+    check_ffi(trailing_offset_type_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str trailing_offset_type_to_str(TrailingOffsetType value):
@@ -276,7 +323,9 @@ cpdef str trailing_offset_type_to_str(TrailingOffsetType value):
 
 
 cpdef TriggerType trigger_type_from_str(str value):
-    return trigger_type_from_cstr(pystr_to_cstr(value))
+    cdef TriggerType out
+    check_ffi(trigger_type_try_from_cstr(pystr_to_cstr(value), &out))
+    return out
 
 
 cpdef str trigger_type_to_str(TriggerType value):
@@ -21,16 +21,18 @@ import msgspec
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.message cimport Event
 from nautilus_trader.core.rust.core cimport uuid4_clone
+from nautilus_trader.core.rust.model cimport StrategyId_t
+from nautilus_trader.core.rust.model cimport TraderId_t
 from nautilus_trader.core.rust.model cimport client_order_id_clone
-from nautilus_trader.core.rust.model cimport component_id_to_cstr
 from nautilus_trader.core.rust.model cimport instrument_id_clone
 from nautilus_trader.core.rust.model cimport order_denied_new
 from nautilus_trader.core.rust.model cimport order_denied_reason_to_cstr
-from nautilus_trader.core.rust.model cimport strategy_id_new
+from nautilus_trader.core.rust.model cimport strategy_id_try_new
 from nautilus_trader.core.rust.model cimport trade_id_clone
-from nautilus_trader.core.rust.model cimport trader_id_new
+from nautilus_trader.core.rust.model cimport trader_id_try_new
 from nautilus_trader.core.string cimport cstr_to_pybytes
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
@@ -468,16 +470,24 @@ cdef class OrderDenied(OrderEvent):
             reconciliation=False,  # Internal system event
         )
 
-        self._mem = order_denied_new(
-            trader_id_new(component_id_to_cstr(&trader_id._mem)),
-            strategy_id_new(component_id_to_cstr(&strategy_id._mem)),
+        cdef str trader_id_str = trader_id.to_str()
+        cdef str strategy_id_str = strategy_id.to_str()
+        cdef TraderId_t trader_id_mem
+        cdef StrategyId_t strategy_id_mem
+        check_ffi(trader_id_try_new(pystr_to_cstr(trader_id_str), &trader_id_mem))
+        check_ffi(strategy_id_try_new(pystr_to_cstr(strategy_id_str), &strategy_id_mem))
+
+        check_ffi(order_denied_new(
+            trader_id_mem,
+            strategy_id_mem,
             instrument_id_clone(&instrument_id._mem),
             client_order_id_clone(&client_order_id._mem),
             pystr_to_cstr(reason),
             uuid4_clone(&event_id._mem),
             ts_init,
             ts_init,
-        )
+            &self._mem,
+        ))
 
     def __str__(self) -> str:
         return (
@@ -14,61 +14,62 @@
 # -------------------------------------------------------------------------------------------------
 
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.rust.model cimport account_id_drop
 from nautilus_trader.core.rust.model cimport account_id_eq
 from nautilus_trader.core.rust.model cimport account_id_hash
-from nautilus_trader.core.rust.model cimport account_id_new
 from nautilus_trader.core.rust.model cimport account_id_to_cstr
+from nautilus_trader.core.rust.model cimport account_id_try_new
 from nautilus_trader.core.rust.model cimport client_order_id_drop
 from nautilus_trader.core.rust.model cimport client_order_id_eq
 from nautilus_trader.core.rust.model cimport client_order_id_hash
-from nautilus_trader.core.rust.model cimport client_order_id_new
 from nautilus_trader.core.rust.model cimport client_order_id_to_cstr
+from nautilus_trader.core.rust.model cimport client_order_id_try_new
 from nautilus_trader.core.rust.model cimport component_id_drop
 from nautilus_trader.core.rust.model cimport component_id_eq
 from nautilus_trader.core.rust.model cimport component_id_hash
-from nautilus_trader.core.rust.model cimport component_id_new
 from nautilus_trader.core.rust.model cimport component_id_to_cstr
+from nautilus_trader.core.rust.model cimport component_id_try_new
 from nautilus_trader.core.rust.model cimport instrument_id_clone
 from nautilus_trader.core.rust.model cimport instrument_id_drop
 from nautilus_trader.core.rust.model cimport instrument_id_eq
 from nautilus_trader.core.rust.model cimport instrument_id_hash
 from nautilus_trader.core.rust.model cimport instrument_id_new
-from nautilus_trader.core.rust.model cimport instrument_id_new_from_cstr
 from nautilus_trader.core.rust.model cimport instrument_id_to_cstr
+from nautilus_trader.core.rust.model cimport instrument_id_try_from_cstr
 from nautilus_trader.core.rust.model cimport order_list_id_drop
 from nautilus_trader.core.rust.model cimport order_list_id_eq
 from nautilus_trader.core.rust.model cimport order_list_id_hash
-from nautilus_trader.core.rust.model cimport order_list_id_new
 from nautilus_trader.core.rust.model cimport order_list_id_to_cstr
+from nautilus_trader.core.rust.model cimport order_list_id_try_new
 from nautilus_trader.core.rust.model cimport position_id_drop
 from nautilus_trader.core.rust.model cimport position_id_eq
 from nautilus_trader.core.rust.model cimport position_id_hash
-from nautilus_trader.core.rust.model cimport position_id_new
 from nautilus_trader.core.rust.model cimport position_id_to_cstr
+from nautilus_trader.core.rust.model cimport position_id_try_new
 from nautilus_trader.core.rust.model cimport symbol_clone
 from nautilus_trader.core.rust.model cimport symbol_drop
 from nautilus_trader.core.rust.model cimport symbol_eq
 from nautilus_trader.core.rust.model cimport symbol_hash
-from nautilus_trader.core.rust.model cimport symbol_new
 from nautilus_trader.core.rust.model cimport symbol_to_cstr
+from nautilus_trader.core.rust.model cimport symbol_try_new
 from nautilus_trader.core.rust.model cimport trade_id_clone
 from nautilus_trader.core.rust.model cimport trade_id_drop
 from nautilus_trader.core.rust.model cimport trade_id_eq
 from nautilus_trader.core.rust.model cimport trade_id_hash
-from nautilus_trader.core.rust.model cimport trade_id_new
 from nautilus_trader.core.rust.model cimport trade_id_to_cstr
+from nautilus_trader.core.rust.model cimport trade_id_try_new
 from nautilus_trader.core.rust.model cimport venue_clone
 from nautilus_trader.core.rust.model cimport venue_drop
 from nautilus_trader.core.rust.model cimport venue_eq
 from nautilus_trader.core.rust.model cimport venue_hash
-from nautilus_trader.core.rust.model cimport venue_new
 from nautilus_trader.core.rust.model cimport venue_order_id_drop
 from nautilus_trader.core.rust.model cimport venue_order_id_eq
 from nautilus_trader.core.rust.model cimport venue_order_id_hash
-from nautilus_trader.core.rust.model cimport venue_order_id_new
 from nautilus_trader.core.rust.model cimport venue_order_id_to_cstr
+from nautilus_trader.core.rust.model cimport venue_order_id_try_new
 from nautilus_trader.core.rust.model cimport venue_to_cstr
+from nautilus_trader.core.rust.model cimport venue_try_new
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
 
@@ -143,7 +144,7 @@ cdef class Symbol(Identifier):
 
     def __init__(self, str value not None):
         Condition.valid_string(value, "value")
-        self._mem = symbol_new(pystr_to_cstr(value))
+        check_ffi(symbol_try_new(pystr_to_cstr(value), &self._mem))
 
     def __del__(self) -> None:
         if self._mem.value != NULL:
@@ -153,7 +154,7 @@ cdef class Symbol(Identifier):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = symbol_new(pystr_to_cstr(state))
+        check_ffi(symbol_try_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, Symbol other) -> bool:
         if other is None:
@@ -190,7 +191,7 @@ cdef class Venue(Identifier):
 
     def __init__(self, str name not None):
         Condition.valid_string(name, "name")
-        self._mem = venue_new(pystr_to_cstr(name))
+        check_ffi(venue_try_new(pystr_to_cstr(name), &self._mem))
 
     def __del__(self) -> None:
         if self._mem.value != NULL:
@@ -200,7 +201,7 @@ cdef class Venue(Identifier):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = venue_new(pystr_to_cstr(state))
+        check_ffi(venue_try_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, Venue other) -> bool:
         if other is None:
@@ -272,9 +273,7 @@ cdef class InstrumentId(Identifier):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = instrument_id_new_from_cstr(
-            pystr_to_cstr(state),
-        )
+        check_ffi(instrument_id_try_from_cstr(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, InstrumentId other) -> bool:
         if other is None:
@@ -296,7 +295,7 @@ cdef class InstrumentId(Identifier):
     @staticmethod
     cdef InstrumentId from_str_c(str value):
         cdef InstrumentId instrument_id = InstrumentId.__new__(InstrumentId)
-        instrument_id._mem = instrument_id_new_from_cstr(pystr_to_cstr(value))
+        check_ffi(instrument_id_try_from_cstr(pystr_to_cstr(value), &instrument_id._mem))
         return instrument_id
 
     @staticmethod
@@ -342,7 +341,7 @@ cdef class ComponentId(Identifier):
 
     def __init__(self, str value not None):
         Condition.valid_string(value, "value")
-        self._mem = component_id_new(pystr_to_cstr(value))
+        check_ffi(component_id_try_new(pystr_to_cstr(value), &self._mem))
 
     def __del__(self) -> None:
         if self._mem.value != NULL:
@@ -352,7 +351,7 @@ cdef class ComponentId(Identifier):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = component_id_new(pystr_to_cstr(state))
+        check_ffi(component_id_try_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, ComponentId other) -> bool:
         if other is None:
@@ -550,7 +549,7 @@ cdef class AccountId(Identifier):
     def __init__(self, str value not None):
         Condition.valid_string(value, "value")
         Condition.true("-" in value, "value was malformed: did not contain a hyphen '-'")
-        self._mem = account_id_new(pystr_to_cstr(value))
+        check_ffi(account_id_try_new(pystr_to_cstr(value), &self._mem))
 
     def __del__(self) -> None:
         if self._mem.value != NULL:
@@ -560,7 +559,7 @@ cdef class AccountId(Identifier):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = account_id_new(pystr_to_cstr(state))
+        check_ffi(account_id_try_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, AccountId other) -> bool:
         if other is None:
@@ -617,7 +616,7 @@ cdef class ClientOrderId(Identifier):
 
     def __init__(self, str value not None):
         Condition.valid_string(value, "value")
-        self._mem = client_order_id_new(pystr_to_cstr(value))
+        check_ffi(client_order_id_try_new(pystr_to_cstr(value), &self._mem))
 
     def __del__(self) -> None:
         if self._mem.value != NULL:
@@ -627,7 +626,7 @@ cdef class ClientOrderId(Identifier):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = client_order_id_new(pystr_to_cstr(state))
+        check_ffi(client_order_id_try_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, ClientOrderId other) -> bool:
         if other is None:
@@ -680,7 +679,7 @@ cdef class VenueOrderId(Identifier):
 
     def __init__(self, str value not None):
         Condition.valid_string(value, "value")
-        self._mem = venue_order_id_new(pystr_to_cstr(value))
+        check_ffi(venue_order_id_try_new(pystr_to_cstr(value), &self._mem))
 
     def __del__(self) -> None:
         if self._mem.value != NULL:
@@ -690,7 +689,7 @@ cdef class VenueOrderId(Identifier):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = venue_order_id_new(pystr_to_cstr(state))
+        check_ffi(venue_order_id_try_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, VenueOrderId other) -> bool:
         if other is None:
@@ -721,7 +720,7 @@ cdef class OrderListId(Identifier):
 
     def __init__(self, str value not None):
         Condition.valid_string(value, "value")
-        self._mem = order_list_id_new(pystr_to_cstr(value))
+        check_ffi(order_list_id_try_new(pystr_to_cstr(value), &self._mem))
 
     def __del__(self) -> None:
         if self._mem.value != NULL:
@@ -731,7 +730,7 @@ cdef class OrderListId(Identifier):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = order_list_id_new(pystr_to_cstr(state))
+        check_ffi(order_list_id_try_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, OrderListId other) -> bool:
         if other is None:
@@ -762,7 +761,7 @@ cdef class PositionId(Identifier):
 
     def __init__(self, str value not None):
         Condition.valid_string(value, "value")
-        self._mem = position_id_new(pystr_to_cstr(value))
+        check_ffi(position_id_try_new(pystr_to_cstr(value), &self._mem))
 
     def __del__(self) -> None:
         if self._mem.value != NULL:
@@ -772,7 +771,7 @@ cdef class PositionId(Identifier):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = position_id_new(pystr_to_cstr(state))
+        check_ffi(position_id_try_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, PositionId other) -> bool:
         if other is None:
@@ -821,7 +820,7 @@ cdef class TradeId(Identifier):
 
     def __init__(self, str value not None):
         Condition.valid_string(value, "value")
-        self._mem = trade_id_new(pystr_to_cstr(value))
+        check_ffi(trade_id_try_new(pystr_to_cstr(value), &self._mem))
 
     def __del__(self) -> None:
         if self._mem.value != NULL:
@@ -831,7 +830,7 @@ cdef class TradeId(Identifier):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = trade_id_new(pystr_to_cstr(state))
+        check_ffi(trade_id_try_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, TradeId other) -> bool:
         if other is None:
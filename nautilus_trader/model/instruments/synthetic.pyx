@@ -21,18 +21,20 @@ from libc.stdint cimport uint8_t
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.rust.core cimport CVec
+from nautilus_trader.core.rust.core cimport CVecElementType
 from nautilus_trader.core.rust.model cimport Price_t
 from nautilus_trader.core.rust.model cimport SyntheticInstrument_API
 from nautilus_trader.core.rust.model cimport symbol_clone
-from nautilus_trader.core.rust.model cimport synthetic_instrument_calculate
-from nautilus_trader.core.rust.model cimport synthetic_instrument_change_formula
 from nautilus_trader.core.rust.model cimport synthetic_instrument_components_to_cstr
 from nautilus_trader.core.rust.model cimport synthetic_instrument_drop
 from nautilus_trader.core.rust.model cimport synthetic_instrument_formula_to_cstr
 from nautilus_trader.core.rust.model cimport synthetic_instrument_id
-from nautilus_trader.core.rust.model cimport synthetic_instrument_new
 from nautilus_trader.core.rust.model cimport synthetic_instrument_precision
+from nautilus_trader.core.rust.model cimport synthetic_instrument_try_calculate
+from nautilus_trader.core.rust.model cimport synthetic_instrument_try_change_formula
+from nautilus_trader.core.rust.model cimport synthetic_instrument_try_new
 from nautilus_trader.core.string cimport cstr_to_pybytes
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pybytes_to_cstr
@@ -84,12 +86,13 @@ cdef class SyntheticInstrument:
         Condition.list_type(components, InstrumentId, "components")
         Condition.valid_string(formula, "formula")
 
-        self._mem = synthetic_instrument_new(
+        check_ffi(synthetic_instrument_try_new(
             symbol_clone(&symbol._mem),
             precision,
             pybytes_to_cstr(msgspec.json.encode([c.value for c in components])),
             pystr_to_cstr(formula),
-        )
+            &self._mem,
+        ))
 
     def __del__(self) -> None:
         if self._mem._0 != NULL:
@@ -156,7 +159,7 @@ cdef class SyntheticInstrument:
         """
         Condition.valid_string(formula, "formula")
 
-        synthetic_instrument_change_formula(&self._mem, pystr_to_cstr(formula))
+        check_ffi(synthetic_instrument_try_change_formula(&self._mem, pystr_to_cstr(formula)))
 
     cpdef Price calculate(self, list[double] inputs):
         """
@@ -189,11 +192,15 @@ cdef class SyntheticInstrument:
         cvec.ptr = data
         cvec.len = len_
         cvec.cap = len_
+        cvec.element_type = CVecElementType.CVecElementType_F64
+        cvec.element_size = sizeof(double)
+        cvec.drop = NULL  # Memory is owned by Python
 
-        cdef Price_t mem = synthetic_instrument_calculate(&self._mem, cvec)
-        cdef Price price = Price.from_mem_c(mem)
+        cdef Price_t mem
+        cdef uint8_t status = synthetic_instrument_try_calculate(&self._mem, cvec, &mem)
 
         PyMem_Free(cvec.ptr) # De-allocate buffer
         PyMem_Free(cvec) # De-allocate cvec
 
-        return price
+        check_ffi(status)
+        return Price.from_mem_c(mem)
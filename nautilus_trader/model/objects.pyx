@@ -31,6 +31,7 @@ from libc.stdint cimport uint8_t
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.rust.core cimport precision_from_cstr
 from nautilus_trader.core.rust.model cimport FIXED_SCALAR as RUST_FIXED_SCALAR
 from nautilus_trader.core.rust.model cimport MONEY_MAX as RUST_MONEY_MAX
@@ -116,7 +117,7 @@ cdef class Quantity:
                 f"invalid `value` less than `QUANTITY_MIN` {QUANTITY_MIN:_}, was {value:_}",
             )
 
-        self._mem = quantity_new(value, precision)
+        check_ffi(quantity_new(value, precision, &self._mem))
 
     def __getstate__(self):
         return self._mem.raw, self._mem.precision
@@ -335,7 +336,9 @@ cdef class Quantity:
 
     @staticmethod
     cdef Quantity from_str_c(str value):
-        return Quantity(float(value), precision=precision_from_cstr(pystr_to_cstr(value)))
+        cdef uint8_t precision
+        check_ffi(precision_from_cstr(pystr_to_cstr(value), &precision))
+        return Quantity(float(value), precision=precision)
 
     @staticmethod
     cdef Quantity from_int_c(int value):
@@ -508,7 +511,7 @@ cdef class Price:
                 f"invalid `value` less than `PRICE_MIX` {PRICE_MIN:_}, was {value:_}",
             )
 
-        self._mem = price_new(value, precision)
+        check_ffi(price_new(value, precision, &self._mem))
 
     def __getstate__(self):
         return self._mem.raw, self._mem.precision
@@ -723,7 +726,9 @@ cdef class Price:
 
     @staticmethod
     cdef Price from_str_c(str value):
-        return Price(float(value), precision=precision_from_cstr(pystr_to_cstr(value)))
+        cdef uint8_t precision
+        check_ffi(precision_from_cstr(pystr_to_cstr(value), &precision))
+        return Price(float(value), precision=precision)
 
     @staticmethod
     cdef Price from_int_c(int value):
@@ -841,7 +846,7 @@ cdef class Money:
             )
 
         cdef Currency_t currency_t = currency._mem
-        self._mem = money_new(value_f64, currency_clone(&currency_t))
+        check_ffi(money_new(value_f64, currency_clone(&currency_t), &self._mem))
 
     def __del__(self) -> None:
         if self._mem.currency.code != NULL:
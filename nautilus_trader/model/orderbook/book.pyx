@@ -27,6 +27,8 @@ from libc.stdint cimport uint64_t
 
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.core.ffi cimport check_ffi
+from nautilus_trader.core.ffi cimport last_ffi_error
 from nautilus_trader.core.rust.core cimport CVec
 from nautilus_trader.core.rust.model cimport BookOrder_t
 from nautilus_trader.core.rust.model cimport Price_t
@@ -48,9 +50,11 @@ from nautilus_trader.core.rust.model cimport orderbook_count
 from nautilus_trader.core.rust.model cimport orderbook_delete
 from nautilus_trader.core.rust.model cimport orderbook_delta_clone
 from nautilus_trader.core.rust.model cimport orderbook_drop
+from nautilus_trader.core.rust.model cimport orderbook_get_avg_px_for_quantity
 from nautilus_trader.core.rust.model cimport orderbook_has_ask
 from nautilus_trader.core.rust.model cimport orderbook_has_bid
 from nautilus_trader.core.rust.model cimport orderbook_instrument_id
+from nautilus_trader.core.rust.model cimport orderbook_microprice
 from nautilus_trader.core.rust.model cimport orderbook_midpoint
 from nautilus_trader.core.rust.model cimport orderbook_new
 from nautilus_trader.core.rust.model cimport orderbook_pprint_to_cstr
@@ -62,6 +66,7 @@ from nautilus_trader.core.rust.model cimport orderbook_ts_last
 from nautilus_trader.core.rust.model cimport orderbook_update
 from nautilus_trader.core.rust.model cimport orderbook_update_quote_tick
 from nautilus_trader.core.rust.model cimport orderbook_update_trade_tick
+from nautilus_trader.core.rust.model cimport orderbook_volume_imbalance
 from nautilus_trader.core.rust.model cimport vec_fills_drop
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.model.data.book cimport BookOrder
@@ -320,8 +325,6 @@ cdef class OrderBook(Data):
         """
         Check book integrity.
 
-        For now will panic from Rust and print the error message to stdout.
-
         For all order books:
         - The bid side price should not be greater than the ask side price.
 
@@ -331,7 +334,8 @@ cdef class OrderBook(Data):
             If any check fails.
 
         """
-        orderbook_check_integrity(&self._mem)
+        if orderbook_check_integrity(&self._mem) != 0:
+            raise BookIntegrityError(last_ffi_error())
 
     cpdef best_bid_price(self):
         """
@@ -345,7 +349,9 @@ cdef class OrderBook(Data):
         if not orderbook_has_bid(&self._mem):
             return None
 
-        return Price.from_mem_c(orderbook_best_bid_price(&self._mem))
+        cdef Price_t price
+        check_ffi(orderbook_best_bid_price(&self._mem, &price))
+        return Price.from_mem_c(price)
 
     cpdef best_ask_price(self):
         """
@@ -359,7 +365,9 @@ cdef class OrderBook(Data):
         if not orderbook_has_ask(&self._mem):
             return None
 
-        return Price.from_mem_c(orderbook_best_ask_price(&self._mem))
+        cdef Price_t price
+        check_ffi(orderbook_best_ask_price(&self._mem, &price))
+        return Price.from_mem_c(price)
 
     cpdef best_bid_size(self):
         """
@@ -373,7 +381,9 @@ cdef class OrderBook(Data):
         if not orderbook_has_bid(&self._mem):
             return None
 
-        return Quantity.from_mem_c(orderbook_best_bid_size(&self._mem))
+        cdef Quantity_t size
+        check_ffi(orderbook_best_bid_size(&self._mem, &size))
+        return Quantity.from_mem_c(size)
 
     cpdef best_ask_size(self):
         """
@@ -387,7 +397,9 @@ cdef class OrderBook(Data):
         if not orderbook_has_ask(&self._mem):
             return None
 
-        return Quantity.from_mem_c(orderbook_best_ask_size(&self._mem))
+        cdef Quantity_t size
+        check_ffi(orderbook_best_ask_size(&self._mem, &size))
+        return Quantity.from_mem_c(size)
 
     cpdef spread(self):
         """
@@ -401,7 +413,9 @@ cdef class OrderBook(Data):
         if not orderbook_has_bid(&self._mem) or not orderbook_has_ask(&self._mem):
             return None
 
-        return orderbook_spread(&self._mem)
+        cdef double spread
+        check_ffi(orderbook_spread(&self._mem, &spread))
+        return spread
 
     cpdef midpoint(self):
         """
@@ -415,7 +429,72 @@ cdef class OrderBook(Data):
         if not orderbook_has_bid(&self._mem) or not orderbook_has_ask(&self._mem):
             return None
 
-        return orderbook_midpoint(&self._mem)
+        cdef double midpoint
+        check_ffi(orderbook_midpoint(&self._mem, &midpoint))
+        return midpoint
+
+    cpdef double get_volume_imbalance(self, int depth=1):
+        """
+        Return the volume imbalance over the top `depth` levels of each side.
+
+        The imbalance is ``(bid_volume - ask_volume) / (bid_volume + ask_volume)``,
+        and is zero if the book is empty.
+
+        Parameters
+        ----------
+        depth : int, default 1
+            The number of levels from the top of each side to include.
+
+        Returns
+        -------
+        double
+
+        """
+        return orderbook_volume_imbalance(&self._mem, depth)
+
+    cpdef get_microprice(self):
+        """
+        Return the size weighted microprice (if no market exists then returns ``None``).
+
+        Returns
+        -------
+        double or ``None``
+
+        """
+        if not orderbook_has_bid(&self._mem) or not orderbook_has_ask(&self._mem):
+            return None
+
+        cdef double microprice
+        check_ffi(orderbook_microprice(&self._mem, &microprice))
+        return microprice
+
+    cpdef double get_avg_px_for_quantity(self, Quantity quantity, OrderSide order_side):
+        """
+        Return the average fill price from consuming the given quantity of liquidity.
+
+        A ``BUY`` consumes the asks and a ``SELL`` consumes the bids. If there is
+        insufficient liquidity then the average is taken over the available size.
+
+        Parameters
+        ----------
+        quantity : Quantity
+            The quantity to consume.
+        order_side : OrderSide
+            The side of the consuming order.
+
+        Returns
+        -------
+        double
+
+        Raises
+        ------
+        ValueError
+            If `order_side` is ``NO_ORDER_SIDE``.
+
+        """
+        cdef double avg_px
+        check_ffi(orderbook_get_avg_px_for_quantity(&self._mem, quantity._mem, order_side, &avg_px))
+        return avg_px
 
     cpdef list simulate_fills(self, Order order, uint8_t price_prec, bint is_aggressive):
         """
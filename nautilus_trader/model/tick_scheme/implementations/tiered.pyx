@@ -15,7 +15,10 @@
 
 import numpy as np
 
+from libc.stdint cimport uint8_t
+
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.ffi cimport check_ffi
 from nautilus_trader.core.rust.core cimport precision_from_cstr
 from nautilus_trader.core.string cimport pystr_to_cstr
 from nautilus_trader.model.objects cimport Price
@@ -66,10 +69,11 @@ cdef class TieredTickScheme(TickScheme):
     cpdef _build_ticks(self):
         # Expand mappings into the full tick values
         cdef list all_ticks = []
+        cdef uint8_t precision
         for start, stop, step in self.tiers:
             if stop == np.inf:
                 stop = start + ((self.max_ticks_per_tier + 1) * step)
-            precision = precision_from_cstr(pystr_to_cstr(str(step)))
+            check_ffi(precision_from_cstr(pystr_to_cstr(str(step)), &precision))
             ticks = [Price(x, precision) for x in np.arange(start, stop, step)]
             if len(ticks) > self.max_ticks_per_tier+1:
                 print(f"{self.name}: too many ticks for tier ({start=}, {stop=}, {step=}, trimming to {self.max_ticks_per_tier} (from {len(ticks)})")
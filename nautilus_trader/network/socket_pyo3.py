@@ -14,19 +14,20 @@
 # -------------------------------------------------------------------------------------------------
 
 import asyncio
+from collections.abc import Coroutine
 from typing import Callable, Optional
 
 from nautilus_trader.common.logging import Logger
 from nautilus_trader.common.logging import LoggerAdapter
 from nautilus_trader.core.nautilus_pyo3.network import SocketClient as RustSocketClient
-from nautilus_trader.network.error import MaxRetriesExceeded
 
 
 class SocketClient:
     """
     Provides a base class for asynchronous raw socket clients.
 
-    The client is capable of reconnects (with max retries).
+    The client is capable of reconnects (with max retries), which are handled
+    with exponential backoff by the underlying Rust client.
 
     Parameters
     ----------
@@ -43,8 +44,11 @@ class SocketClient:
     suffix : bytes, optional
         The message suffix, line feed delimiter on which to split messages.
         If ``None`` then will use a standard CRLF suffix.
+    delimiter : bytes, optional
+        The delimiter on which to split received messages, if different from
+        the `suffix`. An empty delimiter passes on data as it is received.
     max_retries : int, default 6
-        The maximum number of times the client will auto-reconnect before raising an exception.
+        The maximum number of consecutive reconnect attempts before the client gives up.
     name : str, optional
         The custom name for the client (used for logging).
     """
@@ -57,6 +61,7 @@ class SocketClient:
         handler: Callable[[bytes], None],
         ssl: bool = True,
         suffix: Optional[bytes] = None,
+        delimiter: Optional[bytes] = None,
         max_retries: int = 6,
         name: Optional[str] = None,
     ) -> None:
@@ -65,15 +70,13 @@ class SocketClient:
         self._port: int = port
         self._ssl: bool = ssl
         self._suffix: bytes = suffix or b"\r\n"
+        self._delimiter: Optional[bytes] = delimiter
 
         self._handler: Callable[[bytes], None] = handler
         self._max_retries: int = max_retries
-        self._check_interval: float = 1.0
-        self._check_task: Optional[asyncio.Task] = None
+        self._loop: Optional[asyncio.AbstractEventLoop] = None
         self._client: Optional[RustSocketClient] = None
 
-        self._connection_retry_count = 0
-
     async def post_connection(self) -> None:
         """
         Actions to be performed post connection.
@@ -98,40 +101,16 @@ class SocketClient:
         # Override to implement additional disconnection related behaviour
         # (canceling ping tasks etc.).
 
-    async def _check_connection(self):
-        self._log.info(
-            f"Scheduled auto-reconnects (max_retries={self._max_retries} with exponential backoff).",
-        )
-        try:
-            while True:
-                await asyncio.sleep(self._check_interval)
-                if self.is_connected:
-                    continue
-
-                self._log.warning(
-                    f"Reconnecting {self._connection_retry_count=}, {self._max_retries=} ...",
-                )
-                if self._max_retries == 0:
-                    raise MaxRetriesExceeded("disconnected with no retries configured")
-                if self._connection_retry_count > self._max_retries:
-                    raise MaxRetriesExceeded(f"max retries of {self._max_retries} exceeded.")
-                await self._reconnect_backoff()
-                self._connection_retry_count += 1
-                self._log.debug(
-                    f"Attempting reconnect ({self._connection_retry_count}/{self._max_retries}).",
-                )
-
-                await self.reconnect()
-                self._connection_retry_count = 0
-        except asyncio.CancelledError:
-            self._log.debug("`_check_connection` task was canceled.")
-
-    async def _reconnect_backoff(self) -> None:
-        if self._connection_retry_count == 0:
-            return  # Immediately attempt first reconnect
-        backoff = 1.5**self._connection_retry_count
-        self._log.debug(f"Exponential backoff: sleeping for {backoff}")
-        await asyncio.sleep(backoff)
+    def _schedule(self, coro_func: Callable[[], Coroutine]) -> None:
+        # Called from the Rust client's own thread
+        assert self._loop is not None  # Type checking
+        asyncio.run_coroutine_threadsafe(coro_func(), self._loop)
+
+    def _on_reconnection(self) -> None:
+        self._schedule(self.post_reconnection)
+
+    def _on_disconnection(self) -> None:
+        self._schedule(self.post_disconnection)
 
     @property
     def host(self) -> str:
@@ -189,26 +168,34 @@ class SocketClient:
         url = f"{self._host}:{self._port}"
         self._log.info(f"Connecting to {url}")
 
-        # TODO: Raise exception on connection failure and log error
+        self._loop = asyncio.get_running_loop()
         self._client = await RustSocketClient.connect(
             url=url,
             handler=self._handler,
             ssl=self._ssl,
             suffix=self._suffix,
+            delimiter=self._delimiter,
+            post_reconnection=self._on_reconnection,
+            post_disconnection=self._on_disconnection,
+            reconnect_max_attempts=self._max_retries,
         )
 
         self._log.info("Connected.")
         await self.post_connection()
-        if self._check_task is None:
-            self._check_task = asyncio.create_task(self._check_connection())
 
-    async def reconnect(self) -> None:
+    @property
+    def connection_retry_count(self) -> int:
         """
-        Reconnect the client to the server.
+        Return the total number of reconnect attempts made by the client.
+
+        Returns
+        -------
+        int
 
         """
-        await self.connect()
-        await self.post_reconnection()
+        if self._client is None:
+            return 0
+        return self._client.connection_retry_count
 
     async def disconnect(self) -> None:
         """
@@ -221,13 +208,8 @@ class SocketClient:
         assert self._client is not None  # Type checking
 
         self._log.info("Disconnecting...")
-        if self._check_task is not None:
-            self._check_task.cancel()
-            self._check_task = None
-
         await self._client.disconnect()
         self._log.info("Disconnected.")
-        await self.post_disconnection()
 
     async def send(self, data: bytes) -> None:
         """
@@ -28,11 +28,13 @@ from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.datetime cimport as_utc_index
 from nautilus_trader.core.rust.core cimport CVec
+from nautilus_trader.core.rust.core cimport CVecElementType
 from nautilus_trader.core.rust.core cimport secs_to_nanos
 from nautilus_trader.core.rust.model cimport Data_t
 from nautilus_trader.core.rust.model cimport Data_t_Tag
 from nautilus_trader.model.data.bar cimport Bar
 from nautilus_trader.model.data.bar cimport BarType
+from nautilus_trader.model.data.book cimport OrderBookDelta
 from nautilus_trader.model.data.tick cimport QuoteTick
 from nautilus_trader.model.data.tick cimport TradeTick
 from nautilus_trader.model.enums_c cimport AggressorSide
@@ -45,6 +47,9 @@ from nautilus_trader.model.objects cimport Quantity
 # Safety: Do NOT deallocate the capsule here
 cdef inline list capsule_to_data_list(object capsule):
     cdef CVec* data = <CVec*>PyCapsule_GetPointer(capsule, NULL)
+    if data.element_type != CVecElementType.CVecElementType_DATA:
+        raise TypeError(f"Invalid capsule elements, expected `Data` (was {data.element_type})")
+
     cdef Data_t* ptr = <Data_t*>data.ptr
     cdef list ticks = []
 
@@ -54,6 +59,10 @@ cdef inline list capsule_to_data_list(object capsule):
             ticks.append(TradeTick.from_mem_c(ptr[i].trade))
         elif ptr[i].tag == Data_t_Tag.QUOTE:
             ticks.append(QuoteTick.from_mem_c(ptr[i].quote))
+        elif ptr[i].tag == Data_t_Tag.DELTA:
+            ticks.append(OrderBookDelta.from_mem_c(ptr[i].delta))
+        elif ptr[i].tag == Data_t_Tag.BAR:
+            ticks.append(Bar.from_mem_c(ptr[i].bar))
 
     return ticks
 
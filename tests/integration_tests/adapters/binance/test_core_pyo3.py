@@ -0,0 +1,174 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pkgutil
+
+import pytest
+
+from nautilus_trader.core.nautilus_pyo3.adapters import BinanceClient
+from nautilus_trader.model.data.bar import Bar
+from nautilus_trader.model.data.book import OrderBookDelta
+from nautilus_trader.model.data.tick import TradeTick
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.persistence.wranglers import list_from_capsule
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+
+
+ETHUSDT = TestInstrumentProvider.ethusdt_binance()
+
+# Example from the Binance API documentation
+API_SECRET = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j"
+
+
+def _ws_message(resource: str) -> bytes:
+    return pkgutil.get_data(
+        package="tests.integration_tests.adapters.binance.resources.ws_messages",
+        resource=resource,
+    )
+
+
+def _client(**kwargs) -> BinanceClient:
+    client = BinanceClient(account_type="SPOT", **kwargs)
+    client.add_instrument(
+        "ETHUSDT",
+        str(ETHUSDT.id),
+        ETHUSDT.price_precision,
+        ETHUSDT.size_precision,
+    )
+    return client
+
+
+class TestBinanceClientPyo3:
+    def test_urls(self):
+        # Arrange, Act
+        client = BinanceClient(account_type="USDT_FUTURE", testnet=True)
+
+        # Assert
+        assert client.account_type == "USDT_FUTURE"
+        assert client.base_url == "https://testnet.binancefuture.com"
+        assert client.ws_base_url == "wss://stream.binancefuture.com"
+        assert client.api_key is None
+
+    def test_invalid_account_type_raises(self):
+        with pytest.raises(ValueError):
+            BinanceClient(account_type="MARGIN")
+
+    def test_signed_query(self):
+        # Arrange
+        client = _client(api_key="key", api_secret=API_SECRET)
+        params = [
+            ("symbol", "LTCBTC"),
+            ("side", "BUY"),
+            ("type", "LIMIT"),
+            ("timeInForce", "GTC"),
+            ("quantity", "1"),
+            ("price", "0.1"),
+        ]
+
+        # Act
+        query = client.signed_query(params, 1499827319559, recv_window_ms=5000)
+
+        # Assert
+        assert query.endswith(
+            "&signature=c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71",
+        )
+
+    def test_sign_without_credentials_raises(self):
+        with pytest.raises(ValueError):
+            _client().sign("timestamp=1")
+
+    def test_parse_trade(self):
+        # Arrange
+        client = _client()
+
+        # Act
+        capsule = client.parse_market_data(_ws_message("ws_spot_trade.json"), 1)
+        data = list_from_capsule(capsule)
+
+        # Assert
+        assert len(data) == 1
+        trade = data[0]
+        assert isinstance(trade, TradeTick)
+        assert trade.instrument_id == ETHUSDT.id
+        assert trade.price == Price.from_str("4149.74")
+        assert trade.size == Quantity.from_str("0.43870")
+        assert trade.aggressor_side == AggressorSide.SELLER
+        assert trade.trade_id == TradeId("705291099")
+        assert trade.ts_init == 1
+
+    def test_parse_depth_update(self):
+        # Arrange
+        client = _client()
+        message = b"""{"e":"depthUpdate","E":123456789,"s":"ETHUSDT","U":157,"u":160,
+            "b":[["1600.00","10.00000"]],"a":[["1601.00","0.00000"]]}"""
+
+        # Act
+        data = list_from_capsule(client.parse_market_data(message, 0))
+
+        # Assert
+        assert len(data) == 2
+        assert all(isinstance(delta, OrderBookDelta) for delta in data)
+        assert data[0].action == BookAction.UPDATE
+        assert data[1].action == BookAction.DELETE
+
+    def test_parse_closed_kline(self):
+        # Arrange
+        client = _client()
+        message = b"""{"e":"kline","E":1,"s":"ETHUSDT","k":{"t":0,"T":59999,"s":"ETHUSDT",
+            "i":"1m","o":"1600.00","c":"1601.00","h":"1602.00","l":"1599.00","v":"5.00000",
+            "x":true}}"""
+
+        # Act
+        data = list_from_capsule(client.parse_market_data(message, 0))
+
+        # Assert
+        assert len(data) == 1
+        bar = data[0]
+        assert isinstance(bar, Bar)
+        assert str(bar.bar_type) == "ETHUSDT.BINANCE-1-MINUTE-LAST-EXTERNAL"
+        assert bar.close == Price.from_str("1601.00")
+
+    def test_parse_execution_report(self):
+        # Arrange
+        client = _client()
+        message = b"""{"e":"executionReport","E":1,"s":"ETHUSDT","c":"O-1","S":"SELL",
+            "o":"MARKET","f":"GTC","q":"1.00000000","p":"0.00000000","C":"","x":"TRADE",
+            "X":"FILLED","i":42,"l":"1.00000000","z":"1.00000000","L":"1600.00000000",
+            "n":"0.00100000","N":"BNB","T":2,"t":7}"""
+
+        # Act
+        report = client.parse_execution_report(message, 3)
+
+        # Assert
+        assert report.instrument_id == "ETHUSDT.BINANCE"
+        assert report.client_order_id == "O-1"
+        assert report.venue_order_id == "42"
+        assert report.exec_type == "TRADE"
+        assert report.last_px == "1600.00"
+        assert report.last_qty == "1.00000"
+        assert report.trade_id == "7"
+        assert report.is_fill
+        assert report.ts_event == 2_000_000
+
+    def test_parse_execution_report_ignores_market_data(self):
+        # Arrange
+        client = _client()
+
+        # Act, Assert
+        assert client.parse_execution_report(_ws_message("ws_spot_trade.json"), 0) is None
@@ -13,6 +13,7 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+import asyncio
 from collections.abc import Coroutine
 from typing import Any, Callable
 
@@ -32,7 +33,19 @@ async def fixture_test_server(
     async def hello(request):
         return web.Response(text="Hello, world")
 
+    async def echo_headers(request):
+        return web.Response(
+            text=request.headers.get("x-api-key", ""),
+            headers={"x-request-id": "abc-123"},
+        )
+
+    async def slow(request):
+        await asyncio.sleep(3)
+        return web.Response(text="Too late")
+
     app = web.Application()
+    app.router.add_route("GET", "/headers", echo_headers)
+    app.router.add_route("GET", "/slow", slow)
     app.router.add_route("GET", "/get", hello)
     app.router.add_route("POST", "/post", hello)
     app.router.add_route("PATCH", "/patch", hello)
@@ -117,3 +130,34 @@ async def test_client_delete(test_server: Coroutine) -> None:
     # Assert
     assert response.status == 200
     assert len(response.body) > 0
+
+
+@pytest.mark.asyncio()
+async def test_client_default_headers(test_server: Coroutine) -> None:
+    # Arrange
+    server: TestServer = await test_server
+    client = HttpClient(
+        header_keys=["x-request-id"],
+        default_headers={"x-api-key": "secret"},
+    )
+    url = f"http://{server.host}:{server.port}/headers"
+
+    # Act
+    response: HttpResponse = await client.get(url, headers={})
+
+    # Assert
+    assert response.is_success
+    assert response.body == b"secret"
+    assert response.headers == {"x-request-id": "abc-123"}
+
+
+@pytest.mark.asyncio()
+async def test_client_request_timeout(test_server: Coroutine) -> None:
+    # Arrange
+    server: TestServer = await test_server
+    client = HttpClient()
+    url = f"http://{server.host}:{server.port}/slow"
+
+    # Act, Assert
+    with pytest.raises(Exception, match="timed out"):
+        await client.get(url, headers={}, timeout_secs=1)
@@ -0,0 +1,58 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import time
+
+import pytest
+
+from nautilus_trader.core.nautilus_pyo3.network import Quota
+from nautilus_trader.core.nautilus_pyo3.network import RateLimiter
+
+
+def test_quota_rates() -> None:
+    # Arrange, Act
+    quota = Quota.rate_per_second(4).allow_burst(8)
+
+    # Assert
+    assert quota.burst_size == 8
+    assert quota.replenish_interval_ms == 250.0
+
+
+def test_check_limits_keys() -> None:
+    # Arrange
+    limiter = RateLimiter(
+        keyed_quotas=[("orders", Quota.rate_per_minute(2))],
+        default_quota=None,
+    )
+
+    # Act, Assert
+    assert limiter.check(["orders"]) is None
+    assert limiter.check(["orders"]) is None
+    assert limiter.check(["orders"]) > 0
+    assert limiter.check(["unlimited"]) is None
+
+
+@pytest.mark.asyncio()
+async def test_until_ready_waits() -> None:
+    # Arrange
+    limiter = RateLimiter(keyed_quotas=[("orders", Quota.with_period_ms(50))])
+
+    # Act
+    start = time.monotonic()
+    for _ in range(3):
+        await limiter.until_ready(["orders"])
+
+    # Assert
+    assert time.monotonic() - start >= 0.1
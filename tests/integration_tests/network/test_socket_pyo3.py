@@ -42,7 +42,6 @@ async def test_socket_connect(socket_server):
     await eventually(lambda: messages >= [b"hello"] * 6)
 
 
-@pytest.mark.skip(reason="Rust client may be reconnecting automatically already")
 @pytest.mark.asyncio()
 async def test_socket_reconnect_on_incomplete_read(closing_socket_server):
     # Arrange
@@ -63,4 +62,5 @@ async def test_socket_reconnect_on_incomplete_read(closing_socket_server):
 
     # Assert
     # Server will automatically close - reconnect and receive another message
-    await eventually(lambda: client._connection_retry_count >= 1)
+    await eventually(lambda: client.connection_retry_count >= 1)
+    await eventually(lambda: messages >= [b"hello"] * 2)
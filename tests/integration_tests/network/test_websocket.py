@@ -105,18 +105,26 @@ async def test_reconnect_after_close(websocket_server):
     await eventually(lambda: store == [b"connected"] * 2)
 
 
-# @pytest.mark.asyncio()
-# async def test_exponential_backoff(self, websocket_server):
-#     # Arrange
-#     store = []
-#     client = await WebSocketClient.connect(
-#         url=_server_url(websocket_server),
-#         handler=store.append,
-#     )
-#
-#     # Act
-#     for _ in range(2):
-#         await self.client.send(b"close")
-#         await asyncio.sleep(0.1)
-#
-#     assert client.connection_retry_count == 2
+@pytest.mark.asyncio()
+async def test_reconnect_counts_retries(websocket_server):
+    # Arrange
+    store = []
+    reconnections = []
+    client = await WebSocketClient.connect(
+        url=_server_url(websocket_server),
+        handler=store.append,
+        post_reconnection=lambda: reconnections.append(True),
+        reconnect_delay_initial_ms=10,
+    )
+    await eventually(lambda: client.is_alive)
+
+    # Act
+    for count in range(1, 3):
+        await client.send(b"close")
+        await eventually(lambda: len(reconnections) == count, timeout=3.0)  # noqa: B023
+
+    # Assert
+    assert client.connection_retry_count == 2
+    assert store == [b"connected"] * 3
+    await client.disconnect()
+    await eventually(lambda: not client.is_alive)
@@ -0,0 +1,111 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pickle
+
+import pytest
+
+from nautilus_trader.core.nautilus_pyo3.model import Bar
+from nautilus_trader.core.nautilus_pyo3.model import Currency
+from nautilus_trader.core.nautilus_pyo3.model import InstrumentId
+from nautilus_trader.core.nautilus_pyo3.model import Money
+from nautilus_trader.core.nautilus_pyo3.model import OrderSide
+from nautilus_trader.core.nautilus_pyo3.model import Price
+from nautilus_trader.core.nautilus_pyo3.model import Quantity
+from nautilus_trader.core.nautilus_pyo3.model import QuoteTick
+from nautilus_trader.core.nautilus_pyo3.model import StrategyId
+from nautilus_trader.core.nautilus_pyo3.model import Symbol
+from nautilus_trader.core.nautilus_pyo3.model import TraderId
+from nautilus_trader.core.nautilus_pyo3.model import Venue
+
+
+USD = Currency.from_str("USD")
+ETHUSDT_BINANCE = InstrumentId(Symbol("ETHUSDT"), Venue("BINANCE"))
+
+OBJECTS = [
+    TraderId("TRADER-001"),
+    StrategyId("S-001"),
+    ETHUSDT_BINANCE,
+    Price(1.01, 2),
+    Quantity(100.0, 0),
+    USD,
+    Money(1.5, USD),
+    OrderSide.BUY,
+    QuoteTick(
+        ETHUSDT_BINANCE,
+        Price(1.00, 2),
+        Price(1.01, 2),
+        Quantity(1.0, 0),
+        Quantity(2.0, 0),
+        1,
+        2,
+    ),
+    Bar(
+        "ETHUSDT.BINANCE-1-MINUTE-LAST-EXTERNAL",
+        Price(1.00, 2),
+        Price(1.02, 2),
+        Price(0.99, 2),
+        Price(1.01, 2),
+        Quantity(10.0, 0),
+        1,
+        2,
+    ),
+]
+
+
+class TestModelPyo3:
+    @pytest.mark.parametrize("obj", OBJECTS)
+    def test_pickling_round_trip(self, obj):
+        # Arrange, Act
+        unpickled = pickle.loads(pickle.dumps(obj))  # noqa: S301 (pickle is safe here)
+
+        # Assert
+        assert unpickled == obj
+        assert hash(unpickled) == hash(obj)
+        assert str(unpickled) == str(obj)
+
+    def test_identifier_str_and_repr(self):
+        # Arrange
+        trader_id = TraderId("TRADER-001")
+
+        # Act, Assert
+        assert str(trader_id) == "TRADER-001"
+        assert repr(trader_id) == "TraderId('TRADER-001')"
+        assert trader_id.value == "TRADER-001"
+
+    def test_instrument_id_str_and_repr(self):
+        # Arrange, Act, Assert
+        assert str(ETHUSDT_BINANCE) == "ETHUSDT.BINANCE"
+        assert repr(ETHUSDT_BINANCE) == "InstrumentId('ETHUSDT.BINANCE')"
+        assert ETHUSDT_BINANCE == InstrumentId.from_str("ETHUSDT.BINANCE")
+
+    def test_invalid_identifier_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            TraderId("TRADER")
+
+    def test_price_comparisons(self):
+        # Arrange, Act, Assert
+        assert Price(1.00, 2) < Price(1.01, 2)
+        assert Price(1.00, 2) == Price.from_str("1.00")
+        assert repr(Price(1.00, 2)) == "Price('1.00')"
+
+    def test_money_comparison_with_different_currencies_raises_value_error(self):
+        # Arrange
+        eur = Currency.from_str("EUR")
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            assert Money(1.0, USD) < Money(1.0, eur)